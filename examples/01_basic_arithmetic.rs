@@ -1,30 +1,31 @@
 //! Demo 1: Basic Arithmetic with Lazy Evaluation
 //!
 //! This example demonstrates the core graph evaluation system:
-//! - Creating operators (ConstantOp, AddOp, MultiplyOp)
-//! - Connecting operators to form a computation graph
+//! - Creating operators (Constant, Add, Multiply) by registry name
+//! - Wiring ports by name with GraphBuilder
 //! - Lazy evaluation with dirty flag tracking
 //! - Modifying operator values and re-evaluating
 //!
 //! Run with: `cargo run --example 01_basic_arithmetic`
 
 use flux_core::EvalContext;
-use flux_graph::Graph;
-use flux_operators::{AddOp, ConstantOp, MultiplyOp};
+use flux_graph::GraphBuilder;
+use flux_operators::{create_default_registry, ConstantOp};
 
 fn main() {
     println!("╔════════════════════════════════════════╗");
     println!("║ Demo 1: Basic Arithmetic + Lazy Eval   ║");
     println!("╚════════════════════════════════════════╝\n");
 
-    let mut graph = Graph::new();
+    let registry = create_default_registry();
+    let b = GraphBuilder::new(&registry);
 
     // Create operators
-    let const_a = graph.add(ConstantOp::new(5.0));
-    let const_b = graph.add(ConstantOp::new(3.0));
-    let const_c = graph.add(ConstantOp::new(2.0));
-    let add = graph.add(AddOp::new());
-    let multiply = graph.add(MultiplyOp::new());
+    let const_a = b.node_with("Constant", |n| n.input("Value", 5.0));
+    let const_b = b.node_with("Constant", |n| n.input("Value", 3.0));
+    let const_c = b.node_with("Constant", |n| n.input("Value", 2.0));
+    let add = b.node("Add");
+    let multiply = b.node("Multiply");
 
     // Connect: (A + B) * C
     //
@@ -33,38 +34,33 @@ fn main() {
     //  const_b (3) ──┘          ├──▶ multiply ──▶ output
     //  const_c (2) ─────────────┘
     //
-    graph
-        .connect(const_a, 0, add, 0)
-        .expect("connect A -> Add.A");
-    graph
-        .connect(const_b, 0, add, 1)
-        .expect("connect B -> Add.B");
-    graph
-        .connect(add, 0, multiply, 0)
-        .expect("connect Add -> Multiply.A");
-    graph
-        .connect(const_c, 0, multiply, 1)
-        .expect("connect C -> Multiply.B");
+    b.wire(const_a.out("Value"), add.input_ref("A"));
+    b.wire(const_b.out("Value"), add.input_ref("B"));
+    b.wire(add.out("Result"), multiply.input_ref("A"));
+    b.wire(const_c.out("Value"), multiply.input_ref("B"));
+
+    let (mut graph, handles) = b.build().expect("build graph");
+    let output = handles.output(&multiply.out("Result")).expect("Multiply.Result");
 
     let mut ctx = EvalContext::new();
 
     println!("--- First Evaluation ---");
-    let result = graph.evaluate(multiply, 0, &ctx);
+    let result = graph.evaluate_ref(output, &ctx);
     println!("Result: (5 + 3) * 2 = {:?}\n", result);
 
     println!("--- Second Evaluation (no changes, should skip) ---");
     ctx.advance(0.016);
-    let result = graph.evaluate(multiply, 0, &ctx);
+    let result = graph.evaluate_ref(output, &ctx);
     println!("Result: {:?}\n", result);
 
     println!("--- Changing A from 5 to 10 ---\n");
-    if let Some(constant) = graph.get_mut_as::<ConstantOp>(const_a) {
+    if let Some(constant) = graph.get_mut_as::<ConstantOp>(handles.id(const_a)) {
         constant.set_value(10.0);
     }
 
     println!("--- Third Evaluation (A changed, should recompute) ---");
     ctx.advance(0.016);
-    let result = graph.evaluate(multiply, 0, &ctx);
+    let result = graph.evaluate_ref(output, &ctx);
     println!("Result: (10 + 3) * 2 = {:?}\n", result);
 
     println!("Graph stats: {:?}", graph.stats());
@@ -4,46 +4,43 @@
 //! - Using SineWaveOp for animated values
 //! - Time progression with EvalContext::advance()
 //! - Frequency and amplitude modulation
+//! - Building the graph by name with GraphBuilder
 //!
 //! Run with: `cargo run --example 02_sine_wave`
 
-use flux_core::EvalContext;
-use flux_graph::Graph;
-use flux_operators::{AddOp, ConstantOp, SineWaveOp};
+use flux_core::EvalContext;
+use flux_graph::GraphBuilder;
+use flux_operators::create_default_registry;
 
 fn main() {
     println!("╔════════════════════════════════════════╗");
     println!("║ Demo 2: Time-Based SineWave Operator   ║");
     println!("╚════════════════════════════════════════╝\n");
 
-    let mut graph = Graph::new();
+    let registry = create_default_registry();
+    let b = GraphBuilder::new(&registry);
 
     // Create a modulated sine wave: sin(t) * 0.5 + 0.5 (normalized to 0-1)
-    let freq = graph.add(ConstantOp::new(1.0)); // 1 Hz
-    let amp = graph.add(ConstantOp::new(0.5)); // Half amplitude
-    let sine = graph.add(SineWaveOp::new());
-    let offset = graph.add(ConstantOp::new(0.5)); // DC offset
-    let add_offset = graph.add(AddOp::new());
+    let freq = b.node_with("Constant", |n| n.input("Value", 1.0)); // 1 Hz
+    let amp = b.node_with("Constant", |n| n.input("Value", 0.5)); // Half amplitude
+    let sine = b.node("SineWave");
+    let offset = b.node_with("Constant", |n| n.input("Value", 0.5)); // DC offset
+    let add_offset = b.node("Add");
 
     // Connect: sine(freq, amp) + offset
-    graph
-        .connect(freq, 0, sine, 0)
-        .expect("freq -> sine.Frequency");
-    graph
-        .connect(amp, 0, sine, 1)
-        .expect("amp -> sine.Amplitude");
-    graph
-        .connect(sine, 0, add_offset, 0)
-        .expect("sine -> add.A");
-    graph
-        .connect(offset, 0, add_offset, 1)
-        .expect("offset -> add.B");
+    b.wire(freq.out("Value"), sine.input_ref("Frequency"));
+    b.wire(amp.out("Value"), sine.input_ref("Amplitude"));
+    b.wire(sine.out("Value"), add_offset.input_ref("A"));
+    b.wire(offset.out("Value"), add_offset.input_ref("B"));
+
+    let (mut graph, handles) = b.build().expect("build graph");
+    let output = handles.output(&add_offset.out("Result")).expect("Add.Result");
 
     let mut ctx = EvalContext::new();
 
     println!("Sine wave over time (normalized 0-1):");
     for _ in 0..5 {
-        let result = graph.evaluate(add_offset, 0, &ctx);
+        let result = graph.evaluate_ref(output, &ctx);
         println!("  t={:.2}s: {:?}", ctx.time, result);
         ctx.advance(0.25); // Advance 0.25 seconds
     }
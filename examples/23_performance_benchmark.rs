@@ -8,10 +8,12 @@
 //! - Deep graphs (long computation chains)
 //! - Diamond patterns (fan-out/fan-in)
 //! - Time-varying sources
+//! - Animation curve sampling
 //!
 //! Run with: cargo run --example 29_performance_benchmark --release
 
 use flux_core::{EvalContext, Value};
+use flux_graph::animation::{Curve, Keyframe};
 use flux_graph::Graph;
 use flux_operators::{AddOp, ConstantOp, MultiplyOp, SinOp, SineWaveOp};
 use std::time::Instant;
@@ -27,6 +29,7 @@ fn main() {
     bench_deep_graph();
     bench_diamond_graph();
     bench_time_varying();
+    bench_curve_sampling();
 
     println!("\n=== Summary ===\n");
     println!("Performance characteristics:");
@@ -45,6 +48,7 @@ fn main() {
     println!("    - Use compile() for render loops");
     println!("    - Use compile_optimized() for production");
     println!("    - Use evaluate() during graph editing");
+    println!("    - Sample curves in time order to hit the segment cache");
 }
 
 /// Benchmark a wide graph with many parallel branches
@@ -193,10 +197,10 @@ fn bench_diamond_graph() {
 
         // Fan in: accumulate all sin outputs
         let mut current_sum = sin_ids[0];
-        for i in 1..fan {
+        for &sin in &sin_ids[1..fan] {
             let add = graph.add(AddOp::new());
             graph.connect(current_sum, 0, add, 0).unwrap();
-            graph.connect(sin_ids[i], 0, add, 1).unwrap();
+            graph.connect(sin, 0, add, 1).unwrap();
             current_sum = add;
         }
 
@@ -312,3 +316,73 @@ fn bench_time_varying() {
         println!("  {:>8.2}  {:>+10.4}", ctx.time, result.as_float().unwrap_or(0.0));
     }
 }
+
+/// Benchmark curve sampling on a 1000-key curve
+fn bench_curve_sampling() {
+    println!("\n=== Part 5: Animation Curve Sampling ===\n");
+
+    let keys = 1000;
+    let mut curve = Curve::new();
+    for i in 0..keys {
+        curve.add(i as f64, (i as f64 * 0.1).sin());
+    }
+
+    let samples = 100_000;
+    let span = (keys - 1) as f64;
+    let forward: Vec<f64> = (0..samples).map(|i| i as f64 * span / samples as f64).collect();
+    let backward: Vec<f64> = forward.iter().rev().copied().collect();
+    let random: Vec<f64> = (0..samples)
+        .map(|i| ((i as u64).wrapping_mul(2_654_435_761) % 1_000_000) as f64 / 1_000_000.0 * span)
+        .collect();
+
+    println!("  {} keyframes, {} samples per run\n", keys, samples);
+    println!("  {:<22} {:>12}", "Access pattern", "ns/sample");
+    println!("  {:-<22} {:->12}", "", "");
+
+    let linear_keys = curve.keyframes().to_vec();
+    let start = Instant::now();
+    let mut checksum = 0.0;
+    for &t in &forward {
+        checksum += linear_scan_sample(&linear_keys, t);
+    }
+    report("Linear scan (uncached)", start, samples);
+
+    let patterns = [
+        ("Forward (cached)", &forward),
+        ("Backward", &backward),
+        ("Random access", &random),
+    ];
+    for (label, times) in patterns {
+        let start = Instant::now();
+        for &t in times.iter() {
+            checksum += curve.sample(t);
+        }
+        report(label, start, samples);
+    }
+
+    let start = Instant::now();
+    checksum += curve.sample_many(&forward).iter().sum::<f64>();
+    report("sample_many (forward)", start, samples);
+
+    println!("\n  (checksum {:.3})", checksum);
+}
+
+/// Per-sample cost of a run started at `start`
+fn report(label: &str, start: Instant, samples: usize) {
+    let nanos = start.elapsed().as_nanos() as f64 / samples as f64;
+    println!("  {:<22} {:>12.1}", label, nanos);
+}
+
+/// Linear-interpolated sample using a linear segment scan, as a baseline
+fn linear_scan_sample(keys: &[Keyframe], time: f64) -> f64 {
+    if time <= keys[0].time {
+        return keys[0].value;
+    }
+    for pair in keys.windows(2) {
+        if time < pair[1].time {
+            let t = (time - pair[0].time) / (pair[1].time - pair[0].time);
+            return pair[0].value + (pair[1].value - pair[0].value) * t;
+        }
+    }
+    keys[keys.len() - 1].value
+}
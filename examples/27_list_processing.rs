@@ -3,7 +3,7 @@
 //! This example demonstrates Flux's list operators in a connected graph context:
 //! - Multi-input collection (FloatListOp with multiple graph connections)
 //! - Statistical analysis pipeline (Sum, Average, Min, Max)
-//! - List transformations with connected parameters (ListMap)
+//! - List transformations with connected parameters (ListScaleOffset)
 //! - New operators: ListFilter, ListConcat, ListSlice
 //!
 //! Key insight: Lists enable batch processing where a single connection
@@ -16,7 +16,7 @@ use flux_graph::Graph;
 use flux_operators::{
     AddOp, ConstantOp, SineWaveOp, SubtractOp,
     FloatListOp, ListAverageOp, ListGetOp, ListLengthOp,
-    ListMapOp, ListMaxOp, ListMinOp, ListSumOp,
+    ListMaxOp, ListMinOp, ListScaleOffsetOp, ListSumOp,
     ListFilterOp, ListConcatOp, ListSliceOp,
 };
 
@@ -44,7 +44,7 @@ fn main() {
     println!("    ListLength: Count elements");
     println!();
     println!("  Transformation:");
-    println!("    ListMap: Apply scale + offset to all elements");
+    println!("    ListScaleOffset: Apply scale + offset to all elements");
     println!("    ListGet: Extract single element by index");
     println!("    ListFilter: Keep elements matching threshold condition");
     println!("    ListSlice: Extract subrange (supports negative indices)");
@@ -196,8 +196,8 @@ fn demo_list_transformation() {
     let scale = graph.add(ConstantOp::new(2.0));
     let offset = graph.add(ConstantOp::new(100.0));
 
-    // ListMap applies: result[i] = list[i] * scale + offset
-    let map_op = graph.add(ListMapOp::new());
+    // ListScaleOffset applies: result[i] = list[i] * scale + offset
+    let map_op = graph.add(ListScaleOffsetOp::new());
     graph.connect(source_list, 0, map_op, 0).unwrap();  // List input
     graph.connect(scale, 0, map_op, 1).unwrap();        // Scale
     graph.connect(offset, 0, map_op, 2).unwrap();       // Offset
@@ -216,7 +216,7 @@ fn demo_list_transformation() {
     // Chain another transformation
     let scale2 = graph.add(ConstantOp::new(0.5));
     let offset2 = graph.add(ConstantOp::new(-50.0));
-    let map_op2 = graph.add(ListMapOp::new());
+    let map_op2 = graph.add(ListScaleOffsetOp::new());
 
     graph.connect(map_op, 0, map_op2, 0).unwrap();   // Previous result
     graph.connect(scale2, 0, map_op2, 1).unwrap();
@@ -396,7 +396,7 @@ fn demo_sensor_data_pipeline() {
 
     // Convert using dynamic scale: voltage * scale = temperature
     let zero_offset = graph.add(ConstantOp::new(0.0));
-    let to_celsius = graph.add(ListMapOp::new());
+    let to_celsius = graph.add(ListScaleOffsetOp::new());
     graph.connect(readings, 0, to_celsius, 0).unwrap();
     graph.connect(dynamic_scale, 0, to_celsius, 1).unwrap();  // Dynamic scale!
     graph.connect(zero_offset, 0, to_celsius, 2).unwrap();
@@ -428,7 +428,7 @@ fn demo_sensor_data_pipeline() {
     println!("  Pipeline structure:");
     println!("                                 SineWave");
     println!("                                    │");
-    println!("    FloatList ─▶ ListMap ──────────▶+ scale ─┬─▶ Average ─▶ avg");
+    println!("    FloatList ─▶ ListScaleOffset ──▶+ scale ─┬─▶ Average ─▶ avg");
     println!("       [1.2, 2.5, 1.8, 3.1, 2.2]             ├─▶ Min ─┬───▶ min");
     println!("                                             ├─▶ Max ─┼───▶ max");
     println!("                                             │        └─▶ Subtract ─▶ spread");
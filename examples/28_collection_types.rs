@@ -419,7 +419,7 @@ fn demo_conversion_operators() {
 
     // --- Practical Pipeline: Generate points, transform, analyze ---
     println!("  Practical Pipeline:");
-    println!("    IntListRange → IntListToFloatList → ListMap → Statistics");
+    println!("    IntListRange → IntListToFloatList → ListScaleOffset → Statistics");
     println!();
 
     // Generate indices 0-9
@@ -22,7 +22,7 @@ use flux_operators::{
     // Flow operators
     flow::{CounterOp, GateOp, GetFloatVarOp, SwitchOp},
     // List operators
-    list::{FloatListOp, ListAverageOp, ListLengthOp, ListMapOp, ListSumOp},
+    list::{FloatListOp, ListAverageOp, ListLengthOp, ListScaleOffsetOp, ListSumOp},
     // Logic operators
     logic::{AndOp, IntAddOp, IntMultiplyOp, IntToFloatOp, NotOp, OrOp, XorOp},
     // Math operators
@@ -415,13 +415,13 @@ fn main() {
         list_avg.outputs()[0].value.as_float().unwrap()
     );
 
-    let mut list_map = ListMapOp::new();
+    let mut list_map = ListScaleOffsetOp::new();
     list_map.inputs_mut()[0].default = Value::float_list(vec![1.0, 2.0, 3.0]);
     list_map.inputs_mut()[1].default = Value::Float(2.0); // Multiplier
     list_map.inputs_mut()[2].default = Value::Float(10.0); // Offset
     list_map.compute(&ctx, &no_conn);
     let mapped = list_map.outputs()[0].value.as_float_list().unwrap();
-    println!("  ListMap([1,2,3], *2, +10) = {:?}", mapped);
+    println!("  ListScaleOffset([1,2,3], *2, +10) = {:?}", mapped);
 
     println!("\n--- Utility Operators ---");
 
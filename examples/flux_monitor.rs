@@ -0,0 +1,83 @@
+//! Flux Monitor: a live terminal dashboard for a running graph
+//!
+//! This example steps a graph at a fixed rate and redraws a plain-text
+//! dashboard every frame, built from the graph's integration APIs:
+//! - Graph stats (node and connection counts)
+//! - Graph events, drained after every edit
+//! - Watched outputs, printed with the Value pretty-printer
+//! - Per-node timings from evaluation traces (`profile`)
+//! - Operator errors and non-finite outputs
+//!
+//! Type commands on stdin while it runs:
+//!   set ratio B 0       make the ratio divide by zero
+//!   set tint Pattern zz rewire the swizzle
+//!   mute ratio          take the gain out of play (again to restore)
+//!   profile / undo / redo / quit
+//!
+//! The update logic lives in `flux_examples::monitor`, where it is tested
+//! headlessly.
+//!
+//! Run with: cargo run --example flux_monitor [symbol.json]
+
+use std::io::BufRead;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use flux_examples::monitor::Monitor;
+use flux_graph::serialization::load_symbol;
+use flux_operators::create_default_registry;
+
+/// Simulation steps per second
+const RATE: f64 = 30.0;
+
+fn main() {
+    let registry = create_default_registry();
+    let mut monitor = match std::env::args().nth(1) {
+        Some(path) => match load_symbol(&path) {
+            Ok(file) => Monitor::from_symbol(&file, &registry, RATE),
+            Err(e) => {
+                eprintln!("Cannot load {}: {}", path, e);
+                return;
+            }
+        },
+        None => Monitor::demo(&registry, RATE),
+    };
+
+    // Read commands on a separate thread so the dashboard keeps running
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        for line in std::io::stdin().lock().lines().map_while(Result::ok) {
+            if tx.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut reply = String::from("type a command, or 'quit'");
+    let mut last = Instant::now();
+    loop {
+        while let Ok(line) = rx.try_recv() {
+            let line = line.trim();
+            if line == "quit" || line == "q" {
+                return;
+            }
+            if !line.is_empty() {
+                reply = monitor
+                    .execute(line)
+                    .unwrap_or_else(|e| format!("error: {}", e));
+            }
+        }
+
+        let now = Instant::now();
+        monitor.update(now.duration_since(last).as_secs_f64());
+        last = now;
+
+        // Clear the screen and move the cursor home
+        print!("\x1b[2J\x1b[H");
+        println!("{}", monitor.dashboard());
+        println!("> {}", reply);
+
+        thread::sleep(Duration::from_secs_f64(1.0 / RATE));
+    }
+}
@@ -0,0 +1,251 @@
+//! Hierarchical operator categories
+//!
+//! Operators name their category with a string such as `"Math"` or
+//! `"Math/Trig"`; [`Category`] is the parsed form, a path of segments from
+//! the top-level category down. Menus group by it, catalogs sort by it and
+//! colors fall back along it: a theme or palette entry for `Math` also
+//! covers `Math/Trig` unless that has one of its own.
+//!
+//! ```
+//! use flux_core::Category;
+//!
+//! let trig = Category::parse("Math/Trig");
+//! assert_eq!(trig.name(), "Trig");
+//! assert_eq!(trig.parent(), Some(Category::MATH));
+//! assert!(trig.is_within(&Category::MATH));
+//! assert_eq!(trig.to_string(), "Math/Trig");
+//! ```
+
+use std::borrow::Cow;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Separator between segments in the string form
+pub const SEPARATOR: char = '/';
+
+/// A category path such as `Math/Trig`
+///
+/// Never empty: parsing a string without any segments gives
+/// [`Category::UNCATEGORIZED`]. Ordered segment by segment, so a category
+/// sorts directly before its sub-categories.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Category {
+    segments: Cow<'static, [Cow<'static, str>]>,
+}
+
+impl Category {
+    pub const SOURCES: Category = Category::builtin(&[Cow::Borrowed("Sources")]);
+    pub const TIME: Category = Category::builtin(&[Cow::Borrowed("Time")]);
+    pub const MATH: Category = Category::builtin(&[Cow::Borrowed("Math")]);
+    pub const OSCILLATORS: Category = Category::builtin(&[Cow::Borrowed("Oscillators")]);
+    pub const LOGIC: Category = Category::builtin(&[Cow::Borrowed("Logic")]);
+    pub const VECTOR: Category = Category::builtin(&[Cow::Borrowed("Vector")]);
+    pub const COLOR: Category = Category::builtin(&[Cow::Borrowed("Color")]);
+    pub const OUTPUT: Category = Category::builtin(&[Cow::Borrowed("Output")]);
+    pub const LIST: Category = Category::builtin(&[Cow::Borrowed("List")]);
+    pub const FLOW: Category = Category::builtin(&[Cow::Borrowed("Flow")]);
+    pub const UTILITY: Category = Category::builtin(&[Cow::Borrowed("Utility")]);
+    pub const STRING: Category = Category::builtin(&[Cow::Borrowed("String")]);
+    pub const MAP: Category = Category::builtin(&[Cow::Borrowed("Map")]);
+    pub const UNCATEGORIZED: Category = Category::builtin(&[Cow::Borrowed("Uncategorized")]);
+
+    const fn builtin(segments: &'static [Cow<'static, str>]) -> Self {
+        Self { segments: Cow::Borrowed(segments) }
+    }
+
+    /// Parse `"Math/Trig"` style paths
+    ///
+    /// Whitespace around segments and empty segments are ignored.
+    pub fn parse(path: &str) -> Self {
+        Self::from_segments(path.split(SEPARATOR))
+    }
+
+    /// Build a category from its segments, top-level first
+    pub fn from_segments<S: AsRef<str>>(segments: impl IntoIterator<Item = S>) -> Self {
+        let segments: Vec<Cow<'static, str>> = segments
+            .into_iter()
+            .map(|segment| segment.as_ref().trim().to_string())
+            .filter(|segment| !segment.is_empty())
+            .map(Cow::Owned)
+            .collect();
+        if segments.is_empty() {
+            return Self::UNCATEGORIZED;
+        }
+        Self { segments: Cow::Owned(segments) }
+    }
+
+    /// Segments from the top-level category down
+    pub fn segments(&self) -> impl DoubleEndedIterator<Item = &str> + ExactSizeIterator {
+        self.segments.iter().map(|segment| segment.as_ref())
+    }
+
+    /// Number of segments; 1 for a top-level category
+    pub fn depth(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Last segment, e.g. `Trig` for `Math/Trig`
+    pub fn name(&self) -> &str {
+        self.segments.last().map_or("", |segment| segment.as_ref())
+    }
+
+    /// Whether this is a top-level category
+    pub fn is_top_level(&self) -> bool {
+        self.depth() == 1
+    }
+
+    /// The top-level category this one is in (itself if top-level)
+    pub fn top_level(&self) -> Category {
+        self.prefix(1)
+    }
+
+    /// The enclosing category, `None` for a top-level one
+    pub fn parent(&self) -> Option<Category> {
+        (self.depth() > 1).then(|| self.prefix(self.depth() - 1))
+    }
+
+    /// A sub-category of this one
+    pub fn child(&self, name: &str) -> Category {
+        Self::from_segments(self.segments().chain([name]))
+    }
+
+    /// This category and its enclosing ones, innermost first
+    pub fn ancestors(&self) -> impl Iterator<Item = Category> + '_ {
+        (1..=self.depth()).rev().map(|depth| self.prefix(depth))
+    }
+
+    /// Whether this is `other` or one of its sub-categories
+    pub fn is_within(&self, other: &Category) -> bool {
+        self.segments.starts_with(&other.segments)
+    }
+
+    fn prefix(&self, depth: usize) -> Category {
+        match &self.segments {
+            Cow::Borrowed(segments) => {
+                let segments: &'static [Cow<'static, str>] = segments;
+                Self::builtin(&segments[..depth])
+            }
+            Cow::Owned(segments) => Self { segments: Cow::Owned(segments[..depth].to_vec()) },
+        }
+    }
+}
+
+impl Default for Category {
+    fn default() -> Self {
+        Self::UNCATEGORIZED
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments().enumerate() {
+            if i > 0 {
+                write!(f, "{}", SEPARATOR)?;
+            }
+            f.write_str(segment)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Category({:?})", self.to_string())
+    }
+}
+
+impl FromStr for Category {
+    type Err = Infallible;
+
+    fn from_str(path: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(path))
+    }
+}
+
+impl From<&str> for Category {
+    fn from(path: &str) -> Self {
+        Self::parse(path)
+    }
+}
+
+impl PartialEq<str> for Category {
+    fn eq(&self, path: &str) -> bool {
+        let mut other = path.split(SEPARATOR).map(str::trim).filter(|s| !s.is_empty());
+        self.segments().all(|segment| other.next() == Some(segment)) && other.next().is_none()
+    }
+}
+
+impl PartialEq<&str> for Category {
+    fn eq(&self, path: &&str) -> bool {
+        self == *path
+    }
+}
+
+impl Serialize for Category {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Category {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::parse(&String::deserialize(deserializer)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let trig = Category::parse(" Math / Trig/");
+        assert_eq!(trig.segments().collect::<Vec<_>>(), ["Math", "Trig"]);
+        assert_eq!(trig.to_string(), "Math/Trig");
+        assert_eq!(trig, "Math/Trig");
+        assert_eq!(trig, Category::from_segments(["Math", "Trig"]));
+        assert_eq!(Category::MATH.child("Trig"), trig);
+        assert_eq!("Math".parse::<Category>().unwrap(), Category::MATH);
+
+        assert_eq!(Category::parse(""), Category::UNCATEGORIZED);
+        assert_eq!(Category::parse("//"), Category::UNCATEGORIZED);
+        assert_eq!(Category::default(), "Uncategorized");
+    }
+
+    #[test]
+    fn test_hierarchy() {
+        let noise = Category::parse("Math/Random/Noise");
+        assert_eq!(noise.depth(), 3);
+        assert_eq!(noise.name(), "Noise");
+        assert_eq!(noise.top_level(), Category::MATH);
+        assert_eq!(noise.parent(), Some(Category::parse("Math/Random")));
+        assert_eq!(Category::MATH.parent(), None);
+        assert!(Category::MATH.is_top_level());
+
+        let ancestors: Vec<String> = noise.ancestors().map(|c| c.to_string()).collect();
+        assert_eq!(ancestors, ["Math/Random/Noise", "Math/Random", "Math"]);
+        assert!(noise.is_within(&Category::MATH));
+        assert!(!Category::MATH.is_within(&noise));
+        assert!(!Category::parse("Mathematics").is_within(&Category::MATH));
+    }
+
+    #[test]
+    fn test_ordering_and_serde() {
+        let mut categories = [
+            Category::parse("Math/Trig"),
+            Category::LIST,
+            Category::parse("Math/Arithmetic"),
+            Category::MATH,
+        ];
+        categories.sort();
+        let sorted: Vec<String> = categories.iter().map(|c| c.to_string()).collect();
+        assert_eq!(sorted, ["List", "Math", "Math/Arithmetic", "Math/Trig"]);
+
+        let json = serde_json::to_string(&categories[3]).unwrap();
+        assert_eq!(json, "\"Math/Trig\"");
+        assert_eq!(serde_json::from_str::<Category>(&json).unwrap(), categories[3]);
+    }
+}
@@ -85,6 +85,12 @@ impl CallContext {
         Self(self.0.wrapping_mul(31).wrapping_add(index).wrapping_add(1))
     }
 
+    /// Whether this is the root context.
+    #[inline]
+    pub const fn is_root(&self) -> bool {
+        self.0 == 0
+    }
+
     /// Get the raw context value (for debugging or serialization).
     #[inline]
     pub const fn raw(&self) -> u32 {
@@ -3,19 +3,58 @@
 //! This module contains:
 //! - [`EvalContext`] - The main context passed during operator evaluation
 //! - [`CallContext`] - Context identifier for subroutine/loop caching
+//! - [`VarNamespace`] / [`VarNamespaceMut`] - Namespaced, typed access to object variables
+//! - [`ContextDependency`] - Context fields an operator's output depends on
 //! - [`GizmoVisibility`] / [`TransformGizmoMode`] - Gizmo settings
 //! - [`Mat4`] - 4x4 matrix type alias
 
 mod call_context;
 mod types;
+mod vars;
 
 pub use call_context::CallContext;
 pub use types::{GizmoVisibility, Mat4, TransformGizmoMode, MAT4_IDENTITY};
+pub use vars::{
+    namespaced_key, VarNamespace, VarNamespaceMut, NAMESPACE_SEPARATOR, RESERVED_NAMESPACES,
+};
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::sync::{Arc, Mutex};
 
+use crate::error::OperatorError;
+use crate::id::Id;
+use crate::resource::{ResourceEntry, ResourceTable};
 use crate::value::Value;
 
+/// Default limit on list lengths produced during evaluation (16M elements)
+pub const DEFAULT_MAX_LIST_LEN: usize = 16 * 1024 * 1024;
+
+/// Default limit on composite nesting during evaluation
+pub const DEFAULT_MAX_DEPTH: usize = 64;
+
+// ============================================================================
+// Context Dependencies
+// ============================================================================
+
+/// Part of the evaluation context an operator reads besides its inputs and
+/// time (see `Operator::context_dependencies`).
+///
+/// The graph fingerprints the declared parts with each cached output and
+/// recomputes the operator when the fingerprint changes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ContextDependency {
+    /// [`EvalContext::resolution`]
+    Resolution,
+    /// [`EvalContext::camera_to_clip`] and [`EvalContext::world_to_camera`]
+    Camera,
+    /// [`EvalContext::object_to_world`]
+    ObjectTransform,
+    /// [`EvalContext::gizmo_visibility`], [`EvalContext::transform_gizmo_mode`]
+    /// and the selected nodes
+    Gizmos,
+}
+
 // ============================================================================
 // Evaluation Context
 // ============================================================================
@@ -61,11 +100,23 @@ pub struct EvalContext {
     /// String context variables
     pub string_vars: HashMap<String, String>,
     /// Generic object context variables
+    ///
+    /// Prefer namespaced access via [`EvalContext::vars`] / [`EvalContext::vars_mut`].
     pub object_vars: HashMap<String, Value>,
 
+    // === Resources ===
+    /// Host resources that [`Value::ResourceRef`]s resolve against
+    pub resources: Option<Arc<ResourceTable>>,
+
     // === Gizmos ===
     /// Current gizmo visibility setting
     pub show_gizmos: GizmoVisibility,
+    /// Gizmo visibility set on the node being computed (see
+    /// `Graph::set_gizmo_visibility`).
+    ///
+    /// Set by the graph before compute; operators should treat it as
+    /// read-only.
+    pub current_node_gizmos: GizmoVisibility,
     /// Current transform gizmo mode
     pub transform_gizmo_mode: TransformGizmoMode,
 
@@ -76,9 +127,30 @@ pub struct EvalContext {
     /// or loop iterations, this context ensures separate cache entries.
     pub call_context: CallContext,
 
+    // === Limits ===
+    /// Maximum number of elements a list-producing operator may output.
+    ///
+    /// Set by the graph before compute (see `Graph::set_max_list_len`);
+    /// operators should treat it as read-only.
+    pub max_list_len: usize,
+    /// Maximum composite nesting depth (see [`descend`](Self::descend)).
+    ///
+    /// Set by the outermost graph (see `Graph::set_max_depth`).
+    pub max_depth: usize,
+
     // === Internal ===
     /// Parent time for nested time contexts
     parent_time: Option<f64>,
+    /// Names of the composites entered to reach this context, outermost first
+    composite_path: Vec<&'static str>,
+    /// Call contexts enclosing `call_context`, outermost (root) first
+    call_ancestors: Vec<CallContext>,
+    /// Errors reported by operators during compute (shared with child contexts)
+    errors: Arc<Mutex<Vec<OperatorError>>>,
+    /// Nodes selected in the host's editor
+    selected_nodes: HashSet<Id>,
+    /// Inputs of the node being computed whose source reported errors
+    failed_inputs: Vec<usize>,
 }
 
 impl EvalContext {
@@ -108,18 +180,41 @@ impl EvalContext {
             string_vars: HashMap::new(),
             object_vars: HashMap::new(),
 
+            // Resources
+            resources: None,
+
             // Gizmos
             show_gizmos: GizmoVisibility::default(),
+            current_node_gizmos: GizmoVisibility::default(),
             transform_gizmo_mode: TransformGizmoMode::default(),
 
             // Call Context
             call_context: CallContext::root(),
 
+            // Limits
+            max_list_len: DEFAULT_MAX_LIST_LEN,
+            max_depth: DEFAULT_MAX_DEPTH,
+
             // Internal
             parent_time: None,
+            composite_path: Vec::new(),
+            call_ancestors: Vec::new(),
+            errors: Arc::new(Mutex::new(Vec::new())),
+            selected_nodes: HashSet::new(),
+            failed_inputs: Vec::new(),
         }
     }
 
+    /// Look up the resource a [`Value::ResourceRef`] points to.
+    ///
+    /// Returns `None` for other values, when no table is installed, or when
+    /// the table has no resource of that kind under the id.
+    pub fn resource(&self, value: &Value) -> Option<&ResourceEntry> {
+        let (kind, id) = value.as_resource_ref()?;
+        let entry = self.resources.as_ref()?.get(id)?;
+        (entry.kind == kind).then_some(entry)
+    }
+
     /// Reset context to default state
     pub fn reset(&mut self) {
         *self = Self::new();
@@ -185,10 +280,108 @@ impl EvalContext {
     /// ```
     pub fn with_call_context(&self, index: u32) -> Self {
         let mut ctx = self.clone();
+        ctx.call_ancestors.push(self.call_context);
         ctx.call_context = self.call_context.child(index);
         ctx
     }
 
+    /// Call contexts enclosing [`call_context`](Self::call_context), outermost
+    /// (root) first. Empty for the root context.
+    pub fn call_ancestors(&self) -> &[CallContext] {
+        &self.call_ancestors
+    }
+
+    // === Nesting ===
+
+    /// Create a child context for evaluating the inside of a composite.
+    ///
+    /// The composite's resolved gizmo visibility becomes the child's
+    /// [`show_gizmos`](Self::show_gizmos), so inner nodes set to
+    /// [`GizmoVisibility::Inherit`] follow it.
+    ///
+    /// Fails with [`OperatorError::DepthLimitExceeded`] (carrying the chain
+    /// of composite names) once [`max_depth`](Self::max_depth) composites
+    /// are already being evaluated, so runaway nesting ends with an error
+    /// instead of a stack overflow.
+    pub fn descend(&self, composite: &'static str) -> Result<Self, OperatorError> {
+        if self.depth() >= self.max_depth {
+            let mut path: Vec<String> = self.composite_path.iter().map(|s| s.to_string()).collect();
+            path.push(composite.to_string());
+            return Err(OperatorError::depth_limit_exceeded(path, self.max_depth));
+        }
+        let mut ctx = self.clone();
+        ctx.composite_path.push(composite);
+        ctx.show_gizmos = self.gizmo_visibility();
+        ctx.current_node_gizmos = GizmoVisibility::Inherit;
+        Ok(ctx)
+    }
+
+    /// Number of composites entered to reach this context (0 at the top level).
+    pub fn depth(&self) -> usize {
+        self.composite_path.len()
+    }
+
+    /// Names of the composites entered to reach this context, outermost first.
+    pub fn composite_path(&self) -> &[&'static str] {
+        &self.composite_path
+    }
+
+    // === Resolution ===
+
+    /// Width divided by height of [`resolution`](Self::resolution) (1.0 if
+    /// the height is zero).
+    pub fn aspect_ratio(&self) -> f32 {
+        let (width, height) = self.resolution;
+        if height == 0 {
+            1.0
+        } else {
+            width as f32 / height as f32
+        }
+    }
+
+    /// Create a child context rendering at a different resolution.
+    pub fn with_resolution(&self, width: u32, height: u32) -> Self {
+        let mut ctx = self.clone();
+        ctx.resolution = (width, height);
+        ctx
+    }
+
+    /// Hash of the context parts in `dependencies` (0 if there are none).
+    ///
+    /// Two contexts with the same fingerprint produce the same output from
+    /// an operator that only reads those parts.
+    pub fn fingerprint(&self, dependencies: &[ContextDependency]) -> u64 {
+        if dependencies.is_empty() {
+            return 0;
+        }
+        let mut hasher = DefaultHasher::new();
+        for dependency in dependencies {
+            dependency.hash(&mut hasher);
+            match dependency {
+                ContextDependency::Resolution => self.resolution.hash(&mut hasher),
+                ContextDependency::Camera => {
+                    hash_matrix(&self.camera_to_clip, &mut hasher);
+                    hash_matrix(&self.world_to_camera, &mut hasher);
+                }
+                ContextDependency::ObjectTransform => {
+                    hash_matrix(&self.object_to_world, &mut hasher);
+                }
+                ContextDependency::Gizmos => {
+                    self.gizmo_visibility().hash(&mut hasher);
+                    self.transform_gizmo_mode.hash(&mut hasher);
+                    // Order-independent, since the selection is a set
+                    let selection = self.selected_nodes.iter().fold(0u64, |acc, id| {
+                        let mut id_hasher = DefaultHasher::new();
+                        id.hash(&mut id_hasher);
+                        acc ^ id_hasher.finish()
+                    });
+                    selection.hash(&mut hasher);
+                }
+            }
+        }
+        hasher.finish()
+    }
+
     // === Transform Management ===
 
     /// Set to default camera (identity matrices)
@@ -268,15 +461,135 @@ impl EvalContext {
         self.object_vars.get(name)
     }
 
+    // Any variable
+
+    /// Look up a variable by name in every typed map.
+    ///
+    /// The maps are searched float, int, bool, string, then object, so a
+    /// name set in several of them resolves to the first.
+    pub fn get_var(&self, name: &str) -> Option<Value> {
+        if let Some(&v) = self.float_vars.get(name) {
+            return Some(Value::Float(v));
+        }
+        if let Some(&v) = self.int_vars.get(name) {
+            return Some(Value::Int(v));
+        }
+        if let Some(&v) = self.bool_vars.get(name) {
+            return Some(Value::Bool(v));
+        }
+        if let Some(v) = self.string_vars.get(name) {
+            return Some(Value::String(v.clone()));
+        }
+        self.object_vars.get(name).cloned()
+    }
+
     // === Gizmos ===
 
+    /// Replace the set of nodes selected in the editor.
+    pub fn set_selected_nodes(&mut self, nodes: &[Id]) {
+        self.selected_nodes = nodes.iter().copied().collect();
+    }
+
+    /// Returns true if the node is selected in the editor.
+    ///
+    /// Operators drawing gizmos pass their own id and hand the result to
+    /// [`should_show_gizmos`](Self::should_show_gizmos).
+    pub fn is_node_selected(&self, node: Id) -> bool {
+        self.selected_nodes.contains(&node)
+    }
+
+    /// Gizmo visibility for the node being computed.
+    ///
+    /// [`current_node_gizmos`](Self::current_node_gizmos) wins unless it is
+    /// [`Inherit`](GizmoVisibility::Inherit), then
+    /// [`show_gizmos`](Self::show_gizmos) (the graph's, or the enclosing
+    /// composite's). Inheriting all the way to the top shows gizmos.
+    /// Never returns `Inherit`.
+    pub fn gizmo_visibility(&self) -> GizmoVisibility {
+        [self.current_node_gizmos, self.show_gizmos]
+            .into_iter()
+            .find(|visibility| !visibility.is_inherit())
+            .unwrap_or(GizmoVisibility::On)
+    }
+
     /// Check if gizmos should be visible
     pub fn should_show_gizmos(&self, is_selected: bool) -> bool {
-        match self.show_gizmos {
+        match self.gizmo_visibility() {
             GizmoVisibility::Off => false,
-            GizmoVisibility::On => true,
             GizmoVisibility::IfSelected => is_selected,
-            GizmoVisibility::Inherit => true, // Default to showing if no parent context
+            GizmoVisibility::On | GizmoVisibility::Inherit => true,
+        }
+    }
+}
+
+impl EvalContext {
+    // === Error Reporting ===
+
+    /// Report a non-fatal error from the operator currently computing.
+    ///
+    /// The graph collects reported errors after each compute and attributes
+    /// them to the node (see `Graph::node_errors`).
+    pub fn report_error(&self, error: OperatorError) {
+        if let Ok(mut errors) = self.errors.lock() {
+            errors.push(error);
+        }
+    }
+
+    /// Take all errors reported since the last call.
+    pub fn take_errors(&self) -> Vec<OperatorError> {
+        self.errors
+            .lock()
+            .map(|mut errors| std::mem::take(&mut *errors))
+            .unwrap_or_default()
+    }
+
+    /// Replace the inputs whose source reported errors.
+    ///
+    /// Set by the graph before each compute; operators should only read it
+    /// through [`input_failed`](Self::input_failed).
+    pub fn set_failed_inputs(&mut self, inputs: &[usize]) {
+        self.failed_inputs = inputs.to_vec();
+        self.failed_inputs.sort_unstable();
+        self.failed_inputs.dedup();
+    }
+
+    /// Inputs of the node being computed whose source reported errors in its
+    /// most recent compute (see `Graph::node_errors`), in ascending order.
+    pub fn failed_inputs(&self) -> &[usize] {
+        &self.failed_inputs
+    }
+
+    /// Returns true if input `index` of the node being computed is fed by a
+    /// node that reported errors in its most recent compute.
+    pub fn input_failed(&self, index: usize) -> bool {
+        self.failed_inputs.binary_search(&index).is_ok()
+    }
+
+    // === List Limits ===
+
+    /// Clamp a requested list length to [`max_list_len`](Self::max_list_len).
+    ///
+    /// Reports [`OperatorError::ListTooLong`] if the length had to be reduced.
+    /// Call this before allocating so oversized requests never allocate.
+    pub fn clamp_list_len(&self, requested: usize) -> usize {
+        if requested > self.max_list_len {
+            self.report_error(OperatorError::list_too_long(requested, self.max_list_len));
+            self.max_list_len
+        } else {
+            requested
+        }
+    }
+
+    /// Truncate a list value to [`max_list_len`](Self::max_list_len).
+    ///
+    /// Reports [`OperatorError::ListTooLong`] if truncation occurred. Non-list
+    /// values are left unchanged.
+    pub fn limit_list(&self, value: &mut Value) {
+        if let Some(len) = value.list_len() {
+            if len > self.max_list_len {
+                value.truncate_list(self.max_list_len);
+                self.report_error(OperatorError::list_too_long(len, self.max_list_len));
+            }
         }
     }
 }
@@ -287,6 +600,12 @@ impl Default for EvalContext {
     }
 }
 
+fn hash_matrix(matrix: &Mat4, hasher: &mut impl Hasher) {
+    for value in matrix.iter().flatten() {
+        value.to_bits().hash(hasher);
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -305,6 +624,28 @@ mod tests {
         assert_eq!(ctx.resolution, (1920, 1080));
     }
 
+    #[test]
+    fn test_resolution_helpers_and_fingerprint() {
+        let ctx = EvalContext::new();
+        assert!((ctx.aspect_ratio() - 16.0 / 9.0).abs() < 1e-6);
+        let square = ctx.with_resolution(512, 512);
+        assert_eq!(square.resolution, (512, 512));
+        assert_eq!(square.aspect_ratio(), 1.0);
+        assert_eq!(ctx.with_resolution(64, 0).aspect_ratio(), 1.0);
+
+        let resolution = [ContextDependency::Resolution];
+        assert_eq!(ctx.fingerprint(&[]), 0);
+        assert_ne!(ctx.fingerprint(&resolution), square.fingerprint(&resolution));
+        assert_eq!(ctx.fingerprint(&resolution), ctx.clone().fingerprint(&resolution));
+
+        // Only declared parts count
+        let mut moved = ctx.clone();
+        moved.object_to_world[3][0] = 1.0;
+        assert_eq!(moved.fingerprint(&resolution), ctx.fingerprint(&resolution));
+        let transform = [ContextDependency::ObjectTransform];
+        assert_ne!(moved.fingerprint(&transform), ctx.fingerprint(&transform));
+    }
+
     #[test]
     fn test_eval_context_advance() {
         let mut ctx = EvalContext::new();
@@ -385,6 +726,35 @@ mod tests {
         ctx.show_gizmos = GizmoVisibility::IfSelected;
         assert!(ctx.should_show_gizmos(true));
         assert!(!ctx.should_show_gizmos(false));
+
+        // A per-node setting wins over the context's
+        ctx.current_node_gizmos = GizmoVisibility::Off;
+        assert!(!ctx.should_show_gizmos(true));
+    }
+
+    #[test]
+    fn test_gizmo_visibility_inherits_from_composite() {
+        let mut ctx = EvalContext::new();
+        assert_eq!(ctx.gizmo_visibility(), GizmoVisibility::On);
+
+        ctx.show_gizmos = GizmoVisibility::On;
+        ctx.current_node_gizmos = GizmoVisibility::IfSelected;
+        let inner = ctx.descend("Composite").unwrap();
+        assert_eq!(inner.current_node_gizmos, GizmoVisibility::Inherit);
+        assert_eq!(inner.gizmo_visibility(), GizmoVisibility::IfSelected);
+    }
+
+    #[test]
+    fn test_selected_nodes() {
+        let mut ctx = EvalContext::new();
+        let (a, b) = (Id::new(), Id::new());
+        ctx.set_selected_nodes(&[a]);
+        assert!(ctx.is_node_selected(a));
+        assert!(!ctx.is_node_selected(b));
+
+        ctx.set_selected_nodes(&[b]);
+        assert!(!ctx.is_node_selected(a));
+        assert!(ctx.with_call_context(0).is_node_selected(b));
     }
 
     #[test]
@@ -395,4 +765,64 @@ mod tests {
         ctx.transform_gizmo_mode = TransformGizmoMode::Move;
         assert_eq!(ctx.transform_gizmo_mode, TransformGizmoMode::Move);
     }
+
+    #[test]
+    fn test_clamp_list_len_reports_error() {
+        let mut ctx = EvalContext::new();
+        ctx.max_list_len = 100;
+
+        assert_eq!(ctx.clamp_list_len(50), 50);
+        assert!(ctx.take_errors().is_empty());
+
+        assert_eq!(ctx.clamp_list_len(1_000_000_000), 100);
+        let errors = ctx.take_errors();
+        assert!(matches!(
+            errors.as_slice(),
+            [OperatorError::ListTooLong { requested: 1_000_000_000, limit: 100 }]
+        ));
+        assert!(ctx.take_errors().is_empty());
+    }
+
+    #[test]
+    fn test_limit_list_truncates() {
+        let mut ctx = EvalContext::new();
+        ctx.max_list_len = 2;
+        let child = ctx.with_call_context(1);
+
+        let mut value = Value::float_list(vec![1.0, 2.0, 3.0]);
+        child.limit_list(&mut value);
+        assert_eq!(value.as_float_list(), Some(&[1.0, 2.0][..]));
+        // Child contexts report into the parent's error list
+        assert_eq!(ctx.take_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_descend_limits_depth() {
+        let mut ctx = EvalContext::new();
+        ctx.max_depth = 2;
+
+        let outer = ctx.descend("Outer").unwrap();
+        let inner = outer.descend("Inner").unwrap();
+        assert_eq!(inner.depth(), 2);
+        assert_eq!(inner.composite_path(), &["Outer", "Inner"]);
+
+        match inner.descend("Deepest") {
+            Err(OperatorError::DepthLimitExceeded { path, limit }) => {
+                assert_eq!(path, vec!["Outer", "Inner", "Deepest"]);
+                assert_eq!(limit, 2);
+            }
+            other => panic!("expected DepthLimitExceeded, got {:?}", other.map(|c| c.depth())),
+        }
+        assert_eq!(ctx.depth(), 0);
+    }
+
+    #[test]
+    fn test_call_ancestors() {
+        let ctx = EvalContext::new();
+        let outer = ctx.with_call_context(3);
+        let inner = outer.with_call_context(7);
+        assert!(ctx.call_ancestors().is_empty());
+        assert_eq!(inner.call_ancestors(), &[CallContext::root(), outer.call_context]);
+        assert!(!inner.call_context.is_root());
+    }
 }
@@ -3,7 +3,7 @@
 use serde::{Deserialize, Serialize};
 
 /// Gizmo visibility modes
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum GizmoVisibility {
     /// Inherit visibility from parent context
     #[default]
@@ -16,8 +16,15 @@ pub enum GizmoVisibility {
     IfSelected,
 }
 
+impl GizmoVisibility {
+    /// Returns true for the default, inherited visibility.
+    pub fn is_inherit(&self) -> bool {
+        matches!(self, Self::Inherit)
+    }
+}
+
 /// Transform gizmo modes for 3D manipulation
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
 pub enum TransformGizmoMode {
     /// No transform gizmo active
     #[default]
@@ -0,0 +1,388 @@
+//! Namespaced, typed access to context object variables
+//!
+//! [`EvalContext::object_vars`] is a flat map shared by the host and every
+//! operator pack. To avoid key collisions, this module provides a thin wrapper
+//! that prefixes keys with a namespace (`"audio"` + `"level"` → `"audio.level"`)
+//! and offers typed getters and setters on top of [`Value`].
+//!
+//! # Reserved namespaces
+//!
+//! The following namespaces are reserved for Flux and common host integrations.
+//! Operator packs should pick their own namespace instead of writing into these:
+//!
+//! - `"flux"` - Internal use by the graph runtime
+//! - `"audio"` - Audio analysis data provided by the host (levels, spectrum)
+//! - `"playback"` - Transport state (BPM, beat position, playing flag)
+//! - `"transport"` - Playhead state published by `flux_graph::playback::Transport`
+//!
+//! # Example
+//!
+//! ```
+//! use flux_core::{EvalContext, Value};
+//!
+//! let mut ctx = EvalContext::new();
+//! ctx.vars_mut("audio").set_float("level", 0.5);
+//! ctx.vars_mut("audio").set("spectrum", Value::float_list(vec![0.1, 0.2]));
+//!
+//! assert_eq!(ctx.vars("audio").get_float("level"), Some(0.5));
+//! assert_eq!(ctx.get_object_var("audio.level"), Some(&Value::Float(0.5)));
+//! assert_eq!(ctx.var_keys("audio"), vec!["level", "spectrum"]);
+//! ```
+
+use crate::value::{Color, Value, ValueType};
+
+use super::EvalContext;
+
+/// Namespaces reserved for Flux and common host integrations.
+///
+/// - `"flux"` - Internal use by the graph runtime
+/// - `"audio"` - Audio analysis data provided by the host
+/// - `"playback"` - Transport state
+/// - `"transport"` - Playhead state published by the graph's transport
+pub const RESERVED_NAMESPACES: &[&str] = &["flux", "audio", "playback", "transport"];
+
+/// Separator between namespace and key.
+pub const NAMESPACE_SEPARATOR: char = '.';
+
+/// Build the fully qualified key for a variable in a namespace.
+///
+/// An empty namespace leaves the key unchanged.
+pub fn namespaced_key(namespace: &str, key: &str) -> String {
+    if namespace.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}{}{}", namespace, NAMESPACE_SEPARATOR, key)
+    }
+}
+
+/// Strip a namespace prefix from a fully qualified key.
+///
+/// Returns `None` if the key is not in the namespace.
+fn strip_namespace<'a>(namespace: &str, full_key: &'a str) -> Option<&'a str> {
+    if namespace.is_empty() {
+        return Some(full_key);
+    }
+    full_key
+        .strip_prefix(namespace)?
+        .strip_prefix(NAMESPACE_SEPARATOR)
+}
+
+/// Read-only view of the variables in one namespace.
+///
+/// Created by [`EvalContext::vars`].
+#[derive(Clone, Copy)]
+pub struct VarNamespace<'a> {
+    ctx: &'a EvalContext,
+    namespace: &'a str,
+}
+
+/// Mutable view of the variables in one namespace.
+///
+/// Created by [`EvalContext::vars_mut`].
+pub struct VarNamespaceMut<'a> {
+    ctx: &'a mut EvalContext,
+    namespace: &'a str,
+}
+
+/// Typed getters shared by both namespace views.
+macro_rules! impl_var_getters {
+    ($ty:ident) => {
+        impl<'a> $ty<'a> {
+            /// The namespace this view reads from.
+            pub fn namespace(&self) -> &str {
+                self.namespace
+            }
+
+            /// Get the raw value for a key.
+            pub fn get(&self, key: &str) -> Option<&Value> {
+                self.ctx
+                    .object_vars
+                    .get(&namespaced_key(self.namespace, key))
+            }
+
+            /// Returns true if the key is set.
+            pub fn contains(&self, key: &str) -> bool {
+                self.get(key).is_some()
+            }
+
+            /// Get a value coerced to the given type.
+            pub fn get_as(&self, key: &str, target: ValueType) -> Option<Value> {
+                self.get(key)?.coerce_to(target)
+            }
+
+            /// Get a float (Int values are converted).
+            pub fn get_float(&self, key: &str) -> Option<f32> {
+                self.get(key)?.as_float()
+            }
+
+            /// Get a float, or a fallback if missing.
+            pub fn get_float_or(&self, key: &str, default: f32) -> f32 {
+                self.get_float(key).unwrap_or(default)
+            }
+
+            /// Get an int (Float values are truncated).
+            pub fn get_int(&self, key: &str) -> Option<i32> {
+                self.get(key)?.as_int()
+            }
+
+            /// Get a bool.
+            pub fn get_bool(&self, key: &str) -> Option<bool> {
+                self.get(key)?.as_bool()
+            }
+
+            /// Get a string.
+            pub fn get_string(&self, key: &str) -> Option<&str> {
+                self.get(key)?.as_string()
+            }
+
+            /// Get a Vec2.
+            pub fn get_vec2(&self, key: &str) -> Option<[f32; 2]> {
+                self.get(key)?.as_vec2()
+            }
+
+            /// Get a Vec3, coercing from Float, Vec4 or Color.
+            pub fn get_vec3(&self, key: &str) -> Option<[f32; 3]> {
+                self.get_as(key, ValueType::Vec3)?.as_vec3()
+            }
+
+            /// Get a Vec4.
+            pub fn get_vec4(&self, key: &str) -> Option<[f32; 4]> {
+                self.get(key)?.as_vec4()
+            }
+
+            /// Get a Color, coercing from Float, Vec3 or Vec4.
+            pub fn get_color(&self, key: &str) -> Option<Color> {
+                self.get_as(key, ValueType::Color)?.as_color()
+            }
+
+            /// Get a float list.
+            pub fn get_float_list(&self, key: &str) -> Option<&[f32]> {
+                self.get(key)?.as_float_list()
+            }
+
+            /// Get an int list.
+            pub fn get_int_list(&self, key: &str) -> Option<&[i32]> {
+                self.get(key)?.as_int_list()
+            }
+
+            /// Get a Vec3 list.
+            pub fn get_vec3_list(&self, key: &str) -> Option<&[[f32; 3]]> {
+                self.get(key)?.as_vec3_list()
+            }
+
+            /// Get a color list.
+            pub fn get_color_list(&self, key: &str) -> Option<&[Color]> {
+                self.get(key)?.as_color_list()
+            }
+
+            /// Keys in this namespace (without the prefix), sorted.
+            pub fn keys(&self) -> Vec<String> {
+                self.ctx.var_keys(self.namespace)
+            }
+        }
+    };
+}
+
+impl_var_getters!(VarNamespace);
+impl_var_getters!(VarNamespaceMut);
+
+impl<'a> VarNamespaceMut<'a> {
+    /// Set a raw value.
+    pub fn set(&mut self, key: &str, value: Value) -> &mut Self {
+        self.ctx
+            .object_vars
+            .insert(namespaced_key(self.namespace, key), value);
+        self
+    }
+
+    /// Set a float.
+    pub fn set_float(&mut self, key: &str, value: f32) -> &mut Self {
+        self.set(key, Value::Float(value))
+    }
+
+    /// Set an int.
+    pub fn set_int(&mut self, key: &str, value: i32) -> &mut Self {
+        self.set(key, Value::Int(value))
+    }
+
+    /// Set a bool.
+    pub fn set_bool(&mut self, key: &str, value: bool) -> &mut Self {
+        self.set(key, Value::Bool(value))
+    }
+
+    /// Set a string.
+    pub fn set_string(&mut self, key: &str, value: &str) -> &mut Self {
+        self.set(key, Value::String(value.to_string()))
+    }
+
+    /// Set a Vec3.
+    pub fn set_vec3(&mut self, key: &str, value: [f32; 3]) -> &mut Self {
+        self.set(key, Value::Vec3(value))
+    }
+
+    /// Set a Color.
+    pub fn set_color(&mut self, key: &str, value: Color) -> &mut Self {
+        self.set(key, Value::Color(value))
+    }
+
+    /// Set a float list.
+    pub fn set_float_list(&mut self, key: &str, value: Vec<f32>) -> &mut Self {
+        self.set(key, Value::float_list(value))
+    }
+
+    /// Remove a key, returning its previous value.
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.ctx
+            .object_vars
+            .remove(&namespaced_key(self.namespace, key))
+    }
+
+    /// Remove every key in this namespace.
+    pub fn clear(&mut self) {
+        let namespace = self.namespace;
+        self.ctx
+            .object_vars
+            .retain(|full_key, _| strip_namespace(namespace, full_key).is_none());
+    }
+}
+
+impl EvalContext {
+    /// Read-only, namespaced access to object variables.
+    ///
+    /// See [`RESERVED_NAMESPACES`] for namespaces operator packs should avoid.
+    pub fn vars<'a>(&'a self, namespace: &'a str) -> VarNamespace<'a> {
+        VarNamespace {
+            ctx: self,
+            namespace,
+        }
+    }
+
+    /// Mutable, namespaced access to object variables.
+    pub fn vars_mut<'a>(&'a mut self, namespace: &'a str) -> VarNamespaceMut<'a> {
+        VarNamespaceMut {
+            ctx: self,
+            namespace,
+        }
+    }
+
+    /// List the keys in a namespace (without the prefix), sorted.
+    ///
+    /// Keys in nested namespaces (`"audio.bands.low"` for `"audio"`) are
+    /// returned with their remaining prefix (`"bands.low"`).
+    pub fn var_keys(&self, namespace: &str) -> Vec<String> {
+        let mut keys: Vec<String> = self
+            .object_vars
+            .keys()
+            .filter_map(|full_key| strip_namespace(namespace, full_key))
+            .map(str::to_string)
+            .collect();
+        keys.sort();
+        keys
+    }
+
+    /// Copy all variables in a namespace from another context.
+    ///
+    /// Used by composites that forward a subset of their context to child
+    /// contexts. Existing keys in the namespace are overwritten; other keys
+    /// are left untouched. Returns the number of variables copied.
+    pub fn merge_vars_from(&mut self, other: &EvalContext, namespace: &str) -> usize {
+        let mut copied = 0;
+        for (full_key, value) in &other.object_vars {
+            if strip_namespace(namespace, full_key).is_some() {
+                self.object_vars.insert(full_key.clone(), value.clone());
+                copied += 1;
+            }
+        }
+        copied
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_namespaced_key() {
+        assert_eq!(namespaced_key("audio", "level"), "audio.level");
+        assert_eq!(namespaced_key("", "level"), "level");
+    }
+
+    #[test]
+    fn test_set_prefixes_keys() {
+        let mut ctx = EvalContext::new();
+        ctx.vars_mut("audio").set_float("level", 0.5).set_int("bands", 8);
+
+        assert_eq!(ctx.get_object_var("audio.level"), Some(&Value::Float(0.5)));
+        assert_eq!(ctx.get_object_var("audio.bands"), Some(&Value::Int(8)));
+        assert_eq!(ctx.vars("audio").get_float("level"), Some(0.5));
+        assert_eq!(ctx.vars("audio").get_int("bands"), Some(8));
+        assert!(ctx.vars("other").get_float("level").is_none());
+    }
+
+    #[test]
+    fn test_namespaces_do_not_collide() {
+        let mut ctx = EvalContext::new();
+        ctx.vars_mut("packA").set_float("gain", 1.0);
+        ctx.vars_mut("packB").set_float("gain", 2.0);
+
+        assert_eq!(ctx.vars("packA").get_float("gain"), Some(1.0));
+        assert_eq!(ctx.vars("packB").get_float("gain"), Some(2.0));
+    }
+
+    #[test]
+    fn test_var_keys_enumeration() {
+        let mut ctx = EvalContext::new();
+        ctx.vars_mut("audio")
+            .set_float("level", 0.5)
+            .set_float_list("spectrum", vec![0.1, 0.2]);
+        ctx.vars_mut("audiox").set_float("level", 1.0);
+        ctx.vars_mut("playback").set_bool("playing", true);
+
+        assert_eq!(ctx.var_keys("audio"), vec!["level", "spectrum"]);
+        assert_eq!(ctx.vars("playback").keys(), vec!["playing"]);
+        assert_eq!(ctx.var_keys("").len(), 4);
+        assert_eq!(ctx.vars("audio").get_float_list("spectrum"), Some(&[0.1, 0.2][..]));
+    }
+
+    #[test]
+    fn test_coercion_on_get() {
+        let mut ctx = EvalContext::new();
+        ctx.vars_mut("fx")
+            .set_float("gray", 0.25)
+            .set("tint", Value::Vec4([1.0, 0.5, 0.0, 1.0]))
+            .set_color("fog", Color::rgba(0.1, 0.2, 0.3, 0.4));
+
+        assert_eq!(ctx.vars("fx").get_vec3("gray"), Some([0.25, 0.25, 0.25]));
+        assert_eq!(ctx.vars("fx").get_color("tint"), Some(Color::rgba(1.0, 0.5, 0.0, 1.0)));
+        assert_eq!(ctx.vars("fx").get_vec3("fog"), Some([0.1, 0.2, 0.3]));
+        assert_eq!(ctx.vars("fx").get_color("gray"), Some(Color::rgba(0.25, 0.25, 0.25, 1.0)));
+        assert!(ctx.vars("fx").get_vec3("missing").is_none());
+    }
+
+    #[test]
+    fn test_remove_and_clear() {
+        let mut ctx = EvalContext::new();
+        ctx.vars_mut("a").set_float("x", 1.0).set_float("y", 2.0);
+        ctx.vars_mut("b").set_float("x", 3.0);
+
+        assert_eq!(ctx.vars_mut("a").remove("x"), Some(Value::Float(1.0)));
+        ctx.vars_mut("a").clear();
+        assert!(ctx.var_keys("a").is_empty());
+        assert_eq!(ctx.vars("b").get_float("x"), Some(3.0));
+    }
+
+    #[test]
+    fn test_merge_vars_into_child_context() {
+        let mut parent = EvalContext::new();
+        parent.vars_mut("audio").set_float("level", 0.8);
+        parent.vars_mut("private").set_float("secret", 1.0);
+
+        let mut child = EvalContext::new();
+        child.vars_mut("audio").set_float("level", 0.0);
+        let copied = child.merge_vars_from(&parent, "audio");
+
+        assert_eq!(copied, 1);
+        assert_eq!(child.vars("audio").get_float("level"), Some(0.8));
+        assert!(child.vars("private").get_float("secret").is_none());
+    }
+}
@@ -41,6 +41,10 @@ pub enum OperatorError {
     #[error("Invalid value: {message}")]
     InvalidValue { message: String },
 
+    /// List output exceeded the evaluation's list length limit and was truncated
+    #[error("List of {requested} elements exceeds the limit of {limit}; output truncated")]
+    ListTooLong { requested: usize, limit: usize },
+
     // === Connection Errors ===
     /// Attempting to create a cycle in the graph
     #[error("Connection would create a cycle in the graph")]
@@ -88,6 +92,10 @@ pub enum OperatorError {
     #[error("Invalid instance path: {path}")]
     InvalidInstancePath { path: String },
 
+    /// Composite nesting exceeded the evaluation's depth limit
+    #[error("Composite nesting exceeds the depth limit of {limit}: {}", path.join(" > "))]
+    DepthLimitExceeded { path: Vec<String>, limit: usize },
+
     // === Resource Errors ===
     /// Resource not found
     #[error("Resource not found: {path}")]
@@ -211,6 +219,16 @@ impl OperatorError {
         }
     }
 
+    /// Create a list too long error
+    pub fn list_too_long(requested: usize, limit: usize) -> Self {
+        Self::ListTooLong { requested, limit }
+    }
+
+    /// Create a depth limit exceeded error
+    pub fn depth_limit_exceeded(path: Vec<String>, limit: usize) -> Self {
+        Self::DepthLimitExceeded { path, limit }
+    }
+
     /// Create a resource not found error
     pub fn resource_not_found(path: impl Into<String>) -> Self {
         Self::ResourceNotFound { path: path.into() }
@@ -1,19 +1,168 @@
 //! Unique identifiers for the Flux system
+//!
+//! [`Id::new`] is random by default. Tests and build scripts that need
+//! reproducible ids (golden files, diffable saves) can install a
+//! deterministic [`IdGenerator`] for the current thread, or derive stable
+//! ids from names with [`Id::from_name`]:
+//!
+//! ```
+//! use flux_core::id::{Id, IdGenerator};
+//!
+//! let first = Id::with_generator(IdGenerator::seeded(7), || [Id::new(), Id::new()]);
+//! let second = Id::with_generator(IdGenerator::seeded(7), || [Id::new(), Id::new()]);
+//! assert_eq!(first, second);
+//! ```
+
+use std::cell::RefCell;
 
 use serde::{Deserialize, Serialize};
-use uuid::Uuid;
+use uuid::{Builder, Uuid};
+
+thread_local! {
+    static GENERATOR: RefCell<IdGenerator> = RefCell::new(IdGenerator::random());
+}
+
+/// Source of the ids returned by [`Id::new`]
+///
+/// Generators are installed per thread (see [`Id::set_generator`]), so a
+/// deterministic generator in one test never affects ids made on other
+/// threads, including other tests running in parallel. Ids made on
+/// threads the caller spawns stay random unless those threads install a
+/// generator themselves.
+#[derive(Clone, Debug, Default)]
+pub struct IdGenerator {
+    kind: GeneratorKind,
+}
+
+#[derive(Clone, Debug, Default)]
+enum GeneratorKind {
+    /// Random v4 UUIDs
+    #[default]
+    Random,
+    /// `seed` in the high 64 bits, a counter from 1 in the low 64 bits
+    Sequential { seed: u64, counter: u64 },
+    /// v4-shaped UUIDs from a seeded pseudo-random sequence
+    Seeded { state: u64 },
+}
+
+impl IdGenerator {
+    /// Random v4 UUIDs (the default)
+    pub fn random() -> Self {
+        Self::default()
+    }
+
+    /// Readable ids counting up from 1, with `seed` in the high bits
+    ///
+    /// `sequential(2)` yields `00000000-0000-0002-0000-000000000001`, then
+    /// `...-000000000002`, and so on.
+    pub fn sequential(seed: u64) -> Self {
+        Self {
+            kind: GeneratorKind::Sequential { seed, counter: 0 },
+        }
+    }
+
+    /// Random-looking v4 UUIDs, the same sequence for the same seed
+    pub fn seeded(seed: u64) -> Self {
+        Self {
+            kind: GeneratorKind::Seeded { state: seed },
+        }
+    }
 
-/// Unique identifier using UUID v4
+    /// Returns true for the random generator.
+    pub fn is_random(&self) -> bool {
+        matches!(self.kind, GeneratorKind::Random)
+    }
+
+    fn next_uuid(&mut self) -> Uuid {
+        match &mut self.kind {
+            GeneratorKind::Random => Uuid::new_v4(),
+            GeneratorKind::Sequential { seed, counter } => {
+                *counter += 1;
+                Uuid::from_u128((u128::from(*seed) << 64) | u128::from(*counter))
+            }
+            GeneratorKind::Seeded { state } => {
+                let high = splitmix64(state);
+                let low = splitmix64(state);
+                let bits = (u128::from(high) << 64) | u128::from(low);
+                Builder::from_random_bytes(bits.to_be_bytes()).into_uuid()
+            }
+        }
+    }
+}
+
+/// Next value of the SplitMix64 sequence
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+/// 128-bit FNV-1a, stable across platforms and releases
+fn fnv1a_128(parts: &[&[u8]]) -> u128 {
+    const OFFSET: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+    const PRIME: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+    let mut hash = OFFSET;
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            // Never valid UTF-8, so ("a", "bc") and ("ab", "c") differ
+            hash = (hash ^ 0xff).wrapping_mul(PRIME);
+        }
+        for &byte in *part {
+            hash = (hash ^ u128::from(byte)).wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
+
+/// Unique identifier backed by a UUID
 ///
 /// Used to identify symbols, instances, slots, and other entities
-/// throughout the operator system.
+/// throughout the operator system. Random (v4) unless a deterministic
+/// [`IdGenerator`] is installed.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct Id(Uuid);
 
 impl Id {
-    /// Create a new random UUID
+    /// Create a new id from this thread's generator (random by default)
     pub fn new() -> Self {
-        Self(Uuid::new_v4())
+        Self(GENERATOR.with(|generator| generator.borrow_mut().next_uuid()))
+    }
+
+    /// Stable id for a named entity, such as a fixture node
+    ///
+    /// The same namespace and name always give the same id, on every
+    /// platform and release. Like a UUIDv5, but hashed with FNV-1a into a
+    /// version 8 (custom) UUID.
+    pub fn from_name(namespace: &str, name: &str) -> Self {
+        let hash = fnv1a_128(&[namespace.as_bytes(), name.as_bytes()]);
+        Self(Builder::from_custom_bytes(hash.to_be_bytes()).into_uuid())
+    }
+
+    /// Install the generator [`Id::new`] uses on the current thread,
+    /// returning the previous one.
+    ///
+    /// Prefer [`with_generator`](Self::with_generator), which restores the
+    /// previous generator even if `f` panics.
+    pub fn set_generator(generator: IdGenerator) -> IdGenerator {
+        GENERATOR.with(|current| current.replace(generator))
+    }
+
+    /// Run `f` with `generator` installed on the current thread.
+    pub fn with_generator<R>(generator: IdGenerator, f: impl FnOnce() -> R) -> R {
+        struct Restore(Option<IdGenerator>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                if let Some(previous) = self.0.take() {
+                    Id::set_generator(previous);
+                }
+            }
+        }
+
+        let _restore = Restore(Some(Self::set_generator(generator)));
+        f()
     }
 
     /// Create from an existing UUID
@@ -58,6 +207,159 @@ impl From<Uuid> for Id {
     }
 }
 
+// ============================================================================
+// Typed Graph Handles
+// ============================================================================
+
+/// Identifier of a node in a graph
+///
+/// A typed wrapper around [`Id`] so node ids can't be confused with symbol
+/// or slot ids. Serializes as the inner id.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NodeId(pub Id);
+
+impl NodeId {
+    /// The underlying id
+    pub fn id(self) -> Id {
+        self.0
+    }
+}
+
+impl From<Id> for NodeId {
+    fn from(id: Id) -> Self {
+        Self(id)
+    }
+}
+
+impl From<NodeId> for Id {
+    fn from(node: NodeId) -> Self {
+        node.0
+    }
+}
+
+impl std::fmt::Display for NodeId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Index of an output port on a node. Serializes as the inner index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct OutputIndex(pub usize);
+
+/// Index of an input port on a node. Serializes as the inner index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InputIndex(pub usize);
+
+macro_rules! port_index {
+    ($name:ident) => {
+        impl $name {
+            /// The underlying index
+            pub fn get(self) -> usize {
+                self.0
+            }
+        }
+
+        impl From<usize> for $name {
+            fn from(index: usize) -> Self {
+                Self(index)
+            }
+        }
+
+        impl From<$name> for usize {
+            fn from(index: $name) -> Self {
+                index.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                self.0.fmt(f)
+            }
+        }
+    };
+}
+
+port_index!(OutputIndex);
+port_index!(InputIndex);
+
+/// An output port: the source end of a connection
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct OutputRef {
+    /// Node that owns the output
+    pub node: NodeId,
+    /// Output index on the node
+    pub index: OutputIndex,
+}
+
+impl OutputRef {
+    /// Create a reference to output `index` of `node`
+    pub fn new(node: impl Into<NodeId>, index: impl Into<OutputIndex>) -> Self {
+        Self {
+            node: node.into(),
+            index: index.into(),
+        }
+    }
+}
+
+impl From<(Id, usize)> for OutputRef {
+    fn from((node, index): (Id, usize)) -> Self {
+        Self::new(node, index)
+    }
+}
+
+impl From<OutputRef> for (Id, usize) {
+    fn from(output: OutputRef) -> Self {
+        (output.node.0, output.index.0)
+    }
+}
+
+impl std::fmt::Display for OutputRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[out:{}]", self.node, self.index)
+    }
+}
+
+/// An input port: the target end of a connection
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct InputRef {
+    /// Node that owns the input
+    pub node: NodeId,
+    /// Input index on the node
+    pub index: InputIndex,
+}
+
+impl InputRef {
+    /// Create a reference to input `index` of `node`
+    pub fn new(node: impl Into<NodeId>, index: impl Into<InputIndex>) -> Self {
+        Self {
+            node: node.into(),
+            index: index.into(),
+        }
+    }
+}
+
+impl From<(Id, usize)> for InputRef {
+    fn from((node, index): (Id, usize)) -> Self {
+        Self::new(node, index)
+    }
+}
+
+impl From<InputRef> for (Id, usize) {
+    fn from(input: InputRef) -> Self {
+        (input.node.0, input.index.0)
+    }
+}
+
+impl std::fmt::Display for InputRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}[in:{}]", self.node, self.index)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,6 +369,53 @@ mod tests {
         let id1 = Id::new();
         let id2 = Id::new();
         assert_ne!(id1, id2);
+
+        let ids: std::collections::HashSet<Id> = (0..10_000).map(|_| Id::new()).collect();
+        assert_eq!(ids.len(), 10_000);
+    }
+
+    #[test]
+    fn test_sequential_generator() {
+        let ids = Id::with_generator(IdGenerator::sequential(2), || [Id::new(), Id::new()]);
+        assert_eq!(ids[0].to_string(), "00000000-0000-0002-0000-000000000001");
+        assert_eq!(ids[1].to_string(), "00000000-0000-0002-0000-000000000002");
+
+        // The random default is restored afterwards
+        assert_ne!(Id::new().as_uuid().get_version_num(), 0);
+    }
+
+    #[test]
+    fn test_seeded_generator_is_reproducible() {
+        let run = |seed| {
+            Id::with_generator(IdGenerator::seeded(seed), || {
+                (0..100).map(|_| Id::new()).collect::<Vec<_>>()
+            })
+        };
+        let first = run(42);
+        assert_eq!(first, run(42));
+        assert_ne!(first, run(43));
+        assert_eq!(first.iter().collect::<std::collections::HashSet<_>>().len(), 100);
+        assert_eq!(first[0].as_uuid().get_version_num(), 4);
+    }
+
+    #[test]
+    fn test_generator_is_thread_local() {
+        Id::with_generator(IdGenerator::sequential(0), || {
+            let other = std::thread::spawn(Id::new).join().unwrap();
+            assert_eq!(other.as_uuid().get_version_num(), 4);
+            assert_eq!(Id::new().as_uuid().as_u128(), 1);
+        });
+    }
+
+    #[test]
+    fn test_id_from_name() {
+        let id = Id::from_name("fixtures", "blur");
+        assert_eq!(id, Id::from_name("fixtures", "blur"));
+        assert_ne!(id, Id::from_name("fixtures", "blur2"));
+        assert_ne!(Id::from_name("a", "bc"), Id::from_name("ab", "c"));
+        assert_eq!(id.as_uuid().get_version_num(), 8);
+        // Pinned so the hash can't change between releases
+        assert_eq!(id.to_string(), "1c50a871-dd37-8d8a-aee8-ad50512b3cb5");
     }
 
     #[test]
@@ -105,4 +454,36 @@ mod tests {
         let id = Id::from(uuid);
         assert_eq!(id.as_uuid(), &uuid);
     }
+
+    #[test]
+    fn test_typed_handles_convert() {
+        let id = Id::new();
+        let node = NodeId::from(id);
+        assert_eq!(Id::from(node), id);
+        assert_eq!(usize::from(OutputIndex(2)), 2);
+        assert_eq!(InputIndex::from(3).get(), 3);
+
+        let output = OutputRef::new(id, 1);
+        assert_eq!(output, OutputRef::from((id, 1)));
+        assert_eq!(<(Id, usize)>::from(output), (id, 1));
+        let input = InputRef::new(node, InputIndex(4));
+        assert_eq!(<(Id, usize)>::from(input), (id, 4));
+        assert_eq!(input.to_string(), format!("{id}[in:4]"));
+    }
+
+    #[test]
+    fn test_typed_handles_serialize_transparently() {
+        let id = Id::parse("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        assert_eq!(
+            serde_json::to_string(&NodeId(id)).unwrap(),
+            serde_json::to_string(&id).unwrap()
+        );
+        assert_eq!(serde_json::to_string(&OutputIndex(3)).unwrap(), "3");
+        assert_eq!(serde_json::from_str::<InputIndex>("5").unwrap(), InputIndex(5));
+
+        let output = OutputRef::new(id, 2);
+        let json = serde_json::to_string(&output).unwrap();
+        assert_eq!(json, r#"{"node":"550e8400-e29b-41d4-a716-446655440000","index":2}"#);
+        assert_eq!(serde_json::from_str::<OutputRef>(&json).unwrap(), output);
+    }
 }
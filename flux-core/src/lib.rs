@@ -42,6 +42,7 @@
 //! let output = OutputPort::float("result");
 //! ```
 
+pub mod category;
 pub mod context;
 pub mod dirty_flag;
 pub mod error;
@@ -49,21 +50,40 @@ pub mod id;
 pub mod operator;
 pub mod operator_meta;
 pub mod port;
+pub mod resource;
+pub mod theme;
+pub mod unit;
 pub mod value;
 
 // Re-export commonly used types at crate root
+pub use category::Category;
 pub use context::{
-    CallContext, EvalContext, GizmoVisibility, Mat4, TransformGizmoMode, MAT4_IDENTITY,
+    CallContext, ContextDependency, EvalContext, GizmoVisibility, Mat4, TransformGizmoMode, VarNamespace,
+    VarNamespaceMut, DEFAULT_MAX_DEPTH, DEFAULT_MAX_LIST_LEN, MAT4_IDENTITY,
 };
 pub use dirty_flag::{
     advance_invalidation_frame, current_invalidation_frame, reset_invalidation_frame, DirtyFlag,
     DirtyFlagSet, DirtyFlagTrigger,
 };
 pub use error::{EvalResult, OperatorError, OperatorResult};
-pub use id::Id;
-pub use operator::{InputResolver, Operator};
+pub use id::{Id, IdGenerator, InputIndex, InputRef, NodeId, OutputIndex, OutputRef};
+pub use operator::{
+    ActiveInput, InputResolver, InputValidationIssue, Operator, OperatorCost, ValidationSeverity,
+};
 pub use operator_meta::{
-    category_colors, EffectivePortMeta, OperatorMeta, PinShape, PortMeta, PortOverride,
+    category_colors, EffectivePortMeta, OperatorMeta, PinShape, PortClamp, PortMeta, PortOverride,
+};
+pub use port::{
+    DefaultSource, InputPort, OutputPort, OutputTypeRule, PortChange, PortEdit, PortRemap,
+    TriggerInput, TriggerOutput, TypeConstraint,
+};
+pub use resource::{ResourceKind, ResourceTable};
+pub use theme::{
+    global_theme, resolve_category_color, set_global_theme, OperatorStyle, ThemeRegistry,
+};
+pub use unit::{Unit, UnitDimension};
+pub use value::{
+    Color, ColorFormat, ColorVisionDeficiency, FormatOptions, FromValue, FromValueError, Gradient, GradientColorSpace,
+    GradientStop, GradientStopId, Matrix4, MatrixLayout, StopInterpolation, TypeCategory, Value,
+    ValueType,
 };
-pub use port::{InputPort, OutputPort, OutputTypeRule, TriggerInput, TriggerOutput, TypeConstraint};
-pub use value::{Color, Gradient, GradientStop, Matrix4, TypeCategory, Value, ValueType};
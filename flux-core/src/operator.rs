@@ -5,14 +5,102 @@
 
 use std::any::Any;
 
-use crate::context::EvalContext;
+use crate::context::{ContextDependency, EvalContext};
 use crate::id::Id;
-use crate::port::{InputPort, OutputPort, TriggerInput, TriggerOutput};
+use crate::port::{InputPort, OutputPort, PortChange, TriggerInput, TriggerOutput};
 use crate::value::Value;
 
 /// Function type for resolving input values from connected nodes
 pub type InputResolver<'a> = &'a dyn Fn(Id, usize) -> Value;
 
+/// Rough relative cost of one [`Operator::compute`] call
+///
+/// A scheduling hint: among nodes whose dependencies are all evaluated, the
+/// graph orders more expensive ones first so they start as early as possible.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum OperatorCost {
+    /// Constant-time bookkeeping (constants, passthroughs)
+    Trivial,
+    /// A handful of arithmetic operations
+    #[default]
+    Cheap,
+    /// Work proportional to an input list or string
+    Moderate,
+    /// Heavy per-element work over whole lists, noise and similar
+    Expensive,
+}
+
+/// An input an operator reads in its next compute, from
+/// [`Operator::active_inputs`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ActiveInput {
+    /// Every connection of an input
+    Input(usize),
+    /// One value of a multi-input, counted like
+    /// [`InputPort::resolve_multi_flat`]: list elements count individually
+    Value {
+        /// Input index
+        input: usize,
+        /// Index into the flattened values
+        index: usize,
+    },
+}
+
+impl From<usize> for ActiveInput {
+    fn from(input: usize) -> Self {
+        ActiveInput::Input(input)
+    }
+}
+
+/// How serious an [`InputValidationIssue`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValidationSeverity {
+    /// Valid, but probably not what was meant (e.g. a constant output)
+    Info,
+    /// The operator falls back to a documented degraded result
+    Warning,
+    /// The operator can't produce a meaningful result
+    Error,
+}
+
+/// An input value that breaks a constraint of its operator, from
+/// [`Operator::validate_inputs`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct InputValidationIssue {
+    /// Input the issue is shown on
+    pub input: usize,
+    /// How serious it is
+    pub severity: ValidationSeverity,
+    /// Human-readable description, suitable for an inline warning
+    pub message: String,
+}
+
+impl InputValidationIssue {
+    /// Create an issue on an input
+    pub fn new(input: usize, severity: ValidationSeverity, message: impl Into<String>) -> Self {
+        Self {
+            input,
+            severity,
+            message: message.into(),
+        }
+    }
+
+    /// Create a [`ValidationSeverity::Info`] issue
+    pub fn info(input: usize, message: impl Into<String>) -> Self {
+        Self::new(input, ValidationSeverity::Info, message)
+    }
+
+    /// Create a [`ValidationSeverity::Warning`] issue
+    pub fn warning(input: usize, message: impl Into<String>) -> Self {
+        Self::new(input, ValidationSeverity::Warning, message)
+    }
+
+    /// Create a [`ValidationSeverity::Error`] issue
+    pub fn error(input: usize, message: impl Into<String>) -> Self {
+        Self::new(input, ValidationSeverity::Error, message)
+    }
+}
+
 /// Core trait for all operators (object-safe)
 ///
 /// This is the fundamental building block of the operator graph system.
@@ -42,6 +130,16 @@ pub trait Operator: Any {
     /// Unique instance ID
     fn id(&self) -> Id;
 
+    /// Take over another instance's ID.
+    ///
+    /// Only called by `Graph::hot_swap`, on a replacement operator that is
+    /// about to stand in for an existing node. Return `false` (the default)
+    /// if the ID can't be changed; such operators must be constructed with
+    /// the node's ID to be swapped in.
+    fn set_id(&mut self, _id: Id) -> bool {
+        false
+    }
+
     /// Human-readable name
     fn name(&self) -> &'static str;
 
@@ -63,6 +161,69 @@ pub trait Operator: Any {
         false
     }
 
+    /// Parts of the evaluation context this operator reads besides time.
+    /// The graph recomputes it when any of them changes. Defaults to none.
+    fn context_dependencies(&self) -> &'static [ContextDependency] {
+        &[]
+    }
+
+    /// Returns true if this operator keeps state between computes (delays,
+    /// counters, integrators), so computing it has effects beyond its outputs.
+    ///
+    /// Preview evaluation skips stateful operators and reuses their last
+    /// output instead of advancing their state.
+    fn is_stateful(&self) -> bool {
+        false
+    }
+
+    /// Put the state of a stateful operator back to how it was when created,
+    /// as if it had never computed. Inputs and connections are kept.
+    ///
+    /// Returns false if the operator can't reset (the default). Replaying an
+    /// evaluation from the same starting point, as
+    /// `Graph::audit_determinism` does, relies on this.
+    fn reset_state(&mut self) -> bool {
+        false
+    }
+
+    /// Returns true if this operator computes all its outputs together, so a
+    /// dirty output recomputes the node even if nothing reads it. Defaults to
+    /// true.
+    ///
+    /// Operators whose outputs are independent can return false: the graph
+    /// then only recomputes them for dirty outputs the current evaluation
+    /// reads, and serves the others from the cache.
+    fn outputs_coupled(&self) -> bool {
+        true
+    }
+
+    /// Rough cost of computing this operator, used to break ties in the
+    /// evaluation order. Defaults to [`OperatorCost::Cheap`].
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Cheap
+    }
+
+    /// Inputs the next compute will read, if the operator can tell before
+    /// its inputs are evaluated. Defaults to `None`: every input is read.
+    ///
+    /// Called before each evaluation. `resolve_cheap(index)` returns an
+    /// input's value when it's known without computing anything: unconnected
+    /// inputs, and inputs fed by a node that won't recompute this evaluation.
+    /// Otherwise it returns `None`, and so should this method. Nodes feeding
+    /// only inputs left out are skipped, keeping their previous outputs.
+    ///
+    /// Inputs the decision is based on must be listed too: a selector driven
+    /// by an expensive chain is always evaluated, and only once its value is
+    /// cached from an earlier evaluation (and unchanged) can the branches it
+    /// rules out be skipped.
+    fn active_inputs(
+        &self,
+        _ctx: &EvalContext,
+        _resolve_cheap: &dyn Fn(usize) -> Option<Value>,
+    ) -> Option<Vec<ActiveInput>> {
+        None
+    }
+
     /// Returns true if this operator can operate in-place on its inputs.
     ///
     /// When true, the graph evaluator may pass ownership of input values to
@@ -94,6 +255,25 @@ pub trait Operator: Any {
         false
     }
 
+    /// Check input values against constraints between inputs (a range's
+    /// start before its end, a positive frequency).
+    ///
+    /// `proposed` holds one value per input: the defaults the operator will
+    /// read once a change is applied. The graph calls this whenever a default
+    /// or a connection changes and keeps the issues for the UI; the values
+    /// are applied regardless, so `compute` must still cope with them.
+    ///
+    /// Connected inputs are read from upstream, so their entry in `proposed`
+    /// is only the fallback default: skip constraints involving an input
+    /// whose port [`is_connected`](InputPort::is_connected).
+    ///
+    /// # Default
+    ///
+    /// Returns no issues.
+    fn validate_inputs(&self, _proposed: &[Value]) -> Vec<InputValidationIssue> {
+        Vec::new()
+    }
+
     // =========================================================================
     // Trigger ports (optional push-based execution)
     // =========================================================================
@@ -214,4 +394,42 @@ pub trait Operator: Any {
     ) -> Vec<usize> {
         Vec::new()
     }
+
+    // === Lifecycle Hooks ===
+    //
+    // Called by the graph after the structural change has been applied and
+    // before the corresponding event can be drained. Hooks only see the
+    // operator itself, so they cannot mutate the graph; they must not block
+    // or assume any other node still (or already) exists.
+
+    /// Called after the operator has been added to a graph.
+    ///
+    /// Also called when a previously removed node is re-inserted, e.g. when a
+    /// removal is undone.
+    fn on_added_to_graph(&mut self) {}
+
+    /// Called after the operator has been removed from a graph.
+    ///
+    /// Release external resources here. The operator may be added again later
+    /// (undo), in which case [`on_added_to_graph`](Self::on_added_to_graph)
+    /// is called again.
+    fn on_removed_from_graph(&mut self) {}
+
+    /// Called after the connections of a value input changed.
+    ///
+    /// `connected` reports whether the input has any connection afterwards.
+    /// Variadic operators can read the new edge count from
+    /// [`InputPort::connection_count`](crate::port::InputPort::connection_count).
+    fn on_connection_changed(&mut self, _input_index: usize, _connected: bool) {}
+
+    /// Changes this operator made to its own port layout since the last call.
+    ///
+    /// Operators that add, remove or reorder ports (usually while computing)
+    /// record how and hand the change over here, once. The graph asks after
+    /// every compute and moves connections to the ports' new indices, as
+    /// `Graph::notify_ports_changed` does. A port moved in the list must be
+    /// moved whole: its connections travel with it.
+    fn take_port_change(&mut self) -> Option<PortChange> {
+        None
+    }
 }
@@ -29,6 +29,10 @@
 //! }
 //! ```
 
+use crate::category::Category;
+use crate::unit::Unit;
+use crate::value::Value;
+
 /// Visual metadata for operators.
 ///
 /// Implement this trait alongside [`Operator`](crate::Operator) to provide
@@ -37,16 +41,27 @@
 pub trait OperatorMeta {
     /// Category for grouping in menus (e.g., "Math", "Sources", "Logic").
     ///
-    /// Used to organize operators in add-node menus.
+    /// Used to organize operators in add-node menus. Separate sub-categories
+    /// with `/`, e.g. "Math/Trig".
     fn category(&self) -> &'static str {
         "Uncategorized"
     }
 
+    /// Structured form of [`category`](Self::category).
+    ///
+    /// Parses the category string by default; override only if the path is
+    /// built some other way.
+    fn category_path(&self) -> Category {
+        Category::parse(self.category())
+    }
+
     /// Category color as [R, G, B, A] in 0.0-1.0 range.
     ///
-    /// Used to color-code nodes by category.
+    /// Used to color-code nodes by category. Defaults to the built-in color
+    /// of the category or the nearest enclosing one, see
+    /// [`category_colors::for_category`].
     fn category_color(&self) -> [f32; 4] {
-        [0.5, 0.5, 0.5, 1.0]
+        category_colors::for_category(&self.category_path())
     }
 
     /// Icon identifier (e.g., FontAwesome unicode or icon name).
@@ -61,6 +76,13 @@ pub trait OperatorMeta {
         ""
     }
 
+    /// Deprecation notice, e.g. "Use Remap instead".
+    ///
+    /// Node editors can show it as a badge on existing nodes.
+    fn deprecation(&self) -> Option<&'static str> {
+        None
+    }
+
     /// Input port metadata by index.
     ///
     /// Return `None` for indices beyond the operator's input count.
@@ -74,6 +96,15 @@ pub trait OperatorMeta {
     fn output_meta(&self, _index: usize) -> Option<PortMeta> {
         None
     }
+
+    /// Input index pairs whose sources users commonly exchange, e.g. A and B
+    /// of Subtract.
+    ///
+    /// Node editors can offer a swap button for these pairs. Any two inputs of
+    /// compatible types can be swapped; this only advertises the useful ones.
+    fn swappable_input_pairs(&self) -> &'static [(usize, usize)] {
+        &[]
+    }
 }
 
 /// Metadata for a single port (input or output).
@@ -94,6 +125,17 @@ pub struct PortMeta {
 
     /// Unit suffix for display (e.g., "Hz", "ms", "rad").
     pub unit: Option<&'static str>,
+
+    /// Typed unit used for compatibility checks and automatic scaling.
+    pub typed_unit: Option<Unit>,
+
+    /// Step size for UI controls, and for quantization when clamping.
+    pub step: Option<f32>,
+
+    /// Enforce `range` and `step` on resolved input values during evaluation.
+    ///
+    /// Without this, `range` is only a UI hint. See [`PortClamp`].
+    pub clamp_to_range: bool,
 }
 
 impl PortMeta {
@@ -105,6 +147,9 @@ impl PortMeta {
             color: None,
             range: None,
             unit: None,
+            typed_unit: None,
+            step: None,
+            clamp_to_range: false,
         }
     }
 
@@ -126,12 +171,33 @@ impl PortMeta {
         self
     }
 
+    /// Set the step size.
+    pub const fn with_step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Enforce the range (and step) on input values during evaluation.
+    pub const fn with_clamp(mut self) -> Self {
+        self.clamp_to_range = true;
+        self
+    }
+
     /// Set the unit suffix.
     pub const fn with_unit(mut self, unit: &'static str) -> Self {
         self.unit = Some(unit);
         self
     }
 
+    /// Set a typed unit. Also sets the display suffix if none was given.
+    pub const fn with_typed_unit(mut self, unit: Unit) -> Self {
+        self.typed_unit = Some(unit);
+        if self.unit.is_none() {
+            self.unit = Some(unit.symbol());
+        }
+        self
+    }
+
     /// Returns true if this port represents a semantic parameter.
     ///
     /// Semantic parameters are inputs with meaningful names that should be displayed
@@ -153,7 +219,7 @@ impl PortMeta {
     ///     .is_semantic_parameter() // false
     /// ```
     pub const fn is_semantic_parameter(&self) -> bool {
-        self.range.is_some() || self.unit.is_some()
+        self.range.is_some() || self.unit.is_some() || self.typed_unit.is_some()
     }
 }
 
@@ -211,6 +277,18 @@ pub struct PortOverride {
 
     /// Custom step size for UI controls (None = auto).
     pub step: Option<f32>,
+
+    /// Smoothing duration in seconds for default value changes (None = instant).
+    ///
+    /// When set, the graph ramps an unconnected input from its previous default
+    /// toward a newly assigned one over this duration instead of jumping.
+    /// Applies to Float, Vec2, Vec3, Vec4 and Color defaults.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub smoothing_seconds: Option<f32>,
+
+    /// Enforce the range and step during evaluation (None = use PortMeta default).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clamp_to_range: Option<bool>,
 }
 
 impl PortOverride {
@@ -243,12 +321,26 @@ impl PortOverride {
         self
     }
 
+    /// Set a smoothing duration for default value changes.
+    pub fn with_smoothing(mut self, seconds: f32) -> Self {
+        self.smoothing_seconds = Some(seconds);
+        self
+    }
+
+    /// Enable or disable range enforcement during evaluation.
+    pub fn with_clamp(mut self, clamp: bool) -> Self {
+        self.clamp_to_range = Some(clamp);
+        self
+    }
+
     /// Returns true if all fields are None (no overrides).
     pub fn is_empty(&self) -> bool {
         self.range.is_none()
             && self.label.is_none()
             && self.unit.is_none()
             && self.step.is_none()
+            && self.smoothing_seconds.is_none()
+            && self.clamp_to_range.is_none()
     }
 }
 
@@ -281,9 +373,12 @@ pub struct EffectivePortMeta {
     /// Resolved unit suffix (from override or PortMeta, if any).
     pub unit: Option<String>,
 
-    /// Step size from override (None = auto-calculate based on range).
+    /// Resolved step size (None = auto-calculate based on range).
     pub step: Option<f32>,
 
+    /// Whether range and step are enforced during evaluation.
+    pub clamp_to_range: bool,
+
     /// Pin shape (from PortMeta - not overridable).
     pub shape: PinShape,
 
@@ -306,7 +401,8 @@ impl EffectivePortMeta {
             label: override_.label.unwrap_or_else(|| meta.label.to_string()),
             range: override_.range.or(meta.range),
             unit: override_.unit.or_else(|| meta.unit.map(|s| s.to_string())),
-            step: override_.step,
+            step: override_.step.or(meta.step),
+            clamp_to_range: override_.clamp_to_range.unwrap_or(meta.clamp_to_range),
             shape: meta.shape,
             color: meta.color,
         }
@@ -319,10 +415,90 @@ impl Default for EffectivePortMeta {
     }
 }
 
+/// Range and step enforcement for an input port.
+///
+/// Resolved from [`PortMeta`] and [`PortOverride`] when `clamp_to_range` is
+/// set. The graph applies it to an input's resolved value (connected or
+/// default) before the operator sees it. Float, Int and Vec2/Vec3/Vec4 values
+/// are quantized to `step` (counted from the range minimum, if any) and then
+/// clamped to `range`, component-wise; other value types pass through.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PortClamp {
+    /// Inclusive bounds
+    pub range: Option<(f32, f32)>,
+    /// Quantization step (ignored unless positive)
+    pub step: Option<f32>,
+}
+
+impl PortClamp {
+    /// Resolve enforcement for a port, combining metadata and overrides.
+    ///
+    /// Returns `None` if clamping is disabled or there is neither a range nor
+    /// a step to enforce.
+    pub fn resolve(meta: Option<&PortMeta>, override_: Option<&PortOverride>) -> Option<Self> {
+        let enabled = override_
+            .and_then(|o| o.clamp_to_range)
+            .unwrap_or_else(|| meta.is_some_and(|m| m.clamp_to_range));
+        if !enabled {
+            return None;
+        }
+        let clamp = Self {
+            range: override_.and_then(|o| o.range).or(meta.and_then(|m| m.range)),
+            step: override_
+                .and_then(|o| o.step)
+                .or(meta.and_then(|m| m.step))
+                .filter(|&step| step > 0.0),
+        };
+        (clamp.range.is_some() || clamp.step.is_some()).then_some(clamp)
+    }
+
+    /// Apply to a value.
+    pub fn apply(&self, value: Value) -> Value {
+        match value {
+            Value::Float(v) => Value::Float(self.apply_f32(v)),
+            Value::Int(v) => Value::Int(self.apply_int(v)),
+            Value::Vec2(v) => Value::Vec2(v.map(|c| self.apply_f32(c))),
+            Value::Vec3(v) => Value::Vec3(v.map(|c| self.apply_f32(c))),
+            Value::Vec4(v) => Value::Vec4(v.map(|c| self.apply_f32(c))),
+            other => other,
+        }
+    }
+
+    fn apply_f32(&self, value: f32) -> f32 {
+        let mut value = value;
+        if let Some(step) = self.step {
+            let origin = self.range.map_or(0.0, |(min, _)| min);
+            value = origin + ((value - origin) / step).round() * step;
+        }
+        match self.range {
+            Some((min, max)) if min <= max => value.clamp(min, max),
+            _ => value,
+        }
+    }
+
+    fn apply_int(&self, value: i32) -> i32 {
+        let mut value = value as f64;
+        if let Some(step) = self.step {
+            let step = step as f64;
+            let origin = self.range.map_or(0.0, |(min, _)| min as f64);
+            value = origin + ((value - origin) / step).round() * step;
+        }
+        if let Some((min, max)) = self.range {
+            let (min, max) = ((min as f64).ceil(), (max as f64).floor());
+            if min <= max {
+                value = value.clamp(min, max);
+            }
+        }
+        value.round() as i32
+    }
+}
+
 /// Standard category colors for common operator types.
 ///
 /// These are optional conventions - operators can use any color.
 pub mod category_colors {
+    use crate::category::Category;
+
     /// Sources (constants, time, inputs) - green
     pub const SOURCES: [f32; 4] = [0.25, 0.55, 0.35, 1.0];
 
@@ -367,4 +543,105 @@ pub mod category_colors {
 
     /// String operations - light blue/cyan
     pub const STRING: [f32; 4] = [0.35, 0.50, 0.55, 1.0];
+
+    /// Structured map data - olive
+    pub const MAP: [f32; 4] = [0.45, 0.50, 0.30, 1.0];
+
+    /// Color of a built-in category path, without falling back.
+    pub fn builtin(path: &str) -> Option<[f32; 4]> {
+        let color = match path {
+            "Sources" => SOURCES,
+            "Time" => TIME,
+            "Math" => MATH,
+            "Oscillators" => OSCILLATORS,
+            "Logic" => LOGIC,
+            "Vector" => VECTORS,
+            "Color" => COLORS,
+            "Output" => OUTPUT,
+            "List" => LIST,
+            "Flow" => FLOW,
+            "Utility" => UTIL,
+            "String" => STRING,
+            "Map" => MAP,
+            "Uncategorized" => UNCATEGORIZED,
+            _ => return None,
+        };
+        Some(color)
+    }
+
+    /// Color for a category, falling back up the hierarchy.
+    ///
+    /// `Math/Trig` uses the `Math/Trig` color if there is one, else the
+    /// `Math` color; unknown categories are [`UNCATEGORIZED`].
+    pub fn for_category(category: &Category) -> [f32; 4] {
+        category
+            .ancestors()
+            .find_map(|category| builtin(&category.to_string()))
+            .unwrap_or(UNCATEGORIZED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_port_clamp_resolve() {
+        let meta = PortMeta::new("Mix").with_range(0.0, 1.0);
+        assert_eq!(PortClamp::resolve(Some(&meta), None), None);
+
+        let clamped = meta.clone().with_clamp();
+        let clamp = PortClamp::resolve(Some(&clamped), None).unwrap();
+        assert_eq!(clamp.range, Some((0.0, 1.0)));
+
+        // Overrides can enable, disable and narrow enforcement
+        let narrow = PortOverride::new().with_range(0.2, 0.4).with_clamp(true);
+        let clamp = PortClamp::resolve(Some(&meta), Some(&narrow)).unwrap();
+        assert_eq!(clamp.range, Some((0.2, 0.4)));
+        let off = PortOverride::new().with_clamp(false);
+        assert_eq!(PortClamp::resolve(Some(&clamped), Some(&off)), None);
+    }
+
+    #[test]
+    fn test_port_clamp_apply() {
+        let clamp = PortClamp {
+            range: Some((0.0, 1.0)),
+            step: Some(0.05),
+        };
+        let Value::Float(v) = clamp.apply(Value::Float(0.37)) else {
+            panic!("expected float");
+        };
+        assert!((v - 0.35).abs() < 1e-6);
+        assert_eq!(clamp.apply(Value::Float(500.0)), Value::Float(1.0));
+        assert_eq!(clamp.apply(Value::Vec2([-3.0, 0.5])), Value::Vec2([0.0, 0.5]));
+        assert_eq!(clamp.apply(Value::Bool(true)), Value::Bool(true));
+
+        let int_clamp = PortClamp {
+            range: Some((0.5, 10.5)),
+            step: Some(2.0),
+        };
+        assert_eq!(int_clamp.apply(Value::Int(-4)), Value::Int(1));
+        assert_eq!(int_clamp.apply(Value::Int(6)), Value::Int(7));
+    }
+
+    /// Implements only the string method, like operators written before
+    /// categories had paths
+    struct Legacy(&'static str);
+
+    impl OperatorMeta for Legacy {
+        fn category(&self) -> &'static str { self.0 }
+    }
+
+    #[test]
+    fn test_category_path_from_string() {
+        assert_eq!(Legacy("Math").category_path(), Category::MATH);
+        assert_eq!(Legacy("Math/Trig").category_path(), Category::MATH.child("Trig"));
+        assert_eq!(Legacy("").category_path(), Category::UNCATEGORIZED);
+
+        // Colors fall back to the nearest category with one
+        assert_eq!(Legacy("Math").category_color(), category_colors::MATH);
+        assert_eq!(Legacy("Math/Trig/Hyperbolic").category_color(), category_colors::MATH);
+        assert_eq!(Legacy("Custom/Stuff").category_color(), category_colors::UNCATEGORIZED);
+        assert_eq!(category_colors::builtin("Math/Trig"), None);
+    }
 }
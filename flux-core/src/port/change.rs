@@ -0,0 +1,219 @@
+//! Runtime port layout changes
+//!
+//! Operators such as a string template regenerate their ports while in a
+//! graph. Connections refer to ports by index, so after an operator adds,
+//! removes or reorders ports the graph has to move those references along.
+//! A [`PortChange`] tells it how: for each side, either a list of edits in
+//! the order they were made, or the previous port names to match by.
+//!
+//! ```ignore
+//! // The second of three outputs was removed: a connection from output 2
+//! // moves to output 1, connections from output 1 are dropped.
+//! let change = PortChange::new().remove_output(1);
+//!
+//! // Ports were rebuilt from scratch: find the old ones again by name.
+//! let change = PortChange::new().inputs_by_name(["Template", "fps", "time"]);
+//! ```
+
+/// One edit to an operator's input or output list.
+///
+/// Indices refer to the list as it was just before the edit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PortEdit {
+    /// A port was inserted at this index; ports from it onwards shift up
+    Inserted(usize),
+    /// The port at this index was removed; later ports shift down
+    Removed(usize),
+    /// The port at `from` was removed and reinserted at `to`
+    Moved { from: usize, to: usize },
+    /// The port at this index was renamed; its connections stay
+    Renamed(usize),
+}
+
+impl PortEdit {
+    /// Index after this edit of the port at `index` before it.
+    fn apply(self, index: usize) -> Option<usize> {
+        match self {
+            PortEdit::Inserted(at) => Some(if index >= at { index + 1 } else { index }),
+            PortEdit::Removed(at) => match index.cmp(&at) {
+                std::cmp::Ordering::Less => Some(index),
+                std::cmp::Ordering::Equal => None,
+                std::cmp::Ordering::Greater => Some(index - 1),
+            },
+            PortEdit::Moved { from, to } => {
+                if index == from {
+                    return Some(to);
+                }
+                let index = if index > from { index - 1 } else { index };
+                Some(if index >= to { index + 1 } else { index })
+            }
+            PortEdit::Renamed(_) => Some(index),
+        }
+    }
+}
+
+/// How one side of an operator's ports changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PortRemap {
+    /// Existing ports kept their indices (ports may have been appended)
+    #[default]
+    Unchanged,
+    /// Edits applied in order to the previous ports
+    Edits(Vec<PortEdit>),
+    /// Names of the previous ports, in order. Each is found again among the
+    /// current ports by name; names that are gone count as removed.
+    ByName(Vec<String>),
+}
+
+impl PortRemap {
+    /// Current index of the port previously at `index`, or `None` if it was
+    /// removed.
+    ///
+    /// `current` are the names of the ports after the change.
+    pub fn new_index(&self, index: usize, current: &[&str]) -> Option<usize> {
+        let new_index = match self {
+            PortRemap::Unchanged => Some(index),
+            PortRemap::Edits(edits) => edits.iter().try_fold(index, |index, edit| edit.apply(index)),
+            PortRemap::ByName(names) => {
+                let name = names.get(index)?;
+                current.iter().position(|current| current == name)
+            }
+        };
+        new_index.filter(|&index| index < current.len())
+    }
+
+    /// Whether every previous port keeps its index.
+    pub fn is_unchanged(&self) -> bool {
+        match self {
+            PortRemap::Unchanged => true,
+            PortRemap::Edits(edits) => edits.iter().all(|edit| matches!(edit, PortEdit::Renamed(_))),
+            PortRemap::ByName(_) => false,
+        }
+    }
+
+    fn push(&mut self, edit: PortEdit) {
+        match self {
+            PortRemap::Edits(edits) => edits.push(edit),
+            _ => *self = PortRemap::Edits(vec![edit]),
+        }
+    }
+}
+
+/// How an operator's inputs and outputs changed at runtime.
+///
+/// Returned from [`Operator::take_port_change`](crate::Operator::take_port_change)
+/// or passed to `Graph::notify_ports_changed`. The builder methods append
+/// edits, so record them in the order they were made.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PortChange {
+    /// How the value inputs changed
+    pub inputs: PortRemap,
+    /// How the value outputs changed
+    pub outputs: PortRemap,
+}
+
+impl PortChange {
+    /// A change that keeps every port's index.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether no existing port moved or disappeared.
+    pub fn is_unchanged(&self) -> bool {
+        self.inputs.is_unchanged() && self.outputs.is_unchanged()
+    }
+
+    /// An input was inserted at `index`.
+    pub fn insert_input(mut self, index: usize) -> Self {
+        self.inputs.push(PortEdit::Inserted(index));
+        self
+    }
+
+    /// The input at `index` was removed.
+    pub fn remove_input(mut self, index: usize) -> Self {
+        self.inputs.push(PortEdit::Removed(index));
+        self
+    }
+
+    /// The input at `from` moved to `to`.
+    pub fn move_input(mut self, from: usize, to: usize) -> Self {
+        self.inputs.push(PortEdit::Moved { from, to });
+        self
+    }
+
+    /// The input at `index` was renamed.
+    pub fn rename_input(mut self, index: usize) -> Self {
+        self.inputs.push(PortEdit::Renamed(index));
+        self
+    }
+
+    /// An output was inserted at `index`.
+    pub fn insert_output(mut self, index: usize) -> Self {
+        self.outputs.push(PortEdit::Inserted(index));
+        self
+    }
+
+    /// The output at `index` was removed.
+    pub fn remove_output(mut self, index: usize) -> Self {
+        self.outputs.push(PortEdit::Removed(index));
+        self
+    }
+
+    /// The output at `from` moved to `to`.
+    pub fn move_output(mut self, from: usize, to: usize) -> Self {
+        self.outputs.push(PortEdit::Moved { from, to });
+        self
+    }
+
+    /// The output at `index` was renamed.
+    pub fn rename_output(mut self, index: usize) -> Self {
+        self.outputs.push(PortEdit::Renamed(index));
+        self
+    }
+
+    /// Match inputs by name: `previous` are the input names before the change.
+    pub fn inputs_by_name<S: Into<String>>(mut self, previous: impl IntoIterator<Item = S>) -> Self {
+        self.inputs = PortRemap::ByName(previous.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Match outputs by name: `previous` are the output names before the change.
+    pub fn outputs_by_name<S: Into<String>>(mut self, previous: impl IntoIterator<Item = S>) -> Self {
+        self.outputs = PortRemap::ByName(previous.into_iter().map(Into::into).collect());
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_edits_shift_indices() {
+        let change = PortChange::new().remove_output(1);
+        let current = ["a", "c"];
+        assert_eq!(change.outputs.new_index(0, &current), Some(0));
+        assert_eq!(change.outputs.new_index(1, &current), None);
+        assert_eq!(change.outputs.new_index(2, &current), Some(1));
+
+        // Applied in order: insert at 0, then move the old first port to the end
+        let change = PortChange::new().insert_output(0).move_output(1, 2);
+        let current = ["new", "b", "a"];
+        assert_eq!(change.outputs.new_index(0, &current), Some(2));
+        assert_eq!(change.outputs.new_index(1, &current), Some(1));
+        assert!(!change.is_unchanged());
+        assert!(PortChange::new().rename_input(0).is_unchanged());
+    }
+
+    #[test]
+    fn test_by_name_and_out_of_range() {
+        let change = PortChange::new().inputs_by_name(["Template", "a", "b"]);
+        let current = ["Template", "b", "c"];
+        assert_eq!(change.inputs.new_index(0, &current), Some(0));
+        assert_eq!(change.inputs.new_index(1, &current), None);
+        assert_eq!(change.inputs.new_index(2, &current), Some(1));
+
+        // Ports the operator didn't actually keep are treated as removed
+        assert_eq!(PortRemap::Unchanged.new_index(3, &current), None);
+    }
+}
@@ -0,0 +1,97 @@
+//! Where an unconnected input takes its value from
+//!
+//! Most inputs use a fixed default. An input can instead be bound to a
+//! context variable, so one host-controlled value (e.g. `global.intensity`)
+//! drives many ports without wiring variable nodes to each of them.
+
+use serde::{Deserialize, Serialize};
+
+use crate::context::EvalContext;
+use crate::value::{Value, ValueType};
+
+/// Source of an unconnected input's value
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DefaultSource {
+    /// A fixed value
+    Literal(Value),
+
+    /// A context variable, read on every evaluation
+    ///
+    /// `fallback` is used while the variable is absent or can't be coerced
+    /// to the port type.
+    ContextVar { name: String, fallback: Value },
+}
+
+impl DefaultSource {
+    /// Bind to the context variable `name`
+    pub fn context_var(name: impl Into<String>, fallback: Value) -> Self {
+        Self::ContextVar {
+            name: name.into(),
+            fallback,
+        }
+    }
+
+    /// Name of the bound context variable, if any
+    pub fn context_var_name(&self) -> Option<&str> {
+        match self {
+            Self::Literal(_) => None,
+            Self::ContextVar { name, .. } => Some(name),
+        }
+    }
+
+    /// The literal value, or the fallback of a context binding
+    pub fn static_value(&self) -> &Value {
+        match self {
+            Self::Literal(value) => value,
+            Self::ContextVar { fallback, .. } => fallback,
+        }
+    }
+
+    /// Resolve the value for a port of type `value_type`
+    pub fn resolve(&self, ctx: &EvalContext, value_type: ValueType) -> Value {
+        match self {
+            Self::Literal(value) => value.clone(),
+            Self::ContextVar { name, fallback } => ctx
+                .get_var(name)
+                .and_then(|value| value.coerce_to(value_type))
+                .unwrap_or_else(|| fallback.clone()),
+        }
+    }
+}
+
+impl From<Value> for DefaultSource {
+    fn from(value: Value) -> Self {
+        Self::Literal(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_context_var() {
+        let source = DefaultSource::context_var("global.intensity", Value::Float(0.5));
+        let mut ctx = EvalContext::new();
+        assert_eq!(source.resolve(&ctx, ValueType::Float), Value::Float(0.5));
+
+        ctx.set_float_var("global.intensity", 0.8);
+        assert_eq!(source.resolve(&ctx, ValueType::Float), Value::Float(0.8));
+
+        // Coerced to the port type
+        ctx.float_vars.clear();
+        ctx.set_int_var("global.intensity", 2);
+        assert_eq!(source.resolve(&ctx, ValueType::Float), Value::Float(2.0));
+
+        // Values that can't be coerced use the fallback
+        ctx.int_vars.clear();
+        ctx.set_string_var("global.intensity", "high");
+        assert_eq!(source.resolve(&ctx, ValueType::Float), Value::Float(0.5));
+
+        let literal = DefaultSource::from(Value::Int(3));
+        assert_eq!(literal.resolve(&ctx, ValueType::Int), Value::Int(3));
+        assert_eq!(literal.context_var_name(), None);
+        assert_eq!(source.context_var_name(), Some("global.intensity"));
+        assert_eq!(source.static_value(), &Value::Float(0.5));
+    }
+}
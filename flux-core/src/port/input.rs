@@ -2,10 +2,18 @@
 
 use crate::error::{OperatorError, OperatorResult};
 use crate::id::Id;
+use crate::operator::InputResolver;
 use crate::value::{Color, Gradient, Value, ValueType};
 
 use super::TypeConstraint;
 
+/// Connection state of an input port saved by [`InputPort::take_muted`]
+#[derive(Clone, Debug)]
+pub struct TakenConnections {
+    connection: Option<(Id, usize)>,
+    connections: Vec<(Id, usize)>,
+}
+
 /// An input port that can be connected to an output
 #[derive(Clone, Debug)]
 pub struct InputPort {
@@ -23,6 +31,9 @@ pub struct InputPort {
     pub is_multi_input: bool,
     /// For multi-input ports: all connections in order
     pub connections: Vec<(Id, usize)>,
+    /// Muted flags by connection slot: slot 0 is the single connection,
+    /// otherwise slots follow `connections`. Missing slots are unmuted.
+    pub muted: Vec<bool>,
     /// Resolved type after connection (for polymorphic ports)
     pub resolved_type: Option<ValueType>,
 }
@@ -40,6 +51,7 @@ impl InputPort {
             connection: None,
             is_multi_input: false,
             connections: Vec::new(),
+            muted: Vec::new(),
             resolved_type: None,
         }
     }
@@ -55,6 +67,7 @@ impl InputPort {
             connection: None,
             is_multi_input: true,
             connections: Vec::new(),
+            muted: Vec::new(),
             resolved_type: None,
         }
     }
@@ -71,6 +84,7 @@ impl InputPort {
             connection: None,
             is_multi_input: false,
             connections: Vec::new(),
+            muted: Vec::new(),
             resolved_type: None,
         }
     }
@@ -87,6 +101,7 @@ impl InputPort {
             connection: None,
             is_multi_input: false,
             connections: Vec::new(),
+            muted: Vec::new(),
             resolved_type: None,
         }
     }
@@ -102,6 +117,7 @@ impl InputPort {
             connection: None,
             is_multi_input: false,
             connections: Vec::new(),
+            muted: Vec::new(),
             resolved_type: None,
         }
     }
@@ -117,6 +133,7 @@ impl InputPort {
             connection: None,
             is_multi_input: false,
             connections: Vec::new(),
+            muted: Vec::new(),
             resolved_type: None,
         }
     }
@@ -133,6 +150,7 @@ impl InputPort {
             connection: None,
             is_multi_input: false,
             connections: Vec::new(),
+            muted: Vec::new(),
             resolved_type: None,
         }
     }
@@ -227,6 +245,16 @@ impl InputPort {
         Self::new(name, Value::string_list(Vec::new()))
     }
 
+    /// Convenience constructor for map input
+    pub fn map(name: &'static str) -> Self {
+        Self::new(name, Value::Map(Default::default()))
+    }
+
+    /// Convenience constructor for resource reference input (unresolved by default)
+    pub fn resource_ref(name: &'static str) -> Self {
+        Self::new(name, ValueType::ResourceRef.default_value())
+    }
+
     /// Convenience constructor for multi-input bool
     pub fn bool_multi(name: &'static str) -> Self {
         Self::new_multi(name, ValueType::Bool)
@@ -273,6 +301,7 @@ impl InputPort {
             connection: None,
             is_multi_input: false,
             connections: Vec::new(),
+            muted: Vec::new(),
             resolved_type: None,
         }
     }
@@ -300,12 +329,14 @@ impl InputPort {
             self.connections.push((source_node, output_index));
         } else {
             self.connection = Some((source_node, output_index));
+            self.muted.clear();
         }
     }
 
     pub fn disconnect(&mut self) {
         self.connection = None;
         self.connections.clear();
+        self.muted.clear();
     }
 
     /// Disconnect a specific connection (for multi-input)
@@ -313,10 +344,153 @@ impl InputPort {
         if self.is_multi_input {
             if index < self.connections.len() {
                 self.connections.remove(index);
+                if index < self.muted.len() {
+                    self.muted.remove(index);
+                }
             }
         } else {
             self.connection = None;
+            self.muted.clear();
+        }
+    }
+
+    /// Whether the connection in a slot is muted (see [`muted`](Self::muted))
+    pub fn is_muted(&self, slot: usize) -> bool {
+        self.muted.get(slot).copied().unwrap_or(false)
+    }
+
+    /// Whether any connection is muted
+    pub fn has_muted(&self) -> bool {
+        self.muted.contains(&true)
+    }
+
+    /// Mute or unmute the connection in a slot.
+    ///
+    /// Returns false if the slot has no connection.
+    pub fn set_muted(&mut self, slot: usize, muted: bool) -> bool {
+        if slot >= self.connection_count() {
+            return false;
+        }
+        if self.muted.len() <= slot {
+            self.muted.resize(slot + 1, false);
+        }
+        self.muted[slot] = muted;
+        self.trim_muted();
+        true
+    }
+
+    /// Move a multi-input connection to another slot, keeping its muted flag
+    pub fn move_connection(&mut self, from: usize, to: usize) {
+        if from >= self.connections.len() {
+            return;
+        }
+        let mut flags: Vec<bool> = (0..self.connections.len()).map(|s| self.is_muted(s)).collect();
+        let edge = self.connections.remove(from);
+        let flag = flags.remove(from);
+        let to = to.min(self.connections.len());
+        self.connections.insert(to, edge);
+        flags.insert(to, flag);
+        self.muted = flags;
+        self.trim_muted();
+    }
+
+    /// Drop trailing unmuted flags so an unmuted port has none
+    fn trim_muted(&mut self) {
+        while self.muted.last() == Some(&false) {
+            self.muted.pop();
+        }
+    }
+
+    /// Remove muted connections, returning the port's full connection state
+    /// for [`restore_muted`](Self::restore_muted).
+    ///
+    /// While muted connections are taken, the port reads like one that was
+    /// never connected to them: a muted single connection falls back to the
+    /// default, and muted multi-input entries are skipped.
+    pub fn take_muted(&mut self) -> Option<TakenConnections> {
+        if !self.has_muted() {
+            return None;
+        }
+        let connection = self.connection;
+        if !self.is_multi_input && self.is_muted(0) {
+            self.connection = None;
         }
+        let live = self
+            .connections
+            .iter()
+            .enumerate()
+            .filter(|&(slot, _)| !self.is_muted(slot))
+            .map(|(_, &source)| source)
+            .collect();
+        let connections = std::mem::replace(&mut self.connections, live);
+        Some(TakenConnections { connection, connections })
+    }
+
+    /// Put back connections removed by [`take_muted`](Self::take_muted).
+    pub fn restore_muted(&mut self, taken: TakenConnections) {
+        self.connection = taken.connection;
+        self.connections = taken.connections;
+    }
+
+    /// Whether an output of this type connects without a conversion
+    ///
+    /// Besides the port's own type, multi-inputs take the matching list type
+    /// (e.g. `FloatList` on a `Float` multi-input); its elements are spliced
+    /// in by [`resolve_multi_flat`](Self::resolve_multi_flat).
+    pub fn connects_directly(&self, source_type: ValueType) -> bool {
+        source_type == self.value_type
+            || (self.is_multi_input && self.value_type.list_type() == Some(source_type))
+    }
+
+    /// Resolve a multi-input to one value per element, flattening lists
+    ///
+    /// Connections are visited in connection order. Each contributes its
+    /// value, or all elements of a list value in list order, so a Float, a
+    /// 3-element FloatList and another Float give five values. Without
+    /// connections the default is used, flattened the same way.
+    pub fn resolve_multi_flat(&self, get_input: InputResolver) -> Vec<Value> {
+        let values: Vec<Value> = if self.is_multi_input && !self.connections.is_empty() {
+            self.connections.iter().map(|&(node, output)| get_input(node, output)).collect()
+        } else {
+            match self.connection {
+                Some((node, output)) => vec![get_input(node, output)],
+                None => vec![self.default.clone()],
+            }
+        };
+        values
+            .into_iter()
+            .flat_map(|value| value.list_elements().unwrap_or_else(|| vec![value]))
+            .collect()
+    }
+
+    /// [`resolve_multi_flat`](Self::resolve_multi_flat) as floats
+    pub fn resolve_multi_floats(&self, get_input: InputResolver) -> Vec<f32> {
+        let values = self.resolve_multi_flat(get_input);
+        values.iter().map(|v| v.as_float().unwrap_or(0.0)).collect()
+    }
+
+    /// [`resolve_multi_flat`](Self::resolve_multi_flat) as ints
+    pub fn resolve_multi_ints(&self, get_input: InputResolver) -> Vec<i32> {
+        let values = self.resolve_multi_flat(get_input);
+        values.iter().map(|v| v.as_int().unwrap_or(0)).collect()
+    }
+
+    /// [`resolve_multi_flat`](Self::resolve_multi_flat) as bools
+    pub fn resolve_multi_bools(&self, get_input: InputResolver) -> Vec<bool> {
+        let values = self.resolve_multi_flat(get_input);
+        values.iter().map(|v| v.as_bool().unwrap_or(false)).collect()
+    }
+
+    /// [`resolve_multi_flat`](Self::resolve_multi_flat) as vec3s
+    pub fn resolve_multi_vec3s(&self, get_input: InputResolver) -> Vec<[f32; 3]> {
+        let values = self.resolve_multi_flat(get_input);
+        values.iter().map(|v| v.as_vec3().unwrap_or([0.0, 0.0, 0.0])).collect()
+    }
+
+    /// [`resolve_multi_flat`](Self::resolve_multi_flat) as colors
+    pub fn resolve_multi_colors(&self, get_input: InputResolver) -> Vec<Color> {
+        let values = self.resolve_multi_flat(get_input);
+        values.iter().map(|v| v.as_color().unwrap_or(Color::BLACK)).collect()
     }
 
     /// Check if a value can be accepted (with optional coercion)
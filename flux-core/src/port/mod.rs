@@ -5,15 +5,21 @@
 //! - [`OutputPort`] - Ports that produce values for downstream operators
 //! - [`TriggerInput`] - Ports that receive trigger signals (push-based)
 //! - [`TriggerOutput`] - Ports that emit trigger signals (push-based)
+//! - [`DefaultSource`] - Where an unconnected input takes its value from
+//! - [`PortChange`] - How an operator's ports changed at runtime
 //! - [`TypeConstraint`] - Defines what types an input port accepts
 //! - [`OutputTypeRule`] - Defines how an output port's type is determined
 
+mod change;
 mod constraint;
+mod default_source;
 mod input;
 mod output;
 mod trigger;
 
+pub use change::{PortChange, PortEdit, PortRemap};
 pub use constraint::{OutputTypeRule, TypeConstraint};
-pub use input::InputPort;
+pub use default_source::DefaultSource;
+pub use input::{InputPort, TakenConnections};
 pub use output::OutputPort;
 pub use trigger::{TriggerInput, TriggerOutput};
@@ -234,6 +234,16 @@ impl OutputPort {
         Self::new(name, ValueType::StringList)
     }
 
+    /// Convenience constructor for map output
+    pub fn map(name: &'static str) -> Self {
+        Self::new(name, ValueType::Map)
+    }
+
+    /// Convenience constructor for resource reference output
+    pub fn resource_ref(name: &'static str) -> Self {
+        Self::new(name, ValueType::ResourceRef)
+    }
+
     /// Set vec2 value (convenience method)
     pub fn set_vec2(&mut self, value: [f32; 2]) {
         self.set(Value::Vec2(value));
@@ -258,6 +268,15 @@ impl OutputPort {
         self.value_type = resolved;
     }
 
+    /// Set the resolved type directly
+    ///
+    /// For [`OutputTypeRule::Dynamic`] outputs whose type depends on the
+    /// values being processed rather than the connected input types.
+    pub fn set_resolved_type(&mut self, value_type: ValueType) {
+        self.resolved_type = Some(value_type);
+        self.value_type = value_type;
+    }
+
     /// Clear the resolved type (reset to default)
     pub fn clear_resolved_type(&mut self) {
         self.resolved_type = None;
@@ -0,0 +1,295 @@
+//! Host-managed resources referenced from graph values
+//!
+//! Textures, meshes and audio buffers live outside the graph. The host
+//! registers them in a [`ResourceTable`] and installs it on
+//! [`EvalContext::resources`](crate::EvalContext::resources); operators then
+//! pass [`Value::ResourceRef`](crate::Value::ResourceRef)s around without
+//! flux knowing what the handles are.
+//!
+//! Resource ids are only meaningful for the table that issued them, so
+//! serialized values store the resource's kind and name instead. Wrap
+//! (de)serialization in [`with_serde_resources`] to name references on save
+//! and re-resolve them on load:
+//!
+//! ```
+//! use std::sync::Arc;
+//! use flux_core::resource::{with_serde_resources, ResourceKind, ResourceTable};
+//! use flux_core::Value;
+//!
+//! let mut table = ResourceTable::new();
+//! let id = table.insert(ResourceKind::Texture, "brick", ());
+//! let table = Arc::new(table);
+//!
+//! let value = Value::ResourceRef { kind: ResourceKind::Texture, id };
+//! let json = with_serde_resources(&table, || serde_json::to_string(&value)).unwrap();
+//! assert!(json.contains("brick"));
+//! ```
+
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Id that never refers to a resource (unresolved or missing references)
+pub const NULL_RESOURCE_ID: u64 = 0;
+
+/// Opaque host handle stored in a [`ResourceTable`]
+pub type ResourceHandle = Arc<dyn Any + Send + Sync>;
+
+/// What kind of host resource a reference points to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ResourceKind {
+    Texture,
+    Mesh,
+    Audio,
+    /// Host-defined kind
+    Custom(u16),
+}
+
+impl fmt::Display for ResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceKind::Texture => write!(f, "Texture"),
+            ResourceKind::Mesh => write!(f, "Mesh"),
+            ResourceKind::Audio => write!(f, "Audio"),
+            ResourceKind::Custom(kind) => write!(f, "Custom({})", kind),
+        }
+    }
+}
+
+/// A resource registered in a [`ResourceTable`]
+#[derive(Clone)]
+pub struct ResourceEntry {
+    pub kind: ResourceKind,
+    pub name: String,
+    pub handle: ResourceHandle,
+}
+
+impl fmt::Debug for ResourceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResourceEntry")
+            .field("kind", &self.kind)
+            .field("name", &self.name)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Host resources by id, with lookup by name
+///
+/// Names are unique within a table. Ids start at 1 and are never reused,
+/// so a stale reference to a removed resource stays missing.
+#[derive(Clone, Debug)]
+pub struct ResourceTable {
+    entries: HashMap<u64, ResourceEntry>,
+    by_name: HashMap<String, u64>,
+    next_id: u64,
+}
+
+impl Default for ResourceTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ResourceTable {
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            by_name: HashMap::new(),
+            next_id: NULL_RESOURCE_ID + 1,
+        }
+    }
+
+    /// Register a resource, returning its id
+    ///
+    /// Registering a name that is already taken replaces that resource and
+    /// keeps its id, so existing references follow the new handle.
+    pub fn insert<T>(&mut self, kind: ResourceKind, name: &str, handle: T) -> u64
+    where
+        T: Any + Send + Sync,
+    {
+        let id = match self.by_name.get(name) {
+            Some(&id) => id,
+            None => {
+                let id = self.next_id;
+                self.next_id += 1;
+                self.by_name.insert(name.to_string(), id);
+                id
+            }
+        };
+        let entry = ResourceEntry { kind, name: name.to_string(), handle: Arc::new(handle) };
+        self.entries.insert(id, entry);
+        id
+    }
+
+    /// Unregister a resource
+    pub fn remove(&mut self, id: u64) -> Option<ResourceEntry> {
+        let entry = self.entries.remove(&id)?;
+        self.by_name.remove(&entry.name);
+        Some(entry)
+    }
+
+    /// Get a resource by id
+    pub fn get(&self, id: u64) -> Option<&ResourceEntry> {
+        self.entries.get(&id)
+    }
+
+    /// Get a resource's handle, downcast to the host type
+    pub fn handle<T: Any + Send + Sync>(&self, id: u64) -> Option<Arc<T>> {
+        self.get(id)?.handle.clone().downcast().ok()
+    }
+
+    /// Id of the resource registered under a name
+    pub fn lookup(&self, name: &str) -> Option<u64> {
+        self.by_name.get(name).copied()
+    }
+
+    /// Returns true if a resource with this id and kind is registered
+    pub fn contains(&self, kind: ResourceKind, id: u64) -> bool {
+        self.get(id).is_some_and(|entry| entry.kind == kind)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+// ========== Serialization ==========
+
+thread_local! {
+    static SERDE_TABLE: RefCell<Option<Arc<ResourceTable>>> = const { RefCell::new(None) };
+}
+
+/// Run `f` with `table` naming resource references during (de)serialization
+///
+/// Serialized references store their kind and the name `table` has for
+/// their id; deserialized ones are re-resolved by name. References that
+/// can't be named or resolved (including all of them outside this call)
+/// become [`NULL_RESOURCE_ID`]. Calls may nest; the innermost table wins.
+pub fn with_serde_resources<R>(table: &Arc<ResourceTable>, f: impl FnOnce() -> R) -> R {
+    struct Restore(Option<Arc<ResourceTable>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            SERDE_TABLE.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let previous = SERDE_TABLE.with(|current| current.borrow_mut().replace(Arc::clone(table)));
+    let _restore = Restore(previous);
+    f()
+}
+
+fn with_serde_table<R>(f: impl FnOnce(Option<&ResourceTable>) -> R) -> R {
+    SERDE_TABLE.with(|current| f(current.borrow().as_deref()))
+}
+
+/// Saved form of a resource reference
+#[derive(Serialize, Deserialize)]
+struct SavedResourceRef {
+    kind: ResourceKind,
+    name: Option<String>,
+}
+
+pub(crate) fn serialize_ref<S: Serializer>(
+    kind: &ResourceKind,
+    id: &u64,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    let name = with_serde_table(|table| {
+        table.and_then(|table| table.get(*id)).map(|entry| entry.name.clone())
+    });
+    SavedResourceRef { kind: *kind, name }.serialize(serializer)
+}
+
+pub(crate) fn deserialize_ref<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<(ResourceKind, u64), D::Error> {
+    let saved = SavedResourceRef::deserialize(deserializer)?;
+    let id = with_serde_table(|table| {
+        let (table, name) = (table?, saved.name.as_deref()?);
+        table.lookup(name).filter(|&id| table.contains(saved.kind, id))
+    });
+    Ok((saved.kind, id.unwrap_or(NULL_RESOURCE_ID)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Value;
+
+    struct Texture {
+        width: u32,
+    }
+
+    fn table() -> (ResourceTable, u64) {
+        let mut table = ResourceTable::new();
+        let id = table.insert(ResourceKind::Texture, "brick", Texture { width: 256 });
+        table.insert(ResourceKind::Mesh, "teapot", ());
+        (table, id)
+    }
+
+    #[test]
+    fn test_lookup_and_handle() {
+        let (mut table, id) = table();
+        assert_eq!(table.lookup("brick"), Some(id));
+        assert!(table.contains(ResourceKind::Texture, id));
+        assert!(!table.contains(ResourceKind::Mesh, id));
+        assert_eq!(table.handle::<Texture>(id).unwrap().width, 256);
+        assert!(table.handle::<String>(id).is_none());
+
+        // Replacing a name keeps the id
+        assert_eq!(table.insert(ResourceKind::Texture, "brick", Texture { width: 512 }), id);
+        assert_eq!(table.handle::<Texture>(id).unwrap().width, 512);
+
+        table.remove(id);
+        assert_eq!(table.lookup("brick"), None);
+        assert!(table.get(id).is_none());
+        assert_eq!(table.len(), 1);
+    }
+
+    #[test]
+    fn test_serialization_stores_name_and_re_resolves() {
+        let (table, id) = table();
+        let value = Value::ResourceRef { kind: ResourceKind::Texture, id };
+        let table = Arc::new(table);
+        let json = with_serde_resources(&table, || serde_json::to_string(&value)).unwrap();
+        assert!(json.contains("\"brick\""));
+        assert!(!json.contains(&format!("\"id\":{}", id)));
+
+        // A fresh table issues different ids for the same names
+        let mut reloaded = ResourceTable::new();
+        reloaded.insert(ResourceKind::Audio, "kick", ());
+        let new_id = reloaded.insert(ResourceKind::Texture, "brick", Texture { width: 64 });
+        assert_ne!(new_id, id);
+        let loaded: Value =
+            with_serde_resources(&Arc::new(reloaded), || serde_json::from_str(&json)).unwrap();
+        assert_eq!(loaded, Value::ResourceRef { kind: ResourceKind::Texture, id: new_id });
+    }
+
+    #[test]
+    fn test_unresolved_references_load_as_null() {
+        let (table, id) = table();
+        let value = Value::ResourceRef { kind: ResourceKind::Texture, id };
+        let table = Arc::new(table);
+        let json = with_serde_resources(&table, || serde_json::to_string(&value)).unwrap();
+
+        let null = Value::ResourceRef { kind: ResourceKind::Texture, id: NULL_RESOURCE_ID };
+        // No table installed
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), null);
+        // Name registered with a different kind
+        let mut other = ResourceTable::new();
+        other.insert(ResourceKind::Mesh, "brick", ());
+        let loaded: Value =
+            with_serde_resources(&Arc::new(other), || serde_json::from_str(&json)).unwrap();
+        assert_eq!(loaded, null);
+    }
+}
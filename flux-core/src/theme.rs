@@ -0,0 +1,265 @@
+//! Runtime color and icon theming for operators
+//!
+//! [`OperatorMeta::category_color`] and [`OperatorMeta::icon`] are fixed at
+//! compile time. A [`ThemeRegistry`] overrides them per category name and
+//! per operator name, so a host can restyle nodes without touching the
+//! operators. Lookups borrow the theme's maps and never allocate, so they
+//! are fine to call while drawing every frame.
+//!
+//! Category overrides apply to sub-categories too: a `Math` color covers
+//! `Math/Trig` unless the theme also has a `Math/Trig` entry.
+//!
+//! A process-wide theme is installed with [`set_global_theme`] and read with
+//! [`global_theme`]; it starts out empty, which resolves everything to the
+//! operators' own metadata.
+//!
+//! ```
+//! use flux_core::theme::{resolve_category_color, ThemeRegistry};
+//! use flux_core::OperatorMeta;
+//!
+//! struct Add;
+//! impl OperatorMeta for Add {
+//!     fn category(&self) -> &'static str { "Math" }
+//! }
+//!
+//! let theme = ThemeRegistry::new().with_category("Math", [1.0, 0.0, 0.0, 1.0]);
+//! assert_eq!(resolve_category_color(&Add, &theme), [1.0, 0.0, 0.0, 1.0]);
+//! ```
+
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::category::SEPARATOR;
+use crate::operator_meta::OperatorMeta;
+
+/// Color and icon overrides for one operator type
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct OperatorStyle {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<[f32; 4]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon: Option<String>,
+}
+
+/// Category and operator style overrides
+///
+/// Categories are matched against [`OperatorMeta::category`], innermost
+/// first, and operators against [`Operator::name`](crate::Operator::name).
+/// An operator override wins over its category's.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ThemeRegistry {
+    #[serde(default)]
+    pub name: String,
+    /// Colors by category name
+    #[serde(default)]
+    pub categories: HashMap<String, [f32; 4]>,
+    /// Overrides by operator name
+    #[serde(default)]
+    pub operators: HashMap<String, OperatorStyle>,
+}
+
+impl ThemeRegistry {
+    /// Create an empty theme that overrides nothing
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Override the color of every operator in a category
+    pub fn with_category(mut self, category: impl Into<String>, color: [f32; 4]) -> Self {
+        self.categories.insert(category.into(), color);
+        self
+    }
+
+    /// Override the color of one operator type
+    pub fn with_operator_color(mut self, operator: impl Into<String>, color: [f32; 4]) -> Self {
+        self.operators.entry(operator.into()).or_default().color = Some(color);
+        self
+    }
+
+    /// Override the icon of one operator type
+    pub fn with_operator_icon(
+        mut self,
+        operator: impl Into<String>,
+        icon: impl Into<String>,
+    ) -> Self {
+        self.operators.entry(operator.into()).or_default().icon = Some(icon.into());
+        self
+    }
+
+    /// Color the theme gives exactly this category, if any
+    pub fn category_color(&self, category: &str) -> Option<[f32; 4]> {
+        self.categories.get(category).copied()
+    }
+
+    /// Color the theme gives a category path or its nearest enclosing
+    /// category, e.g. `Math` for `Math/Trig`
+    ///
+    /// `path` is in the form [`Category`](crate::Category) displays as.
+    pub fn resolve_category(&self, path: &str) -> Option<[f32; 4]> {
+        let mut path = path;
+        loop {
+            if let Some(color) = self.category_color(path) {
+                return Some(color);
+            }
+            path = path.rsplit_once(SEPARATOR)?.0;
+        }
+    }
+
+    /// Color the theme gives an operator type, if any
+    pub fn operator_color(&self, operator: &str) -> Option<[f32; 4]> {
+        self.operators.get(operator).and_then(|style| style.color)
+    }
+
+    /// Icon the theme gives an operator type, if any
+    pub fn operator_icon(&self, operator: &str) -> Option<&str> {
+        self.operators.get(operator).and_then(|style| style.icon.as_deref())
+    }
+
+    /// Resolve a color: operator override, then category override (see
+    /// [`resolve_category`](Self::resolve_category)), then `fallback`
+    pub fn resolve_color(&self, operator: &str, category: &str, fallback: [f32; 4]) -> [f32; 4] {
+        self.operator_color(operator)
+            .or_else(|| self.resolve_category(category))
+            .unwrap_or(fallback)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.categories.is_empty() && self.operators.is_empty()
+    }
+}
+
+/// Category color after applying `theme`
+///
+/// Falls back to the operator's own [`OperatorMeta::category_color`] when
+/// the theme mentions neither its category nor an enclosing one.
+pub fn resolve_category_color(op: &dyn OperatorMeta, theme: &ThemeRegistry) -> [f32; 4] {
+    theme.resolve_category(op.category()).unwrap_or_else(|| op.category_color())
+}
+
+/// Titlebar color for the operator type `name` after applying `theme`
+pub fn resolve_operator_color(
+    name: &str,
+    op: &dyn OperatorMeta,
+    theme: &ThemeRegistry,
+) -> [f32; 4] {
+    theme.operator_color(name).unwrap_or_else(|| resolve_category_color(op, theme))
+}
+
+/// Icon for the operator type `name` after applying `theme`
+pub fn resolve_icon<'a>(
+    name: &str,
+    op: &dyn OperatorMeta,
+    theme: &'a ThemeRegistry,
+) -> Option<&'a str> {
+    theme.operator_icon(name).or(op.icon())
+}
+
+fn global_slot() -> &'static RwLock<Arc<ThemeRegistry>> {
+    static GLOBAL: OnceLock<RwLock<Arc<ThemeRegistry>>> = OnceLock::new();
+    GLOBAL.get_or_init(|| RwLock::new(Arc::new(ThemeRegistry::new())))
+}
+
+/// The process-wide theme
+///
+/// Cheap to call: it clones an `Arc`. Hold on to the result for a whole
+/// frame rather than calling it per node.
+pub fn global_theme() -> Arc<ThemeRegistry> {
+    Arc::clone(&global_slot().read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Replace the process-wide theme, returning the previous one
+pub fn set_global_theme(theme: ThemeRegistry) -> Arc<ThemeRegistry> {
+    let mut slot = global_slot().write().unwrap_or_else(|e| e.into_inner());
+    std::mem::replace(&mut *slot, Arc::new(theme))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::operator_meta::category_colors;
+
+    struct Meta(&'static str, [f32; 4], Option<&'static str>);
+
+    impl OperatorMeta for Meta {
+        fn category(&self) -> &'static str { self.0 }
+        fn category_color(&self) -> [f32; 4] { self.1 }
+        fn icon(&self) -> Option<&'static str> { self.2 }
+    }
+
+    const RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+    const GREEN: [f32; 4] = [0.0, 1.0, 0.0, 1.0];
+
+    fn theme() -> ThemeRegistry {
+        let json = r#"{
+            "name": "Test",
+            "categories": { "Math": [1.0, 0.0, 0.0, 1.0] },
+            "operators": { "Multiply": { "color": [0.0, 1.0, 0.0, 1.0], "icon": "x" } }
+        }"#;
+        serde_json::from_str(json).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_with_overrides_and_fallback() {
+        let theme = theme();
+        let math = Meta("Math", category_colors::MATH, None);
+        let time = Meta("Time", category_colors::TIME, Some("clock"));
+
+        assert_eq!(resolve_category_color(&math, &theme), RED);
+        assert_eq!(resolve_operator_color("Add", &math, &theme), RED);
+        assert_eq!(resolve_operator_color("Multiply", &math, &theme), GREEN);
+        // The category color ignores operator overrides
+        assert_eq!(resolve_category_color(&math, &theme), RED);
+
+        // Categories the theme doesn't mention keep their own color and icon
+        assert_eq!(resolve_category_color(&time, &theme), category_colors::TIME);
+        assert_eq!(resolve_operator_color("Time", &time, &theme), category_colors::TIME);
+        assert_eq!(resolve_icon("Time", &time, &theme), Some("clock"));
+        assert_eq!(resolve_icon("Multiply", &math, &theme), Some("x"));
+        assert_eq!(resolve_icon("Add", &math, &theme), None);
+    }
+
+    #[test]
+    fn test_category_overrides_cover_sub_categories() {
+        let theme = theme().with_category("Math/Random", GREEN);
+        let trig = Meta("Math/Trig", category_colors::MATH, None);
+        let noise = Meta("Math/Random/Noise", category_colors::MATH, None);
+        let list = Meta("List/Sort", category_colors::LIST, None);
+
+        assert_eq!(resolve_category_color(&trig, &theme), RED);
+        assert_eq!(resolve_category_color(&noise, &theme), GREEN);
+        assert_eq!(resolve_category_color(&list, &theme), category_colors::LIST);
+        assert_eq!(theme.resolve_color("Sin", "Math/Trig", GREEN), RED);
+        assert_eq!(theme.category_color("Math/Trig"), None);
+        assert_eq!(theme.resolve_category("Mathematics"), None);
+    }
+
+    #[test]
+    fn test_builders_match_json_and_roundtrip() {
+        let built = ThemeRegistry::new()
+            .with_name("Test")
+            .with_category("Math", RED)
+            .with_operator_color("Multiply", GREEN)
+            .with_operator_icon("Multiply", "x");
+        assert_eq!(built, theme());
+
+        let json = serde_json::to_string(&built).unwrap();
+        assert_eq!(serde_json::from_str::<ThemeRegistry>(&json).unwrap(), built);
+        assert!(ThemeRegistry::new().is_empty());
+    }
+
+    #[test]
+    fn test_global_theme_switches_at_runtime() {
+        let previous = set_global_theme(theme());
+        assert_eq!(global_theme().category_color("Math"), Some(RED));
+        set_global_theme(ThemeRegistry::new());
+        assert_eq!(global_theme().category_color("Math"), None);
+        set_global_theme((*previous).clone());
+    }
+}
@@ -0,0 +1,163 @@
+//! Typed units for Float ports
+//!
+//! [`PortMeta::unit`](crate::PortMeta::unit) is a free-form display suffix.
+//! A [`Unit`] adds meaning: ports that declare typed units can be checked for
+//! compatibility and automatically scaled when connected (for example a
+//! Degrees output feeding a Radians input).
+//!
+//! Units belong to a [`UnitDimension`]. Units of the same dimension convert
+//! linearly through a base unit; units of different dimensions are incompatible.
+//!
+//! # Example
+//!
+//! ```
+//! use flux_core::Unit;
+//!
+//! let rad = Unit::Degrees.convert(180.0, Unit::Radians).unwrap();
+//! assert!((rad - std::f32::consts::PI).abs() < 1e-6);
+//!
+//! assert_eq!(Unit::Bpm.convert(120.0, Unit::Hertz), Some(2.0));
+//! assert_eq!(Unit::Seconds.convert(1.0, Unit::Degrees), None);
+//! ```
+
+use serde::{Deserialize, Serialize};
+
+/// Physical dimension of a [`Unit`]. Only units of the same dimension convert.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitDimension {
+    /// Durations (base: seconds)
+    Time,
+    /// Rates (base: hertz)
+    Frequency,
+    /// Angles (base: radians)
+    Angle,
+    /// Proportions (base: normalized 0-1)
+    Ratio,
+    /// Screen distances (base: pixels)
+    Length,
+}
+
+/// A typed unit for Float ports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Unit {
+    /// Seconds (s)
+    Seconds,
+    /// Milliseconds (ms)
+    Milliseconds,
+    /// Cycles per second (Hz)
+    Hertz,
+    /// Beats per minute
+    Bpm,
+    /// Angle in degrees
+    Degrees,
+    /// Angle in radians
+    Radians,
+    /// Proportion in 0-100
+    Percent,
+    /// Proportion in 0-1
+    Normalized,
+    /// Screen pixels
+    Pixels,
+}
+
+impl Unit {
+    /// Display suffix for this unit (e.g., "s", "Hz", "rad").
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            Self::Seconds => "s",
+            Self::Milliseconds => "ms",
+            Self::Hertz => "Hz",
+            Self::Bpm => "BPM",
+            Self::Degrees => "deg",
+            Self::Radians => "rad",
+            Self::Percent => "%",
+            Self::Normalized => "0-1",
+            Self::Pixels => "px",
+        }
+    }
+
+    /// The dimension this unit measures.
+    pub const fn dimension(self) -> UnitDimension {
+        match self {
+            Self::Seconds | Self::Milliseconds => UnitDimension::Time,
+            Self::Hertz | Self::Bpm => UnitDimension::Frequency,
+            Self::Degrees | Self::Radians => UnitDimension::Angle,
+            Self::Percent | Self::Normalized => UnitDimension::Ratio,
+            Self::Pixels => UnitDimension::Length,
+        }
+    }
+
+    /// Multiplier converting a value in this unit to the dimension's base unit.
+    pub const fn base_factor(self) -> f64 {
+        match self {
+            Self::Seconds => 1.0,
+            Self::Milliseconds => 0.001,
+            Self::Hertz => 1.0,
+            Self::Bpm => 1.0 / 60.0,
+            Self::Degrees => std::f64::consts::PI / 180.0,
+            Self::Radians => 1.0,
+            Self::Percent => 0.01,
+            Self::Normalized => 1.0,
+            Self::Pixels => 1.0,
+        }
+    }
+
+    /// Returns true if values in this unit can be converted to `other`.
+    pub fn is_compatible_with(self, other: Unit) -> bool {
+        self.dimension() == other.dimension()
+    }
+
+    /// Scale factor converting values from this unit to `target`.
+    ///
+    /// Returns `None` if the units are incompatible.
+    pub fn factor_to(self, target: Unit) -> Option<f32> {
+        if !self.is_compatible_with(target) {
+            return None;
+        }
+        Some((self.base_factor() / target.base_factor()) as f32)
+    }
+
+    /// Convert a value from this unit to `target`.
+    ///
+    /// Returns `None` if the units are incompatible.
+    pub fn convert(self, value: f32, target: Unit) -> Option<f32> {
+        if !self.is_compatible_with(target) {
+            return None;
+        }
+        // Scale in f64 so exact ratios (e.g., 1500 ms -> 1.5 s) stay exact
+        Some((f64::from(value) * self.base_factor() / target.base_factor()) as f32)
+    }
+}
+
+/// Formats as the unit's [`symbol`](Unit::symbol).
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compatible_conversions() {
+        assert_eq!(Unit::Milliseconds.convert(1500.0, Unit::Seconds), Some(1.5));
+        assert_eq!(Unit::Hertz.convert(2.0, Unit::Bpm), Some(120.0));
+        assert_eq!(Unit::Percent.convert(50.0, Unit::Normalized), Some(0.5));
+        let deg = Unit::Radians.convert(std::f32::consts::FRAC_PI_2, Unit::Degrees).unwrap();
+        assert!((deg - 90.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_identity_conversion() {
+        assert_eq!(Unit::Pixels.factor_to(Unit::Pixels), Some(1.0));
+    }
+
+    #[test]
+    fn test_incompatible_units() {
+        assert!(!Unit::Seconds.is_compatible_with(Unit::Degrees));
+        assert_eq!(Unit::Hertz.convert(1.0, Unit::Seconds), None);
+        assert_eq!(Unit::Pixels.factor_to(Unit::Normalized), None);
+    }
+}
@@ -1,4 +1,4 @@
-//! Color type with HSV conversion and interpolation
+//! Color type with HSV conversion, interpolation and accessibility checks
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -141,6 +141,80 @@ impl Color {
     pub fn luminance(&self) -> f32 {
         0.2126 * self.r + 0.7152 * self.g + 0.0722 * self.b
     }
+
+    /// Approximate the color of a blackbody radiator at `kelvin`.
+    ///
+    /// Uses Tanner Helland's curve fit of the CIE 1964 blackbody data, which
+    /// is accurate to a few percent between 1000 K and 40000 K (inputs are
+    /// clamped to that range). Channels are clamped to 0.0-1.0, so the
+    /// brightest channel is always 1.0; 6500 K is close to white.
+    pub fn from_kelvin(kelvin: f32) -> Self {
+        let temp = f64::from(kelvin.clamp(1000.0, 40000.0)) / 100.0;
+
+        let r = if temp <= 66.0 {
+            255.0
+        } else {
+            329.698_727_446 * (temp - 60.0).powf(-0.133_204_759_2)
+        };
+        let g = if temp <= 66.0 {
+            99.470_802_586_1 * temp.ln() - 161.119_568_166_1
+        } else {
+            288.122_169_528_3 * (temp - 60.0).powf(-0.075_514_849_2)
+        };
+        let b = if temp >= 66.0 {
+            255.0
+        } else if temp <= 19.0 {
+            0.0
+        } else {
+            138.517_731_223_1 * (temp - 10.0).ln() - 305.044_792_730_7
+        };
+
+        let channel = |v: f64| (v / 255.0).clamp(0.0, 1.0) as f32;
+        Self::rgb(channel(r), channel(g), channel(b))
+    }
+
+    /// Convert to OkLab `[L, a, b]`, treating components as linear RGB.
+    ///
+    /// Alpha is not part of the result.
+    pub fn to_oklab(&self) -> [f32; 3] {
+        let l = 0.412_221_46 * self.r + 0.536_332_55 * self.g + 0.051_445_995 * self.b;
+        let m = 0.211_903_5 * self.r + 0.680_699_5 * self.g + 0.107_396_96 * self.b;
+        let s = 0.088_302_46 * self.r + 0.281_718_85 * self.g + 0.629_978_7 * self.b;
+
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+        [
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        ]
+    }
+
+    /// Create from OkLab `[L, a, b]` (producing linear RGB) with the given alpha
+    pub fn from_oklab(lab: [f32; 3], alpha: f32) -> Self {
+        let [l, a, b] = lab;
+        let l_ = l + 0.396_337_78 * a + 0.215_803_76 * b;
+        let m_ = l - 0.105_561_346 * a - 0.063_854_17 * b;
+        let s_ = l - 0.089_484_18 * a - 1.291_485_5 * b;
+
+        let (l, m, s) = (l_ * l_ * l_, m_ * m_ * m_, s_ * s_ * s_);
+        Self {
+            r: 4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            g: -1.268_438 * l + 2.609_757_4 * m - 0.341_319_38 * s,
+            b: -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+            a: alpha,
+        }
+    }
+
+    /// Interpolation between two colors in OkLab space
+    pub fn lerp_oklab(a: &Color, b: &Color, t: f32) -> Self {
+        let (lab_a, lab_b) = (a.to_oklab(), b.to_oklab());
+        let lab = [
+            lab_a[0] + (lab_b[0] - lab_a[0]) * t,
+            lab_a[1] + (lab_b[1] - lab_a[1]) * t,
+            lab_a[2] + (lab_b[2] - lab_a[2]) * t,
+        ];
+        Self::from_oklab(lab, a.a + (b.a - a.a) * t)
+    }
 }
 
 impl Default for Color {
@@ -149,6 +223,208 @@ impl Default for Color {
     }
 }
 
+impl Color {
+    /// Hex notation: `#RRGGBB`, or `#RRGGBBAA` when not fully opaque.
+    ///
+    /// Components are clamped to 0.0-1.0 and rounded to 8 bits.
+    pub fn to_hex(&self) -> String {
+        let byte = |v: f32| (v.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let rgb = format!("#{:02X}{:02X}{:02X}", byte(self.r), byte(self.g), byte(self.b));
+        if byte(self.a) == 255 {
+            rgb
+        } else {
+            format!("{}{:02X}", rgb, byte(self.a))
+        }
+    }
+}
+
+/// Color vision deficiency simulated by [`Color::simulate_cvd`]
+///
+/// Selected through an Int "Type" input: 0 = Protanopia, 1 = Deuteranopia,
+/// 2 = Tritanopia, 3 = Achromatopsia.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorVisionDeficiency {
+    /// Missing or shifted long-wavelength (red) cones
+    #[default]
+    Protanopia,
+    /// Missing or shifted medium-wavelength (green) cones
+    Deuteranopia,
+    /// Missing or shifted short-wavelength (blue) cones
+    Tritanopia,
+    /// No color perception; only luminance remains
+    Achromatopsia,
+}
+
+impl ColorVisionDeficiency {
+    /// Deficiency for a Type input value (unknown values are protanopia)
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => ColorVisionDeficiency::Deuteranopia,
+            2 => ColorVisionDeficiency::Tritanopia,
+            3 => ColorVisionDeficiency::Achromatopsia,
+            _ => ColorVisionDeficiency::Protanopia,
+        }
+    }
+
+    /// Type input value for this deficiency
+    pub fn index(self) -> i32 {
+        self as i32
+    }
+
+    /// Linear RGB matrix for full severity
+    ///
+    /// The dichromacies use the severity 1.0 matrices of Machado, Oliveira
+    /// and Fernandes (2009); achromatopsia maps every channel to the Rec. 709
+    /// luminance.
+    fn matrix(self) -> [[f32; 3]; 3] {
+        match self {
+            ColorVisionDeficiency::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            ColorVisionDeficiency::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            ColorVisionDeficiency::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+            ColorVisionDeficiency::Achromatopsia => [[0.2126, 0.7152, 0.0722]; 3],
+        }
+    }
+}
+
+/// Accessibility checks
+///
+/// These treat components as sRGB-encoded, as colors written in hex or
+/// picked on screen are, and ignore alpha.
+impl Color {
+    /// Minimum WCAG contrast ratio for normal text at level AA
+    pub const CONTRAST_AA: f32 = 4.5;
+    /// Minimum WCAG contrast ratio for normal text at level AAA
+    pub const CONTRAST_AAA: f32 = 7.0;
+
+    /// Decode sRGB-encoded components to linear light
+    ///
+    /// Components are clamped to 0.0-1.0 first; alpha is unchanged.
+    pub fn to_linear(&self) -> Self {
+        let decode = |c: f32| {
+            let c = c.clamp(0.0, 1.0);
+            if c <= 0.040_45 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        Self::rgba(decode(self.r), decode(self.g), decode(self.b), self.a)
+    }
+
+    /// Encode linear light components as sRGB
+    ///
+    /// Components are clamped to 0.0-1.0 first; alpha is unchanged.
+    pub fn from_linear(linear: &Color) -> Self {
+        let encode = |c: f32| {
+            let c = c.clamp(0.0, 1.0);
+            if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055 * c.powf(1.0 / 2.4) - 0.055
+            }
+        };
+        Self::rgba(encode(linear.r), encode(linear.g), encode(linear.b), linear.a)
+    }
+
+    /// WCAG 2 relative luminance, from 0.0 (black) to 1.0 (white)
+    ///
+    /// Uses the sRGB decoding threshold 0.04045; WCAG's 0.03928 gives the
+    /// same result for every 8-bit color.
+    pub fn relative_luminance(&self) -> f32 {
+        self.to_linear().luminance()
+    }
+
+    /// WCAG 2 contrast ratio against `other`, from 1.0 to 21.0
+    ///
+    /// The ratio is symmetric: the lighter color's luminance is always the
+    /// numerator.
+    pub fn contrast_ratio(&self, other: &Color) -> f32 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Approximate how this color looks with a color vision deficiency
+    ///
+    /// `severity` blends from the original color (0.0) to the full
+    /// deficiency (1.0); the matrices are applied in linear light.
+    pub fn simulate_cvd(&self, deficiency: ColorVisionDeficiency, severity: f32) -> Self {
+        let t = severity.clamp(0.0, 1.0);
+        let linear = self.to_linear();
+        let rgb = [linear.r, linear.g, linear.b];
+        let row = |i: usize| {
+            let m = deficiency.matrix()[i];
+            let simulated = m[0] * rgb[0] + m[1] * rgb[1] + m[2] * rgb[2];
+            rgb[i] + (simulated - rgb[i]) * t
+        };
+        Self::from_linear(&Self::rgba(row(0), row(1), row(2), self.a))
+    }
+
+    /// Lighten or darken this color until its contrast against `background`
+    /// reaches `min_ratio`
+    ///
+    /// The color moves towards white or black in OkLab, raising or lowering
+    /// its lightness while fading its chroma, by as little as needed. The
+    /// direction that needs the smaller change wins. When neither white nor
+    /// black reaches the ratio, the one with more contrast is returned.
+    /// Alpha is kept.
+    pub fn ensure_contrast(&self, background: &Color, min_ratio: f32) -> Self {
+        let min_ratio = min_ratio.clamp(1.0, 21.0);
+        if self.contrast_ratio(background) >= min_ratio {
+            return *self;
+        }
+        let lab = self.to_linear().to_oklab();
+        let towards = |target: f32, t: f32| {
+            let moved = [
+                lab[0] + (target - lab[0]) * t,
+                lab[1] * (1.0 - t),
+                lab[2] * (1.0 - t),
+            ];
+            Self::from_linear(&Self::from_oklab(moved, self.a))
+        };
+
+        // Smallest step towards each extreme that reaches the ratio
+        let mut best: Option<(f32, Color)> = None;
+        for target in [0.0, 1.0] {
+            if towards(target, 1.0).contrast_ratio(background) < min_ratio {
+                continue;
+            }
+            let (mut lo, mut hi) = (0.0, 1.0);
+            for _ in 0..24 {
+                let mid = (lo + hi) / 2.0;
+                if towards(target, mid).contrast_ratio(background) >= min_ratio {
+                    hi = mid;
+                } else {
+                    lo = mid;
+                }
+            }
+            if best.is_none_or(|(t, _)| hi < t) {
+                best = Some((hi, towards(target, hi)));
+            }
+        }
+
+        best.map(|(_, color)| color).unwrap_or_else(|| {
+            let (black, white) = (towards(0.0, 1.0), towards(1.0, 1.0));
+            if black.contrast_ratio(background) >= white.contrast_ratio(background) {
+                black
+            } else {
+                white
+            }
+        })
+    }
+}
+
 impl fmt::Display for Color {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -193,4 +469,119 @@ mod tests {
         assert!((mid.g - 0.5).abs() < 0.01);
         assert!((mid.b - 0.5).abs() < 0.01);
     }
+
+    #[test]
+    fn test_oklab_roundtrip() {
+        for c in [Color::RED, Color::GREEN, Color::BLUE, Color::rgb(0.2, 0.6, 0.9)] {
+            let back = Color::from_oklab(c.to_oklab(), c.a);
+            assert!((back.r - c.r).abs() < 1e-4);
+            assert!((back.g - c.g).abs() < 1e-4);
+            assert!((back.b - c.b).abs() < 1e-4);
+        }
+        // White has lightness 1 and no chroma
+        let [l, a, b] = Color::WHITE.to_oklab();
+        assert!((l - 1.0).abs() < 1e-3 && a.abs() < 1e-3 && b.abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_to_hex() {
+        assert_eq!(Color::RED.to_hex(), "#FF0000");
+        assert_eq!(Color::rgba(0.0, 0.5, 1.0, 0.5).to_hex(), "#0080FF80");
+        assert_eq!(Color::rgb(2.0, -1.0, 0.2).to_hex(), "#FF0033");
+    }
+
+    #[test]
+    fn test_from_kelvin() {
+        let daylight = Color::from_kelvin(6500.0);
+        assert!(daylight.r > 0.95 && daylight.g > 0.95 && daylight.b > 0.95);
+
+        let candle = Color::from_kelvin(2000.0);
+        assert_eq!(candle.r, 1.0);
+        assert!(candle.g < 0.6 && candle.b < 0.1);
+
+        let sky = Color::from_kelvin(12000.0);
+        assert_eq!(sky.b, 1.0);
+        assert!(sky.r < sky.g && sky.g < sky.b);
+    }
+
+    fn gray(byte: u8) -> Color {
+        let v = byte as f32 / 255.0;
+        Color::rgb(v, v, v)
+    }
+
+    fn assert_close(color: Color, expected: [f32; 3]) {
+        let actual = [color.r, color.g, color.b];
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-3, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((Color::BLACK.contrast_ratio(&Color::WHITE) - 21.0).abs() < 1e-4);
+        assert_eq!(Color::RED.contrast_ratio(&Color::RED), 1.0);
+        assert!((gray(0x80).relative_luminance() - 0.2159).abs() < 1e-4);
+
+        // #777777 on white is the classic near miss for AA; #767676 passes
+        let near_miss = gray(0x77).contrast_ratio(&Color::WHITE);
+        assert!((near_miss - 4.48).abs() < 0.01 && near_miss < Color::CONTRAST_AA);
+        let passing = Color::WHITE.contrast_ratio(&gray(0x76));
+        assert!((passing - 4.54).abs() < 0.01 && passing >= Color::CONTRAST_AA);
+        assert!((Color::RED.contrast_ratio(&Color::BLACK) - 5.25).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_linear_roundtrip() {
+        let c = Color::rgba(0.2, 0.5, 0.9, 0.5);
+        let back = Color::from_linear(&c.to_linear());
+        assert_close(back, [0.2, 0.5, 0.9]);
+        assert_eq!(back.a, 0.5);
+        assert!((gray(0x80).to_linear().r - 0.2159).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_simulate_cvd() {
+        use ColorVisionDeficiency::*;
+        // Red through each dichromacy matrix (first column, encoded as sRGB)
+        assert_close(Color::RED.simulate_cvd(Protanopia, 1.0), [0.4266, 0.3727, 0.0]);
+        assert_close(Color::RED.simulate_cvd(Deuteranopia, 1.0), [0.6401, 0.5658, 0.0]);
+        assert_close(Color::BLUE.simulate_cvd(Tritanopia, 1.0), [0.0, 0.4204, 0.5873]);
+
+        // Neutral colors are unaffected, and severity 0 changes nothing
+        for deficiency in [Protanopia, Deuteranopia, Tritanopia, Achromatopsia] {
+            assert_close(gray(0x80).simulate_cvd(deficiency, 1.0), [0.502; 3]);
+            assert_close(Color::RED.simulate_cvd(deficiency, 0.0), [1.0, 0.0, 0.0]);
+            assert_eq!(ColorVisionDeficiency::from_index(deficiency.index()), deficiency);
+        }
+
+        // Achromatopsia keeps the relative luminance
+        let green = Color::rgba(0.1, 0.8, 0.3, 0.25);
+        let mono = green.simulate_cvd(Achromatopsia, 1.0);
+        assert!((mono.r - mono.g).abs() < 1e-6 && (mono.g - mono.b).abs() < 1e-6);
+        assert!((mono.relative_luminance() - green.relative_luminance()).abs() < 1e-4);
+        assert_eq!(mono.a, 0.25);
+    }
+
+    #[test]
+    fn test_ensure_contrast() {
+        // Already enough: unchanged
+        let fg = Color::rgb(0.1, 0.2, 0.6);
+        assert_eq!(fg.ensure_contrast(&Color::WHITE, 4.5), fg);
+
+        // Light orange on white darkens just enough
+        let orange = Color::rgb(1.0, 0.7, 0.3);
+        let fixed = orange.ensure_contrast(&Color::WHITE, 4.5);
+        let ratio = fixed.contrast_ratio(&Color::WHITE);
+        assert!((4.5..4.6).contains(&ratio), "ratio {}", ratio);
+        assert!(fixed.relative_luminance() < orange.relative_luminance());
+        assert!(fixed.r > fixed.b, "hue faded too early: {:?}", fixed);
+
+        // The same color on black lightens instead
+        let dark = Color::rgb(0.3, 0.1, 0.1).ensure_contrast(&Color::BLACK, 7.0);
+        assert!(dark.contrast_ratio(&Color::BLACK) >= 7.0);
+
+        // Unreachable on mid gray: clamps to the better extreme
+        let clamped = Color::WHITE.ensure_contrast(&gray(0x77), 21.0);
+        assert_close(clamped, [0.0; 3]);
+    }
 }
@@ -0,0 +1,429 @@
+//! Configurable formatting of values for UI display
+//!
+//! [`Display`](std::fmt::Display) for [`Value`] stays terse and stable;
+//! [`Value::format`] renders contents for inspectors, and [`Value::summary`]
+//! gives a one-line description for tooltips.
+
+use super::{Color, Matrix4, Value};
+
+/// How [`Value::format`] lays out a [`Matrix4`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MatrixLayout {
+    /// `[[a, b, c, d], [e, f, g, h], ...]`
+    #[default]
+    SingleLine,
+    /// One row per line
+    Rows,
+}
+
+/// How [`Value::format`] writes colors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorFormat {
+    /// `rgba(1.000, 0.500, 0.000, 1.000)`
+    #[default]
+    Rgba,
+    /// `#FF8000`, see [`Color::to_hex`]
+    Hex,
+}
+
+/// Options for [`Value::format`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct FormatOptions {
+    /// Digits after the decimal point for floats
+    pub precision: usize,
+    /// List elements (and map entries) shown before "… (+N more)"
+    pub max_list_items: usize,
+    /// Prefix the value with its type, e.g. `Vec2: [1.000, 2.000]`
+    pub type_prefix: bool,
+    /// Magnitudes at or above this are written in scientific notation
+    pub scientific_above: f32,
+    /// Non-zero magnitudes below this are written in scientific notation
+    pub scientific_below: f32,
+    /// Matrix layout
+    pub matrix_layout: MatrixLayout,
+    /// Color notation
+    pub color_format: ColorFormat,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            precision: 3,
+            max_list_items: 8,
+            type_prefix: false,
+            scientific_above: 1e6,
+            scientific_below: 1e-4,
+            matrix_layout: MatrixLayout::SingleLine,
+            color_format: ColorFormat::Rgba,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Builder: set the float precision
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Builder: set how many list elements are shown
+    pub fn with_max_list_items(mut self, max: usize) -> Self {
+        self.max_list_items = max;
+        self
+    }
+
+    /// Builder: prefix values with their type
+    pub fn with_type_prefix(mut self, type_prefix: bool) -> Self {
+        self.type_prefix = type_prefix;
+        self
+    }
+
+    /// Builder: set the range outside which floats use scientific notation
+    pub fn with_scientific_thresholds(mut self, below: f32, above: f32) -> Self {
+        self.scientific_below = below;
+        self.scientific_above = above;
+        self
+    }
+
+    /// Builder: set the matrix layout
+    pub fn with_matrix_layout(mut self, layout: MatrixLayout) -> Self {
+        self.matrix_layout = layout;
+        self
+    }
+
+    /// Builder: set the color notation
+    pub fn with_color_format(mut self, format: ColorFormat) -> Self {
+        self.color_format = format;
+        self
+    }
+
+    fn float(&self, v: f32) -> String {
+        if v.is_nan() {
+            return "NaN".to_string();
+        }
+        if v.is_infinite() {
+            return if v > 0.0 { "inf" } else { "-inf" }.to_string();
+        }
+        let magnitude = v.abs();
+        if magnitude >= self.scientific_above
+            || (magnitude > 0.0 && magnitude < self.scientific_below)
+        {
+            format!("{:.*e}", self.precision, v)
+        } else {
+            format!("{:.*}", self.precision, v)
+        }
+    }
+
+    fn floats(&self, v: &[f32]) -> String {
+        let parts: Vec<String> = v.iter().map(|&x| self.float(x)).collect();
+        format!("[{}]", parts.join(", "))
+    }
+
+    fn color(&self, c: &Color) -> String {
+        match self.color_format {
+            ColorFormat::Rgba => {
+                let parts: Vec<String> = c.to_array().iter().map(|&x| self.float(x)).collect();
+                format!("rgba({})", parts.join(", "))
+            }
+            ColorFormat::Hex => c.to_hex(),
+        }
+    }
+
+    fn matrix(&self, m: &Matrix4) -> String {
+        // Stored by column; shown by row so the translation is the last column
+        let rows: Vec<String> = (0..4)
+            .map(|row| self.floats(&[m.0[0][row], m.0[1][row], m.0[2][row], m.0[3][row]]))
+            .collect();
+        match self.matrix_layout {
+            MatrixLayout::SingleLine => format!("[{}]", rows.join(", ")),
+            MatrixLayout::Rows => rows.join("\n"),
+        }
+    }
+
+    /// Up to `max_list_items` formatted elements, then the number left out
+    fn list<T>(&self, items: &[T], item: impl Fn(&T) -> String) -> String {
+        let shown: Vec<String> = items.iter().take(self.max_list_items).map(item).collect();
+        let hidden = items.len() - shown.len();
+        match (shown.is_empty(), hidden) {
+            (_, 0) => format!("[{}]", shown.join(", ")),
+            (true, _) => format!("[… (+{} more)]", hidden),
+            (false, _) => format!("[{}, … (+{} more)]", shown.join(", "), hidden),
+        }
+    }
+}
+
+impl Value {
+    /// Format the value for display according to `opts`.
+    ///
+    /// Unlike [`Display`](std::fmt::Display), lists, gradients and matrices
+    /// show their contents. Matrices are written row by row, so the
+    /// translation is the last column.
+    pub fn format(&self, opts: &FormatOptions) -> String {
+        let body = match self {
+            Value::Float(v) => opts.float(*v),
+            Value::Int(v) => v.to_string(),
+            Value::Bool(v) => v.to_string(),
+            Value::Vec2(v) => opts.floats(v),
+            Value::Vec3(v) => opts.floats(v),
+            Value::Vec4(v) => opts.floats(v),
+            Value::String(v) => format!("{:?}", v),
+            Value::Color(c) => opts.color(c),
+            Value::Gradient(g) => opts.list(&g.stops, |stop| {
+                format!("{}: {}", opts.float(stop.position), opts.color(&stop.color))
+            }),
+            Value::Matrix4(m) => opts.matrix(m),
+            Value::FloatList(v) => opts.list(v, |&x| opts.float(x)),
+            Value::IntList(v) => opts.list(v, i32::to_string),
+            Value::BoolList(v) => opts.list(v, bool::to_string),
+            Value::Vec2List(v) => opts.list(v, |x| opts.floats(x)),
+            Value::Vec3List(v) => opts.list(v, |x| opts.floats(x)),
+            Value::Vec4List(v) => opts.list(v, |x| opts.floats(x)),
+            Value::ColorList(v) => opts.list(v, |c| opts.color(c)),
+            Value::StringList(v) => opts.list(v, |s| format!("{:?}", s)),
+            Value::Map(m) => {
+                let nested = FormatOptions { type_prefix: false, ..opts.clone() };
+                let entries: Vec<_> = m.iter().collect();
+                let list = opts.list(&entries, |(key, value)| {
+                    format!("{}: {}", key, value.format(&nested))
+                });
+                format!("{{{}}}", &list[1..list.len() - 1])
+            }
+            Value::ResourceRef { .. } => self.to_string(),
+        };
+        if !opts.type_prefix {
+            return body;
+        }
+        match self.list_len() {
+            Some(len) => format!("{}[{}]: {}", self.value_type(), len, body),
+            None => format!("{}: {}", self.value_type(), body),
+        }
+    }
+
+    /// One-line description for tooltips.
+    ///
+    /// Scalars, vectors and colors show their value (colors in hex), lists
+    /// their length plus the range of numeric elements, gradients their
+    /// stop count and end colors, and matrices their translation.
+    pub fn summary(&self) -> String {
+        let opts = FormatOptions::default();
+        let kind = self.value_type();
+        match self {
+            Value::Float(_)
+            | Value::Int(_)
+            | Value::Bool(_)
+            | Value::Vec2(_)
+            | Value::Vec3(_)
+            | Value::Vec4(_)
+            | Value::ResourceRef { .. } => format!("{}: {}", kind, self.format(&opts)),
+            Value::String(s) => {
+                const MAX_CHARS: usize = 32;
+                let count = s.chars().count();
+                if count > MAX_CHARS {
+                    let head: String = s.chars().take(MAX_CHARS).collect();
+                    format!("{}[{}]: {:?}…", kind, count, head)
+                } else {
+                    format!("{}[{}]: {:?}", kind, count, s)
+                }
+            }
+            Value::Color(c) => format!("{}: {}", kind, c.to_hex()),
+            Value::Gradient(g) => match (g.stops.first(), g.stops.last()) {
+                (Some(first), Some(last)) => format!(
+                    "{}: {} stops, {} → {}",
+                    kind,
+                    g.stops.len(),
+                    first.color.to_hex(),
+                    last.color.to_hex()
+                ),
+                _ => format!("{}: 0 stops", kind),
+            },
+            Value::Matrix4(m) => {
+                format!("{}: translation {}", kind, opts.floats(&m.get_translation()))
+            }
+            Value::FloatList(v) => list_summary(self, &opts, v.iter().map(|&x| [x])),
+            Value::IntList(v) => {
+                match (v.iter().min(), v.iter().max()) {
+                    (Some(min), Some(max)) => {
+                        format!("{}[{}]: min {}, max {}", kind, v.len(), min, max)
+                    }
+                    _ => format!("{}[0]", kind),
+                }
+            }
+            Value::Vec2List(v) => list_summary(self, &opts, v.iter().copied()),
+            Value::Vec3List(v) => list_summary(self, &opts, v.iter().copied()),
+            Value::Vec4List(v) => list_summary(self, &opts, v.iter().copied()),
+            Value::BoolList(v) => {
+                let set = v.iter().filter(|&&b| b).count();
+                format!("{}[{}]: {} true", kind, v.len(), set)
+            }
+            Value::ColorList(v) => format!("{}[{}]", kind, v.len()),
+            Value::StringList(v) => format!("{}[{}]", kind, v.len()),
+            Value::Map(m) => format!("{}: {} entries", kind, m.len()),
+        }
+    }
+}
+
+/// `Kind[len]: min .., max ..` for lists of float tuples (component-wise
+/// for vectors; NaN elements are ignored)
+fn list_summary<const N: usize>(
+    value: &Value,
+    opts: &FormatOptions,
+    items: impl Iterator<Item = [f32; N]>,
+) -> String {
+    let len = value.list_len().unwrap_or(0);
+    let range = items.fold(None, |range: Option<([f32; N], [f32; N])>, item| {
+        let (mut min, mut max) = range.unwrap_or((item, item));
+        for i in 0..N {
+            min[i] = min[i].min(item[i]);
+            max[i] = max[i].max(item[i]);
+        }
+        Some((min, max))
+    });
+    let Some((min, max)) = range else {
+        return format!("{}[0]", value.value_type());
+    };
+    let show = |v: [f32; N]| match N {
+        1 => opts.float(v[0]),
+        _ => opts.floats(&v),
+    };
+    format!("{}[{}]: min {}, max {}", value.value_type(), len, show(min), show(max))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::resource::ResourceKind;
+    use crate::value::Gradient;
+
+    fn matrix() -> Value {
+        Value::Matrix4(Matrix4::translation(1.0, 2.0, 3.0))
+    }
+
+    #[test]
+    fn test_format_golden() {
+        let opts = FormatOptions::default();
+        let mut map = BTreeMap::new();
+        map.insert("a".to_string(), Value::Int(1));
+        map.insert("b".to_string(), Value::Vec2([0.5, 1.0]));
+        let cases = [
+            (Value::Float(1.5), "1.500"),
+            (Value::Float(-0.25), "-0.250"),
+            (Value::Float(f32::NAN), "NaN"),
+            (Value::Float(f32::NEG_INFINITY), "-inf"),
+            (Value::Float(2.5e7), "2.500e7"),
+            (Value::Float(1.5e-6), "1.500e-6"),
+            (Value::Int(-7), "-7"),
+            (Value::Bool(true), "true"),
+            (Value::Vec2([1.0, 2.0]), "[1.000, 2.000]"),
+            (Value::Vec3([1.0, 2.0, 3.0]), "[1.000, 2.000, 3.000]"),
+            (Value::Vec4([0.0; 4]), "[0.000, 0.000, 0.000, 0.000]"),
+            (Value::String("say \"hi\"".into()), "\"say \\\"hi\\\"\""),
+            (Value::Color(Color::RED), "rgba(1.000, 0.000, 0.000, 1.000)"),
+            (
+                Value::Gradient(Gradient::two_color(Color::BLACK, Color::WHITE)),
+                "[0.000: rgba(0.000, 0.000, 0.000, 1.000), \
+                 1.000: rgba(1.000, 1.000, 1.000, 1.000)]",
+            ),
+            (
+                matrix(),
+                "[[1.000, 0.000, 0.000, 1.000], [0.000, 1.000, 0.000, 2.000], \
+                 [0.000, 0.000, 1.000, 3.000], [0.000, 0.000, 0.000, 1.000]]",
+            ),
+            (Value::float_list(vec![0.5, 1.5]), "[0.500, 1.500]"),
+            (Value::int_list(vec![1, 2, 3]), "[1, 2, 3]"),
+            (Value::bool_list(vec![true, false]), "[true, false]"),
+            (Value::vec2_list(vec![[1.0, 2.0]]), "[[1.000, 2.000]]"),
+            (Value::vec3_list(vec![]), "[]"),
+            (Value::vec4_list(vec![[1.0; 4]]), "[[1.000, 1.000, 1.000, 1.000]]"),
+            (Value::color_list(vec![Color::BLUE]), "[rgba(0.000, 0.000, 1.000, 1.000)]"),
+            (Value::string_list(vec!["x".into()]), "[\"x\"]"),
+            (Value::Map(map), "{a: 1, b: [0.500, 1.000]}"),
+            (Value::ResourceRef { kind: ResourceKind::Mesh, id: 2 }, "Mesh#2"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(value.format(&opts), expected, "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn test_format_options() {
+        let list = Value::float_list((0..1000).map(|i| i as f32).collect());
+        assert_eq!(
+            list.format(&FormatOptions::default().with_max_list_items(3)),
+            "[0.000, 1.000, 2.000, … (+997 more)]"
+        );
+        assert_eq!(
+            list.format(&FormatOptions::default().with_max_list_items(0)),
+            "[… (+1000 more)]"
+        );
+        assert_eq!(
+            list.format(&FormatOptions::default().with_precision(0).with_type_prefix(true)),
+            "FloatList[1000]: [0, 1, 2, 3, 4, 5, 6, 7, … (+992 more)]"
+        );
+
+        let pi = Value::Float(std::f32::consts::PI);
+        assert_eq!(pi.format(&FormatOptions::default().with_precision(1)), "3.1");
+        assert_eq!(pi.format(&FormatOptions::default().with_precision(5)), "3.14159");
+        assert_eq!(
+            pi.format(&FormatOptions::default().with_type_prefix(true)),
+            "Float: 3.142"
+        );
+        let scientific = FormatOptions::default().with_scientific_thresholds(0.0, 1e3);
+        assert_eq!(Value::Float(12345.0).format(&scientific), "1.234e4");
+
+        let hex = FormatOptions::default().with_color_format(ColorFormat::Hex);
+        assert_eq!(Value::Color(Color::rgba(1.0, 0.5, 0.0, 0.5)).format(&hex), "#FF800080");
+        assert_eq!(
+            Value::Gradient(Gradient::two_color(Color::BLACK, Color::WHITE)).format(&hex),
+            "[0.000: #000000, 1.000: #FFFFFF]"
+        );
+
+        let rows = FormatOptions::default()
+            .with_precision(0)
+            .with_matrix_layout(MatrixLayout::Rows);
+        assert_eq!(
+            matrix().format(&rows),
+            "[1, 0, 0, 1]\n[0, 1, 0, 2]\n[0, 0, 1, 3]\n[0, 0, 0, 1]"
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let cases = [
+            (Value::Float(0.5), "Float: 0.500"),
+            (Value::Vec2([1.0, 2.0]), "Vec2: [1.000, 2.000]"),
+            (Value::String("hello".into()), "String[5]: \"hello\""),
+            (Value::String("x".repeat(40)), "String[40]: \"xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx\"…"),
+            (Value::Color(Color::rgb(1.0, 0.5, 0.0)), "Color: #FF8000"),
+            (
+                Value::Gradient(Gradient::two_color(Color::RED, Color::BLUE)),
+                "Gradient: 2 stops, #FF0000 → #0000FF",
+            ),
+            (Value::Gradient(Gradient::empty()), "Gradient: 0 stops"),
+            (matrix(), "Matrix4: translation [1.000, 2.000, 3.000]"),
+            (
+                Value::float_list((0..1000).map(|i| i as f32).collect()),
+                "FloatList[1000]: min 0.000, max 999.000",
+            ),
+            (Value::float_list(vec![]), "FloatList[0]"),
+            (Value::int_list(vec![4, -2, 9]), "IntList[3]: min -2, max 9"),
+            (
+                Value::vec2_list(vec![[0.0, 5.0], [2.0, -1.0]]),
+                "Vec2List[2]: min [0.000, -1.000], max [2.000, 5.000]",
+            ),
+            (Value::bool_list(vec![true, false, true]), "BoolList[3]: 2 true"),
+            (Value::string_list(vec!["a".into()]), "StringList[1]"),
+            (Value::Map(BTreeMap::new()), "Map: 0 entries"),
+        ];
+        for (value, expected) in cases {
+            assert_eq!(value.summary(), expected, "{:?}", value);
+        }
+    }
+
+    #[test]
+    fn test_display_unchanged() {
+        assert_eq!(Value::float_list(vec![1.0; 256]).to_string(), "FloatList[256]");
+        assert_eq!(matrix().to_string(), "Matrix4");
+    }
+}
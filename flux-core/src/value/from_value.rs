@@ -0,0 +1,112 @@
+//! Extracting Rust types from values
+//!
+//! [`FromValue`] is what host code uses to read graph outputs without
+//! matching on [`Value`] by hand. Extraction tries the exact variant first
+//! and falls back to [`Value::coerce_to`], so an `Int` output reads fine as
+//! `f32`. Every implementor also gets `TryFrom<Value>`.
+
+use std::sync::Arc;
+
+use thiserror::Error;
+
+use super::{Color, Gradient, Matrix4, Value, ValueType};
+
+/// A value could not be read as the requested type
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+#[error("cannot read a {actual} value as {requested}")]
+pub struct FromValueError {
+    pub actual: ValueType,
+    pub requested: ValueType,
+}
+
+/// A Rust type that can be read out of a [`Value`]
+pub trait FromValue: Sized {
+    /// Value type that converts to `Self` without loss
+    const VALUE_TYPE: ValueType;
+
+    /// Extract from a value of exactly [`VALUE_TYPE`](Self::VALUE_TYPE).
+    fn from_value_exact(value: &Value) -> Option<Self>;
+
+    /// Extract, coercing the value to [`VALUE_TYPE`](Self::VALUE_TYPE) if needed.
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        Self::from_value_exact(value)
+            .or_else(|| Self::from_value_exact(&value.coerce_to(Self::VALUE_TYPE)?))
+            .ok_or(FromValueError {
+                actual: value.value_type(),
+                requested: Self::VALUE_TYPE,
+            })
+    }
+}
+
+macro_rules! from_value {
+    ($ty:ty, $value_type:ident, $v:ident => $extract:expr) => {
+        impl FromValue for $ty {
+            const VALUE_TYPE: ValueType = ValueType::$value_type;
+
+            fn from_value_exact(value: &Value) -> Option<Self> {
+                match value {
+                    Value::$value_type($v) => Some($extract),
+                    _ => None,
+                }
+            }
+        }
+
+        impl TryFrom<Value> for $ty {
+            type Error = FromValueError;
+
+            fn try_from(value: Value) -> Result<Self, Self::Error> {
+                <$ty as FromValue>::from_value(&value)
+            }
+        }
+
+        impl TryFrom<&Value> for $ty {
+            type Error = FromValueError;
+
+            fn try_from(value: &Value) -> Result<Self, Self::Error> {
+                <$ty as FromValue>::from_value(value)
+            }
+        }
+    };
+}
+
+from_value!(f32, Float, v => *v);
+from_value!(i32, Int, v => *v);
+from_value!(bool, Bool, v => *v);
+from_value!([f32; 2], Vec2, v => *v);
+from_value!([f32; 3], Vec3, v => *v);
+from_value!([f32; 4], Vec4, v => *v);
+from_value!(String, String, v => v.clone());
+from_value!(Color, Color, v => *v);
+from_value!(Gradient, Gradient, v => v.clone());
+from_value!(Matrix4, Matrix4, v => *v);
+from_value!(Vec<f32>, FloatList, v => v.to_vec());
+from_value!(Vec<i32>, IntList, v => v.to_vec());
+from_value!(Vec<bool>, BoolList, v => v.to_vec());
+from_value!(Vec<[f32; 2]>, Vec2List, v => v.to_vec());
+from_value!(Vec<[f32; 3]>, Vec3List, v => v.to_vec());
+from_value!(Vec<[f32; 4]>, Vec4List, v => v.to_vec());
+from_value!(Vec<Color>, ColorList, v => v.to_vec());
+from_value!(Vec<String>, StringList, v => v.to_vec());
+from_value!(Arc<[f32]>, FloatList, v => Arc::clone(v));
+from_value!(Arc<[[f32; 3]]>, Vec3List, v => Arc::clone(v));
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exact_coerced_and_failed_extraction() {
+        assert_eq!(f32::from_value(&Value::Float(1.5)), Ok(1.5));
+        assert_eq!(f32::from_value(&Value::Int(3)), Ok(3.0));
+        assert_eq!(<[f32; 3]>::try_from(Value::Float(2.0)), Ok([2.0; 3]));
+        assert_eq!(
+            Vec::<f32>::try_from(Value::float_list(vec![1.0, 2.0])),
+            Ok(vec![1.0, 2.0])
+        );
+
+        let err = bool::try_from(Value::Gradient(Gradient::default())).unwrap_err();
+        assert_eq!(err.actual, ValueType::Gradient);
+        assert_eq!(err.requested, ValueType::Bool);
+        assert_eq!(err.to_string(), "cannot read a Gradient value as Bool");
+    }
+}
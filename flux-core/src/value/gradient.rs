@@ -1,63 +1,174 @@
 //! Gradient type with color stops and sampling
+//!
+//! Each stop carries a stable [`GradientStopId`] so UI edits and animation can
+//! target a specific stop while others are inserted, moved or removed. The
+//! [`StopInterpolation`] of a stop controls the segment leading to the next
+//! stop; the gradient's [`GradientColorSpace`] controls how colors blend.
+//!
+//! Gradients saved before stop ids, interpolation modes and color spaces
+//! existed still load: missing fields take their defaults and stops without
+//! ids are assigned fresh ones.
 
 use serde::{Deserialize, Serialize};
 
 use super::Color;
 
+/// Stable identifier of a stop within a [`Gradient`]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct GradientStopId(pub u32);
+
+/// How colors blend from a stop to the next one
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum StopInterpolation {
+    /// Blend linearly to the next stop
+    #[default]
+    Linear,
+    /// Hold this stop's color until the next stop (hard edge)
+    Hold,
+    /// Blend with smoothstep easing
+    Smooth,
+}
+
+/// Color space used to blend between stops
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GradientColorSpace {
+    /// Blend RGB components directly
+    #[default]
+    Rgb,
+    /// Blend in OkLab (perceptually uniform)
+    OkLab,
+}
+
 /// A stop in a color gradient
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct GradientStop {
+    /// Stable identifier (assigned by the gradient)
+    #[serde(default)]
+    pub id: GradientStopId,
     /// Position in the gradient (0.0 - 1.0)
     pub position: f32,
     /// Color at this position
     pub color: Color,
+    /// Interpolation toward the next stop
+    #[serde(default)]
+    pub interpolation: StopInterpolation,
 }
 
 /// Color gradient with multiple stops
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "GradientData")]
 pub struct Gradient {
     /// Gradient stops (should be sorted by position)
     pub stops: Vec<GradientStop>,
+    /// Color space used for interpolation
+    pub color_space: GradientColorSpace,
+    /// Next id handed out by `add_stop` (ids are never reused)
+    next_stop_id: u32,
+}
+
+/// Serialized form of [`Gradient`], tolerant of older saves.
+#[derive(Deserialize)]
+struct GradientData {
+    stops: Vec<GradientStopData>,
+    #[serde(default)]
+    color_space: GradientColorSpace,
+    #[serde(default)]
+    next_stop_id: u32,
+}
+
+/// Serialized form of [`GradientStop`]; older saves have no id.
+#[derive(Deserialize)]
+struct GradientStopData {
+    #[serde(default)]
+    id: Option<GradientStopId>,
+    position: f32,
+    color: Color,
+    #[serde(default)]
+    interpolation: StopInterpolation,
+}
+
+impl From<GradientData> for Gradient {
+    fn from(data: GradientData) -> Self {
+        let max_id = data.stops.iter().filter_map(|s| s.id).map(|id| id.0 + 1).max();
+        let mut next_stop_id = data.next_stop_id.max(max_id.unwrap_or(0));
+
+        let stops = data
+            .stops
+            .into_iter()
+            .map(|s| {
+                let id = s.id.unwrap_or_else(|| {
+                    next_stop_id += 1;
+                    GradientStopId(next_stop_id - 1)
+                });
+                GradientStop {
+                    id,
+                    position: s.position,
+                    color: s.color,
+                    interpolation: s.interpolation,
+                }
+            })
+            .collect();
+
+        let mut gradient = Self {
+            stops,
+            color_space: data.color_space,
+            next_stop_id,
+        };
+        gradient.sort_stops();
+        gradient
+    }
 }
 
 impl Gradient {
     /// Create a default black-to-white gradient
     pub fn new() -> Self {
-        Self {
-            stops: vec![
-                GradientStop {
-                    position: 0.0,
-                    color: Color::BLACK,
-                },
-                GradientStop {
-                    position: 1.0,
-                    color: Color::WHITE,
-                },
-            ],
-        }
+        Self::two_color(Color::BLACK, Color::WHITE)
     }
 
     /// Create a gradient between two colors
     pub fn two_color(start: Color, end: Color) -> Self {
+        let mut gradient = Self::empty();
+        gradient.add_stop(0.0, start);
+        gradient.add_stop(1.0, end);
+        gradient
+    }
+
+    /// Create a gradient with no stops
+    pub fn empty() -> Self {
         Self {
-            stops: vec![
-                GradientStop {
-                    position: 0.0,
-                    color: start,
-                },
-                GradientStop {
-                    position: 1.0,
-                    color: end,
-                },
-            ],
+            stops: Vec::new(),
+            color_space: GradientColorSpace::Rgb,
+            next_stop_id: 0,
         }
     }
 
-    /// Add a stop to the gradient (maintains sorted order)
-    pub fn add_stop(&mut self, position: f32, color: Color) {
+    /// Set the interpolation color space (builder style)
+    pub fn with_color_space(mut self, color_space: GradientColorSpace) -> Self {
+        self.color_space = color_space;
+        self
+    }
+
+    /// Add a stop to the gradient (maintains sorted order), returning its id
+    pub fn add_stop(&mut self, position: f32, color: Color) -> GradientStopId {
+        self.add_stop_with(position, color, StopInterpolation::Linear)
+    }
+
+    /// Add a stop with an interpolation mode, returning its id
+    pub fn add_stop_with(
+        &mut self,
+        position: f32,
+        color: Color,
+        interpolation: StopInterpolation,
+    ) -> GradientStopId {
+        let id = GradientStopId(self.next_stop_id);
+        self.next_stop_id += 1;
+
         let stop = GradientStop {
+            id,
             position: position.clamp(0.0, 1.0),
             color,
+            interpolation,
         };
 
         // Find insertion point to maintain sorted order
@@ -68,6 +179,56 @@ impl Gradient {
             .unwrap_or(self.stops.len());
 
         self.stops.insert(idx, stop);
+        id
+    }
+
+    /// Remove a stop by id, returning it if found
+    pub fn remove_stop(&mut self, id: GradientStopId) -> Option<GradientStop> {
+        let idx = self.stops.iter().position(|s| s.id == id)?;
+        Some(self.stops.remove(idx))
+    }
+
+    /// Move a stop to a new position, re-sorting the stops
+    ///
+    /// Returns false if no stop has the given id.
+    pub fn move_stop(&mut self, id: GradientStopId, position: f32) -> bool {
+        let Some(stop) = self.stops.iter_mut().find(|s| s.id == id) else {
+            return false;
+        };
+        stop.position = position.clamp(0.0, 1.0);
+        self.sort_stops();
+        true
+    }
+
+    /// Get a stop by id
+    pub fn stop(&self, id: GradientStopId) -> Option<&GradientStop> {
+        self.stops.iter().find(|s| s.id == id)
+    }
+
+    /// Change a stop's color. Returns false if no stop has the given id.
+    pub fn set_stop_color(&mut self, id: GradientStopId, color: Color) -> bool {
+        match self.stops.iter_mut().find(|s| s.id == id) {
+            Some(stop) => {
+                stop.color = color;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Change a stop's interpolation mode. Returns false if no stop has the given id.
+    pub fn set_stop_interpolation(
+        &mut self,
+        id: GradientStopId,
+        interpolation: StopInterpolation,
+    ) -> bool {
+        match self.stops.iter_mut().find(|s| s.id == id) {
+            Some(stop) => {
+                stop.interpolation = interpolation;
+                true
+            }
+            None => false,
+        }
     }
 
     /// Sample the gradient at position t (0.0 - 1.0)
@@ -85,17 +246,32 @@ impl Gradient {
         let mut prev = &self.stops[0];
         for stop in &self.stops {
             if stop.position >= t {
-                if stop.position == prev.position {
+                if stop.position == prev.position || stop.position == t {
                     return stop.color;
                 }
                 let local_t = (t - prev.position) / (stop.position - prev.position);
-                return Color::lerp(&prev.color, &stop.color, local_t);
+                let local_t = match prev.interpolation {
+                    StopInterpolation::Linear => local_t,
+                    StopInterpolation::Hold => return prev.color,
+                    StopInterpolation::Smooth => local_t * local_t * (3.0 - 2.0 * local_t),
+                };
+                return match self.color_space {
+                    GradientColorSpace::Rgb => Color::lerp(&prev.color, &stop.color, local_t),
+                    GradientColorSpace::OkLab => {
+                        Color::lerp_oklab(&prev.color, &stop.color, local_t)
+                    }
+                };
             }
             prev = stop;
         }
 
         prev.color
     }
+
+    /// Stable sort of stops by position
+    fn sort_stops(&mut self) {
+        self.stops.sort_by(|a, b| a.position.total_cmp(&b.position));
+    }
 }
 
 impl Default for Gradient {
@@ -130,4 +306,92 @@ mod tests {
         assert_eq!(gradient.stops.len(), 3);
         assert_eq!(gradient.stops[1].position, 0.5);
     }
+
+    #[test]
+    fn test_stop_ids_stable_across_edits() {
+        let mut gradient = Gradient::new();
+        let first = gradient.stops[0].id;
+        let red = gradient.add_stop(0.5, Color::RED);
+        let green = gradient.add_stop(0.25, Color::GREEN);
+
+        // Moving re-sorts; ids follow their stops
+        assert!(gradient.move_stop(red, 0.1));
+        assert_eq!(gradient.stops[1].id, red);
+        assert_eq!(gradient.stops[2].id, green);
+        assert_eq!(gradient.stops[0].id, first);
+
+        // Removed ids are not reused
+        assert_eq!(gradient.remove_stop(red).map(|s| s.color), Some(Color::RED));
+        let blue = gradient.add_stop(0.5, Color::BLUE);
+        assert_ne!(blue, red);
+        assert!(!gradient.move_stop(red, 0.5));
+    }
+
+    #[test]
+    fn test_hold_stop_hard_edge() {
+        let mut gradient = Gradient::empty();
+        gradient.add_stop_with(0.0, Color::RED, StopInterpolation::Hold);
+        gradient.add_stop(0.5, Color::BLUE);
+
+        assert_eq!(gradient.sample(0.0), Color::RED);
+        assert_eq!(gradient.sample(0.49), Color::RED);
+        assert_eq!(gradient.sample(0.5), Color::BLUE);
+        assert_eq!(gradient.sample(0.75), Color::BLUE);
+    }
+
+    #[test]
+    fn test_smooth_interpolation_eases() {
+        let mut gradient = Gradient::empty();
+        gradient.add_stop_with(0.0, Color::BLACK, StopInterpolation::Smooth);
+        gradient.add_stop(1.0, Color::WHITE);
+
+        assert!(gradient.sample(0.25).r < 0.25);
+        assert!((gradient.sample(0.5).r - 0.5).abs() < 1e-6);
+        assert!(gradient.sample(0.75).r > 0.75);
+    }
+
+    #[test]
+    fn test_oklab_differs_from_rgb() {
+        let rgb = Gradient::two_color(Color::RED, Color::BLUE);
+        let oklab = rgb.clone().with_color_space(GradientColorSpace::OkLab);
+
+        let a = rgb.sample(0.5);
+        let b = oklab.sample(0.5);
+        let diff = (a.r - b.r).abs() + (a.g - b.g).abs() + (a.b - b.b).abs();
+        assert!(diff > 0.05, "expected measurable difference, got {diff}");
+
+        // Endpoints are unaffected by the color space
+        let end = oklab.sample(1.0);
+        assert!((end.b - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_old_format_json_loads() {
+        let json = r#"{"stops":[
+            {"position":1.0,"color":{"r":1.0,"g":1.0,"b":1.0,"a":1.0}},
+            {"position":0.0,"color":{"r":0.0,"g":0.0,"b":0.0,"a":1.0}}
+        ]}"#;
+        let mut gradient: Gradient = serde_json::from_str(json).unwrap();
+
+        assert_eq!(gradient.color_space, GradientColorSpace::Rgb);
+        assert_eq!(gradient.stops[0].position, 0.0);
+        assert_eq!(gradient.stops[0].interpolation, StopInterpolation::Linear);
+        assert_ne!(gradient.stops[0].id, gradient.stops[1].id);
+        assert!((gradient.sample(0.5).r - 0.5).abs() < 0.01);
+
+        // New stops get fresh ids
+        let id = gradient.add_stop(0.5, Color::RED);
+        assert!(gradient.stops.iter().filter(|s| s.id == id).count() == 1);
+    }
+
+    #[test]
+    fn test_gradient_serde_roundtrip() {
+        let mut gradient = Gradient::new().with_color_space(GradientColorSpace::OkLab);
+        let id = gradient.add_stop_with(0.3, Color::RED, StopInterpolation::Hold);
+        gradient.remove_stop(id);
+
+        let json = serde_json::to_string(&gradient).unwrap();
+        let back: Gradient = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, gradient);
+    }
 }
@@ -3,22 +3,31 @@
 //! This module contains the core value types used throughout the graph:
 //! - [`Value`] - The main enum representing all possible values
 //! - [`ValueType`] - Type identifiers for compile-time and runtime checks
-//! - [`Color`] - RGBA color with HSV conversion
+//! - [`Color`] - RGBA color with HSV conversion and accessibility checks
 //! - [`Gradient`] - Color gradient with stops
 //! - [`Matrix4`] - 4x4 transformation matrix
+//! - [`FormatOptions`] - Configurable display formatting (see [`Value::format`])
+//! - [`ResourceKind`] - Kind of host resource a [`Value::ResourceRef`] points to
+//! - [`FromValue`] - Reading Rust types out of values, with coercion
 
 mod color;
+mod format;
+mod from_value;
 mod gradient;
 mod matrix;
 mod ops;
 
-pub use color::Color;
-pub use gradient::{Gradient, GradientStop};
+pub use color::{Color, ColorVisionDeficiency};
+pub use format::{ColorFormat, FormatOptions, MatrixLayout};
+pub use from_value::{FromValue, FromValueError};
+pub use gradient::{Gradient, GradientColorSpace, GradientStop, GradientStopId, StopInterpolation};
 pub use matrix::Matrix4;
+pub use crate::resource::ResourceKind;
 
 // Re-export ops module items (the std::ops impls are automatic)
 
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
 use std::fmt;
 use std::sync::Arc;
 
@@ -48,6 +57,14 @@ mod arc_slice_serde {
     }
 }
 
+/// Mutable access to a shared slice, copying it first if other values share it
+fn make_mut_slice<T: Clone>(data: &mut Arc<[T]>) -> &mut [T] {
+    if Arc::get_mut(data).is_none() {
+        *data = data.iter().cloned().collect();
+    }
+    Arc::get_mut(data).expect("freshly copied slice is unique")
+}
+
 /// All possible value types in the graph
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum Value {
@@ -78,6 +95,48 @@ pub enum Value {
     Vec4List(#[serde(with = "arc_slice_serde")] Arc<[[f32; 4]]>),
     ColorList(#[serde(with = "arc_slice_serde")] Arc<[Color]>),
     StringList(#[serde(with = "arc_slice_serde")] Arc<[String]>),
+
+    // Structured data (ordered by key for deterministic serialization)
+    Map(BTreeMap<String, Value>),
+
+    // Host resources (saved by kind and name, see `flux_core::resource`)
+    #[serde(
+        serialize_with = "crate::resource::serialize_ref",
+        deserialize_with = "crate::resource::deserialize_ref"
+    )]
+    ResourceRef { kind: ResourceKind, id: u64 },
+}
+
+/// Convert a float to an integer, truncating toward zero.
+///
+/// Out-of-range values clamp to `i32::MIN`/`i32::MAX` and NaN becomes 0, so
+/// the result never depends on how the cast handles non-representable input.
+pub fn float_to_int(value: f32) -> i32 {
+    if value.is_nan() {
+        0
+    } else if value >= i32::MAX as f32 {
+        i32::MAX
+    } else if value <= i32::MIN as f32 {
+        i32::MIN
+    } else {
+        value.trunc() as i32
+    }
+}
+
+/// Parse trimmed text as a finite float ("nan" and "inf" are rejected).
+fn parse_float(text: &str) -> Option<f32> {
+    text.trim().parse::<f32>().ok().filter(|f| f.is_finite())
+}
+
+/// Parse trimmed text as a bool: "true"/"false" (any case) or "1"/"0".
+fn parse_bool(text: &str) -> Option<bool> {
+    match text.trim() {
+        "1" => Some(true),
+        "0" => Some(false),
+        t if t.eq_ignore_ascii_case("true") => Some(true),
+        t if t.eq_ignore_ascii_case("false") => Some(false),
+        _ => None,
+    }
 }
 
 impl Value {
@@ -102,6 +161,8 @@ impl Value {
             Value::Vec4List(_) => ValueType::Vec4List,
             Value::ColorList(_) => ValueType::ColorList,
             Value::StringList(_) => ValueType::StringList,
+            Value::Map(_) => ValueType::Map,
+            Value::ResourceRef { .. } => ValueType::ResourceRef,
         }
     }
 
@@ -120,7 +181,7 @@ impl Value {
     pub fn as_int(&self) -> Option<i32> {
         match self {
             Value::Int(v) => Some(*v),
-            Value::Float(v) => Some(*v as i32),
+            Value::Float(v) => Some(float_to_int(*v)),
             _ => None,
         }
     }
@@ -260,6 +321,142 @@ impl Value {
         }
     }
 
+    // ========== Copy-on-Write List Access ==========
+    //
+    // Cloning a list value only bumps a reference count, so the same buffer
+    // is often shared by many input defaults and cached outputs. The `_mut`
+    // accessors copy the elements first when the buffer is shared, leaving
+    // every other clone untouched.
+
+    /// Mutable float list elements, copied first if shared
+    pub fn as_float_list_mut(&mut self) -> Option<&mut [f32]> {
+        match self {
+            Value::FloatList(v) => Some(make_mut_slice(v)),
+            _ => None,
+        }
+    }
+
+    /// Mutable int list elements, copied first if shared
+    pub fn as_int_list_mut(&mut self) -> Option<&mut [i32]> {
+        match self {
+            Value::IntList(v) => Some(make_mut_slice(v)),
+            _ => None,
+        }
+    }
+
+    /// Mutable bool list elements, copied first if shared
+    pub fn as_bool_list_mut(&mut self) -> Option<&mut [bool]> {
+        match self {
+            Value::BoolList(v) => Some(make_mut_slice(v)),
+            _ => None,
+        }
+    }
+
+    /// Mutable vec2 list elements, copied first if shared
+    pub fn as_vec2_list_mut(&mut self) -> Option<&mut [[f32; 2]]> {
+        match self {
+            Value::Vec2List(v) => Some(make_mut_slice(v)),
+            _ => None,
+        }
+    }
+
+    /// Mutable vec3 list elements, copied first if shared
+    pub fn as_vec3_list_mut(&mut self) -> Option<&mut [[f32; 3]]> {
+        match self {
+            Value::Vec3List(v) => Some(make_mut_slice(v)),
+            _ => None,
+        }
+    }
+
+    /// Mutable vec4 list elements, copied first if shared
+    pub fn as_vec4_list_mut(&mut self) -> Option<&mut [[f32; 4]]> {
+        match self {
+            Value::Vec4List(v) => Some(make_mut_slice(v)),
+            _ => None,
+        }
+    }
+
+    /// Mutable color list elements, copied first if shared
+    pub fn as_color_list_mut(&mut self) -> Option<&mut [Color]> {
+        match self {
+            Value::ColorList(v) => Some(make_mut_slice(v)),
+            _ => None,
+        }
+    }
+
+    /// Mutable string list elements, copied first if shared
+    pub fn as_string_list_mut(&mut self) -> Option<&mut [String]> {
+        match self {
+            Value::StringList(v) => Some(make_mut_slice(v)),
+            _ => None,
+        }
+    }
+
+    // ========== Map Accessors ==========
+
+    /// Try to get as map
+    pub fn as_map(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Value::Map(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    /// Try to get as mutable map
+    pub fn as_map_mut(&mut self) -> Option<&mut BTreeMap<String, Value>> {
+        match self {
+            Value::Map(m) => Some(m),
+            _ => None,
+        }
+    }
+
+    // ========== Resource Accessors ==========
+
+    /// Try to get as a resource reference (kind, id)
+    pub fn as_resource_ref(&self) -> Option<(ResourceKind, u64)> {
+        match self {
+            Value::ResourceRef { kind, id } => Some((*kind, *id)),
+            _ => None,
+        }
+    }
+
+    /// Look up a field by dotted path (e.g. `"particle.position"`)
+    ///
+    /// Returns `None` if any segment is missing or descends into a non-map value.
+    pub fn get_field(&self, path: &str) -> Option<&Value> {
+        path.split('.')
+            .try_fold(self, |value, key| value.as_map()?.get(key))
+    }
+
+    /// Set a field by dotted path, creating intermediate maps as needed
+    ///
+    /// Returns false (leaving the value unchanged) if this value or an
+    /// existing intermediate segment is not a map.
+    pub fn set_field(&mut self, path: &str, field: Value) -> bool {
+        let (parents, key) = match path.rsplit_once('.') {
+            Some((parents, key)) => (Some(parents), key),
+            None => (None, path),
+        };
+
+        let mut target = self;
+        for segment in parents.into_iter().flat_map(|p| p.split('.')) {
+            let Some(map) = target.as_map_mut() else {
+                return false;
+            };
+            target = map
+                .entry(segment.to_string())
+                .or_insert_with(|| Value::Map(BTreeMap::new()));
+        }
+
+        match target.as_map_mut() {
+            Some(map) => {
+                map.insert(key.to_string(), field);
+                true
+            }
+            None => false,
+        }
+    }
+
     // ========== List Constructors ==========
     // These create Arc-wrapped lists from Vec or slice
 
@@ -303,6 +500,58 @@ impl Value {
         Value::StringList(v.into())
     }
 
+    /// Number of elements if this is a list value
+    pub fn list_len(&self) -> Option<usize> {
+        match self {
+            Value::FloatList(l) => Some(l.len()),
+            Value::IntList(l) => Some(l.len()),
+            Value::BoolList(l) => Some(l.len()),
+            Value::Vec2List(l) => Some(l.len()),
+            Value::Vec3List(l) => Some(l.len()),
+            Value::Vec4List(l) => Some(l.len()),
+            Value::ColorList(l) => Some(l.len()),
+            Value::StringList(l) => Some(l.len()),
+            _ => None,
+        }
+    }
+
+    /// Elements of a list value as individual values, in order
+    pub fn list_elements(&self) -> Option<Vec<Value>> {
+        let elements = match self {
+            Value::FloatList(l) => l.iter().map(|&v| Value::Float(v)).collect(),
+            Value::IntList(l) => l.iter().map(|&v| Value::Int(v)).collect(),
+            Value::BoolList(l) => l.iter().map(|&v| Value::Bool(v)).collect(),
+            Value::Vec2List(l) => l.iter().map(|&v| Value::Vec2(v)).collect(),
+            Value::Vec3List(l) => l.iter().map(|&v| Value::Vec3(v)).collect(),
+            Value::Vec4List(l) => l.iter().map(|&v| Value::Vec4(v)).collect(),
+            Value::ColorList(l) => l.iter().map(|&v| Value::Color(v)).collect(),
+            Value::StringList(l) => l.iter().map(|v| Value::String(v.clone())).collect(),
+            _ => return None,
+        };
+        Some(elements)
+    }
+
+    /// Truncate a list value to at most `max_len` elements
+    ///
+    /// Returns true if elements were removed. Non-list values are unchanged.
+    pub fn truncate_list(&mut self, max_len: usize) -> bool {
+        if self.list_len().is_none_or(|len| len <= max_len) {
+            return false;
+        }
+        match self {
+            Value::FloatList(l) => *l = l[..max_len].into(),
+            Value::IntList(l) => *l = l[..max_len].into(),
+            Value::BoolList(l) => *l = l[..max_len].into(),
+            Value::Vec2List(l) => *l = l[..max_len].into(),
+            Value::Vec3List(l) => *l = l[..max_len].into(),
+            Value::Vec4List(l) => *l = l[..max_len].into(),
+            Value::ColorList(l) => *l = l[..max_len].into(),
+            Value::StringList(l) => *l = l[..max_len].into(),
+            _ => {}
+        }
+        true
+    }
+
     // ========== Type Coercion ==========
 
     /// Attempt to coerce this value to the target type
@@ -315,7 +564,7 @@ impl Value {
         match (self, target) {
             // Numeric conversions
             (Value::Int(i), ValueType::Float) => Some(Value::Float(*i as f32)),
-            (Value::Float(f), ValueType::Int) => Some(Value::Int(*f as i32)),
+            (Value::Float(f), ValueType::Int) => Some(Value::Int(float_to_int(*f))),
             (Value::Bool(b), ValueType::Int) => Some(Value::Int(if *b { 1 } else { 0 })),
             (Value::Bool(b), ValueType::Float) => Some(Value::Float(if *b { 1.0 } else { 0.0 })),
             (Value::Int(i), ValueType::Bool) => Some(Value::Bool(*i != 0)),
@@ -348,6 +597,11 @@ impl Value {
             (Value::Float(f), ValueType::String) => Some(Value::String(f.to_string())),
             (Value::Bool(b), ValueType::String) => Some(Value::String(b.to_string())),
 
+            // String parsing (fails unless the whole trimmed text parses)
+            (Value::String(s), ValueType::Float) => parse_float(s).map(Value::Float),
+            (Value::String(s), ValueType::Int) => s.trim().parse().ok().map(Value::Int),
+            (Value::String(s), ValueType::Bool) => parse_bool(s).map(Value::Bool),
+
             // ========== Collection Coercions ==========
 
             // Scalar → List (wrap as single-element list)
@@ -365,7 +619,7 @@ impl Value {
                 Some(Value::float_list(il.iter().map(|i| *i as f32).collect()))
             }
             (Value::FloatList(fl), ValueType::IntList) => {
-                Some(Value::int_list(fl.iter().map(|f| *f as i32).collect()))
+                Some(Value::int_list(fl.iter().map(|&f| float_to_int(f)).collect()))
             }
 
             // ColorList ↔ Vec4List (isomorphic)
@@ -465,6 +719,17 @@ impl fmt::Display for Value {
             Value::Vec4List(v) => write!(f, "Vec4List[{}]", v.len()),
             Value::ColorList(v) => write!(f, "ColorList[{}]", v.len()),
             Value::StringList(v) => write!(f, "StringList[{}]", v.len()),
+            Value::Map(m) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in m.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}: {}", key, value)?;
+                }
+                write!(f, "}}")
+            }
+            Value::ResourceRef { kind, id } => write!(f, "{}#{}", kind, id),
         }
     }
 }
@@ -531,6 +796,12 @@ impl From<Gradient> for Value {
     }
 }
 
+impl From<BTreeMap<String, Value>> for Value {
+    fn from(m: BTreeMap<String, Value>) -> Self {
+        Value::Map(m)
+    }
+}
+
 impl From<Matrix4> for Value {
     fn from(m: Matrix4) -> Self {
         Value::Matrix4(m)
@@ -558,6 +829,8 @@ pub enum ValueType {
     Vec4List,
     ColorList,
     StringList,
+    Map,
+    ResourceRef,
 }
 
 /// Type categories for polymorphic inputs.
@@ -622,9 +895,74 @@ impl ValueType {
             ValueType::Vec4List => Value::vec4_list(Vec::new()),
             ValueType::ColorList => Value::color_list(Vec::new()),
             ValueType::StringList => Value::string_list(Vec::new()),
+            ValueType::Map => Value::Map(BTreeMap::new()),
+            ValueType::ResourceRef => Value::ResourceRef {
+                kind: ResourceKind::Custom(0),
+                id: crate::resource::NULL_RESOURCE_ID,
+            },
         }
     }
 
+    /// List type holding elements of this type (e.g. `Float` -> `FloatList`)
+    pub fn list_type(&self) -> Option<ValueType> {
+        let list = match self {
+            ValueType::Float => ValueType::FloatList,
+            ValueType::Int => ValueType::IntList,
+            ValueType::Bool => ValueType::BoolList,
+            ValueType::Vec2 => ValueType::Vec2List,
+            ValueType::Vec3 => ValueType::Vec3List,
+            ValueType::Vec4 => ValueType::Vec4List,
+            ValueType::Color => ValueType::ColorList,
+            ValueType::String => ValueType::StringList,
+            _ => return None,
+        };
+        Some(list)
+    }
+
+    /// Element type of a list type (e.g. `FloatList` -> `Float`)
+    pub fn element_type(&self) -> Option<ValueType> {
+        let element = match self {
+            ValueType::FloatList => ValueType::Float,
+            ValueType::IntList => ValueType::Int,
+            ValueType::BoolList => ValueType::Bool,
+            ValueType::Vec2List => ValueType::Vec2,
+            ValueType::Vec3List => ValueType::Vec3,
+            ValueType::Vec4List => ValueType::Vec4,
+            ValueType::ColorList => ValueType::Color,
+            ValueType::StringList => ValueType::String,
+            _ => return None,
+        };
+        Some(element)
+    }
+
+    /// Parse a type from its display name (e.g. `"Vec3"`, `"StringList"`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        let value_type = match name {
+            "Float" => ValueType::Float,
+            "Int" => ValueType::Int,
+            "Bool" => ValueType::Bool,
+            "Vec2" => ValueType::Vec2,
+            "Vec3" => ValueType::Vec3,
+            "Vec4" => ValueType::Vec4,
+            "String" => ValueType::String,
+            "Color" => ValueType::Color,
+            "Gradient" => ValueType::Gradient,
+            "Matrix4" => ValueType::Matrix4,
+            "FloatList" => ValueType::FloatList,
+            "IntList" => ValueType::IntList,
+            "BoolList" => ValueType::BoolList,
+            "Vec2List" => ValueType::Vec2List,
+            "Vec3List" => ValueType::Vec3List,
+            "Vec4List" => ValueType::Vec4List,
+            "ColorList" => ValueType::ColorList,
+            "StringList" => ValueType::StringList,
+            "Map" => ValueType::Map,
+            "ResourceRef" => ValueType::ResourceRef,
+            _ => return None,
+        };
+        Some(value_type)
+    }
+
     /// Check if this type can be coerced to the target type
     pub fn can_coerce_to(&self, target: ValueType) -> bool {
         if *self == target {
@@ -656,6 +994,10 @@ impl ValueType {
                 | (ValueType::Int, ValueType::String)
                 | (ValueType::Float, ValueType::String)
                 | (ValueType::Bool, ValueType::String)
+                // From string (parsing may still fail per value)
+                | (ValueType::String, ValueType::Float)
+                | (ValueType::String, ValueType::Int)
+                | (ValueType::String, ValueType::Bool)
                 // Scalar → List
                 | (ValueType::Float, ValueType::FloatList)
                 | (ValueType::Int, ValueType::IntList)
@@ -783,6 +1125,8 @@ impl fmt::Display for ValueType {
             ValueType::Vec4List => write!(f, "Vec4List"),
             ValueType::ColorList => write!(f, "ColorList"),
             ValueType::StringList => write!(f, "StringList"),
+            ValueType::Map => write!(f, "Map"),
+            ValueType::ResourceRef => write!(f, "ResourceRef"),
         }
     }
 }
@@ -798,6 +1142,29 @@ mod tests {
         assert_eq!(result, Some(Value::Float(42.0)));
     }
 
+    #[test]
+    fn test_coerce_float_to_int_clamps() {
+        let cases = [
+            (2.9, 2),
+            (-2.9, -2),
+            (f32::NAN, 0),
+            (f32::INFINITY, i32::MAX),
+            (f32::NEG_INFINITY, i32::MIN),
+            (3.0e9, i32::MAX),
+            (-3.0e9, i32::MIN),
+            (i32::MIN as f32, i32::MIN),
+        ];
+        for (float, int) in cases {
+            assert_eq!(Value::Float(float).coerce_to(ValueType::Int), Some(Value::Int(int)));
+            assert_eq!(Value::Float(float).as_int(), Some(int));
+        }
+        let list = Value::float_list(vec![f32::NAN, 1.0e10, -1.5]);
+        assert_eq!(
+            list.coerce_to(ValueType::IntList),
+            Some(Value::int_list(vec![0, i32::MAX, -1]))
+        );
+    }
+
     #[test]
     fn test_coerce_float_to_vec3() {
         let v = Value::Float(1.5);
@@ -847,6 +1214,50 @@ mod tests {
         assert!(!ValueType::Gradient.can_coerce_to(ValueType::Float));
     }
 
+    #[test]
+    fn test_coerce_string_parses_cleanly() {
+        let text = |s: &str| Value::String(s.into());
+        assert_eq!(text("3.25").coerce_to(ValueType::Float), Some(Value::Float(3.25)));
+        assert_eq!(text(" -2 ").coerce_to(ValueType::Int), Some(Value::Int(-2)));
+        assert_eq!(text("TRUE").coerce_to(ValueType::Bool), Some(Value::Bool(true)));
+        assert_eq!(text("0").coerce_to(ValueType::Bool), Some(Value::Bool(false)));
+
+        // Anything left over fails instead of becoming 0
+        assert_eq!(text("abc").coerce_to(ValueType::Float), None);
+        assert_eq!(text("42 px").coerce_to(ValueType::Float), None);
+        assert_eq!(text("").coerce_to(ValueType::Float), None);
+        assert_eq!(text("nan").coerce_to(ValueType::Float), None);
+        assert_eq!(text("1.5").coerce_to(ValueType::Int), None);
+        assert_eq!(text("yes").coerce_to(ValueType::Bool), None);
+    }
+
+    #[test]
+    fn test_can_coerce_to_table_matches_coerce_to() {
+        const TYPES: [&str; 20] = [
+            "Float", "Int", "Bool", "Vec2", "Vec3", "Vec4", "String", "Color", "Gradient",
+            "Matrix4", "FloatList", "IntList", "BoolList", "Vec2List", "Vec3List", "Vec4List",
+            "ColorList", "StringList", "Map", "ResourceRef",
+        ];
+        let types: Vec<ValueType> = TYPES.iter().map(|n| ValueType::from_name(n).unwrap()).collect();
+
+        for &source in &types {
+            // A string that parses as every scalar, so parse failures don't mask the table
+            let sample = match source {
+                ValueType::String => Value::String("1".into()),
+                _ => source.default_value(),
+            };
+            for &target in &types {
+                assert_eq!(
+                    source.can_coerce_to(target),
+                    sample.coerce_to(target).is_some(),
+                    "{:?} -> {:?}",
+                    source,
+                    target
+                );
+            }
+        }
+    }
+
     // =========================================================================
     // TypeCategory Tests
     // =========================================================================
@@ -942,4 +1353,126 @@ mod tests {
         let string_cats = ValueType::String.categories();
         assert!(string_cats.is_empty());
     }
+
+    // =========================================================================
+    // Map Tests
+    // =========================================================================
+
+    fn particle() -> Value {
+        let mut particle = Value::Map(BTreeMap::new());
+        assert!(particle.set_field("position", Value::Vec3([1.0, 2.0, 3.0])));
+        assert!(particle.set_field("age", Value::Float(0.5)));
+        assert!(particle.set_field("state.alive", Value::Bool(true)));
+        particle
+    }
+
+    #[test]
+    fn test_map_dotted_paths() {
+        let particle = particle();
+        assert_eq!(particle.value_type(), ValueType::Map);
+        assert_eq!(particle.get_field("age"), Some(&Value::Float(0.5)));
+        assert_eq!(particle.get_field("state.alive"), Some(&Value::Bool(true)));
+        assert_eq!(particle.get_field("state").map(Value::value_type), Some(ValueType::Map));
+        assert_eq!(particle.get_field("state.missing"), None);
+        // Can't descend into a non-map
+        assert_eq!(particle.get_field("age.value"), None);
+
+        let mut particle = particle;
+        assert!(!particle.set_field("age.value", Value::Int(1)));
+        assert!(!Value::Float(1.0).set_field("x", Value::Int(1)));
+        assert_eq!(particle.get_field("age"), Some(&Value::Float(0.5)));
+    }
+
+    #[test]
+    fn test_map_display_is_ordered() {
+        assert_eq!(
+            particle().to_string(),
+            "{age: 0.5, position: [1, 2, 3], state: {alive: true}}"
+        );
+    }
+
+    #[test]
+    fn test_map_json_round_trip() {
+        let particle = particle();
+        let json = serde_json::to_string(&particle).unwrap();
+        let back: Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, particle);
+    }
+
+    #[test]
+    fn test_map_is_not_coercible() {
+        let particle = particle();
+        assert!(particle.can_coerce_to(ValueType::Map));
+        assert_eq!(particle.coerce_to(ValueType::String), None);
+        assert_eq!(Value::Float(1.0).coerce_to(ValueType::Map), None);
+        assert!(!ValueType::Map.can_coerce_to(ValueType::StringList));
+        assert!(!ValueType::String.can_coerce_to(ValueType::Map));
+        assert!(ValueType::Map.categories().is_empty());
+        assert_eq!(ValueType::from_name("Map"), Some(ValueType::Map));
+    }
+
+    #[test]
+    fn test_resource_ref_is_not_coercible() {
+        let texture = Value::ResourceRef { kind: ResourceKind::Texture, id: 3 };
+        assert_eq!(texture.as_resource_ref(), Some((ResourceKind::Texture, 3)));
+        assert!(texture.can_coerce_to(ValueType::ResourceRef));
+        assert_eq!(texture.coerce_to(ValueType::Int), None);
+        assert_eq!(texture.coerce_to(ValueType::String), None);
+        assert_eq!(Value::Int(3).coerce_to(ValueType::ResourceRef), None);
+        assert!(!ValueType::ResourceRef.can_coerce_to(ValueType::Map));
+        assert!(ValueType::ResourceRef.categories().is_empty());
+        assert_eq!(texture.to_string(), "Texture#3");
+        assert_eq!(ValueType::from_name("ResourceRef"), Some(ValueType::ResourceRef));
+    }
+
+    #[test]
+    fn test_list_and_element_types() {
+        for list in [ValueType::FloatList, ValueType::ColorList, ValueType::StringList] {
+            let element = list.element_type().unwrap();
+            assert_eq!(element.list_type(), Some(list));
+            assert!(element.can_coerce_to(list));
+        }
+        assert_eq!(ValueType::Map.list_type(), None);
+        assert_eq!(ValueType::Float.element_type(), None);
+
+        let list = Value::int_list(vec![3, 4]);
+        assert_eq!(list.list_elements(), Some(vec![Value::Int(3), Value::Int(4)]));
+        assert_eq!(Value::Int(3).list_elements(), None);
+    }
+
+    #[test]
+    fn test_list_clone_shares_buffer() {
+        let original = Value::float_list(vec![0.5; 1_000_000]);
+        let mut copy = original.clone();
+        match (&original, &copy) {
+            (Value::FloatList(a), Value::FloatList(b)) => assert!(Arc::ptr_eq(a, b)),
+            _ => unreachable!(),
+        }
+
+        // Mutating a shared list copies it; the original keeps its contents
+        copy.as_float_list_mut().unwrap()[0] = 2.0;
+        assert_eq!(original.as_float_list().unwrap()[0], 0.5);
+        assert_eq!(copy.as_float_list().unwrap()[0], 2.0);
+        assert_ne!(original, copy);
+
+        // A uniquely owned list is mutated in place
+        let before = copy.as_float_list().unwrap().as_ptr();
+        copy.as_float_list_mut().unwrap()[1] = 3.0;
+        assert_eq!(copy.as_float_list().unwrap().as_ptr(), before);
+
+        let mut names = Value::string_list(vec!["a".into(), "b".into()]);
+        let shared = names.clone();
+        names.as_string_list_mut().unwrap()[1].push('!');
+        assert_eq!(shared.as_string_list().unwrap(), ["a", "b"]);
+        assert_eq!(names.as_string_list().unwrap(), ["a", "b!"]);
+        assert!(Value::Float(1.0).as_float_list_mut().is_none());
+    }
+
+    #[test]
+    fn test_list_serializes_as_plain_array() {
+        let list = Value::int_list(vec![1, 2, 3]);
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, r#"{"IntList":[1,2,3]}"#);
+        assert_eq!(serde_json::from_str::<Value>(&json).unwrap(), list);
+    }
 }
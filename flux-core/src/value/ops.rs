@@ -215,6 +215,17 @@ impl_binary_op!(Sub, sub, -);
 impl_binary_op!(Mul, mul, *);
 impl_binary_op!(Div, div, /);
 
+/// `a + (b - a) * t`, falling back to `a * (1 - t) + b * t` when the
+/// difference overflows (which would turn `t = 0` into NaN)
+fn lerp_scalar(a: f32, b: f32, t: f32) -> f32 {
+    let delta = b - a;
+    if delta.is_finite() {
+        a + delta * t
+    } else {
+        a * (1.0 - t) + b * t
+    }
+}
+
 // =============================================================================
 // Remainder (Modulo) - special handling for floats
 // =============================================================================
@@ -286,6 +297,12 @@ impl Neg for Value {
 // Additional math operations as methods
 // =============================================================================
 
+/// Clamp that tolerates inverted bounds (`lo > hi` gives `hi`), unlike `f32::clamp`
+fn clamp<T: PartialOrd>(v: T, lo: T, hi: T) -> T {
+    let v = if v < lo { lo } else { v };
+    if v > hi { hi } else { v }
+}
+
 impl Value {
     /// Power operation (self^exponent)
     pub fn pow(&self, exponent: &Value) -> Option<Value> {
@@ -461,42 +478,44 @@ impl Value {
     }
 
     /// Per-component clamp between min and max
+    ///
+    /// Never panics: where min > max the component is set to max.
     pub fn clamp_value(&self, min_val: &Value, max_val: &Value) -> Option<Value> {
         match (self, min_val, max_val) {
-            (Value::Float(v), Value::Float(lo), Value::Float(hi)) => Some(Value::Float(v.clamp(*lo, *hi))),
-            (Value::Int(v), Value::Int(lo), Value::Int(hi)) => Some(Value::Int((*v).clamp(*lo, *hi))),
+            (Value::Float(v), Value::Float(lo), Value::Float(hi)) => Some(Value::Float(clamp(*v, *lo, *hi))),
+            (Value::Int(v), Value::Int(lo), Value::Int(hi)) => Some(Value::Int(clamp(*v, *lo, *hi))),
             (Value::Vec2(v), Value::Vec2(lo), Value::Vec2(hi)) => Some(Value::Vec2([
-                v[0].clamp(lo[0], hi[0]),
-                v[1].clamp(lo[1], hi[1]),
+                clamp(v[0], lo[0], hi[0]),
+                clamp(v[1], lo[1], hi[1]),
             ])),
             (Value::Vec3(v), Value::Vec3(lo), Value::Vec3(hi)) => Some(Value::Vec3([
-                v[0].clamp(lo[0], hi[0]),
-                v[1].clamp(lo[1], hi[1]),
-                v[2].clamp(lo[2], hi[2]),
+                clamp(v[0], lo[0], hi[0]),
+                clamp(v[1], lo[1], hi[1]),
+                clamp(v[2], lo[2], hi[2]),
             ])),
             (Value::Vec4(v), Value::Vec4(lo), Value::Vec4(hi)) => Some(Value::Vec4([
-                v[0].clamp(lo[0], hi[0]),
-                v[1].clamp(lo[1], hi[1]),
-                v[2].clamp(lo[2], hi[2]),
-                v[3].clamp(lo[3], hi[3]),
+                clamp(v[0], lo[0], hi[0]),
+                clamp(v[1], lo[1], hi[1]),
+                clamp(v[2], lo[2], hi[2]),
+                clamp(v[3], lo[3], hi[3]),
             ])),
             (Value::Color(v), Value::Color(lo), Value::Color(hi)) => Some(Value::Color(Color::rgba(
-                v.r.clamp(lo.r, hi.r),
-                v.g.clamp(lo.g, hi.g),
-                v.b.clamp(lo.b, hi.b),
-                v.a.clamp(lo.a, hi.a),
+                clamp(v.r, lo.r, hi.r),
+                clamp(v.g, lo.g, hi.g),
+                clamp(v.b, lo.b, hi.b),
+                clamp(v.a, lo.a, hi.a),
             ))),
             // Scalar broadcast for min/max
             (Value::Vec3(v), Value::Float(lo), Value::Float(hi)) => Some(Value::Vec3([
-                v[0].clamp(*lo, *hi),
-                v[1].clamp(*lo, *hi),
-                v[2].clamp(*lo, *hi),
+                clamp(v[0], *lo, *hi),
+                clamp(v[1], *lo, *hi),
+                clamp(v[2], *lo, *hi),
             ])),
             (Value::Color(v), Value::Float(lo), Value::Float(hi)) => Some(Value::Color(Color::rgba(
-                v.r.clamp(*lo, *hi),
-                v.g.clamp(*lo, *hi),
-                v.b.clamp(*lo, *hi),
-                v.a.clamp(*lo, *hi),
+                clamp(v.r, *lo, *hi),
+                clamp(v.g, *lo, *hi),
+                clamp(v.b, *lo, *hi),
+                clamp(v.a, *lo, *hi),
             ))),
             _ => None,
         }
@@ -561,6 +580,8 @@ impl Value {
     // =========================================================================
 
     /// Linear interpolation: self + (other - self) * t
+    ///
+    /// Finite inputs never give NaN, even when `other - self` overflows.
     pub fn lerp(&self, other: &Value, t: &Value) -> Option<Value> {
         // Get t as a float for scalar interpolation
         let t_float = match t {
@@ -571,36 +592,38 @@ impl Value {
 
         match (self, other, t_float) {
             // Scalar t (most common)
-            (Value::Float(a), Value::Float(b), Some(t)) => Some(Value::Float(a + (b - a) * t)),
-            (Value::Int(a), Value::Int(b), Some(t)) => Some(Value::Float(*a as f32 + (*b - *a) as f32 * t)),
+            (Value::Float(a), Value::Float(b), Some(t)) => Some(Value::Float(lerp_scalar(*a, *b, t))),
+            (Value::Int(a), Value::Int(b), Some(t)) => {
+                Some(Value::Float(lerp_scalar(*a as f32, *b as f32, t)))
+            }
             (Value::Vec2(a), Value::Vec2(b), Some(t)) => Some(Value::Vec2([
-                a[0] + (b[0] - a[0]) * t,
-                a[1] + (b[1] - a[1]) * t,
+                lerp_scalar(a[0], b[0], t),
+                lerp_scalar(a[1], b[1], t),
             ])),
             (Value::Vec3(a), Value::Vec3(b), Some(t)) => Some(Value::Vec3([
-                a[0] + (b[0] - a[0]) * t,
-                a[1] + (b[1] - a[1]) * t,
-                a[2] + (b[2] - a[2]) * t,
+                lerp_scalar(a[0], b[0], t),
+                lerp_scalar(a[1], b[1], t),
+                lerp_scalar(a[2], b[2], t),
             ])),
             (Value::Vec4(a), Value::Vec4(b), Some(t)) => Some(Value::Vec4([
-                a[0] + (b[0] - a[0]) * t,
-                a[1] + (b[1] - a[1]) * t,
-                a[2] + (b[2] - a[2]) * t,
-                a[3] + (b[3] - a[3]) * t,
+                lerp_scalar(a[0], b[0], t),
+                lerp_scalar(a[1], b[1], t),
+                lerp_scalar(a[2], b[2], t),
+                lerp_scalar(a[3], b[3], t),
             ])),
             (Value::Color(a), Value::Color(b), Some(t)) => Some(Value::Color(Color::rgba(
-                a.r + (b.r - a.r) * t,
-                a.g + (b.g - a.g) * t,
-                a.b + (b.b - a.b) * t,
-                a.a + (b.a - a.a) * t,
+                lerp_scalar(a.r, b.r, t),
+                lerp_scalar(a.g, b.g, t),
+                lerp_scalar(a.b, b.b, t),
+                lerp_scalar(a.a, b.a, t),
             ))),
             _ => {
                 // Try per-component t
                 match (self, other, t) {
                     (Value::Vec3(a), Value::Vec3(b), Value::Vec3(tv)) => Some(Value::Vec3([
-                        a[0] + (b[0] - a[0]) * tv[0],
-                        a[1] + (b[1] - a[1]) * tv[1],
-                        a[2] + (b[2] - a[2]) * tv[2],
+                        lerp_scalar(a[0], b[0], tv[0]),
+                        lerp_scalar(a[1], b[1], tv[1]),
+                        lerp_scalar(a[2], b[2], tv[2]),
                     ])),
                     _ => None,
                 }
@@ -609,15 +632,23 @@ impl Value {
     }
 
     /// GLSL-style smoothstep: hermite interpolation between edge0 and edge1
+    ///
+    /// The result is always in 0..1 for finite inputs. Reversed edges give a
+    /// falling curve; equal edges give a step: 0 below the edge, 1 above it
+    /// and 0.5 exactly on it.
     pub fn smoothstep(&self, edge0: &Value, edge1: &Value) -> Option<Value> {
         fn smooth(x: f32, e0: f32, e1: f32) -> f32 {
-            let range = e1 - e0;
-            if range.abs() < f32::EPSILON {
-                if x < e0 { 0.0 } else { 1.0 }
-            } else {
-                let t = ((x - e0) / range).clamp(0.0, 1.0);
-                t * t * (3.0 - 2.0 * t)
+            // In f64 so differences of large edges can't overflow
+            let range = e1 as f64 - e0 as f64;
+            if range.abs() < f32::EPSILON as f64 {
+                return match x.partial_cmp(&e0) {
+                    Some(std::cmp::Ordering::Less) => 0.0,
+                    Some(std::cmp::Ordering::Greater) => 1.0,
+                    _ => 0.5,
+                };
             }
+            let t = ((x as f64 - e0 as f64) / range).clamp(0.0, 1.0);
+            (t * t * (3.0 - 2.0 * t)) as f32
         }
 
         match (self, edge0, edge1) {
@@ -1002,6 +1033,15 @@ mod tests {
         assert_eq!(v.clamp_value(&lo, &hi), Some(Value::Vec3([0.0, 0.5, 1.0])));
     }
 
+    #[test]
+    fn test_clamp_inverted_bounds() {
+        // min > max must not panic; the result is max
+        let v = Value::Float(0.5);
+        assert_eq!(v.clamp_value(&Value::Float(2.0), &Value::Float(1.0)), Some(Value::Float(1.0)));
+        let v = Value::Int(5);
+        assert_eq!(v.clamp_value(&Value::Int(3), &Value::Int(-3)), Some(Value::Int(-3)));
+    }
+
     #[test]
     fn test_sign_float() {
         assert_eq!(Value::Float(5.0).sign(), Some(Value::Float(1.0)));
@@ -1071,6 +1111,35 @@ mod tests {
         assert_eq!(x.smoothstep(&e0, &e1), Some(Value::Float(0.5)));
     }
 
+    #[test]
+    fn test_smoothstep_collapsed_edges() {
+        let edge = Value::Float(2.0);
+        let at = |x: f32| Value::Float(x).smoothstep(&edge, &edge);
+        assert_eq!(at(1.0), Some(Value::Float(0.0)));
+        assert_eq!(at(2.0), Some(Value::Float(0.5)));
+        assert_eq!(at(3.0), Some(Value::Float(1.0)));
+
+        // Edges far apart can't overflow into NaN
+        let (low, high) = (Value::Float(-f32::MAX), Value::Float(f32::MAX));
+        assert_eq!(Value::Float(0.0).smoothstep(&low, &high), Some(Value::Float(0.5)));
+        // Reversed edges fall
+        assert_eq!(
+            Value::Float(0.0).smoothstep(&Value::Float(1.0), &Value::Float(0.0)),
+            Some(Value::Float(1.0))
+        );
+    }
+
+    #[test]
+    fn test_lerp_never_nan_for_finite_inputs() {
+        let (a, b) = (Value::Float(-f32::MAX), Value::Float(f32::MAX));
+        assert_eq!(a.lerp(&b, &Value::Float(0.0)), Some(a.clone()));
+        assert_eq!(a.lerp(&b, &Value::Float(1.0)), Some(b.clone()));
+        assert_eq!(a.lerp(&b, &Value::Float(0.5)), Some(Value::Float(0.0)));
+        // Int differences that don't fit an i32 are fine too
+        let ints = Value::Int(i32::MIN).lerp(&Value::Int(i32::MAX), &Value::Float(0.5));
+        assert!(ints.unwrap().as_float().unwrap().abs() <= 1.0);
+    }
+
     #[test]
     fn test_smoothstep_vec3() {
         let x = Value::Vec3([0.0, 0.5, 1.0]);
@@ -73,6 +73,42 @@ pub enum LoopMode {
     Loop,
     /// Ping-pong between start and end
     PingPong,
+    /// Play once and hold the last frame, staying in the playing state
+    Hold,
+}
+
+/// Direction the playhead is moving in
+///
+/// Only [`LoopMode::PingPong`] ever plays in reverse.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PlaybackDirection {
+    #[default]
+    Forward,
+    Reverse,
+}
+
+/// What happened during a single [`Animator::advance`] call
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AnimatorTick {
+    /// Playback time after the tick
+    pub time: f64,
+    /// Number of times the playhead wrapped around the loop range
+    ///
+    /// In `Loop` mode this counts jumps back to the start; in `PingPong`
+    /// mode it counts reversals at either end. Can exceed one when the
+    /// delta is longer than the loop.
+    pub wraps: u32,
+    /// Playback direction after the tick
+    pub direction: PlaybackDirection,
+    /// Whether playback reached the end of a `Once` or `Hold` range this tick
+    pub finished: bool,
+}
+
+impl AnimatorTick {
+    /// Whether at least one loop wrap occurred
+    pub fn wrapped(&self) -> bool {
+        self.wraps > 0
+    }
 }
 
 /// The Animator manages animation curves and their playback
@@ -99,6 +135,12 @@ pub struct Animator {
     start_time: f64,
     /// End time for playback range
     end_time: f64,
+    /// Current playback direction (ping-pong only)
+    #[serde(default)]
+    direction: PlaybackDirection,
+    /// Whether the end of the range was already reported in `Hold` mode
+    #[serde(default)]
+    finished: bool,
 }
 
 impl Animator {
@@ -113,6 +155,8 @@ impl Animator {
             speed: 1.0,
             start_time: 0.0,
             end_time: 1.0,
+            direction: PlaybackDirection::Forward,
+            finished: false,
         }
     }
 
@@ -166,6 +210,12 @@ impl Animator {
         }
     }
 
+    /// Remove all curve bindings, keeping the playback settings
+    pub fn clear_bindings(&mut self) {
+        self.bindings.clear();
+        self.target_index.clear();
+    }
+
     /// Get all bindings
     pub fn bindings(&self) -> &[CurveBinding] {
         &self.bindings
@@ -192,8 +242,35 @@ impl Animator {
     }
 
     /// Set the current time directly
+    ///
+    /// Times outside the range are mapped the same way [`advance`](Self::advance)
+    /// would reach them from the start: wrapped in `Loop` mode, reflected in
+    /// `PingPong` mode (which also sets the direction) and clamped otherwise.
     pub fn set_time(&mut self, time: f64) {
-        self.current_time = time.clamp(self.start_time, self.end_time);
+        let duration = self.end_time - self.start_time;
+        self.finished = false;
+        self.direction = PlaybackDirection::Forward;
+
+        self.current_time = match self.loop_mode {
+            LoopMode::Loop if duration > 0.0 => {
+                self.start_time + (time - self.start_time).rem_euclid(duration)
+            }
+            LoopMode::PingPong if duration > 0.0 => {
+                let phase = (time - self.start_time).rem_euclid(2.0 * duration);
+                if phase < duration {
+                    self.start_time + phase
+                } else {
+                    self.direction = PlaybackDirection::Reverse;
+                    self.end_time - (phase - duration)
+                }
+            }
+            _ => time.clamp(self.start_time, self.end_time),
+        };
+    }
+
+    /// Get the current playback direction
+    pub fn direction(&self) -> PlaybackDirection {
+        self.direction
     }
 
     /// Get the playback state
@@ -220,6 +297,8 @@ impl Animator {
     pub fn stop(&mut self) {
         self.state = PlaybackState::Stopped;
         self.current_time = self.start_time;
+        self.direction = PlaybackDirection::Forward;
+        self.finished = false;
     }
 
     /// Toggle between play and pause
@@ -231,8 +310,13 @@ impl Animator {
     }
 
     /// Set the loop mode
+    ///
+    /// The current time is kept, so switching modes mid-playback doesn't jump.
     pub fn set_loop_mode(&mut self, mode: LoopMode) {
         self.loop_mode = mode;
+        if mode != LoopMode::PingPong {
+            self.direction = PlaybackDirection::Forward;
+        }
     }
 
     /// Get the loop mode
@@ -251,9 +335,17 @@ impl Animator {
     }
 
     /// Set the playback range
+    ///
+    /// The current time is kept if it lies inside the new range and mapped
+    /// into it like [`set_time`](Self::set_time) otherwise.
     pub fn set_range(&mut self, start: f64, end: f64) {
         self.start_time = start;
         self.end_time = end.max(start);
+        if !(self.start_time..=self.end_time).contains(&self.current_time) {
+            self.set_time(self.current_time);
+        } else if self.current_time < self.end_time {
+            self.finished = false;
+        }
     }
 
     /// Get the playback range
@@ -262,67 +354,133 @@ impl Animator {
     }
 
     /// Advance the playback time by delta (in seconds or bars)
-    pub fn advance(&mut self, delta: f64) {
+    ///
+    /// Returns what happened during the tick: the new time, how often the
+    /// playhead wrapped, the direction and whether playback finished.
+    pub fn advance(&mut self, delta: f64) -> AnimatorTick {
+        let mut tick = AnimatorTick {
+            time: self.current_time,
+            wraps: 0,
+            direction: self.direction,
+            finished: false,
+        };
         if self.state != PlaybackState::Playing {
-            return;
+            return tick;
         }
 
-        let adjusted_delta = delta * self.speed;
-        self.current_time += adjusted_delta;
-
-        // Handle looping
+        let delta = delta * self.speed;
         let duration = self.end_time - self.start_time;
-        if duration <= 0.0 {
-            return;
-        }
 
         match self.loop_mode {
-            LoopMode::Once => {
-                if self.current_time >= self.end_time {
+            LoopMode::Once | LoopMode::Hold => {
+                let time = (self.current_time + delta).max(self.start_time);
+                if time >= self.end_time {
                     self.current_time = self.end_time;
-                    self.state = PlaybackState::Stopped;
+                    if self.loop_mode == LoopMode::Once {
+                        self.state = PlaybackState::Stopped;
+                        tick.finished = true;
+                    } else {
+                        tick.finished = !self.finished;
+                        self.finished = true;
+                    }
+                } else {
+                    self.current_time = time;
                 }
             }
+            LoopMode::Loop | LoopMode::PingPong if duration <= 0.0 => {
+                self.current_time = self.start_time;
+            }
             LoopMode::Loop => {
-                while self.current_time >= self.end_time {
-                    self.current_time -= duration;
-                }
+                let offset = self.current_time - self.start_time + delta;
+                let wraps = (offset / duration).floor();
+                self.current_time = self.start_time + offset.rem_euclid(duration);
+                tick.wraps = wraps.abs() as u32;
             }
             LoopMode::PingPong => {
-                // Count how many times we've crossed the boundary
-                let cycles = ((self.current_time - self.start_time) / duration).floor() as i32;
-                let is_reversed = cycles % 2 != 0;
-
-                // Get position within current cycle
-                let pos_in_cycle = (self.current_time - self.start_time) % duration;
+                // Distance travelled along the current leg
+                let leg = match self.direction {
+                    PlaybackDirection::Forward => self.current_time - self.start_time,
+                    PlaybackDirection::Reverse => self.end_time - self.current_time,
+                } + delta;
+                let bounces = (leg / duration).floor() as i64;
+                if bounces.rem_euclid(2) == 1 {
+                    self.direction = match self.direction {
+                        PlaybackDirection::Forward => PlaybackDirection::Reverse,
+                        PlaybackDirection::Reverse => PlaybackDirection::Forward,
+                    };
+                }
 
-                self.current_time = if is_reversed {
-                    self.end_time - pos_in_cycle
-                } else {
-                    self.start_time + pos_in_cycle
+                let leg = leg.rem_euclid(duration);
+                self.current_time = match self.direction {
+                    PlaybackDirection::Forward => self.start_time + leg,
+                    PlaybackDirection::Reverse => self.end_time - leg,
                 };
+                tick.wraps = bounces.unsigned_abs() as u32;
             }
         }
+
+        tick.time = self.current_time;
+        tick.direction = self.direction;
+        tick
     }
 
     // ========== Sampling ==========
 
     /// Sample all curves at the current time and return values by target
     pub fn sample_all(&mut self) -> Vec<(AnimationTarget, f64)> {
-        let time = self.current_time;
-        self.bindings
-            .iter_mut()
-            .filter(|b| b.enabled)
-            .map(|b| (b.target.clone(), b.curve.sample(time)))
-            .collect()
+        self.sample_all_at(self.current_time)
     }
 
     /// Sample all curves at a specific time
     pub fn sample_all_at(&mut self, time: f64) -> Vec<(AnimationTarget, f64)> {
+        let mut values = Vec::with_capacity(self.bindings.len());
+        values.extend(self.sample_iter(time));
+        values
+    }
+
+    /// Lazily sample all enabled curves at a specific time
+    ///
+    /// Does not allocate, which makes it the cheapest way to push animated
+    /// values into a graph every frame.
+    pub fn sample_iter(&mut self, time: f64) -> impl Iterator<Item = (AnimationTarget, f64)> + '_ {
         self.bindings
             .iter_mut()
             .filter(|b| b.enabled)
-            .map(|b| (b.target.clone(), b.curve.sample(time)))
+            .map(move |b| (b.target.clone(), b.curve.sample(time)))
+    }
+
+    /// Lazily sample the enabled curves that may differ from `previous`
+    ///
+    /// Curves that are constant between the two times (see
+    /// [`Curve::is_constant_between`]) are left out, so a host that applied
+    /// the values at `previous` can skip rewriting, and invalidating, their
+    /// inputs. Without a previous time every enabled curve is sampled, as
+    /// with [`sample_iter`](Self::sample_iter).
+    pub fn sample_changed(
+        &mut self,
+        previous: Option<f64>,
+        time: f64,
+    ) -> impl Iterator<Item = (AnimationTarget, f64)> + '_ {
+        self.bindings.iter_mut().filter_map(move |b| {
+            let unchanged = previous.is_some_and(|prev| b.curve.is_constant_between(prev, time));
+            (b.enabled && !unchanged).then(|| (b.target.clone(), b.curve.sample(time)))
+        })
+    }
+
+    /// Enabled bindings whose value can differ between two times
+    ///
+    /// Lets hosts limit re-evaluation to the inputs that can change, e.g.
+    /// when scrubbing or rendering a range.
+    pub fn changed_bindings(&mut self, prev_time: f64, new_time: f64) -> Vec<&CurveBinding> {
+        let changed: Vec<bool> = self
+            .bindings
+            .iter_mut()
+            .map(|b| b.enabled && !b.curve.is_constant_between(prev_time, new_time))
+            .collect();
+        self.bindings
+            .iter()
+            .zip(changed)
+            .filter_map(|(binding, changed)| changed.then_some(binding))
             .collect()
     }
 
@@ -344,10 +502,8 @@ impl Animator {
     /// Get sampled values as a map of target -> Value
     pub fn get_animated_values(&mut self) -> HashMap<AnimationTarget, Value> {
         let time = self.current_time;
-        self.bindings
-            .iter_mut()
-            .filter(|b| b.enabled)
-            .map(|b| (b.target.clone(), Value::Float(b.curve.sample(time) as f32)))
+        self.sample_iter(time)
+            .map(|(target, value)| (target, Value::Float(value as f32)))
             .collect()
     }
 }
@@ -553,4 +709,180 @@ mod tests {
 
         assert_eq!(values.len(), 2);
     }
+
+    #[test]
+    fn test_sample_iter_skips_disabled() {
+        let node = make_test_node_id();
+        let mut animator = Animator::new();
+        for (input, end) in [(0, 100.0), (1, 20.0)] {
+            let curve = CurveBuilder::new().keyframe(0.0, 0.0).keyframe(1.0, end).build();
+            animator.add_curve(curve, node, input);
+        }
+        animator.get_binding_mut(node, 1).unwrap().enabled = false;
+
+        let values: Vec<_> = animator.sample_iter(0.25).collect();
+        assert_eq!(values, vec![(AnimationTarget::new(node, 0), 25.0)]);
+        assert_eq!(animator.sample_all_at(0.25), values);
+    }
+
+    #[test]
+    fn test_changed_bindings() {
+        let node = make_test_node_id();
+        let mut animator = Animator::with_range(0.0, 10.0);
+        let plateau = CurveBuilder::new()
+            .keyframe(0.0, 0.0)
+            .keyframe(2.0, 1.0)
+            .keyframe(5.0, 1.0)
+            .keyframe(7.0, 3.0)
+            .build();
+        animator.add_curve(plateau, node, 0);
+        let ramp = CurveBuilder::new()
+            .keyframe(0.0, 0.0)
+            .keyframe(10.0, 10.0)
+            .build();
+        animator.add_curve(ramp, node, 1);
+
+        let inputs = |bindings: Vec<&CurveBinding>| -> Vec<usize> {
+            bindings.iter().map(|b| b.target.input_index).collect()
+        };
+        assert_eq!(inputs(animator.changed_bindings(2.5, 4.0)), [1]);
+        assert_eq!(inputs(animator.changed_bindings(4.0, 6.0)), [0, 1]);
+        assert!(animator.changed_bindings(3.0, 3.0).is_empty());
+
+        let sampled: Vec<_> = animator.sample_changed(Some(2.5), 4.0).collect();
+        assert_eq!(sampled, [(AnimationTarget::new(node, 1), 4.0)]);
+        assert_eq!(animator.sample_changed(None, 4.0).count(), 2);
+
+        animator.get_binding_mut(node, 1).unwrap().enabled = false;
+        assert!(animator.changed_bindings(2.5, 4.0).is_empty());
+    }
+
+    /// Animator over 0..1 with a 0 -> 10 ramp bound to input 0
+    fn ramp_animator(mode: LoopMode) -> (Animator, Id) {
+        let node = make_test_node_id();
+        let mut animator = AnimatorBuilder::new()
+            .range(0.0, 1.0)
+            .loop_mode(mode)
+            .curve(CurveBuilder::new().keyframe(0.0, 0.0).keyframe(1.0, 10.0).build(), node, 0)
+            .build();
+        animator.play();
+        (animator, node)
+    }
+
+    #[test]
+    fn test_tick_loop_wraps() {
+        let (mut animator, node) = ramp_animator(LoopMode::Loop);
+        let expected = [
+            (0.375, 0),
+            (0.75, 0),
+            (0.125, 1),
+            (0.5, 0),
+            (0.875, 0),
+            (0.25, 1),
+            (0.625, 0),
+            (0.0, 1),
+        ];
+        for (time, wraps) in expected {
+            let tick = animator.advance(0.375);
+            assert_eq!((tick.time, tick.wraps), (time, wraps));
+            assert_eq!(tick.direction, PlaybackDirection::Forward);
+            assert!(!tick.finished);
+            assert_eq!(animator.sample(node, 0), Some(time * 10.0));
+        }
+
+        // A delta longer than the loop reports every wrap
+        let tick = animator.advance(2.5);
+        assert_eq!((tick.time, tick.wraps), (0.5, 2));
+        assert!(tick.wrapped());
+    }
+
+    #[test]
+    fn test_tick_ping_pong_directions() {
+        use PlaybackDirection::{Forward, Reverse};
+
+        let (mut animator, node) = ramp_animator(LoopMode::PingPong);
+        let expected = [
+            (0.375, Forward, 0),
+            (0.75, Forward, 0),
+            (0.875, Reverse, 1),
+            (0.5, Reverse, 0),
+            (0.125, Reverse, 0),
+            (0.25, Forward, 1),
+            (0.625, Forward, 0),
+            (1.0, Reverse, 1),
+        ];
+        for (time, direction, wraps) in expected {
+            let tick = animator.advance(0.375);
+            assert_eq!((tick.time, tick.direction, tick.wraps), (time, direction, wraps));
+            assert_eq!(animator.sample(node, 0), Some(time * 10.0));
+        }
+        assert_eq!(animator.direction(), Reverse);
+
+        // Two reversals land back on the same direction
+        let tick = animator.advance(2.25);
+        assert_eq!((tick.time, tick.direction, tick.wraps), (0.75, Reverse, 2));
+    }
+
+    #[test]
+    fn test_set_time_matches_advance() {
+        for mode in [LoopMode::Loop, LoopMode::PingPong, LoopMode::Hold] {
+            for time in [0.25, 1.0, 1.5, 2.0, 2.75, 5.125] {
+                let (mut advanced, _) = ramp_animator(mode);
+                advanced.advance(time);
+
+                let (mut scrubbed, _) = ramp_animator(mode);
+                scrubbed.set_time(time);
+
+                assert_eq!(scrubbed.current_time(), advanced.current_time(), "{mode:?} @ {time}");
+                assert_eq!(scrubbed.direction(), advanced.direction(), "{mode:?} @ {time}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_hold_reports_finished_once() {
+        let (mut animator, node) = ramp_animator(LoopMode::Hold);
+
+        assert!(!animator.advance(0.75).finished);
+        let tick = animator.advance(0.75);
+        assert!(tick.finished);
+        assert_eq!(tick.time, 1.0);
+
+        let tick = animator.advance(0.75);
+        assert!(!tick.finished);
+        assert_eq!(tick.time, 1.0);
+        assert!(animator.is_playing());
+        assert_eq!(animator.sample(node, 0), Some(10.0));
+
+        // Scrubbing back re-arms the end
+        animator.set_time(0.5);
+        assert!(animator.advance(0.5).finished);
+    }
+
+    #[test]
+    fn test_once_reports_finished_and_stops() {
+        let (mut animator, _) = ramp_animator(LoopMode::Once);
+        assert!(animator.advance(1.5).finished);
+        assert_eq!(animator.state(), PlaybackState::Stopped);
+        assert!(!animator.advance(0.5).finished);
+    }
+
+    #[test]
+    fn test_mode_and_range_changes_keep_time() {
+        let (mut animator, _) = ramp_animator(LoopMode::PingPong);
+        animator.advance(1.5);
+        assert_eq!(animator.current_time(), 0.5);
+        assert_eq!(animator.direction(), PlaybackDirection::Reverse);
+
+        // Switching modes keeps the playhead where it is
+        animator.set_loop_mode(LoopMode::Loop);
+        assert_eq!(animator.current_time(), 0.5);
+        assert_eq!(animator.advance(0.25).time, 0.75);
+
+        // Growing the range keeps the time, shrinking it wraps only as needed
+        animator.set_range(0.0, 2.0);
+        assert_eq!(animator.current_time(), 0.75);
+        animator.set_range(0.0, 0.5);
+        assert_eq!(animator.current_time(), 0.25);
+    }
 }
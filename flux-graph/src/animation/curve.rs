@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use serde::{Deserialize, Serialize};
 
 use super::{Interpolation, Keyframe};
@@ -7,6 +9,10 @@ use super::{Interpolation, Keyframe};
 /// The curve stores a sorted list of keyframes and provides methods for
 /// sampling values at any point in time. Keyframes are automatically
 /// sorted by time when sampling.
+///
+/// Sampling remembers the segment it last landed in, so playback that moves
+/// forward (or stays put) resolves the surrounding keyframes in constant time.
+/// Jumps fall back to a binary search.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct Curve {
     /// The keyframes in this curve
@@ -16,6 +22,9 @@ pub struct Curve {
     /// Optional name for the curve
     #[serde(default)]
     pub name: Option<String>,
+    /// Index of the segment hit by the last sample (a hint, validated on use)
+    #[serde(skip)]
+    cursor: usize,
 }
 
 impl Curve {
@@ -25,6 +34,7 @@ impl Curve {
             keyframes: Vec::new(),
             sorted: true,
             name: None,
+            cursor: 0,
         }
     }
 
@@ -34,6 +44,7 @@ impl Curve {
             keyframes: Vec::new(),
             sorted: true,
             name: Some(name.into()),
+            cursor: 0,
         }
     }
 
@@ -43,6 +54,7 @@ impl Curve {
             keyframes,
             sorted: false,
             name: None,
+            cursor: 0,
         }
     }
 
@@ -168,7 +180,8 @@ impl Curve {
         }
 
         // Find the two keyframes to interpolate between
-        let (k0, k1) = self.find_surrounding_keyframes(time);
+        let index = self.segment_index(time);
+        let (k0, k1) = (&self.keyframes[index], &self.keyframes[index + 1]);
 
         // Calculate interpolation parameter
         let dt = k1.time - k0.time;
@@ -182,17 +195,30 @@ impl Curve {
         self.interpolate_between(k0, k1, t)
     }
 
-    /// Find the two keyframes surrounding the given time
-    /// Assumes keyframes are sorted and time is within range
-    fn find_surrounding_keyframes(&self, time: f64) -> (&Keyframe, &Keyframe) {
-        for i in 0..self.keyframes.len() - 1 {
-            if time >= self.keyframes[i].time && time < self.keyframes[i + 1].time {
-                return (&self.keyframes[i], &self.keyframes[i + 1]);
-            }
-        }
-        // Shouldn't reach here if time is properly within range
-        let len = self.keyframes.len();
-        (&self.keyframes[len - 2], &self.keyframes[len - 1])
+    /// Find the index of the keyframe starting the segment that contains `time`
+    ///
+    /// Assumes keyframes are sorted, there are at least two of them and time
+    /// is within range. Checks the cached segment and its successor before
+    /// falling back to a binary search.
+    fn segment_index(&mut self, time: f64) -> usize {
+        let last = self.keyframes.len() - 2;
+        let cursor = self.cursor.min(last);
+        let contains = |i: usize| {
+            time >= self.keyframes[i].time && time < self.keyframes[i + 1].time
+        };
+
+        let index = if contains(cursor) {
+            cursor
+        } else if cursor < last && contains(cursor + 1) {
+            cursor + 1
+        } else {
+            self.keyframes
+                .partition_point(|k| k.time <= time)
+                .saturating_sub(1)
+                .min(last)
+        };
+        self.cursor = index;
+        index
     }
 
     /// Interpolate between two keyframes
@@ -211,6 +237,86 @@ impl Curve {
         }
     }
 
+    /// Time ranges over which the sampled value cannot change
+    ///
+    /// Ranges are half-open, sorted and don't overlap. The hold before the
+    /// first keyframe starts at negative infinity and the hold after the
+    /// last ends at positive infinity. A segment is constant if it steps
+    /// (`Constant` outgoing interpolation) or joins two equal values
+    /// linearly or with flat spline tangents; neighbouring constant regions
+    /// with the same value are merged. An empty curve is constant
+    /// everywhere.
+    pub fn constant_ranges(&mut self) -> Vec<Range<f64>> {
+        if self.keyframes.is_empty() {
+            return vec![f64::NEG_INFINITY..f64::INFINITY];
+        }
+        self.ensure_sorted();
+        self.merged_constant_ranges().collect()
+    }
+
+    /// Check whether the curve samples the same value at both times
+    ///
+    /// True if one of the [`constant_ranges`](Self::constant_ranges)
+    /// contains both times. Doesn't allocate.
+    pub fn is_constant_between(&mut self, a: f64, b: f64) -> bool {
+        if self.keyframes.is_empty() || a == b {
+            return true;
+        }
+        self.ensure_sorted();
+        let (lo, hi) = (a.min(b), a.max(b));
+        self.merged_constant_ranges()
+            .any(|range| range.contains(&lo) && range.contains(&hi))
+    }
+
+    /// Constant ranges with their values, unmerged
+    ///
+    /// Assumes keyframes are sorted and there is at least one.
+    fn constant_pieces(&self) -> impl Iterator<Item = (Range<f64>, f64)> + '_ {
+        let first = &self.keyframes[0];
+        let last = &self.keyframes[self.keyframes.len() - 1];
+        let segments = self
+            .keyframes
+            .windows(2)
+            .filter(|pair| Self::segment_is_constant(&pair[0], &pair[1]))
+            .map(|pair| (pair[0].time..pair[1].time, pair[0].value));
+        std::iter::once((f64::NEG_INFINITY..first.time, first.value))
+            .chain(segments)
+            .chain(std::iter::once((last.time..f64::INFINITY, last.value)))
+    }
+
+    fn merged_constant_ranges(&self) -> impl Iterator<Item = Range<f64>> + '_ {
+        let mut pieces = self.constant_pieces().peekable();
+        std::iter::from_fn(move || {
+            let (mut range, value) = pieces.next()?;
+            while let Some((next, _)) =
+                pieces.next_if(|(next, v)| next.start == range.end && *v == value)
+            {
+                range.end = next.end;
+            }
+            Some(range)
+        })
+        .filter(|range| !range.is_empty())
+    }
+
+    /// Whether the value can't change between two adjacent keyframes
+    fn segment_is_constant(k0: &Keyframe, k1: &Keyframe) -> bool {
+        match k0.out_type {
+            Interpolation::Constant => true,
+            Interpolation::Linear => k0.value == k1.value,
+            Interpolation::Spline => {
+                k0.value == k1.value && k0.out_tangent == 0.0 && k1.in_tangent == 0.0
+            }
+        }
+    }
+
+    /// Sample the curve at each of the given times
+    ///
+    /// Equivalent to calling [`sample`](Self::sample) for every time.
+    /// Ordered times make the best use of the segment cache.
+    pub fn sample_many(&mut self, times: &[f64]) -> Vec<f64> {
+        times.iter().map(|&time| self.sample(time)).collect()
+    }
+
     /// Sample multiple points along the curve (for visualization)
     pub fn sample_range(&mut self, start: f64, end: f64, num_samples: usize) -> Vec<(f64, f64)> {
         if num_samples < 2 {
@@ -413,6 +519,35 @@ mod tests {
         assert_eq!(samples[4], (1.0, 10.0));
     }
 
+    #[test]
+    fn test_constant_ranges() {
+        let mut curve = Curve::new();
+        curve.add(0.0, 0.0);
+        curve.add(2.0, 1.0);
+        curve.add(5.0, 1.0);
+        curve.add_constant(7.0, 3.0);
+        curve.add_spline(8.0, 2.0, 0.0, 0.0);
+        curve.add_spline(9.0, 2.0, 0.0, 0.0);
+        curve.add(10.0, 4.0);
+
+        let inf = f64::INFINITY;
+        assert_eq!(
+            curve.constant_ranges(),
+            [-inf..0.0, 2.0..5.0, 7.0..8.0, 8.0..9.0, 10.0..inf]
+        );
+        assert!(curve.is_constant_between(2.5, 4.9));
+        assert!(curve.is_constant_between(7.5, 7.0));
+        assert!(!curve.is_constant_between(4.0, 6.0));
+        assert!(!curve.is_constant_between(7.5, 8.5));
+        assert!(curve.is_constant_between(-3.0, -1.0));
+
+        // A single keyframe, or none, holds everywhere
+        let mut single = Curve::new();
+        assert_eq!(single.constant_ranges(), [-inf..inf]);
+        single.add(1.0, 5.0);
+        assert_eq!(single.constant_ranges(), [-inf..inf]);
+    }
+
     #[test]
     fn test_remove_keyframe() {
         let mut curve = Curve::new();
@@ -425,4 +560,97 @@ mod tests {
         assert_eq!(removed.unwrap().value, 10.0);
         assert_eq!(curve.len(), 2);
     }
+
+    /// Curve with unevenly spaced keyframes of every interpolation type
+    fn mixed_curve(count: usize) -> Curve {
+        let mut curve = Curve::new();
+        for i in 0..count {
+            let time = i as f64 + (i % 3) as f64 * 0.25;
+            let value = (i as f64 * 0.7).sin() * 10.0;
+            match i % 3 {
+                0 => curve.add(time, value),
+                1 => curve.add_constant(time, value),
+                _ => curve.add_spline(time, value, 0.5, -0.5),
+            }
+        }
+        curve.auto_tangents();
+        curve
+    }
+
+    /// Uncached sampling with a linear segment scan
+    fn reference_sample(curve: &Curve, time: f64) -> f64 {
+        let keys = curve.keyframes();
+        let (first, last) = (&keys[0], &keys[keys.len() - 1]);
+        if time <= first.time {
+            return first.value;
+        }
+        if time >= last.time {
+            return last.value;
+        }
+        let i = (0..keys.len() - 1)
+            .find(|&i| time >= keys[i].time && time < keys[i + 1].time)
+            .unwrap();
+        let t = (time - keys[i].time) / (keys[i + 1].time - keys[i].time);
+        curve.interpolate_between(&keys[i], &keys[i + 1], t)
+    }
+
+    fn sample_times(curve: &mut Curve, times: &[f64]) {
+        let reference = curve.clone();
+        for &time in times {
+            assert_eq!(curve.sample(time), reference_sample(&reference, time), "t = {time}");
+        }
+        assert_eq!(
+            curve.sample_many(times),
+            times.iter().map(|&t| reference_sample(&reference, t)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_cached_sampling_forward() {
+        let mut curve = mixed_curve(50);
+        let times: Vec<f64> = (0..600).map(|i| i as f64 * 0.1 - 2.0).collect();
+        sample_times(&mut curve, &times);
+    }
+
+    #[test]
+    fn test_cached_sampling_backward() {
+        let mut curve = mixed_curve(50);
+        let times: Vec<f64> = (0..600).rev().map(|i| i as f64 * 0.1 - 2.0).collect();
+        sample_times(&mut curve, &times);
+    }
+
+    #[test]
+    fn test_cached_sampling_random_access() {
+        let mut curve = mixed_curve(50);
+        // Deterministic pseudo-random times, including keyframe times exactly
+        let mut state = 0x2545_f491_u64;
+        let times: Vec<f64> = (0..1000)
+            .map(|i| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                if i % 10 == 0 {
+                    curve.keyframes()[(state % 50) as usize].time
+                } else {
+                    (state % 60_000) as f64 / 1000.0 - 2.0
+                }
+            })
+            .collect();
+        sample_times(&mut curve, &times);
+    }
+
+    #[test]
+    fn test_cached_sampling_after_edit() {
+        let mut curve = mixed_curve(10);
+        assert_eq!(curve.sample(8.5), reference_sample(&curve, 8.5));
+
+        // Removing keyframes leaves the cursor past the end
+        for time in [9.0, 8.5, 7.25] {
+            curve.remove_keyframe_at(time);
+        }
+        assert_eq!(curve.sample(4.1), reference_sample(&curve, 4.1));
+
+        curve.add(2.1, 100.0);
+        assert_eq!(curve.sample(2.1), 100.0);
+    }
 }
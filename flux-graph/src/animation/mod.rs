@@ -38,7 +38,10 @@ mod curve;
 mod interpolation;
 mod keyframe;
 
-pub use animator::{AnimationTarget, Animator, AnimatorBuilder, CurveBinding, LoopMode, PlaybackState};
+pub use animator::{
+    AnimationTarget, Animator, AnimatorBuilder, AnimatorTick, CurveBinding, LoopMode,
+    PlaybackDirection, PlaybackState,
+};
 pub use curve::{Curve, CurveBuilder};
 pub use interpolation::Interpolation;
 pub use keyframe::Keyframe;
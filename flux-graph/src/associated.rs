@@ -37,6 +37,7 @@ use flux_core::id::Id;
 use flux_core::operator::Operator;
 use flux_core::value::Value;
 
+use crate::export::ExportOptions;
 use crate::graph::{Connection, Graph, GraphError, GraphEvent, GraphStats};
 
 /// A handle that combines both flux and external IDs.
@@ -354,6 +355,32 @@ impl<E: Copy + Eq + Hash> AssociatedGraph<E> {
         self.inner.stats()
     }
 
+    // =========================================================================
+    // Export
+    // =========================================================================
+
+    /// Export as Graphviz DOT, labeling each node with its external ID.
+    ///
+    /// Labels already present in `options` take precedence.
+    pub fn to_dot(&self, options: &ExportOptions) -> String
+    where
+        E: std::fmt::Debug,
+    {
+        let mut options = options.clone();
+        for (&flux_id, external_id) in &self.flux_to_external {
+            options
+                .labels
+                .entry(flux_id)
+                .or_insert_with(|| format!("{:?}", external_id));
+        }
+        self.inner.to_dot(&options)
+    }
+
+    /// Export as a Mermaid flowchart.
+    pub fn to_mermaid(&self) -> String {
+        self.inner.to_mermaid()
+    }
+
     // =========================================================================
     // Evaluation
     // =========================================================================
@@ -596,4 +623,17 @@ mod tests {
             _ => panic!("Expected NodeAdded event"),
         }
     }
+
+    #[test]
+    fn test_to_dot_labels_external_ids() {
+        let mut graph: AssociatedGraph<u32> = AssociatedGraph::new();
+        graph.add_with_external(TestOp::source(), 7);
+        let h2 = graph.add_with_external(TestOp::source(), 8);
+
+        let options = ExportOptions::new().with_label(h2.flux_id, "custom");
+        let dot = graph.to_dot(&options);
+        assert!(dot.contains("\\n7\""));
+        assert!(dot.contains("\\ncustom\""));
+        assert!(!dot.contains("\\n8\""));
+    }
 }
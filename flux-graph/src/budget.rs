@@ -0,0 +1,86 @@
+//! Time-sliced graph evaluation
+//!
+//! Occasionally a single node (e.g. generating a very large list after a
+//! parameter change) takes longer than a frame. [`Graph::evaluate_budgeted`]
+//! computes nodes in topological order until a time budget is spent, then
+//! returns [`EvalProgress::Partial`]. Calling it again resumes from where the
+//! previous slice stopped, reusing the values already cached.
+//!
+//! Slices belong to one logical evaluation: nodes computed in an earlier
+//! slice (including time-varying ones) are not recomputed when resuming. If
+//! the graph structure or cached values change between slices, the pending
+//! evaluation is discarded and the next call starts over.
+//!
+//! [`Graph::evaluate_budgeted`]: crate::Graph::evaluate_budgeted
+
+use std::collections::HashSet;
+
+use flux_core::context::CallContext;
+use flux_core::{Id, Value};
+
+/// Result of a single budgeted evaluation slice.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EvalProgress {
+    /// Evaluation finished; holds the requested output value
+    Complete(Value),
+    /// The budget ran out before all nodes were visited
+    Partial {
+        /// Nodes visited so far in this logical evaluation
+        completed: usize,
+        /// Nodes still to visit
+        remaining: usize,
+    },
+}
+
+impl EvalProgress {
+    /// Returns true if evaluation finished.
+    pub fn is_complete(&self) -> bool {
+        matches!(self, Self::Complete(_))
+    }
+
+    /// The output value, if evaluation finished.
+    pub fn value(&self) -> Option<&Value> {
+        match self {
+            Self::Complete(value) => Some(value),
+            Self::Partial { .. } => None,
+        }
+    }
+}
+
+/// State of an evaluation that has been split across slices.
+#[derive(Debug)]
+pub(crate) struct BudgetedEval {
+    /// Requested output node
+    pub(crate) output_node: Id,
+    /// Requested output index
+    pub(crate) output_index: usize,
+    /// Call context the evaluation runs in
+    pub(crate) call_context: CallContext,
+    /// Graph generation when the evaluation started
+    pub(crate) generation: u64,
+    /// Evaluation order snapshot
+    pub(crate) order: Vec<Id>,
+    /// Index of the next node in `order` to visit
+    pub(crate) cursor: usize,
+    /// Nodes computed so far in this logical evaluation
+    pub(crate) computed_nodes: HashSet<Id>,
+    /// Nodes in branches no consumer reads, worked out when the evaluation
+    /// started
+    pub(crate) inactive: HashSet<Id>,
+}
+
+impl BudgetedEval {
+    /// Returns true if this state continues an evaluation of the same output.
+    pub(crate) fn resumes(
+        &self,
+        output_node: Id,
+        output_index: usize,
+        call_context: CallContext,
+        generation: u64,
+    ) -> bool {
+        self.output_node == output_node
+            && self.output_index == output_index
+            && self.call_context == call_context
+            && self.generation == generation
+    }
+}
@@ -0,0 +1,468 @@
+//! Fluent graph construction by operator and port name
+//!
+//! Building a graph by hand means creating each operator, remembering its ID
+//! and connecting ports by index. [`GraphBuilder`] instead records nodes by
+//! registry name and wires by port name, and resolves everything in
+//! [`build`](GraphBuilder::build), which reports the first unknown operator,
+//! unknown port, unusable input value or rejected connection.
+//!
+//! ```
+//! use flux_core::EvalContext;
+//! use flux_graph::GraphBuilder;
+//! use flux_operators::create_default_registry;
+//!
+//! let registry = create_default_registry();
+//! let b = GraphBuilder::new(&registry);
+//! let time = b.node("Time");
+//! let sine = b.node_with("SineWave", |n| n.input("Frequency", 2.0));
+//! b.wire(time.out("Time"), sine.input_ref("Phase"));
+//!
+//! let (mut graph, handles) = b.build().unwrap();
+//! let output = handles.output(&sine.out("Value")).unwrap();
+//! let value = graph.evaluate_ref(output, &EvalContext::new()).unwrap();
+//! assert_eq!(value.as_float(), Some(0.0));
+//! ```
+
+use std::cell::RefCell;
+
+use flux_core::id::{InputRef, OutputRef};
+use flux_core::{Id, Operator, Value, ValueType};
+use flux_operators::OperatorRegistry;
+use thiserror::Error;
+
+use crate::graph::{Graph, GraphError};
+use crate::serialization::PortDirection;
+
+/// Errors from [`GraphBuilder::build`] and [`GraphHandles`] port lookups
+#[derive(Error, Debug)]
+pub enum BuildError {
+    /// The registry has no operator with this name
+    #[error("Unknown operator '{name}'")]
+    UnknownOperator { name: String },
+
+    /// The operator has no port with this name
+    #[error(
+        "{operator} has no {direction} named '{port}' (available: {})",
+        available.join(", ")
+    )]
+    UnknownPort {
+        operator: &'static str,
+        direction: PortDirection,
+        port: String,
+        available: Vec<&'static str>,
+    },
+
+    /// An input value can't be coerced to the input's type
+    #[error("Cannot set {operator}.{input} ({expected}) to a {found} value")]
+    InvalidValue {
+        operator: &'static str,
+        input: String,
+        expected: ValueType,
+        found: ValueType,
+    },
+
+    /// The graph rejected a wire
+    #[error("Cannot wire {from} -> {to}: {error}")]
+    Wire {
+        from: String,
+        to: String,
+        error: GraphError,
+    },
+}
+
+/// A node recorded by a [`GraphBuilder`]
+///
+/// Only meaningful for the builder that created it and the
+/// [`GraphHandles`] it builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BuilderNode {
+    index: usize,
+}
+
+impl BuilderNode {
+    /// Refer to one of this node's outputs by name.
+    pub fn out(self, name: impl Into<String>) -> BuilderOutput {
+        BuilderOutput {
+            node: self,
+            name: name.into(),
+        }
+    }
+
+    /// Refer to one of this node's inputs by name.
+    pub fn input_ref(self, name: impl Into<String>) -> BuilderInput {
+        BuilderInput {
+            node: self,
+            name: name.into(),
+        }
+    }
+}
+
+/// A named output of a [`BuilderNode`], resolved at build time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuilderOutput {
+    node: BuilderNode,
+    name: String,
+}
+
+/// A named input of a [`BuilderNode`], resolved at build time
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuilderInput {
+    node: BuilderNode,
+    name: String,
+}
+
+/// How an input's default is set
+#[derive(Debug, Clone)]
+enum InputSetting {
+    Value(Value),
+    ContextVar { name: String, fallback: Value },
+}
+
+/// Input settings of a node, passed to [`GraphBuilder::node_with`]
+#[derive(Debug, Clone, Default)]
+pub struct NodeConfig {
+    inputs: Vec<(String, InputSetting)>,
+}
+
+impl NodeConfig {
+    /// Set an input's default value.
+    pub fn input(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.inputs.push((name.into(), InputSetting::Value(value.into())));
+        self
+    }
+
+    /// Bind an input to a context variable, using `fallback` while the
+    /// variable is absent.
+    pub fn bind(
+        mut self,
+        name: impl Into<String>,
+        var: impl Into<String>,
+        fallback: impl Into<Value>,
+    ) -> Self {
+        let setting = InputSetting::ContextVar {
+            name: var.into(),
+            fallback: fallback.into(),
+        };
+        self.inputs.push((name.into(), setting));
+        self
+    }
+}
+
+/// A node waiting to be built
+#[derive(Debug)]
+struct NodeSpec {
+    operator: String,
+    config: NodeConfig,
+}
+
+/// Records nodes and wires by name and builds them into a [`Graph`]
+///
+/// Recording methods take `&self`, so the builder doesn't need to be bound
+/// mutably.
+pub struct GraphBuilder<'r> {
+    registry: &'r OperatorRegistry,
+    nodes: RefCell<Vec<NodeSpec>>,
+    wires: RefCell<Vec<(BuilderOutput, BuilderInput)>>,
+}
+
+impl<'r> GraphBuilder<'r> {
+    /// Create a builder resolving operator names with `registry`.
+    pub fn new(registry: &'r OperatorRegistry) -> Self {
+        Self {
+            registry,
+            nodes: RefCell::new(Vec::new()),
+            wires: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Add a node running the registry operator `operator`.
+    pub fn node(&self, operator: impl Into<String>) -> BuilderNode {
+        self.node_with(operator, |config| config)
+    }
+
+    /// Add a node and configure its inputs.
+    pub fn node_with(
+        &self,
+        operator: impl Into<String>,
+        configure: impl FnOnce(NodeConfig) -> NodeConfig,
+    ) -> BuilderNode {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(NodeSpec {
+            operator: operator.into(),
+            config: configure(NodeConfig::default()),
+        });
+        BuilderNode {
+            index: nodes.len() - 1,
+        }
+    }
+
+    /// Connect an output to an input.
+    pub fn wire(&self, from: BuilderOutput, to: BuilderInput) {
+        self.wires.borrow_mut().push((from, to));
+    }
+
+    /// Create the graph.
+    ///
+    /// Nodes are added in the order they were recorded, then input settings
+    /// are applied, then wires are connected in order.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first unknown operator or port name, input value that
+    /// can't be coerced to its input's type, or wire the graph rejects.
+    pub fn build(self) -> Result<(Graph, GraphHandles), BuildError> {
+        let mut graph = Graph::new();
+        let mut handles = GraphHandles { nodes: Vec::new() };
+
+        for spec in self.nodes.into_inner() {
+            let (op, meta) = self.registry.create_with_meta_by_name(&spec.operator).ok_or_else(|| {
+                BuildError::UnknownOperator {
+                    name: spec.operator.clone(),
+                }
+            })?;
+            let node = BuiltNode::new(op.as_ref());
+            let input_types: Vec<ValueType> =
+                op.inputs().iter().map(|input| input.value_type).collect();
+            let id = graph.add_boxed_with_meta(op, &meta);
+
+            for (input, setting) in spec.config.inputs {
+                let index = node.port_index(PortDirection::Input, &input)?;
+                let expected = input_types[index];
+                let coerce = |value: Value| {
+                    value.coerce_to(expected).ok_or_else(|| BuildError::InvalidValue {
+                        operator: node.operator,
+                        input: input.clone(),
+                        expected,
+                        found: value.value_type(),
+                    })
+                };
+                match setting {
+                    InputSetting::Value(value) => {
+                        graph.set_input_default(id, index, coerce(value)?);
+                    }
+                    InputSetting::ContextVar { name, fallback } => {
+                        graph.set_input_context_binding(id, index, name, coerce(fallback)?);
+                    }
+                }
+            }
+            handles.nodes.push(node);
+        }
+
+        for (from, to) in self.wires.into_inner() {
+            let source = handles.output(&from)?;
+            let target = handles.input(&to)?;
+            if let Err(error) = graph.connect_refs(source, target) {
+                return Err(BuildError::Wire {
+                    from: handles.describe(from.node, &from.name),
+                    to: handles.describe(to.node, &to.name),
+                    error,
+                });
+            }
+        }
+
+        Ok((graph, handles))
+    }
+}
+
+/// A built node and the port names of its operator
+#[derive(Debug, Clone)]
+struct BuiltNode {
+    id: Id,
+    operator: &'static str,
+    inputs: Vec<&'static str>,
+    outputs: Vec<&'static str>,
+}
+
+impl BuiltNode {
+    fn new(op: &dyn Operator) -> Self {
+        Self {
+            id: op.id(),
+            operator: op.name(),
+            inputs: op.inputs().iter().map(|port| port.name).collect(),
+            outputs: op.outputs().iter().map(|port| port.name).collect(),
+        }
+    }
+
+    /// Index of the port called `name`.
+    fn port_index(&self, direction: PortDirection, name: &str) -> Result<usize, BuildError> {
+        let ports = match direction {
+            PortDirection::Input => &self.inputs,
+            PortDirection::Output => &self.outputs,
+        };
+        ports
+            .iter()
+            .position(|port| *port == name)
+            .ok_or_else(|| BuildError::UnknownPort {
+                operator: self.operator,
+                direction,
+                port: name.to_string(),
+                available: ports.clone(),
+            })
+    }
+}
+
+/// Node IDs and port names of a graph built by a [`GraphBuilder`]
+#[derive(Debug, Clone)]
+pub struct GraphHandles {
+    nodes: Vec<BuiltNode>,
+}
+
+impl GraphHandles {
+    /// The ID a builder node was given in the graph.
+    pub fn id(&self, node: BuilderNode) -> Id {
+        self.nodes[node.index].id
+    }
+
+    /// Resolve a named output, e.g. for [`Graph::evaluate_ref`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::UnknownPort`] if the operator has no such output.
+    pub fn output(&self, port: &BuilderOutput) -> Result<OutputRef, BuildError> {
+        let node = &self.nodes[port.node.index];
+        let index = node.port_index(PortDirection::Output, &port.name)?;
+        Ok(OutputRef::new(node.id, index))
+    }
+
+    /// Resolve a named input.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::UnknownPort`] if the operator has no such input.
+    pub fn input(&self, port: &BuilderInput) -> Result<InputRef, BuildError> {
+        let node = &self.nodes[port.node.index];
+        let index = node.port_index(PortDirection::Input, &port.name)?;
+        Ok(InputRef::new(node.id, index))
+    }
+
+    /// `Operator.port` label for error messages.
+    fn describe(&self, node: BuilderNode, port: &str) -> String {
+        format!("{}.{}", self.nodes[node.index].operator, port)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::EvalContext;
+    use flux_operators::create_default_registry;
+
+    #[test]
+    fn test_build_resolves_names() {
+        let registry = create_default_registry();
+        let b = GraphBuilder::new(&registry);
+        let a = b.node_with("Constant", |n| n.input("Value", 2.0));
+        let c = b.node_with("Constant", |n| n.bind("Value", "offset", 1.0));
+        let add = b.node("Add");
+        b.wire(a.out("Value"), add.input_ref("A"));
+        b.wire(c.out("Value"), add.input_ref("B"));
+
+        let (mut graph, handles) = b.build().unwrap();
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.get(handles.id(add)).unwrap().name(), "Add");
+
+        let output = handles.output(&add.out("Result")).unwrap();
+        let mut ctx = EvalContext::new();
+        assert_eq!(graph.evaluate_ref(output, &ctx).unwrap(), Value::Float(3.0));
+        ctx.set_float_var("offset", 5.0);
+        assert_eq!(graph.evaluate_ref(output, &ctx).unwrap(), Value::Float(7.0));
+    }
+
+    #[test]
+    fn test_build_scales_between_port_units() {
+        // RadiansToDegrees outputs degrees, Sin takes radians
+        let registry = create_default_registry();
+        let b = GraphBuilder::new(&registry);
+        let deg = b.node_with("RadiansToDegrees", |n| {
+            n.input("Radians", std::f32::consts::FRAC_PI_6)
+        });
+        let sin = b.node("Sin");
+        b.wire(deg.out("Degrees"), sin.input_ref("Angle"));
+
+        let (mut graph, handles) = b.build().unwrap();
+        assert_eq!(graph.node_count(), 3, "a unit conversion is inserted");
+        let output = handles.output(&sin.out("Result")).unwrap();
+        let result = graph.evaluate_ref(output, &EvalContext::new()).unwrap();
+        assert!((result.as_float().unwrap() - 0.5).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_build_unknown_operator() {
+        let registry = create_default_registry();
+        let b = GraphBuilder::new(&registry);
+        b.node("Constant");
+        b.node("NoSuchOp");
+
+        match b.build() {
+            Err(BuildError::UnknownOperator { name }) => assert_eq!(name, "NoSuchOp"),
+            other => panic!("Expected unknown operator, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_build_unknown_port() {
+        let registry = create_default_registry();
+        let b = GraphBuilder::new(&registry);
+        let a = b.node("Constant");
+        let add = b.node("Add");
+        b.wire(a.out("Value"), add.input_ref("C"));
+
+        let Err(err) = b.build() else {
+            panic!("Expected unknown port");
+        };
+        match &err {
+            BuildError::UnknownPort {
+                operator,
+                direction,
+                port,
+                available,
+            } => {
+                assert_eq!(*operator, "Add");
+                assert_eq!(*direction, PortDirection::Input);
+                assert_eq!(port, "C");
+                assert_eq!(available, &["A", "B"]);
+            }
+            other => panic!("Expected unknown port, got {:?}", other),
+        }
+        assert_eq!(err.to_string(), "Add has no input named 'C' (available: A, B)");
+
+        let b = GraphBuilder::new(&registry);
+        b.node_with("Add", |n| n.input("Sum", 1.0));
+        assert!(matches!(b.build(), Err(BuildError::UnknownPort { .. })));
+    }
+
+    #[test]
+    fn test_build_incompatible_wire() {
+        let registry = create_default_registry();
+        let b = GraphBuilder::new(&registry);
+        let type_of = b.node("TypeOf");
+        let decompose = b.node("Vec3Decompose");
+        b.wire(type_of.out("Type"), decompose.input_ref("Vector"));
+
+        match b.build() {
+            Err(BuildError::Wire { from, to, error }) => {
+                assert_eq!(from, "TypeOf.Type");
+                assert_eq!(to, "Vec3Decompose.Vector");
+                assert!(matches!(error, GraphError::TypeMismatch { .. }));
+            }
+            other => panic!("Expected rejected wire, got {:?}", other.err()),
+        }
+    }
+
+    #[test]
+    fn test_build_invalid_value() {
+        let registry = create_default_registry();
+        let b = GraphBuilder::new(&registry);
+        b.node_with("Constant", |n| n.input("Value", [1.0, 2.0, 3.0]));
+
+        match b.build() {
+            Err(BuildError::InvalidValue { operator, input, expected, found }) => {
+                assert_eq!(operator, "Constant");
+                assert_eq!(input, "Value");
+                assert_eq!(expected, ValueType::Float);
+                assert_eq!(found, ValueType::Vec3);
+            }
+            other => panic!("Expected invalid value, got {:?}", other.err()),
+        }
+    }
+}
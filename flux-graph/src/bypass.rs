@@ -61,6 +61,10 @@ impl BypassableType {
             ValueType::Vec4List => None,
             ValueType::ColorList => None,
             ValueType::StringList => None,
+            // Structured data - not bypassable
+            ValueType::Map => None,
+            // Host resources - not currently bypassable
+            ValueType::ResourceRef => None,
         }
     }
 
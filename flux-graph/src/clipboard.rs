@@ -0,0 +1,493 @@
+//! Clipboard format for copy/paste of node selections
+//!
+//! [`copy`] captures a selection of nodes as a self-contained [`ClipboardData`]
+//! payload (operator names, changed input defaults, port overrides, update
+//! rates, internal connections and optional positions). The payload is
+//! versioned and serializes to JSON, so it can travel between graph instances
+//! and applications.
+//!
+//! [`paste`] re-instantiates the nodes through an [`OperatorRegistry`] with
+//! fresh IDs. Pasting the same payload twice yields two independent copies.
+//! Operators the registry doesn't know are skipped; the remaining nodes stay
+//! wired where possible.
+//!
+//! Auto-inserted conversion nodes are not copied. Connections that pass
+//! through them are recorded end-to-end and [`Graph::connect`] re-inserts the
+//! conversions on paste.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let registry = create_default_registry();
+//! let data = clipboard::copy(&graph, &selected, &registry);
+//! let json = data.to_json()?;
+//!
+//! // ... later, possibly in another application
+//! let data = ClipboardData::from_json(&json)?;
+//! let result = clipboard::paste(&mut other_graph, &data, &registry);
+//! for name in &result.skipped {
+//!     eprintln!("Unknown operator: {}", name);
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use flux_core::id::Id;
+use flux_core::value::Value;
+use flux_core::GizmoVisibility;
+use flux_operators::OperatorRegistry;
+
+use crate::conversion::{ConversionOp, UnitConversionOp};
+use crate::graph::Graph;
+use crate::missing::MissingOp;
+use crate::serialization::{PortUiOverride, Result, SchemaVersion, SerializationError};
+use crate::update_rate::UpdateRate;
+
+/// Major version of the clipboard format.
+const CLIPBOARD_MAJOR_VERSION: u32 = 1;
+
+/// A copied selection of nodes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipboardData {
+    /// Format version
+    pub version: SchemaVersion,
+    /// Copied nodes
+    pub nodes: Vec<ClipboardNode>,
+    /// Connections between copied nodes
+    #[serde(default)]
+    pub connections: Vec<ClipboardConnection>,
+}
+
+/// A single copied node.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipboardNode {
+    /// ID of the node in the source graph (used only to resolve connections)
+    pub id: Id,
+    /// Operator name used to re-create the node from a registry
+    pub operator: String,
+    /// Input defaults that differ from the node's factory defaults or a
+    /// freshly created operator
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub inputs: Vec<ClipboardInput>,
+    /// Per-instance port UI overrides
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub port_ui_overrides: Vec<PortUiOverride>,
+    /// Update rate (omitted when every frame)
+    #[serde(default, skip_serializing_if = "UpdateRate::is_every_frame")]
+    pub update_rate: UpdateRate,
+    /// Gizmo visibility (omitted when inherited)
+    #[serde(default, skip_serializing_if = "GizmoVisibility::is_inherit")]
+    pub gizmo_visibility: GizmoVisibility,
+    /// Position relative to the top-left of the copied selection, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub position: Option<[f32; 2]>,
+}
+
+/// A copied input default value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClipboardInput {
+    /// Input port index
+    pub index: usize,
+    /// Default value (the fallback when bound to a context variable)
+    pub value: Value,
+    /// Context variable the input reads, if bound
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_var: Option<String>,
+}
+
+/// A connection between two copied nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClipboardConnection {
+    pub source: Id,
+    pub source_output: usize,
+    pub target: Id,
+    pub target_input: usize,
+    /// Whether the connection is muted (see [`Graph::set_connection_muted`])
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub muted: bool,
+}
+
+/// Outcome of a [`paste`].
+#[derive(Debug, Clone, Default)]
+pub struct PasteResult {
+    /// Clipboard node ID -> newly created node ID
+    pub id_map: HashMap<Id, Id>,
+    /// Operator names that the registry could not create
+    pub skipped: Vec<String>,
+    /// Connections that could not be restored (missing endpoint or rejected by the graph)
+    pub dropped_connections: Vec<ClipboardConnection>,
+    /// Relative positions of the new nodes, for hosts that supplied them on copy
+    pub positions: HashMap<Id, [f32; 2]>,
+}
+
+impl PasteResult {
+    /// Returns true if every node and connection was restored.
+    pub fn is_complete(&self) -> bool {
+        self.skipped.is_empty() && self.dropped_connections.is_empty()
+    }
+}
+
+impl ClipboardData {
+    /// Serialize to a JSON string.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Parse from a JSON string, rejecting incompatible versions.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let data: ClipboardData = serde_json::from_str(json)?;
+        if !data.version.is_compatible(CLIPBOARD_MAJOR_VERSION) {
+            return Err(SerializationError::VersionMismatch {
+                file_major: data.version.major,
+                file_minor: data.version.minor,
+                expected_major: CLIPBOARD_MAJOR_VERSION,
+            });
+        }
+        Ok(data)
+    }
+
+    /// Returns true if nothing was copied.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+}
+
+fn is_conversion(graph: &Graph, id: Id) -> bool {
+    graph
+        .get(id)
+        .is_some_and(|op| op.as_any().is::<ConversionOp>() || op.as_any().is::<UnitConversionOp>())
+}
+
+/// Follow a source back through auto-inserted conversion nodes.
+fn resolve_source(graph: &Graph, mut node: Id, mut output: usize) -> (Id, usize) {
+    while is_conversion(graph, node) {
+        match graph.get(node).and_then(|op| op.inputs()[0].connection) {
+            Some((source, source_output)) => {
+                node = source;
+                output = source_output;
+            }
+            None => break,
+        }
+    }
+    (node, output)
+}
+
+/// Copy a selection of nodes.
+///
+/// Unknown IDs and auto-inserted conversion nodes in `selection` are ignored.
+/// Only connections between selected nodes are kept.
+pub fn copy(graph: &Graph, selection: &[Id], registry: &OperatorRegistry) -> ClipboardData {
+    copy_with_positions(graph, selection, registry, &HashMap::new())
+}
+
+/// Copy a selection of nodes, including host-supplied positions.
+///
+/// Positions are stored relative to the top-left corner of the selection.
+pub fn copy_with_positions(
+    graph: &Graph,
+    selection: &[Id],
+    registry: &OperatorRegistry,
+    positions: &HashMap<Id, [f32; 2]>,
+) -> ClipboardData {
+    let mut seen = HashSet::new();
+    let selected: Vec<Id> = selection
+        .iter()
+        .copied()
+        .filter(|&id| graph.get(id).is_some() && !is_conversion(graph, id) && seen.insert(id))
+        .collect();
+    let selected_set: HashSet<Id> = selected.iter().copied().collect();
+
+    let origin = selected
+        .iter()
+        .filter_map(|id| positions.get(id))
+        .fold(None, |acc: Option<[f32; 2]>, p| match acc {
+            Some(o) => Some([o[0].min(p[0]), o[1].min(p[1])]),
+            None => Some(*p),
+        })
+        .unwrap_or([0.0, 0.0]);
+
+    let mut nodes = Vec::with_capacity(selected.len());
+    let mut connections = Vec::new();
+
+    for &id in &selected {
+        let op = graph.get(id).expect("selected node exists");
+        // Only store modified defaults. Loading recreates a fresh instance,
+        // so defaults that differ from one are stored too. Placeholders
+        // can't be recreated; they store what their file had.
+        let fresh = registry.create_by_name(op.name());
+        let missing = op.as_any().downcast_ref::<MissingOp>();
+
+        let mut inputs = Vec::new();
+        let mut port_ui_overrides = Vec::new();
+        for (index, input) in op.inputs().iter().enumerate() {
+            let fresh_default = fresh
+                .as_ref()
+                .and_then(|f| f.inputs().get(index))
+                .map(|i| &i.default);
+            let context_var = graph
+                .input_default_source(id, index)
+                .and_then(|source| source.context_var_name().map(str::to_string));
+            let changed = graph
+                .factory_default(id, index)
+                .is_some_and(|factory| *factory != input.default);
+            let modified = match missing {
+                Some(missing) => changed || missing.has_saved_value(index),
+                None => changed || fresh_default != Some(&input.default),
+            };
+            if modified || context_var.is_some() {
+                inputs.push(ClipboardInput {
+                    index,
+                    value: input.default.clone(),
+                    context_var,
+                });
+            }
+            if let Some(override_) = graph.get_input_override(id, index) {
+                port_ui_overrides.push(PortUiOverride::from_port_override(index, override_));
+            }
+
+            let sources = input
+                .connection
+                .into_iter()
+                .chain(input.connections.iter().copied());
+            for (slot, (source, source_output)) in sources.enumerate() {
+                let (source, source_output) = resolve_source(graph, source, source_output);
+                if selected_set.contains(&source) {
+                    connections.push(ClipboardConnection {
+                        source,
+                        source_output,
+                        target: id,
+                        target_input: index,
+                        muted: input.is_muted(slot),
+                    });
+                }
+            }
+        }
+
+        nodes.push(ClipboardNode {
+            id,
+            operator: op.name().to_string(),
+            inputs,
+            port_ui_overrides,
+            update_rate: graph.update_rate(id).unwrap_or_default(),
+            gizmo_visibility: graph.gizmo_visibility(id).unwrap_or_default(),
+            position: positions
+                .get(&id)
+                .map(|p| [p[0] - origin[0], p[1] - origin[1]]),
+        });
+    }
+
+    ClipboardData {
+        version: SchemaVersion::new(CLIPBOARD_MAJOR_VERSION, 0),
+        nodes,
+        connections,
+    }
+}
+
+/// Paste clipboard data into a graph.
+///
+/// Every pasted node gets a fresh ID. Nodes whose operator the registry can't
+/// create are skipped and reported; connections touching them are dropped.
+/// The graph events of a paste form a single batch.
+pub fn paste(graph: &mut Graph, data: &ClipboardData, registry: &OperatorRegistry) -> PasteResult {
+    graph.batch(|graph| paste_unbatched(graph, data, registry))
+}
+
+fn paste_unbatched(graph: &mut Graph, data: &ClipboardData, registry: &OperatorRegistry) -> PasteResult {
+    let mut result = PasteResult::default();
+
+    for node in &data.nodes {
+        let Some((op, meta)) = registry.create_with_meta_by_name(&node.operator) else {
+            result.skipped.push(node.operator.clone());
+            continue;
+        };
+        let new_id = graph.add_boxed_with_meta(op, &meta);
+
+        for input in &node.inputs {
+            let value = input.value.clone();
+            match &input.context_var {
+                Some(name) => graph.set_input_context_binding(new_id, input.index, name, value),
+                None => graph.set_input_default(new_id, input.index, value),
+            };
+        }
+        for override_ in &node.port_ui_overrides {
+            graph.set_input_override(new_id, override_.port_index, override_.to_port_override());
+        }
+        if !node.update_rate.is_every_frame() {
+            graph.set_update_rate(new_id, node.update_rate);
+        }
+        if !node.gizmo_visibility.is_inherit() {
+            graph.set_gizmo_visibility(new_id, node.gizmo_visibility);
+        }
+        if let Some(position) = node.position {
+            result.positions.insert(new_id, position);
+        }
+        result.id_map.insert(node.id, new_id);
+    }
+
+    for conn in &data.connections {
+        let endpoints = (
+            result.id_map.get(&conn.source),
+            result.id_map.get(&conn.target),
+        );
+        let connected = match endpoints {
+            (Some(&source), Some(&target)) => {
+                match graph.connect(source, conn.source_output, target, conn.target_input) {
+                    Ok(conversion) => {
+                        if conn.muted {
+                            let source = (source, conn.source_output);
+                            graph.mute_connected(source, conversion, (target, conn.target_input));
+                        }
+                        true
+                    }
+                    Err(_) => false,
+                }
+            }
+            _ => false,
+        };
+        if !connected {
+            result.dropped_connections.push(*conn);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::EvalContext;
+    use flux_operators::{capture_meta, create_default_registry, BinaryOp, RegistryEntry};
+
+    /// constant(2) -> add.A, constant(3) -> add.B
+    fn build_selection(graph: &mut Graph, registry: &OperatorRegistry) -> Vec<Id> {
+        let a = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        let b = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        let add = graph.add_boxed(registry.create_by_name("Add").unwrap());
+        graph.set_input_default(a, 0, Value::Float(2.0));
+        graph.set_input_default(b, 0, Value::Float(3.0));
+        graph.connect(a, 0, add, 0).unwrap();
+        graph.connect(b, 0, add, 1).unwrap();
+        vec![a, b, add]
+    }
+
+    fn find_add(graph: &Graph, result: &PasteResult) -> Id {
+        *result
+            .id_map
+            .values()
+            .find(|&&id| graph.node_name(id) == Some("Add"))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_copy_paste_twice() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let selection = build_selection(&mut graph, &registry);
+
+        let data = copy(&graph, &selection, &registry);
+        assert_eq!(data.nodes.len(), 3);
+        assert_eq!(data.connections.len(), 2);
+
+        // Roundtrip through JSON like a real clipboard
+        let data = ClipboardData::from_json(&data.to_json().unwrap()).unwrap();
+
+        let first = paste(&mut graph, &data, &registry);
+        let second = paste(&mut graph, &data, &registry);
+        assert!(first.is_complete());
+        assert!(second.is_complete());
+        assert_eq!(graph.node_count(), 9);
+
+        // 6 new, distinct nodes
+        let new_ids: HashSet<Id> = first
+            .id_map
+            .values()
+            .chain(second.id_map.values())
+            .copied()
+            .collect();
+        assert_eq!(new_ids.len(), 6);
+        assert!(new_ids.iter().all(|id| !selection.contains(id)));
+
+        // Internal wiring intact and independent
+        let ctx = EvalContext::new();
+        let add1 = find_add(&graph, &first);
+        let add2 = find_add(&graph, &second);
+        assert_eq!(graph.evaluate(add1, 0, &ctx).unwrap(), Value::Float(5.0));
+        assert_eq!(graph.evaluate(add2, 0, &ctx).unwrap(), Value::Float(5.0));
+        assert!(graph
+            .upstream_of(add1)
+            .iter()
+            .all(|c| first.id_map.values().any(|&id| id == c.source_node)));
+        assert!(graph
+            .upstream_of(add2)
+            .iter()
+            .all(|c| second.id_map.values().any(|&id| id == c.source_node)));
+    }
+
+    #[test]
+    fn test_paste_skips_unknown_operators() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let selection = build_selection(&mut graph, &registry);
+        let data = copy(&graph, &selection, &registry);
+
+        // A registry that knows Add but not Constant
+        let stripped = OperatorRegistry::new();
+        stripped.register(
+            RegistryEntry::new("Add", "Math", ""),
+            || capture_meta(BinaryOp::add()),
+        );
+
+        let mut target = Graph::new();
+        let result = paste(&mut target, &data, &stripped);
+        assert_eq!(
+            result.skipped,
+            vec!["Constant".to_string(), "Constant".to_string()]
+        );
+        assert_eq!(result.id_map.len(), 1);
+        assert_eq!(result.dropped_connections.len(), 2);
+        assert!(!result.is_complete());
+        assert_eq!(target.node_count(), 1);
+    }
+
+    #[test]
+    fn test_copy_ignores_outside_connections_and_keeps_positions() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let selection = build_selection(&mut graph, &registry);
+
+        // Only copy b and add; a -> add is outside the selection
+        let positions =
+            HashMap::from([(selection[1], [100.0, 50.0]), (selection[2], [300.0, 80.0])]);
+        let data = copy_with_positions(&graph, &selection[1..], &registry, &positions);
+        assert_eq!(data.nodes.len(), 2);
+        assert_eq!(data.connections.len(), 1);
+        assert_eq!(data.nodes[0].position, Some([0.0, 0.0]));
+        assert_eq!(data.nodes[1].position, Some([200.0, 30.0]));
+        // Only the changed default is stored
+        assert_eq!(data.nodes[0].inputs.len(), 1);
+        assert!(data.nodes[1].inputs.is_empty());
+
+        let result = paste(&mut graph, &data, &registry);
+        assert_eq!(result.positions.len(), 2);
+    }
+
+    #[test]
+    fn test_from_json_rejects_future_major_version() {
+        let mut data = ClipboardData {
+            version: SchemaVersion::new(2, 0),
+            nodes: vec![],
+            connections: vec![],
+        };
+        let json = data.to_json().unwrap();
+        assert!(matches!(
+            ClipboardData::from_json(&json),
+            Err(SerializationError::VersionMismatch { file_major: 2, .. })
+        ));
+        data.version = SchemaVersion::new(1, 3);
+        assert!(ClipboardData::from_json(&data.to_json().unwrap())
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -79,7 +79,7 @@ impl Command for AddNodeCommand {
     fn undo(&mut self, graph: &mut Graph) {
         if let Some(id) = self.node_id {
             // Remove the node and store it for redo
-            if let Some(operator) = graph.remove(id) {
+            if let Some(operator) = graph.remove_untrashed(id) {
                 self.operator = Some(operator);
             }
         }
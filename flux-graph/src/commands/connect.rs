@@ -1,14 +1,16 @@
 //! ConnectCommand - Connect two ports in the graph
 
-use flux_core::Id;
+use flux_core::{Id, InputValidationIssue};
 
 use super::Command;
-use crate::graph::Graph;
+use crate::graph::{Graph, InputSnapshot};
+use crate::journal::JournalEntry;
 
 /// Command to connect an output port to an input port.
 ///
 /// On execute, the connection is made (possibly inserting a conversion node).
-/// On undo, the connection is removed (and any conversion node is removed).
+/// On undo, the target input gets back exactly the connections it had (and
+/// any conversion node is removed); other edges on a multi-input are kept.
 #[derive(Debug)]
 pub struct ConnectCommand {
     /// Source node ID
@@ -19,10 +21,12 @@ pub struct ConnectCommand {
     target_node: Id,
     /// Target input port index
     target_input: usize,
-    /// Previous connection on the target input (for undo)
-    previous_connection: Option<(Id, usize)>,
+    /// Connection state of the target input before execute (for undo)
+    previous: Option<InputSnapshot>,
     /// Conversion node inserted by auto-conversion (if any)
     conversion_node: Option<Id>,
+    /// Issues the target's operator reported for its inputs after execute
+    issues: Vec<InputValidationIssue>,
     /// Whether the command was successfully executed
     executed: bool,
 }
@@ -40,8 +44,9 @@ impl ConnectCommand {
             source_output,
             target_node,
             target_input,
-            previous_connection: None,
+            previous: None,
             conversion_node: None,
+            issues: Vec::new(),
             executed: false,
         }
     }
@@ -50,6 +55,12 @@ impl ConnectCommand {
     pub fn conversion_node(&self) -> Option<Id> {
         self.conversion_node
     }
+
+    /// Issues the target node's operator reported for its inputs once
+    /// connected (available after execute).
+    pub fn issues(&self) -> &[InputValidationIssue] {
+        &self.issues
+    }
 }
 
 impl Command for ConnectCommand {
@@ -58,12 +69,8 @@ impl Command for ConnectCommand {
     }
 
     fn execute(&mut self, graph: &mut Graph) {
-        // Store previous connection for undo
-        if let Some(node) = graph.get(self.target_node) {
-            if let Some(input) = node.inputs().get(self.target_input) {
-                self.previous_connection = input.connection;
-            }
-        }
+        // Store previous connection state for undo
+        self.previous = graph.input_snapshot(self.target_node, self.target_input);
 
         // Make the connection
         match graph.connect(
@@ -74,6 +81,7 @@ impl Command for ConnectCommand {
         ) {
             Ok(conversion_id) => {
                 self.conversion_node = conversion_id;
+                self.issues = graph.input_issues(self.target_node).to_vec();
                 self.executed = true;
             }
             Err(e) => {
@@ -88,20 +96,27 @@ impl Command for ConnectCommand {
             return;
         }
 
-        // Remove the conversion node if one was inserted
-        if let Some(conv_id) = self.conversion_node.take() {
-            graph.remove(conv_id);
+        // Remove the conversion node if one was inserted and restore the input
+        let conversion = self.conversion_node.take();
+        if let Some(previous) = &self.previous {
+            graph.undo_connect(previous, conversion);
         }
 
-        // Disconnect the target input
-        let _ = graph.disconnect(self.target_node, self.target_input);
+        self.executed = false;
+    }
 
-        // Restore previous connection if there was one
-        if let Some((prev_source, prev_output)) = self.previous_connection {
-            let _ = graph.connect(prev_source, prev_output, self.target_node, self.target_input);
+    fn to_journal_entry(&self) -> Option<serde_json::Value> {
+        // An inserted conversion node is a new node: checkpoint instead
+        if !self.executed || self.conversion_node.is_some() {
+            return None;
         }
-
-        self.executed = false;
+        JournalEntry::Connect {
+            source: self.source_node,
+            output: self.source_output,
+            target: self.target_node,
+            input: self.target_input,
+        }
+        .to_json()
     }
 }
 
@@ -183,4 +198,32 @@ mod tests {
         let sink_node = graph.get(sink_id).unwrap();
         assert_eq!(sink_node.inputs()[0].connection, Some((src1_id, 0)));
     }
+
+    #[test]
+    fn test_connect_undo_keeps_other_multi_input_edges() {
+        use flux_operators::SumOp;
+
+        let mut graph = Graph::new();
+        let src1 = graph.add(TestOp::source(1.0));
+        let src2 = graph.add(TestOp::source(2.0));
+        let sum = graph.add(SumOp::new());
+        graph.connect(src1, 0, sum, 0).unwrap();
+        graph.connect(src2, 0, sum, 0).unwrap();
+        graph.clear_events();
+
+        let mut cmd = ConnectCommand::new(src1, 0, sum, 0);
+        cmd.execute(&mut graph);
+        assert_eq!(graph.get(sum).unwrap().inputs()[0].connections.len(), 3);
+
+        // Only the edge the command added goes away
+        cmd.undo(&mut graph);
+        let connections = &graph.get(sum).unwrap().inputs()[0].connections;
+        assert_eq!(connections, &vec![(src1, 0), (src2, 0)]);
+        assert!(graph.verify_consumer_index());
+        let disconnected = graph
+            .drain_events()
+            .filter(|e| matches!(e, crate::GraphEvent::Disconnected { .. }))
+            .count();
+        assert_eq!(disconnected, 1);
+    }
 }
@@ -4,6 +4,7 @@ use flux_core::Id;
 
 use super::Command;
 use crate::graph::Graph;
+use crate::journal::JournalEntry;
 
 /// Command to disconnect an input port.
 ///
@@ -77,6 +78,17 @@ impl Command for DisconnectCommand {
 
         self.executed = false;
     }
+
+    fn to_journal_entry(&self) -> Option<serde_json::Value> {
+        if !self.executed {
+            return None;
+        }
+        JournalEntry::Disconnect {
+            target: self.target_node,
+            input: self.target_input,
+        }
+        .to_json()
+    }
 }
 
 #[cfg(test)]
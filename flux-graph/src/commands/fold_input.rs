@@ -0,0 +1,105 @@
+//! FoldInputCommand - Replace an input's connection with its current value
+
+use flux_core::{EvalContext, Id, Value};
+
+use super::Command;
+use crate::graph::{Graph, InputSnapshot};
+
+/// Command to fold a connected input into a literal default.
+///
+/// On execute, the source is evaluated once with a default context, the
+/// input is disconnected and the value becomes its default. Only meaningful
+/// when the source doesn't depend on time or context (see
+/// [`lint`](crate::lint)). On undo, the previous default and connection are
+/// restored.
+#[derive(Debug)]
+pub struct FoldInputCommand {
+    /// Target node ID
+    target_node: Id,
+    /// Target input port index
+    target_input: usize,
+    /// Connection state and default before execute (for undo)
+    previous: Option<(InputSnapshot, Value)>,
+}
+
+impl FoldInputCommand {
+    /// Create a new FoldInputCommand.
+    pub fn new(target_node: Id, target_input: usize) -> Self {
+        Self {
+            target_node,
+            target_input,
+            previous: None,
+        }
+    }
+}
+
+impl Command for FoldInputCommand {
+    fn name(&self) -> &str {
+        "Fold Constant"
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        let Some(input) = graph
+            .get(self.target_node)
+            .and_then(|op| op.inputs().get(self.target_input))
+        else {
+            return;
+        };
+        // Multi-inputs have no single default to fold into
+        let Some((source, output)) = input.connection else {
+            return;
+        };
+        let (value_type, previous_default) = (input.value_type, input.default.clone());
+        let Some(snapshot) = graph.input_snapshot(self.target_node, self.target_input) else {
+            return;
+        };
+
+        let value = match graph.evaluate(source, output, &EvalContext::new()) {
+            Ok(value) => value,
+            Err(e) => {
+                eprintln!("FoldInputCommand failed: {}", e);
+                return;
+            }
+        };
+        let value = value.coerce_to(value_type).unwrap_or(value);
+        if graph
+            .disconnect(self.target_node, self.target_input)
+            .is_ok()
+        {
+            graph.set_input_default(self.target_node, self.target_input, value);
+            self.previous = Some((snapshot, previous_default));
+        }
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        if let Some((snapshot, default)) = self.previous.take() {
+            graph.set_input_default(self.target_node, self.target_input, default);
+            graph.undo_connect(&snapshot, None);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::tests::TestOp;
+
+    #[test]
+    fn test_fold_input_execute_undo() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source(3.0));
+        let target = graph.add(TestOp::new(0.0));
+        graph.connect(source, 0, target, 0).unwrap();
+
+        let mut cmd = FoldInputCommand::new(target, 0);
+        cmd.execute(&mut graph);
+        let input = &graph.get(target).unwrap().inputs()[0];
+        assert!(input.connection.is_none());
+        assert_eq!(input.default, Value::Float(3.0));
+
+        cmd.undo(&mut graph);
+        let input = &graph.get(target).unwrap().inputs()[0];
+        assert_eq!(input.connection, Some((source, 0)));
+        assert_eq!(input.default, Value::Float(0.0));
+    }
+}
@@ -0,0 +1,144 @@
+//! CreateGroupCommand / AssignToGroupCommand - Organize nodes into groups
+
+use flux_core::Id;
+
+use super::Command;
+use crate::graph::Graph;
+use crate::group::{Group, GroupId};
+
+/// Command to create an empty group.
+///
+/// On undo, the group is removed (with whatever members it gained since).
+/// Redo brings it back under the same [`GroupId`], so later commands that
+/// refer to the group keep working.
+#[derive(Debug, Clone)]
+pub struct CreateGroupCommand {
+    name: String,
+    color: [f32; 4],
+    /// ID of the created group (available after execute)
+    group: Option<GroupId>,
+    /// The group as it was when undone, restored on redo
+    removed: Option<Group>,
+}
+
+impl CreateGroupCommand {
+    /// Create a new CreateGroupCommand.
+    pub fn new(name: impl Into<String>, color: [f32; 4]) -> Self {
+        Self {
+            name: name.into(),
+            color,
+            group: None,
+            removed: None,
+        }
+    }
+
+    /// Get the ID of the created group (available after execute).
+    pub fn group(&self) -> Option<GroupId> {
+        self.group
+    }
+}
+
+impl Command for CreateGroupCommand {
+    fn name(&self) -> &str {
+        "Create Group"
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        match (self.group, self.removed.take()) {
+            (Some(group), Some(removed)) => graph.restore_group(group, removed),
+            _ => self.group = Some(graph.create_group(self.name.clone(), self.color)),
+        }
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        if let Some(group) = self.group {
+            self.removed = graph.remove_group(group);
+        }
+    }
+}
+
+/// Command to add a node to a group.
+///
+/// On undo, the node is taken out of the group again, unless it already was
+/// a member before execute.
+#[derive(Debug, Clone)]
+pub struct AssignToGroupCommand {
+    node_id: Id,
+    group: GroupId,
+    /// Whether execute added the node (false if it was already a member)
+    assigned: bool,
+}
+
+impl AssignToGroupCommand {
+    /// Create a new AssignToGroupCommand.
+    pub fn new(node_id: Id, group: GroupId) -> Self {
+        Self {
+            node_id,
+            group,
+            assigned: false,
+        }
+    }
+}
+
+impl Command for AssignToGroupCommand {
+    fn name(&self) -> &str {
+        "Assign To Group"
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        self.assigned = graph.assign_to_group(self.node_id, self.group).unwrap_or(false);
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        if std::mem::take(&mut self.assigned) {
+            graph.unassign_from_group(self.node_id, self.group);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::tests::TestOp;
+    use crate::UndoRedoStack;
+
+    #[test]
+    fn test_create_and_assign_undo_redo() {
+        let mut graph = Graph::new();
+        let a = graph.add(TestOp::new(1.0));
+        let b = graph.add(TestOp::new(2.0));
+        let mut history = UndoRedoStack::new();
+
+        history.execute(&mut graph, CreateGroupCommand::new("FX", [1.0, 0.0, 0.0, 1.0]));
+        let (group, _) = graph.groups().next().unwrap();
+        history.execute(&mut graph, AssignToGroupCommand::new(a, group));
+        history.execute(&mut graph, AssignToGroupCommand::new(b, group));
+        assert_eq!(graph.group_members(group), Some(&[a, b][..]));
+
+        history.undo(&mut graph);
+        assert_eq!(graph.group_members(group), Some(&[a][..]));
+        history.undo(&mut graph);
+        history.undo(&mut graph);
+        assert!(graph.group(group).is_none());
+
+        // Redo recreates the group under the same ID, so the assigns replay
+        history.redo(&mut graph);
+        history.redo(&mut graph);
+        history.redo(&mut graph);
+        assert_eq!(graph.group(group).unwrap().name(), "FX");
+        assert_eq!(graph.group_members(group), Some(&[a, b][..]));
+    }
+
+    #[test]
+    fn test_assign_existing_member_undo_keeps_it() {
+        let mut graph = Graph::new();
+        let a = graph.add(TestOp::new(1.0));
+        let group = graph.create_group("FX", [1.0; 4]);
+        graph.assign_to_group(a, group).unwrap();
+
+        let mut cmd = AssignToGroupCommand::new(a, group);
+        cmd.execute(&mut graph);
+        cmd.undo(&mut graph);
+        assert_eq!(graph.groups_of(a), vec![group]);
+    }
+}
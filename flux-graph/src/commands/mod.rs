@@ -24,6 +24,17 @@
 //! - [`ConnectCommand`] - Connect two ports
 //! - [`DisconnectCommand`] - Disconnect a port
 //! - [`SetInputDefaultCommand`] - Change an input's default value
+//! - [`ResetInputsCommand`] - Reset input defaults to their factory values
+//! - [`FoldInputCommand`] - Replace a constant connection with its value
+//! - [`SetInputSourceCommand`] - Bind an input's default to a context variable
+//! - [`SetUpdateRateCommand`] - Change a node's update rate
+//! - [`SetGizmoVisibilityCommand`] - Change a node's gizmo visibility
+//! - [`SpliceNodeCommand`] - Insert a node into an existing connection
+//! - [`UnspliceNodeCommand`] - Remove a node from a wire, reconnecting its neighbours
+//! - [`RetargetConnectionCommand`] - Move one end of an existing connection
+//! - [`SwapInputsCommand`] - Exchange the connections and defaults of two inputs
+//! - [`SetConnectionMutedCommand`] - Mute or unmute a connection
+//! - [`CreateGroupCommand`] / [`AssignToGroupCommand`] - Organize nodes into groups
 //! - [`MacroCommand`] - Group multiple commands for atomic undo
 //!
 //! # Example
@@ -49,16 +60,36 @@
 mod add_node;
 mod connect;
 mod disconnect;
+mod fold_input;
+mod group;
 mod macro_command;
 mod remove_node;
+mod reset_inputs;
+mod retarget;
 mod set_default;
+mod set_gizmo_visibility;
+mod set_muted;
+mod set_source;
+mod set_update_rate;
+mod splice_node;
+mod swap_inputs;
 
 pub use add_node::AddNodeCommand;
 pub use connect::ConnectCommand;
 pub use disconnect::DisconnectCommand;
+pub use fold_input::FoldInputCommand;
+pub use group::{AssignToGroupCommand, CreateGroupCommand};
 pub use macro_command::MacroCommand;
 pub use remove_node::RemoveNodeCommand;
+pub use reset_inputs::ResetInputsCommand;
+pub use retarget::RetargetConnectionCommand;
 pub use set_default::SetInputDefaultCommand;
+pub use set_gizmo_visibility::SetGizmoVisibilityCommand;
+pub use set_muted::SetConnectionMutedCommand;
+pub use set_source::SetInputSourceCommand;
+pub use set_update_rate::SetUpdateRateCommand;
+pub use splice_node::{SpliceNodeCommand, UnspliceNodeCommand};
+pub use swap_inputs::SwapInputsCommand;
 
 use crate::graph::Graph;
 
@@ -71,7 +102,8 @@ use crate::graph::Graph;
 ///
 /// - Commands should store any state needed to undo the operation
 /// - `execute()` may be called multiple times (after undo/redo cycles)
-/// - Commands should be serializable for session persistence (future)
+/// - Commands that can be replayed from a [`Journal`](crate::journal::Journal)
+///   describe themselves through [`to_journal_entry`](Self::to_journal_entry)
 pub trait Command: std::fmt::Debug {
     /// Human-readable name for this command (shown in undo menu).
     fn name(&self) -> &str;
@@ -102,6 +134,16 @@ pub trait Command: std::fmt::Debug {
     fn merge(&mut self, _other: Box<dyn Command>) {
         // Default: no merging
     }
+
+    /// Describe the executed command for a crash recovery journal.
+    ///
+    /// Called after `execute()`. Return a serialized
+    /// [`JournalEntry`](crate::journal::JournalEntry) to have the command
+    /// replayed on recovery; the default `None` makes the journal write a
+    /// checkpoint of the whole graph instead.
+    fn to_journal_entry(&self) -> Option<serde_json::Value> {
+        None
+    }
 }
 
 #[cfg(test)]
@@ -14,6 +14,10 @@ use crate::graph::Graph;
 /// TO this node from other nodes. Those connections are broken permanently.
 /// For full connection restoration, use a MacroCommand that includes
 /// disconnect commands for each affected connection.
+///
+/// If the graph has a trash (see [`Graph::set_trash_capacity`]), the removed
+/// node is kept there rather than in the command, and undo restores it from
+/// the trash together with its connections.
 pub struct RemoveNodeCommand {
     /// The ID of the node to remove
     node_id: Id,
@@ -60,9 +64,11 @@ impl Command for RemoveNodeCommand {
     }
 
     fn undo(&mut self, graph: &mut Graph) {
-        // Re-add the operator
+        // Re-add the operator, or bring it back from the trash
         if let Some(operator) = self.operator.take() {
             graph.add_boxed(operator);
+        } else {
+            let _ = graph.restore_from_trash(self.node_id);
         }
     }
 }
@@ -121,6 +127,29 @@ mod tests {
         assert!(graph.get(id).is_none());
     }
 
+    #[test]
+    fn test_remove_node_undo_with_trash() {
+        let mut graph = Graph::new();
+        graph.set_trash_capacity(4);
+        let source = TestOp::source(42.0);
+        let source_id = source.id;
+        graph.add(source);
+        let sink = TestOp::new(0.0);
+        let sink_id = sink.id;
+        graph.add(sink);
+        graph.connect(source_id, 0, sink_id, 0).unwrap();
+
+        let mut cmd = RemoveNodeCommand::new(source_id);
+        cmd.execute(&mut graph);
+        assert!(cmd.operator.is_none());
+        assert_eq!(graph.trash().len(), 1);
+
+        cmd.undo(&mut graph);
+        assert!(graph.trash().is_empty());
+        assert!(graph.get(source_id).is_some());
+        assert_eq!(graph.get(sink_id).unwrap().inputs()[0].connection, Some((source_id, 0)));
+    }
+
     #[test]
     fn test_remove_nonexistent_node() {
         let mut graph = Graph::new();
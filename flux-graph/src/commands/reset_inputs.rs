@@ -0,0 +1,113 @@
+//! ResetInputsCommand - Reset input defaults to their factory values
+
+use flux_core::{Id, Value};
+
+use super::Command;
+use crate::graph::Graph;
+
+/// Command to reset one or all inputs of a node to their factory defaults.
+///
+/// On execute, the inputs are reset (see [`Graph::reset_input_to_factory`]).
+/// On undo, the defaults they had before are restored.
+#[derive(Debug, Clone)]
+pub struct ResetInputsCommand {
+    /// Node ID
+    node_id: Id,
+    /// Input to reset, or `None` for every modified input
+    input_index: Option<usize>,
+    /// Inputs that were reset and their previous defaults (for undo)
+    previous: Vec<(usize, Value)>,
+}
+
+impl ResetInputsCommand {
+    /// Create a command resetting a single input.
+    pub fn new(node_id: Id, input_index: usize) -> Self {
+        Self {
+            node_id,
+            input_index: Some(input_index),
+            previous: Vec::new(),
+        }
+    }
+
+    /// Create a command resetting every modified input of a node.
+    pub fn all(node_id: Id) -> Self {
+        Self {
+            node_id,
+            input_index: None,
+            previous: Vec::new(),
+        }
+    }
+}
+
+impl Command for ResetInputsCommand {
+    fn name(&self) -> &str {
+        match self.input_index {
+            Some(_) => "Reset Value",
+            None => "Reset All Values",
+        }
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        let indices = match self.input_index {
+            Some(index) => vec![index],
+            None => graph.modified_inputs(self.node_id),
+        };
+        for index in indices {
+            let Some(previous) = graph
+                .get(self.node_id)
+                .and_then(|op| op.inputs().get(index))
+                .map(|input| input.default.clone())
+            else {
+                continue;
+            };
+            if graph.reset_input_to_factory(self.node_id, index) {
+                self.previous.push((index, previous));
+            }
+        }
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        for (index, value) in self.previous.drain(..) {
+            graph.set_input_default(self.node_id, index, value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::tests::TestOp;
+
+    #[test]
+    fn test_reset_inputs_execute_undo() {
+        let mut graph = Graph::new();
+        let id = graph.add(TestOp::new(0.0));
+        graph.set_input_default(id, 0, Value::Float(5.0));
+        assert_eq!(graph.modified_inputs(id), vec![0]);
+
+        let mut cmd = ResetInputsCommand::all(id);
+        cmd.execute(&mut graph);
+        assert_eq!(graph.get(id).unwrap().inputs()[0].default, Value::Float(0.0));
+        assert!(graph.modified_inputs(id).is_empty());
+
+        cmd.undo(&mut graph);
+        assert_eq!(graph.get(id).unwrap().inputs()[0].default, Value::Float(5.0));
+
+        // Redo
+        cmd.execute(&mut graph);
+        assert_eq!(graph.get(id).unwrap().inputs()[0].default, Value::Float(0.0));
+    }
+
+    #[test]
+    fn test_reset_unmodified_input_is_noop() {
+        let mut graph = Graph::new();
+        let id = graph.add(TestOp::new(0.0));
+        graph.drain_events().for_each(drop);
+
+        let mut cmd = ResetInputsCommand::new(id, 0);
+        cmd.execute(&mut graph);
+        assert_eq!(graph.drain_events().count(), 0);
+        cmd.undo(&mut graph);
+        assert_eq!(graph.get(id).unwrap().inputs()[0].default, Value::Float(0.0));
+    }
+}
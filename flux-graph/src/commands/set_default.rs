@@ -1,9 +1,11 @@
 //! SetInputDefaultCommand - Change an input's default value
 
-use flux_core::{Id, Value};
+use flux_core::id::InputRef;
+use flux_core::{Id, InputValidationIssue, Value};
 
 use super::Command;
 use crate::graph::Graph;
+use crate::journal::JournalEntry;
 
 /// Command to change an input port's default value.
 ///
@@ -19,6 +21,8 @@ pub struct SetInputDefaultCommand {
     new_value: Value,
     /// Previous default value (for undo)
     previous_value: Option<Value>,
+    /// Issues the operator reported for its inputs after execute
+    issues: Vec<InputValidationIssue>,
     /// Whether the command was successfully executed
     executed: bool,
 }
@@ -31,14 +35,29 @@ impl SetInputDefaultCommand {
             input_index,
             new_value,
             previous_value: None,
+            issues: Vec::new(),
             executed: false,
         }
     }
 
+    /// Create a command for an input reference, e.g. one resolved by name
+    /// through [`GraphHandles::input`](crate::GraphHandles::input).
+    pub fn for_input(input: InputRef, new_value: Value) -> Self {
+        Self::new(input.node.id(), input.index.get(), new_value)
+    }
+
     /// Get the previous value (available after execute).
     pub fn previous_value(&self) -> Option<&Value> {
         self.previous_value.as_ref()
     }
+
+    /// Issues the node's operator reported for its inputs with the new value
+    /// applied (available after execute).
+    ///
+    /// The value is set either way; these are for showing inline warnings.
+    pub fn issues(&self) -> &[InputValidationIssue] {
+        &self.issues
+    }
 }
 
 impl Command for SetInputDefaultCommand {
@@ -47,15 +66,16 @@ impl Command for SetInputDefaultCommand {
     }
 
     fn execute(&mut self, graph: &mut Graph) {
-        // Access node directly to avoid lifetime issues with dyn Operator
-        if let Some(node) = graph.nodes.get_mut(&self.node_id) {
-            if let Some(input) = node.operator.inputs_mut().get_mut(self.input_index) {
-                // Store previous value for undo
-                self.previous_value = Some(input.default.clone());
-                // Set new value
-                input.default = self.new_value.clone();
-                self.executed = true;
-            }
+        // Store previous value for undo
+        let previous = graph
+            .get(self.node_id)
+            .and_then(|op| op.inputs().get(self.input_index))
+            .map(|input| input.default.clone());
+        // Set through the graph so caches are invalidated and an event is emitted
+        if graph.set_input_default(self.node_id, self.input_index, self.new_value.clone()) {
+            self.previous_value = previous;
+            self.issues = graph.input_issues(self.node_id).to_vec();
+            self.executed = true;
         }
     }
 
@@ -65,16 +85,21 @@ impl Command for SetInputDefaultCommand {
         }
 
         // Restore previous value
-        if let Some(ref prev) = self.previous_value {
-            if let Some(node) = graph.nodes.get_mut(&self.node_id) {
-                if let Some(input) = node.operator.inputs_mut().get_mut(self.input_index) {
-                    input.default = prev.clone();
-                }
-            }
+        if let Some(prev) = self.previous_value.clone() {
+            graph.set_input_default(self.node_id, self.input_index, prev);
         }
 
         self.executed = false;
     }
+
+    fn to_journal_entry(&self) -> Option<serde_json::Value> {
+        let entry = JournalEntry::SetInputDefault {
+            node: self.node_id,
+            input: self.input_index,
+            value: self.new_value.clone(),
+        };
+        self.executed.then(|| entry.to_json()).flatten()
+    }
 }
 
 #[cfg(test)]
@@ -96,6 +121,23 @@ mod tests {
         let node = graph.get(id).unwrap();
         assert_eq!(node.inputs()[0].default, Value::Float(42.0));
         assert_eq!(cmd.previous_value(), Some(&Value::Float(0.0)));
+        assert!(cmd.issues().is_empty());
+    }
+
+    #[test]
+    fn test_set_default_reports_issues() {
+        let mut graph = Graph::new();
+        let slice = graph.add(flux_operators::ListSliceOp::new());
+        graph.set_input_default(slice, 1, Value::Int(5));
+
+        let mut cmd = SetInputDefaultCommand::new(slice, 2, Value::Int(2));
+        cmd.execute(&mut graph);
+        assert_eq!(graph.get(slice).unwrap().inputs()[2].default, Value::Int(2));
+        assert_eq!(cmd.issues().len(), 1);
+        assert_eq!(cmd.issues()[0].input, 2);
+
+        cmd.undo(&mut graph);
+        assert!(graph.input_issues(slice).is_empty());
     }
 
     #[test]
@@ -0,0 +1,97 @@
+//! SetConnectionMutedCommand - Mute or unmute a connection
+
+use super::Command;
+use crate::graph::{Connection, Graph};
+
+/// Command to mute or unmute a value connection.
+///
+/// On execute, the connection is muted or unmuted as requested.
+/// On undo, it is put back in its previous state.
+#[derive(Debug, Clone)]
+pub struct SetConnectionMutedCommand {
+    /// The connection to change
+    connection: Connection,
+    /// New muted state
+    muted: bool,
+    /// Whether execute changed the state (for undo)
+    changed: bool,
+}
+
+impl SetConnectionMutedCommand {
+    /// Create a new SetConnectionMutedCommand.
+    pub fn new(connection: Connection, muted: bool) -> Self {
+        Self {
+            connection,
+            muted,
+            changed: false,
+        }
+    }
+}
+
+impl Command for SetConnectionMutedCommand {
+    fn name(&self) -> &str {
+        if self.muted {
+            "Mute Connection"
+        } else {
+            "Unmute Connection"
+        }
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        self.changed = graph
+            .set_connection_muted(self.connection, self.muted)
+            .unwrap_or(false);
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        if std::mem::take(&mut self.changed) {
+            let connection = Connection {
+                muted: self.muted,
+                ..self.connection
+            };
+            let _ = graph.set_connection_muted(connection, !self.muted);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::tests::TestOp;
+
+    #[test]
+    fn test_set_connection_muted_execute_undo() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source(1.0));
+        let target = graph.add(TestOp::new(0.0));
+        graph.connect(source, 0, target, 0).unwrap();
+        let connection = graph.connections().next().unwrap();
+
+        let mut cmd = SetConnectionMutedCommand::new(connection, true);
+        cmd.execute(&mut graph);
+        assert!(graph.connections().next().unwrap().muted);
+
+        cmd.undo(&mut graph);
+        assert!(!graph.connections().next().unwrap().muted);
+
+        // Redo
+        cmd.execute(&mut graph);
+        assert!(graph.connections().next().unwrap().muted);
+    }
+
+    #[test]
+    fn test_set_connection_muted_noop_undo() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source(1.0));
+        let target = graph.add(TestOp::new(0.0));
+        graph.connect(source, 0, target, 0).unwrap();
+        let connection = graph.connections().next().unwrap();
+        graph.set_connection_muted(connection, true).unwrap();
+
+        // Already muted: undo must not unmute it
+        let mut cmd = SetConnectionMutedCommand::new(connection, true);
+        cmd.execute(&mut graph);
+        cmd.undo(&mut graph);
+        assert!(graph.connections().next().unwrap().muted);
+    }
+}
@@ -0,0 +1,91 @@
+//! SetUpdateRateCommand - Change a node's update rate
+
+use flux_core::Id;
+
+use super::Command;
+use crate::graph::Graph;
+use crate::update_rate::UpdateRate;
+
+/// Command to change how often a node is recomputed.
+///
+/// On execute, the node's update rate is changed to the new mode.
+/// On undo, the previous update rate is restored.
+#[derive(Debug, Clone)]
+pub struct SetUpdateRateCommand {
+    /// Node ID
+    node_id: Id,
+    /// New update rate
+    new_rate: UpdateRate,
+    /// Previous update rate (for undo)
+    previous_rate: Option<UpdateRate>,
+}
+
+impl SetUpdateRateCommand {
+    /// Create a new SetUpdateRateCommand.
+    pub fn new(node_id: Id, new_rate: UpdateRate) -> Self {
+        Self {
+            node_id,
+            new_rate,
+            previous_rate: None,
+        }
+    }
+
+    /// Get the previous update rate (available after execute).
+    pub fn previous_rate(&self) -> Option<UpdateRate> {
+        self.previous_rate
+    }
+}
+
+impl Command for SetUpdateRateCommand {
+    fn name(&self) -> &str {
+        "Set Update Rate"
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        if let Some(previous) = graph.update_rate(self.node_id) {
+            self.previous_rate = Some(previous);
+            graph.set_update_rate(self.node_id, self.new_rate);
+        }
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        if let Some(previous) = self.previous_rate.take() {
+            graph.set_update_rate(self.node_id, previous);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::tests::TestOp;
+
+    #[test]
+    fn test_set_update_rate_execute_undo() {
+        let mut graph = Graph::new();
+        let op = TestOp::new(0.0);
+        let id = op.id;
+        graph.add(op);
+
+        let mut cmd = SetUpdateRateCommand::new(id, UpdateRate::Hertz(10.0));
+        cmd.execute(&mut graph);
+        assert_eq!(graph.update_rate(id), Some(UpdateRate::Hertz(10.0)));
+        assert_eq!(cmd.previous_rate(), Some(UpdateRate::EveryFrame));
+
+        cmd.undo(&mut graph);
+        assert_eq!(graph.update_rate(id), Some(UpdateRate::EveryFrame));
+
+        // Redo
+        cmd.execute(&mut graph);
+        assert_eq!(graph.update_rate(id), Some(UpdateRate::Hertz(10.0)));
+    }
+
+    #[test]
+    fn test_set_update_rate_missing_node() {
+        let mut graph = Graph::new();
+        let mut cmd = SetUpdateRateCommand::new(Id::new(), UpdateRate::OnDemand);
+        cmd.execute(&mut graph);
+        assert_eq!(cmd.previous_rate(), None);
+        cmd.undo(&mut graph);
+    }
+}
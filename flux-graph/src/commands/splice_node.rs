@@ -0,0 +1,183 @@
+//! SpliceNodeCommand / UnspliceNodeCommand - Insert or lift a node on a wire
+
+use flux_core::Id;
+
+use super::Command;
+use crate::graph::{Connection, Graph, SpliceRecord, UnspliceRecord};
+
+/// Command to insert a node into an existing connection.
+///
+/// On execute, the connection is split through the node (see
+/// [`Graph::splice_node`]). On undo, any conversion nodes inserted by the
+/// splice are removed and the original connection is restored.
+#[derive(Debug, Clone)]
+pub struct SpliceNodeCommand {
+    /// The connection to split
+    connection: Connection,
+    /// The node to insert
+    node_id: Id,
+    /// Input on the node that receives the original source
+    in_idx: usize,
+    /// Output on the node that feeds the original target
+    out_idx: usize,
+    /// Undo information (set after a successful execute)
+    record: Option<SpliceRecord>,
+}
+
+impl SpliceNodeCommand {
+    /// Create a new SpliceNodeCommand.
+    pub fn new(connection: Connection, node_id: Id, in_idx: usize, out_idx: usize) -> Self {
+        Self {
+            connection,
+            node_id,
+            in_idx,
+            out_idx,
+            record: None,
+        }
+    }
+
+    /// Returns true if the last execute succeeded.
+    pub fn is_applied(&self) -> bool {
+        self.record.is_some()
+    }
+}
+
+impl Command for SpliceNodeCommand {
+    fn name(&self) -> &str {
+        "Splice Node"
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        match graph.splice_node_recorded(self.connection, self.node_id, self.in_idx, self.out_idx) {
+            Ok(record) => self.record = Some(record),
+            Err(e) => {
+                eprintln!("SpliceNodeCommand failed: {}", e);
+                self.record = None;
+            }
+        }
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        if let Some(record) = self.record.take() {
+            graph.undo_splice(&record);
+        }
+    }
+}
+
+/// Command to remove a node from a wire, reconnecting its neighbours.
+///
+/// On execute, the node is removed and its upstream source is connected to
+/// its downstream target (see [`Graph::unsplice_node`]). On undo, the node is
+/// re-added with its original connections.
+pub struct UnspliceNodeCommand {
+    /// The node to remove
+    node_id: Id,
+    /// Undo information (set after a successful execute)
+    record: Option<UnspliceRecord>,
+}
+
+impl std::fmt::Debug for UnspliceNodeCommand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("UnspliceNodeCommand")
+            .field("node_id", &self.node_id)
+            .field("has_record", &self.record.is_some())
+            .finish()
+    }
+}
+
+impl UnspliceNodeCommand {
+    /// Create a new UnspliceNodeCommand.
+    pub fn new(node_id: Id) -> Self {
+        Self {
+            node_id,
+            record: None,
+        }
+    }
+
+    /// Returns true if the last execute succeeded.
+    pub fn is_applied(&self) -> bool {
+        self.record.is_some()
+    }
+}
+
+impl Command for UnspliceNodeCommand {
+    fn name(&self) -> &str {
+        "Unsplice Node"
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        match graph.unsplice_node_recorded(self.node_id) {
+            Ok(record) => self.record = Some(record),
+            Err(e) => {
+                eprintln!("UnspliceNodeCommand failed: {}", e);
+                self.record = None;
+            }
+        }
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        if let Some(record) = self.record.take() {
+            debug_assert_eq!(record.node_id(), self.node_id);
+            graph.undo_unsplice(record);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::tests::TestOp;
+    use flux_core::EvalContext;
+
+    fn chain() -> (Graph, Id, Id, Id) {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source(3.0));
+        let middle = graph.add(TestOp::new(0.0));
+        let sink = graph.add(TestOp::new(0.0));
+        graph.connect(source, 0, sink, 0).unwrap();
+        (graph, source, middle, sink)
+    }
+
+    fn source_of(graph: &Graph, node: Id) -> Option<(Id, usize)> {
+        graph.get(node).unwrap().inputs()[0].connection
+    }
+
+    #[test]
+    fn test_splice_execute_undo() {
+        let (mut graph, source, middle, sink) = chain();
+        let connection = graph.upstream_of(sink)[0];
+
+        let mut cmd = SpliceNodeCommand::new(connection, middle, 0, 0);
+        cmd.execute(&mut graph);
+        assert!(cmd.is_applied());
+        assert_eq!(source_of(&graph, middle), Some((source, 0)));
+        assert_eq!(source_of(&graph, sink), Some((middle, 0)));
+
+        cmd.undo(&mut graph);
+        assert_eq!(source_of(&graph, middle), None);
+        assert_eq!(source_of(&graph, sink), Some((source, 0)));
+
+        // Redo
+        cmd.execute(&mut graph);
+        assert_eq!(source_of(&graph, sink), Some((middle, 0)));
+    }
+
+    #[test]
+    fn test_unsplice_execute_undo() {
+        let (mut graph, source, middle, sink) = chain();
+        let connection = graph.upstream_of(sink)[0];
+        graph.splice_node(connection, middle, 0, 0).unwrap();
+
+        let mut cmd = UnspliceNodeCommand::new(middle);
+        cmd.execute(&mut graph);
+        assert!(cmd.is_applied());
+        assert!(graph.get(middle).is_none());
+        assert_eq!(source_of(&graph, sink), Some((source, 0)));
+
+        cmd.undo(&mut graph);
+        assert_eq!(source_of(&graph, middle), Some((source, 0)));
+        assert_eq!(source_of(&graph, sink), Some((middle, 0)));
+        let ctx = EvalContext::new();
+        graph.evaluate(sink, 0, &ctx).unwrap();
+    }
+}
@@ -0,0 +1,137 @@
+//! SwapInputsCommand - Exchange two inputs of a node
+
+use flux_core::{Id, Value};
+
+use super::Command;
+use crate::graph::Graph;
+
+/// Command to exchange the connections, defaults and overrides of two inputs.
+///
+/// On execute, the inputs are swapped (see [`Graph::swap_inputs`]).
+/// On undo, they are swapped back and any default that didn't survive the
+/// round trip through a type conversion is restored.
+#[derive(Debug, Clone)]
+pub struct SwapInputsCommand {
+    /// Node ID
+    node_id: Id,
+    /// First input port index
+    input_a: usize,
+    /// Second input port index
+    input_b: usize,
+    /// Defaults of both inputs before execute (set after a successful execute)
+    previous_defaults: Option<(Value, Value)>,
+}
+
+impl SwapInputsCommand {
+    /// Create a new SwapInputsCommand.
+    pub fn new(node_id: Id, input_a: usize, input_b: usize) -> Self {
+        Self {
+            node_id,
+            input_a,
+            input_b,
+            previous_defaults: None,
+        }
+    }
+
+    /// Returns true if the last execute succeeded.
+    pub fn is_applied(&self) -> bool {
+        self.previous_defaults.is_some()
+    }
+
+    fn defaults(&self, graph: &Graph) -> Option<(Value, Value)> {
+        let inputs = graph.get(self.node_id)?.inputs();
+        let default = |index: usize| inputs.get(index).map(|input| input.default.clone());
+        Some((default(self.input_a)?, default(self.input_b)?))
+    }
+}
+
+impl Command for SwapInputsCommand {
+    fn name(&self) -> &str {
+        "Swap Inputs"
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        let defaults = self.defaults(graph);
+        match graph.swap_inputs(self.node_id, self.input_a, self.input_b) {
+            Ok(()) => self.previous_defaults = defaults,
+            Err(e) => {
+                eprintln!("SwapInputsCommand failed: {}", e);
+                self.previous_defaults = None;
+            }
+        }
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        let Some((default_a, default_b)) = self.previous_defaults.take() else {
+            return;
+        };
+        if graph.swap_inputs(self.node_id, self.input_a, self.input_b).is_err() {
+            return;
+        }
+        // Coercing between differing port types can be lossy (e.g. Float to Int)
+        if let Some((current_a, current_b)) = self.defaults(graph) {
+            if current_a != default_a {
+                graph.set_input_default(self.node_id, self.input_a, default_a);
+            }
+            if current_b != default_b {
+                graph.set_input_default(self.node_id, self.input_b, default_b);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Connection;
+    use flux_core::EvalContext;
+    use flux_operators::SubtractOp;
+
+    fn wiring(graph: &Graph) -> Vec<Connection> {
+        let mut connections: Vec<Connection> = graph.connections().collect();
+        connections.sort_by_key(|c| (c.target_input, c.source_output));
+        connections
+    }
+
+    #[test]
+    fn test_swap_inputs_undo_restores_wiring() {
+        let mut graph = Graph::new();
+        let a = graph.add(SubtractOp::new());
+        let sub = graph.add(SubtractOp::new());
+        graph.set_input_default(a, 0, Value::Float(7.0));
+        graph.connect(a, 0, sub, 0).unwrap();
+        graph.set_input_default(sub, 1, Value::Float(2.0));
+        let before = wiring(&graph);
+
+        let mut cmd = SwapInputsCommand::new(sub, 0, 1);
+        cmd.execute(&mut graph);
+        assert!(cmd.is_applied());
+        assert_ne!(wiring(&graph), before);
+
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sub, 0, &ctx).unwrap(), Value::Float(-5.0));
+
+        cmd.undo(&mut graph);
+        assert_eq!(wiring(&graph), before);
+        let inputs = graph.get(sub).unwrap().inputs();
+        assert_eq!(inputs[0].default, Value::Float(0.0));
+        assert_eq!(inputs[1].default, Value::Float(2.0));
+        assert_eq!(graph.evaluate(sub, 0, &ctx).unwrap(), Value::Float(5.0));
+
+        // Redo
+        cmd.execute(&mut graph);
+        assert_eq!(graph.get(sub).unwrap().inputs()[1].connection, Some((a, 0)));
+    }
+
+    #[test]
+    fn test_swap_inputs_failure_is_not_undone() {
+        let mut graph = Graph::new();
+        let sub = graph.add(SubtractOp::new());
+
+        let mut cmd = SwapInputsCommand::new(sub, 0, 5);
+        cmd.execute(&mut graph);
+        assert!(!cmd.is_applied());
+        cmd.undo(&mut graph);
+        assert_eq!(graph.get(sub).unwrap().inputs()[0].default, Value::Float(0.0));
+    }
+}
@@ -32,7 +32,7 @@ use std::collections::HashMap;
 
 use flux_core::{EvalContext, Id, Value};
 
-use crate::graph::{Graph, GraphError};
+use crate::graph::{live_sources, Graph, GraphError};
 
 /// A compiled representation of a graph, optimized for execution.
 ///
@@ -88,20 +88,30 @@ impl CompiledGraph {
                 // The node_output_base map lets us convert source_id lookups to buffer indices
                 let node_output_base = &self.node_output_base;
                 let outputs_ref = &outputs;
+                let clamps = node.connected_clamps();
 
                 let get_input = |source_id: Id, source_output: usize| -> Value {
                     // Look up the base index for the source node
-                    if let Some(&base) = node_output_base.get(&source_id) {
+                    let value = if let Some(&base) = node_output_base.get(&source_id) {
                         outputs_ref
                             .get(base + source_output)
                             .cloned()
                             .unwrap_or_default()
                     } else {
                         Value::Float(0.0)
+                    };
+                    // Enforce clamped input ranges, as Graph::evaluate does
+                    match clamps.iter().find(|(source, _)| *source == (source_id, source_output)) {
+                        Some((_, clamp)) => clamp.apply(value),
+                        None => value,
                     }
                 };
 
+                let muted = node.apply_connection_mutes();
+                let clamped = node.apply_default_clamps();
                 node.operator.compute(ctx, &get_input);
+                node.restore_input_defaults(clamped);
+                node.restore_connection_mutes(muted);
 
                 // Copy outputs to buffer
                 for (i, output) in node.operator.outputs().iter().enumerate() {
@@ -360,14 +370,8 @@ impl Graph {
 
             if let Some(node) = self.nodes.get(&current) {
                 for input in node.operator.inputs() {
-                    // Check single connection
-                    if let Some((dep_id, _)) = input.connection {
-                        if !deps.contains(&dep_id) {
-                            stack.push(dep_id);
-                        }
-                    }
-                    // Check multi-input connections
-                    for &(dep_id, _) in &input.connections {
+                    // Muted connections don't contribute to the result
+                    for (dep_id, _) in live_sources(input) {
                         if !deps.contains(&dep_id) {
                             stack.push(dep_id);
                         }
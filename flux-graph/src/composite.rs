@@ -1,12 +1,18 @@
 use std::any::Any;
+use std::collections::HashMap;
 
 use flux_core::context::EvalContext;
+use flux_core::error::OperatorError;
+use crate::animation::{AnimationTarget, Animator};
 use crate::graph::{Graph, GraphError};
 use flux_core::id::Id;
 use crate::instance_path::InstancePath;
+use crate::serialization::AnimationOverride;
+use crate::symbol::{Symbol, SymbolError, SymbolRegistry};
 use flux_core::operator::{InputResolver, Operator};
 use flux_core::port::{InputPort, OutputPort};
 use flux_core::value::{Value, ValueType};
+use flux_operators::OperatorRegistry;
 
 /// An exposed slot that maps external inputs/outputs to internal nodes
 #[derive(Clone, Debug)]
@@ -48,6 +54,16 @@ pub struct CompositeOp {
     /// Mapping from external output index to internal node/slot
     exposed_outputs: Vec<ExposedSlot>,
 
+    /// Internal node for each symbol child (when built from a Symbol)
+    child_nodes: HashMap<Id, Id>,
+
+    /// Animation of internal node inputs
+    animator: Animator,
+    /// Added to the outer time to get the composite's local time
+    time_offset: f64,
+    /// Local time the animation was last applied at
+    animation_time: Option<f64>,
+
     /// Instance path for nested evaluation
     #[allow(dead_code)]
     instance_path: InstancePath,
@@ -65,10 +81,142 @@ impl CompositeOp {
             outputs: Vec::new(),
             exposed_inputs: Vec::new(),
             exposed_outputs: Vec::new(),
+            child_nodes: HashMap::new(),
+            animator: Animator::new(),
+            time_offset: 0.0,
+            animation_time: None,
             instance_path: InstancePath::root(id),
         }
     }
 
+    /// Build a composite from a symbol whose children are registered operators
+    ///
+    /// Each child's symbol name is looked up in `operators`. Child input
+    /// overrides, internal connections and promoted inputs/outputs carry
+    /// over, as does the symbol's animation, retargeted to the internal
+    /// nodes. Symbol inputs and outputs that were not promoted from a child
+    /// have nothing to drive and are skipped.
+    pub fn from_symbol(
+        symbol: &Symbol,
+        symbols: &SymbolRegistry,
+        operators: &OperatorRegistry,
+    ) -> Result<Self, SymbolError> {
+        // Leak the names to get &'static str, as Instance does
+        symbol.check_recursion(symbols)?;
+
+        let name: &'static str = Box::leak(symbol.name.clone().into_boxed_str());
+        let mut composite = Self::new(name);
+
+        for child in symbol.children.values() {
+            let child_symbol = symbols
+                .get(child.symbol_id)
+                .ok_or(SymbolError::SymbolNotFound(child.symbol_id))?;
+            let (op, meta) = operators
+                .create_with_meta_by_name(&child_symbol.name)
+                .ok_or_else(|| SymbolError::OperatorNotFound(child_symbol.name.clone()))?;
+            let node = composite.subgraph.add_boxed_with_meta(op, &meta);
+            composite.child_nodes.insert(child.id, node);
+
+            for (index, input) in child_symbol.input_definitions.iter().enumerate() {
+                if let Some(value) = child.get_input_value(input.id) {
+                    if child.has_input_override(input.id) {
+                        composite.subgraph.set_input_default(node, index, value.clone());
+                    }
+                }
+            }
+        }
+
+        for connection in &symbol.connections {
+            let source = connection.source.node_id().and_then(|id| composite.child_node(id));
+            let target = connection.target.node_id().and_then(|id| composite.child_node(id));
+            let (Some(source), Some(target)) = (source, target) else {
+                return Err(SymbolError::InvalidConnection);
+            };
+            composite
+                .connect_internal(
+                    source,
+                    connection.source.slot_index,
+                    target,
+                    connection.target.slot_index,
+                )
+                .map_err(|_| SymbolError::InvalidConnection)?;
+        }
+
+        for input in &symbol.input_definitions {
+            let Some(target) = input.target else { continue };
+            let node = composite
+                .child_node(target.child_id)
+                .ok_or(SymbolError::ChildNotFound(target.child_id))?;
+            let name: &'static str = Box::leak(input.name.clone().into_boxed_str());
+            let index = composite
+                .expose_input(name, node, target.slot_index)
+                .map_err(|_| SymbolError::ChildSlotNotFound(target.child_id, target.slot_index))?;
+            composite.inputs[index].default = input.default_value.clone();
+        }
+
+        for output in &symbol.output_definitions {
+            let Some(source) = output.source else { continue };
+            let node = composite
+                .child_node(source.child_id)
+                .ok_or(SymbolError::ChildNotFound(source.child_id))?;
+            let name: &'static str = Box::leak(output.name.clone().into_boxed_str());
+            composite
+                .expose_output(name, node, source.slot_index)
+                .map_err(|_| SymbolError::ChildSlotNotFound(source.child_id, source.slot_index))?;
+        }
+
+        composite.animator = symbol.animator.clone();
+        composite.animator.clear_bindings();
+        for binding in symbol.animator.bindings() {
+            let child_id = binding.target.node_id;
+            let node = composite.child_node(child_id).ok_or(SymbolError::ChildNotFound(child_id))?;
+            let mut binding = binding.clone();
+            binding.target.node_id = node;
+            composite.animator.add_binding(binding);
+        }
+
+        Ok(composite)
+    }
+
+    /// Internal node created for a symbol child (see [`from_symbol`](Self::from_symbol))
+    pub fn child_node(&self, child_id: Id) -> Option<Id> {
+        self.child_nodes.get(&child_id).copied()
+    }
+
+    /// Animation of internal node inputs
+    pub fn animator(&self) -> &Animator {
+        &self.animator
+    }
+
+    /// Get the animator mutably (bindings target internal node IDs)
+    pub fn animator_mut(&mut self) -> &mut Animator {
+        // The bindings may change, so the next frame writes every value
+        self.animation_time = None;
+        &mut self.animator
+    }
+
+    /// Offset added to the outer time before sampling the animation
+    pub fn time_offset(&self) -> f64 {
+        self.time_offset
+    }
+
+    /// Shift this composite's animation against others built from the same symbol
+    pub fn set_time_offset(&mut self, offset: f64) {
+        self.time_offset = offset;
+    }
+
+    /// Disable or retarget the animation of a symbol child's input
+    ///
+    /// Returns false if the child is unknown, or its input isn't animated
+    /// and the override brings no curve of its own.
+    pub fn apply_animation_override(&mut self, animation: &AnimationOverride) -> bool {
+        let Some(node) = self.child_node(animation.target_child) else {
+            return false;
+        };
+        self.animation_time = None;
+        animation.apply(&mut self.animator, AnimationTarget::new(node, animation.target_input))
+    }
+
     /// Add an operator to the internal subgraph
     pub fn add<O: Operator + 'static>(&mut self, op: O) -> Id {
         self.subgraph.add(op)
@@ -170,6 +318,8 @@ impl CompositeOp {
 
     /// Get the internal subgraph mutably
     pub fn subgraph_mut(&mut self) -> &mut Graph {
+        // Animated inputs may be edited, so the next frame checks them all
+        self.animation_time = None;
         &mut self.subgraph
     }
 
@@ -182,6 +332,21 @@ impl CompositeOp {
     pub fn exposed_outputs(&self) -> &[ExposedSlot] {
         &self.exposed_outputs
     }
+
+    /// Unwrap the internal subgraph
+    ///
+    /// The current default of each exposed input is written to the internal
+    /// input it maps to, so the subgraph evaluates the same on its own.
+    pub fn into_subgraph(mut self) -> Graph {
+        for (exposed, input) in self.exposed_inputs.iter().zip(&self.inputs) {
+            self.subgraph.set_input_default(
+                exposed.internal_node,
+                exposed.internal_slot_index,
+                input.default.clone(),
+            );
+        }
+        self.subgraph
+    }
 }
 
 impl Operator for CompositeOp {
@@ -217,7 +382,20 @@ impl Operator for CompositeOp {
         &mut self.outputs
     }
 
+    fn is_time_varying(&self) -> bool {
+        self.animator.binding_count() > 0
+    }
+
     fn compute(&mut self, ctx: &EvalContext, get_input_value: InputResolver) {
+        // Step 0: Enter the composite, failing cleanly if nested too deep
+        let inner_ctx = match ctx.descend(self.name) {
+            Ok(inner_ctx) => inner_ctx,
+            Err(e) => {
+                ctx.report_error(e);
+                return;
+            }
+        };
+
         // Step 1: Collect external input values (before borrowing subgraph)
         let input_values: Vec<(Id, usize, Value)> = self
             .exposed_inputs
@@ -234,7 +412,19 @@ impl Operator for CompositeOp {
 
         // Step 2: Apply input values to internal nodes using Graph helper
         for (internal_node, internal_slot_index, value) in input_values {
-            self.subgraph.set_input_default(internal_node, internal_slot_index, value);
+            set_changed_default(&mut self.subgraph, internal_node, internal_slot_index, value);
+        }
+
+        // Step 2b: Apply animated values at the composite's local time,
+        // skipping curves that are flat since the last application
+        if self.animator.binding_count() > 0 {
+            self.animator.set_time(ctx.time + self.time_offset);
+            let local_time = self.animator.current_time();
+            let previous = self.animation_time.replace(local_time);
+            for (target, value) in self.animator.sample_changed(previous, local_time) {
+                let value = Value::Float(value as f32);
+                set_changed_default(&mut self.subgraph, target.node_id, target.input_index, value);
+            }
         }
 
         // Step 3: Collect output node/slot info (before borrowing subgraph again)
@@ -247,10 +437,15 @@ impl Operator for CompositeOp {
         // Step 4: Evaluate the internal subgraph for each exposed output
         for (ext_idx, (internal_node, internal_slot_index)) in output_targets.into_iter().enumerate()
         {
-            match self.subgraph.evaluate(internal_node, internal_slot_index, ctx) {
+            match self.subgraph.evaluate(internal_node, internal_slot_index, &inner_ctx) {
                 Ok(value) => {
                     self.outputs[ext_idx].set(value);
                 }
+                Err(GraphError::DepthLimitExceeded { path }) => {
+                    // Pass the failure up to the graph evaluating this composite
+                    ctx.report_error(OperatorError::depth_limit_exceeded(path, ctx.max_depth));
+                    return;
+                }
                 Err(e) => {
                     eprintln!(
                         "  [{}] Error evaluating internal graph: {}",
@@ -326,6 +521,28 @@ impl CompositeBuilder {
     }
 }
 
+/// Set an internal input's default unless it already holds `value`
+///
+/// Writing an equal value would still invalidate the node's cache entry.
+pub(crate) fn set_changed_default(subgraph: &mut Graph, node: Id, input: usize, value: Value) {
+    let current = subgraph.get(node).and_then(|op| op.inputs().get(input));
+    if current.is_none_or(|port| port.default != value) {
+        subgraph.set_input_default(node, input, value);
+    }
+}
+
+/// Find the graph holding the node at the end of `path`
+///
+/// Every segment before the last must be a [`CompositeOp`].
+pub(crate) fn resolve_node<'a>(graph: &'a mut Graph, path: &InstancePath) -> Option<(&'a mut Graph, Id)> {
+    let (&node, parents) = path.segments().split_last()?;
+    let mut graph = graph;
+    for &parent in parents {
+        graph = graph.get_mut_as::<CompositeOp>(parent)?.subgraph_mut();
+    }
+    Some((graph, node))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,4 +584,214 @@ mod tests {
         assert_eq!(composite.outputs().len(), 1);
         assert_eq!(composite.name(), "AddAndDouble");
     }
+
+    #[test]
+    fn test_composite_from_symbol_with_promoted_slots() {
+        use crate::slot_ref::Connection;
+        use crate::symbol::{InputDefinition, OutputDefinition, SymbolChild};
+        use flux_operators::create_default_registry;
+
+        let symbols = SymbolRegistry::new();
+        let binary = |name: &str| {
+            let mut symbol = Symbol::new(name);
+            symbol.add_input(InputDefinition::float("A", 0.0));
+            symbol.add_input(InputDefinition::float("B", 0.0));
+            symbol.add_output(OutputDefinition::float("Result"));
+            symbol
+        };
+        let add_id = symbols.register(binary("Add"));
+        let multiply = binary("Multiply");
+        let factor_id = multiply.input_definitions[1].id;
+        let multiply_id = symbols.register(multiply);
+
+        // (X + 0) * 2, with X promoted from Add.A and Out from Multiply.Result
+        let mut symbol = Symbol::new("Doubler");
+        let add = symbol.add_child(SymbolChild::new(Id::new(), add_id));
+        let mut mul_child = SymbolChild::new(Id::new(), multiply_id);
+        mul_child.set_input_value(factor_id, Value::Float(2.0));
+        let mul = symbol.add_child(mul_child);
+        symbol.add_connection(Connection::simple(add, 0, mul, 0)).unwrap();
+        symbol.promote_child_input(&symbols, add, 0, "X").unwrap();
+        symbol.promote_child_output(&symbols, mul, 0, "Out").unwrap();
+
+        let composite =
+            CompositeOp::from_symbol(&symbol, &symbols, &create_default_registry()).unwrap();
+        assert_eq!(composite.inputs().len(), 1);
+        assert_eq!(composite.outputs().len(), 1);
+        let add_node = composite.child_node(add).unwrap();
+
+        let mut graph = Graph::new();
+        let five = graph.add(ConstantOp::new(5.0));
+        let doubler = graph.add(composite);
+        graph.connect(five, 0, doubler, 0).unwrap();
+
+        let result = graph.evaluate(doubler, 0, &EvalContext::new()).unwrap();
+        assert_eq!(result, Value::Float(10.0));
+
+        // The internal Add saw the outer value
+        let composite = graph
+            .get(doubler)
+            .and_then(|op| op.as_any().downcast_ref::<CompositeOp>())
+            .unwrap();
+        let add_input = &composite.subgraph().get(add_node).unwrap().inputs()[0];
+        assert_eq!(add_input.default, Value::Float(5.0));
+    }
+
+    /// `levels` composites nested inside each other around a constant
+    fn nested_composites(levels: usize) -> CompositeOp {
+        let mut composite = CompositeOp::new("Nested");
+        let constant = composite.add(ConstantOp::new(1.0));
+        composite.expose_output("Out", constant, 0).unwrap();
+        for _ in 1..levels {
+            let mut outer = CompositeOp::new("Nested");
+            let inner = outer.add(composite);
+            outer.expose_output("Out", inner, 0).unwrap();
+            composite = outer;
+        }
+        composite
+    }
+
+    #[test]
+    fn test_composite_depth_limit() {
+        let mut graph = Graph::new();
+        let shallow = graph.add(nested_composites(5));
+        let result = graph.evaluate(shallow, 0, &EvalContext::new()).unwrap();
+        assert_eq!(result, Value::Float(1.0));
+
+        // 70 levels exceed the default limit of 64 with an error, not a crash
+        let deep = graph.add(nested_composites(70));
+        match graph.evaluate(deep, 0, &EvalContext::new()) {
+            Err(GraphError::DepthLimitExceeded { path }) => {
+                assert_eq!(path.len(), flux_core::DEFAULT_MAX_DEPTH + 1);
+                assert!(path.iter().all(|name| name == "Nested"));
+            }
+            other => panic!("expected DepthLimitExceeded, got {:?}", other),
+        }
+        // Retrying fails the same way instead of returning a stale value
+        assert!(matches!(
+            graph.evaluate(deep, 0, &EvalContext::new()),
+            Err(GraphError::DepthLimitExceeded { .. })
+        ));
+
+        // The limit is configurable on the outermost graph
+        graph.set_max_depth(4);
+        match graph.evaluate(shallow, 0, &EvalContext::new()) {
+            Err(GraphError::DepthLimitExceeded { path }) => assert_eq!(path.len(), 5),
+            other => panic!("expected DepthLimitExceeded, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_symbol_animation_with_time_offsets() {
+        use crate::serialization::{
+            load_symbol_str, save_symbol_str, AnimationDef, ChildDef, CurveDef, ExtrapolationMode,
+            InstanceOverride, KeyframeDef, SymbolDef, SymbolFile,
+        };
+        use crate::symbol::{InputDefinition, OutputDefinition, SymbolChild};
+        use flux_operators::create_default_registry;
+
+        // "Pulse": a constant ramping from 0 to 10 over a second, repeating
+        let constant = ChildDef::builtin("Constant");
+        let mut ramp = AnimationDef::new(constant.id, 0).with_target_name("Value");
+        ramp.add_keyframe(KeyframeDef::new(0.0, 0.0)).add_keyframe(KeyframeDef::new(1.0, 10.0));
+        ramp.curve.post_behavior = ExtrapolationMode::Cycle;
+        let mut def = SymbolDef::new("Pulse");
+        def.add_child(constant);
+        def.animations.push(ramp);
+        let json = save_symbol_str(&SymbolFile::from_def(def)).unwrap();
+        let file = load_symbol_str(&json).unwrap();
+
+        // Runtime symbol with the file's child and animation
+        let symbols = SymbolRegistry::new();
+        let mut constant_symbol = Symbol::new("Constant");
+        let value_id = constant_symbol.add_input(InputDefinition::float("Value", 0.0));
+        constant_symbol.add_output(OutputDefinition::float("Value"));
+        let constant_symbol = symbols.register(constant_symbol);
+        let child = file.symbol.children[0].id;
+        let mut pulse = Symbol::new(file.symbol.name.clone());
+        let mut constant = SymbolChild::new(child, constant_symbol);
+        constant.set_input_value(value_id, Value::Float(4.0));
+        pulse.add_child(constant);
+        pulse.promote_child_output(&symbols, child, 0, "Out").unwrap();
+        pulse.animator = file.symbol.animator();
+
+        let operators = create_default_registry();
+        let instance = |offset: f64, overrides: &InstanceOverride| {
+            let mut composite = CompositeOp::from_symbol(&pulse, &symbols, &operators).unwrap();
+            composite.set_time_offset(offset);
+            for animation in &overrides.animations {
+                assert!(composite.apply_animation_override(animation));
+            }
+            composite
+        };
+        let none = InstanceOverride::new("pulse");
+        let disabled = InstanceOverride::new("pulse")
+            .with_animation(AnimationOverride::disable(child, 0));
+        let mut steep = CurveDef::new();
+        steep.add_keyframe(KeyframeDef::new(0.0, 0.0));
+        steep.add_keyframe(KeyframeDef::new(1.0, 100.0));
+        let retargeted = InstanceOverride::new("pulse")
+            .with_animation(AnimationOverride::retarget(child, 0, steep));
+        let retargeted: InstanceOverride =
+            serde_json::from_str(&serde_json::to_string(&retargeted).unwrap()).unwrap();
+
+        let mut graph = Graph::new();
+        let first = graph.add(instance(0.0, &none));
+        let second = graph.add(instance(0.5, &none));
+        let still = graph.add(instance(0.0, &disabled));
+        let steep = graph.add(instance(0.0, &retargeted));
+
+        let mut ctx = EvalContext::new();
+        let mut sample = |time: f64| {
+            ctx.time = time;
+            [first, second, still, steep].map(|id| graph.evaluate(id, 0, &ctx).unwrap())
+        };
+        let floats = |values: [f32; 4]| values.map(Value::Float);
+        assert_eq!(sample(0.25), floats([2.5, 7.5, 4.0, 25.0]));
+        // The offset instance wraps around half a loop earlier
+        assert_eq!(sample(0.75), floats([7.5, 2.5, 4.0, 75.0]));
+        assert_eq!(sample(2.25), floats([2.5, 7.5, 4.0, 25.0]));
+    }
+
+    #[test]
+    fn test_flat_animation_keeps_cache_while_scrubbing() {
+        use crate::animation::CurveBuilder;
+        use crate::graph::GraphEvent;
+
+        // Constant animated 0 -> 1 over 0..2, flat at 1 until 5, then up to 3
+        let mut composite = CompositeOp::new("Plateau");
+        let constant = composite.add(ConstantOp::new(0.0));
+        composite.expose_output("Out", constant, 0).unwrap();
+        let curve = CurveBuilder::new()
+            .keyframe(0.0, 0.0)
+            .keyframe(2.0, 1.0)
+            .keyframe(5.0, 1.0)
+            .keyframe(7.0, 3.0)
+            .build();
+        composite.animator_mut().set_range(0.0, 10.0);
+        composite.animator_mut().add_curve(curve, constant, 0);
+
+        let mut graph = Graph::new();
+        let id = graph.add(composite);
+        let mut ctx = EvalContext::new();
+        let mut scrub = |graph: &mut Graph, time: f64| {
+            ctx.time = time;
+            let value = graph.evaluate(id, 0, &ctx).unwrap();
+            let inner = &mut graph.get_mut_as::<CompositeOp>(id).unwrap().subgraph;
+            let writes = inner
+                .drain_events()
+                .filter(|e| matches!(e, GraphEvent::InputDefaultChanged { .. }))
+                .count();
+            let recomputed = inner.frame_evaluated_nodes().any(|node| node == constant);
+            (value, writes, recomputed)
+        };
+
+        assert_eq!(scrub(&mut graph, 1.0), (Value::Float(0.5), 1, true));
+        assert_eq!(scrub(&mut graph, 2.5), (Value::Float(1.0), 1, true));
+        for time in [3.0, 4.5, 2.2, 4.9] {
+            let frame = scrub(&mut graph, time);
+            assert_eq!(frame, (Value::Float(1.0), 0, false), "t = {}", time);
+        }
+        assert_eq!(scrub(&mut graph, 6.0), (Value::Float(2.0), 1, true));
+    }
 }
@@ -16,6 +16,12 @@
 //! // [Float Output] -> [ConversionOp(Float->Vec3)] -> [Vec3 Input]
 //! graph.connect(float_source, 0, vec3_target, 0)?;
 //! ```
+//!
+//! # Unit Conversion
+//!
+//! Float ports may also declare typed [`Unit`]s. Connecting a Float output to
+//! a Float input with a different but compatible unit (e.g., Degrees to
+//! Radians) inserts a [`UnitConversionOp`] that scales the value.
 
 use std::any::Any;
 
@@ -23,13 +29,16 @@ use flux_core::context::EvalContext;
 use flux_core::id::Id;
 use flux_core::operator::{InputResolver, Operator};
 use flux_core::port::{InputPort, OutputPort};
-use flux_core::value::ValueType;
+use flux_core::unit::Unit;
+use flux_core::value::{Value, ValueType};
 
 /// An operator that converts values from one type to another.
 ///
 /// This operator is automatically inserted by the graph when connecting
 /// ports of different but compatible types. It uses the [`Value::coerce_to`]
-/// method to perform the actual conversion.
+/// method to perform the actual conversion. Coercions that can fail per
+/// value (e.g. String to Float on text that doesn't parse) output the target
+/// type's default instead.
 ///
 /// ConversionOps are marked as "synthetic" nodes - they are auto-generated
 /// by the system rather than explicitly created by users. UI layers may
@@ -118,7 +127,7 @@ impl Operator for ConversionOp {
         &mut self.outputs
     }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         // Get input value (either from connection or default)
         let input_value = match self.inputs[0].connection {
             Some((node_id, output_idx)) => get_input(node_id, output_idx),
@@ -126,9 +135,11 @@ impl Operator for ConversionOp {
         };
 
         // Coerce to target type
-        let output_value = input_value
+        let mut output_value = input_value
             .coerce_to(self.target_type)
             .unwrap_or_else(|| self.target_type.default_value());
+        // Flattening coercions (e.g. Vec3List -> FloatList) can grow lists
+        ctx.limit_list(&mut output_value);
 
         self.outputs[0].set(output_value);
     }
@@ -139,10 +150,112 @@ impl Operator for ConversionOp {
     }
 }
 
+/// An operator that scales a Float between two compatible units.
+///
+/// Inserted automatically by the graph when a Float output and a Float input
+/// declare different but compatible typed units. Like [`ConversionOp`], it is
+/// a synthetic node.
+pub struct UnitConversionOp {
+    id: Id,
+    source_unit: Unit,
+    target_unit: Unit,
+    factor: f32,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 1],
+}
+
+impl UnitConversionOp {
+    /// Create a new unit conversion operator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the units are incompatible.
+    /// Use [`Unit::is_compatible_with`] to check compatibility first.
+    pub fn new(source_unit: Unit, target_unit: Unit) -> Self {
+        let factor = source_unit.factor_to(target_unit).unwrap_or_else(|| {
+            panic!(
+                "Cannot create UnitConversionOp: {:?} cannot be converted to {:?}",
+                source_unit, target_unit
+            )
+        });
+
+        Self {
+            id: Id::new(),
+            source_unit,
+            target_unit,
+            factor,
+            inputs: [InputPort::float("In", 0.0)],
+            outputs: [OutputPort::float("Out")],
+        }
+    }
+
+    /// Get the unit this operator converts from.
+    pub fn source_unit(&self) -> Unit {
+        self.source_unit
+    }
+
+    /// Get the unit this operator converts to.
+    pub fn target_unit(&self) -> Unit {
+        self.target_unit
+    }
+
+    /// Check if this is a synthetic (auto-generated) node.
+    pub fn is_synthetic(&self) -> bool {
+        true
+    }
+}
+
+impl Operator for UnitConversionOp {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        "ConvertUnit"
+    }
+
+    fn inputs(&self) -> &[InputPort] {
+        &self.inputs
+    }
+
+    fn inputs_mut(&mut self) -> &mut [InputPort] {
+        &mut self.inputs
+    }
+
+    fn outputs(&self) -> &[OutputPort] {
+        &self.outputs
+    }
+
+    fn outputs_mut(&mut self) -> &mut [OutputPort] {
+        &mut self.outputs
+    }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let input_value = match self.inputs[0].connection {
+            Some((node_id, output_idx)) => get_input(node_id, output_idx),
+            None => self.inputs[0].default.clone(),
+        };
+
+        let value = input_value.as_float().unwrap_or(0.0);
+        self.outputs[0].set(Value::Float(value * self.factor));
+    }
+
+    fn can_operate_in_place(&self) -> bool {
+        true
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use flux_core::Value;
 
     #[test]
     fn test_conversion_op_float_to_vec3() {
@@ -198,6 +311,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_conversion_op_unparsable_string_outputs_default() {
+        let mut op = ConversionOp::new(ValueType::String, ValueType::Float);
+        let ctx = EvalContext::new();
+        let get_input = |_: Id, _: usize| Value::Float(0.0);
+
+        op.inputs_mut()[0].default = Value::String(" 2.5 ".into());
+        op.compute(&ctx, &get_input);
+        assert_eq!(op.outputs()[0].value, Value::Float(2.5));
+
+        op.inputs_mut()[0].default = Value::String("abc".into());
+        op.compute(&ctx, &get_input);
+        assert_eq!(op.outputs()[0].value, Value::Float(0.0));
+    }
+
     #[test]
     #[should_panic(expected = "Cannot create ConversionOp")]
     fn test_conversion_op_incompatible_types() {
@@ -210,4 +338,26 @@ mod tests {
         let op = ConversionOp::new(ValueType::Float, ValueType::Vec3);
         assert!(op.can_operate_in_place());
     }
+
+    #[test]
+    fn test_unit_conversion_op_degrees_to_radians() {
+        let mut op = UnitConversionOp::new(Unit::Degrees, Unit::Radians);
+        assert_eq!(op.name(), "ConvertUnit");
+        assert_eq!(op.source_unit(), Unit::Degrees);
+        assert_eq!(op.target_unit(), Unit::Radians);
+
+        op.inputs_mut()[0].default = Value::Float(180.0);
+        let ctx = EvalContext::new();
+        let get_input = |_: Id, _: usize| Value::Float(0.0);
+        op.compute(&ctx, &get_input);
+
+        let out = op.outputs()[0].value.as_float().unwrap();
+        assert!((out - std::f32::consts::PI).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Cannot create UnitConversionOp")]
+    fn test_unit_conversion_op_incompatible_units() {
+        UnitConversionOp::new(Unit::Seconds, Unit::Degrees);
+    }
 }
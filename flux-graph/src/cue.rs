@@ -0,0 +1,650 @@
+//! Cues: named graph states with timed transitions
+//!
+//! A [`Cue`] names a set of input values ("fade these eight parameters to
+//! these values over 2 seconds"). Firing it on a [`CueList`] starts one
+//! transition per target; [`CueList::update`] moves each input default
+//! toward its target once per frame, shaped by the cue's [`Interpolation`].
+//!
+//! - Targets are addressed by [`InstancePath`], so inputs inside composite
+//!   operators can be cued. Values are coerced to the input's type.
+//! - Connected inputs are skipped and reported as a [`CueDiagnostic`].
+//! - Firing a cue that touches an input another cue is still moving
+//!   retargets it from its current value, without a jump.
+//! - A cue can follow on to another cue after a delay once it completes.
+//!
+//! Transition timing follows [`EvalContext::time`]; each transition starts
+//! on the first update after its cue fires.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut cues = CueList::new();
+//! cues.add(
+//!     Cue::new("12")
+//!         .with_duration(2.0)
+//!         .with_easing(Interpolation::Spline)
+//!         .target(InstancePath::root(dimmer), 0, Value::Float(0.0))
+//!         .follow("13", 0.5),
+//! );
+//! cues.on_complete(|name| println!("cue {name} complete"));
+//!
+//! cues.fire("12")?;
+//! loop {
+//!     ctx.advance(1.0 / 60.0);
+//!     cues.update(&mut graph, &ctx);
+//! }
+//! ```
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use flux_core::context::EvalContext;
+use flux_core::value::{Value, ValueType};
+
+use crate::animation::Interpolation;
+use crate::composite::resolve_node;
+use crate::graph::Graph;
+use crate::instance_path::InstancePath;
+
+/// Errors from [`CueList::fire`]
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum CueError {
+    /// No cue with this name
+    #[error("Cue not found: {0}")]
+    CueNotFound(String),
+}
+
+/// A cue target that could not be applied
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum CueDiagnostic {
+    /// The path or input index doesn't name an input in the graph
+    #[error("Cue '{cue}': no input {input} at {path:?}")]
+    TargetNotFound {
+        cue: String,
+        path: InstancePath,
+        input: usize,
+    },
+
+    /// The input is connected, so its default has no effect
+    #[error("Cue '{cue}': input {input} at {path:?} is connected")]
+    InputConnected {
+        cue: String,
+        path: InstancePath,
+        input: usize,
+    },
+
+    /// The target value can't be coerced to the input's type
+    #[error("Cue '{cue}': cannot convert {value_type} to {input_type} for input {input}")]
+    TypeMismatch {
+        cue: String,
+        path: InstancePath,
+        input: usize,
+        value_type: ValueType,
+        input_type: ValueType,
+    },
+
+    /// A follow-on cue names a cue that doesn't exist
+    #[error("Cue '{cue}': follow-on cue '{follow}' not found")]
+    FollowNotFound { cue: String, follow: String },
+}
+
+/// One input a cue drives
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CueTarget {
+    /// Path to the node owning the input
+    pub path: InstancePath,
+    /// Input index on that node
+    pub input: usize,
+    /// Value the input ends at
+    pub value: Value,
+}
+
+/// Cue fired automatically after another cue completes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CueFollow {
+    /// Name of the cue to fire
+    pub cue: String,
+    /// Seconds to wait after completion
+    #[serde(default)]
+    pub delay: f64,
+}
+
+/// A named set of input values reached over a transition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cue {
+    pub name: String,
+    #[serde(default)]
+    pub targets: Vec<CueTarget>,
+    /// Transition length in seconds (0 snaps immediately)
+    #[serde(default)]
+    pub duration: f64,
+    /// Shape of the transition
+    #[serde(default)]
+    pub easing: Interpolation,
+    /// Cue to fire once this one completes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub follow: Option<CueFollow>,
+}
+
+impl Cue {
+    /// Create an empty cue that snaps immediately
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            targets: Vec::new(),
+            duration: 0.0,
+            easing: Interpolation::Linear,
+            follow: None,
+        }
+    }
+
+    /// Builder: set the transition length in seconds
+    pub fn with_duration(mut self, seconds: f64) -> Self {
+        self.duration = seconds.max(0.0);
+        self
+    }
+
+    /// Builder: set the transition shape
+    pub fn with_easing(mut self, easing: Interpolation) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Builder: drive an input toward `value`
+    pub fn target(mut self, path: InstancePath, input: usize, value: Value) -> Self {
+        self.targets.push(CueTarget { path, input, value });
+        self
+    }
+
+    /// Builder: fire `cue` `delay` seconds after this cue completes
+    pub fn follow(mut self, cue: &str, delay: f64) -> Self {
+        self.follow = Some(CueFollow {
+            cue: cue.to_string(),
+            delay: delay.max(0.0),
+        });
+        self
+    }
+}
+
+/// An input moving toward a cue's target value
+#[derive(Debug, Clone)]
+struct Transition {
+    cue: String,
+    path: InstancePath,
+    input: usize,
+    target: Value,
+    duration: f64,
+    easing: Interpolation,
+    /// Value and time the transition starts from, captured on its first update
+    start: Option<(Value, f64)>,
+}
+
+impl Transition {
+    /// Value at `time`, and whether the transition has finished
+    fn sample(&mut self, current: &Value, time: f64) -> (Value, bool) {
+        let (from, start_time) = self.start.get_or_insert_with(|| (current.clone(), time));
+        let t = if self.duration > 0.0 {
+            ((time - *start_time) / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        if t >= 1.0 {
+            return (self.target.clone(), true);
+        }
+        let eased = self.easing.interpolate(0.0, 1.0, t);
+        // Types without interpolation hold their start value until the end
+        let value = from
+            .lerp(&self.target, &Value::Float(eased as f32))
+            .and_then(|v| v.coerce_to(self.target.value_type()))
+            .unwrap_or_else(|| from.clone());
+        (value, false)
+    }
+}
+
+/// A follow-on cue waiting to fire
+#[derive(Debug, Clone)]
+struct PendingFollow {
+    /// Cue that completed
+    from: String,
+    cue: String,
+    /// Seconds after the completion
+    delay: f64,
+    /// Time the follow fires, set on the first update after completion
+    fire_at: Option<f64>,
+}
+
+type CompletionCallback = Box<dyn FnMut(&str) + Send>;
+
+/// Cues and the transitions they have in flight
+#[derive(Default)]
+pub struct CueList {
+    cues: Vec<Cue>,
+    transitions: Vec<Transition>,
+    /// Fired cues that haven't completed, in firing order
+    running: Vec<String>,
+    pending: Vec<PendingFollow>,
+    diagnostics: Vec<CueDiagnostic>,
+    on_complete: Option<CompletionCallback>,
+}
+
+impl CueList {
+    /// Create an empty cue list
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a cue list from saved cues
+    pub fn from_cues(cues: Vec<Cue>) -> Self {
+        let mut list = Self::new();
+        for cue in cues {
+            list.add(cue);
+        }
+        list
+    }
+
+    /// Add a cue, replacing any cue with the same name
+    pub fn add(&mut self, cue: Cue) {
+        match self.cues.iter_mut().find(|c| c.name == cue.name) {
+            Some(existing) => *existing = cue,
+            None => self.cues.push(cue),
+        }
+    }
+
+    /// Remove a cue and stop its transitions
+    pub fn remove(&mut self, name: &str) -> Option<Cue> {
+        let index = self.cues.iter().position(|c| c.name == name)?;
+        self.stop(name);
+        Some(self.cues.remove(index))
+    }
+
+    /// Get a cue by name
+    pub fn get(&self, name: &str) -> Option<&Cue> {
+        self.cues.iter().find(|c| c.name == name)
+    }
+
+    /// All cues, in the order they were added
+    pub fn cues(&self) -> &[Cue] {
+        &self.cues
+    }
+
+    /// Number of cues
+    pub fn len(&self) -> usize {
+        self.cues.len()
+    }
+
+    /// Returns true if there are no cues
+    pub fn is_empty(&self) -> bool {
+        self.cues.is_empty()
+    }
+
+    /// Call `callback` with the cue name whenever a fired cue completes
+    pub fn on_complete(&mut self, callback: impl FnMut(&str) + Send + 'static) {
+        self.on_complete = Some(Box::new(callback));
+    }
+
+    /// Start a cue's transitions
+    ///
+    /// Inputs another cue is still moving are taken over and continue from
+    /// their current value. Firing a running cue restarts it.
+    pub fn fire(&mut self, name: &str) -> Result<(), CueError> {
+        let cue = self
+            .get(name)
+            .cloned()
+            .ok_or_else(|| CueError::CueNotFound(name.to_string()))?;
+
+        self.stop(name);
+        for target in cue.targets {
+            self.transitions
+                .retain(|t| !(t.path == target.path && t.input == target.input));
+            self.transitions.push(Transition {
+                cue: cue.name.clone(),
+                path: target.path,
+                input: target.input,
+                target: target.value,
+                duration: cue.duration,
+                easing: cue.easing,
+                start: None,
+            });
+        }
+        self.running.push(cue.name);
+        Ok(())
+    }
+
+    /// Stop a cue without completing it or firing its follow-on
+    ///
+    /// Inputs keep their current values.
+    pub fn stop(&mut self, name: &str) {
+        self.transitions.retain(|t| t.cue != name);
+        self.running.retain(|cue| cue != name);
+    }
+
+    /// Stop every cue and cancel pending follow-ons
+    pub fn stop_all(&mut self) {
+        self.transitions.clear();
+        self.running.clear();
+        self.pending.clear();
+    }
+
+    /// Returns true if the cue was fired and hasn't completed
+    pub fn is_running(&self, name: &str) -> bool {
+        self.running.iter().any(|cue| cue == name)
+    }
+
+    /// Returns true if any transition or follow-on is outstanding
+    pub fn is_active(&self) -> bool {
+        !self.running.is_empty() || !self.pending.is_empty()
+    }
+
+    /// Take the diagnostics collected since the last call
+    pub fn take_diagnostics(&mut self) -> Vec<CueDiagnostic> {
+        std::mem::take(&mut self.diagnostics)
+    }
+
+    /// Advance every transition to `ctx.time` and write the input defaults
+    ///
+    /// Completes cues whose transitions have all finished, calling the
+    /// completion callback and scheduling their follow-on cues.
+    pub fn update(&mut self, graph: &mut Graph, ctx: &EvalContext) {
+        let time = ctx.time;
+        self.fire_due_follows(time);
+
+        let mut index = 0;
+        while index < self.transitions.len() {
+            if self.advance(index, graph, time) {
+                self.transitions.remove(index);
+            } else {
+                index += 1;
+            }
+        }
+
+        let transitions = &self.transitions;
+        let (completed, running): (Vec<String>, Vec<String>) = std::mem::take(&mut self.running)
+            .into_iter()
+            .partition(|cue| !transitions.iter().any(|t| &t.cue == cue));
+        self.running = running;
+
+        for name in completed {
+            if let Some(callback) = self.on_complete.as_mut() {
+                callback(&name);
+            }
+            if let Some(follow) = self.get(&name).and_then(|cue| cue.follow.clone()) {
+                self.pending.push(PendingFollow {
+                    from: name.clone(),
+                    cue: follow.cue,
+                    delay: follow.delay,
+                    fire_at: (follow.delay <= 0.0).then_some(time),
+                });
+            }
+        }
+        // Zero-delay follow-ons start this frame
+        self.fire_due_follows(time);
+    }
+
+    /// Fire follow-on cues whose delay has elapsed
+    fn fire_due_follows(&mut self, time: f64) {
+        for pending in &mut self.pending {
+            pending.fire_at.get_or_insert(time + pending.delay);
+        }
+        let (due, waiting): (Vec<_>, Vec<_>) = std::mem::take(&mut self.pending)
+            .into_iter()
+            .partition(|p| p.fire_at.is_some_and(|at| at <= time));
+        self.pending = waiting;
+
+        for pending in due {
+            if self.fire(&pending.cue).is_err() {
+                self.diagnostics.push(CueDiagnostic::FollowNotFound {
+                    cue: pending.from,
+                    follow: pending.cue,
+                });
+            }
+        }
+    }
+
+    /// Advance one transition; returns true once it's finished or dropped
+    fn advance(&mut self, index: usize, graph: &mut Graph, time: f64) -> bool {
+        let transition = &mut self.transitions[index];
+        let Some((graph, node)) = resolve_node(graph, &transition.path) else {
+            self.diagnostics.push(transition.not_found());
+            return true;
+        };
+        let Some(input) = graph.get(node).and_then(|op| op.inputs().get(transition.input)) else {
+            self.diagnostics.push(transition.not_found());
+            return true;
+        };
+        if input.is_connected() {
+            self.diagnostics.push(CueDiagnostic::InputConnected {
+                cue: transition.cue.clone(),
+                path: transition.path.clone(),
+                input: transition.input,
+            });
+            return true;
+        }
+
+        if transition.start.is_none() {
+            let input_type = input.value_type;
+            match transition.target.coerce_to(input_type) {
+                Some(target) => transition.target = target,
+                None => {
+                    self.diagnostics.push(CueDiagnostic::TypeMismatch {
+                        cue: transition.cue.clone(),
+                        path: transition.path.clone(),
+                        input: transition.input,
+                        value_type: transition.target.value_type(),
+                        input_type,
+                    });
+                    return true;
+                }
+            }
+        }
+
+        let current = input.default.clone();
+        let (value, finished) = transition.sample(&current, time);
+        if value != current {
+            graph.set_input_default(node, transition.input, value);
+        }
+        finished
+    }
+}
+
+impl Transition {
+    fn not_found(&self) -> CueDiagnostic {
+        CueDiagnostic::TargetNotFound {
+            cue: self.cue.clone(),
+            path: self.path.clone(),
+            input: self.input,
+        }
+    }
+}
+
+impl std::fmt::Debug for CueList {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CueList")
+            .field("cues", &self.cues)
+            .field("running", &self.running)
+            .field("transitions", &self.transitions.len())
+            .field("pending", &self.pending)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+    use flux_core::id::Id;
+    use flux_operators::create_default_registry;
+
+    fn constant(graph: &mut Graph) -> Id {
+        graph.add_boxed(create_default_registry().create_by_name("Constant").unwrap())
+    }
+
+    fn default_of(graph: &Graph, node: Id) -> Value {
+        graph.get(node).unwrap().inputs()[0].default.clone()
+    }
+
+    fn step(cues: &mut CueList, graph: &mut Graph, ctx: &mut EvalContext, dt: f64) -> Value {
+        ctx.advance(dt);
+        cues.update(graph, ctx);
+        default_of(graph, graph.node_ids().next().unwrap())
+    }
+
+    fn record_completions(cues: &mut CueList) -> Arc<Mutex<Vec<String>>> {
+        let completed = Arc::new(Mutex::new(Vec::new()));
+        let sink = completed.clone();
+        cues.on_complete(move |name| sink.lock().unwrap().push(name.to_string()));
+        completed
+    }
+
+    #[test]
+    fn test_fire_transitions_to_targets() {
+        let mut graph = Graph::new();
+        let node = constant(&mut graph);
+        let mut cues = CueList::new();
+        cues.add(
+            Cue::new("up")
+                .with_duration(1.0)
+                .target(InstancePath::root(node), 0, Value::Float(10.0)),
+        );
+        let completed = record_completions(&mut cues);
+        let mut ctx = EvalContext::new();
+
+        assert_eq!(cues.fire("missing"), Err(CueError::CueNotFound("missing".into())));
+        cues.fire("up").unwrap();
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.0), Value::Float(0.0));
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.25), Value::Float(2.5));
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.25), Value::Float(5.0));
+        assert!(cues.is_running("up"));
+        assert!(completed.lock().unwrap().is_empty());
+
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.5), Value::Float(10.0));
+        assert!(!cues.is_active());
+        assert_eq!(*completed.lock().unwrap(), vec!["up".to_string()]);
+        assert!(cues.take_diagnostics().is_empty());
+    }
+
+    #[test]
+    fn test_eased_transition() {
+        let mut graph = Graph::new();
+        let node = constant(&mut graph);
+        let mut cues = CueList::from_cues(vec![Cue::new("ease")
+            .with_duration(1.0)
+            .with_easing(Interpolation::Spline)
+            .target(InstancePath::root(node), 0, Value::Float(1.0))]);
+        let mut ctx = EvalContext::new();
+
+        cues.fire("ease").unwrap();
+        step(&mut cues, &mut graph, &mut ctx, 0.0);
+        let quarter = step(&mut cues, &mut graph, &mut ctx, 0.25).as_float().unwrap();
+        assert!((quarter - 0.15625).abs() < 1e-6);
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.25), Value::Float(0.5));
+    }
+
+    #[test]
+    fn test_overlapping_cue_retargets_smoothly() {
+        let mut graph = Graph::new();
+        let node = constant(&mut graph);
+        let path = InstancePath::root(node);
+        let mut cues = CueList::new();
+        cues.add(Cue::new("up").with_duration(1.0).target(path.clone(), 0, Value::Float(10.0)));
+        cues.add(Cue::new("down").with_duration(1.0).target(path, 0, Value::Float(0.0)));
+        let completed = record_completions(&mut cues);
+        let mut ctx = EvalContext::new();
+
+        cues.fire("up").unwrap();
+        step(&mut cues, &mut graph, &mut ctx, 0.0);
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.5), Value::Float(5.0));
+
+        // The new cue starts from the in-flight value, not from "up"'s start or target
+        cues.fire("down").unwrap();
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.0), Value::Float(5.0));
+        assert_eq!(*completed.lock().unwrap(), vec!["up".to_string()]);
+
+        let mut previous = 5.0;
+        for _ in 0..4 {
+            let value = step(&mut cues, &mut graph, &mut ctx, 0.25).as_float().unwrap();
+            assert!(value < previous && previous - value <= 1.25 + 1e-6);
+            previous = value;
+        }
+        assert_eq!(previous, 0.0);
+        assert!(!cues.is_running("down"));
+    }
+
+    #[test]
+    fn test_follow_on_cue_fires_after_delay() {
+        let mut graph = Graph::new();
+        let node = constant(&mut graph);
+        let path = InstancePath::root(node);
+        let mut cues = CueList::new();
+        cues.add(
+            Cue::new("1")
+                .with_duration(0.5)
+                .target(path.clone(), 0, Value::Float(1.0))
+                .follow("2", 0.5),
+        );
+        cues.add(Cue::new("2").target(path, 0, Value::Float(2.0)).follow("3", 0.0));
+        let mut ctx = EvalContext::new();
+
+        cues.fire("1").unwrap();
+        step(&mut cues, &mut graph, &mut ctx, 0.0);
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.5), Value::Float(1.0));
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.25), Value::Float(1.0));
+        assert!(cues.is_active());
+
+        // Cue 2 snaps; its follow-on names a missing cue
+        assert_eq!(step(&mut cues, &mut graph, &mut ctx, 0.25), Value::Float(2.0));
+        assert!(!cues.is_active());
+        assert_eq!(
+            cues.take_diagnostics(),
+            vec![CueDiagnostic::FollowNotFound {
+                cue: "2".into(),
+                follow: "3".into()
+            }]
+        );
+    }
+
+    #[test]
+    fn test_skipped_targets_report_diagnostics() {
+        let mut graph = Graph::new();
+        let source = constant(&mut graph);
+        let sink = constant(&mut graph);
+        graph.connect(source, 0, sink, 0).unwrap();
+        let mut cues = CueList::new();
+        cues.add(
+            Cue::new("cue")
+                .target(InstancePath::root(sink), 0, Value::Float(1.0))
+                .target(InstancePath::root(source), 0, Value::Int(3))
+                .target(InstancePath::root(source), 4, Value::Float(1.0))
+                .target(InstancePath::root(Id::new()), 0, Value::Float(1.0)),
+        );
+        let mut ctx = EvalContext::new();
+
+        cues.fire("cue").unwrap();
+        ctx.advance(0.0);
+        cues.update(&mut graph, &ctx);
+
+        // The Int target is coerced to the Float input
+        assert_eq!(default_of(&graph, source), Value::Float(3.0));
+        let diagnostics = cues.take_diagnostics();
+        assert_eq!(diagnostics.len(), 3);
+        assert!(matches!(diagnostics[0], CueDiagnostic::InputConnected { .. }));
+        assert!(matches!(diagnostics[1], CueDiagnostic::TargetNotFound { input: 4, .. }));
+        assert!(matches!(diagnostics[2], CueDiagnostic::TargetNotFound { .. }));
+        assert!(!cues.is_active());
+    }
+
+    #[test]
+    fn test_cue_serialization_roundtrip() {
+        let cue = Cue::new("12")
+            .with_duration(2.0)
+            .with_easing(Interpolation::Spline)
+            .target(InstancePath::root(Id::new()), 1, Value::Float(0.5))
+            .follow("13", 1.0);
+        let json = serde_json::to_string(&cue).unwrap();
+        let restored: Cue = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, cue);
+
+        let minimal: Cue = serde_json::from_str(r#"{"name":"blackout"}"#).unwrap();
+        assert_eq!(minimal, Cue::new("blackout"));
+    }
+}
@@ -0,0 +1,205 @@
+//! Determinism audits
+//!
+//! [`Graph::audit_determinism`] evaluates a graph several times with the
+//! same context and compares every node's outputs across the runs. Replays
+//! and golden-output tests rely on identical inputs giving identical
+//! outputs; an operator that reads the wall clock, iterates a `HashMap` or
+//! keeps state it doesn't declare shows up here as a [`DivergentNode`].
+//!
+//! Each run is computed from scratch in its own call context under
+//! [`AUDIT_CALL_INDEX`], like a preview, so main-context cache entries are
+//! not touched. Stateful operators (see [`Operator::is_stateful`]) are put
+//! back to their initial state with [`Operator::reset_state`] before every
+//! run; those that can't be reset are listed as [`UnauditableNode`]s and
+//! their last main-context output is reused instead.
+//!
+//! [`Graph::audit_determinism`]: crate::Graph::audit_determinism
+//! [`Operator::is_stateful`]: flux_core::Operator::is_stateful
+//! [`Operator::reset_state`]: flux_core::Operator::reset_state
+
+use std::sync::Arc;
+
+use flux_core::{Id, Value};
+
+/// Call context index of the audit runs, relative to the caller's
+/// (ASCII `audi`, well clear of loop iteration indices). Run `n` is
+/// evaluated in the child context `n` of it.
+pub const AUDIT_CALL_INDEX: u32 = 0x6175_6469;
+
+/// Result of [`Graph::audit_determinism`](crate::Graph::audit_determinism).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeterminismReport {
+    /// Number of evaluations compared
+    pub runs: usize,
+    /// Number of nodes whose outputs were compared
+    pub audited: usize,
+    /// Nodes with an output that differed between runs, in evaluation order
+    pub divergent: Vec<DivergentNode>,
+    /// Stateful nodes that couldn't be reset between runs and weren't audited
+    pub unauditable: Vec<UnauditableNode>,
+}
+
+impl DeterminismReport {
+    /// True if no audited node produced differing outputs.
+    pub fn is_deterministic(&self) -> bool {
+        self.divergent.is_empty()
+    }
+
+    /// Divergent nodes whose sources all produced identical values, where a
+    /// difference must have started.
+    pub fn origins(&self) -> impl Iterator<Item = &DivergentNode> {
+        self.divergent.iter().filter(|node| node.origin)
+    }
+}
+
+/// A node that produced differing outputs across identical evaluations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DivergentNode {
+    pub node: Id,
+    /// Operator name of the node
+    pub operator: &'static str,
+    /// False if one of the node's sources diverged as well, so the
+    /// difference may only have been passed on
+    pub origin: bool,
+    /// The outputs that differed, by index
+    pub outputs: Vec<OutputDivergence>,
+}
+
+/// How one output differed across runs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OutputDivergence {
+    pub output: usize,
+    /// Value of the first run
+    pub first: Value,
+    /// Value of the first later run that differed from it
+    pub differing: Value,
+    /// Number of runs after the first that differed from it
+    pub differing_runs: usize,
+    /// Largest difference of any numeric component from the first run's
+    /// value, `None` if the values aren't numbers of the same shape
+    pub magnitude: Option<f64>,
+}
+
+/// A stateful node left out of an audit because it can't reset its state.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnauditableNode {
+    pub node: Id,
+    /// Operator name of the node
+    pub operator: &'static str,
+}
+
+/// Record the outputs of a later run that differ from the first run's.
+pub(crate) fn compare_outputs(
+    divergences: &mut Vec<OutputDivergence>,
+    first: &[Arc<Value>],
+    outputs: &[Arc<Value>],
+    epsilon: f64,
+) {
+    for (output, (first, value)) in first.iter().zip(outputs).enumerate() {
+        if same_within(first, value, epsilon) {
+            continue;
+        }
+        let magnitude = difference(first, value);
+        match divergences.iter_mut().find(|d| d.output == output) {
+            Some(divergence) => {
+                divergence.differing_runs += 1;
+                divergence.magnitude = divergence.magnitude.zip(magnitude).map(|(a, b)| a.max(b));
+            }
+            None => divergences.push(OutputDivergence {
+                output,
+                first: first.as_ref().clone(),
+                differing: value.as_ref().clone(),
+                differing_runs: 1,
+                magnitude,
+            }),
+        }
+    }
+}
+
+/// Whether two run results count as the same within `epsilon`.
+///
+/// NaN components equal each other, so an output that is NaN every run
+/// doesn't show up as divergent.
+pub(crate) fn same_within(a: &Value, b: &Value, epsilon: f64) -> bool {
+    a == b || difference(a, b).is_some_and(|magnitude| magnitude <= epsilon)
+}
+
+/// Largest difference between the numeric components of two values.
+///
+/// `None` unless both are numbers of the same shape (including list
+/// length). A component that is NaN in only one of them differs infinitely.
+pub(crate) fn difference(a: &Value, b: &Value) -> Option<f64> {
+    if a.value_type() != b.value_type() {
+        return None;
+    }
+    let (a, b) = (components(a)?, components(b)?);
+    if a.len() != b.len() {
+        return None;
+    }
+    let magnitude = a.iter().zip(&b).fold(0.0_f64, |max, (&x, &y)| {
+        let diff = match (x.is_nan(), y.is_nan()) {
+            (true, true) => 0.0,
+            (false, false) if x == y => 0.0,
+            (false, false) => (x - y).abs(),
+            _ => f64::INFINITY,
+        };
+        max.max(diff)
+    });
+    Some(magnitude)
+}
+
+/// Numeric components of a value; `None` for non-numeric kinds.
+fn components(value: &Value) -> Option<Vec<f64>> {
+    let floats = |values: &[f32]| values.iter().map(|&v| v as f64).collect();
+    Some(match value {
+        Value::Float(v) => vec![*v as f64],
+        Value::Int(v) => vec![*v as f64],
+        Value::Vec2(v) => floats(v),
+        Value::Vec3(v) => floats(v),
+        Value::Vec4(v) => floats(v),
+        Value::Color(c) => floats(&[c.r, c.g, c.b, c.a]),
+        Value::Matrix4(m) => floats(m.0.as_flattened()),
+        Value::FloatList(v) => floats(v),
+        Value::IntList(v) => v.iter().map(|&v| v as f64).collect(),
+        Value::Vec2List(v) => floats(v.as_flattened()),
+        Value::Vec3List(v) => floats(v.as_flattened()),
+        Value::Vec4List(v) => floats(v.as_flattened()),
+        Value::ColorList(v) => v.iter().flat_map(|c| [c.r, c.g, c.b, c.a]).map(f64::from).collect(),
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_difference_of_numeric_values() {
+        assert_eq!(difference(&Value::Float(1.0), &Value::Float(1.5)), Some(0.5));
+        assert_eq!(
+            difference(&Value::Vec3([0.0, 1.0, 2.0]), &Value::Vec3([0.0, 3.0, 2.5])),
+            Some(2.0)
+        );
+        assert_eq!(difference(&Value::Float(f32::NAN), &Value::Float(f32::NAN)), Some(0.0));
+        assert_eq!(
+            difference(&Value::Float(f32::NAN), &Value::Float(0.0)),
+            Some(f64::INFINITY)
+        );
+
+        // Different shapes and non-numeric values have no magnitude
+        let short: Value = Value::FloatList(vec![1.0].into());
+        let long: Value = Value::FloatList(vec![1.0, 2.0].into());
+        assert_eq!(difference(&short, &long), None);
+        assert_eq!(difference(&Value::Float(1.0), &Value::Vec2([1.0, 1.0])), None);
+        assert_eq!(difference(&Value::String("a".into()), &Value::String("b".into())), None);
+    }
+
+    #[test]
+    fn test_same_within_epsilon() {
+        assert!(same_within(&Value::Float(1.0), &Value::Float(1.0), 0.0));
+        assert!(!same_within(&Value::Float(1.0), &Value::Float(1.001), 0.0));
+        assert!(same_within(&Value::Float(1.0), &Value::Float(1.001), 0.01));
+        assert!(same_within(&Value::Float(f32::NAN), &Value::Float(f32::NAN), 0.0));
+        assert!(!same_within(&Value::String("a".into()), &Value::String("b".into()), 1.0));
+    }
+}
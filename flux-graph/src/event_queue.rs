@@ -0,0 +1,59 @@
+//! Bounding the pending event queue
+//!
+//! Graph edits queue [`GraphEvent`]s until the host calls
+//! [`Graph::drain_events`](crate::Graph::drain_events). A headless host may
+//! never drain, so the queue can be capped with
+//! [`Graph::set_event_capacity`](crate::Graph::set_event_capacity); what
+//! happens past the cap is chosen with
+//! [`Graph::set_event_overflow`](crate::Graph::set_event_overflow). The queue
+//! is unbounded by default.
+//!
+//! When events were dropped, the next drain starts with
+//! [`GraphEvent::EventsDropped`] so consumers know to resynchronize.
+//! Events emitted by one atomic operation are never dropped or merged one at
+//! a time: the capacity is enforced when the operation completes, so the
+//! queue can briefly exceed it.
+
+use crate::graph::GraphEvent;
+
+/// What a bounded event queue does with events past its capacity
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EventOverflow {
+    /// Drop the oldest queued events
+    #[default]
+    DropOldest,
+    /// Drop the events that don't fit
+    DropNewest,
+    /// Merge repeated `InputDefaultChanged` events for the same input into
+    /// the queued one, then drop the oldest events if still full
+    ///
+    /// Merging applies even without a capacity. The merged event keeps the
+    /// first `previous` value and takes the latest `value`.
+    Coalesce,
+}
+
+/// Merge a default change into a queued change of the same input
+///
+/// Only the run of `InputDefaultChanged` events at the end of the queue is
+/// searched, so no other event is reordered around the merge. Returns the
+/// event back if nothing matched.
+pub(crate) fn coalesce(queue: &mut [GraphEvent], event: GraphEvent) -> Option<GraphEvent> {
+    let GraphEvent::InputDefaultChanged { node, input, value, .. } = &event else {
+        return Some(event);
+    };
+    let queued = queue
+        .iter_mut()
+        .rev()
+        .take_while(|queued| matches!(queued, GraphEvent::InputDefaultChanged { .. }))
+        .find(|queued| {
+            matches!(queued, GraphEvent::InputDefaultChanged { node: n, input: i, .. }
+                if n == node && i == input)
+        });
+    match queued {
+        Some(GraphEvent::InputDefaultChanged { value: latest, .. }) => {
+            *latest = value.clone();
+            None
+        }
+        _ => Some(event),
+    }
+}
@@ -0,0 +1,524 @@
+//! Text export of graph structure
+//!
+//! Renders a [`Graph`] as Graphviz DOT or Mermaid flowchart text, for
+//! documentation and bug reports.
+//!
+//! - Nodes are labeled with their operator name (plus an optional label)
+//! - Value edges are labeled `output → input : Type`
+//! - Auto-inserted conversion nodes are styled differently
+//! - Trigger connections are drawn dashed
+//! - Optionally, DOT output draws node groups as clusters
+//!
+//! Output is deterministic for a given graph: nodes and edges are sorted by ID.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let dot = graph.to_dot(&ExportOptions::new().with_values());
+//! std::fs::write("graph.dot", dot)?;
+//!
+//! println!("{}", graph.to_mermaid());
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+use flux_core::id::Id;
+
+use crate::conversion::{ConversionOp, UnitConversionOp};
+use crate::graph::Graph;
+use crate::group::GroupId;
+
+/// Options for [`Graph::to_dot`].
+#[derive(Debug, Clone, Default)]
+pub struct ExportOptions {
+    /// Include the current cached output values as node tooltips
+    pub include_values: bool,
+    /// Extra per-node labels shown under the operator name
+    pub labels: HashMap<Id, String>,
+    /// Draw [groups](crate::group) as DOT clusters
+    ///
+    /// Clusters can't overlap, so a node in several groups is drawn in the
+    /// oldest one.
+    pub cluster_groups: bool,
+}
+
+impl ExportOptions {
+    /// Create default options (no values, no labels).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder: include cached output values as tooltips.
+    pub fn with_values(mut self) -> Self {
+        self.include_values = true;
+        self
+    }
+
+    /// Builder: draw groups as clusters.
+    pub fn with_group_clusters(mut self) -> Self {
+        self.cluster_groups = true;
+        self
+    }
+
+    /// Builder: add a label for a node.
+    pub fn with_label(mut self, node_id: Id, label: impl Into<String>) -> Self {
+        self.labels.insert(node_id, label.into());
+        self
+    }
+}
+
+/// An edge prepared for export.
+struct ExportEdge {
+    source: Id,
+    source_output: usize,
+    target: Id,
+    target_input: usize,
+    /// Value type name, or `None` for trigger edges
+    value_type: Option<String>,
+}
+
+impl ExportEdge {
+    fn label(&self) -> String {
+        match &self.value_type {
+            Some(ty) => format!("{} → {} : {}", self.source_output, self.target_input, ty),
+            None => format!("trigger {} → {}", self.source_output, self.target_input),
+        }
+    }
+}
+
+/// Stable, syntax-safe node identifier for both DOT and Mermaid.
+fn node_key(id: Id) -> String {
+    format!("n{}", id.as_uuid().simple())
+}
+
+/// Escape a string for use inside a DOT double-quoted string.
+fn escape_dot(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// `#rrggbb` for an RGBA color (alpha is ignored).
+fn hex_color(color: [f32; 4]) -> String {
+    let channel = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", channel(color[0]), channel(color[1]), channel(color[2]))
+}
+
+/// Escape a string for use inside a Mermaid double-quoted label.
+fn escape_mermaid(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("#quot;"),
+            '#' => out.push_str("#35;"),
+            '<' => out.push_str("#lt;"),
+            '>' => out.push_str("#gt;"),
+            '\n' => out.push_str("<br/>"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+impl Graph {
+    /// Node IDs sorted for deterministic output.
+    fn export_node_order(&self) -> Vec<Id> {
+        let mut ids: Vec<Id> = self.nodes.keys().copied().collect();
+        ids.sort_by(|a, b| a.as_uuid().cmp(b.as_uuid()));
+        ids
+    }
+
+    /// Display text for a node, and whether it is an auto-inserted conversion.
+    fn export_node_text(&self, id: Id, labels: &HashMap<Id, String>) -> (String, bool) {
+        let op = self.nodes[&id].operator.as_ref();
+        let any = op.as_any();
+        let (mut text, is_conversion) = if let Some(conv) = any.downcast_ref::<ConversionOp>() {
+            let text = format!("{}\n{} → {}", op.name(), conv.source_type(), conv.target_type());
+            (text, true)
+        } else if let Some(conv) = any.downcast_ref::<UnitConversionOp>() {
+            let text = format!("{}\n{} → {}", op.name(), conv.source_unit(), conv.target_unit());
+            (text, true)
+        } else {
+            (op.name().to_string(), false)
+        };
+
+        if let Some(label) = labels.get(&id) {
+            text.push('\n');
+            text.push_str(label);
+        }
+        (text, is_conversion)
+    }
+
+    /// All value and trigger edges, sorted for deterministic output.
+    fn export_edges(&self) -> Vec<ExportEdge> {
+        let mut edges: Vec<ExportEdge> = self
+            .connections()
+            .map(|c| {
+                let value_type = self
+                    .nodes
+                    .get(&c.source_node)
+                    .and_then(|n| n.operator.outputs().get(c.source_output))
+                    .map(|o| o.value_type.to_string())
+                    .unwrap_or_else(|| "?".to_string());
+                ExportEdge {
+                    source: c.source_node,
+                    source_output: c.source_output,
+                    target: c.target_node,
+                    target_input: c.target_input,
+                    value_type: Some(value_type),
+                }
+            })
+            .collect();
+
+        edges.extend(self.trigger_connections().map(|c| ExportEdge {
+            source: c.source_node,
+            source_output: c.source_output,
+            target: c.target_node,
+            target_input: c.target_input,
+            value_type: None,
+        }));
+
+        edges.sort_by_key(|e| {
+            (
+                e.value_type.is_none(),
+                *e.source.as_uuid(),
+                e.source_output,
+                *e.target.as_uuid(),
+                e.target_input,
+            )
+        });
+        edges
+    }
+
+    /// Export the graph as Graphviz DOT.
+    ///
+    /// Conversion nodes are drawn dashed and grey, trigger edges dashed.
+    /// With [`ExportOptions::include_values`], each evaluated node gets a tooltip
+    /// listing its cached output values.
+    pub fn to_dot(&self, options: &ExportOptions) -> String {
+        let mut out = String::new();
+        out.push_str("digraph flux {\n");
+        out.push_str("    rankdir=LR;\n");
+        out.push_str("    node [shape=box, style=rounded];\n");
+
+        // Group -> member nodes drawn in its cluster
+        let mut clusters: BTreeMap<GroupId, Vec<String>> = BTreeMap::new();
+        for id in self.export_node_order() {
+            let (text, is_conversion) = self.export_node_text(id, &options.labels);
+            let mut attrs = format!("label=\"{}\"", escape_dot(&text));
+            if is_conversion {
+                attrs.push_str(", style=\"rounded,dashed\", color=gray50, fontcolor=gray30");
+            }
+            if options.include_values {
+                let outputs = self.nodes[&id].operator.outputs();
+                let values: Vec<String> = (0..outputs.len())
+                    .filter_map(|i| {
+                        self.cached_output(id, i)
+                            .map(|v| format!("{} = {}", outputs[i].name, v))
+                    })
+                    .collect();
+                if !values.is_empty() {
+                    let _ = write!(attrs, ", tooltip=\"{}\"", escape_dot(&values.join("\n")));
+                }
+            }
+            let line = format!("\"{}\" [{}];", node_key(id), attrs);
+            match self.groups_of(id).first() {
+                Some(&group) if options.cluster_groups => {
+                    clusters.entry(group).or_default().push(line);
+                }
+                _ => {
+                    let _ = writeln!(out, "    {}", line);
+                }
+            }
+        }
+
+        for (group, lines) in clusters {
+            let entry = self.group(group).expect("clustered group exists");
+            let _ = writeln!(out, "    subgraph cluster_{} {{", group.index());
+            let _ = writeln!(out, "        label=\"{}\";", escape_dot(entry.name()));
+            let _ = writeln!(out, "        color=\"{}\";", hex_color(entry.color()));
+            for line in lines {
+                let _ = writeln!(out, "        {}", line);
+            }
+            out.push_str("    }\n");
+        }
+
+        for edge in self.export_edges() {
+            let style = if edge.value_type.is_none() { ", style=dashed" } else { "" };
+            let _ = writeln!(
+                out,
+                "    \"{}\" -> \"{}\" [label=\"{}\"{}];",
+                node_key(edge.source),
+                node_key(edge.target),
+                escape_dot(&edge.label()),
+                style
+            );
+        }
+
+        out.push_str("}\n");
+        out
+    }
+
+    /// Export the graph as a Mermaid flowchart.
+    ///
+    /// Conversion nodes use the `conversion` class, trigger edges are dotted.
+    pub fn to_mermaid(&self) -> String {
+        let mut out = String::new();
+        out.push_str("flowchart LR\n");
+
+        let no_labels = HashMap::new();
+        let mut conversions = Vec::new();
+        for id in self.export_node_order() {
+            let (text, is_conversion) = self.export_node_text(id, &no_labels);
+            let key = node_key(id);
+            let _ = writeln!(out, "    {}[\"{}\"]", key, escape_mermaid(&text));
+            if is_conversion {
+                conversions.push(key);
+            }
+        }
+
+        for edge in self.export_edges() {
+            let arrow = if edge.value_type.is_none() { "-.->" } else { "-->" };
+            let _ = writeln!(
+                out,
+                "    {} {}|\"{}\"| {}",
+                node_key(edge.source),
+                arrow,
+                escape_mermaid(&edge.label()),
+                node_key(edge.target)
+            );
+        }
+
+        if !conversions.is_empty() {
+            out.push_str("    classDef conversion stroke-dasharray: 4 4, fill:#eeeeee;\n");
+            let _ = writeln!(out, "    class {} conversion;", conversions.join(","));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::{EvalContext, InputPort, Operator, OutputPort, TriggerInput, TriggerOutput, Value};
+
+    struct ExportTestOp {
+        id: Id,
+        name: &'static str,
+        inputs: Vec<InputPort>,
+        outputs: Vec<OutputPort>,
+        trigger_inputs: Vec<TriggerInput>,
+        trigger_outputs: Vec<TriggerOutput>,
+    }
+
+    impl ExportTestOp {
+        fn source(name: &'static str) -> Self {
+            let mut output = OutputPort::float("Out");
+            output.set(Value::Float(1.5));
+            Self {
+                id: Id::new(),
+                name,
+                inputs: vec![],
+                outputs: vec![output],
+                trigger_inputs: vec![],
+                trigger_outputs: vec![TriggerOutput::new("Fired")],
+            }
+        }
+
+        fn sum(name: &'static str) -> Self {
+            Self {
+                id: Id::new(),
+                name,
+                inputs: vec![InputPort::float_multi("Values")],
+                outputs: vec![OutputPort::float("Sum")],
+                trigger_inputs: vec![TriggerInput::new("Reset")],
+                trigger_outputs: vec![],
+            }
+        }
+
+        fn vec3_sink(name: &'static str) -> Self {
+            Self {
+                id: Id::new(),
+                name,
+                inputs: vec![InputPort::new("In", Value::Vec3([0.0; 3]))],
+                outputs: vec![],
+                trigger_inputs: vec![],
+                trigger_outputs: vec![],
+            }
+        }
+    }
+
+    impl Operator for ExportTestOp {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            self.name
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn trigger_inputs(&self) -> &[TriggerInput] {
+            &self.trigger_inputs
+        }
+        fn trigger_inputs_mut(&mut self) -> &mut [TriggerInput] {
+            &mut self.trigger_inputs
+        }
+        fn trigger_outputs(&self) -> &[TriggerOutput] {
+            &self.trigger_outputs
+        }
+        fn trigger_outputs_mut(&mut self) -> &mut [TriggerOutput] {
+            &mut self.trigger_outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {}
+    }
+
+    /// a -> sum (twice, multi-input), a -> [conv] -> sink, a ~> sum (trigger)
+    fn build_graph() -> (Graph, Id, Id, Id, Id) {
+        let mut graph = Graph::new();
+        let a = graph.add(ExportTestOp::source("Source"));
+        let sum = graph.add(ExportTestOp::sum("Sum"));
+        let sink = graph.add(ExportTestOp::vec3_sink("Sink"));
+        graph.connect(a, 0, sum, 0).unwrap();
+        graph.connect(a, 0, sum, 0).unwrap();
+        let conv = graph.connect(a, 0, sink, 0).unwrap().unwrap();
+        graph.connect_trigger(a, 0, sum, 0).unwrap();
+        (graph, a, sum, sink, conv)
+    }
+
+    #[test]
+    fn test_to_dot_snapshot() {
+        let (graph, a, sum, sink, conv) = build_graph();
+        let dot = graph.to_dot(&ExportOptions::new());
+
+        assert!(dot.starts_with("digraph flux {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains(&format!("    \"{}\" [label=\"Source\"];", node_key(a))));
+        assert!(dot.contains(&format!("    \"{}\" [label=\"Sum\"];", node_key(sum))));
+        assert!(dot.contains(&format!(
+            "    \"{}\" [label=\"Convert\\nFloat → Vec3\", style=\"rounded,dashed\", color=gray50, fontcolor=gray30];",
+            node_key(conv)
+        )));
+
+        // Multi-input: two edges into the same port
+        let edge = format!(
+            "    \"{}\" -> \"{}\" [label=\"0 → 0 : Float\"];",
+            node_key(a),
+            node_key(sum)
+        );
+        assert_eq!(dot.matches(&edge).count(), 2);
+        assert!(dot.contains(&format!(
+            "    \"{}\" -> \"{}\" [label=\"0 → 0 : Vec3\"];",
+            node_key(conv),
+            node_key(sink)
+        )));
+        // Trigger edge is dashed
+        assert!(dot.contains(&format!(
+            "    \"{}\" -> \"{}\" [label=\"trigger 0 → 0\", style=dashed];",
+            node_key(a),
+            node_key(sum)
+        )));
+        // 3 nodes + conversion, 4 value edges + 1 trigger edge
+        assert_eq!(dot.matches(" [label=").count(), 9);
+    }
+
+    #[test]
+    fn test_to_dot_group_clusters() {
+        let (mut graph, a, sum, sink, _) = build_graph();
+        let fx = graph.create_group("Background \"FX\"", [1.0, 0.5, 0.0, 1.0]);
+        let other = graph.create_group("Other", [0.0, 0.0, 1.0, 1.0]);
+        graph.assign_to_group(a, fx).unwrap();
+        graph.assign_to_group(sum, fx).unwrap();
+        // Also in a newer group; drawn in the oldest
+        graph.assign_to_group(sum, other).unwrap();
+
+        let plain = graph.to_dot(&ExportOptions::new());
+        assert!(!plain.contains("subgraph"));
+
+        let dot = graph.to_dot(&ExportOptions::new().with_group_clusters());
+        let header = format!(
+            "    subgraph cluster_{} {{\n        label=\"Background \\\"FX\\\"\";\n        color=\"#ff8000\";\n",
+            fx.index()
+        );
+        let start = dot.find(&header).expect("cluster header");
+        let cluster = &dot[start..start + dot[start..].find("    }\n").unwrap()];
+        assert!(cluster.contains(&format!("        \"{}\" [label=\"Source\"];", node_key(a))));
+        assert!(cluster.contains(&format!("        \"{}\" [label=\"Sum\"];", node_key(sum))));
+        assert!(!dot.contains(&format!("cluster_{}", other.index())));
+        // Ungrouped nodes stay at the top level
+        assert!(dot.contains(&format!("\n    \"{}\" [label=\"Sink\"];", node_key(sink))));
+    }
+
+    #[test]
+    fn test_to_dot_escapes_labels() {
+        let (graph, a, ..) = build_graph();
+        let options = ExportOptions::new().with_label(a, r#"My "quoted" {node} \ here"#);
+        let dot = graph.to_dot(&options);
+        assert!(dot.contains(&format!(
+            "    \"{}\" [label=\"Source\\nMy \\\"quoted\\\" {{node}} \\\\ here\"];",
+            node_key(a)
+        )));
+    }
+
+    #[test]
+    fn test_to_dot_includes_values() {
+        let (mut graph, a, _, sink, _) = build_graph();
+        graph.evaluate(a, 0, &EvalContext::new()).unwrap();
+
+        let dot = graph.to_dot(&ExportOptions::new().with_values());
+        assert!(dot.contains(&format!(
+            "    \"{}\" [label=\"Source\", tooltip=\"Out = 1.5\"];",
+            node_key(a)
+        )));
+        // Nodes without outputs get no tooltip
+        assert!(dot.contains(&format!("    \"{}\" [label=\"Sink\"];", node_key(sink))));
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let (graph, a, sum, _, conv) = build_graph();
+        let mermaid = graph.to_mermaid();
+
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains(&format!("    {}[\"Source\"]", node_key(a))));
+        assert!(mermaid.contains(&format!(
+            "    {} -->|\"0 → 0 : Float\"| {}",
+            node_key(a),
+            node_key(sum)
+        )));
+        assert!(mermaid.contains(&format!(
+            "    {} -.->|\"trigger 0 → 0\"| {}",
+            node_key(a),
+            node_key(sum)
+        )));
+        assert!(mermaid.contains(&format!("    class {} conversion;", node_key(conv))));
+    }
+
+    #[test]
+    fn test_escape_mermaid() {
+        assert_eq!(escape_mermaid(r#"a "b" <c>"#), "a #quot;b#quot; #lt;c#gt;");
+    }
+}
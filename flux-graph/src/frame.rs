@@ -0,0 +1,185 @@
+//! Evaluation epochs ("frames")
+//!
+//! Some graph state only makes sense per frame: which nodes computed, which
+//! errors were reported, when watched outputs are recorded and when the
+//! cache is collected. A host that evaluates several outputs per frame
+//! brackets them with [`Graph::begin_frame`] and [`Graph::end_frame`]:
+//!
+//! ```ignore
+//! graph.begin_frame(&ctx)?;
+//! let position = graph.evaluate(mover, 0, &ctx)?;
+//! let color = graph.evaluate(tint, 0, &ctx)?;
+//! graph.end_frame()?; // emits GraphEvent::FrameCompleted
+//!
+//! for (node, error) in graph.frame_errors() {
+//!     log_error(*node, error);
+//! }
+//! ```
+//!
+//! Inside a frame:
+//! - [`Graph::frame_evaluated_nodes`] and [`Graph::frame_errors`] accumulate
+//!   across evaluations
+//! - watched outputs are snapshotted once, when the frame ends, in the call
+//!   context of the last evaluation
+//! - automatic cache collection ([`Graph::set_cache_max_age`]) runs when the
+//!   frame ends
+//!
+//! Evaluations should use the frame number and time the frame was opened
+//! with. A frame's state stays readable after it ends, until the next one
+//! begins.
+//!
+//! Evaluating outside an explicit frame opens and closes one around the
+//! call, so a host that never calls `begin_frame` sees every evaluation as
+//! its own frame, as before. Implicit frames don't emit `FrameCompleted`.
+//!
+//! [`Graph::begin_frame`]: crate::Graph::begin_frame
+//! [`Graph::end_frame`]: crate::Graph::end_frame
+//! [`Graph::frame_evaluated_nodes`]: crate::Graph::frame_evaluated_nodes
+//! [`Graph::frame_errors`]: crate::Graph::frame_errors
+//! [`Graph::set_cache_max_age`]: crate::Graph::set_cache_max_age
+
+use std::collections::HashSet;
+
+use flux_core::{CallContext, EvalContext, Id, OperatorError};
+
+/// State of the current (or most recent) frame
+#[derive(Debug, Default)]
+pub(crate) struct FrameEpoch {
+    /// Frame number the frame was opened with
+    pub(crate) frame: u64,
+    /// Time the frame was opened with
+    pub(crate) time: f64,
+    /// True between `begin_frame` and `end_frame`
+    pub(crate) explicit: bool,
+    /// Call context of the last evaluation, used for watch snapshots
+    pub(crate) call_context: CallContext,
+    /// Nodes computed during the frame
+    pub(crate) evaluated: HashSet<Id>,
+    /// Errors reported during the frame, in the order they were reported
+    pub(crate) errors: Vec<(Id, OperatorError)>,
+}
+
+impl FrameEpoch {
+    pub(crate) fn new(ctx: &EvalContext, explicit: bool) -> Self {
+        Self {
+            frame: ctx.frame,
+            time: ctx.time,
+            explicit,
+            call_context: ctx.call_context,
+            evaluated: HashSet::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    /// Record that `node` computed and reported `errors`.
+    pub(crate) fn record(&mut self, node: Id, errors: &[OperatorError]) {
+        self.evaluated.insert(node);
+        self.errors
+            .extend(errors.iter().map(|error| (node, error.clone())));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::graph::{Graph, GraphError, GraphEvent};
+    use flux_core::{EvalContext, Id, Value};
+    use flux_operators::{AddOp, ConstantOp, SwizzleOp};
+
+    /// A Constant -> Add chain and a Swizzle whose pattern the tests break
+    fn test_graph() -> (Graph, Id, Id) {
+        let mut graph = Graph::new();
+        let constant = graph.add(ConstantOp::new(2.0));
+        let add = graph.add(AddOp::new());
+        graph.connect(constant, 0, add, 0).unwrap();
+        let swizzle = graph.add(SwizzleOp::new());
+        (graph, add, swizzle)
+    }
+
+    fn set_pattern(graph: &mut Graph, swizzle: Id, pattern: &str) {
+        graph.set_input_default(swizzle, 1, Value::String(pattern.to_string()));
+    }
+
+    fn frame_completed(graph: &mut Graph) -> Vec<(u64, usize)> {
+        graph
+            .drain_events()
+            .filter_map(|event| match event {
+                GraphEvent::FrameCompleted {
+                    frame,
+                    evaluated_nodes,
+                } => Some((frame, evaluated_nodes)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_evaluations_in_one_frame_share_state() {
+        let (mut graph, add, swizzle) = test_graph();
+        let ctx = EvalContext::new();
+
+        graph.begin_frame(&ctx).unwrap();
+        assert!(matches!(
+            graph.begin_frame(&ctx),
+            Err(GraphError::FrameAlreadyOpen { frame: 0 })
+        ));
+        graph.evaluate(add, 0, &ctx).unwrap();
+        set_pattern(&mut graph, swizzle, "q");
+        graph.evaluate(swizzle, 0, &ctx).unwrap();
+        set_pattern(&mut graph, swizzle, "xy");
+        graph.evaluate(swizzle, 0, &ctx).unwrap();
+        assert!(graph.in_frame());
+
+        // The error from the second evaluation outlives the clean third one
+        assert!(graph.node_errors(swizzle).is_empty());
+        let errored: Vec<Id> = graph.frame_errors().iter().map(|(id, _)| *id).collect();
+        assert_eq!(errored, [swizzle]);
+
+        assert_eq!(graph.end_frame().unwrap(), 3);
+        assert_eq!(graph.frame_evaluated_nodes().count(), 3);
+        assert!(!graph.in_frame());
+        assert!(matches!(graph.end_frame(), Err(GraphError::NoFrameOpen)));
+        assert_eq!(frame_completed(&mut graph), [(0, 3)]);
+
+        // The next frame starts clean
+        let mut next = ctx.clone();
+        next.advance(1.0 / 60.0);
+        graph.begin_frame(&next).unwrap();
+        assert!(graph.frame_errors().is_empty());
+        assert_eq!(graph.frame_evaluated_nodes().count(), 0);
+    }
+
+    #[test]
+    fn test_implicit_frames_match_plain_evaluation() {
+        let (mut graph, add, swizzle) = test_graph();
+        let watch = graph.watch_output(add, 0).unwrap();
+        let ctx = EvalContext::new();
+
+        assert_eq!(graph.evaluate(add, 0, &ctx).unwrap(), Value::Float(2.0));
+        assert_eq!(graph.watch_values()[&watch], Value::Float(2.0));
+        assert_eq!(graph.frame_evaluated_nodes().count(), 3);
+        assert!(!graph.in_frame());
+
+        // Each evaluation is its own frame
+        set_pattern(&mut graph, swizzle, "q");
+        graph.evaluate(add, 0, &ctx).unwrap();
+        assert_eq!(graph.frame_evaluated_nodes().collect::<Vec<_>>(), [swizzle]);
+        assert_eq!(graph.frame_errors().len(), 1);
+        set_pattern(&mut graph, swizzle, "xy");
+        graph.evaluate(add, 0, &ctx).unwrap();
+        assert!(graph.frame_errors().is_empty());
+        assert!(frame_completed(&mut graph).is_empty());
+    }
+
+    #[test]
+    fn test_watches_snapshot_at_end_of_frame() {
+        let (mut graph, add, _) = test_graph();
+        let watch = graph.watch_output(add, 0).unwrap();
+        let ctx = EvalContext::new();
+
+        graph.begin_frame(&ctx).unwrap();
+        graph.evaluate(add, 0, &ctx).unwrap();
+        assert_eq!(graph.watch_values().get(&watch), None);
+        graph.end_frame().unwrap();
+        assert_eq!(graph.watch_values()[&watch], Value::Float(2.0));
+    }
+}
@@ -1,12 +1,38 @@
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
+use std::cell::OnceCell;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
-
-use crate::conversion::ConversionOp;
-use flux_core::context::{CallContext, EvalContext};
-use flux_core::id::Id;
-use flux_core::operator::Operator;
-use flux_core::operator_meta::{EffectivePortMeta, PortOverride};
-use flux_core::value::{Value, ValueType};
+use std::time::{Duration, Instant};
+
+use crate::budget::{BudgetedEval, EvalProgress};
+use crate::conversion::{ConversionOp, UnitConversionOp};
+use crate::determinism::{
+    compare_outputs, DeterminismReport, DivergentNode, OutputDivergence, UnauditableNode,
+    AUDIT_CALL_INDEX,
+};
+use crate::event_queue::{coalesce, EventOverflow};
+use crate::frame::FrameEpoch;
+use crate::group::{Group, GroupId, GroupSet};
+use crate::missing::MissingOp;
+use crate::preview::{PreviewResult, PreviewSettings, PREVIEW_CALL_INDEX};
+use crate::quarantine::{panic_message, NodePanic, PanicPolicy};
+use crate::trace::{EvalTrace, NoTrace, TraceReason, TraceSink, Tracer};
+use crate::trash::{RestoreReport, TrashedNode};
+use crate::update_rate::UpdateRate;
+use crate::watch::{WatchId, WatchSet, WatchUpdate};
+use flux_core::context::{
+    CallContext, EvalContext, GizmoVisibility, DEFAULT_MAX_DEPTH, DEFAULT_MAX_LIST_LEN,
+};
+use flux_core::error::OperatorError;
+use flux_core::id::{Id, InputRef, NodeId, OutputRef};
+use flux_core::operator::{ActiveInput, InputValidationIssue, Operator, ValidationSeverity};
+use flux_core::operator_meta::{EffectivePortMeta, OperatorMeta, PortClamp, PortMeta, PortOverride};
+use flux_core::port::{DefaultSource, InputPort, PortChange, TakenConnections};
+use flux_core::unit::Unit;
+use flux_core::value::{FromValue, Value, ValueType};
+use flux_operators::CapturedMeta;
 
 /// Cache key combining node ID and call context for context-aware caching.
 ///
@@ -18,12 +44,473 @@ struct CacheKey {
     call_context: CallContext,
 }
 
+/// Cached outputs of a node in one call context.
+struct CacheEntry {
+    outputs: Vec<Arc<Value>>,
+    /// Frame the entry was last computed or reused in (see [`Graph::gc_cache`])
+    last_used: u64,
+    /// Fingerprint of the context parts the operator declared it depends on
+    context_fingerprint: u64,
+    /// Outputs the operator left clean when the entry was stored
+    valid: Vec<bool>,
+}
+
+/// Outputs an evaluation reads: the requested output, and every output
+/// feeding a node upstream of it.
+///
+/// Only consulted for operators whose outputs aren't coupled (see
+/// [`Operator::outputs_coupled`]), so the upstream walk is done on first use.
+struct OutputDemand {
+    target: (Id, usize),
+    upstream: OnceCell<HashSet<Id>>,
+}
+
+impl OutputDemand {
+    fn new(output_node: Id, output_index: usize) -> Self {
+        Self {
+            target: (output_node, output_index),
+            upstream: OnceCell::new(),
+        }
+    }
+}
+
+/// An operator's [`validate_inputs`](Operator::validate_inputs) issues for
+/// its current input defaults.
+fn current_input_issues(operator: &dyn Operator) -> Vec<InputValidationIssue> {
+    let defaults: Vec<Value> = operator.inputs().iter().map(|i| i.default.clone()).collect();
+    operator.validate_inputs(&defaults)
+}
+
+/// Sources connected to an input port (single connection, then multi-input).
+fn port_sources(input: &InputPort) -> impl Iterator<Item = (Id, usize)> + '_ {
+    input.connection.iter().chain(&input.connections).copied()
+}
+
+/// Sources of an input port's unmuted connections.
+pub(crate) fn live_sources(input: &InputPort) -> impl Iterator<Item = (Id, usize)> + '_ {
+    port_sources(input)
+        .enumerate()
+        .filter(|&(slot, _)| !input.is_muted(slot))
+        .map(|(_, source)| source)
+}
+
+/// Connection slot of an edge on an input port, preferring one whose muted
+/// state matches `muted` when the same source feeds the input more than once.
+fn edge_slot(input: &InputPort, source: (Id, usize), muted: bool) -> Option<usize> {
+    let slots: Vec<usize> = port_sources(input)
+        .enumerate()
+        .filter(|&(_, s)| s == source)
+        .map(|(slot, _)| slot)
+        .collect();
+    let preferred = slots.iter().copied().find(|&slot| input.is_muted(slot) == muted);
+    preferred.or(slots.first().copied())
+}
+
 /// A node in the graph (wraps an operator)
 pub(crate) struct Node {
     pub(crate) operator: Box<dyn Operator>,
+    /// Order in which the node was added, used to break ties in the
+    /// evaluation order deterministically
+    sequence: u64,
     /// Per-instance overrides for input port UI behavior.
     /// Sparse storage - only extends to highest overridden index.
     input_overrides: Vec<Option<PortOverride>>,
+    /// In-flight default value transitions for inputs with smoothing enabled.
+    input_transitions: HashMap<usize, InputTransition>,
+    /// Inputs whose default is read from a context variable
+    context_bindings: HashMap<usize, ContextBinding>,
+    /// How often this node is recomputed
+    update_rate: UpdateRate,
+    /// Frame and time of the last computation (for update rate throttling)
+    last_update: Option<(u64, f64)>,
+    /// Set by `Graph::request_update`; forces the next evaluation
+    update_requested: bool,
+    /// Gizmo visibility passed to the operator as `EvalContext::current_node_gizmos`
+    gizmo_visibility: GizmoVisibility,
+    /// Outputs pinned by `Graph::freeze_output`, with their pinned values
+    /// (`None` until the node's first evaluation)
+    frozen_outputs: BTreeMap<usize, Option<Value>>,
+    /// Typed units declared on inputs (sparse, like `input_overrides`)
+    input_units: Vec<Option<Unit>>,
+    /// Typed units declared on outputs (sparse, like `input_overrides`)
+    output_units: Vec<Option<Unit>>,
+    /// Input metadata recorded by `Graph::add_with_meta`
+    input_meta: Vec<Option<PortMeta>>,
+    /// Range enforcement per input, resolved from `input_meta` and
+    /// `input_overrides` (empty when no input is clamped)
+    input_clamps: Vec<Option<PortClamp>>,
+    /// Input defaults as they were when the node was added
+    factory_defaults: Vec<Value>,
+    /// Editor position set by `Graph::set_node_position`
+    position: Option<[f32; 2]>,
+    /// Issues from the operator's `validate_inputs` as of the last default
+    /// or connection change
+    input_issues: Vec<InputValidationIssue>,
+}
+
+/// An input bound to a context variable.
+///
+/// The input's `default` holds the fallback used while the variable is absent.
+#[derive(Debug, Clone)]
+struct ContextBinding {
+    /// Variable name looked up in the evaluation context
+    name: String,
+    /// Variable value used by the last compute (`None` if it was absent)
+    resolved: Option<Value>,
+}
+
+impl ContextBinding {
+    /// Current value of the variable, coerced to `value_type`.
+    fn lookup(&self, ctx: &EvalContext, value_type: ValueType) -> Option<Value> {
+        ctx.get_var(&self.name)
+            .and_then(|value| value.coerce_to(value_type))
+    }
+}
+
+/// A smoothed transition from a previous input default toward the current one.
+#[derive(Debug, Clone)]
+struct InputTransition {
+    /// Value the ramp starts from
+    from: Value,
+    /// Time the ramp started (captured on the first evaluation after the change)
+    start_time: Option<f64>,
+    /// Ramp duration in seconds
+    duration: f64,
+    /// Most recently applied value, used as the start point if retargeted mid-flight
+    current: Value,
+}
+
+impl InputTransition {
+    /// Returns true if the value type can be interpolated by input smoothing.
+    fn is_smoothable(value: &Value) -> bool {
+        matches!(
+            value,
+            Value::Float(_) | Value::Vec2(_) | Value::Vec3(_) | Value::Vec4(_) | Value::Color(_)
+        )
+    }
+
+    /// Sample the ramp toward `target` at `time`. Returns the value and whether it finished.
+    fn sample(&mut self, target: &Value, time: f64) -> (Value, bool) {
+        let start = *self.start_time.get_or_insert(time);
+        let t = if self.duration > 0.0 {
+            ((time - start) / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        if t >= 1.0 {
+            return (target.clone(), true);
+        }
+        let value = self
+            .from
+            .lerp(target, &Value::Float(t as f32))
+            .unwrap_or_else(|| target.clone());
+        self.current = value.clone();
+        (value, false)
+    }
+}
+
+impl Node {
+    fn new(operator: Box<dyn Operator>, sequence: u64) -> Self {
+        let factory_defaults = operator.inputs().iter().map(|i| i.default.clone()).collect();
+        Self {
+            operator,
+            sequence,
+            input_overrides: Vec::new(),
+            input_transitions: HashMap::new(),
+            context_bindings: HashMap::new(),
+            update_rate: UpdateRate::EveryFrame,
+            last_update: None,
+            update_requested: false,
+            gizmo_visibility: GizmoVisibility::Inherit,
+            frozen_outputs: BTreeMap::new(),
+            input_units: Vec::new(),
+            output_units: Vec::new(),
+            input_meta: Vec::new(),
+            input_clamps: Vec::new(),
+            factory_defaults,
+            position: None,
+            input_issues: Vec::new(),
+        }
+    }
+
+    /// The context to compute this node with, carrying its gizmo visibility.
+    fn context<'a>(&self, ctx: &'a EvalContext) -> Cow<'a, EvalContext> {
+        if self.gizmo_visibility == ctx.current_node_gizmos {
+            Cow::Borrowed(ctx)
+        } else {
+            let mut ctx = ctx.clone();
+            ctx.current_node_gizmos = self.gizmo_visibility;
+            Cow::Owned(ctx)
+        }
+    }
+
+    /// Record the operator's declared port metadata and the typed units in it.
+    fn set_port_meta(&mut self, input_meta: Vec<Option<PortMeta>>, output_meta: &[Option<PortMeta>]) {
+        let typed_unit = |meta: &Option<PortMeta>| meta.as_ref().and_then(|m| m.typed_unit);
+        self.input_units = input_meta.iter().map(typed_unit).collect();
+        self.output_units = output_meta.iter().map(typed_unit).collect();
+        self.input_meta = input_meta;
+        self.refresh_clamps();
+    }
+
+    /// Re-resolve `input_clamps` after metadata or overrides changed.
+    fn refresh_clamps(&mut self) {
+        let count = self.input_meta.len().max(self.input_overrides.len());
+        self.input_clamps = (0..count)
+            .map(|i| {
+                PortClamp::resolve(
+                    self.input_meta.get(i).and_then(Option::as_ref),
+                    self.input_overrides.get(i).and_then(Option::as_ref),
+                )
+            })
+            .collect();
+        if self.input_clamps.iter().all(Option::is_none) {
+            self.input_clamps.clear();
+        }
+    }
+
+    /// Swap clamped values into the defaults of unconnected clamped inputs.
+    ///
+    /// Returns the original defaults that must be restored after computing.
+    pub(crate) fn apply_default_clamps(&mut self) -> Vec<(usize, Value)> {
+        let mut restore = Vec::new();
+        let inputs = self.operator.inputs_mut();
+        for (index, clamp) in self.input_clamps.iter().enumerate() {
+            let (Some(clamp), Some(input)) = (clamp, inputs.get_mut(index)) else {
+                continue;
+            };
+            if input.is_connected() {
+                continue;
+            }
+            let clamped = clamp.apply(input.default.clone());
+            if clamped != input.default {
+                restore.push((index, std::mem::replace(&mut input.default, clamped)));
+            }
+        }
+        restore
+    }
+
+    /// Clamps for the sources feeding clamped, connected inputs.
+    ///
+    /// If one source output feeds several inputs of this node, the first
+    /// clamped input's limits apply to all of them.
+    pub(crate) fn connected_clamps(&self) -> Vec<((Id, usize), PortClamp)> {
+        let inputs = self.operator.inputs();
+        self.input_clamps
+            .iter()
+            .enumerate()
+            .filter_map(|(index, clamp)| Some((inputs.get(index)?, (*clamp)?)))
+            .flat_map(|(input, clamp)| {
+                input
+                    .connection
+                    .iter()
+                    .chain(&input.connections)
+                    .map(move |&source| (source, clamp))
+            })
+            .collect()
+    }
+
+    /// Swap smoothed values into the defaults of inputs with in-flight transitions.
+    ///
+    /// Returns the target defaults that must be restored after computing.
+    /// Transitions on connected inputs are dropped.
+    fn apply_input_smoothing(&mut self, time: f64) -> Vec<(usize, Value)> {
+        if self.input_transitions.is_empty() {
+            return Vec::new();
+        }
+
+        let mut restore = Vec::with_capacity(self.input_transitions.len());
+        let inputs = self.operator.inputs_mut();
+        self.input_transitions.retain(|&index, transition| {
+            let Some(input) = inputs.get_mut(index) else {
+                return false;
+            };
+            if input.is_connected() {
+                return false;
+            }
+            let (value, finished) = transition.sample(&input.default, time);
+            if finished {
+                return false;
+            }
+            let target = std::mem::replace(&mut input.default, value);
+            restore.push((index, target));
+            true
+        });
+        restore
+    }
+
+    /// Swap context variable values into the defaults of bound, unconnected inputs.
+    ///
+    /// Returns the fallback defaults that must be restored after computing.
+    fn apply_context_bindings(&mut self, ctx: &EvalContext) -> Vec<(usize, Value)> {
+        let mut restore = Vec::new();
+        let inputs = self.operator.inputs_mut();
+        for (&index, binding) in &mut self.context_bindings {
+            let Some(input) = inputs.get_mut(index) else {
+                continue;
+            };
+            if input.is_connected() {
+                continue;
+            }
+            binding.resolved = binding.lookup(ctx, input.value_type);
+            if let Some(value) = binding.resolved.clone() {
+                restore.push((index, std::mem::replace(&mut input.default, value)));
+            }
+        }
+        restore
+    }
+
+    /// Value an unconnected input will have in the next compute, or `None`
+    /// while it's transitioning to a new default.
+    fn unconnected_input_value(&self, index: usize, ctx: &EvalContext) -> Option<Value> {
+        if self.input_transitions.contains_key(&index) {
+            return None;
+        }
+        let input = self.operator.inputs().get(index)?;
+        let value = self
+            .context_bindings
+            .get(&index)
+            .and_then(|binding| binding.lookup(ctx, input.value_type))
+            .unwrap_or_else(|| input.default.clone());
+        Some(self.clamp_input(index, value))
+    }
+
+    /// Apply an input's range enforcement, if it has any.
+    fn clamp_input(&self, index: usize, value: Value) -> Value {
+        match self.input_clamps.get(index).copied().flatten() {
+            Some(clamp) => clamp.apply(value),
+            None => value,
+        }
+    }
+
+    /// Returns true if a bound variable changed since the last compute.
+    fn context_bindings_changed(&self, ctx: &EvalContext) -> bool {
+        let inputs = self.operator.inputs();
+        self.context_bindings.iter().any(|(&index, binding)| {
+            inputs.get(index).is_some_and(|input| {
+                !input.is_connected()
+                    && binding.lookup(ctx, input.value_type) != binding.resolved
+            })
+        })
+    }
+
+    /// Take muted connections off the inputs so the operator reads them as
+    /// absent.
+    ///
+    /// Returns the full connection state that must be restored after computing.
+    pub(crate) fn apply_connection_mutes(&mut self) -> Vec<(usize, TakenConnections)> {
+        let inputs = self.operator.inputs_mut();
+        inputs
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, input)| Some((index, input.take_muted()?)))
+            .collect()
+    }
+
+    /// Restore connections taken by [`Node::apply_connection_mutes`].
+    pub(crate) fn restore_connection_mutes(&mut self, restore: Vec<(usize, TakenConnections)>) {
+        let inputs = self.operator.inputs_mut();
+        for (index, taken) in restore {
+            if let Some(input) = inputs.get_mut(index) {
+                input.restore_muted(taken);
+            }
+        }
+    }
+
+    /// Restore target defaults replaced by [`Node::apply_input_smoothing`].
+    pub(crate) fn restore_input_defaults(&mut self, restore: Vec<(usize, Value)>) {
+        let inputs = self.operator.inputs_mut();
+        for (index, value) in restore {
+            if let Some(input) = inputs.get_mut(index) {
+                input.default = value;
+            }
+        }
+    }
+
+    /// Names of the operator's current inputs and outputs.
+    fn port_names(&self) -> (Vec<&'static str>, Vec<&'static str>) {
+        (
+            self.operator.inputs().iter().map(|i| i.name).collect(),
+            self.operator.outputs().iter().map(|o| o.name).collect(),
+        )
+    }
+
+    /// Move per-port state to the ports' indices after `change`, dropping
+    /// the state of removed ports.
+    fn remap_ports(&mut self, change: &PortChange) {
+        let (inputs, outputs) = self.port_names();
+        let input_map = |index| change.inputs.new_index(index, &inputs);
+        let output_map = |index| change.outputs.new_index(index, &outputs);
+
+        self.input_overrides = remap_slots(std::mem::take(&mut self.input_overrides), input_map);
+        self.input_units = remap_slots(std::mem::take(&mut self.input_units), input_map);
+        self.input_meta = remap_slots(std::mem::take(&mut self.input_meta), input_map);
+        self.output_units = remap_slots(std::mem::take(&mut self.output_units), output_map);
+        self.input_transitions = remap_keys(std::mem::take(&mut self.input_transitions), input_map);
+        self.context_bindings = remap_keys(std::mem::take(&mut self.context_bindings), input_map);
+        self.frozen_outputs = remap_keys(std::mem::take(&mut self.frozen_outputs), output_map);
+
+        // New inputs start from their current default
+        let mut factory: Vec<Option<Value>> = vec![None; inputs.len()];
+        for (index, value) in std::mem::take(&mut self.factory_defaults).into_iter().enumerate() {
+            if let Some(new_index) = input_map(index) {
+                factory[new_index] = Some(value);
+            }
+        }
+        self.factory_defaults = factory
+            .into_iter()
+            .zip(self.operator.inputs())
+            .map(|(value, input)| value.unwrap_or_else(|| input.default.clone()))
+            .collect();
+        self.refresh_clamps();
+    }
+
+    /// Exchange the connections and per-instance state of inputs `a` and
+    /// `b`, giving them the new defaults `(default_a, default_b)`.
+    fn swap_inputs(&mut self, a: usize, b: usize, (default_a, default_b): (Value, Value)) {
+        let inputs = self.operator.inputs_mut();
+        inputs[a].default = default_a;
+        inputs[b].default = default_b;
+        let (head, tail) = inputs.split_at_mut(a.max(b));
+        let (first, second) = (&mut head[a.min(b)], &mut tail[0]);
+        std::mem::swap(&mut first.connection, &mut second.connection);
+        std::mem::swap(&mut first.connections, &mut second.connections);
+        std::mem::swap(&mut first.muted, &mut second.muted);
+        std::mem::swap(&mut first.resolved_type, &mut second.resolved_type);
+
+        let swap = |index| Some(if index == a { b } else if index == b { a } else { index });
+        self.input_overrides = remap_slots(std::mem::take(&mut self.input_overrides), swap);
+        self.input_transitions = remap_keys(std::mem::take(&mut self.input_transitions), swap);
+        self.context_bindings = remap_keys(std::mem::take(&mut self.context_bindings), swap);
+        self.refresh_clamps();
+    }
+}
+
+/// Move indexed slots to new indices, dropping those `map` removes.
+fn remap_slots<T>(slots: Vec<Option<T>>, map: impl Fn(usize) -> Option<usize>) -> Vec<Option<T>> {
+    let mut remapped: Vec<Option<T>> = Vec::new();
+    for (index, slot) in slots.into_iter().enumerate() {
+        let (Some(slot), Some(new_index)) = (slot, map(index)) else {
+            continue;
+        };
+        if remapped.len() <= new_index {
+            remapped.resize_with(new_index + 1, || None);
+        }
+        remapped[new_index] = Some(slot);
+    }
+    remapped
+}
+
+/// Move map entries keyed by port index to new indices, dropping those
+/// `map` removes.
+fn remap_keys<C, T>(entries: C, map: impl Fn(usize) -> Option<usize>) -> C
+where
+    C: IntoIterator<Item = (usize, T)> + FromIterator<(usize, T)>,
+{
+    entries
+        .into_iter()
+        .filter_map(|(index, value)| Some((map(index)?, value)))
+        .collect()
 }
 
 /// Events emitted by the graph when its structure changes.
@@ -31,6 +518,15 @@ pub(crate) struct Node {
 /// These events enable reactive synchronization with visual layers (like nodal)
 /// without requiring the integration layer to poll for changes.
 ///
+/// Events that change or remove state carry what was there before, so
+/// external mirrors can be updated without keeping their own copy. Only the
+/// graph constructs them.
+///
+/// Operations that produce several events at once (removing a connected
+/// node, connecting through an auto-inserted conversion, splicing) follow
+/// them with a [`BatchEnd`](GraphEvent::BatchEnd) marker, so consumers can
+/// coalesce UI updates until the batch is complete.
+///
 /// # Example
 ///
 /// ```ignore
@@ -55,7 +551,16 @@ pub enum GraphEvent {
     /// A node was added to the graph.
     NodeAdded { id: Id },
     /// A node was removed from the graph.
-    NodeRemoved { id: Id },
+    ///
+    /// Preceded by a `Disconnected` event for each connection in `severed`.
+    #[non_exhaustive]
+    NodeRemoved {
+        id: Id,
+        /// Operator name of the removed node
+        operator: &'static str,
+        /// Value connections into and out of the node that were removed with it
+        severed: Vec<Connection>,
+    },
     /// A connection was created between two nodes.
     Connected {
         source: Id,
@@ -64,13 +569,66 @@ pub enum GraphEvent {
         target_input: usize,
     },
     /// A connection was removed.
-    Disconnected { target: Id, target_input: usize },
+    ///
+    /// Disconnecting a multi-input emits one event per former source.
+    #[non_exhaustive]
+    Disconnected {
+        /// Node the input was connected to
+        source: Id,
+        source_output: usize,
+        target: Id,
+        target_input: usize,
+    },
+    /// One end of an existing connection was moved to another port.
+    ///
+    /// Emitted by [`Graph::retarget_connection`] instead of a
+    /// `Disconnected`/`Connected` pair. If a conversion node had to be
+    /// inserted, `ConversionInserted` is emitted as well and `new` describes
+    /// the connection around it.
+    ConnectionRetargeted { old: Connection, new: Connection },
+    /// A connection was muted or unmuted by [`Graph::set_connection_muted`].
+    ///
+    /// `connection.muted` is the new state.
+    ConnectionMuted { connection: Connection },
+    /// Two inputs of a node exchanged their connections, defaults and
+    /// overrides by [`Graph::swap_inputs`].
+    ///
+    /// Stands in for the `Disconnected`/`Connected` and
+    /// `InputDefaultChanged` events the swap would otherwise produce.
+    InputsSwapped {
+        node: Id,
+        input_a: usize,
+        input_b: usize,
+    },
     /// An input's default value was changed.
+    #[non_exhaustive]
     InputDefaultChanged {
         node: Id,
         input: usize,
+        /// Default before the change
+        previous: Value,
         value: Value,
     },
+    /// The issues a node's operator reports for its inputs changed.
+    ///
+    /// Emitted after the default or connection change that caused it;
+    /// `issues` is the new list, as returned by [`Graph::input_issues`].
+    InputIssuesChanged {
+        node: Id,
+        issues: Vec<InputValidationIssue>,
+    },
+    /// An input was bound to a context variable, unbound, or rebound.
+    ///
+    /// Emitted by [`Graph::set_input_default_source`] and
+    /// [`Graph::set_input_context_binding`].
+    #[non_exhaustive]
+    InputSourceChanged {
+        node: Id,
+        input: usize,
+        /// Source before the change
+        previous: DefaultSource,
+        source: DefaultSource,
+    },
     /// The evaluation order was recomputed.
     OrderRecomputed,
     /// A conversion node was auto-inserted to bridge incompatible types.
@@ -86,6 +644,29 @@ pub enum GraphEvent {
         /// The target type being converted to
         target_type: ValueType,
     },
+    /// A unit conversion node was auto-inserted between two Float ports.
+    ///
+    /// Emitted when `connect()` links ports that declare different but
+    /// compatible typed units (e.g., Degrees to Radians).
+    UnitConversionInserted {
+        /// The auto-generated unit conversion node
+        conversion_node: Id,
+        /// The unit of the source output
+        source_unit: Unit,
+        /// The unit of the target input
+        target_unit: Unit,
+    },
+    /// Two ports with incompatible typed units were connected.
+    ///
+    /// This is a warning, not an error: the connection is made without scaling.
+    UnitMismatch {
+        source: Id,
+        source_output: usize,
+        target: Id,
+        target_input: usize,
+        source_unit: Unit,
+        target_unit: Unit,
+    },
     /// A trigger connection was created between two nodes.
     TriggerConnected {
         source: Id,
@@ -100,6 +681,65 @@ pub enum GraphEvent {
         target: Id,
         target_input: usize,
     },
+    /// An operator reported a non-fatal error while computing.
+    OperatorError {
+        node: Id,
+        error: OperatorError,
+    },
+    /// A node's operator was replaced by [`Graph::hot_swap`]; its ports may
+    /// have changed.
+    OperatorSwapped { id: Id },
+    /// A node's operator added, removed or reordered its own ports.
+    ///
+    /// Emitted by [`Graph::notify_ports_changed`], or after a compute whose
+    /// operator reported a change through
+    /// [`Operator::take_port_change`](flux_core::Operator::take_port_change).
+    /// Preceded by a `ConnectionRetargeted` event for each connection moved to
+    /// a new index and a `Disconnected` event for each one removed.
+    PortsChanged { node: Id },
+    /// A node was given a new editor position by [`Graph::set_node_position`].
+    NodeMoved { node: Id, position: [f32; 2] },
+    /// A group was created (or restored by undo).
+    GroupCreated { group: GroupId },
+    /// A group was removed, explicitly or because its last member was.
+    GroupRemoved { group: GroupId },
+    /// A group was renamed or recolored.
+    GroupChanged { group: GroupId },
+    /// A node joined or left a group.
+    ///
+    /// Removing a node emits one of these with `member: false` for each
+    /// group it was in.
+    GroupMembershipChanged { group: GroupId, node: Id, member: bool },
+    /// An operator panicked while computing under [`PanicPolicy::Catch`].
+    ///
+    /// The node is quarantined until [`Graph::clear_quarantine`] is called.
+    NodePanicked {
+        node: Id,
+        /// Operator name of the node
+        operator: &'static str,
+        message: String,
+    },
+    /// An explicit frame was closed by [`Graph::end_frame`].
+    ///
+    /// Not emitted for the implicit frame around an evaluation outside
+    /// [`Graph::begin_frame`].
+    FrameCompleted {
+        /// Frame number the frame was opened with
+        frame: u64,
+        /// Number of nodes computed during the frame
+        evaluated_nodes: usize,
+    },
+    /// Marks the end of events produced by one atomic operation.
+    ///
+    /// `events_in_batch` counts the events before this marker that belong to
+    /// the batch. Only emitted for batches of more than one event.
+    #[non_exhaustive]
+    BatchEnd { events_in_batch: usize },
+    /// Events were dropped from a bounded queue since the last drain.
+    ///
+    /// Always the first event of [`Graph::drain_events`] when present; see
+    /// [`Graph::set_event_capacity`].
+    EventsDropped { count: u64 },
 }
 
 /// The operator graph
@@ -109,6 +749,8 @@ pub struct Graph {
     pub(crate) eval_order: Vec<Id>,
     /// Whether the evaluation order needs recomputation
     order_dirty: bool,
+    /// Sequence number given to the next added node
+    next_sequence: u64,
     /// Cache of output values (CacheKey -> Vec<Arc<Value>>)
     ///
     /// The cache key includes both node ID and call context, ensuring that
@@ -118,9 +760,63 @@ pub struct Graph {
     /// Values are wrapped in `Arc` to enable reference stealing: when an
     /// operator is the sole consumer of a value (refcount == 1), we can
     /// pass ownership instead of cloning, avoiding unnecessary allocations.
-    value_cache: HashMap<CacheKey, Vec<Arc<Value>>>,
+    value_cache: HashMap<CacheKey, CacheEntry>,
+    /// Enclosing call contexts of each non-root context with cache entries,
+    /// used by [`evict_call_context_subtree`](Self::evict_call_context_subtree)
+    call_ancestors: HashMap<CallContext, Vec<CallContext>>,
+    /// Automatic [`gc_cache`](Self::gc_cache) age applied after evaluation
+    cache_max_age: Option<u64>,
+    /// Frame of the last automatic cache collection
+    last_gc_frame: Option<u64>,
+    /// Reverse connection index: `(source, output)` -> `(target, input)` for
+    /// every value edge, kept in sync with the target ports by each mutation
+    consumers: HashMap<(Id, usize), Vec<(Id, usize)>>,
     /// Pending events since last drain
     pending_events: Vec<GraphEvent>,
+    /// Maximum queued events outside an atomic operation (`None` is unbounded)
+    event_capacity: Option<usize>,
+    /// What happens to events past `event_capacity`
+    event_overflow: EventOverflow,
+    /// Events dropped over the graph's lifetime
+    dropped_events: u64,
+    /// Events dropped since the last drain
+    undrained_drops: u64,
+    /// Nesting depth of [`batch`](Self::batch) calls in progress
+    batch_depth: usize,
+    /// Bumped whenever structure or cached values are invalidated
+    generation: u64,
+    /// Pending time-sliced evaluation (see [`evaluate_budgeted`](Self::evaluate_budgeted))
+    budgeted: Option<BudgetedEval>,
+    /// Maximum list length passed to operators via `EvalContext::max_list_len`
+    max_list_len: usize,
+    /// Maximum composite nesting depth passed to operators via `EvalContext::max_depth`
+    max_depth: usize,
+    /// Errors reported by each node during its most recent compute
+    node_errors: HashMap<Id, Vec<OperatorError>>,
+    /// Outputs observed across evaluations (see [`watch_output`](Self::watch_output))
+    watches: WatchSet,
+    /// Limits applied by [`evaluate_preview`](Self::evaluate_preview)
+    preview: PreviewSettings,
+    /// Removed nodes kept for [`restore_from_trash`](Self::restore_from_trash), oldest first
+    trash: Vec<TrashedNode>,
+    /// Maximum number of trashed nodes (0 disables the trash)
+    trash_capacity: usize,
+    /// Frame and time of the most recent completed evaluation
+    last_eval: (u64, f64),
+    /// Current or most recent evaluation frame (see [`begin_frame`](Self::begin_frame))
+    epoch: FrameEpoch,
+    /// Organizational node groups (see [`create_group`](Self::create_group))
+    groups: GroupSet,
+    /// What evaluation does when an operator panics
+    panic_policy: PanicPolicy,
+    /// Nodes skipped by evaluation after panicking, with the caught panic
+    quarantine: HashMap<Id, NodePanic>,
+    /// Nodes whose outputs only reach muted connections, directly or through
+    /// other such nodes (recomputed with the evaluation order)
+    muted_branches: HashSet<Id>,
+    /// Nodes skipped as an inactive branch when they would have recomputed;
+    /// they recompute the next time they're evaluated
+    missed_updates: HashSet<CacheKey>,
 }
 
 impl Graph {
@@ -129,8 +825,34 @@ impl Graph {
             nodes: HashMap::new(),
             eval_order: Vec::new(),
             order_dirty: true,
+            next_sequence: 0,
             value_cache: HashMap::new(),
+            call_ancestors: HashMap::new(),
+            cache_max_age: None,
+            last_gc_frame: None,
+            consumers: HashMap::new(),
             pending_events: Vec::new(),
+            event_capacity: None,
+            event_overflow: EventOverflow::default(),
+            dropped_events: 0,
+            undrained_drops: 0,
+            batch_depth: 0,
+            generation: 0,
+            budgeted: None,
+            max_list_len: DEFAULT_MAX_LIST_LEN,
+            max_depth: DEFAULT_MAX_DEPTH,
+            node_errors: HashMap::new(),
+            watches: WatchSet::default(),
+            preview: PreviewSettings::default(),
+            trash: Vec::new(),
+            trash_capacity: 0,
+            last_eval: (0, 0.0),
+            epoch: FrameEpoch::default(),
+            groups: GroupSet::default(),
+            panic_policy: PanicPolicy::default(),
+            quarantine: HashMap::new(),
+            muted_branches: HashSet::new(),
+            missed_updates: HashSet::new(),
         }
     }
 
@@ -144,11 +866,145 @@ impl Graph {
     /// to ensure stale cached values are not used.
     fn invalidate_cache_for_node(&mut self, node_id: Id) {
         self.value_cache.retain(|key, _| key.node_id != node_id);
+        self.missed_updates.retain(|key| key.node_id != node_id);
+        self.generation += 1;
     }
 
     /// Clear the entire value cache (all nodes, all contexts).
     pub fn clear_cache(&mut self) {
         self.value_cache.clear();
+        self.missed_updates.clear();
+        self.call_ancestors.clear();
+        self.generation += 1;
+    }
+
+    /// Number of cached node outputs across all call contexts.
+    ///
+    /// Each node has one entry per call context it was evaluated in.
+    pub fn cache_entry_count(&self) -> usize {
+        self.value_cache.len()
+    }
+
+    /// Drop all cached values of a call context.
+    ///
+    /// For operators and hosts that know a loop iteration or subroutine call
+    /// has ended. Returns the number of entries removed.
+    pub fn evict_call_context(&mut self, call_context: CallContext) -> usize {
+        self.evict_cache_where(|key, _| key.call_context == call_context)
+    }
+
+    /// Drop all cached values of a call context and every context nested in
+    /// it (derived from it through [`EvalContext::with_call_context`]).
+    ///
+    /// Returns the number of entries removed.
+    pub fn evict_call_context_subtree(&mut self, call_context: CallContext) -> usize {
+        if call_context.is_root() {
+            let removed = self.value_cache.len();
+            self.clear_cache();
+            return removed;
+        }
+        let ancestors = &self.call_ancestors;
+        let in_subtree = |context: CallContext| {
+            context == call_context
+                || ancestors
+                    .get(&context)
+                    .is_some_and(|chain| chain.contains(&call_context))
+        };
+        let doomed: HashSet<CallContext> =
+            ancestors.keys().copied().filter(|c| in_subtree(*c)).collect();
+        self.evict_cache_where(|key, _| doomed.contains(&key.call_context))
+    }
+
+    /// Drop non-root cache entries not used in the last `max_age_frames`
+    /// frames.
+    ///
+    /// Root-context entries are never collected, so normal lazy caching is
+    /// unaffected. Returns the number of entries removed.
+    pub fn gc_cache(&mut self, max_age_frames: u64, current_frame: u64) -> usize {
+        self.evict_cache_where(|key, entry| {
+            !key.call_context.is_root()
+                && current_frame.saturating_sub(entry.last_used) > max_age_frames
+        })
+    }
+
+    /// Run [`gc_cache`](Self::gc_cache) automatically, at most once per frame,
+    /// after each evaluation (`None` disables it, the default).
+    pub fn set_cache_max_age(&mut self, max_age_frames: Option<u64>) {
+        self.cache_max_age = max_age_frames;
+    }
+
+    /// The automatic cache collection age, if enabled.
+    pub fn cache_max_age(&self) -> Option<u64> {
+        self.cache_max_age
+    }
+
+    /// Remove matching cache entries and forget contexts left without any.
+    fn evict_cache_where(
+        &mut self,
+        mut evict: impl FnMut(&CacheKey, &CacheEntry) -> bool,
+    ) -> usize {
+        let before = self.value_cache.len();
+        self.value_cache.retain(|key, entry| !evict(key, entry));
+        let removed = before - self.value_cache.len();
+        if removed > 0 {
+            let live: HashSet<CallContext> =
+                self.value_cache.keys().map(|key| key.call_context).collect();
+            self.call_ancestors.retain(|context, _| live.contains(context));
+            self.generation += 1;
+        }
+        removed
+    }
+
+    /// Apply the automatic cache collection policy at the end of `frame`.
+    fn auto_gc_cache(&mut self, frame: u64) {
+        if let Some(max_age) = self.cache_max_age {
+            if self.last_gc_frame != Some(frame) {
+                self.last_gc_frame = Some(frame);
+                self.gc_cache(max_age, frame);
+            }
+        }
+    }
+
+    /// Mark a node's cached outputs as used in the current frame.
+    fn touch_cache_entry(&mut self, node_id: Id, ctx: &EvalContext) {
+        let key = CacheKey {
+            node_id,
+            call_context: ctx.call_context,
+        };
+        if let Some(entry) = self.value_cache.get_mut(&key) {
+            entry.last_used = ctx.frame;
+        }
+    }
+
+    /// Mark the evaluation order as stale after a structural change.
+    fn mark_order_dirty(&mut self) {
+        self.order_dirty = true;
+        self.generation += 1;
+    }
+
+    /// Get the last cached value of a node's output in the root call context.
+    ///
+    /// Returns `None` if the node has not been evaluated yet.
+    pub fn cached_output(&self, node_id: Id, output_index: usize) -> Option<&Value> {
+        self.cached_output_in(node_id, output_index, CallContext::root())
+    }
+
+    /// Get the last cached value of a node's output in a call context.
+    pub(crate) fn cached_output_in(
+        &self,
+        node_id: Id,
+        output_index: usize,
+        call_context: CallContext,
+    ) -> Option<&Value> {
+        let key = CacheKey {
+            node_id,
+            call_context,
+        };
+        self.value_cache
+            .get(&key)?
+            .outputs
+            .get(output_index)
+            .map(|arc| arc.as_ref())
     }
 
     // =========================================================================
@@ -178,13 +1034,18 @@ impl Graph {
     ///     }
     /// }
     /// ```
+    ///
+    /// If a bounded queue dropped events since the last drain, the first
+    /// event is [`GraphEvent::EventsDropped`].
     pub fn drain_events(&mut self) -> impl Iterator<Item = GraphEvent> + '_ {
-        self.pending_events.drain(..)
+        let dropped = std::mem::take(&mut self.undrained_drops);
+        let notice = (dropped > 0).then_some(GraphEvent::EventsDropped { count: dropped });
+        notice.into_iter().chain(self.pending_events.drain(..))
     }
 
     /// Check if there are any pending events.
     pub fn has_pending_events(&self) -> bool {
-        !self.pending_events.is_empty()
+        !self.pending_events.is_empty() || self.undrained_drops > 0
     }
 
     /// Get the number of pending events.
@@ -195,11 +1056,94 @@ impl Graph {
     /// Clear all pending events without processing them.
     pub fn clear_events(&mut self) {
         self.pending_events.clear();
+        self.undrained_drops = 0;
+    }
+
+    /// Cap the number of queued events (`None`, the default, is unbounded).
+    ///
+    /// Events past the cap are handled by the [`EventOverflow`] policy set
+    /// with [`set_event_overflow`](Self::set_event_overflow). Lowering the
+    /// cap applies it to the events already queued.
+    pub fn set_event_capacity(&mut self, capacity: Option<usize>) {
+        self.event_capacity = capacity;
+        self.enforce_event_capacity();
+    }
+
+    /// Maximum number of queued events, if bounded.
+    pub fn event_capacity(&self) -> Option<usize> {
+        self.event_capacity
+    }
+
+    pub fn set_event_overflow(&mut self, policy: EventOverflow) {
+        self.event_overflow = policy;
+    }
+
+    pub fn event_overflow(&self) -> EventOverflow {
+        self.event_overflow
+    }
+
+    /// Number of events dropped by the capacity since the graph was created.
+    ///
+    /// Events merged by [`EventOverflow::Coalesce`] are not counted.
+    pub fn dropped_event_count(&self) -> u64 {
+        self.dropped_events
     }
 
     /// Push an event to the pending queue.
     fn emit(&mut self, event: GraphEvent) {
+        if self.batch_depth > 0 {
+            self.pending_events.push(event);
+            return;
+        }
+        let event = match self.event_overflow {
+            EventOverflow::Coalesce => match coalesce(&mut self.pending_events, event) {
+                Some(event) => event,
+                None => return,
+            },
+            EventOverflow::DropOldest | EventOverflow::DropNewest => event,
+        };
         self.pending_events.push(event);
+        self.enforce_event_capacity();
+    }
+
+    /// Drop queued events past the capacity according to the overflow policy.
+    fn enforce_event_capacity(&mut self) {
+        let Some(capacity) = self.event_capacity else {
+            return;
+        };
+        let excess = self.pending_events.len().saturating_sub(capacity);
+        if excess == 0 {
+            return;
+        }
+        match self.event_overflow {
+            EventOverflow::DropNewest => self.pending_events.truncate(capacity),
+            EventOverflow::DropOldest | EventOverflow::Coalesce => {
+                self.pending_events.drain(..excess);
+            }
+        }
+        self.dropped_events += excess as u64;
+        self.undrained_drops += excess as u64;
+    }
+
+    /// Run `f` as one atomic operation, following its events with
+    /// [`GraphEvent::BatchEnd`] if it emitted more than one.
+    ///
+    /// Nested batches merge into the outermost one.
+    pub(crate) fn batch<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        let start = self.pending_events.len();
+        self.batch_depth += 1;
+        let result = f(self);
+        self.batch_depth -= 1;
+
+        let events_in_batch = self.pending_events.len().saturating_sub(start);
+        if self.batch_depth == 0 {
+            if events_in_batch > 1 {
+                self.emit(GraphEvent::BatchEnd { events_in_batch });
+            } else {
+                self.enforce_event_capacity();
+            }
+        }
+        result
     }
 
     // =========================================================================
@@ -214,26 +1158,64 @@ impl Graph {
     /// Add a pre-boxed operator to the graph, returns its ID
     pub fn add_boxed(&mut self, op: Box<dyn Operator>) -> Id {
         let id = op.id();
-        self.nodes.insert(
-            id,
-            Node {
-                operator: op,
-                input_overrides: Vec::new(),
-            },
-        );
-        self.order_dirty = true;
+        let mut node = Node::new(op, self.next_sequence);
+        self.next_sequence += 1;
+        node.operator.on_added_to_graph();
+        self.nodes.insert(id, node);
+        self.index_node(id);
+        self.mark_order_dirty();
         self.emit(GraphEvent::NodeAdded { id });
         id
     }
 
-    /// Get a reference to an operator by ID
-    pub fn get(&self, id: Id) -> Option<&dyn Operator> {
-        self.nodes.get(&id).map(|n| n.operator.as_ref())
-    }
-
-    /// Get a mutable reference to an operator by ID
-    pub fn get_mut(&mut self, id: Id) -> Option<&mut (dyn Operator + '_)> {
-        self.nodes.get_mut(&id).map(|n| n.operator.as_mut())
+    /// Add an operator and record the typed units and input metadata declared
+    /// in its [`OperatorMeta`].
+    ///
+    /// Units are used by [`connect`](Self::connect) to scale between compatible
+    /// units automatically. Operators added with [`add`](Self::add) have no units
+    /// unless set via [`set_input_unit`](Self::set_input_unit) /
+    /// [`set_output_unit`](Self::set_output_unit).
+    ///
+    /// Inputs whose [`PortMeta`] sets `clamp_to_range` have their range and step
+    /// enforced during evaluation. Operators added with [`add`](Self::add) can
+    /// opt in per input through [`PortOverride::clamp_to_range`].
+    pub fn add_with_meta<O: Operator + OperatorMeta + 'static>(&mut self, op: O) -> Id {
+        let input_meta: Vec<Option<PortMeta>> =
+            (0..op.inputs().len()).map(|i| op.input_meta(i)).collect();
+        let output_meta: Vec<Option<PortMeta>> =
+            (0..op.outputs().len()).map(|i| op.output_meta(i)).collect();
+
+        let id = self.add(op);
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.set_port_meta(input_meta, &output_meta);
+        }
+        id
+    }
+
+    /// Add an operator created by the registry along with the metadata it
+    /// captured, as [`add_with_meta`](Self::add_with_meta) does for a concrete
+    /// operator type.
+    ///
+    /// ```ignore
+    /// let (op, meta) = registry.create_with_meta_by_name("Sin").unwrap();
+    /// let sin = graph.add_boxed_with_meta(op, &meta);
+    /// ```
+    pub fn add_boxed_with_meta(&mut self, op: Box<dyn Operator>, meta: &CapturedMeta) -> Id {
+        let id = self.add_boxed(op);
+        if let Some(node) = self.nodes.get_mut(&id) {
+            node.set_port_meta(meta.inputs.clone(), &meta.outputs);
+        }
+        id
+    }
+
+    /// Get a reference to an operator by ID
+    pub fn get(&self, id: Id) -> Option<&dyn Operator> {
+        self.nodes.get(&id).map(|n| n.operator.as_ref())
+    }
+
+    /// Get a mutable reference to an operator by ID
+    pub fn get_mut(&mut self, id: Id) -> Option<&mut (dyn Operator + '_)> {
+        self.nodes.get_mut(&id).map(|n| n.operator.as_mut())
     }
 
     /// Get a mutable reference to a specific operator type by ID
@@ -258,6 +1240,16 @@ impl Graph {
         self.nodes.keys().copied()
     }
 
+    /// All node IDs in the order the nodes were added.
+    ///
+    /// Unlike [`node_ids`](Self::node_ids), the order is the same every run,
+    /// so savers use it to produce identical files for identical graphs.
+    pub fn node_ids_in_insertion_order(&self) -> Vec<Id> {
+        let mut ids: Vec<Id> = self.node_ids().collect();
+        ids.sort_by_key(|id| self.nodes[id].sequence);
+        ids
+    }
+
     /// Remove a node from the graph.
     ///
     /// This will:
@@ -270,8 +1262,193 @@ impl Graph {
     /// connected FROM this node will have stale connection references that point
     /// to a non-existent node. These will safely return default values during evaluation.
     ///
-    /// Returns the removed operator if found.
+    /// The removed operator's [`on_removed_from_graph`](Operator::on_removed_from_graph)
+    /// hook is called, as is [`on_connection_changed`](Operator::on_connection_changed)
+    /// on every downstream input that lost its edge.
+    ///
+    /// Returns the removed operator if found. If the trash is enabled (see
+    /// [`set_trash_capacity`](Self::set_trash_capacity)) the operator is kept
+    /// there instead and `None` is returned.
     pub fn remove(&mut self, id: Id) -> Option<Box<dyn Operator>> {
+        if self.trash_capacity == 0 {
+            return self.remove_untrashed(id);
+        }
+        self.trash_node(id);
+        None
+    }
+
+    /// Remove a node as in [`remove`](Self::remove), bypassing the trash.
+    pub(crate) fn remove_untrashed(&mut self, id: Id) -> Option<Box<dyn Operator>> {
+        self.take_node(id).map(|node| node.operator)
+    }
+
+    /// Remove a node into the trash, evicting the oldest entries past capacity.
+    fn trash_node(&mut self, id: Id) {
+        let mut connections = self.upstream_of(id);
+        connections.extend(self.downstream_of(id));
+        let mut triggers = self.trigger_upstream_of(id);
+        triggers.extend(self.trigger_downstream_of(id));
+        let slots = self.multi_input_slots(&connections);
+        let groups = self.groups.groups_of(id);
+        let Some(mut node) = self.take_node(id) else {
+            return;
+        };
+        // Incoming edges are re-created on restore, once their sources are known to exist
+        for input in node.operator.inputs_mut() {
+            input.disconnect();
+        }
+
+        let (frame, time) = self.last_eval;
+        self.trash.push(TrashedNode {
+            node,
+            connections,
+            slots,
+            triggers,
+            groups,
+            frame,
+            time,
+        });
+        let excess = self.trash.len().saturating_sub(self.trash_capacity);
+        self.trash.drain(..excess);
+    }
+
+    // =========================================================================
+    // Trash
+    // =========================================================================
+
+    /// Keep up to `capacity` removed nodes for
+    /// [`restore_from_trash`](Self::restore_from_trash), dropping the oldest
+    /// ones past it. 0 (the default) disables the trash and empties it.
+    ///
+    /// See the [`trash`](crate::trash) module.
+    pub fn set_trash_capacity(&mut self, capacity: usize) {
+        self.trash_capacity = capacity;
+        let excess = self.trash.len().saturating_sub(capacity);
+        self.trash.drain(..excess);
+    }
+
+    /// Maximum number of nodes kept in the trash.
+    pub fn trash_capacity(&self) -> usize {
+        self.trash_capacity
+    }
+
+    /// Removed nodes that can be restored, oldest first.
+    pub fn trash(&self) -> &[TrashedNode] {
+        &self.trash
+    }
+
+    /// Drop every node in the trash, returning how many there were.
+    pub fn empty_trash(&mut self) -> usize {
+        let count = self.trash.len();
+        self.trash.clear();
+        count
+    }
+
+    /// Put a trashed node back under its original ID.
+    ///
+    /// Severed connections are re-created where the other node still exists
+    /// and the input they fed hasn't been connected elsewhere since; the
+    /// rest are listed in the report.
+    ///
+    /// # Errors
+    ///
+    /// [`GraphError::NodeNotFound`] if the node isn't in the trash, or
+    /// [`GraphError::DuplicateNode`] if a node with its ID was added since.
+    pub fn restore_from_trash(&mut self, id: Id) -> Result<RestoreReport, GraphError> {
+        let position = self
+            .trash
+            .iter()
+            .position(|entry| entry.id() == id)
+            .ok_or(GraphError::node_not_found(id, None))?;
+        if self.nodes.contains_key(&id) {
+            return Err(GraphError::DuplicateNode { id });
+        }
+        let entry = self.trash.remove(position);
+
+        Ok(self.batch(|graph| {
+            graph.insert_node(entry.node);
+            let mut report = RestoreReport {
+                id,
+                unrestored: Vec::new(),
+                unrestored_triggers: Vec::new(),
+            };
+            // Fill multi-input slots front to back so each lands where it was
+            let mut connections: Vec<_> = entry.connections.into_iter().zip(entry.slots).collect();
+            connections.sort_by_key(|&(_, slot)| slot);
+            for (c, slot) in connections {
+                let Connection { source_node, source_output, target_node, target_input, .. } = c;
+                let restored = !graph.input_taken(target_node, target_input)
+                    && graph
+                        .connect_direct(source_node, source_output, target_node, target_input)
+                        .is_ok();
+                if !restored {
+                    report.unrestored.push(c);
+                    continue;
+                }
+                if let Some(slot) = slot {
+                    graph.move_last_connection(c.target_node, c.target_input, slot);
+                }
+                if c.muted {
+                    let _ = graph.set_connection_muted(Connection { muted: false, ..c }, true);
+                }
+            }
+            for c in entry.triggers {
+                let restored = graph
+                    .connect_trigger(c.source_node, c.source_output, c.target_node, c.target_input)
+                    .is_ok();
+                if !restored {
+                    report.unrestored_triggers.push(c);
+                }
+            }
+            for group in entry.groups {
+                // Groups deleted since are skipped
+                let _ = graph.assign_to_group(id, group);
+            }
+            report
+        }))
+    }
+
+    /// Position of each connection within its target's multi-input list
+    /// (`None` for single-connection inputs).
+    fn multi_input_slots(&self, connections: &[Connection]) -> Vec<Option<usize>> {
+        connections
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let input = self.nodes.get(&c.target_node)?.operator.inputs().get(c.target_input)?;
+                if !input.is_multi_input {
+                    return None;
+                }
+                // The same source can feed an input more than once
+                let earlier = connections[..i].iter().filter(|&other| other == c).count();
+                let source = (c.source_node, c.source_output);
+                let mut slots =
+                    input.connections.iter().enumerate().filter(|&(_, &s)| s == source);
+                slots.nth(earlier).map(|(slot, _)| slot)
+            })
+            .collect()
+    }
+
+    /// Whether a single-connection input is already connected.
+    fn input_taken(&self, node: Id, input: usize) -> bool {
+        self.nodes
+            .get(&node)
+            .and_then(|n| n.operator.inputs().get(input))
+            .is_some_and(|input| !input.is_multi_input && input.connection.is_some())
+    }
+
+    /// Remove a node as in [`remove`](Self::remove), keeping its per-node state.
+    fn take_node(&mut self, id: Id) -> Option<Node> {
+        self.batch(|graph| graph.take_node_unbatched(id))
+    }
+
+    fn take_node_unbatched(&mut self, id: Id) -> Option<Node> {
+        if !self.nodes.contains_key(&id) {
+            return None;
+        }
+        let mut severed = self.upstream_of(id);
+        severed.extend(self.downstream_of(id));
+
         // First, find all nodes that have connections FROM the node being removed
         // and disconnect them (connections are stored on the target side)
         let nodes_to_update: Vec<(Id, usize)> = self
@@ -301,32 +1478,429 @@ impl Graph {
             .collect();
 
         // Disconnect those inputs
-        for (node_id, input_idx) in nodes_to_update {
+        for &(node_id, input_idx) in &nodes_to_update {
+            let previous = self.input_sources(node_id, input_idx);
             if let Some(node) = self.nodes.get_mut(&node_id) {
                 let input = &mut node.operator.inputs_mut()[input_idx];
                 // Clear single connection if it points to removed node
                 if input.connection.map(|(src, _)| src == id).unwrap_or(false) {
                     input.connection = None;
+                    input.muted.clear();
+                }
+                // Remove from multi-input connections, back to front so the
+                // muted flags of the remaining slots stay aligned
+                for slot in (0..input.connections.len()).rev() {
+                    if input.connections[slot].0 == id {
+                        input.disconnect_at(slot);
+                    }
                 }
-                // Remove from multi-input connections
-                input.connections.retain(|(src, _)| *src != id);
             }
+            self.reindex_input(node_id, input_idx, &previous);
             self.invalidate_cache_for_node(node_id);
         }
+        self.unindex_node(id);
+
+        // Trigger edges are recorded on both ends; drop them from the
+        // neighbors and from the removed node itself
+        let mut severed_triggers = self.trigger_upstream_of(id);
+        severed_triggers.extend(
+            self.trigger_downstream_of(id).into_iter().filter(|c| c.target_node != id),
+        );
+        for node in self.nodes.values_mut() {
+            for output in node.operator.trigger_outputs_mut() {
+                output.disconnect_node(id);
+            }
+            for input in node.operator.trigger_inputs_mut() {
+                if input.connection.is_some_and(|(source, _)| source == id) {
+                    input.disconnect();
+                }
+            }
+        }
+        if let Some(node) = self.nodes.get_mut(&id) {
+            for output in node.operator.trigger_outputs_mut() {
+                output.connections.clear();
+            }
+            for input in node.operator.trigger_inputs_mut() {
+                input.disconnect();
+            }
+        }
 
         // Remove from cache
         self.invalidate_cache_for_node(id);
+        self.node_errors.remove(&id);
+        self.quarantine.remove(&id);
+        self.watches.node_removed(id);
+        for (group, deleted) in self.groups.node_removed(id) {
+            self.emit(GraphEvent::GroupMembershipChanged { group, node: id, member: false });
+            if deleted {
+                self.emit(GraphEvent::GroupRemoved { group });
+            }
+        }
 
         // Remove the node itself
-        let node = self.nodes.remove(&id)?;
+        let mut node = self.nodes.remove(&id)?;
+        node.operator.on_removed_from_graph();
+        for (node_id, input_idx) in nodes_to_update {
+            self.notify_connection_changed(node_id, input_idx);
+        }
 
         // Mark order as dirty
-        self.order_dirty = true;
+        self.mark_order_dirty();
+
+        // Emit events
+        for c in &severed {
+            self.emit(GraphEvent::Disconnected {
+                source: c.source_node,
+                source_output: c.source_output,
+                target: c.target_node,
+                target_input: c.target_input,
+            });
+        }
+        for c in &severed_triggers {
+            self.emit(GraphEvent::TriggerDisconnected {
+                source: c.source_node,
+                source_output: c.source_output,
+                target: c.target_node,
+                target_input: c.target_input,
+            });
+        }
+        self.emit(GraphEvent::NodeRemoved {
+            id,
+            operator: node.operator.name(),
+            severed,
+        });
 
-        // Emit event
-        self.emit(GraphEvent::NodeRemoved { id });
+        Some(node)
+    }
+
+    /// Replace the operator behind a node, keeping its ID and compatible
+    /// connections.
+    ///
+    /// `new_op` must report the node's ID, or adopt it through
+    /// [`Operator::set_id`]. Incoming and outgoing connections survive when the
+    /// port at the same index keeps its type; input defaults are carried over
+    /// by port name. Connections that can't be kept are removed and listed in
+    /// the returned [`SwapReport`] rather than failing the swap.
+    ///
+    /// Per-node units and input metadata recorded by
+    /// [`add_with_meta`](Self::add_with_meta) belong to the old operator and
+    /// are cleared (see [`hot_swap_with_meta`](Self::hot_swap_with_meta));
+    /// input overrides are kept. The value cache of the node and
+    /// everything downstream of it is reset, and
+    /// [`GraphEvent::OperatorSwapped`] is emitted.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the node doesn't exist or `new_op` reports a
+    /// different ID that it can't change.
+    pub fn hot_swap(
+        &mut self,
+        node_id: Id,
+        mut new_op: Box<dyn Operator>,
+    ) -> Result<SwapReport, GraphError> {
+        if !self.nodes.contains_key(&node_id) {
+            return Err(GraphError::node_not_found(node_id, None));
+        }
+        if new_op.id() != node_id && !(new_op.set_id(node_id) && new_op.id() == node_id) {
+            return Err(GraphError::IdMismatch {
+                node_id,
+                operator_id: new_op.id(),
+            });
+        }
+
+        let upstream = self.upstream_of(node_id);
+        let downstream = self.downstream_of(node_id);
+        let mut report = SwapReport::default();
+        self.unindex_node(node_id);
+
+        let node = self.nodes.get_mut(&node_id).expect("checked above");
+        let old_inputs = node.operator.inputs();
+        let old_outputs = node.operator.outputs();
+
+        for (index, input) in new_op.inputs_mut().iter_mut().enumerate() {
+            let old = old_inputs.iter().find(|old| old.name == input.name);
+            if let Some(old) = old.filter(|old| old.value_type == input.value_type) {
+                input.default = old.default.clone();
+                report.carried_defaults.push(index);
+            }
+        }
+
+        for (index, old) in old_inputs.iter().enumerate() {
+            if !old.is_connected() {
+                continue;
+            }
+            match new_op.inputs_mut().get_mut(index) {
+                Some(input)
+                    if input.value_type == old.value_type
+                        && input.is_multi_input == old.is_multi_input =>
+                {
+                    input.connection = old.connection;
+                    input.connections = old.connections.clone();
+                    input.muted = old.muted.clone();
+                    report.kept_inputs.push(index);
+                }
+                _ => report
+                    .dropped_inputs
+                    .extend(upstream.iter().filter(|c| c.target_input == index)),
+            }
+        }
+
+        let new_outputs = new_op.outputs();
+        report.dropped_outputs = downstream
+            .into_iter()
+            .filter(|c| {
+                let old_type = old_outputs.get(c.source_output).map(|o| o.value_type);
+                let new_type = new_outputs.get(c.source_output).map(|o| o.value_type);
+                new_type.is_none() || new_type != old_type
+            })
+            .collect();
+
+        let input_count = new_op.inputs().len();
+        let mut old_op = std::mem::replace(&mut node.operator, new_op);
+        old_op.on_removed_from_graph();
+        node.operator.on_added_to_graph();
+        node.input_overrides.truncate(input_count);
+        node.input_transitions.clear();
+        node.context_bindings.retain(|&index, _| index < input_count);
+        node.input_units.clear();
+        node.output_units.clear();
+        node.input_meta.clear();
+        node.refresh_clamps();
+        self.index_node(node_id);
+
+        for &index in &report.kept_inputs {
+            self.notify_connection_changed(node_id, index);
+        }
+        for connection in &report.dropped_outputs {
+            self.detach_edge(connection);
+        }
+
+        self.node_errors.remove(&node_id);
+        self.quarantine.remove(&node_id);
+        self.invalidate_downstream(node_id);
+        self.mark_order_dirty();
+        self.emit(GraphEvent::OperatorSwapped { id: node_id });
+
+        Ok(report)
+    }
+
+    /// [`hot_swap`](Self::hot_swap) to an operator created by the registry,
+    /// recording the units and input metadata it captured as
+    /// [`add_boxed_with_meta`](Self::add_boxed_with_meta) does.
+    ///
+    /// Units only affect connections made afterwards; kept connections stay
+    /// as they were.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the node doesn't exist or `new_op` reports a
+    /// different ID that it can't change.
+    pub fn hot_swap_with_meta(
+        &mut self,
+        node_id: Id,
+        new_op: Box<dyn Operator>,
+        meta: &CapturedMeta,
+    ) -> Result<SwapReport, GraphError> {
+        let report = self.hot_swap(node_id, new_op)?;
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            node.set_port_meta(meta.inputs.clone(), &meta.outputs);
+        }
+        Ok(report)
+    }
+
+    /// Move a node's connections after its operator changed its own ports.
+    ///
+    /// Connections refer to ports by index; `change` says where each previous
+    /// port went (see [`PortChange`]). Connections into and out of the node
+    /// follow their port to its new index. Those of removed ports are
+    /// removed, as are outgoing connections whose new output can't feed their
+    /// input directly. Per-port state (input overrides, context bindings,
+    /// units, frozen outputs) moves with its port; watches of removed outputs
+    /// are dropped.
+    ///
+    /// Emits `ConnectionRetargeted` for each moved connection, `Disconnected`
+    /// for each removed one, then [`GraphEvent::PortsChanged`]. The value
+    /// cache of the node and everything downstream of it is reset.
+    ///
+    /// Operators that change their ports while computing can report it
+    /// through [`Operator::take_port_change`] instead; the graph applies it
+    /// right after that compute.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the node doesn't exist.
+    pub fn notify_ports_changed(
+        &mut self,
+        node_id: Id,
+        change: PortChange,
+    ) -> Result<PortChangeReport, GraphError> {
+        if !self.nodes.contains_key(&node_id) {
+            return Err(GraphError::node_not_found(node_id, None));
+        }
+        Ok(self.batch(|graph| graph.apply_port_change(node_id, &change)))
+    }
+
+    /// Body of [`notify_ports_changed`](Self::notify_ports_changed).
+    ///
+    /// The consumer index still describes the connections before the change,
+    /// so it tells where each edge used to be.
+    fn apply_port_change(&mut self, node_id: Id, change: &PortChange) -> PortChangeReport {
+        let mut report = PortChangeReport::default();
+        let Some(node) = self.nodes.get_mut(&node_id) else {
+            return report;
+        };
+        node.remap_ports(change);
+        let (input_names, output_names) = node.port_names();
+        let output_types: Vec<ValueType> =
+            node.operator.outputs().iter().map(|o| o.value_type).collect();
+
+        // Incoming edges: the operator moved them along with its input ports
+        let mut incoming: Vec<((Id, usize), usize)> = Vec::new();
+        for (&source, targets) in &self.consumers {
+            for &(target, input) in targets {
+                if target == node_id && !incoming.contains(&(source, input)) {
+                    incoming.push((source, input));
+                }
+            }
+        }
+        for targets in self.consumers.values_mut() {
+            targets.retain(|&(target, _)| target != node_id);
+        }
+        self.consumers.retain(|_, targets| !targets.is_empty());
+        self.index_node(node_id);
+        for ((source_node, source_output), old_input) in incoming {
+            let old = Connection {
+                source_node,
+                source_output,
+                target_node: node_id,
+                target_input: old_input,
+                muted: false,
+            };
+            let new_input = change.inputs.new_index(old_input, &input_names).filter(|&input| {
+                self.input_sources(node_id, input)
+                    .contains(&(source_node, source_output))
+            });
+            match new_input {
+                Some(input) if input == old_input => {}
+                Some(input) => report.remapped.push((
+                    old,
+                    Connection {
+                        target_input: input,
+                        ..old
+                    },
+                )),
+                None => report.dropped.push(old),
+            }
+        }
+
+        // Outgoing edges: rewrite the references held by consumer inputs
+        let mut consumers: Vec<(Id, usize)> = Vec::new();
+        for (&(source, _), targets) in &self.consumers {
+            if source == node_id {
+                for &target in targets {
+                    if !consumers.contains(&target) {
+                        consumers.push(target);
+                    }
+                }
+            }
+        }
+        let output_map = |output| {
+            change
+                .outputs
+                .new_index(output, &output_names)
+                .map(|new_output| (new_output, output_types[new_output]))
+        };
+        for (target, target_input) in consumers {
+            let previous = self.input_sources(target, target_input);
+            let Some(input) = self
+                .nodes
+                .get_mut(&target)
+                .and_then(|n| n.operator.inputs_mut().get_mut(target_input))
+            else {
+                continue;
+            };
+            let mut dropped = false;
+            let slots: Vec<(usize, (Id, usize))> = port_sources(input).enumerate().collect();
+            // Back to front, so removing a multi-input slot keeps the others' positions
+            for (slot, (source, old_output)) in slots.into_iter().rev() {
+                if source != node_id {
+                    continue;
+                }
+                let old = Connection {
+                    source_node: node_id,
+                    source_output: old_output,
+                    target_node: target,
+                    target_input,
+                    muted: input.is_muted(slot),
+                };
+                let new_output = output_map(old_output)
+                    .filter(|&(_, value_type)| input.connects_directly(value_type))
+                    .map(|(new_output, _)| new_output);
+                let single = input.connection.is_some();
+                match new_output {
+                    Some(new_output) if new_output == old_output => continue,
+                    Some(new_output) => {
+                        if single {
+                            input.connection = Some((node_id, new_output));
+                        } else {
+                            input.connections[slot] = (node_id, new_output);
+                        }
+                        let new = Connection {
+                            source_output: new_output,
+                            ..old
+                        };
+                        report.remapped.push((old, new));
+                    }
+                    None => {
+                        if single {
+                            input.connection = None;
+                            input.muted.clear();
+                        } else {
+                            input.disconnect_at(slot);
+                        }
+                        report.dropped.push(old);
+                        dropped = true;
+                    }
+                }
+            }
+            self.reindex_input(target, target_input, &previous);
+            if dropped {
+                self.notify_connection_changed(target, target_input);
+            }
+        }
+
+        let watch_map = |output| change.outputs.new_index(output, &output_names);
+        self.watches.remap_outputs(node_id, watch_map);
+        self.revalidate_inputs(node_id);
+        self.invalidate_downstream(node_id);
+        self.mark_order_dirty();
+
+        for &(old, new) in &report.remapped {
+            self.emit(GraphEvent::ConnectionRetargeted { old, new });
+        }
+        for connection in &report.dropped {
+            self.emit(GraphEvent::Disconnected {
+                source: connection.source_node,
+                source_output: connection.source_output,
+                target: connection.target_node,
+                target_input: connection.target_input,
+            });
+        }
+        self.emit(GraphEvent::PortsChanged { node: node_id });
+        report
+    }
 
-        Some(node.operator)
+    /// Invalidate the cache of a node and every node downstream of it.
+    fn invalidate_downstream(&mut self, node_id: Id) {
+        let mut pending = vec![node_id];
+        let mut visited = HashSet::new();
+        while let Some(id) = pending.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            self.invalidate_cache_for_node(id);
+            pending.extend(self.downstream_of(id).iter().map(|c| c.target_node));
+        }
     }
 
     /// Iterate over all connections in the graph.
@@ -339,1116 +1913,6244 @@ impl Graph {
                 .iter()
                 .enumerate()
                 .flat_map(move |(input_idx, input)| {
-                    // Collect single connection
-                    let single = input.connection.map(|(source_id, source_output)| Connection {
-                        source_node: source_id,
-                        source_output,
-                        target_node: target_id,
-                        target_input: input_idx,
-                    });
-
-                    // Collect multi-input connections
-                    let multi = input
-                        .connections
-                        .iter()
-                        .map(move |&(source_id, source_output)| Connection {
+                    // Single connection, then multi-input connections
+                    port_sources(input).enumerate().map(
+                        move |(slot, (source_id, source_output))| Connection {
                             source_node: source_id,
                             source_output,
                             target_node: target_id,
                             target_input: input_idx,
-                        });
-
-                    single.into_iter().chain(multi)
+                            muted: input.is_muted(slot),
+                        },
+                    )
                 })
         })
     }
 
     /// Get all nodes that this node's outputs connect to (downstream).
     pub fn downstream_of(&self, id: Id) -> Vec<Connection> {
-        self.connections()
-            .filter(|c| c.source_node == id)
-            .collect()
+        let output_count = self.nodes.get(&id).map_or(0, |n| n.operator.outputs().len());
+        let mut downstream = Vec::new();
+        for output in 0..output_count {
+            let mut targets = self.consumers_of(id, output).to_vec();
+            // An input fed twice by this output is listed once per edge
+            let mut seen = HashSet::new();
+            targets.retain(|&target| seen.insert(target));
+            for (target_node, target_input) in targets {
+                let Some(input) =
+                    self.nodes.get(&target_node).and_then(|n| n.operator.inputs().get(target_input))
+                else {
+                    continue;
+                };
+                downstream.extend(
+                    port_sources(input)
+                        .enumerate()
+                        .filter(|&(_, source)| source == (id, output))
+                        .map(|(slot, _)| Connection {
+                            source_node: id,
+                            source_output: output,
+                            target_node,
+                            target_input,
+                            muted: input.is_muted(slot),
+                        }),
+                );
+            }
+        }
+        downstream
     }
 
     /// Get all nodes that connect to this node's inputs (upstream).
     pub fn upstream_of(&self, id: Id) -> Vec<Connection> {
-        self.connections()
-            .filter(|c| c.target_node == id)
+        let Some(node) = self.nodes.get(&id) else {
+            return Vec::new();
+        };
+        node.operator
+            .inputs()
+            .iter()
+            .enumerate()
+            .flat_map(|(input_idx, input)| {
+                port_sources(input).enumerate().map(
+                    move |(slot, (source_node, source_output))| Connection {
+                        source_node,
+                        source_output,
+                        target_node: id,
+                        target_input: input_idx,
+                        muted: input.is_muted(slot),
+                    },
+                )
+            })
             .collect()
     }
 
-    /// Set the default value for an input port on a node
-    /// This is used by composite operators to pass values to internal nodes
-    pub fn set_input_default(&mut self, node_id: Id, input_index: usize, value: Value) -> bool {
-        if let Some(node) = self.nodes.get_mut(&node_id) {
-            if let Some(input_port) = node.operator.inputs_mut().get_mut(input_index) {
-                input_port.default = value.clone();
-                // Mark outputs as dirty since input changed
-                for output in node.operator.outputs_mut() {
-                    output.mark_dirty();
-                }
-                // Invalidate cache for this node and dependents
-                self.invalidate_cache_for_node(node_id);
-
-                // Emit event
-                self.emit(GraphEvent::InputDefaultChanged {
-                    node: node_id,
-                    input: input_index,
-                    value,
-                });
-
-                return true;
-            }
-        }
-        false
+    /// Inputs fed by a node output, as `(target_node, target_input)` pairs.
+    ///
+    /// Served from an index maintained on every connection change, so it is
+    /// cheap enough to call while drawing wires.
+    pub fn consumers_of(&self, node_id: Id, output_index: usize) -> &[(Id, usize)] {
+        self.consumers
+            .get(&(node_id, output_index))
+            .map_or(&[], Vec::as_slice)
     }
 
-    // =========================================================================
-    // Port Override API
-    // =========================================================================
+    /// Number of inputs fed by a node output.
+    pub fn output_fanout(&self, node_id: Id, output_index: usize) -> usize {
+        self.consumers_of(node_id, output_index).len()
+    }
 
-    /// Get the override for an input port, if any.
-    pub fn get_input_override(&self, node_id: Id, input_index: usize) -> Option<&PortOverride> {
-        self.nodes
-            .get(&node_id)?
-            .input_overrides
-            .get(input_index)?
-            .as_ref()
+    /// Check the consumer index against a full scan of the input ports.
+    ///
+    /// A debugging aid: the index only drifts if connections are edited on an
+    /// operator obtained through [`get_mut`](Self::get_mut) instead of the
+    /// graph's connection methods.
+    pub fn verify_consumer_index(&self) -> bool {
+        let mut scanned: HashMap<(Id, usize), Vec<(Id, usize)>> = HashMap::new();
+        for c in self.connections() {
+            scanned
+                .entry((c.source_node, c.source_output))
+                .or_default()
+                .push((c.target_node, c.target_input));
+        }
+        let mut indexed = self.consumers.clone();
+        for targets in scanned.values_mut().chain(indexed.values_mut()) {
+            targets.sort_by_key(|&(id, input)| (*id.as_uuid(), input));
+        }
+        scanned == indexed
     }
 
-    /// Set an override for an input port.
+    /// Check that every value and trigger connection refers to ports that
+    /// exist, and that both ends of each trigger connection record it.
     ///
-    /// Extends the override vector if necessary. If the override is empty
-    /// (all fields None), it's equivalent to clearing the override.
-    pub fn set_input_override(&mut self, node_id: Id, input_index: usize, override_: PortOverride) {
-        if let Some(node) = self.nodes.get_mut(&node_id) {
-            // Extend vector if needed
-            if node.input_overrides.len() <= input_index {
-                node.input_overrides.resize(input_index + 1, None);
-            }
-            // Store override (or None if empty)
-            node.input_overrides[input_index] = if override_.is_empty() {
-                None
-            } else {
-                Some(override_)
-            };
-        }
-    }
+    /// Graph methods keep these invariants; they only break when ports are
+    /// edited on an operator obtained through [`get_mut`](Self::get_mut).
+    /// Input values each operator rejects in
+    /// [`validate_inputs`](Operator::validate_inputs) are reported as
+    /// [`GraphIssue::InvalidInput`]. Returns an empty list for a consistent
+    /// graph.
+    pub fn validate(&self) -> Vec<GraphIssue> {
+        let mut issues: Vec<GraphIssue> = self
+            .connections()
+            .filter(|c| {
+                self.nodes
+                    .get(&c.source_node)
+                    .is_none_or(|n| c.source_output >= n.operator.outputs().len())
+            })
+            .map(GraphIssue::DanglingConnection)
+            .collect();
 
-    /// Clear an override for an input port.
-    pub fn clear_input_override(&mut self, node_id: Id, input_index: usize) {
-        if let Some(node) = self.nodes.get_mut(&node_id) {
-            if let Some(slot) = node.input_overrides.get_mut(input_index) {
-                *slot = None;
+        let has_trigger_output = |node: Id, output: usize| {
+            self.nodes
+                .get(&node)
+                .is_some_and(|n| output < n.operator.trigger_outputs().len())
+        };
+        let has_trigger_input = |node: Id, input: usize| {
+            self.nodes
+                .get(&node)
+                .is_some_and(|n| input < n.operator.trigger_inputs().len())
+        };
+        for (&id, node) in &self.nodes {
+            for (output_idx, output) in node.operator.trigger_outputs().iter().enumerate() {
+                for &(target, input_idx) in &output.connections {
+                    let edge = Connection {
+                        source_node: id,
+                        source_output: output_idx,
+                        target_node: target,
+                        target_input: input_idx,
+                        muted: false,
+                    };
+                    if !has_trigger_input(target, input_idx) {
+                        issues.push(GraphIssue::DanglingTrigger(edge));
+                    } else if self.nodes[&target].operator.trigger_inputs()[input_idx].connection
+                        != Some((id, output_idx))
+                    {
+                        issues.push(GraphIssue::UnpairedTrigger(edge));
+                    }
+                }
+            }
+            for (input_idx, input) in node.operator.trigger_inputs().iter().enumerate() {
+                let Some((source, output_idx)) = input.connection else {
+                    continue;
+                };
+                let edge = Connection {
+                    source_node: source,
+                    source_output: output_idx,
+                    target_node: id,
+                    target_input: input_idx,
+                    muted: false,
+                };
+                if !has_trigger_output(source, output_idx) {
+                    issues.push(GraphIssue::DanglingTrigger(edge));
+                } else if !self.nodes[&source].operator.trigger_outputs()[output_idx]
+                    .connections
+                    .contains(&(id, input_idx))
+                {
+                    issues.push(GraphIssue::UnpairedTrigger(edge));
+                }
+            }
+            for (&output_idx, value) in &node.frozen_outputs {
+                let Some(value) = value else { continue };
+                let output = node.operator.outputs().get(output_idx);
+                if output.is_none_or(|output| output.value.value_type() != value.value_type()) {
+                    issues.push(GraphIssue::StaleFreeze(OutputRef::new(id, output_idx)));
+                }
+            }
+            for issue in current_input_issues(node.operator.as_ref()) {
+                issues.push(GraphIssue::InvalidInput {
+                    input: InputRef::new(id, issue.input),
+                    severity: issue.severity,
+                });
             }
         }
+        issues
     }
 
-    /// Get effective metadata for an input (combines PortMeta defaults + per-instance override).
-    ///
-    /// Returns resolved metadata ready for UI rendering.
-    ///
-    /// **Note**: Currently, PortMeta from operator is not accessible through `dyn Operator`.
-    /// For full OperatorMeta support, use FluxNodalBridge which can access concrete types
-    /// during node creation. This method applies overrides to sensible defaults.
+    /// Check the graph's internal bookkeeping, describing each violation.
     ///
-    /// # Arguments
+    /// Covers everything [`validate`](Self::validate) and
+    /// [`verify_consumer_index`](Self::verify_consumer_index) check, plus
+    /// state they can't see: the cached evaluation order (every node exactly
+    /// once, after its sources) and cache, error and trash entries for
+    /// nodes that no longer exist. Returns an empty list for a consistent
+    /// graph.
     ///
-    /// * `node_id` - The node to get metadata for
-    /// * `input_index` - The input port index
-    /// * `port_meta` - Optional PortMeta from the operator (caller must provide if known)
-    pub fn get_effective_input_meta_with_default(
-        &self,
-        node_id: Id,
-        input_index: usize,
-        port_meta: Option<flux_core::PortMeta>,
-    ) -> Option<EffectivePortMeta> {
-        let node = self.nodes.get(&node_id)?;
+    /// Meant for tests and fuzzing; outside this crate it requires the
+    /// `invariant-checks` feature.
+    #[cfg(any(test, feature = "invariant-checks"))]
+    pub fn check_invariants(&self) -> Vec<String> {
+        let mut violations: Vec<String> = self
+            .validate()
+            .iter()
+            .filter(|issue| !matches!(issue, GraphIssue::InvalidInput { .. }))
+            .map(|issue| format!("{:?}", issue))
+            .collect();
+        if !self.verify_consumer_index() {
+            violations.push("consumer index differs from the input ports".to_string());
+        }
 
-        // Get per-instance override if any
-        let override_ = node
-            .input_overrides
-            .get(input_index)
-            .and_then(|o| o.as_ref());
+        if !self.order_dirty {
+            let positions: HashMap<Id, usize> =
+                self.eval_order.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+            if positions.len() != self.eval_order.len() {
+                violations.push("evaluation order lists a node twice".to_string());
+            }
+            for id in self.nodes.keys().filter(|id| !positions.contains_key(id)) {
+                violations.push(format!("node {} missing from evaluation order", id));
+            }
+            for id in positions.keys().filter(|id| !self.nodes.contains_key(id)) {
+                violations.push(format!("evaluation order lists removed node {}", id));
+            }
+            for c in self.connections() {
+                if let (Some(source), Some(target)) =
+                    (positions.get(&c.source_node), positions.get(&c.target_node))
+                {
+                    if source > target {
+                        violations.push(format!("{:?} runs its target first", c));
+                    }
+                }
+            }
+        }
 
-        Some(EffectivePortMeta::from_meta(port_meta, override_))
+        let mut stale: Vec<(&str, Id)> = Vec::new();
+        stale.extend(self.value_cache.keys().map(|key| ("cache entry", key.node_id)));
+        stale.extend(self.node_errors.keys().map(|&id| ("error entry", id)));
+        stale.extend(self.quarantine.keys().map(|&id| ("quarantine entry", id)));
+        for (what, id) in stale {
+            if !self.nodes.contains_key(&id) {
+                violations.push(format!("{} for removed node {}", what, id));
+            }
+        }
+        for entry in &self.trash {
+            if self.nodes.contains_key(&entry.id()) {
+                violations.push(format!("trashed node {} is also in the graph", entry.id()));
+            }
+        }
+        for (group, entry) in self.groups.iter() {
+            for member in entry.members() {
+                if !self.nodes.contains_key(member) {
+                    violations.push(format!("{} lists removed node {}", group, member));
+                }
+            }
+        }
+        violations
     }
 
-    /// Get per-instance override for an input, if any exists.
-    ///
-    /// This is useful when you need to check if a specific override is set
-    /// before applying defaults.
-    pub fn get_input_override_raw(&self, node_id: Id, input_index: usize) -> Option<&PortOverride> {
-        self.get_input_override(node_id, input_index)
+    /// Sources currently connected to an input port.
+    fn input_sources(&self, node_id: Id, input_index: usize) -> Vec<(Id, usize)> {
+        self.nodes
+            .get(&node_id)
+            .and_then(|n| n.operator.inputs().get(input_index))
+            .map(|input| port_sources(input).collect())
+            .unwrap_or_default()
     }
 
-    /// Connect a source output to a target input with type checking and auto-conversion.
-    ///
-    /// If the source and target types differ but can be coerced, a [`ConversionOp`]
-    /// is automatically inserted between them. This makes type conversion explicit
-    /// and visible in the graph.
-    ///
-    /// # Returns
-    ///
-    /// - `Ok(None)` - Direct connection (types match exactly)
-    /// - `Ok(Some(id))` - Connection via auto-inserted conversion node
-    /// - `Err(...)` - Connection failed (incompatible types, cycle, etc.)
-    ///
-    /// # Example
-    ///
-    /// ```ignore
-    /// // Float to Vec3 connection - auto-inserts ConversionOp
-    /// let conversion_id = graph.connect(float_node, 0, vec3_node, 0)?;
-    /// if let Some(conv_id) = conversion_id {
-    ///     println!("Conversion node inserted: {:?}", conv_id);
-    /// }
-    /// ```
-    pub fn connect(
-        &mut self,
-        source_node: Id,
-        source_output: usize,
-        target_node: Id,
-        target_input: usize,
-    ) -> Result<Option<Id>, GraphError> {
-        // Get source output type
-        let source = self
-            .nodes
-            .get(&source_node)
-            .ok_or(GraphError::NodeNotFound { id: source_node, name: None })?;
+    /// Update the consumer index after an input's connections changed from
+    /// `previous` to whatever the port holds now.
+    fn reindex_input(&mut self, node_id: Id, input_index: usize, previous: &[(Id, usize)]) {
+        let target = (node_id, input_index);
+        for source in previous {
+            if let Some(targets) = self.consumers.get_mut(source) {
+                if let Some(position) = targets.iter().position(|&t| t == target) {
+                    targets.remove(position);
+                }
+                if targets.is_empty() {
+                    self.consumers.remove(source);
+                }
+            }
+        }
+        for source in self.input_sources(node_id, input_index) {
+            self.consumers.entry(source).or_default().push(target);
+        }
+    }
 
-        let source_name = source.operator.name();
-        let outputs = source.operator.outputs();
-        if source_output >= outputs.len() {
-            return Err(GraphError::output_not_found(
-                source_node,
-                source_output,
-                source_name,
-                outputs.len(),
-            ));
+    /// Add all of a node's incoming edges to the consumer index.
+    fn index_node(&mut self, node_id: Id) {
+        let input_count = self.nodes.get(&node_id).map_or(0, |n| n.operator.inputs().len());
+        for input_index in 0..input_count {
+            self.reindex_input(node_id, input_index, &[]);
         }
-        let source_type = outputs[source_output].value_type;
+    }
 
-        // Get target input type
-        let target = self
-            .nodes
-            .get(&target_node)
-            .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
+    /// Remove all of a node's incoming edges from the consumer index.
+    fn unindex_node(&mut self, node_id: Id) {
+        let input_count = self.nodes.get(&node_id).map_or(0, |n| n.operator.inputs().len());
+        for input_index in 0..input_count {
+            let previous = self.input_sources(node_id, input_index);
+            let target = (node_id, input_index);
+            for source in previous {
+                if let Some(targets) = self.consumers.get_mut(&source) {
+                    targets.retain(|&t| t != target);
+                    if targets.is_empty() {
+                        self.consumers.remove(&source);
+                    }
+                }
+            }
+        }
+    }
 
-        let target_name = target.operator.name();
-        let input_count = target.operator.inputs().len();
+    /// Set the default value for an input port on a node
+    /// This is used by composite operators to pass values to internal nodes
+    ///
+    /// For an input bound to a context variable this sets the fallback; the
+    /// binding is kept.
+    pub fn set_input_default(&mut self, node_id: Id, input_index: usize, value: Value) -> bool {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            let smoothing = node
+                .input_overrides
+                .get(input_index)
+                .and_then(|o| o.as_ref())
+                .and_then(|o| o.smoothing_seconds)
+                .filter(|&secs| secs > 0.0);
 
-        if target_input >= input_count {
-            return Err(GraphError::input_not_found(
-                target_node,
-                target_input,
-                target_name,
-                input_count,
-            ));
-        }
+            if let Some(input_port) = node.operator.inputs_mut().get_mut(input_index) {
+                let previous = std::mem::replace(&mut input_port.default, value.clone());
+
+                // Start (or retarget) a smoothed transition toward the new default
+                let smoothable = !input_port.is_connected()
+                    && previous.value_type() == value.value_type()
+                    && InputTransition::is_smoothable(&value);
+                match smoothing {
+                    Some(secs) if smoothable && previous != value => {
+                        let from = node
+                            .input_transitions
+                            .get(&input_index)
+                            .map(|t| t.current.clone())
+                            .unwrap_or_else(|| previous.clone());
+                        node.input_transitions.insert(
+                            input_index,
+                            InputTransition {
+                                current: from.clone(),
+                                from,
+                                start_time: None,
+                                duration: secs as f64,
+                            },
+                        );
+                    }
+                    _ => {
+                        node.input_transitions.remove(&input_index);
+                    }
+                }
+                // Mark outputs as dirty since input changed
+                for output in node.operator.outputs_mut() {
+                    output.mark_dirty();
+                }
+                // Invalidate cache for this node and dependents
+                self.invalidate_cache_for_node(node_id);
 
-        let target_type = target.operator.inputs()[target_input].value_type;
+                // Emit event
+                self.emit(GraphEvent::InputDefaultChanged {
+                    node: node_id,
+                    input: input_index,
+                    previous,
+                    value,
+                });
+                self.revalidate_inputs(node_id);
 
-        // Determine connection strategy based on types
-        if source_type == target_type {
-            // Direct connection - types match exactly
-            self.connect_direct(source_node, source_output, target_node, target_input)?;
-            Ok(None)
-        } else if source_type.can_coerce_to(target_type) {
-            // Auto-insert conversion operator
-            let conv_op = ConversionOp::new(source_type, target_type);
-            let conv_id = conv_op.id();
-            self.add(conv_op);
+                return true;
+            }
+        }
+        false
+    }
 
-            // Connect: source -> conversion -> target
-            self.connect_direct(source_node, source_output, conv_id, 0)?;
-            self.connect_direct(conv_id, 0, target_node, target_input)?;
+    /// The default an input had when its node was added to the graph.
+    ///
+    /// Returns `None` if the node or input doesn't exist, or the operator
+    /// created the input after it was added.
+    pub fn factory_default(&self, node_id: Id, input_index: usize) -> Option<&Value> {
+        self.nodes.get(&node_id)?.factory_defaults.get(input_index)
+    }
 
-            // Emit conversion insertion event
-            self.emit(GraphEvent::ConversionInserted {
-                conversion_node: conv_id,
-                source_type,
-                target_type,
-            });
+    /// Issues the node's operator reported for its input values.
+    ///
+    /// Updated whenever [`set_input_default`](Self::set_input_default) or a
+    /// connection change touches the node; the values are applied either
+    /// way. Empty if the node doesn't exist.
+    pub fn input_issues(&self, node_id: Id) -> &[InputValidationIssue] {
+        self.nodes
+            .get(&node_id)
+            .map_or(&[], |node| node.input_issues.as_slice())
+    }
 
-            Ok(Some(conv_id))
-        } else {
-            // Incompatible types - cannot connect
-            Err(GraphError::type_mismatch(
-                source_node,
-                source_type,
-                target_node,
-                target_type,
-            ))
+    /// Re-run a node's [`validate_inputs`](Operator::validate_inputs) and
+    /// store the result, emitting `InputIssuesChanged` if it changed.
+    fn revalidate_inputs(&mut self, node_id: Id) {
+        let Some(node) = self.nodes.get_mut(&node_id) else {
+            return;
+        };
+        let issues = current_input_issues(node.operator.as_ref());
+        if issues == node.input_issues {
+            return;
+        }
+        node.input_issues = issues.clone();
+        self.emit(GraphEvent::InputIssuesChanged {
+            node: node_id,
+            issues,
+        });
+    }
+
+    /// Give [`MissingOp`] placeholder ports the type of the port they are
+    /// about to be connected to, so the connection links them directly.
+    ///
+    /// A retyped input's factory default follows its new type, so it isn't
+    /// reported as modified. Does nothing for other operators.
+    pub(crate) fn adopt_placeholder_types(&mut self, source: (Id, usize), target: (Id, usize)) {
+        let input_type = self
+            .get(target.0)
+            .and_then(|op| op.inputs().get(target.1))
+            .map(|port| port.value_type);
+        if let (Some(input_type), Some(missing)) =
+            (input_type, self.get_mut_as::<MissingOp>(source.0))
+        {
+            missing.adopt_output_type(source.1, input_type);
+        }
+        let Some(output_type) = self
+            .get(source.0)
+            .and_then(|op| op.outputs().get(source.1))
+            .map(|port| port.value_type)
+        else {
+            return;
+        };
+        let Some(node) = self.nodes.get_mut(&target.0) else {
+            return;
+        };
+        let Some(missing) = node.operator.as_any_mut().downcast_mut::<MissingOp>() else {
+            return;
+        };
+        if missing.adopt_input_type(target.1, output_type) {
+            if let Some(factory) = node.factory_defaults.get_mut(target.1) {
+                *factory = missing.inputs()[target.1].default.clone();
+            }
         }
     }
 
-    /// Connect a source output to a target input directly, without auto-conversion.
+    /// Inputs whose default differs from their
+    /// [`factory_default`](Self::factory_default), in port order.
     ///
-    /// This method performs the raw connection without checking for type compatibility
-    /// beyond exact equality. It's used internally by `connect()` and can be used
-    /// when you want to bypass auto-conversion (e.g., when manually inserting
-    /// conversion nodes).
+    /// UIs use this to highlight edited parameters.
+    pub fn modified_inputs(&self, node_id: Id) -> Vec<usize> {
+        let Some(node) = self.nodes.get(&node_id) else {
+            return Vec::new();
+        };
+        node.operator
+            .inputs()
+            .iter()
+            .zip(&node.factory_defaults)
+            .enumerate()
+            .filter(|(_, (input, factory))| input.default != **factory)
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Set an input's default back to its
+    /// [`factory_default`](Self::factory_default).
     ///
-    /// # Errors
+    /// Goes through [`set_input_default`](Self::set_input_default), so a
+    /// context binding keeps its variable. Returns false if the input was not
+    /// modified.
+    pub fn reset_input_to_factory(&mut self, node_id: Id, input_index: usize) -> bool {
+        let Some(node) = self.nodes.get(&node_id) else {
+            return false;
+        };
+        let current = node.operator.inputs().get(input_index).map(|i| &i.default);
+        let Some(factory) = node.factory_defaults.get(input_index) else {
+            return false;
+        };
+        if current.is_none_or(|current| current == factory) {
+            return false;
+        }
+        let factory = factory.clone();
+        self.set_input_default(node_id, input_index, factory)
+    }
+
+    /// Reset every [modified](Self::modified_inputs) input of a node to its
+    /// factory default, returning the indices that were reset.
+    pub fn reset_all_inputs(&mut self, node_id: Id) -> Vec<usize> {
+        self.modified_inputs(node_id)
+            .into_iter()
+            .filter(|&index| self.reset_input_to_factory(node_id, index))
+            .collect()
+    }
+
+    /// Bind an input's default to a context variable.
     ///
-    /// Returns an error if:
-    /// - Source or target node doesn't exist
-    /// - Output or input index is out of bounds
-    /// - Types don't match exactly
-    /// - Connection would create a cycle
-    pub fn connect_direct(
+    /// While unconnected, the input reads the variable `name` from the
+    /// evaluation context (coerced to the port type) and uses `fallback` while
+    /// the variable is absent. The node is recomputed whenever the variable's
+    /// value changes.
+    pub fn set_input_context_binding(
         &mut self,
-        source_node: Id,
-        source_output: usize,
-        target_node: Id,
-        target_input: usize,
-    ) -> Result<(), GraphError> {
-        // Get source output type
-        let source = self
-            .nodes
-            .get(&source_node)
-            .ok_or(GraphError::NodeNotFound { id: source_node, name: None })?;
+        node_id: Id,
+        input_index: usize,
+        name: impl Into<String>,
+        fallback: Value,
+    ) -> bool {
+        let source = DefaultSource::context_var(name, fallback);
+        self.set_input_default_source(node_id, input_index, source)
+    }
 
-        let source_name = source.operator.name();
-        let outputs = source.operator.outputs();
-        if source_output >= outputs.len() {
-            return Err(GraphError::output_not_found(
-                source_node,
-                source_output,
-                source_name,
-                outputs.len(),
-            ));
+    /// Where an input's default comes from.
+    ///
+    /// Returns `None` if the node or input doesn't exist.
+    pub fn input_default_source(&self, node_id: Id, input_index: usize) -> Option<DefaultSource> {
+        let node = self.nodes.get(&node_id)?;
+        let default = node.operator.inputs().get(input_index)?.default.clone();
+        Some(match node.context_bindings.get(&input_index) {
+            Some(binding) => DefaultSource::context_var(binding.name.clone(), default),
+            None => DefaultSource::Literal(default),
+        })
+    }
+
+    /// Set where an input's default comes from.
+    ///
+    /// A [`DefaultSource::Literal`] removes any context binding. Emits
+    /// [`GraphEvent::InputSourceChanged`].
+    pub fn set_input_default_source(
+        &mut self,
+        node_id: Id,
+        input_index: usize,
+        source: DefaultSource,
+    ) -> bool {
+        let Some(previous) = self.input_default_source(node_id, input_index) else {
+            return false;
+        };
+        let Some(node) = self.nodes.get_mut(&node_id) else {
+            return false;
+        };
+        match &source {
+            DefaultSource::Literal(_) => {
+                node.context_bindings.remove(&input_index);
+            }
+            DefaultSource::ContextVar { name, .. } => {
+                let binding = ContextBinding {
+                    name: name.clone(),
+                    resolved: None,
+                };
+                node.context_bindings.insert(input_index, binding);
+            }
         }
-        let source_type = outputs[source_output].value_type;
+        node.input_transitions.remove(&input_index);
+        node.operator.inputs_mut()[input_index].default = source.static_value().clone();
+        for output in node.operator.outputs_mut() {
+            output.mark_dirty();
+        }
+        self.invalidate_cache_for_node(node_id);
 
-        // Get target input type and connect
-        let target = self
-            .nodes
-            .get_mut(&target_node)
-            .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
+        self.emit(GraphEvent::InputSourceChanged {
+            node: node_id,
+            input: input_index,
+            previous,
+            source,
+        });
+        true
+    }
 
-        let target_name = target.operator.name();
-        let input_count = target.operator.inputs().len();
+    // =========================================================================
+    // Port Override API
+    // =========================================================================
 
-        if target_input >= input_count {
-            return Err(GraphError::input_not_found(
-                target_node,
-                target_input,
-                target_name,
-                input_count,
-            ));
-        }
+    /// Get the override for an input port, if any.
+    pub fn get_input_override(&self, node_id: Id, input_index: usize) -> Option<&PortOverride> {
+        self.nodes
+            .get(&node_id)?
+            .input_overrides
+            .get(input_index)?
+            .as_ref()
+    }
 
-        let inputs = target.operator.inputs_mut();
-        let target_type = inputs[target_input].value_type;
+    /// Set an override for an input port.
+    ///
+    /// Extends the override vector if necessary. If the override is empty
+    /// (all fields None), it's equivalent to clearing the override.
+    pub fn set_input_override(&mut self, node_id: Id, input_index: usize, override_: PortOverride) {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            // Extend vector if needed
+            if node.input_overrides.len() <= input_index {
+                node.input_overrides.resize(input_index + 1, None);
+            }
+            // Store override (or None if empty)
+            node.input_overrides[input_index] = if override_.is_empty() {
+                None
+            } else {
+                Some(override_)
+            };
+            node.refresh_clamps();
+            self.invalidate_cache_for_node(node_id);
+        }
+    }
 
-        // Type check - require exact match for direct connection
-        if source_type != target_type {
-            return Err(GraphError::type_mismatch(
-                source_node,
-                source_type,
-                target_node,
-                target_type,
-            ));
+    /// Clear an override for an input port.
+    pub fn clear_input_override(&mut self, node_id: Id, input_index: usize) {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            if let Some(slot) = node.input_overrides.get_mut(input_index) {
+                *slot = None;
+            }
+            node.refresh_clamps();
+            self.invalidate_cache_for_node(node_id);
         }
+    }
 
-        // Track previous connection state for multi-input rollback
-        let was_multi = inputs[target_input].is_multi_input;
-        let prev_connection_count = inputs[target_input].connections.len();
+    /// Get the range enforcement active on an input, if any.
+    ///
+    /// See [`PortClamp`] for how values are constrained.
+    pub fn input_clamp(&self, node_id: Id, input_index: usize) -> Option<PortClamp> {
+        *self.nodes.get(&node_id)?.input_clamps.get(input_index)?
+    }
 
-        inputs[target_input].connect(source_node, source_output);
+    /// Returns true if an input is currently ramping toward a new default.
+    ///
+    /// Only inputs with [`PortOverride::smoothing_seconds`] set can be smoothing.
+    /// UIs can use this to indicate in-flight transitions.
+    pub fn is_input_smoothing(&self, node_id: Id, input_index: usize) -> bool {
+        self.nodes
+            .get(&node_id)
+            .is_some_and(|n| n.input_transitions.contains_key(&input_index))
+    }
 
-        // Check for cycles after connecting
-        if let Err(cycle_nodes) = self.check_for_cycles() {
-            // Undo only the newly-added connection
-            if let Some(target) = self.nodes.get_mut(&target_node) {
-                let input = &mut target.operator.inputs_mut()[target_input];
-                if was_multi {
-                    // For multi-input, remove only the last added connection
-                    if input.connections.len() > prev_connection_count {
-                        input.connections.pop();
-                    }
-                } else {
-                    // For single-input, clear the connection
-                    input.connection = None;
-                }
+    // =========================================================================
+    // Port Unit API
+    // =========================================================================
+
+    /// Get the typed unit declared on an input, if any.
+    pub fn input_unit(&self, node_id: Id, input_index: usize) -> Option<Unit> {
+        *self.nodes.get(&node_id)?.input_units.get(input_index)?
+    }
+
+    /// Get the typed unit declared on an output, if any.
+    pub fn output_unit(&self, node_id: Id, output_index: usize) -> Option<Unit> {
+        *self.nodes.get(&node_id)?.output_units.get(output_index)?
+    }
+
+    /// Set (or clear) the typed unit of an input.
+    ///
+    /// Only affects connections made afterwards.
+    pub fn set_input_unit(&mut self, node_id: Id, input_index: usize, unit: Option<Unit>) {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            if node.input_units.len() <= input_index {
+                node.input_units.resize(input_index + 1, None);
             }
-            return Err(GraphError::CycleDetected { nodes: cycle_nodes });
+            node.input_units[input_index] = unit;
         }
+    }
 
-        // Invalidate cache for target node since its input changed
-        self.invalidate_cache_for_node(target_node);
-        self.order_dirty = true;
+    /// Set (or clear) the typed unit of an output.
+    ///
+    /// Only affects connections made afterwards.
+    pub fn set_output_unit(&mut self, node_id: Id, output_index: usize, unit: Option<Unit>) {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            if node.output_units.len() <= output_index {
+                node.output_units.resize(output_index + 1, None);
+            }
+            node.output_units[output_index] = unit;
+        }
+    }
 
-        // Emit event
-        self.emit(GraphEvent::Connected {
-            source: source_node,
-            source_output,
-            target: target_node,
-            target_input,
-        });
+    // =========================================================================
+    // Update Rate API
+    // =========================================================================
 
-        Ok(())
+    /// Set how often a node is recomputed.
+    ///
+    /// Throttled nodes keep serving their last cached outputs between updates,
+    /// even if they are time-varying or their inputs changed.
+    pub fn set_update_rate(&mut self, node_id: Id, mode: UpdateRate) {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            node.update_rate = mode;
+        }
     }
 
-    /// Disconnect a target input
-    pub fn disconnect(&mut self, target_node: Id, target_input: usize) -> Result<(), GraphError> {
-        let target = self
-            .nodes
-            .get_mut(&target_node)
-            .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
+    /// Get a node's update rate. Returns `None` if the node doesn't exist.
+    pub fn update_rate(&self, node_id: Id) -> Option<UpdateRate> {
+        self.nodes.get(&node_id).map(|n| n.update_rate)
+    }
 
-        let target_name = target.operator.name();
-        let input_count = target.operator.inputs().len();
+    // =========================================================================
+    // Layout API
+    // =========================================================================
 
-        if target_input >= input_count {
-            return Err(GraphError::input_not_found(
-                target_node,
-                target_input,
-                target_name,
-                input_count,
-            ));
+    /// Set where an editor draws a node.
+    ///
+    /// Positions are metadata for frontends and don't affect evaluation. They
+    /// are saved with [`GraphDef::with_positions_from`](crate::serialization::GraphDef::with_positions_from);
+    /// [`layout::auto_layout`](crate::layout::auto_layout) computes them for
+    /// graphs that have none. Emits [`GraphEvent::NodeMoved`] if the position
+    /// changed. Returns false if the node doesn't exist.
+    pub fn set_node_position(&mut self, node_id: Id, position: [f32; 2]) -> bool {
+        let Some(node) = self.nodes.get_mut(&node_id) else {
+            return false;
+        };
+        if node.position != Some(position) {
+            node.position = Some(position);
+            self.emit(GraphEvent::NodeMoved { node: node_id, position });
         }
-        target.operator.inputs_mut()[target_input].disconnect();
-        // Invalidate cache for target node since its input changed
-        self.invalidate_cache_for_node(target_node);
-        self.order_dirty = true;
-
-        // Emit event
-        self.emit(GraphEvent::Disconnected {
-            target: target_node,
-            target_input,
-        });
+        true
+    }
 
-        Ok(())
+    /// Get a node's editor position. Returns `None` if the node doesn't exist
+    /// or was never positioned.
+    pub fn node_position(&self, node_id: Id) -> Option<[f32; 2]> {
+        self.nodes.get(&node_id).and_then(|n| n.position)
     }
 
     // =========================================================================
-    // Trigger Connections
+    // Gizmo API
     // =========================================================================
 
-    /// Connect a trigger output to a trigger input.
+    /// Override gizmo visibility for a node.
     ///
-    /// Unlike value connections, trigger connections don't carry data - they
-    /// signal "execute now" to the target operator.
+    /// The operator sees it as [`EvalContext::current_node_gizmos`];
+    /// [`GizmoVisibility::Inherit`] (the default) follows the context's
+    /// [`show_gizmos`](EvalContext::show_gizmos). Operators declaring
+    /// [`ContextDependency::Gizmos`](flux_core::ContextDependency::Gizmos)
+    /// recompute when it changes.
+    pub fn set_gizmo_visibility(&mut self, node_id: Id, visibility: GizmoVisibility) {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            node.gizmo_visibility = visibility;
+        }
+    }
+
+    /// Get a node's gizmo visibility. Returns `None` if the node doesn't exist.
+    pub fn gizmo_visibility(&self, node_id: Id) -> Option<GizmoVisibility> {
+        self.nodes.get(&node_id).map(|n| n.gizmo_visibility)
+    }
+
+    // =========================================================================
+    // Freeze API
+    // =========================================================================
+
+    /// Pin an output to its current value (`frozen = true`) or release it.
     ///
-    /// # Arguments
+    /// While any of its outputs is frozen a node is not recomputed (except
+    /// once per call context, if it has never been evaluated there), and
+    /// consumers of a frozen output read the pinned value, so edits upstream
+    /// don't reach them. Releasing an output recomputes the node (any other
+    /// frozen outputs stay pinned) and everything downstream on the next
+    /// evaluation.
     ///
-    /// * `source_node` - Node emitting the trigger
-    /// * `source_output` - Index of the trigger output on the source
-    /// * `target_node` - Node receiving the trigger
-    /// * `target_input` - Index of the trigger input on the target
+    /// The pinned value is the output's last evaluated value. Outputs of a
+    /// node that hasn't been evaluated yet are pinned to whatever its first
+    /// evaluation produces.
     ///
     /// # Errors
     ///
-    /// Returns error if:
-    /// - Source or target node doesn't exist
-    /// - Trigger output or input index is out of bounds
-    pub fn connect_trigger(
+    /// Returns error if the node or output doesn't exist.
+    pub fn freeze_output(
         &mut self,
-        source_node: Id,
-        source_output: usize,
-        target_node: Id,
-        target_input: usize,
+        node_id: Id,
+        output: usize,
+        frozen: bool,
     ) -> Result<(), GraphError> {
-        // Verify source node and trigger output exist
-        {
-            let source = self
-                .nodes
-                .get(&source_node)
-                .ok_or(GraphError::NodeNotFound { id: source_node, name: None })?;
+        let node = self
+            .nodes
+            .get(&node_id)
+            .ok_or_else(|| GraphError::node_not_found(node_id, None))?;
+        let outputs = node.operator.outputs();
+        if output >= outputs.len() {
+            return Err(GraphError::output_not_found(
+                node_id,
+                output,
+                node.operator.name(),
+                outputs.len(),
+            ));
+        }
 
-            let trigger_outputs = source.operator.trigger_outputs();
-            if source_output >= trigger_outputs.len() {
-                return Err(GraphError::TriggerNotFound {
-                    node_id: source_node,
-                    is_output: true,
-                    index: source_output,
-                    available: trigger_outputs.len(),
-                });
+        if !frozen {
+            let node = self.nodes.get_mut(&node_id).expect("node exists");
+            if node.frozen_outputs.remove(&output).is_some() {
+                self.invalidate_cache_for_node(node_id);
+            }
+            return Ok(());
+        }
+        if node.frozen_outputs.contains_key(&output) {
+            return Ok(());
+        }
+        let value = self.cached_result(node_id, output, CallContext::root()).ok();
+        // Pin the value in every evaluation of the node cached so far
+        if let Some(value) = &value {
+            for (key, entry) in &mut self.value_cache {
+                if key.node_id == node_id {
+                    if let Some(slot) = entry.outputs.get_mut(output) {
+                        *slot = Arc::new(value.clone());
+                    }
+                }
             }
         }
+        self.generation += 1;
+        let node = self.nodes.get_mut(&node_id).expect("node exists");
+        node.frozen_outputs.insert(output, value);
+        Ok(())
+    }
 
-        // Verify target node and trigger input exist, then connect
-        {
-            let target = self
-                .nodes
-                .get_mut(&target_node)
-                .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
+    /// Returns true if the output is frozen (see [`freeze_output`](Self::freeze_output)).
+    pub fn is_output_frozen(&self, node_id: Id, output: usize) -> bool {
+        self.nodes
+            .get(&node_id)
+            .is_some_and(|node| node.frozen_outputs.contains_key(&output))
+    }
 
-            let trigger_input_count = target.operator.trigger_inputs().len();
-            if target_input >= trigger_input_count {
-                return Err(GraphError::TriggerNotFound {
-                    node_id: target_node,
-                    is_output: false,
-                    index: target_input,
-                    available: trigger_input_count,
-                });
-            }
+    /// Force a node to recompute on the next evaluation, regardless of its update rate.
+    ///
+    /// This is the only way to refresh a node set to [`UpdateRate::OnDemand`].
+    pub fn request_update(&mut self, node_id: Id) {
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            node.update_requested = true;
+        }
+    }
 
-            // Connect the target's trigger input
-            target.operator.trigger_inputs_mut()[target_input].connect(source_node, source_output);
+    /// Reset per-evaluation state so the next evaluation starts fresh.
+    ///
+    /// Clears cached values, update-rate timing, pending update requests,
+    /// node errors and any pending budgeted evaluation. Operator-internal state
+    /// (accumulators, previous-frame values, etc.) is not touched; rebuild the
+    /// graph if those must start from scratch.
+    pub fn reset_evaluation_state(&mut self) {
+        self.clear_cache();
+        self.budgeted = None;
+        self.node_errors.clear();
+        for node in self.nodes.values_mut() {
+            node.last_update = None;
+            node.update_requested = false;
         }
+    }
 
-        // Add connection to source's trigger output
-        {
-            let source = self
-                .nodes
-                .get_mut(&source_node)
-                .expect("Source node verified above");
-
-            source.operator.trigger_outputs_mut()[source_output].connect(target_node, target_input);
-        }
+    /// Get effective metadata for an input (combines PortMeta defaults + per-instance override).
+    ///
+    /// Returns resolved metadata ready for UI rendering.
+    ///
+    /// **Note**: Currently, PortMeta from operator is not accessible through `dyn Operator`.
+    /// For full OperatorMeta support, use FluxNodalBridge which can access concrete types
+    /// during node creation. This method applies overrides to sensible defaults.
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - The node to get metadata for
+    /// * `input_index` - The input port index
+    /// * `port_meta` - Optional PortMeta from the operator (caller must provide if known)
+    pub fn get_effective_input_meta_with_default(
+        &self,
+        node_id: Id,
+        input_index: usize,
+        port_meta: Option<flux_core::PortMeta>,
+    ) -> Option<EffectivePortMeta> {
+        let node = self.nodes.get(&node_id)?;
 
-        // Emit event
-        self.emit(GraphEvent::TriggerConnected {
-            source: source_node,
-            source_output,
-            target: target_node,
-            target_input,
-        });
+        // Get per-instance override if any
+        let override_ = node
+            .input_overrides
+            .get(input_index)
+            .and_then(|o| o.as_ref());
 
-        Ok(())
+        Some(EffectivePortMeta::from_meta(port_meta, override_))
     }
 
-    /// Disconnect a trigger input from its source.
-    ///
-    /// # Arguments
+    /// Get effective metadata for an input from the [`PortMeta`] captured by
+    /// [`add_with_meta`](Self::add_with_meta) or
+    /// [`add_boxed_with_meta`](Self::add_boxed_with_meta) and any per-instance
+    /// override.
     ///
-    /// * `target_node` - Node with the trigger input to disconnect
-    /// * `target_input` - Index of the trigger input
+    /// Nodes added with [`add`](Self::add) have no captured metadata, so only
+    /// their overrides apply. Returns None if the node doesn't exist.
+    pub fn effective_input_meta(
+        &self,
+        node_id: Id,
+        input_index: usize,
+    ) -> Option<EffectivePortMeta> {
+        let node = self.nodes.get(&node_id)?;
+        let meta = node.input_meta.get(input_index).cloned().flatten();
+        self.get_effective_input_meta_with_default(node_id, input_index, meta)
+    }
+
+    /// Get per-instance override for an input, if any exists.
     ///
-    /// # Returns
+    /// This is useful when you need to check if a specific override is set
+    /// before applying defaults.
+    pub fn get_input_override_raw(&self, node_id: Id, input_index: usize) -> Option<&PortOverride> {
+        self.get_input_override(node_id, input_index)
+    }
+
+    /// Connect a source output to a target input with type checking and auto-conversion.
     ///
-    /// The previous connection (source_node, source_output) if there was one.
-    pub fn disconnect_trigger(
+    /// Untyped form of [`connect_refs`](Self::connect_refs).
+    pub fn connect(
         &mut self,
+        source_node: Id,
+        source_output: usize,
         target_node: Id,
         target_input: usize,
-    ) -> Result<Option<(Id, usize)>, GraphError> {
-        let prev_connection;
-
-        // Get the current connection and disconnect target's trigger input
-        {
-            let target = self
-                .nodes
-                .get_mut(&target_node)
-                .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
-
-            let trigger_input_count = target.operator.trigger_inputs().len();
-            if target_input >= trigger_input_count {
-                return Err(GraphError::TriggerNotFound {
-                    node_id: target_node,
-                    is_output: false,
-                    index: target_input,
-                    available: trigger_input_count,
-                });
-            }
-
-            prev_connection = target.operator.trigger_inputs()[target_input].connection;
-            target.operator.trigger_inputs_mut()[target_input].disconnect();
-        }
-
-        // Remove connection from source's trigger output
-        if let Some((source_node, source_output)) = prev_connection {
-            if let Some(source) = self.nodes.get_mut(&source_node) {
-                source.operator.trigger_outputs_mut()[source_output]
-                    .disconnect(target_node, target_input);
-            }
-
-            // Emit event
-            self.emit(GraphEvent::TriggerDisconnected {
-                source: source_node,
-                source_output,
-                target: target_node,
-                target_input,
-            });
-        }
-
-        Ok(prev_connection)
+    ) -> Result<Option<Id>, GraphError> {
+        self.connect_refs(
+            OutputRef::new(source_node, source_output),
+            InputRef::new(target_node, target_input),
+        )
+        .map(|conversion| conversion.map(NodeId::id))
     }
 
-    /// Fire a trigger output and propagate to all connected trigger inputs.
+    /// Connect a source output to a target input with type checking and auto-conversion.
     ///
-    /// This initiates push-based execution. When a trigger fires:
-    /// 1. All connected trigger inputs receive the signal
-    /// 2. Each target operator's `on_triggered()` is called
-    /// 3. Any triggers returned by `on_triggered()` are fired recursively
+    /// If the source and target types differ but can be coerced, a [`ConversionOp`]
+    /// is automatically inserted between them. This makes type conversion explicit
+    /// and visible in the graph.
     ///
-    /// # Arguments
+    /// If both ports are Float and declare different typed units, a
+    /// [`UnitConversionOp`] is inserted when the units are compatible. Incompatible
+    /// units are connected directly and reported via [`GraphEvent::UnitMismatch`].
     ///
-    /// * `node_id` - Node whose trigger output to fire
-    /// * `trigger_output` - Index of the trigger output to fire
-    /// * `ctx` - Evaluation context for timing information
+    /// # Returns
+    ///
+    /// - `Ok(None)` - Direct connection (types match exactly)
+    /// - `Ok(Some(id))` - Connection via auto-inserted conversion node
+    /// - `Err(...)` - Connection failed (incompatible types, cycle, etc.)
     ///
     /// # Example
     ///
     /// ```ignore
-    /// // Fire the "OnFrame" trigger from the main loop
-    /// graph.fire_trigger(main_loop_id, 0, &ctx);
+    /// // Float to Vec3 connection - auto-inserts ConversionOp
+    /// let conversion_id = graph.connect_refs(
+    ///     OutputRef::new(float_node, 0),
+    ///     InputRef::new(vec3_node, 0),
+    /// )?;
+    /// if let Some(conv_id) = conversion_id {
+    ///     println!("Conversion node inserted: {}", conv_id);
+    /// }
     /// ```
-    pub fn fire_trigger(&mut self, node_id: Id, trigger_output: usize, ctx: &EvalContext) {
-        // Get the targets for this trigger output
-        let targets: Vec<(Id, usize)> = {
-            let node = match self.nodes.get(&node_id) {
-                Some(n) => n,
-                None => return,
-            };
-
-            let trigger_outputs = node.operator.trigger_outputs();
-            if trigger_output >= trigger_outputs.len() {
-                return;
-            }
-
-            trigger_outputs[trigger_output].connections.clone()
-        };
-
-        // Fire each connected target
-        for (target_id, target_input) in targets {
-            self.trigger_node(target_id, target_input, ctx);
-        }
+    pub fn connect_refs(
+        &mut self,
+        source: OutputRef,
+        target: InputRef,
+    ) -> Result<Option<NodeId>, GraphError> {
+        self.batch(|graph| graph.connect_refs_unbatched(source, target))
     }
 
-    /// Internal: Trigger a specific node's trigger input and handle cascading triggers.
-    fn trigger_node(&mut self, node_id: Id, trigger_input: usize, ctx: &EvalContext) {
-        // Create the input resolver closure
-        let get_input_value = |source_id: Id, output_idx: usize| -> Value {
-            // Try to get from cache first
-            let cache_key = CacheKey {
-                node_id: source_id,
-                call_context: ctx.call_context,
-            };
+    fn connect_refs_unbatched(
+        &mut self,
+        source: OutputRef,
+        target: InputRef,
+    ) -> Result<Option<NodeId>, GraphError> {
+        let (source_node, source_output) = source.into();
+        let (target_node, target_input) = target.into();
 
-            if let Some(cached) = self.value_cache.get(&cache_key) {
-                if let Some(value) = cached.get(output_idx) {
-                    return (**value).clone();
-                }
-            }
+        // Get source output type
+        let source = self
+            .nodes
+            .get(&source_node)
+            .ok_or(GraphError::NodeNotFound { id: source_node, name: None })?;
 
-            // Not cached - return a default value
-            // In practice, trigger-based operators should either:
-            // 1. Use inputs that are already cached from prior evaluation
-            // 2. Not depend on value inputs for their triggered behavior
-            Value::Float(0.0)
-        };
+        let source_name = source.operator.name();
+        let outputs = source.operator.outputs();
+        if source_output >= outputs.len() {
+            return Err(GraphError::output_not_found(
+                source_node,
+                source_output,
+                source_name,
+                outputs.len(),
+            ));
+        }
+        let source_type = outputs[source_output].value_type;
 
-        // Call the operator's on_triggered method
-        let triggers_to_fire: Vec<usize> = {
-            let node = match self.nodes.get_mut(&node_id) {
-                Some(n) => n,
-                None => return,
-            };
+        // Get target input type
+        let target = self
+            .nodes
+            .get(&target_node)
+            .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
 
-            node.operator.on_triggered(trigger_input, ctx, &get_input_value)
-        };
+        let target_name = target.operator.name();
+        let input_count = target.operator.inputs().len();
 
-        // Fire any cascading triggers
-        for output_idx in triggers_to_fire {
-            self.fire_trigger(node_id, output_idx, ctx);
+        if target_input >= input_count {
+            return Err(GraphError::input_not_found(
+                target_node,
+                target_input,
+                target_name,
+                input_count,
+            ));
         }
-    }
 
-    /// Check for cycles in the graph using DFS
-    fn check_for_cycles(&self) -> Result<(), Vec<Id>> {
-        let mut visited = HashSet::new();
-        let mut rec_stack = HashSet::new();
-        let mut cycle_nodes = Vec::new();
+        let target_type = target.operator.inputs()[target_input].value_type;
 
-        for &node_id in self.nodes.keys() {
-            if self.has_cycle_dfs(node_id, &mut visited, &mut rec_stack, &mut cycle_nodes) {
-                return Err(cycle_nodes);
+        // Float ports with differing typed units are scaled or flagged
+        if source_type == ValueType::Float && target_type == ValueType::Float {
+            let units = (
+                self.output_unit(source_node, source_output),
+                self.input_unit(target_node, target_input),
+            );
+            if let (Some(source_unit), Some(target_unit)) = units {
+                if source_unit != target_unit {
+                    return self
+                        .connect_with_units(
+                            source_node,
+                            source_output,
+                            source_unit,
+                            target_node,
+                            target_input,
+                            target_unit,
+                        )
+                        .map(|conversion| conversion.map(NodeId));
+                }
             }
         }
-        Ok(())
-    }
 
-    fn has_cycle_dfs(
-        &self,
-        node_id: Id,
-        visited: &mut HashSet<Id>,
-        rec_stack: &mut HashSet<Id>,
-        cycle_nodes: &mut Vec<Id>,
-    ) -> bool {
-        if rec_stack.contains(&node_id) {
-            cycle_nodes.push(node_id);
-            return true;
-        }
-        if visited.contains(&node_id) {
-            return false;
-        }
+        // Determine connection strategy based on types
+        if target.operator.inputs()[target_input].connects_directly(source_type) {
+            // Direct connection - types match (or a list feeds a multi-input)
+            self.connect_direct(source_node, source_output, target_node, target_input)?;
+            Ok(None)
+        } else if source_type.can_coerce_to(target_type) {
+            // Auto-insert conversion operator
+            let conv_op = ConversionOp::new(source_type, target_type);
+            let conv_id = conv_op.id();
+            self.add(conv_op);
 
-        visited.insert(node_id);
-        rec_stack.insert(node_id);
+            // Connect: source -> conversion -> target
+            self.connect_direct(source_node, source_output, conv_id, 0)?;
+            self.connect_direct(conv_id, 0, target_node, target_input)?;
 
-        if let Some(node) = self.nodes.get(&node_id) {
-            for input in node.operator.inputs() {
-                // Check single connection
-                if let Some((dep_id, _)) = input.connection {
-                    if self.has_cycle_dfs(dep_id, visited, rec_stack, cycle_nodes) {
-                        cycle_nodes.push(node_id);
-                        return true;
-                    }
-                }
-                // Check multi-input connections
-                for &(dep_id, _) in &input.connections {
-                    if self.has_cycle_dfs(dep_id, visited, rec_stack, cycle_nodes) {
-                        cycle_nodes.push(node_id);
-                        return true;
-                    }
-                }
-            }
+            // Emit conversion insertion event
+            self.emit(GraphEvent::ConversionInserted {
+                conversion_node: conv_id,
+                source_type,
+                target_type,
+            });
+
+            Ok(Some(NodeId(conv_id)))
+        } else {
+            // Incompatible types - cannot connect
+            Err(GraphError::type_mismatch(
+                source_node,
+                source_type,
+                target_node,
+                target_type,
+            ))
+        }
+    }
+
+    /// Connect two Float ports whose typed units differ.
+    fn connect_with_units(
+        &mut self,
+        source_node: Id,
+        source_output: usize,
+        source_unit: Unit,
+        target_node: Id,
+        target_input: usize,
+        target_unit: Unit,
+    ) -> Result<Option<Id>, GraphError> {
+        if !source_unit.is_compatible_with(target_unit) {
+            // Warn rather than fail so loosely-typed patches keep working
+            self.connect_direct(source_node, source_output, target_node, target_input)?;
+            self.emit(GraphEvent::UnitMismatch {
+                source: source_node,
+                source_output,
+                target: target_node,
+                target_input,
+                source_unit,
+                target_unit,
+            });
+            return Ok(None);
         }
 
-        rec_stack.remove(&node_id);
-        false
+        let conv_id = self.add(UnitConversionOp::new(source_unit, target_unit));
+        self.set_input_unit(conv_id, 0, Some(source_unit));
+        self.set_output_unit(conv_id, 0, Some(target_unit));
+
+        if let Err(e) = self
+            .connect_direct(source_node, source_output, conv_id, 0)
+            .and_then(|_| self.connect_direct(conv_id, 0, target_node, target_input))
+        {
+            self.remove_untrashed(conv_id);
+            return Err(e);
+        }
+
+        self.emit(GraphEvent::UnitConversionInserted {
+            conversion_node: conv_id,
+            source_unit,
+            target_unit,
+        });
+
+        Ok(Some(conv_id))
     }
 
-    /// Compute topological order for evaluation using Kahn's algorithm
-    pub(crate) fn compute_order(&mut self) -> Result<(), GraphError> {
-        if !self.order_dirty {
-            return Ok(());
+    /// Connect a source output to a target input directly, without auto-conversion.
+    ///
+    /// This method performs the raw connection without checking for type compatibility
+    /// beyond exact equality. It's used internally by `connect()` and can be used
+    /// when you want to bypass auto-conversion (e.g., when manually inserting
+    /// conversion nodes).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - Source or target node doesn't exist
+    /// - Output or input index is out of bounds
+    /// - Types don't match exactly
+    /// - Connection would create a cycle
+    pub fn connect_direct(
+        &mut self,
+        source_node: Id,
+        source_output: usize,
+        target_node: Id,
+        target_input: usize,
+    ) -> Result<(), GraphError> {
+        // Get source output type
+        let source = self
+            .nodes
+            .get(&source_node)
+            .ok_or(GraphError::NodeNotFound { id: source_node, name: None })?;
+
+        let source_name = source.operator.name();
+        let outputs = source.operator.outputs();
+        if source_output >= outputs.len() {
+            return Err(GraphError::output_not_found(
+                source_node,
+                source_output,
+                source_name,
+                outputs.len(),
+            ));
         }
+        let source_type = outputs[source_output].value_type;
+
+        // Get target input type and connect
+        let target = self
+            .nodes
+            .get_mut(&target_node)
+            .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
 
-        let mut remaining: Vec<Id> = self.nodes.keys().copied().collect();
-        let mut order = Vec::with_capacity(remaining.len());
-        // HashSet for O(1) dependency lookups instead of O(n) Vec::contains
-        let mut order_set: HashSet<Id> = HashSet::with_capacity(remaining.len());
-        let mut made_progress = true;
+        let target_name = target.operator.name();
+        let input_count = target.operator.inputs().len();
 
-        while !remaining.is_empty() && made_progress {
-            made_progress = false;
+        if target_input >= input_count {
+            return Err(GraphError::input_not_found(
+                target_node,
+                target_input,
+                target_name,
+                input_count,
+            ));
+        }
 
-            remaining.retain(|&id| {
-                let node = match self.nodes.get(&id) {
-                    Some(n) => n,
-                    None => return false, // Node disappeared, remove from remaining
-                };
+        let inputs = target.operator.inputs_mut();
+        let target_type = inputs[target_input].value_type;
 
-                // Check if all dependencies are already in order
-                let deps_satisfied = node.operator.inputs().iter().all(|input| {
-                    // Check single connection
-                    let single_ok = match input.connection {
-                        None => true,
-                        Some((dep_id, _)) => order_set.contains(&dep_id),
-                    };
-                    // Check multi-input connections
-                    let multi_ok = input
-                        .connections
-                        .iter()
-                        .all(|(dep_id, _)| order_set.contains(dep_id));
+        // Type check - require exact match (or the list type on a multi-input)
+        if !inputs[target_input].connects_directly(source_type) {
+            return Err(GraphError::type_mismatch(
+                source_node,
+                source_type,
+                target_node,
+                target_type,
+            ));
+        }
 
-                    single_ok && multi_ok
-                });
+        // Track previous connection state for multi-input rollback
+        let was_multi = inputs[target_input].is_multi_input;
+        let prev_connection_count = inputs[target_input].connections.len();
+        let prev_connection = inputs[target_input].connection;
+        let prev_muted = inputs[target_input].muted.clone();
+        let previous: Vec<(Id, usize)> = port_sources(&inputs[target_input]).collect();
+
+        inputs[target_input].connect(source_node, source_output);
 
-                if deps_satisfied {
-                    order.push(id);
-                    order_set.insert(id);
-                    made_progress = true;
-                    false // remove from remaining
+        // Check for cycles after connecting
+        if let Err(cycle_nodes) = self.check_for_cycles() {
+            // Undo only the newly-added connection
+            if let Some(target) = self.nodes.get_mut(&target_node) {
+                let input = &mut target.operator.inputs_mut()[target_input];
+                if was_multi {
+                    // For multi-input, remove only the last added connection
+                    if input.connections.len() > prev_connection_count {
+                        input.connections.pop();
+                    }
                 } else {
-                    true // keep in remaining
+                    // For single-input, restore whatever it was connected to before
+                    input.connection = prev_connection;
+                    input.muted = prev_muted;
                 }
-            });
-        }
-
-        if !remaining.is_empty() {
-            return Err(GraphError::CycleDetected { nodes: remaining });
+            }
+            return Err(GraphError::CycleDetected { nodes: cycle_nodes });
         }
+        self.reindex_input(target_node, target_input, &previous);
 
-        self.eval_order = order;
-        self.order_dirty = false;
+        // Invalidate cache for target node since its input changed
+        self.invalidate_cache_for_node(target_node);
+        self.mark_order_dirty();
+        self.notify_connection_changed(target_node, target_input);
 
-        // Emit event when order is recomputed
-        self.emit(GraphEvent::OrderRecomputed);
+        // Emit event
+        self.emit(GraphEvent::Connected {
+            source: source_node,
+            source_output,
+            target: target_node,
+            target_input,
+        });
 
         Ok(())
     }
 
-    /// Check if a node needs evaluation based on its dirty state and dependencies
-    fn needs_evaluation(
-        &self,
-        node_id: Id,
-        call_context: CallContext,
-        computed_nodes: &HashSet<Id>,
-    ) -> bool {
-        let node = match self.nodes.get(&node_id) {
-            Some(n) => n,
-            None => return false,
-        };
+    /// Disconnect a target input
+    ///
+    /// Untyped form of [`disconnect_ref`](Self::disconnect_ref).
+    pub fn disconnect(&mut self, target_node: Id, target_input: usize) -> Result<(), GraphError> {
+        self.disconnect_ref(InputRef::new(target_node, target_input))
+    }
 
-        // Create cache key with call context
-        let cache_key = CacheKey {
-            node_id,
-            call_context,
-        };
+    /// Disconnect a target input
+    pub fn disconnect_ref(&mut self, input: InputRef) -> Result<(), GraphError> {
+        let (target_node, target_input) = input.into();
+        let target = self
+            .nodes
+            .get_mut(&target_node)
+            .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
 
-        // If node has never been computed (not in cache for this context), it needs evaluation
-        if !self.value_cache.contains_key(&cache_key) {
-            return true;
-        }
+        let target_name = target.operator.name();
+        let input_count = target.operator.inputs().len();
 
-        // Time-varying operators always need to be recomputed
-        if node.operator.is_time_varying() {
-            return true;
+        if target_input >= input_count {
+            return Err(GraphError::input_not_found(
+                target_node,
+                target_input,
+                target_name,
+                input_count,
+            ));
         }
-
-        // Check if any output is dirty
-        if node.operator.outputs().iter().any(|o| o.is_dirty()) {
-            return true;
+        let input = &mut target.operator.inputs_mut()[target_input];
+        let was_connected = input.is_connected();
+        let previous: Vec<(Id, usize)> = port_sources(input).collect();
+        input.disconnect();
+        if was_connected {
+            target.operator.on_connection_changed(target_input, false);
         }
-
-        // Check if any connected input comes from a node that was just computed
-        for input in node.operator.inputs() {
-            if let Some((source_id, _)) = input.connection {
-                if computed_nodes.contains(&source_id) {
-                    return true;
-                }
-            }
-            // Check multi-input connections
-            for &(source_id, _) in &input.connections {
-                if computed_nodes.contains(&source_id) {
-                    return true;
-                }
+        self.reindex_input(target_node, target_input, &previous);
+        self.revalidate_inputs(target_node);
+        // Invalidate cache for target node since its input changed
+        self.invalidate_cache_for_node(target_node);
+        self.mark_order_dirty();
+
+        // Emit events
+        self.batch(|graph| {
+            for (source, source_output) in previous {
+                graph.emit(GraphEvent::Disconnected {
+                    source,
+                    source_output,
+                    target: target_node,
+                    target_input,
+                });
             }
+        });
+
+        Ok(())
+    }
+
+    /// Mute or unmute a value connection without disconnecting it.
+    ///
+    /// A muted connection stays in place (including its position on a
+    /// multi-input) but evaluation treats it as absent: a single input falls
+    /// back to its default and a multi-input skips the entry. Upstream nodes
+    /// that only feed muted connections stop being evaluated. Emits
+    /// [`GraphEvent::ConnectionMuted`] if the state changed.
+    ///
+    /// If the same source feeds a multi-input more than once, `conn.muted`
+    /// picks an edge in that state first, as reported by
+    /// [`connections`](Self::connections).
+    ///
+    /// Returns whether the state changed.
+    ///
+    /// # Errors
+    ///
+    /// [`GraphError::ConnectionNotFound`] if the connection doesn't exist.
+    pub fn set_connection_muted(&mut self, conn: Connection, muted: bool) -> Result<bool, GraphError> {
+        let not_found = GraphError::ConnectionNotFound { connection: conn };
+        let Some(input) = self
+            .nodes
+            .get_mut(&conn.target_node)
+            .and_then(|node| node.operator.inputs_mut().get_mut(conn.target_input))
+        else {
+            return Err(not_found);
+        };
+        let slot = edge_slot(input, (conn.source_node, conn.source_output), conn.muted)
+            .ok_or(not_found)?;
+        if input.is_muted(slot) == muted {
+            return Ok(false);
         }
+        input.set_muted(slot, muted);
 
-        false
+        self.invalidate_downstream(conn.target_node);
+        self.mark_order_dirty();
+        self.emit(GraphEvent::ConnectionMuted {
+            connection: Connection { muted, ..conn },
+        });
+        Ok(true)
     }
 
-    /// Evaluate the graph and return the output value of a specific node
-    pub fn evaluate(
+    /// Mute the edge made by a [`connect`](Self::connect) call, which runs
+    /// from `conversion` if one was inserted.
+    pub(crate) fn mute_connected(
         &mut self,
-        output_node: Id,
-        output_index: usize,
-        ctx: &EvalContext,
-    ) -> Result<Value, GraphError> {
-        self.compute_order()?;
+        (source_node, source_output): (Id, usize),
+        conversion: Option<Id>,
+        (target_node, target_input): (Id, usize),
+    ) {
+        let (source_node, source_output) =
+            conversion.map_or((source_node, source_output), |id| (id, 0));
+        let edge = Connection {
+            source_node,
+            source_output,
+            target_node,
+            target_input,
+            muted: false,
+        };
+        let _ = self.set_connection_muted(edge, true);
+    }
 
-        // Get the call context for this evaluation
-        let call_context = ctx.call_context;
+    // =========================================================================
+    // Splicing
+    // =========================================================================
 
-        // Track which nodes were computed this frame (HashSet for O(1) lookups)
-        let mut computed_nodes: HashSet<Id> = HashSet::new();
+    /// Insert a node into an existing value connection.
+    ///
+    /// The edge described by `connection` is replaced by
+    /// `source -> new_node[in_idx]` and `new_node[out_idx] -> target`, with
+    /// conversion nodes inserted as [`connect`](Self::connect) would. On a
+    /// multi-input target the new edge keeps the original edge's position.
+    ///
+    /// The operation is atomic: if either half cannot be connected, the graph
+    /// is restored to its previous state, no events are emitted, and the error
+    /// is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - `new_node` doesn't exist
+    /// - The connection doesn't exist
+    /// - Either half fails to connect (incompatible types, bad index, cycle)
+    pub fn splice_node(
+        &mut self,
+        connection: Connection,
+        new_node: Id,
+        in_idx: usize,
+        out_idx: usize,
+    ) -> Result<(), GraphError> {
+        self.splice_node_recorded(connection, new_node, in_idx, out_idx)
+            .map(|_| ())
+    }
 
-        // Clone eval_order to avoid borrow issues
-        let eval_order = self.eval_order.clone();
+    /// Splice as in [`splice_node`](Self::splice_node), returning what is
+    /// needed to undo it exactly.
+    pub(crate) fn splice_node_recorded(
+        &mut self,
+        connection: Connection,
+        new_node: Id,
+        in_idx: usize,
+        out_idx: usize,
+    ) -> Result<SpliceRecord, GraphError> {
+        self.batch(|graph| graph.splice_node_unbatched(connection, new_node, in_idx, out_idx))
+    }
 
-        for &node_id in &eval_order {
-            let needs_eval = self.needs_evaluation(node_id, call_context, &computed_nodes);
+    fn splice_node_unbatched(
+        &mut self,
+        connection: Connection,
+        new_node: Id,
+        in_idx: usize,
+        out_idx: usize,
+    ) -> Result<SpliceRecord, GraphError> {
+        if !self.nodes.contains_key(&new_node) {
+            return Err(GraphError::node_not_found(new_node, None));
+        }
+
+        let event_mark = self.pending_events.len();
+        let existing: HashSet<Id> = self.nodes.keys().copied().collect();
+        let target_snapshot = self
+            .input_snapshot(connection.target_node, connection.target_input)
+            .ok_or(GraphError::ConnectionNotFound { connection })?;
+        let node_snapshot = self.input_snapshot(new_node, in_idx);
+
+        let position = self
+            .detach_edge(&connection)
+            .ok_or(GraphError::ConnectionNotFound { connection })?;
+        self.emit(GraphEvent::Disconnected {
+            source: connection.source_node,
+            source_output: connection.source_output,
+            target: connection.target_node,
+            target_input: connection.target_input,
+        });
 
-            if !needs_eval {
-                continue;
-            }
+        let result = self
+            .connect(connection.source_node, connection.source_output, new_node, in_idx)
+            .and_then(|_| {
+                self.connect(new_node, out_idx, connection.target_node, connection.target_input)
+            });
+        let inserted: Vec<Id> = self
+            .nodes
+            .keys()
+            .filter(|id| !existing.contains(id))
+            .copied()
+            .collect();
 
-            // Get node reference safely
-            let node = match self.nodes.get_mut(&node_id) {
-                Some(n) => n,
-                None => {
-                    // Node was removed during evaluation, skip it
-                    continue;
-                }
-            };
+        if let Err(e) = result {
+            // Roll back: drop conversion nodes, restore both inputs, retract events
+            for id in inserted {
+                self.take_node(id);
+            }
+            self.restore_input(&target_snapshot);
+            if let Some(snapshot) = &node_snapshot {
+                self.restore_input(snapshot);
+            }
+            self.pending_events.truncate(event_mark);
+            return Err(e);
+        }
 
-            // Create lookup closure that captures a reference to value_cache
-            // We need to use a separate reference because we can't borrow self
-            // while also having a mutable borrow of node
-            //
-            // Note: The closure looks up values using the same call context,
-            // ensuring context-aware cache isolation for subroutines/loops.
-            //
-            // Reference stealing: When an Arc has refcount == 1, we could pass
-            // ownership instead of cloning. However, since the closure captures
-            // an immutable reference, we clone here. Full reference stealing
-            // would require a more complex evaluation model where we pre-collect
-            // inputs before computing.
-            let cache_ref = &self.value_cache;
-            let get_input = |dep_id: Id, idx: usize| -> Value {
-                let key = CacheKey {
-                    node_id: dep_id,
-                    call_context,
-                };
-                cache_ref
-                    .get(&key)
-                    .and_then(|outputs| outputs.get(idx))
-                    .map(|arc| {
-                        // Try to steal the reference if we're the sole owner
-                        // Note: This won't work with the immutable borrow, but we
-                        // set up the infrastructure for future optimization
-                        Arc::unwrap_or_clone(arc.clone())
-                    })
-                    .unwrap_or_default()
-            };
+        if let Some(position) = position {
+            self.move_last_connection(connection.target_node, connection.target_input, position);
+        }
 
-            node.operator.compute(ctx, &get_input);
+        Ok(SpliceRecord {
+            connection,
+            new_node,
+            in_idx,
+            inserted,
+            target_snapshot,
+            node_snapshot,
+        })
+    }
 
-            // Update the cache with new output values wrapped in Arc
-            let cache_key = CacheKey {
-                node_id,
-                call_context,
-            };
-            let outputs: Vec<Arc<Value>> = node
-                .operator
-                .outputs()
-                .iter()
-                .map(|o| Arc::new(o.value.clone()))
-                .collect();
-            self.value_cache.insert(cache_key, outputs);
+    /// Reverse a splice made by [`splice_node_recorded`](Self::splice_node_recorded).
+    pub(crate) fn undo_splice(&mut self, record: &SpliceRecord) {
+        self.batch(|graph| graph.undo_splice_unbatched(record));
+    }
 
-            computed_nodes.insert(node_id);
+    fn undo_splice_unbatched(&mut self, record: &SpliceRecord) {
+        for &id in &record.inserted {
+            self.take_node(id);
+        }
+        if let Some(snapshot) = &record.node_snapshot {
+            self.restore_input(snapshot);
         }
+        self.restore_input(&record.target_snapshot);
 
-        // Return requested output (using the current call context)
-        let output_key = CacheKey {
-            node_id: output_node,
-            call_context,
-        };
-        self.value_cache
-            .get(&output_key)
-            .and_then(|outputs| outputs.get(output_index))
-            .map(|arc| Arc::unwrap_or_clone(arc.clone()))
-            .ok_or_else(|| GraphError::node_not_found(output_node, self.node_name(output_node)))
+        self.emit(GraphEvent::Disconnected {
+            source: record.connection.source_node,
+            source_output: record.connection.source_output,
+            target: record.new_node,
+            target_input: record.in_idx,
+        });
+        let c = record.connection;
+        self.emit(GraphEvent::Connected {
+            source: c.source_node,
+            source_output: c.source_output,
+            target: c.target_node,
+            target_input: c.target_input,
+        });
     }
 
-    /// Get statistics about the graph
-    pub fn stats(&self) -> GraphStats {
-        let mut connection_count = 0;
-        for node in self.nodes.values() {
-            for input in node.operator.inputs() {
-                if input.connection.is_some() {
-                    connection_count += 1;
-                }
-                connection_count += input.connections.len();
+    /// Remove a node that sits on a single wire and reconnect its neighbours.
+    ///
+    /// The node must have exactly one incoming and one outgoing value
+    /// connection. After removal, its upstream source is connected to its
+    /// downstream target (inserting a conversion node if needed). On a
+    /// multi-input target the new edge keeps the removed edge's position.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The node doesn't exist
+    /// - The node doesn't have exactly one incoming and one outgoing connection
+    /// - The upstream output can't be connected to the downstream input
+    ///
+    /// The graph is left unchanged on error.
+    pub fn unsplice_node(&mut self, node_id: Id) -> Result<(), GraphError> {
+        self.unsplice_node_recorded(node_id).map(|_| ())
+    }
+
+    /// Unsplice as in [`unsplice_node`](Self::unsplice_node), returning the
+    /// removed node so the operation can be undone.
+    pub(crate) fn unsplice_node_recorded(
+        &mut self,
+        node_id: Id,
+    ) -> Result<UnspliceRecord, GraphError> {
+        self.batch(|graph| graph.unsplice_node_unbatched(node_id))
+    }
+
+    fn unsplice_node_unbatched(&mut self, node_id: Id) -> Result<UnspliceRecord, GraphError> {
+        if !self.nodes.contains_key(&node_id) {
+            return Err(GraphError::node_not_found(node_id, None));
+        }
+
+        let incoming = self.upstream_of(node_id);
+        let outgoing = self.downstream_of(node_id);
+        let (incoming, outgoing) = match (incoming.as_slice(), outgoing.as_slice()) {
+            ([incoming], [outgoing]) => (*incoming, *outgoing),
+            _ => {
+                return Err(GraphError::NotSpliceable {
+                    node_id,
+                    incoming: incoming.len(),
+                    outgoing: outgoing.len(),
+                })
             }
+        };
+
+        // Check the bridging connection up front so removal never has to be undone
+        let source_type = self.output_type(incoming.source_node, incoming.source_output)?;
+        let target_type = self.input_type(outgoing.target_node, outgoing.target_input)?;
+        if !self.accepts_type(outgoing.target_node, outgoing.target_input, source_type) {
+            return Err(GraphError::type_mismatch(
+                incoming.source_node,
+                source_type,
+                outgoing.target_node,
+                target_type,
+            ));
         }
 
-        GraphStats {
-            node_count: self.nodes.len(),
-            connection_count,
+        let target_snapshot = self
+            .input_snapshot(outgoing.target_node, outgoing.target_input)
+            .ok_or(GraphError::ConnectionNotFound { connection: outgoing })?;
+        let position = target_snapshot
+            .connections
+            .iter()
+            .position(|&edge| edge == (node_id, outgoing.source_output));
+
+        let node = self
+            .take_node(node_id)
+            .ok_or(GraphError::node_not_found(node_id, None))?;
+
+        // Types were checked and removing a node can't create a cycle
+        let inserted = match self.connect(
+            incoming.source_node,
+            incoming.source_output,
+            outgoing.target_node,
+            outgoing.target_input,
+        ) {
+            Ok(inserted) => inserted,
+            Err(e) => {
+                self.restore_input(&target_snapshot);
+                self.insert_node(node);
+                return Err(e);
+            }
+        };
+        if let Some(position) = position {
+            self.move_last_connection(outgoing.target_node, outgoing.target_input, position);
         }
+
+        Ok(UnspliceRecord {
+            node,
+            outgoing,
+            inserted,
+            target_snapshot,
+        })
     }
-}
 
-impl Default for Graph {
-    fn default() -> Self {
-        Self::new()
+    /// Reverse an unsplice made by [`unsplice_node_recorded`](Self::unsplice_node_recorded).
+    pub(crate) fn undo_unsplice(&mut self, record: UnspliceRecord) {
+        self.batch(|graph| graph.undo_unsplice_unbatched(record));
     }
-}
 
-/// Statistics about the graph
-#[derive(Debug, Clone)]
-pub struct GraphStats {
-    pub node_count: usize,
-    pub connection_count: usize,
-}
+    fn undo_unsplice_unbatched(&mut self, record: UnspliceRecord) {
+        if let Some(id) = record.inserted {
+            self.take_node(id);
+        }
+        // The node's own inputs still reference its upstream source
+        self.insert_node(record.node);
+        self.restore_input(&record.target_snapshot);
 
-/// Represents a connection between two nodes.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Connection {
-    /// The node that produces the value.
-    pub source_node: Id,
-    /// The output index on the source node.
-    pub source_output: usize,
-    /// The node that consumes the value.
-    pub target_node: Id,
-    /// The input index on the target node.
-    pub target_input: usize,
-}
+        let c = record.outgoing;
+        self.emit(GraphEvent::Connected {
+            source: c.source_node,
+            source_output: c.source_output,
+            target: c.target_node,
+            target_input: c.target_input,
+        });
+    }
 
-/// Errors that can occur during graph operations
-#[derive(Debug)]
-pub enum GraphError {
-    NodeNotFound {
-        id: Id,
-        name: Option<&'static str>,
-    },
-    InputNotFound {
-        node_id: Id,
-        input_index: usize,
-        node_name: &'static str,
-        input_count: usize,
-    },
-    OutputNotFound {
-        node_id: Id,
-        output_index: usize,
-        node_name: &'static str,
-        output_count: usize,
-    },
-    TypeMismatch {
-        source_node: Id,
-        source_type: ValueType,
-        target_node: Id,
-        target_type: ValueType,
-    },
-    CycleDetected {
-        nodes: Vec<Id>,
-    },
-    /// Trigger port not found on a node
-    TriggerNotFound {
-        node_id: Id,
-        is_output: bool,
-        index: usize,
-        available: usize,
-    },
-}
+    // =========================================================================
+    // Retargeting
+    // =========================================================================
 
-impl GraphError {
-    pub(crate) fn node_not_found(id: Id, name: Option<&'static str>) -> Self {
-        GraphError::NodeNotFound { id, name }
+    /// Move the source end of an existing value connection to another output.
+    ///
+    /// The new edge is validated (port indices, type compatibility, cycles)
+    /// before anything changes and then swapped in for the old one, so the
+    /// target input is never seen unconnected. On a multi-input target the
+    /// new edge keeps the old edge's position.
+    ///
+    /// Emits a single [`GraphEvent::ConnectionRetargeted`], plus
+    /// `ConversionInserted` if a conversion node was needed.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(None)` - Direct connection
+    /// - `Ok(Some(id))` - Connection via auto-inserted conversion node
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The connection doesn't exist
+    /// - The new source doesn't exist or its output index is out of bounds
+    /// - The new source can't be connected (incompatible types, cycle)
+    ///
+    /// The original connection is left untouched on error.
+    pub fn retarget_connection(
+        &mut self,
+        old: Connection,
+        new_source: (Id, usize),
+    ) -> Result<Option<Id>, GraphError> {
+        let new = Connection {
+            source_node: new_source.0,
+            source_output: new_source.1,
+            ..old
+        };
+        self.retarget_recorded(old, new).map(|record| record.inserted)
     }
 
-    pub(crate) fn input_not_found(
-        node_id: Id,
-        input_index: usize,
-        node_name: &'static str,
-        input_count: usize,
-    ) -> Self {
-        GraphError::InputNotFound {
-            node_id,
-            input_index,
-            node_name,
-            input_count,
+    /// Move the target end of an existing value connection to another input.
+    ///
+    /// Works like [`retarget_connection`](Self::retarget_connection). If the
+    /// new target is a single input that is already connected, its previous
+    /// connection is replaced.
+    pub fn retarget_connection_target(
+        &mut self,
+        old: Connection,
+        new_target: (Id, usize),
+    ) -> Result<Option<Id>, GraphError> {
+        let new = Connection {
+            target_node: new_target.0,
+            target_input: new_target.1,
+            ..old
+        };
+        self.retarget_recorded(old, new).map(|record| record.inserted)
+    }
+
+    /// Replace the edge `old` with `new`, returning what is needed to undo it.
+    pub(crate) fn retarget_recorded(
+        &mut self,
+        old: Connection,
+        new: Connection,
+    ) -> Result<RetargetRecord, GraphError> {
+        self.batch(|graph| graph.retarget_unbatched(old, new))
+    }
+
+    fn retarget_unbatched(
+        &mut self,
+        old: Connection,
+        new: Connection,
+    ) -> Result<RetargetRecord, GraphError> {
+        let edge = (old.source_node, old.source_output);
+        let old_snapshot = self
+            .input_snapshot(old.target_node, old.target_input)
+            .filter(|s| s.connection == Some(edge) || s.connections.contains(&edge))
+            .ok_or(GraphError::ConnectionNotFound { connection: old })?;
+        self.validate_connection(&new)?;
+
+        // The moved wire keeps its muted state
+        let muted = self
+            .nodes
+            .get(&old.target_node)
+            .and_then(|node| node.operator.inputs().get(old.target_input))
+            .and_then(|input| Some(input.is_muted(edge_slot(input, edge, old.muted)?)))
+            .unwrap_or(false);
+        let (old, new) = (Connection { muted, ..old }, Connection { muted, ..new });
+
+        let same_input =
+            new.target_node == old.target_node && new.target_input == old.target_input;
+        let new_snapshot = if same_input {
+            None
+        } else {
+            self.input_snapshot(new.target_node, new.target_input)
+        };
+
+        let event_mark = self.pending_events.len();
+        let existing: HashSet<Id> = self.nodes.keys().copied().collect();
+        let position = self
+            .detach_edge(&old)
+            .ok_or(GraphError::ConnectionNotFound { connection: old })?;
+
+        let inserted = match self.connect(
+            new.source_node,
+            new.source_output,
+            new.target_node,
+            new.target_input,
+        ) {
+            Ok(inserted) => inserted,
+            Err(e) => {
+                // Not expected after validation, but never leave a half-made edit
+                let added: Vec<Id> = self
+                    .nodes
+                    .keys()
+                    .filter(|id| !existing.contains(id))
+                    .copied()
+                    .collect();
+                for id in added {
+                    self.take_node(id);
+                }
+                if let Some(snapshot) = &new_snapshot {
+                    self.restore_input(snapshot);
+                }
+                self.restore_input(&old_snapshot);
+                self.pending_events.truncate(event_mark);
+                return Err(e);
+            }
+        };
+        if let (true, Some(position)) = (same_input, position) {
+            self.move_last_connection(new.target_node, new.target_input, position);
+        }
+        if muted {
+            let source = inserted.map_or((new.source_node, new.source_output), |id| (id, 0));
+            let input = self
+                .nodes
+                .get_mut(&new.target_node)
+                .and_then(|node| node.operator.inputs_mut().get_mut(new.target_input));
+            if let Some(input) = input {
+                if let Some(slot) = edge_slot(input, source, false) {
+                    input.set_muted(slot, true);
+                }
+            }
         }
+
+        // One retarget event stands in for the individual Connected events
+        let emitted = self.pending_events.split_off(event_mark);
+        self.pending_events.extend(
+            emitted
+                .into_iter()
+                .filter(|e| !matches!(e, GraphEvent::Connected { .. })),
+        );
+        self.emit(GraphEvent::ConnectionRetargeted { old, new });
+
+        Ok(RetargetRecord {
+            old,
+            new,
+            inserted,
+            old_snapshot,
+            new_snapshot,
+        })
     }
 
-    pub(crate) fn output_not_found(
-        node_id: Id,
-        output_index: usize,
-        node_name: &'static str,
-        output_count: usize,
-    ) -> Self {
-        GraphError::OutputNotFound {
-            node_id,
-            output_index,
-            node_name,
-            output_count,
+    /// Reverse a retarget made by [`retarget_recorded`](Self::retarget_recorded).
+    pub(crate) fn undo_retarget(&mut self, record: &RetargetRecord) {
+        self.batch(|graph| graph.undo_retarget_unbatched(record));
+    }
+
+    fn undo_retarget_unbatched(&mut self, record: &RetargetRecord) {
+        if let Some(id) = record.inserted {
+            self.take_node(id);
         }
+        if let Some(snapshot) = &record.new_snapshot {
+            self.restore_input(snapshot);
+        }
+        self.restore_input(&record.old_snapshot);
+
+        self.emit(GraphEvent::ConnectionRetargeted {
+            old: record.new,
+            new: record.old,
+        });
     }
 
-    pub(crate) fn type_mismatch(
-        source_node: Id,
-        source_type: ValueType,
-        target_node: Id,
-        target_type: ValueType,
-    ) -> Self {
-        GraphError::TypeMismatch {
-            source_node,
-            source_type,
-            target_node,
-            target_type,
+    /// Whether [`connect`](Self::connect) takes an output of `source_type` on
+    /// an input, directly or through a conversion node
+    fn accepts_type(&self, target_node: Id, target_input: usize, source_type: ValueType) -> bool {
+        self.nodes
+            .get(&target_node)
+            .and_then(|node| node.operator.inputs().get(target_input))
+            .is_some_and(|input| {
+                input.connects_directly(source_type) || source_type.can_coerce_to(input.value_type)
+            })
+    }
+
+    /// Check that [`connect`](Self::connect) would accept `connection`,
+    /// without changing the graph.
+    fn validate_connection(&self, connection: &Connection) -> Result<(), GraphError> {
+        let source_type = self.output_type(connection.source_node, connection.source_output)?;
+        let target_type = self.input_type(connection.target_node, connection.target_input)?;
+        if !self.accepts_type(connection.target_node, connection.target_input, source_type) {
+            return Err(GraphError::type_mismatch(
+                connection.source_node,
+                source_type,
+                connection.target_node,
+                target_type,
+            ));
+        }
+
+        // The new edge closes a cycle if its source already depends on its target
+        let mut visited = HashSet::new();
+        if let Some(nodes) =
+            self.dependency_path(connection.source_node, connection.target_node, &mut visited)
+        {
+            return Err(GraphError::CycleDetected { nodes });
         }
+        Ok(())
     }
-}
 
-impl std::fmt::Display for GraphError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            GraphError::NodeNotFound { id, name } => {
-                if let Some(name) = name {
-                    write!(f, "Node '{}' ({}) not found", name, id)
-                } else {
-                    write!(f, "Node {} not found", id)
+    /// Path of value connections from `to` down to `from`, if `from` depends
+    /// on `to` (a node depends on itself).
+    fn dependency_path(&self, from: Id, to: Id, visited: &mut HashSet<Id>) -> Option<Vec<Id>> {
+        if from == to {
+            return Some(vec![to]);
+        }
+        if !visited.insert(from) {
+            return None;
+        }
+        for input in self.nodes.get(&from)?.operator.inputs() {
+            for &(dep_id, _) in input.connection.iter().chain(&input.connections) {
+                if let Some(mut path) = self.dependency_path(dep_id, to, visited) {
+                    path.push(from);
+                    return Some(path);
                 }
             }
-            GraphError::InputNotFound {
-                node_id,
-                input_index,
-                node_name,
-                input_count,
-            } => {
-                write!(
-                    f,
-                    "Input index {} not found on '{}' ({}). Node has {} input(s).",
-                    input_index, node_name, node_id, input_count
-                )
+        }
+        None
+    }
+
+    /// Re-insert a node previously taken with [`take_node`](Self::take_node).
+    fn insert_node(&mut self, mut node: Node) {
+        let id = node.operator.id();
+        node.operator.on_added_to_graph();
+        self.nodes.insert(id, node);
+        self.index_node(id);
+        self.mark_order_dirty();
+        self.emit(GraphEvent::NodeAdded { id });
+    }
+
+    /// Value type of a node's output port.
+    fn output_type(&self, node_id: Id, output: usize) -> Result<ValueType, GraphError> {
+        let node = self
+            .nodes
+            .get(&node_id)
+            .ok_or(GraphError::node_not_found(node_id, None))?;
+        let outputs = node.operator.outputs();
+        outputs.get(output).map(|o| o.value_type).ok_or_else(|| {
+            GraphError::output_not_found(node_id, output, node.operator.name(), outputs.len())
+        })
+    }
+
+    /// Value type of a node's input port.
+    fn input_type(&self, node_id: Id, input: usize) -> Result<ValueType, GraphError> {
+        let node = self
+            .nodes
+            .get(&node_id)
+            .ok_or(GraphError::node_not_found(node_id, None))?;
+        let inputs = node.operator.inputs();
+        inputs.get(input).map(|i| i.value_type).ok_or_else(|| {
+            GraphError::input_not_found(node_id, input, node.operator.name(), inputs.len())
+        })
+    }
+
+    /// Capture the connection state of an input port.
+    pub(crate) fn input_snapshot(&self, node_id: Id, input_index: usize) -> Option<InputSnapshot> {
+        let input = self.nodes.get(&node_id)?.operator.inputs().get(input_index)?;
+        Some(InputSnapshot {
+            node_id,
+            input_index,
+            connection: input.connection,
+            connections: input.connections.clone(),
+            muted: input.muted.clone(),
+        })
+    }
+
+    /// Restore an input port to a previously captured connection state.
+    fn restore_input(&mut self, snapshot: &InputSnapshot) {
+        let previous = self.input_sources(snapshot.node_id, snapshot.input_index);
+        let Some(node) = self.nodes.get_mut(&snapshot.node_id) else {
+            return;
+        };
+        if let Some(input) = node.operator.inputs_mut().get_mut(snapshot.input_index) {
+            let changed = input.connection != snapshot.connection
+                || input.connections != snapshot.connections;
+            input.connection = snapshot.connection;
+            input.connections = snapshot.connections.clone();
+            input.muted = snapshot.muted.clone();
+            if changed {
+                let connected = input.is_connected();
+                node.operator
+                    .on_connection_changed(snapshot.input_index, connected);
             }
-            GraphError::OutputNotFound {
-                node_id,
-                output_index,
-                node_name,
-                output_count,
-            } => {
-                write!(
-                    f,
-                    "Output index {} not found on '{}' ({}). Node has {} output(s).",
-                    output_index, node_name, node_id, output_count
-                )
+        }
+        self.reindex_input(snapshot.node_id, snapshot.input_index, &previous);
+        self.revalidate_inputs(snapshot.node_id);
+        self.invalidate_cache_for_node(snapshot.node_id);
+        self.mark_order_dirty();
+    }
+
+    /// Reverse a [`connect`](Self::connect) by putting the target input back
+    /// as captured in `snapshot`, removing the conversion node it inserted.
+    ///
+    /// Unlike [`disconnect`](Self::disconnect), other edges on a multi-input
+    /// are kept in place.
+    pub(crate) fn undo_connect(&mut self, snapshot: &InputSnapshot, conversion: Option<Id>) {
+        self.batch(|graph| {
+            if let Some(id) = conversion {
+                graph.take_node(id);
             }
-            GraphError::TypeMismatch {
-                source_node,
-                source_type,
-                target_node,
-                target_type,
-            } => {
-                write!(
-                    f,
-                    "Type mismatch: cannot connect {} output ({}) to {} input ({})",
-                    source_type, source_node, target_type, target_node
-                )
+            let (target, target_input) = (snapshot.node_id, snapshot.input_index);
+            let mut removed = graph.input_sources(target, target_input);
+            graph.restore_input(snapshot);
+            let mut added = Vec::new();
+            for source in graph.input_sources(target, target_input) {
+                match removed.iter().position(|&s| s == source) {
+                    Some(i) => drop(removed.remove(i)),
+                    None => added.push(source),
+                }
             }
-            GraphError::CycleDetected { nodes } => {
-                write!(f, "Cycle detected in graph involving {} node(s)", nodes.len())
+            for (source, source_output) in removed {
+                graph.emit(GraphEvent::Disconnected {
+                    source,
+                    source_output,
+                    target,
+                    target_input,
+                });
             }
-            GraphError::TriggerNotFound {
-                node_id,
-                is_output,
-                index,
-                available,
-            } => {
-                let port_type = if *is_output { "output" } else { "input" };
-                write!(
-                    f,
-                    "Trigger {} index {} not found on node {}. Node has {} trigger {}(s).",
-                    port_type, index, node_id, available, port_type
-                )
+            for (source, source_output) in added {
+                graph.emit(GraphEvent::Connected {
+                    source,
+                    source_output,
+                    target,
+                    target_input,
+                });
+            }
+        });
+    }
+
+    /// Remove one value edge without touching other edges on the same input.
+    ///
+    /// Returns `None` if the edge doesn't exist, otherwise the edge's position
+    /// among the input's multi-input connections (`None` for single inputs).
+    fn detach_edge(&mut self, connection: &Connection) -> Option<Option<usize>> {
+        let edge = (connection.source_node, connection.source_output);
+        let previous = self.input_sources(connection.target_node, connection.target_input);
+        let input = self
+            .nodes
+            .get_mut(&connection.target_node)?
+            .operator
+            .inputs_mut()
+            .get_mut(connection.target_input)?;
+
+        let position = if input.connection == Some(edge) {
+            input.connection = None;
+            input.muted.clear();
+            None
+        } else {
+            let position = edge_slot(input, edge, connection.muted)?;
+            input.disconnect_at(position);
+            Some(position)
+        };
+
+        self.reindex_input(connection.target_node, connection.target_input, &previous);
+        self.notify_connection_changed(connection.target_node, connection.target_input);
+        self.invalidate_cache_for_node(connection.target_node);
+        self.mark_order_dirty();
+        Some(position)
+    }
+
+    /// Call a node's [`on_connection_changed`](Operator::on_connection_changed)
+    /// hook with the input's current connection state.
+    fn notify_connection_changed(&mut self, node_id: Id, input_index: usize) {
+        let Some(node) = self.nodes.get_mut(&node_id) else {
+            return;
+        };
+        let connected = node
+            .operator
+            .inputs()
+            .get(input_index)
+            .is_some_and(|input| input.is_connected());
+        node.operator.on_connection_changed(input_index, connected);
+        self.revalidate_inputs(node_id);
+    }
+
+    /// Move the most recently added multi-input connection to `position`.
+    fn move_last_connection(&mut self, node_id: Id, input_index: usize, position: usize) {
+        let Some(input) = self
+            .nodes
+            .get_mut(&node_id)
+            .and_then(|n| n.operator.inputs_mut().get_mut(input_index))
+        else {
+            return;
+        };
+        if let Some(last) = input.connections.len().checked_sub(1) {
+            input.move_connection(last, position);
+        }
+    }
+
+    // =========================================================================
+    // Swapping Inputs
+    // =========================================================================
+
+    /// Exchange everything about two inputs of a node.
+    ///
+    /// Connections (including multi-input connection lists and their muted
+    /// state), defaults, context bindings and per-instance overrides trade
+    /// places, so swapping A and B of a Subtract negates its result. Either
+    /// side may be unconnected: its default moves across while the other
+    /// side's connection takes its place. Swapping an input with itself does
+    /// nothing.
+    ///
+    /// Emits a single [`GraphEvent::InputsSwapped`]. The node and everything
+    /// downstream of it are recomputed on the next evaluation.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - The node or either input doesn't exist
+    /// - The ports' types differ and don't convert both ways, or only one of
+    ///   them is a multi-input ([`GraphError::IncompatibleInputs`])
+    /// - A connection doesn't connect directly to the port it would move to
+    ///
+    /// Nothing changes on error.
+    pub fn swap_inputs(
+        &mut self,
+        node_id: Id,
+        input_a: usize,
+        input_b: usize,
+    ) -> Result<(), GraphError> {
+        let node = self
+            .nodes
+            .get(&node_id)
+            .ok_or(GraphError::node_not_found(node_id, None))?;
+        let inputs = node.operator.inputs();
+        for index in [input_a, input_b] {
+            if index >= inputs.len() {
+                return Err(GraphError::input_not_found(
+                    node_id,
+                    index,
+                    node.operator.name(),
+                    inputs.len(),
+                ));
+            }
+        }
+        if input_a == input_b {
+            return Ok(());
+        }
+        let (a, b) = (&inputs[input_a], &inputs[input_b]);
+        let incompatible = GraphError::IncompatibleInputs {
+            node_id,
+            input_a,
+            input_b,
+        };
+        let convertible = a.value_type == b.value_type
+            || (a.value_type.can_coerce_to(b.value_type) && b.value_type.can_coerce_to(a.value_type));
+        if !convertible || a.is_multi_input != b.is_multi_input {
+            return Err(incompatible);
+        }
+        // Moved connections get no conversion node, so they must fit as they are
+        for (from, to) in [(a, b), (b, a)] {
+            for (source, output) in port_sources(from) {
+                let source_type = self.output_type(source, output)?;
+                if !to.connects_directly(source_type) {
+                    return Err(GraphError::type_mismatch(
+                        source,
+                        source_type,
+                        node_id,
+                        to.value_type,
+                    ));
+                }
+            }
+        }
+        let (Some(default_a), Some(default_b)) = (
+            b.default.coerce_to(a.value_type),
+            a.default.coerce_to(b.value_type),
+        ) else {
+            return Err(incompatible);
+        };
+        let reconnected = a.is_connected() != b.is_connected();
+        let previous_a = self.input_sources(node_id, input_a);
+        let previous_b = self.input_sources(node_id, input_b);
+
+        self.batch(|graph| {
+            if let Some(node) = graph.nodes.get_mut(&node_id) {
+                node.swap_inputs(input_a, input_b, (default_a, default_b));
+                for output in node.operator.outputs_mut() {
+                    output.mark_dirty();
+                }
+            }
+            graph.reindex_input(node_id, input_a, &previous_a);
+            graph.reindex_input(node_id, input_b, &previous_b);
+            graph.invalidate_downstream(node_id);
+            graph.mark_order_dirty();
+            graph.emit(GraphEvent::InputsSwapped {
+                node: node_id,
+                input_a,
+                input_b,
+            });
+            if reconnected {
+                graph.notify_connection_changed(node_id, input_a);
+                graph.notify_connection_changed(node_id, input_b);
+            } else {
+                graph.revalidate_inputs(node_id);
+            }
+        });
+        Ok(())
+    }
+
+    // =========================================================================
+    // Trigger Connections
+    // =========================================================================
+
+    /// Connect a trigger output to a trigger input.
+    ///
+    /// Unlike value connections, trigger connections don't carry data - they
+    /// signal "execute now" to the target operator.
+    ///
+    /// # Arguments
+    ///
+    /// * `source_node` - Node emitting the trigger
+    /// * `source_output` - Index of the trigger output on the source
+    /// * `target_node` - Node receiving the trigger
+    /// * `target_input` - Index of the trigger input on the target
+    ///
+    /// # Errors
+    ///
+    /// Returns error if:
+    /// - Source or target node doesn't exist
+    /// - Trigger output or input index is out of bounds
+    pub fn connect_trigger(
+        &mut self,
+        source_node: Id,
+        source_output: usize,
+        target_node: Id,
+        target_input: usize,
+    ) -> Result<(), GraphError> {
+        // Verify source node and trigger output exist
+        {
+            let source = self
+                .nodes
+                .get(&source_node)
+                .ok_or(GraphError::NodeNotFound { id: source_node, name: None })?;
+
+            let trigger_outputs = source.operator.trigger_outputs();
+            if source_output >= trigger_outputs.len() {
+                return Err(GraphError::TriggerNotFound {
+                    node_id: source_node,
+                    is_output: true,
+                    index: source_output,
+                    available: trigger_outputs.len(),
+                });
+            }
+        }
+
+        // Verify target node and trigger input exist, then connect
+        {
+            let target = self
+                .nodes
+                .get_mut(&target_node)
+                .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
+
+            let trigger_input_count = target.operator.trigger_inputs().len();
+            if target_input >= trigger_input_count {
+                return Err(GraphError::TriggerNotFound {
+                    node_id: target_node,
+                    is_output: false,
+                    index: target_input,
+                    available: trigger_input_count,
+                });
+            }
+
+            // Connect the target's trigger input
+            target.operator.trigger_inputs_mut()[target_input].connect(source_node, source_output);
+        }
+
+        // Add connection to source's trigger output
+        {
+            let source = self
+                .nodes
+                .get_mut(&source_node)
+                .expect("Source node verified above");
+
+            source.operator.trigger_outputs_mut()[source_output].connect(target_node, target_input);
+        }
+
+        // Emit event
+        self.emit(GraphEvent::TriggerConnected {
+            source: source_node,
+            source_output,
+            target: target_node,
+            target_input,
+        });
+
+        Ok(())
+    }
+
+    /// Disconnect a trigger input from its source.
+    ///
+    /// # Arguments
+    ///
+    /// * `target_node` - Node with the trigger input to disconnect
+    /// * `target_input` - Index of the trigger input
+    ///
+    /// # Returns
+    ///
+    /// The previous connection (source_node, source_output) if there was one.
+    pub fn disconnect_trigger(
+        &mut self,
+        target_node: Id,
+        target_input: usize,
+    ) -> Result<Option<(Id, usize)>, GraphError> {
+        let prev_connection;
+
+        // Get the current connection and disconnect target's trigger input
+        {
+            let target = self
+                .nodes
+                .get_mut(&target_node)
+                .ok_or(GraphError::NodeNotFound { id: target_node, name: None })?;
+
+            let trigger_input_count = target.operator.trigger_inputs().len();
+            if target_input >= trigger_input_count {
+                return Err(GraphError::TriggerNotFound {
+                    node_id: target_node,
+                    is_output: false,
+                    index: target_input,
+                    available: trigger_input_count,
+                });
+            }
+
+            prev_connection = target.operator.trigger_inputs()[target_input].connection;
+            target.operator.trigger_inputs_mut()[target_input].disconnect();
+        }
+
+        // Remove connection from source's trigger output
+        if let Some((source_node, source_output)) = prev_connection {
+            if let Some(source) = self.nodes.get_mut(&source_node) {
+                source.operator.trigger_outputs_mut()[source_output]
+                    .disconnect(target_node, target_input);
+            }
+
+            // Emit event
+            self.emit(GraphEvent::TriggerDisconnected {
+                source: source_node,
+                source_output,
+                target: target_node,
+                target_input,
+            });
+        }
+
+        Ok(prev_connection)
+    }
+
+    /// Iterate over all trigger connections in the graph.
+    ///
+    /// Edges are read from the source side; ends that no longer exist are
+    /// skipped (see [`validate`](Self::validate)).
+    pub fn trigger_connections(&self) -> impl Iterator<Item = TriggerConnection> + '_ {
+        self.nodes.keys().flat_map(|&id| self.trigger_downstream_of(id))
+    }
+
+    /// Trigger connections leaving a node's trigger outputs.
+    pub fn trigger_downstream_of(&self, id: Id) -> Vec<TriggerConnection> {
+        let Some(node) = self.nodes.get(&id) else {
+            return Vec::new();
+        };
+        node.operator
+            .trigger_outputs()
+            .iter()
+            .enumerate()
+            .flat_map(|(output_idx, output)| {
+                output.connections.iter().filter_map(move |&(target, input_idx)| {
+                    self.trigger_connection(id, output_idx, target, input_idx)
+                })
+            })
+            .collect()
+    }
+
+    /// Trigger connections arriving at a node's trigger inputs.
+    pub fn trigger_upstream_of(&self, id: Id) -> Vec<TriggerConnection> {
+        let Some(node) = self.nodes.get(&id) else {
+            return Vec::new();
+        };
+        node.operator
+            .trigger_inputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(input_idx, input)| {
+                let (source, output_idx) = input.connection?;
+                self.trigger_connection(source, output_idx, id, input_idx)
+            })
+            .collect()
+    }
+
+    /// Describe a trigger edge, if both of its ports exist.
+    fn trigger_connection(
+        &self,
+        source_node: Id,
+        source_output: usize,
+        target_node: Id,
+        target_input: usize,
+    ) -> Option<TriggerConnection> {
+        let source = self.nodes.get(&source_node)?.operator.trigger_outputs().get(source_output)?;
+        let target = self.nodes.get(&target_node)?.operator.trigger_inputs().get(target_input)?;
+        Some(TriggerConnection {
+            source_node,
+            source_output,
+            source_name: source.name,
+            target_node,
+            target_input,
+            target_name: target.name,
+        })
+    }
+
+    /// Fire a trigger output and propagate to all connected trigger inputs.
+    ///
+    /// This initiates push-based execution. When a trigger fires:
+    /// 1. All connected trigger inputs receive the signal
+    /// 2. Each target operator's `on_triggered()` is called
+    /// 3. Any triggers returned by `on_triggered()` are fired recursively
+    ///
+    /// # Arguments
+    ///
+    /// * `node_id` - Node whose trigger output to fire
+    /// * `trigger_output` - Index of the trigger output to fire
+    /// * `ctx` - Evaluation context for timing information
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Fire the "OnFrame" trigger from the main loop
+    /// graph.fire_trigger(main_loop_id, 0, &ctx);
+    /// ```
+    pub fn fire_trigger(&mut self, node_id: Id, trigger_output: usize, ctx: &EvalContext) {
+        // Get the targets for this trigger output
+        let targets: Vec<(Id, usize)> = {
+            let node = match self.nodes.get(&node_id) {
+                Some(n) => n,
+                None => return,
+            };
+
+            let trigger_outputs = node.operator.trigger_outputs();
+            if trigger_output >= trigger_outputs.len() {
+                return;
+            }
+
+            trigger_outputs[trigger_output].connections.clone()
+        };
+
+        // Fire each connected target
+        for (target_id, target_input) in targets {
+            self.trigger_node(target_id, target_input, ctx);
+        }
+    }
+
+    /// Internal: Trigger a specific node's trigger input and handle cascading triggers.
+    fn trigger_node(&mut self, node_id: Id, trigger_input: usize, ctx: &EvalContext) {
+        // Create the input resolver closure
+        let get_input_value = |source_id: Id, output_idx: usize| -> Value {
+            // Try to get from cache first
+            let cache_key = CacheKey {
+                node_id: source_id,
+                call_context: ctx.call_context,
+            };
+
+            if let Some(cached) = self.value_cache.get(&cache_key) {
+                if let Some(value) = cached.outputs.get(output_idx) {
+                    return (**value).clone();
+                }
+            }
+
+            // Not cached - return a default value
+            // In practice, trigger-based operators should either:
+            // 1. Use inputs that are already cached from prior evaluation
+            // 2. Not depend on value inputs for their triggered behavior
+            Value::Float(0.0)
+        };
+
+        // Call the operator's on_triggered method
+        let triggers_to_fire: Vec<usize> = {
+            let node = match self.nodes.get_mut(&node_id) {
+                Some(n) => n,
+                None => return,
+            };
+
+            node.operator.on_triggered(trigger_input, ctx, &get_input_value)
+        };
+
+        // Fire any cascading triggers
+        for output_idx in triggers_to_fire {
+            self.fire_trigger(node_id, output_idx, ctx);
+        }
+    }
+
+    /// Check for cycles in the graph using DFS
+    fn check_for_cycles(&self) -> Result<(), Vec<Id>> {
+        let mut visited = HashSet::new();
+        let mut rec_stack = HashSet::new();
+        let mut cycle_nodes = Vec::new();
+
+        for &node_id in self.nodes.keys() {
+            if self.has_cycle_dfs(node_id, &mut visited, &mut rec_stack, &mut cycle_nodes) {
+                return Err(cycle_nodes);
+            }
+        }
+        Ok(())
+    }
+
+    fn has_cycle_dfs(
+        &self,
+        node_id: Id,
+        visited: &mut HashSet<Id>,
+        rec_stack: &mut HashSet<Id>,
+        cycle_nodes: &mut Vec<Id>,
+    ) -> bool {
+        if rec_stack.contains(&node_id) {
+            cycle_nodes.push(node_id);
+            return true;
+        }
+        if visited.contains(&node_id) {
+            return false;
+        }
+
+        visited.insert(node_id);
+        rec_stack.insert(node_id);
+
+        if let Some(node) = self.nodes.get(&node_id) {
+            for input in node.operator.inputs() {
+                // Check single connection
+                if let Some((dep_id, _)) = input.connection {
+                    if self.has_cycle_dfs(dep_id, visited, rec_stack, cycle_nodes) {
+                        cycle_nodes.push(node_id);
+                        return true;
+                    }
+                }
+                // Check multi-input connections
+                for &(dep_id, _) in &input.connections {
+                    if self.has_cycle_dfs(dep_id, visited, rec_stack, cycle_nodes) {
+                        cycle_nodes.push(node_id);
+                        return true;
+                    }
+                }
+            }
+        }
+
+        rec_stack.remove(&node_id);
+        false
+    }
+
+    /// Compute topological order for evaluation using Kahn's algorithm
+    pub(crate) fn compute_order(&mut self) -> Result<(), GraphError> {
+        if !self.order_dirty {
+            return Ok(());
+        }
+
+        // Kahn's algorithm; among ready nodes, the most expensive goes first,
+        // then the earliest added, so the order doesn't depend on map iteration
+        let mut pending: HashMap<Id, usize> = HashMap::with_capacity(self.nodes.len());
+        // Source -> (dependent, whether the edge is muted)
+        let mut dependents: HashMap<Id, Vec<(Id, bool)>> = HashMap::new();
+        for (&id, node) in &self.nodes {
+            let mut count = 0;
+            for input in node.operator.inputs() {
+                for (slot, (source, _)) in port_sources(input).enumerate() {
+                    dependents.entry(source).or_default().push((id, input.is_muted(slot)));
+                    count += 1;
+                }
+            }
+            pending.insert(id, count);
+        }
+
+        let priority = |id: Id| {
+            let node = &self.nodes[&id];
+            (node.operator.cost_hint(), Reverse(node.sequence), *id.as_uuid())
+        };
+        let mut ready: BinaryHeap<_> = pending
+            .iter()
+            .filter(|&(_, &count)| count == 0)
+            .map(|(&id, _)| priority(id))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some((_, _, uuid)) = ready.pop() {
+            let id = Id::from_uuid(uuid);
+            order.push(id);
+            for &(dependent, _) in dependents.get(&id).into_iter().flatten() {
+                let count = pending.get_mut(&dependent).expect("dependent is a node");
+                *count -= 1;
+                if *count == 0 {
+                    ready.push(priority(dependent));
+                }
+            }
+        }
+
+        if order.len() < self.nodes.len() {
+            let placed: HashSet<Id> = order.iter().copied().collect();
+            let mut remaining: Vec<Id> =
+                self.nodes.keys().copied().filter(|id| !placed.contains(id)).collect();
+            remaining.sort_by_key(|&id| self.nodes[&id].sequence);
+            return Err(GraphError::CycleDetected { nodes: remaining });
+        }
+
+        // Consumers come later in the order, so walking it backwards settles
+        // them before the nodes feeding them
+        self.muted_branches.clear();
+        for &id in order.iter().rev() {
+            let Some(edges) = dependents.get(&id) else {
+                continue;
+            };
+            if edges.iter().all(|&(dependent, muted)| muted || self.muted_branches.contains(&dependent)) {
+                self.muted_branches.insert(id);
+            }
+        }
+
+        self.eval_order = order;
+        self.order_dirty = false;
+
+        // Emit event when order is recomputed
+        self.emit(GraphEvent::OrderRecomputed);
+
+        Ok(())
+    }
+
+    /// Check if a node needs evaluation based on its dirty state and dependencies
+    fn needs_evaluation(
+        &self,
+        node_id: Id,
+        ctx: &EvalContext,
+        computed_nodes: &HashSet<Id>,
+        demand: &OutputDemand,
+    ) -> bool {
+        self.evaluation_reason(node_id, ctx, computed_nodes, demand).computes()
+    }
+
+    /// Why a node is computed, or served from the cache, in this evaluation
+    fn evaluation_reason(
+        &self,
+        node_id: Id,
+        ctx: &EvalContext,
+        computed_nodes: &HashSet<Id>,
+        demand: &OutputDemand,
+    ) -> TraceReason {
+        let node = match self.nodes.get(&node_id) {
+            Some(n) => n,
+            None => return TraceReason::UpToDate,
+        };
+
+        // Create cache key with call context
+        let cache_key = CacheKey {
+            node_id,
+            call_context: ctx.call_context,
+        };
+
+        // If node has never been computed (not in cache for this context), it needs evaluation
+        let Some(entry) = self.value_cache.get(&cache_key) else {
+            return TraceReason::NeverComputed;
+        };
+
+        // Frozen nodes keep serving their pinned outputs, quarantined ones
+        // whatever they had before panicking
+        if !node.frozen_outputs.is_empty() {
+            return TraceReason::Frozen;
+        }
+        if self.quarantine.contains_key(&node_id) {
+            return TraceReason::Quarantined;
+        }
+
+        // Explicit update requests bypass throttling
+        if node.update_requested {
+            return TraceReason::UpdateRequested;
+        }
+
+        // Throttled nodes keep their cached value until due, even if time-varying
+        if !node.update_rate.is_due(node.last_update, ctx.frame, ctx.time) {
+            return TraceReason::Throttled;
+        }
+
+        // Changes that arrived while the node was an inactive branch
+        if self.missed_updates.contains(&cache_key) {
+            return TraceReason::MissedUpdate;
+        }
+
+        // Inputs bound to context variables follow the variable
+        if node.context_bindings_changed(ctx) {
+            return TraceReason::ContextBinding;
+        }
+
+        // Time-varying operators always need to be recomputed
+        if node.operator.is_time_varying() {
+            return TraceReason::TimeVarying;
+        }
+
+        // Context-dependent operators follow the parts of the context they read
+        let dependencies = node.operator.context_dependencies();
+        if !dependencies.is_empty()
+            && entry.context_fingerprint != node.context(ctx).fingerprint(dependencies)
+        {
+            return TraceReason::ContextChanged;
+        }
+
+        // Inputs ramping toward a new default change every frame
+        if !node.input_transitions.is_empty() {
+            return TraceReason::InputTransition;
+        }
+
+        // Check if any output is dirty. Operators with independent outputs
+        // only count the outputs this evaluation reads.
+        let outputs = node.operator.outputs();
+        let dirty = if node.operator.outputs_coupled() {
+            outputs.iter().any(|o| o.is_dirty())
+        } else {
+            outputs.iter().enumerate().any(|(index, output)| {
+                let stale = output.is_dirty() || entry.valid.get(index) == Some(&false);
+                stale && self.output_demanded(demand, node_id, index)
+            })
+        };
+        if dirty {
+            return TraceReason::DirtyOutput;
+        }
+
+        // Check if any connected input comes from a node that was just
+        // computed; muted connections don't count
+        for input in node.operator.inputs() {
+            if live_sources(input).any(|(source_id, _)| computed_nodes.contains(&source_id)) {
+                return TraceReason::UpstreamRecomputed;
+            }
+        }
+
+        TraceReason::UpToDate
+    }
+
+    /// True if the evaluation behind `demand` reads output `index` of a node.
+    fn output_demanded(&self, demand: &OutputDemand, node_id: Id, index: usize) -> bool {
+        if demand.target == (node_id, index) {
+            return true;
+        }
+        let upstream = demand
+            .upstream
+            .get_or_init(|| self.upstream_closure(demand.target.0));
+        self.consumers_of(node_id, index)
+            .iter()
+            .any(|(consumer, _)| upstream.contains(consumer))
+    }
+
+    /// Evaluate the graph and return the output value of a specific node
+    ///
+    /// Untyped form of [`evaluate_ref`](Self::evaluate_ref).
+    pub fn evaluate(
+        &mut self,
+        output_node: Id,
+        output_index: usize,
+        ctx: &EvalContext,
+    ) -> Result<Value, GraphError> {
+        self.evaluate_ref(OutputRef::new(output_node, output_index), ctx)
+    }
+
+    /// Evaluate an output and read it as a Rust type.
+    ///
+    /// The value is coerced if it isn't already of `T`'s type (see
+    /// [`FromValue`]), so an `Int` output can be read as `f32`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EvalTypeError::Eval`] if evaluation fails, or
+    /// [`EvalTypeError::TypeMismatch`] naming the node, output and both types
+    /// if the value can't be read as `T`.
+    pub fn evaluate_as<T: FromValue>(
+        &mut self,
+        output_node: Id,
+        output_index: usize,
+        ctx: &EvalContext,
+    ) -> Result<T, EvalTypeError> {
+        let value = self.evaluate(output_node, output_index, ctx)?;
+        T::from_value(&value).map_err(|e| EvalTypeError::TypeMismatch {
+            node: output_node,
+            operator: self.node_name(output_node).unwrap_or("?"),
+            output: output_index,
+            actual: e.actual,
+            requested: e.requested,
+        })
+    }
+
+    /// Evaluate the graph and return the value of an output
+    ///
+    /// Nodes whose outputs only feed muted connections (see
+    /// [`set_connection_muted`](Self::set_connection_muted)) are not computed,
+    /// unless `output` belongs to one of them.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the graph contains a cycle, the output node is not
+    /// found, or composite nesting exceeds [`max_depth`](Self::max_depth).
+    pub fn evaluate_ref(&mut self, output: OutputRef, ctx: &EvalContext) -> Result<Value, GraphError> {
+        self.evaluate_with_sink(output, ctx, &mut NoTrace)
+    }
+
+    /// Evaluate an output like [`evaluate`](Self::evaluate), recording what
+    /// happened to every node.
+    ///
+    /// The trace is returned even if evaluation fails, covering the nodes
+    /// visited before the failure. See the [`trace`](crate::trace) module.
+    pub fn trace_evaluate(
+        &mut self,
+        output_node: Id,
+        output_index: usize,
+        ctx: &EvalContext,
+    ) -> (Result<Value, GraphError>, EvalTrace) {
+        let output = OutputRef::new(output_node, output_index);
+        let mut tracer = Tracer::new(output, ctx);
+        let result = self.evaluate_with_sink(output, ctx, &mut tracer);
+        (result, tracer.finish())
+    }
+
+    /// Shared body of [`evaluate_ref`](Self::evaluate_ref) and
+    /// [`trace_evaluate`](Self::trace_evaluate).
+    fn evaluate_with_sink(
+        &mut self,
+        output: OutputRef,
+        ctx: &EvalContext,
+        sink: &mut impl TraceSink,
+    ) -> Result<Value, GraphError> {
+        let (output_node, output_index) = output.into();
+        self.compute_order()?;
+        self.enter_evaluation(ctx);
+        let ctx = self.limited_context(ctx);
+        let ctx = ctx.as_ref();
+
+        // Track which nodes were computed this evaluation (HashSet for O(1) lookups)
+        let mut computed_nodes: HashSet<Id> = HashSet::new();
+
+        // Clone eval_order to avoid borrow issues
+        let eval_order = self.eval_order.clone();
+        let demand = OutputDemand::new(output_node, output_index);
+        let skip_muted = !self.muted_branches.contains(&output_node);
+        let inactive = self.inactive_branches(&eval_order, ctx, &demand);
+        let skip_inactive = !inactive.contains(&output_node);
+
+        for &node_id in &eval_order {
+            let reason = if skip_muted && self.muted_branches.contains(&node_id) {
+                TraceReason::MutedBranch
+            } else if skip_inactive && inactive.contains(&node_id) {
+                self.skip_inactive(node_id, ctx, &computed_nodes, &demand);
+                TraceReason::InactiveBranch
+            } else {
+                self.evaluation_reason(node_id, ctx, &computed_nodes, &demand)
+            };
+            sink.begin_node(self, node_id, ctx, reason);
+
+            if !reason.computes() {
+                self.touch_cache_entry(node_id, ctx);
+                sink.end_node(self, node_id, ctx, false);
+                continue;
+            }
+
+            let computed = self.compute_node(node_id, ctx);
+            sink.end_node(self, node_id, ctx, computed);
+            if computed {
+                computed_nodes.insert(node_id);
+                self.record_frame_compute(node_id);
+                self.check_depth_limit(node_id)?;
+            }
+        }
+
+        self.complete_evaluation(ctx);
+        self.cached_result(output_node, output_index, ctx.call_context)
+    }
+
+    /// Evaluate the graph within a time budget, resuming across calls.
+    ///
+    /// Nodes are computed in topological order until `budget` is spent (at
+    /// least one node is computed per call, so evaluation always progresses).
+    /// If nodes remain, returns [`EvalProgress::Partial`] and keeps the
+    /// intermediate results; the next call for the same output continues from
+    /// there. Returns [`EvalProgress::Complete`] with the output value once
+    /// every node has been visited.
+    ///
+    /// Nodes computed in an earlier slice are not recomputed when resuming,
+    /// even if time-varying. If the graph structure or cached values change
+    /// between slices, or a different output or call context is requested,
+    /// the pending evaluation is discarded and starts over.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the graph contains a cycle, composite nesting exceeds
+    /// [`max_depth`](Self::max_depth) (the pending evaluation is discarded),
+    /// or the output node is not found once evaluation completes.
+    pub fn evaluate_budgeted(
+        &mut self,
+        output_node: Id,
+        output_index: usize,
+        ctx: &EvalContext,
+        budget: Duration,
+    ) -> Result<EvalProgress, GraphError> {
+        let started = Instant::now();
+        let call_context = ctx.call_context;
+        self.enter_evaluation(ctx);
+        let ctx = self.limited_context(ctx);
+        let ctx = ctx.as_ref();
+
+        self.compute_order()?;
+        let demand = OutputDemand::new(output_node, output_index);
+        let mut state = match self.budgeted.take() {
+            Some(state)
+                if state.resumes(output_node, output_index, call_context, self.generation) =>
+            {
+                state
+            }
+            _ => BudgetedEval {
+                output_node,
+                output_index,
+                call_context,
+                generation: self.generation,
+                order: self.eval_order.clone(),
+                cursor: 0,
+                computed_nodes: HashSet::new(),
+                inactive: self.inactive_branches(&self.eval_order, ctx, &demand),
+            },
+        };
+
+        while state.cursor < state.order.len() {
+            let node_id = state.order[state.cursor];
+            state.cursor += 1;
+
+            let muted = self.muted_branches.contains(&node_id)
+                && !self.muted_branches.contains(&state.output_node);
+            let inactive = state.inactive.contains(&node_id)
+                && !state.inactive.contains(&state.output_node);
+            if inactive {
+                self.skip_inactive(node_id, ctx, &state.computed_nodes, &demand);
+            }
+            let skip = muted
+                || inactive
+                || !self.needs_evaluation(node_id, ctx, &state.computed_nodes, &demand);
+            if skip {
+                self.touch_cache_entry(node_id, ctx);
+            } else if self.compute_node(node_id, ctx) {
+                state.computed_nodes.insert(node_id);
+                self.record_frame_compute(node_id);
+                self.check_depth_limit(node_id)?;
+            }
+
+            if state.cursor < state.order.len() && started.elapsed() >= budget {
+                let progress = EvalProgress::Partial {
+                    completed: state.cursor,
+                    remaining: state.order.len() - state.cursor,
+                };
+                self.budgeted = Some(state);
+                return Ok(progress);
+            }
+        }
+
+        self.complete_evaluation(ctx);
+        let result = self.cached_result(output_node, output_index, call_context);
+        result.map(EvalProgress::Complete)
+    }
+
+    // =========================================================================
+    // Frames
+    // =========================================================================
+
+    /// Open an evaluation frame for `ctx`.
+    ///
+    /// Clears the previous frame's computed nodes and errors; evaluations
+    /// until [`end_frame`](Self::end_frame) share them. See the
+    /// [`frame`](crate::frame) module.
+    ///
+    /// # Errors
+    ///
+    /// [`GraphError::FrameAlreadyOpen`] if the current frame hasn't been
+    /// ended. It stays open.
+    pub fn begin_frame(&mut self, ctx: &EvalContext) -> Result<(), GraphError> {
+        if self.epoch.explicit {
+            return Err(GraphError::FrameAlreadyOpen {
+                frame: self.epoch.frame,
+            });
+        }
+        self.epoch = FrameEpoch::new(ctx, true);
+        Ok(())
+    }
+
+    /// Close the frame opened by [`begin_frame`](Self::begin_frame).
+    ///
+    /// Snapshots watched outputs, applies automatic cache collection and
+    /// emits [`GraphEvent::FrameCompleted`]. Returns the number of nodes
+    /// computed during the frame.
+    ///
+    /// # Errors
+    ///
+    /// [`GraphError::NoFrameOpen`] if no frame is open.
+    pub fn end_frame(&mut self) -> Result<usize, GraphError> {
+        if !self.epoch.explicit {
+            return Err(GraphError::NoFrameOpen);
+        }
+        self.finish_frame();
+        let evaluated_nodes = self.epoch.evaluated.len();
+        self.emit(GraphEvent::FrameCompleted {
+            frame: self.epoch.frame,
+            evaluated_nodes,
+        });
+        Ok(evaluated_nodes)
+    }
+
+    /// Returns true between [`begin_frame`](Self::begin_frame) and
+    /// [`end_frame`](Self::end_frame).
+    pub fn in_frame(&self) -> bool {
+        self.epoch.explicit
+    }
+
+    /// Nodes computed during the current or most recent frame.
+    pub fn frame_evaluated_nodes(&self) -> impl Iterator<Item = Id> + '_ {
+        self.epoch.evaluated.iter().copied()
+    }
+
+    /// Errors reported during the current or most recent frame, in the order
+    /// they were reported.
+    ///
+    /// Unlike [`node_errors`](Self::node_errors), errors from an earlier
+    /// evaluation in the frame are kept when the node later computes cleanly.
+    pub fn frame_errors(&self) -> &[(Id, OperatorError)] {
+        &self.epoch.errors
+    }
+
+    /// Start the implicit frame around an evaluation outside an explicit one.
+    fn enter_evaluation(&mut self, ctx: &EvalContext) {
+        if !self.epoch.explicit {
+            self.epoch = FrameEpoch::new(ctx, false);
+        }
+    }
+
+    /// Note a node computed by an evaluation in the current frame.
+    fn record_frame_compute(&mut self, node_id: Id) {
+        let errors = self.node_errors.get(&node_id).map_or(&[][..], Vec::as_slice);
+        self.epoch.record(node_id, errors);
+    }
+
+    /// Finish a completed evaluation, closing its frame if it was implicit.
+    fn complete_evaluation(&mut self, ctx: &EvalContext) {
+        self.epoch.call_context = ctx.call_context;
+        if !self.epoch.explicit {
+            self.finish_frame();
+        }
+    }
+
+    /// Per-frame work done when a frame closes.
+    fn finish_frame(&mut self) {
+        self.epoch.explicit = false;
+        let (frame, time) = (self.epoch.frame, self.epoch.time);
+        self.snapshot_watches(frame, self.epoch.call_context);
+        self.last_eval = (frame, time);
+        self.auto_gc_cache(frame);
+    }
+
+    // =========================================================================
+    // Groups
+    // =========================================================================
+
+    /// Create an empty group.
+    ///
+    /// See the [`group`](crate::group) module.
+    pub fn create_group(&mut self, name: impl Into<String>, color: [f32; 4]) -> GroupId {
+        let group = self.groups.create(name.into(), color);
+        self.emit(GraphEvent::GroupCreated { group });
+        group
+    }
+
+    /// Remove a group, returning it. Its member nodes are not affected.
+    pub fn remove_group(&mut self, group: GroupId) -> Option<Group> {
+        let removed = self.groups.remove(group)?;
+        self.emit(GraphEvent::GroupRemoved { group });
+        Some(removed)
+    }
+
+    /// Put a removed group back under its old handle (used by undo).
+    ///
+    /// Members that are no longer in the graph are dropped.
+    pub(crate) fn restore_group(&mut self, group: GroupId, mut entry: Group) {
+        entry.members.retain(|id| self.nodes.contains_key(id));
+        self.groups.restore(group, entry);
+        self.emit(GraphEvent::GroupCreated { group });
+    }
+
+    pub fn group(&self, group: GroupId) -> Option<&Group> {
+        self.groups.get(group)
+    }
+
+    /// All groups, in creation order.
+    pub fn groups(&self) -> impl Iterator<Item = (GroupId, &Group)> {
+        self.groups.iter()
+    }
+
+    /// Rename a group. Returns false if it doesn't exist.
+    pub fn rename_group(&mut self, group: GroupId, name: impl Into<String>) -> bool {
+        let Some(entry) = self.groups.get_mut(group) else {
+            return false;
+        };
+        entry.name = name.into();
+        self.emit(GraphEvent::GroupChanged { group });
+        true
+    }
+
+    /// Change a group's color. Returns false if it doesn't exist.
+    pub fn set_group_color(&mut self, group: GroupId, color: [f32; 4]) -> bool {
+        let Some(entry) = self.groups.get_mut(group) else {
+            return false;
+        };
+        entry.color = color;
+        self.emit(GraphEvent::GroupChanged { group });
+        true
+    }
+
+    /// Add a node to a group.
+    ///
+    /// Returns false if the node already was a member.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the node or group doesn't exist.
+    pub fn assign_to_group(&mut self, node: Id, group: GroupId) -> Result<bool, GraphError> {
+        if !self.nodes.contains_key(&node) {
+            return Err(GraphError::node_not_found(node, None));
+        }
+        let entry = self.groups.get_mut(group).ok_or(GraphError::GroupNotFound { group })?;
+        if entry.contains(node) {
+            return Ok(false);
+        }
+        entry.members.push(node);
+        self.emit(GraphEvent::GroupMembershipChanged { group, node, member: true });
+        Ok(true)
+    }
+
+    /// Take a node out of a group. Returns false if it wasn't a member.
+    ///
+    /// The group is kept even if it becomes empty.
+    pub fn unassign_from_group(&mut self, node: Id, group: GroupId) -> bool {
+        let Some(entry) = self.groups.get_mut(group) else {
+            return false;
+        };
+        let Some(position) = entry.members.iter().position(|&m| m == node) else {
+            return false;
+        };
+        entry.members.remove(position);
+        self.emit(GraphEvent::GroupMembershipChanged { group, node, member: false });
+        true
+    }
+
+    /// Members of a group in the order they were assigned, or `None` if the
+    /// group doesn't exist.
+    pub fn group_members(&self, group: GroupId) -> Option<&[Id]> {
+        self.groups.get(group).map(Group::members)
+    }
+
+    /// Groups a node belongs to, in creation order.
+    pub fn groups_of(&self, node: Id) -> Vec<GroupId> {
+        self.groups.groups_of(node)
+    }
+
+    /// Delete groups automatically once node removal leaves them empty.
+    ///
+    /// Off by default. Groups emptied by
+    /// [`unassign_from_group`](Self::unassign_from_group) are always kept.
+    pub fn set_remove_empty_groups(&mut self, enabled: bool) {
+        self.groups.remove_empty = enabled;
+    }
+
+    // =========================================================================
+    // Watches
+    // =========================================================================
+
+    /// Watch a node output across evaluations.
+    ///
+    /// After each completed evaluation the output's cached value is
+    /// snapshotted; [`poll_watches`](Self::poll_watches) reports it whenever
+    /// it changed since the last poll. Removing the node drops the watch.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the node or output doesn't exist.
+    pub fn watch_output(&mut self, node: Id, output: usize) -> Result<WatchId, GraphError> {
+        let op = self.get(node).ok_or_else(|| GraphError::node_not_found(node, None))?;
+        if output >= op.outputs().len() {
+            return Err(GraphError::output_not_found(node, output, op.name(), op.outputs().len()));
+        }
+        Ok(self.watches.add(node, output))
+    }
+
+    /// Stop watching an output. Returns false if the watch doesn't exist.
+    pub fn unwatch(&mut self, watch: WatchId) -> bool {
+        self.watches.remove(watch)
+    }
+
+    /// Take the watched outputs that changed since the previous poll.
+    ///
+    /// Watches whose node was removed are reported once as
+    /// [`WatchUpdate::Removed`], after all changes.
+    pub fn poll_watches(&mut self) -> Vec<WatchUpdate> {
+        self.watches.poll()
+    }
+
+    /// The latest recorded value of every watched output.
+    ///
+    /// Capture this before a simulation step and pass it to
+    /// [`blend_outputs`](Self::blend_outputs) afterwards. Watches that
+    /// haven't been evaluated yet are missing.
+    pub fn watch_values(&self) -> BTreeMap<WatchId, Value> {
+        self.watches.values()
+    }
+
+    /// Watched values interpolated between `previous` (at `alpha = 0`) and
+    /// the latest evaluation (at `alpha = 1`).
+    ///
+    /// `previous` is normally a [`watch_values`](Self::watch_values) capture
+    /// taken before the last step, and `alpha` the leftover fraction from
+    /// [`FrameStepper::alpha`](crate::FrameStepper::alpha). Numeric values
+    /// (Float, Int, vectors, Color) are interpolated linearly; anything else,
+    /// and watches missing from `previous`, report the latest value.
+    pub fn blend_outputs(
+        &self,
+        previous: &BTreeMap<WatchId, Value>,
+        alpha: f64,
+    ) -> BTreeMap<WatchId, Value> {
+        self.watches.blend(previous, alpha as f32)
+    }
+
+    /// Record watched outputs at the end of `frame`.
+    fn snapshot_watches(&mut self, frame: u64, call_context: CallContext) {
+        if self.watches.is_empty() {
+            return;
+        }
+        let cache = &self.value_cache;
+        self.watches.snapshot(frame, |node_id, output| {
+            let key = CacheKey {
+                node_id,
+                call_context,
+            };
+            cache.get(&key)?.outputs.get(output).map(|value| value.as_ref())
+        });
+    }
+
+    /// Returns true if a budgeted evaluation is waiting to be resumed.
+    pub fn has_pending_budgeted_eval(&self) -> bool {
+        self.budgeted.is_some()
+    }
+
+    // =========================================================================
+    // Preview
+    // =========================================================================
+
+    /// Set the limits applied by [`evaluate_preview`](Self::evaluate_preview).
+    pub fn set_preview_settings(&mut self, settings: PreviewSettings) {
+        self.preview = settings;
+    }
+
+    /// The limits applied by [`evaluate_preview`](Self::evaluate_preview).
+    pub fn preview_settings(&self) -> &PreviewSettings {
+        &self.preview
+    }
+
+    /// Evaluate an output for a thumbnail without disturbing the main
+    /// evaluation.
+    ///
+    /// Only nodes upstream of `output_node` are visited, in a call context
+    /// derived from `ctx` (see [`PREVIEW_CALL_INDEX`]) at the preview
+    /// resolution. Main-context cache entries, node errors, events, update
+    /// throttling and operator outputs are left as they were. Stateful
+    /// operators are not computed; their last main-context output is reused.
+    ///
+    /// Once [`max_nodes`](PreviewSettings::max_nodes) nodes have been computed
+    /// or the [`time_budget`](PreviewSettings::time_budget) is spent, returns
+    /// [`PreviewResult::Truncated`] with the best value available.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the graph contains a cycle or the output doesn't exist.
+    pub fn evaluate_preview(
+        &mut self,
+        output_node: Id,
+        output_index: usize,
+        ctx: &EvalContext,
+    ) -> Result<PreviewResult, GraphError> {
+        let started = Instant::now();
+        let op = self
+            .get(output_node)
+            .ok_or_else(|| GraphError::node_not_found(output_node, None))?;
+        if output_index >= op.outputs().len() {
+            let count = op.outputs().len();
+            return Err(GraphError::output_not_found(output_node, output_index, op.name(), count));
+        }
+        self.compute_order()?;
+
+        let main_context = ctx.call_context;
+        let mut preview_ctx = self.limited_context(ctx).with_call_context(PREVIEW_CALL_INDEX);
+        preview_ctx.resolution = self.preview.resolution;
+
+        let upstream = self.upstream_closure(output_node);
+        let order: Vec<Id> = self
+            .eval_order
+            .iter()
+            .copied()
+            .filter(|id| upstream.contains(id))
+            .collect();
+        let demand = OutputDemand {
+            target: (output_node, output_index),
+            upstream: OnceCell::from(upstream),
+        };
+        let inactive = self.inactive_branches(&order, &preview_ctx, &demand);
+
+        let mut computed_nodes: HashSet<Id> = HashSet::new();
+        let mut computed = 0;
+        let mut truncated = false;
+        for node_id in order {
+            if inactive.contains(&node_id) {
+                self.skip_inactive(node_id, &preview_ctx, &computed_nodes, &demand);
+                self.touch_cache_entry(node_id, &preview_ctx);
+                continue;
+            }
+            if !self.needs_evaluation(node_id, &preview_ctx, &computed_nodes, &demand) {
+                self.touch_cache_entry(node_id, &preview_ctx);
+                continue;
+            }
+            let stateful = self
+                .nodes
+                .get(&node_id)
+                .is_some_and(|node| node.operator.is_stateful());
+            if stateful {
+                self.reuse_main_outputs(node_id, main_context, &preview_ctx);
+            } else {
+                if computed >= self.preview.max_nodes
+                    || started.elapsed() >= self.preview.time_budget
+                {
+                    truncated = true;
+                    break;
+                }
+                self.compute_node_isolated(node_id, &preview_ctx);
+                computed += 1;
+            }
+            computed_nodes.insert(node_id);
+        }
+
+        let result = self.cached_result(output_node, output_index, preview_ctx.call_context);
+        if !truncated {
+            return result.map(PreviewResult::Complete);
+        }
+        let value = result
+            .or_else(|_| self.cached_result(output_node, output_index, main_context))
+            .unwrap_or_else(|_| self.nodes[&output_node].operator.outputs()[output_index].value.clone());
+        Ok(PreviewResult::Truncated(value))
+    }
+
+    /// Drop the cache entries of previews evaluated in `ctx`, including those
+    /// of composites evaluated inside them.
+    ///
+    /// Main-context entries are kept. Returns the number of entries removed.
+    pub fn clear_preview_cache(&mut self, ctx: &EvalContext) -> usize {
+        self.evict_call_context_subtree(ctx.call_context.child(PREVIEW_CALL_INDEX))
+    }
+
+    /// A node and every node it transitively reads from through unmuted
+    /// connections.
+    fn upstream_closure(&self, node_id: Id) -> HashSet<Id> {
+        let mut visited = HashSet::new();
+        let mut stack = vec![node_id];
+        while let Some(id) = stack.pop() {
+            if !visited.insert(id) {
+                continue;
+            }
+            if let Some(node) = self.nodes.get(&id) {
+                for input in node.operator.inputs() {
+                    stack.extend(live_sources(input).map(|(source, _)| source));
+                }
+            }
+        }
+        visited
+    }
+
+    /// Nodes of `order` whose outputs only reach inputs their consumers won't
+    /// read (see [`Operator::active_inputs`]), directly or through other such
+    /// nodes.
+    ///
+    /// Only consumers in `order` count: nodes without one are always kept.
+    fn inactive_branches(
+        &self,
+        order: &[Id],
+        ctx: &EvalContext,
+        demand: &OutputDemand,
+    ) -> HashSet<Id> {
+        // Which nodes will recompute is only worked out if an operator asks
+        // for a connected input
+        let computing = OnceCell::new();
+        let will_compute = || {
+            computing.get_or_init(|| {
+                let mut computing = HashSet::new();
+                for &id in order {
+                    if self.evaluation_reason(id, ctx, &computing, demand).computes() {
+                        computing.insert(id);
+                    }
+                }
+                computing
+            })
+        };
+
+        let mut active = HashMap::new();
+        for &id in order {
+            let Some(node) = self.nodes.get(&id) else {
+                continue;
+            };
+            let resolve_cheap = |index: usize| self.cheap_input_value(node, index, ctx, &will_compute);
+            if let Some(inputs) = node.operator.active_inputs(ctx, &resolve_cheap) {
+                active.insert(id, self.active_slots(node, &inputs));
+            }
+        }
+        if active.is_empty() {
+            return HashSet::new();
+        }
+
+        // Source -> (consumer, whether the consumer reads the edge)
+        let members: HashSet<Id> = order.iter().copied().collect();
+        let mut consumers: HashMap<Id, Vec<(Id, bool)>> = HashMap::new();
+        for &id in order {
+            let Some(node) = self.nodes.get(&id) else {
+                continue;
+            };
+            let slots = active.get(&id);
+            for (index, input) in node.operator.inputs().iter().enumerate() {
+                for (slot, (source, _)) in port_sources(input).enumerate() {
+                    if !members.contains(&source) {
+                        continue;
+                    }
+                    let read = !input.is_muted(slot)
+                        && slots.is_none_or(|slots| slots.contains(&(index, slot)));
+                    consumers.entry(source).or_default().push((id, read));
+                }
+            }
+        }
+
+        // Same backwards walk as for muted branches
+        let mut inactive = HashSet::new();
+        for &id in order.iter().rev() {
+            let Some(edges) = consumers.get(&id) else {
+                continue;
+            };
+            if edges.iter().all(|&(consumer, read)| !read || inactive.contains(&consumer)) {
+                inactive.insert(id);
+            }
+        }
+        inactive
+    }
+
+    /// `resolve_cheap` for [`Operator::active_inputs`]: an input's value if
+    /// it's known without computing anything.
+    ///
+    /// That's an unconnected input's default (or bound variable), or the
+    /// cached output feeding a single connection when its node won't
+    /// recompute.
+    fn cheap_input_value<'a>(
+        &self,
+        node: &Node,
+        index: usize,
+        ctx: &EvalContext,
+        will_compute: &dyn Fn() -> &'a HashSet<Id>,
+    ) -> Option<Value> {
+        let input = node.operator.inputs().get(index)?;
+        let mut sources = live_sources(input);
+        let Some((source, output)) = sources.next() else {
+            return node.unconnected_input_value(index, ctx);
+        };
+        if input.is_multi_input || sources.next().is_some() || will_compute().contains(&source) {
+            return None;
+        }
+        let key = CacheKey {
+            node_id: source,
+            call_context: ctx.call_context,
+        };
+        let value = self.value_cache.get(&key)?.outputs.get(output)?;
+        Some(node.clamp_input(index, Value::clone(value)))
+    }
+
+    /// Connection slots, as `(input, slot)`, a node reads given its active
+    /// inputs.
+    fn active_slots(&self, node: &Node, active: &[ActiveInput]) -> HashSet<(usize, usize)> {
+        let inputs = node.operator.inputs();
+        let mut slots = HashSet::new();
+        for &active in active {
+            let (index, value) = match active {
+                ActiveInput::Input(index) => (index, None),
+                ActiveInput::Value { input, index } => (input, Some(index)),
+            };
+            let Some(input) = inputs.get(index) else {
+                continue;
+            };
+            match value.map(|value| self.value_slot(input, value)) {
+                Some(Some(slot)) => slots.extend(slot.map(|slot| (index, slot))),
+                _ => slots.extend((0..input.connection_count()).map(|slot| (index, slot))),
+            }
+        }
+        slots
+    }
+
+    /// Slot of the connection providing flattened value `index` of an input
+    /// (see [`InputPort::resolve_multi_flat`]), or `Some(None)` if there are
+    /// fewer values.
+    ///
+    /// Returns `None` if a connection may carry a list, whose length isn't
+    /// known before evaluation.
+    fn value_slot(&self, input: &InputPort, index: usize) -> Option<Option<usize>> {
+        let live = port_sources(input)
+            .enumerate()
+            .filter(|&(slot, _)| !input.is_muted(slot));
+        for (position, (slot, (source, output))) in live.enumerate() {
+            let port = self.nodes.get(&source)?.operator.outputs().get(output)?;
+            let is_list = |value_type: ValueType| value_type.element_type().is_some();
+            if is_list(port.value_type) || is_list(port.value.value_type()) {
+                return None;
+            }
+            if position == index {
+                return Some(Some(slot));
+            }
+        }
+        Some(None)
+    }
+
+    /// Skip a node in an inactive branch, remembering whether it would have
+    /// recomputed so it catches up once it's read again.
+    fn skip_inactive(
+        &mut self,
+        node_id: Id,
+        ctx: &EvalContext,
+        computed_nodes: &HashSet<Id>,
+        demand: &OutputDemand,
+    ) {
+        if self.evaluation_reason(node_id, ctx, computed_nodes, demand).computes() {
+            self.missed_updates.insert(CacheKey {
+                node_id,
+                call_context: ctx.call_context,
+            });
+        }
+    }
+
+    /// Inputs of a node fed by a node that reported errors in its most recent
+    /// compute.
+    fn failed_inputs(&self, node_id: Id) -> Vec<usize> {
+        if self.node_errors.is_empty() {
+            return Vec::new();
+        }
+        let Some(node) = self.nodes.get(&node_id) else {
+            return Vec::new();
+        };
+        node.operator
+            .inputs()
+            .iter()
+            .enumerate()
+            .filter(|(_, input)| {
+                live_sources(input).any(|(source, _)| self.node_errors.contains_key(&source))
+            })
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// Cache a stateful node's main-context outputs under a preview context.
+    ///
+    /// Falls back to the operator's current outputs if the node hasn't been
+    /// evaluated in the main context.
+    fn reuse_main_outputs(&mut self, node_id: Id, main_context: CallContext, ctx: &EvalContext) {
+        let key = CacheKey {
+            node_id,
+            call_context: main_context,
+        };
+        let outputs = match (self.value_cache.get(&key), self.nodes.get(&node_id)) {
+            (Some(entry), _) => entry.outputs.clone(),
+            (None, Some(node)) => node
+                .operator
+                .outputs()
+                .iter()
+                .map(|o| Arc::new(o.value.clone()))
+                .collect(),
+            (None, None) => return,
+        };
+        self.store_outputs(node_id, ctx, outputs);
+    }
+
+    /// Compute a node for a preview, keeping only its cache entry for `ctx`.
+    ///
+    /// The node's output ports, throttling, smoothing and binding state, its
+    /// reported errors and any events emitted are restored afterwards.
+    fn compute_node_isolated(&mut self, node_id: Id, ctx: &EvalContext) {
+        let Some(node) = self.nodes.get(&node_id) else {
+            return;
+        };
+        let outputs = node.operator.outputs().to_vec();
+        let last_update = node.last_update;
+        let update_requested = node.update_requested;
+        let input_transitions = node.input_transitions.clone();
+        let context_bindings = node.context_bindings.clone();
+        let errors = self.node_errors.get(&node_id).cloned();
+        let event_count = self.pending_events.len();
+
+        // Hold the capacity so the events emitted here can be retracted
+        self.batch_depth += 1;
+        self.compute_node(node_id, ctx);
+        self.batch_depth -= 1;
+
+        self.pending_events.truncate(event_count);
+        match errors {
+            Some(errors) => self.node_errors.insert(node_id, errors),
+            None => self.node_errors.remove(&node_id),
+        };
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            for (port, saved) in node.operator.outputs_mut().iter_mut().zip(outputs) {
+                *port = saved;
+            }
+            node.last_update = last_update;
+            node.update_requested = update_requested;
+            node.input_transitions = input_transitions;
+            node.context_bindings = context_bindings;
+        }
+    }
+
+    /// Compute a single node and cache its outputs.
+    ///
+    /// Quarantined nodes are not computed: they keep their cached outputs,
+    /// or get type defaults in a call context they have no entry for.
+    ///
+    /// Returns false if the node no longer exists or is quarantined.
+    fn compute_node(&mut self, node_id: Id, ctx: &EvalContext) -> bool {
+        let call_context = ctx.call_context;
+        if self.quarantine.contains_key(&node_id) {
+            self.keep_outputs_after_panic(node_id, ctx);
+            return false;
+        }
+        let failed_inputs = self.failed_inputs(node_id);
+
+        // Get node reference safely
+        let node = match self.nodes.get_mut(&node_id) {
+            Some(n) => n,
+            None => {
+                // Node was removed during evaluation, skip it
+                return false;
+            }
+        };
+        let mut node_ctx = node.context(ctx);
+        if node_ctx.failed_inputs() != failed_inputs.as_slice() {
+            node_ctx.to_mut().set_failed_inputs(&failed_inputs);
+        }
+        let ctx = node_ctx.as_ref();
+
+        // Create lookup closure that captures a reference to value_cache
+        // We need to use a separate reference because we can't borrow self
+        // while also having a mutable borrow of node
+        //
+        // Note: The closure looks up values using the same call context,
+        // ensuring context-aware cache isolation for subroutines/loops.
+        //
+        // Reference stealing: When an Arc has refcount == 1, we could pass
+        // ownership instead of cloning. However, since the closure captures
+        // an immutable reference, we clone here. Full reference stealing
+        // would require a more complex evaluation model where we pre-collect
+        // inputs before computing.
+        let cache_ref = &self.value_cache;
+        let clamps = node.connected_clamps();
+        let get_input = |dep_id: Id, idx: usize| -> Value {
+            let key = CacheKey {
+                node_id: dep_id,
+                call_context,
+            };
+            let value = cache_ref
+                .get(&key)
+                .and_then(|entry| entry.outputs.get(idx))
+                .map(|arc| {
+                    // Try to steal the reference if we're the sole owner
+                    // Note: This won't work with the immutable borrow, but we
+                    // set up the infrastructure for future optimization
+                    Arc::unwrap_or_clone(arc.clone())
+                })
+                .unwrap_or_default();
+            match clamps.iter().find(|(source, _)| *source == (dep_id, idx)) {
+                Some((_, clamp)) => clamp.apply(value),
+                None => value,
+            }
+        };
+
+        // Drop stale reports so errors are attributed to this node
+        ctx.take_errors();
+
+        let muted = node.apply_connection_mutes();
+        let bound = node.apply_context_bindings(ctx);
+        let smoothed = node.apply_input_smoothing(ctx.time);
+        let clamped = node.apply_default_clamps();
+        let panicked = match self.panic_policy {
+            PanicPolicy::Propagate => {
+                node.operator.compute(ctx, &get_input);
+                None
+            }
+            PanicPolicy::Catch => {
+                let operator = &mut node.operator;
+                std::panic::catch_unwind(AssertUnwindSafe(|| operator.compute(ctx, &get_input)))
+                    .err()
+            }
+        };
+
+        // Inputs the operator moved while computing get their values back
+        // at the new index
+        let port_change = node.operator.take_port_change();
+        let (clamped, smoothed, bound, muted) = match &port_change {
+            Some(change) if !change.inputs.is_unchanged() => {
+                let (inputs, _) = node.port_names();
+                let map = |index| change.inputs.new_index(index, &inputs);
+                (
+                    remap_keys(clamped, map),
+                    remap_keys(smoothed, map),
+                    remap_keys(bound, map),
+                    remap_keys(muted, map),
+                )
+            }
+            _ => (clamped, smoothed, bound, muted),
+        };
+        node.restore_input_defaults(clamped);
+        node.restore_input_defaults(smoothed);
+        node.restore_input_defaults(bound);
+        node.restore_connection_mutes(muted);
+        // Before caching, so only entries in the old layout are invalidated
+        if let Some(change) = port_change {
+            self.apply_port_change(node_id, &change);
+        }
+        let Some(node) = self.nodes.get_mut(&node_id) else {
+            return false;
+        };
+        if let Some(payload) = panicked {
+            let operator = node.operator.name();
+            self.quarantine_node(node_id, operator, panic_message(payload.as_ref()), ctx);
+            return true;
+        }
+        node.last_update = Some((ctx.frame, ctx.time));
+        node.update_requested = false;
+
+        // Update the cache with new output values wrapped in Arc
+        let outputs: Vec<Arc<Value>> = node
+            .operator
+            .outputs()
+            .iter()
+            .map(|o| Arc::new(o.value.clone()))
+            .collect();
+        self.store_outputs(node_id, ctx, outputs);
+        self.missed_updates.remove(&CacheKey {
+            node_id,
+            call_context,
+        });
+
+        let errors = ctx.take_errors();
+        if errors.is_empty() {
+            self.node_errors.remove(&node_id);
+        } else {
+            for error in &errors {
+                self.emit(GraphEvent::OperatorError {
+                    node: node_id,
+                    error: error.clone(),
+                });
+            }
+            self.node_errors.insert(node_id, errors);
+        }
+
+        true
+    }
+
+    /// Quarantine a node whose compute just panicked.
+    ///
+    /// Its output ports may be half-written, so the cache keeps what it had
+    /// before the compute. Errors reported before the panic are kept along
+    /// with the panic itself.
+    fn quarantine_node(
+        &mut self,
+        node_id: Id,
+        operator: &'static str,
+        message: String,
+        ctx: &EvalContext,
+    ) {
+        self.keep_outputs_after_panic(node_id, ctx);
+
+        let mut errors = ctx.take_errors();
+        errors.push(OperatorError::evaluation_failed(
+            node_id,
+            format!("{} panicked: {}", operator, message),
+        ));
+        for error in &errors {
+            self.emit(GraphEvent::OperatorError {
+                node: node_id,
+                error: error.clone(),
+            });
+        }
+        self.node_errors.insert(node_id, errors);
+        self.emit(GraphEvent::NodePanicked {
+            node: node_id,
+            operator,
+            message: message.clone(),
+        });
+        self.quarantine.insert(node_id, NodePanic { operator, message, frame: ctx.frame });
+    }
+
+    /// Make sure a node that can't compute has a cache entry for `ctx`.
+    ///
+    /// An existing entry is kept; otherwise each output gets its type's default.
+    fn keep_outputs_after_panic(&mut self, node_id: Id, ctx: &EvalContext) {
+        let key = CacheKey {
+            node_id,
+            call_context: ctx.call_context,
+        };
+        if self.value_cache.contains_key(&key) {
+            self.touch_cache_entry(node_id, ctx);
+            return;
+        }
+        let Some(node) = self.nodes.get(&node_id) else {
+            return;
+        };
+        let outputs = node
+            .operator
+            .outputs()
+            .iter()
+            .map(|o| Arc::new(o.effective_type().default_value()))
+            .collect();
+        self.store_outputs(node_id, ctx, outputs);
+    }
+
+    /// Cache a node's outputs for the call context of `ctx`.
+    ///
+    /// Frozen outputs are cached with their pinned values.
+    fn store_outputs(&mut self, node_id: Id, ctx: &EvalContext, mut outputs: Vec<Arc<Value>>) {
+        let call_context = ctx.call_context;
+        let cache_key = CacheKey {
+            node_id,
+            call_context,
+        };
+        let mut context_fingerprint = 0;
+        let mut valid = vec![true; outputs.len()];
+        if let Some(node) = self.nodes.get_mut(&node_id) {
+            context_fingerprint = ctx.fingerprint(node.operator.context_dependencies());
+            for (valid, port) in valid.iter_mut().zip(node.operator.outputs()) {
+                *valid = !port.is_dirty();
+            }
+            for (&index, value) in &mut node.frozen_outputs {
+                if let Some(slot) = outputs.get_mut(index) {
+                    // A freeze set before the first evaluation pins its result
+                    let value = value.get_or_insert_with(|| (**slot).clone());
+                    *slot = Arc::new(value.clone());
+                    valid[index] = true;
+                }
+            }
+        }
+        self.value_cache.insert(
+            cache_key,
+            CacheEntry {
+                outputs,
+                last_used: ctx.frame,
+                context_fingerprint,
+                valid,
+            },
+        );
+        if !call_context.is_root() && !self.call_ancestors.contains_key(&call_context) {
+            self.call_ancestors.insert(call_context, ctx.call_ancestors().to_vec());
+        }
+    }
+
+    /// The context to compute with, carrying this graph's limits.
+    ///
+    /// The depth limit is only applied at the top level, so the outermost
+    /// graph's limit holds for all nested composites.
+    fn limited_context<'a>(&self, ctx: &'a EvalContext) -> Cow<'a, EvalContext> {
+        let apply_depth = ctx.depth() == 0 && ctx.max_depth != self.max_depth;
+        if ctx.max_list_len == self.max_list_len && !apply_depth {
+            Cow::Borrowed(ctx)
+        } else {
+            let mut ctx = ctx.clone();
+            ctx.max_list_len = self.max_list_len;
+            if apply_depth {
+                ctx.max_depth = self.max_depth;
+            }
+            Cow::Owned(ctx)
+        }
+    }
+
+    /// Fail if a node hit the composite depth limit during its compute.
+    ///
+    /// The node's cached outputs are dropped so it is retried (and fails
+    /// again) on the next evaluation instead of serving stale values.
+    fn check_depth_limit(&mut self, node_id: Id) -> Result<(), GraphError> {
+        let path = self.node_errors.get(&node_id).and_then(|errors| {
+            errors.iter().find_map(|e| match e {
+                OperatorError::DepthLimitExceeded { path, .. } => Some(path.clone()),
+                _ => None,
+            })
+        });
+        match path {
+            Some(path) => {
+                self.invalidate_cache_for_node(node_id);
+                Err(GraphError::DepthLimitExceeded { path })
+            }
+            None => Ok(()),
+        }
+    }
+
+    // =========================================================================
+    // Determinism Audit
+    // =========================================================================
+
+    /// Evaluate the graph `runs` times with the same context and report the
+    /// nodes whose outputs differ between runs.
+    ///
+    /// Covers every node upstream of a watched output, or every node if
+    /// nothing is watched. Each run starts from an empty cache in its own
+    /// call context (see [`AUDIT_CALL_INDEX`]), and stateful operators are
+    /// reset through [`Operator::reset_state`] before it, so all runs see the
+    /// same inputs. Outputs must be exactly equal; use
+    /// [`audit_determinism_within`](Self::audit_determinism_within) to allow
+    /// for float noise. Fewer than 2 `runs` count as 2.
+    ///
+    /// Like [`evaluate_preview`](Self::evaluate_preview), this leaves
+    /// main-context cache entries, node errors, events, update throttling
+    /// and operator outputs as they were, and drops the audit's own cache
+    /// entries afterwards. Stateful operators that were reset keep the state
+    /// of the last run. Those that can't be reset are not computed: they are
+    /// reported as unauditable and their last main-context output is reused
+    /// in every run.
+    ///
+    /// Meant for diagnostics: every node is computed once per run. A graph
+    /// with a cycle gives an empty report.
+    pub fn audit_determinism(&mut self, ctx: &EvalContext, runs: usize) -> DeterminismReport {
+        self.audit_determinism_within(ctx, runs, 0.0)
+    }
+
+    /// Like [`audit_determinism`](Self::audit_determinism), but numeric
+    /// outputs whose components differ by at most `epsilon` count as equal.
+    pub fn audit_determinism_within(
+        &mut self,
+        ctx: &EvalContext,
+        runs: usize,
+        epsilon: f64,
+    ) -> DeterminismReport {
+        let mut report = DeterminismReport {
+            runs: runs.max(2),
+            ..Default::default()
+        };
+        if self.compute_order().is_err() {
+            return report;
+        }
+        let order: Vec<Id> = if self.watches.is_empty() {
+            self.eval_order.clone()
+        } else {
+            let upstream: HashSet<Id> =
+                self.watches.nodes().flat_map(|node| self.upstream_closure(node)).collect();
+            self.eval_order.iter().copied().filter(|id| upstream.contains(id)).collect()
+        };
+
+        let main_context = ctx.call_context;
+        let audit_ctx = self.limited_context(ctx).with_call_context(AUDIT_CALL_INDEX);
+        let mut first_run: HashMap<Id, Vec<Arc<Value>>> = HashMap::new();
+        let mut divergences: HashMap<Id, Vec<OutputDivergence>> = HashMap::new();
+        let mut unauditable: HashSet<Id> = HashSet::new();
+        for run in 0..report.runs {
+            let run_ctx = audit_ctx.with_call_context(run as u32);
+            for &node_id in &order {
+                let Some(node) = self.nodes.get_mut(&node_id) else {
+                    continue;
+                };
+                if node.operator.is_stateful() && !node.operator.reset_state() {
+                    unauditable.insert(node_id);
+                    self.reuse_main_outputs(node_id, main_context, &run_ctx);
+                    continue;
+                }
+                self.compute_node_isolated(node_id, &run_ctx);
+                let key = CacheKey {
+                    node_id,
+                    call_context: run_ctx.call_context,
+                };
+                let outputs = self
+                    .value_cache
+                    .get(&key)
+                    .map(|entry| entry.outputs.clone())
+                    .unwrap_or_default();
+                match first_run.get(&node_id) {
+                    Some(first) => {
+                        let found = divergences.entry(node_id).or_default();
+                        compare_outputs(found, first, &outputs, epsilon);
+                    }
+                    None => {
+                        first_run.insert(node_id, outputs);
+                    }
+                }
+            }
+        }
+        self.evict_call_context_subtree(audit_ctx.call_context);
+
+        divergences.retain(|_, found| !found.is_empty());
+        for &node_id in &order {
+            let Some(node) = self.nodes.get(&node_id) else {
+                continue;
+            };
+            let operator = node.operator.name();
+            if unauditable.contains(&node_id) {
+                report.unauditable.push(UnauditableNode { node: node_id, operator });
+                continue;
+            }
+            report.audited += 1;
+            let Some(outputs) = divergences.get(&node_id) else {
+                continue;
+            };
+            let origin = !node
+                .operator
+                .inputs()
+                .iter()
+                .flat_map(live_sources)
+                .any(|(source, _)| divergences.contains_key(&source));
+            report.divergent.push(DivergentNode {
+                node: node_id,
+                operator,
+                origin,
+                outputs: outputs.clone(),
+            });
+        }
+        report
+    }
+
+    // =========================================================================
+    // Limits & Diagnostics
+    // =========================================================================
+
+    /// Set the maximum number of elements list-producing operators may output.
+    ///
+    /// Operators clamp longer outputs and report [`OperatorError::ListTooLong`]
+    /// (see [`node_errors`](Self::node_errors)). Defaults to
+    /// [`DEFAULT_MAX_LIST_LEN`].
+    pub fn set_max_list_len(&mut self, max_len: usize) {
+        if self.max_list_len != max_len {
+            self.max_list_len = max_len;
+            self.clear_cache();
+        }
+    }
+
+    /// Get the maximum list length passed to operators.
+    pub fn max_list_len(&self) -> usize {
+        self.max_list_len
+    }
+
+    /// Set the maximum composite nesting depth for evaluations started on
+    /// this graph.
+    ///
+    /// Composites nested deeper fail with [`GraphError::DepthLimitExceeded`]
+    /// instead of overflowing the stack. Only the outermost graph's limit
+    /// applies. Defaults to [`DEFAULT_MAX_DEPTH`].
+    pub fn set_max_depth(&mut self, max_depth: usize) {
+        if self.max_depth != max_depth {
+            self.max_depth = max_depth;
+            self.clear_cache();
+        }
+    }
+
+    /// Get the maximum composite nesting depth.
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Errors a node reported during its most recent compute.
+    ///
+    /// Empty if the node computed cleanly or hasn't been computed.
+    pub fn node_errors(&self, node_id: Id) -> &[OperatorError] {
+        self.node_errors
+            .get(&node_id)
+            .map(Vec::as_slice)
+            .unwrap_or(&[])
+    }
+
+    /// Returns true if any node reported errors during its most recent compute.
+    pub fn has_node_errors(&self) -> bool {
+        !self.node_errors.is_empty()
+    }
+
+    /// Choose whether operator panics unwind out of evaluation or quarantine
+    /// the panicking node (see [`quarantine`](crate::quarantine)).
+    ///
+    /// Defaults to [`PanicPolicy::Propagate`].
+    pub fn set_panic_policy(&mut self, policy: PanicPolicy) {
+        self.panic_policy = policy;
+    }
+
+    pub fn panic_policy(&self) -> PanicPolicy {
+        self.panic_policy
+    }
+
+    /// Returns true if the node panicked and is skipped by evaluation.
+    pub fn is_quarantined(&self, node_id: Id) -> bool {
+        self.quarantine.contains_key(&node_id)
+    }
+
+    /// The panic that quarantined a node, if it is quarantined.
+    pub fn node_panic(&self, node_id: Id) -> Option<&NodePanic> {
+        self.quarantine.get(&node_id)
+    }
+
+    /// All quarantined nodes with the panic that quarantined them.
+    pub fn quarantined(&self) -> impl Iterator<Item = (Id, &NodePanic)> {
+        self.quarantine.iter().map(|(&id, panic)| (id, panic))
+    }
+
+    /// Let a quarantined node compute again.
+    ///
+    /// Its panic error is cleared and it and everything downstream are
+    /// recomputed on the next evaluation. Returns false if the node wasn't
+    /// quarantined.
+    pub fn clear_quarantine(&mut self, node_id: Id) -> bool {
+        if self.quarantine.remove(&node_id).is_none() {
+            return false;
+        }
+        self.node_errors.remove(&node_id);
+        self.invalidate_downstream(node_id);
+        true
+    }
+
+    /// Read an output value from the cache for the given call context.
+    fn cached_result(
+        &self,
+        output_node: Id,
+        output_index: usize,
+        call_context: CallContext,
+    ) -> Result<Value, GraphError> {
+        // Return requested output (using the current call context)
+        let output_key = CacheKey {
+            node_id: output_node,
+            call_context,
+        };
+        self.value_cache
+            .get(&output_key)
+            .and_then(|entry| entry.outputs.get(output_index))
+            .map(|arc| Arc::unwrap_or_clone(arc.clone()))
+            .ok_or_else(|| GraphError::node_not_found(output_node, self.node_name(output_node)))
+    }
+
+    /// Get statistics about the graph
+    pub fn stats(&self) -> GraphStats {
+        let mut connection_count = 0;
+        for node in self.nodes.values() {
+            for input in node.operator.inputs() {
+                if input.connection.is_some() {
+                    connection_count += 1;
+                }
+                connection_count += input.connections.len();
+            }
+        }
+
+        GraphStats {
+            node_count: self.nodes.len(),
+            connection_count,
+            frozen_output_count: self.nodes.values().map(|n| n.frozen_outputs.len()).sum(),
+        }
+    }
+
+    /// Get detailed structural statistics for diagnostics.
+    ///
+    /// `category_of` resolves an operator name to its category (for example via
+    /// the operator registry). Names it cannot resolve are counted as
+    /// `"Uncategorized"`.
+    ///
+    /// This does not mutate the graph: it makes one pass over all inputs to
+    /// build adjacency, then a topological sweep for depth and a BFS for islands.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// let registry = create_default_registry();
+    /// let stats = graph.detailed_stats(|name| {
+    ///     registry.get_extended_meta_by_name(name).map(|e| e.meta.category)
+    /// });
+    /// println!("depth={} islands={}", stats.max_depth, stats.island_count);
+    /// ```
+    pub fn detailed_stats<F>(&self, category_of: F) -> DetailedGraphStats
+    where
+        F: Fn(&str) -> Option<&'static str>,
+    {
+        let mut stats = DetailedGraphStats {
+            node_count: self.nodes.len(),
+            ..Default::default()
+        };
+
+        // Single pass: per-node counts and adjacency (edges only between live nodes)
+        let mut fan_in: HashMap<Id, usize> = HashMap::with_capacity(self.nodes.len());
+        let mut fan_out: HashMap<Id, usize> = HashMap::with_capacity(self.nodes.len());
+        let mut downstream: HashMap<Id, Vec<Id>> = HashMap::new();
+        let mut neighbors: HashMap<Id, Vec<Id>> = HashMap::new();
+
+        for (&id, node) in &self.nodes {
+            let op = node.operator.as_ref();
+            let name = op.name();
+            *stats.operator_counts.entry(name).or_default() += 1;
+            let category = category_of(name).unwrap_or("Uncategorized");
+            *stats.category_counts.entry(category).or_default() += 1;
+            if op.is_time_varying() {
+                stats.time_varying_count += 1;
+            }
+            if op.as_any().is::<ConversionOp>() || op.as_any().is::<UnitConversionOp>() {
+                stats.conversion_count += 1;
+            }
+
+            fan_in.entry(id).or_default();
+            fan_out.entry(id).or_default();
+
+            for input in op.inputs() {
+                for &(source, _) in input.connection.iter().chain(input.connections.iter()) {
+                    // Inputs still pointing at a removed node don't count as edges
+                    if self.nodes.contains_key(&source) {
+                        stats.connection_count += 1;
+                        *fan_in.entry(id).or_default() += 1;
+                        *fan_out.entry(source).or_default() += 1;
+                        downstream.entry(source).or_default().push(id);
+                        neighbors.entry(source).or_default().push(id);
+                        neighbors.entry(id).or_default().push(source);
+                    }
+                }
+            }
+        }
+
+        for &count in fan_in.values() {
+            *stats.fan_in_histogram.entry(count).or_default() += 1;
+        }
+        for &count in fan_out.values() {
+            *stats.fan_out_histogram.entry(count).or_default() += 1;
+        }
+        stats.max_fan_in = fan_in.values().copied().max().unwrap_or(0);
+        stats.max_fan_out = fan_out.values().copied().max().unwrap_or(0);
+        if stats.node_count > 0 {
+            let n = stats.node_count as f32;
+            stats.avg_fan_in = fan_in.values().sum::<usize>() as f32 / n;
+            stats.avg_fan_out = fan_out.values().sum::<usize>() as f32 / n;
+        }
+
+        // Longest path (in edges) via a Kahn sweep over live edges
+        let mut indegree: HashMap<Id, usize> = self.nodes.keys().map(|&id| (id, 0)).collect();
+        for targets in downstream.values() {
+            for target in targets {
+                *indegree.get_mut(target).expect("edge target is a live node") += 1;
+            }
+        }
+        let mut depth: HashMap<Id, usize> = HashMap::with_capacity(self.nodes.len());
+        let mut queue: VecDeque<Id> = indegree
+            .iter()
+            .filter(|(_, &deg)| deg == 0)
+            .map(|(&id, _)| id)
+            .collect();
+        while let Some(id) = queue.pop_front() {
+            let d = *depth.entry(id).or_insert(0);
+            stats.max_depth = stats.max_depth.max(d);
+            for &target in downstream.get(&id).into_iter().flatten() {
+                let target_depth = depth.entry(target).or_insert(0);
+                *target_depth = (*target_depth).max(d + 1);
+                let deg = indegree.get_mut(&target).expect("edge target is a live node");
+                *deg -= 1;
+                if *deg == 0 {
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        let mut grouped: HashSet<Id> = HashSet::new();
+        for (group, entry) in self.groups.iter() {
+            stats.group_counts.insert(group, entry.members().len());
+            grouped.extend(entry.members());
+        }
+        stats.ungrouped_count = stats.node_count - grouped.len();
+
+        // Islands: connected components ignoring edge direction
+        let mut seen: HashSet<Id> = HashSet::with_capacity(self.nodes.len());
+        for &start in self.nodes.keys() {
+            if !seen.insert(start) {
+                continue;
+            }
+            stats.island_count += 1;
+            let mut queue = VecDeque::from([start]);
+            while let Some(id) = queue.pop_front() {
+                for &next in neighbors.get(&id).into_iter().flatten() {
+                    if seen.insert(next) {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+
+        stats
+    }
+}
+
+impl Default for Graph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Statistics about the graph
+#[derive(Debug, Clone)]
+pub struct GraphStats {
+    pub node_count: usize,
+    pub connection_count: usize,
+    /// Outputs pinned by [`Graph::freeze_output`]
+    pub frozen_output_count: usize,
+}
+
+/// Detailed structural statistics, see [`Graph::detailed_stats`].
+#[derive(Debug, Clone, Default)]
+pub struct DetailedGraphStats {
+    pub node_count: usize,
+    /// Connections between nodes in the graph
+    pub connection_count: usize,
+    /// Longest dependency chain, measured in connections
+    pub max_depth: usize,
+    /// Average number of incoming connections per node
+    pub avg_fan_in: f32,
+    /// Largest number of incoming connections on a single node
+    pub max_fan_in: usize,
+    /// Average number of outgoing connections per node
+    pub avg_fan_out: f32,
+    /// Largest number of outgoing connections from a single node
+    pub max_fan_out: usize,
+    /// Fan-in value -> number of nodes with that fan-in
+    pub fan_in_histogram: BTreeMap<usize, usize>,
+    /// Fan-out value -> number of nodes with that fan-out
+    pub fan_out_histogram: BTreeMap<usize, usize>,
+    /// Operator name -> node count
+    pub operator_counts: HashMap<&'static str, usize>,
+    /// Operator category -> node count
+    pub category_counts: HashMap<&'static str, usize>,
+    /// Number of nodes whose operator is time-varying
+    pub time_varying_count: usize,
+    /// Number of auto-inserted [`ConversionOp`] and [`UnitConversionOp`] nodes
+    pub conversion_count: usize,
+    /// Number of connected components (ignoring connection direction)
+    pub island_count: usize,
+    /// Group -> number of member nodes (see [`Graph::create_group`])
+    pub group_counts: BTreeMap<GroupId, usize>,
+    /// Number of nodes that belong to no group
+    pub ungrouped_count: usize,
+}
+
+/// Represents a connection between two nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Connection {
+    /// The node that produces the value.
+    pub source_node: Id,
+    /// The output index on the source node.
+    pub source_output: usize,
+    /// The node that consumes the value.
+    pub target_node: Id,
+    /// The input index on the target node.
+    pub target_input: usize,
+    /// Whether the connection is muted (see [`Graph::set_connection_muted`]).
+    pub muted: bool,
+}
+
+/// A connection from a trigger output to a trigger input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TriggerConnection {
+    /// The node that fires the trigger.
+    pub source_node: Id,
+    /// The trigger output index on the source node.
+    pub source_output: usize,
+    /// Name of the trigger output.
+    pub source_name: &'static str,
+    /// The node that receives the trigger.
+    pub target_node: Id,
+    /// The trigger input index on the target node.
+    pub target_input: usize,
+    /// Name of the trigger input.
+    pub target_name: &'static str,
+}
+
+/// A structural inconsistency reported by [`Graph::validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GraphIssue {
+    /// A value input is connected to a node or output that doesn't exist
+    DanglingConnection(Connection),
+    /// One end of a trigger connection is a node or port that doesn't exist
+    DanglingTrigger(Connection),
+    /// A trigger connection is recorded on only one of its two ports
+    UnpairedTrigger(Connection),
+    /// A frozen output no longer exists or no longer has the type of its
+    /// pinned value (see [`Graph::freeze_output`])
+    StaleFreeze(OutputRef),
+    /// The operator rejects an input's value (see [`Graph::input_issues`]
+    /// for the message)
+    InvalidInput {
+        input: InputRef,
+        severity: ValidationSeverity,
+    },
+}
+
+/// Outcome of a [`Graph::hot_swap`]
+///
+/// Connections are kept when the port at the same index has the same type
+/// (and, for inputs, the same multi-input mode) on the new operator; input
+/// defaults carry over by port name. Everything else is listed here.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SwapReport {
+    /// New input indices whose default was carried over from a same-named input
+    pub carried_defaults: Vec<usize>,
+    /// Input indices whose incoming connections were kept
+    pub kept_inputs: Vec<usize>,
+    /// Incoming connections dropped because their input is gone or changed
+    pub dropped_inputs: Vec<Connection>,
+    /// Outgoing connections dropped because their output is gone or changed
+    pub dropped_outputs: Vec<Connection>,
+}
+
+impl SwapReport {
+    /// Whether every connection survived the swap
+    pub fn is_clean(&self) -> bool {
+        self.dropped_inputs.is_empty() && self.dropped_outputs.is_empty()
+    }
+}
+
+/// What [`Graph::notify_ports_changed`] did to a node's connections.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PortChangeReport {
+    /// Connections moved to their port's new index, as (before, after)
+    pub remapped: Vec<(Connection, Connection)>,
+    /// Connections removed because their port is gone, or the output that
+    /// replaced it can't feed the input directly
+    pub dropped: Vec<Connection>,
+}
+
+impl PortChangeReport {
+    /// Whether every connection survived the change
+    pub fn is_clean(&self) -> bool {
+        self.dropped.is_empty()
+    }
+}
+
+impl Connection {
+    /// The source output as a typed reference
+    pub fn source_ref(&self) -> OutputRef {
+        OutputRef::new(self.source_node, self.source_output)
+    }
+
+    /// The target input as a typed reference
+    pub fn target_ref(&self) -> InputRef {
+        InputRef::new(self.target_node, self.target_input)
+    }
+}
+
+/// Saved connection state of one input port, used to roll back edits.
+#[derive(Debug, Clone)]
+pub(crate) struct InputSnapshot {
+    node_id: Id,
+    input_index: usize,
+    connection: Option<(Id, usize)>,
+    connections: Vec<(Id, usize)>,
+    muted: Vec<bool>,
+}
+
+/// Everything needed to undo a [`Graph::splice_node`].
+#[derive(Debug, Clone)]
+pub(crate) struct SpliceRecord {
+    /// The original edge
+    connection: Connection,
+    /// The node that was spliced in
+    new_node: Id,
+    /// Input on the spliced node that received the original source
+    in_idx: usize,
+    /// Conversion nodes inserted while connecting
+    inserted: Vec<Id>,
+    /// Original target input state
+    target_snapshot: InputSnapshot,
+    /// Spliced node's input state before the splice
+    node_snapshot: Option<InputSnapshot>,
+}
+
+/// Everything needed to undo a [`Graph::retarget_connection`].
+#[derive(Debug, Clone)]
+pub(crate) struct RetargetRecord {
+    /// The replaced edge
+    old: Connection,
+    /// The edge that replaced it
+    new: Connection,
+    /// Conversion node inserted while connecting
+    inserted: Option<Id>,
+    /// Original state of the old edge's target input
+    old_snapshot: InputSnapshot,
+    /// Original state of the new edge's target input, if it is a different input
+    new_snapshot: Option<InputSnapshot>,
+}
+
+/// Everything needed to undo a [`Graph::unsplice_node`].
+pub(crate) struct UnspliceRecord {
+    /// The removed node, with its input connections intact
+    node: Node,
+    /// The removed node's outgoing edge
+    outgoing: Connection,
+    /// Conversion node inserted while reconnecting
+    inserted: Option<Id>,
+    /// Downstream input state before the unsplice
+    target_snapshot: InputSnapshot,
+}
+
+impl UnspliceRecord {
+    /// ID of the removed node.
+    pub(crate) fn node_id(&self) -> Id {
+        self.node.operator.id()
+    }
+}
+
+/// Errors that can occur during graph operations
+#[derive(Debug)]
+pub enum GraphError {
+    NodeNotFound {
+        id: Id,
+        name: Option<&'static str>,
+    },
+    InputNotFound {
+        node_id: Id,
+        input_index: usize,
+        node_name: &'static str,
+        input_count: usize,
+    },
+    OutputNotFound {
+        node_id: Id,
+        output_index: usize,
+        node_name: &'static str,
+        output_count: usize,
+    },
+    TypeMismatch {
+        source_node: Id,
+        source_type: ValueType,
+        target_node: Id,
+        target_type: ValueType,
+    },
+    CycleDetected {
+        nodes: Vec<Id>,
+    },
+    /// Trigger port not found on a node
+    TriggerNotFound {
+        node_id: Id,
+        is_output: bool,
+        index: usize,
+        available: usize,
+    },
+    /// The given value connection does not exist
+    ConnectionNotFound {
+        connection: Connection,
+    },
+    /// Node cannot be unspliced (needs exactly one incoming and one outgoing connection)
+    NotSpliceable {
+        node_id: Id,
+        incoming: usize,
+        outgoing: usize,
+    },
+    /// Composite nesting exceeded the depth limit
+    DepthLimitExceeded {
+        /// Names of the nested composites, outermost first
+        path: Vec<String>,
+    },
+    /// A replacement operator reports a different ID and can't adopt the node's
+    IdMismatch {
+        node_id: Id,
+        operator_id: Id,
+    },
+    /// A node with this ID is already in the graph
+    DuplicateNode { id: Id },
+    /// The given group does not exist
+    GroupNotFound { group: GroupId },
+    /// [`Graph::begin_frame`] was called while a frame was still open
+    FrameAlreadyOpen { frame: u64 },
+    /// [`Graph::end_frame`] was called without an open frame
+    NoFrameOpen,
+    /// Two inputs can't be swapped: their types don't convert both ways or
+    /// only one of them is a multi-input
+    IncompatibleInputs {
+        node_id: Id,
+        input_a: usize,
+        input_b: usize,
+    },
+}
+
+impl GraphError {
+    pub(crate) fn node_not_found(id: Id, name: Option<&'static str>) -> Self {
+        GraphError::NodeNotFound { id, name }
+    }
+
+    pub(crate) fn input_not_found(
+        node_id: Id,
+        input_index: usize,
+        node_name: &'static str,
+        input_count: usize,
+    ) -> Self {
+        GraphError::InputNotFound {
+            node_id,
+            input_index,
+            node_name,
+            input_count,
+        }
+    }
+
+    pub(crate) fn output_not_found(
+        node_id: Id,
+        output_index: usize,
+        node_name: &'static str,
+        output_count: usize,
+    ) -> Self {
+        GraphError::OutputNotFound {
+            node_id,
+            output_index,
+            node_name,
+            output_count,
+        }
+    }
+
+    pub(crate) fn type_mismatch(
+        source_node: Id,
+        source_type: ValueType,
+        target_node: Id,
+        target_type: ValueType,
+    ) -> Self {
+        GraphError::TypeMismatch {
+            source_node,
+            source_type,
+            target_node,
+            target_type,
+        }
+    }
+}
+
+impl std::fmt::Display for GraphError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GraphError::NodeNotFound { id, name } => {
+                if let Some(name) = name {
+                    write!(f, "Node '{}' ({}) not found", name, id)
+                } else {
+                    write!(f, "Node {} not found", id)
+                }
+            }
+            GraphError::InputNotFound {
+                node_id,
+                input_index,
+                node_name,
+                input_count,
+            } => {
+                write!(
+                    f,
+                    "Input index {} not found on '{}' ({}). Node has {} input(s).",
+                    input_index, node_name, node_id, input_count
+                )
+            }
+            GraphError::OutputNotFound {
+                node_id,
+                output_index,
+                node_name,
+                output_count,
+            } => {
+                write!(
+                    f,
+                    "Output index {} not found on '{}' ({}). Node has {} output(s).",
+                    output_index, node_name, node_id, output_count
+                )
+            }
+            GraphError::TypeMismatch {
+                source_node,
+                source_type,
+                target_node,
+                target_type,
+            } => {
+                write!(
+                    f,
+                    "Type mismatch: cannot connect {} output ({}) to {} input ({})",
+                    source_type, source_node, target_type, target_node
+                )
+            }
+            GraphError::CycleDetected { nodes } => {
+                write!(f, "Cycle detected in graph involving {} node(s)", nodes.len())
+            }
+            GraphError::TriggerNotFound {
+                node_id,
+                is_output,
+                index,
+                available,
+            } => {
+                let port_type = if *is_output { "output" } else { "input" };
+                write!(
+                    f,
+                    "Trigger {} index {} not found on node {}. Node has {} trigger {}(s).",
+                    port_type, index, node_id, available, port_type
+                )
+            }
+            GraphError::ConnectionNotFound { connection } => {
+                write!(
+                    f,
+                    "Connection {}:{} -> {}:{} not found",
+                    connection.source_node,
+                    connection.source_output,
+                    connection.target_node,
+                    connection.target_input
+                )
+            }
+            GraphError::NotSpliceable {
+                node_id,
+                incoming,
+                outgoing,
+            } => {
+                write!(
+                    f,
+                    "Node {} cannot be unspliced: needs exactly 1 incoming and 1 outgoing connection, has {} and {}",
+                    node_id, incoming, outgoing
+                )
+            }
+            GraphError::DepthLimitExceeded { path } => {
+                write!(
+                    f,
+                    "Composite nesting too deep ({} levels): {}",
+                    path.len(),
+                    path.join(" > ")
+                )
+            }
+            GraphError::IdMismatch {
+                node_id,
+                operator_id,
+            } => {
+                write!(
+                    f,
+                    "Replacement operator has ID {} and can't take over node {}",
+                    operator_id, node_id
+                )
+            }
+            GraphError::DuplicateNode { id } => write!(f, "Node {} already exists", id),
+            GraphError::GroupNotFound { group } => write!(f, "{} not found", group),
+            GraphError::FrameAlreadyOpen { frame } => {
+                write!(f, "Frame {} is still open; end it before beginning another", frame)
+            }
+            GraphError::NoFrameOpen => write!(f, "No frame is open"),
+            GraphError::IncompatibleInputs {
+                node_id,
+                input_a,
+                input_b,
+            } => {
+                write!(
+                    f,
+                    "Inputs {} and {} of node {} have incompatible types and can't be swapped",
+                    input_a, input_b, node_id
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+/// Errors from [`Graph::evaluate_as`]
+#[derive(Debug)]
+pub enum EvalTypeError {
+    /// Evaluation failed
+    Eval(GraphError),
+    /// The output's value can't be read as the requested type
+    TypeMismatch {
+        node: Id,
+        /// Operator name of the node
+        operator: &'static str,
+        output: usize,
+        actual: ValueType,
+        requested: ValueType,
+    },
+}
+
+impl From<GraphError> for EvalTypeError {
+    fn from(error: GraphError) -> Self {
+        EvalTypeError::Eval(error)
+    }
+}
+
+impl std::fmt::Display for EvalTypeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalTypeError::Eval(error) => error.fmt(f),
+            EvalTypeError::TypeMismatch {
+                node,
+                operator,
+                output,
+                actual,
+                requested,
+            } => write!(
+                f,
+                "Output {} of '{}' ({}) is {}, which cannot be read as {}",
+                output, operator, node, actual, requested
+            ),
+        }
+    }
+}
+
+impl std::error::Error for EvalTypeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EvalTypeError::Eval(error) => Some(error),
+            EvalTypeError::TypeMismatch { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::{
+        ContextDependency, InputPort, Operator, OperatorCost, OutputPort, Value, ValueType,
+    };
+
+    /// Simple test operator for event system tests
+    struct TestOp {
+        id: Id,
+        inputs: Vec<InputPort>,
+        outputs: Vec<OutputPort>,
+        cost: OperatorCost,
+    }
+
+    impl TestOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![InputPort::new("in", Value::Float(0.0))],
+                outputs: vec![OutputPort::new("out", ValueType::Float)],
+                cost: OperatorCost::Cheap,
+            }
+        }
+
+        fn source() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![],
+                outputs: vec![OutputPort::new("out", ValueType::Float)],
+                cost: OperatorCost::Cheap,
+            }
+        }
+
+        fn with_cost(mut self, cost: OperatorCost) -> Self {
+            self.cost = cost;
+            self
+        }
+    }
+
+    impl Operator for TestOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "Test"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn cost_hint(&self) -> OperatorCost {
+            self.cost
+        }
+        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
+            if !self.inputs.is_empty() {
+                if let Some((source_id, source_output)) = self.inputs[0].connection {
+                    let val = get_input(source_id, source_output);
+                    self.outputs[0].value = val;
+                }
+            }
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_node_added_event() {
+        let mut graph = Graph::new();
+        assert!(!graph.has_pending_events());
+
+        let op = TestOp::source();
+        let id = graph.add(op);
+
+        assert!(graph.has_pending_events());
+        assert_eq!(graph.pending_event_count(), 1);
+
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            GraphEvent::NodeAdded { id: event_id } => assert_eq!(*event_id, id),
+            _ => panic!("Expected NodeAdded event"),
+        }
+
+        assert!(!graph.has_pending_events());
+    }
+
+    #[test]
+    fn test_node_removed_event() {
+        let mut graph = Graph::new();
+        let op = TestOp::source();
+        let id = graph.add(op);
+
+        // Clear add event
+        graph.clear_events();
+
+        graph.remove(id);
+
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            GraphEvent::NodeRemoved { id: event_id, .. } => assert_eq!(*event_id, id),
+            _ => panic!("Expected NodeRemoved event"),
+        }
+    }
+
+    #[test]
+    fn test_remove_connected_node_events() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source());
+        let hub = graph.add(TestOp::new());
+        let targets: Vec<Id> = (0..4).map(|_| graph.add(TestOp::new())).collect();
+        graph.connect(source, 0, hub, 0).unwrap();
+        for &target in &targets {
+            graph.connect(hub, 0, target, 0).unwrap();
+        }
+        graph.clear_events();
+
+        graph.remove(hub);
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 7);
+
+        let disconnected: Vec<(Id, Id)> = events[..5]
+            .iter()
+            .map(|e| match e {
+                GraphEvent::Disconnected {
+                    source,
+                    source_output: 0,
+                    target,
+                    target_input: 0,
+                } => (*source, *target),
+                other => panic!("Expected Disconnected, got {:?}", other),
+            })
+            .collect();
+        assert_eq!(disconnected[0], (source, hub));
+        for &target in &targets {
+            assert!(disconnected.contains(&(hub, target)));
+        }
+
+        match &events[5] {
+            GraphEvent::NodeRemoved { id, operator, severed } => {
+                assert_eq!(*id, hub);
+                assert_eq!(*operator, "Test");
+                assert_eq!(severed.len(), 5);
+                assert!(severed.iter().all(|c| c.source_node == hub || c.target_node == hub));
+            }
+            other => panic!("Expected NodeRemoved, got {:?}", other),
+        }
+        assert!(matches!(events[6], GraphEvent::BatchEnd { events_in_batch: 6 }));
+    }
+
+    #[test]
+    fn test_restore_from_trash() {
+        let mut graph = Graph::new();
+        graph.set_trash_capacity(8);
+        let source = graph.add(TestOp::source());
+        let hub = graph.add(TestOp::new());
+        let target = graph.add(TestOp::new());
+        graph.connect(source, 0, hub, 0).unwrap();
+        graph.connect(hub, 0, target, 0).unwrap();
+        graph.set_input_default(hub, 0, Value::Float(3.0));
+
+        assert!(graph.remove(hub).is_none());
+        assert!(graph.get(hub).is_none());
+        assert_eq!(graph.get(target).unwrap().inputs()[0].connection, None);
+        assert_eq!(graph.trash().len(), 1);
+        assert_eq!(graph.trash()[0].id(), hub);
+        assert_eq!(graph.trash()[0].name(), "Test");
+        assert_eq!(graph.trash()[0].connections().len(), 2);
+
+        let report = graph.restore_from_trash(hub).unwrap();
+        assert_eq!(report.id, hub);
+        assert!(report.is_complete());
+        assert!(graph.trash().is_empty());
+        let restored = graph.get(hub).unwrap();
+        assert_eq!(restored.id(), hub);
+        assert_eq!(restored.inputs()[0].connection, Some((source, 0)));
+        assert_eq!(restored.inputs()[0].default, Value::Float(3.0));
+        assert_eq!(graph.get(target).unwrap().inputs()[0].connection, Some((hub, 0)));
+
+        assert!(matches!(
+            graph.restore_from_trash(hub),
+            Err(GraphError::NodeNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_restore_from_trash_with_missing_neighbor() {
+        let mut graph = Graph::new();
+        graph.set_trash_capacity(8);
+        let source = graph.add(TestOp::source());
+        let hub = graph.add(TestOp::new());
+        let target = graph.add(TestOp::new());
+        let other = graph.add(TestOp::source());
+        graph.connect(source, 0, hub, 0).unwrap();
+        graph.connect(hub, 0, target, 0).unwrap();
+
+        graph.remove(hub);
+        graph.remove(source);
+        // The target's input was reused while the hub was gone
+        graph.connect(other, 0, target, 0).unwrap();
+
+        let report = graph.restore_from_trash(hub).unwrap();
+        assert!(!report.is_complete());
+        assert_eq!(report.unrestored.len(), 2);
+        assert_eq!(graph.get(hub).unwrap().inputs()[0].connection, None);
+        assert_eq!(graph.get(target).unwrap().inputs()[0].connection, Some((other, 0)));
+
+        // The source was removed after the hub, so it had nothing left to sever
+        assert!(graph.restore_from_trash(source).unwrap().is_complete());
+        assert_eq!(graph.get(hub).unwrap().inputs()[0].connection, None);
+    }
+
+    #[test]
+    fn test_restore_from_trash_keeps_multi_input_order() {
+        use flux_operators::SumOp;
+
+        let mut graph = Graph::new();
+        graph.set_trash_capacity(4);
+        let a = graph.add(FloatSourceOp::new(1.0));
+        let b = graph.add(FloatSourceOp::new(2.0));
+        let c = graph.add(FloatSourceOp::new(3.0));
+        let sum = graph.add(SumOp::new());
+        for source in [a, b, c] {
+            graph.connect(source, 0, sum, 0).unwrap();
+        }
+
+        graph.remove(b);
+        assert!(graph.restore_from_trash(b).unwrap().is_complete());
+        let connections = &graph.get(sum).unwrap().inputs()[0].connections;
+        assert_eq!(connections, &vec![(a, 0), (b, 0), (c, 0)]);
+    }
+
+    #[test]
+    fn test_trash_capacity() {
+        let mut graph = Graph::new();
+        let id = graph.add(TestOp::source());
+        assert!(graph.remove(id).is_some());
+        assert!(graph.trash().is_empty());
+
+        graph.set_trash_capacity(2);
+        let ids: Vec<Id> = (0..3).map(|_| graph.add(TestOp::source())).collect();
+        for &id in &ids {
+            graph.remove(id);
+        }
+        // Oldest entry evicted
+        let trashed: Vec<Id> = graph.trash().iter().map(|entry| entry.id()).collect();
+        assert_eq!(trashed, ids[1..]);
+
+        // A node with the trashed ID was added since
+        graph.add(TestOp { id: ids[1], ..TestOp::source() });
+        assert!(matches!(
+            graph.restore_from_trash(ids[1]),
+            Err(GraphError::DuplicateNode { .. })
+        ));
+
+        assert_eq!(graph.empty_trash(), 2);
+        assert!(graph.restore_from_trash(ids[2]).is_err());
+
+        graph.remove(ids[1]);
+        assert_eq!(graph.trash().len(), 1);
+        graph.set_trash_capacity(0);
+        assert!(graph.trash().is_empty());
+    }
+
+    #[test]
+    fn test_single_event_operations_are_not_batched() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source());
+        let target = graph.add(TestOp::new());
+        graph.connect(source, 0, target, 0).unwrap();
+        graph.disconnect(target, 0).unwrap();
+        // Disconnecting an unconnected input changes nothing
+        graph.disconnect(target, 0).unwrap();
+
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 4);
+        assert!(!events.iter().any(|e| matches!(e, GraphEvent::BatchEnd { .. })));
+    }
+
+    #[test]
+    fn test_connected_event() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source());
+        let target = graph.add(TestOp::new());
+
+        // Clear add events
+        graph.clear_events();
+
+        graph.connect(source, 0, target, 0).unwrap();
+
+        let events: Vec<_> = graph.drain_events().collect();
+        // We expect Connected + OrderRecomputed (from evaluation order)
+        assert!(!events.is_empty());
+
+        let connected = events.iter().find(|e| matches!(e, GraphEvent::Connected { .. }));
+        assert!(connected.is_some());
+
+        match connected.unwrap() {
+            GraphEvent::Connected {
+                source: src,
+                source_output,
+                target: tgt,
+                target_input,
+            } => {
+                assert_eq!(*src, source);
+                assert_eq!(*source_output, 0);
+                assert_eq!(*tgt, target);
+                assert_eq!(*target_input, 0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_disconnected_event() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source());
+        let target = graph.add(TestOp::new());
+        graph.connect(source, 0, target, 0).unwrap();
+
+        // Clear previous events
+        graph.clear_events();
+
+        graph.disconnect(target, 0).unwrap();
+
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(!events.is_empty());
+
+        let disconnected = events
+            .iter()
+            .find(|e| matches!(e, GraphEvent::Disconnected { .. }));
+        assert!(disconnected.is_some());
+
+        match disconnected.unwrap() {
+            GraphEvent::Disconnected {
+                source: src,
+                source_output,
+                target: tgt,
+                target_input,
+            } => {
+                assert_eq!(*src, source);
+                assert_eq!(*source_output, 0);
+                assert_eq!(*tgt, target);
+                assert_eq!(*target_input, 0);
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn test_input_default_changed_event() {
+        let mut graph = Graph::new();
+        let node = graph.add(TestOp::new());
+
+        // Clear add event
+        graph.clear_events();
+
+        let success = graph.set_input_default(node, 0, Value::Float(42.0));
+        assert!(success);
+
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 1);
+
+        match &events[0] {
+            GraphEvent::InputDefaultChanged {
+                node: n,
+                input,
+                previous,
+                value,
+            } => {
+                assert_eq!(*n, node);
+                assert_eq!(*input, 0);
+                assert_eq!(*previous, Value::Float(0.0));
+                assert_eq!(*value, Value::Float(42.0));
+            }
+            _ => panic!("Expected InputDefaultChanged event"),
+        }
+    }
+
+    #[test]
+    fn test_order_recomputed_event() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source());
+        let target = graph.add(TestOp::new());
+        graph.connect(source, 0, target, 0).unwrap();
+
+        // Clear previous events
+        graph.clear_events();
+
+        // Trigger order recomputation via evaluate
+        let ctx = EvalContext::default();
+        let _ = graph.evaluate(target, 0, &ctx);
+
+        let events: Vec<_> = graph.drain_events().collect();
+        let order_recomputed = events
+            .iter()
+            .any(|e| matches!(e, GraphEvent::OrderRecomputed));
+        assert!(order_recomputed, "Expected OrderRecomputed event");
+    }
+
+    fn build_fan_graph() -> (Graph, Vec<Id>) {
+        let mut graph = Graph::new();
+        let mut ids = Vec::new();
+        for _ in 0..8 {
+            ids.push(graph.add(TestOp::source()));
+        }
+        for i in 0..8 {
+            let sink = graph.add(TestOp::new());
+            graph.connect(ids[i], 0, sink, 0).unwrap();
+            ids.push(sink);
+        }
+        (graph, ids)
+    }
+
+    #[test]
+    fn test_eval_order_is_deterministic() {
+        let positions = |graph: &mut Graph, ids: &[Id]| -> Vec<usize> {
+            graph.compute_order().unwrap();
+            graph
+                .eval_order
+                .iter()
+                .map(|id| ids.iter().position(|n| n == id).unwrap())
+                .collect()
+        };
+
+        let (mut first, first_ids) = build_fan_graph();
+        let (mut second, second_ids) = build_fan_graph();
+        let first_order = positions(&mut first, &first_ids);
+        let second_order = positions(&mut second, &second_ids);
+
+        assert_eq!(first_order, second_order);
+        // Equal-cost sources are placed in insertion order
+        assert_eq!(&first_order[..8], &[0, 1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn test_eval_order_prefers_expensive_nodes() {
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source());
+        let trivial = graph.add(TestOp::new().with_cost(OperatorCost::Trivial));
+        let expensive = graph.add(TestOp::new().with_cost(OperatorCost::Expensive));
+        graph.connect(source, 0, trivial, 0).unwrap();
+        graph.connect(source, 0, expensive, 0).unwrap();
+
+        graph.compute_order().unwrap();
+        let position = |id: Id| graph.eval_order.iter().position(|n| *n == id).unwrap();
+        assert_eq!(position(source), 0);
+        assert!(position(expensive) < position(trivial));
+    }
+
+    #[test]
+    fn test_multiple_events_accumulate() {
+        let mut graph = Graph::new();
+
+        // Add multiple nodes without draining
+        let _a = graph.add(TestOp::source());
+        let _b = graph.add(TestOp::source());
+        let _c = graph.add(TestOp::source());
+
+        assert_eq!(graph.pending_event_count(), 3);
+
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 3);
+        assert!(events.iter().all(|e| matches!(e, GraphEvent::NodeAdded { .. })));
+    }
+
+    /// Set input 0 of `node` to 1.0, 2.0, ... `count` times
+    fn flood_defaults(graph: &mut Graph, node: Id, count: usize) {
+        for i in 1..=count {
+            graph.set_input_default(node, 0, Value::Float(i as f32));
+        }
+    }
+
+    fn changed_value(event: &GraphEvent) -> Option<&Value> {
+        match event {
+            GraphEvent::InputDefaultChanged { value, .. } => Some(value),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_event_queue_is_unbounded_by_default() {
+        let mut graph = Graph::new();
+        let node = graph.add(TestOp::new());
+        graph.clear_events();
+        flood_defaults(&mut graph, node, 10_000);
+
+        assert_eq!(graph.event_capacity(), None);
+        assert_eq!(graph.pending_event_count(), 10_000);
+        assert_eq!(graph.dropped_event_count(), 0);
+    }
+
+    #[test]
+    fn test_event_capacity_drop_oldest() {
+        let mut graph = Graph::new();
+        let node = graph.add(TestOp::new());
+        graph.clear_events();
+        graph.set_event_capacity(Some(100));
+        flood_defaults(&mut graph, node, 10_000);
+
+        assert_eq!(graph.pending_event_count(), 100);
+        assert_eq!(graph.dropped_event_count(), 9_900);
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 101);
+        assert!(matches!(events[0], GraphEvent::EventsDropped { count: 9_900 }));
+        assert_eq!(changed_value(&events[1]), Some(&Value::Float(9_901.0)));
+        assert_eq!(changed_value(&events[100]), Some(&Value::Float(10_000.0)));
+
+        // The notice is only sent once; the lifetime counter stays
+        graph.set_input_default(node, 0, Value::Float(0.5));
+        assert_eq!(graph.drain_events().count(), 1);
+        assert_eq!(graph.dropped_event_count(), 9_900);
+
+        // An atomic operation is trimmed after it completes
+        let source = graph.add(TestOp::source());
+        graph.connect(source, 0, node, 0).unwrap();
+        graph.clear_events();
+        graph.set_event_capacity(Some(1));
+        graph.remove(source);
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(matches!(events[..], [
+            GraphEvent::EventsDropped { count: 2 },
+            GraphEvent::BatchEnd { events_in_batch: 2 },
+        ]));
+    }
+
+    #[test]
+    fn test_event_capacity_drop_newest() {
+        let mut graph = Graph::new();
+        let node = graph.add(TestOp::new());
+        graph.clear_events();
+        graph.set_event_overflow(EventOverflow::DropNewest);
+        graph.set_event_capacity(Some(100));
+        flood_defaults(&mut graph, node, 10_000);
+
+        assert_eq!(graph.pending_event_count(), 100);
+        assert_eq!(graph.dropped_event_count(), 9_900);
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(matches!(events[0], GraphEvent::EventsDropped { count: 9_900 }));
+        assert_eq!(changed_value(&events[1]), Some(&Value::Float(1.0)));
+        assert_eq!(changed_value(&events[100]), Some(&Value::Float(100.0)));
+
+        // Lowering the capacity trims what is already queued
+        flood_defaults(&mut graph, node, 10);
+        graph.set_event_capacity(Some(4));
+        assert_eq!(graph.pending_event_count(), 4);
+        assert_eq!(graph.dropped_event_count(), 9_906);
+    }
+
+    #[test]
+    fn test_event_capacity_coalesce() {
+        let mut graph = Graph::new();
+        let a = graph.add(TestOp::new());
+        let b = graph.add(TestOp::new());
+        graph.clear_events();
+        graph.set_event_overflow(EventOverflow::Coalesce);
+        graph.set_event_capacity(Some(100));
+        for i in 1..=5_000 {
+            graph.set_input_default(a, 0, Value::Float(i as f32));
+            graph.set_input_default(b, 0, Value::Float(-(i as f32)));
+        }
+
+        assert_eq!(graph.pending_event_count(), 2);
+        assert_eq!(graph.dropped_event_count(), 0);
+        let events: Vec<_> = graph.drain_events().collect();
+        let [GraphEvent::InputDefaultChanged { node, previous, value, .. }, second] = &events[..]
+        else {
+            panic!("expected two default changes, got {:?}", events);
+        };
+        assert_eq!((*node, previous, value), (a, &Value::Float(0.0), &Value::Float(5_000.0)));
+        assert_eq!(changed_value(second), Some(&Value::Float(-5_000.0)));
+
+        // Other events end the run that can be merged into
+        graph.set_input_default(a, 0, Value::Float(1.0));
+        let c = graph.add(TestOp::new());
+        graph.set_input_default(a, 0, Value::Float(2.0));
+        assert_eq!(graph.pending_event_count(), 3);
+
+        // Events that can't be merged still respect the capacity
+        graph.clear_events();
+        graph.set_event_capacity(Some(2));
+        for node in [a, b, c] {
+            graph.set_input_default(node, 0, Value::Float(7.0));
+        }
+        assert_eq!(graph.pending_event_count(), 2);
+        assert_eq!(graph.dropped_event_count(), 1);
+    }
+
+    // =========================================================================
+    // Phase 1 Feature Tests: CallContext-Aware Caching
+    // =========================================================================
+
+    /// Test operator that tracks how many times compute() is called
+    struct CountingOp {
+        id: Id,
+        inputs: Vec<InputPort>,
+        outputs: Vec<OutputPort>,
+        compute_count: std::cell::Cell<u32>,
+    }
+
+    impl CountingOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![InputPort::new("in", Value::Float(1.0))],
+                outputs: vec![OutputPort::new("out", ValueType::Float)],
+                compute_count: std::cell::Cell::new(0),
+            }
+        }
+
+        fn get_compute_count(&self) -> u32 {
+            self.compute_count.get()
+        }
+    }
+
+    impl Operator for CountingOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "CountingOp"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
+            self.compute_count.set(self.compute_count.get() + 1);
+            // Double the input value
+            if let Some((source_id, source_output)) = self.inputs[0].connection {
+                let val = get_input(source_id, source_output);
+                if let Value::Float(f) = val {
+                    // Use set() to mark output as clean after computation
+                    self.outputs[0].set(Value::Float(f * 2.0));
+                }
+            } else if let Value::Float(f) = self.inputs[0].default {
+                // Use set() to mark output as clean after computation
+                self.outputs[0].set(Value::Float(f * 2.0));
+            }
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_call_context_cache_isolation() {
+        // Test that the same operator evaluated with different CallContexts
+        // gets separate cache entries
+
+        let mut graph = Graph::new();
+        let op = CountingOp::new();
+        let op_id = op.id;
+        graph.add(op);
+
+        // First evaluation with root context
+        let ctx_root = EvalContext::new();
+        let result1 = graph.evaluate(op_id, 0, &ctx_root).unwrap();
+
+        // Second evaluation with different call context (simulating a subroutine call)
+        let ctx_child1 = ctx_root.with_call_context(1);
+        let result2 = graph.evaluate(op_id, 0, &ctx_child1).unwrap();
+
+        // Third evaluation with another different call context
+        let ctx_child2 = ctx_root.with_call_context(2);
+        let result3 = graph.evaluate(op_id, 0, &ctx_child2).unwrap();
+
+        // All results should be the same value (2.0 = 1.0 * 2)
+        assert_eq!(result1, Value::Float(2.0));
+        assert_eq!(result2, Value::Float(2.0));
+        assert_eq!(result3, Value::Float(2.0));
+
+        // The operator should have been computed 3 times (once per context)
+        let op = graph.get(op_id).unwrap();
+        let counting_op = op.as_any().downcast_ref::<CountingOp>().unwrap();
+        assert_eq!(counting_op.get_compute_count(), 3);
+    }
+
+    #[test]
+    fn test_same_context_uses_cache() {
+        // Test that evaluating with the same context reuses cached values
+
+        let mut graph = Graph::new();
+        let op = CountingOp::new();
+        let op_id = op.id;
+        graph.add(op);
+
+        let ctx = EvalContext::new();
+
+        // First evaluation - should compute
+        let result1 = graph.evaluate(op_id, 0, &ctx).unwrap();
+
+        // Second evaluation with same context - should use cache
+        let result2 = graph.evaluate(op_id, 0, &ctx).unwrap();
+
+        // Third evaluation with same context - should still use cache
+        let result3 = graph.evaluate(op_id, 0, &ctx).unwrap();
+
+        // All results should be the same
+        assert_eq!(result1, Value::Float(2.0));
+        assert_eq!(result2, Value::Float(2.0));
+        assert_eq!(result3, Value::Float(2.0));
+
+        // The operator should have been computed only once
+        let op = graph.get(op_id).unwrap();
+        let counting_op = op.as_any().downcast_ref::<CountingOp>().unwrap();
+        assert_eq!(counting_op.get_compute_count(), 1);
+    }
+    fn compute_count(graph: &Graph, op_id: Id) -> u32 {
+        let op = graph.get(op_id).unwrap();
+        op.as_any().downcast_ref::<CountingOp>().unwrap().get_compute_count()
+    }
+
+    #[test]
+    fn test_uncoupled_outputs_skip_unread_dirty_outputs() {
+        use flux_operators::Vec3DecomposeOp;
+
+        let mut graph = Graph::new();
+        let decompose = graph.add(Vec3DecomposeOp::new());
+        graph.set_input_default(decompose, 0, Value::Vec3([1.0, 2.0, 3.0]));
+        let x_sink = graph.add(CountingOp::new());
+        let y_consumer = graph.add(CountingOp::new());
+        let y_sink = graph.add(CountingOp::new());
+        graph.connect(decompose, 0, x_sink, 0).unwrap();
+        graph.connect(decompose, 1, y_consumer, 0).unwrap();
+        graph.connect(y_consumer, 0, y_sink, 0).unwrap();
+        assert!(!graph.get(decompose).unwrap().outputs_coupled());
+
+        let ctx = EvalContext::new();
+        let computed = |graph: &Graph| graph.frame_evaluated_nodes().collect::<HashSet<_>>();
+        assert_eq!(graph.evaluate(x_sink, 0, &ctx).unwrap(), Value::Float(2.0));
+        assert_eq!(graph.evaluate(y_sink, 0, &ctx).unwrap(), Value::Float(8.0));
+
+        // Editing the Y chain doesn't reach the decompose
+        graph.disconnect(y_sink, 0).unwrap();
+        graph.connect(y_consumer, 0, y_sink, 0).unwrap();
+        assert_eq!(graph.evaluate(x_sink, 0, &ctx).unwrap(), Value::Float(2.0));
+        assert!(!computed(&graph).contains(&decompose));
+
+        // A set path that only dirties Y leaves X's consumers on the cache
+        let op = graph.get_mut_as::<Vec3DecomposeOp>(decompose).unwrap();
+        op.outputs_mut()[1].mark_dirty();
+        assert_eq!(graph.evaluate(x_sink, 0, &ctx).unwrap(), Value::Float(2.0));
+        assert!(computed(&graph).is_empty());
+        assert_eq!(graph.evaluate(x_sink, 0, &ctx).unwrap(), Value::Float(2.0));
+        assert!(computed(&graph).is_empty());
+
+        // Reading Y recomputes it
+        assert_eq!(graph.evaluate(y_sink, 0, &ctx).unwrap(), Value::Float(8.0));
+        let recomputed = computed(&graph);
+        assert!(recomputed.contains(&decompose));
+        assert!(recomputed.contains(&y_consumer));
+    }
+
+    #[test]
+    fn test_resolution_change_recomputes_dependent_nodes() {
+        use flux_operators::{NormalizeCoordsOp, ResolutionOp};
+
+        let mut graph = Graph::new();
+        let resolution = graph.add(ResolutionOp::new());
+        let width_doubled = graph.add(CountingOp::new());
+        graph.connect(resolution, 0, width_doubled, 0).unwrap();
+        let normalize = graph.add(NormalizeCoordsOp::new());
+        graph.set_input_default(normalize, 0, Value::Vec2([100.0, 0.0]));
+        let unrelated = graph.add(CountingOp::new());
+
+        let ctx = EvalContext::new().with_resolution(200, 100);
+        let evaluate_all = |graph: &mut Graph, ctx: &EvalContext| {
+            (
+                graph.evaluate(width_doubled, 0, ctx).unwrap(),
+                graph.evaluate(normalize, 0, ctx).unwrap(),
+                graph.evaluate(unrelated, 0, ctx).unwrap(),
+            )
+        };
+        let first = evaluate_all(&mut graph, &ctx);
+        assert_eq!(first.0, Value::Float(400.0));
+        assert_eq!(first.1, Value::Vec2([0.0, 1.0]));
+        assert_eq!(evaluate_all(&mut graph, &ctx), first);
+        assert_eq!(compute_count(&graph, width_doubled), 1);
+
+        let resized = ctx.with_resolution(400, 100);
+        let (width, coords, _) = evaluate_all(&mut graph, &resized);
+        assert_eq!(width, Value::Float(800.0));
+        assert_eq!(coords, Value::Vec2([-0.5, 1.0]));
+        assert_eq!(compute_count(&graph, width_doubled), 2);
+        assert_eq!(compute_count(&graph, unrelated), 1);
+    }
+
+    /// Outputs whether it would draw its gizmo
+    struct GizmoOp {
+        id: Id,
+        outputs: Vec<OutputPort>,
+    }
+
+    impl GizmoOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                outputs: vec![OutputPort::new("shown", ValueType::Bool)],
+            }
+        }
+    }
+
+    impl Operator for GizmoOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "GizmoOp"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &[]
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut []
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn context_dependencies(&self) -> &'static [ContextDependency] {
+            &[ContextDependency::Gizmos]
+        }
+        fn compute(&mut self, ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            let shown = ctx.should_show_gizmos(ctx.is_node_selected(self.id));
+            self.outputs[0].set(Value::Bool(shown));
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_per_node_gizmo_visibility() {
+        let mut graph = Graph::new();
+        let node = graph.add(GizmoOp::new());
+        let mut ctx = EvalContext::new();
+        ctx.show_gizmos = GizmoVisibility::IfSelected;
+
+        let cases = [
+            (GizmoVisibility::Off, [false, false]),
+            (GizmoVisibility::On, [true, true]),
+            (GizmoVisibility::IfSelected, [false, true]),
+            // Follows the context's IfSelected
+            (GizmoVisibility::Inherit, [false, true]),
+        ];
+        for (visibility, expected) in cases {
+            graph.set_gizmo_visibility(node, visibility);
+            for (selected, expected) in [false, true].into_iter().zip(expected) {
+                let selection: &[Id] = if selected { &[node] } else { &[] };
+                ctx.set_selected_nodes(selection);
+                assert_eq!(
+                    graph.evaluate(node, 0, &ctx).unwrap(),
+                    Value::Bool(expected),
+                    "{:?}, selected: {}",
+                    visibility,
+                    selected
+                );
+            }
+        }
+
+        ctx.show_gizmos = GizmoVisibility::Off;
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Bool(false));
+        ctx.show_gizmos = GizmoVisibility::Inherit;
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn test_gc_cache_drops_stale_loop_contexts() {
+        let mut graph = Graph::new();
+        let op_id = graph.add(CountingOp::new());
+
+        let mut ctx = EvalContext::new();
+        graph.evaluate(op_id, 0, &ctx).unwrap();
+        // A loop that ran one iteration per frame
+        for i in 0..100 {
+            ctx.frame = i;
+            graph.evaluate(op_id, 0, &ctx.with_call_context(i as u32)).unwrap();
+        }
+        assert_eq!(graph.cache_entry_count(), 101);
+
+        assert_eq!(graph.gc_cache(5, 99), 94);
+        assert_eq!(graph.cache_entry_count(), 7);
+        assert!(graph.cached_output(op_id, 0).is_some());
+
+        // Surviving iterations are still cached, collected ones recompute
+        let computed = compute_count(&graph, op_id);
+        graph.evaluate(op_id, 0, &ctx.with_call_context(95)).unwrap();
+        assert_eq!(compute_count(&graph, op_id), computed);
+        graph.evaluate(op_id, 0, &ctx.with_call_context(3)).unwrap();
+        assert_eq!(compute_count(&graph, op_id), computed + 1);
+
+        // Reuse refreshes an entry's age; the root context is never collected
+        ctx.frame = 200;
+        graph.evaluate(op_id, 0, &ctx.with_call_context(95)).unwrap();
+        assert_eq!(graph.gc_cache(0, 200), 6);
+        assert_eq!(graph.cache_entry_count(), 2);
+    }
+
+    #[test]
+    fn test_evict_call_context_subtree() {
+        let mut graph = Graph::new();
+        let source = graph.add(FloatSourceOp::new(1.0));
+        let op_id = graph.add(CountingOp::new());
+        graph.connect(source, 0, op_id, 0).unwrap();
+
+        let ctx = EvalContext::new();
+        let outer: Vec<EvalContext> = (0..2).map(|i| ctx.with_call_context(i)).collect();
+        for outer_ctx in &outer {
+            for j in 0..3 {
+                graph.evaluate(op_id, 0, &outer_ctx.with_call_context(j)).unwrap();
+            }
+        }
+        graph.evaluate(op_id, 0, &outer[1]).unwrap();
+        assert_eq!(graph.cache_entry_count(), 14);
+
+        // Only the nested contexts of outer[0] were evaluated
+        assert_eq!(graph.evict_call_context_subtree(outer[0].call_context), 6);
+        assert_eq!(graph.evict_call_context(outer[1].with_call_context(2).call_context), 2);
+        assert_eq!(graph.evict_call_context(outer[1].with_call_context(2).call_context), 0);
+        assert_eq!(graph.evict_call_context_subtree(outer[1].call_context), 6);
+        assert_eq!(graph.cache_entry_count(), 0);
+    }
+
+    #[test]
+    fn test_automatic_cache_gc() {
+        let mut graph = Graph::new();
+        let op_id = graph.add(CountingOp::new());
+        graph.set_cache_max_age(Some(2));
+
+        let mut ctx = EvalContext::new();
+        for frame in 0..10 {
+            ctx.frame = frame;
+            graph.evaluate(op_id, 0, &ctx).unwrap();
+            for i in 0..4 {
+                graph.evaluate(op_id, 0, &ctx.with_call_context(frame as u32 * 4 + i)).unwrap();
+            }
+        }
+        // Root plus the iterations of the last three frames; the final frame's
+        // iterations ran after its collection
+        assert_eq!(graph.cache_entry_count(), 1 + 3 * 4);
+    }
+
+    #[test]
+    fn test_nested_call_contexts_are_isolated() {
+        // Test that nested call contexts (like nested loop iterations) are isolated
+
+        let mut graph = Graph::new();
+        let op = CountingOp::new();
+        let op_id = op.id;
+        graph.add(op);
+
+        let ctx_root = EvalContext::new();
+
+        // Simulate nested loops: outer loop iterations 0 and 1
+        let ctx_outer_0 = ctx_root.with_call_context(0);
+        let ctx_outer_1 = ctx_root.with_call_context(1);
+
+        // Inner loop iterations within outer loop 0
+        let ctx_0_0 = ctx_outer_0.with_call_context(0);
+        let ctx_0_1 = ctx_outer_0.with_call_context(1);
+
+        // Inner loop iterations within outer loop 1
+        let ctx_1_0 = ctx_outer_1.with_call_context(0);
+        let ctx_1_1 = ctx_outer_1.with_call_context(1);
+
+        // Evaluate all 4 nested contexts
+        graph.evaluate(op_id, 0, &ctx_0_0).unwrap();
+        graph.evaluate(op_id, 0, &ctx_0_1).unwrap();
+        graph.evaluate(op_id, 0, &ctx_1_0).unwrap();
+        graph.evaluate(op_id, 0, &ctx_1_1).unwrap();
+
+        // Each nested context should have its own cache entry
+        let op = graph.get(op_id).unwrap();
+        let counting_op = op.as_any().downcast_ref::<CountingOp>().unwrap();
+        assert_eq!(counting_op.get_compute_count(), 4);
+    }
+
+    #[test]
+    fn test_can_operate_in_place_default() {
+        // Test that the default can_operate_in_place() returns false
+
+        let op = TestOp::new();
+        assert!(!op.can_operate_in_place());
+    }
+
+    /// Test operator that declares it can operate in-place
+    struct InPlaceOp {
+        id: Id,
+        inputs: Vec<InputPort>,
+        outputs: Vec<OutputPort>,
+    }
+
+    impl InPlaceOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![InputPort::new("in", Value::Float(0.0))],
+                outputs: vec![OutputPort::new("out", ValueType::Float)],
+            }
+        }
+    }
+
+    impl Operator for InPlaceOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "InPlaceOp"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            // Use set() to mark output as clean after computation
+            self.outputs[0].set(Value::Float(42.0));
+        }
+        fn can_operate_in_place(&self) -> bool {
+            true
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_can_operate_in_place_override() {
+        // Test that operators can override can_operate_in_place() to return true
+
+        let op = InPlaceOp::new();
+        assert!(op.can_operate_in_place());
+    }
+
+    #[test]
+    fn test_clear_cache_clears_all_contexts() {
+        // Test that clear_cache() removes entries for all call contexts
+
+        let mut graph = Graph::new();
+        let op = CountingOp::new();
+        let op_id = op.id;
+        graph.add(op);
+
+        let ctx_root = EvalContext::new();
+        let ctx_child = ctx_root.with_call_context(1);
+
+        // Evaluate with both contexts to populate cache
+        graph.evaluate(op_id, 0, &ctx_root).unwrap();
+        graph.evaluate(op_id, 0, &ctx_child).unwrap();
+
+        // Clear the cache
+        graph.clear_cache();
+
+        // Evaluate again - should recompute since cache was cleared
+        graph.evaluate(op_id, 0, &ctx_root).unwrap();
+        graph.evaluate(op_id, 0, &ctx_child).unwrap();
+
+        // Should have computed 4 times total (2 before clear, 2 after)
+        let op = graph.get(op_id).unwrap();
+        let counting_op = op.as_any().downcast_ref::<CountingOp>().unwrap();
+        assert_eq!(counting_op.get_compute_count(), 4);
+    }
+
+    // =========================================================================
+    // Phase 2 Feature Tests: Auto-Conversion at Connect Time
+    // =========================================================================
+
+    /// Test operator that outputs a Float
+    struct FloatSourceOp {
+        id: Id,
+        outputs: Vec<OutputPort>,
+    }
+
+    impl FloatSourceOp {
+        fn new(value: f32) -> Self {
+            let mut output = OutputPort::float("Out");
+            output.set(Value::Float(value));
+            Self {
+                id: Id::new(),
+                outputs: vec![output],
+            }
+        }
+    }
+
+    impl Operator for FloatSourceOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "FloatSource"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &[]
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut []
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            // Value is already set in constructor
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    /// Test operator that accepts a Vec3 input
+    struct Vec3SinkOp {
+        id: Id,
+        inputs: Vec<InputPort>,
+        outputs: Vec<OutputPort>,
+    }
+
+    impl Vec3SinkOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![InputPort::new("In", Value::Vec3([0.0, 0.0, 0.0]))],
+                outputs: vec![OutputPort::vec3("Out")],
+            }
+        }
+    }
+
+    impl Operator for Vec3SinkOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "Vec3Sink"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
+            let input = if let Some((node_id, output_idx)) = self.inputs[0].connection {
+                get_input(node_id, output_idx)
+            } else {
+                self.inputs[0].default.clone()
+            };
+            self.outputs[0].set(input);
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    #[test]
+    fn test_connect_exact_type_match() {
+        // When types match exactly, connect directly without conversion node
+        let mut graph = Graph::new();
+        let source = graph.add(TestOp::source());
+        let target = graph.add(TestOp::new());
+
+        // Clear events from adding nodes
+        graph.clear_events();
+
+        // Connect Float -> Float (exact match)
+        let result = graph.connect(source, 0, target, 0);
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap(), None); // No conversion node inserted
+
+        // Should have emitted Connected event but no ConversionInserted event
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(events.iter().any(|e| matches!(e, GraphEvent::Connected { .. })));
+        assert!(!events.iter().any(|e| matches!(e, GraphEvent::ConversionInserted { .. })));
+    }
+
+    #[test]
+    fn test_connect_auto_conversion() {
+        // When types can be coerced, auto-insert conversion node
+        let mut graph = Graph::new();
+        let float_source = graph.add(FloatSourceOp::new(2.5));
+        let vec3_sink = graph.add(Vec3SinkOp::new());
+
+        // Clear events from adding nodes
+        graph.clear_events();
+
+        // Connect Float -> Vec3 (requires conversion)
+        let result = graph.connect(float_source, 0, vec3_sink, 0);
+        assert!(result.is_ok());
+
+        let conversion_id = result.unwrap();
+        assert!(conversion_id.is_some()); // Conversion node was inserted
+
+        let conv_id = conversion_id.unwrap();
+
+        // Verify the conversion node exists and has correct types
+        let conv_op = graph.get(conv_id).unwrap();
+        assert_eq!(conv_op.name(), "Convert");
+
+        // Check events
+        let events: Vec<_> = graph.drain_events().collect();
+        let conversion_event = events.iter().find(|e| {
+            matches!(e, GraphEvent::ConversionInserted { .. })
+        });
+        assert!(conversion_event.is_some());
+
+        if let Some(GraphEvent::ConversionInserted {
+            conversion_node,
+            source_type,
+            target_type,
+        }) = conversion_event
+        {
+            assert_eq!(*conversion_node, conv_id);
+            assert_eq!(*source_type, ValueType::Float);
+            assert_eq!(*target_type, ValueType::Vec3);
+        }
+
+        // Node, both halves and the conversion marker form one batch
+        assert_eq!(events.len(), 5);
+        assert!(matches!(events[0], GraphEvent::NodeAdded { id } if id == conv_id));
+        assert!(matches!(
+            events[1],
+            GraphEvent::Connected { source, target, .. } if source == float_source && target == conv_id
+        ));
+        assert!(matches!(
+            events[2],
+            GraphEvent::Connected { source, target, .. } if source == conv_id && target == vec3_sink
+        ));
+        assert!(matches!(events[4], GraphEvent::BatchEnd { events_in_batch: 4 }));
+
+        // Removing the conversion node severs both halves in one batch
+        graph.remove(conv_id);
+        let events: Vec<_> = graph.drain_events().collect();
+        match &events[2] {
+            GraphEvent::NodeRemoved { operator, severed, .. } => {
+                assert_eq!(*operator, "Convert");
+                assert_eq!(severed.len(), 2);
+            }
+            other => panic!("Expected NodeRemoved, got {:?}", other),
+        }
+        assert!(matches!(events[3], GraphEvent::BatchEnd { events_in_batch: 3 }));
+    }
+
+    #[test]
+    fn test_connect_auto_conversion_evaluation() {
+        // Verify that auto-conversion works correctly during evaluation
+        let mut graph = Graph::new();
+        let float_source = graph.add(FloatSourceOp::new(2.5));
+        let vec3_sink_id = {
+            let sink = Vec3SinkOp::new();
+            let id = sink.id;
+            graph.add(sink);
+            id
+        };
+
+        // Connect with auto-conversion
+        let conversion_id = graph.connect(float_source, 0, vec3_sink_id, 0).unwrap();
+        assert!(conversion_id.is_some());
+
+        // Evaluate the graph
+        let ctx = EvalContext::new();
+        let result = graph.evaluate(vec3_sink_id, 0, &ctx).unwrap();
+
+        // Float 2.5 should be broadcast to Vec3 [2.5, 2.5, 2.5]
+        assert_eq!(result, Value::Vec3([2.5, 2.5, 2.5]));
+    }
+
+    #[test]
+    fn test_connect_incompatible_types() {
+        // When types cannot be coerced, return error
+        let mut graph = Graph::new();
+
+        // String source
+        struct StringSourceOp {
+            id: Id,
+            outputs: Vec<OutputPort>,
+        }
+        impl StringSourceOp {
+            fn new() -> Self {
+                Self {
+                    id: Id::new(),
+                    outputs: vec![OutputPort::string("Out")],
+                }
+            }
+        }
+        impl Operator for StringSourceOp {
+            fn id(&self) -> Id { self.id }
+            fn name(&self) -> &'static str { "StringSource" }
+            fn inputs(&self) -> &[InputPort] { &[] }
+            fn inputs_mut(&mut self) -> &mut [InputPort] { &mut [] }
+            fn outputs(&self) -> &[OutputPort] { &self.outputs }
+            fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+            fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {}
+            fn as_any(&self) -> &dyn std::any::Any { self }
+            fn as_any_mut(&mut self) -> &mut dyn std::any::Any { self }
+        }
+
+        let string_source = graph.add(StringSourceOp::new());
+        let vec3_sink = graph.add(Vec3SinkOp::new());
+
+        // Connect String -> Vec3 (incompatible)
+        let result = graph.connect(string_source, 0, vec3_sink, 0);
+        assert!(result.is_err());
+
+        if let Err(GraphError::TypeMismatch { source_type, target_type, .. }) = result {
+            assert_eq!(source_type, ValueType::String);
+            assert_eq!(target_type, ValueType::Vec3);
+        } else {
+            panic!("Expected TypeMismatch error");
+        }
+    }
+
+    #[test]
+    fn test_connect_direct_requires_exact_match() {
+        // connect_direct() should require exact type match, no auto-conversion
+        let mut graph = Graph::new();
+        let float_source = graph.add(FloatSourceOp::new(2.5));
+        let vec3_sink = graph.add(Vec3SinkOp::new());
+
+        // connect_direct Float -> Vec3 should fail
+        let result = graph.connect_direct(float_source, 0, vec3_sink, 0);
+        assert!(result.is_err());
+
+        if let Err(GraphError::TypeMismatch { .. }) = result {
+            // Expected
+        } else {
+            panic!("Expected TypeMismatch error from connect_direct");
+        }
+    }
+
+    #[test]
+    fn test_multi_input_flattens_mixed_scalar_and_list_sources() {
+        use flux_operators::{FloatListOp, SumOp};
+
+        let mut graph = Graph::new();
+        let first = graph.add(FloatSourceOp::new(1.0));
+        let list = graph.add(FloatListOp::new());
+        graph.set_input_default(list, 0, Value::float_list(vec![2.0, 3.0, 4.0]));
+        let last = graph.add(FloatSourceOp::new(5.0));
+        let collected = graph.add(FloatListOp::new());
+        let sum = graph.add(SumOp::new());
+
+        // The list connects to the Float multi-inputs without a conversion
+        for source in [first, list, last] {
+            assert_eq!(graph.connect(source, 0, collected, 0).unwrap(), None);
+            assert_eq!(graph.connect(source, 0, sum, 0).unwrap(), None);
+        }
+        assert_eq!(graph.node_count(), 5);
+        // Only on multi-inputs
+        let sink = graph.add(TestOp::new());
+        assert!(graph.connect(list, 0, sink, 0).is_err());
+
+        let ctx = EvalContext::new();
+        assert_eq!(
+            graph.evaluate(collected, 0, &ctx).unwrap(),
+            Value::float_list(vec![1.0, 2.0, 3.0, 4.0, 5.0])
+        );
+        assert_eq!(graph.evaluate(sum, 0, &ctx).unwrap(), Value::Float(15.0));
+    }
+
+    #[test]
+    fn test_map_ports_only_connect_to_maps() {
+        use flux_operators::{GetFieldOp, MakeMapOp};
+
+        let mut graph = Graph::new();
+        let scale = graph.add(FloatSourceOp::new(2.0));
+        let make = graph.add(MakeMapOp::with_fields("position:Vec3, scale"));
+        let get = graph.add(GetFieldOp::with_field("position"));
+        let vec3_sink = graph.add(Vec3SinkOp::new());
+
+        // Maps never coerce, in either direction
+        let result = graph.connect(make, 0, vec3_sink, 0);
+        assert!(matches!(
+            result,
+            Err(GraphError::TypeMismatch { source_type: ValueType::Map, .. })
+        ));
+        assert!(graph.connect(scale, 0, get, 0).is_err());
+
+        graph.connect(scale, 0, make, 1).unwrap();
+        assert_eq!(graph.connect(make, 0, get, 0).unwrap(), None);
+
+        // GetField's output takes the field's type once evaluated
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(get, 0, &ctx).unwrap(), Value::Vec3([0.0; 3]));
+        assert_eq!(graph.get(get).unwrap().outputs()[0].value_type, ValueType::Vec3);
+        assert_eq!(graph.connect(get, 0, vec3_sink, 0).unwrap(), None);
+    }
+
+    // =========================================================================
+    // Trigger System Tests
+    // =========================================================================
+
+    /// Operator with trigger ports for testing push-based execution
+    struct TriggerTestOp {
+        id: Id,
+        inputs: Vec<InputPort>,
+        outputs: Vec<OutputPort>,
+        trigger_inputs: Vec<flux_core::TriggerInput>,
+        trigger_outputs: Vec<flux_core::TriggerOutput>,
+        trigger_count: std::cell::Cell<usize>,
+    }
+
+    impl TriggerTestOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![InputPort::new("in", Value::Float(0.0))],
+                outputs: vec![OutputPort::new("out", ValueType::Float)],
+                trigger_inputs: vec![flux_core::TriggerInput::new("OnFrame")],
+                trigger_outputs: vec![flux_core::TriggerOutput::new("Done")],
+                trigger_count: std::cell::Cell::new(0),
+            }
+        }
+
+        fn trigger_count(&self) -> usize {
+            self.trigger_count.get()
+        }
+    }
+
+    impl Operator for TriggerTestOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "TriggerTestOp"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn trigger_inputs(&self) -> &[flux_core::TriggerInput] {
+            &self.trigger_inputs
+        }
+        fn trigger_inputs_mut(&mut self) -> &mut [flux_core::TriggerInput] {
+            &mut self.trigger_inputs
+        }
+        fn trigger_outputs(&self) -> &[flux_core::TriggerOutput] {
+            &self.trigger_outputs
+        }
+        fn trigger_outputs_mut(&mut self) -> &mut [flux_core::TriggerOutput] {
+            &mut self.trigger_outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            self.outputs[0].set(Value::Float(42.0));
+        }
+        fn on_triggered(
+            &mut self,
+            trigger_index: usize,
+            _ctx: &EvalContext,
+            _get_input: flux_core::InputResolver,
+        ) -> Vec<usize> {
+            if trigger_index == 0 {
+                self.trigger_count.set(self.trigger_count.get() + 1);
+                // Fire "Done" trigger
+                vec![0]
+            } else {
+                vec![]
             }
         }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
     }
-}
-
-impl std::error::Error for GraphError {}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use flux_core::{InputPort, Operator, OutputPort, Value, ValueType};
 
-    /// Simple test operator for event system tests
-    struct TestOp {
+    /// Source operator that has trigger outputs but no inputs
+    struct TriggerSourceOp {
         id: Id,
-        inputs: Vec<InputPort>,
         outputs: Vec<OutputPort>,
+        trigger_outputs: Vec<flux_core::TriggerOutput>,
     }
 
-    impl TestOp {
+    impl TriggerSourceOp {
         fn new() -> Self {
             Self {
                 id: Id::new(),
-                inputs: vec![InputPort::new("in", Value::Float(0.0))],
-                outputs: vec![OutputPort::new("out", ValueType::Float)],
-            }
-        }
-
-        fn source() -> Self {
-            Self {
-                id: Id::new(),
-                inputs: vec![],
                 outputs: vec![OutputPort::new("out", ValueType::Float)],
+                trigger_outputs: vec![flux_core::TriggerOutput::new("OnFrame")],
             }
         }
     }
 
-    impl Operator for TestOp {
+    impl Operator for TriggerSourceOp {
         fn id(&self) -> Id {
             self.id
         }
         fn name(&self) -> &'static str {
-            "Test"
+            "TriggerSourceOp"
         }
         fn inputs(&self) -> &[InputPort] {
-            &self.inputs
+            &[]
         }
         fn inputs_mut(&mut self) -> &mut [InputPort] {
-            &mut self.inputs
+            &mut []
         }
         fn outputs(&self) -> &[OutputPort] {
             &self.outputs
@@ -1456,13 +8158,14 @@ mod tests {
         fn outputs_mut(&mut self) -> &mut [OutputPort] {
             &mut self.outputs
         }
-        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
-            if !self.inputs.is_empty() {
-                if let Some((source_id, source_output)) = self.inputs[0].connection {
-                    let val = get_input(source_id, source_output);
-                    self.outputs[0].value = val;
-                }
-            }
+        fn trigger_outputs(&self) -> &[flux_core::TriggerOutput] {
+            &self.trigger_outputs
+        }
+        fn trigger_outputs_mut(&mut self) -> &mut [flux_core::TriggerOutput] {
+            &mut self.trigger_outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            self.outputs[0].set(Value::Float(1.0));
         }
         fn as_any(&self) -> &dyn std::any::Any {
             self
@@ -1472,210 +8175,525 @@ mod tests {
         }
     }
 
-    #[test]
-    fn test_node_added_event() {
-        let mut graph = Graph::new();
-        assert!(!graph.has_pending_events());
+    #[test]
+    fn test_trigger_port_connection() {
+        let mut graph = Graph::new();
+
+        let source = graph.add(TriggerSourceOp::new());
+        let target_id = {
+            let op = TriggerTestOp::new();
+            let id = op.id;
+            graph.add(op);
+            id
+        };
+
+        // Clear events from node additions
+        graph.clear_events();
+
+        // Connect trigger output to trigger input
+        let result = graph.connect_trigger(source, 0, target_id, 0);
+        assert!(result.is_ok());
+
+        // Check events
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 1);
+
+        match &events[0] {
+            GraphEvent::TriggerConnected {
+                source: s,
+                source_output,
+                target: t,
+                target_input,
+            } => {
+                assert_eq!(*s, source);
+                assert_eq!(*source_output, 0);
+                assert_eq!(*t, target_id);
+                assert_eq!(*target_input, 0);
+            }
+            _ => panic!("Expected TriggerConnected event"),
+        }
+    }
+
+    #[test]
+    fn test_trigger_port_connection_invalid_source() {
+        let mut graph = Graph::new();
+
+        let source = graph.add(TestOp::source()); // No trigger outputs
+        let target_id = {
+            let op = TriggerTestOp::new();
+            let id = op.id;
+            graph.add(op);
+            id
+        };
+
+        // Connect should fail - source has no trigger outputs
+        let result = graph.connect_trigger(source, 0, target_id, 0);
+        assert!(result.is_err());
+
+        match result {
+            Err(GraphError::TriggerNotFound {
+                node_id,
+                is_output,
+                index,
+                available,
+            }) => {
+                assert_eq!(node_id, source);
+                assert!(is_output);
+                assert_eq!(index, 0);
+                assert_eq!(available, 0);
+            }
+            _ => panic!("Expected TriggerNotFound error"),
+        }
+    }
+
+    #[test]
+    fn test_trigger_port_connection_invalid_target() {
+        let mut graph = Graph::new();
+
+        let source = graph.add(TriggerSourceOp::new());
+        let target = graph.add(TestOp::new()); // No trigger inputs
+
+        // Connect should fail - target has no trigger inputs
+        let result = graph.connect_trigger(source, 0, target, 0);
+        assert!(result.is_err());
+
+        match result {
+            Err(GraphError::TriggerNotFound {
+                node_id,
+                is_output,
+                index,
+                available,
+            }) => {
+                assert_eq!(node_id, target);
+                assert!(!is_output);
+                assert_eq!(index, 0);
+                assert_eq!(available, 0);
+            }
+            _ => panic!("Expected TriggerNotFound error"),
+        }
+    }
+
+    #[test]
+    fn test_trigger_disconnection() {
+        let mut graph = Graph::new();
+
+        let source = graph.add(TriggerSourceOp::new());
+        let target_id = {
+            let op = TriggerTestOp::new();
+            let id = op.id;
+            graph.add(op);
+            id
+        };
+
+        // Connect
+        graph.connect_trigger(source, 0, target_id, 0).unwrap();
+        graph.clear_events();
+
+        // Disconnect
+        let prev = graph.disconnect_trigger(target_id, 0).unwrap();
+        assert_eq!(prev, Some((source, 0)));
+
+        // Check events
+        let events: Vec<_> = graph.drain_events().collect();
+        assert_eq!(events.len(), 1);
+
+        match &events[0] {
+            GraphEvent::TriggerDisconnected {
+                source: s,
+                source_output,
+                target: t,
+                target_input,
+            } => {
+                assert_eq!(*s, source);
+                assert_eq!(*source_output, 0);
+                assert_eq!(*t, target_id);
+                assert_eq!(*target_input, 0);
+            }
+            _ => panic!("Expected TriggerDisconnected event"),
+        }
+    }
+
+    #[test]
+    fn test_fire_trigger_propagation() {
+        let mut graph = Graph::new();
+
+        let source = graph.add(TriggerSourceOp::new());
+        let target_id = {
+            let op = TriggerTestOp::new();
+            let id = op.id;
+            graph.add(op);
+            id
+        };
+
+        // Connect trigger
+        graph.connect_trigger(source, 0, target_id, 0).unwrap();
+
+        // Fire trigger from source
+        let ctx = EvalContext::new();
+        graph.fire_trigger(source, 0, &ctx);
+
+        // Check that target was triggered
+        let target = graph.get(target_id).unwrap();
+        let test_op = target.as_any().downcast_ref::<TriggerTestOp>().unwrap();
+        assert_eq!(test_op.trigger_count(), 1);
+    }
+
+    #[test]
+    fn test_fire_trigger_cascading() {
+        // Test trigger chain: source -> op1 -> op2
+        let mut graph = Graph::new();
+
+        let source = graph.add(TriggerSourceOp::new());
+
+        let op1_id = {
+            let op = TriggerTestOp::new();
+            let id = op.id;
+            graph.add(op);
+            id
+        };
+
+        let op2_id = {
+            let op = TriggerTestOp::new();
+            let id = op.id;
+            graph.add(op);
+            id
+        };
+
+        // Connect: source[0] -> op1[0]
+        graph.connect_trigger(source, 0, op1_id, 0).unwrap();
 
-        let op = TestOp::source();
-        let id = graph.add(op);
+        // Connect: op1.Done -> op2.OnFrame
+        graph.connect_trigger(op1_id, 0, op2_id, 0).unwrap();
 
-        assert!(graph.has_pending_events());
-        assert_eq!(graph.pending_event_count(), 1);
+        // Fire trigger from source
+        let ctx = EvalContext::new();
+        graph.fire_trigger(source, 0, &ctx);
 
-        let events: Vec<_> = graph.drain_events().collect();
-        assert_eq!(events.len(), 1);
-        match &events[0] {
-            GraphEvent::NodeAdded { id: event_id } => assert_eq!(*event_id, id),
-            _ => panic!("Expected NodeAdded event"),
-        }
+        // Both ops should have been triggered
+        let op1 = graph.get(op1_id).unwrap();
+        let test_op1 = op1.as_any().downcast_ref::<TriggerTestOp>().unwrap();
+        assert_eq!(test_op1.trigger_count(), 1);
 
-        assert!(!graph.has_pending_events());
+        let op2 = graph.get(op2_id).unwrap();
+        let test_op2 = op2.as_any().downcast_ref::<TriggerTestOp>().unwrap();
+        assert_eq!(test_op2.trigger_count(), 1);
     }
 
     #[test]
-    fn test_node_removed_event() {
+    fn test_fire_trigger_fan_out() {
+        // Test trigger fan-out: source -> [op1, op2]
         let mut graph = Graph::new();
-        let op = TestOp::source();
-        let id = graph.add(op);
 
-        // Clear add event
-        graph.clear_events();
-
-        graph.remove(id);
+        let source = graph.add(TriggerSourceOp::new());
 
-        let events: Vec<_> = graph.drain_events().collect();
-        assert_eq!(events.len(), 1);
-        match &events[0] {
-            GraphEvent::NodeRemoved { id: event_id } => assert_eq!(*event_id, id),
-            _ => panic!("Expected NodeRemoved event"),
-        }
-    }
+        let op1_id = {
+            let op = TriggerTestOp::new();
+            let id = op.id;
+            graph.add(op);
+            id
+        };
 
-    #[test]
-    fn test_connected_event() {
-        let mut graph = Graph::new();
-        let source = graph.add(TestOp::source());
-        let target = graph.add(TestOp::new());
+        let op2_id = {
+            let op = TriggerTestOp::new();
+            let id = op.id;
+            graph.add(op);
+            id
+        };
 
-        // Clear add events
-        graph.clear_events();
+        // Connect both to the same trigger output
+        graph.connect_trigger(source, 0, op1_id, 0).unwrap();
+        graph.connect_trigger(source, 0, op2_id, 0).unwrap();
 
-        graph.connect(source, 0, target, 0).unwrap();
+        // Fire trigger from source
+        let ctx = EvalContext::new();
+        graph.fire_trigger(source, 0, &ctx);
 
-        let events: Vec<_> = graph.drain_events().collect();
-        // We expect Connected + OrderRecomputed (from evaluation order)
-        assert!(!events.is_empty());
+        // Both ops should have been triggered
+        let op1 = graph.get(op1_id).unwrap();
+        let test_op1 = op1.as_any().downcast_ref::<TriggerTestOp>().unwrap();
+        assert_eq!(test_op1.trigger_count(), 1);
 
-        let connected = events.iter().find(|e| matches!(e, GraphEvent::Connected { .. }));
-        assert!(connected.is_some());
+        let op2 = graph.get(op2_id).unwrap();
+        let test_op2 = op2.as_any().downcast_ref::<TriggerTestOp>().unwrap();
+        assert_eq!(test_op2.trigger_count(), 1);
+    }
 
-        match connected.unwrap() {
-            GraphEvent::Connected {
-                source: src,
-                source_output,
-                target: tgt,
-                target_input,
-            } => {
-                assert_eq!(*src, source);
-                assert_eq!(*source_output, 0);
-                assert_eq!(*tgt, target);
-                assert_eq!(*target_input, 0);
+    #[test]
+    fn test_trigger_topology_and_remove_cleanup() {
+        // a ~> b ~> c
+        let mut graph = Graph::new();
+        let a = graph.add(TriggerTestOp::new());
+        let b = graph.add(TriggerTestOp::new());
+        let c = graph.add(TriggerTestOp::new());
+        graph.connect_trigger(a, 0, b, 0).unwrap();
+        graph.connect_trigger(b, 0, c, 0).unwrap();
+
+        assert_eq!(graph.trigger_connections().count(), 2);
+        let down = graph.trigger_downstream_of(a);
+        assert_eq!(down.len(), 1);
+        assert_eq!(
+            down[0],
+            TriggerConnection {
+                source_node: a,
+                source_output: 0,
+                source_name: "Done",
+                target_node: b,
+                target_input: 0,
+                target_name: "OnFrame",
             }
-            _ => unreachable!(),
-        }
+        );
+        let up = graph.trigger_upstream_of(c);
+        assert_eq!(up.len(), 1);
+        assert_eq!(up[0].source_node, b);
+        assert_eq!(graph.trigger_upstream_of(b), down);
+        assert!(graph.validate().is_empty());
+        graph.drain_events().for_each(drop);
+
+        let removed = graph.remove(b).unwrap();
+        assert!(removed.trigger_inputs()[0].connection.is_none());
+        assert!(!removed.trigger_outputs()[0].is_connected());
+
+        // Neighbors no longer point at the removed node
+        assert!(!graph.get(a).unwrap().trigger_outputs()[0].is_connected());
+        assert!(graph.get(c).unwrap().trigger_inputs()[0].connection.is_none());
+        assert_eq!(graph.trigger_connections().count(), 0);
+        assert!(graph.validate().is_empty());
+
+        let severed = graph
+            .drain_events()
+            .filter(|e| matches!(e, GraphEvent::TriggerDisconnected { .. }))
+            .count();
+        assert_eq!(severed, 2);
     }
 
     #[test]
-    fn test_disconnected_event() {
+    fn test_validate_reports_inconsistent_triggers() {
         let mut graph = Graph::new();
-        let source = graph.add(TestOp::source());
-        let target = graph.add(TestOp::new());
-        graph.connect(source, 0, target, 0).unwrap();
-
-        // Clear previous events
-        graph.clear_events();
-
-        graph.disconnect(target, 0).unwrap();
+        let a = graph.add(TriggerTestOp::new());
+        let b = graph.add(TriggerTestOp::new());
+        graph.connect_trigger(a, 0, b, 0).unwrap();
+        assert!(graph.validate().is_empty());
+
+        // Edit the ports behind the graph's back
+        let ghost = Id::new();
+        let op_a = graph.get_mut_as::<TriggerTestOp>(a).unwrap();
+        op_a.trigger_outputs[0].connect(ghost, 0);
+        let op_b = graph.get_mut_as::<TriggerTestOp>(b).unwrap();
+        op_b.trigger_inputs[0].disconnect();
+
+        let issues = graph.validate();
+        assert_eq!(issues.len(), 2);
+        let edge = |target_node| Connection {
+            source_node: a,
+            source_output: 0,
+            target_node,
+            target_input: 0,
+            muted: false,
+        };
+        assert!(issues.contains(&GraphIssue::DanglingTrigger(edge(ghost))));
+        assert!(issues.contains(&GraphIssue::UnpairedTrigger(edge(b))));
 
-        let events: Vec<_> = graph.drain_events().collect();
-        assert!(!events.is_empty());
+        // Queries only report edges whose ports exist
+        assert_eq!(graph.trigger_downstream_of(a).len(), 1);
+        assert!(graph.trigger_upstream_of(b).is_empty());
+    }
 
-        let disconnected = events
-            .iter()
-            .find(|e| matches!(e, GraphEvent::Disconnected { .. }));
-        assert!(disconnected.is_some());
+    // =========================================================================
+    // Input Smoothing Tests
+    // =========================================================================
 
-        match disconnected.unwrap() {
-            GraphEvent::Disconnected {
-                target: tgt,
-                target_input,
-            } => {
-                assert_eq!(*tgt, target);
-                assert_eq!(*target_input, 0);
-            }
-            _ => unreachable!(),
-        }
+    fn ctx_at(time: f64) -> EvalContext {
+        let mut ctx = EvalContext::new();
+        ctx.time = time;
+        ctx
     }
 
     #[test]
-    fn test_input_default_changed_event() {
+    fn test_input_smoothing_ramps_float_default() {
         let mut graph = Graph::new();
-        let node = graph.add(TestOp::new());
+        let node = graph.add(CountingOp::new());
+        graph.set_input_override(node, 0, PortOverride::new().with_smoothing(1.0));
+
+        // Initial default 1.0 -> output 2.0
+        assert_eq!(graph.evaluate(node, 0, &ctx_at(0.0)).unwrap(), Value::Float(2.0));
+
+        graph.set_input_default(node, 0, Value::Float(3.0));
+        assert!(graph.is_input_smoothing(node, 0));
+
+        // Ramp starts at the first evaluation after the change
+        assert_eq!(graph.evaluate(node, 0, &ctx_at(10.0)).unwrap(), Value::Float(2.0));
+        // Midpoint: 1.0 -> 3.0 at t=0.5 is 2.0, doubled to 4.0
+        assert_eq!(graph.evaluate(node, 0, &ctx_at(10.5)).unwrap(), Value::Float(4.0));
+        assert!(graph.is_input_smoothing(node, 0));
+        // Endpoint
+        assert_eq!(graph.evaluate(node, 0, &ctx_at(11.0)).unwrap(), Value::Float(6.0));
+        assert!(!graph.is_input_smoothing(node, 0));
+
+        // The stored default is the target throughout
+        assert_eq!(graph.get(node).unwrap().inputs()[0].default, Value::Float(3.0));
+    }
 
-        // Clear add event
-        graph.clear_events();
+    #[test]
+    fn test_input_smoothing_vec3_midpoint() {
+        let mut graph = Graph::new();
+        let node = graph.add(Vec3SinkOp::new());
+        graph.set_input_override(node, 0, PortOverride::new().with_smoothing(2.0));
+        graph.evaluate(node, 0, &ctx_at(0.0)).unwrap();
+
+        graph.set_input_default(node, 0, Value::Vec3([2.0, 4.0, -2.0]));
+        graph.evaluate(node, 0, &ctx_at(0.0)).unwrap();
+        let mid = graph.evaluate(node, 0, &ctx_at(1.0)).unwrap();
+        assert_eq!(mid, Value::Vec3([1.0, 2.0, -1.0]));
+        let end = graph.evaluate(node, 0, &ctx_at(2.5)).unwrap();
+        assert_eq!(end, Value::Vec3([2.0, 4.0, -2.0]));
+    }
 
-        let success = graph.set_input_default(node, 0, Value::Float(42.0));
-        assert!(success);
+    #[test]
+    fn test_input_smoothing_retarget_starts_from_current() {
+        let mut graph = Graph::new();
+        let node = graph.add(CountingOp::new());
+        graph.set_input_override(node, 0, PortOverride::new().with_smoothing(1.0));
+        graph.evaluate(node, 0, &ctx_at(0.0)).unwrap();
+
+        graph.set_input_default(node, 0, Value::Float(3.0));
+        graph.evaluate(node, 0, &ctx_at(0.0)).unwrap();
+        graph.evaluate(node, 0, &ctx_at(0.5)).unwrap(); // input at 2.0
+
+        // Retarget mid-flight: ramp continues from 2.0 toward 0.0
+        graph.set_input_default(node, 0, Value::Float(0.0));
+        assert_eq!(graph.evaluate(node, 0, &ctx_at(0.5)).unwrap(), Value::Float(4.0));
+        assert_eq!(graph.evaluate(node, 0, &ctx_at(1.0)).unwrap(), Value::Float(2.0));
+    }
 
-        let events: Vec<_> = graph.drain_events().collect();
-        assert_eq!(events.len(), 1);
+    #[test]
+    fn test_input_smoothing_disabled_without_override() {
+        let mut graph = Graph::new();
+        let node = graph.add(CountingOp::new());
+        graph.evaluate(node, 0, &ctx_at(0.0)).unwrap();
 
-        match &events[0] {
-            GraphEvent::InputDefaultChanged {
-                node: n,
-                input,
-                value,
-            } => {
-                assert_eq!(*n, node);
-                assert_eq!(*input, 0);
-                assert_eq!(*value, Value::Float(42.0));
-            }
-            _ => panic!("Expected InputDefaultChanged event"),
-        }
+        graph.set_input_default(node, 0, Value::Float(5.0));
+        assert!(!graph.is_input_smoothing(node, 0));
+        assert_eq!(graph.evaluate(node, 0, &ctx_at(0.0)).unwrap(), Value::Float(10.0));
     }
 
     #[test]
-    fn test_order_recomputed_event() {
+    fn test_input_smoothing_skips_connected_inputs() {
         let mut graph = Graph::new();
-        let source = graph.add(TestOp::source());
-        let target = graph.add(TestOp::new());
-        graph.connect(source, 0, target, 0).unwrap();
+        let source = graph.add(FloatSourceOp::new(7.0));
+        let node = graph.add(CountingOp::new());
+        graph.connect(source, 0, node, 0).unwrap();
+        graph.set_input_override(node, 0, PortOverride::new().with_smoothing(1.0));
+
+        graph.set_input_default(node, 0, Value::Float(3.0));
+        assert!(!graph.is_input_smoothing(node, 0));
+        assert_eq!(graph.evaluate(node, 0, &ctx_at(0.0)).unwrap(), Value::Float(14.0));
+    }
 
-        // Clear previous events
-        graph.clear_events();
+    // =========================================================================
+    // Context Binding Tests
+    // =========================================================================
 
-        // Trigger order recomputation via evaluate
-        let ctx = EvalContext::default();
-        let _ = graph.evaluate(target, 0, &ctx);
+    #[test]
+    fn test_context_bound_input_follows_variable() {
+        let mut graph = Graph::new();
+        let node = graph.add(CountingOp::new());
+        assert!(graph.set_input_context_binding(node, 0, "global.intensity", Value::Float(2.0)));
+
+        // The fallback is used while the variable is absent
+        let mut ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(4.0));
+
+        ctx.advance(1.0 / 60.0);
+        ctx.set_float_var("global.intensity", 3.0);
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(6.0));
+
+        // An unchanged variable serves the cached value
+        ctx.advance(1.0 / 60.0);
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(6.0));
+        assert_eq!(compute_count(&graph, node), 2);
+
+        // Int variables are coerced to the Float port
+        ctx.advance(1.0 / 60.0);
+        ctx.float_vars.clear();
+        ctx.set_int_var("global.intensity", 5);
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(10.0));
+
+        // The stored default stays the fallback
+        assert_eq!(graph.get(node).unwrap().inputs()[0].default, Value::Float(2.0));
+        assert_eq!(
+            graph.input_default_source(node, 0),
+            Some(DefaultSource::context_var("global.intensity", Value::Float(2.0)))
+        );
 
-        let events: Vec<_> = graph.drain_events().collect();
-        let order_recomputed = events
-            .iter()
-            .any(|e| matches!(e, GraphEvent::OrderRecomputed));
-        assert!(order_recomputed, "Expected OrderRecomputed event");
+        // Setting a literal source removes the binding
+        graph.set_input_default_source(node, 0, Value::Float(1.5).into());
+        ctx.advance(1.0 / 60.0);
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(3.0));
     }
 
     #[test]
-    fn test_multiple_events_accumulate() {
+    fn test_context_binding_ignored_while_connected() {
         let mut graph = Graph::new();
+        let source = graph.add(FloatSourceOp::new(7.0));
+        let node = graph.add(CountingOp::new());
+        graph.set_input_context_binding(node, 0, "gain", Value::Float(0.0));
+        graph.connect(source, 0, node, 0).unwrap();
+
+        let mut ctx = EvalContext::new();
+        ctx.set_float_var("gain", 1.0);
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(14.0));
+        ctx.set_float_var("gain", 2.0);
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(14.0));
+        assert_eq!(compute_count(&graph, node), 1);
+    }
 
-        // Add multiple nodes without draining
-        let _a = graph.add(TestOp::source());
-        let _b = graph.add(TestOp::source());
-        let _c = graph.add(TestOp::source());
-
-        assert_eq!(graph.pending_event_count(), 3);
+    #[test]
+    fn test_context_binding_event() {
+        let mut graph = Graph::new();
+        let node = graph.add(CountingOp::new());
+        graph.clear_events();
 
+        graph.set_input_context_binding(node, 0, "gain", Value::Float(0.5));
         let events: Vec<_> = graph.drain_events().collect();
-        assert_eq!(events.len(), 3);
-        assert!(events.iter().all(|e| matches!(e, GraphEvent::NodeAdded { .. })));
+        assert!(matches!(
+            &events[..],
+            [GraphEvent::InputSourceChanged { node: n, input: 0, previous, source, .. }]
+                if *n == node
+                    && *previous == DefaultSource::Literal(Value::Float(1.0))
+                    && source.context_var_name() == Some("gain")
+        ));
+        assert!(!graph.set_input_context_binding(node, 3, "gain", Value::Float(0.5)));
     }
 
     // =========================================================================
-    // Phase 1 Feature Tests: CallContext-Aware Caching
+    // Detailed Statistics Tests
     // =========================================================================
 
-    /// Test operator that tracks how many times compute() is called
-    struct CountingOp {
+    /// Operator with a configurable number of float inputs and time variance
+    struct StatsOp {
         id: Id,
         inputs: Vec<InputPort>,
         outputs: Vec<OutputPort>,
-        compute_count: std::cell::Cell<u32>,
+        time_varying: bool,
     }
 
-    impl CountingOp {
-        fn new() -> Self {
+    impl StatsOp {
+        fn new(input_count: usize, time_varying: bool) -> Self {
             Self {
                 id: Id::new(),
-                inputs: vec![InputPort::new("in", Value::Float(1.0))],
-                outputs: vec![OutputPort::new("out", ValueType::Float)],
-                compute_count: std::cell::Cell::new(0),
+                inputs: (0..input_count).map(|_| InputPort::float("in", 0.0)).collect(),
+                outputs: vec![OutputPort::float("out")],
+                time_varying,
             }
         }
-
-        fn get_compute_count(&self) -> u32 {
-            self.compute_count.get()
-        }
     }
 
-    impl Operator for CountingOp {
+    impl Operator for StatsOp {
         fn id(&self) -> Id {
             self.id
         }
         fn name(&self) -> &'static str {
-            "CountingOp"
+            "StatsOp"
         }
         fn inputs(&self) -> &[InputPort] {
             &self.inputs
@@ -1689,164 +8707,208 @@ mod tests {
         fn outputs_mut(&mut self) -> &mut [OutputPort] {
             &mut self.outputs
         }
-        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
-            self.compute_count.set(self.compute_count.get() + 1);
-            // Double the input value
-            if let Some((source_id, source_output)) = self.inputs[0].connection {
-                let val = get_input(source_id, source_output);
-                if let Value::Float(f) = val {
-                    // Use set() to mark output as clean after computation
-                    self.outputs[0].set(Value::Float(f * 2.0));
-                }
-            } else if let Value::Float(f) = self.inputs[0].default {
-                // Use set() to mark output as clean after computation
-                self.outputs[0].set(Value::Float(f * 2.0));
-            }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {}
+        fn is_time_varying(&self) -> bool {
+            self.time_varying
         }
         fn as_any(&self) -> &dyn std::any::Any {
             self
         }
         fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
             self
-        }
-    }
-
-    #[test]
-    fn test_call_context_cache_isolation() {
-        // Test that the same operator evaluated with different CallContexts
-        // gets separate cache entries
-
-        let mut graph = Graph::new();
-        let op = CountingOp::new();
-        let op_id = op.id;
-        graph.add(op);
-
-        // First evaluation with root context
-        let ctx_root = EvalContext::new();
-        let result1 = graph.evaluate(op_id, 0, &ctx_root).unwrap();
-
-        // Second evaluation with different call context (simulating a subroutine call)
-        let ctx_child1 = ctx_root.with_call_context(1);
-        let result2 = graph.evaluate(op_id, 0, &ctx_child1).unwrap();
-
-        // Third evaluation with another different call context
-        let ctx_child2 = ctx_root.with_call_context(2);
-        let result3 = graph.evaluate(op_id, 0, &ctx_child2).unwrap();
-
-        // All results should be the same value (2.0 = 1.0 * 2)
-        assert_eq!(result1, Value::Float(2.0));
-        assert_eq!(result2, Value::Float(2.0));
-        assert_eq!(result3, Value::Float(2.0));
-
-        // The operator should have been computed 3 times (once per context)
-        let op = graph.get(op_id).unwrap();
-        let counting_op = op.as_any().downcast_ref::<CountingOp>().unwrap();
-        assert_eq!(counting_op.get_compute_count(), 3);
+        }
     }
 
     #[test]
-    fn test_same_context_uses_cache() {
-        // Test that evaluating with the same context reuses cached values
-
+    fn test_detailed_stats_known_shape() {
+        // a ==> b -> c -> f      (a feeds both inputs of b)
+        // a -> [conv] -> d
+        // e                      (isolated)
         let mut graph = Graph::new();
-        let op = CountingOp::new();
-        let op_id = op.id;
-        graph.add(op);
-
-        let ctx = EvalContext::new();
-
-        // First evaluation - should compute
-        let result1 = graph.evaluate(op_id, 0, &ctx).unwrap();
-
-        // Second evaluation with same context - should use cache
-        let result2 = graph.evaluate(op_id, 0, &ctx).unwrap();
-
-        // Third evaluation with same context - should still use cache
-        let result3 = graph.evaluate(op_id, 0, &ctx).unwrap();
-
-        // All results should be the same
-        assert_eq!(result1, Value::Float(2.0));
-        assert_eq!(result2, Value::Float(2.0));
-        assert_eq!(result3, Value::Float(2.0));
-
-        // The operator should have been computed only once
-        let op = graph.get(op_id).unwrap();
-        let counting_op = op.as_any().downcast_ref::<CountingOp>().unwrap();
-        assert_eq!(counting_op.get_compute_count(), 1);
+        let a = graph.add(FloatSourceOp::new(1.0));
+        let b = graph.add(StatsOp::new(2, true));
+        let c = graph.add(TestOp::new());
+        let f = graph.add(TestOp::new());
+        let d = graph.add(Vec3SinkOp::new());
+        let _e = graph.add(TestOp::source());
+
+        graph.connect(a, 0, b, 0).unwrap();
+        graph.connect(a, 0, b, 1).unwrap();
+        graph.connect(b, 0, c, 0).unwrap();
+        graph.connect(c, 0, f, 0).unwrap();
+        let conv = graph.connect(a, 0, d, 0).unwrap();
+        assert!(conv.is_some());
+
+        let stats = graph.detailed_stats(|name| (name == "Test").then_some("Testing"));
+
+        assert_eq!(stats.node_count, 7);
+        assert_eq!(stats.connection_count, 6);
+        assert_eq!(stats.max_depth, 3);
+        assert_eq!(stats.max_fan_in, 2);
+        assert_eq!(stats.max_fan_out, 3);
+        assert!((stats.avg_fan_in - 6.0 / 7.0).abs() < 1e-6);
+        assert!((stats.avg_fan_out - 6.0 / 7.0).abs() < 1e-6);
+        assert_eq!(stats.fan_in_histogram, BTreeMap::from([(0, 2), (1, 4), (2, 1)]));
+        assert_eq!(stats.fan_out_histogram, BTreeMap::from([(0, 3), (1, 3), (3, 1)]));
+        assert_eq!(stats.operator_counts.get("Test"), Some(&3));
+        assert_eq!(stats.operator_counts.get("StatsOp"), Some(&1));
+        assert_eq!(stats.operator_counts.get("FloatSource"), Some(&1));
+        assert_eq!(stats.operator_counts.get("Vec3Sink"), Some(&1));
+        assert_eq!(stats.operator_counts.values().sum::<usize>(), 7);
+        assert_eq!(stats.category_counts.get("Testing"), Some(&3));
+        assert_eq!(stats.category_counts.get("Uncategorized"), Some(&4));
+        assert_eq!(stats.time_varying_count, 1);
+        assert_eq!(stats.conversion_count, 1);
+        assert_eq!(stats.island_count, 2);
     }
 
     #[test]
-    fn test_nested_call_contexts_are_isolated() {
-        // Test that nested call contexts (like nested loop iterations) are isolated
+    fn test_detailed_stats_empty_graph() {
+        let graph = Graph::new();
+        let stats = graph.detailed_stats(|_| None);
+        assert_eq!(stats.node_count, 0);
+        assert_eq!(stats.max_depth, 0);
+        assert_eq!(stats.avg_fan_in, 0.0);
+        assert_eq!(stats.island_count, 0);
+    }
 
+    #[test]
+    fn test_detailed_stats_ignores_dangling_inputs() {
         let mut graph = Graph::new();
-        let op = CountingOp::new();
-        let op_id = op.id;
-        graph.add(op);
-
-        let ctx_root = EvalContext::new();
-
-        // Simulate nested loops: outer loop iterations 0 and 1
-        let ctx_outer_0 = ctx_root.with_call_context(0);
-        let ctx_outer_1 = ctx_root.with_call_context(1);
-
-        // Inner loop iterations within outer loop 0
-        let ctx_0_0 = ctx_outer_0.with_call_context(0);
-        let ctx_0_1 = ctx_outer_0.with_call_context(1);
-
-        // Inner loop iterations within outer loop 1
-        let ctx_1_0 = ctx_outer_1.with_call_context(0);
-        let ctx_1_1 = ctx_outer_1.with_call_context(1);
+        let a = graph.add(TestOp::source());
+        let b = graph.add(TestOp::new());
+        let c = graph.add(TestOp::new());
+        graph.connect(a, 0, b, 0).unwrap();
+        graph.get_mut_as::<TestOp>(c).unwrap().inputs[0].connect(Id::new(), 0);
+
+        let stats = graph.detailed_stats(|_| None);
+        assert_eq!(stats.connection_count, 1);
+        assert_eq!(stats.max_fan_in, 1);
+        assert_eq!(stats.avg_fan_in, stats.avg_fan_out);
+        assert_eq!(stats.fan_in_histogram, BTreeMap::from([(0, 2), (1, 1)]));
+        assert_eq!(stats.island_count, 2);
+    }
 
-        // Evaluate all 4 nested contexts
-        graph.evaluate(op_id, 0, &ctx_0_0).unwrap();
-        graph.evaluate(op_id, 0, &ctx_0_1).unwrap();
-        graph.evaluate(op_id, 0, &ctx_1_0).unwrap();
-        graph.evaluate(op_id, 0, &ctx_1_1).unwrap();
+    // =========================================================================
+    // Group Tests
+    // =========================================================================
 
-        // Each nested context should have its own cache entry
-        let op = graph.get(op_id).unwrap();
-        let counting_op = op.as_any().downcast_ref::<CountingOp>().unwrap();
-        assert_eq!(counting_op.get_compute_count(), 4);
+    #[test]
+    fn test_groups_membership_events_and_removal() {
+        let mut graph = Graph::new();
+        let a = graph.add(TestOp::source());
+        let b = graph.add(TestOp::new());
+        let c = graph.add(TestOp::new());
+        graph.drain_events().for_each(drop);
+
+        let fx = graph.create_group("FX", [1.0, 0.0, 0.0, 1.0]);
+        let solo = graph.create_group("Solo", [0.0, 1.0, 0.0, 1.0]);
+        assert!(graph.assign_to_group(a, fx).unwrap());
+        assert!(graph.assign_to_group(b, fx).unwrap());
+        assert!(!graph.assign_to_group(b, fx).unwrap());
+        graph.assign_to_group(b, solo).unwrap();
+        let gone = graph.create_group("Gone", [0.0; 4]);
+        graph.remove_group(gone);
+        assert!(matches!(
+            graph.assign_to_group(c, gone),
+            Err(GraphError::GroupNotFound { group }) if group == gone
+        ));
+        assert_eq!(graph.group_members(fx), Some(&[a, b][..]));
+        assert_eq!(graph.groups_of(b), vec![fx, solo]);
+        assert!(graph.groups_of(c).is_empty());
+
+        let stats = graph.detailed_stats(|_| None);
+        assert_eq!(stats.group_counts, BTreeMap::from([(fx, 2), (solo, 1)]));
+        assert_eq!(stats.ungrouped_count, 1);
+
+        graph.drain_events().for_each(drop);
+        graph.remove(b);
+        let left: Vec<GroupId> = graph
+            .drain_events()
+            .filter_map(|event| match event {
+                GraphEvent::GroupMembershipChanged { group, node, member: false } if node == b => {
+                    Some(group)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(left, vec![fx, solo]);
+        // Groups persist when they lose members, even all of them
+        assert_eq!(graph.group_members(fx), Some(&[a][..]));
+        assert_eq!(graph.group_members(solo), Some(&[][..]));
+        assert!(graph.check_invariants().is_empty());
     }
 
     #[test]
-    fn test_can_operate_in_place_default() {
-        // Test that the default can_operate_in_place() returns false
+    fn test_empty_groups_can_be_removed_automatically() {
+        let mut graph = Graph::new();
+        let a = graph.add(TestOp::source());
+        let b = graph.add(TestOp::source());
+        graph.set_remove_empty_groups(true);
+        let group = graph.create_group("FX", [1.0; 4]);
+        graph.assign_to_group(a, group).unwrap();
+        graph.assign_to_group(b, group).unwrap();
+
+        graph.remove(a);
+        assert!(graph.group(group).is_some());
+        graph.drain_events().for_each(drop);
+        graph.remove(b);
+        assert!(graph.group(group).is_none());
+        assert!(graph
+            .drain_events()
+            .any(|event| matches!(event, GraphEvent::GroupRemoved { group: g } if g == group)));
+    }
 
-        let op = TestOp::new();
-        assert!(!op.can_operate_in_place());
+    #[test]
+    fn test_trash_restore_rejoins_groups() {
+        let mut graph = Graph::new();
+        graph.set_trash_capacity(4);
+        let a = graph.add(TestOp::source());
+        let kept = graph.create_group("Kept", [1.0; 4]);
+        let deleted = graph.create_group("Deleted", [1.0; 4]);
+        graph.assign_to_group(a, kept).unwrap();
+        graph.assign_to_group(a, deleted).unwrap();
+
+        graph.remove(a);
+        assert!(graph.group_members(kept).unwrap().is_empty());
+        graph.remove_group(deleted);
+        graph.restore_from_trash(a).unwrap();
+        assert_eq!(graph.groups_of(a), vec![kept]);
     }
 
-    /// Test operator that declares it can operate in-place
-    struct InPlaceOp {
+    // =========================================================================
+    // Update Rate Tests
+    // =========================================================================
+
+    /// Time-varying source that outputs the current frame number
+    struct FrameSourceOp {
         id: Id,
-        inputs: Vec<InputPort>,
         outputs: Vec<OutputPort>,
+        compute_count: u32,
     }
 
-    impl InPlaceOp {
+    impl FrameSourceOp {
         fn new() -> Self {
             Self {
                 id: Id::new(),
-                inputs: vec![InputPort::new("in", Value::Float(0.0))],
-                outputs: vec![OutputPort::new("out", ValueType::Float)],
+                outputs: vec![OutputPort::new("frame", ValueType::Float)],
+                compute_count: 0,
             }
         }
     }
 
-    impl Operator for InPlaceOp {
+    impl Operator for FrameSourceOp {
         fn id(&self) -> Id {
             self.id
         }
         fn name(&self) -> &'static str {
-            "InPlaceOp"
+            "FrameSourceOp"
         }
         fn inputs(&self) -> &[InputPort] {
-            &self.inputs
+            &[]
         }
         fn inputs_mut(&mut self) -> &mut [InputPort] {
-            &mut self.inputs
+            &mut []
         }
         fn outputs(&self) -> &[OutputPort] {
             &self.outputs
@@ -1854,11 +8916,11 @@ mod tests {
         fn outputs_mut(&mut self) -> &mut [OutputPort] {
             &mut self.outputs
         }
-        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
-            // Use set() to mark output as clean after computation
-            self.outputs[0].set(Value::Float(42.0));
+        fn compute(&mut self, ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            self.compute_count += 1;
+            self.outputs[0].set(Value::Float(ctx.frame as f32));
         }
-        fn can_operate_in_place(&self) -> bool {
+        fn is_time_varying(&self) -> bool {
             true
         }
         fn as_any(&self) -> &dyn std::any::Any {
@@ -1869,76 +8931,544 @@ mod tests {
         }
     }
 
+    fn frame_source_count(graph: &Graph, id: Id) -> u32 {
+        graph
+            .get(id)
+            .unwrap()
+            .as_any()
+            .downcast_ref::<FrameSourceOp>()
+            .unwrap()
+            .compute_count
+    }
+
     #[test]
-    fn test_can_operate_in_place_override() {
-        // Test that operators can override can_operate_in_place() to return true
+    fn test_update_rate_hertz() {
+        let mut graph = Graph::new();
+        let source = FrameSourceOp::new();
+        let source_id = source.id;
+        graph.add(source);
+        graph.set_update_rate(source_id, UpdateRate::Hertz(10.0));
+
+        // One second at 100 fps: 10 updates
+        let mut ctx = EvalContext::new();
+        for _ in 0..100 {
+            graph.evaluate(source_id, 0, &ctx).unwrap();
+            ctx.advance(0.01);
+        }
+        let count = frame_source_count(&graph, source_id);
+        assert!((10..=11).contains(&count), "computed {} times", count);
+    }
 
-        let op = InPlaceOp::new();
-        assert!(op.can_operate_in_place());
+    #[test]
+    fn test_update_rate_on_demand() {
+        let mut graph = Graph::new();
+        let source = FrameSourceOp::new();
+        let source_id = source.id;
+        graph.add(source);
+        graph.set_update_rate(source_id, UpdateRate::OnDemand);
+
+        let mut ctx = EvalContext::new();
+        for _ in 0..5 {
+            graph.evaluate(source_id, 0, &ctx).unwrap();
+            ctx.advance(1.0 / 60.0);
+        }
+        // Only the initial computation
+        assert_eq!(frame_source_count(&graph, source_id), 1);
+
+        graph.request_update(source_id);
+        let result = graph.evaluate(source_id, 0, &ctx).unwrap();
+        assert_eq!(result, Value::Float(5.0));
+        assert_eq!(frame_source_count(&graph, source_id), 2);
+
+        // Request is consumed
+        ctx.advance(1.0 / 60.0);
+        graph.evaluate(source_id, 0, &ctx).unwrap();
+        assert_eq!(frame_source_count(&graph, source_id), 2);
     }
 
     #[test]
-    fn test_clear_cache_clears_all_contexts() {
-        // Test that clear_cache() removes entries for all call contexts
+    fn test_update_rate_default_is_every_frame() {
+        let mut graph = Graph::new();
+        let source = FrameSourceOp::new();
+        let source_id = source.id;
+        graph.add(source);
+        assert_eq!(graph.update_rate(source_id), Some(UpdateRate::EveryFrame));
+        assert_eq!(graph.update_rate(Id::new()), None);
+
+        let mut ctx = EvalContext::new();
+        for _ in 0..3 {
+            graph.evaluate(source_id, 0, &ctx).unwrap();
+            ctx.advance(1.0 / 60.0);
+        }
+        assert_eq!(frame_source_count(&graph, source_id), 3);
+    }
+
+    // =========================================================================
+    // Unit Conversion Tests
+    // =========================================================================
+
+    #[test]
+    fn test_connect_degrees_to_radians_inserts_unit_conversion() {
+        use flux_operators::{RadiansToDegreesOp, SinOp};
 
         let mut graph = Graph::new();
-        let op = CountingOp::new();
-        let op_id = op.id;
-        graph.add(op);
+        // Degrees output: pi/6 rad -> 30 deg
+        let deg_id = graph.add_with_meta(RadiansToDegreesOp::new());
+        graph.set_input_default(deg_id, 0, Value::Float(std::f32::consts::FRAC_PI_6));
+        // Radians input
+        let sin_id = graph.add_with_meta(SinOp::new());
+        assert_eq!(graph.output_unit(deg_id, 0), Some(Unit::Degrees));
+        assert_eq!(graph.input_unit(sin_id, 0), Some(Unit::Radians));
+        graph.clear_events();
 
-        let ctx_root = EvalContext::new();
-        let ctx_child = ctx_root.with_call_context(1);
+        let conv_id = graph.connect(deg_id, 0, sin_id, 0).unwrap().unwrap();
+        assert!(graph.get(conv_id).unwrap().as_any().is::<UnitConversionOp>());
 
-        // Evaluate with both contexts to populate cache
-        graph.evaluate(op_id, 0, &ctx_root).unwrap();
-        graph.evaluate(op_id, 0, &ctx_child).unwrap();
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GraphEvent::UnitConversionInserted {
+                conversion_node,
+                source_unit: Unit::Degrees,
+                target_unit: Unit::Radians,
+            } if *conversion_node == conv_id
+        )));
+
+        // sin(30 deg) == 0.5 without a manual DegreesToRadians node
+        let result = graph.evaluate(sin_id, 0, &EvalContext::new()).unwrap();
+        assert!((result.as_float().unwrap() - 0.5).abs() < 1e-5);
+    }
 
-        // Clear the cache
-        graph.clear_cache();
+    #[test]
+    fn test_connect_incompatible_units_warns() {
+        let mut graph = Graph::new();
+        let source = FloatSourceOp::new(1.0);
+        let source_id = source.id;
+        graph.add(source);
+        let target = CountingOp::new();
+        let target_id = target.id;
+        graph.add(target);
+        graph.set_output_unit(source_id, 0, Some(Unit::Seconds));
+        graph.set_input_unit(target_id, 0, Some(Unit::Degrees));
+        graph.clear_events();
 
-        // Evaluate again - should recompute since cache was cleared
-        graph.evaluate(op_id, 0, &ctx_root).unwrap();
-        graph.evaluate(op_id, 0, &ctx_child).unwrap();
+        // Connects directly, no scaling
+        assert_eq!(graph.connect(source_id, 0, target_id, 0).unwrap(), None);
+        assert_eq!(graph.node_count(), 2);
 
-        // Should have computed 4 times total (2 before clear, 2 after)
-        let op = graph.get(op_id).unwrap();
-        let counting_op = op.as_any().downcast_ref::<CountingOp>().unwrap();
-        assert_eq!(counting_op.get_compute_count(), 4);
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GraphEvent::UnitMismatch {
+                source_unit: Unit::Seconds,
+                target_unit: Unit::Degrees,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_connect_matching_or_missing_units_is_direct() {
+        let mut graph = Graph::new();
+        let source = FloatSourceOp::new(1.0);
+        let source_id = source.id;
+        graph.add(source);
+        let a = CountingOp::new();
+        let a_id = a.id;
+        graph.add(a);
+        let b = CountingOp::new();
+        let b_id = b.id;
+        graph.add(b);
+
+        graph.set_output_unit(source_id, 0, Some(Unit::Hertz));
+        graph.set_input_unit(a_id, 0, Some(Unit::Hertz));
+        // b declares no unit
+
+        assert_eq!(graph.connect(source_id, 0, a_id, 0).unwrap(), None);
+        assert_eq!(graph.connect(source_id, 0, b_id, 0).unwrap(), None);
+        assert_eq!(graph.node_count(), 3);
     }
 
     // =========================================================================
-    // Phase 2 Feature Tests: Auto-Conversion at Connect Time
+    // Splice Tests
     // =========================================================================
 
-    /// Test operator that outputs a Float
-    struct FloatSourceOp {
+    #[test]
+    fn test_splice_multiply_into_add_to_sink() {
+        use flux_operators::BinaryOp;
+
+        let mut graph = Graph::new();
+        let add_id = graph.add(BinaryOp::add());
+        graph.set_input_default(add_id, 0, Value::Float(2.0));
+        graph.set_input_default(add_id, 1, Value::Float(3.0));
+        let sink = CountingOp::new();
+        let sink_id = sink.id;
+        graph.add(sink);
+        graph.connect(add_id, 0, sink_id, 0).unwrap();
+
+        let ctx = EvalContext::new();
+        // (2 + 3) * 2
+        assert_eq!(graph.evaluate(sink_id, 0, &ctx).unwrap(), Value::Float(10.0));
+
+        let mul_id = graph.add(BinaryOp::mul());
+        graph.set_input_default(mul_id, 1, Value::Float(4.0));
+        graph.clear_events();
+
+        let connection = graph.upstream_of(sink_id)[0];
+        graph.splice_node(connection, mul_id, 0, 0).unwrap();
+
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(events.iter().any(|e| matches!(
+            e,
+            GraphEvent::Disconnected { target, target_input: 0, .. } if *target == sink_id
+        )));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, GraphEvent::Connected { .. }))
+                .count(),
+            2
+        );
+
+        // (2 + 3) * 4 * 2
+        assert_eq!(graph.evaluate(sink_id, 0, &ctx).unwrap(), Value::Float(40.0));
+    }
+
+    #[test]
+    fn test_splice_rolls_back_on_incompatible_types() {
+        let mut graph = Graph::new();
+        let source = FloatSourceOp::new(1.0);
+        let source_id = source.id;
+        graph.add(source);
+        let sink = CountingOp::new();
+        let sink_id = sink.id;
+        graph.add(sink);
+        graph.connect(source_id, 0, sink_id, 0).unwrap();
+        // Float -> Vec3 converts, but Vec3 -> Float does not
+        let vec3_id = graph.add(Vec3SinkOp::new());
+        graph.clear_events();
+
+        let connection = graph.upstream_of(sink_id)[0];
+        let result = graph.splice_node(connection, vec3_id, 0, 0);
+        assert!(matches!(result, Err(GraphError::TypeMismatch { .. })));
+
+        // Original edge intact, no leftover conversion nodes or events
+        assert_eq!(graph.node_count(), 3);
+        assert_eq!(graph.upstream_of(sink_id), vec![connection]);
+        assert!(graph.upstream_of(vec3_id).is_empty());
+        assert!(!graph.has_pending_events());
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sink_id, 0, &ctx).unwrap(), Value::Float(2.0));
+    }
+
+    #[test]
+    fn test_splice_missing_connection() {
+        let mut graph = Graph::new();
+        let source = FloatSourceOp::new(1.0);
+        let source_id = source.id;
+        graph.add(source);
+        let sink = CountingOp::new();
+        let sink_id = sink.id;
+        graph.add(sink);
+        let middle_id = graph.add(CountingOp::new());
+
+        let connection = Connection {
+            source_node: source_id,
+            source_output: 0,
+            target_node: sink_id,
+            target_input: 0,
+            muted: false,
+        };
+        let result = graph.splice_node(connection, middle_id, 0, 0);
+        assert!(matches!(result, Err(GraphError::ConnectionNotFound { .. })));
+    }
+
+    #[test]
+    fn test_unsplice_reconnects_neighbours() {
+        let mut graph = Graph::new();
+        let source = FloatSourceOp::new(3.0);
+        let source_id = source.id;
+        graph.add(source);
+        let middle_id = graph.add(CountingOp::new());
+        let sink = CountingOp::new();
+        let sink_id = sink.id;
+        graph.add(sink);
+        graph.connect(source_id, 0, middle_id, 0).unwrap();
+        graph.connect(middle_id, 0, sink_id, 0).unwrap();
+
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sink_id, 0, &ctx).unwrap(), Value::Float(12.0));
+
+        graph.unsplice_node(middle_id).unwrap();
+        assert!(graph.get(middle_id).is_none());
+        assert_eq!(graph.upstream_of(sink_id)[0].source_node, source_id);
+        assert_eq!(graph.evaluate(sink_id, 0, &ctx).unwrap(), Value::Float(6.0));
+    }
+
+    #[test]
+    fn test_unsplice_requires_single_wire() {
+        let mut graph = Graph::new();
+        let source = FloatSourceOp::new(3.0);
+        let source_id = source.id;
+        graph.add(source);
+        let middle_id = graph.add(CountingOp::new());
+        let a_id = graph.add(CountingOp::new());
+        let b_id = graph.add(CountingOp::new());
+        graph.connect(source_id, 0, middle_id, 0).unwrap();
+        graph.connect(middle_id, 0, a_id, 0).unwrap();
+        graph.connect(middle_id, 0, b_id, 0).unwrap();
+
+        let result = graph.unsplice_node(middle_id);
+        assert!(matches!(
+            result,
+            Err(GraphError::NotSpliceable { incoming: 1, outgoing: 2, .. })
+        ));
+        assert!(graph.get(middle_id).is_some());
+    }
+
+    // =========================================================================
+    // Retarget Tests
+    // =========================================================================
+
+    #[test]
+    fn test_retarget_connection_source_is_atomic() {
+        let mut graph = Graph::new();
+        let a = FloatSourceOp::new(1.0);
+        let a_id = a.id;
+        graph.add(a);
+        let b = FloatSourceOp::new(5.0);
+        let b_id = b.id;
+        graph.add(b);
+        let sink = CountingOp::new();
+        let sink_id = sink.id;
+        graph.add(sink);
+        graph.connect(a_id, 0, sink_id, 0).unwrap();
+
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sink_id, 0, &ctx).unwrap(), Value::Float(2.0));
+        graph.clear_events();
+
+        let old = graph.upstream_of(sink_id)[0];
+        assert_eq!(graph.retarget_connection(old, (b_id, 0)).unwrap(), None);
+
+        let new = Connection {
+            source_node: b_id,
+            ..old
+        };
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(matches!(
+            events.as_slice(),
+            [GraphEvent::ConnectionRetargeted { old: o, new: n }] if *o == old && *n == new
+        ));
+        assert_eq!(graph.upstream_of(sink_id), vec![new]);
+        assert_eq!(graph.evaluate(sink_id, 0, &ctx).unwrap(), Value::Float(10.0));
+
+        // Moving the target end onto a Vec3 input inserts a conversion
+        let vec3_id = graph.add(Vec3SinkOp::new());
+        graph.clear_events();
+        let conv_id = graph
+            .retarget_connection_target(new, (vec3_id, 0))
+            .unwrap()
+            .expect("conversion node");
+        assert!(graph.upstream_of(sink_id).is_empty());
+        assert_eq!(graph.upstream_of(vec3_id)[0].source_node, conv_id);
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(events
+            .iter()
+            .any(|e| matches!(e, GraphEvent::ConversionInserted { .. })));
+        assert_eq!(
+            events
+                .iter()
+                .filter(|e| matches!(e, GraphEvent::ConnectionRetargeted { .. }))
+                .count(),
+            1
+        );
+        assert!(!events.iter().any(|e| matches!(
+            e,
+            GraphEvent::Connected { .. } | GraphEvent::Disconnected { .. }
+        )));
+    }
+
+    #[test]
+    fn test_retarget_connection_rejects_cycle() {
+        let mut graph = Graph::new();
+        let source = FloatSourceOp::new(3.0);
+        let source_id = source.id;
+        graph.add(source);
+        let middle_id = graph.add(CountingOp::new());
+        let sink = CountingOp::new();
+        let sink_id = sink.id;
+        graph.add(sink);
+        graph.connect(source_id, 0, middle_id, 0).unwrap();
+        graph.connect(middle_id, 0, sink_id, 0).unwrap();
+        graph.clear_events();
+
+        // Feeding middle from its own downstream sink would close a loop
+        let old = graph.upstream_of(middle_id)[0];
+        let result = graph.retarget_connection(old, (sink_id, 0));
+        assert!(matches!(result, Err(GraphError::CycleDetected { .. })));
+
+        // Original edge untouched and still evaluating
+        assert_eq!(graph.upstream_of(middle_id), vec![old]);
+        assert!(!graph.has_pending_events());
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sink_id, 0, &ctx).unwrap(), Value::Float(12.0));
+
+        let missing = Connection {
+            source_node: sink_id,
+            ..old
+        };
+        let result = graph.retarget_connection(missing, (source_id, 0));
+        assert!(matches!(result, Err(GraphError::ConnectionNotFound { .. })));
+    }
+
+    // =========================================================================
+    // Swap Inputs Tests
+    // =========================================================================
+
+    #[test]
+    fn test_swap_inputs_flips_subtract() {
+        use flux_operators::SubtractOp;
+
+        let mut graph = Graph::new();
+        let ten = FloatSourceOp::new(10.0);
+        let ten_id = ten.id;
+        graph.add(ten);
+        let three = FloatSourceOp::new(3.0);
+        let three_id = three.id;
+        graph.add(three);
+        let sub = graph.add(SubtractOp::new());
+        graph.connect(ten_id, 0, sub, 0).unwrap();
+        graph.connect(three_id, 0, sub, 1).unwrap();
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sub, 0, &ctx).unwrap(), Value::Float(7.0));
+
+        graph.clear_events();
+        graph.swap_inputs(sub, 0, 1).unwrap();
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(matches!(
+            events.as_slice(),
+            [GraphEvent::InputsSwapped { node, input_a: 0, input_b: 1 }] if *node == sub
+        ));
+        assert_eq!(graph.evaluate(sub, 0, &ctx).unwrap(), Value::Float(-7.0));
+        assert_eq!(graph.downstream_of(ten_id)[0].target_input, 1);
+        assert_eq!(graph.downstream_of(three_id)[0].target_input, 0);
+        assert!(graph.check_invariants().is_empty());
+
+        // Swapping back restores the original result
+        graph.swap_inputs(sub, 1, 0).unwrap();
+        assert_eq!(graph.evaluate(sub, 0, &ctx).unwrap(), Value::Float(7.0));
+    }
+
+    #[test]
+    fn test_swap_inputs_moves_connection_to_unconnected_side() {
+        use flux_operators::SubtractOp;
+
+        let mut graph = Graph::new();
+        let source = FloatSourceOp::new(10.0);
+        let source_id = source.id;
+        graph.add(source);
+        let sub = graph.add(SubtractOp::new());
+        graph.connect(source_id, 0, sub, 0).unwrap();
+        graph.set_input_default(sub, 0, Value::Float(1.0));
+        graph.set_input_default(sub, 1, Value::Float(4.0));
+        graph.set_input_override(
+            sub,
+            1,
+            PortOverride {
+                label: Some("Offset".into()),
+                ..Default::default()
+            },
+        );
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sub, 0, &ctx).unwrap(), Value::Float(6.0));
+
+        graph.swap_inputs(sub, 0, 1).unwrap();
+        let inputs = graph.get(sub).unwrap().inputs();
+        assert_eq!(inputs[0].connection, None);
+        assert_eq!(inputs[0].default, Value::Float(4.0));
+        assert_eq!(inputs[1].connection, Some((source_id, 0)));
+        assert_eq!(inputs[1].default, Value::Float(1.0));
+        assert_eq!(
+            graph.get_input_override(sub, 0).and_then(|o| o.label.as_deref()),
+            Some("Offset")
+        );
+        assert!(graph.get_input_override(sub, 1).is_none());
+        assert_eq!(graph.evaluate(sub, 0, &ctx).unwrap(), Value::Float(-6.0));
+        assert!(graph.check_invariants().is_empty());
+    }
+
+    #[test]
+    fn test_swap_inputs_rejects_incompatible_types() {
+        use flux_operators::Vec3ScaleOp;
+
+        let mut graph = Graph::new();
+        let scale = graph.add(Vec3ScaleOp::new());
+        graph.clear_events();
+
+        // Float converts to Vec3 but not back
+        assert!(matches!(
+            graph.swap_inputs(scale, 0, 1),
+            Err(GraphError::IncompatibleInputs { input_a: 0, input_b: 1, .. })
+        ));
+        assert!(matches!(
+            graph.swap_inputs(scale, 0, 2),
+            Err(GraphError::InputNotFound { input_index: 2, .. })
+        ));
+        let inputs = graph.get(scale).unwrap().inputs();
+        assert_eq!(inputs[0].default, Value::Vec3([0.0, 0.0, 0.0]));
+        assert_eq!(inputs[1].default, Value::Float(1.0));
+        assert_eq!(graph.drain_events().count(), 0);
+
+        // An input swapped with itself is left alone
+        graph.swap_inputs(scale, 1, 1).unwrap();
+        assert_eq!(graph.drain_events().count(), 0);
+    }
+
+    // =========================================================================
+    // Budgeted Evaluation Tests
+    // =========================================================================
+
+    /// Test operator that sleeps during compute and adds 1 to its input
+    struct SlowOp {
         id: Id,
+        inputs: Vec<InputPort>,
         outputs: Vec<OutputPort>,
+        time_varying: bool,
+        compute_count: std::cell::Cell<u32>,
     }
 
-    impl FloatSourceOp {
-        fn new(value: f32) -> Self {
-            let mut output = OutputPort::float("Out");
-            output.set(Value::Float(value));
+    impl SlowOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![InputPort::float("In", 0.0)],
+                outputs: vec![OutputPort::float("Out")],
+                time_varying: false,
+                compute_count: std::cell::Cell::new(0),
+            }
+        }
+
+        fn time_varying() -> Self {
             Self {
-                id: Id::new(),
-                outputs: vec![output],
+                time_varying: true,
+                ..Self::new()
             }
         }
     }
 
-    impl Operator for FloatSourceOp {
+    impl Operator for SlowOp {
         fn id(&self) -> Id {
             self.id
         }
         fn name(&self) -> &'static str {
-            "FloatSource"
+            "SlowOp"
         }
         fn inputs(&self) -> &[InputPort] {
-            &[]
+            &self.inputs
         }
         fn inputs_mut(&mut self) -> &mut [InputPort] {
-            &mut []
+            &mut self.inputs
         }
         fn outputs(&self) -> &[OutputPort] {
             &self.outputs
@@ -1946,8 +9476,17 @@ mod tests {
         fn outputs_mut(&mut self) -> &mut [OutputPort] {
             &mut self.outputs
         }
-        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
-            // Value is already set in constructor
+        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
+            self.compute_count.set(self.compute_count.get() + 1);
+            std::thread::sleep(Duration::from_millis(2));
+            let input = match self.inputs[0].connection {
+                Some((source_id, source_output)) => get_input(source_id, source_output),
+                None => self.inputs[0].default.clone(),
+            };
+            self.outputs[0].set(Value::Float(input.as_float().unwrap_or(0.0) + 1.0));
+        }
+        fn is_time_varying(&self) -> bool {
+            self.time_varying
         }
         fn as_any(&self) -> &dyn std::any::Any {
             self
@@ -1957,29 +9496,266 @@ mod tests {
         }
     }
 
-    /// Test operator that accepts a Vec3 input
-    struct Vec3SinkOp {
+    /// Build a chain of `len` SlowOps, returning the graph and node IDs in order.
+    fn slow_chain(len: usize) -> (Graph, Vec<Id>) {
+        let mut graph = Graph::new();
+        let mut ids: Vec<Id> = Vec::new();
+        for i in 0..len {
+            let id = graph.add(SlowOp::new());
+            if i > 0 {
+                graph.connect(ids[i - 1], 0, id, 0).unwrap();
+            }
+            ids.push(id);
+        }
+        (graph, ids)
+    }
+
+    /// Run budgeted slices until complete, returning the value and slice count.
+    fn run_budgeted(graph: &mut Graph, output: Id, ctx: &EvalContext) -> (Value, usize) {
+        let mut slices = 0;
+        loop {
+            slices += 1;
+            match graph
+                .evaluate_budgeted(output, 0, ctx, Duration::from_millis(1))
+                .unwrap()
+            {
+                EvalProgress::Complete(value) => return (value, slices),
+                EvalProgress::Partial { .. } => assert!(slices < 100),
+            }
+        }
+    }
+
+    #[test]
+    fn test_budgeted_matches_unbudgeted() {
+        let (mut graph, ids) = slow_chain(4);
+        let sink = ids[3];
+        let ctx = EvalContext::new();
+
+        let first = graph
+            .evaluate_budgeted(sink, 0, &ctx, Duration::from_millis(1))
+            .unwrap();
+        assert_eq!(first, EvalProgress::Partial { completed: 1, remaining: 3 });
+        assert!(graph.has_pending_budgeted_eval());
+
+        let (value, slices) = run_budgeted(&mut graph, sink, &ctx);
+        assert_eq!(slices, 3);
+        assert!(!graph.has_pending_budgeted_eval());
+
+        let (mut reference, ref_ids) = slow_chain(4);
+        assert_eq!(reference.evaluate(ref_ids[3], 0, &ctx).unwrap(), value);
+        assert_eq!(value, Value::Float(4.0));
+    }
+
+    #[test]
+    fn test_budgeted_large_budget_completes_in_one_slice() {
+        let (mut graph, ids) = slow_chain(3);
+        let ctx = EvalContext::new();
+        let progress = graph
+            .evaluate_budgeted(ids[2], 0, &ctx, Duration::from_secs(10))
+            .unwrap();
+        assert_eq!(progress.value(), Some(&Value::Float(3.0)));
+    }
+
+    #[test]
+    fn test_budgeted_restarts_after_structure_change() {
+        let (mut graph, ids) = slow_chain(4);
+        let ctx = EvalContext::new();
+        let budget = Duration::from_millis(1);
+
+        graph.evaluate_budgeted(ids[3], 0, &ctx, budget).unwrap();
+        graph.evaluate_budgeted(ids[3], 0, &ctx, budget).unwrap();
+
+        // Feed the first node from a new source before the evaluation resumes
+        let source = FloatSourceOp::new(10.0);
+        let source_id = source.id;
+        graph.add(source);
+        graph.connect(source_id, 0, ids[0], 0).unwrap();
+
+        // Restarted over the new five-node order rather than resuming at node 3
+        let progress = graph.evaluate_budgeted(ids[3], 0, &ctx, budget).unwrap();
+        match progress {
+            EvalProgress::Partial { completed, remaining } => {
+                assert_eq!(completed + remaining, 5);
+                assert!(completed <= 2);
+            }
+            EvalProgress::Complete(_) => panic!("Expected partial progress"),
+        }
+
+        let (value, _) = run_budgeted(&mut graph, ids[3], &ctx);
+        assert_eq!(value, Value::Float(14.0));
+    }
+
+    #[test]
+    fn test_budgeted_time_varying_not_recomputed_when_resuming() {
+        let mut graph = Graph::new();
+        let source = SlowOp::time_varying();
+        let source_id = source.id;
+        graph.add(source);
+        let sink = graph.add(SlowOp::new());
+        graph.connect(source_id, 0, sink, 0).unwrap();
+        let _ = graph.add(SlowOp::new());
+
+        let mut ctx = EvalContext::new();
+        let mut slices = 0;
+        loop {
+            slices += 1;
+            let progress = graph
+                .evaluate_budgeted(sink, 0, &ctx, Duration::from_millis(1))
+                .unwrap();
+            if progress.is_complete() {
+                break;
+            }
+            ctx.advance(1.0 / 60.0);
+        }
+        assert!(slices > 1);
+
+        let count = |graph: &Graph| {
+            graph
+                .get(source_id)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<SlowOp>()
+                .unwrap()
+                .compute_count
+                .get()
+        };
+        assert_eq!(count(&graph), 1);
+
+        // A new logical evaluation recomputes it
+        ctx.advance(1.0 / 60.0);
+        run_budgeted(&mut graph, sink, &ctx);
+        assert_eq!(count(&graph), 2);
+    }
+
+    // =========================================================================
+    // List Limit Tests
+    // =========================================================================
+
+    #[test]
+    fn test_wired_huge_range_is_clamped_with_diagnostic() {
+        use flux_operators::{IntAddOp, IntListRangeOp};
+
+        let mut graph = Graph::new();
+        graph.set_max_list_len(1000);
+        assert_eq!(graph.max_list_len(), 1000);
+
+        let count_id = graph.add(IntAddOp::new());
+        graph.set_input_default(count_id, 0, Value::Int(1_000_000_000));
+        let range_id = graph.add(IntListRangeOp::new());
+        graph.connect(count_id, 0, range_id, 1).unwrap();
+        graph.clear_events();
+
+        let ctx = EvalContext::new();
+        let result = graph.evaluate(range_id, 0, &ctx).unwrap();
+        assert_eq!(result.as_int_list().map(<[i32]>::len), Some(1000));
+
+        assert!(matches!(
+            graph.node_errors(range_id),
+            [OperatorError::ListTooLong { requested: 1_000_000_000, limit: 1000 }]
+        ));
+        assert!(graph.node_errors(count_id).is_empty());
+        assert!(graph.drain_events().any(|e| matches!(
+            e,
+            GraphEvent::OperatorError { node, .. } if node == range_id
+        )));
+
+        // A request within the limit clears the diagnostic
+        graph.set_input_default(count_id, 0, Value::Int(10));
+        graph.evaluate(range_id, 0, &ctx).unwrap();
+        assert!(!graph.has_node_errors());
+    }
+
+    #[test]
+    fn test_conversion_respects_list_limit() {
+        let mut graph = Graph::new();
+        graph.set_max_list_len(4);
+        let mut conv = ConversionOp::new(ValueType::Vec3List, ValueType::FloatList);
+        conv.inputs_mut()[0].default = Value::vec3_list(vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let conv_id = graph.add(conv);
+
+        let result = graph.evaluate(conv_id, 0, &EvalContext::new()).unwrap();
+        assert_eq!(result.as_float_list(), Some(&[1.0, 2.0, 3.0, 4.0][..]));
+        assert_eq!(graph.node_errors(conv_id).len(), 1);
+    }
+
+    #[test]
+    fn test_watches_report_changes_per_poll() {
+        let mut graph = Graph::new();
+        let frame_id = graph.add(FrameSourceOp::new());
+        let const_id = graph.add(FloatSourceOp::new(3.0));
+        let frame_watch = graph.watch_output(frame_id, 0).unwrap();
+        let const_watch = graph.watch_output(const_id, 0).unwrap();
+        assert!(graph.watch_output(const_id, 1).is_err());
+
+        let mut seen = Vec::new();
+        for frame in 0..3 {
+            let mut ctx = EvalContext::new();
+            ctx.frame = frame;
+            graph.evaluate(frame_id, 0, &ctx).unwrap();
+            seen.extend(graph.poll_watches());
+        }
+
+        let const_updates: Vec<_> = seen.iter().filter(|u| u.watch() == const_watch).collect();
+        assert_eq!(
+            const_updates,
+            vec![&WatchUpdate::Changed {
+                watch: const_watch,
+                value: Value::Float(3.0),
+                frame: 0
+            }]
+        );
+        let frames: Vec<u64> = seen
+            .iter()
+            .filter_map(|u| match u {
+                WatchUpdate::Changed { watch, frame, .. } if *watch == frame_watch => Some(*frame),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(frames, vec![0, 1, 2]);
+
+        // Nothing changed since the last poll
+        assert!(graph.poll_watches().is_empty());
+
+        graph.remove(frame_id);
+        assert_eq!(
+            graph.poll_watches(),
+            vec![WatchUpdate::Removed { watch: frame_watch, frame: 2 }]
+        );
+        assert!(graph.poll_watches().is_empty());
+
+        assert!(graph.unwatch(const_watch));
+        assert!(!graph.unwatch(frame_watch));
+    }
+
+    /// Records every lifecycle hook call into a shared log
+    struct LifecycleOp {
         id: Id,
+        log: Arc<std::sync::Mutex<Vec<String>>>,
         inputs: Vec<InputPort>,
         outputs: Vec<OutputPort>,
     }
 
-    impl Vec3SinkOp {
-        fn new() -> Self {
+    impl LifecycleOp {
+        fn new(log: &Arc<std::sync::Mutex<Vec<String>>>) -> Self {
             Self {
                 id: Id::new(),
-                inputs: vec![InputPort::new("In", Value::Vec3([0.0, 0.0, 0.0]))],
-                outputs: vec![OutputPort::vec3("Out")],
+                log: Arc::clone(log),
+                inputs: vec![InputPort::new("in", Value::Float(0.0))],
+                outputs: vec![OutputPort::new("out", ValueType::Float)],
             }
         }
+
+        fn record(&self, entry: String) {
+            self.log.lock().unwrap().push(entry);
+        }
     }
 
-    impl Operator for Vec3SinkOp {
+    impl Operator for LifecycleOp {
         fn id(&self) -> Id {
             self.id
         }
         fn name(&self) -> &'static str {
-            "Vec3Sink"
+            "Lifecycle"
         }
         fn inputs(&self) -> &[InputPort] {
             &self.inputs
@@ -1993,209 +9769,368 @@ mod tests {
         fn outputs_mut(&mut self) -> &mut [OutputPort] {
             &mut self.outputs
         }
-        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
-            let input = if let Some((node_id, output_idx)) = self.inputs[0].connection {
-                get_input(node_id, output_idx)
-            } else {
-                self.inputs[0].default.clone()
-            };
-            self.outputs[0].set(input);
-        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {}
         fn as_any(&self) -> &dyn std::any::Any {
             self
         }
         fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
             self
         }
+        fn on_added_to_graph(&mut self) {
+            self.record("added".into());
+        }
+        fn on_removed_from_graph(&mut self) {
+            self.record("removed".into());
+        }
+        fn on_connection_changed(&mut self, input_index: usize, connected: bool) {
+            self.record(format!("input {input_index} connected={connected}"));
+        }
     }
 
     #[test]
-    fn test_connect_exact_type_match() {
-        // When types match exactly, connect directly without conversion node
+    fn test_lifecycle_hook_sequence() {
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
         let mut graph = Graph::new();
         let source = graph.add(TestOp::source());
-        let target = graph.add(TestOp::new());
 
-        // Clear events from adding nodes
-        graph.clear_events();
-
-        // Connect Float -> Float (exact match)
-        let result = graph.connect(source, 0, target, 0);
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap(), None); // No conversion node inserted
+        let id = graph.add(LifecycleOp::new(&log));
+        graph.connect(source, 0, id, 0).unwrap();
+        graph.disconnect(id, 0).unwrap();
+        // Disconnecting an unconnected input is not a change
+        graph.disconnect(id, 0).unwrap();
+        graph.remove(id);
 
-        // Should have emitted Connected event but no ConversionInserted event
-        let events: Vec<_> = graph.drain_events().collect();
-        assert!(events.iter().any(|e| matches!(e, GraphEvent::Connected { .. })));
-        assert!(!events.iter().any(|e| matches!(e, GraphEvent::ConversionInserted { .. })));
+        assert_eq!(
+            *log.lock().unwrap(),
+            vec!["added", "input 0 connected=true", "input 0 connected=false", "removed"]
+        );
     }
 
     #[test]
-    fn test_connect_auto_conversion() {
-        // When types can be coerced, auto-insert conversion node
+    fn test_lifecycle_hooks_on_side_effects() {
+        let log = Arc::new(std::sync::Mutex::new(Vec::new()));
         let mut graph = Graph::new();
-        let float_source = graph.add(FloatSourceOp::new(2.5));
-        let vec3_sink = graph.add(Vec3SinkOp::new());
-
-        // Clear events from adding nodes
-        graph.clear_events();
+        let source = graph.add(TestOp::source());
+        let id = graph.add(LifecycleOp::new(&log));
+        graph.connect(source, 0, id, 0).unwrap();
+        log.lock().unwrap().clear();
+
+        // A failed connection leaves no trace
+        let mut vec_source = TestOp::source();
+        vec_source.outputs[0] = OutputPort::new("out", ValueType::Vec3);
+        let vec_source = graph.add(vec_source);
+        assert!(graph.connect_direct(vec_source, 0, id, 0).is_err());
+        assert!(log.lock().unwrap().is_empty());
+
+        // Removing the upstream node disconnects the input
+        graph.remove(source);
+        assert_eq!(*log.lock().unwrap(), vec!["input 0 connected=false"]);
+    }
 
-        // Connect Float -> Vec3 (requires conversion)
-        let result = graph.connect(float_source, 0, vec3_sink, 0);
-        assert!(result.is_ok());
+    // =========================================================================
+    // Range Clamping Tests
+    // =========================================================================
 
-        let conversion_id = result.unwrap();
-        assert!(conversion_id.is_some()); // Conversion node was inserted
+    #[test]
+    fn test_clamped_input_limits_upstream_value() {
+        let mut graph = Graph::new();
+        let source = graph.add(FloatSourceOp::new(500.0));
+        let doubler = graph.add(CountingOp::new());
+        graph.connect(source, 0, doubler, 0).unwrap();
+        graph.set_input_override(
+            doubler,
+            0,
+            PortOverride::new().with_range(0.0, 10.0).with_clamp(true),
+        );
+        assert!(graph.input_clamp(doubler, 0).is_some());
 
-        let conv_id = conversion_id.unwrap();
+        let ctx = EvalContext::new();
+        let result = graph.evaluate(doubler, 0, &ctx).unwrap();
+        assert_eq!(result.as_float(), Some(20.0));
 
-        // Verify the conversion node exists and has correct types
-        let conv_op = graph.get(conv_id).unwrap();
-        assert_eq!(conv_op.name(), "Convert");
+        // The compiled path enforces the same range
+        let compiled = graph.compile(doubler, 0).unwrap();
+        assert_eq!(compiled.execute(&mut graph, &ctx).as_float(), Some(20.0));
+    }
 
-        // Check events
-        let events: Vec<_> = graph.drain_events().collect();
-        let conversion_event = events.iter().find(|e| {
-            matches!(e, GraphEvent::ConversionInserted { .. })
-        });
-        assert!(conversion_event.is_some());
+    #[test]
+    fn test_clamped_default_snaps_to_step() {
+        let mut graph = Graph::new();
+        let doubler = graph.add(CountingOp::new());
+        graph.set_input_default(doubler, 0, Value::Float(0.37));
+        graph.set_input_override(
+            doubler,
+            0,
+            PortOverride::new()
+                .with_range(0.0, 1.0)
+                .with_step(0.05)
+                .with_clamp(true),
+        );
 
-        if let Some(GraphEvent::ConversionInserted {
-            conversion_node,
-            source_type,
-            target_type,
-        }) = conversion_event
-        {
-            assert_eq!(*conversion_node, conv_id);
-            assert_eq!(*source_type, ValueType::Float);
-            assert_eq!(*target_type, ValueType::Vec3);
-        }
+        let result = graph.evaluate(doubler, 0, &EvalContext::new()).unwrap();
+        assert!((result.as_float().unwrap() - 0.7).abs() < 1e-5);
+        // The stored default is left untouched
+        assert_eq!(graph.get(doubler).unwrap().inputs()[0].default, Value::Float(0.37));
     }
 
     #[test]
-    fn test_connect_auto_conversion_evaluation() {
-        // Verify that auto-conversion works correctly during evaluation
+    fn test_unclamped_range_passes_values_through() {
         let mut graph = Graph::new();
-        let float_source = graph.add(FloatSourceOp::new(2.5));
-        let vec3_sink_id = {
-            let sink = Vec3SinkOp::new();
-            let id = sink.id;
-            graph.add(sink);
-            id
-        };
+        let source = graph.add(FloatSourceOp::new(500.0));
+        let doubler = graph.add(CountingOp::new());
+        graph.connect(source, 0, doubler, 0).unwrap();
+        // A range without clamping stays a UI hint
+        graph.set_input_override(doubler, 0, PortOverride::new().with_range(0.0, 10.0));
+        assert!(graph.input_clamp(doubler, 0).is_none());
+
+        let result = graph.evaluate(doubler, 0, &EvalContext::new()).unwrap();
+        assert_eq!(result.as_float(), Some(1000.0));
+    }
 
-        // Connect with auto-conversion
-        let conversion_id = graph.connect(float_source, 0, vec3_sink_id, 0).unwrap();
-        assert!(conversion_id.is_some());
+    // =========================================================================
+    // Typed Handle Tests
+    // =========================================================================
+
+    #[test]
+    fn test_typed_api_connect_evaluate_disconnect() {
+        let mut graph = Graph::new();
+        let source = NodeId(graph.add(FloatSourceOp::new(2.5)));
+        let doubler = NodeId(graph.add(CountingOp::new()));
+        let vec3_sink = NodeId(graph.add(Vec3SinkOp::new()));
+
+        let direct = graph
+            .connect_refs(OutputRef::new(source, 0), InputRef::new(doubler, 0))
+            .unwrap();
+        assert_eq!(direct, None);
+        let conversion = graph
+            .connect_refs(OutputRef::new(source, 0), InputRef::new(vec3_sink, 0))
+            .unwrap()
+            .expect("Float -> Vec3 inserts a conversion node");
+        assert_eq!(graph.node_name(conversion.id()), Some("Convert"));
+
+        let connection = graph.upstream_of(doubler.id())[0];
+        assert_eq!(connection.source_ref(), OutputRef::new(source, 0));
+        assert_eq!(connection.target_ref(), InputRef::new(doubler, 0));
 
-        // Evaluate the graph
         let ctx = EvalContext::new();
-        let result = graph.evaluate(vec3_sink_id, 0, &ctx).unwrap();
+        let output = OutputRef::new(doubler, 0);
+        assert_eq!(graph.evaluate_ref(output, &ctx).unwrap(), Value::Float(5.0));
+        assert_eq!(
+            graph.evaluate(doubler.id(), 0, &ctx).unwrap(),
+            graph.evaluate_ref(output, &ctx).unwrap()
+        );
 
-        // Float 2.5 should be broadcast to Vec3 [2.5, 2.5, 2.5]
-        assert_eq!(result, Value::Vec3([2.5, 2.5, 2.5]));
+        graph.disconnect_ref(InputRef::new(doubler, 0)).unwrap();
+        assert!(graph.upstream_of(doubler.id()).is_empty());
+        assert!(graph.disconnect_ref(InputRef::new(doubler, 5)).is_err());
+        assert!(graph.evaluate_ref(OutputRef::new(doubler, 3), &ctx).is_err());
     }
 
-    #[test]
-    fn test_connect_incompatible_types() {
-        // When types cannot be coerced, return error
-        let mut graph = Graph::new();
+    // =========================================================================
+    // Hot Swap Tests
+    // =========================================================================
 
-        // String source
-        struct StringSourceOp {
-            id: Id,
-            outputs: Vec<OutputPort>,
+    /// Operator whose ports and formula are chosen at construction, standing
+    /// in for successive versions of a plugin operator
+    struct VersionedOp {
+        id: Id,
+        inputs: Vec<InputPort>,
+        outputs: Vec<OutputPort>,
+        formula: fn(&[f32]) -> f32,
+        adopts_id: bool,
+    }
+
+    impl VersionedOp {
+        /// v1: Out = In * Gain
+        fn v1() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![InputPort::float("In", 1.0), InputPort::float("Gain", 2.0)],
+                outputs: vec![OutputPort::float("Out")],
+                formula: |v| v[0] * v[1],
+                adopts_id: true,
+            }
         }
-        impl StringSourceOp {
-            fn new() -> Self {
-                Self {
-                    id: Id::new(),
-                    outputs: vec![OutputPort::string("Out")],
-                }
+
+        /// v2: Result = In * Gain + Offset (extra input, renamed output)
+        fn v2() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![
+                    InputPort::float("In", 1.0),
+                    InputPort::float("Gain", 1.0),
+                    InputPort::float("Offset", 0.5),
+                ],
+                outputs: vec![OutputPort::float("Result")],
+                formula: |v| v[0] * v[1] + v[2],
+                adopts_id: true,
             }
         }
-        impl Operator for StringSourceOp {
-            fn id(&self) -> Id { self.id }
-            fn name(&self) -> &'static str { "StringSource" }
-            fn inputs(&self) -> &[InputPort] { &[] }
-            fn inputs_mut(&mut self) -> &mut [InputPort] { &mut [] }
-            fn outputs(&self) -> &[OutputPort] { &self.outputs }
-            fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
-            fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {}
-            fn as_any(&self) -> &dyn std::any::Any { self }
-            fn as_any_mut(&mut self) -> &mut dyn std::any::Any { self }
+
+        /// v3: Vec3 output, incompatible with Float consumers
+        fn v3() -> Self {
+            Self {
+                outputs: vec![OutputPort::vec3("Out")],
+                ..Self::v1()
+            }
+        }
+    }
+
+    impl Operator for VersionedOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn set_id(&mut self, id: Id) -> bool {
+            if self.adopts_id {
+                self.id = id;
+            }
+            self.adopts_id
+        }
+        fn name(&self) -> &'static str {
+            "Versioned"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
+            let values: Vec<f32> = self
+                .inputs
+                .iter()
+                .map(|input| match input.connection {
+                    Some((id, index)) => get_input(id, index),
+                    None => input.default.clone(),
+                })
+                .map(|v| v.as_float().unwrap_or(0.0))
+                .collect();
+            if self.outputs[0].value_type == ValueType::Float {
+                self.outputs[0].set(Value::Float((self.formula)(&values)));
+            }
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
         }
+    }
 
-        let string_source = graph.add(StringSourceOp::new());
-        let vec3_sink = graph.add(Vec3SinkOp::new());
+    #[test]
+    fn test_hot_swap_preserves_identity_connections_and_defaults() {
+        let mut graph = Graph::new();
+        let source = graph.add(FloatSourceOp::new(3.0));
+        let node = graph.add(VersionedOp::v1());
+        let sink = graph.add(CountingOp::new());
+        graph.connect(source, 0, node, 0).unwrap();
+        graph.connect(node, 0, sink, 0).unwrap();
+        graph.set_input_default(node, 1, Value::Float(4.0));
 
-        // Connect String -> Vec3 (incompatible)
-        let result = graph.connect(string_source, 0, vec3_sink, 0);
-        assert!(result.is_err());
+        let ctx = EvalContext::new();
+        // (3 * 4) * 2
+        assert_eq!(graph.evaluate(sink, 0, &ctx).unwrap(), Value::Float(24.0));
+        graph.clear_events();
 
-        if let Err(GraphError::TypeMismatch { source_type, target_type, .. }) = result {
-            assert_eq!(source_type, ValueType::String);
-            assert_eq!(target_type, ValueType::Vec3);
-        } else {
-            panic!("Expected TypeMismatch error");
-        }
+        let report = graph.hot_swap(node, Box::new(VersionedOp::v2())).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.kept_inputs, vec![0]);
+        // In and Gain carried by name; the new Offset keeps its own default
+        assert_eq!(report.carried_defaults, vec![0, 1]);
+
+        let op = graph.get(node).unwrap();
+        assert_eq!(op.id(), node);
+        assert_eq!(op.inputs().len(), 3);
+        assert_eq!(op.inputs()[1].default, Value::Float(4.0));
+        assert_eq!(op.outputs()[0].name, "Result");
+        assert!(graph.drain_events().any(|e| matches!(e, GraphEvent::OperatorSwapped { id } if id == node)));
+
+        // Renamed output at the same index and type keeps its consumer
+        assert_eq!(graph.downstream_of(node).len(), 1);
+        // (3 * 4 + 0.5) * 2
+        assert_eq!(graph.evaluate(sink, 0, &ctx).unwrap(), Value::Float(25.0));
     }
 
     #[test]
-    fn test_connect_direct_requires_exact_match() {
-        // connect_direct() should require exact type match, no auto-conversion
+    fn test_hot_swap_reports_incompatible_output() {
         let mut graph = Graph::new();
-        let float_source = graph.add(FloatSourceOp::new(2.5));
-        let vec3_sink = graph.add(Vec3SinkOp::new());
-
-        // connect_direct Float -> Vec3 should fail
-        let result = graph.connect_direct(float_source, 0, vec3_sink, 0);
-        assert!(result.is_err());
+        let node = graph.add(VersionedOp::v1());
+        let sink = graph.add(CountingOp::new());
+        graph.connect(node, 0, sink, 0).unwrap();
+
+        let report = graph.hot_swap(node, Box::new(VersionedOp::v3())).unwrap();
+        assert!(!report.is_clean());
+        assert_eq!(
+            report.dropped_outputs,
+            vec![Connection {
+                source_node: node,
+                source_output: 0,
+                target_node: sink,
+                target_input: 0,
+                muted: false,
+            }]
+        );
+        assert!(graph.upstream_of(sink).is_empty());
 
-        if let Err(GraphError::TypeMismatch { .. }) = result {
-            // Expected
-        } else {
-            panic!("Expected TypeMismatch error from connect_direct");
-        }
+        // Operators that can't adopt the node's ID are rejected untouched
+        let stubborn = VersionedOp {
+            adopts_id: false,
+            ..VersionedOp::v1()
+        };
+        assert!(matches!(
+            graph.hot_swap(node, Box::new(stubborn)),
+            Err(GraphError::IdMismatch { node_id, .. }) if node_id == node
+        ));
+        assert_eq!(graph.get(node).unwrap().outputs()[0].value_type, ValueType::Vec3);
     }
 
     // =========================================================================
-    // Trigger System Tests
+    // Port Change Tests
     // =========================================================================
 
-    /// Operator with trigger ports for testing push-based execution
-    struct TriggerTestOp {
+    /// Outputs In + 1, In + 2 and In + 3; can drop an output on its next
+    /// compute, like an operator regenerating its ports
+    struct ChannelsOp {
         id: Id,
         inputs: Vec<InputPort>,
         outputs: Vec<OutputPort>,
-        trigger_inputs: Vec<flux_core::TriggerInput>,
-        trigger_outputs: Vec<flux_core::TriggerOutput>,
-        trigger_count: std::cell::Cell<usize>,
+        /// Offset added by each output
+        offsets: Vec<f32>,
+        remove_on_compute: Option<usize>,
+        port_change: Option<PortChange>,
     }
 
-    impl TriggerTestOp {
+    impl ChannelsOp {
         fn new() -> Self {
             Self {
                 id: Id::new(),
-                inputs: vec![InputPort::new("in", Value::Float(0.0))],
-                outputs: vec![OutputPort::new("out", ValueType::Float)],
-                trigger_inputs: vec![flux_core::TriggerInput::new("OnFrame")],
-                trigger_outputs: vec![flux_core::TriggerOutput::new("Done")],
-                trigger_count: std::cell::Cell::new(0),
+                inputs: vec![InputPort::float("In", 0.0)],
+                outputs: ["A", "B", "C"].into_iter().map(OutputPort::float).collect(),
+                offsets: vec![1.0, 2.0, 3.0],
+                remove_on_compute: None,
+                port_change: None,
             }
         }
 
-        fn trigger_count(&self) -> usize {
-            self.trigger_count.get()
+        fn remove_output(&mut self, index: usize) -> PortChange {
+            self.outputs.remove(index);
+            self.offsets.remove(index);
+            PortChange::new().remove_output(index)
         }
     }
 
-    impl Operator for TriggerTestOp {
+    impl Operator for ChannelsOp {
         fn id(&self) -> Id {
             self.id
         }
         fn name(&self) -> &'static str {
-            "TriggerTestOp"
+            "Channels"
         }
         fn inputs(&self) -> &[InputPort] {
             &self.inputs
@@ -2209,35 +10144,22 @@ mod tests {
         fn outputs_mut(&mut self) -> &mut [OutputPort] {
             &mut self.outputs
         }
-        fn trigger_inputs(&self) -> &[flux_core::TriggerInput] {
-            &self.trigger_inputs
-        }
-        fn trigger_inputs_mut(&mut self) -> &mut [flux_core::TriggerInput] {
-            &mut self.trigger_inputs
-        }
-        fn trigger_outputs(&self) -> &[flux_core::TriggerOutput] {
-            &self.trigger_outputs
-        }
-        fn trigger_outputs_mut(&mut self) -> &mut [flux_core::TriggerOutput] {
-            &mut self.trigger_outputs
-        }
-        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
-            self.outputs[0].set(Value::Float(42.0));
-        }
-        fn on_triggered(
-            &mut self,
-            trigger_index: usize,
-            _ctx: &EvalContext,
-            _get_input: flux_core::InputResolver,
-        ) -> Vec<usize> {
-            if trigger_index == 0 {
-                self.trigger_count.set(self.trigger_count.get() + 1);
-                // Fire "Done" trigger
-                vec![0]
-            } else {
-                vec![]
+        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
+            if let Some(index) = self.remove_on_compute.take() {
+                self.port_change = Some(self.remove_output(index));
+            }
+            let value = match self.inputs[0].connection {
+                Some((id, index)) => get_input(id, index),
+                None => self.inputs[0].default.clone(),
+            };
+            let value = value.as_float().unwrap_or(0.0);
+            for (output, offset) in self.outputs.iter_mut().zip(&self.offsets) {
+                output.set(Value::Float(value + offset));
             }
         }
+        fn take_port_change(&mut self) -> Option<PortChange> {
+            self.port_change.take()
+        }
         fn as_any(&self) -> &dyn std::any::Any {
             self
         }
@@ -2246,35 +10168,328 @@ mod tests {
         }
     }
 
-    /// Source operator that has trigger outputs but no inputs
-    struct TriggerSourceOp {
+    #[test]
+    fn test_removed_output_remaps_later_consumers() {
+        let mut graph = Graph::new();
+        let channels = graph.add(ChannelsOp::new());
+        let sink_b = graph.add(CountingOp::new());
+        let sink_c = graph.add(CountingOp::new());
+        graph.connect(channels, 1, sink_b, 0).unwrap();
+        graph.connect(channels, 2, sink_c, 0).unwrap();
+
+        let ctx = EvalContext::new();
+        // (0 + 3) * 2
+        assert_eq!(graph.evaluate(sink_c, 0, &ctx).unwrap(), Value::Float(6.0));
+        graph.clear_events();
+
+        // The operator drops B while computing
+        graph.get_mut_as::<ChannelsOp>(channels).unwrap().remove_on_compute = Some(1);
+        graph.set_input_default(channels, 0, Value::Float(1.0));
+        // (1 + 3) * 2: C is now output 1
+        assert_eq!(graph.evaluate(sink_c, 0, &ctx).unwrap(), Value::Float(8.0));
+        assert_eq!(graph.consumers_of(channels, 1), &[(sink_c, 0)]);
+        assert!(graph.upstream_of(sink_b).is_empty());
+
+        let old = Connection {
+            source_node: channels,
+            source_output: 2,
+            target_node: sink_c,
+            target_input: 0,
+            muted: false,
+        };
+        let new = Connection {
+            source_output: 1,
+            ..old
+        };
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(events.iter().any(
+            |e| matches!(e, GraphEvent::ConnectionRetargeted { old: o, new: n } if *o == old && *n == new)
+        ));
+        assert!(events.iter().any(
+            |e| matches!(e, GraphEvent::Disconnected { target, source_output: 1, .. } if *target == sink_b)
+        ));
+        assert!(events.iter().any(|e| matches!(e, GraphEvent::PortsChanged { node } if *node == channels)));
+    }
+
+    #[test]
+    fn test_notify_ports_changed_reports_and_moves_watches() {
+        let mut graph = Graph::new();
+        let channels = graph.add(ChannelsOp::new());
+        let sink = graph.add(CountingOp::new());
+        graph.connect(channels, 2, sink, 0).unwrap();
+        let watch_b = graph.watch_output(channels, 1).unwrap();
+        let watch_c = graph.watch_output(channels, 2).unwrap();
+        let ctx = EvalContext::new();
+        graph.evaluate(sink, 0, &ctx).unwrap();
+        graph.poll_watches();
+
+        // Remove A and move C to the front: C -> 0, B -> 1
+        let change = {
+            let op = graph.get_mut_as::<ChannelsOp>(channels).unwrap();
+            op.remove_output(0);
+            op.outputs.swap(0, 1);
+            op.offsets.swap(0, 1);
+            PortChange::new().outputs_by_name(["A", "B", "C"])
+        };
+        let report = graph.notify_ports_changed(channels, change).unwrap();
+        assert!(report.is_clean());
+        assert_eq!(report.remapped.len(), 1);
+        assert_eq!(graph.consumers_of(channels, 0), &[(sink, 0)]);
+
+        // The sink is recomputed from the moved output
+        graph.set_input_default(channels, 0, Value::Float(2.0));
+        assert_eq!(graph.evaluate(sink, 0, &ctx).unwrap(), Value::Float(10.0));
+        let updates = graph.poll_watches();
+        assert!(updates.contains(&WatchUpdate::Changed {
+            watch: watch_c,
+            value: Value::Float(5.0),
+            frame: 0
+        }));
+        assert!(updates.contains(&WatchUpdate::Changed {
+            watch: watch_b,
+            value: Value::Float(4.0),
+            frame: 0
+        }));
+
+        assert!(matches!(
+            graph.notify_ports_changed(Id::new(), PortChange::new()),
+            Err(GraphError::NodeNotFound { .. })
+        ));
+    }
+
+    // =========================================================================
+    // Consumer Index Tests
+    // =========================================================================
+
+    fn sorted_consumers(graph: &Graph, node: Id, output: usize) -> Vec<(Id, usize)> {
+        let mut consumers = graph.consumers_of(node, output).to_vec();
+        consumers.sort_by_key(|&(id, input)| (*id.as_uuid(), input));
+        consumers
+    }
+
+    #[test]
+    fn test_consumer_index_tracks_every_mutation() {
+        use flux_operators::SumOp;
+
+        let mut graph = Graph::new();
+        let a = graph.add(FloatSourceOp::new(1.0));
+        let b = graph.add(FloatSourceOp::new(2.0));
+        let sum = graph.add(SumOp::new());
+        let middle = graph.add(CountingOp::new());
+        let sink = graph.add(CountingOp::new());
+
+        // Multi-input fan-in, including the same source twice
+        graph.connect(a, 0, sum, 0).unwrap();
+        graph.connect(b, 0, sum, 0).unwrap();
+        graph.connect(a, 0, sum, 0).unwrap();
+        graph.connect(sum, 0, middle, 0).unwrap();
+        graph.connect(middle, 0, sink, 0).unwrap();
+        assert_eq!(graph.output_fanout(a, 0), 2);
+        assert_eq!(graph.consumers_of(sum, 0), &[(middle, 0)]);
+        assert!(graph.verify_consumer_index());
+
+        // Replacing a single-input connection moves the consumer
+        graph.connect(b, 0, sink, 0).unwrap();
+        assert_eq!(graph.output_fanout(middle, 0), 0);
+        assert_eq!(graph.output_fanout(b, 0), 2);
+        assert!(graph.verify_consumer_index());
+
+        // A rejected cycle leaves the index untouched
+        assert!(graph.connect(middle, 0, sum, 0).is_err());
+        assert_eq!(graph.output_fanout(middle, 0), 0);
+
+        // Auto-inserted conversions are indexed on both sides
+        let vec3_sink = graph.add(Vec3SinkOp::new());
+        let conversion = graph.connect(a, 0, vec3_sink, 0).unwrap().unwrap();
+        assert_eq!(graph.consumers_of(conversion, 0), &[(vec3_sink, 0)]);
+        assert_eq!(graph.output_fanout(a, 0), 3);
+        assert!(graph.verify_consumer_index());
+
+        // Splice and unsplice
+        let spliced = graph.add(CountingOp::new());
+        let connection = graph.upstream_of(sink)[0];
+        graph.splice_node(connection, spliced, 0, 0).unwrap();
+        assert_eq!(graph.consumers_of(spliced, 0), &[(sink, 0)]);
+        assert!(graph.verify_consumer_index());
+        graph.unsplice_node(spliced).unwrap();
+        assert_eq!(sorted_consumers(&graph, b, 0), {
+            let mut expected = vec![(sum, 0), (sink, 0)];
+            expected.sort_by_key(|&(id, input)| (*id.as_uuid(), input));
+            expected
+        });
+        assert!(graph.verify_consumer_index());
+
+        // Removing a multi-input source drops every edge it fed; removing the
+        // multi-input target drops its incoming edges
+        graph.remove(a).unwrap();
+        assert_eq!(graph.upstream_of(sum).len(), 1);
+        assert!(graph.consumers_of(a, 0).is_empty());
+        assert!(graph.verify_consumer_index());
+        let removed = graph.remove(sum).unwrap();
+        assert_eq!(graph.consumers_of(b, 0), &[(sink, 0)]);
+        assert!(graph.consumers_of(sum, 0).is_empty());
+        assert!(graph.verify_consumer_index());
+
+        // Re-adding a removed operator restores its incoming edges
+        graph.add_boxed(removed);
+        assert_eq!(graph.output_fanout(b, 0), 2);
+        assert!(graph.verify_consumer_index());
+
+        graph.disconnect(sum, 0).unwrap();
+        graph.disconnect(sink, 0).unwrap();
+        assert_eq!(graph.output_fanout(b, 0), 0);
+        assert!(graph.verify_consumer_index());
+    }
+
+    #[test]
+    fn test_consumer_index_survives_hot_swap() {
+        let mut graph = Graph::new();
+        let source = graph.add(FloatSourceOp::new(1.0));
+        let node = graph.add(VersionedOp::v1());
+        let sink = graph.add(CountingOp::new());
+        graph.connect(source, 0, node, 0).unwrap();
+        graph.connect(source, 0, node, 1).unwrap();
+        graph.connect(node, 0, sink, 0).unwrap();
+
+        graph.hot_swap(node, Box::new(VersionedOp::v2())).unwrap();
+        assert_eq!(graph.output_fanout(source, 0), 2);
+        assert_eq!(graph.consumers_of(node, 0), &[(sink, 0)]);
+        assert!(graph.verify_consumer_index());
+
+        // v3 changes the output type, dropping the downstream edge
+        graph.hot_swap(node, Box::new(VersionedOp::v3())).unwrap();
+        assert_eq!(graph.output_fanout(node, 0), 0);
+        assert!(graph.verify_consumer_index());
+        assert_eq!(graph.downstream_of(source).len(), graph.output_fanout(source, 0));
+    }
+
+    #[test]
+    fn test_rejected_cycle_keeps_previous_connection() {
+        let mut graph = Graph::new();
+        let source = graph.add(FloatSourceOp::new(1.0));
+        let middle = graph.add(CountingOp::new());
+        let sink = graph.add(CountingOp::new());
+        graph.connect(source, 0, middle, 0).unwrap();
+        graph.connect(middle, 0, sink, 0).unwrap();
+
+        // Replacing middle's source with its own consumer is rejected
+        assert!(graph.connect(sink, 0, middle, 0).is_err());
+        assert_eq!(graph.get(middle).unwrap().inputs()[0].connection, Some((source, 0)));
+        assert!(graph.verify_consumer_index());
+        assert!(graph.check_invariants().is_empty());
+    }
+
+    #[test]
+    fn test_check_invariants() {
+        let mut graph = Graph::new();
+        let source = graph.add(FloatSourceOp::new(1.0));
+        let sink = graph.add(CountingOp::new());
+        graph.connect(source, 0, sink, 0).unwrap();
+        graph.evaluate(sink, 0, &EvalContext::new()).unwrap();
+        assert!(graph.check_invariants().is_empty());
+
+        // Connect behind the graph's back: the consumer index goes stale
+        let ghost = Id::new();
+        graph.get_mut_as::<CountingOp>(sink).unwrap().inputs[0].connect(ghost, 0);
+        let violations = graph.check_invariants();
+        assert!(violations.iter().any(|v| v.contains("consumer index")), "{:?}", violations);
+        assert!(violations.iter().any(|v| v.contains("Dangling")), "{:?}", violations);
+    }
+
+    // =========================================================================
+    // Freeze & Hold Tests
+    // =========================================================================
+
+    #[test]
+    fn test_freeze_output_pins_branch() {
+        let mut graph = Graph::new();
+        let head = graph.add(CountingOp::new());
+        let middle = graph.add(CountingOp::new());
+        let sink = graph.add(CountingOp::new());
+        graph.connect(head, 0, middle, 0).unwrap();
+        graph.connect(middle, 0, sink, 0).unwrap();
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sink, 0, &ctx).unwrap(), Value::Float(8.0));
+
+        graph.freeze_output(middle, 0, true).unwrap();
+        assert!(graph.is_output_frozen(middle, 0));
+        assert_eq!(graph.stats().frozen_output_count, 1);
+        assert!(graph.validate().is_empty());
+        assert!(graph.freeze_output(middle, 1, true).is_err());
+
+        // Editing upstream doesn't reach past the frozen output
+        graph.set_input_default(head, 0, Value::Float(5.0));
+        assert_eq!(graph.evaluate(sink, 0, &ctx).unwrap(), Value::Float(8.0));
+        assert_eq!(graph.evaluate(middle, 0, &ctx).unwrap(), Value::Float(4.0));
+        assert_eq!(compute_count(&graph, middle), 1);
+        assert_eq!(compute_count(&graph, head), 2);
+
+        graph.freeze_output(middle, 0, false).unwrap();
+        assert_eq!(graph.stats().frozen_output_count, 0);
+        assert_eq!(graph.evaluate(sink, 0, &ctx).unwrap(), Value::Float(40.0));
+        assert_eq!(compute_count(&graph, middle), 2);
+    }
+
+    #[test]
+    fn test_validate_reports_stale_freeze() {
+        let mut graph = Graph::new();
+        let node = graph.add(VersionedOp::v1());
+        graph.evaluate(node, 0, &EvalContext::new()).unwrap();
+        graph.freeze_output(node, 0, true).unwrap();
+
+        // v3's output is a Vec3, which the pinned Float can't stand in for
+        graph.hot_swap(node, Box::new(VersionedOp::v3())).unwrap();
+        assert_eq!(graph.validate(), vec![GraphIssue::StaleFreeze(OutputRef::new(node, 0))]);
+    }
+
+    #[test]
+    fn test_freeze_before_first_evaluation_pins_first_result() {
+        use flux_operators::ListFirstOp;
+
+        let mut graph = Graph::new();
+        let first = graph.add(ListFirstOp::new());
+        graph.set_input_default(first, 0, Value::IntList(vec![7, 8].into()));
+        graph.freeze_output(first, 0, true).unwrap();
+
+        // The first evaluation turns the Float output into an Int and pins it
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(first, 0, &ctx).unwrap(), Value::Int(7));
+        assert!(graph.validate().is_empty());
+        assert!(graph.check_invariants().is_empty());
+
+        graph.set_input_default(first, 0, Value::IntList(vec![9].into()));
+        assert_eq!(graph.evaluate(first, 0, &ctx).unwrap(), Value::Int(7));
+    }
+
+    /// Outputs 1.0, reporting an error while its input is positive
+    struct FlakyOp {
         id: Id,
+        inputs: Vec<InputPort>,
         outputs: Vec<OutputPort>,
-        trigger_outputs: Vec<flux_core::TriggerOutput>,
     }
 
-    impl TriggerSourceOp {
+    impl FlakyOp {
         fn new() -> Self {
             Self {
                 id: Id::new(),
-                outputs: vec![OutputPort::new("out", ValueType::Float)],
-                trigger_outputs: vec![flux_core::TriggerOutput::new("OnFrame")],
+                inputs: vec![InputPort::float("Fail", 0.0)],
+                outputs: vec![OutputPort::float("Out")],
             }
         }
     }
 
-    impl Operator for TriggerSourceOp {
+    impl Operator for FlakyOp {
         fn id(&self) -> Id {
             self.id
         }
         fn name(&self) -> &'static str {
-            "TriggerSourceOp"
+            "Flaky"
         }
         fn inputs(&self) -> &[InputPort] {
-            &[]
+            &self.inputs
         }
         fn inputs_mut(&mut self) -> &mut [InputPort] {
-            &mut []
+            &mut self.inputs
         }
         fn outputs(&self) -> &[OutputPort] {
             &self.outputs
@@ -2282,14 +10497,12 @@ mod tests {
         fn outputs_mut(&mut self) -> &mut [OutputPort] {
             &mut self.outputs
         }
-        fn trigger_outputs(&self) -> &[flux_core::TriggerOutput] {
-            &self.trigger_outputs
-        }
-        fn trigger_outputs_mut(&mut self) -> &mut [flux_core::TriggerOutput] {
-            &mut self.trigger_outputs
-        }
-        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
-            self.outputs[0].set(Value::Float(1.0));
+        fn compute(&mut self, ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            let failing = self.inputs[0].default.as_float().unwrap_or(0.0) > 0.0;
+            if failing {
+                ctx.report_error(OperatorError::evaluation_failed(self.id, "flaky"));
+            }
+            self.outputs[0].set(Value::Float(if failing { 0.0 } else { 1.0 }));
         }
         fn as_any(&self) -> &dyn std::any::Any {
             self
@@ -2300,245 +10513,684 @@ mod tests {
     }
 
     #[test]
-    fn test_trigger_port_connection() {
-        let mut graph = Graph::new();
+    fn test_hold_last_valid_bridges_rewiring_and_errors() {
+        use flux_operators::HoldLastValidOp;
 
-        let source = graph.add(TriggerSourceOp::new());
-        let target_id = {
-            let op = TriggerTestOp::new();
-            let id = op.id;
-            graph.add(op);
-            id
+        let mut graph = Graph::new();
+        let source = graph.add(FloatSourceOp::new(3.0));
+        let hold = graph.add(HoldLastValidOp::new());
+        let sink = graph.add(CountingOp::new());
+        graph.connect(source, 0, hold, 0).unwrap();
+        graph.connect(hold, 0, sink, 0).unwrap();
+        let mut ctx = EvalContext::new();
+        let frame = |graph: &mut Graph, ctx: &mut EvalContext, time: f64| {
+            ctx.time = time;
+            ctx.frame += 1;
+            graph.evaluate(sink, 0, ctx).unwrap()
         };
+        assert_eq!(frame(&mut graph, &mut ctx, 0.0), Value::Float(6.0));
+
+        // Disconnecting mid-run keeps the value instead of dropping to the default
+        graph.disconnect(hold, 0).unwrap();
+        for time in [0.1, 0.2, 5.0] {
+            assert_eq!(frame(&mut graph, &mut ctx, time), Value::Float(6.0));
+        }
+        assert_eq!(graph.evaluate(hold, 1, &ctx).unwrap(), Value::Bool(true));
+
+        // Rewired to a source that starts failing: its error output is ignored
+        let flaky = graph.add(FlakyOp::new());
+        graph.connect(flaky, 0, hold, 0).unwrap();
+        assert_eq!(frame(&mut graph, &mut ctx, 6.0), Value::Float(2.0));
+        graph.set_input_default(flaky, 0, Value::Float(1.0));
+        assert_eq!(frame(&mut graph, &mut ctx, 7.0), Value::Float(2.0));
+        assert_eq!(graph.node_errors(flaky).len(), 1);
+        graph.set_input_default(flaky, 0, Value::Float(0.0));
+        assert_eq!(frame(&mut graph, &mut ctx, 8.0), Value::Float(2.0));
+        assert_eq!(graph.evaluate(hold, 1, &ctx).unwrap(), Value::Bool(false));
+    }
 
-        // Clear events from node additions
-        graph.clear_events();
-
-        // Connect trigger output to trigger input
-        let result = graph.connect_trigger(source, 0, target_id, 0);
-        assert!(result.is_ok());
+    #[test]
+    fn test_evaluate_as_extracts_coerces_and_reports_mismatch() {
+        use flux_operators::IntAddOp;
 
-        // Check events
-        let events: Vec<_> = graph.drain_events().collect();
-        assert_eq!(events.len(), 1);
+        let mut graph = Graph::new();
+        let float = graph.add(FloatSourceOp::new(2.5));
+        let int = graph.add(IntAddOp::new());
+        graph.set_input_default(int, 0, Value::Int(3));
+        let ctx = EvalContext::new();
 
-        match &events[0] {
-            GraphEvent::TriggerConnected {
-                source: s,
-                source_output,
-                target: t,
-                target_input,
-            } => {
-                assert_eq!(*s, source);
-                assert_eq!(*source_output, 0);
-                assert_eq!(*t, target_id);
-                assert_eq!(*target_input, 0);
+        assert_eq!(graph.evaluate_as::<f32>(float, 0, &ctx).unwrap(), 2.5);
+        assert_eq!(graph.evaluate_as::<i32>(int, 0, &ctx).unwrap(), 3);
+        assert_eq!(graph.evaluate_as::<f32>(int, 0, &ctx).unwrap(), 3.0);
+        assert_eq!(graph.evaluate_as::<[f32; 3]>(float, 0, &ctx).unwrap(), [2.5; 3]);
+
+        let err = graph.evaluate_as::<Vec<String>>(int, 0, &ctx).unwrap_err();
+        assert!(matches!(
+            err,
+            EvalTypeError::TypeMismatch {
+                operator: "IntAdd",
+                output: 0,
+                actual: ValueType::Int,
+                requested: ValueType::StringList,
+                ..
             }
-            _ => panic!("Expected TriggerConnected event"),
-        }
+        ));
+        let message = err.to_string();
+        assert!(message.contains("Output 0 of 'IntAdd'"), "{}", message);
+        assert!(message.contains("is Int, which cannot be read as StringList"), "{}", message);
+
+        let missing = graph.evaluate_as::<f32>(Id::new(), 0, &ctx).unwrap_err();
+        assert!(matches!(missing, EvalTypeError::Eval(GraphError::NodeNotFound { .. })));
     }
 
     #[test]
-    fn test_trigger_port_connection_invalid_source() {
+    fn test_input_validation_issues_follow_defaults_and_connections() {
+        use flux_operators::{IntAddOp, ListSliceOp};
+
         let mut graph = Graph::new();
+        let slice = graph.add(ListSliceOp::new());
+        let start = graph.add(IntAddOp::new());
+        graph.set_input_default(slice, 0, Value::float_list(vec![1.0, 2.0, 3.0, 4.0]));
+        graph.set_input_default(slice, 1, Value::Int(3));
+        graph.drain_events().for_each(drop);
+
+        // End before Start: the value is applied and the issue recorded
+        assert!(graph.set_input_default(slice, 2, Value::Int(1)));
+        assert_eq!(graph.get(slice).unwrap().inputs()[2].default, Value::Int(1));
+        let issues = graph.input_issues(slice).to_vec();
+        assert_eq!(issues.len(), 1);
+        assert_eq!((issues[0].input, issues[0].severity), (2, ValidationSeverity::Warning));
+        let events: Vec<GraphEvent> = graph.drain_events().collect();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], GraphEvent::InputDefaultChanged { .. }));
+        assert!(matches!(
+            &events[1],
+            GraphEvent::InputIssuesChanged { node, issues: reported }
+                if *node == slice && *reported == issues
+        ));
+        assert_eq!(
+            graph.validate(),
+            vec![GraphIssue::InvalidInput {
+                input: InputRef::new(slice, 2),
+                severity: ValidationSeverity::Warning,
+            }]
+        );
 
-        let source = graph.add(TestOp::source()); // No trigger outputs
-        let target_id = {
-            let op = TriggerTestOp::new();
-            let id = op.id;
-            graph.add(op);
-            id
-        };
+        // Evaluation degrades to an empty slice
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(slice, 0, &ctx).unwrap(), Value::float_list(vec![]));
 
-        // Connect should fail - source has no trigger outputs
-        let result = graph.connect_trigger(source, 0, target_id, 0);
-        assert!(result.is_err());
+        // A connected Start is only known upstream, so the issue goes away
+        graph.connect(start, 0, slice, 1).unwrap();
+        assert!(graph.input_issues(slice).is_empty());
+        graph.disconnect(slice, 1).unwrap();
+        assert_eq!(graph.input_issues(slice), issues.as_slice());
 
-        match result {
-            Err(GraphError::TriggerNotFound {
-                node_id,
-                is_output,
-                index,
-                available,
-            }) => {
-                assert_eq!(node_id, source);
-                assert!(is_output);
-                assert_eq!(index, 0);
-                assert_eq!(available, 0);
+        graph.set_input_default(slice, 2, Value::Int(4));
+        assert!(graph.input_issues(slice).is_empty());
+        assert!(graph.validate().is_empty());
+    }
+
+    // =========================================================================
+    // Panic Quarantine Tests
+    // =========================================================================
+
+    /// Test operator that outputs the frame number and panics on one frame
+    struct PanicOnFrameOp {
+        id: Id,
+        outputs: Vec<OutputPort>,
+        panic_frame: u64,
+    }
+
+    impl PanicOnFrameOp {
+        fn new(panic_frame: u64) -> Self {
+            Self {
+                id: Id::new(),
+                outputs: vec![OutputPort::float("Frame")],
+                panic_frame,
+            }
+        }
+    }
+
+    impl Operator for PanicOnFrameOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "PanicOnFrame"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &[]
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut []
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn is_time_varying(&self) -> bool {
+            true
+        }
+        fn compute(&mut self, ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            if ctx.frame == self.panic_frame {
+                panic!("boom on frame {}", ctx.frame);
             }
-            _ => panic!("Expected TriggerNotFound error"),
+            self.outputs[0].set(Value::Float(ctx.frame as f32));
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
         }
     }
 
     #[test]
-    fn test_trigger_port_connection_invalid_target() {
+    fn test_caught_panic_quarantines_node_until_cleared() {
         let mut graph = Graph::new();
+        graph.set_panic_policy(PanicPolicy::Catch);
+        let bad = graph.add(PanicOnFrameOp::new(3));
+        let good = graph.add(PanicOnFrameOp::new(u64::MAX));
+        let sink = graph.add(TestOp::new());
+        graph.connect(bad, 0, sink, 0).unwrap();
+
+        let mut ctx = EvalContext::new();
+        for frame in 1..=5 {
+            ctx.frame = frame;
+            // The rest of the graph keeps evaluating
+            assert_eq!(graph.evaluate(good, 0, &ctx).unwrap(), Value::Float(frame as f32));
+            // The panicking node keeps its last good output while quarantined
+            let expected = if frame < 3 { frame } else { 2 };
+            assert_eq!(graph.evaluate(bad, 0, &ctx).unwrap(), Value::Float(expected as f32));
+        }
+
+        assert!(graph.is_quarantined(bad));
+        assert!(!graph.is_quarantined(good));
+        let panic = graph.node_panic(bad).unwrap();
+        assert_eq!((panic.operator, panic.frame), ("PanicOnFrame", 3));
+        assert_eq!(panic.message, "boom on frame 3");
+        assert_eq!(graph.node_errors(bad).len(), 1);
+        let panicked: Vec<_> = graph
+            .drain_events()
+            .filter(|e| matches!(e, GraphEvent::NodePanicked { node, .. } if *node == bad))
+            .collect();
+        assert_eq!(panicked.len(), 1, "reported once, not every frame");
+        assert!(graph.check_invariants().is_empty());
+
+        assert!(graph.clear_quarantine(bad));
+        assert!(!graph.clear_quarantine(bad));
+        ctx.frame = 6;
+        assert_eq!(graph.evaluate(bad, 0, &ctx).unwrap(), Value::Float(6.0));
+        assert!(graph.node_errors(bad).is_empty());
+    }
 
-        let source = graph.add(TriggerSourceOp::new());
-        let target = graph.add(TestOp::new()); // No trigger inputs
+    // =========================================================================
+    // Preview Tests
+    // =========================================================================
 
-        // Connect should fail - target has no trigger inputs
-        let result = graph.connect_trigger(source, 0, target, 0);
-        assert!(result.is_err());
+    /// Test operator that adds its input to a running total on every compute
+    struct RunningSumOp {
+        id: Id,
+        inputs: Vec<InputPort>,
+        outputs: Vec<OutputPort>,
+        total: f32,
+    }
 
-        match result {
-            Err(GraphError::TriggerNotFound {
-                node_id,
-                is_output,
-                index,
-                available,
-            }) => {
-                assert_eq!(node_id, target);
-                assert!(!is_output);
-                assert_eq!(index, 0);
-                assert_eq!(available, 0);
+    impl RunningSumOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: vec![InputPort::new("in", Value::Float(0.0))],
+                outputs: vec![OutputPort::new("out", ValueType::Float)],
+                total: 0.0,
             }
-            _ => panic!("Expected TriggerNotFound error"),
         }
     }
 
-    #[test]
-    fn test_trigger_disconnection() {
+    impl Operator for RunningSumOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "RunningSum"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, get_input: &dyn Fn(Id, usize) -> Value) {
+            let input = match self.inputs[0].connection {
+                Some((source_id, source_output)) => get_input(source_id, source_output),
+                None => self.inputs[0].default.clone(),
+            };
+            self.total += input.as_float().unwrap_or(0.0);
+            self.outputs[0].set(Value::Float(self.total));
+        }
+        fn is_time_varying(&self) -> bool {
+            true
+        }
+        fn is_stateful(&self) -> bool {
+            true
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
+
+    /// Build `source(2.0) -> running sum -> doubler`, returning the sum and doubler.
+    fn running_sum_graph() -> (Graph, Id, Id) {
         let mut graph = Graph::new();
+        let source = FloatSourceOp::new(2.0);
+        let source_id = source.id;
+        graph.add(source);
+        let sum = graph.add(RunningSumOp::new());
+        let double = graph.add(CountingOp::new());
+        graph.connect(source_id, 0, sum, 0).unwrap();
+        graph.connect(sum, 0, double, 0).unwrap();
+        (graph, sum, double)
+    }
 
-        let source = graph.add(TriggerSourceOp::new());
-        let target_id = {
-            let op = TriggerTestOp::new();
-            let id = op.id;
-            graph.add(op);
-            id
-        };
+    #[test]
+    fn test_preview_does_not_change_main_results() {
+        let (mut graph, _, double) = running_sum_graph();
+        let (mut reference, _, ref_double) = running_sum_graph();
+        let mut ctx = EvalContext::new();
+
+        for _ in 0..3 {
+            let preview = graph.evaluate_preview(double, 0, &ctx).unwrap();
+            assert!(!preview.is_truncated());
+            let value = graph.evaluate(double, 0, &ctx).unwrap();
+            assert_eq!(value, reference.evaluate(ref_double, 0, &ctx).unwrap());
+            ctx.advance(1.0 / 60.0);
+        }
+        assert!(graph.drain_events().all(|e| !matches!(e, GraphEvent::OperatorError { .. })));
+
+        // The running sum isn't advanced by the preview, which reuses its last
+        // main-context output (2 + 2 + 2)
+        let preview = graph.evaluate_preview(double, 0, &ctx).unwrap();
+        assert_eq!(preview, PreviewResult::Complete(Value::Float(12.0)));
+        assert_eq!(graph.evaluate(double, 0, &ctx).unwrap(), Value::Float(16.0));
+    }
 
-        // Connect
-        graph.connect_trigger(source, 0, target_id, 0).unwrap();
-        graph.clear_events();
+    #[test]
+    fn test_preview_cache_evicted_separately() {
+        let mut graph = Graph::new();
+        let a = graph.add(CountingOp::new());
+        let b = graph.add(CountingOp::new());
+        graph.connect(a, 0, b, 0).unwrap();
+        let ctx = EvalContext::new();
 
-        // Disconnect
-        let prev = graph.disconnect_trigger(target_id, 0).unwrap();
-        assert_eq!(prev, Some((source, 0)));
+        assert_eq!(graph.evaluate(b, 0, &ctx).unwrap(), Value::Float(4.0));
+        assert_eq!(graph.cache_entry_count(), 2);
+        let preview = graph.evaluate_preview(b, 0, &ctx).unwrap();
+        assert_eq!(preview.value(), &Value::Float(4.0));
+        assert_eq!(graph.cache_entry_count(), 4);
+
+        assert_eq!(graph.clear_preview_cache(&ctx), 2);
+        assert_eq!(graph.cache_entry_count(), 2);
+        assert_eq!(graph.cached_output(b, 0), Some(&Value::Float(4.0)));
+
+        // The main evaluation is still served from its cache
+        let count = |graph: &Graph| {
+            graph
+                .get(b)
+                .unwrap()
+                .as_any()
+                .downcast_ref::<CountingOp>()
+                .unwrap()
+                .get_compute_count()
+        };
+        let before = count(&graph);
+        graph.evaluate(b, 0, &ctx).unwrap();
+        assert_eq!(count(&graph), before);
+    }
 
-        // Check events
-        let events: Vec<_> = graph.drain_events().collect();
-        assert_eq!(events.len(), 1);
+    #[test]
+    fn test_preview_truncates_at_node_budget() {
+        let (mut graph, ids) = slow_chain(3);
+        assert_eq!(graph.preview_settings().resolution, (64, 64));
+        graph.set_preview_settings(
+            PreviewSettings::default()
+                .with_max_nodes(2)
+                .with_time_budget(Duration::from_secs(10)),
+        );
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(ids[2], 0, &ctx).unwrap(), Value::Float(3.0));
 
-        match &events[0] {
-            GraphEvent::TriggerDisconnected {
-                source: s,
-                source_output,
-                target: t,
-                target_input,
-            } => {
-                assert_eq!(*s, source);
-                assert_eq!(*source_output, 0);
-                assert_eq!(*t, target_id);
-                assert_eq!(*target_input, 0);
-            }
-            _ => panic!("Expected TriggerDisconnected event"),
-        }
+        // Falls back to the main-context value until the preview catches up
+        let first = graph.evaluate_preview(ids[2], 0, &ctx).unwrap();
+        assert_eq!(first, PreviewResult::Truncated(Value::Float(3.0)));
+        let second = graph.evaluate_preview(ids[2], 0, &ctx).unwrap();
+        assert_eq!(second, PreviewResult::Complete(Value::Float(3.0)));
     }
 
     #[test]
-    fn test_fire_trigger_propagation() {
+    fn test_muted_connection_falls_back_to_default_and_stops_upstream() {
         let mut graph = Graph::new();
+        let frames = graph.add(PanicOnFrameOp::new(u64::MAX));
+        let doubler = graph.add(CountingOp::new());
+        graph.connect(frames, 0, doubler, 0).unwrap();
+        let connection = graph.connections().next().unwrap();
+        assert!(!connection.muted);
 
-        let source = graph.add(TriggerSourceOp::new());
-        let target_id = {
-            let op = TriggerTestOp::new();
-            let id = op.id;
-            graph.add(op);
-            id
-        };
+        let mut ctx = EvalContext::new();
+        ctx.frame = 1;
+        assert_eq!(graph.evaluate(doubler, 0, &ctx).unwrap(), Value::Float(2.0));
 
-        // Connect trigger
-        graph.connect_trigger(source, 0, target_id, 0).unwrap();
+        graph.clear_events();
+        assert!(graph.set_connection_muted(connection, true).unwrap());
+        assert!(!graph.set_connection_muted(connection, true).unwrap());
+        let events: Vec<_> = graph.drain_events().collect();
+        assert!(matches!(
+            events.as_slice(),
+            [GraphEvent::ConnectionMuted { connection }] if connection.muted
+        ));
+        let muted = graph.connections().next().unwrap();
+        assert_eq!(muted, Connection { muted: true, ..connection });
+        assert_eq!(graph.upstream_of(doubler), vec![muted]);
+        assert_eq!(graph.downstream_of(frames), vec![muted]);
+
+        // The input reads its default (1.0) and the time-varying source idles
+        for frame in 2..=4 {
+            ctx.frame = frame;
+            assert_eq!(graph.evaluate(doubler, 0, &ctx).unwrap(), Value::Float(2.0));
+        }
+        assert_eq!(graph.cached_output(frames, 0), Some(&Value::Float(1.0)));
+        assert_eq!(compute_count(&graph, doubler), 2);
+        // Still connected, and evaluating the muted branch itself computes it
+        assert!(graph.get(doubler).unwrap().inputs()[0].is_connected());
+        assert_eq!(graph.evaluate(frames, 0, &ctx).unwrap(), Value::Float(4.0));
+
+        graph.set_connection_muted(muted, false).unwrap();
+        ctx.frame = 5;
+        assert_eq!(graph.evaluate(doubler, 0, &ctx).unwrap(), Value::Float(10.0));
+        assert!(graph.check_invariants().is_empty());
+    }
 
-        // Fire trigger from source
+    #[test]
+    fn test_muted_multi_input_entry_is_skipped_in_place() {
+        use flux_operators::FloatListOp;
+
+        let mut graph = Graph::new();
+        let sources: Vec<Id> =
+            [1.0, 2.0, 4.0].map(|v| graph.add(FloatSourceOp::new(v))).to_vec();
+        let collected = graph.add(FloatListOp::new());
+        for &source in &sources {
+            graph.connect(source, 0, collected, 0).unwrap();
+        }
+        let middle = graph.upstream_of(collected)[1];
         let ctx = EvalContext::new();
-        graph.fire_trigger(source, 0, &ctx);
 
-        // Check that target was triggered
-        let target = graph.get(target_id).unwrap();
-        let test_op = target.as_any().downcast_ref::<TriggerTestOp>().unwrap();
-        assert_eq!(test_op.trigger_count(), 1);
+        let mut history = crate::UndoRedoStack::new();
+        history.execute(&mut graph, crate::SetConnectionMutedCommand::new(middle, true));
+        assert_eq!(
+            graph.evaluate(collected, 0, &ctx).unwrap(),
+            Value::float_list(vec![1.0, 4.0])
+        );
+        let muted: Vec<bool> = graph.upstream_of(collected).iter().map(|c| c.muted).collect();
+        assert_eq!(muted, [false, true, false]);
+
+        history.undo(&mut graph);
+        assert_eq!(
+            graph.evaluate(collected, 0, &ctx).unwrap(),
+            Value::float_list(vec![1.0, 2.0, 4.0])
+        );
+        let order: Vec<Id> = graph.upstream_of(collected).iter().map(|c| c.source_node).collect();
+        assert_eq!(order, sources);
     }
 
     #[test]
-    fn test_fire_trigger_cascading() {
-        // Test trigger chain: source -> op1 -> op2
+    fn test_select_skips_inactive_branch() {
+        use flux_operators::SelectOp;
+
+        // Two counters, each adding 1 per compute, feeding a Select
         let mut graph = Graph::new();
+        let counters = [graph.add(RunningSumOp::new()), graph.add(RunningSumOp::new())];
+        let select = graph.add(SelectOp::new());
+        for counter in counters {
+            graph.set_input_default(counter, 0, Value::Float(1.0));
+            graph.connect(counter, 0, select, 1).unwrap();
+        }
+        let total = |graph: &Graph, id: Id| {
+            let op = graph.get(id).unwrap().as_any();
+            op.downcast_ref::<RunningSumOp>().unwrap().total
+        };
 
-        let source = graph.add(TriggerSourceOp::new());
+        let mut ctx = EvalContext::new();
+        for frame in 1..=10 {
+            ctx.frame = frame;
+            assert_eq!(graph.evaluate(select, 0, &ctx).unwrap(), Value::Float(frame as f32));
+        }
+        assert_eq!(total(&graph, counters[0]), 10.0);
+        assert_eq!(total(&graph, counters[1]), 0.0);
+        let (_, trace) = graph.trace_evaluate(select, 0, &ctx);
+        assert_eq!(trace.node(counters[1]).unwrap().reason, TraceReason::InactiveBranch);
 
-        let op1_id = {
-            let op = TriggerTestOp::new();
-            let id = op.id;
-            graph.add(op);
-            id
-        };
+        // Flipping the selector flips which counter runs
+        graph.set_input_default(select, 0, Value::Int(1));
+        for frame in 11..=20 {
+            ctx.frame = frame;
+            graph.evaluate(select, 0, &ctx).unwrap();
+        }
+        assert_eq!(total(&graph, counters[0]), 11.0);
+        assert_eq!(total(&graph, counters[1]), 10.0);
+        assert_eq!(graph.cached_output(select, 0), Some(&Value::Float(10.0)));
 
-        let op2_id = {
-            let op = TriggerTestOp::new();
-            let id = op.id;
-            graph.add(op);
-            id
-        };
+        // Evaluating the inactive branch itself still computes it
+        assert_eq!(graph.evaluate(counters[0], 0, &ctx).unwrap(), Value::Float(12.0));
+    }
 
-        // Connect: source[0] -> op1[0]
-        graph.connect_trigger(source, 0, op1_id, 0).unwrap();
+    #[test]
+    fn test_crossfade_skips_silent_branch() {
+        use flux_operators::CrossfadeOp;
 
-        // Connect: op1.Done -> op2.OnFrame
-        graph.connect_trigger(op1_id, 0, op2_id, 0).unwrap();
+        let mut graph = Graph::new();
+        let branches = [graph.add(CountingOp::new()), graph.add(CountingOp::new())];
+        let fade = graph.add(CrossfadeOp::new());
+        graph.connect(branches[0], 0, fade, 0).unwrap();
+        graph.connect(branches[1], 0, fade, 1).unwrap();
+        graph.set_input_default(branches[1], 0, Value::Float(3.0));
 
-        // Fire trigger from source
         let ctx = EvalContext::new();
-        graph.fire_trigger(source, 0, &ctx);
+        assert_eq!(graph.evaluate(fade, 0, &ctx).unwrap(), Value::Float(2.0));
+        assert_eq!(compute_count(&graph, branches[1]), 0);
+
+        graph.set_input_default(fade, 2, Value::Float(0.5));
+        assert_eq!(graph.evaluate(fade, 0, &ctx).unwrap(), Value::Float(4.0));
+        assert_eq!(compute_count(&graph, branches[1]), 1);
+
+        // At the other end, changes to A wait until it's audible again
+        graph.set_input_default(fade, 2, Value::Float(1.0));
+        graph.set_input_default(branches[0], 0, Value::Float(5.0));
+        assert_eq!(graph.evaluate(fade, 0, &ctx).unwrap(), Value::Float(6.0));
+        assert_eq!(compute_count(&graph, branches[0]), 1);
+    }
 
-        // Both ops should have been triggered
-        let op1 = graph.get(op1_id).unwrap();
-        let test_op1 = op1.as_any().downcast_ref::<TriggerTestOp>().unwrap();
-        assert_eq!(test_op1.trigger_count(), 1);
+    #[test]
+    fn test_inactive_branch_selector_chain() {
+        use flux_operators::{CompareOp, SwitchOp};
 
-        let op2 = graph.get(op2_id).unwrap();
-        let test_op2 = op2.as_any().downcast_ref::<TriggerTestOp>().unwrap();
-        assert_eq!(test_op2.trigger_count(), 1);
+        // A connected condition is read from the cache once its node stops
+        // recomputing; until then both branches run
+        let mut graph = Graph::new();
+        let condition = graph.add(CompareOp::equal());
+        let branches = [graph.add(CountingOp::new()), graph.add(CountingOp::new())];
+        let switch = graph.add(SwitchOp::new());
+        graph.connect(condition, 0, switch, 0).unwrap();
+        graph.connect(branches[0], 0, switch, 1).unwrap();
+        graph.connect(branches[1], 0, switch, 2).unwrap();
+        // The false branch reads a node that is also read elsewhere
+        let shared = graph.add(CountingOp::new());
+        let sink = graph.add(CountingOp::new());
+        graph.connect(shared, 0, branches[1], 0).unwrap();
+        graph.connect(shared, 0, sink, 0).unwrap();
+
+        let mut ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(switch, 0, &ctx).unwrap(), Value::Float(2.0));
+        assert_eq!(compute_count(&graph, branches[1]), 1);
+
+        // Changes reaching the inactive branch wait until it's read again
+        graph.set_input_default(shared, 0, Value::Float(2.0));
+        graph.set_input_default(branches[0], 0, Value::Float(3.0));
+        ctx.frame = 1;
+        assert_eq!(graph.evaluate(switch, 0, &ctx).unwrap(), Value::Float(6.0));
+        assert_eq!(compute_count(&graph, branches[1]), 1);
+
+        // The frame the condition changes, both branches are read
+        graph.set_input_default(condition, 0, Value::Float(1.0));
+        ctx.frame = 2;
+        let (result, trace) = graph.trace_evaluate(switch, 0, &ctx);
+        assert_eq!(result.unwrap(), Value::Float(8.0));
+        assert_eq!(trace.node(branches[1]).unwrap().reason, TraceReason::MissedUpdate);
+        assert_eq!(trace.node(branches[0]).unwrap().reason, TraceReason::UpToDate);
+        ctx.frame = 3;
+        let (_, trace) = graph.trace_evaluate(switch, 0, &ctx);
+        assert_eq!(trace.node(branches[0]).unwrap().reason, TraceReason::InactiveBranch);
     }
 
     #[test]
-    fn test_fire_trigger_fan_out() {
-        // Test trigger fan-out: source -> [op1, op2]
+    fn test_trash_restores_muted_connections() {
         let mut graph = Graph::new();
+        graph.set_trash_capacity(4);
+        let source = graph.add(FloatSourceOp::new(3.0));
+        let sink = graph.add(CountingOp::new());
+        graph.connect(source, 0, sink, 0).unwrap();
+        let connection = graph.connections().next().unwrap();
+        graph.set_connection_muted(connection, true).unwrap();
+
+        graph.remove(sink);
+        assert!(graph.restore_from_trash(sink).unwrap().unrestored.is_empty());
+        assert!(graph.connections().next().unwrap().muted);
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(sink, 0, &ctx).unwrap(), Value::Float(2.0));
+    }
 
-        let source = graph.add(TriggerSourceOp::new());
+    // =========================================================================
+    // Determinism Audit Tests
+    // =========================================================================
 
-        let op1_id = {
-            let op = TriggerTestOp::new();
-            let id = op.id;
-            graph.add(op);
-            id
-        };
+    /// Test operator whose output counts its computes without declaring any
+    /// state, like an operator reading the wall clock
+    struct NoisyOp {
+        id: Id,
+        outputs: Vec<OutputPort>,
+        computes: u32,
+    }
 
-        let op2_id = {
-            let op = TriggerTestOp::new();
-            let id = op.id;
-            graph.add(op);
-            id
-        };
+    impl NoisyOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                outputs: vec![OutputPort::float("Out")],
+                computes: 0,
+            }
+        }
+    }
 
-        // Connect both to the same trigger output
-        graph.connect_trigger(source, 0, op1_id, 0).unwrap();
-        graph.connect_trigger(source, 0, op2_id, 0).unwrap();
+    impl Operator for NoisyOp {
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "Noisy"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &[]
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut []
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: &dyn Fn(Id, usize) -> Value) {
+            self.computes += 1;
+            self.outputs[0].set(Value::Float(self.computes as f32));
+        }
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+            self
+        }
+    }
 
-        // Fire trigger from source
+    #[test]
+    fn test_audit_determinism_flags_nondeterministic_operator() {
+        use flux_operators::RandomOp;
+
+        let mut graph = Graph::new();
+        let random = graph.add(RandomOp::new());
+        graph.set_input_default(random, 2, Value::Int(42));
+        let noisy = graph.add(NoisyOp::new());
         let ctx = EvalContext::new();
-        graph.fire_trigger(source, 0, &ctx);
 
-        // Both ops should have been triggered
-        let op1 = graph.get(op1_id).unwrap();
-        let test_op1 = op1.as_any().downcast_ref::<TriggerTestOp>().unwrap();
-        assert_eq!(test_op1.trigger_count(), 1);
+        let report = graph.audit_determinism(&ctx, 3);
+        assert_eq!((report.runs, report.audited), (3, 2));
+        assert!(report.unauditable.is_empty());
+        let [divergent] = report.divergent.as_slice() else {
+            panic!("expected one divergent node, got {:?}", report.divergent);
+        };
+        assert_eq!((divergent.node, divergent.operator), (noisy, "Noisy"));
+        assert!(divergent.origin);
+        let [output] = divergent.outputs.as_slice() else {
+            panic!("expected one divergent output");
+        };
+        assert_eq!(output.output, 0);
+        assert_ne!(output.first, output.differing);
+        assert_eq!(output.differing_runs, 2);
+        assert_eq!(output.magnitude, Some(2.0));
 
-        let op2 = graph.get(op2_id).unwrap();
-        let test_op2 = op2.as_any().downcast_ref::<TriggerTestOp>().unwrap();
-        assert_eq!(test_op2.trigger_count(), 1);
+        // Differences within the tolerance are accepted
+        assert!(graph.audit_determinism_within(&ctx, 3, 2.0).is_deterministic());
+    }
+
+    #[test]
+    fn test_audit_determinism_resets_state_and_keeps_main_cache() {
+        use flux_operators::AccumulatorOp;
+
+        let (mut graph, sum, double) = running_sum_graph();
+        let accumulator = graph.add(AccumulatorOp::new());
+        let noisy = graph.add(NoisyOp::new());
+        let noisy_double = graph.add(CountingOp::new());
+        graph.connect(noisy, 0, noisy_double, 0).unwrap();
+        let mut ctx = EvalContext::new();
+        ctx.time = 2.0;
+        assert_eq!(graph.evaluate(double, 0, &ctx).unwrap(), Value::Float(4.0));
+        let cached = graph.cache_entry_count();
+        graph.clear_events();
+
+        let report = graph.audit_determinism(&ctx, 4);
+        // RunningSum can't be reset; its doubler reads its main-context output
+        let unauditable: Vec<_> = report.unauditable.iter().map(|u| (u.node, u.operator)).collect();
+        assert_eq!(unauditable, [(sum, "RunningSum")]);
+        let divergent: Vec<_> = report.divergent.iter().map(|d| (d.node, d.origin)).collect();
+        assert_eq!(divergent, [(noisy, true), (noisy_double, false)]);
+        assert_eq!(report.origins().map(|d| d.node).collect::<Vec<_>>(), [noisy]);
+        assert!(!divergent.iter().any(|&(node, _)| node == accumulator));
+
+        // The main evaluation is left as it was
+        assert_eq!(graph.cache_entry_count(), cached);
+        assert_eq!(graph.cached_output(double, 0), Some(&Value::Float(4.0)));
+        assert_eq!(graph.pending_event_count(), 0);
+        let sum_op = graph.get(sum).unwrap().as_any().downcast_ref::<RunningSumOp>().unwrap();
+        assert_eq!(sum_op.total, 2.0);
     }
 }
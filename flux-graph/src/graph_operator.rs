@@ -0,0 +1,329 @@
+//! Whole graphs as operators
+//!
+//! [`GraphOperator`] wraps an owned [`Graph`] so another graph can add it as
+//! a single node, without going through the symbol system. Inner node ports
+//! are promoted to ports on the adapter:
+//!
+//! ```ignore
+//! let mut inner = Graph::new();
+//! let add = inner.add(AddOp::new());
+//! let double = inner.add(MultiplyOp::new());
+//! inner.connect(add, 0, double, 0)?;
+//! inner.set_input_default(double, 1, Value::Float(2.0));
+//!
+//! let mut adapter = GraphOperator::new("AddAndDouble", inner);
+//! adapter.promote_input(add, 0, "A", Value::Float(0.0))?;
+//! adapter.promote_input(add, 1, "B", Value::Float(0.0))?;
+//! adapter.promote_output(double, 0, "Result")?;
+//! let node = outer.add(adapter);
+//! ```
+//!
+//! Every adapter owns its graph, so two adapters built from the same recipe
+//! keep separate caches. Errors reported inside are passed up to the graph
+//! evaluating the adapter, and adapters nest like composites do.
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::error::OperatorError;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::port::{InputPort, OutputPort};
+use flux_core::value::Value;
+
+use crate::composite::set_changed_default;
+use crate::graph::{Graph, GraphError};
+
+/// An operator that evaluates an owned graph
+///
+/// Promoted inputs are written to their inner input's default before each
+/// evaluation; an inner input that is connected keeps its connection.
+/// Promoted outputs are evaluated in the inner graph and copied out.
+pub struct GraphOperator {
+    id: Id,
+    name: &'static str,
+    graph: Graph,
+    inputs: Vec<InputPort>,
+    outputs: Vec<OutputPort>,
+    /// Inner node and input each adapter input is forwarded to
+    input_targets: Vec<(Id, usize)>,
+    /// Inner node and output each adapter output is read from
+    output_sources: Vec<(Id, usize)>,
+}
+
+impl GraphOperator {
+    /// Wrap `graph` in an operator without ports
+    pub fn new(name: &'static str, graph: Graph) -> Self {
+        Self {
+            id: Id::new(),
+            name,
+            graph,
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            input_targets: Vec::new(),
+            output_sources: Vec::new(),
+        }
+    }
+
+    /// Add an input forwarding to an inner node's input
+    ///
+    /// The input takes the inner input's type. Returns the new input's index.
+    pub fn promote_input(
+        &mut self,
+        inner_node: Id,
+        inner_input: usize,
+        name: &'static str,
+        default: Value,
+    ) -> Result<usize, GraphError> {
+        let op = self
+            .graph
+            .get(inner_node)
+            .ok_or_else(|| GraphError::node_not_found(inner_node, None))?;
+        let port = op
+            .inputs()
+            .get(inner_input)
+            .ok_or(GraphError::InputNotFound {
+                node_id: inner_node,
+                input_index: inner_input,
+                node_name: op.name(),
+                input_count: op.inputs().len(),
+            })?;
+
+        self.inputs
+            .push(InputPort::new_typed(name, port.value_type, default));
+        self.input_targets.push((inner_node, inner_input));
+        Ok(self.inputs.len() - 1)
+    }
+
+    /// Add an output reading an inner node's output
+    ///
+    /// The output takes the inner output's type. Returns the new output's
+    /// index.
+    pub fn promote_output(
+        &mut self,
+        inner_node: Id,
+        inner_output: usize,
+        name: &'static str,
+    ) -> Result<usize, GraphError> {
+        let op = self
+            .graph
+            .get(inner_node)
+            .ok_or_else(|| GraphError::node_not_found(inner_node, None))?;
+        let port = op
+            .outputs()
+            .get(inner_output)
+            .ok_or(GraphError::OutputNotFound {
+                node_id: inner_node,
+                output_index: inner_output,
+                node_name: op.name(),
+                output_count: op.outputs().len(),
+            })?;
+
+        self.outputs
+            .push(OutputPort::new_typed(name, port.value_type));
+        self.output_sources.push((inner_node, inner_output));
+        Ok(self.outputs.len() - 1)
+    }
+
+    /// The wrapped graph
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// The wrapped graph, mutably
+    ///
+    /// Edits take effect at the next evaluation; the outer graph only
+    /// recomputes this node when its inputs change or it is time-varying.
+    pub fn graph_mut(&mut self) -> &mut Graph {
+        &mut self.graph
+    }
+
+    /// Inner node and input a promoted input forwards to
+    pub fn input_target(&self, index: usize) -> Option<(Id, usize)> {
+        self.input_targets.get(index).copied()
+    }
+
+    /// Inner node and output a promoted output reads
+    pub fn output_source(&self, index: usize) -> Option<(Id, usize)> {
+        self.output_sources.get(index).copied()
+    }
+
+    /// Unwrap the graph, dropping the promotions
+    pub fn into_graph(self) -> Graph {
+        self.graph
+    }
+}
+
+impl Operator for GraphOperator {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn inputs(&self) -> &[InputPort] {
+        &self.inputs
+    }
+
+    fn inputs_mut(&mut self) -> &mut [InputPort] {
+        &mut self.inputs
+    }
+
+    fn outputs(&self) -> &[OutputPort] {
+        &self.outputs
+    }
+
+    fn outputs_mut(&mut self) -> &mut [OutputPort] {
+        &mut self.outputs
+    }
+
+    fn is_time_varying(&self) -> bool {
+        self.graph
+            .node_ids()
+            .filter_map(|id| self.graph.get(id))
+            .any(|op| op.is_time_varying())
+    }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input_value: InputResolver) {
+        // Nest like a composite: same call context, one level deeper
+        let inner_ctx = match ctx.descend(self.name) {
+            Ok(inner_ctx) => inner_ctx,
+            Err(e) => {
+                ctx.report_error(e);
+                return;
+            }
+        };
+
+        for (input, &(node, index)) in self.inputs.iter().zip(&self.input_targets) {
+            let value = match input.connection {
+                Some((source, output)) => get_input_value(source, output),
+                None => input.default.clone(),
+            };
+            set_changed_default(&mut self.graph, node, index, value);
+        }
+
+        for (output, &(node, index)) in self.outputs.iter_mut().zip(&self.output_sources) {
+            match self.graph.evaluate(node, index, &inner_ctx) {
+                Ok(value) => output.set(value),
+                Err(GraphError::DepthLimitExceeded { path }) => {
+                    ctx.report_error(OperatorError::depth_limit_exceeded(path, ctx.max_depth));
+                    return;
+                }
+                Err(e) => {
+                    ctx.report_error(OperatorError::evaluation_failed(self.id, e.to_string()))
+                }
+            }
+            for (_, error) in self.graph.frame_errors() {
+                ctx.report_error(error.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_operators::{AddOp, ConstantOp, MultiplyOp, TimeOp};
+
+    /// (A + B) * 2, with A and B promoted
+    fn add_and_double() -> GraphOperator {
+        let mut inner = Graph::new();
+        let add = inner.add(AddOp::new());
+        let double = inner.add(MultiplyOp::new());
+        inner.connect(add, 0, double, 0).unwrap();
+        inner.set_input_default(double, 1, Value::Float(2.0));
+
+        let mut adapter = GraphOperator::new("AddAndDouble", inner);
+        adapter
+            .promote_input(add, 0, "A", Value::Float(0.0))
+            .unwrap();
+        adapter
+            .promote_input(add, 1, "B", Value::Float(1.0))
+            .unwrap();
+        adapter.promote_output(double, 0, "Result").unwrap();
+        adapter
+    }
+
+    #[test]
+    fn test_instances_are_isolated() {
+        let mut graph = Graph::new();
+        let one = graph.add(ConstantOp::new(1.0));
+        let ten = graph.add(ConstantOp::new(10.0));
+        let first = graph.add(add_and_double());
+        let second = graph.add(add_and_double());
+        graph.connect(one, 0, first, 0).unwrap();
+        graph.connect(ten, 0, second, 0).unwrap();
+        graph.set_input_default(second, 1, Value::Float(3.0));
+
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(first, 0, &ctx).unwrap(), Value::Float(4.0));
+        assert_eq!(graph.evaluate(second, 0, &ctx).unwrap(), Value::Float(26.0));
+
+        // Changing the first instance recomputes only its inner graph
+        graph.set_input_default(one, 0, Value::Float(5.0));
+        assert_eq!(graph.evaluate(first, 0, &ctx).unwrap(), Value::Float(12.0));
+        let inner_nodes = |graph: &mut Graph, node: Id| {
+            let adapter = graph.get_mut_as::<GraphOperator>(node).unwrap();
+            adapter.graph().frame_evaluated_nodes().count()
+        };
+        assert_eq!(inner_nodes(&mut graph, first), 2);
+        assert_eq!(graph.evaluate(second, 0, &ctx).unwrap(), Value::Float(26.0));
+        assert!(!graph.frame_evaluated_nodes().any(|id| id == second));
+    }
+
+    #[test]
+    fn test_nested_adapters() {
+        let mut middle = Graph::new();
+        let x = middle.add(ConstantOp::new(1.0));
+        let doubled = middle.add(add_and_double());
+        middle.connect(x, 0, doubled, 0).unwrap();
+
+        let mut outer = GraphOperator::new("Outer", middle);
+        outer.promote_input(x, 0, "X", Value::Float(4.0)).unwrap();
+        outer.promote_output(doubled, 0, "Y").unwrap();
+        assert!(!outer.is_time_varying());
+
+        let mut graph = Graph::new();
+        let node = graph.add(outer);
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(10.0));
+        graph.set_input_default(node, 0, Value::Float(0.5));
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(3.0));
+    }
+
+    #[test]
+    fn test_promotion_errors_and_time_varying() {
+        let mut inner = Graph::new();
+        let time = inner.add(TimeOp::new());
+        let mut adapter = GraphOperator::new("Clock", inner);
+        assert!(matches!(
+            adapter.promote_output(time, 5, "Out"),
+            Err(GraphError::OutputNotFound {
+                output_index: 5,
+                ..
+            })
+        ));
+        assert!(matches!(
+            adapter.promote_input(Id::new(), 0, "In", Value::Float(0.0)),
+            Err(GraphError::NodeNotFound { .. })
+        ));
+        assert!(adapter.is_time_varying());
+
+        adapter.promote_output(time, 0, "Time").unwrap();
+        let mut graph = Graph::new();
+        let node = graph.add(adapter);
+        let mut ctx = EvalContext::new();
+        ctx.time = 2.5;
+        assert_eq!(graph.evaluate(node, 0, &ctx).unwrap(), Value::Float(2.5));
+    }
+}
@@ -0,0 +1,132 @@
+//! Named groups of nodes
+//!
+//! Groups are purely organizational: a node can belong to any number of
+//! groups, and groups have no effect on evaluation (unlike composites, they
+//! don't hide their members behind ports). Tools use them to frame regions
+//! of the graph; [`Graph::detailed_stats`] counts nodes per group and
+//! [`Graph::to_dot`] can draw them as clusters.
+//!
+//! Removing a node takes it out of its groups. The groups themselves stay,
+//! even when empty, unless [`Graph::set_remove_empty_groups`] is enabled.
+//! Groups are saved with a graph through
+//! [`GraphDef::groups`](crate::serialization::GraphDef::groups).
+//!
+//! [`Graph::detailed_stats`]: crate::Graph::detailed_stats
+//! [`Graph::to_dot`]: crate::Graph::to_dot
+//! [`Graph::set_remove_empty_groups`]: crate::Graph::set_remove_empty_groups
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use flux_core::Id;
+
+/// Handle to a group, returned by [`Graph::create_group`](crate::Graph::create_group).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GroupId(u64);
+
+impl GroupId {
+    /// Number of the group within its graph, unique for the graph's lifetime
+    pub fn index(self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for GroupId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "group {}", self.0)
+    }
+}
+
+/// A named, colored set of nodes
+#[derive(Clone, Debug, PartialEq)]
+pub struct Group {
+    pub(crate) name: String,
+    pub(crate) color: [f32; 4],
+    /// Members in the order they were assigned
+    pub(crate) members: Vec<Id>,
+}
+
+impl Group {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Frame color (RGBA)
+    pub fn color(&self) -> [f32; 4] {
+        self.color
+    }
+
+    /// Member nodes in the order they were assigned
+    pub fn members(&self) -> &[Id] {
+        &self.members
+    }
+
+    pub fn contains(&self, node: Id) -> bool {
+        self.members.contains(&node)
+    }
+}
+
+/// All groups of a graph.
+#[derive(Debug, Default)]
+pub(crate) struct GroupSet {
+    next_id: u64,
+    groups: BTreeMap<GroupId, Group>,
+    /// Delete groups whose last member is removed from the graph
+    pub(crate) remove_empty: bool,
+}
+
+impl GroupSet {
+    pub(crate) fn create(&mut self, name: String, color: [f32; 4]) -> GroupId {
+        let id = GroupId(self.next_id);
+        self.next_id += 1;
+        self.groups.insert(id, Group { name, color, members: Vec::new() });
+        id
+    }
+
+    /// Put a group back under a handle it had before being removed.
+    pub(crate) fn restore(&mut self, id: GroupId, group: Group) {
+        self.next_id = self.next_id.max(id.0 + 1);
+        self.groups.insert(id, group);
+    }
+
+    pub(crate) fn remove(&mut self, id: GroupId) -> Option<Group> {
+        self.groups.remove(&id)
+    }
+
+    pub(crate) fn get(&self, id: GroupId) -> Option<&Group> {
+        self.groups.get(&id)
+    }
+
+    pub(crate) fn get_mut(&mut self, id: GroupId) -> Option<&mut Group> {
+        self.groups.get_mut(&id)
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (GroupId, &Group)> {
+        self.groups.iter().map(|(&id, group)| (id, group))
+    }
+
+    /// Groups containing `node`, in creation order.
+    pub(crate) fn groups_of(&self, node: Id) -> Vec<GroupId> {
+        self.iter().filter(|(_, group)| group.contains(node)).map(|(id, _)| id).collect()
+    }
+
+    /// Take a removed node out of every group.
+    ///
+    /// Returns the groups it left and whether each was deleted for being
+    /// empty afterwards.
+    pub(crate) fn node_removed(&mut self, node: Id) -> Vec<(GroupId, bool)> {
+        let mut left = Vec::new();
+        for (&id, group) in &mut self.groups {
+            if let Some(position) = group.members.iter().position(|&m| m == node) {
+                group.members.remove(position);
+                left.push((id, self.remove_empty && group.members.is_empty()));
+            }
+        }
+        for &(id, deleted) in &left {
+            if deleted {
+                self.groups.remove(&id);
+            }
+        }
+        left
+    }
+}
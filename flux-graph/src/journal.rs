@@ -0,0 +1,556 @@
+//! Crash recovery journal for editing sessions
+//!
+//! A [`Journal`] appends every command executed through an
+//! [`UndoRedoStack`](crate::UndoRedoStack) to a file, one JSON record per
+//! line. Commands that describe themselves (see
+//! [`Command::to_journal_entry`]) are written as small entries; anything
+//! else, including undo and redo, writes a checkpoint of the whole graph.
+//! A checkpoint is also written every [`checkpoint_interval`] entries, so
+//! recovery never replays more than that.
+//!
+//! ```ignore
+//! let journal = Journal::create("session.fluxjournal", registry.clone(), &graph)?
+//!     .with_checkpoint_interval(50)
+//!     .with_max_bytes(8 * 1024 * 1024);
+//! history.attach_journal(journal);
+//!
+//! // ... after a crash
+//! let recovery = Journal::recover_with_report("session.fluxjournal", &registry)?;
+//! println!("replayed {}, lost {}", recovery.replayed, recovery.unreadable);
+//! ```
+//!
+//! Recovery instantiates the latest readable checkpoint and replays the
+//! entries after it. A line cut short by a crash, and everything after it,
+//! is counted as unreadable instead of failing the recovery.
+//!
+//! [`checkpoint_interval`]: Journal::with_checkpoint_interval
+
+use std::collections::HashMap;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use flux_core::id::Id;
+use flux_core::value::Value;
+use flux_operators::OperatorRegistry;
+
+use crate::commands::Command;
+use crate::graph::Graph;
+use crate::serialization::{GraphDef, LoadReport, SymbolDef};
+
+/// Checkpoint interval used unless [`Journal::with_checkpoint_interval`] is called
+pub const DEFAULT_CHECKPOINT_INTERVAL: usize = 100;
+
+/// A graph change that can be written to and replayed from a journal
+///
+/// Node IDs are those of the graph being edited; recovery maps them to the
+/// nodes the latest checkpoint was loaded as.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command")]
+pub enum JournalEntry {
+    /// An input's default was set (see [`Graph::set_input_default`])
+    SetInputDefault { node: Id, input: usize, value: Value },
+    /// An output was connected to an input (see [`Graph::connect`])
+    Connect {
+        source: Id,
+        output: usize,
+        target: Id,
+        input: usize,
+    },
+    /// An input was disconnected (see [`Graph::disconnect`])
+    Disconnect { target: Id, input: usize },
+}
+
+impl JournalEntry {
+    /// The entry as the JSON a command returns from
+    /// [`Command::to_journal_entry`]
+    pub fn to_json(&self) -> Option<serde_json::Value> {
+        serde_json::to_value(self).ok()
+    }
+
+    /// Apply to a graph, mapping saved node IDs through `id_map`
+    ///
+    /// Returns false if the change could not be made.
+    fn apply(&self, graph: &mut Graph, id_map: &HashMap<Id, Id>) -> bool {
+        let node = |id: &Id| id_map.get(id).copied().unwrap_or(*id);
+        match self {
+            JournalEntry::SetInputDefault { node: id, input, value } => {
+                graph.set_input_default(node(id), *input, value.clone())
+            }
+            JournalEntry::Connect {
+                source,
+                output,
+                target,
+                input,
+            } => graph
+                .connect(node(source), *output, node(target), *input)
+                .is_ok(),
+            JournalEntry::Disconnect { target, input } => {
+                graph.disconnect(node(target), *input).is_ok()
+            }
+        }
+    }
+}
+
+/// One line of a journal file
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum Record {
+    /// The whole graph: nodes and connections, then positions and groups
+    Checkpoint {
+        seq: u64,
+        symbol: Box<SymbolDef>,
+        layout: Box<GraphDef>,
+    },
+    /// A command described by its [`JournalEntry`]
+    Command {
+        seq: u64,
+        name: String,
+        entry: serde_json::Value,
+    },
+}
+
+/// Errors from [`Journal::recover`]
+#[derive(Error, Debug)]
+pub enum RecoverError {
+    /// The journal file could not be read
+    #[error("IO error: {0}")]
+    Io(#[from] io::Error),
+
+    /// No checkpoint in the journal could be read
+    #[error("Journal has no readable checkpoint")]
+    NoCheckpoint,
+}
+
+/// A graph rebuilt from a journal, with what was and wasn't restored
+pub struct Recovery {
+    /// The graph as of the last entry that could be replayed
+    pub graph: Graph,
+    /// Entries replayed on top of the checkpoint
+    pub replayed: usize,
+    /// Trailing records that were truncated, unreadable or failed to apply
+    pub unreadable: usize,
+    /// Problems recovered from while loading the checkpoint
+    pub report: LoadReport,
+}
+
+/// Appends executed commands and checkpoints to a journal file
+///
+/// Each record is written in a single call and reaches the operating system
+/// before [`record`](Self::record) returns. Write errors don't interrupt
+/// editing through an [`UndoRedoStack`](crate::UndoRedoStack); the first one
+/// is kept for [`take_error`](Self::take_error).
+pub struct Journal {
+    path: PathBuf,
+    registry: Arc<OperatorRegistry>,
+    file: File,
+    /// Size of the current file in bytes
+    bytes: u64,
+    /// Sequence number of the next record
+    next_seq: u64,
+    /// Entries written since the last checkpoint
+    since_checkpoint: usize,
+    checkpoint_interval: usize,
+    max_bytes: Option<u64>,
+    rotations: usize,
+    error: Option<io::Error>,
+}
+
+impl fmt::Debug for Journal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Journal")
+            .field("path", &self.path)
+            .field("bytes", &self.bytes)
+            .field("next_seq", &self.next_seq)
+            .field("since_checkpoint", &self.since_checkpoint)
+            .finish()
+    }
+}
+
+impl Journal {
+    /// Start a journal at `path`, replacing any file there, with a
+    /// checkpoint of `graph`
+    pub fn create(
+        path: impl AsRef<Path>,
+        registry: Arc<OperatorRegistry>,
+        graph: &Graph,
+    ) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let file = File::create(&path)?;
+        let mut journal = Self {
+            path,
+            registry,
+            file,
+            bytes: 0,
+            next_seq: 0,
+            since_checkpoint: 0,
+            checkpoint_interval: DEFAULT_CHECKPOINT_INTERVAL,
+            max_bytes: None,
+            rotations: 1,
+            error: None,
+        };
+        journal.checkpoint(graph)?;
+        Ok(journal)
+    }
+
+    /// Builder: write a checkpoint after every `interval` entries
+    pub fn with_checkpoint_interval(mut self, interval: usize) -> Self {
+        self.checkpoint_interval = interval.max(1);
+        self
+    }
+
+    /// Builder: start a new file once the journal grows past `max_bytes`
+    ///
+    /// The new file begins with a checkpoint, so the cap should comfortably
+    /// exceed the size of one.
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Builder: keep `count` rotated files (`<path>.1` is the newest);
+    /// 0 deletes them. The default is 1.
+    pub fn with_rotations(mut self, count: usize) -> Self {
+        self.rotations = count;
+        self
+    }
+
+    /// Path of the current journal file
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Path of a rotated journal file (1 is the newest)
+    pub fn rotated_path(&self, index: usize) -> PathBuf {
+        rotated_path(&self.path, index)
+    }
+
+    /// Size of the current journal file in bytes
+    pub fn len(&self) -> u64 {
+        self.bytes
+    }
+
+    /// Returns true if nothing has been written to the current file
+    pub fn is_empty(&self) -> bool {
+        self.bytes == 0
+    }
+
+    /// The first write error since the last call, if any
+    pub fn take_error(&mut self) -> Option<io::Error> {
+        self.error.take()
+    }
+
+    /// Journal a command that was just executed on `graph`
+    ///
+    /// Writes the command's entry, or a checkpoint if it has none, followed
+    /// by a checkpoint when one is due.
+    pub fn record(&mut self, graph: &Graph, command: &dyn Command) -> io::Result<()> {
+        if self.max_bytes.is_some_and(|max| self.bytes > max) {
+            return self.rotate(graph);
+        }
+        let Some(entry) = command.to_journal_entry() else {
+            return self.checkpoint(graph);
+        };
+        let seq = self.take_seq();
+        self.append(&Record::Command {
+            seq,
+            name: command.name().to_string(),
+            entry,
+        })?;
+        self.since_checkpoint += 1;
+        if self.since_checkpoint >= self.checkpoint_interval {
+            self.checkpoint(graph)?;
+        }
+        Ok(())
+    }
+
+    /// Write a checkpoint of the whole graph
+    pub fn checkpoint(&mut self, graph: &Graph) -> io::Result<()> {
+        let record = self.checkpoint_record(graph);
+        self.append(&record)?;
+        self.since_checkpoint = 0;
+        Ok(())
+    }
+
+    /// Record through [`record`](Self::record) or [`checkpoint`](Self::checkpoint),
+    /// keeping a failure for [`take_error`](Self::take_error)
+    pub(crate) fn observe(&mut self, graph: &Graph, command: Option<&dyn Command>) {
+        let result = match command {
+            Some(command) => self.record(graph, command),
+            None => self.checkpoint(graph),
+        };
+        if let Err(e) = result {
+            self.error.get_or_insert(e);
+        }
+    }
+
+    /// Rebuild the graph a journal file describes
+    pub fn recover(
+        path: impl AsRef<Path>,
+        registry: &OperatorRegistry,
+    ) -> Result<Graph, RecoverError> {
+        Ok(Self::recover_with_report(path, registry)?.graph)
+    }
+
+    /// Rebuild the graph a journal file describes, reporting how much of
+    /// the journal was used
+    pub fn recover_with_report(
+        path: impl AsRef<Path>,
+        registry: &OperatorRegistry,
+    ) -> Result<Recovery, RecoverError> {
+        let bytes = fs::read(path)?;
+        let lines: Vec<&[u8]> = bytes
+            .split(|&b| b == b'\n')
+            .filter(|line| !line.is_empty())
+            .collect();
+
+        // Records up to the first one that can't be read
+        let records: Vec<Record> = lines
+            .iter()
+            .map_while(|line| serde_json::from_slice(line).ok())
+            .collect();
+        let mut unreadable = lines.len() - records.len();
+
+        let start = records
+            .iter()
+            .rposition(|record| matches!(record, Record::Checkpoint { .. }))
+            .ok_or(RecoverError::NoCheckpoint)?;
+        let Record::Checkpoint { symbol, layout, .. } = &records[start] else {
+            unreachable!("position of a checkpoint");
+        };
+
+        let mut graph = Graph::new();
+        let loaded = symbol.instantiate(&mut graph, registry);
+        layout.restore_positions(&mut graph, &loaded.id_map);
+        layout.restore_groups(&mut graph, &loaded.id_map);
+
+        let mut replayed = 0;
+        let entries = &records[start + 1..];
+        for record in entries {
+            let Record::Command { entry, .. } = record else {
+                unreachable!("no checkpoint after the latest");
+            };
+            let applied = serde_json::from_value::<JournalEntry>(entry.clone())
+                .is_ok_and(|entry| entry.apply(&mut graph, &loaded.id_map));
+            if !applied {
+                break;
+            }
+            replayed += 1;
+        }
+        unreadable += entries.len() - replayed;
+
+        Ok(Recovery {
+            graph,
+            replayed,
+            unreadable,
+            report: loaded.report,
+        })
+    }
+
+    fn take_seq(&mut self) -> u64 {
+        self.next_seq += 1;
+        self.next_seq - 1
+    }
+
+    fn checkpoint_record(&mut self, graph: &Graph) -> Record {
+        let symbol = SymbolDef::from_graph("Journal", graph, &self.registry);
+        let layout = GraphDef::new("Journal", symbol.id)
+            .with_positions_from(graph)
+            .with_groups_from(graph);
+        Record::Checkpoint {
+            seq: self.take_seq(),
+            symbol: Box::new(symbol),
+            layout: Box::new(layout),
+        }
+    }
+
+    fn append(&mut self, record: &Record) -> io::Result<()> {
+        let mut line = serde_json::to_vec(record)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.file.flush()?;
+        self.bytes += line.len() as u64;
+        Ok(())
+    }
+
+    /// Move the current file aside and start a new one with a checkpoint
+    ///
+    /// The new file is written completely before it replaces the old one.
+    fn rotate(&mut self, graph: &Graph) -> io::Result<()> {
+        let record = self.checkpoint_record(graph);
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        let fresh = suffixed(&self.path, ".tmp");
+        fs::write(&fresh, &line)?;
+
+        if self.rotations == 0 {
+            fs::remove_file(&self.path)?;
+        } else {
+            for index in (1..self.rotations).rev() {
+                let older = rotated_path(&self.path, index);
+                if older.exists() {
+                    fs::rename(&older, rotated_path(&self.path, index + 1))?;
+                }
+            }
+            fs::rename(&self.path, rotated_path(&self.path, 1))?;
+        }
+        fs::rename(&fresh, &self.path)?;
+
+        self.file = OpenOptions::new().append(true).open(&self.path)?;
+        self.bytes = line.len() as u64;
+        self.since_checkpoint = 0;
+        Ok(())
+    }
+}
+
+fn rotated_path(path: &Path, index: usize) -> PathBuf {
+    suffixed(path, &format!(".{}", index))
+}
+
+/// `path` with `suffix` appended to its file name
+fn suffixed(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::{AddNodeCommand, ConnectCommand, SetInputDefaultCommand};
+    use crate::UndoRedoStack;
+    use flux_core::{EvalContext, Operator};
+    use flux_operators::{create_default_registry, AddOp, ConstantOp};
+
+    fn journal_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("flux_journal_{}_{}", name, Id::new()))
+    }
+
+    /// Value of the single Add node in a graph
+    fn add_result(graph: &mut Graph) -> Value {
+        let add = graph
+            .node_ids()
+            .find(|&id| graph.node_name(id) == Some("Add"))
+            .unwrap();
+        graph.evaluate(add, 0, &EvalContext::new()).unwrap()
+    }
+
+    #[test]
+    fn test_recover_after_truncated_write() {
+        let registry = Arc::new(create_default_registry());
+        let path = journal_path("truncated");
+        let mut graph = Graph::new();
+        let mut history = UndoRedoStack::new();
+        let journal = Journal::create(&path, registry.clone(), &graph)
+            .unwrap()
+            .with_checkpoint_interval(3);
+        history.attach_journal(journal);
+
+        // Added nodes are checkpointed; the rest are entries
+        let constant = ConstantOp::new(1.0);
+        let constant_id = constant.id();
+        history.execute(&mut graph, AddNodeCommand::new(constant));
+        let add = AddOp::new();
+        let add_id = add.id();
+        history.execute(&mut graph, AddNodeCommand::new(add));
+        history.execute(&mut graph, ConnectCommand::new(constant_id, 0, add_id, 0));
+        history.execute(
+            &mut graph,
+            SetInputDefaultCommand::new(add_id, 1, Value::Float(10.0)),
+        );
+        // Third entry: followed by a checkpoint
+        history.execute(
+            &mut graph,
+            SetInputDefaultCommand::new(constant_id, 0, Value::Float(2.0)),
+        );
+        history.execute(
+            &mut graph,
+            SetInputDefaultCommand::new(add_id, 1, Value::Float(20.0)),
+        );
+        let len_before_last = history.journal().unwrap().len();
+        history.execute(
+            &mut graph,
+            SetInputDefaultCommand::new(add_id, 1, Value::Float(30.0)),
+        );
+        assert!(history.journal_mut().unwrap().take_error().is_none());
+
+        // Crash halfway through writing the last entry
+        let journal = history.detach_journal().unwrap();
+        let full_len = journal.len();
+        drop(journal);
+        let file = OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len((len_before_last + full_len) / 2).unwrap();
+
+        let mut recovery = Journal::recover_with_report(&path, &registry).unwrap();
+        assert_eq!((recovery.replayed, recovery.unreadable), (1, 1));
+        assert!(recovery.report.is_clean());
+        assert_eq!(recovery.graph.node_count(), 2);
+        assert_eq!(add_result(&mut recovery.graph), Value::Float(22.0));
+
+        let mut graph = Journal::recover(&path, &registry).unwrap();
+        assert_eq!(add_result(&mut graph), Value::Float(22.0));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_undo_checkpoints_and_missing_checkpoint() {
+        let registry = Arc::new(create_default_registry());
+        let path = journal_path("undo");
+        let mut graph = Graph::new();
+        let constant = graph.add(ConstantOp::new(1.0));
+        let add = graph.add(AddOp::new());
+        graph.connect(constant, 0, add, 0).unwrap();
+
+        let mut history = UndoRedoStack::new();
+        history.attach_journal(Journal::create(&path, registry.clone(), &graph).unwrap());
+        history.execute(
+            &mut graph,
+            SetInputDefaultCommand::new(add, 1, Value::Float(5.0)),
+        );
+        history.undo(&mut graph);
+        let mut recovered = Journal::recover(&path, &registry).unwrap();
+        assert_eq!(add_result(&mut recovered), Value::Float(1.0));
+
+        // A file cut inside its only checkpoint has nothing to recover
+        let file = OpenOptions::new().write(true).open(&path).unwrap();
+        file.set_len(10).unwrap();
+        assert!(matches!(
+            Journal::recover(&path, &registry),
+            Err(RecoverError::NoCheckpoint)
+        ));
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_rotation_at_size_cap() {
+        let registry = Arc::new(create_default_registry());
+        let path = journal_path("rotate");
+        let mut graph = Graph::new();
+        let add = graph.add(AddOp::new());
+        let mut journal = Journal::create(&path, registry.clone(), &graph)
+            .unwrap()
+            .with_max_bytes(2048)
+            .with_rotations(2);
+
+        for i in 0..200 {
+            let mut command = SetInputDefaultCommand::new(add, 0, Value::Float(i as f32));
+            command.execute(&mut graph);
+            journal.record(&graph, &command).unwrap();
+            assert!(journal.len() <= 4096);
+        }
+        assert!(journal.rotated_path(1).exists());
+        assert!(journal.rotated_path(2).exists());
+        assert!(!journal.rotated_path(3).exists());
+
+        let mut recovered = Journal::recover(&path, &registry).unwrap();
+        assert_eq!(add_result(&mut recovered), Value::Float(199.0));
+        for file in [path.clone(), journal.rotated_path(1), journal.rotated_path(2)] {
+            fs::remove_file(file).unwrap();
+        }
+    }
+}
@@ -0,0 +1,439 @@
+//! Automatic node layout
+//!
+//! Graphs built in code, or loaded into a frontend that doesn't know their
+//! saved positions, have nothing to draw their nodes at. [`auto_layout`]
+//! computes a readable left-to-right arrangement with a layered
+//! (Sugiyama-style) algorithm:
+//!
+//! 1. Nodes are ranked by their longest path from a source, so every
+//!    connection points to a higher rank
+//! 2. Connections spanning several ranks get a placeholder in each rank
+//!    they pass, so the ordering step sees them
+//! 3. Nodes are ordered within their rank by the median position of their
+//!    neighbours in the adjacent rank, sweeping back and forth and keeping
+//!    the ordering with the fewest crossings
+//! 4. Ranks become columns and order becomes rows, centered vertically
+//!
+//! Only value connections take part; trigger connections don't affect the
+//! layout. Auto-inserted conversion nodes can be left out of the ranking
+//! and placed halfway along the connection they sit on.
+//!
+//! ```ignore
+//! let positions = layout::auto_layout(&graph, &LayoutOptions::default());
+//! for (node, position) in positions {
+//!     graph.set_node_position(node, position);
+//! }
+//! ```
+//!
+//! The result is deterministic for a given graph.
+
+use std::collections::HashMap;
+
+use flux_core::id::Id;
+
+use crate::conversion::{ConversionOp, UnitConversionOp};
+use crate::graph::Graph;
+
+/// Options for [`auto_layout`]
+#[derive(Debug, Clone)]
+pub struct LayoutOptions {
+    /// Position of the top-left slot
+    pub origin: [f32; 2],
+    /// Horizontal distance between ranks
+    pub rank_spacing: f32,
+    /// Vertical distance between nodes in a rank
+    pub node_spacing: f32,
+    /// Down-and-up sweeps of the median heuristic
+    pub sweeps: usize,
+    /// Place conversion nodes at the midpoint of the connection they sit on
+    /// instead of giving them a rank of their own
+    pub conversions_at_midpoint: bool,
+}
+
+impl Default for LayoutOptions {
+    fn default() -> Self {
+        Self {
+            origin: [0.0, 0.0],
+            rank_spacing: 250.0,
+            node_spacing: 100.0,
+            sweeps: 4,
+            conversions_at_midpoint: true,
+        }
+    }
+}
+
+impl LayoutOptions {
+    /// Create default options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builder: set the distance between ranks and between nodes in a rank
+    pub fn with_spacing(mut self, rank_spacing: f32, node_spacing: f32) -> Self {
+        self.rank_spacing = rank_spacing;
+        self.node_spacing = node_spacing;
+        self
+    }
+
+    /// Builder: give conversion nodes a rank like any other node
+    pub fn with_ranked_conversions(mut self) -> Self {
+        self.conversions_at_midpoint = false;
+        self
+    }
+}
+
+/// A conversion node placed between its source and target
+struct Midpoint {
+    node: Id,
+    source: Id,
+    target: Id,
+}
+
+/// Compute a position for every node in `graph`
+pub fn auto_layout(graph: &Graph, options: &LayoutOptions) -> HashMap<Id, [f32; 2]> {
+    let order = graph.node_ids_in_insertion_order();
+    let midpoints = if options.conversions_at_midpoint {
+        conversion_midpoints(graph, &order)
+    } else {
+        Vec::new()
+    };
+
+    // Index the ranked nodes in insertion order
+    let ranked: Vec<Id> = order
+        .iter()
+        .copied()
+        .filter(|id| !midpoints.iter().any(|m| m.node == *id))
+        .collect();
+    let index: HashMap<Id, usize> = ranked.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+
+    // Edges between ranked nodes, bridging over the midpoint conversions
+    let bridged = |id: Id, upstream: bool| {
+        midpoints.iter().find(|m| m.node == id).map_or(id, |m| {
+            if upstream {
+                m.source
+            } else {
+                m.target
+            }
+        })
+    };
+    let mut edges: Vec<(usize, usize)> = graph
+        .connections()
+        .filter_map(|c| {
+            let source = index.get(&bridged(c.source_node, true))?;
+            let target = index.get(&bridged(c.target_node, false))?;
+            Some((*source, *target))
+        })
+        .collect();
+    edges.sort_unstable();
+    edges.dedup();
+
+    let mut layers = Layers::new(ranked.len(), &edges);
+    layers.reduce_crossings(options.sweeps);
+
+    let mut positions = HashMap::with_capacity(order.len());
+    let tallest = layers.ranks.iter().map(Vec::len).max().unwrap_or(0);
+    for (rank, members) in layers.ranks.iter().enumerate() {
+        let offset = (tallest - members.len()) as f32 / 2.0;
+        for (row, &vertex) in members.iter().enumerate() {
+            if let Some(&id) = ranked.get(vertex) {
+                let x = options.origin[0] + rank as f32 * options.rank_spacing;
+                let y = options.origin[1] + (offset + row as f32) * options.node_spacing;
+                positions.insert(id, [x, y]);
+            }
+        }
+    }
+
+    for midpoint in &midpoints {
+        let (source, target) = (positions[&midpoint.source], positions[&midpoint.target]);
+        let mut position = [(source[0] + target[0]) / 2.0, (source[1] + target[1]) / 2.0];
+        // Conversions sharing a midpoint, or landing on a node, stack downwards
+        while positions
+            .values()
+            .any(|other| overlaps(*other, position, options))
+        {
+            position[1] += options.node_spacing / 2.0;
+        }
+        positions.insert(midpoint.node, position);
+    }
+    positions
+}
+
+/// Conversion nodes with one incoming and one outgoing connection between
+/// nodes that aren't conversions themselves
+fn conversion_midpoints(graph: &Graph, order: &[Id]) -> Vec<Midpoint> {
+    let is_conversion = |id: Id| {
+        graph.get(id).is_some_and(|op| {
+            op.as_any().is::<ConversionOp>() || op.as_any().is::<UnitConversionOp>()
+        })
+    };
+    order
+        .iter()
+        .filter(|&&id| is_conversion(id))
+        .filter_map(|&node| {
+            let (upstream, downstream) = (graph.upstream_of(node), graph.downstream_of(node));
+            let ([input], [output]) = (upstream.as_slice(), downstream.as_slice()) else {
+                return None;
+            };
+            let (source, target) = (input.source_node, output.target_node);
+            (!is_conversion(source) && !is_conversion(target)).then_some(Midpoint {
+                node,
+                source,
+                target,
+            })
+        })
+        .collect()
+}
+
+/// Returns true if two node positions are closer than half a slot
+fn overlaps(a: [f32; 2], b: [f32; 2], options: &LayoutOptions) -> bool {
+    (a[0] - b[0]).abs() < options.rank_spacing / 2.0
+        && (a[1] - b[1]).abs() < options.node_spacing / 2.0
+}
+
+/// Ranked vertices: the nodes, then one placeholder per rank a long edge
+/// passes through
+struct Layers {
+    /// Vertices of each rank, in order
+    ranks: Vec<Vec<usize>>,
+    /// Vertices in the previous rank connected to each vertex
+    upper: Vec<Vec<usize>>,
+    /// Vertices in the next rank connected to each vertex
+    lower: Vec<Vec<usize>>,
+}
+
+impl Layers {
+    /// Rank `node_count` nodes by longest path and split long edges
+    fn new(node_count: usize, edges: &[(usize, usize)]) -> Self {
+        let mut incoming = vec![0usize; node_count];
+        let mut outgoing: Vec<Vec<usize>> = vec![Vec::new(); node_count];
+        for &(source, target) in edges {
+            incoming[target] += 1;
+            outgoing[source].push(target);
+        }
+
+        // Kahn's algorithm in insertion order; the graph is acyclic
+        let mut rank = vec![0usize; node_count];
+        let mut ready: Vec<usize> = (0..node_count)
+            .rev()
+            .filter(|&v| incoming[v] == 0)
+            .collect();
+        while let Some(vertex) = ready.pop() {
+            for &next in &outgoing[vertex] {
+                rank[next] = rank[next].max(rank[vertex] + 1);
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+
+        let rank_count = rank.iter().max().map_or(0, |r| r + 1);
+        let mut layers = Self {
+            ranks: vec![Vec::new(); rank_count],
+            upper: vec![Vec::new(); node_count],
+            lower: vec![Vec::new(); node_count],
+        };
+        for (vertex, &r) in rank.iter().enumerate() {
+            layers.ranks[r].push(vertex);
+        }
+        for &(source, target) in edges {
+            let mut previous = source;
+            for r in rank[source] + 1..rank[target] {
+                let placeholder = layers.upper.len();
+                layers.upper.push(Vec::new());
+                layers.lower.push(Vec::new());
+                layers.ranks[r].push(placeholder);
+                layers.link(previous, placeholder);
+                previous = placeholder;
+            }
+            layers.link(previous, target);
+        }
+        layers
+    }
+
+    fn link(&mut self, upper: usize, lower: usize) {
+        self.lower[upper].push(lower);
+        self.upper[lower].push(upper);
+    }
+
+    /// Reorder ranks with the median heuristic, keeping the best ordering
+    fn reduce_crossings(&mut self, sweeps: usize) {
+        let mut best = self.ranks.clone();
+        let mut best_crossings = self.crossings();
+        for _ in 0..sweeps {
+            if best_crossings == 0 {
+                break;
+            }
+            for r in 1..self.ranks.len() {
+                self.order_by_median(r, r - 1, true);
+            }
+            for r in (0..self.ranks.len().saturating_sub(1)).rev() {
+                self.order_by_median(r, r + 1, false);
+            }
+            let crossings = self.crossings();
+            if crossings < best_crossings {
+                best = self.ranks.clone();
+                best_crossings = crossings;
+            }
+        }
+        self.ranks = best;
+    }
+
+    /// Sort rank `r` by the median row of each vertex's neighbours in rank
+    /// `fixed`; vertices without neighbours there keep their row
+    fn order_by_median(&mut self, r: usize, fixed: usize, from_upper: bool) {
+        let row = rows(&self.ranks[fixed], self.upper.len());
+        let mut keyed: Vec<(f32, usize)> = self.ranks[r]
+            .iter()
+            .enumerate()
+            .map(|(current, &vertex)| {
+                let neighbours = if from_upper {
+                    &self.upper[vertex]
+                } else {
+                    &self.lower[vertex]
+                };
+                let mut positions: Vec<usize> = neighbours.iter().map(|&n| row[n]).collect();
+                positions.sort_unstable();
+                let mid = positions.len() / 2;
+                let key = match positions.len() {
+                    0 => current as f32,
+                    len if len % 2 == 1 => positions[mid] as f32,
+                    _ => (positions[mid - 1] + positions[mid]) as f32 / 2.0,
+                };
+                (key, vertex)
+            })
+            .collect();
+        keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+        self.ranks[r] = keyed.into_iter().map(|(_, vertex)| vertex).collect();
+    }
+
+    /// Number of edge crossings between all adjacent ranks
+    fn crossings(&self) -> usize {
+        let mut total = 0;
+        for pair in self.ranks.windows(2) {
+            let (upper_row, lower_row) = (
+                rows(&pair[0], self.upper.len()),
+                rows(&pair[1], self.upper.len()),
+            );
+            let edges: Vec<(usize, usize)> = pair[0]
+                .iter()
+                .flat_map(|&u| self.lower[u].iter().map(move |&l| (u, l)))
+                .map(|(u, l)| (upper_row[u], lower_row[l]))
+                .collect();
+            for (i, a) in edges.iter().enumerate() {
+                total += edges[i + 1..]
+                    .iter()
+                    .filter(|b| (a.0 < b.0 && a.1 > b.1) || (a.0 > b.0 && a.1 < b.1))
+                    .count();
+            }
+        }
+        total
+    }
+}
+
+/// Row of each vertex in `rank`, indexed by vertex
+fn rows(rank: &[usize], vertex_count: usize) -> Vec<usize> {
+    let mut row = vec![0; vertex_count];
+    for (i, &vertex) in rank.iter().enumerate() {
+        row[vertex] = i;
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_operators::{AddOp, ConstantOp, IntAddOp, MultiplyOp};
+
+    /// (a + b) * (c + a) + a, and a lone constant
+    fn diamond() -> (Graph, [Id; 7]) {
+        let mut graph = Graph::new();
+        let a = graph.add(ConstantOp::new(1.0));
+        let b = graph.add(ConstantOp::new(2.0));
+        let c = graph.add(ConstantOp::new(3.0));
+        let lone = graph.add(ConstantOp::new(4.0));
+        let add = graph.add(AddOp::new());
+        let sum = graph.add(AddOp::new());
+        let mul = graph.add(MultiplyOp::new());
+        let out = graph.add(AddOp::new());
+        graph.connect(a, 0, add, 0).unwrap();
+        graph.connect(b, 0, add, 1).unwrap();
+        graph.connect(c, 0, sum, 0).unwrap();
+        graph.connect(a, 0, sum, 1).unwrap();
+        graph.connect(add, 0, mul, 0).unwrap();
+        graph.connect(sum, 0, mul, 1).unwrap();
+        graph.connect(mul, 0, out, 0).unwrap();
+        graph.connect(a, 0, out, 1).unwrap();
+        (graph, [a, b, c, lone, add, mul, out])
+    }
+
+    #[test]
+    fn test_layout_follows_dependencies() {
+        let (graph, [a, b, c, lone, add, mul, out]) = diamond();
+        let positions = auto_layout(&graph, &LayoutOptions::default());
+        assert_eq!(positions.len(), graph.node_count());
+
+        for connection in graph.connections() {
+            let (source, target) = (
+                positions[&connection.source_node],
+                positions[&connection.target_node],
+            );
+            assert!(source[0] < target[0], "{:?} -> {:?}", source, target);
+        }
+        for id in [a, b, c, lone] {
+            assert_eq!(positions[&id][0], 0.0);
+        }
+        assert_eq!(positions[&add][0], 250.0);
+        assert_eq!(positions[&mul][0], 500.0);
+        assert_eq!(positions[&out][0], 750.0);
+
+        // No two nodes share a slot
+        let all: Vec<[f32; 2]> = positions.values().copied().collect();
+        for (i, p) in all.iter().enumerate() {
+            for q in &all[i + 1..] {
+                assert!(
+                    p[0] != q[0] || (p[1] - q[1]).abs() >= 100.0,
+                    "{:?} {:?}",
+                    p,
+                    q
+                );
+            }
+        }
+        assert_eq!(auto_layout(&graph, &LayoutOptions::default()), positions);
+    }
+
+    #[test]
+    fn test_median_ordering_removes_crossings() {
+        // Sources added in the opposite order to their targets
+        let mut graph = Graph::new();
+        let sources: Vec<Id> = (0..3)
+            .map(|i| graph.add(ConstantOp::new(i as f32)))
+            .collect();
+        let targets: Vec<Id> = (0..3).map(|_| graph.add(AddOp::new())).collect();
+        for (source, target) in sources.iter().zip(targets.iter().rev()) {
+            graph.connect(*source, 0, *target, 0).unwrap();
+        }
+
+        let positions = auto_layout(&graph, &LayoutOptions::default());
+        for (source, target) in sources.iter().zip(targets.iter().rev()) {
+            assert_eq!(positions[source][1], positions[target][1]);
+        }
+    }
+
+    #[test]
+    fn test_conversions_at_midpoint() {
+        let mut graph = Graph::new();
+        let value = graph.add(ConstantOp::new(1.0));
+        let int_add = graph.add(IntAddOp::new());
+        let conversion = graph.connect(value, 0, int_add, 0).unwrap().unwrap();
+
+        let positions = auto_layout(&graph, &LayoutOptions::default());
+        assert_eq!(positions[&value], [0.0, 0.0]);
+        assert_eq!(positions[&int_add], [250.0, 0.0]);
+        assert_eq!(positions[&conversion], [125.0, 0.0]);
+
+        let ranked = auto_layout(&graph, &LayoutOptions::new().with_ranked_conversions());
+        assert_eq!(ranked[&conversion], [250.0, 0.0]);
+        assert_eq!(ranked[&int_add], [500.0, 0.0]);
+    }
+}
@@ -6,43 +6,127 @@
 //!
 //! - [`graph`] - The main graph structure for connecting and evaluating operators
 //! - [`associated`] - Associated graph wrapper for external ID management
+//! - [`budget`] - Time-sliced evaluation across frames
+//! - [`builder`] - Fluent graph construction by operator and port name
 //! - [`bypass`] - Bypass state management for disabled nodes
+//! - [`clipboard`] - Copy/paste of node selections
 //! - [`composite`] - Composite operators (nested graphs)
 //! - [`conversion`] - Type conversion operators (auto-inserted by graph)
+//! - [`cue`] - Named graph states with timed transitions
+//! - [`event_queue`] - Capacity and overflow policy for pending graph events
+//! - [`export`] - DOT/Mermaid text export of graph structure
+//! - [`frame`] - Explicit evaluation frames and per-frame state
+//! - [`graph_operator`] - Whole graphs wrapped as single operators
+//! - [`layout`] - Automatic layered node layout for position-less graphs
+//! - [`lint`] - Opinionated hygiene rules with suggested fixes
+//! - [`missing`] - Placeholders for operators a loaded file references but the registry lacks
+//! - [`mutate`] - Seeded parameter randomization and snapshot morphing
+//! - [`offline`] - Frame-accurate evaluation over a time range
 //! - [`slot_ref`] - Slot references for input/output connections
 //! - [`instance_path`] - Path tracking for nested operator instances
+//! - [`journal`] - Crash recovery journal of executed commands
 //! - [`symbol`] - Symbol table for operator definitions
 //! - [`animation`] - Keyframe animation system
 //! - [`serialization`] - Graph serialization to/from JSON
+//! - [`search`] - Text search over nodes for "find node in project"
+//! - [`tools`] - Find and replace of values across graphs, composites and symbols
+//! - [`stepper`] - Fixed-timestep stepping for host frame loops
+//! - [`trace`] - Structured record of one evaluation for debugging
 //! - [`resource`] - Resource management (textures, meshes, etc.)
 //! - [`playback`] - Audio and timeline playback
+//! - [`preview`] - Budgeted thumbnail evaluation of node outputs
+//! - [`project`] - Multi-graph project runtime with cross-scene references
+//! - [`quarantine`] - Surviving operator panics during evaluation
+//! - [`determinism`] - Auditing operators for differing outputs across identical evaluations
+//! - [`trash`] - Restorable trash for removed nodes
+//! - [`update_rate`] - Per-node update rate throttling
+//! - [`watch`] - Observing node outputs across evaluations
+//! - [`group`] - Organizational node groups
 
 pub mod animation;
 pub mod associated;
+pub mod budget;
+pub mod builder;
 pub mod bypass;
+pub mod clipboard;
 pub mod commands;
 pub mod compiler;
 pub mod composite;
 pub mod conversion;
+pub mod cue;
+pub mod determinism;
+pub mod event_queue;
+pub mod export;
+pub mod frame;
 pub mod graph;
+pub mod graph_operator;
+pub mod group;
 pub mod instance_path;
+pub mod journal;
+pub mod layout;
+pub mod lint;
+pub mod missing;
+pub mod mutate;
+pub mod offline;
 pub mod playback;
+pub mod preview;
+pub mod project;
+pub mod quarantine;
+pub mod search;
 pub mod serialization;
 pub mod slot_ref;
+pub mod stepper;
 pub mod symbol;
+pub mod tools;
+pub mod trace;
+pub mod trash;
 pub mod undo;
+pub mod update_rate;
+pub mod watch;
 
 // Re-export main types
 pub use associated::{AssociatedGraph, NodeHandle};
+pub use budget::EvalProgress;
+pub use builder::{
+    BuildError, BuilderInput, BuilderNode, BuilderOutput, GraphBuilder, GraphHandles, NodeConfig,
+};
 pub use bypass::{Bypassable, BypassableType, BypassInfo, BypassState};
+pub use clipboard::{ClipboardData, PasteResult};
 pub use commands::{
-    AddNodeCommand, Command, ConnectCommand, DisconnectCommand, MacroCommand, RemoveNodeCommand,
-    SetInputDefaultCommand,
+    AddNodeCommand, AssignToGroupCommand, Command, ConnectCommand, CreateGroupCommand,
+    DisconnectCommand, FoldInputCommand, MacroCommand, RemoveNodeCommand, ResetInputsCommand,
+    RetargetConnectionCommand, SetConnectionMutedCommand, SetGizmoVisibilityCommand,
+    SetInputDefaultCommand, SetInputSourceCommand, SetUpdateRateCommand, SpliceNodeCommand,
+    SwapInputsCommand, UnspliceNodeCommand,
 };
 pub use compiler::CompiledGraph;
 pub use composite::CompositeOp;
-pub use conversion::ConversionOp;
-pub use graph::{Connection, Graph, GraphEvent, GraphStats};
+pub use conversion::{ConversionOp, UnitConversionOp};
+pub use cue::{Cue, CueDiagnostic, CueError, CueList};
+pub use event_queue::EventOverflow;
+pub use export::ExportOptions;
+pub use graph::{
+    Connection, DetailedGraphStats, EvalTypeError, Graph, GraphEvent, GraphIssue, GraphStats,
+    PortChangeReport, SwapReport, TriggerConnection,
+};
+pub use graph_operator::GraphOperator;
+pub use group::{Group, GroupId};
 pub use instance_path::InstancePath;
+pub use journal::{Journal, JournalEntry, RecoverError, Recovery};
+pub use layout::{auto_layout, LayoutOptions};
+pub use lint::{lint, LintConfig, LintFinding, LintKind, LintRule, LintSeverity};
+pub use missing::MissingOp;
+pub use mutate::{GraphSnapshot, Mutator};
+pub use offline::{render_range, RangeRender, RangeResult};
+pub use determinism::{DeterminismReport, DivergentNode, OutputDivergence, UnauditableNode};
+pub use preview::{PreviewResult, PreviewSettings};
+pub use project::{Project, ProjectError, SceneReferenceOp};
+pub use quarantine::{NodePanic, PanicPolicy};
+pub use search::{MatchField, NodeIndex, NodeMatch};
 pub use slot_ref::SlotRef;
+pub use stepper::FrameStepper;
+pub use trace::{EvalTrace, NodeTrace, TraceReason, TracedPort, TracedValue};
+pub use trash::{RestoreReport, TrashedNode};
 pub use undo::UndoRedoStack;
+pub use update_rate::UpdateRate;
+pub use watch::{WatchId, WatchUpdate};
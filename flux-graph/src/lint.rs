@@ -0,0 +1,898 @@
+//! Opinionated lint rules for project hygiene
+//!
+//! Where [`Graph::validate`] reports broken structure, [`lint`] reports
+//! patches that work but could be simpler or cheaper: unused outputs, chains
+//! computed from constants only, stacked conversions, animation that never
+//! moves, duplicated nodes and deep delay chains.
+//!
+//! Each [`LintFinding`] names the nodes involved and, when the cleanup is
+//! mechanical, carries a [`Command`] that applies it. Executing the fix on an
+//! [`UndoRedoStack`](crate::UndoRedoStack) makes it one undo step.
+//!
+//! Rules implement [`LintRule`]; [`default_rules`] is the built-in set and
+//! [`LintConfig`] switches each of them on or off.
+//!
+//! ```ignore
+//! let config = LintConfig::new().disable(LintKind::UnusedOutput);
+//! for mut finding in lint(&graph, &config) {
+//!     println!("{:?}: {}", finding.severity, finding.message);
+//!     if let Some(fix) = finding.fix.take() {
+//!         history.execute_boxed(&mut graph, fix);
+//!     }
+//! }
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use flux_core::port::DefaultSource;
+use flux_core::{Id, Operator};
+
+use crate::animation::Animator;
+use crate::commands::{
+    Command, ConnectCommand, FoldInputCommand, MacroCommand, RemoveNodeCommand,
+    RetargetConnectionCommand,
+};
+use crate::conversion::ConversionOp;
+use crate::graph::{Connection, Graph};
+
+/// Operators that hold a value back by at least one frame
+const DELAY_OPERATORS: &[&str] = &["Delay", "DelayLine", "Previous"];
+
+/// How much attention a finding deserves
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /// Worth knowing, nothing to change
+    Info,
+    /// A simplification is available
+    Suggestion,
+    /// Likely a mistake or a performance problem
+    Warning,
+}
+
+/// The rule that produced a finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintKind {
+    /// An unconnected output on a node whose other outputs are used
+    UnusedOutput,
+    /// An input computed from constants only, which could be a literal
+    ConstantChain,
+    /// Two or more conversion nodes in a row
+    ConversionChain,
+    /// An animated input whose curve never leaves the type default
+    StaticAnimation,
+    /// Nodes with the same operator and the same inputs
+    DuplicateNode,
+    /// More delay nodes in a row than [`LintConfig::max_delay_depth`]
+    DeepDelayChain,
+}
+
+/// One problem reported by a lint rule
+#[derive(Debug)]
+pub struct LintFinding {
+    /// Rule that reported it
+    pub kind: LintKind,
+    /// How much attention it deserves
+    pub severity: LintSeverity,
+    /// Nodes involved, the one to look at first leading
+    pub nodes: Vec<Id>,
+    /// Human-readable description
+    pub message: String,
+    /// Command that resolves the finding, if it can be fixed mechanically
+    pub fix: Option<Box<dyn Command>>,
+}
+
+/// Which rules run, and their thresholds
+#[derive(Debug, Clone)]
+pub struct LintConfig {
+    /// Rules switched off
+    disabled: HashSet<LintKind>,
+    /// Longest run of delay nodes accepted by [`LintKind::DeepDelayChain`]
+    pub max_delay_depth: usize,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            disabled: HashSet::new(),
+            max_delay_depth: 16,
+        }
+    }
+}
+
+impl LintConfig {
+    /// All rules enabled with default thresholds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Switch a rule off.
+    pub fn disable(mut self, kind: LintKind) -> Self {
+        self.set_enabled(kind, false);
+        self
+    }
+
+    /// Switch a rule back on.
+    pub fn enable(mut self, kind: LintKind) -> Self {
+        self.set_enabled(kind, true);
+        self
+    }
+
+    /// Set the longest run of delay nodes that isn't reported.
+    pub fn with_max_delay_depth(mut self, depth: usize) -> Self {
+        self.max_delay_depth = depth;
+        self
+    }
+
+    /// Switch a rule on or off.
+    pub fn set_enabled(&mut self, kind: LintKind, enabled: bool) {
+        if enabled {
+            self.disabled.remove(&kind);
+        } else {
+            self.disabled.insert(kind);
+        }
+    }
+
+    /// Whether a rule runs.
+    pub fn is_enabled(&self, kind: LintKind) -> bool {
+        !self.disabled.contains(&kind)
+    }
+}
+
+/// What a rule can see while checking
+pub struct LintContext<'a> {
+    /// The graph being linted
+    pub graph: &'a Graph,
+    /// Animation driving the graph's inputs, if known
+    pub animator: Option<&'a Animator>,
+    /// Rule switches and thresholds
+    pub config: &'a LintConfig,
+}
+
+impl LintContext<'_> {
+    /// Whether an enabled curve drives this input.
+    fn is_animated(&self, node: Id, input: usize) -> bool {
+        self.animator
+            .and_then(|animator| animator.get_binding(node, input))
+            .is_some_and(|binding| binding.enabled)
+    }
+}
+
+/// A lint rule.
+///
+/// Rules only read the graph; anything they suggest changing goes into
+/// [`LintFinding::fix`].
+pub trait LintRule {
+    /// The kind of finding this rule reports (used for toggling).
+    fn kind(&self) -> LintKind;
+
+    /// Append this rule's findings for the graph in `cx`.
+    fn check(&self, cx: &LintContext<'_>, findings: &mut Vec<LintFinding>);
+}
+
+/// The built-in rules, one per [`LintKind`].
+pub fn default_rules() -> Vec<Box<dyn LintRule>> {
+    vec![
+        Box::new(UnusedOutputRule),
+        Box::new(ConstantChainRule),
+        Box::new(ConversionChainRule),
+        Box::new(StaticAnimationRule),
+        Box::new(DuplicateNodeRule),
+        Box::new(DeepDelayChainRule),
+    ]
+}
+
+/// Run the default rules enabled in `config`.
+///
+/// [`LintKind::StaticAnimation`] needs the animator and reports nothing here;
+/// use [`lint_animated`] to include it.
+pub fn lint(graph: &Graph, config: &LintConfig) -> Vec<LintFinding> {
+    let cx = LintContext {
+        graph,
+        animator: None,
+        config,
+    };
+    run_rules(&cx, &default_rules())
+}
+
+/// Run the default rules enabled in `config`, taking animation into account.
+///
+/// Animated inputs are not treated as constant.
+pub fn lint_animated(graph: &Graph, animator: &Animator, config: &LintConfig) -> Vec<LintFinding> {
+    let cx = LintContext {
+        graph,
+        animator: Some(animator),
+        config,
+    };
+    run_rules(&cx, &default_rules())
+}
+
+/// Run the given rules that are enabled in `cx.config`, in order.
+pub fn run_rules(cx: &LintContext<'_>, rules: &[Box<dyn LintRule>]) -> Vec<LintFinding> {
+    let mut findings = Vec::new();
+    for rule in rules {
+        if cx.config.is_enabled(rule.kind()) {
+            rule.check(cx, &mut findings);
+        }
+    }
+    findings
+}
+
+/// Reports unconnected outputs on nodes that have other outputs in use.
+///
+/// Nodes with no connected outputs at all are sinks and aren't reported.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnusedOutputRule;
+
+impl LintRule for UnusedOutputRule {
+    fn kind(&self) -> LintKind {
+        LintKind::UnusedOutput
+    }
+
+    fn check(&self, cx: &LintContext<'_>, findings: &mut Vec<LintFinding>) {
+        let graph = cx.graph;
+        for id in graph.node_ids_in_insertion_order() {
+            let op = graph.get(id).unwrap();
+            let unused: Vec<usize> = (0..op.outputs().len())
+                .filter(|&output| graph.consumers_of(id, output).is_empty())
+                .collect();
+            if unused.is_empty() || unused.len() == op.outputs().len() {
+                continue;
+            }
+            for output in unused {
+                findings.push(LintFinding {
+                    kind: LintKind::UnusedOutput,
+                    severity: LintSeverity::Info,
+                    nodes: vec![id],
+                    message: format!(
+                        "output '{}' of {} is not connected",
+                        op.outputs()[output].name,
+                        op.name()
+                    ),
+                    fix: None,
+                });
+            }
+        }
+    }
+}
+
+/// Suggests folding inputs fed by chains computed from constants only.
+///
+/// A node is constant when it doesn't depend on time, context or its own
+/// state, no input is bound to a context variable or animated, and every
+/// connected input is itself fed by a constant node. An input is reported
+/// where such a chain (at least one computing node besides conversions)
+/// meets a node that isn't constant. The fix is a [`FoldInputCommand`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConstantChainRule;
+
+impl LintRule for ConstantChainRule {
+    fn kind(&self) -> LintKind {
+        LintKind::ConstantChain
+    }
+
+    fn check(&self, cx: &LintContext<'_>, findings: &mut Vec<LintFinding>) {
+        let graph = cx.graph;
+        let mut constant = HashMap::new();
+        for id in graph.node_ids_in_insertion_order() {
+            if is_constant(cx, id, &mut constant) {
+                continue;
+            }
+            let op = graph.get(id).unwrap();
+            for conn in live_upstream(graph, id) {
+                let source = conn.source_node;
+                if !is_constant(cx, source, &mut constant) || !computes(graph, source) {
+                    continue;
+                }
+                let input = &op.inputs()[conn.target_input];
+                let fix: Option<Box<dyn Command>> = (!input.is_multi_input)
+                    .then(|| Box::new(FoldInputCommand::new(id, conn.target_input)) as _);
+                let mut nodes = vec![id];
+                nodes.extend(constant_closure(graph, source));
+                findings.push(LintFinding {
+                    kind: LintKind::ConstantChain,
+                    severity: LintSeverity::Suggestion,
+                    nodes,
+                    message: format!(
+                        "input '{}' of {} only depends on constants and can be folded into a value",
+                        input.name,
+                        op.name()
+                    ),
+                    fix,
+                });
+            }
+        }
+    }
+}
+
+/// Suggests connecting directly across two or more conversion nodes in a row.
+///
+/// Only conversions that feed nothing but the next one count as a chain. The
+/// fix reconnects the chain's source to its consumers (letting the graph
+/// insert a single conversion if one is needed) and removes the chain; it is
+/// only offered when every consumer is a single input that accepts the
+/// source type.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConversionChainRule;
+
+impl LintRule for ConversionChainRule {
+    fn kind(&self) -> LintKind {
+        LintKind::ConversionChain
+    }
+
+    fn check(&self, cx: &LintContext<'_>, findings: &mut Vec<LintFinding>) {
+        let graph = cx.graph;
+        let is_conversion = |id: Id| {
+            graph
+                .get(id)
+                .is_some_and(|op| op.as_any().is::<ConversionOp>())
+        };
+        // The next link of a chain: the only consumer of a conversion, if it is one
+        let next = |id: Id| match graph.consumers_of(id, 0) {
+            &[(consumer, _)] if is_conversion(consumer) => Some(consumer),
+            _ => None,
+        };
+        let source_of = |id: Id| graph.get(id).unwrap().inputs()[0].connection;
+
+        for id in graph.node_ids_in_insertion_order() {
+            if !is_conversion(id) {
+                continue;
+            }
+            // Start at the head of a chain only
+            if source_of(id)
+                .is_some_and(|(source, _)| is_conversion(source) && next(source) == Some(id))
+            {
+                continue;
+            }
+            let mut chain = vec![id];
+            while let Some(consumer) = next(*chain.last().unwrap()) {
+                chain.push(consumer);
+            }
+            if chain.len() < 2 {
+                continue;
+            }
+
+            let tail = *chain.last().unwrap();
+            let fix = source_of(id).and_then(|source| retype_fix(graph, source, tail, &chain));
+            findings.push(LintFinding {
+                kind: LintKind::ConversionChain,
+                severity: LintSeverity::Suggestion,
+                message: format!(
+                    "{} conversions in a row; connect the source directly instead",
+                    chain.len()
+                ),
+                nodes: chain,
+                fix,
+            });
+        }
+    }
+}
+
+/// Reconnect `source` to every consumer of `tail` and remove `chain`.
+fn retype_fix(
+    graph: &Graph,
+    source: (Id, usize),
+    tail: Id,
+    chain: &[Id],
+) -> Option<Box<dyn Command>> {
+    let consumers = graph.consumers_of(tail, 0);
+    let source_type = graph.get(source.0)?.outputs().get(source.1)?.value_type;
+    let accepts = |&(node, input): &(Id, usize)| {
+        let input = &graph.get(node).unwrap().inputs()[input];
+        !input.is_multi_input
+            && (input.connects_directly(source_type) || source_type.can_coerce_to(input.value_type))
+    };
+    if consumers.is_empty() || !consumers.iter().all(accepts) {
+        return None;
+    }
+
+    let mut fix = MacroCommand::new("Remove Conversion Chain");
+    for &(node, input) in consumers {
+        fix.push(ConnectCommand::new(source.0, source.1, node, input));
+    }
+    for &id in chain {
+        fix.push(RemoveNodeCommand::new(id));
+    }
+    Some(Box::new(fix))
+}
+
+/// Warns about animated inputs whose curve stays at the type default.
+///
+/// Such an input never moves, so the animation is either unfinished or
+/// leftover. Needs the animator (see [`lint_animated`]); there is no fix
+/// since curves live outside the graph.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StaticAnimationRule;
+
+impl LintRule for StaticAnimationRule {
+    fn kind(&self) -> LintKind {
+        LintKind::StaticAnimation
+    }
+
+    fn check(&self, cx: &LintContext<'_>, findings: &mut Vec<LintFinding>) {
+        let Some(animator) = cx.animator else {
+            return;
+        };
+        for binding in animator.bindings().iter().filter(|b| b.enabled) {
+            let target = &binding.target;
+            let Some(op) = cx.graph.get(target.node_id) else {
+                continue;
+            };
+            let Some(input) = op.inputs().get(target.input_index) else {
+                continue;
+            };
+            let Some(default) = input.value_type.default_value().as_float() else {
+                continue;
+            };
+            let default = f64::from(default);
+            let flat = binding.curve.keyframes().iter().all(|k| {
+                k.value == default
+                    && (!k.uses_spline() || (k.in_tangent == 0.0 && k.out_tangent == 0.0))
+            });
+            if input.is_connected() || !flat {
+                continue;
+            }
+            findings.push(LintFinding {
+                kind: LintKind::StaticAnimation,
+                severity: LintSeverity::Warning,
+                nodes: vec![target.node_id],
+                message: format!(
+                    "input '{}' of {} is animated but never leaves its default of {}",
+                    input.name,
+                    op.name(),
+                    default
+                ),
+                fix: None,
+            });
+        }
+    }
+}
+
+/// Suggests merging nodes with the same operator and identical inputs.
+///
+/// Only pure operators with at least one input are compared, since state or
+/// hidden parameters could make look-alikes differ. The later node is
+/// reported together with the earlier one it duplicates; the fix moves its
+/// outgoing connections to the earlier node and removes it.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DuplicateNodeRule;
+
+impl LintRule for DuplicateNodeRule {
+    fn kind(&self) -> LintKind {
+        LintKind::DuplicateNode
+    }
+
+    fn check(&self, cx: &LintContext<'_>, findings: &mut Vec<LintFinding>) {
+        let graph = cx.graph;
+        let mut originals: HashMap<&'static str, Vec<Id>> = HashMap::new();
+        for id in graph.node_ids_in_insertion_order() {
+            let op = graph.get(id).unwrap();
+            if !is_pure(op) || op.inputs().is_empty() {
+                continue;
+            }
+            let candidates = originals.entry(op.name()).or_default();
+            let Some(&original) = candidates
+                .iter()
+                .find(|&&other| same_inputs(graph, other, id))
+            else {
+                candidates.push(id);
+                continue;
+            };
+
+            let mut fix = MacroCommand::new("Merge Duplicate Node");
+            for old in graph.downstream_of(id) {
+                let new = Connection {
+                    source_node: original,
+                    ..old
+                };
+                fix.push(RetargetConnectionCommand::new(old, new));
+            }
+            fix.push(RemoveNodeCommand::new(id));
+            findings.push(LintFinding {
+                kind: LintKind::DuplicateNode,
+                severity: LintSeverity::Suggestion,
+                nodes: vec![id, original],
+                message: format!("{} duplicates another node with the same inputs", op.name()),
+                fix: Some(Box::new(fix)),
+            });
+        }
+    }
+}
+
+/// Whether two nodes of the same operator read exactly the same inputs.
+fn same_inputs(graph: &Graph, a: Id, b: Id) -> bool {
+    let (a_inputs, b_inputs) = (
+        graph.get(a).unwrap().inputs(),
+        graph.get(b).unwrap().inputs(),
+    );
+    a_inputs.len() == b_inputs.len()
+        && a_inputs
+            .iter()
+            .zip(b_inputs)
+            .enumerate()
+            .all(|(i, (x, y))| {
+                x.connection == y.connection
+                    && x.connections == y.connections
+                    && x.muted == y.muted
+                    && ((x.is_connected() && !x.has_muted())
+                        || graph.input_default_source(a, i) == graph.input_default_source(b, i))
+            })
+}
+
+/// Warns about runs of delay nodes longer than [`LintConfig::max_delay_depth`].
+///
+/// Every delay keeps state and is recomputed each frame, so long chains are
+/// usually better expressed with a single longer delay. Reported once per
+/// chain, listing it from its head.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeepDelayChainRule;
+
+impl LintRule for DeepDelayChainRule {
+    fn kind(&self) -> LintKind {
+        LintKind::DeepDelayChain
+    }
+
+    fn check(&self, cx: &LintContext<'_>, findings: &mut Vec<LintFinding>) {
+        let graph = cx.graph;
+        let is_delay = |id: Id| {
+            graph
+                .node_name(id)
+                .is_some_and(|name| DELAY_OPERATORS.contains(&name))
+        };
+        let mut depths = HashMap::new();
+        for id in graph.node_ids_in_insertion_order() {
+            if !is_delay(id)
+                || graph
+                    .downstream_of(id)
+                    .iter()
+                    .any(|c| is_delay(c.target_node))
+            {
+                continue;
+            }
+            let depth = delay_depth(graph, id, &is_delay, &mut depths);
+            if depth <= cx.config.max_delay_depth {
+                continue;
+            }
+
+            // Walk back along the deepest path
+            let mut chain = vec![id];
+            while let Some(previous) = live_upstream(graph, *chain.last().unwrap())
+                .map(|c| c.source_node)
+                .filter(|&source| is_delay(source))
+                .max_by_key(|source| depths[source])
+            {
+                chain.push(previous);
+            }
+            chain.reverse();
+            findings.push(LintFinding {
+                kind: LintKind::DeepDelayChain,
+                severity: LintSeverity::Warning,
+                message: format!(
+                    "{} delay nodes in a row (limit {}); consider a single longer delay",
+                    depth, cx.config.max_delay_depth
+                ),
+                nodes: chain,
+                fix: None,
+            });
+        }
+    }
+}
+
+/// Length of the longest run of delay nodes ending at `id`.
+fn delay_depth(
+    graph: &Graph,
+    id: Id,
+    is_delay: &impl Fn(Id) -> bool,
+    depths: &mut HashMap<Id, usize>,
+) -> usize {
+    if let Some(&depth) = depths.get(&id) {
+        return depth;
+    }
+    let depth = 1 + live_upstream(graph, id)
+        .map(|c| c.source_node)
+        .filter(|&source| is_delay(source))
+        .map(|source| delay_depth(graph, source, is_delay, depths))
+        .max()
+        .unwrap_or(0);
+    depths.insert(id, depth);
+    depth
+}
+
+/// Connections into `id` that aren't muted.
+fn live_upstream(graph: &Graph, id: Id) -> impl Iterator<Item = Connection> {
+    graph.upstream_of(id).into_iter().filter(|c| !c.muted)
+}
+
+/// Whether an operator's outputs depend on nothing but its inputs.
+fn is_pure(op: &dyn Operator) -> bool {
+    !op.is_time_varying()
+        && !op.is_stateful()
+        && op.context_dependencies().is_empty()
+        && op.trigger_inputs().is_empty()
+        && op.trigger_outputs().is_empty()
+}
+
+/// Whether a node always produces the same value (memoized in `memo`).
+fn is_constant(cx: &LintContext<'_>, id: Id, memo: &mut HashMap<Id, bool>) -> bool {
+    if let Some(&constant) = memo.get(&id) {
+        return constant;
+    }
+    let Some(op) = cx.graph.get(id) else {
+        return false;
+    };
+    let constant = is_pure(op)
+        && (0..op.inputs().len()).all(|input| {
+            matches!(
+                cx.graph.input_default_source(id, input),
+                Some(DefaultSource::Literal(_))
+            ) && !cx.is_animated(id, input)
+        })
+        && live_upstream(cx.graph, id).all(|c| is_constant(cx, c.source_node, memo));
+    memo.insert(id, constant);
+    constant
+}
+
+/// Whether a constant node computes something from other nodes, looking
+/// through conversions (a constant behind a conversion is still just a
+/// constant).
+fn computes(graph: &Graph, mut id: Id) -> bool {
+    loop {
+        let mut sources = live_upstream(graph, id);
+        let Some(first) = sources.next() else {
+            return false;
+        };
+        if !graph.get(id).unwrap().as_any().is::<ConversionOp>() {
+            return true;
+        }
+        id = first.source_node;
+    }
+}
+
+/// `id` and every node upstream of it.
+fn constant_closure(graph: &Graph, id: Id) -> Vec<Id> {
+    let mut seen = HashSet::from([id]);
+    let mut stack = vec![id];
+    let mut closure = Vec::new();
+    while let Some(node) = stack.pop() {
+        closure.push(node);
+        for conn in live_upstream(graph, node) {
+            if seen.insert(conn.source_node) {
+                stack.push(conn.source_node);
+            }
+        }
+    }
+    closure
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::CurveBuilder;
+    use flux_core::{EvalContext, Value};
+    use flux_operators::{AddOp, ConstantOp, DelayOp, MultiplyOp, Vec3DecomposeOp};
+
+    fn of_kind(findings: &[LintFinding], kind: LintKind) -> Vec<&LintFinding> {
+        findings.iter().filter(|f| f.kind == kind).collect()
+    }
+
+    /// Lint, apply the only finding of `kind`, and return the re-lint
+    fn apply_fix(graph: &mut Graph, kind: LintKind) -> Vec<LintFinding> {
+        let mut findings = lint(graph, &LintConfig::new());
+        let mut matching = findings.iter_mut().filter(|f| f.kind == kind);
+        let finding = matching.next().expect("finding");
+        assert!(matching.next().is_none());
+        finding.fix.take().expect("fix").execute(graph);
+        lint(graph, &LintConfig::new())
+    }
+
+    #[test]
+    fn test_unused_output_on_non_sink() {
+        let mut graph = Graph::new();
+        let split = graph.add(Vec3DecomposeOp::new());
+        let sink = graph.add(AddOp::new());
+        graph.connect(split, 0, sink, 0).unwrap();
+
+        let findings = lint(&graph, &LintConfig::new());
+        let unused = of_kind(&findings, LintKind::UnusedOutput);
+        // Y and Z are unused; the Add node is a sink and isn't reported
+        assert_eq!(unused.len(), 2);
+        assert!(unused
+            .iter()
+            .all(|f| f.nodes == [split] && f.severity == LintSeverity::Info));
+        assert!(unused.iter().all(|f| f.fix.is_none()));
+    }
+
+    #[test]
+    fn test_constant_chain_is_folded() {
+        let mut graph = Graph::new();
+        let a = graph.add(ConstantOp::new(2.0));
+        let b = graph.add(ConstantOp::new(3.0));
+        let add = graph.add(AddOp::new());
+        let delay = graph.add(DelayOp::new());
+        graph.connect(a, 0, add, 0).unwrap();
+        graph.connect(b, 0, add, 1).unwrap();
+        graph.connect(add, 0, delay, 0).unwrap();
+
+        let findings = lint(&graph, &LintConfig::new());
+        let chains = of_kind(&findings, LintKind::ConstantChain);
+        // Constants feeding Add directly aren't a chain by themselves
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].nodes[0], delay);
+        assert!(chains[0].nodes.contains(&add));
+
+        let relint = apply_fix(&mut graph, LintKind::ConstantChain);
+        assert!(of_kind(&relint, LintKind::ConstantChain).is_empty());
+        let input = &graph.get(delay).unwrap().inputs()[0];
+        assert!(!input.is_connected());
+        assert_eq!(input.default, Value::Float(5.0));
+    }
+
+    #[test]
+    fn test_constant_chain_ignores_animated_inputs() {
+        let mut graph = Graph::new();
+        let a = graph.add(ConstantOp::new(2.0));
+        let add = graph.add(AddOp::new());
+        let delay = graph.add(DelayOp::new());
+        graph.connect(a, 0, add, 0).unwrap();
+        graph.connect(add, 0, delay, 0).unwrap();
+
+        let mut animator = Animator::new();
+        animator.add_curve(
+            CurveBuilder::new()
+                .keyframe(0.0, 0.0)
+                .keyframe(1.0, 1.0)
+                .build(),
+            add,
+            1,
+        );
+        let findings = lint_animated(&graph, &animator, &LintConfig::new());
+        assert!(of_kind(&findings, LintKind::ConstantChain).is_empty());
+    }
+
+    #[test]
+    fn test_conversion_chain_is_replaced() {
+        let mut graph = Graph::new();
+        let source = graph.add(ConstantOp::new(2.0));
+        let to_int = graph.add(ConversionOp::new(
+            flux_core::ValueType::Float,
+            flux_core::ValueType::Int,
+        ));
+        let to_float = graph.add(ConversionOp::new(
+            flux_core::ValueType::Int,
+            flux_core::ValueType::Float,
+        ));
+        let delay = graph.add(DelayOp::new());
+        graph.connect(source, 0, to_int, 0).unwrap();
+        graph.connect(to_int, 0, to_float, 0).unwrap();
+        graph.connect(to_float, 0, delay, 0).unwrap();
+
+        let findings = lint(&graph, &LintConfig::new());
+        let chains = of_kind(&findings, LintKind::ConversionChain);
+        assert_eq!(chains.len(), 1);
+        assert_eq!(chains[0].nodes, [to_int, to_float]);
+
+        let relint = apply_fix(&mut graph, LintKind::ConversionChain);
+        assert!(of_kind(&relint, LintKind::ConversionChain).is_empty());
+        assert_eq!(
+            graph.get(delay).unwrap().inputs()[0].connection,
+            Some((source, 0))
+        );
+        assert!(graph.get(to_int).is_none() && graph.get(to_float).is_none());
+    }
+
+    #[test]
+    fn test_static_animation_warning() {
+        let mut graph = Graph::new();
+        let add = graph.add(AddOp::new());
+        let mut animator = Animator::new();
+        animator.add_curve(
+            CurveBuilder::new()
+                .keyframe(0.0, 0.0)
+                .keyframe(2.0, 0.0)
+                .build(),
+            add,
+            0,
+        );
+        animator.add_curve(
+            CurveBuilder::new()
+                .keyframe(0.0, 0.0)
+                .keyframe(2.0, 1.0)
+                .build(),
+            add,
+            1,
+        );
+
+        let findings = lint_animated(&graph, &animator, &LintConfig::new());
+        let statics = of_kind(&findings, LintKind::StaticAnimation);
+        assert_eq!(statics.len(), 1);
+        assert_eq!(statics[0].severity, LintSeverity::Warning);
+        assert_eq!(statics[0].nodes, [add]);
+        assert!(statics[0].fix.is_none());
+
+        // Without the animator the rule has nothing to look at
+        assert!(of_kind(&lint(&graph, &LintConfig::new()), LintKind::StaticAnimation).is_empty());
+    }
+
+    #[test]
+    fn test_duplicate_nodes_are_merged() {
+        let mut graph = Graph::new();
+        let a = graph.add(ConstantOp::new(2.0));
+        let b = graph.add(ConstantOp::new(3.0));
+        let first = graph.add(AddOp::new());
+        let second = graph.add(AddOp::new());
+        let product = graph.add(MultiplyOp::new());
+        for add in [first, second] {
+            graph.connect(a, 0, add, 0).unwrap();
+            graph.connect(b, 0, add, 1).unwrap();
+        }
+        graph.connect(first, 0, product, 0).unwrap();
+        graph.connect(second, 0, product, 1).unwrap();
+
+        let findings = lint(&graph, &LintConfig::new());
+        let duplicates = of_kind(&findings, LintKind::DuplicateNode);
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].nodes, [second, first]);
+
+        let relint = apply_fix(&mut graph, LintKind::DuplicateNode);
+        assert!(of_kind(&relint, LintKind::DuplicateNode).is_empty());
+        assert!(graph.get(second).is_none());
+        let value = graph.evaluate(product, 0, &EvalContext::new()).unwrap();
+        assert_eq!(value, Value::Float(25.0));
+    }
+
+    #[test]
+    fn test_different_defaults_are_not_duplicates() {
+        let mut graph = Graph::new();
+        let first = graph.add(AddOp::new());
+        let second = graph.add(AddOp::new());
+        graph.set_input_default(second, 0, Value::Float(1.0));
+        assert!(of_kind(&lint(&graph, &LintConfig::new()), LintKind::DuplicateNode).is_empty());
+
+        graph.set_input_default(first, 0, Value::Float(1.0));
+        assert_eq!(
+            of_kind(&lint(&graph, &LintConfig::new()), LintKind::DuplicateNode).len(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_deep_delay_chain() {
+        let mut graph = Graph::new();
+        let mut previous = graph.add(ConstantOp::new(1.0));
+        let mut delays = Vec::new();
+        for _ in 0..5 {
+            let delay = graph.add(DelayOp::new());
+            graph.connect(previous, 0, delay, 0).unwrap();
+            delays.push(delay);
+            previous = delay;
+        }
+
+        let config = LintConfig::new().with_max_delay_depth(3);
+        let findings = lint(&graph, &config);
+        let deep = of_kind(&findings, LintKind::DeepDelayChain);
+        assert_eq!(deep.len(), 1);
+        assert_eq!(deep[0].nodes, delays);
+        assert_eq!(deep[0].severity, LintSeverity::Warning);
+
+        let config = config.with_max_delay_depth(5);
+        assert!(of_kind(&lint(&graph, &config), LintKind::DeepDelayChain).is_empty());
+    }
+
+    #[test]
+    fn test_rules_toggle_individually() {
+        let mut graph = Graph::new();
+        let split = graph.add(Vec3DecomposeOp::new());
+        let sink = graph.add(AddOp::new());
+        graph.connect(split, 0, sink, 0).unwrap();
+
+        let all = lint(&graph, &LintConfig::new());
+        assert!(!of_kind(&all, LintKind::UnusedOutput).is_empty());
+
+        let config = LintConfig::new().disable(LintKind::UnusedOutput);
+        let findings = lint(&graph, &config);
+        assert!(of_kind(&findings, LintKind::UnusedOutput).is_empty());
+        assert!(config
+            .clone()
+            .enable(LintKind::UnusedOutput)
+            .is_enabled(LintKind::UnusedOutput));
+    }
+}
@@ -0,0 +1,205 @@
+//! Placeholder nodes for operators that aren't available
+//!
+//! When a saved file references an operator the registry doesn't know (a
+//! plugin that isn't loaded, an operator renamed in a newer library), the
+//! child is loaded as a [`MissingOp`] instead of being skipped. It keeps the
+//! original symbol reference, the saved input values and enough ports for
+//! every saved connection, so saving the graph again writes the child back
+//! as it was found. It evaluates every output to its type's default.
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::port::{InputPort, OutputPort};
+use flux_core::value::{Value, ValueType};
+
+/// Stand-in for a child whose operator could not be created
+///
+/// Ports are declared while loading: inputs take their saved names and
+/// values, and ports only referenced by index are named "Input N" or
+/// "Output N". Until connected, ports without a saved value are Float.
+pub struct MissingOp {
+    id: Id,
+    name: &'static str,
+    symbol_ref: String,
+    inputs: Vec<InputPort>,
+    outputs: Vec<OutputPort>,
+    /// Inputs whose value came from the saved file
+    saved_values: Vec<bool>,
+    /// Outputs whose type was taken from a connected input
+    typed_outputs: Vec<bool>,
+}
+
+impl MissingOp {
+    /// Create a placeholder without ports for a saved symbol reference
+    ///
+    /// The operator name is the reference without its `builtin:` prefix.
+    pub fn new(symbol_ref: &str) -> Self {
+        let name = symbol_ref.strip_prefix("builtin:").unwrap_or(symbol_ref);
+        Self {
+            id: Id::new(),
+            name: Box::leak(name.to_string().into_boxed_str()),
+            symbol_ref: symbol_ref.to_string(),
+            inputs: Vec::new(),
+            outputs: Vec::new(),
+            saved_values: Vec::new(),
+            typed_outputs: Vec::new(),
+        }
+    }
+
+    /// The symbol reference the saved file used for this child
+    pub fn symbol_ref(&self) -> &str {
+        &self.symbol_ref
+    }
+
+    /// Returns true if the input's value came from the saved file
+    pub fn has_saved_value(&self, index: usize) -> bool {
+        self.saved_values.get(index).copied().unwrap_or(false)
+    }
+
+    /// Declare an input, adding unnamed ones before it as needed
+    ///
+    /// A saved `value` sets the input's type and default.
+    pub fn declare_input(&mut self, index: usize, name: Option<&str>, value: Option<&Value>) {
+        while self.inputs.len() <= index {
+            let gap = format!("Input {}", self.inputs.len());
+            self.inputs
+                .push(InputPort::any(leak(gap), Value::Float(0.0)));
+            self.saved_values.push(false);
+        }
+        let port = &mut self.inputs[index];
+        if let Some(name) = name.filter(|&name| name != port.name) {
+            port.name = leak(name.to_string());
+        }
+        if let Some(value) = value {
+            port.value_type = value.value_type();
+            port.default = value.clone();
+            self.saved_values[index] = true;
+        }
+    }
+
+    /// Let an input take several connections
+    pub fn declare_multi_input(&mut self, index: usize) {
+        self.declare_input(index, None, None);
+        self.inputs[index].is_multi_input = true;
+    }
+
+    /// Declare an output, adding unnamed ones before it as needed
+    pub fn declare_output(&mut self, index: usize, name: Option<&str>) {
+        while self.outputs.len() <= index {
+            let gap = format!("Output {}", self.outputs.len());
+            self.outputs.push(OutputPort::float(leak(gap)));
+            self.typed_outputs.push(false);
+        }
+        let port = &mut self.outputs[index];
+        if let Some(name) = name.filter(|&name| name != port.name) {
+            port.name = leak(name.to_string());
+        }
+    }
+
+    /// Take the type of the output about to feed an input
+    ///
+    /// Inputs with a saved value or an existing connection keep their type.
+    /// Returns true if the input changed.
+    pub(crate) fn adopt_input_type(&mut self, index: usize, value_type: ValueType) -> bool {
+        let Some(port) = self.inputs.get_mut(index) else {
+            return false;
+        };
+        if self.saved_values[index] || port.is_connected() || port.value_type == value_type {
+            return false;
+        }
+        port.value_type = value_type;
+        port.default = value_type.default_value();
+        true
+    }
+
+    /// Take the type of the first input an output is connected to
+    pub(crate) fn adopt_output_type(&mut self, index: usize, value_type: ValueType) {
+        let Some(port) = self.outputs.get_mut(index) else {
+            return;
+        };
+        if !self.typed_outputs[index] {
+            self.typed_outputs[index] = true;
+            port.value_type = value_type;
+            port.set(value_type.default_value());
+        }
+    }
+}
+
+fn leak(name: String) -> &'static str {
+    Box::leak(name.into_boxed_str())
+}
+
+impl Operator for MissingOp {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        self.name
+    }
+
+    fn inputs(&self) -> &[InputPort] {
+        &self.inputs
+    }
+
+    fn inputs_mut(&mut self) -> &mut [InputPort] {
+        &mut self.inputs
+    }
+
+    fn outputs(&self) -> &[OutputPort] {
+        &self.outputs
+    }
+
+    fn outputs_mut(&mut self) -> &mut [OutputPort] {
+        &mut self.outputs
+    }
+
+    fn compute(&mut self, _ctx: &EvalContext, _get_input: InputResolver) {
+        for output in &mut self.outputs {
+            output.set(output.value_type.default_value());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declared_ports() {
+        let mut op = MissingOp::new("builtin:Warp");
+        assert_eq!(op.name(), "Warp");
+        op.declare_input(2, Some("Amount"), Some(&Value::Vec3([1.0, 2.0, 3.0])));
+        op.declare_input(0, Some("Source"), None);
+        op.declare_output(1, None);
+
+        let names: Vec<&str> = op.inputs().iter().map(|p| p.name).collect();
+        assert_eq!(names, ["Source", "Input 1", "Amount"]);
+        assert_eq!(op.inputs()[2].value_type, ValueType::Vec3);
+        assert!(op.has_saved_value(2) && !op.has_saved_value(0));
+        assert_eq!(op.outputs()[1].name, "Output 1");
+
+        // Saved values keep their type; the rest follow their connections
+        assert!(!op.adopt_input_type(2, ValueType::Float));
+        assert!(op.adopt_input_type(0, ValueType::Color));
+        op.adopt_output_type(0, ValueType::Int);
+        op.adopt_output_type(0, ValueType::Vec2);
+        assert_eq!(op.outputs()[0].value_type, ValueType::Int);
+
+        let ctx = EvalContext::new();
+        op.compute(&ctx, &|_, _| Value::Float(9.0));
+        assert_eq!(op.outputs()[0].value, Value::Int(0));
+        assert_eq!(op.outputs()[1].value, Value::Float(0.0));
+    }
+}
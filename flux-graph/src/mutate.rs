@@ -0,0 +1,404 @@
+//! Parameter randomization for generative exploration
+//!
+//! A [`Mutator`] nudges input defaults by seeded random offsets. Inputs with a
+//! range (from their [`PortMeta`](flux_core::PortMeta) or a per-instance
+//! override) move by a fraction of that range and stay inside it; inputs
+//! without one move by a fraction of their current value. Each
+//! [`Mutator::mutate`] call builds one [`MacroCommand`], so executing it on an
+//! [`UndoRedoStack`](crate::UndoRedoStack) makes the whole pass one undo step.
+//!
+//! [`GraphSnapshot`] captures input defaults, and [`Mutator::interpolate`]
+//! blends two captures for A/B morphing between states found this way.
+//!
+//! Only Float, Int and Vec2/3/4 defaults are mutated; connected inputs are
+//! skipped since their default has no effect. Structural inputs such as
+//! counts usually shouldn't be randomized: exclude them by port name with
+//! [`Mutator::exclude_named`] or individually with [`Mutator::exclude`].
+//!
+//! ```ignore
+//! let mut mutator = Mutator::new(42)
+//!     .with_strength(0.1)
+//!     .with_group_floats(&graph, &[fx_group])
+//!     .exclude_named("Count");
+//! let a = GraphSnapshot::capture(&graph, mutator.targets().iter().copied());
+//! let pass = mutator.mutate(&graph);
+//! history.execute(&mut graph, pass);
+//! let b = GraphSnapshot::capture(&graph, mutator.targets().iter().copied());
+//! let halfway = Mutator::interpolate(&a, &b, 0.5).restore_command(&graph);
+//! history.execute(&mut graph, halfway);
+//! ```
+
+use flux_core::id::{Id, InputRef};
+use flux_core::value::{float_to_int, Value};
+
+use crate::commands::{MacroCommand, SetInputDefaultCommand};
+use crate::graph::Graph;
+use crate::group::GroupId;
+
+/// Seeded random offsets for input defaults
+#[derive(Debug, Clone)]
+pub struct Mutator {
+    /// SplitMix64 state, advanced by every offset drawn
+    state: u64,
+    strength: f32,
+    fallback_spread: f32,
+    targets: Vec<InputRef>,
+    excluded: Vec<InputRef>,
+    excluded_names: Vec<String>,
+}
+
+impl Mutator {
+    /// Create a mutator with no targets.
+    ///
+    /// The same seed, targets and graph state give the same sequence of passes.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            strength: 0.25,
+            fallback_spread: 0.5,
+            targets: Vec::new(),
+            excluded: Vec::new(),
+            excluded_names: Vec::new(),
+        }
+    }
+
+    /// Largest offset as a fraction of an input's range (default 0.25).
+    pub fn with_strength(mut self, strength: f32) -> Self {
+        self.strength = strength;
+        self
+    }
+
+    /// Largest offset for inputs without a range, as a fraction of their
+    /// current value before `strength` is applied (default 0.5).
+    ///
+    /// Such inputs stay put while their value is zero.
+    pub fn with_fallback_spread(mut self, spread: f32) -> Self {
+        self.fallback_spread = spread;
+        self
+    }
+
+    /// Add an input to mutate.
+    pub fn with_target(mut self, input: impl Into<InputRef>) -> Self {
+        let input = input.into();
+        if !self.targets.contains(&input) {
+            self.targets.push(input);
+        }
+        self
+    }
+
+    /// Add several inputs to mutate.
+    pub fn with_targets(self, inputs: impl IntoIterator<Item = InputRef>) -> Self {
+        inputs.into_iter().fold(self, Self::with_target)
+    }
+
+    /// Add every unconnected Float input of the members of `groups`.
+    pub fn with_group_floats(self, graph: &Graph, groups: &[GroupId]) -> Self {
+        let members = groups
+            .iter()
+            .filter_map(|&group| graph.group_members(group))
+            .flatten()
+            .copied();
+        let inputs: Vec<InputRef> = members
+            .filter_map(|node| graph.get(node).map(|op| (node, op)))
+            .flat_map(|(node, op)| {
+                op.inputs()
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, input)| matches!(input.default, Value::Float(_)))
+                    .filter(|(_, input)| !input.is_connected())
+                    .map(move |(index, _)| InputRef::new(node, index))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        self.with_targets(inputs)
+    }
+
+    /// Never mutate this input, even if it is a target.
+    pub fn exclude(mut self, input: impl Into<InputRef>) -> Self {
+        self.excluded.push(input.into());
+        self
+    }
+
+    /// Never mutate inputs whose port is named `name` (e.g. "Count").
+    pub fn exclude_named(mut self, name: impl Into<String>) -> Self {
+        self.excluded_names.push(name.into());
+        self
+    }
+
+    /// Inputs this mutator targets, in the order they were added.
+    pub fn targets(&self) -> &[InputRef] {
+        &self.targets
+    }
+
+    /// Build one mutation pass over the targets.
+    ///
+    /// The graph is not changed; execute the returned command (named
+    /// "Mutate") to apply the pass. Excluded, connected, missing and
+    /// non-numeric inputs are skipped.
+    pub fn mutate(&mut self, graph: &Graph) -> MacroCommand {
+        let mut pass = MacroCommand::new("Mutate");
+        for &target in &self.targets.clone() {
+            if self.excluded.contains(&target) {
+                continue;
+            }
+            let (node, index): (Id, usize) = target.into();
+            let Some(input) = graph.get(node).and_then(|op| op.inputs().get(index)) else {
+                continue;
+            };
+            if input.is_connected() || self.excluded_names.iter().any(|n| n == input.name) {
+                continue;
+            }
+            let range = graph
+                .effective_input_meta(node, index)
+                .and_then(|meta| meta.range);
+            if let Some(value) = self.mutate_value(&input.default, range) {
+                if value != input.default {
+                    pass.push(SetInputDefaultCommand::new(node, index, value));
+                }
+            }
+        }
+        pass
+    }
+
+    /// Blend two snapshots: `a` at `t = 0`, `b` at `t = 1`.
+    ///
+    /// Covers the inputs captured in both. `b`'s values are coerced to the
+    /// type of `a`'s; inputs whose values can't be interpolated (strings,
+    /// lists, ...) are left out.
+    pub fn interpolate(a: &GraphSnapshot, b: &GraphSnapshot, t: f32) -> GraphSnapshot {
+        let values = a
+            .values
+            .iter()
+            .filter_map(|(input, from)| {
+                let to = b.get(*input)?.coerce_to(from.value_type())?;
+                let blended = from.lerp(&to, &Value::Float(t))?.coerce_to(from.value_type())?;
+                // Exact endpoints; the lerp can round at t = 1
+                let value = match t {
+                    t if t <= 0.0 => from.clone(),
+                    t if t >= 1.0 => to,
+                    _ => blended,
+                };
+                Some((*input, value))
+            })
+            .collect();
+        GraphSnapshot { values }
+    }
+
+    fn mutate_value(&mut self, value: &Value, range: Option<(f32, f32)>) -> Option<Value> {
+        Some(match value {
+            Value::Float(v) => Value::Float(self.offset(*v, range)),
+            Value::Int(v) => Value::Int(float_to_int(self.offset(*v as f32, range).round())),
+            Value::Vec2(v) => Value::Vec2(v.map(|c| self.offset(c, range))),
+            Value::Vec3(v) => Value::Vec3(v.map(|c| self.offset(c, range))),
+            Value::Vec4(v) => Value::Vec4(v.map(|c| self.offset(c, range))),
+            _ => return None,
+        })
+    }
+
+    fn offset(&mut self, value: f32, range: Option<(f32, f32)>) -> f32 {
+        let amount = self.next_signed() * self.strength;
+        match range {
+            Some((a, b)) => {
+                let (min, max) = (a.min(b), a.max(b));
+                (value + amount * (max - min)).clamp(min, max)
+            }
+            None => value + amount * self.fallback_spread * value.abs(),
+        }
+    }
+
+    /// Next SplitMix64 draw mapped to `[-1, 1)`
+    fn next_signed(&mut self) -> f32 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+        (z >> 40) as f32 / (1u64 << 23) as f32 - 1.0
+    }
+}
+
+/// Input defaults captured from a graph
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GraphSnapshot {
+    values: Vec<(InputRef, Value)>,
+}
+
+impl GraphSnapshot {
+    /// Capture the defaults of `inputs`; inputs that don't exist are skipped.
+    pub fn capture(graph: &Graph, inputs: impl IntoIterator<Item = InputRef>) -> Self {
+        let values = inputs
+            .into_iter()
+            .filter_map(|input| {
+                let (node, index): (Id, usize) = input.into();
+                let port = graph.get(node)?.inputs().get(index)?;
+                Some((input, port.default.clone()))
+            })
+            .collect();
+        Self { values }
+    }
+
+    /// Capture the defaults of every unconnected input in the graph.
+    pub fn capture_all(graph: &Graph) -> Self {
+        let inputs: Vec<InputRef> = graph
+            .node_ids()
+            .flat_map(|node| {
+                let op = graph.get(node);
+                let inputs = op.map(|op| op.inputs()).unwrap_or(&[]);
+                (0..inputs.len())
+                    .filter(|&index| !inputs[index].is_connected())
+                    .map(move |index| InputRef::new(node, index))
+            })
+            .collect();
+        Self::capture(graph, inputs)
+    }
+
+    /// Captured value of an input
+    pub fn get(&self, input: InputRef) -> Option<&Value> {
+        self.values
+            .iter()
+            .find(|(captured, _)| *captured == input)
+            .map(|(_, value)| value)
+    }
+
+    /// Captured inputs and values, in capture order
+    pub fn values(&self) -> &[(InputRef, Value)] {
+        &self.values
+    }
+
+    /// Number of captured inputs
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether no inputs were captured
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Command that sets every captured input whose default differs back to
+    /// its captured value (named "Restore Snapshot").
+    pub fn restore_command(&self, graph: &Graph) -> MacroCommand {
+        let mut restore = MacroCommand::new("Restore Snapshot");
+        for (input, value) in &self.values {
+            let (node, index): (Id, usize) = (*input).into();
+            let current = graph.get(node).and_then(|op| op.inputs().get(index));
+            if current.is_some_and(|port| port.default != *value) {
+                restore.push(SetInputDefaultCommand::new(node, index, value.clone()));
+            }
+        }
+        restore
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::tests::TestOp;
+    use crate::commands::Command;
+    use crate::UndoRedoStack;
+    use flux_core::PortOverride;
+
+    /// Three Float inputs: one with a range, one without, one connected
+    fn graph() -> (Graph, [Id; 3]) {
+        let mut graph = Graph::new();
+        let ranged = graph.add(TestOp::new(0.0));
+        let free = graph.add(TestOp::new(0.0));
+        let wired = graph.add(TestOp::new(0.0));
+        graph.set_input_default(ranged, 0, Value::Float(0.5));
+        graph.set_input_default(free, 0, Value::Float(10.0));
+        graph.set_input_override(
+            ranged,
+            0,
+            PortOverride {
+                range: Some((0.0, 1.0)),
+                ..Default::default()
+            },
+        );
+        graph.connect(free, 0, wired, 0).unwrap();
+        (graph, [ranged, free, wired])
+    }
+
+    fn default_of(graph: &Graph, node: Id) -> f32 {
+        graph.get(node).unwrap().inputs()[0].default.as_float().unwrap()
+    }
+
+    #[test]
+    fn test_seeded_pass_is_reproducible_and_one_undo_step() {
+        let (mut graph, nodes) = graph();
+        let targets = nodes.map(|node| InputRef::new(node, 0));
+        let mut history = UndoRedoStack::new();
+
+        let pass = Mutator::new(7).with_targets(targets).mutate(&graph);
+        history.execute(&mut graph, pass);
+        let first = nodes.map(|node| default_of(&graph, node));
+        assert_eq!(history.history_len(), 1);
+        assert_ne!(first[0], 0.5);
+        assert_ne!(first[1], 10.0);
+        assert_eq!(first[2], 0.0, "connected inputs are skipped");
+
+        history.undo(&mut graph);
+        assert_eq!(nodes.map(|node| default_of(&graph, node)), [0.5, 10.0, 0.0]);
+
+        let pass = Mutator::new(7).with_targets(targets).mutate(&graph);
+        history.execute(&mut graph, pass);
+        assert_eq!(nodes.map(|node| default_of(&graph, node)), first);
+    }
+
+    #[test]
+    fn test_mutation_respects_ranges_and_exclusions() {
+        let (mut graph, [ranged, free, _]) = graph();
+        let mut mutator = Mutator::new(1)
+            .with_strength(1.0)
+            .with_fallback_spread(0.1)
+            .with_target((ranged, 0))
+            .with_target((free, 0));
+        for _ in 0..50 {
+            let mut pass = mutator.mutate(&graph);
+            pass.execute(&mut graph);
+            assert!((0.0..=1.0).contains(&default_of(&graph, ranged)));
+        }
+        // Without a range, each pass moves by at most 10% of the value
+        let free_value = default_of(&graph, free);
+        assert!(free_value > 10.0 * 0.9f32.powi(50) && free_value < 10.0 * 1.1f32.powi(50));
+
+        let both = Mutator::new(1).with_target((ranged, 0)).with_target((free, 0));
+        assert_eq!(both.clone().exclude((ranged, 0)).mutate(&graph).len(), 1);
+        assert!(both.exclude_named("In").mutate(&graph).is_empty());
+    }
+
+    #[test]
+    fn test_group_floats_target_unconnected_members() {
+        let (mut graph, [ranged, free, wired]) = graph();
+        let group = graph.create_group("Look", [1.0; 4]);
+        for node in [ranged, free, wired] {
+            graph.assign_to_group(node, group).unwrap();
+        }
+        let mutator = Mutator::new(0).with_group_floats(&graph, &[group, group]);
+        assert_eq!(mutator.targets(), &[InputRef::new(ranged, 0), InputRef::new(free, 0)]);
+    }
+
+    #[test]
+    fn test_snapshot_interpolation() {
+        let (mut graph, [ranged, free, _]) = graph();
+        let a = GraphSnapshot::capture_all(&graph);
+        graph.set_input_default(ranged, 0, Value::Float(0.9));
+        graph.set_input_default(free, 0, Value::Int(20));
+        let b = GraphSnapshot::capture_all(&graph);
+        assert_eq!(a.len(), 2);
+
+        assert_eq!(Mutator::interpolate(&a, &b, 0.0), a);
+        let end = Mutator::interpolate(&a, &b, 1.0);
+        assert_eq!(end.get(InputRef::new(ranged, 0)), Some(&Value::Float(0.9)));
+        // Coerced to the type captured in `a`
+        assert_eq!(end.get(InputRef::new(free, 0)), Some(&Value::Float(20.0)));
+
+        let mid = Mutator::interpolate(&a, &b, 0.5);
+        let ranged_mid = mid.get(InputRef::new(ranged, 0)).unwrap().as_float().unwrap();
+        assert!(ranged_mid > 0.5 && ranged_mid < 0.9);
+        assert_eq!(mid.get(InputRef::new(free, 0)), Some(&Value::Float(15.0)));
+
+        let mut restore = mid.restore_command(&graph);
+        restore.execute(&mut graph);
+        assert_eq!(default_of(&graph, free), 15.0);
+    }
+}
@@ -0,0 +1,402 @@
+//! Offline evaluation over a time range
+//!
+//! [`render_range`] steps a graph through a time range at a fixed frame rate
+//! and records the requested outputs at every frame, for offline rendering
+//! and data export. Frame times are computed from the frame index
+//! (`start + frame / fps`) rather than by accumulating `dt`, so long renders
+//! don't drift.
+//!
+//! Use [`RangeRender`] for looping playback, progress reporting and
+//! cancellation.
+//!
+//! # State
+//!
+//! Cached values and update-rate timing are reset before the first frame
+//! (see [`Graph::reset_evaluation_state`]). Operators that keep internal
+//! state between frames are not reset; rebuild the graph before rendering if
+//! such operators must start from scratch.
+//!
+//! # Example
+//!
+//! ```ignore
+//! let result = render_range(&mut graph, &[(sine_id, 0)], 0.0, 2.0, 30.0)?;
+//! std::fs::write("sine.csv", result.to_csv())?;
+//! ```
+
+use std::time::{Duration, Instant};
+
+use flux_core::{EvalContext, Id, Value};
+use serde::{Deserialize, Serialize};
+
+use crate::graph::{Graph, GraphError};
+use crate::playback::PlaybackSettings;
+
+/// Progress of an offline render, passed to the progress callback.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RangeProgress {
+    /// Frames rendered so far
+    pub frames_done: usize,
+    /// Total frames in the range
+    pub total_frames: usize,
+    /// Time of the most recently rendered frame
+    pub time: f64,
+}
+
+impl RangeProgress {
+    /// Completed fraction (0.0 - 1.0).
+    pub fn fraction(&self) -> f32 {
+        if self.total_frames == 0 {
+            1.0
+        } else {
+            self.frames_done as f32 / self.total_frames as f32
+        }
+    }
+}
+
+/// Sampled values of one output over the rendered frames.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OutputSeries {
+    /// Node the output belongs to
+    pub node: Id,
+    /// Output index on the node
+    pub output: usize,
+    /// One value per rendered frame
+    pub values: Vec<Value>,
+}
+
+/// Timing statistics for an offline render.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct RangeStats {
+    /// Number of frames rendered
+    pub frames: usize,
+    /// Wall-clock time for all frames
+    pub total: Duration,
+    /// Fastest frame
+    pub min_frame: Duration,
+    /// Slowest frame
+    pub max_frame: Duration,
+}
+
+impl RangeStats {
+    /// Average wall-clock time per frame.
+    pub fn mean_frame(&self) -> Duration {
+        if self.frames == 0 {
+            Duration::ZERO
+        } else {
+            self.total / self.frames as u32
+        }
+    }
+
+    fn record(&mut self, elapsed: Duration) {
+        if self.frames == 0 || elapsed < self.min_frame {
+            self.min_frame = elapsed;
+        }
+        self.max_frame = self.max_frame.max(elapsed);
+        self.total += elapsed;
+        self.frames += 1;
+    }
+}
+
+/// Result of an offline render.
+#[derive(Clone, Debug)]
+pub struct RangeResult {
+    /// Range time of each rendered frame (before loop wrapping)
+    pub times: Vec<f64>,
+    /// One series per requested output, in request order
+    pub series: Vec<OutputSeries>,
+    /// Timing statistics
+    pub stats: RangeStats,
+    /// True if the progress callback cancelled the render
+    pub cancelled: bool,
+}
+
+impl RangeResult {
+    /// Number of frames rendered.
+    pub fn frame_count(&self) -> usize {
+        self.times.len()
+    }
+
+    /// Export as CSV: a `time` column followed by one column per output.
+    ///
+    /// Output columns are named `<node>:<output>`. Non-scalar values use
+    /// their display form, quoted.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("time");
+        for series in &self.series {
+            csv.push_str(&format!(",{}:{}", series.node, series.output));
+        }
+        csv.push('\n');
+
+        for (frame, time) in self.times.iter().enumerate() {
+            csv.push_str(&time.to_string());
+            for series in &self.series {
+                csv.push(',');
+                if let Some(value) = series.values.get(frame) {
+                    csv.push_str(&csv_cell(value));
+                }
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+
+    /// Export as JSON with `times` and per-output `series`.
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "times": self.times,
+            "series": self.series,
+            "cancelled": self.cancelled,
+        })
+    }
+}
+
+/// Format a value as a CSV cell.
+fn csv_cell(value: &Value) -> String {
+    match value {
+        Value::Float(v) => v.to_string(),
+        Value::Int(v) => v.to_string(),
+        Value::Bool(v) => v.to_string(),
+        other => format!("\"{}\"", other.to_string().replace('"', "\"\"")),
+    }
+}
+
+/// Callback receiving progress; return `false` to cancel the render.
+type ProgressCallback<'a> = Box<dyn FnMut(RangeProgress) -> bool + 'a>;
+
+/// Configurable offline render over a time range.
+///
+/// The range is half-open: frames are rendered at `start + n / fps` for all
+/// `n` with `start + n / fps < end`.
+pub struct RangeRender<'a> {
+    start: f64,
+    end: f64,
+    fps: f64,
+    playback: Option<&'a PlaybackSettings>,
+    context: EvalContext,
+    progress: Option<ProgressCallback<'a>>,
+}
+
+impl<'a> RangeRender<'a> {
+    /// Create a render of `[start, end)` at `fps` frames per second.
+    pub fn new(start: f64, end: f64, fps: f64) -> Self {
+        Self {
+            start,
+            end,
+            fps,
+            playback: None,
+            context: EvalContext::new(),
+            progress: None,
+        }
+    }
+
+    /// Wrap frame times through the playback settings' loop range.
+    pub fn with_playback(mut self, playback: &'a PlaybackSettings) -> Self {
+        self.playback = Some(playback);
+        self
+    }
+
+    /// Use `context` as the base for every frame (timing fields are overwritten).
+    pub fn with_context(mut self, context: EvalContext) -> Self {
+        self.context = context;
+        self
+    }
+
+    /// Call `progress` after every frame; returning `false` cancels the render.
+    pub fn with_progress(mut self, progress: impl FnMut(RangeProgress) -> bool + 'a) -> Self {
+        self.progress = Some(Box::new(progress));
+        self
+    }
+
+    /// Number of frames in the range.
+    pub fn frame_count(&self) -> usize {
+        if !self.fps.is_finite() || self.fps <= 0.0 || self.end <= self.start {
+            return 0;
+        }
+        // Tolerate rounding so e.g. 2.0s at 10fps is exactly 20 frames
+        ((self.end - self.start) * self.fps - 1e-9).ceil().max(0.0) as usize
+    }
+
+    /// Range time of a frame.
+    pub fn frame_time(&self, frame: usize) -> f64 {
+        self.start + frame as f64 / self.fps
+    }
+
+    /// Render the range, sampling `outputs` at every frame.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first evaluation error (e.g. a cycle or missing node).
+    pub fn run(
+        mut self,
+        graph: &mut Graph,
+        outputs: &[(Id, usize)],
+    ) -> Result<RangeResult, GraphError> {
+        let total_frames = self.frame_count();
+        let mut result = RangeResult {
+            times: Vec::with_capacity(total_frames),
+            series: outputs
+                .iter()
+                .map(|&(node, output)| OutputSeries {
+                    node,
+                    output,
+                    values: Vec::with_capacity(total_frames),
+                })
+                .collect(),
+            stats: RangeStats::default(),
+            cancelled: false,
+        };
+
+        graph.reset_evaluation_state();
+        let mut ctx = self.context.clone();
+        let frame_dt = 1.0 / self.fps;
+
+        for frame in 0..total_frames {
+            let range_time = self.frame_time(frame);
+            let time = self
+                .playback
+                .map_or(range_time, |playback| playback.apply_loop(range_time));
+
+            ctx.time = time;
+            ctx.local_time = time;
+            ctx.local_fx_time = time;
+            ctx.frame = frame as u64;
+            ctx.delta_time = if frame == 0 { 0.0 } else { frame_dt };
+
+            let started = Instant::now();
+            for (series, &(node, output)) in result.series.iter_mut().zip(outputs) {
+                series.values.push(graph.evaluate(node, output, &ctx)?);
+            }
+            result.stats.record(started.elapsed());
+            result.times.push(range_time);
+
+            if let Some(progress) = self.progress.as_mut() {
+                let keep_going = progress(RangeProgress {
+                    frames_done: frame + 1,
+                    total_frames,
+                    time: range_time,
+                });
+                if !keep_going {
+                    result.cancelled = frame + 1 < total_frames;
+                    break;
+                }
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Evaluate `outputs` at every frame of `[start, end)` at `fps`.
+///
+/// Shorthand for [`RangeRender::new`]`(start, end, fps).run(graph, outputs)`.
+pub fn render_range(
+    graph: &mut Graph,
+    outputs: &[(Id, usize)],
+    start: f64,
+    end: f64,
+    fps: f64,
+) -> Result<RangeResult, GraphError> {
+    RangeRender::new(start, end, fps).run(graph, outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_operators::SineWaveOp;
+
+    fn sine_graph() -> (Graph, Id) {
+        let mut graph = Graph::new();
+        let id = graph.add(SineWaveOp::new());
+        (graph, id)
+    }
+
+    #[test]
+    fn test_sine_matches_analytic_values() {
+        let (mut graph, sine) = sine_graph();
+        let result = render_range(&mut graph, &[(sine, 0)], 0.0, 2.0, 10.0).unwrap();
+
+        assert_eq!(result.frame_count(), 20);
+        assert_eq!(result.stats.frames, 20);
+        assert!(!result.cancelled);
+
+        let values = &result.series[0].values;
+        for (frame, value) in values.iter().enumerate() {
+            let t = frame as f64 / 10.0;
+            assert_eq!(result.times[frame], t);
+            let expected = (2.0 * std::f64::consts::PI * t).sin() as f32;
+            let actual = value.as_float().unwrap();
+            assert!(
+                (actual - expected).abs() < 1e-4,
+                "frame {frame}: {actual} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_frame_times_do_not_drift() {
+        let render = RangeRender::new(1.0, 100.0, 60.0);
+        assert_eq!(render.frame_count(), 5940);
+        assert_eq!(render.frame_time(5939), 1.0 + 5939.0 / 60.0);
+    }
+
+    #[test]
+    fn test_playback_loop_wraps_times() {
+        let (mut graph, sine) = sine_graph();
+        let mut playback = PlaybackSettings::new();
+        playback.set_loop_range(0.0, 0.5);
+
+        let result = RangeRender::new(0.0, 1.0, 4.0)
+            .with_playback(&playback)
+            .run(&mut graph, &[(sine, 0)])
+            .unwrap();
+
+        // 0.5 and 0.75 wrap to 0.0 and 0.25
+        let values = &result.series[0].values;
+        assert_eq!(result.times, vec![0.0, 0.25, 0.5, 0.75]);
+        assert_eq!(values[2], values[0]);
+        assert_eq!(values[3], values[1]);
+    }
+
+    #[test]
+    fn test_progress_cancel_returns_partial() {
+        let (mut graph, sine) = sine_graph();
+        let mut seen = Vec::new();
+
+        let result = RangeRender::new(0.0, 1.0, 10.0)
+            .with_progress(|progress| {
+                seen.push(progress.frames_done);
+                progress.frames_done < 3
+            })
+            .run(&mut graph, &[(sine, 0)])
+            .unwrap();
+
+        assert!(result.cancelled);
+        assert_eq!(result.frame_count(), 3);
+        assert_eq!(result.series[0].values.len(), 3);
+        assert_eq!(seen, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_csv_and_json_export() {
+        let (mut graph, sine) = sine_graph();
+        let result = render_range(&mut graph, &[(sine, 0)], 0.0, 0.5, 4.0).unwrap();
+
+        let csv = result.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], format!("time,{}:0", sine));
+        assert!(lines[2].starts_with("0.25,1"));
+
+        let json = result.to_json();
+        assert_eq!(json["times"].as_array().unwrap().len(), 2);
+        let series: Vec<OutputSeries> = serde_json::from_value(json["series"].clone()).unwrap();
+        assert_eq!(series, result.series);
+    }
+
+    #[test]
+    fn test_missing_node_is_an_error() {
+        let mut graph = Graph::new();
+        let result = render_range(&mut graph, &[(Id::new(), 0)], 0.0, 1.0, 10.0);
+        assert!(matches!(result, Err(GraphError::NodeNotFound { .. })));
+    }
+}
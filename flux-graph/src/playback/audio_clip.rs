@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 
 use flux_core::Id;
 
+use super::waveform::{PeakOptions, WaveformPeaks};
+
 /// Audio clip reference
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AudioClip {
@@ -79,6 +81,13 @@ impl AudioClip {
             time >= self.start_time && time < self.end_time
         }
     }
+
+    /// Compute a mono waveform overview from decoded, interleaved samples
+    ///
+    /// See [`WaveformPeaks::compute`] for per-channel or normalized peaks.
+    pub fn compute_peaks(samples: &[f32], channels: usize, buckets: usize) -> WaveformPeaks {
+        WaveformPeaks::compute(samples, channels, buckets, PeakOptions::default())
+    }
 }
 
 impl Default for AudioClip {
@@ -1,13 +1,20 @@
 //! Playback settings for symbols
 //!
 //! This module provides playback configuration including audio clips,
-//! BPM settings, sync modes, and beat locking.
+//! BPM settings and tempo maps, sync modes, beat locking, and cached
+//! waveform overviews, plus a [`Transport`] that drives the playhead.
 
 mod audio_clip;
+mod tempo;
+mod transport;
 mod types;
+mod waveform;
 
 pub use audio_clip::AudioClip;
+pub use tempo::TempoChange;
+pub use transport::{Transport, TransportEvent, TRANSPORT_NAMESPACE};
 pub use types::{AudioSource, PlaybackState, SyncMode};
+pub use waveform::{PeakOptions, WaveformCache, WaveformPeaks};
 
 use serde::{Deserialize, Serialize};
 
@@ -18,10 +25,16 @@ use flux_core::Id;
 pub struct PlaybackSettings {
     /// Whether playback is enabled
     pub enabled: bool,
-    /// Tempo in beats per minute
+    /// Tempo in beats per minute (before the first tempo change, if any)
     pub bpm: f64,
+    /// Tempo changes ordered by time; empty keeps `bpm` throughout
+    #[serde(default)]
+    pub tempo_map: Vec<TempoChange>,
     /// Audio clips in this symbol
     pub audio_clips: Vec<AudioClip>,
+    /// Waveform peaks computed for the audio clips
+    #[serde(default)]
+    pub waveforms: WaveformCache,
     /// Audio source type
     pub audio_source: AudioSource,
     /// Sync mode
@@ -51,7 +64,9 @@ impl Default for PlaybackSettings {
         Self {
             enabled: true,
             bpm: 120.0,
+            tempo_map: Vec::new(),
             audio_clips: Vec::new(),
+            waveforms: WaveformCache::default(),
             audio_source: AudioSource::default(),
             sync_mode: SyncMode::default(),
             state: PlaybackState::default(),
@@ -124,9 +139,85 @@ impl PlaybackSettings {
         id
     }
 
+    /// Move a clip's trim points, dropping waveforms cached for the old range
+    ///
+    /// Returns `false` if no clip has the given ID.
+    pub fn set_clip_range(&mut self, id: Id, start_time: f64, end_time: f64) -> bool {
+        let Some(clip) = self.audio_clips.iter_mut().find(|c| c.id == id) else {
+            return false;
+        };
+        clip.start_time = start_time;
+        clip.end_time = end_time;
+        self.waveforms.retain_current(&self.audio_clips);
+        true
+    }
+
+    // === Waveforms ===
+
+    /// Get cached waveform peaks for a clip, if computed for its current range
+    pub fn waveform_for(&self, clip_id: Id, buckets: usize) -> Option<&WaveformPeaks> {
+        let clip = self.audio_clips.iter().find(|c| c.id == clip_id)?;
+        self.waveforms.get(clip, buckets)
+    }
+
+    /// Cache waveform peaks computed for a clip's current range
+    ///
+    /// Returns `false` if no clip has the given ID.
+    pub fn cache_waveform(&mut self, clip_id: Id, buckets: usize, peaks: WaveformPeaks) -> bool {
+        match self.audio_clips.iter().find(|c| c.id == clip_id) {
+            Some(clip) => {
+                self.waveforms.insert(clip, buckets, peaks);
+                true
+            }
+            None => false,
+        }
+    }
+
+    // === Tempo Map ===
+
+    /// Add a tempo change, keeping the map ordered by time
+    ///
+    /// A change at the same time as an existing one replaces it. Returns the
+    /// index of the change in [`tempo_map`](Self::tempo_map).
+    pub fn add_tempo_change(&mut self, change: TempoChange) -> usize {
+        match self
+            .tempo_map
+            .binary_search_by(|c| c.time_sec.total_cmp(&change.time_sec))
+        {
+            Ok(index) => {
+                self.tempo_map[index] = change;
+                index
+            }
+            Err(index) => {
+                self.tempo_map.insert(index, change);
+                index
+            }
+        }
+    }
+
+    /// Remove the tempo change at `index`
+    pub fn remove_tempo_change(&mut self, index: usize) -> Option<TempoChange> {
+        if index < self.tempo_map.len() {
+            Some(self.tempo_map.remove(index))
+        } else {
+            None
+        }
+    }
+
+    /// Get the tempo at a given time, following the tempo map
+    pub fn bpm_at_time(&self, time: f64) -> f64 {
+        if self.tempo_map.is_empty() {
+            return 60.0 / self.beat_duration();
+        }
+        tempo::bpm_at_time(self.bpm, &self.tempo_map, time)
+    }
+
     // === Beat Calculations ===
 
-    /// Get the duration of one beat in seconds
+    /// Get the duration of one beat in seconds at the base `bpm`
+    ///
+    /// Ignores the tempo map; use [`bpm_at_time`](Self::bpm_at_time) for
+    /// the tempo at a specific time.
     pub fn beat_duration(&self) -> f64 {
         if self.bpm > 0.0 {
             60.0 / self.bpm
@@ -136,13 +227,21 @@ impl PlaybackSettings {
     }
 
     /// Get the current beat number at a given time
+    ///
+    /// Beats are integrated across tempo map segments.
     pub fn beat_at_time(&self, time: f64) -> f64 {
-        time / self.beat_duration()
+        if self.tempo_map.is_empty() {
+            return time / self.beat_duration();
+        }
+        tempo::beat_at_time(self.bpm, &self.tempo_map, time)
     }
 
     /// Get the time at a specific beat number
     pub fn time_at_beat(&self, beat: f64) -> f64 {
-        beat * self.beat_duration()
+        if self.tempo_map.is_empty() {
+            return beat * self.beat_duration();
+        }
+        tempo::time_at_beat(self.bpm, &self.tempo_map, beat)
     }
 
     /// Quantize a time to the nearest beat
@@ -239,6 +338,7 @@ impl PlaybackSettings {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use flux_core::EvalContext;
 
     #[test]
     fn test_playback_settings_default() {
@@ -419,4 +519,184 @@ mod tests {
         assert_eq!(restored.audio_clips.len(), 1);
         assert!(restored.enable_beat_locking);
     }
+
+    #[test]
+    fn test_tempo_map_integrates_beats() {
+        let mut settings = PlaybackSettings::with_bpm(120.0);
+        settings.add_tempo_change(TempoChange::new(10.0, 140.0));
+
+        // 10s at 120 BPM + 10s at 140 BPM
+        let expected = 20.0 + 10.0 * 140.0 / 60.0;
+        assert!((settings.beat_at_time(20.0) - expected).abs() < 1e-9);
+        assert!((settings.time_at_beat(expected) - 20.0).abs() < 1e-9);
+        assert!((settings.beat_at_time(5.0) - 10.0).abs() < 1e-9);
+        assert_eq!(settings.bpm_at_time(9.9), 120.0);
+        assert_eq!(settings.bpm_at_time(10.0), 140.0);
+
+        // Quantizing snaps to the local grid on either side of the change
+        assert!((settings.quantize_to_beat(9.1) - 9.0).abs() < 1e-9);
+        assert!((settings.quantize_to_beat(10.5) - (10.0 + 60.0 / 140.0)).abs() < 1e-9);
+        let half_beat_after = 10.0 + 1.5 * 60.0 / 140.0;
+        assert!((settings.beat_fraction(half_beat_after) - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_tempo_map_ramp() {
+        let mut settings = PlaybackSettings::with_bpm(120.0);
+        settings.add_tempo_change(TempoChange::new(20.0, 100.0));
+        // Inserted out of order; the map stays sorted
+        assert_eq!(settings.add_tempo_change(TempoChange::ramp(10.0, 60.0)), 0);
+
+        // Linear 60 -> 100 BPM over 10s averages 80 BPM
+        assert_eq!(settings.bpm_at_time(15.0), 80.0);
+        let at_20 = 20.0 + 10.0 * 80.0 / 60.0;
+        assert!((settings.beat_at_time(20.0) - at_20).abs() < 1e-9);
+        for time in [12.0, 15.0, 19.5, 25.0] {
+            let beat = settings.beat_at_time(time);
+            assert!((settings.time_at_beat(beat) - time).abs() < 1e-9);
+        }
+
+        assert_eq!(settings.remove_tempo_change(0), Some(TempoChange::ramp(10.0, 60.0)));
+        assert_eq!(settings.remove_tempo_change(5), None);
+    }
+
+    #[test]
+    fn test_tempo_map_serialization() {
+        let mut settings = PlaybackSettings::with_bpm(120.0);
+        settings.add_tempo_change(TempoChange::new(10.0, 140.0));
+        settings.add_tempo_change(TempoChange::ramp(30.0, 90.0));
+
+        let restored = PlaybackSettings::from_json(&settings.to_json()).unwrap();
+        assert_eq!(restored.tempo_map, settings.tempo_map);
+
+        // Settings saved before tempo maps existed still load
+        let mut json = settings.to_json();
+        json.as_object_mut().unwrap().remove("tempo_map");
+        let restored = PlaybackSettings::from_json(&json).unwrap();
+        assert!(restored.tempo_map.is_empty());
+        assert!((restored.beat_at_time(20.0) - 40.0).abs() < 1e-9);
+    }
+
+    fn sine(amplitude: f32, frames: usize, channels: usize) -> Vec<f32> {
+        (0..frames)
+            .flat_map(|i| {
+                let s = amplitude * (i as f32 * 0.05).sin();
+                std::iter::repeat_n(s, channels)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_waveform_peaks_of_sine() {
+        let samples = sine(0.8, 48_000, 2);
+        let peaks = AudioClip::compute_peaks(&samples, 2, 64);
+        assert_eq!(peaks.channel_count(), 1);
+        assert_eq!(peaks.bucket_count(), 64);
+        for [min, max] in &peaks.channels[0] {
+            assert!((min + 0.8).abs() < 0.01, "min {min}");
+            assert!((max - 0.8).abs() < 0.01, "max {max}");
+        }
+
+        let options = PeakOptions { mixdown: false, normalize: true };
+        let peaks = WaveformPeaks::compute(&samples, 2, 10, options);
+        assert_eq!(peaks.channel_count(), 2);
+        assert!((peaks.max_amplitude() - 1.0).abs() < 1e-6);
+
+        // More buckets than frames leaves empty buckets silent
+        let peaks = AudioClip::compute_peaks(&[1.0, -1.0], 1, 4);
+        assert_eq!(peaks.channels[0], vec![[0.0, 0.0], [1.0, 1.0], [0.0, 0.0], [-1.0, -1.0]]);
+    }
+
+    #[test]
+    fn test_waveform_cache() {
+        let mut settings = PlaybackSettings::new();
+        let id = settings.set_soundtrack("music/track.wav", 30.0);
+        let peaks = AudioClip::compute_peaks(&sine(0.5, 1000, 1), 1, 16);
+
+        assert!(settings.waveform_for(id, 16).is_none());
+        assert!(settings.cache_waveform(id, 16, peaks.clone()));
+        assert_eq!(settings.waveform_for(id, 16), Some(&peaks));
+        assert!(settings.waveform_for(id, 32).is_none());
+
+        // Cached peaks survive a save/load round trip
+        let restored = PlaybackSettings::from_json(&settings.to_json()).unwrap();
+        assert_eq!(restored.waveform_for(id, 16), Some(&peaks));
+
+        // Trimming the clip invalidates the entry
+        assert!(settings.set_clip_range(id, 2.0, 30.0));
+        assert!(settings.waveform_for(id, 16).is_none());
+        assert!(settings.waveforms.is_empty());
+    }
+
+    #[test]
+    fn test_transport_event_stream() {
+        let mut settings = PlaybackSettings::new();
+        settings.set_loop_range(1.0, 3.0);
+        let mut transport = Transport::new(settings);
+        let mut ctx = EvalContext::new();
+
+        transport.play();
+        assert_eq!(transport.tick(0.5), 0.5);
+        assert_eq!(transport.tick(1.0), 1.5);
+        transport.pause();
+        assert_eq!(transport.tick(1.0), 1.5);
+
+        // Seeking while paused shows up in the context without playing
+        transport.seek(2.5);
+        transport.apply_to_context(&mut ctx);
+        assert_eq!(ctx.time, 2.5);
+        assert_eq!(ctx.local_time, 2.5);
+        assert_eq!(ctx.vars(TRANSPORT_NAMESPACE).get_bool("playing"), Some(false));
+
+        transport.toggle();
+        assert_eq!(transport.tick(1.0), 1.5);
+        // The loop end itself wraps to the loop start
+        transport.seek(3.0);
+        assert_eq!(transport.playhead(), 1.0);
+        transport.apply_to_context(&mut ctx);
+        assert_eq!(ctx.time, 1.0);
+        transport.stop();
+        assert_eq!(transport.playhead(), 0.0);
+
+        let events: Vec<_> = transport.drain_events().collect();
+        assert_eq!(
+            events,
+            vec![
+                TransportEvent::Started,
+                TransportEvent::Paused,
+                TransportEvent::Seeked { from: 1.5, to: 2.5 },
+                TransportEvent::Started,
+                TransportEvent::LoopWrapped,
+                TransportEvent::Seeked { from: 1.5, to: 1.0 },
+                TransportEvent::Stopped,
+            ]
+        );
+        assert!(!transport.has_pending_events());
+    }
+
+    #[test]
+    fn test_transport_context_vars() {
+        let mut transport = Transport::new(PlaybackSettings::with_bpm(120.0));
+        let mut ctx = EvalContext::new();
+
+        // Stopped and redundant state changes neither advance nor queue events
+        assert_eq!(transport.tick(1.0), 0.0);
+        transport.pause();
+        transport.stop();
+        assert!(!transport.has_pending_events());
+
+        transport.play();
+        transport.play();
+        transport.tick(2.0);
+        transport.apply_to_context(&mut ctx);
+        let vars = ctx.vars(TRANSPORT_NAMESPACE);
+        assert_eq!(vars.get_bool("playing"), Some(true));
+        assert_eq!(vars.get_float("beat"), Some(4.0));
+        assert_eq!(vars.get_float("bpm"), Some(120.0));
+        assert_eq!(vars.get_bool("looping"), Some(false));
+        assert_eq!(transport.drain_events().count(), 1);
+
+        transport.settings_mut().enabled = false;
+        assert_eq!(transport.tick(1.0), 2.0);
+    }
 }
@@ -0,0 +1,126 @@
+//! Tempo map types and beat integration
+//!
+//! A tempo map is an ordered list of [`TempoChange`]s. Before the first
+//! change the settings' flat BPM applies, anchored so that beat 0 is at
+//! time 0. Each change either holds its tempo until the next change or, when
+//! `ramp` is set, moves linearly to the next change's tempo. Beat positions
+//! are the integral of tempo over time, computed analytically per segment.
+
+use serde::{Deserialize, Serialize};
+
+/// A tempo change in a [`PlaybackSettings`](super::PlaybackSettings) tempo map
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TempoChange {
+    /// Time the change takes effect, in seconds
+    pub time_sec: f64,
+    /// Tempo in beats per minute from this point on
+    pub bpm: f64,
+    /// Ramp linearly to the next change's tempo instead of holding this one
+    #[serde(default)]
+    pub ramp: bool,
+}
+
+impl TempoChange {
+    /// A tempo that holds until the next change
+    pub fn new(time_sec: f64, bpm: f64) -> Self {
+        Self { time_sec, bpm, ramp: false }
+    }
+
+    /// A tempo that ramps linearly to the next change's tempo
+    pub fn ramp(time_sec: f64, bpm: f64) -> Self {
+        Self { time_sec, bpm, ramp: true }
+    }
+}
+
+/// Non-positive tempos fall back to 60 BPM (one beat per second)
+fn positive_bpm(bpm: f64) -> f64 {
+    if bpm > 0.0 {
+        bpm
+    } else {
+        60.0
+    }
+}
+
+/// A stretch of the tempo map with constant or linearly changing tempo
+struct Segment {
+    start_time: f64,
+    start_beat: f64,
+    start_bpm: f64,
+    /// Tempo change per second (0 for constant tempo)
+    slope: f64,
+}
+
+impl Segment {
+    fn bpm_after(&self, dt: f64) -> f64 {
+        self.start_bpm + self.slope * dt
+    }
+
+    fn beats_after(&self, dt: f64) -> f64 {
+        (self.start_bpm * dt + 0.5 * self.slope * dt * dt) / 60.0
+    }
+
+    /// Inverse of [`beats_after`](Self::beats_after)
+    fn time_after(&self, beats: f64) -> f64 {
+        if self.slope == 0.0 {
+            return 60.0 * beats / self.start_bpm;
+        }
+        // Root of slope/2 dt² + bpm dt - 60 beats = 0, in a form that stays
+        // accurate when the slope is tiny
+        let disc = (self.start_bpm * self.start_bpm + 120.0 * self.slope * beats).max(0.0);
+        120.0 * beats / (self.start_bpm + disc.sqrt())
+    }
+}
+
+/// Segments of a non-empty tempo map, with the beat at each segment start
+fn segments(flat_bpm: f64, map: &[TempoChange]) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::with_capacity(map.len());
+    let mut beat = map.first().map_or(0.0, |c| c.time_sec * positive_bpm(flat_bpm) / 60.0);
+
+    for (i, change) in map.iter().enumerate() {
+        let start_bpm = positive_bpm(change.bpm);
+        let slope = match map.get(i + 1) {
+            Some(next) if change.ramp && next.time_sec > change.time_sec => {
+                (positive_bpm(next.bpm) - start_bpm) / (next.time_sec - change.time_sec)
+            }
+            _ => 0.0,
+        };
+        let segment = Segment {
+            start_time: change.time_sec,
+            start_beat: beat,
+            start_bpm,
+            slope,
+        };
+        if let Some(next) = map.get(i + 1) {
+            beat += segment.beats_after(next.time_sec - change.time_sec);
+        }
+        segments.push(segment);
+    }
+    segments
+}
+
+/// Tempo at `time`
+pub(crate) fn bpm_at_time(flat_bpm: f64, map: &[TempoChange], time: f64) -> f64 {
+    let segments = segments(flat_bpm, map);
+    match segments.iter().rev().find(|s| s.start_time <= time) {
+        Some(s) => s.bpm_after(time - s.start_time),
+        None => positive_bpm(flat_bpm),
+    }
+}
+
+/// Beat position at `time`
+pub(crate) fn beat_at_time(flat_bpm: f64, map: &[TempoChange], time: f64) -> f64 {
+    let segments = segments(flat_bpm, map);
+    match segments.iter().rev().find(|s| s.start_time <= time) {
+        Some(s) => s.start_beat + s.beats_after(time - s.start_time),
+        None => time * positive_bpm(flat_bpm) / 60.0,
+    }
+}
+
+/// Time at beat position `beat`
+pub(crate) fn time_at_beat(flat_bpm: f64, map: &[TempoChange], beat: f64) -> f64 {
+    let segments = segments(flat_bpm, map);
+    match segments.iter().rev().find(|s| s.start_beat <= beat) {
+        Some(s) => s.start_time + s.time_after(beat - s.start_beat),
+        None => 60.0 * beat / positive_bpm(flat_bpm),
+    }
+}
@@ -0,0 +1,206 @@
+//! Playback transport: playhead, state changes and context publishing
+//!
+//! [`PlaybackSettings`] only records whether playback is running. A
+//! [`Transport`] owns the settings together with the playhead, advances the
+//! playhead each frame with [`tick`](Transport::tick), and queues a
+//! [`TransportEvent`] for every state change, seek and loop wrap so the UI
+//! and the evaluation loop can follow along.
+//!
+//! # Context variables
+//!
+//! [`apply_to_context`](Transport::apply_to_context) sets
+//! [`EvalContext::time`] and [`EvalContext::local_time`] to the playhead and
+//! publishes these variables in the [`TRANSPORT_NAMESPACE`] namespace:
+//!
+//! - `"transport.playing"` - Bool, whether playback is running
+//! - `"transport.beat"` - Float, beat position of the playhead
+//! - `"transport.bpm"` - Float, tempo at the playhead
+//! - `"transport.looping"` - Bool, whether a loop range is active
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut transport = Transport::new(project.playback.clone());
+//! transport.play();
+//!
+//! loop {
+//!     transport.tick(frame_dt);
+//!     transport.apply_to_context(&mut ctx);
+//!     graph.evaluate(output, 0, &ctx);
+//!
+//!     for event in transport.drain_events() {
+//!         ui.on_transport_event(event);
+//!     }
+//! }
+//! ```
+
+use flux_core::EvalContext;
+
+use super::{PlaybackSettings, PlaybackState};
+
+/// Namespace of the context variables published by a [`Transport`]
+pub const TRANSPORT_NAMESPACE: &str = "transport";
+
+/// A change in a [`Transport`], in the order it happened
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TransportEvent {
+    /// Playback started or resumed
+    Started,
+    /// Playback paused; the playhead stays where it is
+    Paused,
+    /// Playback stopped and the playhead returned to 0
+    Stopped,
+    /// The playhead was moved by [`Transport::seek`]
+    ///
+    /// `to` is where the playhead ended up after loop wrapping.
+    Seeked { from: f64, to: f64 },
+    /// Playback reached the loop end and jumped back into the loop range
+    LoopWrapped,
+}
+
+/// Playhead and playback state shared by the UI and the evaluation loop
+///
+/// State changes should go through the transport rather than
+/// [`settings_mut`](Self::settings_mut) so that they queue events.
+#[derive(Clone, Debug, Default)]
+pub struct Transport {
+    settings: PlaybackSettings,
+    playhead: f64,
+    pending_events: Vec<TransportEvent>,
+}
+
+impl Transport {
+    /// Create a transport for the given settings with the playhead at 0
+    pub fn new(settings: PlaybackSettings) -> Self {
+        Self {
+            settings,
+            playhead: 0.0,
+            pending_events: Vec::new(),
+        }
+    }
+
+    /// Get the wrapped playback settings
+    pub fn settings(&self) -> &PlaybackSettings {
+        &self.settings
+    }
+
+    /// Get the wrapped playback settings for editing (tempo, loop range, clips)
+    pub fn settings_mut(&mut self) -> &mut PlaybackSettings {
+        &mut self.settings
+    }
+
+    /// Unwrap the playback settings
+    pub fn into_settings(self) -> PlaybackSettings {
+        self.settings
+    }
+
+    /// Get the playhead position in seconds
+    pub fn playhead(&self) -> f64 {
+        self.playhead
+    }
+
+    /// Get the current playback state
+    pub fn state(&self) -> PlaybackState {
+        self.settings.state
+    }
+
+    /// Check if currently playing
+    pub fn is_playing(&self) -> bool {
+        self.settings.is_playing()
+    }
+
+    // === Playback Control ===
+
+    /// Start or resume playback from the playhead
+    pub fn play(&mut self) {
+        if !self.is_playing() {
+            self.settings.play();
+            self.pending_events.push(TransportEvent::Started);
+        }
+    }
+
+    /// Pause playback, keeping the playhead
+    ///
+    /// Does nothing unless playing.
+    pub fn pause(&mut self) {
+        if self.is_playing() {
+            self.settings.pause();
+            self.pending_events.push(TransportEvent::Paused);
+        }
+    }
+
+    /// Stop playback and return the playhead to 0
+    pub fn stop(&mut self) {
+        if self.settings.state != PlaybackState::Stopped {
+            self.settings.stop();
+            self.playhead = 0.0;
+            self.pending_events.push(TransportEvent::Stopped);
+        }
+    }
+
+    /// Toggle play/pause
+    pub fn toggle(&mut self) {
+        if self.is_playing() {
+            self.pause();
+        } else {
+            self.play();
+        }
+    }
+
+    /// Move the playhead to `time` seconds without changing the state
+    ///
+    /// The target is wrapped into the loop range like a playing playhead, so
+    /// seeking to the loop end lands on the loop start.
+    pub fn seek(&mut self, time: f64) {
+        let from = self.playhead;
+        self.playhead = self.settings.apply_loop(time);
+        self.pending_events.push(TransportEvent::Seeked { from, to: self.playhead });
+    }
+
+    /// Advance the playhead by `wall_dt` seconds of wall-clock time
+    ///
+    /// Only advances while playing and while playback is enabled. Returns
+    /// the new playhead position.
+    pub fn tick(&mut self, wall_dt: f64) -> f64 {
+        if !self.is_playing() || !self.settings.enabled || wall_dt <= 0.0 {
+            return self.playhead;
+        }
+        let unwrapped = self.playhead + wall_dt;
+        self.playhead = self.settings.apply_loop(unwrapped);
+        if self.playhead != unwrapped {
+            self.pending_events.push(TransportEvent::LoopWrapped);
+        }
+        self.playhead
+    }
+
+    // === Context ===
+
+    /// Publish the playhead and transport state into an evaluation context
+    ///
+    /// Variables go in the [`TRANSPORT_NAMESPACE`] namespace: `playing`,
+    /// `beat`, `bpm` and `looping`.
+    pub fn apply_to_context(&self, ctx: &mut EvalContext) {
+        ctx.time = self.playhead;
+        ctx.local_time = self.playhead;
+
+        let settings = &self.settings;
+        let looping = settings.loop_playback && settings.loop_end > settings.loop_start;
+        ctx.vars_mut(TRANSPORT_NAMESPACE)
+            .set_bool("playing", self.is_playing())
+            .set_float("beat", settings.beat_at_time(self.playhead) as f32)
+            .set_float("bpm", settings.bpm_at_time(self.playhead) as f32)
+            .set_bool("looping", looping);
+    }
+
+    // === Events ===
+
+    /// Drain all pending transport events, oldest first
+    pub fn drain_events(&mut self) -> impl Iterator<Item = TransportEvent> + '_ {
+        self.pending_events.drain(..)
+    }
+
+    /// Check if there are any pending events
+    pub fn has_pending_events(&self) -> bool {
+        !self.pending_events.is_empty()
+    }
+}
@@ -0,0 +1,199 @@
+//! Waveform peak extraction and caching
+//!
+//! Timeline UIs draw audio clips as waveform overviews: a `[min, max]` pair
+//! per horizontal bucket. The host decodes the audio and hands over the
+//! interleaved samples; bucketing, mixdown and normalization happen here.
+//!
+//! Computed peaks are stored in a [`WaveformCache`] that serializes with the
+//! playback settings, so they don't have to be recomputed on every load.
+//! Each entry remembers the clip range it was computed for and is treated as
+//! stale once the clip's trim points change.
+
+use serde::{Deserialize, Serialize};
+
+use super::AudioClip;
+
+/// Options for [`WaveformPeaks::compute`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PeakOptions {
+    /// Average all channels into a single mono envelope
+    pub mixdown: bool,
+    /// Scale the peaks so the loudest one reaches ±1.0
+    pub normalize: bool,
+}
+
+impl Default for PeakOptions {
+    fn default() -> Self {
+        Self {
+            mixdown: true,
+            normalize: false,
+        }
+    }
+}
+
+/// Min/max waveform envelope of an audio buffer
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WaveformPeaks {
+    /// `[min, max]` per bucket for each channel (a single channel when mixed down)
+    pub channels: Vec<Vec<[f32; 2]>>,
+}
+
+impl WaveformPeaks {
+    /// Compute peaks from interleaved samples
+    ///
+    /// Frames are split into `buckets` ranges of (nearly) equal length.
+    /// Buckets that receive no frames, as happens when there are fewer
+    /// frames than buckets, hold `[0.0, 0.0]`.
+    pub fn compute(samples: &[f32], channels: usize, buckets: usize, options: PeakOptions) -> Self {
+        let channels = channels.max(1);
+        let frames = samples.len() / channels;
+        let lanes = if options.mixdown { 1 } else { channels };
+        let mut peaks = vec![vec![[0.0f32; 2]; buckets]; lanes];
+
+        for bucket in 0..buckets {
+            let start = bucket * frames / buckets;
+            let end = (bucket + 1) * frames / buckets;
+            if start == end {
+                continue;
+            }
+            for (lane, lane_peaks) in peaks.iter_mut().enumerate() {
+                let mut min = f32::INFINITY;
+                let mut max = f32::NEG_INFINITY;
+                for frame in samples[start * channels..end * channels].chunks_exact(channels) {
+                    let sample = if options.mixdown {
+                        frame.iter().sum::<f32>() / channels as f32
+                    } else {
+                        frame[lane]
+                    };
+                    min = min.min(sample);
+                    max = max.max(sample);
+                }
+                lane_peaks[bucket] = [min, max];
+            }
+        }
+
+        let mut result = Self { channels: peaks };
+        if options.normalize {
+            result.normalize();
+        }
+        result
+    }
+
+    /// Number of buckets per channel
+    pub fn bucket_count(&self) -> usize {
+        self.channels.first().map_or(0, Vec::len)
+    }
+
+    /// Number of channels
+    pub fn channel_count(&self) -> usize {
+        self.channels.len()
+    }
+
+    /// Largest absolute peak across all channels
+    pub fn max_amplitude(&self) -> f32 {
+        self.channels
+            .iter()
+            .flatten()
+            .fold(0.0f32, |acc, [min, max]| acc.max(min.abs()).max(max.abs()))
+    }
+
+    /// Scale all peaks so the loudest reaches ±1.0 (silence is left as is)
+    pub fn normalize(&mut self) {
+        let amplitude = self.max_amplitude();
+        if amplitude > 0.0 {
+            for pair in self.channels.iter_mut().flatten() {
+                pair[0] /= amplitude;
+                pair[1] /= amplitude;
+            }
+        }
+    }
+}
+
+/// A cached waveform and the clip range it was computed for
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct CachedWaveform {
+    file_path: String,
+    buckets: usize,
+    start_time: f64,
+    end_time: f64,
+    peaks: WaveformPeaks,
+}
+
+impl CachedWaveform {
+    fn is_current(&self, clip: &AudioClip) -> bool {
+        self.start_time == clip.start_time && self.end_time == clip.end_time
+    }
+}
+
+/// Computed waveform peaks keyed by `(file_path, buckets)`
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WaveformCache {
+    entries: Vec<CachedWaveform>,
+}
+
+impl WaveformCache {
+    /// Create an empty cache
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the peaks for a clip, unless its trim points changed since they
+    /// were computed
+    pub fn get(&self, clip: &AudioClip, buckets: usize) -> Option<&WaveformPeaks> {
+        self.find(&clip.file_path, buckets)
+            .map(|index| &self.entries[index])
+            .filter(|entry| entry.is_current(clip))
+            .map(|entry| &entry.peaks)
+    }
+
+    /// Store peaks computed for a clip's current range, replacing any entry
+    /// with the same key
+    pub fn insert(&mut self, clip: &AudioClip, buckets: usize, peaks: WaveformPeaks) {
+        let entry = CachedWaveform {
+            file_path: clip.file_path.clone(),
+            buckets,
+            start_time: clip.start_time,
+            end_time: clip.end_time,
+            peaks,
+        };
+        match self.find(&clip.file_path, buckets) {
+            Some(index) => self.entries[index] = entry,
+            None => self.entries.push(entry),
+        }
+    }
+
+    /// Drop every entry for a file (e.g. after the file changed on disk)
+    pub fn invalidate_file(&mut self, file_path: &str) {
+        self.entries.retain(|entry| entry.file_path != file_path);
+    }
+
+    /// Drop entries that no longer match any clip's file and trim points
+    pub fn retain_current(&mut self, clips: &[AudioClip]) {
+        self.entries.retain(|entry| {
+            clips
+                .iter()
+                .any(|clip| clip.file_path == entry.file_path && entry.is_current(clip))
+        });
+    }
+
+    /// Number of cached waveforms
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether the cache is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Remove all entries
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    fn find(&self, file_path: &str, buckets: usize) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| entry.file_path == file_path && entry.buckets == buckets)
+    }
+}
@@ -0,0 +1,96 @@
+//! Thumbnail evaluation of node outputs
+//!
+//! Editors show small previews of intermediate outputs while the main
+//! evaluation keeps running. [`Graph::evaluate_preview`] computes only the
+//! nodes upstream of the previewed output, in a call context derived from
+//! the caller's, at a reduced resolution and within a node and time budget.
+//! Its cache entries never overwrite main-context ones, and stateful
+//! operators (see [`Operator::is_stateful`]) are not computed: their last
+//! main-context output is reused so previewing doesn't advance their state.
+//!
+//! Preview entries live under [`PREVIEW_CALL_INDEX`] and can be dropped with
+//! [`Graph::clear_preview_cache`] without touching the main cache.
+//!
+//! [`Graph::evaluate_preview`]: crate::Graph::evaluate_preview
+//! [`Graph::clear_preview_cache`]: crate::Graph::clear_preview_cache
+//! [`Operator::is_stateful`]: flux_core::Operator::is_stateful
+
+use std::time::Duration;
+
+use flux_core::Value;
+
+/// Call context index of the preview context, relative to the caller's
+/// (ASCII `prev`, well clear of loop iteration indices).
+pub const PREVIEW_CALL_INDEX: u32 = 0x7072_6576;
+
+/// Limits applied to preview evaluations.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PreviewSettings {
+    /// Resolution passed to operators via `EvalContext::resolution`
+    pub resolution: (u32, u32),
+    /// Maximum number of nodes computed per preview
+    pub max_nodes: usize,
+    /// Maximum wall-clock time spent per preview
+    pub time_budget: Duration,
+}
+
+impl Default for PreviewSettings {
+    fn default() -> Self {
+        Self {
+            resolution: (64, 64),
+            max_nodes: 256,
+            time_budget: Duration::from_millis(4),
+        }
+    }
+}
+
+impl PreviewSettings {
+    /// Set the preview resolution.
+    pub fn with_resolution(mut self, width: u32, height: u32) -> Self {
+        self.resolution = (width, height);
+        self
+    }
+
+    /// Set the maximum number of nodes computed per preview.
+    pub fn with_max_nodes(mut self, max_nodes: usize) -> Self {
+        self.max_nodes = max_nodes;
+        self
+    }
+
+    /// Set the maximum time spent per preview.
+    pub fn with_time_budget(mut self, time_budget: Duration) -> Self {
+        self.time_budget = time_budget;
+        self
+    }
+}
+
+/// Result of a preview evaluation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum PreviewResult {
+    /// Every upstream node was visited; holds the previewed value
+    Complete(Value),
+    /// The budget ran out; holds the best value available (the preview
+    /// cache, then the main cache, then the output's last computed value)
+    Truncated(Value),
+}
+
+impl PreviewResult {
+    /// The previewed value, complete or not.
+    pub fn value(&self) -> &Value {
+        match self {
+            Self::Complete(value) | Self::Truncated(value) => value,
+        }
+    }
+
+    /// Returns true if the budget ran out before the preview finished.
+    pub fn is_truncated(&self) -> bool {
+        matches!(self, Self::Truncated(_))
+    }
+
+    /// Consume the result, returning the value.
+    pub fn into_value(self) -> Value {
+        match self {
+            Self::Complete(value) | Self::Truncated(value) => value,
+        }
+    }
+}
@@ -0,0 +1,719 @@
+//! Multi-graph project runtime
+//!
+//! A [`Project`] owns several named scene graphs together with the state
+//! they share: the symbol and operator registries, the playback settings and
+//! a base [`EvalContext`]. Hosts write time and context variables into the
+//! base context once per frame; every scene evaluates with its own fork of
+//! it, so all scenes see the same values.
+//!
+//! Scenes publish named outputs. A [`SceneReferenceOp`] placed in one scene
+//! reads a published output of another: before a scene is evaluated, the
+//! project evaluates every scene it references and feeds the values into
+//! the reference nodes. Scenes that reference each other, directly or
+//! through other scenes, fail with [`ProjectError::SceneCycle`].
+//!
+//! # Example
+//!
+//! ```ignore
+//! let mut project = Project::load("show.rproj", symbols, operators)?;
+//! loop {
+//!     project.context_mut().advance(1.0 / 60.0);
+//!     for (name, value) in project.evaluate_active()? {
+//!         println!("{name}: {value:?}");
+//!     }
+//! }
+//! ```
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::Arc;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::port::{InputPort, OutputPort};
+use flux_core::value::{Value, ValueType};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use flux_operators::{capture_meta, OperatorRegistry, RegistryEntry};
+use thiserror::Error;
+
+use crate::composite::CompositeOp;
+use crate::cue::CueList;
+use crate::graph::{Graph, GraphError};
+use crate::playback::PlaybackSettings;
+use crate::serialization::{self, GraphDef, ProjectFile, SerializationError};
+use crate::symbol::{SymbolError, SymbolRegistry};
+
+/// Errors from loading or evaluating a [`Project`]
+#[derive(Error, Debug)]
+pub enum ProjectError {
+    /// No scene with this name
+    #[error("Scene not found: {0}")]
+    SceneNotFound(String),
+
+    /// The scene publishes no output with this name
+    #[error("Scene '{scene}' has no output named '{output}'")]
+    OutputNotFound { scene: String, output: String },
+
+    /// Scenes reference each other; names the cycle, starting and ending
+    /// with the same scene
+    #[error("Scene reference cycle: {}", .0.join(" -> "))]
+    SceneCycle(Vec<String>),
+
+    /// Evaluating a scene graph failed
+    #[error("Graph error: {0}")]
+    Graph(#[from] GraphError),
+
+    /// Building a scene from its root symbol failed
+    #[error("Symbol error: {0}")]
+    Symbol(#[from] SymbolError),
+
+    /// Reading a project or graph file failed
+    #[error(transparent)]
+    Serialization(#[from] SerializationError),
+}
+
+/// A named output published by a scene
+#[derive(Clone, Debug, PartialEq)]
+struct SceneOutput {
+    name: String,
+    node: Id,
+    index: usize,
+}
+
+struct Scene {
+    graph: Graph,
+    outputs: Vec<SceneOutput>,
+}
+
+impl Scene {
+    fn output(&self, name: &str) -> Option<&SceneOutput> {
+        self.outputs.iter().find(|output| output.name == name)
+    }
+}
+
+/// Several named scene graphs evaluated with shared registries, playback
+/// settings and context
+pub struct Project {
+    scenes: BTreeMap<String, Scene>,
+    active: Option<String>,
+    symbols: Arc<SymbolRegistry>,
+    operators: Arc<OperatorRegistry>,
+    playback: PlaybackSettings,
+    context: EvalContext,
+    cues: CueList,
+}
+
+impl Project {
+    /// Create an empty project
+    ///
+    /// Registers [`SceneReferenceOp`] with `operators` so symbols can use it.
+    pub fn new(symbols: Arc<SymbolRegistry>, operators: Arc<OperatorRegistry>) -> Self {
+        SceneReferenceOp::register(&operators);
+        Self {
+            scenes: BTreeMap::new(),
+            active: None,
+            symbols,
+            operators,
+            playback: PlaybackSettings::default(),
+            context: EvalContext::new(),
+            cues: CueList::new(),
+        }
+    }
+
+    /// Load a project file and all of its graphs
+    ///
+    /// Graph paths are resolved relative to the project file's directory.
+    pub fn load(
+        path: impl AsRef<Path>,
+        symbols: Arc<SymbolRegistry>,
+        operators: Arc<OperatorRegistry>,
+    ) -> Result<Self, ProjectError> {
+        let path = path.as_ref();
+        let file = serialization::load_project(path)?;
+        let root = path.parent().unwrap_or_else(|| Path::new(""));
+        Self::from_project_file(&file, root, symbols, operators)
+    }
+
+    /// Build a project from a loaded project file
+    ///
+    /// Loads `main_graph` and every entry of `scenes` from `root`. The main
+    /// graph becomes the active scene and provides the playback settings.
+    pub fn from_project_file(
+        file: &ProjectFile,
+        root: impl AsRef<Path>,
+        symbols: Arc<SymbolRegistry>,
+        operators: Arc<OperatorRegistry>,
+    ) -> Result<Self, ProjectError> {
+        let root = root.as_ref();
+        let mut project = Self::new(symbols, operators);
+
+        let main = serialization::load_graph(root.join(&file.main_graph))?;
+        project.playback = main.graph.playback.to_settings();
+        let main_name = project.add_scene_from_def(&main.graph)?;
+        for scene in &file.scenes {
+            let graph_file = serialization::load_graph(root.join(scene))?;
+            project.add_scene_from_def(&graph_file.graph)?;
+        }
+        project.active = Some(main_name);
+        project.cues = CueList::from_cues(file.cues.clone());
+        Ok(project)
+    }
+
+    /// Build a scene from a graph definition's root symbol
+    ///
+    /// The scene is named after the definition and publishes the symbol's
+    /// outputs under their names. Instance overrides are not applied.
+    /// Returns the scene name.
+    pub fn add_scene_from_def(&mut self, def: &GraphDef) -> Result<String, ProjectError> {
+        let symbol = self
+            .symbols
+            .get(def.root_symbol)
+            .ok_or(SymbolError::SymbolNotFound(def.root_symbol))?;
+        let composite = CompositeOp::from_symbol(&symbol, &self.symbols, &self.operators)?;
+        let outputs = composite
+            .exposed_outputs()
+            .iter()
+            .map(|slot| SceneOutput {
+                name: slot.name.to_string(),
+                node: slot.internal_node,
+                index: slot.internal_slot_index,
+            })
+            .collect();
+        let graph = composite.into_subgraph();
+        self.scenes.insert(def.name.clone(), Scene { graph, outputs });
+        Ok(def.name.clone())
+    }
+
+    /// Add a scene, replacing any scene with the same name
+    ///
+    /// The first scene added becomes the active one.
+    pub fn add_scene(&mut self, name: impl Into<String>, graph: Graph) -> Option<Graph> {
+        let name = name.into();
+        if self.active.is_none() {
+            self.active = Some(name.clone());
+        }
+        let scene = Scene {
+            graph,
+            outputs: Vec::new(),
+        };
+        self.scenes.insert(name, scene).map(|old| old.graph)
+    }
+
+    /// Remove a scene (clears the active scene if it was this one)
+    pub fn remove_scene(&mut self, name: &str) -> Option<Graph> {
+        if self.active.as_deref() == Some(name) {
+            self.active = None;
+        }
+        self.scenes.remove(name).map(|scene| scene.graph)
+    }
+
+    /// Get a scene graph
+    pub fn scene(&self, name: &str) -> Option<&Graph> {
+        self.scenes.get(name).map(|scene| &scene.graph)
+    }
+
+    /// Get a scene graph mutably
+    pub fn scene_mut(&mut self, name: &str) -> Option<&mut Graph> {
+        self.scenes.get_mut(name).map(|scene| &mut scene.graph)
+    }
+
+    /// Scene names in alphabetical order
+    pub fn scene_names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.scenes.keys().map(String::as_str)
+    }
+
+    /// Number of scenes
+    pub fn scene_count(&self) -> usize {
+        self.scenes.len()
+    }
+
+    /// Publish a node output of a scene under a name, replacing any output
+    /// with the same name
+    pub fn expose_output(
+        &mut self,
+        scene: &str,
+        name: impl Into<String>,
+        node: Id,
+        index: usize,
+    ) -> Result<(), ProjectError> {
+        let entry = self
+            .scenes
+            .get_mut(scene)
+            .ok_or_else(|| ProjectError::SceneNotFound(scene.to_string()))?;
+        let op = entry
+            .graph
+            .get(node)
+            .ok_or_else(|| GraphError::node_not_found(node, None))?;
+        if index >= op.outputs().len() {
+            return Err(GraphError::output_not_found(node, index, op.name(), op.outputs().len()).into());
+        }
+
+        let output = SceneOutput {
+            name: name.into(),
+            node,
+            index,
+        };
+        match entry.outputs.iter_mut().find(|o| o.name == output.name) {
+            Some(existing) => *existing = output,
+            None => entry.outputs.push(output),
+        }
+        Ok(())
+    }
+
+    /// Names of the outputs a scene publishes, in publication order
+    pub fn output_names(&self, scene: &str) -> Vec<&str> {
+        self.scenes
+            .get(scene)
+            .map(|scene| scene.outputs.iter().map(|o| o.name.as_str()).collect())
+            .unwrap_or_default()
+    }
+
+    /// Name of the active scene
+    pub fn active_scene(&self) -> Option<&str> {
+        self.active.as_deref()
+    }
+
+    /// Make a scene the active one
+    pub fn set_active_scene(&mut self, name: &str) -> Result<(), ProjectError> {
+        if !self.scenes.contains_key(name) {
+            return Err(ProjectError::SceneNotFound(name.to_string()));
+        }
+        self.active = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Shared symbol registry
+    pub fn symbols(&self) -> &Arc<SymbolRegistry> {
+        &self.symbols
+    }
+
+    /// Shared operator registry
+    pub fn operators(&self) -> &Arc<OperatorRegistry> {
+        &self.operators
+    }
+
+    /// Playback settings shared by all scenes
+    pub fn playback(&self) -> &PlaybackSettings {
+        &self.playback
+    }
+
+    /// Playback settings shared by all scenes (mutable)
+    pub fn playback_mut(&mut self) -> &mut PlaybackSettings {
+        &mut self.playback
+    }
+
+    /// Base context every scene evaluation is forked from
+    pub fn context(&self) -> &EvalContext {
+        &self.context
+    }
+
+    /// Base context (mutable), for time and context variables
+    pub fn context_mut(&mut self) -> &mut EvalContext {
+        &mut self.context
+    }
+
+    /// Cues targeting the active scene
+    pub fn cues(&self) -> &CueList {
+        &self.cues
+    }
+
+    /// Cues targeting the active scene (mutable), for firing and editing
+    pub fn cues_mut(&mut self) -> &mut CueList {
+        &mut self.cues
+    }
+
+    /// Advance cue transitions on the active scene to the base context's time
+    ///
+    /// Call once per frame before evaluating. Does nothing without an active
+    /// scene.
+    pub fn update_cues(&mut self) {
+        let Some(scene) = self.active.as_ref().and_then(|name| self.scenes.get_mut(name)) else {
+            return;
+        };
+        self.cues.update(&mut scene.graph, &self.context);
+    }
+
+    /// Evaluate every output published by the active scene
+    ///
+    /// Returns `(name, value)` pairs in publication order.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectError::SceneNotFound`] if no scene is active, or any
+    /// error from [`evaluate_output`](Self::evaluate_output).
+    pub fn evaluate_active(&mut self) -> Result<Vec<(String, Value)>, ProjectError> {
+        let active = self
+            .active
+            .clone()
+            .ok_or_else(|| ProjectError::SceneNotFound(String::new()))?;
+        let names: Vec<String> = self
+            .output_names(&active)
+            .into_iter()
+            .map(str::to_string)
+            .collect();
+        names
+            .into_iter()
+            .map(|name| {
+                let value = self.evaluate_output(&active, &name)?;
+                Ok((name, value))
+            })
+            .collect()
+    }
+
+    /// Evaluate a published output of a scene
+    ///
+    /// Scenes referenced through [`SceneReferenceOp`] nodes are evaluated
+    /// first.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ProjectError::SceneCycle`] if the scene depends on itself
+    /// through scene references, or any error from a referenced scene.
+    pub fn evaluate_output(&mut self, scene: &str, output: &str) -> Result<Value, ProjectError> {
+        self.evaluate_scene_output(scene, output, &mut Vec::new())
+    }
+
+    fn evaluate_scene_output(
+        &mut self,
+        scene: &str,
+        output: &str,
+        stack: &mut Vec<String>,
+    ) -> Result<Value, ProjectError> {
+        if let Some(start) = stack.iter().position(|name| name == scene) {
+            let mut cycle = stack[start..].to_vec();
+            cycle.push(scene.to_string());
+            return Err(ProjectError::SceneCycle(cycle));
+        }
+
+        stack.push(scene.to_string());
+        self.resolve_references(scene, stack)?;
+        stack.pop();
+
+        let ctx = self.context.clone();
+        let entry = self
+            .scenes
+            .get_mut(scene)
+            .ok_or_else(|| ProjectError::SceneNotFound(scene.to_string()))?;
+        let target = entry.output(output).ok_or_else(|| ProjectError::OutputNotFound {
+            scene: scene.to_string(),
+            output: output.to_string(),
+        })?;
+        let (node, index) = (target.node, target.index);
+        Ok(entry.graph.evaluate(node, index, &ctx)?)
+    }
+
+    /// Evaluate the scenes referenced from `scene` and feed their values
+    /// into the reference nodes
+    fn resolve_references(&mut self, scene: &str, stack: &mut Vec<String>) -> Result<(), ProjectError> {
+        let graph = &self
+            .scenes
+            .get(scene)
+            .ok_or_else(|| ProjectError::SceneNotFound(scene.to_string()))?
+            .graph;
+        let references: Vec<(Id, String, String)> = graph
+            .node_ids()
+            .filter_map(|id| {
+                let op = graph.get(id)?.as_any().downcast_ref::<SceneReferenceOp>()?;
+                Some((id, op.scene().to_string(), op.output().to_string()))
+            })
+            .collect();
+
+        for (node, target_scene, target_output) in references {
+            let value = self.evaluate_scene_output(&target_scene, &target_output, stack)?;
+            if let Some(op) = self
+                .scenes
+                .get_mut(scene)
+                .and_then(|entry| entry.graph.get_mut_as::<SceneReferenceOp>(node))
+            {
+                op.set_value(value);
+            }
+        }
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Scene Reference Operator
+// ============================================================================
+
+/// Outputs the value of a named output of another scene in the [`Project`]
+///
+/// The scene and output names are read from the input defaults; connecting
+/// them has no effect. Outside a project the node outputs its last value.
+pub struct SceneReferenceOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+    value: Value,
+}
+
+impl SceneReferenceOp {
+    /// Reference `output` of `scene`, producing a Float
+    pub fn new(scene: &str, output: &str) -> Self {
+        Self::with_type(scene, output, ValueType::Float)
+    }
+
+    /// Reference `output` of `scene`, producing `value_type`
+    pub fn with_type(scene: &str, output: &str, value_type: ValueType) -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::string("Scene", scene),
+                InputPort::string("Output", output),
+            ],
+            outputs: [OutputPort::new("Value", value_type)],
+            value: value_type.default_value(),
+        }
+    }
+
+    /// Name of the referenced scene
+    pub fn scene(&self) -> &str {
+        self.inputs[0].default.as_string().unwrap_or_default()
+    }
+
+    /// Name of the referenced output
+    pub fn output(&self) -> &str {
+        self.inputs[1].default.as_string().unwrap_or_default()
+    }
+
+    /// Set the value to output, marking the node dirty if it changed
+    pub fn set_value(&mut self, value: Value) {
+        if value != self.value {
+            self.value = value;
+            self.outputs[0].mark_dirty();
+        }
+    }
+
+    pub fn register(registry: &OperatorRegistry) {
+        registry.register(
+            RegistryEntry::new("SceneReference", "Flow", "Value of an output of another scene"),
+            || capture_meta(SceneReferenceOp::default()),
+        );
+    }
+}
+
+impl Default for SceneReferenceOp {
+    fn default() -> Self {
+        Self::new("", "")
+    }
+}
+
+impl Operator for SceneReferenceOp {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn id(&self) -> Id {
+        self.id
+    }
+
+    fn name(&self) -> &'static str {
+        "SceneReference"
+    }
+
+    fn inputs(&self) -> &[InputPort] {
+        &self.inputs
+    }
+
+    fn inputs_mut(&mut self) -> &mut [InputPort] {
+        &mut self.inputs
+    }
+
+    fn outputs(&self) -> &[OutputPort] {
+        &self.outputs
+    }
+
+    fn outputs_mut(&mut self) -> &mut [OutputPort] {
+        &mut self.outputs
+    }
+
+    fn compute(&mut self, _ctx: &EvalContext, _get_input: InputResolver) {
+        self.outputs[0].set(self.value.clone());
+    }
+}
+
+impl OperatorMeta for SceneReferenceOp {
+    fn category(&self) -> &'static str {
+        "Flow"
+    }
+
+    fn category_color(&self) -> [f32; 4] {
+        category_colors::FLOW
+    }
+
+    fn description(&self) -> &'static str {
+        "Value of an output of another scene"
+    }
+
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Scene")),
+            1 => Some(PortMeta::new("Output")),
+            _ => None,
+        }
+    }
+
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_operators::{create_default_registry, AddOp, MultiplyOp};
+
+    fn project() -> Project {
+        Project::new(Arc::new(SymbolRegistry::new()), Arc::new(create_default_registry()))
+    }
+
+    /// Scene "A" publishes `Sum` = 2 + 3
+    fn add_scene_a(project: &mut Project) -> Id {
+        let mut graph = Graph::new();
+        let add = graph.add(AddOp::new());
+        graph.set_input_default(add, 0, Value::Float(2.0));
+        graph.set_input_default(add, 1, Value::Float(3.0));
+        project.add_scene("A", graph);
+        project.expose_output("A", "Sum", add, 0).unwrap();
+        add
+    }
+
+    /// Scene "B" publishes `Result` = A.Sum * 10
+    fn add_scene_b(project: &mut Project) {
+        let mut graph = Graph::new();
+        let reference = graph.add(SceneReferenceOp::new("A", "Sum"));
+        let multiply = graph.add(MultiplyOp::new());
+        graph.connect(reference, 0, multiply, 0).unwrap();
+        graph.set_input_default(multiply, 1, Value::Float(10.0));
+        project.add_scene("B", graph);
+        project.expose_output("B", "Result", multiply, 0).unwrap();
+    }
+
+    #[test]
+    fn test_scene_reads_output_of_other_scene() {
+        let mut project = project();
+        let add = add_scene_a(&mut project);
+        add_scene_b(&mut project);
+        assert_eq!(project.active_scene(), Some("A"));
+
+        project.set_active_scene("B").unwrap();
+        let outputs = project.evaluate_active().unwrap();
+        assert_eq!(outputs, vec![("Result".to_string(), Value::Float(50.0))]);
+
+        // Changes in the referenced scene reach the referencing one
+        project.scene_mut("A").unwrap().set_input_default(add, 1, Value::Float(8.0));
+        assert_eq!(project.evaluate_output("B", "Result").unwrap(), Value::Float(100.0));
+
+        assert!(matches!(
+            project.evaluate_output("B", "Missing"),
+            Err(ProjectError::OutputNotFound { .. })
+        ));
+        assert!(matches!(
+            project.set_active_scene("C"),
+            Err(ProjectError::SceneNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_mutual_scene_references_error() {
+        let mut project = project();
+        let add = add_scene_a(&mut project);
+        add_scene_b(&mut project);
+
+        // A now also depends on B
+        let graph = project.scene_mut("A").unwrap();
+        let reference = graph.add(SceneReferenceOp::new("B", "Result"));
+        graph.connect(reference, 0, add, 1).unwrap();
+
+        match project.evaluate_output("B", "Result") {
+            Err(ProjectError::SceneCycle(cycle)) => assert_eq!(cycle, ["B", "A", "B"]),
+            other => panic!("expected a scene cycle, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_scene_from_graph_def() {
+        use crate::slot_ref::Connection;
+        use crate::symbol::{InputDefinition, OutputDefinition, Symbol, SymbolChild};
+
+        let mut project = project();
+        add_scene_a(&mut project);
+
+        // Root symbol: SceneReference(A.Sum) -> Add.A, with Add.Result promoted as "Out"
+        let symbols = project.symbols().clone();
+        let mut reference = Symbol::new("SceneReference");
+        let scene_input =
+            reference.add_input(InputDefinition::new("Scene", ValueType::String, Value::from("")));
+        let output_input =
+            reference.add_input(InputDefinition::new("Output", ValueType::String, Value::from("")));
+        reference.add_output(OutputDefinition::float("Value"));
+        let reference_id = symbols.register(reference);
+        let mut add = Symbol::new("Add");
+        add.add_input(InputDefinition::float("A", 0.0));
+        let offset_input = add.add_input(InputDefinition::float("B", 0.0));
+        add.add_output(OutputDefinition::float("Result"));
+        let add_id = symbols.register(add);
+
+        let mut root = Symbol::new("Main");
+        let mut reference_child = SymbolChild::new(Id::new(), reference_id);
+        reference_child.set_input_value(scene_input, Value::from("A"));
+        reference_child.set_input_value(output_input, Value::from("Sum"));
+        let reference_child = root.add_child(reference_child);
+        let mut add_child = SymbolChild::new(Id::new(), add_id);
+        add_child.set_input_value(offset_input, Value::Float(1.0));
+        let add_child = root.add_child(add_child);
+        root.add_connection(Connection::simple(reference_child, 0, add_child, 0)).unwrap();
+        root.promote_child_output(&symbols, add_child, 0, "Out").unwrap();
+        let root_id = symbols.register(root);
+
+        let name = project.add_scene_from_def(&GraphDef::new("Main", root_id)).unwrap();
+        assert_eq!(name, "Main");
+        assert_eq!(project.output_names("Main"), ["Out"]);
+        assert_eq!(project.evaluate_output("Main", "Out").unwrap(), Value::Float(6.0));
+
+        assert!(matches!(
+            project.add_scene_from_def(&GraphDef::new("Broken", Id::new())),
+            Err(ProjectError::Symbol(SymbolError::SymbolNotFound(_)))
+        ));
+    }
+
+    #[test]
+    fn test_cues_drive_active_scene() {
+        use crate::cue::Cue;
+        use crate::instance_path::InstancePath;
+
+        let mut project = project();
+        let add = add_scene_a(&mut project);
+        let cue = Cue::new("boost")
+            .with_duration(1.0)
+            .target(InstancePath::root(add), 1, Value::Float(13.0));
+
+        // Cues travel with the project file
+        let mut file = ProjectFile::new("Show");
+        file.cues.push(cue.clone());
+        let json = serialization::save_project_str(&file).unwrap();
+        let restored = serialization::load_project_str(&json).unwrap();
+        assert_eq!(restored.cues, vec![cue.clone()]);
+
+        project.cues_mut().add(cue);
+        project.cues_mut().fire("boost").unwrap();
+        project.update_cues();
+        project.context_mut().advance(0.5);
+        project.update_cues();
+        assert_eq!(project.evaluate_output("A", "Sum").unwrap(), Value::Float(10.0));
+
+        project.context_mut().advance(0.5);
+        project.update_cues();
+        assert_eq!(project.evaluate_output("A", "Sum").unwrap(), Value::Float(15.0));
+        assert!(!project.cues().is_active());
+    }
+}
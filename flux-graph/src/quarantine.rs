@@ -0,0 +1,50 @@
+//! Surviving operator panics
+//!
+//! By default a panic in [`Operator::compute`](flux_core::Operator::compute)
+//! unwinds out of [`Graph::evaluate`](crate::Graph::evaluate), which is what
+//! you want while debugging an operator. Hosts that run third-party
+//! operators can switch to [`PanicPolicy::Catch`] with
+//! [`Graph::set_panic_policy`](crate::Graph::set_panic_policy): a panicking
+//! node then keeps its previously cached outputs (type defaults if it never
+//! computed), reports [`GraphEvent::NodePanicked`](crate::GraphEvent::NodePanicked)
+//! and an [`OperatorError`](flux_core::OperatorError), and is quarantined.
+//! Quarantined nodes are skipped on later evaluations until
+//! [`Graph::clear_quarantine`](crate::Graph::clear_quarantine) is called, so
+//! a node that panics every frame is reported once.
+//!
+//! Catching relies on operators tolerating being called again after a panic
+//! left them half-updated; a quarantined operator is only computed again
+//! once the host clears it. The process-wide panic hook still runs, so the
+//! message is printed as usual.
+
+/// What evaluation does when an operator panics
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PanicPolicy {
+    /// Let the panic unwind out of evaluation
+    #[default]
+    Propagate,
+    /// Catch the panic and quarantine the node
+    Catch,
+}
+
+/// Record of a caught operator panic
+#[derive(Clone, Debug, PartialEq)]
+pub struct NodePanic {
+    /// Operator name of the node
+    pub operator: &'static str,
+    /// Panic message (`"<non-string panic payload>"` if it wasn't a string)
+    pub message: String,
+    /// Frame of the evaluation that panicked
+    pub frame: u64,
+}
+
+/// Message carried by a panic payload.
+pub(crate) fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "<non-string panic payload>".to_string()
+    }
+}
@@ -0,0 +1,431 @@
+//! Node search for "find node in project"
+//!
+//! A [`NodeIndex`] holds the searchable text of every node in a graph: the
+//! operator name, an optional user label, the operator's registry category
+//! and description, and the current defaults of String-typed inputs (so a
+//! query for `udp://` finds the node configured with that address).
+//!
+//! The index is built once and then kept current by feeding it the graph's
+//! [`GraphEvent`]s with [`NodeIndex::apply_event`]; [`NodeIndex::rebuild`]
+//! re-reads everything from scratch.
+//!
+//! ```ignore
+//! let mut index = NodeIndex::new(&graph, &registry);
+//!
+//! // ...edit the graph...
+//! for event in graph.drain_events().collect::<Vec<_>>() {
+//!     index.apply_event(&graph, &event);
+//! }
+//!
+//! for found in index.find("osc") {
+//!     println!("{:?} matched on {:?} ({})", found.node, found.field, found.score);
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use flux_core::{Id, Value};
+use flux_operators::{ExtendedEntry, OperatorRegistry};
+
+use crate::associated::AssociatedGraph;
+use crate::graph::{Graph, GraphEvent};
+
+/// The part of a node that matched a query
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchField {
+    /// Operator name
+    Name,
+    /// User-assigned label
+    Label,
+    /// Registry category of the operator
+    Category,
+    /// Registry description of the operator
+    Description,
+    /// Current default of a String-typed input
+    InputValue { input: usize },
+}
+
+impl MatchField {
+    /// Relevance bonus for matches in this field
+    fn weight(self) -> u32 {
+        match self {
+            MatchField::Label => 40,
+            MatchField::Name => 30,
+            MatchField::InputValue { .. } => 20,
+            MatchField::Category => 10,
+            MatchField::Description => 0,
+        }
+    }
+}
+
+/// A node found by [`NodeIndex::find`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NodeMatch {
+    pub node: Id,
+    /// Best-scoring field of the node
+    pub field: MatchField,
+    /// Relevance; higher is better
+    pub score: u32,
+}
+
+/// Registry metadata for one operator name
+#[derive(Debug, Clone, Default)]
+struct OperatorInfo {
+    category: String,
+    description: String,
+}
+
+/// Searchable text of one node
+#[derive(Debug, Clone, PartialEq)]
+struct IndexEntry {
+    name: &'static str,
+    label: Option<String>,
+    category: String,
+    description: String,
+    /// (input index, lowercased default) for String-typed inputs
+    strings: Vec<(usize, String)>,
+}
+
+impl IndexEntry {
+    /// Best match of `query` (lowercase) in this entry, if any
+    fn best_match(&self, query: &str) -> Option<(MatchField, u32)> {
+        let mut fields = vec![
+            (MatchField::Name, self.name.to_lowercase()),
+            (MatchField::Category, self.category.to_lowercase()),
+            (MatchField::Description, self.description.to_lowercase()),
+        ];
+        if let Some(label) = &self.label {
+            fields.push((MatchField::Label, label.to_lowercase()));
+        }
+        for (input, text) in &self.strings {
+            fields.push((MatchField::InputValue { input: *input }, text.clone()));
+        }
+
+        fields
+            .into_iter()
+            .filter_map(|(field, text)| {
+                let quality = if text == query {
+                    300
+                } else if text.starts_with(query) {
+                    200
+                } else if text.contains(query) {
+                    100
+                } else {
+                    return None;
+                };
+                Some((field, quality + field.weight()))
+            })
+            .max_by_key(|&(_, score)| score)
+    }
+}
+
+/// Search index over the nodes of a graph
+#[derive(Debug, Clone, Default)]
+pub struct NodeIndex {
+    entries: HashMap<Id, IndexEntry>,
+    labels: HashMap<Id, String>,
+    operators: HashMap<&'static str, OperatorInfo>,
+}
+
+impl NodeIndex {
+    /// Index every node of `graph`, looking up operator metadata in `registry`.
+    pub fn new(graph: &Graph, registry: &OperatorRegistry) -> Self {
+        Self::with_labels(graph, registry, HashMap::new())
+    }
+
+    /// Index every node of `graph` with user labels for some nodes.
+    pub fn with_labels(
+        graph: &Graph,
+        registry: &OperatorRegistry,
+        labels: HashMap<Id, String>,
+    ) -> Self {
+        let operators = registry
+            .list_all_including_deprecated()
+            .into_iter()
+            .map(|ExtendedEntry { meta: entry, .. }| {
+                let info = OperatorInfo {
+                    category: entry.category.to_string(),
+                    description: entry.description.to_string(),
+                };
+                (entry.name, info)
+            })
+            .collect();
+        let mut index = Self { entries: HashMap::new(), labels, operators };
+        index.rebuild(graph);
+        index
+    }
+
+    /// Index an [`AssociatedGraph`], labeling each node with its external ID.
+    pub fn from_associated<E>(graph: &AssociatedGraph<E>, registry: &OperatorRegistry) -> Self
+    where
+        E: Copy + Eq + std::hash::Hash + std::fmt::Debug,
+    {
+        let labels = graph
+            .handles()
+            .map(|handle| (handle.flux_id, format!("{:?}", handle.external_id)))
+            .collect();
+        Self::with_labels(graph.inner(), registry, labels)
+    }
+
+    /// Re-read every node of `graph`, discarding the current entries.
+    pub fn rebuild(&mut self, graph: &Graph) {
+        self.entries.clear();
+        for id in graph.node_ids() {
+            self.index_node(graph, id);
+        }
+    }
+
+    /// Update the index for one event from [`Graph::drain_events`].
+    ///
+    /// Events that don't affect searchable text are ignored; after
+    /// [`GraphEvent::EventsDropped`] the whole index is rebuilt.
+    pub fn apply_event(&mut self, graph: &Graph, event: &GraphEvent) {
+        match event {
+            GraphEvent::EventsDropped { .. } => self.rebuild(graph),
+            GraphEvent::NodeAdded { id } => self.index_node(graph, *id),
+            GraphEvent::NodeRemoved { id, .. } => {
+                self.entries.remove(id);
+                self.labels.remove(id);
+            }
+            GraphEvent::InputDefaultChanged { node, .. } => self.index_node(graph, *node),
+            _ => {}
+        }
+    }
+
+    /// Set or clear the user label of a node.
+    pub fn set_label(&mut self, node: Id, label: Option<String>) {
+        match &label {
+            Some(label) => self.labels.insert(node, label.clone()),
+            None => self.labels.remove(&node),
+        };
+        if let Some(entry) = self.entries.get_mut(&node) {
+            entry.label = label;
+        }
+    }
+
+    /// Number of indexed nodes
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no nodes are indexed
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Find nodes matching `query`, case-insensitively.
+    ///
+    /// Each node appears at most once, with its best-scoring field. Exact
+    /// matches rank above prefix matches, which rank above substring
+    /// matches; within each, labels rank highest and descriptions lowest.
+    /// An empty query matches nothing.
+    pub fn find(&self, query: &str) -> Vec<NodeMatch> {
+        let query = query.trim().to_lowercase();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let mut matches: Vec<NodeMatch> = self
+            .entries
+            .iter()
+            .filter_map(|(&node, entry)| {
+                entry
+                    .best_match(&query)
+                    .map(|(field, score)| NodeMatch { node, field, score })
+            })
+            .collect();
+        matches.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.node.as_uuid().cmp(b.node.as_uuid()))
+        });
+        matches
+    }
+
+    fn index_node(&mut self, graph: &Graph, id: Id) {
+        let Some(op) = graph.get(id) else {
+            self.entries.remove(&id);
+            return;
+        };
+        let name = op.name();
+        let info = self.operators.get(name).cloned().unwrap_or_default();
+        let strings = op
+            .inputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, input)| match &input.default {
+                Value::String(text) => Some((index, text.to_lowercase())),
+                _ => None,
+            })
+            .collect();
+        let entry = IndexEntry {
+            name,
+            label: self.labels.get(&id).cloned(),
+            category: info.category,
+            description: info.description,
+            strings,
+        };
+        self.entries.insert(id, entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::{EvalContext, InputPort, InputResolver, Operator, OutputPort};
+    use flux_operators::create_default_registry;
+    use std::any::Any;
+
+    struct SenderOp {
+        id: Id,
+        inputs: [InputPort; 2],
+        outputs: [OutputPort; 1],
+    }
+
+    impl SenderOp {
+        fn new(address: &str) -> Self {
+            Self {
+                id: Id::new(),
+                inputs: [
+                    InputPort::string("Address", address),
+                    InputPort::float("Value", 0.0),
+                ],
+                outputs: [OutputPort::float("Sent")],
+            }
+        }
+    }
+
+    impl Operator for SenderOp {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "Sender"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: InputResolver) {}
+    }
+
+    fn registry() -> OperatorRegistry {
+        let registry = create_default_registry();
+        registry.register_simple("Sender", || Box::new(SenderOp::new("")));
+        registry
+    }
+
+    /// 50 nodes: 20 constants, 20 adds, 10 senders with distinct addresses
+    fn synthetic_graph(registry: &OperatorRegistry) -> (Graph, Vec<Id>) {
+        let mut graph = Graph::new();
+        let mut ids = Vec::new();
+        for i in 0..50 {
+            let op = match i % 5 {
+                0 | 1 => registry.create_by_name("Constant").unwrap(),
+                2 | 3 => registry.create_by_name("Add").unwrap(),
+                _ => Box::new(SenderOp::new(&format!("udp://10.0.0.{}:9000", i))),
+            };
+            ids.push(graph.add_boxed(op));
+        }
+        (graph, ids)
+    }
+
+    fn sorted(mut matches: Vec<NodeMatch>) -> Vec<(Id, MatchField, u32)> {
+        matches.sort_by(|a, b| a.node.as_uuid().cmp(b.node.as_uuid()));
+        matches.into_iter().map(|m| (m.node, m.field, m.score)).collect()
+    }
+
+    #[test]
+    fn test_find_by_every_field() {
+        let registry = registry();
+        let (graph, ids) = synthetic_graph(&registry);
+        let add_meta = registry.get_extended_meta_by_name("Add").unwrap().meta;
+        let mut labels = HashMap::new();
+        labels.insert(ids[0], "Master Gain".to_string());
+        let index = NodeIndex::with_labels(&graph, &registry, labels);
+        assert_eq!(index.len(), 50);
+
+        let found = index.find("sender");
+        assert_eq!(found.len(), 10);
+        assert!(found.iter().all(|m| m.field == MatchField::Name));
+
+        let found = index.find("master");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].node, ids[0]);
+        assert_eq!(found[0].field, MatchField::Label);
+
+        let found = index.find(add_meta.category);
+        assert!(found.iter().any(|m| m.field == MatchField::Category));
+
+        let found = index.find(add_meta.description);
+        assert!(found.iter().any(|m| m.field == MatchField::Description));
+
+        let found = index.find("UDP://");
+        assert_eq!(found.len(), 10);
+        assert!(found.iter().all(|m| m.field == MatchField::InputValue { input: 0 }));
+
+        let found = index.find("udp://10.0.0.9:9000");
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].node, ids[9]);
+
+        assert!(index.find("").is_empty());
+        assert!(index.find("no such thing").is_empty());
+    }
+
+    #[test]
+    fn test_exact_match_ranks_first() {
+        let registry = registry();
+        let (graph, ids) = synthetic_graph(&registry);
+        let mut index = NodeIndex::new(&graph, &registry);
+        index.set_label(ids[2], Some("add".to_string()));
+
+        let found = index.find("add");
+        assert_eq!(found[0].node, ids[2]);
+        assert_eq!(found[0].field, MatchField::Label);
+        assert!(found.windows(2).all(|w| w[0].score >= w[1].score));
+    }
+
+    #[test]
+    fn test_incremental_updates_match_rebuild() {
+        let registry = registry();
+        let (mut graph, ids) = synthetic_graph(&registry);
+        let mut index = NodeIndex::new(&graph, &registry);
+        graph.clear_events();
+
+        graph.remove(ids[4]);
+        graph.remove(ids[0]);
+        graph.set_input_default(ids[9], 0, Value::String("osc://studio".into()));
+        let added = graph.add(SenderOp::new("udp://192.168.1.20:7000"));
+        graph.connect(ids[1], 0, ids[2], 0).unwrap();
+
+        let events: Vec<_> = graph.drain_events().collect();
+        for event in &events {
+            index.apply_event(&graph, event);
+        }
+
+        let mut rebuilt = index.clone();
+        rebuilt.rebuild(&graph);
+        for query in ["sender", "udp://", "osc://", "add", "constant", "192.168"] {
+            assert_eq!(sorted(index.find(query)), sorted(rebuilt.find(query)), "{}", query);
+        }
+
+        assert_eq!(index.len(), 49);
+        assert_eq!(index.find("osc://")[0].node, ids[9]);
+        assert!(index.find("192.168").iter().any(|m| m.node == added));
+        assert!(index.find("udp://").iter().all(|m| m.node != ids[4]));
+    }
+}
@@ -7,6 +7,8 @@ use serde::{Deserialize, Serialize};
 
 use flux_core::Id;
 
+use crate::animation::{AnimationTarget, Curve, CurveBinding, Interpolation, Keyframe};
+
 /// Animation definition for a single input
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AnimationDef {
@@ -14,6 +16,9 @@ pub struct AnimationDef {
     pub target_child: Id,
     /// Target input index
     pub target_input: usize,
+    /// Target input name at save time, resolved before the index on load
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_input_name: Option<String>,
     /// Animation curve
     pub curve: CurveDef,
 }
@@ -24,15 +29,30 @@ impl AnimationDef {
         Self {
             target_child,
             target_input,
+            target_input_name: None,
             curve: CurveDef::new(),
         }
     }
 
+    /// Builder: record the target input's name
+    pub fn with_target_name(mut self, name: &str) -> Self {
+        self.target_input_name = Some(name.to_string());
+        self
+    }
+
     /// Add a keyframe to the curve
     pub fn add_keyframe(&mut self, keyframe: KeyframeDef) -> &mut Self {
         self.curve.add_keyframe(keyframe);
         self
     }
+
+    /// Runtime binding of the curve to its target child input
+    ///
+    /// The target's node ID is the child ID; the input is taken by index.
+    pub fn to_binding(&self) -> CurveBinding {
+        let target = AnimationTarget::new(self.target_child, self.target_input);
+        CurveBinding::new(self.curve.to_curve(), target)
+    }
 }
 
 /// Animation curve definition
@@ -76,6 +96,57 @@ impl CurveDef {
             self.keyframes.last().unwrap().time,
         ))
     }
+
+    /// Build the runtime curve
+    ///
+    /// Bezier keyframes keep their tangent slopes (weights are dropped) and
+    /// smooth keyframes get Catmull-Rom tangents. Extrapolation is not part
+    /// of a [`Curve`]; see [`SymbolDef::animator`](super::SymbolDef::animator).
+    pub fn to_curve(&self) -> Curve {
+        let point = |key: &KeyframeDef| Keyframe::new(key.time, key.value);
+        let slope = |tangent: &Option<TangentDef>| tangent.as_ref().map_or(0.0, |t| t.value);
+        let keyframes = self
+            .keyframes
+            .iter()
+            .enumerate()
+            .map(|(i, key)| match key.interpolation {
+                InterpolationMode::Constant => Keyframe::constant(key.time, key.value),
+                InterpolationMode::Linear => point(key),
+                InterpolationMode::Bezier => {
+                    let (in_slope, out_slope) = (slope(&key.in_tangent), slope(&key.out_tangent));
+                    Keyframe::spline(key.time, key.value, in_slope, out_slope)
+                }
+                InterpolationMode::Smooth => {
+                    let prev = i.checked_sub(1).map(|i| point(&self.keyframes[i]));
+                    let next = self.keyframes.get(i + 1).map(point);
+                    let tangent = Keyframe::auto_tangent(prev.as_ref(), &point(key), next.as_ref());
+                    Keyframe::spline(key.time, key.value, tangent, tangent)
+                }
+            })
+            .collect();
+        Curve::from_keyframes(keyframes)
+    }
+
+    /// Describe a runtime curve
+    ///
+    /// Each keyframe is saved with its outgoing interpolation; spline
+    /// keyframes become Bezier keyframes with their tangent slopes, so
+    /// [`to_curve`](Self::to_curve) gives back the same curve. The curve's
+    /// name is not saved.
+    pub fn from_curve(curve: &Curve) -> Self {
+        let mut def = Self::new();
+        for key in curve.keyframes() {
+            let keyframe = KeyframeDef::new(key.time, key.value);
+            def.add_keyframe(match key.out_type {
+                Interpolation::Constant => keyframe.with_interpolation(InterpolationMode::Constant),
+                Interpolation::Linear => keyframe,
+                Interpolation::Spline => keyframe
+                    .with_interpolation(InterpolationMode::Bezier)
+                    .with_tangents(key.in_tangent, key.out_tangent),
+            });
+        }
+        def
+    }
 }
 
 impl Default for CurveDef {
@@ -247,6 +318,50 @@ mod tests {
         assert_eq!(anim.curve.keyframes.len(), 2);
     }
 
+    #[test]
+    fn test_curve_def_to_curve() {
+        let mut curve = CurveDef::new();
+        let key = |time: f64, mode| KeyframeDef::new(time, time * 10.0).with_interpolation(mode);
+        curve.add_keyframe(key(0.0, InterpolationMode::Constant));
+        curve.add_keyframe(key(1.0, InterpolationMode::Linear));
+        curve.add_keyframe(key(2.0, InterpolationMode::Smooth));
+        curve.add_keyframe(KeyframeDef::new(3.0, 30.0));
+
+        let mut runtime = curve.to_curve();
+        assert_eq!(runtime.len(), 4);
+        assert_eq!(runtime.sample(0.5), 0.0);
+        assert_eq!(runtime.sample(1.5), 15.0);
+        // Smooth keys get the slope of their neighbors
+        let smooth = &runtime.keyframes()[2];
+        assert_eq!((smooth.in_tangent, smooth.out_tangent), (10.0, 10.0));
+    }
+
+    #[test]
+    fn test_curve_def_from_curve() {
+        let mut curve = Curve::new();
+        curve.add_constant(0.0, 1.0);
+        curve.add(1.0, 2.0);
+        curve.add_spline(2.0, 0.0, -1.0, 0.5);
+        curve.add(3.0, 4.0);
+
+        let def = CurveDef::from_curve(&curve);
+        let modes: Vec<_> = def.keyframes.iter().map(|k| k.interpolation).collect();
+        assert_eq!(
+            modes,
+            [
+                InterpolationMode::Constant,
+                InterpolationMode::Linear,
+                InterpolationMode::Bezier,
+                InterpolationMode::Linear,
+            ]
+        );
+
+        let mut restored = def.to_curve();
+        for time in [0.5, 1.5, 2.25, 2.75, 3.5] {
+            assert_eq!(restored.sample(time), curve.sample(time));
+        }
+    }
+
     #[test]
     fn test_animation_serialize() {
         let child_id = Id::new();
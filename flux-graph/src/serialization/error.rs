@@ -1,7 +1,10 @@
 //! Serialization error types
 
+use flux_core::value::ValueType;
 use thiserror::Error;
 
+use super::report::LoadReport;
+
 /// Errors that can occur during serialization/deserialization
 #[derive(Error, Debug)]
 pub enum SerializationError {
@@ -48,6 +51,26 @@ pub enum SerializationError {
     /// File too large to load
     #[error("File too large: {size} bytes exceeds maximum of {max_size} bytes")]
     FileTooLarge { size: u64, max_size: u64 },
+
+    /// A template parameter without a default was not given
+    #[error("Missing template parameter: {0}")]
+    MissingTemplateParam(String),
+
+    /// A template parameter was given or referenced but isn't declared
+    #[error("Unknown template parameter: {0}")]
+    UnknownTemplateParam(String),
+
+    /// A template parameter value doesn't match its declared type
+    #[error("Template parameter {name}: expected {expected}, got {actual}")]
+    TemplateParamType {
+        name: String,
+        expected: ValueType,
+        actual: ValueType,
+    },
+
+    /// A strict load found problems it would otherwise have recovered from
+    #[error("Load rejected in strict mode:\n{0}")]
+    LoadRejected(LoadReport),
 }
 
 /// Result type for serialization operations
@@ -3,12 +3,19 @@
 //! Graphs represent compositions - instances of symbols with specific
 //! configuration and playback settings.
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use flux_core::value::Value;
 use flux_core::Id;
 
+use super::animation::CurveDef;
 use super::version::SchemaVersion;
+use crate::animation::{AnimationTarget, Animator, CurveBinding};
+use crate::graph::Graph;
+use crate::group::GroupId;
+use crate::playback::PlaybackSettings;
 
 /// Graph file schema (.rgraph)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,9 +58,13 @@ pub struct GraphDef {
     #[serde(default)]
     pub playback: PlaybackDef,
 
-    /// View/camera state (for 3D graphs)
+    /// View/camera state (for 3D graphs) and node positions
     #[serde(default)]
     pub view: ViewDef,
+
+    /// Node groups, with members stored by node (child) ID
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<GroupDef>,
 }
 
 impl GraphDef {
@@ -67,6 +78,7 @@ impl GraphDef {
             instance_overrides: Vec::new(),
             playback: PlaybackDef::default(),
             view: ViewDef::default(),
+            groups: Vec::new(),
         }
     }
 
@@ -81,6 +93,88 @@ impl GraphDef {
         self.instance_overrides.push(override_def);
         self
     }
+
+    /// Builder: record every group of a graph, replacing any saved ones
+    pub fn with_groups_from(mut self, graph: &Graph) -> Self {
+        self.groups = graph
+            .groups()
+            .map(|(_, group)| GroupDef {
+                name: group.name().to_string(),
+                color: group.color(),
+                members: group.members().to_vec(),
+            })
+            .collect();
+        self
+    }
+
+    /// Builder: record the editor position of every positioned node,
+    /// replacing any saved ones
+    pub fn with_positions_from(mut self, graph: &Graph) -> Self {
+        self.view.node_positions = graph
+            .node_ids_in_insertion_order()
+            .into_iter()
+            .filter_map(|node| {
+                let position = graph.node_position(node)?;
+                Some(NodePositionDef { node, position })
+            })
+            .collect();
+        self
+    }
+
+    /// Set the saved node positions in a graph
+    ///
+    /// `id_map` is used as in [`restore_groups`](Self::restore_groups);
+    /// nodes that aren't in the graph are skipped. Returns the number of
+    /// nodes positioned.
+    pub fn restore_positions(&self, graph: &mut Graph, id_map: &HashMap<Id, Id>) -> usize {
+        self.view
+            .node_positions
+            .iter()
+            .filter(|def| {
+                let node = id_map.get(&def.node).copied().unwrap_or(def.node);
+                graph.set_node_position(node, def.position)
+            })
+            .count()
+    }
+
+    /// Create the saved groups in a graph
+    ///
+    /// `id_map` maps saved node IDs to the nodes they were loaded as (see
+    /// [`InstantiateResult::id_map`](super::InstantiateResult::id_map)); IDs
+    /// it doesn't mention are looked up as-is. Members that aren't in the
+    /// graph are skipped. Returns the new groups in saved order.
+    pub fn restore_groups(&self, graph: &mut Graph, id_map: &HashMap<Id, Id>) -> Vec<GroupId> {
+        self.groups
+            .iter()
+            .map(|def| {
+                let group = graph.create_group(def.name.clone(), def.color);
+                for member in &def.members {
+                    let node = id_map.get(member).copied().unwrap_or(*member);
+                    let _ = graph.assign_to_group(node, group);
+                }
+                group
+            })
+            .collect()
+    }
+}
+
+/// A saved node group (see [`crate::group`])
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GroupDef {
+    pub name: String,
+    /// Frame color (RGBA)
+    pub color: [f32; 4],
+    /// Member node IDs in assignment order
+    #[serde(default)]
+    pub members: Vec<Id>,
+}
+
+/// A saved editor position (see [`Graph::set_node_position`])
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct NodePositionDef {
+    /// Node (child) ID
+    pub node: Id,
+    pub position: [f32; 2],
 }
 
 /// Override for a specific instance in the graph hierarchy
@@ -94,6 +188,9 @@ pub struct InstanceOverride {
     /// Port UI metadata overrides (ranges, labels, etc.)
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub port_ui_overrides: Vec<PortUiOverride>,
+    /// Changes to the symbol's default animation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub animations: Vec<AnimationOverride>,
 }
 
 impl InstanceOverride {
@@ -103,6 +200,7 @@ impl InstanceOverride {
             path: path.to_string(),
             inputs: Vec::new(),
             port_ui_overrides: Vec::new(),
+            animations: Vec::new(),
         }
     }
 
@@ -117,6 +215,63 @@ impl InstanceOverride {
         self.port_ui_overrides.push(port_ui);
         self
     }
+
+    /// Add an animation override
+    pub fn with_animation(mut self, animation: AnimationOverride) -> Self {
+        self.animations.push(animation);
+        self
+    }
+}
+
+/// Per-instance change to a symbol's default animation of a child input
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnimationOverride {
+    /// Target child ID
+    pub target_child: Id,
+    /// Target input index
+    pub target_input: usize,
+    /// Stop animating the input; it keeps the value it last received
+    #[serde(default)]
+    pub disabled: bool,
+    /// Curve replacing the symbol's one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub curve: Option<CurveDef>,
+}
+
+impl AnimationOverride {
+    /// Turn off the animation of a child input
+    pub fn disable(target_child: Id, target_input: usize) -> Self {
+        Self { target_child, target_input, disabled: true, curve: None }
+    }
+
+    /// Animate a child input with a different curve
+    pub fn retarget(target_child: Id, target_input: usize, curve: CurveDef) -> Self {
+        Self { target_child, target_input, disabled: false, curve: Some(curve) }
+    }
+
+    /// Apply to an instance's animator, where the child input is `target`
+    ///
+    /// A curve for an input the symbol doesn't animate adds a binding.
+    /// Returns false if there was nothing to apply to.
+    pub fn apply(&self, animator: &mut Animator, target: AnimationTarget) -> bool {
+        let (node_id, input_index) = (target.node_id, target.input_index);
+        if let Some(binding) = animator.get_binding_mut(node_id, input_index) {
+            if let Some(curve) = &self.curve {
+                binding.curve = curve.to_curve();
+            }
+            binding.enabled = !self.disabled;
+            return true;
+        }
+        match &self.curve {
+            Some(curve) => {
+                let mut binding = CurveBinding::new(curve.to_curve(), target);
+                binding.enabled = !self.disabled;
+                animator.add_binding(binding);
+                true
+            }
+            None => false,
+        }
+    }
 }
 
 /// Input value override
@@ -148,6 +303,12 @@ pub struct PortUiOverride {
     /// Custom step size (None = auto)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub step: Option<f32>,
+    /// Smoothing duration in seconds for default changes (None = instant)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub smoothing_seconds: Option<f32>,
+    /// Enforce range and step during evaluation (None = use operator default)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub clamp_to_range: Option<bool>,
 }
 
 impl PortUiOverride {
@@ -159,6 +320,8 @@ impl PortUiOverride {
             label: None,
             unit: None,
             step: None,
+            smoothing_seconds: None,
+            clamp_to_range: None,
         }
     }
 
@@ -186,6 +349,18 @@ impl PortUiOverride {
         self
     }
 
+    /// Builder: set smoothing duration
+    pub fn with_smoothing(mut self, seconds: f32) -> Self {
+        self.smoothing_seconds = Some(seconds);
+        self
+    }
+
+    /// Builder: enable or disable range enforcement
+    pub fn with_clamp(mut self, clamp: bool) -> Self {
+        self.clamp_to_range = Some(clamp);
+        self
+    }
+
     /// Convert from runtime PortOverride
     pub fn from_port_override(port_index: usize, override_: &flux_core::PortOverride) -> Self {
         Self {
@@ -194,6 +369,8 @@ impl PortUiOverride {
             label: override_.label.clone(),
             unit: override_.unit.clone(),
             step: override_.step,
+            smoothing_seconds: override_.smoothing_seconds,
+            clamp_to_range: override_.clamp_to_range,
         }
     }
 
@@ -204,12 +381,19 @@ impl PortUiOverride {
             label: self.label.clone(),
             unit: self.unit.clone(),
             step: self.step,
+            smoothing_seconds: self.smoothing_seconds,
+            clamp_to_range: self.clamp_to_range,
         }
     }
 
     /// Returns true if all override fields are None
     pub fn is_empty(&self) -> bool {
-        self.range.is_none() && self.label.is_none() && self.unit.is_none() && self.step.is_none()
+        self.range.is_none()
+            && self.label.is_none()
+            && self.unit.is_none()
+            && self.step.is_none()
+            && self.smoothing_seconds.is_none()
+            && self.clamp_to_range.is_none()
     }
 }
 
@@ -245,7 +429,33 @@ impl Default for PlaybackDef {
     }
 }
 
-/// Camera/view settings for the graph
+impl PlaybackDef {
+    /// Describe the tempo and loop range of playback settings
+    ///
+    /// Only the base tempo is saved; tempo changes, audio and the playback
+    /// state are not part of a graph file.
+    pub fn from_settings(settings: &PlaybackSettings) -> Self {
+        Self {
+            bpm: settings.bpm,
+            start_time: settings.loop_start,
+            end_time: settings.loop_end,
+            loop_enabled: settings.loop_playback,
+        }
+    }
+
+    /// Build playback settings with this tempo and loop range
+    pub fn to_settings(&self) -> PlaybackSettings {
+        PlaybackSettings {
+            bpm: self.bpm,
+            loop_playback: self.loop_enabled,
+            loop_start: self.start_time,
+            loop_end: self.end_time,
+            ..PlaybackSettings::default()
+        }
+    }
+}
+
+/// Camera/view settings and node layout for the graph
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ViewDef {
     /// Camera position
@@ -257,6 +467,9 @@ pub struct ViewDef {
     /// Field of view in degrees
     #[serde(default = "default_fov")]
     pub fov: f32,
+    /// Editor positions of the nodes that have one, in insertion order
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub node_positions: Vec<NodePositionDef>,
 }
 
 fn default_camera_position() -> [f32; 3] {
@@ -277,6 +490,7 @@ impl Default for ViewDef {
             camera_position: default_camera_position(),
             camera_target: default_camera_target(),
             fov: default_fov(),
+            node_positions: Vec::new(),
         }
     }
 }
@@ -302,6 +516,19 @@ mod tests {
         assert!(playback.loop_enabled);
     }
 
+    #[test]
+    fn test_playback_def_settings_roundtrip() {
+        let mut settings = PlaybackSettings { bpm: 96.0, ..PlaybackSettings::default() };
+        settings.set_loop_range(2.0, 6.0);
+
+        let def = PlaybackDef::from_settings(&settings);
+        assert_eq!((def.bpm, def.start_time, def.end_time), (96.0, 2.0, 6.0));
+        let restored = def.to_settings();
+        assert_eq!(restored.bpm, 96.0);
+        assert!(restored.loop_playback);
+        assert_eq!((restored.loop_start, restored.loop_end), (2.0, 6.0));
+    }
+
     #[test]
     fn test_view_def_default() {
         let view = ViewDef::default();
@@ -373,6 +600,24 @@ mod tests {
         assert_eq!(back.label, runtime.label);
     }
 
+    #[test]
+    fn test_port_ui_override_smoothing_roundtrip() {
+        let runtime = flux_core::PortOverride::new().with_smoothing(0.25);
+        let serialized = PortUiOverride::from_port_override(1, &runtime);
+        assert!(!serialized.is_empty());
+
+        let json = serde_json::to_string(&serialized).unwrap();
+        assert!(json.contains("smoothing_seconds"));
+
+        let restored: PortUiOverride = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.to_port_override().smoothing_seconds, Some(0.25));
+
+        // Older files without the field still load
+        let legacy: PortUiOverride = serde_json::from_str(r#"{"port_index":0}"#).unwrap();
+        assert_eq!(legacy.smoothing_seconds, None);
+        assert_eq!(legacy.clamp_to_range, None);
+    }
+
     #[test]
     fn test_instance_override_with_port_ui() {
         let input_id = Id::new();
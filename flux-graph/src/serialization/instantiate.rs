@@ -0,0 +1,1051 @@
+//! Saving graphs as symbol definitions and loading them back
+//!
+//! [`SymbolDef::from_graph`] records every node as a `builtin:<Operator>`
+//! child, with changed input defaults and connections stored by port index
+//! and port name. [`SymbolDef::instantiate`] rebuilds the nodes through an
+//! [`OperatorRegistry`], resolving each port by name first so saved graphs
+//! survive operators gaining or reordering ports. Children the registry
+//! can't create are loaded as [`MissingOp`] placeholders, which save back
+//! unchanged. Everything recovered from is collected in a [`LoadReport`].
+//!
+//! ```ignore
+//! let def = SymbolDef::from_graph("Scene", &graph, &registry);
+//! let json = save_symbol_str(&SymbolFile::from_def(def))?;
+//!
+//! // ... later, against a newer operator library
+//! let file = load_symbol_str(&json)?;
+//! let result = file.symbol.instantiate(&mut graph, &registry);
+//! println!("{}", result.report);
+//! ```
+
+use std::collections::HashMap;
+
+use flux_core::id::Id;
+use flux_core::operator::Operator;
+use flux_core::value::Value;
+use flux_operators::OperatorRegistry;
+
+use super::animation::{AnimationDef, CurveDef, ExtrapolationMode};
+use super::error::Result;
+use super::ports::{resolve_port, PortDiagnostic, PortDirection};
+use super::report::{LoadIssue, LoadMode, LoadReport};
+use super::symbol::{ChildDef, ConnectionDef, SymbolDef};
+use crate::animation::{AnimationTarget, Animator, CurveBinding, LoopMode};
+use crate::clipboard;
+use crate::graph::Graph;
+use crate::missing::MissingOp;
+
+/// Prefix of child symbol references that name a registry operator
+const BUILTIN_PREFIX: &str = "builtin:";
+
+/// Outcome of [`SymbolDef::instantiate`]
+#[derive(Debug, Clone, Default)]
+pub struct InstantiateResult {
+    /// Saved child ID -> newly created node ID
+    pub id_map: HashMap<Id, Id>,
+    /// Placeholder nodes created for children that could not be created
+    pub placeholders: Vec<Id>,
+    /// Connections that could not be restored
+    pub dropped_connections: Vec<ConnectionDef>,
+    /// Resolved target of each animation, in `SymbolDef::animations` order
+    pub animation_targets: Vec<Option<AnimationTarget>>,
+    /// Ports whose saved name and index disagreed with the operator
+    pub diagnostics: Vec<PortDiagnostic>,
+    /// Every problem recovered from, in the order found
+    pub report: LoadReport,
+}
+
+impl InstantiateResult {
+    /// Returns true if everything was restored without port mismatches
+    pub fn is_clean(&self) -> bool {
+        self.report.is_clean()
+    }
+}
+
+impl SymbolDef {
+    /// Describe a graph's nodes and connections as a symbol definition
+    ///
+    /// Only modified input defaults (see [`Graph::modified_inputs`]) are
+    /// stored. Auto-inserted conversion nodes are left out; connections
+    /// through them are recorded end-to-end. Placeholders are stored under
+    /// the symbol reference they were loaded from.
+    pub fn from_graph(name: &str, graph: &Graph, registry: &OperatorRegistry) -> Self {
+        let nodes = graph.node_ids_in_insertion_order();
+        let data = clipboard::copy(graph, &nodes, registry);
+        let mut def = SymbolDef::new(name);
+
+        for node in &data.nodes {
+            let op = graph.get(node.id).expect("copied node exists");
+            let symbol_ref = match op.as_any().downcast_ref::<MissingOp>() {
+                Some(missing) => missing.symbol_ref().to_string(),
+                None => format!("{}{}", BUILTIN_PREFIX, node.operator),
+            };
+            let mut child = ChildDef::with_id(node.id, &symbol_ref)
+                .with_update_rate(node.update_rate)
+                .with_gizmo_visibility(node.gizmo_visibility);
+            for input in &node.inputs {
+                let name = op.inputs()[input.index].name;
+                let value = input.value.clone();
+                child = match &input.context_var {
+                    Some(var) => child.with_input_binding(input.index, name, var, value),
+                    None => child.with_input_at(input.index, name, value),
+                };
+            }
+            def.add_child(child);
+        }
+
+        for conn in &data.connections {
+            let source = graph.get(conn.source).expect("copied node exists");
+            let target = graph.get(conn.target).expect("copied node exists");
+            def.add_connection(
+                ConnectionDef::new(conn.source, conn.source_output, conn.target, conn.target_input)
+                    .with_port_names(
+                        source.outputs()[conn.source_output].name,
+                        target.inputs()[conn.target_input].name,
+                    )
+                    .with_muted(conn.muted),
+            );
+        }
+
+        def
+    }
+
+    /// Record an animator's curves as this definition's animations
+    ///
+    /// Use with [`from_graph`](Self::from_graph) on the same graph: targets
+    /// are stored by node ID and input name. Looping and ping-pong animators
+    /// cycle or oscillate their curves after the last keyframe, so
+    /// [`animator`](Self::animator) restores the loop mode; the playback
+    /// range is restored as the span of the keyframes. Disabled bindings are
+    /// skipped.
+    pub fn add_animations(&mut self, animator: &Animator, graph: &Graph) -> &mut Self {
+        let post_behavior = match animator.loop_mode() {
+            LoopMode::Loop => ExtrapolationMode::Cycle,
+            LoopMode::PingPong => ExtrapolationMode::Oscillate,
+            LoopMode::Once | LoopMode::Hold => ExtrapolationMode::Constant,
+        };
+        for binding in animator.bindings().iter().filter(|b| b.enabled) {
+            let target = &binding.target;
+            let mut animation = AnimationDef::new(target.node_id, target.input_index);
+            let input = graph.get(target.node_id).and_then(|op| op.inputs().get(target.input_index));
+            if let Some(input) = input {
+                animation = animation.with_target_name(input.name);
+            }
+            animation.curve = CurveDef::from_curve(&binding.curve);
+            animation.curve.post_behavior = post_behavior;
+            self.animations.push(animation);
+        }
+        self
+    }
+
+    /// Build the animator of an instance created by
+    /// [`instantiate`](Self::instantiate)
+    ///
+    /// Like [`animator`](Self::animator), but bound to the created nodes
+    /// through [`InstantiateResult::animation_targets`]. Animations whose
+    /// target couldn't be resolved are left out.
+    pub fn instance_animator(&self, result: &InstantiateResult) -> Animator {
+        let mut animator = self.animator();
+        animator.clear_bindings();
+        for (animation, target) in self.animations.iter().zip(&result.animation_targets) {
+            if let Some(target) = target {
+                animator.add_binding(CurveBinding::new(animation.curve.to_curve(), target.clone()));
+            }
+        }
+        animator
+    }
+
+    /// Create this definition's children in a graph
+    ///
+    /// Children that reference other symbols, or operators the registry
+    /// doesn't know, become [`MissingOp`] placeholders. Ports are resolved
+    /// by name before index, and saved values the port no longer accepts
+    /// are converted. Everything recovered from is listed in
+    /// [`InstantiateResult::report`]. Template parameters take their
+    /// declared defaults (see [`template`](super::template)).
+    pub fn instantiate(&self, graph: &mut Graph, registry: &OperatorRegistry) -> InstantiateResult {
+        if !self.template_params.is_empty() {
+            return self.with_template_defaults().instantiate_children(graph, registry);
+        }
+        self.instantiate_children(graph, registry)
+    }
+
+    fn instantiate_children(&self, graph: &mut Graph, registry: &OperatorRegistry) -> InstantiateResult {
+        let mut result = InstantiateResult::default();
+
+        for child in &self.children {
+            let name = child.symbol_ref.strip_prefix(BUILTIN_PREFIX);
+            let op = name.and_then(|name| registry.create_with_meta_by_name(name));
+            if let (Some(name), Some(_)) = (name, &op) {
+                if let Some(to) = registry.renamed_to(name) {
+                    result.report.push(LoadIssue::OperatorRenamed {
+                        child: child.id,
+                        from: name.to_string(),
+                        to: to.to_string(),
+                    });
+                }
+            }
+            let new_id = match op {
+                Some((op, meta)) => graph.add_boxed_with_meta(op, &meta),
+                None => {
+                    let new_id = graph.add(self.placeholder(child));
+                    result.placeholders.push(new_id);
+                    result.report.push(LoadIssue::MissingOperator {
+                        child: child.id,
+                        symbol_ref: child.symbol_ref.clone(),
+                    });
+                    new_id
+                }
+            };
+            result.id_map.insert(child.id, new_id);
+
+            for input in &child.input_values {
+                let index = resolve(
+                    graph,
+                    &mut result,
+                    (child.id, new_id),
+                    PortDirection::Input,
+                    input.input_index,
+                    input.input_name.as_deref(),
+                );
+                let Some(index) = index else {
+                    continue;
+                };
+                let Some(value) =
+                    fit_value(graph, &mut result.report, (child.id, new_id), index, &input.value)
+                else {
+                    continue;
+                };
+                match &input.context_var {
+                    Some(var) => graph.set_input_context_binding(new_id, index, var, value),
+                    None => graph.set_input_default(new_id, index, value),
+                };
+            }
+            if !child.update_rate.is_every_frame() {
+                graph.set_update_rate(new_id, child.update_rate);
+            }
+            if !child.gizmo_visibility.is_inherit() {
+                graph.set_gizmo_visibility(new_id, child.gizmo_visibility);
+            }
+        }
+
+        for conn in &self.connections {
+            let source = result.id_map.get(&conn.source_child).copied();
+            let target = result.id_map.get(&conn.target_child).copied();
+            let (Some(source), Some(target)) = (source, target) else {
+                drop_connection(&mut result, conn);
+                continue;
+            };
+            let source_output = resolve(
+                graph,
+                &mut result,
+                (conn.source_child, source),
+                PortDirection::Output,
+                Some(conn.source_output),
+                conn.source_output_name.as_deref(),
+            );
+            let target_input = resolve(
+                graph,
+                &mut result,
+                (conn.target_child, target),
+                PortDirection::Input,
+                Some(conn.target_input),
+                conn.target_input_name.as_deref(),
+            );
+            let connected = match (source_output, target_input) {
+                (Some(output), Some(input)) => {
+                    graph.adopt_placeholder_types((source, output), (target, input));
+                    match graph.connect(source, output, target, input) {
+                        Ok(conversion) => {
+                            if conn.muted {
+                                graph.mute_connected((source, output), conversion, (target, input));
+                            }
+                            true
+                        }
+                        Err(_) => false,
+                    }
+                }
+                _ => false,
+            };
+            if !connected {
+                drop_connection(&mut result, conn);
+            }
+        }
+
+        for animation in &self.animations {
+            let target = resolve_animation(graph, animation, &mut result);
+            result.animation_targets.push(target);
+        }
+
+        result
+    }
+
+    /// Create this definition's children, recovering or failing per `mode`
+    ///
+    /// In [`LoadMode::Strict`] the definition is first tried on a scratch
+    /// graph; if that reports any warning, the error carries the full
+    /// report and `graph` is left untouched.
+    pub fn instantiate_with(
+        &self,
+        graph: &mut Graph,
+        registry: &OperatorRegistry,
+        mode: LoadMode,
+    ) -> Result<InstantiateResult> {
+        if mode == LoadMode::Strict {
+            let trial = self.instantiate(&mut Graph::new(), registry);
+            mode.check(trial.report)?;
+        }
+        Ok(self.instantiate(graph, registry))
+    }
+
+    /// A placeholder for a child, with ports for everything saved against it
+    fn placeholder(&self, child: &ChildDef) -> MissingOp {
+        let mut op = MissingOp::new(&child.symbol_ref);
+        for input in &child.input_values {
+            if let Some(index) = input.input_index {
+                op.declare_input(index, input.input_name.as_deref(), Some(&input.value));
+            }
+        }
+        let mut sources_per_input: HashMap<usize, usize> = HashMap::new();
+        for conn in &self.connections {
+            if conn.source_child == child.id {
+                op.declare_output(conn.source_output, conn.source_output_name.as_deref());
+            }
+            if conn.target_child == child.id {
+                op.declare_input(conn.target_input, conn.target_input_name.as_deref(), None);
+                *sources_per_input.entry(conn.target_input).or_default() += 1;
+            }
+        }
+        for (index, sources) in sources_per_input {
+            if sources > 1 {
+                op.declare_multi_input(index);
+            }
+        }
+        for animation in self.animations.iter().filter(|a| a.target_child == child.id) {
+            op.declare_input(animation.target_input, animation.target_input_name.as_deref(), None);
+        }
+        op
+    }
+}
+
+fn drop_connection(result: &mut InstantiateResult, conn: &ConnectionDef) {
+    result.dropped_connections.push(conn.clone());
+    result.report.push(LoadIssue::ConnectionDropped(conn.clone()));
+}
+
+/// A saved input value, converted if the port no longer accepts its type
+///
+/// Returns `None`, reporting it, if the value can't be converted.
+fn fit_value(
+    graph: &Graph,
+    report: &mut LoadReport,
+    (child, node): (Id, Id),
+    index: usize,
+    value: &Value,
+) -> Option<Value> {
+    let op = graph.get(node)?;
+    let port = op.inputs().get(index)?;
+    let from = value.value_type();
+    if port.constraint.accepts(from) {
+        return Some(value.clone());
+    }
+    let coerced = value.coerce_to(port.value_type);
+    let operator = op.name().to_string();
+    let input = port.name.to_string();
+    report.push(match coerced {
+        Some(_) => LoadIssue::ValueCoerced {
+            child,
+            operator,
+            input,
+            from,
+            to: port.value_type,
+        },
+        None => LoadIssue::ValueDropped {
+            child,
+            operator,
+            input,
+            value_type: from,
+        },
+    });
+    coerced
+}
+
+fn resolve_animation(
+    graph: &Graph,
+    animation: &AnimationDef,
+    result: &mut InstantiateResult,
+) -> Option<AnimationTarget> {
+    let node = *result.id_map.get(&animation.target_child)?;
+    let index = resolve(
+        graph,
+        result,
+        (animation.target_child, node),
+        PortDirection::Input,
+        Some(animation.target_input),
+        animation.target_input_name.as_deref(),
+    )?;
+    Some(AnimationTarget::new(node, index))
+}
+
+/// Resolve a saved port on a created node, recording a diagnostic on mismatch
+fn resolve(
+    graph: &Graph,
+    result: &mut InstantiateResult,
+    (child, node): (Id, Id),
+    direction: PortDirection,
+    index: Option<usize>,
+    name: Option<&str>,
+) -> Option<usize> {
+    let op = graph.get(node)?;
+    let resolution = resolve_port(&port_names(op, direction), index, name);
+    if resolution.needs_review() {
+        let diagnostic = PortDiagnostic {
+            child,
+            operator: op.name().to_string(),
+            direction,
+            saved_index: index,
+            saved_name: name.map(str::to_string),
+            resolution,
+        };
+        result.diagnostics.push(diagnostic.clone());
+        result.report.push(LoadIssue::Port(diagnostic));
+    }
+    resolution.index()
+}
+
+fn port_names(op: &dyn Operator, direction: PortDirection) -> Vec<&'static str> {
+    match direction {
+        PortDirection::Input => op.inputs().iter().map(|p| p.name).collect(),
+        PortDirection::Output => op.outputs().iter().map(|p| p.name).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::graph::GraphEvent;
+    use crate::layout::{auto_layout, LayoutOptions};
+    use crate::serialization::{
+        load_graph_str, load_symbol_str, load_symbol_str_with, save_graph_str, save_symbol_str,
+        GraphFile, PortResolution, SerializationError, Severity, SymbolFile,
+    };
+    use flux_core::context::{EvalContext, GizmoVisibility};
+    use flux_core::id::IdGenerator;
+    use flux_core::operator::InputResolver;
+    use flux_core::port::{DefaultSource, InputPort, OutputPort};
+    use flux_core::resource::{with_serde_resources, ResourceKind, ResourceTable};
+    use flux_core::value::Value;
+    use flux_operators::create_default_registry;
+
+    /// A later "Add" whose inputs gained a leading Offset port
+    struct OffsetAddOp {
+        id: Id,
+        inputs: [InputPort; 3],
+        outputs: [OutputPort; 1],
+    }
+
+    impl OffsetAddOp {
+        fn new() -> Self {
+            Self {
+                id: Id::new(),
+                inputs: [
+                    InputPort::float("Offset", 0.0),
+                    InputPort::float("A", 0.0),
+                    InputPort::float("B", 0.0),
+                ],
+                outputs: [OutputPort::float("Result")],
+            }
+        }
+    }
+
+    impl Operator for OffsetAddOp {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            "Add"
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+        fn compute(&mut self, _ctx: &EvalContext, _get_input: InputResolver) {}
+    }
+
+    fn input_source(graph: &Graph, node: Id, input: usize) -> Option<(Id, usize)> {
+        graph.get(node).unwrap().inputs()[input].connection
+    }
+
+    /// constant(2) -> add.A, constant(3) -> add.B, saved as a symbol file
+    fn save_add_graph() -> (String, Id) {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let a = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        let b = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        let add = graph.add_boxed(registry.create_by_name("Add").unwrap());
+        graph.set_input_default(a, 0, Value::Float(2.0));
+        graph.set_input_default(b, 0, Value::Float(3.0));
+        graph.set_input_default(add, 1, Value::Float(5.0));
+        graph.connect(a, 0, add, 0).unwrap();
+        graph.connect(b, 0, add, 1).unwrap();
+
+        let mut def = SymbolDef::from_graph("Sum", &graph, &registry);
+        let mut animation = AnimationDef::new(add, 1).with_target_name("B");
+        animation.add_keyframe(crate::serialization::KeyframeDef::new(0.0, 1.0));
+        def.animations.push(animation);
+        (save_symbol_str(&SymbolFile::from_def(def)).unwrap(), add)
+    }
+
+    #[test]
+    fn test_groups_survive_save_and_load() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let nodes: Vec<Id> = (0..4)
+            .map(|_| graph.add_boxed(registry.create_by_name("Constant").unwrap()))
+            .collect();
+        let background = graph.create_group("Background FX", [0.2, 0.4, 0.6, 1.0]);
+        let overlay = graph.create_group("Overlay", [0.9, 0.1, 0.1, 1.0]);
+        for &node in &nodes[..3] {
+            graph.assign_to_group(node, background).unwrap();
+        }
+        graph.assign_to_group(nodes[2], overlay).unwrap();
+        graph.assign_to_group(nodes[3], overlay).unwrap();
+
+        // Removing a member keeps the group
+        graph.remove(nodes[0]);
+        assert_eq!(graph.group_members(background), Some(&nodes[1..3]));
+
+        let symbol = save_symbol_str(&SymbolFile::from_def(SymbolDef::from_graph(
+            "Scene", &graph, &registry,
+        )))
+        .unwrap();
+        let mut file = GraphFile::new("Main", Id::new());
+        file.graph = file.graph.with_groups_from(&graph);
+        let json = save_graph_str(&file).unwrap();
+
+        let mut loaded = Graph::new();
+        let result = load_symbol_str(&symbol).unwrap().symbol.instantiate(&mut loaded, &registry);
+        let def = load_graph_str(&json).unwrap().graph;
+        let groups = def.restore_groups(&mut loaded, &result.id_map);
+        assert_eq!(groups.len(), 2);
+
+        let background = loaded.group(groups[0]).unwrap();
+        assert_eq!(background.name(), "Background FX");
+        assert_eq!(background.color(), [0.2, 0.4, 0.6, 1.0]);
+        let expected: Vec<Id> = nodes[1..3].iter().map(|id| result.id_map[id]).collect();
+        assert_eq!(background.members(), &expected[..]);
+        assert_eq!(loaded.group(groups[1]).unwrap().color(), [0.9, 0.1, 0.1, 1.0]);
+        assert_eq!(loaded.groups_of(result.id_map[&nodes[2]]), groups);
+        assert_eq!(loaded.groups_of(result.id_map[&nodes[3]]), vec![groups[1]]);
+    }
+
+    #[test]
+    fn test_positions_survive_save_and_load() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let a = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        let b = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        let add = graph.add_boxed(registry.create_by_name("Add").unwrap());
+        graph.connect(a, 0, add, 0).unwrap();
+        graph.connect(b, 0, add, 1).unwrap();
+        for (node, position) in auto_layout(&graph, &LayoutOptions::default()) {
+            assert!(graph.set_node_position(node, position));
+        }
+        let moved = graph
+            .drain_events()
+            .filter(|e| matches!(e, GraphEvent::NodeMoved { .. }))
+            .count();
+        assert_eq!(moved, 3);
+        assert_eq!(graph.node_position(add), Some([250.0, 50.0]));
+        assert!(graph.set_node_position(add, [250.0, 80.0]));
+        assert!(graph.set_node_position(add, [250.0, 80.0]));
+        assert_eq!(graph.drain_events().count(), 1, "unchanged position emitted");
+
+        let symbol = save_symbol_str(&SymbolFile::from_def(SymbolDef::from_graph(
+            "Scene", &graph, &registry,
+        )))
+        .unwrap();
+        let mut file = GraphFile::new("Main", Id::new());
+        file.graph = file.graph.with_positions_from(&graph);
+        let json = save_graph_str(&file).unwrap();
+
+        let mut loaded = Graph::new();
+        let result = load_symbol_str(&symbol).unwrap().symbol.instantiate(&mut loaded, &registry);
+        let def = load_graph_str(&json).unwrap().graph;
+        assert_eq!(def.restore_positions(&mut loaded, &result.id_map), 3);
+        for node in [a, b, add] {
+            let position = loaded.node_position(result.id_map[&node]);
+            assert_eq!(position, graph.node_position(node));
+        }
+        assert_eq!(loaded.node_position(result.id_map[&add]), Some([250.0, 80.0]));
+    }
+
+    #[test]
+    fn test_roundtrip_is_clean() {
+        let registry = create_default_registry();
+        let (json, _) = save_add_graph();
+        assert!(json.contains("\"target_input_name\": \"B\""));
+
+        let file = load_symbol_str(&json).unwrap();
+        let mut graph = Graph::new();
+        let result = file.symbol.instantiate(&mut graph, &registry);
+        assert!(result.is_clean(), "{:?}", result);
+        assert_eq!(graph.node_count(), 3);
+
+        let ctx = EvalContext::new();
+        let add = graph
+            .node_ids()
+            .find(|&id| graph.get(id).unwrap().name() == "Add")
+            .unwrap();
+        assert_eq!(graph.evaluate(add, 0, &ctx).unwrap(), Value::Float(5.0));
+    }
+
+    #[test]
+    fn test_reset_inputs_are_not_saved() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let lerp = graph.add_boxed(registry.create_by_name("Lerp").unwrap());
+        graph.set_input_default(lerp, 1, Value::Float(4.0));
+        graph.set_input_default(lerp, 2, Value::Float(0.25));
+        assert_eq!(graph.modified_inputs(lerp), vec![1, 2]);
+        assert!(graph.reset_input_to_factory(lerp, 1));
+        assert_eq!(graph.modified_inputs(lerp), vec![2]);
+
+        let def = SymbolDef::from_graph("Blend", &graph, &registry);
+        assert_eq!(def.children[0].input_values.len(), 1);
+        let json = save_symbol_str(&SymbolFile::from_def(def)).unwrap();
+
+        let mut loaded = Graph::new();
+        let result = load_symbol_str(&json).unwrap().symbol.instantiate(&mut loaded, &registry);
+        let lerp = result.id_map[&lerp];
+        assert_eq!(loaded.modified_inputs(lerp), vec![2]);
+        assert_eq!(loaded.get(lerp).unwrap().inputs()[2].default, Value::Float(0.25));
+    }
+
+    #[test]
+    fn test_connections_follow_renamed_ports() {
+        let (json, saved_add) = save_add_graph();
+
+        let registry = create_default_registry();
+        registry.register_simple("Add", || Box::new(OffsetAddOp::new()));
+
+        let file = load_symbol_str(&json).unwrap();
+        let mut graph = Graph::new();
+        let result = file.symbol.instantiate(&mut graph, &registry);
+        assert!(result.placeholders.is_empty());
+        assert!(result.dropped_connections.is_empty());
+
+        let add = result.id_map[&saved_add];
+        let constant_for = |value: f32| {
+            let is_constant = |id: Id| {
+                let op = graph.get(id).unwrap();
+                op.name() == "Constant" && op.inputs()[0].default == Value::Float(value)
+            };
+            result.id_map.values().copied().find(|&id| is_constant(id)).unwrap()
+        };
+        assert_eq!(input_source(&graph, add, 0), None);
+        assert_eq!(input_source(&graph, add, 1), Some((constant_for(2.0), 0)));
+        assert_eq!(input_source(&graph, add, 2), Some((constant_for(3.0), 0)));
+
+        // The B default and the animation followed the name as well
+        let inputs = graph.get(add).unwrap().inputs();
+        assert_eq!(inputs[2].default, Value::Float(5.0));
+        assert_eq!(inputs[1].default, Value::Float(0.0));
+        assert_eq!(result.animation_targets, vec![Some(AnimationTarget::new(add, 2))]);
+
+        // A, B, the B default and the animation target all moved
+        assert_eq!(result.diagnostics.len(), 4);
+        assert!(result.diagnostics.iter().all(|d| d.child == saved_add
+            && d.direction == PortDirection::Input
+            && matches!(d.resolution, PortResolution::Moved(_))));
+        let moved_a = result
+            .diagnostics
+            .iter()
+            .find(|d| d.saved_name.as_deref() == Some("A"))
+            .unwrap();
+        assert_eq!(moved_a.saved_index, Some(0));
+        assert_eq!(moved_a.resolution, PortResolution::Moved(1));
+        assert_eq!(moved_a.to_string(), "input 'A' (index 0) of Add: moved to index 1");
+    }
+
+    #[test]
+    fn test_muted_connections_roundtrip() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let constant = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        let add = graph.add_boxed(registry.create_by_name("Add").unwrap());
+        graph.set_input_default(constant, 0, Value::Float(2.0));
+        graph.set_input_default(add, 1, Value::Float(3.0));
+        graph.connect(constant, 0, add, 0).unwrap();
+        graph.connect(constant, 0, add, 1).unwrap();
+        let muted = graph.upstream_of(add)[1];
+        graph.set_connection_muted(muted, true).unwrap();
+
+        let def = SymbolDef::from_graph("Muted", &graph, &registry);
+        let json = save_symbol_str(&SymbolFile::from_def(def)).unwrap();
+        assert_eq!(json.matches("\"muted\": true").count(), 1);
+
+        let file = load_symbol_str(&json).unwrap();
+        let mut loaded = Graph::new();
+        let result = file.symbol.instantiate(&mut loaded, &registry);
+        assert!(result.is_clean(), "{:?}", result);
+        let add = result.id_map[&add];
+        let muted: Vec<bool> = loaded.upstream_of(add).iter().map(|c| c.muted).collect();
+        assert_eq!(muted, [false, true]);
+
+        // B reads its default while muted
+        let ctx = EvalContext::new();
+        assert_eq!(loaded.evaluate(add, 0, &ctx).unwrap(), Value::Float(5.0));
+    }
+
+    #[test]
+    fn test_seeded_ids_give_identical_saves() {
+        let build = || {
+            Id::with_generator(IdGenerator::seeded(1), || {
+                let registry = create_default_registry();
+                let mut graph = Graph::new();
+                let constants: Vec<Id> = (0..8)
+                    .map(|i| {
+                        let id = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+                        graph.set_input_default(id, 0, Value::Float(i as f32));
+                        id
+                    })
+                    .collect();
+                for pair in constants.chunks(2) {
+                    let add = graph.add_boxed(registry.create_by_name("Add").unwrap());
+                    graph.connect(pair[0], 0, add, 0).unwrap();
+                    graph.connect(pair[1], 0, add, 1).unwrap();
+                }
+                let def = SymbolDef::from_graph("Fixture", &graph, &registry);
+                save_symbol_str(&SymbolFile::from_def(def)).unwrap()
+            })
+        };
+        assert_eq!(build(), build());
+    }
+
+    #[test]
+    fn test_context_bindings_roundtrip() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let constant = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        graph.set_input_context_binding(constant, 0, "global.intensity", Value::Float(0.25));
+
+        let def = SymbolDef::from_graph("Bound", &graph, &registry);
+        let json = save_symbol_str(&SymbolFile::from_def(def)).unwrap();
+        assert!(json.contains("\"context_var\": \"global.intensity\""));
+
+        let file = load_symbol_str(&json).unwrap();
+        let mut loaded = Graph::new();
+        let result = file.symbol.instantiate(&mut loaded, &registry);
+        let constant = result.id_map[&constant];
+        assert_eq!(
+            loaded.input_default_source(constant, 0),
+            Some(DefaultSource::context_var("global.intensity", Value::Float(0.25)))
+        );
+
+        let mut ctx = EvalContext::new();
+        ctx.set_float_var("global.intensity", 0.75);
+        assert_eq!(loaded.evaluate(constant, 0, &ctx).unwrap(), Value::Float(0.75));
+    }
+
+    #[test]
+    fn test_gizmo_visibility_roundtrip() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let shown = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        let inherited = graph.add_boxed(registry.create_by_name("Constant").unwrap());
+        graph.set_gizmo_visibility(shown, GizmoVisibility::IfSelected);
+
+        let def = SymbolDef::from_graph("Gizmos", &graph, &registry);
+        let json = save_symbol_str(&SymbolFile::from_def(def)).unwrap();
+        assert_eq!(json.matches("gizmo_visibility").count(), 1);
+
+        let file = load_symbol_str(&json).unwrap();
+        let mut loaded = Graph::new();
+        let result = file.symbol.instantiate(&mut loaded, &registry);
+        let visibility = |id| loaded.gizmo_visibility(result.id_map[&id]);
+        assert_eq!(visibility(shown), Some(GizmoVisibility::IfSelected));
+        assert_eq!(visibility(inherited), Some(GizmoVisibility::Inherit));
+    }
+
+    #[test]
+    fn test_resource_refs_roundtrip_by_name() {
+        let registry = create_default_registry();
+        let mut saved_table = ResourceTable::new();
+        saved_table.insert(ResourceKind::Audio, "kick", ());
+        let brick = saved_table.insert(ResourceKind::Texture, "brick", ());
+
+        let mut graph = Graph::new();
+        let info = graph.add_boxed(registry.create_by_name("ResourceInfo").unwrap());
+        let texture = Value::ResourceRef { kind: ResourceKind::Texture, id: brick };
+        graph.set_input_default(info, 0, texture);
+        let def = SymbolDef::from_graph("Textured", &graph, &registry);
+        let json = with_serde_resources(&Arc::new(saved_table), || {
+            save_symbol_str(&SymbolFile::from_def(def)).unwrap()
+        });
+        assert!(json.contains("\"brick\""));
+
+        // The host registers the same names in a different order on load
+        let mut table = ResourceTable::new();
+        let new_brick = table.insert(ResourceKind::Texture, "brick", ());
+        table.insert(ResourceKind::Audio, "kick", ());
+        let table = Arc::new(table);
+        let file = with_serde_resources(&table, || load_symbol_str(&json).unwrap());
+        let mut loaded = Graph::new();
+        let result = file.symbol.instantiate(&mut loaded, &registry);
+        let info = result.id_map[&info];
+        assert_eq!(
+            loaded.get(info).unwrap().inputs()[0].default,
+            Value::ResourceRef { kind: ResourceKind::Texture, id: new_brick }
+        );
+
+        let mut ctx = EvalContext::new();
+        ctx.resources = Some(table);
+        assert_eq!(loaded.evaluate(info, 1, &ctx).unwrap(), Value::Bool(true));
+    }
+
+    #[test]
+    fn test_files_without_port_names_load_by_index() {
+        let registry = create_default_registry();
+        let constant = ChildDef::builtin("Constant");
+        let add = ChildDef::builtin("Add");
+        let mut def = SymbolDef::new("Legacy");
+        def.add_connection(ConnectionDef::new(constant.id, 0, add.id, 1));
+        def.add_connection(ConnectionDef::new(constant.id, 0, add.id, 7));
+        def.add_child(constant).add_child(add.clone());
+
+        let mut graph = Graph::new();
+        let result = def.instantiate(&mut graph, &registry);
+        let new_add = result.id_map[&add.id];
+        assert!(input_source(&graph, new_add, 1).is_some());
+        assert_eq!(result.dropped_connections.len(), 1);
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].resolution, PortResolution::Missing);
+    }
+
+    /// constant -> warp.Source, warp.Result -> add.A, where no "Warp"
+    /// operator is registered; warp has a saved and a bound value
+    fn missing_operator_json() -> (String, Id) {
+        let constant = ChildDef::builtin("Constant").with_input_at(0, "Value", Value::Float(2.0));
+        let warp = ChildDef::builtin("Warp")
+            .with_input_at(2, "Amount", Value::Vec3([1.0, 2.0, 3.0]))
+            .with_input_binding(3, "Speed", "global.speed", Value::Float(0.5));
+        let add = ChildDef::builtin("Add").with_input_at(1, "B", Value::Float(4.0));
+        let mut def = SymbolDef::new("Warped");
+        def.add_connection(
+            ConnectionDef::new(constant.id, 0, warp.id, 0).with_port_names("Value", "Source"),
+        );
+        def.add_connection(
+            ConnectionDef::new(warp.id, 1, add.id, 0).with_port_names("Result", "A"),
+        );
+        let warp_id = warp.id;
+        def.add_child(constant).add_child(warp).add_child(add);
+        (save_symbol_str(&SymbolFile::from_def(def)).unwrap(), warp_id)
+    }
+
+    /// Children and connections by symbol reference and port, ignoring IDs
+    fn contents(def: &SymbolDef) -> (Vec<String>, Vec<String>) {
+        let symbol_ref = |id: Id| {
+            let child = def.children.iter().find(|c| c.id == id).unwrap();
+            child.symbol_ref.clone()
+        };
+        let mut children: Vec<String> = def
+            .children
+            .iter()
+            .map(|child| {
+                let values: Vec<String> = child
+                    .input_values
+                    .iter()
+                    .map(|v| {
+                        let (index, name) = (v.input_index, &v.input_name);
+                        format!("{:?} {:?} {:?} {:?}", index, name, v.value, v.context_var)
+                    })
+                    .collect();
+                format!("{} {:?}", child.symbol_ref, values)
+            })
+            .collect();
+        let mut connections: Vec<String> = def
+            .connections
+            .iter()
+            .map(|c| {
+                format!(
+                    "{}.{} {:?} -> {}.{} {:?}",
+                    symbol_ref(c.source_child),
+                    c.source_output,
+                    c.source_output_name,
+                    symbol_ref(c.target_child),
+                    c.target_input,
+                    c.target_input_name
+                )
+            })
+            .collect();
+        children.sort();
+        connections.sort();
+        (children, connections)
+    }
+
+    #[test]
+    fn test_missing_operator_lenient() {
+        let registry = create_default_registry();
+        let (json, saved_warp) = missing_operator_json();
+        let (file, report) = load_symbol_str_with(&json, LoadMode::Lenient).unwrap();
+        assert!(report.is_clean());
+
+        let mut graph = Graph::new();
+        let result = file
+            .symbol
+            .instantiate_with(&mut graph, &registry, LoadMode::Lenient)
+            .unwrap();
+        let warp = result.id_map[&saved_warp];
+        assert_eq!(result.placeholders, [warp]);
+        assert!(result.dropped_connections.is_empty());
+        assert_eq!(result.report.max_severity(), Some(Severity::Warning));
+        assert_eq!(
+            result.report.to_string(),
+            "warning: unknown operator 'builtin:Warp': replaced with a placeholder"
+        );
+
+        // The placeholder has the saved ports and evaluates to defaults
+        let placeholder = graph.get(warp).unwrap();
+        assert_eq!(placeholder.name(), "Warp");
+        let names: Vec<&str> = placeholder.inputs().iter().map(|p| p.name).collect();
+        assert_eq!(names, ["Source", "Input 1", "Amount", "Speed"]);
+        assert!(graph.modified_inputs(warp).is_empty());
+        let ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(warp, 1, &ctx).unwrap(), Value::Float(0.0));
+        let add = graph.downstream_of(warp)[0].target_node;
+        assert_eq!(graph.evaluate(add, 0, &ctx).unwrap(), Value::Float(4.0));
+
+        // Saving again writes the child back as it was found
+        let resaved = SymbolDef::from_graph("Warped", &graph, &registry);
+        assert_eq!(contents(&resaved), contents(&file.symbol));
+    }
+
+    #[test]
+    fn test_missing_operator_strict() {
+        let registry = create_default_registry();
+        let (json, saved_warp) = missing_operator_json();
+        let (file, _) = load_symbol_str_with(&json, LoadMode::Strict).unwrap();
+
+        let mut graph = Graph::new();
+        let error = file
+            .symbol
+            .instantiate_with(&mut graph, &registry, LoadMode::Strict)
+            .unwrap_err();
+        let SerializationError::LoadRejected(report) = &error else {
+            panic!("unexpected error: {}", error);
+        };
+        assert!(matches!(
+            report.issues(),
+            [LoadIssue::MissingOperator { child, symbol_ref }]
+                if *child == saved_warp && symbol_ref == "builtin:Warp"
+        ));
+        assert!(error.to_string().contains("unknown operator 'builtin:Warp'"));
+        assert_eq!(graph.node_count(), 0);
+    }
+
+    #[test]
+    fn test_renamed_operator_loads_under_new_name() {
+        let registry = create_default_registry();
+        let mut def = SymbolDef::new("Scaled");
+        let child = ChildDef::builtin("ListMap").with_input_at(1, "Scale", Value::Float(2.0));
+        let saved = child.id;
+        def.add_child(child);
+        let json = save_symbol_str(&SymbolFile::from_def(def)).unwrap();
+
+        // Renames are informational, so strict loading accepts them
+        let file = load_symbol_str(&json).unwrap();
+        let mut graph = Graph::new();
+        let result = file
+            .symbol
+            .instantiate_with(&mut graph, &registry, LoadMode::Strict)
+            .unwrap();
+        let node = result.id_map[&saved];
+        assert!(result.placeholders.is_empty());
+        assert_eq!(graph.get(node).unwrap().name(), "ListScaleOffset");
+        assert_eq!(graph.get(node).unwrap().inputs()[1].default, Value::Float(2.0));
+        assert!(matches!(
+            result.report.issues(),
+            [LoadIssue::OperatorRenamed { child, from, to }]
+                if *child == saved && from == "ListMap" && to == "ListScaleOffset"
+        ));
+        assert_eq!(
+            result.report.to_string(),
+            "info: operator 'ListMap' was renamed to 'ListScaleOffset'"
+        );
+
+        // Saving again writes the new name
+        let resaved = SymbolDef::from_graph("Scaled", &graph, &registry);
+        assert_eq!(resaved.children[0].symbol_ref, "builtin:ListScaleOffset");
+    }
+
+    #[test]
+    fn test_changed_value_types_are_converted() {
+        let registry = create_default_registry();
+        let constant = ChildDef::builtin("Constant").with_input_at(0, "Value", Value::Int(3));
+        let unusable = ChildDef::builtin("Constant")
+            .with_input_at(0, "Value", Value::String("three".to_string()));
+        let (constant_id, unusable_id) = (constant.id, unusable.id);
+        let mut def = SymbolDef::new("Retyped");
+        def.add_child(constant).add_child(unusable);
+
+        let mut graph = Graph::new();
+        let result = def.instantiate(&mut graph, &registry);
+        let value = |id| graph.get(result.id_map[&id]).unwrap().inputs()[0].default.clone();
+        assert_eq!(value(constant_id), Value::Float(3.0));
+        assert_eq!(value(unusable_id), Value::Float(0.0));
+        let severities: Vec<Severity> =
+            result.report.issues().iter().map(LoadIssue::severity).collect();
+        assert_eq!(severities, [Severity::Warning, Severity::Error]);
+        assert_eq!(
+            result.report.issues()[0].to_string(),
+            "input 'Value' of Constant: saved Int value converted to Float"
+        );
+        assert!(def
+            .instantiate_with(&mut Graph::new(), &registry, LoadMode::Strict)
+            .is_err());
+    }
+
+    #[test]
+    fn test_animator_roundtrip() {
+        let registry = create_default_registry();
+        let mut graph = Graph::new();
+        let add = graph.add_boxed(registry.create_by_name("Add").unwrap());
+        let mut curve = crate::animation::Curve::new();
+        curve.add(0.0, 1.0);
+        curve.add(2.0, 5.0);
+        let mut animator = Animator::with_range(0.0, 2.0);
+        animator.set_loop_mode(LoopMode::Loop);
+        animator.add_curve(curve, add, 1);
+
+        let mut def = SymbolDef::from_graph("Animated", &graph, &registry);
+        def.add_animations(&animator, &graph);
+        assert_eq!(def.animations[0].target_input_name.as_deref(), Some("B"));
+        let json = save_symbol_str(&SymbolFile::from_def(def)).unwrap();
+
+        let file = load_symbol_str(&json).unwrap();
+        let mut loaded = Graph::new();
+        let result = file.symbol.instantiate(&mut loaded, &registry);
+        let mut restored = file.symbol.instance_animator(&result);
+        assert_eq!(restored.loop_mode(), LoopMode::Loop);
+        assert_eq!(restored.range(), (0.0, 2.0));
+        assert_eq!(restored.sample_at(result.id_map[&add], 1, 1.0), Some(3.0));
+    }
+}
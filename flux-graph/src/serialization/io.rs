@@ -5,9 +5,12 @@
 use std::fs;
 use std::path::Path;
 
+use flux_core::theme::ThemeRegistry;
+
 use super::error::{Result, SerializationError};
 use super::graph::GraphFile;
 use super::project::ProjectFile;
+use super::report::{check_version, LoadMode, LoadReport};
 use super::symbol::SymbolFile;
 use super::version::SchemaVersion;
 
@@ -86,6 +89,20 @@ pub fn load_symbol(path: impl AsRef<Path>) -> Result<SymbolFile> {
     Ok(symbol)
 }
 
+/// Load a symbol file, reporting recovered problems
+///
+/// Same-major schema versions other than the current one are accepted and
+/// reported; in [`LoadMode::Strict`] a newer minor version is rejected.
+/// Problems with the symbol's contents are reported when it is
+/// instantiated (see [`SymbolDef::instantiate_with`]).
+///
+/// [`SymbolDef::instantiate_with`]: super::SymbolDef::instantiate_with
+pub fn load_symbol_with(path: impl AsRef<Path>, mode: LoadMode) -> Result<(SymbolFile, LoadReport)> {
+    check_file_size(&path)?;
+    let content = fs::read_to_string(path)?;
+    load_symbol_str_with(&content, mode)
+}
+
 /// Save a symbol file
 pub fn save_symbol(symbol: &SymbolFile, path: impl AsRef<Path>) -> Result<()> {
     let content = serde_json::to_string_pretty(symbol)?;
@@ -100,6 +117,16 @@ pub fn load_symbol_str(json: &str) -> Result<SymbolFile> {
     Ok(symbol)
 }
 
+/// Load a symbol file from a JSON string, reporting recovered problems
+///
+/// See [`load_symbol_with`].
+pub fn load_symbol_str_with(json: &str, mode: LoadMode) -> Result<(SymbolFile, LoadReport)> {
+    let mut symbol: SymbolFile = serde_json::from_str(json)?;
+    let mut report = LoadReport::new();
+    check_version(&mut symbol.version, &mut report)?;
+    Ok((symbol, mode.check(report)?))
+}
+
 /// Serialize a symbol file to JSON string
 pub fn save_symbol_str(symbol: &SymbolFile) -> Result<String> {
     Ok(serde_json::to_string_pretty(symbol)?)
@@ -118,6 +145,15 @@ pub fn load_graph(path: impl AsRef<Path>) -> Result<GraphFile> {
     Ok(graph)
 }
 
+/// Load a graph file, reporting recovered problems
+///
+/// See [`load_symbol_with`] for how versions are handled.
+pub fn load_graph_with(path: impl AsRef<Path>, mode: LoadMode) -> Result<(GraphFile, LoadReport)> {
+    check_file_size(&path)?;
+    let content = fs::read_to_string(path)?;
+    load_graph_str_with(&content, mode)
+}
+
 /// Save a graph file
 pub fn save_graph(graph: &GraphFile, path: impl AsRef<Path>) -> Result<()> {
     let content = serde_json::to_string_pretty(graph)?;
@@ -132,11 +168,51 @@ pub fn load_graph_str(json: &str) -> Result<GraphFile> {
     Ok(graph)
 }
 
+/// Load a graph file from a JSON string, reporting recovered problems
+///
+/// See [`load_symbol_with`] for how versions are handled.
+pub fn load_graph_str_with(json: &str, mode: LoadMode) -> Result<(GraphFile, LoadReport)> {
+    let mut graph: GraphFile = serde_json::from_str(json)?;
+    let mut report = LoadReport::new();
+    check_version(&mut graph.version, &mut report)?;
+    Ok((graph, mode.check(report)?))
+}
+
 /// Serialize a graph file to JSON string
 pub fn save_graph_str(graph: &GraphFile) -> Result<String> {
     Ok(serde_json::to_string_pretty(graph)?)
 }
 
+// ============================================================================
+// Theme Files (.rtheme)
+// ============================================================================
+
+/// Load a theme file
+///
+/// Install the result with [`flux_core::set_global_theme`] to switch themes
+/// at runtime.
+pub fn load_theme(path: impl AsRef<Path>) -> Result<ThemeRegistry> {
+    check_file_size(&path)?;
+    let content = fs::read_to_string(path)?;
+    load_theme_str(&content)
+}
+
+/// Save a theme file
+pub fn save_theme(theme: &ThemeRegistry, path: impl AsRef<Path>) -> Result<()> {
+    fs::write(path, save_theme_str(theme)?)?;
+    Ok(())
+}
+
+/// Load a theme from a JSON string
+pub fn load_theme_str(json: &str) -> Result<ThemeRegistry> {
+    Ok(serde_json::from_str(json)?)
+}
+
+/// Serialize a theme to JSON string
+pub fn save_theme_str(theme: &ThemeRegistry) -> Result<String> {
+    Ok(serde_json::to_string_pretty(theme)?)
+}
+
 // ============================================================================
 // Auto-detect File Type
 // ============================================================================
@@ -147,6 +223,7 @@ pub enum FileType {
     Project,
     Symbol,
     Graph,
+    Theme,
     Unknown,
 }
 
@@ -157,6 +234,7 @@ impl FileType {
             Some("rproj") => Self::Project,
             Some("rsym") => Self::Symbol,
             Some("rgraph") => Self::Graph,
+            Some("rtheme") => Self::Theme,
             _ => Self::Unknown,
         }
     }
@@ -167,6 +245,7 @@ impl FileType {
             Self::Project => "rproj",
             Self::Symbol => "rsym",
             Self::Graph => "rgraph",
+            Self::Theme => "rtheme",
             Self::Unknown => "",
         }
     }
@@ -202,11 +281,24 @@ mod tests {
         assert_eq!(restored.graph.name, "Main");
     }
 
+    #[test]
+    fn test_theme_roundtrip_str() {
+        let theme = ThemeRegistry::new()
+            .with_name("Dark")
+            .with_category("Math", [0.2, 0.2, 0.6, 1.0])
+            .with_operator_icon("Add", "plus");
+        let json = save_theme_str(&theme).unwrap();
+        assert_eq!(load_theme_str(&json).unwrap(), theme);
+        // Every section is optional
+        assert!(load_theme_str("{}").unwrap().is_empty());
+    }
+
     #[test]
     fn test_file_type_detection() {
         assert_eq!(FileType::from_path("test.rproj"), FileType::Project);
         assert_eq!(FileType::from_path("test.rsym"), FileType::Symbol);
         assert_eq!(FileType::from_path("test.rgraph"), FileType::Graph);
+        assert_eq!(FileType::from_path("test.rtheme"), FileType::Theme);
         assert_eq!(FileType::from_path("test.txt"), FileType::Unknown);
     }
 
@@ -225,4 +317,27 @@ mod tests {
         let result = load_project_str(json);
         assert!(matches!(result, Err(SerializationError::VersionMismatch { .. })));
     }
+
+    #[test]
+    fn test_newer_minor_version_by_mode() {
+        let mut file = GraphFile::new("Main", Id::new());
+        file.version = SchemaVersion::new(1, 4);
+        let json = save_graph_str(&file).unwrap();
+
+        let (loaded, report) = load_graph_str_with(&json, LoadMode::Lenient).unwrap();
+        assert_eq!(loaded.version, SchemaVersion::new(1, 4));
+        assert_eq!(
+            report.to_string(),
+            "warning: file uses schema 1.4, newer than the supported 1.0; unknown fields were ignored"
+        );
+        assert!(matches!(
+            load_graph_str_with(&json, LoadMode::Strict),
+            Err(SerializationError::LoadRejected(report)) if report.issues().len() == 1
+        ));
+
+        // Current files load cleanly in either mode
+        let json = save_symbol_str(&SymbolFile::new("Clean")).unwrap();
+        let (_, report) = load_symbol_str_with(&json, LoadMode::Strict).unwrap();
+        assert!(report.is_clean());
+    }
 }
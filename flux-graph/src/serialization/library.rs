@@ -9,6 +9,7 @@ use flux_core::Id;
 
 use super::symbol::{SymbolDef, SymbolFile};
 use super::io;
+use super::report::{LoadMode, LoadReport};
 
 /// Error encountered while loading a symbol file
 #[derive(Debug)]
@@ -32,6 +33,8 @@ pub struct LoadResult {
     pub loaded: usize,
     /// Errors encountered during loading
     pub errors: Vec<LoadError>,
+    /// Files that loaded with recovered problems, and what was recovered
+    pub reports: Vec<(PathBuf, LoadReport)>,
 }
 
 /// Manages loading and caching of symbol definitions
@@ -44,6 +47,8 @@ pub struct SymbolLibrary {
     search_paths: Vec<PathBuf>,
     /// Built-in symbols (always available)
     builtins: HashMap<Id, SymbolFile>,
+    /// How files with recoverable problems are treated
+    load_mode: LoadMode,
 }
 
 impl SymbolLibrary {
@@ -54,6 +59,7 @@ impl SymbolLibrary {
             name_index: HashMap::new(),
             search_paths: Vec::new(),
             builtins: HashMap::new(),
+            load_mode: LoadMode::default(),
         };
         lib.register_builtins();
         lib
@@ -91,6 +97,14 @@ impl SymbolLibrary {
         true
     }
 
+    /// Set how files with recoverable problems are loaded
+    ///
+    /// In [`LoadMode::Strict`] such files are reported as [`LoadError`]s
+    /// instead of being registered.
+    pub fn set_load_mode(&mut self, mode: LoadMode) {
+        self.load_mode = mode;
+    }
+
     /// Load all symbols from search paths
     ///
     /// Returns a `LoadResult` containing both the count of successfully loaded
@@ -99,11 +113,13 @@ impl SymbolLibrary {
         let mut result = LoadResult {
             loaded: 0,
             errors: Vec::new(),
+            reports: Vec::new(),
         };
         for path in self.search_paths.clone() {
             let dir_result = self.load_from_directory(&path);
             result.loaded += dir_result.loaded;
             result.errors.extend(dir_result.errors);
+            result.reports.extend(dir_result.reports);
         }
         result
     }
@@ -113,6 +129,7 @@ impl SymbolLibrary {
         let mut result = LoadResult {
             loaded: 0,
             errors: Vec::new(),
+            reports: Vec::new(),
         };
 
         if !dir.exists() {
@@ -148,11 +165,15 @@ impl SymbolLibrary {
                 let sub_result = self.load_from_directory(&path);
                 result.loaded += sub_result.loaded;
                 result.errors.extend(sub_result.errors);
+                result.reports.extend(sub_result.reports);
             } else if path.extension().map(|e| e == "rsym").unwrap_or(false) {
-                match io::load_symbol(&path) {
-                    Ok(symbol) => {
+                match io::load_symbol_with(&path, self.load_mode) {
+                    Ok((symbol, report)) => {
                         self.register(symbol);
                         result.loaded += 1;
+                        if !report.is_clean() {
+                            result.reports.push((path.clone(), report));
+                        }
                     }
                     Err(e) => {
                         result.errors.push(LoadError {
@@ -447,6 +468,34 @@ mod tests {
         assert!(results.iter().any(|s| s.name.contains("sine")));
     }
 
+    #[test]
+    fn test_load_mode_for_newer_files() {
+        let dir = std::env::temp_dir().join(format!("flux_library_{}", Id::new()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut newer = SymbolFile::new("FromTheFuture");
+        newer.version = crate::serialization::SchemaVersion::new(1, 2);
+        io::save_symbol(&newer, dir.join("newer.rsym")).unwrap();
+        io::save_symbol(&SymbolFile::new("Current"), dir.join("current.rsym")).unwrap();
+
+        let mut lib = SymbolLibrary::new();
+        lib.add_search_path(&dir);
+        let result = lib.load_all();
+        assert_eq!(result.loaded, 2);
+        assert_eq!(result.reports.len(), 1);
+        assert!(result.reports[0].0.ends_with("newer.rsym"));
+
+        let mut strict = SymbolLibrary::new();
+        strict.set_load_mode(LoadMode::Strict);
+        strict.add_search_path(&dir);
+        let result = strict.load_all();
+        assert_eq!(result.loaded, 1);
+        assert_eq!(result.errors.len(), 1);
+        assert!(result.errors[0].message.contains("newer than the supported 1.0"));
+        assert!(!strict.contains_name("FromTheFuture"));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_library_unregister() {
         let mut lib = SymbolLibrary::new();
@@ -14,6 +14,7 @@
 //! | `.rproj` | Project configuration |
 //! | `.rsym` | Symbol definition |
 //! | `.rgraph` | Graph/composition |
+//! | `.rtheme` | Operator color/icon theme |
 //!
 //! ## Example: Creating a Symbol File
 //!
@@ -34,27 +35,37 @@
 pub mod animation;
 pub mod error;
 pub mod graph;
+pub mod instantiate;
 pub mod io;
 pub mod library;
+pub mod ports;
 pub mod project;
+pub mod report;
 pub mod symbol;
+pub mod template;
 pub mod version;
 
 // Re-export main types
 pub use animation::{AnimationDef, CurveDef, ExtrapolationMode, InterpolationMode, KeyframeDef, TangentDef};
 pub use error::{Result, SerializationError};
 pub use graph::{
-    GraphDef, GraphFile, InputOverride, InstanceOverride, PlaybackDef, PortUiOverride, ViewDef,
+    AnimationOverride, GraphDef, GraphFile, GroupDef, InputOverride, InstanceOverride,
+    NodePositionDef, PlaybackDef, PortUiOverride, ViewDef,
 };
 pub use io::{
-    load_graph, load_graph_str, load_project, load_project_str, load_symbol, load_symbol_str,
-    save_graph, save_graph_str, save_project, save_project_str, save_symbol, save_symbol_str,
-    FileType,
+    load_graph, load_graph_str, load_graph_str_with, load_graph_with, load_project,
+    load_project_str, load_symbol, load_symbol_str, load_symbol_str_with, load_symbol_with,
+    load_theme, load_theme_str, save_graph, save_graph_str, save_project, save_project_str,
+    save_symbol, save_symbol_str, save_theme, save_theme_str, FileType,
 };
+pub use instantiate::InstantiateResult;
 pub use library::{LoadError, LoadResult, SymbolLibrary};
+pub use ports::{resolve_port, PortDiagnostic, PortDirection, PortResolution};
 pub use project::{ProjectFile, ProjectMeta, ResourceConfig};
+pub use report::{LoadIssue, LoadMode, LoadReport, Severity};
 pub use symbol::{
     ChildDef, ConnectionDef, InputDef, InputUiMeta, InputValueDef, OutputDef, SymbolDef,
     SymbolFile, SymbolUiMeta,
 };
+pub use template::{instantiate_graph_with, TemplateParamDef};
 pub use version::SchemaVersion;
@@ -0,0 +1,138 @@
+//! Port resolution for saved connections and input values
+//!
+//! Saved files record operator ports by index and by name. When an operator
+//! gains, loses or reorders ports between library versions the index alone
+//! would silently point at the wrong port, so loading looks the name up on
+//! the freshly constructed operator first and only falls back to the index
+//! when the name is gone. Every case where the two disagree is reported as a
+//! [`PortDiagnostic`] for the user to review.
+
+use std::fmt;
+
+use flux_core::Id;
+
+/// Which side of an operator a port is on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortDirection {
+    Input,
+    Output,
+}
+
+impl fmt::Display for PortDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PortDirection::Input => f.write_str("input"),
+            PortDirection::Output => f.write_str("output"),
+        }
+    }
+}
+
+/// Outcome of resolving a saved port against an operator's current ports
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PortResolution {
+    /// Name and index agree, or no name was saved and the index is valid
+    Exact(usize),
+    /// The saved name now lives at a different index
+    Moved(usize),
+    /// The saved name no longer exists; the saved index was used instead
+    IndexFallback(usize),
+    /// Neither the name nor the index matches a port
+    Missing,
+}
+
+impl PortResolution {
+    /// Resolved port index, if any
+    pub fn index(self) -> Option<usize> {
+        match self {
+            PortResolution::Exact(index)
+            | PortResolution::Moved(index)
+            | PortResolution::IndexFallback(index) => Some(index),
+            PortResolution::Missing => None,
+        }
+    }
+
+    /// Whether this resolution should be reported to the user
+    pub fn needs_review(self) -> bool {
+        !matches!(self, PortResolution::Exact(_))
+    }
+}
+
+/// Resolve a saved port by name first, falling back to its index
+///
+/// `names` are the current port names of the operator, in order. Files
+/// written before names were recorded pass `None` and resolve by index.
+pub fn resolve_port(names: &[&str], index: Option<usize>, name: Option<&str>) -> PortResolution {
+    let by_index = index.filter(|&i| i < names.len());
+    match name.map(|name| names.iter().position(|&n| n == name)) {
+        Some(Some(found)) if Some(found) == index => PortResolution::Exact(found),
+        Some(Some(found)) => PortResolution::Moved(found),
+        Some(None) => by_index.map_or(PortResolution::Missing, PortResolution::IndexFallback),
+        None => by_index.map_or(PortResolution::Missing, PortResolution::Exact),
+    }
+}
+
+/// A saved port reference that didn't match the operator as saved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PortDiagnostic {
+    /// Child ID from the saved file
+    pub child: Id,
+    /// Operator name of the child
+    pub operator: String,
+    pub direction: PortDirection,
+    /// Port index at save time
+    pub saved_index: Option<usize>,
+    /// Port name at save time
+    pub saved_name: Option<String>,
+    /// How the port was resolved
+    pub resolution: PortResolution,
+}
+
+impl fmt::Display for PortDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = self.direction;
+        let port = match (&self.saved_name, self.saved_index) {
+            (Some(name), Some(index)) => format!("{} '{}' (index {})", direction, name, index),
+            (Some(name), None) => format!("{} '{}'", direction, name),
+            (None, Some(index)) => format!("{} {}", direction, index),
+            (None, None) => direction.to_string(),
+        };
+        match self.resolution {
+            PortResolution::Exact(index) => {
+                write!(f, "{} of {}: resolved to index {}", port, self.operator, index)
+            }
+            PortResolution::Moved(index) => {
+                write!(f, "{} of {}: moved to index {}", port, self.operator, index)
+            }
+            PortResolution::IndexFallback(index) => write!(
+                f,
+                "{} of {}: name not found, using index {}",
+                port, self.operator, index
+            ),
+            PortResolution::Missing => write!(f, "{} of {}: no matching port", port, self.operator),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_port() {
+        let names = ["Offset", "A", "B"];
+        assert_eq!(resolve_port(&names, Some(1), Some("A")), PortResolution::Exact(1));
+        assert_eq!(resolve_port(&names, Some(0), Some("A")), PortResolution::Moved(1));
+        assert_eq!(resolve_port(&names, Some(2), Some("C")), PortResolution::IndexFallback(2));
+        assert_eq!(resolve_port(&names, Some(3), Some("C")), PortResolution::Missing);
+        assert_eq!(resolve_port(&names, Some(5), Some("B")), PortResolution::Moved(2));
+
+        // Files without names resolve by index
+        assert_eq!(resolve_port(&names, Some(0), None), PortResolution::Exact(0));
+        assert_eq!(resolve_port(&names, Some(3), None), PortResolution::Missing);
+        assert_eq!(resolve_port(&names, None, None), PortResolution::Missing);
+
+        assert!(!PortResolution::Exact(0).needs_review());
+        assert!(PortResolution::Moved(1).needs_review());
+        assert_eq!(PortResolution::Missing.index(), None);
+    }
+}
@@ -8,6 +8,7 @@ use serde::{Deserialize, Serialize};
 use flux_core::Id;
 
 use super::version::SchemaVersion;
+use crate::cue::Cue;
 
 /// Project file schema (.rproj)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,6 +25,12 @@ pub struct ProjectFile {
     pub symbol_paths: Vec<String>,
     /// Main graph entry point (relative to project root)
     pub main_graph: String,
+    /// Additional scene graphs (relative to project root)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scenes: Vec<String>,
+    /// Cues targeting the active scene
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cues: Vec<Cue>,
 }
 
 impl ProjectFile {
@@ -35,6 +42,8 @@ impl ProjectFile {
             resources: ResourceConfig::default(),
             symbol_paths: vec!["symbols".into()],
             main_graph: "graphs/main.rgraph".into(),
+            scenes: Vec::new(),
+            cues: Vec::new(),
         }
     }
 }
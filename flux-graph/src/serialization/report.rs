@@ -0,0 +1,325 @@
+//! Load modes and structured reports of recovered problems
+//!
+//! Loading is forgiving by default: an unknown operator becomes a
+//! [`MissingOp`](crate::MissingOp) placeholder, a renamed port is found by
+//! its saved index, and a value whose port changed type is coerced. Every
+//! such recovery is recorded as a [`LoadIssue`] in a [`LoadReport`], so the
+//! host can show the user what changed. In [`LoadMode::Strict`] any warning
+//! aborts the load instead, with the full report in the error.
+//!
+//! ```ignore
+//! let (file, mut report) = load_symbol_with(&path, LoadMode::Lenient)?;
+//! let result = file.symbol.instantiate_with(&mut graph, &registry, LoadMode::Lenient)?;
+//! report.extend(result.report);
+//! for issue in report.issues() {
+//!     eprintln!("{}: {}", issue.severity(), issue);
+//! }
+//! ```
+
+use std::fmt;
+
+use flux_core::value::ValueType;
+use flux_core::Id;
+
+use super::error::{Result, SerializationError};
+use super::ports::{PortDiagnostic, PortResolution};
+use super::symbol::ConnectionDef;
+use super::version::SchemaVersion;
+
+/// How loading treats recoverable problems
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LoadMode {
+    /// Fail with [`SerializationError::LoadRejected`] on any warning
+    Strict,
+    /// Recover where possible and report what was done
+    #[default]
+    Lenient,
+}
+
+impl LoadMode {
+    /// Pass `report` through, or reject it in strict mode if it has warnings
+    pub fn check(self, report: LoadReport) -> Result<LoadReport> {
+        if self == LoadMode::Strict && report.has_warnings() {
+            return Err(SerializationError::LoadRejected(report));
+        }
+        Ok(report)
+    }
+}
+
+/// How serious a [`LoadIssue`] is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    /// Nothing was lost; the file was adapted to the current library
+    Info,
+    /// Loaded, but possibly not as saved (e.g. a placeholder or a guess)
+    Warning,
+    /// Part of the file could not be restored
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => f.write_str("info"),
+            Severity::Warning => f.write_str("warning"),
+            Severity::Error => f.write_str("error"),
+        }
+    }
+}
+
+/// A problem found, and recovered from where possible, while loading
+#[derive(Debug, Clone)]
+pub enum LoadIssue {
+    /// A child's operator is unknown; a placeholder keeps its data
+    MissingOperator { child: Id, symbol_ref: String },
+    /// A child used an operator's old name; the renamed operator was created
+    OperatorRenamed { child: Id, from: String, to: String },
+    /// A saved port didn't match the operator's current ports
+    Port(PortDiagnostic),
+    /// A saved input value was converted to the port's current type
+    ValueCoerced {
+        child: Id,
+        operator: String,
+        input: String,
+        from: ValueType,
+        to: ValueType,
+    },
+    /// A saved input value could not be converted and was not applied
+    ValueDropped {
+        child: Id,
+        operator: String,
+        input: String,
+        value_type: ValueType,
+    },
+    /// A saved connection could not be restored
+    ConnectionDropped(ConnectionDef),
+    /// The file was written by an older schema and upgraded
+    VersionMigrated {
+        from: SchemaVersion,
+        to: SchemaVersion,
+    },
+    /// The file was written by a newer schema; unknown fields were ignored
+    NewerVersion {
+        found: SchemaVersion,
+        supported: SchemaVersion,
+    },
+}
+
+impl LoadIssue {
+    /// Severity of this issue
+    pub fn severity(&self) -> Severity {
+        match self {
+            LoadIssue::VersionMigrated { .. } | LoadIssue::OperatorRenamed { .. } => {
+                Severity::Info
+            }
+            LoadIssue::Port(diagnostic) => match diagnostic.resolution {
+                PortResolution::Exact(_) | PortResolution::Moved(_) => Severity::Info,
+                PortResolution::IndexFallback(_) => Severity::Warning,
+                PortResolution::Missing => Severity::Error,
+            },
+            LoadIssue::MissingOperator { .. }
+            | LoadIssue::ValueCoerced { .. }
+            | LoadIssue::NewerVersion { .. } => Severity::Warning,
+            LoadIssue::ValueDropped { .. } | LoadIssue::ConnectionDropped(_) => Severity::Error,
+        }
+    }
+}
+
+impl fmt::Display for LoadIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LoadIssue::MissingOperator { symbol_ref, .. } => {
+                write!(
+                    f,
+                    "unknown operator '{}': replaced with a placeholder",
+                    symbol_ref
+                )
+            }
+            LoadIssue::OperatorRenamed { from, to, .. } => {
+                write!(f, "operator '{}' was renamed to '{}'", from, to)
+            }
+            LoadIssue::Port(diagnostic) => diagnostic.fmt(f),
+            LoadIssue::ValueCoerced {
+                operator,
+                input,
+                from,
+                to,
+                ..
+            } => write!(
+                f,
+                "input '{}' of {}: saved {} value converted to {}",
+                input, operator, from, to
+            ),
+            LoadIssue::ValueDropped {
+                operator,
+                input,
+                value_type,
+                ..
+            } => write!(
+                f,
+                "input '{}' of {}: saved {} value doesn't fit the port and was dropped",
+                input, operator, value_type
+            ),
+            LoadIssue::ConnectionDropped(conn) => {
+                let output = conn
+                    .source_output_name
+                    .clone()
+                    .unwrap_or_else(|| conn.source_output.to_string());
+                let input = conn
+                    .target_input_name
+                    .clone()
+                    .unwrap_or_else(|| conn.target_input.to_string());
+                write!(
+                    f,
+                    "connection from output '{}' to input '{}' could not be restored",
+                    output, input
+                )
+            }
+            LoadIssue::VersionMigrated { from, to } => {
+                write!(f, "migrated from schema {} to {}", from, to)
+            }
+            LoadIssue::NewerVersion { found, supported } => write!(
+                f,
+                "file uses schema {}, newer than the supported {}; unknown fields were ignored",
+                found, supported
+            ),
+        }
+    }
+}
+
+/// Every issue recovered from while loading, in the order found
+#[derive(Debug, Clone, Default)]
+pub struct LoadReport {
+    issues: Vec<LoadIssue>,
+}
+
+impl LoadReport {
+    /// Create an empty report
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an issue
+    pub fn push(&mut self, issue: LoadIssue) {
+        self.issues.push(issue);
+    }
+
+    /// Append the issues of another report
+    pub fn extend(&mut self, other: LoadReport) {
+        self.issues.extend(other.issues);
+    }
+
+    /// Issues in the order they were found
+    pub fn issues(&self) -> &[LoadIssue] {
+        &self.issues
+    }
+
+    /// Returns true if nothing was reported
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// Highest severity reported, or `None` for a clean report
+    pub fn max_severity(&self) -> Option<Severity> {
+        self.issues.iter().map(LoadIssue::severity).max()
+    }
+
+    /// Returns true if any issue is a warning or an error
+    pub fn has_warnings(&self) -> bool {
+        self.max_severity() >= Some(Severity::Warning)
+    }
+}
+
+impl fmt::Display for LoadReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, issue) in self.issues.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", issue.severity(), issue)?;
+        }
+        Ok(())
+    }
+}
+
+/// Check a file's schema version, migrating same-major versions to current
+///
+/// Files from another major version are rejected as before.
+pub(crate) fn check_version(version: &mut SchemaVersion, report: &mut LoadReport) -> Result<()> {
+    let current = SchemaVersion::CURRENT;
+    if version.major != current.major {
+        return Err(SerializationError::VersionMismatch {
+            file_major: version.major,
+            file_minor: version.minor,
+            expected_major: current.major,
+        });
+    }
+    if version.is_newer_than(&current) {
+        report.push(LoadIssue::NewerVersion {
+            found: *version,
+            supported: current,
+        });
+    } else if *version != current {
+        report.push(LoadIssue::VersionMigrated {
+            from: *version,
+            to: current,
+        });
+        *version = current;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::PortDirection;
+
+    fn port_issue(resolution: PortResolution) -> LoadIssue {
+        LoadIssue::Port(PortDiagnostic {
+            child: Id::new(),
+            operator: "Add".to_string(),
+            direction: PortDirection::Input,
+            saved_index: Some(0),
+            saved_name: Some("A".to_string()),
+            resolution,
+        })
+    }
+
+    #[test]
+    fn test_severities_and_strict_check() {
+        let mut report = LoadReport::new();
+        assert_eq!(report.max_severity(), None);
+        report.push(port_issue(PortResolution::Moved(1)));
+        assert!(!report.has_warnings());
+        let report = LoadMode::Strict.check(report).unwrap();
+
+        let mut warned = report.clone();
+        warned.push(port_issue(PortResolution::IndexFallback(0)));
+        assert_eq!(warned.max_severity(), Some(Severity::Warning));
+        assert!(LoadMode::Lenient.check(warned.clone()).is_ok());
+        let Err(SerializationError::LoadRejected(rejected)) = LoadMode::Strict.check(warned) else {
+            panic!("strict mode accepted a warning");
+        };
+        assert_eq!(rejected.issues().len(), 2);
+        assert_eq!(
+            rejected.to_string(),
+            "info: input 'A' (index 0) of Add: moved to index 1\n\
+             warning: input 'A' (index 0) of Add: name not found, using index 0"
+        );
+    }
+
+    #[test]
+    fn test_version_check() {
+        let mut report = LoadReport::new();
+        let mut newer = SchemaVersion::new(1, 3);
+        check_version(&mut newer, &mut report).unwrap();
+        assert_eq!(newer, SchemaVersion::new(1, 3));
+        assert_eq!(report.max_severity(), Some(Severity::Warning));
+
+        let mut other_major = SchemaVersion::new(2, 0);
+        assert!(matches!(
+            check_version(&mut other_major, &mut report),
+            Err(SerializationError::VersionMismatch { file_major: 2, .. })
+        ));
+    }
+}
@@ -6,10 +6,14 @@
 use serde::{Deserialize, Serialize};
 
 use flux_core::value::{Value, ValueType};
-use flux_core::Id;
+use flux_core::{GizmoVisibility, Id};
 
-use super::animation::AnimationDef;
+use super::animation::{AnimationDef, ExtrapolationMode};
+use super::template::TemplateParamDef;
 use super::version::SchemaVersion;
+use crate::animation::{Animator, LoopMode};
+use crate::symbol::ChildSlot;
+use crate::update_rate::UpdateRate;
 
 /// Symbol file schema (.rsym)
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,6 +74,9 @@ pub struct SymbolDef {
     /// Animation data
     #[serde(default)]
     pub animations: Vec<AnimationDef>,
+    /// Parameters substituted into input values at instantiation
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub template_params: Vec<TemplateParamDef>,
 
     /// UI metadata
     #[serde(default)]
@@ -90,6 +97,7 @@ impl SymbolDef {
             children: Vec::new(),
             connections: Vec::new(),
             animations: Vec::new(),
+            template_params: Vec::new(),
             ui: SymbolUiMeta::default(),
         }
     }
@@ -142,6 +150,37 @@ impl SymbolDef {
         self.connections.push(connection);
         self
     }
+
+    /// Build the default animation every instance of this symbol starts with
+    ///
+    /// Curves are bound to `(child ID, input index)`. The playback range
+    /// spans all keyframes; it loops when any curve cycles after its last
+    /// keyframe, ping-pongs when one oscillates, and holds the end otherwise.
+    pub fn animator(&self) -> Animator {
+        let ranges = self.animations.iter().filter_map(|a| a.curve.time_range());
+        let Some((start, end)) = ranges.reduce(|(s0, e0), (s1, e1)| (s0.min(s1), e0.max(e1)))
+        else {
+            return Animator::new();
+        };
+
+        let post = |modes: &[ExtrapolationMode]| {
+            self.animations.iter().any(|a| modes.contains(&a.curve.post_behavior))
+        };
+        let loop_mode = if post(&[ExtrapolationMode::Cycle, ExtrapolationMode::CycleOffset]) {
+            LoopMode::Loop
+        } else if post(&[ExtrapolationMode::Oscillate]) {
+            LoopMode::PingPong
+        } else {
+            LoopMode::Hold
+        };
+
+        let mut animator = Animator::with_range(start, end);
+        animator.set_loop_mode(loop_mode);
+        for animation in &self.animations {
+            animator.add_binding(animation.to_binding());
+        }
+        animator
+    }
 }
 
 /// Input slot definition
@@ -161,6 +200,9 @@ pub struct InputDef {
     /// Whether this accepts multiple connections
     #[serde(default)]
     pub is_multi_input: bool,
+    /// Child input this input drives, if it was promoted from one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target: Option<ChildSlot>,
     /// UI metadata
     #[serde(default)]
     pub ui: InputUiMeta,
@@ -176,6 +218,7 @@ impl InputDef {
             default,
             description: None,
             is_multi_input: false,
+            target: None,
             ui: InputUiMeta::default(),
         }
     }
@@ -223,6 +266,12 @@ impl InputDef {
         self.ui.max = Some(max);
         self
     }
+
+    /// Builder: drive a child's input from this input
+    pub fn with_target(mut self, child: Id, input_index: usize) -> Self {
+        self.target = Some(ChildSlot::new(child, input_index));
+        self
+    }
 }
 
 /// Output slot definition
@@ -237,6 +286,9 @@ pub struct OutputDef {
     /// Optional description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Child output this output reads from, if it was promoted from one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<ChildSlot>,
 }
 
 impl OutputDef {
@@ -247,9 +299,16 @@ impl OutputDef {
             name: name.to_string(),
             value_type,
             description: None,
+            source: None,
         }
     }
 
+    /// Builder: read this output from a child's output
+    pub fn with_source(mut self, child: Id, output_index: usize) -> Self {
+        self.source = Some(ChildSlot::new(child, output_index));
+        self
+    }
+
     /// Create a float output
     pub fn float(name: &str) -> Self {
         Self::new(name, ValueType::Float)
@@ -288,6 +347,12 @@ pub struct ChildDef {
     /// Whether this child is disabled
     #[serde(default)]
     pub is_disabled: bool,
+    /// How often this child is recomputed
+    #[serde(default, skip_serializing_if = "UpdateRate::is_every_frame")]
+    pub update_rate: UpdateRate,
+    /// Gizmo visibility override for this child
+    #[serde(default, skip_serializing_if = "GizmoVisibility::is_inherit")]
+    pub gizmo_visibility: GizmoVisibility,
 }
 
 impl ChildDef {
@@ -301,6 +366,8 @@ impl ChildDef {
             position: [0.0, 0.0],
             is_bypassed: false,
             is_disabled: false,
+            update_rate: UpdateRate::EveryFrame,
+            gizmo_visibility: GizmoVisibility::Inherit,
         }
     }
 
@@ -330,31 +397,117 @@ impl ChildDef {
 
     /// Builder: set an input value
     pub fn with_input(mut self, input_id: Id, value: Value) -> Self {
-        self.input_values.push(InputValueDef { input_id, value });
+        self.input_values.push(InputValueDef::new(input_id, value));
+        self
+    }
+
+    /// Builder: set an input value by port index and name
+    pub fn with_input_at(mut self, index: usize, name: &str, value: Value) -> Self {
+        self.input_values.push(InputValueDef::at_port(index, name, value));
+        self
+    }
+
+    /// Builder: bind an input to a context variable by port index and name
+    pub fn with_input_binding(
+        mut self,
+        index: usize,
+        name: &str,
+        context_var: &str,
+        fallback: Value,
+    ) -> Self {
+        let def = InputValueDef::at_port(index, name, fallback).bound_to(context_var);
+        self.input_values.push(def);
+        self
+    }
+
+    /// Builder: set the update rate
+    pub fn with_update_rate(mut self, rate: UpdateRate) -> Self {
+        self.update_rate = rate;
+        self
+    }
+
+    /// Builder: set the gizmo visibility
+    pub fn with_gizmo_visibility(mut self, visibility: GizmoVisibility) -> Self {
+        self.gizmo_visibility = visibility;
         self
     }
 }
 
 /// Input value override
+///
+/// Operator inputs are identified by index and name; loading resolves the
+/// name first so values survive ports being added or reordered.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InputValueDef {
     /// Input slot ID
     pub input_id: Id,
-    /// Override value
+    /// Input port index at save time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_index: Option<usize>,
+    /// Input port name at save time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input_name: Option<String>,
+    /// Override value (the fallback when bound to a context variable)
     pub value: Value,
+    /// Context variable the input reads instead of `value`, if bound
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context_var: Option<String>,
+}
+
+impl InputValueDef {
+    /// Create an override for a symbol input slot
+    pub fn new(input_id: Id, value: Value) -> Self {
+        Self {
+            input_id,
+            input_index: None,
+            input_name: None,
+            value,
+            context_var: None,
+        }
+    }
+
+    /// Create an override for an operator input port
+    pub fn at_port(index: usize, name: &str, value: Value) -> Self {
+        Self {
+            input_id: Id::new(),
+            input_index: Some(index),
+            input_name: Some(name.to_string()),
+            value,
+            context_var: None,
+        }
+    }
+
+    /// Builder: read the context variable `name`, with `value` as fallback
+    pub fn bound_to(mut self, name: &str) -> Self {
+        self.context_var = Some(name.to_string());
+        self
+    }
 }
 
 /// Connection between operators
+///
+/// Ports are stored by index and, for files written since port names were
+/// recorded, by name. Loading resolves the name first (see
+/// [`resolve_port`](super::ports::resolve_port)).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ConnectionDef {
     /// Source child ID (or special value for symbol inputs)
     pub source_child: Id,
     /// Source output index
     pub source_output: usize,
+    /// Source output name at save time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_output_name: Option<String>,
     /// Target child ID (or special value for symbol outputs)
     pub target_child: Id,
     /// Target input index
     pub target_input: usize,
+    /// Target input name at save time
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_input_name: Option<String>,
+    /// Whether the connection is muted
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub muted: bool,
 }
 
 impl ConnectionDef {
@@ -363,10 +516,26 @@ impl ConnectionDef {
         Self {
             source_child,
             source_output,
+            source_output_name: None,
             target_child,
             target_input,
+            target_input_name: None,
+            muted: false,
         }
     }
+
+    /// Builder: record the port names of both endpoints
+    pub fn with_port_names(mut self, source_output: &str, target_input: &str) -> Self {
+        self.source_output_name = Some(source_output.to_string());
+        self.target_input_name = Some(target_input.to_string());
+        self
+    }
+
+    /// Builder: set whether the connection is muted
+    pub fn with_muted(mut self, muted: bool) -> Self {
+        self.muted = muted;
+        self
+    }
 }
 
 /// Symbol UI metadata
@@ -449,6 +618,20 @@ mod tests {
         assert_eq!(child.position, [100.0, 50.0]);
     }
 
+    #[test]
+    fn test_child_def_update_rate_roundtrip() {
+        let child = ChildDef::builtin("analyze").with_update_rate(UpdateRate::EveryNFrames(4));
+        let json = serde_json::to_string(&child).unwrap();
+        let restored: ChildDef = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.update_rate, UpdateRate::EveryNFrames(4));
+
+        // Default rate is omitted and restored on load
+        let json = serde_json::to_string(&ChildDef::builtin("add")).unwrap();
+        assert!(!json.contains("update_rate"));
+        let restored: ChildDef = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.update_rate, UpdateRate::EveryFrame);
+    }
+
     #[test]
     fn test_symbol_file_serialize() {
         let mut symbol = SymbolDef::new("ColorPulse")
@@ -475,6 +658,22 @@ mod tests {
         assert_eq!(restored.symbol.outputs.len(), 1);
     }
 
+    #[test]
+    fn test_promoted_slots_roundtrip() {
+        let child = ChildDef::builtin("add");
+        let mut symbol = SymbolDef::new("Offset");
+        symbol.add_input(InputDef::float("Amount", 1.0).with_target(child.id, 1));
+        symbol.add_input(InputDef::float("Unmapped", 0.0));
+        symbol.add_output(OutputDef::float("Result").with_source(child.id, 0));
+        symbol.add_child(child.clone());
+
+        let json = serde_json::to_string(&symbol).unwrap();
+        let restored: SymbolDef = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.inputs[0].target, Some(ChildSlot::new(child.id, 1)));
+        assert_eq!(restored.inputs[1].target, None);
+        assert_eq!(restored.outputs[0].source, Some(ChildSlot::new(child.id, 0)));
+    }
+
     #[test]
     fn test_connection_def() {
         let source_id = Id::new();
@@ -0,0 +1,397 @@
+//! Graph templates with parameters substituted at instantiation
+//!
+//! A [`SymbolDef`] can declare template parameters, each with a type and
+//! an optional default. Saved input values refer to them as `${name}`: a
+//! String value that is exactly `"${universe}"` becomes the parameter's
+//! value, so Int and Float parameters can fill numeric inputs, and any other
+//! String value has each reference replaced by the parameter as text.
+//!
+//! ```ignore
+//! let mut def = SymbolDef::from_graph("Fixture", &graph, &registry);
+//! def.add_template_param(TemplateParamDef::new("universe", ValueType::Int));
+//! def.add_template_param(TemplateParamDef::new("label", ValueType::String)
+//!     .with_default(Value::String("Fixture".into())));
+//!
+//! for universe in 0..8 {
+//!     let params = HashMap::from([("universe".to_string(), Value::Int(universe))]);
+//!     let (graph, result) = instantiate_graph_with(&def, &registry, &params)?;
+//! }
+//! ```
+//!
+//! [`SymbolDef::instantiate`] substitutes the declared defaults, so template
+//! files load through the usual paths; references to required parameters are
+//! then left as they were saved.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use flux_core::value::{Value, ValueType};
+use flux_operators::OperatorRegistry;
+
+use super::error::{Result, SerializationError};
+use super::instantiate::InstantiateResult;
+use super::symbol::SymbolDef;
+use crate::graph::Graph;
+
+/// A named parameter of a template graph
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TemplateParamDef {
+    pub name: String,
+    pub value_type: ValueType,
+    /// Value used when the parameter isn't given; `None` makes it required
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+}
+
+impl TemplateParamDef {
+    /// Create a required parameter
+    pub fn new(name: &str, value_type: ValueType) -> Self {
+        Self {
+            name: name.to_string(),
+            value_type,
+            default: None,
+        }
+    }
+
+    /// Builder: make the parameter optional with a default
+    pub fn with_default(mut self, default: Value) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// `value` as this parameter's type
+    ///
+    /// Int and Float convert into each other; other types must match.
+    fn check(&self, value: &Value) -> Result<Value> {
+        let actual = value.value_type();
+        if actual == self.value_type {
+            return Ok(value.clone());
+        }
+        let numeric = |t: ValueType| matches!(t, ValueType::Int | ValueType::Float);
+        if numeric(actual) && numeric(self.value_type) {
+            if let Some(value) = value.coerce_to(self.value_type) {
+                return Ok(value);
+            }
+        }
+        Err(SerializationError::TemplateParamType {
+            name: self.name.clone(),
+            expected: self.value_type,
+            actual,
+        })
+    }
+}
+
+impl SymbolDef {
+    /// Declare a template parameter
+    pub fn add_template_param(&mut self, param: TemplateParamDef) -> &mut Self {
+        self.template_params.push(param);
+        self
+    }
+
+    /// Value of every declared parameter, from `params` or the defaults
+    ///
+    /// Fails on a parameter that isn't declared, a required parameter
+    /// missing from `params`, or a value of the wrong type.
+    pub fn template_values(
+        &self,
+        params: &HashMap<String, Value>,
+    ) -> Result<HashMap<String, Value>> {
+        if let Some(unknown) = params
+            .keys()
+            .find(|name| !self.template_params.iter().any(|p| &p.name == *name))
+        {
+            return Err(SerializationError::UnknownTemplateParam(unknown.clone()));
+        }
+        self.template_params
+            .iter()
+            .map(|param| {
+                let value = match (params.get(&param.name), &param.default) {
+                    (Some(value), _) | (None, Some(value)) => param.check(value)?,
+                    (None, None) => {
+                        return Err(SerializationError::MissingTemplateParam(param.name.clone()))
+                    }
+                };
+                Ok((param.name.clone(), value))
+            })
+            .collect()
+    }
+
+    /// A copy with every parameter reference substituted
+    ///
+    /// The copy declares no parameters, so it saves as a plain graph.
+    pub fn substitute(&self, params: &HashMap<String, Value>) -> Result<SymbolDef> {
+        let values = self.template_values(params)?;
+        let substitute = |value: &mut Value| match substitute_value(value, &values) {
+            Ok(Some(substituted)) => {
+                *value = substituted;
+                Ok(())
+            }
+            Ok(None) => Ok(()),
+            Err(name) => Err(SerializationError::UnknownTemplateParam(name)),
+        };
+
+        let mut def = self.clone();
+        def.template_params.clear();
+        for input in &mut def.inputs {
+            substitute(&mut input.default)?;
+        }
+        for child in &mut def.children {
+            for input in &mut child.input_values {
+                substitute(&mut input.value)?;
+            }
+        }
+        Ok(def)
+    }
+
+    /// A copy with the declared defaults substituted where possible
+    ///
+    /// References to required or undeclared parameters are left unchanged.
+    pub(crate) fn with_template_defaults(&self) -> SymbolDef {
+        let values: HashMap<String, Value> = self
+            .template_params
+            .iter()
+            .filter_map(|param| {
+                let value = param.check(param.default.as_ref()?).ok()?;
+                Some((param.name.clone(), value))
+            })
+            .collect();
+        let substitute = |value: &mut Value| {
+            if let Ok(Some(substituted)) = substitute_value(value, &values) {
+                *value = substituted;
+            }
+        };
+
+        let mut def = self.clone();
+        for input in &mut def.inputs {
+            substitute(&mut input.default);
+        }
+        for child in &mut def.children {
+            for input in &mut child.input_values {
+                substitute(&mut input.value);
+            }
+        }
+        def
+    }
+}
+
+/// Instantiate a template into a new graph with `params` substituted
+///
+/// Parameters missing from `params` take their declared defaults. Fails
+/// before creating anything if a parameter is required but missing, has
+/// the wrong type, or isn't declared.
+pub fn instantiate_graph_with(
+    def: &SymbolDef,
+    registry: &OperatorRegistry,
+    params: &HashMap<String, Value>,
+) -> Result<(Graph, InstantiateResult)> {
+    let def = def.substitute(params)?;
+    let mut graph = Graph::new();
+    let result = def.instantiate(&mut graph, registry);
+    Ok((graph, result))
+}
+
+/// Substitute the parameter references in a saved value
+///
+/// Returns `Ok(None)` if the value has no references, and the name of the
+/// first parameter missing from `values` as the error.
+fn substitute_value(
+    value: &Value,
+    values: &HashMap<String, Value>,
+) -> std::result::Result<Option<Value>, String> {
+    let Some(text) = value.as_string() else {
+        return Ok(None);
+    };
+    let references = references(text);
+    if references.is_empty() {
+        return Ok(None);
+    }
+
+    // A lone reference takes the parameter's value and type
+    if let [(range, name)] = &references[..] {
+        if *range == (0..text.len()) {
+            return values.get(*name).cloned().map(Some).ok_or(name.to_string());
+        }
+    }
+
+    let mut out = String::with_capacity(text.len());
+    let mut end = 0;
+    for (range, name) in references {
+        let value = values.get(name).ok_or(name.to_string())?;
+        out.push_str(&text[end..range.start]);
+        match value.as_string() {
+            Some(s) => out.push_str(s),
+            None => out.push_str(&value.to_string()),
+        }
+        end = range.end;
+    }
+    out.push_str(&text[end..]);
+    Ok(Some(Value::String(out)))
+}
+
+/// Byte range and name of every `${name}` in `text`
+fn references(text: &str) -> Vec<(std::ops::Range<usize>, &str)> {
+    let mut found = Vec::new();
+    let mut from = 0;
+    while let Some(start) = text[from..].find("${").map(|i| from + i) {
+        let Some(len) = text[start + 2..].find('}') else {
+            break;
+        };
+        let end = start + 2 + len + 1;
+        let name = text[start + 2..end - 1].trim();
+        if !name.is_empty() {
+            found.push((start..end, name));
+        }
+        from = end;
+    }
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::serialization::{load_symbol_str, save_symbol_str, ChildDef, SymbolFile};
+    use flux_core::context::EvalContext;
+    use flux_operators::create_default_registry;
+
+    /// `Index * 10` and `"${label} #${fixture_index}"`, with `label` optional
+    fn fixture_template() -> SymbolDef {
+        let mut def = SymbolDef::new("Fixture");
+        def.add_template_param(TemplateParamDef::new("fixture_index", ValueType::Int));
+        def.add_template_param(
+            TemplateParamDef::new("label", ValueType::String)
+                .with_default(Value::String("Fixture".into())),
+        );
+        def.add_child(
+            ChildDef::builtin("Multiply")
+                .with_input_at(0, "A", Value::String("${fixture_index}".into()))
+                .with_input_at(1, "B", Value::Float(10.0)),
+        );
+        def.add_child(ChildDef::builtin("StringConcat").with_input_at(
+            0,
+            "A",
+            Value::String("${label} #${fixture_index}".into()),
+        ));
+        def
+    }
+
+    fn outputs(graph: &mut Graph) -> (Value, Value) {
+        let ctx = EvalContext::new();
+        let find = |graph: &Graph, name: &str| {
+            graph
+                .node_ids()
+                .find(|&id| graph.get(id).unwrap().name() == name)
+                .unwrap()
+        };
+        let multiply = find(graph, "Multiply");
+        let concat = find(graph, "StringConcat");
+        (
+            graph.evaluate(multiply, 0, &ctx).unwrap(),
+            graph.evaluate(concat, 0, &ctx).unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_instances_reflect_parameters() {
+        let registry = create_default_registry();
+        let json = save_symbol_str(&SymbolFile::from_def(fixture_template())).unwrap();
+        assert!(json.contains("\"template_params\""));
+        let def = load_symbol_str(&json).unwrap().symbol;
+        assert_eq!(def.template_params, fixture_template().template_params);
+
+        let params = HashMap::from([("fixture_index".to_string(), Value::Int(3))]);
+        let (mut first, result) = instantiate_graph_with(&def, &registry, &params).unwrap();
+        assert!(result.report.is_clean(), "{}", result.report);
+        assert_eq!(
+            outputs(&mut first),
+            (Value::Float(30.0), Value::String("Fixture #3".into()))
+        );
+
+        let params = HashMap::from([
+            ("fixture_index".to_string(), Value::Int(7)),
+            ("label".to_string(), Value::String("Wash".into())),
+        ]);
+        let (mut second, _) = instantiate_graph_with(&def, &registry, &params).unwrap();
+        assert_eq!(
+            outputs(&mut second),
+            (Value::Float(70.0), Value::String("Wash #7".into()))
+        );
+    }
+
+    #[test]
+    fn test_parameter_errors_name_the_parameter() {
+        let registry = create_default_registry();
+        let def = fixture_template();
+        let error_for = |params: &[(&str, Value)]| {
+            let params = params
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.clone()))
+                .collect();
+            match instantiate_graph_with(&def, &registry, &params) {
+                Ok(_) => panic!("instantiated with {:?}", params),
+                Err(error) => error,
+            }
+        };
+
+        let error = error_for(&[]);
+        assert!(
+            matches!(&error, SerializationError::MissingTemplateParam(name) if name == "fixture_index")
+        );
+        assert_eq!(
+            error.to_string(),
+            "Missing template parameter: fixture_index"
+        );
+
+        let error = error_for(&[("fixture_index", Value::String("3".into()))]);
+        assert!(matches!(
+            &error,
+            SerializationError::TemplateParamType { name, expected: ValueType::Int, .. }
+                if name == "fixture_index"
+        ));
+
+        let error = error_for(&[
+            ("fixture_index", Value::Float(2.0)),
+            ("universe", Value::Int(1)),
+        ]);
+        assert_eq!(error.to_string(), "Unknown template parameter: universe");
+    }
+
+    #[test]
+    fn test_plain_instantiate_uses_defaults() {
+        let registry = create_default_registry();
+        let mut def = fixture_template();
+        def.template_params[0].default = Some(Value::Int(1));
+
+        let mut graph = Graph::new();
+        def.instantiate(&mut graph, &registry);
+        assert_eq!(
+            outputs(&mut graph),
+            (Value::Float(10.0), Value::String("Fixture #1".into()))
+        );
+    }
+
+    #[test]
+    fn test_references() {
+        let found: Vec<&str> = references("${a}-${ b }-${}-${c")
+            .into_iter()
+            .map(|(_, n)| n)
+            .collect();
+        assert_eq!(found, ["a", "b"]);
+
+        let values = HashMap::from([("x".to_string(), Value::Float(1.5))]);
+        let text = |s: &str| Value::String(s.into());
+        assert_eq!(
+            substitute_value(&text("${x}"), &values),
+            Ok(Some(Value::Float(1.5)))
+        );
+        assert_eq!(
+            substitute_value(&text("x=${x}!"), &values),
+            Ok(Some(text("x=1.5!")))
+        );
+        assert_eq!(substitute_value(&text("plain"), &values), Ok(None));
+        assert_eq!(
+            substitute_value(&text("${y}"), &values),
+            Err("y".to_string())
+        );
+    }
+}
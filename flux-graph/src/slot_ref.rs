@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use flux_core::id::Id;
+use flux_core::id::{Id, InputRef, OutputRef};
 use crate::instance_path::InstancePath;
 
 /// Reference to a slot on an operator instance
@@ -62,6 +62,34 @@ impl SlotRef {
     pub fn depth(&self) -> usize {
         self.instance_path.depth()
     }
+
+    /// The leaf node's output as a typed reference (`None` for inputs)
+    pub fn output_ref(&self) -> Option<OutputRef> {
+        if !self.is_output {
+            return None;
+        }
+        Some(OutputRef::new(self.node_id()?, self.slot_index))
+    }
+
+    /// The leaf node's input as a typed reference (`None` for outputs)
+    pub fn input_ref(&self) -> Option<InputRef> {
+        if self.is_output {
+            return None;
+        }
+        Some(InputRef::new(self.node_id()?, self.slot_index))
+    }
+}
+
+impl From<OutputRef> for SlotRef {
+    fn from(output: OutputRef) -> Self {
+        Self::simple_output(output.node.id(), output.index.get())
+    }
+}
+
+impl From<InputRef> for SlotRef {
+    fn from(input: InputRef) -> Self {
+        Self::simple_input(input.node.id(), input.index.get())
+    }
 }
 
 impl std::fmt::Display for SlotRef {
@@ -103,6 +131,11 @@ impl Connection {
         )
     }
 
+    /// Create a connection between two typed references in a flat graph
+    pub fn from_refs(source: OutputRef, target: InputRef) -> Self {
+        Self::new(source.into(), target.into())
+    }
+
     /// Check if this connection crosses composite boundaries
     pub fn is_cross_boundary(&self) -> bool {
         self.source.is_nested() || self.target.is_nested()
@@ -130,6 +163,22 @@ mod tests {
         assert!(!slot_ref.is_nested());
     }
 
+    #[test]
+    fn test_slot_ref_typed_conversions() {
+        let node_id = Id::new();
+        let output = OutputRef::new(node_id, 2);
+        let slot_ref = SlotRef::from(output);
+        assert_eq!(slot_ref, SlotRef::simple_output(node_id, 2));
+        assert_eq!(slot_ref.output_ref(), Some(output));
+        assert_eq!(slot_ref.input_ref(), None);
+
+        let input = InputRef::new(node_id, 1);
+        assert_eq!(SlotRef::from(input).input_ref(), Some(input));
+
+        let connection = Connection::from_refs(output, input);
+        assert_eq!(connection, Connection::simple(node_id, 2, node_id, 1));
+    }
+
     #[test]
     fn test_slot_ref_nested() {
         let parent = Id::new();
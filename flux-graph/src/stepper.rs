@@ -0,0 +1,241 @@
+//! Fixed-timestep stepping for host frame loops
+//!
+//! Simulation-like graphs (integrators, physics, smoothing) behave the same
+//! on every machine only when they are stepped at a fixed rate, while the
+//! display runs at whatever rate it gets. [`FrameStepper`] accumulates wall
+//! time and says how many fixed steps are due; the host evaluates once per
+//! step and then renders between the last two simulated states using
+//! [`FrameStepper::alpha`] and [`Graph::blend_outputs`].
+//!
+//! A slow frame could otherwise demand more steps than the host can run in
+//! one frame, making the next frame slower still. Steps per
+//! [`advance`](FrameStepper::advance) are capped; time beyond the cap is
+//! dropped, so the simulation slows down instead.
+//!
+//! ```ignore
+//! let mut stepper = FrameStepper::new(120.0);
+//! let watch = graph.watch_output(position, 0)?;
+//! let mut previous = graph.watch_values();
+//!
+//! // Every display frame:
+//! for _ in 0..stepper.advance(wall_dt) {
+//!     previous = graph.watch_values();
+//!     graph.evaluate(position, 0, stepper.begin_step())?;
+//! }
+//! let shown = graph.blend_outputs(&previous, stepper.alpha());
+//! draw(&shown[&watch]);
+//! ```
+//!
+//! [`Graph::blend_outputs`]: crate::Graph::blend_outputs
+
+use flux_core::EvalContext;
+
+/// Steps per [`FrameStepper::advance`] unless changed with
+/// [`FrameStepper::with_max_steps`]
+const DEFAULT_MAX_STEPS: u32 = 8;
+
+/// Turns variable wall-clock frame times into fixed simulation steps.
+///
+/// Wraps the [`EvalContext`] the graph is evaluated with; each
+/// [`begin_step`](Self::begin_step) advances its time by exactly one step
+/// and its frame counter by one.
+#[derive(Debug)]
+pub struct FrameStepper {
+    /// Context advanced by every step
+    ctx: EvalContext,
+    /// Duration of one step in seconds
+    step: f64,
+    /// Wall time not yet simulated (less than one step after `advance`)
+    accumulator: f64,
+    /// Most steps a single `advance` returns
+    max_steps: u32,
+    /// Steps skipped because of `max_steps`
+    dropped_steps: u64,
+}
+
+impl FrameStepper {
+    /// Create a stepper running at `rate` steps per second, with a fresh context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not a positive, finite number.
+    pub fn new(rate: f64) -> Self {
+        Self::with_context(EvalContext::new(), rate)
+    }
+
+    /// Create a stepper running at `rate` steps per second from `ctx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rate` is not a positive, finite number.
+    pub fn with_context(ctx: EvalContext, rate: f64) -> Self {
+        assert!(
+            rate.is_finite() && rate > 0.0,
+            "FrameStepper rate must be positive, got {}",
+            rate
+        );
+        Self {
+            ctx,
+            step: 1.0 / rate,
+            accumulator: 0.0,
+            max_steps: DEFAULT_MAX_STEPS,
+            dropped_steps: 0,
+        }
+    }
+
+    /// Set the most steps a single [`advance`](Self::advance) returns (at least 1).
+    pub fn with_max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
+    /// Steps per second.
+    pub fn rate(&self) -> f64 {
+        1.0 / self.step
+    }
+
+    /// Duration of one step in seconds.
+    pub fn step_duration(&self) -> f64 {
+        self.step
+    }
+
+    /// Add `wall_dt` seconds of wall time and return how many steps are due.
+    ///
+    /// Call [`begin_step`](Self::begin_step) that many times. If more than
+    /// the maximum are due, the excess whole steps are dropped (see
+    /// [`dropped_steps`](Self::dropped_steps)). Negative or NaN `wall_dt`
+    /// counts as zero.
+    pub fn advance(&mut self, wall_dt: f64) -> u32 {
+        if wall_dt > 0.0 {
+            self.accumulator += wall_dt;
+        }
+        let due = (self.accumulator / self.step).floor();
+        self.accumulator = (self.accumulator - due * self.step).max(0.0);
+        let due = due as u64;
+        let steps = due.min(u64::from(self.max_steps));
+        self.dropped_steps += due - steps;
+        steps as u32
+    }
+
+    /// Advance the context by one step and return it for evaluation.
+    pub fn begin_step(&mut self) -> &EvalContext {
+        self.ctx.advance(self.step);
+        &self.ctx
+    }
+
+    /// How far wall time has moved past the last step, as a fraction of a
+    /// step (0.0 - 1.0).
+    ///
+    /// Use it to interpolate from the state before the last step (0.0)
+    /// to the state after it (1.0).
+    pub fn alpha(&self) -> f64 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+
+    /// Total steps dropped so far because an advance exceeded the maximum.
+    pub fn dropped_steps(&self) -> u64 {
+        self.dropped_steps
+    }
+
+    /// The context as of the last step.
+    pub fn context(&self) -> &EvalContext {
+        &self.ctx
+    }
+
+    /// Mutable access to the context, e.g. to set variables between steps.
+    pub fn context_mut(&mut self) -> &mut EvalContext {
+        &mut self.ctx
+    }
+
+    /// Discard accumulated wall time, e.g. after a pause.
+    pub fn reset_accumulator(&mut self) {
+        self.accumulator = 0.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Graph;
+    use flux_core::Value;
+    use flux_operators::TimeOp;
+
+    #[test]
+    fn test_advance_at_120hz() {
+        let mut stepper = FrameStepper::new(120.0);
+        let steps = stepper.advance(0.033);
+        assert_eq!(steps, 3);
+        // 33ms - 3 * 8.33ms = 8ms left, just under one step
+        assert!((stepper.alpha() - 0.96).abs() < 1e-9);
+
+        for _ in 0..steps {
+            stepper.begin_step();
+        }
+        assert_eq!(stepper.context().frame, 3);
+        assert!((stepper.context().time - 0.025).abs() < 1e-12);
+
+        // The residual carries over into the next frame
+        assert_eq!(stepper.advance(0.001), 1);
+        assert!((stepper.alpha() - 0.08).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_max_steps_drops_excess() {
+        let mut stepper = FrameStepper::new(100.0).with_max_steps(4);
+        assert_eq!(stepper.advance(1.005), 4);
+        assert_eq!(stepper.dropped_steps(), 96);
+        assert!((stepper.alpha() - 0.5).abs() < 1e-6);
+
+        assert_eq!(stepper.advance(-1.0), 0);
+        assert_eq!(stepper.advance(f64::NAN), 0);
+        assert!((stepper.alpha() - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_blended_watch_lies_between_steps() {
+        let mut graph = Graph::new();
+        let time = graph.add(TimeOp::new());
+        let watch = graph.watch_output(time, 0).unwrap();
+        let mut stepper = FrameStepper::new(120.0);
+
+        let mut previous = graph.watch_values();
+        for _ in 0..stepper.advance(0.033) {
+            previous = graph.watch_values();
+            graph.evaluate(time, 0, stepper.begin_step()).unwrap();
+        }
+        let before = previous[&watch].as_float().unwrap();
+        let after = graph.watch_values()[&watch].as_float().unwrap();
+        let shown = graph.blend_outputs(&previous, stepper.alpha())[&watch]
+            .as_float()
+            .unwrap();
+
+        assert!(before < shown && shown < after);
+        let expected = before + (after - before) * stepper.alpha() as f32;
+        assert!((shown - expected).abs() < 1e-6);
+
+        // Endpoints are exact
+        assert_eq!(
+            graph.blend_outputs(&previous, 0.0)[&watch],
+            Value::Float(before)
+        );
+        assert_eq!(
+            graph.blend_outputs(&previous, 1.0)[&watch],
+            Value::Float(after)
+        );
+    }
+
+    #[test]
+    fn test_blend_takes_latest_for_missing_or_non_numeric() {
+        let mut graph = Graph::new();
+        let time = graph.add(TimeOp::new());
+        let watch = graph.watch_output(time, 0).unwrap();
+        graph.evaluate(time, 0, &EvalContext::new()).unwrap();
+
+        let latest = graph.watch_values()[&watch].clone();
+        let blended = graph.blend_outputs(&Default::default(), 0.5);
+        assert_eq!(blended[&watch], latest);
+
+        let strings = [(watch, Value::String("a".into()))].into_iter().collect();
+        assert_eq!(graph.blend_outputs(&strings, 0.5)[&watch], latest);
+    }
+}
@@ -1,8 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
-use super::{InputDefinition, Instance, OutputDefinition, SymbolChild};
+use super::{ChildSlot, InputDefinition, Instance, OutputDefinition, SymbolChild, SymbolRegistry};
 use crate::animation::Animator;
 use flux_core::id::Id;
 use crate::instance_path::InstancePath;
@@ -41,7 +41,10 @@ pub struct Symbol {
     /// Connections between children
     pub connections: Vec<Connection>,
 
-    /// Animation data for this symbol
+    /// Animation of child inputs, keyed by child ID, that every instance starts with
+    ///
+    /// Build it from a symbol file with
+    /// [`SymbolDef::animator`](crate::serialization::SymbolDef::animator).
     #[serde(skip)]
     pub animator: Animator,
 
@@ -158,6 +161,118 @@ impl Symbol {
         }
     }
 
+    // ========== Promotion ==========
+
+    /// Expose a child's input as a symbol-level input
+    ///
+    /// The new input takes the child input's type and current value (its
+    /// override, or the child symbol's default) and records the mapping so
+    /// instances and composites drive the child input from it.
+    /// `registry` is used to look up the child's symbol.
+    pub fn promote_child_input(
+        &mut self,
+        registry: &SymbolRegistry,
+        child_id: Id,
+        input_index: usize,
+        exposed_name: impl Into<String>,
+    ) -> Result<InputDefinition, SymbolError> {
+        let exposed_name = exposed_name.into();
+        if self.get_input_by_name(&exposed_name).is_some() {
+            return Err(SymbolError::DuplicateName(exposed_name));
+        }
+        let slot = ChildSlot::new(child_id, input_index);
+        if self.input_definitions.iter().any(|i| i.target == Some(slot)) {
+            return Err(SymbolError::AlreadyPromoted(child_id, input_index));
+        }
+
+        let child = self.get_child(child_id).ok_or(SymbolError::ChildNotFound(child_id))?;
+        let child_symbol = registry
+            .get(child.symbol_id)
+            .ok_or(SymbolError::SymbolNotFound(child.symbol_id))?;
+        let child_input = child_symbol
+            .get_input_at(input_index)
+            .ok_or(SymbolError::ChildSlotNotFound(child_id, input_index))?;
+
+        let default = match child.get_input_value(child_input.id) {
+            Some(value) if child.has_input_override(child_input.id) => value.clone(),
+            _ => child_input.default_value.clone(),
+        };
+
+        let mut input = InputDefinition::new(exposed_name, child_input.value_type, default);
+        input.is_multi_input = child_input.is_multi_input;
+        input.description = child_input.description.clone();
+        input.target = Some(slot);
+
+        self.input_definitions.push(input.clone());
+        Ok(input)
+    }
+
+    /// Expose a child's output as a symbol-level output
+    pub fn promote_child_output(
+        &mut self,
+        registry: &SymbolRegistry,
+        child_id: Id,
+        output_index: usize,
+        exposed_name: impl Into<String>,
+    ) -> Result<OutputDefinition, SymbolError> {
+        let exposed_name = exposed_name.into();
+        if self.get_output_by_name(&exposed_name).is_some() {
+            return Err(SymbolError::DuplicateName(exposed_name));
+        }
+
+        let child = self.get_child(child_id).ok_or(SymbolError::ChildNotFound(child_id))?;
+        let child_symbol = registry
+            .get(child.symbol_id)
+            .ok_or(SymbolError::SymbolNotFound(child.symbol_id))?;
+        let child_output = child_symbol
+            .get_output_at(output_index)
+            .ok_or(SymbolError::ChildSlotNotFound(child_id, output_index))?;
+
+        let mut output = OutputDefinition::new(exposed_name, child_output.value_type);
+        output.dirty_flag_trigger = child_output.dirty_flag_trigger;
+        output.description = child_output.description.clone();
+        output.source = Some(ChildSlot::new(child_id, output_index));
+
+        self.output_definitions.push(output.clone());
+        Ok(output)
+    }
+
+    /// Remove a promoted input or output by name
+    ///
+    /// A demoted input's current default is kept as an override on the
+    /// child input, so the child behaves as before. `registry` is used to
+    /// look up the child's symbol.
+    pub fn demote(&mut self, registry: &SymbolRegistry, name: &str) -> Result<(), SymbolError> {
+        if let Some(idx) = self
+            .input_definitions
+            .iter()
+            .position(|i| i.name == name && i.target.is_some())
+        {
+            let input = self.input_definitions.remove(idx);
+            let slot = input.target.and_then(|t| Some((t, self.children.get_mut(&t.child_id)?)));
+            if let Some((slot, child)) = slot {
+                let child_input = registry
+                    .get(child.symbol_id)
+                    .and_then(|symbol| symbol.get_input_at(slot.slot_index).map(|i| i.id));
+                if let Some(input_id) = child_input {
+                    child.set_input_value(input_id, input.default_value);
+                }
+            }
+            return Ok(());
+        }
+
+        if let Some(idx) = self
+            .output_definitions
+            .iter()
+            .position(|o| o.name == name && o.source.is_some())
+        {
+            self.output_definitions.remove(idx);
+            return Ok(());
+        }
+
+        Err(SymbolError::NotPromoted(name.to_string()))
+    }
+
     // ========== Child Management ==========
 
     /// Add a child operator
@@ -179,10 +294,14 @@ impl Symbol {
 
     /// Remove a child
     pub fn remove_child(&mut self, child_id: Id) -> Option<SymbolChild> {
-        // Also remove any connections involving this child
+        // Also remove any connections and promotions involving this child
         self.connections.retain(|c| {
             c.source.node_id() != Some(child_id) && c.target.node_id() != Some(child_id)
         });
+        self.input_definitions
+            .retain(|i| i.target.map(|t| t.child_id) != Some(child_id));
+        self.output_definitions
+            .retain(|o| o.source.map(|s| s.child_id) != Some(child_id));
         self.children.remove(&child_id)
     }
 
@@ -257,7 +376,45 @@ impl Symbol {
 
     // ========== Instance Creation ==========
 
+    /// Check that this symbol doesn't contain itself through its children.
+    ///
+    /// Child symbols are resolved through `symbols`; children whose symbol
+    /// isn't registered are ignored. Returns
+    /// [`SymbolError::RecursiveDefinition`] naming the first cycle found.
+    pub fn check_recursion(&self, symbols: &SymbolRegistry) -> Result<(), SymbolError> {
+        fn visit(
+            symbol: &Symbol,
+            symbols: &SymbolRegistry,
+            stack: &mut Vec<(Id, String)>,
+            checked: &mut HashSet<Id>,
+        ) -> Result<(), SymbolError> {
+            stack.push((symbol.id, symbol.name.clone()));
+            for child in symbol.children.values() {
+                if let Some(start) = stack.iter().position(|(id, _)| *id == child.symbol_id) {
+                    let mut cycle: Vec<String> =
+                        stack[start..].iter().map(|(_, name)| name.clone()).collect();
+                    cycle.push(stack[start].1.clone());
+                    return Err(SymbolError::RecursiveDefinition(cycle));
+                }
+                if checked.contains(&child.symbol_id) {
+                    continue;
+                }
+                if let Some(child_symbol) = symbols.get(child.symbol_id) {
+                    visit(&child_symbol, symbols, stack, checked)?;
+                }
+            }
+            stack.pop();
+            checked.insert(symbol.id);
+            Ok(())
+        }
+
+        visit(self, symbols, &mut Vec::new(), &mut HashSet::new())
+    }
+
     /// Create a runtime instance from this symbol
+    ///
+    /// Children are not resolved, so nesting isn't validated; use
+    /// [`SymbolRegistry::create_instance`] to reject recursive definitions.
     pub fn create_instance(&self) -> Instance {
         Instance::from_symbol(self, InstancePath::root(self.id))
     }
@@ -279,6 +436,20 @@ pub enum SymbolError {
     InputNotFound(Id),
     /// Output not found
     OutputNotFound(Id),
+    /// A symbol referenced by a child is not registered
+    SymbolNotFound(Id),
+    /// A child has no input/output at the given index
+    ChildSlotNotFound(Id, usize),
+    /// An input or output with this name already exists
+    DuplicateName(String),
+    /// The child input is already promoted
+    AlreadyPromoted(Id, usize),
+    /// No promoted input or output has this name
+    NotPromoted(String),
+    /// No operator is registered under the child symbol's name
+    OperatorNotFound(String),
+    /// The symbol contains itself; names the cycle, starting and ending with the same symbol
+    RecursiveDefinition(Vec<String>),
 }
 
 impl std::fmt::Display for SymbolError {
@@ -288,6 +459,19 @@ impl std::fmt::Display for SymbolError {
             SymbolError::InvalidConnection => write!(f, "Invalid connection"),
             SymbolError::InputNotFound(id) => write!(f, "Input not found: {:?}", id),
             SymbolError::OutputNotFound(id) => write!(f, "Output not found: {:?}", id),
+            SymbolError::SymbolNotFound(id) => write!(f, "Symbol not found: {:?}", id),
+            SymbolError::ChildSlotNotFound(id, index) => {
+                write!(f, "Child {:?} has no slot {}", id, index)
+            }
+            SymbolError::DuplicateName(name) => write!(f, "Duplicate slot name: {}", name),
+            SymbolError::AlreadyPromoted(id, index) => {
+                write!(f, "Input {} of child {:?} is already promoted", index, id)
+            }
+            SymbolError::NotPromoted(name) => write!(f, "No promoted slot named {}", name),
+            SymbolError::OperatorNotFound(name) => write!(f, "Operator not found: {}", name),
+            SymbolError::RecursiveDefinition(cycle) => {
+                write!(f, "Recursive symbol definition: {}", cycle.join(" > "))
+            }
         }
     }
 }
@@ -297,7 +481,7 @@ impl std::error::Error for SymbolError {}
 #[cfg(test)]
 mod tests {
     use super::*;
-    
+    use flux_core::value::{Value, ValueType};
 
     #[test]
     fn test_symbol_creation() {
@@ -343,4 +527,61 @@ mod tests {
         parent.remove_child(child_id);
         assert_eq!(parent.child_count(), 0);
     }
+
+    fn add_symbol() -> Symbol {
+        let mut symbol = Symbol::new("Add");
+        symbol.add_input(InputDefinition::float("A", 0.0));
+        symbol.add_input(InputDefinition::float("B", 0.0));
+        symbol.add_output(OutputDefinition::float("Result"));
+        symbol
+    }
+
+    #[test]
+    fn test_promote_and_demote_child_input() {
+        let registry = SymbolRegistry::new();
+        let add = add_symbol();
+        let b_id = add.input_definitions[1].id;
+        let add_id = registry.register(add);
+
+        let mut parent = Symbol::new("Parent");
+        let mut first = SymbolChild::new(Id::new(), add_id);
+        first.set_input_value(b_id, Value::Float(3.0));
+        let first = parent.add_child(first);
+        let second = parent.add_child(SymbolChild::new(Id::new(), add_id));
+
+        let input = parent.promote_child_input(&registry, first, 1, "Offset").unwrap();
+        assert_eq!(input.value_type, ValueType::Float);
+        assert_eq!(input.default_value, Value::Float(3.0));
+        assert_eq!(input.target, Some(ChildSlot::new(first, 1)));
+
+        assert_eq!(
+            parent.promote_child_input(&registry, second, 0, "Offset").unwrap_err(),
+            SymbolError::DuplicateName("Offset".into())
+        );
+        assert_eq!(
+            parent.promote_child_input(&registry, first, 1, "Other").unwrap_err(),
+            SymbolError::AlreadyPromoted(first, 1)
+        );
+        assert_eq!(
+            parent.promote_child_input(&registry, second, 5, "Other").unwrap_err(),
+            SymbolError::ChildSlotNotFound(second, 5)
+        );
+        parent.promote_child_output(&registry, second, 0, "Sum").unwrap();
+
+        let instance = parent.create_instance();
+        assert_eq!(instance.input_targets, vec![Some(ChildSlot::new(first, 1))]);
+        assert_eq!(instance.output_sources, vec![Some(ChildSlot::new(second, 0))]);
+
+        // Demoting keeps the exposed default on the child
+        parent.input_definitions[0].default_value = Value::Float(7.0);
+        parent.demote(&registry, "Offset").unwrap();
+        assert!(parent.input_definitions.is_empty());
+        assert_eq!(parent.get_child(first).unwrap().get_input_value(b_id), Some(&Value::Float(7.0)));
+
+        parent.demote(&registry, "Sum").unwrap();
+        assert_eq!(
+            parent.demote(&registry, "Sum"),
+            Err(SymbolError::NotPromoted("Sum".into()))
+        );
+    }
 }
@@ -23,6 +23,9 @@ pub struct InputDefinition {
     /// Optional description for UI/documentation
     #[serde(default)]
     pub description: Option<String>,
+    /// Child input this input drives, if it was promoted from one
+    #[serde(default)]
+    pub target: Option<ChildSlot>,
 }
 
 impl InputDefinition {
@@ -35,6 +38,7 @@ impl InputDefinition {
             default_value,
             is_multi_input: false,
             description: None,
+            target: None,
         }
     }
 
@@ -85,6 +89,9 @@ pub struct OutputDefinition {
     /// Optional description for UI/documentation
     #[serde(default)]
     pub description: Option<String>,
+    /// Child output this output reads from, if it was promoted from one
+    #[serde(default)]
+    pub source: Option<ChildSlot>,
 }
 
 impl OutputDefinition {
@@ -96,6 +103,7 @@ impl OutputDefinition {
             value_type,
             dirty_flag_trigger: DirtyFlagTrigger::default(),
             description: None,
+            source: None,
         }
     }
 
@@ -132,6 +140,25 @@ impl OutputDefinition {
     }
 }
 
+/// A slot on a child of a Symbol, used to map promoted inputs and outputs
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ChildSlot {
+    /// ID of the child within the parent symbol
+    pub child_id: Id,
+    /// Index of the input or output on the child
+    pub slot_index: usize,
+}
+
+impl ChildSlot {
+    /// Create a new child slot reference
+    pub fn new(child_id: Id, slot_index: usize) -> Self {
+        Self {
+            child_id,
+            slot_index,
+        }
+    }
+}
+
 /// What causes an output to become dirty and need recomputation
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DirtyFlagTrigger {
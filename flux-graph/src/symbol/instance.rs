@@ -2,9 +2,11 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
-use super::Symbol;
+use super::{ChildSlot, Symbol};
+use crate::animation::{AnimationTarget, Animator};
 use flux_core::id::Id;
 use crate::instance_path::InstancePath;
+use crate::serialization::AnimationOverride;
 use flux_core::port::{InputPort, OutputPort};
 use flux_core::value::Value;
 
@@ -32,6 +34,16 @@ pub struct Instance {
     /// Child instances (lazily created)
     pub children: InstanceChildren,
 
+    /// Child input driven by each input, for promoted inputs
+    pub input_targets: Vec<Option<ChildSlot>>,
+    /// Child output read by each output, for promoted outputs
+    pub output_sources: Vec<Option<ChildSlot>>,
+
+    /// Animation of child inputs, copied from the symbol
+    pub animator: Animator,
+    /// Added to the parent's time to get this instance's local time
+    pub time_offset: f64,
+
     /// Current status flags
     pub status: InstanceStatus,
 }
@@ -71,6 +83,10 @@ impl Instance {
             inputs,
             outputs,
             children: InstanceChildren::new(symbol.id),
+            input_targets: symbol.input_definitions.iter().map(|d| d.target).collect(),
+            output_sources: symbol.output_definitions.iter().map(|d| d.source).collect(),
+            animator: symbol.animator.clone(),
+            time_offset: 0.0,
             status: InstanceStatus::UNINITIALIZED,
         }
     }
@@ -172,6 +188,67 @@ impl Instance {
             output.set(value);
         }
     }
+
+    /// Copy promoted input values into the child inputs they drive
+    ///
+    /// Children that have not been instantiated yet are skipped.
+    pub fn propagate_inputs(&mut self) {
+        for (input, target) in self.inputs.iter().zip(&self.input_targets) {
+            let Some(target) = target else { continue };
+            if let Some(slot) = self
+                .children
+                .get_mut(target.child_id)
+                .and_then(|child| child.inputs.get_mut(target.slot_index))
+            {
+                slot.default = input.default.clone();
+            }
+        }
+    }
+
+    /// Write the animated child input values at a parent time
+    ///
+    /// The animator is sampled at `time + time_offset`, mapped into its
+    /// playback range. Children that have not been instantiated yet are
+    /// skipped.
+    pub fn apply_animation(&mut self, time: f64) {
+        if self.animator.binding_count() == 0 {
+            return;
+        }
+        self.animator.set_time(time + self.time_offset);
+        let local_time = self.animator.current_time();
+        for (target, value) in self.animator.sample_iter(local_time) {
+            if let Some(slot) = self
+                .children
+                .get_mut(target.node_id)
+                .and_then(|child| child.inputs.get_mut(target.input_index))
+            {
+                slot.default = Value::Float(value as f32);
+            }
+        }
+    }
+
+    /// Disable or retarget one of the symbol's animations for this instance
+    ///
+    /// Returns false if the symbol doesn't animate that input and the
+    /// override brings no curve of its own.
+    pub fn apply_animation_override(&mut self, animation: &AnimationOverride) -> bool {
+        let target = AnimationTarget::new(animation.target_child, animation.target_input);
+        animation.apply(&mut self.animator, target)
+    }
+
+    /// Copy child output values into the promoted outputs that read them
+    pub fn collect_outputs(&mut self) {
+        for (output, source) in self.outputs.iter_mut().zip(&self.output_sources) {
+            let Some(source) = source else { continue };
+            if let Some(value) = self
+                .children
+                .get(source.child_id)
+                .and_then(|child| child.get_output_value(source.slot_index))
+            {
+                output.set(value);
+            }
+        }
+    }
 }
 
 /// Status flags for an instance
@@ -385,4 +462,30 @@ mod tests {
         assert!(!children.is_instantiated(child_id));
         assert!(children.is_empty());
     }
+
+    #[test]
+    fn test_instance_animation_with_time_offset() {
+        use crate::animation::CurveBuilder;
+
+        let child_id = Id::new();
+        let mut parent = Symbol::new("Parent");
+        let curve = CurveBuilder::new().keyframe(0.0, 0.0).keyframe(2.0, 20.0).build();
+        parent.animator = Animator::with_range(0.0, 2.0);
+        parent.animator.add_curve(curve, child_id, 1);
+
+        let mut instance = parent.create_instance();
+        instance.time_offset = 0.5;
+        instance.children.insert(child_id, make_test_symbol().create_instance());
+        instance.apply_animation(1.0);
+        let child_input = |instance: &Instance| {
+            instance.children.get(child_id).unwrap().inputs[1].default.clone()
+        };
+        assert_eq!(child_input(&instance), Value::Float(15.0));
+
+        // A disabled animation leaves the last value in place
+        assert!(instance.apply_animation_override(&AnimationOverride::disable(child_id, 1)));
+        instance.apply_animation(0.0);
+        assert_eq!(child_input(&instance), Value::Float(15.0));
+        assert!(!instance.apply_animation_override(&AnimationOverride::disable(child_id, 0)));
+    }
 }
@@ -39,6 +39,6 @@ mod registry;
 
 pub use child::{ChildInput, ChildOutput, SymbolChild};
 pub use core::{Symbol, SymbolError};
-pub use definition::{InputDefinition, OutputDefinition};
+pub use definition::{ChildSlot, InputDefinition, OutputDefinition};
 pub use instance::{Instance, InstanceChildren, InstanceStatus};
 pub use registry::SymbolRegistry;
@@ -3,7 +3,7 @@ use std::sync::{Arc, RwLock};
 
 use flux_core::id::Id;
 
-use super::Symbol;
+use super::{Instance, Symbol, SymbolError};
 
 /// Registry for managing Symbol definitions
 ///
@@ -53,6 +53,16 @@ impl SymbolRegistry {
         self.get(id)
     }
 
+    /// Create a runtime instance of a registered symbol
+    ///
+    /// Fails if the symbol isn't registered or contains itself, directly or
+    /// through other symbols (see [`Symbol::check_recursion`]).
+    pub fn create_instance(&self, id: Id) -> Result<Instance, SymbolError> {
+        let symbol = self.get(id).ok_or(SymbolError::SymbolNotFound(id))?;
+        symbol.check_recursion(self)?;
+        Ok(symbol.create_instance())
+    }
+
     /// Get symbol ID by name
     pub fn get_id(&self, name: &str) -> Option<Id> {
         self.by_name.read().unwrap().get(name).copied()
@@ -131,7 +141,7 @@ impl std::fmt::Debug for SymbolRegistry {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::symbol::{InputDefinition, OutputDefinition};
+    use crate::symbol::{InputDefinition, OutputDefinition, SymbolChild};
 
     fn make_test_symbol(name: &str, category: &str) -> Symbol {
         let mut symbol = Symbol::new(name).with_category(category);
@@ -168,6 +178,53 @@ mod tests {
         assert_eq!(found_by_name.unwrap().id, id);
     }
 
+    #[test]
+    fn test_create_instance_rejects_recursion() {
+        let registry = SymbolRegistry::new();
+
+        // Direct: a symbol containing itself
+        let mut direct = make_test_symbol("Direct", "Test");
+        direct.add_child(SymbolChild::new(Id::new(), direct.id));
+        let direct_id = registry.register(direct);
+        assert_eq!(
+            registry.create_instance(direct_id).unwrap_err(),
+            SymbolError::RecursiveDefinition(vec!["Direct".into(), "Direct".into()])
+        );
+
+        // Indirect: Outer -> Middle -> Inner -> Middle
+        let mut outer = make_test_symbol("Outer", "Test");
+        let mut middle = make_test_symbol("Middle", "Test");
+        let mut inner = make_test_symbol("Inner", "Test");
+        outer.add_child(SymbolChild::new(Id::new(), middle.id));
+        middle.add_child(SymbolChild::new(Id::new(), inner.id));
+        inner.add_child(SymbolChild::new(Id::new(), middle.id));
+        let outer_id = registry.register(outer);
+        registry.register(middle);
+        registry.register(inner);
+        assert_eq!(
+            registry.create_instance(outer_id).unwrap_err(),
+            SymbolError::RecursiveDefinition(vec![
+                "Middle".into(),
+                "Inner".into(),
+                "Middle".into()
+            ])
+        );
+
+        // Using the same symbol twice is not recursion
+        let leaf_id = registry.register(make_test_symbol("Leaf", "Test"));
+        let mut pair = make_test_symbol("Pair", "Test");
+        pair.add_child(SymbolChild::new(Id::new(), leaf_id));
+        pair.add_child(SymbolChild::new(Id::new(), leaf_id));
+        let pair_id = registry.register(pair);
+        assert!(registry.create_instance(pair_id).is_ok());
+
+        let missing = Id::new();
+        assert_eq!(
+            registry.create_instance(missing).unwrap_err(),
+            SymbolError::SymbolNotFound(missing)
+        );
+    }
+
     #[test]
     fn test_registry_unregister() {
         let registry = SymbolRegistry::new();
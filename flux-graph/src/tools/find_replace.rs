@@ -0,0 +1,600 @@
+//! Find and replace values
+//!
+//! [`find_values`] walks a graph, the composites inside it and optionally a
+//! symbol registry, collecting every value a [`ValueQuery`] matches:
+//!
+//! - input defaults of nodes, addressed by [`InstancePath`] and input index
+//! - keyframes of the curves animating inputs inside composites
+//! - default values of symbol inputs, and values set on symbol children
+//! - keyframes of symbol animations
+//!
+//! [`replace_values`] turns a selection of those matches into a single
+//! [`MacroCommand`], so moving a file path or address across a project is
+//! one undo step.
+//!
+//! ```ignore
+//! let scope = ValueScope::scene(&project, "main").unwrap();
+//! let matches = find_values(scope, &ValueQuery::Contains("192.168.1.10".into()));
+//! let command = replace_values(&matches, &ReplacementSpec::substring("192.168.1.10", "10.0.0.5"));
+//! history.execute(project.scene_mut("main").unwrap(), command);
+//! ```
+//!
+//! Node and keyframe edits apply to the graph the command is executed on,
+//! which must be the graph that was searched. Symbol edits replace the
+//! symbol in its registry; graphs already instantiated from it keep their
+//! values.
+
+use std::sync::Arc;
+
+use flux_core::{Id, Value};
+
+use crate::animation::{AnimationTarget, Animator, Keyframe};
+use crate::commands::{Command, MacroCommand};
+use crate::composite::{resolve_node, CompositeOp};
+use crate::graph::Graph;
+use crate::instance_path::InstancePath;
+use crate::project::Project;
+use crate::symbol::{Symbol, SymbolRegistry};
+
+/// What [`find_values`] looks for
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueQuery {
+    /// String values containing the text
+    Contains(String),
+    /// String values equal to the text
+    Equals(String),
+    /// String values matching a glob pattern as a whole: `*` matches any run
+    /// of characters, `?` any single character
+    Glob(String),
+    /// Float and Int values, and keyframes, within `epsilon` of `value`
+    Number { value: f64, epsilon: f64 },
+}
+
+impl ValueQuery {
+    /// Whether a value matches the query.
+    pub fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (ValueQuery::Contains(text), Value::String(s)) => s.contains(text.as_str()),
+            (ValueQuery::Equals(text), Value::String(s)) => s == text,
+            (ValueQuery::Glob(pattern), Value::String(s)) => glob_matches(pattern, s),
+            (ValueQuery::Number { .. }, Value::Float(x)) => self.matches_number(*x as f64),
+            (ValueQuery::Number { .. }, Value::Int(x)) => self.matches_number(*x as f64),
+            _ => false,
+        }
+    }
+
+    /// Whether a number, such as a keyframe value, matches the query.
+    pub fn matches_number(&self, x: f64) -> bool {
+        match self {
+            ValueQuery::Number { value, epsilon } => (x - value).abs() <= *epsilon,
+            _ => false,
+        }
+    }
+}
+
+/// Whether `text` matches `pattern` as a whole, `*` and `?` being wildcards
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Last `*` seen, and the text position it currently matches up to
+    let mut star: Option<(usize, usize)> = None;
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = star {
+            // Let the `*` swallow one more character
+            star = Some((star_p, star_t + 1));
+            p = star_p + 1;
+            t = star_t + 1;
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+/// Where a value found by [`find_values`] lives
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ValueLocation {
+    /// Default of a node input; the path runs through composites to the node
+    Input { path: InstancePath, input: usize },
+    /// Keyframe of the curve animating a node input inside a composite, in
+    /// that composite's animator
+    Keyframe {
+        path: InstancePath,
+        input: usize,
+        keyframe: usize,
+    },
+    /// Default value of a symbol input
+    SymbolInput { symbol: Id, input: Id },
+    /// Value set on an input of a symbol's child, by input definition ID
+    SymbolChildInput { symbol: Id, child: Id, input: Id },
+    /// Keyframe of the curve animating a child input in a symbol's animator
+    SymbolKeyframe {
+        symbol: Id,
+        child: Id,
+        input: usize,
+        keyframe: usize,
+    },
+}
+
+impl ValueLocation {
+    /// The symbol holding the value, for symbol locations.
+    pub fn symbol(&self) -> Option<Id> {
+        match self {
+            ValueLocation::Input { .. } | ValueLocation::Keyframe { .. } => None,
+            ValueLocation::SymbolInput { symbol, .. }
+            | ValueLocation::SymbolChildInput { symbol, .. }
+            | ValueLocation::SymbolKeyframe { symbol, .. } => Some(*symbol),
+        }
+    }
+}
+
+/// A value found by [`find_values`]
+#[derive(Debug, Clone)]
+pub struct ValueMatch {
+    pub location: ValueLocation,
+    /// The value when it was found; keyframe values as Floats
+    pub value: Value,
+    /// Registry of the symbol, for symbol locations
+    symbols: Option<Arc<SymbolRegistry>>,
+}
+
+/// How [`replace_values`] changes each match
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReplacementSpec {
+    /// Replace every occurrence of `from` in String values with `to`
+    Substring { from: String, to: String },
+    /// Replace values with a number, coerced to each value's type
+    Number(f64),
+}
+
+impl ReplacementSpec {
+    pub fn substring(from: impl Into<String>, to: impl Into<String>) -> Self {
+        ReplacementSpec::Substring {
+            from: from.into(),
+            to: to.into(),
+        }
+    }
+
+    /// The replacement for a value, or `None` if it leaves it unchanged.
+    fn apply(&self, value: &Value) -> Option<Value> {
+        let replaced = match (self, value) {
+            (ReplacementSpec::Substring { from, to }, Value::String(s)) if !from.is_empty() => {
+                Value::String(s.replace(from.as_str(), to))
+            }
+            (ReplacementSpec::Number(x), value) => Value::Float(*x as f32).coerce_to(value.value_type())?,
+            _ => return None,
+        };
+        (replaced != *value).then_some(replaced)
+    }
+
+    /// The replacement for a keyframe value.
+    fn apply_number(&self, value: f64) -> Option<f64> {
+        match self {
+            ReplacementSpec::Number(x) if *x != value => Some(*x),
+            _ => None,
+        }
+    }
+}
+
+/// Where [`find_values`] looks: a graph with the composites inside it, and
+/// optionally a symbol registry
+#[derive(Clone, Copy)]
+pub struct ValueScope<'a> {
+    graph: &'a Graph,
+    symbols: Option<&'a Arc<SymbolRegistry>>,
+}
+
+impl<'a> ValueScope<'a> {
+    pub fn new(graph: &'a Graph) -> Self {
+        Self { graph, symbols: None }
+    }
+
+    /// Also search the symbols of a registry.
+    pub fn with_symbols(mut self, symbols: &'a Arc<SymbolRegistry>) -> Self {
+        self.symbols = Some(symbols);
+        self
+    }
+
+    /// A scene of a project together with the project's symbols, or `None`
+    /// if the project has no such scene.
+    pub fn scene(project: &'a Project, scene: &str) -> Option<Self> {
+        Some(Self::new(project.scene(scene)?).with_symbols(project.symbols()))
+    }
+}
+
+impl<'a> From<&'a Graph> for ValueScope<'a> {
+    fn from(graph: &'a Graph) -> Self {
+        Self::new(graph)
+    }
+}
+
+/// Find every value in scope that matches a query.
+///
+/// Graph values come first, in node insertion order with each composite's
+/// keyframes and contents after its inputs, then symbol values by symbol
+/// name.
+pub fn find_values<'a>(scope: impl Into<ValueScope<'a>>, query: &ValueQuery) -> Vec<ValueMatch> {
+    let scope = scope.into();
+    let mut found = Vec::new();
+    find_in_graph(scope.graph, &InstancePath::empty(), query, &mut found);
+    if let Some(symbols) = scope.symbols {
+        find_in_symbols(symbols, query, &mut found);
+    }
+    found
+}
+
+fn find_in_graph(graph: &Graph, parent: &InstancePath, query: &ValueQuery, found: &mut Vec<ValueMatch>) {
+    for node in graph.node_ids_in_insertion_order() {
+        let Some(op) = graph.get(node) else {
+            continue;
+        };
+        let path = parent.child(node);
+        for (input, port) in op.inputs().iter().enumerate() {
+            if query.matches(&port.default) {
+                found.push(ValueMatch {
+                    location: ValueLocation::Input {
+                        path: path.clone(),
+                        input,
+                    },
+                    value: port.default.clone(),
+                    symbols: None,
+                });
+            }
+        }
+        if let Some(composite) = op.as_any().downcast_ref::<CompositeOp>() {
+            for (target, keyframe, value) in matching_keyframes(composite.animator(), query) {
+                found.push(ValueMatch {
+                    location: ValueLocation::Keyframe {
+                        path: path.child(target.node_id),
+                        input: target.input_index,
+                        keyframe,
+                    },
+                    value,
+                    symbols: None,
+                });
+            }
+            find_in_graph(composite.subgraph(), &path, query, found);
+        }
+    }
+}
+
+fn find_in_symbols(registry: &Arc<SymbolRegistry>, query: &ValueQuery, found: &mut Vec<ValueMatch>) {
+    let mut symbols: Vec<Arc<Symbol>> = registry.ids().into_iter().filter_map(|id| registry.get(id)).collect();
+    symbols.sort_by(|a, b| a.name.cmp(&b.name));
+    let mut push = |location, value| {
+        found.push(ValueMatch {
+            location,
+            value,
+            symbols: Some(registry.clone()),
+        })
+    };
+
+    for symbol in symbols {
+        for input in &symbol.input_definitions {
+            if query.matches(&input.default_value) {
+                let location = ValueLocation::SymbolInput {
+                    symbol: symbol.id,
+                    input: input.id,
+                };
+                push(location, input.default_value.clone());
+            }
+        }
+
+        let mut children: Vec<_> = symbol.children.values().collect();
+        children.sort_by_key(|child| *child.id.as_uuid());
+        for child in children {
+            let mut inputs: Vec<_> = child.inputs.values().filter(|input| !input.is_default).collect();
+            inputs.sort_by_key(|input| *input.definition_id.as_uuid());
+            for input in inputs {
+                if query.matches(&input.value) {
+                    let location = ValueLocation::SymbolChildInput {
+                        symbol: symbol.id,
+                        child: child.id,
+                        input: input.definition_id,
+                    };
+                    push(location, input.value.clone());
+                }
+            }
+        }
+
+        for (target, keyframe, value) in matching_keyframes(&symbol.animator, query) {
+            let location = ValueLocation::SymbolKeyframe {
+                symbol: symbol.id,
+                child: target.node_id,
+                input: target.input_index,
+                keyframe,
+            };
+            push(location, value);
+        }
+    }
+}
+
+/// Keyframes of an animator matching a query: target, keyframe index, value
+fn matching_keyframes(animator: &Animator, query: &ValueQuery) -> Vec<(AnimationTarget, usize, Value)> {
+    let mut found = Vec::new();
+    for binding in animator.bindings() {
+        for (index, keyframe) in binding.curve.keyframes().iter().enumerate() {
+            if query.matches_number(keyframe.value) {
+                found.push((binding.target.clone(), index, Value::Float(keyframe.value as f32)));
+            }
+        }
+    }
+    found
+}
+
+/// Build one undoable command replacing the values at `matches`.
+///
+/// Each value is replaced when the command executes, starting from its
+/// value at that time; values the replacement doesn't apply to (a substring
+/// that no longer occurs, a number for a String that can't be coerced) and
+/// locations that no longer exist are left alone.
+pub fn replace_values(matches: &[ValueMatch], replacement: &ReplacementSpec) -> MacroCommand {
+    let mut command = MacroCommand::new("Replace Values");
+    for target in matches {
+        command.push(ReplaceValueCommand {
+            target: target.clone(),
+            replacement: replacement.clone(),
+            previous: None,
+        });
+    }
+    command
+}
+
+/// Replaces the value at one location
+#[derive(Debug)]
+struct ReplaceValueCommand {
+    target: ValueMatch,
+    replacement: ReplacementSpec,
+    /// What the location held before execute, if it was changed
+    previous: Option<Previous>,
+}
+
+#[derive(Debug)]
+enum Previous {
+    Value(Value),
+    Keyframe(f64),
+    /// The whole symbol, restored into the registry on undo
+    Symbol(Arc<Symbol>),
+}
+
+impl ReplaceValueCommand {
+    fn replace(&self, graph: &mut Graph) -> Option<Previous> {
+        let replacement = &self.replacement;
+        match &self.target.location {
+            ValueLocation::Input { path, input } => {
+                let (graph, node) = resolve_node(graph, path)?;
+                let current = graph.get(node)?.inputs().get(*input)?.default.clone();
+                graph.set_input_default(node, *input, replacement.apply(&current)?);
+                Some(Previous::Value(current))
+            }
+            ValueLocation::Keyframe { path, input, keyframe } => {
+                let keyframe = composite_keyframe(graph, path, *input, *keyframe)?;
+                let current = keyframe.value;
+                keyframe.value = replacement.apply_number(current)?;
+                Some(Previous::Keyframe(current))
+            }
+            location => {
+                let registry = self.target.symbols.as_ref()?;
+                let current = registry.get(location.symbol()?)?;
+                let mut symbol = (*current).clone();
+                replace_in_symbol(&mut symbol, location, replacement)?;
+                registry.register(symbol);
+                Some(Previous::Symbol(current))
+            }
+        }
+    }
+}
+
+impl Command for ReplaceValueCommand {
+    fn name(&self) -> &str {
+        "Replace Value"
+    }
+
+    fn execute(&mut self, graph: &mut Graph) {
+        self.previous = self.replace(graph);
+    }
+
+    fn undo(&mut self, graph: &mut Graph) {
+        match (self.previous.take(), &self.target.location) {
+            (Some(Previous::Value(value)), ValueLocation::Input { path, input }) => {
+                if let Some((graph, node)) = resolve_node(graph, path) {
+                    graph.set_input_default(node, *input, value);
+                }
+            }
+            (Some(Previous::Keyframe(value)), ValueLocation::Keyframe { path, input, keyframe }) => {
+                if let Some(keyframe) = composite_keyframe(graph, path, *input, *keyframe) {
+                    keyframe.value = value;
+                }
+            }
+            (Some(Previous::Symbol(symbol)), _) => {
+                if let Some(registry) = &self.target.symbols {
+                    registry.register((*symbol).clone());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Replace the value at a symbol location; `None` if nothing changed.
+fn replace_in_symbol(symbol: &mut Symbol, location: &ValueLocation, replacement: &ReplacementSpec) -> Option<()> {
+    match *location {
+        ValueLocation::SymbolInput { input, .. } => {
+            let input = symbol.input_definitions.iter_mut().find(|i| i.id == input)?;
+            input.default_value = replacement.apply(&input.default_value)?;
+        }
+        ValueLocation::SymbolChildInput { child, input, .. } => {
+            let input = symbol.children.get_mut(&child)?.inputs.get_mut(&input)?;
+            input.value = replacement.apply(&input.value)?;
+        }
+        ValueLocation::SymbolKeyframe {
+            child,
+            input,
+            keyframe,
+            ..
+        } => {
+            let keyframe = animator_keyframe(&mut symbol.animator, child, input, keyframe)?;
+            keyframe.value = replacement.apply_number(keyframe.value)?;
+        }
+        ValueLocation::Input { .. } | ValueLocation::Keyframe { .. } => return None,
+    }
+    Some(())
+}
+
+/// Keyframe animating the node at the end of `path`, in the animator of the
+/// composite containing it
+fn composite_keyframe<'a>(
+    graph: &'a mut Graph,
+    path: &InstancePath,
+    input: usize,
+    index: usize,
+) -> Option<&'a mut Keyframe> {
+    let node = path.leaf()?;
+    let (graph, composite) = resolve_node(graph, &path.parent()?)?;
+    let composite = graph.get_mut_as::<CompositeOp>(composite)?;
+    animator_keyframe(composite.animator_mut(), node, input, index)
+}
+
+fn animator_keyframe(animator: &mut Animator, node: Id, input: usize, index: usize) -> Option<&mut Keyframe> {
+    let curve = &mut animator.get_binding_mut(node, input)?.curve;
+    let time = curve.keyframes().get(index)?.time;
+    curve.get_keyframe_mut(time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Curve;
+    use crate::symbol::InputDefinition;
+    use crate::undo::UndoRedoStack;
+    use flux_core::ValueType;
+    use flux_operators::{create_default_registry, ConstantOp, StringConcatOp};
+
+    const CLIP: &str = "/media/show/intro.mov";
+
+    fn string_default(graph: &mut Graph, path: &InstancePath, input: usize) -> Value {
+        let (graph, node) = resolve_node(graph, path).unwrap();
+        graph.get(node).unwrap().inputs()[input].default.clone()
+    }
+
+    #[test]
+    fn test_find_and_replace_across_project() {
+        let mut project = Project::new(Arc::new(SymbolRegistry::new()), Arc::new(create_default_registry()));
+        let mut symbol = Symbol::new("Player");
+        let clip_input = symbol.add_input(InputDefinition::new("Clip", ValueType::String, Value::String(CLIP.into())));
+        let symbol_id = project.symbols().register(symbol);
+
+        let mut graph = Graph::new();
+        let top = graph.add(StringConcatOp::new());
+        graph.set_input_default(top, 0, Value::String(CLIP.into()));
+        let mut composite = CompositeOp::new("Nested");
+        let inner = composite.add(StringConcatOp::new());
+        composite.subgraph_mut().set_input_default(inner, 1, Value::String(format!("file://{}", CLIP)));
+        let composite = graph.add(composite);
+        project.add_scene("main", graph);
+
+        let scope = ValueScope::scene(&project, "main").unwrap();
+        let matches = find_values(scope, &ValueQuery::Contains("/media/show/".into()));
+        let top_path = InstancePath::root(top);
+        let inner_path = InstancePath::from_segments(vec![composite, inner]);
+        let locations: Vec<_> = matches.iter().map(|m| m.location.clone()).collect();
+        assert_eq!(
+            locations,
+            vec![
+                ValueLocation::Input {
+                    path: top_path.clone(),
+                    input: 0
+                },
+                ValueLocation::Input {
+                    path: inner_path.clone(),
+                    input: 1
+                },
+                ValueLocation::SymbolInput {
+                    symbol: symbol_id,
+                    input: clip_input
+                },
+            ]
+        );
+
+        let mut history = UndoRedoStack::new();
+        let command = replace_values(&matches, &ReplacementSpec::substring("/media/show/", "/mnt/live/"));
+        history.execute(project.scene_mut("main").unwrap(), command);
+        let symbol_default = |project: &Project| {
+            let symbol = project.symbols().get(symbol_id).unwrap();
+            symbol.get_input(clip_input).unwrap().default_value.clone()
+        };
+        let scene = project.scene_mut("main").unwrap();
+        assert_eq!(string_default(scene, &top_path, 0), Value::String("/mnt/live/intro.mov".into()));
+        assert_eq!(
+            string_default(scene, &inner_path, 1),
+            Value::String("file:///mnt/live/intro.mov".into())
+        );
+        assert_eq!(symbol_default(&project), Value::String("/mnt/live/intro.mov".into()));
+
+        // One undo step restores all three
+        assert_eq!(history.history_len(), 1);
+        history.undo(project.scene_mut("main").unwrap());
+        let scene = project.scene_mut("main").unwrap();
+        assert_eq!(string_default(scene, &top_path, 0), Value::String(CLIP.into()));
+        assert_eq!(string_default(scene, &inner_path, 1), Value::String(format!("file://{}", CLIP)));
+        assert_eq!(symbol_default(&project), Value::String(CLIP.into()));
+    }
+
+    #[test]
+    fn test_replace_numbers_and_keyframes() {
+        let mut graph = Graph::new();
+        let constant = graph.add(ConstantOp::new(0.5));
+        let mut composite = CompositeOp::new("Animated");
+        let inner = composite.add(ConstantOp::new(0.0));
+        let mut curve = Curve::new();
+        curve.add(0.0, 0.5);
+        curve.add(1.0, 2.0);
+        composite.animator_mut().add_curve(curve, inner, 0);
+        let composite = graph.add(composite);
+
+        let query = ValueQuery::Number {
+            value: 0.5,
+            epsilon: 1e-6,
+        };
+        let matches = find_values(&graph, &query);
+        let keyframe = ValueLocation::Keyframe {
+            path: InstancePath::from_segments(vec![composite, inner]),
+            input: 0,
+            keyframe: 0,
+        };
+        assert_eq!(matches.len(), 2);
+        assert_eq!(matches[1].location, keyframe);
+
+        let mut command = replace_values(&matches, &ReplacementSpec::Number(0.75));
+        command.execute(&mut graph);
+        assert_eq!(string_default(&mut graph, &InstancePath::root(constant), 0), Value::Float(0.75));
+        let animated = graph.get(composite).unwrap().as_any().downcast_ref::<CompositeOp>().unwrap();
+        assert_eq!(animated.animator().bindings()[0].curve.keyframes()[0].value, 0.75);
+        assert!(find_values(&graph, &query).is_empty());
+
+        command.undo(&mut graph);
+        assert_eq!(find_values(&graph, &query).len(), 2);
+    }
+
+    #[test]
+    fn test_query_patterns() {
+        let clip = Value::String(CLIP.into());
+        assert!(ValueQuery::Equals(CLIP.into()).matches(&clip));
+        assert!(ValueQuery::Glob("/media/*.mov".into()).matches(&clip));
+        assert!(ValueQuery::Glob("*/intro.m?v".into()).matches(&clip));
+        assert!(!ValueQuery::Glob("*.mo".into()).matches(&clip));
+        assert!(!ValueQuery::Contains("0.5".into()).matches(&Value::Float(0.5)));
+
+        let number = ValueQuery::Number { value: 3.0, epsilon: 0.1 };
+        assert!(number.matches(&Value::Int(3)));
+        assert!(number.matches(&Value::Float(3.05)));
+        assert!(!number.matches(&Value::String("3".into())));
+    }
+}
@@ -0,0 +1,11 @@
+//! Editing tools that work across a whole graph
+//!
+//! - [`find_values`] / [`replace_values`] - Find input values, symbol
+//!   defaults and keyframes by content, and replace them in one undo step
+
+mod find_replace;
+
+pub use find_replace::{
+    find_values, replace_values, ReplacementSpec, ValueLocation, ValueMatch, ValueQuery,
+    ValueScope,
+};
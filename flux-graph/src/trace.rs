@@ -0,0 +1,453 @@
+//! Structured trace of one evaluation
+//!
+//! [`Graph::trace_evaluate`] evaluates an output exactly like
+//! [`Graph::evaluate`] and returns an [`EvalTrace`] alongside the result.
+//! The trace lists every node in evaluation order: whether it was computed
+//! or served from the cache and why, the input values it read, the outputs
+//! it produced and how long it took.
+//!
+//! Both paths run the same code; plain evaluation passes a sink that records
+//! nothing, so a trace always describes what evaluation really does.
+//!
+//! [`EvalTrace`] is `Serialize` for dumping to JSON and inspecting in
+//! external tools; [`EvalTrace::to_text`] formats it for a terminal.
+//!
+//! ```ignore
+//! let (result, trace) = graph.trace_evaluate(output, 0, &ctx);
+//! println!("{}", trace.to_text());
+//! std::fs::write("frame.json", serde_json::to_string_pretty(&trace)?)?;
+//! ```
+//!
+//! [`Graph::trace_evaluate`]: crate::Graph::trace_evaluate
+//! [`Graph::evaluate`]: crate::Graph::evaluate
+
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use flux_core::{EvalContext, Id, OutputRef, Value, ValueType};
+use serde::Serialize;
+
+use crate::graph::{live_sources, Graph};
+
+/// Lists longer than this are summarized in traces.
+const MAX_TRACED_ELEMENTS: usize = 16;
+
+/// Why a node was computed or served from the cache.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize)]
+pub enum TraceReason {
+    // ----- Computed -----
+    /// No cached outputs for this call context yet
+    NeverComputed,
+    /// An update was requested explicitly
+    UpdateRequested,
+    /// A context variable bound to an input changed
+    ContextBinding,
+    /// The operator is time-varying
+    TimeVarying,
+    /// A part of the context the operator depends on changed
+    ContextChanged,
+    /// An input is still transitioning to a new default
+    InputTransition,
+    /// An output was marked dirty, e.g. by changing an input default
+    DirtyOutput,
+    /// A node feeding one of its inputs was computed this evaluation
+    UpstreamRecomputed,
+    /// It would have recomputed while skipped as an inactive branch
+    MissedUpdate,
+
+    // ----- Served from cache -----
+    /// Nothing the node depends on changed
+    UpToDate,
+    /// Outputs are frozen
+    Frozen,
+    /// The node is quarantined after a panic
+    Quarantined,
+    /// The node's update rate isn't due yet
+    Throttled,
+    /// Outputs only feed muted connections
+    MutedBranch,
+    /// Outputs only feed inputs their consumers won't read this evaluation
+    /// (see [`Operator::active_inputs`](flux_core::Operator::active_inputs))
+    InactiveBranch,
+}
+
+impl TraceReason {
+    /// True if nodes with this reason are computed.
+    pub fn computes(self) -> bool {
+        !matches!(
+            self,
+            TraceReason::UpToDate
+                | TraceReason::Frozen
+                | TraceReason::Quarantined
+                | TraceReason::Throttled
+                | TraceReason::MutedBranch
+                | TraceReason::InactiveBranch
+        )
+    }
+
+    /// Short human-readable description.
+    pub fn label(self) -> &'static str {
+        match self {
+            TraceReason::NeverComputed => "never computed",
+            TraceReason::UpdateRequested => "update requested",
+            TraceReason::ContextBinding => "context binding changed",
+            TraceReason::TimeVarying => "time-varying",
+            TraceReason::ContextChanged => "context changed",
+            TraceReason::InputTransition => "input transition",
+            TraceReason::DirtyOutput => "dirty output",
+            TraceReason::UpstreamRecomputed => "upstream recomputed",
+            TraceReason::MissedUpdate => "missed update",
+            TraceReason::UpToDate => "up to date",
+            TraceReason::Frozen => "frozen",
+            TraceReason::Quarantined => "quarantined",
+            TraceReason::Throttled => "throttled",
+            TraceReason::MutedBranch => "muted branch",
+            TraceReason::InactiveBranch => "inactive branch",
+        }
+    }
+}
+
+/// A value as recorded in a trace.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum TracedValue {
+    /// The complete value
+    Full(Value),
+    /// A long list, cut down to its first elements
+    Summary {
+        /// Type of the list
+        value_type: ValueType,
+        /// Number of elements in the original list
+        len: usize,
+        /// The list truncated to its first elements
+        head: Value,
+    },
+}
+
+impl TracedValue {
+    /// Record `value`, summarizing lists longer than 16 elements.
+    pub fn new(value: &Value) -> Self {
+        match value.list_len() {
+            Some(len) if len > MAX_TRACED_ELEMENTS => {
+                let mut head = value.clone();
+                head.truncate_list(MAX_TRACED_ELEMENTS);
+                TracedValue::Summary {
+                    value_type: value.value_type(),
+                    len,
+                    head,
+                }
+            }
+            _ => TracedValue::Full(value.clone()),
+        }
+    }
+
+    /// The complete value, or `None` if it was summarized.
+    pub fn value(&self) -> Option<&Value> {
+        match self {
+            TracedValue::Full(value) => Some(value),
+            TracedValue::Summary { .. } => None,
+        }
+    }
+}
+
+impl fmt::Display for TracedValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (value, hidden) = match self {
+            TracedValue::Full(value) => (value, 0),
+            TracedValue::Summary { len, head, .. } => (head, len - head.list_len().unwrap_or(0)),
+        };
+        let Some(elements) = value.list_elements() else {
+            return write!(f, "{}", value);
+        };
+        write!(f, "{:?}[", value.value_type())?;
+        for (i, element) in elements.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", element)?;
+        }
+        if hidden > 0 {
+            write!(f, ", … {} more", hidden)?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// A named input or output value in a trace.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TracedPort {
+    /// Port name
+    pub name: &'static str,
+    /// Value on the port
+    pub value: TracedValue,
+}
+
+/// What happened to one node during a traced evaluation.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct NodeTrace {
+    /// The node
+    pub node: Id,
+    /// Operator name
+    pub name: &'static str,
+    /// True if the operator's compute ran
+    pub computed: bool,
+    /// Why it was computed or served from the cache
+    pub reason: TraceReason,
+    /// Input values the node read, one entry per connection on multi-inputs.
+    ///
+    /// Connected inputs show the source's cached output before clamping;
+    /// unconnected ones their default.
+    pub inputs: Vec<TracedPort>,
+    /// Cached outputs after the node was visited
+    pub outputs: Vec<TracedPort>,
+    /// Wall time spent computing (or reusing) the node
+    pub wall_time: Duration,
+}
+
+/// Record of a single evaluation, from [`Graph::trace_evaluate`].
+///
+/// [`Graph::trace_evaluate`]: crate::Graph::trace_evaluate
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct EvalTrace {
+    /// Node of the requested output
+    pub output_node: Id,
+    /// Index of the requested output
+    pub output_index: usize,
+    /// Frame of the evaluation context
+    pub frame: u64,
+    /// Time of the evaluation context in seconds
+    pub time: f64,
+    /// Visited nodes in evaluation order
+    pub nodes: Vec<NodeTrace>,
+    /// Wall time of the whole evaluation
+    pub total: Duration,
+}
+
+impl EvalTrace {
+    /// The entry for a node, if it was visited.
+    pub fn node(&self, node: Id) -> Option<&NodeTrace> {
+        self.nodes.iter().find(|entry| entry.node == node)
+    }
+
+    /// Number of nodes whose compute ran.
+    pub fn computed_count(&self) -> usize {
+        self.nodes.iter().filter(|entry| entry.computed).count()
+    }
+
+    /// Format the trace for quick terminal inspection, one block per node.
+    pub fn to_text(&self) -> String {
+        let mut text = format!(
+            "Evaluated {}:{} at frame {} (t = {}s): {} nodes, {} computed, {:?}\n",
+            self.output_node,
+            self.output_index,
+            self.frame,
+            self.time,
+            self.nodes.len(),
+            self.computed_count(),
+            self.total,
+        );
+        for entry in &self.nodes {
+            let action = if entry.computed { "computed" } else { "cached" };
+            text.push_str(&format!(
+                "  {} {}: {} ({}) {:?}\n",
+                entry.name,
+                entry.node,
+                action,
+                entry.reason.label(),
+                entry.wall_time,
+            ));
+            for port in &entry.inputs {
+                text.push_str(&format!("    in  {} = {}\n", port.name, port.value));
+            }
+            for port in &entry.outputs {
+                text.push_str(&format!("    out {} = {}\n", port.name, port.value));
+            }
+        }
+        text
+    }
+}
+
+/// Receives the nodes visited by an evaluation.
+pub(crate) trait TraceSink {
+    /// Called before a node is computed or reused.
+    fn begin_node(&mut self, graph: &Graph, node: Id, ctx: &EvalContext, reason: TraceReason);
+
+    /// Called once the node's outputs are in the cache.
+    fn end_node(&mut self, graph: &Graph, node: Id, ctx: &EvalContext, computed: bool);
+}
+
+/// Sink for untraced evaluation.
+pub(crate) struct NoTrace;
+
+impl TraceSink for NoTrace {
+    #[inline]
+    fn begin_node(&mut self, _: &Graph, _: Id, _: &EvalContext, _: TraceReason) {}
+
+    #[inline]
+    fn end_node(&mut self, _: &Graph, _: Id, _: &EvalContext, _: bool) {}
+}
+
+/// Sink that builds an [`EvalTrace`].
+pub(crate) struct Tracer {
+    trace: EvalTrace,
+    started: Instant,
+    /// Node being visited and when its compute started
+    pending: Option<(NodeTrace, Instant)>,
+}
+
+impl Tracer {
+    pub(crate) fn new(output: OutputRef, ctx: &EvalContext) -> Self {
+        let (output_node, output_index) = output.into();
+        Self {
+            trace: EvalTrace {
+                output_node,
+                output_index,
+                frame: ctx.frame,
+                time: ctx.time,
+                nodes: Vec::new(),
+                total: Duration::ZERO,
+            },
+            started: Instant::now(),
+            pending: None,
+        }
+    }
+
+    pub(crate) fn finish(mut self) -> EvalTrace {
+        self.trace.total = self.started.elapsed();
+        self.trace
+    }
+}
+
+impl TraceSink for Tracer {
+    fn begin_node(&mut self, graph: &Graph, node: Id, ctx: &EvalContext, reason: TraceReason) {
+        let Some(op) = graph.get(node) else {
+            return;
+        };
+        let call_context = ctx.call_context;
+        let mut inputs = Vec::new();
+        for input in op.inputs() {
+            let mut connected = false;
+            for (source, output) in live_sources(input) {
+                connected = true;
+                let value = graph
+                    .cached_output_in(source, output, call_context)
+                    .cloned()
+                    .unwrap_or_default();
+                inputs.push(TracedPort {
+                    name: input.name,
+                    value: TracedValue::new(&value),
+                });
+            }
+            if !connected {
+                inputs.push(TracedPort {
+                    name: input.name,
+                    value: TracedValue::new(&input.default),
+                });
+            }
+        }
+
+        let entry = NodeTrace {
+            node,
+            name: op.name(),
+            computed: false,
+            reason,
+            inputs,
+            outputs: Vec::new(),
+            wall_time: Duration::ZERO,
+        };
+        self.pending = Some((entry, Instant::now()));
+    }
+
+    fn end_node(&mut self, graph: &Graph, node: Id, ctx: &EvalContext, computed: bool) {
+        let Some((mut entry, started)) = self.pending.take() else {
+            return;
+        };
+        entry.wall_time = started.elapsed();
+        entry.computed = computed;
+        if let Some(op) = graph.get(node) {
+            entry.outputs = op
+                .outputs()
+                .iter()
+                .enumerate()
+                .filter_map(|(index, port)| {
+                    let value = graph.cached_output_in(node, index, ctx.call_context)?;
+                    Some(TracedPort {
+                        name: port.name,
+                        value: TracedValue::new(value),
+                    })
+                })
+                .collect();
+        }
+        self.trace.nodes.push(entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_operators::{AddOp, ConstantOp, TimeOp};
+
+    #[test]
+    fn test_second_trace_shows_cache_hits() {
+        let mut graph = Graph::new();
+        let constant = graph.add(ConstantOp::new(2.0));
+        let time = graph.add(TimeOp::new());
+        let add = graph.add(AddOp::new());
+        let sum = graph.add(AddOp::new());
+        graph.connect(constant, 0, add, 0).unwrap();
+        graph.connect(constant, 0, sum, 0).unwrap();
+        graph.connect(time, 0, sum, 1).unwrap();
+        let ctx = EvalContext::new();
+
+        let (first, trace) = graph.trace_evaluate(sum, 0, &ctx);
+        assert!(trace.nodes.iter().all(|entry| entry.computed));
+        assert!(trace
+            .nodes
+            .iter()
+            .all(|entry| entry.reason == TraceReason::NeverComputed));
+        assert_eq!(
+            trace.node(add).unwrap().inputs[0].value,
+            TracedValue::Full(Value::Float(2.0))
+        );
+
+        let (second, trace) = graph.trace_evaluate(sum, 0, &ctx);
+        assert_eq!(first.unwrap(), second.unwrap());
+        assert_eq!(trace.nodes.len(), 4);
+
+        let reason = |node| {
+            let entry = trace.node(node).unwrap();
+            (entry.computed, entry.reason)
+        };
+        assert_eq!(reason(constant), (false, TraceReason::UpToDate));
+        assert_eq!(reason(add), (false, TraceReason::UpToDate));
+        assert_eq!(reason(time), (true, TraceReason::TimeVarying));
+        assert_eq!(reason(sum), (true, TraceReason::UpstreamRecomputed));
+        assert_eq!(trace.computed_count(), 2);
+
+        // Cached nodes still report their outputs
+        let outputs = &trace.node(add).unwrap().outputs;
+        assert_eq!(outputs[0].value, TracedValue::Full(Value::Float(2.0)));
+
+        let text = trace.to_text();
+        assert!(text.contains("cached (up to date)"));
+        assert!(text.contains("computed (upstream recomputed)"));
+        let json = serde_json::to_value(&trace).unwrap();
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 4);
+    }
+
+    #[test]
+    fn test_long_lists_are_summarized() {
+        let short = Value::float_list(vec![1.0, 2.0]);
+        assert_eq!(TracedValue::new(&short), TracedValue::Full(short.clone()));
+        assert_eq!(TracedValue::new(&short).to_string(), "FloatList[1, 2]");
+
+        let long = Value::float_list((0..100).map(|i| i as f32).collect());
+        let traced = TracedValue::new(&long);
+        let TracedValue::Summary { len, head, .. } = &traced else {
+            panic!("Expected summarized list");
+        };
+        assert_eq!(*len, 100);
+        assert_eq!(head.list_len(), Some(16));
+        assert!(traced.value().is_none());
+        assert!(traced.to_string().ends_with("15, … 84 more]"));
+    }
+}
@@ -0,0 +1,83 @@
+//! Removed nodes kept for restoring within a session
+//!
+//! With a trash capacity set ([`Graph::set_trash_capacity`]), removing a node
+//! keeps its operator and per-node state (input defaults, overrides,
+//! bindings, update rate, group membership) together with the connections
+//! the removal severed.
+//! [`Graph::restore_from_trash`] puts the node back under its original ID
+//! and reconnects whatever neighbors still exist. The oldest entries are
+//! dropped once the trash is full.
+//!
+//! Nodes removed by the graph itself (auto-inserted conversions) and by
+//! undoing an [`AddNodeCommand`](crate::AddNodeCommand) never go to the trash.
+//!
+//! [`Graph::set_trash_capacity`]: crate::Graph::set_trash_capacity
+//! [`Graph::restore_from_trash`]: crate::Graph::restore_from_trash
+
+use flux_core::Id;
+
+use crate::graph::{Connection, Node, TriggerConnection};
+use crate::group::GroupId;
+
+/// A removed node held in a graph's trash
+pub struct TrashedNode {
+    pub(crate) node: Node,
+    /// Value connections to and from the node when it was removed
+    pub(crate) connections: Vec<Connection>,
+    /// Where each connection sat in its target's multi-input list
+    pub(crate) slots: Vec<Option<usize>>,
+    /// Trigger connections to and from the node when it was removed
+    pub(crate) triggers: Vec<TriggerConnection>,
+    /// Groups the node was in, rejoined on restore if they still exist
+    pub(crate) groups: Vec<GroupId>,
+    pub(crate) frame: u64,
+    pub(crate) time: f64,
+}
+
+impl TrashedNode {
+    /// ID the node had, and gets back when restored
+    pub fn id(&self) -> Id {
+        self.node.operator.id()
+    }
+
+    /// Operator name
+    pub fn name(&self) -> &'static str {
+        self.node.operator.name()
+    }
+
+    /// Frame of the last evaluation before the node was removed
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// Time of the last evaluation before the node was removed
+    pub fn time(&self) -> f64 {
+        self.time
+    }
+
+    /// Value connections severed by the removal
+    pub fn connections(&self) -> &[Connection] {
+        &self.connections
+    }
+}
+
+/// Outcome of a [`Graph::restore_from_trash`](crate::Graph::restore_from_trash)
+///
+/// Connections are not restored when the other node no longer exists or
+/// the input they fed has been connected to something else since.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RestoreReport {
+    /// ID of the restored node (the one it had before removal)
+    pub id: Id,
+    /// Value connections that could not be restored
+    pub unrestored: Vec<Connection>,
+    /// Trigger connections that could not be restored
+    pub unrestored_triggers: Vec<TriggerConnection>,
+}
+
+impl RestoreReport {
+    /// Whether every severed connection was restored
+    pub fn is_complete(&self) -> bool {
+        self.unrestored.is_empty() && self.unrestored_triggers.is_empty()
+    }
+}
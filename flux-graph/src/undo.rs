@@ -28,6 +28,7 @@
 
 use crate::commands::Command;
 use crate::graph::Graph;
+use crate::journal::Journal;
 
 /// A stack-based undo/redo system for graph commands.
 ///
@@ -51,6 +52,8 @@ pub struct UndoRedoStack {
     dirty: bool,
     /// Position at which the graph was last saved
     saved_position: Option<usize>,
+    /// Crash recovery journal every change is written to
+    journal: Option<Journal>,
 }
 
 impl Default for UndoRedoStack {
@@ -68,6 +71,7 @@ impl UndoRedoStack {
             max_size: None,
             dirty: false,
             saved_position: Some(0),
+            journal: None,
         }
     }
 
@@ -81,6 +85,7 @@ impl UndoRedoStack {
             max_size: Some(max_size),
             dirty: false,
             saved_position: Some(0),
+            journal: None,
         }
     }
 
@@ -91,6 +96,9 @@ impl UndoRedoStack {
     pub fn execute<C: Command + 'static>(&mut self, graph: &mut Graph, mut command: C) {
         // Execute the command
         command.execute(graph);
+        if let Some(journal) = &mut self.journal {
+            journal.observe(graph, Some(&command));
+        }
 
         // If we're not at the end of history, truncate future commands
         if self.position < self.history.len() {
@@ -130,6 +138,9 @@ impl UndoRedoStack {
     /// Execute a boxed command and add it to the history.
     pub fn execute_boxed(&mut self, graph: &mut Graph, mut command: Box<dyn Command>) {
         command.execute(graph);
+        if let Some(journal) = &mut self.journal {
+            journal.observe(graph, Some(command.as_ref()));
+        }
 
         if self.position < self.history.len() {
             self.history.truncate(self.position);
@@ -170,6 +181,9 @@ impl UndoRedoStack {
         self.position -= 1;
         self.history[self.position].undo(graph);
         self.dirty = self.saved_position != Some(self.position);
+        if let Some(journal) = &mut self.journal {
+            journal.observe(graph, None);
+        }
 
         true
     }
@@ -185,6 +199,9 @@ impl UndoRedoStack {
         self.history[self.position].execute(graph);
         self.position += 1;
         self.dirty = self.saved_position != Some(self.position);
+        if let Some(journal) = &mut self.journal {
+            journal.observe(graph, None);
+        }
 
         true
     }
@@ -251,6 +268,29 @@ impl UndoRedoStack {
         self.saved_position = Some(0);
     }
 
+    /// Write every executed, undone or redone command to `journal`.
+    ///
+    /// Undo and redo are journaled as checkpoints. Replaces any attached
+    /// journal.
+    pub fn attach_journal(&mut self, journal: Journal) {
+        self.journal = Some(journal);
+    }
+
+    /// Stop journaling, returning the journal that was attached.
+    pub fn detach_journal(&mut self) -> Option<Journal> {
+        self.journal.take()
+    }
+
+    /// The attached journal, if any.
+    pub fn journal(&self) -> Option<&Journal> {
+        self.journal.as_ref()
+    }
+
+    /// The attached journal, mutably (e.g. to check for write errors).
+    pub fn journal_mut(&mut self) -> Option<&mut Journal> {
+        self.journal.as_mut()
+    }
+
     /// Get a list of command names for display in an undo history menu.
     ///
     /// Returns (past_commands, future_commands) where each is a slice of names.
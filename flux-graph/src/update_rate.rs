@@ -0,0 +1,101 @@
+//! Per-node update rate control
+//!
+//! Expensive operators (large list processing, analysis, etc.) often don't
+//! need to run every frame. An [`UpdateRate`] throttles how often a node is
+//! recomputed; between updates, downstream nodes keep consuming the last
+//! cached output.
+//!
+//! Throttling takes precedence over everything that would otherwise force a
+//! recompute, including [`Operator::is_time_varying`](flux_core::Operator::is_time_varying)
+//! and upstream changes. A node that has never been computed is always
+//! evaluated once so that it has a value to serve.
+
+use serde::{Deserialize, Serialize};
+
+/// How often a node is recomputed during graph evaluation.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub enum UpdateRate {
+    /// Recompute whenever needed (the default)
+    #[default]
+    EveryFrame,
+    /// Recompute at most once every N frames (based on `EvalContext::frame`)
+    EveryNFrames(u32),
+    /// Recompute at most this many times per second (based on `EvalContext::time`)
+    Hertz(f32),
+    /// Recompute only when explicitly requested via `Graph::request_update`
+    OnDemand,
+}
+
+impl UpdateRate {
+    /// Returns true for the default, unthrottled rate.
+    pub fn is_every_frame(&self) -> bool {
+        matches!(self, Self::EveryFrame)
+    }
+
+    /// Returns true if a node last updated at `last` may update at `frame`/`time`.
+    ///
+    /// `last` is the `(frame, time)` of the previous update, or `None` if the
+    /// node has not been updated yet. Frame or time moving backwards (e.g. after
+    /// a seek or context reset) always allows an update.
+    pub fn is_due(&self, last: Option<(u64, f64)>, frame: u64, time: f64) -> bool {
+        let Some((last_frame, last_time)) = last else {
+            return true;
+        };
+        match *self {
+            Self::EveryFrame => true,
+            Self::EveryNFrames(n) => {
+                frame < last_frame || frame - last_frame >= u64::from(n.max(1))
+            }
+            Self::Hertz(hz) => {
+                if hz <= 0.0 {
+                    return false;
+                }
+                time < last_time || time - last_time >= 1.0 / f64::from(hz)
+            }
+            Self::OnDemand => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_every_n_frames_due() {
+        let rate = UpdateRate::EveryNFrames(4);
+        assert!(rate.is_due(None, 0, 0.0));
+        assert!(!rate.is_due(Some((0, 0.0)), 3, 0.0));
+        assert!(rate.is_due(Some((0, 0.0)), 4, 0.0));
+        // Frame reset allows an update
+        assert!(rate.is_due(Some((10, 0.0)), 2, 0.0));
+    }
+
+    #[test]
+    fn test_hertz_due() {
+        let rate = UpdateRate::Hertz(10.0);
+        assert!(!rate.is_due(Some((0, 1.0)), 1, 1.05));
+        assert!(rate.is_due(Some((0, 1.0)), 1, 1.1));
+        assert!(!UpdateRate::Hertz(0.0).is_due(Some((0, 0.0)), 1, 100.0));
+    }
+
+    #[test]
+    fn test_on_demand_never_due_after_first() {
+        assert!(UpdateRate::OnDemand.is_due(None, 0, 0.0));
+        assert!(!UpdateRate::OnDemand.is_due(Some((0, 0.0)), 100, 100.0));
+    }
+
+    #[test]
+    fn test_update_rate_serde_roundtrip() {
+        for rate in [
+            UpdateRate::EveryFrame,
+            UpdateRate::EveryNFrames(3),
+            UpdateRate::Hertz(12.5),
+            UpdateRate::OnDemand,
+        ] {
+            let json = serde_json::to_string(&rate).unwrap();
+            let back: UpdateRate = serde_json::from_str(&json).unwrap();
+            assert_eq!(back, rate);
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//! Watched node outputs
+//!
+//! A watch tracks one node output across evaluations, for live readouts in a
+//! UI. After every completed evaluation the graph snapshots each watched
+//! output from the value cache (in the call context that was evaluated);
+//! [`Graph::poll_watches`] then reports only the outputs whose value changed
+//! since the previous poll.
+//!
+//! Watches are independent of [`GraphEvent`](crate::GraphEvent)s. When a
+//! watched node or output is removed, the watch is dropped and the next poll reports
+//! [`WatchUpdate::Removed`] for it.
+//!
+//! For display between fixed simulation steps (see
+//! [`FrameStepper`](crate::FrameStepper)), [`Graph::watch_values`] captures
+//! the watched values before a step and [`Graph::blend_outputs`] interpolates
+//! from that capture to the current values.
+//!
+//! [`Graph::poll_watches`]: crate::Graph::poll_watches
+//! [`Graph::watch_values`]: crate::Graph::watch_values
+//! [`Graph::blend_outputs`]: crate::Graph::blend_outputs
+
+use std::collections::BTreeMap;
+
+use flux_core::{Id, Value};
+
+/// Handle to a watched output, returned by [`Graph::watch_output`](crate::Graph::watch_output).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WatchId(u64);
+
+/// A change reported by [`Graph::poll_watches`](crate::Graph::poll_watches).
+#[derive(Clone, Debug, PartialEq)]
+pub enum WatchUpdate {
+    /// The watched output has a new value
+    Changed {
+        watch: WatchId,
+        value: Value,
+        /// Frame of the evaluation that produced the value
+        frame: u64,
+    },
+    /// The watched node or output was removed; the watch no longer exists
+    Removed {
+        watch: WatchId,
+        /// Frame of the last evaluation before removal
+        frame: u64,
+    },
+}
+
+impl WatchUpdate {
+    /// The watch this update belongs to.
+    pub fn watch(&self) -> WatchId {
+        match self {
+            Self::Changed { watch, .. } | Self::Removed { watch, .. } => *watch,
+        }
+    }
+}
+
+/// A single watched output.
+#[derive(Debug)]
+struct Watch {
+    node: Id,
+    output: usize,
+    /// Most recent snapshot and the frame it was taken in
+    current: Option<(Value, u64)>,
+    /// Value reported by the last poll
+    reported: Option<Value>,
+}
+
+/// All watches of a graph.
+#[derive(Debug, Default)]
+pub(crate) struct WatchSet {
+    next_id: u64,
+    watches: BTreeMap<WatchId, Watch>,
+    removed: Vec<WatchUpdate>,
+    last_frame: u64,
+}
+
+impl WatchSet {
+    pub(crate) fn add(&mut self, node: Id, output: usize) -> WatchId {
+        let id = WatchId(self.next_id);
+        self.next_id += 1;
+        self.watches.insert(
+            id,
+            Watch {
+                node,
+                output,
+                current: None,
+                reported: None,
+            },
+        );
+        id
+    }
+
+    pub(crate) fn remove(&mut self, id: WatchId) -> bool {
+        self.watches.remove(&id).is_some()
+    }
+
+    /// Record the current value of every watched output.
+    ///
+    /// Values are only cloned when they differ from the previous snapshot.
+    pub(crate) fn snapshot<'a>(
+        &mut self,
+        frame: u64,
+        lookup: impl Fn(Id, usize) -> Option<&'a Value>,
+    ) {
+        self.last_frame = frame;
+        for watch in self.watches.values_mut() {
+            let Some(value) = lookup(watch.node, watch.output) else {
+                continue;
+            };
+            match &mut watch.current {
+                Some((current, _)) if current == value => {}
+                current => *current = Some((value.clone(), frame)),
+            }
+        }
+    }
+
+    /// Drop all watches on a removed node, queueing removal notices.
+    pub(crate) fn node_removed(&mut self, node: Id) {
+        let frame = self.last_frame;
+        let removed = &mut self.removed;
+        self.watches.retain(|&watch, w| {
+            if w.node == node {
+                removed.push(WatchUpdate::Removed { watch, frame });
+                false
+            } else {
+                true
+            }
+        });
+    }
+
+    /// Move watches on a node's outputs after its ports changed; `map` gives
+    /// each previous output's new index. Watches on removed outputs are
+    /// dropped with a removal notice.
+    pub(crate) fn remap_outputs(&mut self, node: Id, map: impl Fn(usize) -> Option<usize>) {
+        let frame = self.last_frame;
+        let removed = &mut self.removed;
+        self.watches.retain(|&watch, w| {
+            if w.node != node {
+                return true;
+            }
+            match map(w.output) {
+                Some(output) => {
+                    w.output = output;
+                    true
+                }
+                None => {
+                    removed.push(WatchUpdate::Removed { watch, frame });
+                    false
+                }
+            }
+        });
+    }
+
+    /// Changes since the previous poll, followed by removal notices.
+    pub(crate) fn poll(&mut self) -> Vec<WatchUpdate> {
+        let mut updates = Vec::new();
+        for (&watch, w) in &mut self.watches {
+            let Some((value, frame)) = &w.current else {
+                continue;
+            };
+            if w.reported.as_ref() != Some(value) {
+                w.reported = Some(value.clone());
+                updates.push(WatchUpdate::Changed {
+                    watch,
+                    value: value.clone(),
+                    frame: *frame,
+                });
+            }
+        }
+        updates.append(&mut self.removed);
+        updates
+    }
+
+    /// The latest snapshot of every watch that has one.
+    pub(crate) fn values(&self) -> BTreeMap<WatchId, Value> {
+        self.watches
+            .iter()
+            .filter_map(|(&watch, w)| Some((watch, w.current.as_ref()?.0.clone())))
+            .collect()
+    }
+
+    /// Latest snapshots blended from `previous` by `alpha`.
+    ///
+    /// Values that can't be interpolated, or have no previous value, are
+    /// taken as they are.
+    pub(crate) fn blend(
+        &self,
+        previous: &BTreeMap<WatchId, Value>,
+        alpha: f32,
+    ) -> BTreeMap<WatchId, Value> {
+        let mut values = self.values();
+        // Exact current values; the lerp can round at 1
+        if alpha >= 1.0 {
+            return values;
+        }
+        let alpha = Value::Float(alpha.max(0.0));
+        for (watch, value) in &mut values {
+            let blended = previous.get(watch).and_then(|from| {
+                let to = value.coerce_to(from.value_type())?;
+                from.lerp(&to, &alpha)?.coerce_to(value.value_type())
+            });
+            if let Some(blended) = blended {
+                *value = blended;
+            }
+        }
+        values
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.watches.is_empty()
+    }
+
+    /// Nodes with a watched output (once per watch).
+    pub(crate) fn nodes(&self) -> impl Iterator<Item = Id> + '_ {
+        self.watches.values().map(|watch| watch.node)
+    }
+}
@@ -0,0 +1,65 @@
+//! Value caching across call contexts and throttled update rates
+
+use flux_core::{EvalContext, Value};
+use flux_graph::{Graph, UpdateRate};
+use flux_test_utils::{
+    assert_cache_hit, assert_cache_miss, assert_evaluates_to, run_frames, CountingOp,
+    ScriptedSourceOp,
+};
+
+#[test]
+fn test_call_context_cache_isolation() {
+    let mut graph = Graph::new();
+    let op = CountingOp::new();
+    let computes = op.counter();
+    let op = graph.add(op);
+
+    // Each call context (a subroutine call or loop iteration) gets its own
+    // cache entry, so the operator computes once per context
+    let root = EvalContext::new();
+    for ctx in [root.clone(), root.with_call_context(1), root.with_call_context(2)] {
+        assert_cache_miss!(graph, op, ctx);
+        assert_evaluates_to!(graph, op, 0, ctx, Value::Float(1.0));
+    }
+    assert_eq!(computes.get(), 3);
+}
+
+#[test]
+fn test_same_context_uses_cache() {
+    let mut graph = Graph::new();
+    let op = CountingOp::new();
+    let computes = op.counter();
+    let op = graph.add(op);
+
+    let ctx = EvalContext::new();
+    assert_evaluates_to!(graph, op, 0, ctx, Value::Float(1.0));
+    assert_cache_hit!(graph, op, ctx);
+    assert_evaluates_to!(graph, op, 0, ctx, Value::Float(1.0));
+    assert_eq!(computes.get(), 1);
+}
+
+#[test]
+fn test_update_rate_every_n_frames() {
+    let mut graph = Graph::new();
+    let source = ScriptedSourceOp::floats((0..20).map(|frame| frame as f32));
+    let source_computes = source.counter();
+    let source = graph.add(source);
+    let consumer = CountingOp::new();
+    let consumer_computes = consumer.counter();
+    let consumer = graph.add(consumer);
+    graph.connect(source, 0, consumer, 0).unwrap();
+
+    graph.set_update_rate(source, UpdateRate::EveryNFrames(4));
+    assert_eq!(graph.update_rate(source), Some(UpdateRate::EveryNFrames(4)));
+
+    run_frames(&mut graph, 20, 60.0, |graph, frame, ctx| {
+        // Consumer sees the value from the most recent source update
+        let last_update = frame - frame % 4;
+        assert_evaluates_to!(graph, consumer, 0, ctx, Value::Float(last_update as f32));
+    });
+
+    // Frames 0, 4, 8, 12, 16
+    assert_eq!(source_computes.get(), 5);
+    // Consumer only recomputes when its (throttled) source does
+    assert_eq!(consumer_computes.get(), 5);
+}
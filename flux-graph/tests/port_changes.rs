@@ -0,0 +1,42 @@
+//! Connections following ports that operators regenerate at runtime
+
+use flux_core::{EvalContext, Value};
+use flux_graph::{Graph, GraphEvent};
+use flux_operators::StringTemplateOp;
+use flux_test_utils::{assert_evaluates_to, ScriptedSourceOp};
+
+#[test]
+fn test_template_edit_moves_connections() {
+    let mut graph = Graph::new();
+    let a = graph.add(ScriptedSourceOp::floats([1.0]));
+    let b = graph.add(ScriptedSourceOp::floats([5.0]));
+    let template = graph.add(StringTemplateOp::with_template("{a} {b}"));
+    graph.connect(a, 0, template, 1).unwrap();
+    graph.connect(b, 0, template, 2).unwrap();
+
+    let ctx = EvalContext::new();
+    assert_evaluates_to!(graph, template, 0, ctx, Value::String("1 5".into()));
+    graph.clear_events();
+
+    // `a` disappears, so `b` moves from input 2 to input 1
+    graph.set_input_default(template, 0, Value::String("{b}!".into()));
+    assert_evaluates_to!(graph, template, 0, ctx, Value::String("5!".into()));
+    assert_eq!(graph.consumers_of(b, 0), &[(template, 1)]);
+    assert!(graph.consumers_of(a, 0).is_empty());
+
+    let events: Vec<_> = graph.drain_events().collect();
+    assert!(events.iter().any(|e| matches!(
+        e,
+        GraphEvent::ConnectionRetargeted { old, new }
+            if old.source_node == b && old.target_input == 2 && new.target_input == 1
+    )));
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, GraphEvent::Disconnected { source, .. } if *source == a)));
+
+    // Ports regenerate when the new template is computed, not before
+    graph.set_input_default(template, 0, Value::String("{c} {b}".into()));
+    assert_eq!(graph.consumers_of(b, 0), &[(template, 1)]);
+    assert_evaluates_to!(graph, template, 0, ctx, Value::String("0 5".into()));
+    assert_eq!(graph.consumers_of(b, 0), &[(template, 2)]);
+}
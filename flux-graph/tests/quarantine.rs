@@ -0,0 +1,36 @@
+//! Operator panics under the default and catching panic policies
+
+use flux_core::{EvalContext, Value};
+use flux_graph::{Graph, GraphEvent, PanicPolicy};
+use flux_test_utils::{assert_evaluates_to, assert_no_events_of_kind, PanickingOp};
+
+#[test]
+fn test_panic_without_cached_outputs_gives_type_defaults() {
+    let mut graph = Graph::new();
+    graph.set_panic_policy(PanicPolicy::Catch);
+    let bad = graph.add(PanickingOp::new());
+    assert_evaluates_to!(graph, bad, 0, EvalContext::new(), Value::Float(0.0));
+    assert!(graph.is_quarantined(bad));
+
+    graph.remove(bad);
+    assert_eq!(graph.quarantined().count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "PanickingOp panicked on frame 0")]
+fn test_panics_propagate_by_default() {
+    let mut graph = Graph::new();
+    assert_eq!(graph.panic_policy(), PanicPolicy::Propagate);
+    let bad = graph.add(PanickingOp::new());
+    let _ = graph.evaluate(bad, 0, &EvalContext::new());
+}
+
+#[test]
+fn test_nodes_that_do_not_panic_report_nothing() {
+    let mut graph = Graph::new();
+    graph.set_panic_policy(PanicPolicy::Catch);
+    let later = graph.add(PanickingOp::on_frame(10));
+    assert_evaluates_to!(graph, later, 0, EvalContext::new(), Value::Float(0.0));
+    assert_no_events_of_kind!(graph, GraphEvent::NodePanicked { .. });
+    assert!(!graph.is_quarantined(later));
+}
@@ -34,6 +34,44 @@
 //! }
 //! ```
 //!
+//! # Range Enforcement
+//!
+//! `range = (min, max)` is a UI hint only. Add `clamp` to have the graph
+//! clamp resolved values into the range during evaluation, and `step` to
+//! quantize them:
+//!
+//! ```ignore
+//! #[input(label = "Mix", default = 0.5, range = (0.0, 1.0, clamp), step = 0.05)]
+//! mix: f32,
+//! ```
+//!
+//! The same syntax works in `#[input_meta(..)]`.
+//!
+//! # Categories
+//!
+//! `category` takes a path with sub-categories separated by `/`, such as
+//! `category = "Math/Trig"`. Without a `category_color`, operators get the
+//! built-in color of their category or its nearest enclosing one, so
+//! `Math/Trig` operators are colored like `Math`.
+//!
+//! # Registration
+//!
+//! Add `register` to the operator attributes to also generate a
+//! `register_<snake_name>` function that registers the operator (through
+//! `capture_meta`) under its declared name, category and description.
+//! Category modules pass these functions to `flux_operators::registry::collect!`:
+//!
+//! ```ignore
+//! #[derive(Operator)]
+//! #[operator(name = "Divide", category = "Math", register)]
+//! #[operator(category_color = category_colors::MATH)]
+//! pub struct DivideOp { /* ... */ }
+//!
+//! pub fn register(registry: &OperatorRegistry) {
+//!     collect!(registry, register_divide_op);
+//! }
+//! ```
+//!
 //! # OperatorMeta Derive Only
 //!
 //! For existing operators that already implement `Operator`, use `OperatorMeta` derive:
@@ -68,10 +106,11 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
 
     // Parse struct-level attributes
     let operator_name = get_operator_attr(&input.attrs, "name").unwrap_or_else(|| name.to_string());
-    let category = get_operator_attr(&input.attrs, "category").unwrap_or_else(|| "Uncategorized".to_string());
+    let category = category_path(get_operator_attr(&input.attrs, "category"));
     let description = get_operator_attr(&input.attrs, "description").unwrap_or_default();
     let icon = get_operator_attr(&input.attrs, "icon");
-    let category_color = get_color_attr(&input.attrs).unwrap_or([0.5, 0.5, 0.5, 1.0]);
+    let category_color = color_impl(get_color_attr(&input.attrs));
+    let register = has_flag(&input.attrs, "operator", "register");
 
     let fields = match &input.data {
         Data::Struct(data) => match &data.fields {
@@ -92,7 +131,11 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
             let label = get_attr_value(&field.attrs, "input", "label")
                 .unwrap_or_else(|| capitalize(&field_name.to_string()));
             let default_value = get_attr_value(&field.attrs, "input", "default");
-            let range = get_range_attr(&field.attrs, "input");
+            let (range, clamp) = match get_range_attr(&field.attrs, "input") {
+                Some((min, max, clamp)) => (Some((min, max)), clamp),
+                None => (None, false),
+            };
+            let step = get_attr_value(&field.attrs, "input", "step");
             let unit = get_attr_value(&field.attrs, "input", "unit");
             let shape = get_attr_value(&field.attrs, "input", "shape")
                 .unwrap_or_else(|| "CircleFilled".to_string());
@@ -103,6 +146,8 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
                 label,
                 default_value,
                 range,
+                clamp,
+                step,
                 unit,
                 shape,
             });
@@ -229,6 +274,15 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
                 builder = quote! { #builder.with_range(#min_val, #max_val) };
             }
 
+            if let Some(step) = &f.step {
+                let step_val: f32 = step.parse().unwrap_or(0.0);
+                builder = quote! { #builder.with_step(#step_val) };
+            }
+
+            if f.clamp {
+                builder = quote! { #builder.with_clamp() };
+            }
+
             if let Some(unit) = &f.unit {
                 builder = quote! { #builder.with_unit(#unit) };
             }
@@ -272,8 +326,26 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
-    // Category color array
-    let [r, g, b, a] = category_color;
+    // Registration function for `collect!`
+    let register_fn = if register {
+        let vis = &input.vis;
+        let register_name = format_ident!("register_{}", snake_case(&name.to_string()));
+        quote! {
+            /// Registers the operator with its declared name, category and description.
+            #vis fn #register_name(registry: &flux_operators::registry::OperatorRegistry) {
+                registry.register(
+                    flux_operators::registry::RegistryEntry::new(
+                        #operator_name,
+                        #category,
+                        #description,
+                    ),
+                    || flux_operators::registry::capture_meta(#name::new()),
+                );
+            }
+        }
+    } else {
+        quote! {}
+    };
 
     let expanded = quote! {
         impl #name {
@@ -288,6 +360,13 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
                 }
             }
 
+            /// Creates a new instance with default values and the given ID.
+            pub fn with_id(id: Id) -> Self {
+                let mut op = Self::new();
+                op._id = id;
+                op
+            }
+
             #(#input_getters)*
             #(#output_setters)*
         }
@@ -311,6 +390,11 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
                 self._id
             }
 
+            fn set_id(&mut self, id: Id) -> bool {
+                self._id = id;
+                true
+            }
+
             fn name(&self) -> &'static str {
                 #operator_name
             }
@@ -341,9 +425,7 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
                 #category
             }
 
-            fn category_color(&self) -> [f32; 4] {
-                [#r, #g, #b, #a]
-            }
+            #category_color
 
             fn description(&self) -> &'static str {
                 #description
@@ -365,6 +447,8 @@ pub fn derive_operator(input: TokenStream) -> TokenStream {
                 }
             }
         }
+
+        #register_fn
     };
 
     TokenStream::from(expanded)
@@ -393,12 +477,11 @@ pub fn derive_operator_meta(input: TokenStream) -> TokenStream {
     let name = &input.ident;
 
     // Parse struct-level attributes
-    let category = get_attr_value(&input.attrs, "meta", "category")
-        .unwrap_or_else(|| "Uncategorized".to_string());
+    let category = category_path(get_attr_value(&input.attrs, "meta", "category"));
     let description = get_attr_value(&input.attrs, "meta", "description")
         .unwrap_or_default();
     let icon = get_attr_value(&input.attrs, "meta", "icon");
-    let category_color = get_meta_color_attr(&input.attrs).unwrap_or([0.5, 0.5, 0.5, 1.0]);
+    let category_color = color_impl(get_meta_color_attr(&input.attrs).map(|[r, g, b, a]| quote! { [#r, #g, #b, #a] }));
 
     // Parse input_meta and output_meta attributes
     let input_metas = parse_port_meta_attrs(&input.attrs, "input_meta");
@@ -420,6 +503,14 @@ pub fn derive_operator_meta(input: TokenStream) -> TokenStream {
                 builder = quote! { #builder.with_range(#min, #max) };
             }
 
+            if let Some(step) = &pm.step {
+                builder = quote! { #builder.with_step(#step) };
+            }
+
+            if pm.clamp {
+                builder = quote! { #builder.with_clamp() };
+            }
+
             if let Some(unit) = &pm.unit {
                 builder = quote! { #builder.with_unit(#unit) };
             }
@@ -463,17 +554,13 @@ pub fn derive_operator_meta(input: TokenStream) -> TokenStream {
         quote! {}
     };
 
-    let [r, g, b, a] = category_color;
-
     let expanded = quote! {
         impl OperatorMeta for #name {
             fn category(&self) -> &'static str {
                 #category
             }
 
-            fn category_color(&self) -> [f32; 4] {
-                [#r, #g, #b, #a]
-            }
+            #category_color
 
             fn description(&self) -> &'static str {
                 #description
@@ -510,6 +597,9 @@ struct InputFieldInfo {
     label: String,
     default_value: Option<String>,
     range: Option<(String, String)>,
+    /// `range = (min, max, clamp)`: enforce the range during evaluation
+    clamp: bool,
+    step: Option<String>,
     unit: Option<String>,
     shape: String,
 }
@@ -522,6 +612,8 @@ impl Clone for InputFieldInfo {
             label: self.label.clone(),
             default_value: self.default_value.clone(),
             range: self.range.clone(),
+            clamp: self.clamp,
+            step: self.step.clone(),
             unit: self.unit.clone(),
             shape: self.shape.clone(),
         }
@@ -541,6 +633,8 @@ struct PortMetaInfo {
     label: String,
     shape: String,
     range: Option<(f32, f32)>,
+    clamp: bool,
+    step: Option<f32>,
     unit: Option<String>,
 }
 
@@ -552,6 +646,15 @@ fn has_attr(attrs: &[Attribute], name: &str) -> bool {
     attrs.iter().any(|a| a.path().is_ident(name))
 }
 
+/// Returns true if a bare `flag` (no `= value`) appears in any `#[attr_name(..)]`.
+fn has_flag(attrs: &[Attribute], attr_name: &str, flag: &str) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(attr_name))
+        .filter_map(|attr| attr.meta.require_list().ok())
+        .any(|meta| meta.tokens.to_string().split(',').any(|part| part.trim() == flag))
+}
+
 fn get_operator_attr(attrs: &[Attribute], key: &str) -> Option<String> {
     get_attr_value(attrs, "operator", key)
 }
@@ -596,20 +699,52 @@ fn parse_kv(tokens: &str, key: &str) -> Option<String> {
     Some(rest[..end].trim().to_string())
 }
 
-fn get_range_attr(attrs: &[Attribute], attr_name: &str) -> Option<(String, String)> {
+/// Parse `range = (min, max)` or `range = (min, max, clamp)`.
+fn get_range_attr(attrs: &[Attribute], attr_name: &str) -> Option<(String, String, bool)> {
     let range_str = get_attr_value(attrs, attr_name, "range")?;
     let inner = range_str.trim_start_matches('(').trim_end_matches(')');
-    let parts: Vec<&str> = inner.split(',').collect();
-    if parts.len() == 2 {
-        Some((parts[0].trim().to_string(), parts[1].trim().to_string()))
-    } else {
-        None
+    let parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+    match parts.as_slice() {
+        [min, max] => Some((min.to_string(), max.to_string(), false)),
+        [min, max, "clamp"] => Some((min.to_string(), max.to_string(), true)),
+        _ => None,
     }
 }
 
-fn get_color_attr(attrs: &[Attribute]) -> Option<[f32; 4]> {
+/// `category = "Math/Trig"`, normalized, or "Uncategorized" when absent.
+///
+/// Panics on empty segments such as `"Math//Trig"`.
+fn category_path(category: Option<String>) -> String {
+    let Some(category) = category else {
+        return "Uncategorized".to_string();
+    };
+    let segments: Vec<&str> = category.split('/').map(str::trim).collect();
+    if segments.iter().any(|segment| segment.is_empty()) {
+        panic!("invalid category \"{}\": separate sub-categories with a single '/'", category);
+    }
+    segments.join("/")
+}
+
+/// `category_color = [r, g, b, a]` or a constant such as `category_colors::MATH`.
+fn get_color_attr(attrs: &[Attribute]) -> Option<proc_macro2::TokenStream> {
     let color_str = get_attr_value(attrs, "operator", "category_color")?;
-    parse_color_array(&color_str)
+    if let Some([r, g, b, a]) = parse_color_array(&color_str) {
+        return Some(quote! { [#r, #g, #b, #a] });
+    }
+    syn::parse_str::<syn::Path>(&color_str).ok().map(|path| quote! { #path })
+}
+
+/// `category_color` method for a declared color. Without one, the trait
+/// default picks the color of the category or its nearest enclosing one.
+fn color_impl(color: Option<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+    match color {
+        Some(color) => quote! {
+            fn category_color(&self) -> [f32; 4] {
+                #color
+            }
+        },
+        None => quote! {},
+    }
 }
 
 fn get_meta_color_attr(attrs: &[Attribute]) -> Option<[f32; 4]> {
@@ -657,16 +792,20 @@ fn parse_port_meta_attrs(attrs: &[Attribute], attr_name: &str) -> Vec<PortMetaIn
                     }
                 });
                 let unit = parse_kv(rest, "unit");
+                let step = parse_kv(rest, "step").and_then(|s| s.parse().ok());
+                let mut clamp = false;
                 let range = parse_kv(rest, "range").and_then(|r| {
                     let inner = r.trim_start_matches('(').trim_end_matches(')');
-                    let parts: Vec<&str> = inner.split(',').collect();
-                    if parts.len() == 2 {
-                        let min: f32 = parts[0].trim().parse().ok()?;
-                        let max: f32 = parts[1].trim().parse().ok()?;
-                        Some((min, max))
-                    } else {
-                        None
-                    }
+                    let parts: Vec<&str> = inner.split(',').map(str::trim).collect();
+                    let (min, max) = match parts.as_slice() {
+                        [min, max] => (min, max),
+                        [min, max, "clamp"] => {
+                            clamp = true;
+                            (min, max)
+                        }
+                        _ => return None,
+                    };
+                    Some((min.parse().ok()?, max.parse().ok()?))
                 });
 
                 result.push(PortMetaInfo {
@@ -674,6 +813,8 @@ fn parse_port_meta_attrs(attrs: &[Attribute], attr_name: &str) -> Vec<PortMetaIn
                     label,
                     shape,
                     range,
+                    clamp,
+                    step,
                     unit,
                 });
             }
@@ -738,6 +879,22 @@ fn get_default_for_type(ty: &Type) -> Expr {
     syn::parse_str::<Expr>(default_str).unwrap()
 }
 
+/// `TestMultOp` -> `test_mult_op`
+fn snake_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    for (i, c) in s.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
 fn capitalize(s: &str) -> String {
     let mut chars = s.chars();
     match chars.next() {
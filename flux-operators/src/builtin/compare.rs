@@ -48,16 +48,23 @@ impl CompareMode {
 pub struct CompareOp {
     id: Id,
     pub mode: CompareMode,
-    inputs: [InputPort; 2],
+    inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
 }
 
 impl CompareOp {
+    /// Default tolerance for Equal/NotEqual.
+    pub const DEFAULT_EPSILON: f32 = 1e-6;
+
     pub fn new(mode: CompareMode) -> Self {
         Self {
             id: Id::new(),
             mode,
-            inputs: [InputPort::float("A", 0.0), InputPort::float("B", 0.0)],
+            inputs: [
+                InputPort::float("A", 0.0),
+                InputPort::float("B", 0.0),
+                InputPort::float("Epsilon", Self::DEFAULT_EPSILON),
+            ],
             outputs: [OutputPort::bool("Result")],
         }
     }
@@ -117,10 +124,15 @@ impl Operator for CompareOp {
             Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float().unwrap_or(0.0),
             None => self.inputs[1].default.as_float().unwrap_or(0.0),
         };
+        let epsilon = match self.inputs[2].connection {
+            Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float().unwrap_or(0.0),
+            None => self.inputs[2].default.as_float().unwrap_or(0.0),
+        }
+        .abs();
 
         let result = match self.mode {
-            CompareMode::Equal => a == b,
-            CompareMode::NotEqual => a != b,
+            CompareMode::Equal => (a - b).abs() <= epsilon,
+            CompareMode::NotEqual => (a - b).abs() > epsilon,
             CompareMode::LessThan => a < b,
             CompareMode::LessOrEqual => a <= b,
             CompareMode::GreaterThan => a > b,
@@ -155,6 +167,7 @@ impl OperatorMeta for CompareOp {
         match index {
             0 => Some(PortMeta::new("A")),
             1 => Some(PortMeta::new("B")),
+            2 => Some(PortMeta::new("Epsilon").with_range(0.0, 1.0)),
             _ => None,
         }
     }
@@ -194,6 +207,23 @@ mod tests {
         assert!(!compute_compare(&mut op));
     }
 
+    #[test]
+    fn test_equal_within_epsilon() {
+        let mut op = CompareOp::new(CompareMode::Equal);
+        op.inputs_mut()[0].default = Value::Float(0.1 + 0.2);
+        op.inputs_mut()[1].default = Value::Float(0.3);
+        assert!(compute_compare(&mut op));
+
+        op.inputs_mut()[0].default = Value::Float(1.0);
+        op.inputs_mut()[1].default = Value::Float(1.05);
+        assert!(!compute_compare(&mut op));
+        op.inputs_mut()[2].default = Value::Float(0.1);
+        assert!(compute_compare(&mut op));
+
+        op.mode = CompareMode::NotEqual;
+        assert!(!compute_compare(&mut op));
+    }
+
     #[test]
     fn test_not_equal_true() {
         let mut op = CompareOp::new(CompareMode::NotEqual);
@@ -134,6 +134,7 @@ mod tests {
     }
 
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_constant_compute() {
         let mut op = ConstantOp::new(3.14);
         let result = compute_op(&mut op);
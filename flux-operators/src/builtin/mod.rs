@@ -9,6 +9,7 @@
 //! - [`Vec3ComposeOp`] - Vector composition
 //! - [`ScopeOp`] - Waveform visualization
 
+#[cfg(feature = "math")]
 mod arithmetic;
 mod compare;
 mod compose;
@@ -17,6 +18,7 @@ mod scope;
 mod sum;
 mod wave;
 
+#[cfg(feature = "math")]
 pub use arithmetic::{AddOp, MultiplyOp};
 pub use compare::{CompareMode, CompareOp};
 pub use compose::Vec3ComposeOp;
@@ -24,3 +26,5 @@ pub use constant::ConstantOp;
 pub use scope::ScopeOp;
 pub use sum::SumOp;
 pub use wave::SineWaveOp;
+#[cfg(feature = "time")]
+pub(crate) use wave::{validate_frequency, PhaseAccumulator};
@@ -139,6 +139,15 @@ impl Operator for ScopeOp {
         // Always needs to be re-evaluated to update the buffer
         true
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.reset();
+        true
+    }
 }
 
 impl OperatorMeta for ScopeOp {
@@ -65,14 +65,13 @@ impl Operator for SumOp {
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let input = &self.inputs[0];
-        let mut sum = 0.0;
-        let mut values = Vec::new();
-
-        for &(node_id, output_idx) in &input.connections {
-            let val = get_input(node_id, output_idx).as_float().unwrap_or(0.0);
-            values.push(val);
-            sum += val;
-        }
+        // Lists contribute all their elements
+        let values = if input.is_connected() {
+            input.resolve_multi_floats(get_input)
+        } else {
+            Vec::new()
+        };
+        let sum: f32 = values.iter().sum();
 
         if values.is_empty() {
             println!("  [Sum] (no inputs) = 0");
@@ -5,14 +5,66 @@ use std::any::Any;
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
 use flux_core::port::{InputPort, OutputPort};
+use flux_core::value::Value;
+
+use flux_core::{
+    category_colors, InputResolver, InputValidationIssue, Operator, OperatorMeta, PinShape,
+    PortMeta, Unit,
+};
+
+/// Position within an oscillator's cycle, accumulated in f64
+///
+/// Converting absolute time to f32 before multiplying by the frequency loses
+/// precision as a show runs (at six hours, f32 seconds are ~2ms apart) and the
+/// wave visibly stutters. Accumulating the phase from time steps keeps it
+/// exact and continuous when the frequency changes. Jumps backwards in time
+/// (and the first evaluation) resync to the absolute phase.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct PhaseAccumulator {
+    cycle: f64,
+    last_time: Option<f64>,
+}
+
+impl PhaseAccumulator {
+    /// Advance to `time` at `frequency` Hz, returning the cycle position in [0, 1)
+    pub(crate) fn advance(&mut self, time: f64, frequency: f32) -> f64 {
+        let frequency = frequency as f64;
+        let cycle = match self.last_time {
+            Some(last) if time >= last => self.cycle + frequency * (time - last),
+            _ => time * frequency,
+        };
+        self.cycle = cycle.rem_euclid(1.0);
+        self.last_time = Some(time);
+        self.cycle
+    }
+}
 
-use flux_core::{category_colors, InputResolver, Operator, OperatorMeta, PinShape, PortMeta};
+/// Flag an oscillator's Frequency input (input 0) unless it is positive
+///
+/// The wave still runs: at zero it holds its current value, and a negative
+/// frequency plays it backwards.
+pub(crate) fn validate_frequency(
+    inputs: &[InputPort],
+    proposed: &[Value],
+) -> Vec<InputValidationIssue> {
+    if inputs[0].is_connected() {
+        return Vec::new();
+    }
+    match proposed.first().and_then(Value::as_float) {
+        Some(freq) if freq <= 0.0 => vec![InputValidationIssue::warning(
+            0,
+            format!("Frequency must be greater than zero (got {} Hz)", freq),
+        )],
+        _ => Vec::new(),
+    }
+}
 
 /// SineWave Operator - time-based sine wave generator
 pub struct SineWaveOp {
     id: Id,
     inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
+    phase: PhaseAccumulator,
 }
 
 impl SineWaveOp {
@@ -25,6 +77,7 @@ impl SineWaveOp {
                 InputPort::float("Phase", 0.0), // radians
             ],
             outputs: [OutputPort::float("Value")],
+            phase: PhaseAccumulator::default(),
         }
     }
 }
@@ -82,14 +135,18 @@ impl Operator for SineWaveOp {
             None => self.inputs[2].default.as_float().unwrap_or(0.0),
         };
 
-        let time = ctx.time as f32;
-        let result = amp * (2.0 * std::f32::consts::PI * freq * time + phase).sin();
-        self.outputs[0].set_float(result);
+        let cycle = self.phase.advance(ctx.time, freq);
+        let result = amp as f64 * (std::f64::consts::TAU * cycle + phase as f64).sin();
+        self.outputs[0].set_float(result as f32);
     }
 
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn validate_inputs(&self, proposed: &[Value]) -> Vec<InputValidationIssue> {
+        validate_frequency(&self.inputs, proposed)
+    }
 }
 
 impl OperatorMeta for SineWaveOp {
@@ -110,13 +167,13 @@ impl OperatorMeta for SineWaveOp {
             0 => Some(
                 PortMeta::new("Frequency")
                     .with_range(0.01, 100.0)
-                    .with_unit("Hz"),
+                    .with_typed_unit(Unit::Hertz),
             ),
             1 => Some(PortMeta::new("Amplitude").with_range(0.0, 10.0)),
             2 => Some(
                 PortMeta::new("Phase")
                     .with_range(0.0, std::f32::consts::TAU)
-                    .with_unit("rad"),
+                    .with_typed_unit(Unit::Radians),
             ),
             _ => None,
         }
@@ -129,3 +186,63 @@ impl OperatorMeta for SineWaveOp {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::Value;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    /// Largest deviation from an exact 1Hz sine over ten seconds of 60fps
+    /// frames starting at `start`
+    fn max_error(start: f64, mut sample: impl FnMut(f64) -> f32) -> f32 {
+        let times: Vec<f64> = (0..600).map(|i| start + i as f64 / 60.0).collect();
+        let values: Vec<f32> = times.iter().map(|&time| sample(time)).collect();
+        assert!(values.windows(2).all(|w| w[0] != w[1]), "repeated value");
+        let exact = times.iter().map(|time| (std::f64::consts::TAU * time).sin() as f32);
+        values.iter().zip(exact).map(|(value, exact)| (value - exact).abs()).fold(0.0, f32::max)
+    }
+
+    #[test]
+    fn test_sine_wave_is_smooth_after_six_hours() {
+        let six_hours = 6.0 * 3600.0;
+        let mut op = SineWaveOp::new();
+        let mut ctx = EvalContext::new();
+        let error = max_error(six_hours, |time| {
+            ctx.time = time;
+            op.compute(&ctx, &no_connections);
+            op.outputs[0].value.as_float().unwrap()
+        });
+        assert!(error < 1e-5, "error {}", error);
+
+        // Sampling from f32 time, as before, is off by up to a millisecond
+        let truncated = max_error(six_hours, |time| {
+            (2.0 * std::f32::consts::PI * (time as f32)).sin()
+        });
+        assert!(truncated > 1e-3, "truncated error {}", truncated);
+    }
+
+    #[test]
+    fn test_phase_resyncs_on_backward_jump() {
+        let mut phase = PhaseAccumulator::default();
+        assert!((phase.advance(0.25, 1.0) - 0.25).abs() < 1e-12);
+        assert!((phase.advance(0.5, 2.0) - 0.75).abs() < 1e-12);
+        // Seeking backwards uses the absolute phase again
+        assert!((phase.advance(0.1, 2.0) - 0.2).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_frequency_must_be_positive() {
+        let mut op = SineWaveOp::new();
+        let proposed = |freq: f32| [Value::Float(freq), Value::Float(1.0), Value::Float(0.0)];
+        assert!(op.validate_inputs(&proposed(2.0)).is_empty());
+        assert_eq!(op.validate_inputs(&proposed(0.0))[0].input, 0);
+        assert_eq!(op.validate_inputs(&proposed(-1.0)).len(), 1);
+
+        op.inputs[0].connect(Id::new(), 0);
+        assert!(op.validate_inputs(&proposed(0.0)).is_empty());
+    }
+}
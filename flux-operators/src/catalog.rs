@@ -0,0 +1,437 @@
+//! Operator catalog export
+//!
+//! Describes every registered operator as plain data so documentation sites and
+//! remote node pickers can list operators without linking Rust. Each operator is
+//! instantiated once through its registry factory to read its ports and the
+//! metadata captured alongside it. [`OperatorRegistry::catalog_with_theme`]
+//! additionally records the colors and icons a [`ThemeRegistry`] resolves.
+//! Deprecated operators and renamed names are left out unless
+//! [`OperatorRegistry::catalog_including_deprecated`] asks for them.
+//!
+//! ```ignore
+//! let registry = create_default_registry();
+//! std::fs::write("catalog.json", registry.export_catalog().to_string())?;
+//! std::fs::write("OPERATORS.md", registry.export_catalog_markdown())?;
+//! ```
+
+use std::fmt::Write;
+
+use flux_core::category::Category;
+use flux_core::operator::Operator;
+use flux_core::operator_meta::{category_colors, PortMeta};
+use flux_core::theme::ThemeRegistry;
+use flux_core::value::{Value, ValueType};
+use serde::Serialize;
+
+use crate::registry::{CapturedMeta, ExtendedEntry, OperatorRegistry, ParameterMeta, ParameterValue};
+
+/// List defaults longer than this are truncated in the catalog
+pub const MAX_DEFAULT_ITEMS: usize = 16;
+
+/// Exported description of all operators in a registry
+#[derive(Debug, Clone, Serialize)]
+pub struct Catalog {
+    /// Operators sorted by category, then name
+    pub operators: Vec<CatalogOperator>,
+}
+
+/// Exported description of a single operator type
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogOperator {
+    pub name: &'static str,
+    /// Registry category, exported as its path string, e.g. "Math/Trig"
+    pub category: Category,
+    pub description: &'static str,
+    /// `None` for operators registered without `OperatorMeta`
+    pub category_color: Option<[f32; 4]>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<&'static str>,
+    /// Color after applying the theme, when exported with one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_color: Option<[f32; 4]>,
+    /// Icon after applying the theme, when exported with one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub theme_icon: Option<String>,
+    pub inputs: Vec<CatalogPort>,
+    pub outputs: Vec<CatalogPort>,
+    pub trigger_inputs: Vec<&'static str>,
+    pub trigger_outputs: Vec<&'static str>,
+    pub time_varying: bool,
+    /// Constructor parameters accepted by `create_with_params`
+    pub parameters: Vec<ParameterMeta>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub deprecated: bool,
+    /// Operator to use instead of a deprecated one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replaced_by: Option<&'static str>,
+    /// Deprecation notice from `OperatorMeta`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deprecation: Option<&'static str>,
+}
+
+/// Exported description of an input or output port
+#[derive(Debug, Clone, Serialize)]
+pub struct CatalogPort {
+    pub name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<&'static str>,
+    pub value_type: ValueType,
+    /// Default value of an input (lists are cut to [`MAX_DEFAULT_ITEMS`])
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    /// Full length of a list default that was truncated
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_len: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<(f32, f32)>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shape: Option<String>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub multi_input: bool,
+}
+
+impl CatalogPort {
+    fn new(name: &'static str, value_type: ValueType, meta: Option<&PortMeta>) -> Self {
+        Self {
+            name,
+            label: meta.map(|m| m.label).filter(|label| *label != name),
+            value_type,
+            default: None,
+            default_len: None,
+            range: meta.and_then(|m| m.range),
+            unit: meta.and_then(|m| m.unit),
+            shape: meta.map(|m| format!("{:?}", m.shape)),
+            multi_input: false,
+        }
+    }
+
+    fn with_default(mut self, default: &Value) -> Self {
+        let mut default = default.clone();
+        let len = default.list_len();
+        if default.truncate_list(MAX_DEFAULT_ITEMS) {
+            self.default_len = len;
+        }
+        self.default = Some(default);
+        self
+    }
+}
+
+impl CatalogOperator {
+    fn new(entry: &ExtendedEntry, op: &dyn Operator, meta: &CapturedMeta) -> Self {
+        let port_meta = |metas: &[Option<PortMeta>], index: usize| metas.get(index).cloned().flatten();
+
+        let inputs = op
+            .inputs()
+            .iter()
+            .enumerate()
+            .map(|(i, input)| {
+                let meta = port_meta(&meta.inputs, i);
+                let mut port = CatalogPort::new(input.name, input.value_type, meta.as_ref())
+                    .with_default(&input.default);
+                port.multi_input = input.is_multi_input;
+                port
+            })
+            .collect();
+        let outputs = op
+            .outputs()
+            .iter()
+            .enumerate()
+            .map(|(i, output)| {
+                let meta = port_meta(&meta.outputs, i);
+                CatalogPort::new(output.name, output.value_type, meta.as_ref())
+            })
+            .collect();
+
+        Self {
+            name: entry.meta.name,
+            category: entry.meta.category_path(),
+            description: entry.meta.description,
+            category_color: meta.category_color,
+            icon: meta.icon,
+            theme_color: None,
+            theme_icon: None,
+            inputs,
+            outputs,
+            trigger_inputs: op.trigger_inputs().iter().map(|t| t.name).collect(),
+            trigger_outputs: op.trigger_outputs().iter().map(|t| t.name).collect(),
+            time_varying: op.is_time_varying(),
+            parameters: entry.parameters.clone(),
+            deprecated: entry.meta.deprecated || meta.deprecation.is_some(),
+            replaced_by: entry.meta.replaced_by,
+            deprecation: meta.deprecation,
+        }
+    }
+
+    /// Fill in the theme's colors, matching categories by the `OperatorMeta`
+    /// category (the registry category for operators without one)
+    fn apply_theme(&mut self, meta: &CapturedMeta, theme: &ThemeRegistry) {
+        let category = meta.category.as_ref().unwrap_or(&self.category).to_string();
+        let fallback = self.category_color.unwrap_or(category_colors::UNCATEGORIZED);
+        self.theme_color = Some(theme.resolve_color(self.name, &category, fallback));
+        self.theme_icon = theme.operator_icon(self.name).or(self.icon).map(str::to_string);
+    }
+}
+
+impl Catalog {
+    /// Look up an operator by name
+    pub fn get(&self, name: &str) -> Option<&CatalogOperator> {
+        self.operators.iter().find(|op| op.name == name)
+    }
+
+    /// Render a human-readable reference grouped by category
+    ///
+    /// Sub-categories get headings one level below their parent, and
+    /// operators one level below their category.
+    pub fn to_markdown(&self) -> String {
+        let mut out = String::from("# Operator Reference\n");
+        let mut previous: Option<&Category> = None;
+
+        for op in &self.operators {
+            if previous != Some(&op.category) {
+                // Headings for the levels this category doesn't share with the previous one
+                let shared = previous.map_or(0, |previous| {
+                    previous.segments().zip(op.category.segments()).take_while(|(a, b)| a == b).count()
+                });
+                for (depth, segment) in op.category.segments().enumerate().skip(shared) {
+                    let _ = write!(out, "\n{} {}\n", "#".repeat(depth + 2), segment);
+                }
+                previous = Some(&op.category);
+            }
+
+            let _ = write!(out, "\n{} {}\n\n", "#".repeat(op.category.depth() + 2), op.name);
+            if !op.description.is_empty() {
+                let _ = writeln!(out, "{}\n", op.description);
+            }
+            if op.deprecated {
+                match (op.replaced_by, op.deprecation) {
+                    (Some(name), _) => {
+                        let _ = writeln!(out, "*Deprecated: use {} instead.*\n", name);
+                    }
+                    (None, Some(notice)) => {
+                        let _ = writeln!(out, "*Deprecated: {}*\n", notice);
+                    }
+                    (None, None) => out.push_str("*Deprecated.*\n\n"),
+                }
+            }
+            if op.time_varying {
+                out.push_str("*Time-varying: re-evaluates every frame.*\n\n");
+            }
+
+            if !op.inputs.is_empty() {
+                out.push_str("| Input | Type | Default | Range | Unit |\n");
+                out.push_str("|-------|------|---------|-------|------|\n");
+                for port in &op.inputs {
+                    let default = match (&port.default, port.default_len) {
+                        (Some(value), Some(len)) => format!("{} (of {})", value, len),
+                        (Some(value), None) => value.to_string(),
+                        (None, _) => String::new(),
+                    };
+                    let range = port
+                        .range
+                        .map(|(min, max)| format!("{} – {}", min, max))
+                        .unwrap_or_default();
+                    let multi = if port.multi_input { " (multi)" } else { "" };
+                    let _ = writeln!(
+                        out,
+                        "| {}{} | {} | {} | {} | {} |",
+                        port.name,
+                        multi,
+                        port.value_type,
+                        default,
+                        range,
+                        port.unit.unwrap_or("")
+                    );
+                }
+                out.push('\n');
+            }
+
+            if !op.outputs.is_empty() {
+                let outputs: Vec<String> = op
+                    .outputs
+                    .iter()
+                    .map(|port| format!("{} ({})", port.name, port.value_type))
+                    .collect();
+                let _ = writeln!(out, "**Outputs:** {}\n", outputs.join(", "));
+            }
+            if !op.trigger_inputs.is_empty() {
+                let _ = writeln!(out, "**Trigger inputs:** {}\n", op.trigger_inputs.join(", "));
+            }
+            if !op.trigger_outputs.is_empty() {
+                let _ = writeln!(out, "**Trigger outputs:** {}\n", op.trigger_outputs.join(", "));
+            }
+            if !op.parameters.is_empty() {
+                let params: Vec<String> = op
+                    .parameters
+                    .iter()
+                    .map(|p| {
+                        let default = match &p.default {
+                            ParameterValue::Float(v) => v.to_string(),
+                            ParameterValue::Int(v) => v.to_string(),
+                            ParameterValue::Bool(v) => v.to_string(),
+                            ParameterValue::Enum(v) => v.to_string(),
+                            ParameterValue::String(v) => format!("{:?}", v),
+                        };
+                        format!("`{}` (default {})", p.name, default)
+                    })
+                    .collect();
+                let _ = writeln!(out, "**Parameters:** {}\n", params.join(", "));
+            }
+        }
+
+        out
+    }
+}
+
+impl OperatorRegistry {
+    /// Describe every registered operator that isn't deprecated
+    ///
+    /// Each operator is created once with its default factory. Operators are
+    /// sorted by category, then name.
+    pub fn catalog(&self) -> Catalog {
+        self.build_catalog(self.list_all_extended(), None)
+    }
+
+    /// Describe every registered operator with the colors and icons `theme`
+    /// resolves to in `theme_color` and `theme_icon`
+    pub fn catalog_with_theme(&self, theme: &ThemeRegistry) -> Catalog {
+        self.build_catalog(self.list_all_extended(), Some(theme))
+    }
+
+    /// Describe every operator name that can be created, including
+    /// deprecated operators and renamed names
+    pub fn catalog_including_deprecated(&self) -> Catalog {
+        self.build_catalog(self.list_all_including_deprecated(), None)
+    }
+
+    fn build_catalog(&self, entries: Vec<ExtendedEntry>, theme: Option<&ThemeRegistry>) -> Catalog {
+        let mut operators: Vec<CatalogOperator> = entries
+            .iter()
+            .filter_map(|entry| {
+                let (op, meta) = self.create_with_meta_by_id(entry.meta.type_id)?;
+                let mut operator = CatalogOperator::new(entry, op.as_ref(), &meta);
+                if let Some(theme) = theme {
+                    operator.apply_theme(&meta, theme);
+                }
+                Some(operator)
+            })
+            .collect();
+        operators.sort_by(|a, b| (&a.category, a.name).cmp(&(&b.category, b.name)));
+        Catalog { operators }
+    }
+
+    /// Export the operator catalog as JSON
+    pub fn export_catalog(&self) -> serde_json::Value {
+        serde_json::to_value(self.catalog()).expect("catalog serializes to JSON")
+    }
+
+    /// Export the operator catalog as JSON with colors resolved by `theme`
+    pub fn export_catalog_with_theme(&self, theme: &ThemeRegistry) -> serde_json::Value {
+        serde_json::to_value(self.catalog_with_theme(theme)).expect("catalog serializes to JSON")
+    }
+
+    /// Export the operator catalog as a Markdown reference grouped by category
+    pub fn export_catalog_markdown(&self) -> String {
+        self.catalog().to_markdown()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::create_default_registry;
+
+    #[test]
+    #[cfg(all(feature = "math", feature = "list"))]
+    fn test_export_default_registry() {
+        let registry = create_default_registry();
+        let catalog = registry.catalog();
+        assert_eq!(catalog.operators.len(), registry.len());
+
+        let add = catalog.get("Add").unwrap();
+        assert_eq!(add.category, "Math/Arithmetic");
+        assert_eq!(add.inputs.len(), 2);
+        assert_eq!(add.outputs.len(), 1);
+        assert!(add.category_color.is_some());
+        assert!(!add.time_varying);
+
+        let list_get = catalog.get("ListGet").unwrap();
+        let input_types: Vec<ValueType> = list_get.inputs.iter().map(|p| p.value_type).collect();
+        assert_eq!(input_types, vec![ValueType::FloatList, ValueType::Int]);
+        assert_eq!(list_get.outputs[0].value_type, ValueType::Float);
+
+        let sine = catalog.get("SineWave").unwrap();
+        assert!(sine.time_varying);
+        assert_eq!(sine.outputs.len(), 1);
+        assert_eq!(sine.outputs[0].value_type, ValueType::Float);
+
+        let compare = catalog.get("Compare").unwrap();
+        assert_eq!(compare.parameters[0].name, "mode");
+
+        let json = registry.export_catalog();
+        let operators = json["operators"].as_array().unwrap();
+        let add = operators.iter().find(|op| op["name"] == "Add").unwrap();
+        assert_eq!(add["category"], "Math/Arithmetic");
+        assert_eq!(add["inputs"].as_array().unwrap().len(), 2);
+        assert_eq!(add["outputs"][0]["value_type"], "Float");
+    }
+
+    #[test]
+    #[cfg(feature = "math")]
+    fn test_catalog_with_theme() {
+        let red = [1.0, 0.0, 0.0, 1.0];
+        let green = [0.0, 1.0, 0.0, 1.0];
+        let theme = ThemeRegistry::new()
+            .with_category("Math", red)
+            .with_operator_color("Multiply", green)
+            .with_operator_icon("Multiply", "x");
+        let registry = create_default_registry();
+        let catalog = registry.catalog_with_theme(&theme);
+
+        assert_eq!(catalog.get("Add").unwrap().theme_color, Some(red));
+        let multiply = catalog.get("Multiply").unwrap();
+        assert_eq!(multiply.theme_color, Some(green));
+        assert_eq!(multiply.theme_icon.as_deref(), Some("x"));
+        // Categories the theme doesn't mention resolve to the operator's own color
+        let sine = catalog.get("SineWave").unwrap();
+        assert_eq!(sine.theme_color, sine.category_color);
+
+        let json = registry.export_catalog();
+        let add = json["operators"].as_array().unwrap().iter().find(|op| op["name"] == "Add");
+        assert!(add.unwrap().get("theme_color").is_none());
+    }
+
+    #[test]
+    fn test_list_defaults_are_truncated() {
+        let port = CatalogPort::new("Values", ValueType::FloatList, None)
+            .with_default(&Value::float_list(vec![0.0; 100]));
+        assert_eq!(port.default.unwrap().list_len(), Some(MAX_DEFAULT_ITEMS));
+        assert_eq!(port.default_len, Some(100));
+
+        let port = CatalogPort::new("Value", ValueType::Float, None).with_default(&Value::Float(1.0));
+        assert_eq!(port.default_len, None);
+    }
+
+    #[test]
+    fn test_markdown_groups_by_category() {
+        let markdown = create_default_registry().export_catalog_markdown();
+        assert!(markdown.starts_with("# Operator Reference"));
+        assert!(markdown.contains("## Oscillators"));
+        assert!(markdown.contains("### SineWave"));
+        assert!(markdown.contains("*Time-varying"));
+    }
+
+    #[test]
+    #[cfg(feature = "math")]
+    fn test_markdown_nests_sub_categories() {
+        let markdown = create_default_registry().export_catalog_markdown();
+        let math = markdown.find("\n## Math\n").unwrap();
+        let arithmetic = markdown.find("\n### Arithmetic\n").unwrap();
+        let trig = markdown.find("\n### Trig\n").unwrap();
+        assert!(math < arithmetic && arithmetic < trig);
+        assert!(markdown.contains("\n#### Add\n"));
+        // One heading for Math, however many sub-categories follow
+        assert_eq!(markdown.matches("\n## Math\n").count(), 1);
+    }
+}
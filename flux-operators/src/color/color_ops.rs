@@ -1,5 +1,7 @@
 //! Color operators: RgbaColor, HsvToRgb, RgbToHsv, BlendColors, SampleGradient,
-//!                  AdjustBrightness, AdjustSaturation, ColorToVec4
+//!                  AdjustBrightness, AdjustSaturation, ColorToVec4,
+//!                  ColorTemperature, Exposure, ColorContrast,
+//!                  ContrastRatio, SimulateCvd, EnsureContrast
 
 use std::any::Any;
 
@@ -9,7 +11,7 @@ use flux_core::operator::{InputResolver, Operator};
 use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::port::{InputPort, OutputPort};
-use flux_core::value::{Color, Gradient};
+use flux_core::value::{Color, ColorVisionDeficiency, Gradient};
 
 fn get_float(input: &InputPort, get_input: InputResolver) -> f32 {
     match input.connection {
@@ -18,6 +20,20 @@ fn get_float(input: &InputPort, get_input: InputResolver) -> f32 {
     }
 }
 
+fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_int().unwrap_or(0),
+        None => input.default.as_int().unwrap_or(0),
+    }
+}
+
+fn get_bool(input: &InputPort, get_input: InputResolver) -> bool {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_bool().unwrap_or(false),
+        None => input.default.as_bool().unwrap_or(false),
+    }
+}
+
 fn get_color(input: &InputPort, get_input: InputResolver) -> Color {
     match input.connection {
         Some((node_id, output_idx)) => {
@@ -588,90 +604,502 @@ impl OperatorMeta for ColorToVec4Op {
     }
 }
 
+// ============================================================================
+// ColorTemperature Operator
+// ============================================================================
+
+pub struct ColorTemperatureOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 1],
+}
+
+impl ColorTemperatureOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::float("Kelvin", 6500.0)],
+            outputs: [OutputPort::color("Color")],
+        }
+    }
+}
+
+impl Default for ColorTemperatureOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ColorTemperatureOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ColorTemperature" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let kelvin = get_float(&self.inputs[0], get_input).clamp(1000.0, 20000.0);
+        let color = Color::from_kelvin(kelvin);
+        self.outputs[0].set_color(color.r, color.g, color.b, color.a);
+    }
+}
+
+impl OperatorMeta for ColorTemperatureOp {
+    fn category(&self) -> &'static str { "Color" }
+    fn category_color(&self) -> [f32; 4] { category_colors::COLORS }
+    fn description(&self) -> &'static str { "Blackbody color from temperature" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Kelvin").with_range(1000.0, 20000.0).with_unit("K")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Color").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Exposure Operator
+// ============================================================================
+
+pub struct ExposureOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl ExposureOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::color("Color", [1.0, 1.0, 1.0, 1.0]),
+                InputPort::float("EV", 0.0),
+                InputPort::bool("Clamp", false),
+            ],
+            outputs: [OutputPort::color("Result")],
+        }
+    }
+}
+
+impl Default for ExposureOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ExposureOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "Exposure" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let color = get_color(&self.inputs[0], get_input);
+        let ev = get_float(&self.inputs[1], get_input);
+        let clamp = get_bool(&self.inputs[2], get_input);
+
+        // Alpha is not light, so it is left unscaled
+        let scale = 2.0_f32.powf(ev);
+        let mut result = Color::rgba(color.r * scale, color.g * scale, color.b * scale, color.a);
+        if clamp {
+            result = result.clamp();
+        }
+        self.outputs[0].set_color(result.r, result.g, result.b, result.a);
+    }
+}
+
+impl OperatorMeta for ExposureOp {
+    fn category(&self) -> &'static str { "Color" }
+    fn category_color(&self) -> [f32; 4] { category_colors::COLORS }
+    fn description(&self) -> &'static str { "Scale color by 2^EV" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Color")),
+            1 => Some(PortMeta::new("EV").with_range(-10.0, 10.0).with_unit("EV")),
+            2 => Some(PortMeta::new("Clamp")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// ColorContrast Operator
+// ============================================================================
+
+pub struct ColorContrastOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl ColorContrastOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::color("Color", [1.0, 1.0, 1.0, 1.0]),
+                InputPort::float("Contrast", 1.0),
+                InputPort::float("Pivot", 0.5),
+            ],
+            outputs: [OutputPort::color("Result")],
+        }
+    }
+}
+
+impl Default for ColorContrastOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ColorContrastOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ColorContrast" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let color = get_color(&self.inputs[0], get_input);
+        let contrast = get_float(&self.inputs[1], get_input);
+        let pivot = get_float(&self.inputs[2], get_input);
+
+        // Push channels away from (or towards) the pivot; negative light is invalid
+        let adjust = |c: f32| ((c - pivot) * contrast + pivot).max(0.0);
+        self.outputs[0].set_color(adjust(color.r), adjust(color.g), adjust(color.b), color.a);
+    }
+}
+
+impl OperatorMeta for ColorContrastOp {
+    fn category(&self) -> &'static str { "Color" }
+    fn category_color(&self) -> [f32; 4] { category_colors::COLORS }
+    fn description(&self) -> &'static str { "Adjust color contrast around a pivot" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Color")),
+            1 => Some(PortMeta::new("Contrast").with_range(0.0, 3.0)),
+            2 => Some(PortMeta::new("Pivot").with_range(0.0, 1.0)),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// ContrastRatio Operator
+// ============================================================================
+
+pub struct ContrastRatioOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 3],
+}
+
+impl ContrastRatioOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::color("Foreground", [0.0, 0.0, 0.0, 1.0]),
+                InputPort::color("Background", [1.0, 1.0, 1.0, 1.0]),
+            ],
+            outputs: [
+                OutputPort::float("Ratio"),
+                OutputPort::bool("PassAA"),
+                OutputPort::bool("PassAAA"),
+            ],
+        }
+    }
+}
+
+impl Default for ContrastRatioOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ContrastRatioOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ContrastRatio" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let foreground = get_color(&self.inputs[0], get_input);
+        let background = get_color(&self.inputs[1], get_input);
+
+        let ratio = foreground.contrast_ratio(&background);
+        self.outputs[0].set_float(ratio);
+        self.outputs[1].set_bool(ratio >= Color::CONTRAST_AA);
+        self.outputs[2].set_bool(ratio >= Color::CONTRAST_AAA);
+    }
+}
+
+impl OperatorMeta for ContrastRatioOp {
+    fn category(&self) -> &'static str { "Color" }
+    fn category_color(&self) -> [f32; 4] { category_colors::COLORS }
+    fn description(&self) -> &'static str { "WCAG contrast ratio between two colors" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Foreground")),
+            1 => Some(PortMeta::new("Background")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Ratio").with_range(1.0, 21.0)),
+            1 => Some(PortMeta::new("PassAA")),
+            2 => Some(PortMeta::new("PassAAA")),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// SimulateCvd Operator
+// ============================================================================
+
+pub struct SimulateCvdOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl SimulateCvdOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::color("Color", [1.0, 1.0, 1.0, 1.0]),
+                InputPort::int("Type", ColorVisionDeficiency::Protanopia.index()),
+                InputPort::float("Severity", 1.0),
+            ],
+            outputs: [OutputPort::color("Result")],
+        }
+    }
+}
+
+impl Default for SimulateCvdOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for SimulateCvdOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "SimulateCvd" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let color = get_color(&self.inputs[0], get_input);
+        let deficiency = ColorVisionDeficiency::from_index(get_int(&self.inputs[1], get_input));
+        let severity = get_float(&self.inputs[2], get_input);
+
+        let result = color.simulate_cvd(deficiency, severity);
+        self.outputs[0].set_color(result.r, result.g, result.b, result.a);
+    }
+}
+
+impl OperatorMeta for SimulateCvdOp {
+    fn category(&self) -> &'static str { "Color" }
+    fn category_color(&self) -> [f32; 4] { category_colors::COLORS }
+    fn description(&self) -> &'static str { "Simulate a color vision deficiency" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Color")),
+            1 => Some(PortMeta::new("Type").with_range(0.0, 3.0).with_step(1.0)),
+            2 => Some(PortMeta::new("Severity").with_range(0.0, 1.0)),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// EnsureContrast Operator
+// ============================================================================
+
+pub struct EnsureContrastOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl EnsureContrastOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::color("Foreground", [0.5, 0.5, 0.5, 1.0]),
+                InputPort::color("Background", [1.0, 1.0, 1.0, 1.0]),
+                InputPort::float("MinRatio", Color::CONTRAST_AA),
+            ],
+            outputs: [OutputPort::color("Result")],
+        }
+    }
+}
+
+impl Default for EnsureContrastOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for EnsureContrastOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "EnsureContrast" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let foreground = get_color(&self.inputs[0], get_input);
+        let background = get_color(&self.inputs[1], get_input);
+        let min_ratio = get_float(&self.inputs[2], get_input);
+
+        let result = foreground.ensure_contrast(&background, min_ratio);
+        self.outputs[0].set_color(result.r, result.g, result.b, result.a);
+    }
+}
+
+impl OperatorMeta for EnsureContrastOp {
+    fn category(&self) -> &'static str { "Color" }
+    fn category_color(&self) -> [f32; 4] { category_colors::COLORS }
+    fn description(&self) -> &'static str { "Adjust lightness to reach a contrast ratio" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Foreground")),
+            1 => Some(PortMeta::new("Background")),
+            2 => Some(PortMeta::new("MinRatio").with_range(1.0, 21.0)),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // Registration
 // ============================================================================
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "RgbaColor",
-            category: "Color",
-            description: "Create color from RGBA components",
-        },
+        RegistryEntry::new("RgbaColor", "Color", "Create color from RGBA components"),
         || capture_meta(RgbaColorOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "HsvToRgb",
-            category: "Color",
-            description: "Convert HSV to RGB color",
-        },
+        RegistryEntry::new("HsvToRgb", "Color", "Convert HSV to RGB color"),
         || capture_meta(HsvToRgbOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "RgbToHsv",
-            category: "Color",
-            description: "Convert RGB color to HSV",
-        },
+        RegistryEntry::new("RgbToHsv", "Color", "Convert RGB color to HSV"),
         || capture_meta(RgbToHsvOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "BlendColors",
-            category: "Color",
-            description: "Blend two colors",
-        },
+        RegistryEntry::new("BlendColors", "Color", "Blend two colors"),
         || capture_meta(BlendColorsOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "SampleGradient",
-            category: "Color",
-            description: "Sample color from gradient at position",
-        },
+        RegistryEntry::new("SampleGradient", "Color", "Sample color from gradient at position"),
         || capture_meta(SampleGradientOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "AdjustBrightness",
-            category: "Color",
-            description: "Adjust color brightness",
-        },
+        RegistryEntry::new("AdjustBrightness", "Color", "Adjust color brightness"),
         || capture_meta(AdjustBrightnessOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "AdjustSaturation",
-            category: "Color",
-            description: "Adjust color saturation",
-        },
+        RegistryEntry::new("AdjustSaturation", "Color", "Adjust color saturation"),
         || capture_meta(AdjustSaturationOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ColorToVec4",
-            category: "Color",
-            description: "Convert color to Vec4",
-        },
+        RegistryEntry::new("ColorToVec4", "Color", "Convert color to Vec4"),
         || capture_meta(ColorToVec4Op::new()),
     );
+
+    registry.register(
+        RegistryEntry::new("ColorTemperature", "Color", "Blackbody color from temperature"),
+        || capture_meta(ColorTemperatureOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("Exposure", "Color", "Scale color by 2^EV"),
+        || capture_meta(ExposureOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("ColorContrast", "Color", "Adjust color contrast around a pivot"),
+        || capture_meta(ColorContrastOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("ContrastRatio", "Color", "WCAG contrast ratio between two colors"),
+        || capture_meta(ContrastRatioOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("SimulateCvd", "Color", "Simulate a color vision deficiency"),
+        || capture_meta(SimulateCvdOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("EnsureContrast", "Color", "Adjust lightness to reach a contrast ratio"),
+        || capture_meta(EnsureContrastOp::new()),
+    );
 }
 
 #[cfg(test)]
@@ -740,4 +1168,105 @@ mod tests {
         assert!((result.g - original.g).abs() < 0.01);
         assert!((result.b - original.b).abs() < 0.01);
     }
+
+    #[test]
+    fn test_color_temperature() {
+        let mut op = ColorTemperatureOp::new();
+        let ctx = EvalContext::new();
+
+        op.compute(&ctx, &no_connections);
+        let white = op.outputs[0].value.as_color().unwrap();
+        assert!(white.r > 0.95 && white.g > 0.95 && white.b > 0.95);
+
+        op.inputs[0].default = Value::Float(2000.0);
+        op.compute(&ctx, &no_connections);
+        let orange = op.outputs[0].value.as_color().unwrap();
+        assert!(orange.r > 0.9 && orange.g < 0.6 && orange.b < 0.1);
+
+        op.inputs[0].default = Value::Float(12000.0);
+        op.compute(&ctx, &no_connections);
+        let blue = op.outputs[0].value.as_color().unwrap();
+        assert!(blue.b > blue.r && blue.b > blue.g);
+    }
+
+    #[test]
+    fn test_exposure() {
+        let mut op = ExposureOp::new();
+        op.inputs[0].default = Value::Color(Color::rgba(0.25, 0.5, 0.75, 0.5));
+        op.inputs[1].default = Value::Float(1.0);
+        let ctx = EvalContext::new();
+
+        op.compute(&ctx, &no_connections);
+        let color = op.outputs[0].value.as_color().unwrap();
+        assert_eq!(color, Color::rgba(0.5, 1.0, 1.5, 0.5));
+
+        op.inputs[2].default = Value::Bool(true);
+        op.compute(&ctx, &no_connections);
+        let clamped = op.outputs[0].value.as_color().unwrap();
+        assert_eq!(clamped, Color::rgba(0.5, 1.0, 1.0, 0.5));
+    }
+
+    #[test]
+    fn test_color_contrast() {
+        let mut op = ColorContrastOp::new();
+        op.inputs[0].default = Value::Color(Color::rgb(0.25, 0.5, 0.75));
+        op.inputs[1].default = Value::Float(2.0);
+        let ctx = EvalContext::new();
+
+        op.compute(&ctx, &no_connections);
+        let color = op.outputs[0].value.as_color().unwrap();
+        assert_eq!(color, Color::rgb(0.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let mut op = ContrastRatioOp::new();
+        let ctx = EvalContext::new();
+
+        op.compute(&ctx, &no_connections);
+        assert!((op.outputs[0].as_float() - 21.0).abs() < 1e-4);
+        assert_eq!(op.outputs[2].value, Value::Bool(true));
+
+        // #777777 on white just misses AA
+        let v = 0x77 as f32 / 255.0;
+        op.inputs[0].default = Value::Color(Color::rgb(v, v, v));
+        op.compute(&ctx, &no_connections);
+        assert!((op.outputs[0].as_float() - 4.48).abs() < 0.01);
+        assert_eq!(op.outputs[1].value, Value::Bool(false));
+        assert_eq!(op.outputs[2].value, Value::Bool(false));
+    }
+
+    #[test]
+    fn test_simulate_cvd() {
+        let mut op = SimulateCvdOp::new();
+        op.inputs[0].default = Value::Color(Color::RED);
+        let ctx = EvalContext::new();
+
+        op.compute(&ctx, &no_connections);
+        let protan = op.outputs[0].value.as_color().unwrap();
+        assert!((protan.r - 0.4266).abs() < 1e-3 && (protan.g - 0.3727).abs() < 1e-3);
+
+        op.inputs[1].default = Value::Int(ColorVisionDeficiency::Achromatopsia.index());
+        op.inputs[2].default = Value::Float(0.0);
+        op.compute(&ctx, &no_connections);
+        let unchanged = op.outputs[0].value.as_color().unwrap();
+        assert!((unchanged.r - 1.0).abs() < 1e-4 && unchanged.g.abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_ensure_contrast() {
+        let mut op = EnsureContrastOp::new();
+        let ctx = EvalContext::new();
+
+        op.compute(&ctx, &no_connections);
+        let result = op.outputs[0].value.as_color().unwrap();
+        assert!(result.contrast_ratio(&Color::WHITE) >= Color::CONTRAST_AA);
+
+        op.inputs[1].default = Value::Color(Color::BLACK);
+        op.inputs[2].default = Value::Float(Color::CONTRAST_AAA);
+        op.compute(&ctx, &no_connections);
+        let lightened = op.outputs[0].value.as_color().unwrap();
+        assert!(lightened.r > 0.5);
+        assert!(lightened.contrast_ratio(&Color::BLACK) >= Color::CONTRAST_AAA);
+    }
 }
@@ -1,4 +1,4 @@
-//! Color operators (8 total)
+//! Color operators (14 total)
 
 use crate::registry::OperatorRegistry;
 
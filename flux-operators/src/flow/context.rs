@@ -1,4 +1,8 @@
 //! Context variable operators: GetFloatVar, SetFloatVar, GetIntVar
+//!
+//! Each operator has an optional `Namespace` input. When empty, the plain
+//! float/int variables are used; otherwise the namespaced object variables
+//! (see [`EvalContext::vars`]) are read or written.
 
 use std::any::Any;
 
@@ -39,7 +43,7 @@ fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
 
 pub struct GetFloatVarOp {
     id: Id,
-    inputs: [InputPort; 2],
+    inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
 }
 
@@ -50,6 +54,7 @@ impl GetFloatVarOp {
             inputs: [
                 InputPort::string("Name", ""),
                 InputPort::float("Default", 0.0),
+                InputPort::string("Namespace", ""),
             ],
             outputs: [OutputPort::float("Value")],
         }
@@ -75,7 +80,12 @@ impl Operator for GetFloatVarOp {
     fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let name = get_string(&self.inputs[0], get_input);
         let default = get_float(&self.inputs[1], get_input);
-        let value = ctx.get_float_var_or(&name, default);
+        let namespace = get_string(&self.inputs[2], get_input);
+        let value = if namespace.is_empty() {
+            ctx.get_float_var_or(&name, default)
+        } else {
+            ctx.vars(&namespace).get_float_or(&name, default)
+        };
         self.outputs[0].set_float(value);
     }
 }
@@ -88,6 +98,7 @@ impl OperatorMeta for GetFloatVarOp {
         match index {
             0 => Some(PortMeta::new("Name")),
             1 => Some(PortMeta::new("Default")),
+            2 => Some(PortMeta::new("Namespace")),
             _ => None,
         }
     }
@@ -105,9 +116,10 @@ impl OperatorMeta for GetFloatVarOp {
 
 pub struct SetFloatVarOp {
     id: Id,
-    inputs: [InputPort; 2],
+    inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
     var_name: String,
+    var_namespace: String,
     var_value: f32,
 }
 
@@ -118,9 +130,11 @@ impl SetFloatVarOp {
             inputs: [
                 InputPort::string("Name", ""),
                 InputPort::float("Value", 0.0),
+                InputPort::string("Namespace", ""),
             ],
             outputs: [OutputPort::float("Value")],
             var_name: String::new(),
+            var_namespace: String::new(),
             var_value: 0.0,
         }
     }
@@ -133,6 +147,22 @@ impl SetFloatVarOp {
             None
         }
     }
+
+    /// Get the namespace of the pending variable (empty = plain float variable)
+    pub fn get_pending_namespace(&self) -> &str {
+        &self.var_namespace
+    }
+
+    /// Write the pending variable into a context, honoring the namespace.
+    pub fn apply_pending_var(&self, ctx: &mut EvalContext) {
+        if let Some((name, value)) = self.get_pending_var() {
+            if self.var_namespace.is_empty() {
+                ctx.set_float_var(name, value);
+            } else {
+                ctx.vars_mut(&self.var_namespace).set_float(name, value);
+            }
+        }
+    }
 }
 
 impl Default for SetFloatVarOp {
@@ -154,9 +184,11 @@ impl Operator for SetFloatVarOp {
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let name = get_string(&self.inputs[0], get_input);
         let value = get_float(&self.inputs[1], get_input);
+        let namespace = get_string(&self.inputs[2], get_input);
 
         // Store for later application to context
         self.var_name = name;
+        self.var_namespace = namespace;
         self.var_value = value;
 
         // Pass through the value
@@ -172,6 +204,7 @@ impl OperatorMeta for SetFloatVarOp {
         match index {
             0 => Some(PortMeta::new("Name")),
             1 => Some(PortMeta::new("Value")),
+            2 => Some(PortMeta::new("Namespace")),
             _ => None,
         }
     }
@@ -189,7 +222,7 @@ impl OperatorMeta for SetFloatVarOp {
 
 pub struct GetIntVarOp {
     id: Id,
-    inputs: [InputPort; 2],
+    inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
 }
 
@@ -200,6 +233,7 @@ impl GetIntVarOp {
             inputs: [
                 InputPort::string("Name", ""),
                 InputPort::int("Default", 0),
+                InputPort::string("Namespace", ""),
             ],
             outputs: [OutputPort::int("Value")],
         }
@@ -225,7 +259,12 @@ impl Operator for GetIntVarOp {
     fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let name = get_string(&self.inputs[0], get_input);
         let default = get_int(&self.inputs[1], get_input);
-        let value = ctx.get_int_var_or(&name, default);
+        let namespace = get_string(&self.inputs[2], get_input);
+        let value = if namespace.is_empty() {
+            ctx.get_int_var_or(&name, default)
+        } else {
+            ctx.vars(&namespace).get_int(&name).unwrap_or(default)
+        };
         self.outputs[0].set_int(value);
     }
 }
@@ -238,6 +277,7 @@ impl OperatorMeta for GetIntVarOp {
         match index {
             0 => Some(PortMeta::new("Name")),
             1 => Some(PortMeta::new("Default")),
+            2 => Some(PortMeta::new("Namespace")),
             _ => None,
         }
     }
@@ -255,32 +295,17 @@ impl OperatorMeta for GetIntVarOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "GetFloatVar",
-            category: "Flow",
-            description: "Get float variable from context",
-        },
+        RegistryEntry::new("GetFloatVar", "Flow", "Get float variable from context"),
         || capture_meta(GetFloatVarOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "SetFloatVar",
-            category: "Flow",
-            description: "Set float variable in context",
-        },
+        RegistryEntry::new("SetFloatVar", "Flow", "Set float variable in context"),
         || capture_meta(SetFloatVarOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "GetIntVar",
-            category: "Flow",
-            description: "Get integer variable from context",
-        },
+        RegistryEntry::new("GetIntVar", "Flow", "Get integer variable from context"),
         || capture_meta(GetIntVarOp::new()),
     );
 }
@@ -346,4 +371,46 @@ mod tests {
         op.compute(&ctx, &no_connections);
         assert_eq!(op.outputs[0].value.as_int(), Some(42));
     }
+
+    #[test]
+    fn test_get_float_var_namespaced() {
+        let mut op = GetFloatVarOp::new();
+        let mut ctx = EvalContext::new();
+        ctx.set_float_var("level", 1.0);
+        ctx.vars_mut("audio").set_float("level", 0.25);
+
+        op.inputs[0].default = Value::String("level".to_string());
+        op.inputs[2].default = Value::String("audio".to_string());
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_float(), Some(0.25));
+    }
+
+    #[test]
+    fn test_set_float_var_namespaced() {
+        let mut op = SetFloatVarOp::new();
+        let mut ctx = EvalContext::new();
+
+        op.inputs[0].default = Value::String("level".to_string());
+        op.inputs[1].default = Value::Float(0.75);
+        op.inputs[2].default = Value::String("audio".to_string());
+        op.compute(&ctx, &no_connections);
+
+        assert_eq!(op.get_pending_namespace(), "audio");
+        op.apply_pending_var(&mut ctx);
+        assert_eq!(ctx.vars("audio").get_float("level"), Some(0.75));
+        assert_eq!(ctx.get_float_var("level"), None);
+    }
+
+    #[test]
+    fn test_get_int_var_namespaced() {
+        let mut op = GetIntVarOp::new();
+        let mut ctx = EvalContext::new();
+        ctx.vars_mut("scene").set_int("index", 3);
+
+        op.inputs[0].default = Value::String("index".to_string());
+        op.inputs[1].default = Value::Int(-1);
+        op.inputs[2].default = Value::String("scene".to_string());
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_int(), Some(3));
+    }
 }
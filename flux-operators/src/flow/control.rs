@@ -4,7 +4,7 @@ use std::any::Any;
 
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
-use flux_core::operator::{InputResolver, Operator};
+use flux_core::operator::{ActiveInput, InputResolver, Operator};
 use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::port::{InputPort, OutputPort};
@@ -80,6 +80,16 @@ impl Operator for SwitchOp {
         };
         self.outputs[0].value = value;
     }
+
+    fn active_inputs(
+        &self,
+        _ctx: &EvalContext,
+        resolve_cheap: &dyn Fn(usize) -> Option<Value>,
+    ) -> Option<Vec<ActiveInput>> {
+        let condition = resolve_cheap(0)?.as_bool().unwrap_or(false);
+        let branch = if condition { 1 } else { 2 };
+        Some(vec![ActiveInput::Input(0), ActiveInput::Input(branch)])
+    }
 }
 
 impl OperatorMeta for SwitchOp {
@@ -145,15 +155,25 @@ impl Operator for SelectOp {
         let index = get_int(&self.inputs[0], get_input) as usize;
         let values_input = &self.inputs[1];
 
-        let value = if index < values_input.connections.len() {
-            let (node_id, output_idx) = values_input.connections[index];
-            get_input(node_id, output_idx)
+        let value = if values_input.is_connected() {
+            // Indexes the flattened values: list elements count individually
+            let values = values_input.resolve_multi_flat(get_input);
+            values.into_iter().nth(index).unwrap_or(Value::Float(0.0))
         } else {
             Value::Float(0.0)
         };
 
         self.outputs[0].value = value;
     }
+
+    fn active_inputs(
+        &self,
+        _ctx: &EvalContext,
+        resolve_cheap: &dyn Fn(usize) -> Option<Value>,
+    ) -> Option<Vec<ActiveInput>> {
+        let index = resolve_cheap(0)?.as_int().unwrap_or(0) as usize;
+        Some(vec![ActiveInput::Input(0), ActiveInput::Value { input: 1, index }])
+    }
 }
 
 impl OperatorMeta for SelectOp {
@@ -223,6 +243,16 @@ impl Operator for GateOp {
         }
         // When closed, keep previous value (don't update)
     }
+
+    fn active_inputs(
+        &self,
+        _ctx: &EvalContext,
+        resolve_cheap: &dyn Fn(usize) -> Option<Value>,
+    ) -> Option<Vec<ActiveInput>> {
+        // A closed gate doesn't need its value
+        let open = resolve_cheap(1)?.as_bool().unwrap_or(false);
+        (!open).then(|| vec![ActiveInput::Input(1)])
+    }
 }
 
 impl OperatorMeta for GateOp {
@@ -514,52 +544,27 @@ impl OperatorMeta for ForEachOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Switch",
-            category: "Flow",
-            description: "Select between two values based on condition",
-        },
+        RegistryEntry::new("Switch", "Flow", "Select between two values based on condition"),
         || capture_meta(SwitchOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Select",
-            category: "Flow",
-            description: "Select value by index",
-        },
+        RegistryEntry::new("Select", "Flow", "Select value by index"),
         || capture_meta(SelectOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Gate",
-            category: "Flow",
-            description: "Pass value when open",
-        },
+        RegistryEntry::new("Gate", "Flow", "Pass value when open"),
         || capture_meta(GateOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Loop",
-            category: "Flow",
-            description: "Execute body N times",
-        },
+        RegistryEntry::new("Loop", "Flow", "Execute body N times"),
         || capture_meta(LoopOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ForEach",
-            category: "Flow",
-            description: "Iterate over list elements",
-        },
+        RegistryEntry::new("ForEach", "Flow", "Iterate over list elements"),
         || capture_meta(ForEachOp::new()),
     );
 }
@@ -605,6 +610,30 @@ mod tests {
         assert_eq!(op.outputs[0].value.as_float(), Some(42.0));
     }
 
+    #[test]
+    fn test_active_inputs() {
+        let ctx = EvalContext::new();
+        let unknown = |_: usize| None;
+        let selector = |value: Value| move |index: usize| (index == 0).then(|| value.clone());
+
+        let switch = SwitchOp::new();
+        let active = switch.active_inputs(&ctx, &selector(Value::Bool(false)));
+        assert_eq!(active, Some(vec![ActiveInput::Input(0), ActiveInput::Input(2)]));
+        assert_eq!(switch.active_inputs(&ctx, &unknown), None);
+
+        let select = SelectOp::new();
+        let active = select.active_inputs(&ctx, &selector(Value::Int(3)));
+        assert_eq!(
+            active,
+            Some(vec![ActiveInput::Input(0), ActiveInput::Value { input: 1, index: 3 }])
+        );
+
+        let gate = GateOp::new();
+        let open = |open: bool| move |index: usize| (index == 1).then_some(Value::Bool(open));
+        assert_eq!(gate.active_inputs(&ctx, &open(true)), None);
+        assert_eq!(gate.active_inputs(&ctx, &open(false)), Some(vec![ActiveInput::Input(1)]));
+    }
+
     #[test]
     fn test_loop_basic() {
         let mut op = LoopOp::new();
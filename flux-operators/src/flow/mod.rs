@@ -1,20 +1,25 @@
-//! Flow/Control operators (14 total)
+//! Flow/Control operators (20 total)
 //! - Control: Switch, Select, Gate, Loop, ForEach (5)
-//! - State: Delay, Previous, Changed, Trigger, Once, Counter (6)
+//! - State: Delay, Previous, Changed, Trigger, Once, Counter, HoldLastValid,
+//!   AutoNormalize, PredictiveSmooth (9)
+//! - Temporal: HistoryBuffer, MovingAverage, DelayLine (3)
 //! - Context: GetFloatVar, SetFloatVar, GetIntVar (3)
 
 use crate::registry::OperatorRegistry;
 
 mod control;
 mod state;
+mod temporal;
 mod context;
 
 pub use control::*;
 pub use state::*;
+pub use temporal::*;
 pub use context::*;
 
 pub fn register_all(registry: &OperatorRegistry) {
     control::register(registry);
     state::register(registry);
+    temporal::register(registry);
     context::register(registry);
 }
@@ -1,4 +1,5 @@
-//! State operators: Delay, Previous, Changed, Trigger, Once, Counter
+//! State operators: Delay, Previous, Changed, Trigger, Once, Counter, HoldLastValid,
+//! AutoNormalize, PredictiveSmooth
 
 use std::any::Any;
 use std::collections::VecDeque;
@@ -6,7 +7,7 @@ use std::collections::VecDeque;
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
 use flux_core::operator::{InputResolver, Operator};
-use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Unit};
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::port::{InputPort, OutputPort};
 use flux_core::Value;
@@ -25,6 +26,13 @@ fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
     }
 }
 
+fn get_float(input: &InputPort, get_input: InputResolver) -> f32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float().unwrap_or(0.0),
+        None => input.default.as_float().unwrap_or(0.0),
+    }
+}
+
 fn get_value(input: &InputPort, get_input: InputResolver) -> Value {
     match input.connection {
         Some((node_id, output_idx)) => get_input(node_id, output_idx),
@@ -95,6 +103,15 @@ impl Operator for DelayOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.buffer.clear();
+        true
+    }
 }
 
 impl OperatorMeta for DelayOp {
@@ -163,6 +180,15 @@ impl Operator for PreviousOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.previous = Value::Float(0.0);
+        true
+    }
 }
 
 impl OperatorMeta for PreviousOp {
@@ -234,6 +260,15 @@ impl Operator for ChangedOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.previous = None;
+        true
+    }
 }
 
 impl OperatorMeta for ChangedOp {
@@ -302,6 +337,15 @@ impl Operator for TriggerOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.previous = false;
+        true
+    }
 }
 
 impl OperatorMeta for TriggerOp {
@@ -379,6 +423,16 @@ impl Operator for OnceOp {
 
         self.outputs[0].value = self.stored_value.clone();
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.executed = false;
+        self.stored_value = Value::Float(0.0);
+        true
+    }
 }
 
 impl OperatorMeta for OnceOp {
@@ -460,6 +514,16 @@ impl Operator for CounterOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.count = 0;
+        self.previous_trigger = false;
+        true
+    }
 }
 
 impl OperatorMeta for CounterOp {
@@ -481,70 +545,640 @@ impl OperatorMeta for CounterOp {
     }
 }
 
+// ============================================================================
+// HoldLastValid Operator
+// ============================================================================
+
+/// Passes its input through, holding the last good value while the input is
+/// disconnected or its source reports errors
+///
+/// A held value is kept for `Timeout` seconds (forever if `Timeout` <= 0),
+/// then fades linearly to the input's default over `FadeTime` seconds.
+/// Before the input has ever been good the default passes through.
+pub struct HoldLastValidOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 2],
+    /// Most recent value read from a healthy connection
+    last_valid: Option<f32>,
+    /// Time the current hold started
+    hold_start: Option<f64>,
+}
+
+impl HoldLastValidOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float("Value", 0.0),
+                InputPort::float("Timeout", 0.0),
+                InputPort::float("FadeTime", 1.0),
+            ],
+            outputs: [OutputPort::float("Value"), OutputPort::bool("Holding")],
+            last_valid: None,
+            hold_start: None,
+        }
+    }
+
+    /// Value output while holding `held`, `elapsed` seconds into the hold
+    fn held_value(&self, held: f32, elapsed: f64, get_input: InputResolver) -> f32 {
+        let timeout = get_float(&self.inputs[1], get_input) as f64;
+        if timeout <= 0.0 || elapsed < timeout {
+            return held;
+        }
+        let fade = get_float(&self.inputs[2], get_input) as f64;
+        let t = if fade > 0.0 { ((elapsed - timeout) / fade).min(1.0) } else { 1.0 };
+        let fallback = self.inputs[0].default.as_float().unwrap_or(0.0);
+        held + (fallback - held) * t as f32
+    }
+}
+
+impl Default for HoldLastValidOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for HoldLastValidOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "HoldLastValid" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let valid = self.inputs[0].is_connected() && !ctx.input_failed(0);
+        let held = self.last_valid.filter(|_| !valid);
+        let value = match held {
+            Some(held) => {
+                let start = *self.hold_start.get_or_insert(ctx.time);
+                self.held_value(held, (ctx.time - start).max(0.0), get_input)
+            }
+            None => {
+                self.hold_start = None;
+                let value = get_float(&self.inputs[0], get_input);
+                if valid {
+                    self.last_valid = Some(value);
+                }
+                value
+            }
+        };
+        self.outputs[0].set_float(value);
+        self.outputs[1].set_bool(held.is_some());
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.last_valid = None;
+        self.hold_start = None;
+        true
+    }
+}
+
+impl OperatorMeta for HoldLastValidOp {
+    fn category(&self) -> &'static str { "Flow" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STATE }
+    fn description(&self) -> &'static str { "Hold the last good value while the input is disconnected or failing" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value")),
+            1 => Some(
+                PortMeta::new("Timeout")
+                    .with_range(0.0, 60.0)
+                    .with_typed_unit(Unit::Seconds),
+            ),
+            2 => Some(
+                PortMeta::new("FadeTime")
+                    .with_range(0.0, 10.0)
+                    .with_typed_unit(Unit::Seconds),
+            ),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Holding")),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// AutoNormalize Operator
+// ============================================================================
+
+/// Streaming quantile estimate in constant memory (the P² algorithm, Jain &
+/// Chlamtac 1985).
+///
+/// Five markers track the minimum, the target quantile, the maximum and the
+/// quantiles halfway between. Each sample nudges the middle markers towards
+/// their desired positions along a parabola through their neighbours, so a
+/// single extreme sample moves the estimate little.
+#[derive(Clone, Debug)]
+struct QuantileEstimator {
+    quantile: f64,
+    count: usize,
+    /// Marker heights; the first samples until there are five
+    heights: [f64; 5],
+    /// Actual marker positions (1-based sample ranks)
+    positions: [f64; 5],
+    /// Desired marker positions
+    desired: [f64; 5],
+    /// Desired position increment per sample
+    increments: [f64; 5],
+}
+
+impl QuantileEstimator {
+    fn new(quantile: f64) -> Self {
+        Self {
+            quantile,
+            count: 0,
+            heights: [0.0; 5],
+            positions: [1.0, 2.0, 3.0, 4.0, 5.0],
+            desired: [1.0, 1.0 + 2.0 * quantile, 1.0 + 4.0 * quantile, 3.0 + 2.0 * quantile, 5.0],
+            increments: [0.0, quantile / 2.0, quantile, (1.0 + quantile) / 2.0, 1.0],
+        }
+    }
+
+    fn add(&mut self, x: f64) {
+        if self.count < 5 {
+            self.heights[self.count] = x;
+            self.count += 1;
+            if self.count == 5 {
+                self.heights.sort_by(f64::total_cmp);
+            }
+            return;
+        }
+        self.count += 1;
+
+        let h = &mut self.heights;
+        let cell = if x < h[0] {
+            h[0] = x;
+            0
+        } else if x >= h[4] {
+            h[4] = x;
+            3
+        } else {
+            (0..4).find(|&i| x < h[i + 1]).unwrap_or(3)
+        };
+        for position in &mut self.positions[cell + 1..] {
+            *position += 1.0;
+        }
+        for (desired, increment) in self.desired.iter_mut().zip(self.increments) {
+            *desired += increment;
+        }
+
+        let n = &mut self.positions;
+        for i in 1..4 {
+            let offset = self.desired[i] - n[i];
+            if (offset >= 1.0 && n[i + 1] - n[i] > 1.0) || (offset <= -1.0 && n[i - 1] - n[i] < -1.0) {
+                let d = offset.signum();
+                let parabolic = h[i]
+                    + d / (n[i + 1] - n[i - 1])
+                        * ((n[i] - n[i - 1] + d) * (h[i + 1] - h[i]) / (n[i + 1] - n[i])
+                            + (n[i + 1] - n[i] - d) * (h[i] - h[i - 1]) / (n[i] - n[i - 1]));
+                h[i] = if h[i - 1] < parabolic && parabolic < h[i + 1] {
+                    parabolic
+                } else {
+                    let j = if d > 0.0 { i + 1 } else { i - 1 };
+                    h[i] + d * (h[j] - h[i]) / (n[j] - n[i])
+                };
+                n[i] += d;
+            }
+        }
+    }
+
+    /// Current estimate, or `None` before the first sample.
+    fn estimate(&self) -> Option<f64> {
+        match self.count {
+            0 => None,
+            1..=4 => {
+                // Nearest rank among the samples so far
+                let mut samples = self.heights[..self.count].to_vec();
+                samples.sort_by(f64::total_cmp);
+                let rank = (self.quantile * (self.count - 1) as f64).round() as usize;
+                Some(samples[rank])
+            }
+            _ => Some(self.heights[2]),
+        }
+    }
+}
+
+/// Remaps an input with an unknown range to 0..1 using the range it has
+/// seen so far
+///
+/// In the default mode the learned range expands immediately to new
+/// extremes and contracts back towards the input at `Rate` per second
+/// (never, if `Rate` <= 0). In `Percentile` mode the range follows the 5th
+/// and 95th percentiles of everything seen since the last reset, easing
+/// towards them at `Rate` per second (immediately, if `Rate` <= 0), so
+/// occasional outliers don't stretch it. `Freeze` stops learning and
+/// `Reset` (rising edge) forgets the range.
+///
+/// A range of zero width maps everything to 0.5. The learned state is
+/// runtime-only and not saved with the graph.
+pub struct AutoNormalizeOp {
+    id: Id,
+    inputs: [InputPort; 5],
+    outputs: [OutputPort; 3],
+    /// Learned (min, max), once a sample has been seen
+    range: Option<(f32, f32)>,
+    low: QuantileEstimator,
+    high: QuantileEstimator,
+    last_time: Option<f64>,
+    previous_reset: bool,
+}
+
+impl AutoNormalizeOp {
+    /// Quantiles tracked in percentile mode
+    const PERCENTILES: (f64, f64) = (0.05, 0.95);
+
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float("Value", 0.0),
+                InputPort::float("Rate", 0.5),
+                InputPort::bool("Percentile", false),
+                InputPort::bool("Freeze", false),
+                InputPort::bool("Reset", false),
+            ],
+            outputs: [
+                OutputPort::float("Value"),
+                OutputPort::float("Min"),
+                OutputPort::float("Max"),
+            ],
+            range: None,
+            low: QuantileEstimator::new(Self::PERCENTILES.0),
+            high: QuantileEstimator::new(Self::PERCENTILES.1),
+            last_time: None,
+            previous_reset: false,
+        }
+    }
+
+    fn reset(&mut self) {
+        self.range = None;
+        self.low = QuantileEstimator::new(Self::PERCENTILES.0);
+        self.high = QuantileEstimator::new(Self::PERCENTILES.1);
+    }
+
+    /// Update the learned range with a sample, `blend` being how far this
+    /// step moves it towards its target (`None` for rates <= 0).
+    fn learn(&mut self, value: f32, blend: Option<f32>, percentile: bool) {
+        self.low.add(value as f64);
+        self.high.add(value as f64);
+        let Some((min, max)) = self.range else {
+            self.range = Some((value, value));
+            return;
+        };
+        self.range = Some(if percentile {
+            let target_min = self.low.estimate().unwrap_or(value as f64) as f32;
+            let target_max = self.high.estimate().unwrap_or(value as f64) as f32;
+            let blend = blend.unwrap_or(1.0);
+            (min + (target_min - min) * blend, max + (target_max - max) * blend)
+        } else {
+            let blend = blend.unwrap_or(0.0);
+            let min = if value < min { value } else { min + (value - min) * blend };
+            let max = if value > max { value } else { max + (value - max) * blend };
+            (min, max)
+        });
+    }
+}
+
+impl Default for AutoNormalizeOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for AutoNormalizeOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "AutoNormalize" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let value = get_float(&self.inputs[0], get_input);
+        let rate = get_float(&self.inputs[1], get_input);
+        let percentile = get_bool(&self.inputs[2], get_input);
+        let freeze = get_bool(&self.inputs[3], get_input);
+        let reset = get_bool(&self.inputs[4], get_input);
+
+        if reset && !self.previous_reset {
+            self.reset();
+        }
+        self.previous_reset = reset;
+        let dt = self.last_time.map_or(0.0, |last| (ctx.time - last).max(0.0)) as f32;
+        self.last_time = Some(ctx.time);
+
+        if !freeze && value.is_finite() {
+            let blend = (rate > 0.0).then(|| 1.0 - (-rate * dt).exp());
+            self.learn(value, blend, percentile);
+        }
+
+        let (min, max) = self.range.unwrap_or((value, value));
+        let normalized = if max - min > f32::EPSILON * max.abs().max(min.abs()).max(1.0) {
+            ((value - min) / (max - min)).clamp(0.0, 1.0)
+        } else {
+            0.5
+        };
+        self.outputs[0].set_float(if normalized.is_nan() { 0.5 } else { normalized });
+        self.outputs[1].set_float(min);
+        self.outputs[2].set_float(max);
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.reset();
+        self.last_time = None;
+        self.previous_reset = false;
+        true
+    }
+}
+
+impl OperatorMeta for AutoNormalizeOp {
+    fn category(&self) -> &'static str { "Flow" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STATE }
+    fn description(&self) -> &'static str { "Remap an input to 0..1 using its learned range" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value")),
+            1 => Some(PortMeta::new("Rate").with_range(0.0, 10.0)),
+            2 => Some(PortMeta::new("Percentile")),
+            3 => Some(PortMeta::new("Freeze")),
+            4 => Some(PortMeta::new("Reset")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Min")),
+            2 => Some(PortMeta::new("Max")),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// PredictiveSmooth Operator
+// ============================================================================
+
+/// Fills in the frames between sporadic input updates, such as network
+/// parameters arriving slower than the frame rate
+///
+/// Every change of the input counts as a received sample, timestamped with
+/// the frame time. Between samples the output dead-reckons: the latest
+/// sample plus the velocity fitted to the last few samples times the time
+/// since it arrived, plus `LatencyHint` seconds for how late samples arrive.
+/// Extrapolation stops `MaxExtrapolation` seconds past the sample. When a
+/// sample disagrees with the prediction, the output eases onto the new
+/// course at `Blend` per second instead of jumping.
+///
+/// A sample further than `SnapThreshold` from the prediction (or a time
+/// running backwards) restarts the estimate at that sample, and the output
+/// snaps to it; a threshold <= 0 never snaps. `StaleSeconds` counts the time
+/// since the last change, so graphs can react when the source goes quiet.
+/// An input that keeps sending the same value looks the same as one that
+/// stopped.
+pub struct PredictiveSmoothOp {
+    id: Id,
+    inputs: [InputPort; 5],
+    outputs: [OutputPort; 2],
+    /// Recent received samples as (time, value), oldest first
+    samples: VecDeque<(f64, f32)>,
+    /// Velocity fitted to `samples`, per second
+    velocity: f32,
+    /// Difference between the previous course and the current one when the
+    /// latest sample arrived, decaying towards zero
+    offset: f32,
+}
+
+impl PredictiveSmoothOp {
+    /// Samples the velocity is fitted to
+    const HISTORY: usize = 4;
+
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float("Value", 0.0),
+                InputPort::float("LatencyHint", 0.0),
+                InputPort::float("Blend", 10.0),
+                InputPort::float("MaxExtrapolation", 0.25),
+                InputPort::float("SnapThreshold", 1.0),
+            ],
+            outputs: [OutputPort::float("Value"), OutputPort::float("StaleSeconds")],
+            samples: VecDeque::with_capacity(Self::HISTORY),
+            velocity: 0.0,
+            offset: 0.0,
+        }
+    }
+
+    /// Output at `time` on the current course, with the latest sample taken
+    /// at `received`.
+    fn predict(&self, time: f64, (received, value): (f64, f32), latency: f32, max: f32, blend: f32) -> f32 {
+        let elapsed = (time - received) as f32;
+        let ahead = (elapsed + latency).clamp(0.0, max.max(0.0));
+        value + self.velocity * ahead + self.offset * (-blend.max(0.0) * elapsed).exp()
+    }
+
+    /// Least-squares slope of the received samples.
+    fn fit_velocity(&self) -> f32 {
+        let n = self.samples.len() as f64;
+        if n < 2.0 {
+            return 0.0;
+        }
+        let mean_t = self.samples.iter().map(|&(t, _)| t).sum::<f64>() / n;
+        let mean_v = self.samples.iter().map(|&(_, v)| v as f64).sum::<f64>() / n;
+        let (mut covariance, mut variance) = (0.0, 0.0);
+        for &(t, v) in &self.samples {
+            covariance += (t - mean_t) * (v as f64 - mean_v);
+            variance += (t - mean_t) * (t - mean_t);
+        }
+        if variance > 0.0 { (covariance / variance) as f32 } else { 0.0 }
+    }
+}
+
+impl Default for PredictiveSmoothOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for PredictiveSmoothOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "PredictiveSmooth" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let value = get_float(&self.inputs[0], get_input);
+        let latency = get_float(&self.inputs[1], get_input);
+        let blend = get_float(&self.inputs[2], get_input);
+        let max = get_float(&self.inputs[3], get_input);
+        let snap = get_float(&self.inputs[4], get_input);
+        let time = ctx.time;
+
+        match self.samples.back().copied() {
+            Some(latest) if latest.1 == value && time >= latest.0 => {}
+            Some(latest) if time >= latest.0 => {
+                let previous = self.predict(time, latest, latency, max, blend);
+                if snap > 0.0 && (value - previous).abs() > snap {
+                    self.samples.clear();
+                }
+                if self.samples.len() == Self::HISTORY {
+                    self.samples.pop_front();
+                }
+                self.samples.push_back((time, value));
+                self.velocity = self.fit_velocity();
+                self.offset = 0.0;
+                if self.samples.len() > 1 {
+                    // Continue from where the previous course had got to
+                    self.offset = previous - self.predict(time, (time, value), latency, max, blend);
+                }
+            }
+            // First sample, or time ran backwards
+            _ => {
+                self.samples.clear();
+                self.samples.push_back((time, value));
+                self.velocity = 0.0;
+                self.offset = 0.0;
+            }
+        }
+
+        let latest = *self.samples.back().expect("a sample was just recorded");
+        let output = self.predict(time, latest, latency, max, blend);
+        self.outputs[0].set_float(if output.is_finite() { output } else { latest.1 });
+        self.outputs[1].set_float((time - latest.0) as f32);
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.samples.clear();
+        self.velocity = 0.0;
+        self.offset = 0.0;
+        true
+    }
+}
+
+impl OperatorMeta for PredictiveSmoothOp {
+    fn category(&self) -> &'static str { "Flow" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STATE }
+    fn description(&self) -> &'static str { "Extrapolate and smooth a sporadically updated input" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value")),
+            1 => Some(
+                PortMeta::new("LatencyHint")
+                    .with_range(0.0, 1.0)
+                    .with_typed_unit(Unit::Seconds),
+            ),
+            2 => Some(PortMeta::new("Blend").with_range(0.0, 50.0)),
+            3 => Some(
+                PortMeta::new("MaxExtrapolation")
+                    .with_range(0.0, 2.0)
+                    .with_typed_unit(Unit::Seconds),
+            ),
+            4 => Some(PortMeta::new("SnapThreshold")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("StaleSeconds").with_typed_unit(Unit::Seconds)),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // Registration
 // ============================================================================
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Delay",
-            category: "Flow",
-            description: "Delay value by frames",
-        },
+        RegistryEntry::new("Delay", "Flow", "Delay value by frames"),
         || capture_meta(DelayOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Previous",
-            category: "Flow",
-            description: "Previous frame value",
-        },
+        RegistryEntry::new("Previous", "Flow", "Previous frame value"),
         || capture_meta(PreviousOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Changed",
-            category: "Flow",
-            description: "Detect value changes",
-        },
+        RegistryEntry::new("Changed", "Flow", "Detect value changes"),
         || capture_meta(ChangedOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Trigger",
-            category: "Flow",
-            description: "Rising edge detection",
-        },
+        RegistryEntry::new("Trigger", "Flow", "Rising edge detection"),
         || capture_meta(TriggerOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Once",
-            category: "Flow",
-            description: "Execute once until reset",
-        },
+        RegistryEntry::new("Once", "Flow", "Execute once until reset"),
         || capture_meta(OnceOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Counter",
-            category: "Flow",
-            description: "Count trigger events",
-        },
+        RegistryEntry::new("Counter", "Flow", "Count trigger events"),
         || capture_meta(CounterOp::new()),
     );
+
+    registry.register(
+        RegistryEntry::new("HoldLastValid", "Flow", "Hold last good value on disconnect or error"),
+        || capture_meta(HoldLastValidOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("AutoNormalize", "Flow", "Remap to 0..1 by learned range"),
+        || capture_meta(AutoNormalizeOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "PredictiveSmooth",
+            "Flow",
+            "Extrapolate sporadic updates between frames",
+        ),
+        || capture_meta(PredictiveSmoothOp::new()),
+    );
 }
 
 #[cfg(test)]
@@ -610,4 +1244,188 @@ mod tests {
         op.compute(&ctx, &no_connections);
         assert_eq!(op.outputs[0].value.as_int(), Some(0));
     }
+
+    #[test]
+    fn test_reset_state_replays_from_scratch() {
+        let mut op = CounterOp::new();
+        let ctx = EvalContext::new();
+        op.inputs[0].default = Value::Bool(true);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_int(), Some(1));
+
+        // A rising edge again, as on the first compute
+        assert!(op.reset_state());
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_int(), Some(1));
+        assert!(op.inputs[0].default.as_bool().unwrap());
+    }
+
+    #[test]
+    fn test_hold_last_valid() {
+        let source = Id::new();
+        let upstream = |_: Id, _: usize| Value::Float(5.0);
+        let mut op = HoldLastValidOp::new();
+        op.inputs[1].default = Value::Float(2.0);
+        op.inputs[2].default = Value::Float(4.0);
+        let mut ctx = EvalContext::new();
+        let mut at = |op: &mut HoldLastValidOp, time: f64, failed: &[usize]| {
+            ctx.time = time;
+            ctx.set_failed_inputs(failed);
+            op.compute(&ctx, &upstream);
+            let held = op.outputs[1].value.as_bool().unwrap();
+            (op.outputs[0].value.as_float().unwrap(), held)
+        };
+
+        // Never valid: the default passes through
+        assert_eq!(at(&mut op, 0.0, &[]), (0.0, false));
+
+        op.inputs[0].connect(source, 0);
+        assert_eq!(at(&mut op, 1.0, &[]), (5.0, false));
+
+        // Held through the timeout, then fades to the default
+        op.inputs[0].disconnect();
+        assert_eq!(at(&mut op, 2.0, &[]), (5.0, true));
+        assert_eq!(at(&mut op, 3.5, &[]), (5.0, true));
+        assert_eq!(at(&mut op, 6.0, &[]), (2.5, true));
+        assert_eq!(at(&mut op, 9.0, &[]), (0.0, true));
+
+        // Reconnecting passes through again; a failing source is held
+        op.inputs[0].connect(source, 0);
+        assert_eq!(at(&mut op, 10.0, &[]), (5.0, false));
+        assert_eq!(at(&mut op, 11.0, &[0]), (5.0, true));
+    }
+
+    /// Feed one value a frame later (60 fps); returns (value, min, max)
+    fn normalize(op: &mut AutoNormalizeOp, ctx: &mut EvalContext, value: f32) -> (f32, f32, f32) {
+        op.inputs[0].default = Value::Float(value);
+        op.compute(ctx, &no_connections);
+        ctx.time += 1.0 / 60.0;
+        let output = |i: usize| op.outputs[i].value.as_float().unwrap();
+        (output(0), output(1), output(2))
+    }
+
+    #[test]
+    fn test_auto_normalize_converges() {
+        let mut ctx = EvalContext::new();
+
+        // A constant input has no range to map into
+        let mut op = AutoNormalizeOp::new();
+        assert_eq!(normalize(&mut op, &mut ctx, 3.0), (0.5, 3.0, 3.0));
+        assert_eq!(normalize(&mut op, &mut ctx, 3.0), (0.5, 3.0, 3.0));
+
+        // Without contraction the range is the ramp's extremes
+        let mut op = AutoNormalizeOp::new();
+        op.inputs[1].default = Value::Float(0.0);
+        for i in 0..=100 {
+            normalize(&mut op, &mut ctx, i as f32 / 10.0);
+        }
+        assert_eq!(normalize(&mut op, &mut ctx, 2.5), (0.25, 0.0, 10.0));
+
+        // With it, the range closes in on an input that settles
+        op.inputs[1].default = Value::Float(2.0);
+        let mut last = (0.0, 0.0, 0.0);
+        for _ in 0..300 {
+            last = normalize(&mut op, &mut ctx, 4.0);
+        }
+        assert!((last.1 - 4.0).abs() < 0.01 && (last.2 - 4.0).abs() < 0.01, "{:?}", last);
+    }
+
+    #[test]
+    fn test_auto_normalize_percentile_rejects_outliers() {
+        let mut ctx = EvalContext::new();
+        let mut minmax = AutoNormalizeOp::new();
+        let mut percentile = AutoNormalizeOp::new();
+        percentile.inputs[2].default = Value::Bool(true);
+        for op in [&mut minmax, &mut percentile] {
+            op.inputs[1].default = Value::Float(0.0);
+        }
+
+        let samples = (0..1000).map(|i| (i % 101) as f32 / 100.0);
+        for value in samples.clone().take(500).chain([100.0]).chain(samples.take(50)) {
+            normalize(&mut minmax, &mut ctx, value);
+            normalize(&mut percentile, &mut ctx, value);
+        }
+        assert_eq!(normalize(&mut minmax, &mut ctx, 0.5).2, 100.0);
+        let (value, min, max) = normalize(&mut percentile, &mut ctx, 0.5);
+        assert!((min - 0.05).abs() < 0.03, "min {}", min);
+        assert!((max - 0.95).abs() < 0.03, "max {}", max);
+        assert!((value - 0.5).abs() < 0.03, "value {}", value);
+    }
+
+    #[test]
+    fn test_auto_normalize_freeze_and_reset() {
+        let mut ctx = EvalContext::new();
+        let mut op = AutoNormalizeOp::new();
+        op.inputs[1].default = Value::Float(0.0);
+        normalize(&mut op, &mut ctx, 0.0);
+        normalize(&mut op, &mut ctx, 1.0);
+
+        // Frozen, the mapping ignores new extremes
+        op.inputs[3].default = Value::Bool(true);
+        assert_eq!(normalize(&mut op, &mut ctx, 2.0), (1.0, 0.0, 1.0));
+        assert_eq!(normalize(&mut op, &mut ctx, 0.25), (0.25, 0.0, 1.0));
+        op.inputs[3].default = Value::Bool(false);
+        assert_eq!(normalize(&mut op, &mut ctx, 2.0), (1.0, 0.0, 2.0));
+
+        // Reset forgets the range on its rising edge only
+        op.inputs[4].default = Value::Bool(true);
+        assert_eq!(normalize(&mut op, &mut ctx, 5.0), (0.5, 5.0, 5.0));
+        assert_eq!(normalize(&mut op, &mut ctx, 6.0), (1.0, 5.0, 6.0));
+    }
+
+    /// Run 60 fps frames fed by `source(frame)`; returns (value, stale) per frame
+    fn predict_frames(op: &mut PredictiveSmoothOp, frames: std::ops::Range<u64>, source: impl Fn(u64) -> f32) -> Vec<(f32, f32)> {
+        let mut ctx = EvalContext::new();
+        frames
+            .map(|frame| {
+                ctx.time = frame as f64 / 60.0;
+                op.inputs[0].default = Value::Float(source(frame));
+                op.compute(&ctx, &no_connections);
+                let output = |i: usize| op.outputs[i].value.as_float().unwrap();
+                (output(0), output(1))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_predictive_smooth_beats_sample_and_hold() {
+        // A ramp of slope 1 sent at 20 Hz: every third frame
+        let held = |frame: u64| (frame - frame % 3) as f32 / 60.0;
+        let mut op = PredictiveSmoothOp::new();
+        let outputs = predict_frames(&mut op, 0..120, held);
+
+        // Rising every frame once two samples have given it a velocity
+        for pair in outputs[4..].windows(2) {
+            assert!(pair[1].0 > pair[0].0, "{:?}", pair);
+        }
+        let error = |value: f32, frame: u64| (value - frame as f32 / 60.0).abs();
+        let (mut predicted, mut naive) = (0.0, 0.0);
+        for frame in 6..120 {
+            predicted += error(outputs[frame as usize].0, frame);
+            naive += error(held(frame), frame);
+        }
+        assert!(predicted < naive * 0.5, "predicted {} vs held {}", predicted, naive);
+    }
+
+    #[test]
+    fn test_predictive_smooth_stale_and_snap() {
+        // Updates stop at frame 30 (0.5s)
+        let source = |frame: u64| (frame.min(30) - frame.min(30) % 3) as f32 / 60.0;
+        let mut op = PredictiveSmoothOp::new();
+        let outputs = predict_frames(&mut op, 0..60, source);
+
+        // Stale time counts from the last change; extrapolation stops after
+        // MaxExtrapolation (0.25s = 15 frames)
+        assert!((outputs[59].1 - 29.0 / 60.0).abs() < 1e-4, "{:?}", outputs[59]);
+        assert!((outputs[59].0 - outputs[50].0).abs() < 1e-4);
+        assert!((outputs[59].0 - 0.75).abs() < 0.01, "{:?}", outputs[59]);
+
+        // A jump beyond SnapThreshold is taken as is
+        let mut ctx = EvalContext::new();
+        ctx.time = 1.0;
+        op.inputs[0].default = Value::Float(10.0);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_float(), Some(10.0));
+        assert_eq!(op.outputs[1].value.as_float(), Some(0.0));
+    }
 }
@@ -0,0 +1,447 @@
+//! Temporal operators: HistoryBuffer, MovingAverage, DelayLine
+//!
+//! These keep a buffer of past input values across frames, for smoothing and
+//! delaying signals (e.g. audio-reactive parameters).
+
+use std::any::Any;
+use std::collections::VecDeque;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Unit};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort};
+use flux_core::Value;
+
+fn get_float(input: &InputPort, get_input: InputResolver) -> f32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float().unwrap_or(0.0),
+        None => input.default.as_float().unwrap_or(0.0),
+    }
+}
+
+fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_int().unwrap_or(0),
+        None => input.default.as_int().unwrap_or(0),
+    }
+}
+
+// ============================================================================
+// HistoryBuffer Operator
+// ============================================================================
+
+/// Collects the last `Size` input values into a list, newest last.
+pub struct HistoryBufferOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+    history: VecDeque<f32>,
+}
+
+impl HistoryBufferOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float("Value", 0.0),
+                InputPort::int("Size", 16),
+            ],
+            outputs: [OutputPort::float_list("History")],
+            history: VecDeque::new(),
+        }
+    }
+}
+
+impl Default for HistoryBufferOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for HistoryBufferOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "HistoryBuffer" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let value = get_float(&self.inputs[0], get_input);
+        let size = ctx.clamp_list_len(get_int(&self.inputs[1], get_input).max(1) as usize);
+
+        self.history.push_back(value);
+        while self.history.len() > size {
+            self.history.pop_front();
+        }
+
+        self.outputs[0].value = Value::float_list(self.history.iter().copied().collect());
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.history.clear();
+        true
+    }
+}
+
+impl OperatorMeta for HistoryBufferOp {
+    fn category(&self) -> &'static str { "Flow" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STATE }
+    fn description(&self) -> &'static str { "List of the last N values" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value")),
+            1 => Some(PortMeta::new("Size").with_range(1.0, 1024.0)),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("History").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// MovingAverage Operator
+// ============================================================================
+
+/// Averages the last `Window` input values.
+///
+/// The window always holds exactly `Window` samples: it starts filled with the
+/// first input, and when the window grows it is padded with the oldest stored
+/// value so the output doesn't jump.
+pub struct MovingAverageOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+    window: VecDeque<f32>,
+}
+
+impl MovingAverageOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float("Value", 0.0),
+                InputPort::int("Window", 8),
+            ],
+            outputs: [OutputPort::float("Average")],
+            window: VecDeque::new(),
+        }
+    }
+}
+
+impl Default for MovingAverageOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for MovingAverageOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "MovingAverage" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let value = get_float(&self.inputs[0], get_input);
+        let size = ctx.clamp_list_len(get_int(&self.inputs[1], get_input).max(1) as usize);
+
+        self.window.push_back(value);
+        while self.window.len() > size {
+            self.window.pop_front();
+        }
+        let oldest = self.window.front().copied().unwrap_or(value);
+        while self.window.len() < size {
+            self.window.push_front(oldest);
+        }
+
+        // Sum in f64 to keep large windows accurate
+        let sum: f64 = self.window.iter().map(|&v| f64::from(v)).sum();
+        self.outputs[0].set_float((sum / size as f64) as f32);
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.window.clear();
+        true
+    }
+}
+
+impl OperatorMeta for MovingAverageOp {
+    fn category(&self) -> &'static str { "Flow" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STATE }
+    fn description(&self) -> &'static str { "Average of the last N values" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value")),
+            1 => Some(PortMeta::new("Window").with_range(1.0, 256.0)),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Average").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// DelayLine Operator
+// ============================================================================
+
+/// Outputs the input value from `DelaySeconds` ago.
+///
+/// Samples are stamped with `ctx.time` and the output is interpolated between
+/// the two samples around the delayed time. Until enough history exists the
+/// oldest stored value is output. Time moving backwards (seek, loop) clears
+/// the history.
+pub struct DelayLineOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+    samples: VecDeque<(f64, f32)>,
+}
+
+impl DelayLineOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float("Value", 0.0),
+                InputPort::float("DelaySeconds", 0.5),
+            ],
+            outputs: [OutputPort::float("Result")],
+            samples: VecDeque::new(),
+        }
+    }
+
+    fn record(&mut self, time: f64, value: f32) {
+        match self.samples.back_mut() {
+            Some(last) if last.0 > time => {
+                self.samples.clear();
+                self.samples.push_back((time, value));
+            }
+            // Re-evaluated at the same time: replace rather than duplicate
+            Some(last) if last.0 == time => last.1 = value,
+            _ => self.samples.push_back((time, value)),
+        }
+    }
+
+    fn sample_at(&mut self, target: f64) -> f32 {
+        // Keep at most one sample at or before the target
+        while self.samples.len() > 1 && self.samples[1].0 <= target {
+            self.samples.pop_front();
+        }
+
+        let Some(&(t0, v0)) = self.samples.front() else {
+            return 0.0;
+        };
+        match self.samples.get(1) {
+            Some(&(t1, v1)) if t0 < target => {
+                let t = (target - t0) / (t1 - t0);
+                (f64::from(v0) + (f64::from(v1) - f64::from(v0)) * t) as f32
+            }
+            _ => v0,
+        }
+    }
+}
+
+impl Default for DelayLineOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for DelayLineOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "DelayLine" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let value = get_float(&self.inputs[0], get_input);
+        let delay = f64::from(get_float(&self.inputs[1], get_input).max(0.0));
+
+        self.record(ctx.time, value);
+        let result = self.sample_at(ctx.time - delay);
+        self.outputs[0].set_float(result);
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.samples.clear();
+        true
+    }
+}
+
+impl OperatorMeta for DelayLineOp {
+    fn category(&self) -> &'static str { "Flow" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STATE }
+    fn description(&self) -> &'static str { "Delay value by seconds" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value")),
+            1 => Some(
+                PortMeta::new("DelaySeconds")
+                    .with_range(0.0, 10.0)
+                    .with_typed_unit(Unit::Seconds),
+            ),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new("HistoryBuffer", "Flow", "List of the last N values"),
+        || capture_meta(HistoryBufferOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("MovingAverage", "Flow", "Average of the last N values"),
+        || capture_meta(MovingAverageOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("DelayLine", "Flow", "Delay value by seconds"),
+        || capture_meta(DelayLineOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DT: f64 = 0.1;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    /// Step `op` one frame at `frame * DT` with input 0 set to `value`.
+    fn step(op: &mut dyn Operator, frame: u64, value: f32) -> Value {
+        let mut ctx = EvalContext::new();
+        ctx.frame = frame;
+        ctx.time = frame as f64 * DT;
+        ctx.delta_time = DT;
+        op.inputs_mut()[0].default = Value::Float(value);
+        op.compute(&ctx, &no_connections);
+        op.outputs()[0].value.clone()
+    }
+
+    #[test]
+    fn test_history_buffer_keeps_newest_last() {
+        let mut op = HistoryBufferOp::new();
+        op.inputs[1].default = Value::Int(3);
+
+        step(&mut op, 0, 0.0);
+        assert_eq!(op.outputs[0].value.as_float_list(), Some(&[0.0][..]));
+
+        for frame in 1..5 {
+            step(&mut op, frame, frame as f32);
+        }
+        assert_eq!(op.outputs[0].value.as_float_list(), Some(&[2.0, 3.0, 4.0][..]));
+
+        op.inputs[1].default = Value::Int(2);
+        let history = step(&mut op, 5, 5.0);
+        assert_eq!(history.as_float_list(), Some(&[4.0, 5.0][..]));
+    }
+
+    #[test]
+    fn test_moving_average_of_ramp() {
+        let mut op = MovingAverageOp::new();
+        op.inputs[1].default = Value::Int(4);
+
+        let averages: Vec<f32> = (0..5)
+            .map(|frame| step(&mut op, frame, frame as f32).as_float().unwrap())
+            .collect();
+        assert_eq!(averages, vec![0.0, 0.25, 0.75, 1.5, 2.5]);
+
+        // Shrinking truncates to the newest values: [4, 5]
+        op.inputs[1].default = Value::Int(2);
+        assert_eq!(step(&mut op, 5, 5.0).as_float(), Some(4.5));
+
+        // Growing pads with the oldest value: [4, 4, 5, 6]
+        op.inputs[1].default = Value::Int(4);
+        assert_eq!(step(&mut op, 6, 6.0).as_float(), Some(4.75));
+    }
+
+    #[test]
+    fn test_delay_line_interpolates_ramp() {
+        let mut op = DelayLineOp::new();
+        op.inputs[1].default = Value::Float(0.25);
+
+        for frame in 0..20 {
+            let time = frame as f64 * DT;
+            // Ramp of 10 units per second
+            let result = step(&mut op, frame, (time * 10.0) as f32).as_float().unwrap();
+            let expected = ((time - 0.25).max(0.0) * 10.0) as f32;
+            assert!(
+                (result - expected).abs() < 1e-4,
+                "frame {frame}: {result} != {expected}"
+            );
+        }
+        // Only the samples around the delayed time are kept
+        assert!(op.samples.len() <= 4);
+    }
+
+    #[test]
+    fn test_delay_line_resets_when_time_goes_back() {
+        let mut op = DelayLineOp::new();
+        op.inputs[1].default = Value::Float(0.1);
+
+        for frame in 0..5 {
+            step(&mut op, frame, 100.0 + frame as f32);
+        }
+        // Seek back to the start: old history must not leak through
+        assert_eq!(step(&mut op, 0, 7.0).as_float(), Some(7.0));
+    }
+}
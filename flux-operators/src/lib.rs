@@ -1,23 +1,29 @@
 //! Flux Operators - Operator implementations for the Flux graph system
 //!
 //! This crate provides all the built-in operators for creating computational graphs.
-//! Operators are organized by category:
+//! Operators are organized by category. Every category except [`builtin`] is
+//! behind a cargo feature of the same name (all enabled by default), so
+//! `default-features = false, features = ["math", "flow"]` builds a minimal
+//! crate and registry:
 //!
 //! - [`builtin`] - Core operators (Constant, Add, Multiply, SineWave, etc.)
 //! - [`math`] - Mathematical operations (arithmetic, trig, interpolation, etc.)
 //! - [`logic`] - Boolean and integer logic
-//! - [`vector`] - Vec2, Vec3, Vec4 operations
+//! - [`vector`] - Vec2, Vec3, Vec4 operations and coordinate conversions
 //! - [`color`] - Color manipulation
 //! - [`time`] - Time-based operations (clocks, oscillators)
 //! - [`flow`] - Control flow (state, context, conditionals)
 //! - [`string`] - String manipulation
 //! - [`list`] - List operations
+//! - [`map`] - String-keyed records (make, get/set fields by dotted path)
 //! - [`util`] - Utility operators (debug, etc.)
 //!
 //! # Registry
 //!
 //! The [`OperatorRegistry`] provides dynamic operator creation by name or type ID.
 //! Use [`create_default_registry`] to get a registry with all built-in operators.
+//! [`OperatorRegistry::export_catalog`] describes every registered operator as
+//! JSON (or Markdown) for documentation and external node pickers.
 //!
 //! # Derive Macro
 //!
@@ -53,53 +59,111 @@
 
 #![allow(ambiguous_glob_reexports)]
 
+// Lets `#[operator(register)]` expansions name this crate from inside it
+extern crate self as flux_operators;
+
 // Re-export the derive macros
 pub use flux_macros::Operator;
 pub use flux_macros::OperatorMeta as DeriveOperatorMeta;
 
 pub mod builtin;
+pub mod catalog;
+#[cfg(feature = "color")]
 pub mod color;
+#[cfg(feature = "flow")]
 pub mod flow;
+#[cfg(feature = "list")]
 pub mod list;
+#[cfg(feature = "logic")]
 pub mod logic;
+#[cfg(feature = "map")]
+pub mod map;
+#[cfg(feature = "math")]
 pub mod math;
+#[cfg(any(feature = "string", feature = "map"))]
+mod names;
 pub mod registry;
+#[cfg(feature = "string")]
 pub mod string;
+#[cfg(feature = "time")]
 pub mod time;
+#[cfg(feature = "util")]
 pub mod util;
+#[cfg(feature = "vector")]
 pub mod vector;
 
 // Re-export builtin operators at the crate root
 pub use builtin::*;
 
-// Re-export all category operators
+// Re-export all enabled category operators
+#[cfg(feature = "color")]
 pub use color::*;
+#[cfg(feature = "flow")]
 pub use flow::*;
+#[cfg(feature = "list")]
 pub use list::*;
+#[cfg(feature = "logic")]
 pub use logic::*;
+#[cfg(feature = "map")]
+pub use map::*;
+#[cfg(feature = "math")]
 pub use math::*;
+#[cfg(feature = "string")]
 pub use string::*;
+#[cfg(feature = "time")]
 pub use time::*;
+#[cfg(feature = "util")]
 pub use util::*;
+#[cfg(feature = "vector")]
 pub use vector::*;
 
 // Re-export registry types
+pub use catalog::{Catalog, CatalogOperator, CatalogPort};
 pub use registry::{
-    capture_meta, capture_meta_simple, create_default_registry, ExtendedEntry,
+    capture_meta, capture_meta_simple, create_default_registry, CapturedMeta, CategoryNode, ExtendedEntry,
     MetaCapturingFactory, OperatorFactory, OperatorParams, OperatorRegistry, OperatorWithMeta,
     ParameterMeta, ParameterizedMetaFactory, ParameterType, ParameterValue, RegistryEntry,
 };
 
-/// Register all operators with the given registry
+/// Register the operators of all enabled categories with the given registry
+///
+/// Registration is idempotent: calling this again replaces each operator's
+/// registration in place, keeping its type ID.
+#[cfg_attr(
+    not(any(
+        feature = "math",
+        feature = "logic",
+        feature = "vector",
+        feature = "color",
+        feature = "time",
+        feature = "flow",
+        feature = "string",
+        feature = "list",
+        feature = "map",
+        feature = "util"
+    )),
+    allow(unused_variables)
+)]
 pub fn register_all_operators(registry: &OperatorRegistry) {
+    #[cfg(feature = "math")]
     math::register_all(registry);
+    #[cfg(feature = "logic")]
     logic::register_all(registry);
+    #[cfg(feature = "vector")]
     vector::register_all(registry);
+    #[cfg(feature = "color")]
     color::register_all(registry);
+    #[cfg(feature = "time")]
     time::register_all(registry);
+    #[cfg(feature = "flow")]
     flow::register_all(registry);
+    #[cfg(feature = "string")]
     string::register_all(registry);
+    #[cfg(feature = "list")]
     list::register_all(registry);
+    #[cfg(feature = "map")]
+    map::register_all(registry);
+    #[cfg(feature = "util")]
     util::register_all(registry);
 }
 
@@ -114,14 +178,14 @@ mod derive_macro_tests {
     /// A test operator created with the derive macro.
     /// This demonstrates the full attribute syntax.
     #[derive(Operator)]
-    #[operator(name = "TestMult", category = "Math", description = "Multiplies two numbers")]
+    #[operator(name = "TestMult", category = "Math", description = "Multiplies two numbers", register)]
     #[operator(category_color = [0.35, 0.35, 0.55, 1.0])]
     #[allow(dead_code)] // Marker fields are intentionally unused at runtime
     struct TestMultOp {
         _id: Id,
         _inputs: Vec<InputPort>,
         _outputs: Vec<OutputPort>,
-        #[input(label = "A", default = 1.0)]
+        #[input(label = "A", default = 1.0, range = (0.0, 100.0, clamp), step = 0.5)]
         a: f32,
         #[input(label = "B", default = 1.0, range = (0.0, 10.0), unit = "x")]
         b: f32,
@@ -137,6 +201,35 @@ mod derive_macro_tests {
         }
     }
 
+    #[derive(Operator)]
+    #[operator(name = "TestHalf", category = "Math / Trig")]
+    #[allow(dead_code)]
+    struct TestHalfOp {
+        _id: Id,
+        _inputs: Vec<InputPort>,
+        _outputs: Vec<OutputPort>,
+        #[input(label = "X", default = 0.0)]
+        x: f32,
+        #[output(label = "Half")]
+        half: f32,
+    }
+
+    impl TestHalfOp {
+        fn compute_impl(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+            let x = self.get_x(get_input);
+            self.set_half(x * 0.5);
+        }
+    }
+
+    #[test]
+    fn test_derive_sub_category() {
+        let op = TestHalfOp::new();
+        assert_eq!(op.category(), "Math/Trig");
+        assert_eq!(op.category_path(), flux_core::Category::MATH.child("Trig"));
+        // No color given: the enclosing category's
+        assert_eq!(op.category_color(), flux_core::category_colors::MATH);
+    }
+
     #[test]
     fn test_derive_operator_trait() {
         let op = TestMultOp::new();
@@ -169,11 +262,15 @@ mod derive_macro_tests {
         let input_a = op.input_meta(0).unwrap();
         assert_eq!(input_a.label, "A");
         assert_eq!(input_a.shape, PinShape::CircleFilled);
+        assert_eq!(input_a.range, Some((0.0, 100.0)));
+        assert_eq!(input_a.step, Some(0.5));
+        assert!(input_a.clamp_to_range);
 
         let input_b = op.input_meta(1).unwrap();
         assert_eq!(input_b.label, "B");
         assert_eq!(input_b.range, Some((0.0, 10.0)));
         assert_eq!(input_b.unit, Some("x"));
+        assert!(!input_b.clamp_to_range);
 
         // Test output meta
         let output = op.output_meta(0).unwrap();
@@ -197,4 +294,42 @@ mod derive_macro_tests {
         // Check result
         assert_eq!(op.outputs()[0].value.as_float(), Some(12.0));
     }
+
+    #[test]
+    fn test_derive_explicit_id() {
+        let id = Id::new();
+        assert_eq!(TestMultOp::with_id(id).id(), id);
+
+        let mut op = TestMultOp::new();
+        assert!(op.set_id(id));
+        assert_eq!(op.id(), id);
+    }
+
+    #[test]
+    fn test_derive_register() {
+        let registry = crate::OperatorRegistry::new();
+        crate::registry::collect!(&registry, register_test_mult_op);
+
+        let entry = registry.get_extended_meta_by_name("TestMult").unwrap();
+        assert_eq!(entry.meta.category, "Math");
+        assert_eq!(entry.meta.description, "Multiplies two numbers");
+
+        // Metadata is captured through capture_meta
+        let (op, meta) = registry.create_with_meta_by_name("TestMult").unwrap();
+        assert_eq!(op.name(), "TestMult");
+        assert_eq!(meta.category_color, Some([0.35, 0.35, 0.55, 1.0]));
+        assert_eq!(meta.inputs[0].as_ref().unwrap().range, Some((0.0, 100.0)));
+    }
+
+    #[test]
+    #[cfg(feature = "logic")]
+    fn test_derived_logic_operators_registered() {
+        let registry = crate::create_default_registry();
+        for name in ["And", "Or", "Not", "Xor"] {
+            let entry = registry.get_extended_meta_by_name(name).unwrap();
+            assert_eq!(entry.meta.category, "Logic");
+            let (_, meta) = registry.create_with_meta_by_name(name).unwrap();
+            assert_eq!(meta.category_color, Some(flux_core::category_colors::LOGIC));
+        }
+    }
 }
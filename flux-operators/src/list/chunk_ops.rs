@@ -0,0 +1,407 @@
+//! Float list grouping operators
+//!
+//! Operators splitting a FloatList into groups and joining groups back:
+//! - ListChunk: One fixed-size chunk by index, plus the chunk count
+//! - ListDeinterleave: Every Stride-th element starting at Offset
+//! - ListFlattenInterleave: Round-robin merge of several lists
+//!
+//! Deinterleaving with Stride 3 and Offsets 0, 1 and 2 splits interleaved
+//! RGB data into channels; interleaving the channels restores it. Chunk
+//! sizes and strides below 1 are treated as 1.
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator, OperatorCost};
+use flux_core::value::ValueType;
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort};
+use flux_core::Value;
+
+fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_int().unwrap_or(0),
+        None => input.default.as_int().unwrap_or(0),
+    }
+}
+
+fn float_list(value: Value) -> Vec<f32> {
+    match value {
+        Value::FloatList(list) => list.to_vec(),
+        Value::Float(f) => vec![f],
+        _ => Vec::new(),
+    }
+}
+
+fn get_list(input: &InputPort, get_input: InputResolver) -> Vec<f32> {
+    match input.connection {
+        Some((node_id, output_idx)) => float_list(get_input(node_id, output_idx)),
+        None => float_list(input.default.clone()),
+    }
+}
+
+/// A size or stride input, clamped to at least 1
+fn get_size(input: &InputPort, get_input: InputResolver) -> usize {
+    get_int(input, get_input).max(1) as usize
+}
+
+// ============================================================================
+// ListChunk Operator
+// ============================================================================
+
+/// Splits a list into chunks of ChunkSize elements and outputs the one at
+/// Index (negative counts from the end)
+///
+/// The last chunk holds the remainder and may be shorter. An Index outside
+/// the chunks gives an empty list.
+pub struct ListChunkOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 2],
+}
+
+impl ListChunkOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float_list("List"),
+                InputPort::int("ChunkSize", 4),
+                InputPort::int("Index", 0),
+            ],
+            outputs: [OutputPort::float_list("Chunk"), OutputPort::int("Count")],
+        }
+    }
+}
+
+impl Default for ListChunkOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ListChunkOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ListChunk" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let list = get_list(&self.inputs[0], get_input);
+        let size = get_size(&self.inputs[1], get_input);
+        let index = get_int(&self.inputs[2], get_input);
+
+        let count = list.len().div_ceil(size);
+        let index = if index < 0 { count as i64 + index as i64 } else { index as i64 };
+        let chunk = match usize::try_from(index).ok().filter(|&i| i < count) {
+            Some(i) => list.chunks(size).nth(i).unwrap_or_default().to_vec(),
+            None => Vec::new(),
+        };
+        self.outputs[0].value = Value::float_list(chunk);
+        self.outputs[1].set_int(count as i32);
+    }
+}
+
+impl OperatorMeta for ListChunkOp {
+    fn category(&self) -> &'static str { "List" }
+    fn category_color(&self) -> [f32; 4] { category_colors::LIST }
+    fn description(&self) -> &'static str { "One fixed-size chunk of a list by index, and the number of chunks" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("List")),
+            1 => Some(PortMeta::new("ChunkSize").with_range(1.0, 256.0)),
+            2 => Some(PortMeta::new("Index")), // Negative = from end
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Chunk").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Count").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// ListDeinterleave Operator
+// ============================================================================
+
+/// Every Stride-th element starting at Offset
+///
+/// A negative Offset is treated as 0; an Offset past the end gives an empty
+/// list.
+pub struct ListDeinterleaveOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl ListDeinterleaveOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float_list("List"),
+                InputPort::int("Stride", 3),
+                InputPort::int("Offset", 0),
+            ],
+            outputs: [OutputPort::float_list("Result")],
+        }
+    }
+}
+
+impl Default for ListDeinterleaveOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ListDeinterleaveOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ListDeinterleave" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let list = get_list(&self.inputs[0], get_input);
+        let stride = get_size(&self.inputs[1], get_input);
+        let offset = get_int(&self.inputs[2], get_input).max(0) as usize;
+
+        let result: Vec<f32> = list.iter().skip(offset).step_by(stride).copied().collect();
+        self.outputs[0].value = Value::float_list(result);
+    }
+}
+
+impl OperatorMeta for ListDeinterleaveOp {
+    fn category(&self) -> &'static str { "List" }
+    fn category_color(&self) -> [f32; 4] { category_colors::LIST }
+    fn description(&self) -> &'static str { "Every Stride-th element of a list, starting at Offset" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("List")),
+            1 => Some(PortMeta::new("Stride").with_range(1.0, 16.0)),
+            2 => Some(PortMeta::new("Offset").with_range(0.0, 15.0)),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// ListFlattenInterleave Operator
+// ============================================================================
+
+/// Takes one element from each connected list in turn (zip-shortest)
+///
+/// Lists `[r0, r1]`, `[g0, g1]` and `[b0, b1]` give `[r0, g0, b0, r1, g1, b1]`.
+/// Stops at the end of the shortest list; without connections the default
+/// list passes through.
+pub struct ListFlattenInterleaveOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 1],
+}
+
+impl ListFlattenInterleaveOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::new_multi("Lists", ValueType::FloatList)],
+            outputs: [OutputPort::float_list("Result")],
+        }
+    }
+}
+
+impl Default for ListFlattenInterleaveOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ListFlattenInterleaveOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ListFlattenInterleave" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let input = &self.inputs[0];
+        if input.connections.is_empty() {
+            self.outputs[0].value = Value::float_list(get_list(input, get_input));
+            return;
+        }
+
+        let lists: Vec<Vec<f32>> = input
+            .connections
+            .iter()
+            .map(|&(node_id, output_idx)| float_list(get_input(node_id, output_idx)))
+            .collect();
+        let len = lists.iter().map(Vec::len).min().unwrap_or(0);
+        let result: Vec<f32> = (0..len).flat_map(|i| lists.iter().map(move |list| list[i])).collect();
+        self.outputs[0].value = Value::float_list(result);
+    }
+}
+
+impl OperatorMeta for ListFlattenInterleaveOp {
+    fn category(&self) -> &'static str { "List" }
+    fn category_color(&self) -> [f32; 4] { category_colors::LIST }
+    fn description(&self) -> &'static str { "Interleave several lists element by element (zip-shortest)" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Lists")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new("ListChunk", "List", "One fixed-size chunk of a list"),
+        || capture_meta(ListChunkOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("ListDeinterleave", "List", "Every Stride-th element of a list"),
+        || capture_meta(ListDeinterleaveOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("ListFlattenInterleave", "List", "Interleave several lists"),
+        || capture_meta(ListFlattenInterleaveOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    fn output_list(op: &dyn Operator) -> Vec<f32> {
+        op.outputs()[0].value.as_float_list().unwrap().to_vec()
+    }
+
+    #[test]
+    fn test_list_chunk() {
+        let mut op = ListChunkOp::new();
+        let ctx = EvalContext::new();
+        op.inputs[0].default = Value::float_list((0..10).map(|i| i as f32).collect());
+        op.inputs[1].default = Value::Int(4);
+
+        op.inputs[2].default = Value::Int(1);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(output_list(&op), [4.0, 5.0, 6.0, 7.0]);
+        assert_eq!(op.outputs[1].value.as_int(), Some(3));
+
+        // The last chunk holds the remainder
+        op.inputs[2].default = Value::Int(-1);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(output_list(&op), [8.0, 9.0]);
+
+        for index in [3, -4] {
+            op.inputs[2].default = Value::Int(index);
+            op.compute(&ctx, &no_connections);
+            assert!(output_list(&op).is_empty());
+        }
+
+        // Sizes below 1 give one element per chunk
+        op.inputs[1].default = Value::Int(0);
+        op.inputs[2].default = Value::Int(2);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(output_list(&op), [2.0]);
+        assert_eq!(op.outputs[1].value.as_int(), Some(10));
+    }
+
+    #[test]
+    fn test_deinterleave_and_interleave_rgb() {
+        let rgb: Vec<f32> = (0..12).map(|i| i as f32 * 0.5).collect();
+        let ctx = EvalContext::new();
+
+        let channels: Vec<Vec<f32>> = (0..3)
+            .map(|offset| {
+                let mut op = ListDeinterleaveOp::new();
+                op.inputs[0].default = Value::float_list(rgb.clone());
+                op.inputs[2].default = Value::Int(offset);
+                op.compute(&ctx, &no_connections);
+                output_list(&op)
+            })
+            .collect();
+        assert_eq!(channels[0], [0.0, 1.5, 3.0, 4.5]);
+        assert_eq!(channels[2], [1.0, 2.5, 4.0, 5.5]);
+
+        let sources = [Id::new(), Id::new(), Id::new()];
+        let resolve = |node: Id, _: usize| {
+            let channel = sources.iter().position(|&id| id == node).unwrap();
+            Value::float_list(channels[channel].clone())
+        };
+        let mut op = ListFlattenInterleaveOp::new();
+        op.inputs[0].connections = sources.iter().map(|&id| (id, 0)).collect();
+        op.compute(&ctx, &resolve);
+        assert_eq!(output_list(&op), rgb);
+    }
+
+    #[test]
+    fn test_deinterleave_clamps_stride_and_offset() {
+        let mut op = ListDeinterleaveOp::new();
+        let ctx = EvalContext::new();
+        op.inputs[0].default = Value::float_list(vec![1.0, 2.0, 3.0]);
+        op.inputs[1].default = Value::Int(-2);
+        op.inputs[2].default = Value::Int(-1);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(output_list(&op), [1.0, 2.0, 3.0]);
+
+        op.inputs[2].default = Value::Int(5);
+        op.compute(&ctx, &no_connections);
+        assert!(output_list(&op).is_empty());
+    }
+
+    #[test]
+    fn test_interleave_stops_at_shortest_list() {
+        let (a, b) = (Id::new(), Id::new());
+        let resolve = |node: Id, _: usize| {
+            if node == a {
+                Value::float_list(vec![1.0, 2.0, 3.0])
+            } else {
+                Value::float_list(vec![10.0, 20.0])
+            }
+        };
+        let mut op = ListFlattenInterleaveOp::new();
+        op.inputs[0].connections = vec![(a, 0), (b, 0)];
+        op.compute(&EvalContext::new(), &resolve);
+        assert_eq!(output_list(&op), [1.0, 10.0, 2.0, 20.0]);
+    }
+}
@@ -43,24 +43,6 @@ fn get_color_list(input: &InputPort, get_input: InputResolver) -> Vec<Color> {
     }
 }
 
-fn collect_colors(input: &InputPort, get_input: InputResolver) -> Vec<Color> {
-    if !input.connections.is_empty() {
-        input
-            .connections
-            .iter()
-            .map(|(node_id, output_idx)| {
-                get_input(*node_id, *output_idx).as_color().unwrap_or(Color::BLACK)
-            })
-            .collect()
-    } else {
-        match &input.default {
-            Value::ColorList(list) => list.to_vec(),
-            Value::Color(c) => vec![*c],
-            _ => Vec::new(),
-        }
-    }
-}
-
 // ============================================================================
 // ColorList Operator (Creation)
 // ============================================================================
@@ -97,8 +79,9 @@ impl Operator for ColorListOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let values = collect_colors(&self.inputs[0], get_input);
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let mut values = self.inputs[0].resolve_multi_colors(get_input);
+        values.truncate(ctx.clamp_list_len(values.len()));
         self.outputs[0].value = Value::color_list(values);
     }
 }
@@ -302,32 +285,17 @@ impl OperatorMeta for ColorListBlendOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ColorList",
-            category: "List",
-            description: "Create color list (palette)",
-        },
+        RegistryEntry::new("ColorList", "List", "Create color list (palette)"),
         || capture_meta(ColorListOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ColorListSample",
-            category: "List",
-            description: "Sample color from palette",
-        },
+        RegistryEntry::new("ColorListSample", "List", "Sample color from palette"),
         || capture_meta(ColorListSampleOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ColorListBlend",
-            category: "List",
-            description: "Blend all colors",
-        },
+        RegistryEntry::new("ColorListBlend", "List", "Blend all colors"),
         || capture_meta(ColorListBlendOp::new()),
     );
 }
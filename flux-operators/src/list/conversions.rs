@@ -195,11 +195,12 @@ impl Operator for Vec3ListFlattenOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let value = get_value(&self.inputs[0], get_input);
         match value {
             Value::Vec3List(vl) => {
-                let fl: Vec<f32> = vl.iter().flat_map(|v| vec![v[0], v[1], v[2]]).collect();
+                let len = ctx.clamp_list_len(vl.len().saturating_mul(3));
+                let fl: Vec<f32> = vl.iter().flat_map(|v| *v).take(len).collect();
                 self.outputs[0].value = Value::float_list(fl);
             }
             _ => {
@@ -441,62 +442,32 @@ impl OperatorMeta for Vec4ListToColorListOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntListToFloatList",
-            category: "List",
-            description: "Convert IntList to FloatList",
-        },
+        RegistryEntry::new("IntListToFloatList", "List", "Convert IntList to FloatList"),
         || capture_meta(IntListToFloatListOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "FloatListToIntList",
-            category: "List",
-            description: "Convert FloatList to IntList",
-        },
+        RegistryEntry::new("FloatListToIntList", "List", "Convert FloatList to IntList"),
         || capture_meta(FloatListToIntListOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3ListFlatten",
-            category: "List",
-            description: "Flatten Vec3List to FloatList",
-        },
+        RegistryEntry::new("Vec3ListFlatten", "List", "Flatten Vec3List to FloatList"),
         || capture_meta(Vec3ListFlattenOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "FloatListToVec3List",
-            category: "List",
-            description: "Group FloatList to Vec3List",
-        },
+        RegistryEntry::new("FloatListToVec3List", "List", "Group FloatList to Vec3List"),
         || capture_meta(FloatListToVec3ListOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ColorListToVec4List",
-            category: "List",
-            description: "Convert ColorList to Vec4List",
-        },
+        RegistryEntry::new("ColorListToVec4List", "List", "Convert ColorList to Vec4List"),
         || capture_meta(ColorListToVec4ListOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec4ListToColorList",
-            category: "List",
-            description: "Convert Vec4List to ColorList",
-        },
+        RegistryEntry::new("Vec4ListToColorList", "List", "Convert Vec4List to ColorList"),
         || capture_meta(Vec4ListToColorListOp::new()),
     );
 }
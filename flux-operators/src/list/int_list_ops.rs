@@ -43,24 +43,6 @@ fn get_int_list(input: &InputPort, get_input: InputResolver) -> Vec<i32> {
     }
 }
 
-fn collect_ints(input: &InputPort, get_input: InputResolver) -> Vec<i32> {
-    if !input.connections.is_empty() {
-        input
-            .connections
-            .iter()
-            .map(|(node_id, output_idx)| {
-                get_input(*node_id, *output_idx).as_int().unwrap_or(0)
-            })
-            .collect()
-    } else {
-        match &input.default {
-            Value::IntList(list) => list.to_vec(),
-            Value::Int(i) => vec![*i],
-            _ => Vec::new(),
-        }
-    }
-}
-
 // ============================================================================
 // IntList Operator (Creation)
 // ============================================================================
@@ -97,8 +79,9 @@ impl Operator for IntListOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let values = collect_ints(&self.inputs[0], get_input);
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let mut values = self.inputs[0].resolve_multi_ints(get_input);
+        values.truncate(ctx.clamp_list_len(values.len()));
         self.outputs[0].value = Value::int_list(values);
     }
 }
@@ -344,25 +327,20 @@ impl Operator for IntListRangeOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let start = get_int(&self.inputs[0], get_input);
         let end = get_int(&self.inputs[1], get_input);
         let step = get_int(&self.inputs[2], get_input).max(1); // Ensure step >= 1
 
-        let mut result = Vec::new();
-        let mut i = start;
-        if start <= end {
-            while i < end {
-                result.push(i);
-                i += step;
-            }
-        } else {
-            // Descending range
-            while i > end {
-                result.push(i);
-                i -= step;
-            }
-        }
+        // Count elements up front so huge ranges are clamped before allocating
+        let span = (i64::from(end) - i64::from(start)).unsigned_abs();
+        let count = ctx.clamp_list_len(span.div_ceil(step as u64) as usize);
+        // Descending range when start > end
+        let step = if start <= end { i64::from(step) } else { -i64::from(step) };
+
+        let result: Vec<i32> = (0..count as i64)
+            .map(|k| (i64::from(start) + k * step) as i32)
+            .collect();
 
         self.outputs[0].value = Value::int_list(result);
     }
@@ -394,52 +372,27 @@ impl OperatorMeta for IntListRangeOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntList",
-            category: "List",
-            description: "Create integer list from values",
-        },
+        RegistryEntry::new("IntList", "List", "Create integer list from values"),
         || capture_meta(IntListOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntListSum",
-            category: "List",
-            description: "Sum of integer list",
-        },
+        RegistryEntry::new("IntListSum", "List", "Sum of integer list"),
         || capture_meta(IntListSumOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntListMin",
-            category: "List",
-            description: "Minimum value in integer list",
-        },
+        RegistryEntry::new("IntListMin", "List", "Minimum value in integer list"),
         || capture_meta(IntListMinOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntListMax",
-            category: "List",
-            description: "Maximum value in integer list",
-        },
+        RegistryEntry::new("IntListMax", "List", "Maximum value in integer list"),
         || capture_meta(IntListMaxOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntListRange",
-            category: "List",
-            description: "Generate integer range",
-        },
+        RegistryEntry::new("IntListRange", "List", "Generate integer range"),
         || capture_meta(IntListRangeOp::new()),
     );
 }
@@ -511,4 +464,35 @@ mod tests {
             panic!("Expected IntList");
         }
     }
+
+    #[test]
+    fn test_int_list_range_descending() {
+        let mut op = IntListRangeOp::new();
+        let ctx = EvalContext::new();
+
+        op.inputs[0].default = Value::Int(5);
+        op.inputs[1].default = Value::Int(0);
+        op.inputs[2].default = Value::Int(2);
+        op.compute(&ctx, &no_connections);
+
+        assert_eq!(op.outputs[0].value.as_int_list(), Some(&[5, 3, 1][..]));
+    }
+
+    #[test]
+    fn test_int_list_range_clamped_to_limit() {
+        let mut op = IntListRangeOp::new();
+        let mut ctx = EvalContext::new();
+        ctx.max_list_len = 100;
+
+        op.inputs[1].default = Value::Int(1_000_000_000);
+        op.compute(&ctx, &no_connections);
+
+        let result = op.outputs[0].value.as_int_list().unwrap();
+        assert_eq!(result.len(), 100);
+        assert_eq!(result[99], 99);
+        assert!(matches!(
+            ctx.take_errors().as_slice(),
+            [flux_core::OperatorError::ListTooLong { requested: 1_000_000_000, limit: 100 }]
+        ));
+    }
 }
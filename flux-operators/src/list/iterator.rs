@@ -201,14 +201,14 @@ impl Operator for ArrayIterator {
             }
 
             // Fire OnElement (index 0)
-            return vec![0];
+            vec![0]
         } else {
             // Iteration complete or empty list - reset state and fire OnComplete
             self.current_index = 0;
             self.is_iterating = false;
 
             // Fire OnComplete (index 1)
-            return vec![1];
+            vec![1]
         }
     }
 }
@@ -242,12 +242,7 @@ impl OperatorMeta for ArrayIterator {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ArrayIterator",
-            category: "List",
-            description: "Trigger-based list iteration",
-        },
+        RegistryEntry::new("ArrayIterator", "List", "Trigger-based list iteration"),
         || capture_meta(ArrayIterator::new()),
     );
 }
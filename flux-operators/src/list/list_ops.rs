@@ -1,4 +1,4 @@
-//! List operators: FloatList, ListLength, ListGet, ListSum, ListAverage, ListMin, ListMax, ListMap
+//! List operators: FloatList, ListLength, ListGet, ListSum, ListAverage, ListMin, ListMax, ListScaleOffset
 //!
 //! ## Polymorphic vs Type-Specific
 //!
@@ -6,13 +6,13 @@
 //! - ListLength, ListGet, ListSlice, ListConcat, ListReverse, ListFirst, ListLast
 //!
 //! **Type-specific operators** (require specific element types):
-//! - FloatList (creation), ListSum, ListAverage, ListMin, ListMax, ListMap, ListFilter
+//! - FloatList (creation), ListSum, ListAverage, ListMin, ListMax, ListScaleOffset, ListFilter
 
 use std::any::Any;
 
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
-use flux_core::operator::{InputResolver, Operator};
+use flux_core::operator::{InputResolver, InputValidationIssue, Operator, OperatorCost};
 use flux_core::value::{Color, ValueType};
 use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
@@ -53,6 +53,19 @@ fn get_list(input: &InputPort, get_input: InputResolver) -> Vec<f32> {
     }
 }
 
+/// Get float list as a value, sharing the buffer for copy-on-write edits
+fn get_list_value(input: &InputPort, get_input: InputResolver) -> Value {
+    let value = match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx),
+        None => input.default.clone(),
+    };
+    match value {
+        Value::FloatList(_) => value,
+        Value::Float(f) => Value::float_list(vec![f]),
+        _ => Value::float_list(Vec::new()),
+    }
+}
+
 // ============================================================================
 // Polymorphic List Helpers
 // ============================================================================
@@ -171,56 +184,50 @@ fn list_slice(value: &Value, start: i32, end: i32) -> Value {
     }
 }
 
-/// Concatenate two lists of the same type
-fn list_concat(a: &Value, b: &Value) -> Value {
+/// Concatenate two lists of the same type, keeping at most `max_len` elements
+fn list_concat(a: &Value, b: &Value, max_len: usize) -> Value {
     match (a, b) {
         (Value::FloatList(la), Value::FloatList(lb)) => {
-            let mut result: Vec<f32> = la.iter().copied().collect();
-            result.extend(lb.iter().copied());
+            let result: Vec<f32> = la.iter().chain(lb.iter()).take(max_len).copied().collect();
             Value::float_list(result)
         }
         (Value::IntList(la), Value::IntList(lb)) => {
-            let mut result: Vec<i32> = la.iter().copied().collect();
-            result.extend(lb.iter().copied());
+            let result: Vec<i32> = la.iter().chain(lb.iter()).take(max_len).copied().collect();
             Value::int_list(result)
         }
         (Value::BoolList(la), Value::BoolList(lb)) => {
-            let mut result: Vec<bool> = la.iter().copied().collect();
-            result.extend(lb.iter().copied());
+            let result: Vec<bool> = la.iter().chain(lb.iter()).take(max_len).copied().collect();
             Value::bool_list(result)
         }
         (Value::Vec2List(la), Value::Vec2List(lb)) => {
-            let mut result: Vec<[f32; 2]> = la.iter().copied().collect();
-            result.extend(lb.iter().copied());
+            let result: Vec<[f32; 2]> = la.iter().chain(lb.iter()).take(max_len).copied().collect();
             Value::vec2_list(result)
         }
         (Value::Vec3List(la), Value::Vec3List(lb)) => {
-            let mut result: Vec<[f32; 3]> = la.iter().copied().collect();
-            result.extend(lb.iter().copied());
+            let result: Vec<[f32; 3]> = la.iter().chain(lb.iter()).take(max_len).copied().collect();
             Value::vec3_list(result)
         }
         (Value::Vec4List(la), Value::Vec4List(lb)) => {
-            let mut result: Vec<[f32; 4]> = la.iter().copied().collect();
-            result.extend(lb.iter().copied());
+            let result: Vec<[f32; 4]> = la.iter().chain(lb.iter()).take(max_len).copied().collect();
             Value::vec4_list(result)
         }
         (Value::ColorList(la), Value::ColorList(lb)) => {
-            let mut result: Vec<Color> = la.iter().copied().collect();
-            result.extend(lb.iter().copied());
+            let result: Vec<Color> = la.iter().chain(lb.iter()).take(max_len).copied().collect();
             Value::color_list(result)
         }
         (Value::StringList(la), Value::StringList(lb)) => {
-            let mut result: Vec<String> = la.iter().cloned().collect();
-            result.extend(lb.iter().cloned());
+            let result: Vec<String> = la.iter().chain(lb.iter()).take(max_len).cloned().collect();
             Value::string_list(result)
         }
         // Cross-type: try coercion or return first list
         _ => {
             // Attempt to coerce b to a's type
             if let Some(coerced) = b.coerce_to(a.value_type()) {
-                list_concat(a, &coerced)
+                list_concat(a, &coerced, max_len)
             } else {
-                a.clone()
+                let mut result = a.clone();
+                result.truncate_list(max_len);
+                result
             }
         }
     }
@@ -257,25 +264,6 @@ fn element_type_for_list(list_type: ValueType) -> ValueType {
     }
 }
 
-// Helper to collect floats from multi-input
-fn collect_floats(input: &InputPort, get_input: InputResolver) -> Vec<f32> {
-    if !input.connections.is_empty() {
-        input
-            .connections
-            .iter()
-            .map(|(node_id, output_idx)| {
-                get_input(*node_id, *output_idx).as_float().unwrap_or(0.0)
-            })
-            .collect()
-    } else {
-        match &input.default {
-            Value::FloatList(list) => list.to_vec(),
-            Value::Float(f) => vec![*f],
-            _ => Vec::new(),
-        }
-    }
-}
-
 // ============================================================================
 // FloatList Operator
 // ============================================================================
@@ -312,8 +300,9 @@ impl Operator for FloatListOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let values = collect_floats(&self.inputs[0], get_input);
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let mut values = self.inputs[0].resolve_multi_floats(get_input);
+        values.truncate(ctx.clamp_list_len(values.len()));
         self.outputs[0].value = Value::float_list(values);
     }
 }
@@ -507,6 +496,7 @@ impl Operator for ListSumOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let list = get_list(&self.inputs[0], get_input);
@@ -568,6 +558,7 @@ impl Operator for ListAverageOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let list = get_list(&self.inputs[0], get_input);
@@ -633,6 +624,7 @@ impl Operator for ListMinOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let list = get_list(&self.inputs[0], get_input);
@@ -695,6 +687,7 @@ impl Operator for ListMaxOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let list = get_list(&self.inputs[0], get_input);
@@ -723,16 +716,24 @@ impl OperatorMeta for ListMaxOp {
 }
 
 // ============================================================================
-// ListMap Operator (Scale & Offset)
+// ListScaleOffset Operator (formerly ListMap)
 // ============================================================================
 
-pub struct ListMapOp {
+/// Multiplies every value by Scale and adds Offset
+///
+/// Registered as "ListScaleOffset"; files saved with the old "ListMap" name
+/// load as this operator.
+pub struct ListScaleOffsetOp {
     id: Id,
     inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
 }
 
-impl ListMapOp {
+/// Old name of [`ListScaleOffsetOp`]
+#[deprecated(note = "renamed to ListScaleOffsetOp")]
+pub type ListMapOp = ListScaleOffsetOp;
+
+impl ListScaleOffsetOp {
     pub fn new() -> Self {
         Self {
             id: Id::new(),
@@ -746,33 +747,38 @@ impl ListMapOp {
     }
 }
 
-impl Default for ListMapOp {
+impl Default for ListScaleOffsetOp {
     fn default() -> Self {
         Self::new()
     }
 }
 
-impl Operator for ListMapOp {
+impl Operator for ListScaleOffsetOp {
     fn as_any(&self) -> &dyn Any { self }
     fn as_any_mut(&mut self) -> &mut dyn Any { self }
     fn id(&self) -> Id { self.id }
-    fn name(&self) -> &'static str { "ListMap" }
+    fn name(&self) -> &'static str { "ListScaleOffset" }
     fn inputs(&self) -> &[InputPort] { &self.inputs }
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Expensive }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let list = get_list(&self.inputs[0], get_input);
+        let mut result = get_list_value(&self.inputs[0], get_input);
         let scale = get_float(&self.inputs[1], get_input);
         let offset = get_float(&self.inputs[2], get_input);
 
-        let result: Vec<f32> = list.iter().map(|v| v * scale + offset).collect();
-        self.outputs[0].value = Value::float_list(result);
+        if let Some(values) = result.as_float_list_mut() {
+            for v in values {
+                *v = *v * scale + offset;
+            }
+        }
+        self.outputs[0].value = result;
     }
 }
 
-impl OperatorMeta for ListMapOp {
+impl OperatorMeta for ListScaleOffsetOp {
     fn category(&self) -> &'static str { "List" }
     fn category_color(&self) -> [f32; 4] { category_colors::LIST }
     fn description(&self) -> &'static str { "Scale and offset all values in list" }
@@ -831,6 +837,7 @@ impl Operator for ListFilterOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Expensive }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let list = get_list(&self.inputs[0], get_input);
@@ -909,12 +916,18 @@ impl Operator for ListConcatOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let list_a = get_any_list(&self.inputs[0], get_input);
-        let list_b = get_any_list(&self.inputs[1], get_input);
+        let mut list_b = get_any_list(&self.inputs[1], get_input);
+        if let Some(coerced) = list_b.coerce_to(list_a.value_type()) {
+            list_b = coerced;
+        }
 
-        let result = list_concat(&list_a, &list_b);
+        // Clamp before concatenating so oversized results never allocate
+        let requested = list_length(&list_a) + list_length(&list_b);
+        let result = list_concat(&list_a, &list_b, ctx.clamp_list_len(requested));
 
         // Update output type if needed
         if self.outputs[0].value_type != result.value_type() {
@@ -947,6 +960,8 @@ impl OperatorMeta for ListConcatOp {
 // ListSlice Operator (Polymorphic)
 // ============================================================================
 
+/// Elements from Start (inclusive) to End (exclusive); negative indices count
+/// from the end. When End doesn't come after Start the slice is empty.
 pub struct ListSliceOp {
     id: Id,
     inputs: [InputPort; 3],
@@ -996,6 +1011,24 @@ impl Operator for ListSliceOp {
         }
         self.outputs[0].value = result;
     }
+
+    fn validate_inputs(&self, proposed: &[Value]) -> Vec<InputValidationIssue> {
+        let literal = |index: usize| {
+            (!self.inputs[index].is_connected())
+                .then(|| proposed.get(index).and_then(Value::as_int))
+                .flatten()
+        };
+        // Indices of opposite signs depend on the list length
+        match (literal(1), literal(2)) {
+            (Some(start), Some(end)) if (start < 0) == (end < 0) && start >= end => {
+                vec![InputValidationIssue::warning(
+                    2,
+                    format!("End ({}) must come after Start ({}); the slice is empty", end, start),
+                )]
+            }
+            _ => Vec::new(),
+        }
+    }
 }
 
 impl OperatorMeta for ListSliceOp {
@@ -1053,6 +1086,7 @@ impl Operator for ListReverseOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let list_value = get_any_list(&self.inputs[0], get_input);
@@ -1257,6 +1291,7 @@ impl Operator for ListAddOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let a = get_list(&self.inputs[0], get_input);
@@ -1320,6 +1355,7 @@ impl Operator for ListSubOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let a = get_list(&self.inputs[0], get_input);
@@ -1383,6 +1419,7 @@ impl Operator for ListMulOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let a = get_list(&self.inputs[0], get_input);
@@ -1446,6 +1483,7 @@ impl Operator for ListDivOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let a = get_list(&self.inputs[0], get_input);
@@ -1512,6 +1550,7 @@ impl Operator for ListPowOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Moderate }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let a = get_list(&self.inputs[0], get_input);
@@ -1546,193 +1585,99 @@ impl OperatorMeta for ListPowOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "FloatList",
-            category: "List",
-            description: "Create list from values",
-        },
+        RegistryEntry::new("FloatList", "List", "Create list from values"),
         || capture_meta(FloatListOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListLength",
-            category: "List",
-            description: "Get list length",
-        },
+        RegistryEntry::new("ListLength", "List", "Get list length"),
         || capture_meta(ListLengthOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListGet",
-            category: "List",
-            description: "Get value at index",
-        },
+        RegistryEntry::new("ListGet", "List", "Get value at index"),
         || capture_meta(ListGetOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListSum",
-            category: "List",
-            description: "Sum of list values",
-        },
+        RegistryEntry::new("ListSum", "List", "Sum of list values"),
         || capture_meta(ListSumOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListAverage",
-            category: "List",
-            description: "Average of list values",
-        },
+        RegistryEntry::new("ListAverage", "List", "Average of list values"),
         || capture_meta(ListAverageOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListMin",
-            category: "List",
-            description: "Minimum value in list",
-        },
+        RegistryEntry::new("ListMin", "List", "Minimum value in list"),
         || capture_meta(ListMinOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListMax",
-            category: "List",
-            description: "Maximum value in list",
-        },
+        RegistryEntry::new("ListMax", "List", "Maximum value in list"),
         || capture_meta(ListMaxOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListMap",
-            category: "List",
-            description: "Scale and offset list values",
-        },
-        || capture_meta(ListMapOp::new()),
+        RegistryEntry::new("ListScaleOffset", "List", "Scale and offset list values"),
+        || capture_meta(ListScaleOffsetOp::new()),
     );
+    registry.register_renamed("ListMap", "ListScaleOffset");
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListFilter",
-            category: "List",
-            description: "Filter list by threshold",
-        },
+        RegistryEntry::new("ListFilter", "List", "Filter list by threshold"),
         || capture_meta(ListFilterOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListConcat",
-            category: "List",
-            description: "Concatenate two lists",
-        },
+        RegistryEntry::new("ListConcat", "List", "Concatenate two lists"),
         || capture_meta(ListConcatOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListSlice",
-            category: "List",
-            description: "Extract slice from list",
-        },
+        RegistryEntry::new("ListSlice", "List", "Extract slice from list"),
         || capture_meta(ListSliceOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListReverse",
-            category: "List",
-            description: "Reverse list order",
-        },
+        RegistryEntry::new("ListReverse", "List", "Reverse list order"),
         || capture_meta(ListReverseOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListFirst",
-            category: "List",
-            description: "Get first list element",
-        },
+        RegistryEntry::new("ListFirst", "List", "Get first list element"),
         || capture_meta(ListFirstOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListLast",
-            category: "List",
-            description: "Get last list element",
-        },
+        RegistryEntry::new("ListLast", "List", "Get last list element"),
         || capture_meta(ListLastOp::new()),
     );
 
     // Binary list operations
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListAdd",
-            category: "List",
-            description: "Element-wise list addition",
-        },
+        RegistryEntry::new("ListAdd", "List", "Element-wise list addition"),
         || capture_meta(ListAddOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListSub",
-            category: "List",
-            description: "Element-wise list subtraction",
-        },
+        RegistryEntry::new("ListSub", "List", "Element-wise list subtraction"),
         || capture_meta(ListSubOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListMul",
-            category: "List",
-            description: "Element-wise list multiplication",
-        },
+        RegistryEntry::new("ListMul", "List", "Element-wise list multiplication"),
         || capture_meta(ListMulOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListDiv",
-            category: "List",
-            description: "Element-wise list division",
-        },
+        RegistryEntry::new("ListDiv", "List", "Element-wise list division"),
         || capture_meta(ListDivOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "ListPow",
-            category: "List",
-            description: "Element-wise list power",
-        },
+        RegistryEntry::new("ListPow", "List", "Element-wise list power"),
         || capture_meta(ListPowOp::new()),
     );
 }
@@ -1813,8 +1758,8 @@ mod tests {
     }
 
     #[test]
-    fn test_list_map() {
-        let mut op = ListMapOp::new();
+    fn test_list_scale_offset() {
+        let mut op = ListScaleOffsetOp::new();
         let ctx = EvalContext::new();
 
         op.inputs[0].default = Value::float_list(vec![1.0, 2.0, 3.0]);
@@ -1829,6 +1774,8 @@ mod tests {
         } else {
             panic!("Expected FloatList");
         }
+        // The shared default is copied before mapping, not modified
+        assert_eq!(op.inputs[0].default.as_float_list(), Some(&[1.0, 2.0, 3.0][..]));
     }
 
     #[test]
@@ -1883,6 +1830,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_list_concat_clamped_to_limit() {
+        let mut op = ListConcatOp::new();
+        let mut ctx = EvalContext::new();
+        ctx.max_list_len = 4;
+
+        op.inputs[0].default = Value::float_list(vec![1.0, 2.0]);
+        op.inputs[1].default = Value::float_list(vec![3.0, 4.0, 5.0]);
+        op.compute(&ctx, &no_connections);
+
+        assert_eq!(op.outputs[0].value.as_float_list(), Some(&[1.0, 2.0, 3.0, 4.0][..]));
+        assert_eq!(ctx.take_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_list_slice_validation() {
+        let mut op = ListSliceOp::new();
+        let proposed = |start: i32, end: i32| {
+            vec![Value::float_list(vec![]), Value::Int(start), Value::Int(end)]
+        };
+        assert!(op.validate_inputs(&proposed(1, 3)).is_empty());
+        assert!(op.validate_inputs(&proposed(-3, -1)).is_empty());
+        // Opposite signs depend on the list length
+        assert!(op.validate_inputs(&proposed(2, -3)).is_empty());
+
+        let issues = op.validate_inputs(&proposed(3, 1));
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].input, 2);
+        assert_eq!(op.validate_inputs(&proposed(-1, -2)).len(), 1);
+
+        op.inputs[1].connect(Id::new(), 0);
+        assert!(op.validate_inputs(&proposed(3, 1)).is_empty());
+    }
+
     #[test]
     fn test_list_slice() {
         let mut op = ListSliceOp::new();
@@ -1,4 +1,4 @@
-//! List operators (40 total)
+//! List operators (47 total)
 //!
 //! ## Polymorphic (work with any list type)
 //! - ListLength, ListGet, ListSlice, ListConcat
@@ -6,11 +6,14 @@
 //!
 //! ## FloatList-specific
 //! - FloatList, ListSum, ListAverage, ListMin, ListMax
-//! - ListMap, ListFilter
+//! - ListScaleOffset, ListFilter
 //!
 //! ## Binary List Operations (element-wise, zip-shortest)
 //! - ListAdd, ListSub, ListMul, ListDiv, ListPow
 //!
+//! ## Chunking and interleaving
+//! - ListChunk, ListDeinterleave, ListFlattenInterleave
+//!
 //! ## Iteration
 //! - ArrayIterator (trigger-based)
 //!
@@ -20,6 +23,9 @@
 //! ## Vec3List-specific
 //! - Vec3List, Vec3ListNormalize, Vec3ListCentroid, Vec3ListBounds
 //!
+//! ## Vec2List geometry
+//! - PolylineLength, PolylineResample, PointInPolygon, PolylineNormals
+//!
 //! ## ColorList-specific
 //! - ColorList, ColorListSample, ColorListBlend
 //!
@@ -32,23 +38,29 @@ use crate::registry::OperatorRegistry;
 
 mod list_ops;
 mod int_list_ops;
+mod vec2_list_ops;
 mod vec3_list_ops;
 mod color_list_ops;
 mod conversions;
+mod chunk_ops;
 mod iterator;
 
 pub use list_ops::*;
 pub use int_list_ops::*;
+pub use vec2_list_ops::*;
 pub use vec3_list_ops::*;
 pub use color_list_ops::*;
 pub use conversions::*;
+pub use chunk_ops::*;
 pub use iterator::*;
 
 pub fn register_all(registry: &OperatorRegistry) {
     list_ops::register(registry);
     int_list_ops::register(registry);
+    vec2_list_ops::register(registry);
     vec3_list_ops::register(registry);
     color_list_ops::register(registry);
     conversions::register(registry);
+    chunk_ops::register(registry);
     iterator::register(registry);
 }
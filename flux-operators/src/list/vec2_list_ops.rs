@@ -0,0 +1,575 @@
+//! Vec2 list geometry operators
+//!
+//! Operators treating a Vec2List as a polyline or polygon:
+//! - PolylineLength: Total length (optionally closed)
+//! - PolylineResample: Evenly spaced points by arc length
+//! - PointInPolygon: Even-odd containment test
+//! - PolylineNormals: Per-point unit normals
+//!
+//! Degenerate input (empty lists, single points, zero-length segments) never
+//! produces NaN: lengths are 0, resampled points collapse onto the first
+//! point and undefined normals are `[0, 0]`.
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator, OperatorCost};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort};
+use flux_core::Value;
+
+/// Distance below which a point counts as lying on a polygon edge
+const EDGE_EPSILON: f32 = 1e-6;
+
+fn get_vec2_list(input: &InputPort, get_input: InputResolver) -> Vec<[f32; 2]> {
+    match input.connection {
+        Some((node_id, output_idx)) => {
+            let value = get_input(node_id, output_idx);
+            match value {
+                Value::Vec2List(list) => list.to_vec(),
+                Value::Vec2(v) => vec![v],
+                _ => Vec::new(),
+            }
+        }
+        None => match &input.default {
+            Value::Vec2List(list) => list.to_vec(),
+            Value::Vec2(v) => vec![*v],
+            _ => Vec::new(),
+        },
+    }
+}
+
+fn get_vec2(input: &InputPort, get_input: InputResolver) -> [f32; 2] {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_vec2().unwrap_or([0.0, 0.0]),
+        None => input.default.as_vec2().unwrap_or([0.0, 0.0]),
+    }
+}
+
+fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_int().unwrap_or(0),
+        None => input.default.as_int().unwrap_or(0),
+    }
+}
+
+fn get_bool(input: &InputPort, get_input: InputResolver) -> bool {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_bool().unwrap_or(false),
+        None => input.default.as_bool().unwrap_or(false),
+    }
+}
+
+fn distance(a: [f32; 2], b: [f32; 2]) -> f32 {
+    (b[0] - a[0]).hypot(b[1] - a[1])
+}
+
+fn direction(a: [f32; 2], b: [f32; 2]) -> [f32; 2] {
+    let len = distance(a, b);
+    if len > 1e-10 {
+        [(b[0] - a[0]) / len, (b[1] - a[1]) / len]
+    } else {
+        [0.0, 0.0]
+    }
+}
+
+/// Segments of a polyline, including the closing segment when `closed`.
+fn segments(points: &[[f32; 2]], closed: bool) -> impl Iterator<Item = ([f32; 2], [f32; 2])> + '_ {
+    let closing = match (closed && points.len() > 2, points.first(), points.last()) {
+        (true, Some(&first), Some(&last)) => Some((last, first)),
+        _ => None,
+    };
+    points.windows(2).map(|w| (w[0], w[1])).chain(closing)
+}
+
+/// Total length of a polyline.
+pub fn polyline_length(points: &[[f32; 2]], closed: bool) -> f32 {
+    segments(points, closed).map(|(a, b)| distance(a, b)).sum()
+}
+
+/// Resample a polyline into `count` points evenly spaced by arc length.
+///
+/// Open polylines include both endpoints. Closed polylines start at the
+/// first point and don't repeat it at the end, so the spacing is uniform
+/// around the loop.
+pub fn resample_polyline(points: &[[f32; 2]], count: usize, closed: bool) -> Vec<[f32; 2]> {
+    let Some(&first) = points.first() else {
+        return Vec::new();
+    };
+    let total = polyline_length(points, closed);
+    if total <= 0.0 || count < 2 {
+        return vec![first; count];
+    }
+
+    let divisions = if closed { count } else { count - 1 };
+    let step = total / divisions as f32;
+    let segs: Vec<_> = segments(points, closed).collect();
+
+    let mut result = Vec::with_capacity(count);
+    let mut seg_index = 0;
+    let mut seg_start = 0.0;
+    for i in 0..count {
+        let target = step * i as f32;
+        // Advance to the segment containing the target distance
+        while seg_index + 1 < segs.len() {
+            let len = distance(segs[seg_index].0, segs[seg_index].1);
+            if seg_start + len > target {
+                break;
+            }
+            seg_start += len;
+            seg_index += 1;
+        }
+
+        let (a, b) = segs[seg_index];
+        let len = distance(a, b);
+        let t = if len > 0.0 {
+            ((target - seg_start) / len).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        result.push([a[0] + (b[0] - a[0]) * t, a[1] + (b[1] - a[1]) * t]);
+    }
+    result
+}
+
+fn distance_to_segment(p: [f32; 2], a: [f32; 2], b: [f32; 2]) -> f32 {
+    let ab = [b[0] - a[0], b[1] - a[1]];
+    let len_sq = ab[0] * ab[0] + ab[1] * ab[1];
+    let t = if len_sq > 0.0 {
+        (((p[0] - a[0]) * ab[0] + (p[1] - a[1]) * ab[1]) / len_sq).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    distance(p, [a[0] + ab[0] * t, a[1] + ab[1] * t])
+}
+
+/// Test whether `point` is inside `polygon` using the even-odd rule.
+///
+/// Points on the boundary (within a small epsilon of an edge or vertex)
+/// count as inside. Polygons with fewer than 3 points contain nothing.
+pub fn point_in_polygon(polygon: &[[f32; 2]], point: [f32; 2]) -> bool {
+    if polygon.len() < 3 {
+        return false;
+    }
+
+    let mut inside = false;
+    for (a, b) in segments(polygon, true) {
+        if distance_to_segment(point, a, b) <= EDGE_EPSILON {
+            return true;
+        }
+        // Half-open comparison so a ray through a vertex is counted once
+        if (a[1] > point[1]) != (b[1] > point[1]) {
+            let x = a[0] + (point[1] - a[1]) / (b[1] - a[1]) * (b[0] - a[0]);
+            if point[0] < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Per-point unit normals of a polyline.
+///
+/// Each normal is perpendicular to the average direction of the point's
+/// adjacent segments, rotated 90° clockwise from the direction of travel
+/// (outward for counter-clockwise polygons). Endpoints of open polylines use
+/// their single segment; points without a defined direction get `[0, 0]`.
+pub fn polyline_normals(points: &[[f32; 2]], closed: bool) -> Vec<[f32; 2]> {
+    let n = points.len();
+    let wrap = closed && n > 2;
+    (0..n)
+        .map(|i| {
+            let prev = if i > 0 { Some(i - 1) } else if wrap { Some(n - 1) } else { None };
+            let next = if i + 1 < n { Some(i + 1) } else if wrap { Some(0) } else { None };
+
+            let incoming = prev.map_or([0.0, 0.0], |p| direction(points[p], points[i]));
+            let outgoing = next.map_or([0.0, 0.0], |q| direction(points[i], points[q]));
+            let mut tangent = direction([0.0, 0.0], [incoming[0] + outgoing[0], incoming[1] + outgoing[1]]);
+            if tangent == [0.0, 0.0] {
+                // Segments cancel out (a U-turn); fall back to either one
+                tangent = if outgoing != [0.0, 0.0] { outgoing } else { incoming };
+            }
+            [tangent[1], -tangent[0]]
+        })
+        .collect()
+}
+
+// ============================================================================
+// PolylineLength Operator
+// ============================================================================
+
+pub struct PolylineLengthOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+}
+
+impl PolylineLengthOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::vec2_list("Points"),
+                InputPort::bool("Closed", false),
+            ],
+            outputs: [OutputPort::float("Length")],
+        }
+    }
+}
+
+impl Default for PolylineLengthOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for PolylineLengthOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "PolylineLength" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let points = get_vec2_list(&self.inputs[0], get_input);
+        let closed = get_bool(&self.inputs[1], get_input);
+        self.outputs[0].set_float(polyline_length(&points, closed));
+    }
+}
+
+impl OperatorMeta for PolylineLengthOp {
+    fn category(&self) -> &'static str { "List" }
+    fn category_color(&self) -> [f32; 4] { category_colors::LIST }
+    fn description(&self) -> &'static str { "Total length of a polyline" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Points")),
+            1 => Some(PortMeta::new("Closed")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Length").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// PolylineResample Operator
+// ============================================================================
+
+pub struct PolylineResampleOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl PolylineResampleOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::vec2_list("Points"),
+                InputPort::int("Count", 16),
+                InputPort::bool("Closed", false),
+            ],
+            outputs: [OutputPort::vec2_list("Resampled")],
+        }
+    }
+}
+
+impl Default for PolylineResampleOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for PolylineResampleOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "PolylineResample" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Expensive }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let points = get_vec2_list(&self.inputs[0], get_input);
+        let count = ctx.clamp_list_len(get_int(&self.inputs[1], get_input).max(0) as usize);
+        let closed = get_bool(&self.inputs[2], get_input);
+        self.outputs[0].value = Value::vec2_list(resample_polyline(&points, count, closed));
+    }
+}
+
+impl OperatorMeta for PolylineResampleOp {
+    fn category(&self) -> &'static str { "List" }
+    fn category_color(&self) -> [f32; 4] { category_colors::LIST }
+    fn description(&self) -> &'static str { "Evenly spaced points along a polyline" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Points")),
+            1 => Some(PortMeta::new("Count").with_range(0.0, 1024.0)),
+            2 => Some(PortMeta::new("Closed")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Resampled").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// PointInPolygon Operator
+// ============================================================================
+
+pub struct PointInPolygonOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+}
+
+impl PointInPolygonOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::vec2_list("Polygon"),
+                InputPort::vec2("Point", [0.0, 0.0]),
+            ],
+            outputs: [OutputPort::bool("Inside")],
+        }
+    }
+}
+
+impl Default for PointInPolygonOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for PointInPolygonOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "PointInPolygon" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Expensive }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let polygon = get_vec2_list(&self.inputs[0], get_input);
+        let point = get_vec2(&self.inputs[1], get_input);
+        self.outputs[0].set_bool(point_in_polygon(&polygon, point));
+    }
+}
+
+impl OperatorMeta for PointInPolygonOp {
+    fn category(&self) -> &'static str { "List" }
+    fn category_color(&self) -> [f32; 4] { category_colors::LIST }
+    fn description(&self) -> &'static str { "Test if a point is inside a polygon (boundary counts as inside)" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Polygon")),
+            1 => Some(PortMeta::new("Point")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Inside").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// PolylineNormals Operator
+// ============================================================================
+
+pub struct PolylineNormalsOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+}
+
+impl PolylineNormalsOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::vec2_list("Points"),
+                InputPort::bool("Closed", false),
+            ],
+            outputs: [OutputPort::vec2_list("Normals")],
+        }
+    }
+}
+
+impl Default for PolylineNormalsOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for PolylineNormalsOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "PolylineNormals" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let points = get_vec2_list(&self.inputs[0], get_input);
+        let closed = get_bool(&self.inputs[1], get_input);
+        self.outputs[0].value = Value::vec2_list(polyline_normals(&points, closed));
+    }
+}
+
+impl OperatorMeta for PolylineNormalsOp {
+    fn category(&self) -> &'static str { "List" }
+    fn category_color(&self) -> [f32; 4] { category_colors::LIST }
+    fn description(&self) -> &'static str { "Per-point unit normals of a polyline" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Points")),
+            1 => Some(PortMeta::new("Closed")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Normals").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new("PolylineLength", "List", "Total length of a polyline"),
+        || capture_meta(PolylineLengthOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("PolylineResample", "List", "Evenly spaced points along a polyline"),
+        || capture_meta(PolylineResampleOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("PointInPolygon", "List", "Test if a point is inside a polygon"),
+        || capture_meta(PointInPolygonOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("PolylineNormals", "List", "Per-point unit normals of a polyline"),
+        || capture_meta(PolylineNormalsOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    fn unit_square() -> Vec<[f32; 2]> {
+        vec![[0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [0.0, 1.0]]
+    }
+
+    #[test]
+    fn test_polyline_length() {
+        let mut op = PolylineLengthOp::new();
+        let ctx = EvalContext::new();
+        op.inputs[0].default = Value::vec2_list(unit_square());
+
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_float(), Some(3.0));
+
+        op.inputs[1].default = Value::Bool(true);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_float(), Some(4.0));
+    }
+
+    #[test]
+    fn test_resample_unit_square() {
+        let mut op = PolylineResampleOp::new();
+        let ctx = EvalContext::new();
+        op.inputs[0].default = Value::vec2_list(unit_square());
+        op.inputs[1].default = Value::Int(8);
+        op.inputs[2].default = Value::Bool(true);
+
+        op.compute(&ctx, &no_connections);
+        let points = op.outputs[0].value.as_vec2_list().unwrap();
+        let expected = [
+            [0.0, 0.0], [0.5, 0.0], [1.0, 0.0], [1.0, 0.5],
+            [1.0, 1.0], [0.5, 1.0], [0.0, 1.0], [0.0, 0.5],
+        ];
+        assert_eq!(points.len(), expected.len());
+        for (p, e) in points.iter().zip(expected) {
+            assert!(distance(*p, e) < 1e-5, "{p:?} != {e:?}");
+        }
+    }
+
+    #[test]
+    fn test_resample_open_includes_endpoints() {
+        let line = [[0.0, 0.0], [1.0, 0.0], [1.0, 0.0], [3.0, 0.0]];
+        let points = resample_polyline(&line, 4, false);
+        assert_eq!(points, vec![[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [3.0, 0.0]]);
+    }
+
+    #[test]
+    fn test_point_in_polygon() {
+        let square = unit_square();
+        assert!(point_in_polygon(&square, [0.5, 0.5]));
+        assert!(!point_in_polygon(&square, [1.5, 0.5]));
+        assert!(!point_in_polygon(&square, [-0.5, 0.0]));
+        // Boundary counts as inside
+        assert!(point_in_polygon(&square, [1.0, 1.0]));
+        assert!(point_in_polygon(&square, [0.0, 0.5]));
+        assert!(!point_in_polygon(&square[..2], [0.5, 0.0]));
+    }
+
+    #[test]
+    fn test_polyline_normals() {
+        let normals = polyline_normals(&[[0.0, 0.0], [1.0, 0.0], [2.0, 0.0]], false);
+        assert_eq!(normals, vec![[0.0, -1.0]; 3]);
+
+        // Counter-clockwise square corners point outward diagonally
+        let corners = polyline_normals(&unit_square(), true);
+        let d = std::f32::consts::FRAC_1_SQRT_2;
+        assert!(distance(corners[0], [-d, -d]) < 1e-5);
+        assert!(distance(corners[2], [d, d]) < 1e-5);
+    }
+
+    #[test]
+    fn test_degenerate_input() {
+        let point = [[2.0, 3.0]];
+        assert_eq!(polyline_length(&point, true), 0.0);
+        assert_eq!(resample_polyline(&point, 3, false), vec![[2.0, 3.0]; 3]);
+        assert_eq!(resample_polyline(&[], 3, false), Vec::<[f32; 2]>::new());
+        assert_eq!(polyline_normals(&[[1.0, 1.0], [1.0, 1.0]], false), vec![[0.0, 0.0]; 2]);
+    }
+}
@@ -34,24 +34,6 @@ fn get_vec3_list(input: &InputPort, get_input: InputResolver) -> Vec<[f32; 3]> {
     }
 }
 
-fn collect_vec3s(input: &InputPort, get_input: InputResolver) -> Vec<[f32; 3]> {
-    if !input.connections.is_empty() {
-        input
-            .connections
-            .iter()
-            .map(|(node_id, output_idx)| {
-                get_input(*node_id, *output_idx).as_vec3().unwrap_or([0.0, 0.0, 0.0])
-            })
-            .collect()
-    } else {
-        match &input.default {
-            Value::Vec3List(list) => list.to_vec(),
-            Value::Vec3(v) => vec![*v],
-            _ => Vec::new(),
-        }
-    }
-}
-
 fn normalize_vec3(v: [f32; 3]) -> [f32; 3] {
     let len = (v[0] * v[0] + v[1] * v[1] + v[2] * v[2]).sqrt();
     if len > 1e-10 {
@@ -97,8 +79,9 @@ impl Operator for Vec3ListOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let values = collect_vec3s(&self.inputs[0], get_input);
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let mut values = self.inputs[0].resolve_multi_vec3s(get_input);
+        values.truncate(ctx.clamp_list_len(values.len()));
         self.outputs[0].value = Value::vec3_list(values);
     }
 }
@@ -341,42 +324,22 @@ impl OperatorMeta for Vec3ListBoundsOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3List",
-            category: "List",
-            description: "Create Vec3 list from values",
-        },
+        RegistryEntry::new("Vec3List", "List", "Create Vec3 list from values"),
         || capture_meta(Vec3ListOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3ListNormalize",
-            category: "List",
-            description: "Normalize all vectors",
-        },
+        RegistryEntry::new("Vec3ListNormalize", "List", "Normalize all vectors"),
         || capture_meta(Vec3ListNormalizeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3ListCentroid",
-            category: "List",
-            description: "Average position (centroid)",
-        },
+        RegistryEntry::new("Vec3ListCentroid", "List", "Average position (centroid)"),
         || capture_meta(Vec3ListCentroidOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3ListBounds",
-            category: "List",
-            description: "Compute bounding box",
-        },
+        RegistryEntry::new("Vec3ListBounds", "List", "Compute bounding box"),
         || capture_meta(Vec3ListBoundsOp::new()),
     );
 }
@@ -1,80 +1,43 @@
 //! Boolean logic operators: And, Or, Not, Xor, All, Any
+//!
+//! The two-input operators are derived and registered with
+//! `#[operator(register)]`; All and Any take multi-inputs and are written by hand.
 
 use std::any::Any;
 
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
 use flux_core::operator::{InputResolver, Operator};
-use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
-use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Value};
+use flux_macros::Operator;
+use crate::registry::{capture_meta, collect, OperatorRegistry, RegistryEntry};
 use flux_core::port::{InputPort, OutputPort};
 
-fn get_bool(input: &InputPort, get_input: InputResolver) -> bool {
-    match input.connection {
-        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_bool().unwrap_or(false),
-        None => input.default.as_bool().unwrap_or(false),
-    }
-}
-
 // ============================================================================
 // And Operator
 // ============================================================================
 
+#[derive(Operator)]
+#[operator(name = "And", category = "Logic", description = "Logical AND of two booleans", register)]
+#[operator(category_color = category_colors::LOGIC)]
+#[allow(dead_code)] // Marker fields describe the ports
 pub struct AndOp {
-    id: Id,
-    inputs: [InputPort; 2],
-    outputs: [OutputPort; 1],
+    _id: Id,
+    _inputs: Vec<InputPort>,
+    _outputs: Vec<OutputPort>,
+    #[input(label = "A", default = false)]
+    a: bool,
+    #[input(label = "B", default = false)]
+    b: bool,
+    #[output(label = "Result")]
+    result: bool,
 }
 
 impl AndOp {
-    pub fn new() -> Self {
-        Self {
-            id: Id::new(),
-            inputs: [InputPort::bool("A", false), InputPort::bool("B", false)],
-            outputs: [OutputPort::bool("Result")],
-        }
-    }
-}
-
-impl Default for AndOp {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Operator for AndOp {
-    fn as_any(&self) -> &dyn Any { self }
-    fn as_any_mut(&mut self) -> &mut dyn Any { self }
-    fn id(&self) -> Id { self.id }
-    fn name(&self) -> &'static str { "And" }
-    fn inputs(&self) -> &[InputPort] { &self.inputs }
-    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
-    fn outputs(&self) -> &[OutputPort] { &self.outputs }
-    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
-
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let a = get_bool(&self.inputs[0], get_input);
-        let b = get_bool(&self.inputs[1], get_input);
-        self.outputs[0].set_bool(a && b);
-    }
-}
-
-impl OperatorMeta for AndOp {
-    fn category(&self) -> &'static str { "Logic" }
-    fn category_color(&self) -> [f32; 4] { category_colors::LOGIC }
-    fn description(&self) -> &'static str { "Logical AND of two booleans" }
-    fn input_meta(&self, index: usize) -> Option<PortMeta> {
-        match index {
-            0 => Some(PortMeta::new("A")),
-            1 => Some(PortMeta::new("B")),
-            _ => None,
-        }
-    }
-    fn output_meta(&self, index: usize) -> Option<PortMeta> {
-        match index {
-            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
-            _ => None,
-        }
+    fn compute_impl(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let a = self.get_a(get_input);
+        let b = self.get_b(get_input);
+        self.set_result(a && b);
     }
 }
 
@@ -82,61 +45,27 @@ impl OperatorMeta for AndOp {
 // Or Operator
 // ============================================================================
 
+#[derive(Operator)]
+#[operator(name = "Or", category = "Logic", description = "Logical OR of two booleans", register)]
+#[operator(category_color = category_colors::LOGIC)]
+#[allow(dead_code)] // Marker fields describe the ports
 pub struct OrOp {
-    id: Id,
-    inputs: [InputPort; 2],
-    outputs: [OutputPort; 1],
+    _id: Id,
+    _inputs: Vec<InputPort>,
+    _outputs: Vec<OutputPort>,
+    #[input(label = "A", default = false)]
+    a: bool,
+    #[input(label = "B", default = false)]
+    b: bool,
+    #[output(label = "Result")]
+    result: bool,
 }
 
 impl OrOp {
-    pub fn new() -> Self {
-        Self {
-            id: Id::new(),
-            inputs: [InputPort::bool("A", false), InputPort::bool("B", false)],
-            outputs: [OutputPort::bool("Result")],
-        }
-    }
-}
-
-impl Default for OrOp {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Operator for OrOp {
-    fn as_any(&self) -> &dyn Any { self }
-    fn as_any_mut(&mut self) -> &mut dyn Any { self }
-    fn id(&self) -> Id { self.id }
-    fn name(&self) -> &'static str { "Or" }
-    fn inputs(&self) -> &[InputPort] { &self.inputs }
-    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
-    fn outputs(&self) -> &[OutputPort] { &self.outputs }
-    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
-
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let a = get_bool(&self.inputs[0], get_input);
-        let b = get_bool(&self.inputs[1], get_input);
-        self.outputs[0].set_bool(a || b);
-    }
-}
-
-impl OperatorMeta for OrOp {
-    fn category(&self) -> &'static str { "Logic" }
-    fn category_color(&self) -> [f32; 4] { category_colors::LOGIC }
-    fn description(&self) -> &'static str { "Logical OR of two booleans" }
-    fn input_meta(&self, index: usize) -> Option<PortMeta> {
-        match index {
-            0 => Some(PortMeta::new("A")),
-            1 => Some(PortMeta::new("B")),
-            _ => None,
-        }
-    }
-    fn output_meta(&self, index: usize) -> Option<PortMeta> {
-        match index {
-            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
-            _ => None,
-        }
+    fn compute_impl(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let a = self.get_a(get_input);
+        let b = self.get_b(get_input);
+        self.set_result(a || b);
     }
 }
 
@@ -144,59 +73,24 @@ impl OperatorMeta for OrOp {
 // Not Operator
 // ============================================================================
 
+#[derive(Operator)]
+#[operator(name = "Not", category = "Logic", description = "Logical NOT", register)]
+#[operator(category_color = category_colors::LOGIC)]
+#[allow(dead_code)] // Marker fields describe the ports
 pub struct NotOp {
-    id: Id,
-    inputs: [InputPort; 1],
-    outputs: [OutputPort; 1],
+    _id: Id,
+    _inputs: Vec<InputPort>,
+    _outputs: Vec<OutputPort>,
+    #[input(label = "Value", default = false)]
+    value: bool,
+    #[output(label = "Result")]
+    result: bool,
 }
 
 impl NotOp {
-    pub fn new() -> Self {
-        Self {
-            id: Id::new(),
-            inputs: [InputPort::bool("Value", false)],
-            outputs: [OutputPort::bool("Result")],
-        }
-    }
-}
-
-impl Default for NotOp {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Operator for NotOp {
-    fn as_any(&self) -> &dyn Any { self }
-    fn as_any_mut(&mut self) -> &mut dyn Any { self }
-    fn id(&self) -> Id { self.id }
-    fn name(&self) -> &'static str { "Not" }
-    fn inputs(&self) -> &[InputPort] { &self.inputs }
-    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
-    fn outputs(&self) -> &[OutputPort] { &self.outputs }
-    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
-
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let value = get_bool(&self.inputs[0], get_input);
-        self.outputs[0].set_bool(!value);
-    }
-}
-
-impl OperatorMeta for NotOp {
-    fn category(&self) -> &'static str { "Logic" }
-    fn category_color(&self) -> [f32; 4] { category_colors::LOGIC }
-    fn description(&self) -> &'static str { "Logical NOT" }
-    fn input_meta(&self, index: usize) -> Option<PortMeta> {
-        match index {
-            0 => Some(PortMeta::new("Value")),
-            _ => None,
-        }
-    }
-    fn output_meta(&self, index: usize) -> Option<PortMeta> {
-        match index {
-            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
-            _ => None,
-        }
+    fn compute_impl(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let value = self.get_value(get_input);
+        self.set_result(!value);
     }
 }
 
@@ -204,61 +98,27 @@ impl OperatorMeta for NotOp {
 // Xor Operator
 // ============================================================================
 
+#[derive(Operator)]
+#[operator(name = "Xor", category = "Logic", description = "Exclusive OR", register)]
+#[operator(category_color = category_colors::LOGIC)]
+#[allow(dead_code)] // Marker fields describe the ports
 pub struct XorOp {
-    id: Id,
-    inputs: [InputPort; 2],
-    outputs: [OutputPort; 1],
+    _id: Id,
+    _inputs: Vec<InputPort>,
+    _outputs: Vec<OutputPort>,
+    #[input(label = "A", default = false)]
+    a: bool,
+    #[input(label = "B", default = false)]
+    b: bool,
+    #[output(label = "Result")]
+    result: bool,
 }
 
 impl XorOp {
-    pub fn new() -> Self {
-        Self {
-            id: Id::new(),
-            inputs: [InputPort::bool("A", false), InputPort::bool("B", false)],
-            outputs: [OutputPort::bool("Result")],
-        }
-    }
-}
-
-impl Default for XorOp {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-impl Operator for XorOp {
-    fn as_any(&self) -> &dyn Any { self }
-    fn as_any_mut(&mut self) -> &mut dyn Any { self }
-    fn id(&self) -> Id { self.id }
-    fn name(&self) -> &'static str { "Xor" }
-    fn inputs(&self) -> &[InputPort] { &self.inputs }
-    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
-    fn outputs(&self) -> &[OutputPort] { &self.outputs }
-    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
-
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
-        let a = get_bool(&self.inputs[0], get_input);
-        let b = get_bool(&self.inputs[1], get_input);
-        self.outputs[0].set_bool(a ^ b);
-    }
-}
-
-impl OperatorMeta for XorOp {
-    fn category(&self) -> &'static str { "Logic" }
-    fn category_color(&self) -> [f32; 4] { category_colors::LOGIC }
-    fn description(&self) -> &'static str { "Exclusive OR" }
-    fn input_meta(&self, index: usize) -> Option<PortMeta> {
-        match index {
-            0 => Some(PortMeta::new("A")),
-            1 => Some(PortMeta::new("B")),
-            _ => None,
-        }
-    }
-    fn output_meta(&self, index: usize) -> Option<PortMeta> {
-        match index {
-            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
-            _ => None,
-        }
+    fn compute_impl(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let a = self.get_a(get_input);
+        let b = self.get_b(get_input);
+        self.set_result(a ^ b);
     }
 }
 
@@ -307,12 +167,7 @@ impl Operator for AllOp {
             return;
         }
 
-        let result = input
-            .connections
-            .iter()
-            .all(|&(node_id, output_idx)| {
-                get_input(node_id, output_idx).as_bool().unwrap_or(false)
-            });
+        let result = input.resolve_multi_bools(get_input).into_iter().all(|value| value);
 
         self.outputs[0].set_bool(result);
     }
@@ -381,12 +236,7 @@ impl Operator for AnyOp {
             return;
         }
 
-        let result = input
-            .connections
-            .iter()
-            .any(|&(node_id, output_idx)| {
-                get_input(node_id, output_idx).as_bool().unwrap_or(false)
-            });
+        let result = input.resolve_multi_bools(get_input).into_iter().any(|value| value);
 
         self.outputs[0].set_bool(result);
     }
@@ -415,63 +265,15 @@ impl OperatorMeta for AnyOp {
 // ============================================================================
 
 pub fn register(registry: &OperatorRegistry) {
-    registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "And",
-            category: "Logic",
-            description: "Logical AND of two booleans",
-        },
-        || capture_meta(AndOp::new()),
-    );
-
-    registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Or",
-            category: "Logic",
-            description: "Logical OR of two booleans",
-        },
-        || capture_meta(OrOp::new()),
-    );
-
-    registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Not",
-            category: "Logic",
-            description: "Logical NOT",
-        },
-        || capture_meta(NotOp::new()),
-    );
-
-    registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Xor",
-            category: "Logic",
-            description: "Exclusive OR",
-        },
-        || capture_meta(XorOp::new()),
-    );
+    collect!(registry, register_and_op, register_or_op, register_not_op, register_xor_op);
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "All",
-            category: "Logic",
-            description: "True if all inputs are true",
-        },
+        RegistryEntry::new("All", "Logic", "True if all inputs are true"),
         || capture_meta(AllOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Any",
-            category: "Logic",
-            description: "True if any input is true",
-        },
+        RegistryEntry::new("Any", "Logic", "True if any input is true"),
         || capture_meta(AnyOp::new()),
     );
 }
@@ -479,7 +281,6 @@ pub fn register(registry: &OperatorRegistry) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use flux_core::Value;
 
     fn no_connections(_: Id, _: usize) -> Value {
         Value::Bool(false)
@@ -490,14 +291,14 @@ mod tests {
         let mut op = AndOp::new();
         let ctx = EvalContext::new();
 
-        op.inputs[0].default = Value::Bool(true);
-        op.inputs[1].default = Value::Bool(true);
+        op.inputs_mut()[0].default = Value::Bool(true);
+        op.inputs_mut()[1].default = Value::Bool(true);
         op.compute(&ctx, &no_connections);
-        assert_eq!(op.outputs[0].value.as_bool(), Some(true));
+        assert_eq!(op.outputs()[0].value.as_bool(), Some(true));
 
-        op.inputs[1].default = Value::Bool(false);
+        op.inputs_mut()[1].default = Value::Bool(false);
         op.compute(&ctx, &no_connections);
-        assert_eq!(op.outputs[0].value.as_bool(), Some(false));
+        assert_eq!(op.outputs()[0].value.as_bool(), Some(false));
     }
 
     #[test]
@@ -505,14 +306,14 @@ mod tests {
         let mut op = OrOp::new();
         let ctx = EvalContext::new();
 
-        op.inputs[0].default = Value::Bool(false);
-        op.inputs[1].default = Value::Bool(true);
+        op.inputs_mut()[0].default = Value::Bool(false);
+        op.inputs_mut()[1].default = Value::Bool(true);
         op.compute(&ctx, &no_connections);
-        assert_eq!(op.outputs[0].value.as_bool(), Some(true));
+        assert_eq!(op.outputs()[0].value.as_bool(), Some(true));
 
-        op.inputs[1].default = Value::Bool(false);
+        op.inputs_mut()[1].default = Value::Bool(false);
         op.compute(&ctx, &no_connections);
-        assert_eq!(op.outputs[0].value.as_bool(), Some(false));
+        assert_eq!(op.outputs()[0].value.as_bool(), Some(false));
     }
 
     #[test]
@@ -520,13 +321,13 @@ mod tests {
         let mut op = NotOp::new();
         let ctx = EvalContext::new();
 
-        op.inputs[0].default = Value::Bool(true);
+        op.inputs_mut()[0].default = Value::Bool(true);
         op.compute(&ctx, &no_connections);
-        assert_eq!(op.outputs[0].value.as_bool(), Some(false));
+        assert_eq!(op.outputs()[0].value.as_bool(), Some(false));
 
-        op.inputs[0].default = Value::Bool(false);
+        op.inputs_mut()[0].default = Value::Bool(false);
         op.compute(&ctx, &no_connections);
-        assert_eq!(op.outputs[0].value.as_bool(), Some(true));
+        assert_eq!(op.outputs()[0].value.as_bool(), Some(true));
     }
 
     #[test]
@@ -534,13 +335,13 @@ mod tests {
         let mut op = XorOp::new();
         let ctx = EvalContext::new();
 
-        op.inputs[0].default = Value::Bool(true);
-        op.inputs[1].default = Value::Bool(false);
+        op.inputs_mut()[0].default = Value::Bool(true);
+        op.inputs_mut()[1].default = Value::Bool(false);
         op.compute(&ctx, &no_connections);
-        assert_eq!(op.outputs[0].value.as_bool(), Some(true));
+        assert_eq!(op.outputs()[0].value.as_bool(), Some(true));
 
-        op.inputs[1].default = Value::Bool(true);
+        op.inputs_mut()[1].default = Value::Bool(true);
         op.compute(&ctx, &no_connections);
-        assert_eq!(op.outputs[0].value.as_bool(), Some(false));
+        assert_eq!(op.outputs()[0].value.as_bool(), Some(false));
     }
 }
@@ -0,0 +1,260 @@
+//! Polymorphic equality operator: Equals
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::{category_colors, OperatorError, OperatorMeta, PinShape, PortMeta, Value, ValueType};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort};
+
+fn get_value(input: &InputPort, get_input: InputResolver) -> Value {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx),
+        None => input.default.clone(),
+    }
+}
+
+fn get_float(input: &InputPort, get_input: InputResolver) -> f32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float().unwrap_or(0.0),
+        None => input.default.as_float().unwrap_or(0.0),
+    }
+}
+
+fn floats_equal(a: &[f32], b: &[f32], epsilon: f32) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(x, y)| (x - y).abs() <= epsilon)
+}
+
+/// Compare two values of the same type.
+///
+/// Float-based values (floats, vectors, colors, matrices) compare
+/// component-wise within `epsilon`; everything else compares exactly.
+/// Lists must have the same length; maps must have the same keys with equal
+/// values. Returns `None` if the types differ.
+fn same_type_equal(a: &Value, b: &Value, epsilon: f32) -> Option<bool> {
+    let equal = match (a, b) {
+        (Value::Float(a), Value::Float(b)) => (a - b).abs() <= epsilon,
+        (Value::Int(a), Value::Int(b)) => a == b,
+        (Value::Bool(a), Value::Bool(b)) => a == b,
+        (Value::Vec2(a), Value::Vec2(b)) => floats_equal(a, b, epsilon),
+        (Value::Vec3(a), Value::Vec3(b)) => floats_equal(a, b, epsilon),
+        (Value::Vec4(a), Value::Vec4(b)) => floats_equal(a, b, epsilon),
+        (Value::String(a), Value::String(b)) => a == b,
+        (Value::Color(a), Value::Color(b)) => floats_equal(&a.to_array(), &b.to_array(), epsilon),
+        (Value::Gradient(a), Value::Gradient(b)) => a == b,
+        (Value::Matrix4(a), Value::Matrix4(b)) => floats_equal(&a.to_array(), &b.to_array(), epsilon),
+        (Value::FloatList(a), Value::FloatList(b)) => floats_equal(a, b, epsilon),
+        (Value::IntList(a), Value::IntList(b)) => a == b,
+        (Value::BoolList(a), Value::BoolList(b)) => a == b,
+        (Value::Vec2List(a), Value::Vec2List(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| floats_equal(x, y, epsilon))
+        }
+        (Value::Vec3List(a), Value::Vec3List(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| floats_equal(x, y, epsilon))
+        }
+        (Value::Vec4List(a), Value::Vec4List(b)) => {
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(x, y)| floats_equal(x, y, epsilon))
+        }
+        (Value::ColorList(a), Value::ColorList(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b.iter())
+                    .all(|(x, y)| floats_equal(&x.to_array(), &y.to_array(), epsilon))
+        }
+        (Value::StringList(a), Value::StringList(b)) => a == b,
+        (Value::Map(a), Value::Map(b)) => {
+            a.len() == b.len()
+                && a.iter().zip(b.iter()).all(|((ka, va), (kb, vb))| {
+                    ka == kb && same_type_equal(va, vb, epsilon) == Some(true)
+                })
+        }
+        _ => return None,
+    };
+    Some(equal)
+}
+
+/// Compare two values for equality, coercing B to A's type (or A to B's) if
+/// the types differ.
+///
+/// Strings never take part in coercion, so `"1"` and `1` are incomparable
+/// rather than equal. Returns `None` if the values cannot be compared.
+pub fn values_equal(a: &Value, b: &Value, epsilon: f32) -> Option<bool> {
+    let (ta, tb) = (a.value_type(), b.value_type());
+    if ta == tb {
+        return same_type_equal(a, b, epsilon);
+    }
+    if ta == ValueType::String || tb == ValueType::String {
+        return None;
+    }
+    if let Some(b) = b.coerce_to(ta) {
+        return same_type_equal(a, &b, epsilon);
+    }
+    a.coerce_to(tb).and_then(|a| same_type_equal(&a, b, epsilon))
+}
+
+// ============================================================================
+// Equals Operator
+// ============================================================================
+
+pub struct EqualsOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl EqualsOp {
+    /// Default component tolerance for float-based values.
+    pub const DEFAULT_EPSILON: f32 = 1e-6;
+
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::any("A", Value::Float(0.0)),
+                InputPort::any("B", Value::Float(0.0)),
+                InputPort::float("Epsilon", Self::DEFAULT_EPSILON),
+            ],
+            outputs: [OutputPort::bool("Result")],
+        }
+    }
+}
+
+impl Default for EqualsOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for EqualsOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "Equals" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let a = get_value(&self.inputs[0], get_input);
+        let b = get_value(&self.inputs[1], get_input);
+        let epsilon = get_float(&self.inputs[2], get_input).abs();
+
+        let result = match values_equal(&a, &b, epsilon) {
+            Some(equal) => equal,
+            None => {
+                ctx.report_error(OperatorError::type_mismatch(a.value_type(), b.value_type()));
+                false
+            }
+        };
+        self.outputs[0].set_bool(result);
+    }
+}
+
+impl OperatorMeta for EqualsOp {
+    fn category(&self) -> &'static str { "Logic" }
+    fn category_color(&self) -> [f32; 4] { category_colors::LOGIC }
+    fn description(&self) -> &'static str { "True if A equals B (floats compare within Epsilon)" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("A")),
+            1 => Some(PortMeta::new("B")),
+            2 => Some(PortMeta::new("Epsilon").with_range(0.0, 1.0)),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new("Equals", "Logic", "True if A equals B (floats compare within Epsilon)"),
+        || capture_meta(EqualsOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    fn equals(op: &mut EqualsOp, a: Value, b: Value) -> (bool, Vec<OperatorError>) {
+        let ctx = EvalContext::new();
+        op.inputs[0].default = a;
+        op.inputs[1].default = b;
+        op.compute(&ctx, &no_connections);
+        (op.outputs[0].value.as_bool().unwrap(), ctx.take_errors())
+    }
+
+    #[test]
+    fn test_float_within_default_epsilon() {
+        let mut op = EqualsOp::new();
+        let (eq, errors) = equals(&mut op, Value::Float(0.1 + 0.2), Value::Float(0.3));
+        assert!(eq);
+        assert!(errors.is_empty());
+
+        let (eq, _) = equals(&mut op, Value::Float(0.3), Value::Float(0.31));
+        assert!(!eq);
+
+        // Int coerces to float
+        let (eq, errors) = equals(&mut op, Value::Float(2.0), Value::Int(2));
+        assert!(eq);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_vec3() {
+        let mut op = EqualsOp::new();
+        let (eq, _) = equals(&mut op, Value::Vec3([1.0, 2.0, 3.0]), Value::Vec3([1.0, 2.0, 3.0 + 1e-7]));
+        assert!(eq);
+
+        let (eq, _) = equals(&mut op, Value::Vec3([1.0, 2.0, 3.0]), Value::Vec3([1.0, 2.5, 3.0]));
+        assert!(!eq);
+
+        op.inputs[2].default = Value::Float(0.5);
+        let (eq, _) = equals(&mut op, Value::Vec3([1.0, 2.0, 3.0]), Value::Vec3([1.0, 2.5, 3.0]));
+        assert!(eq);
+    }
+
+    #[test]
+    fn test_strings_and_lists() {
+        let mut op = EqualsOp::new();
+        let (eq, _) = equals(&mut op, Value::String("flux".into()), Value::String("flux".into()));
+        assert!(eq);
+        let (eq, _) = equals(&mut op, Value::String("flux".into()), Value::String("Flux".into()));
+        assert!(!eq);
+
+        let (eq, _) = equals(&mut op, Value::float_list(vec![1.0, 2.0]), Value::float_list(vec![1.0, 2.0]));
+        assert!(eq);
+        let (eq, _) = equals(&mut op, Value::float_list(vec![1.0, 2.0]), Value::float_list(vec![1.0]));
+        assert!(!eq);
+    }
+
+    #[test]
+    fn test_mismatched_types_report_error() {
+        let mut op = EqualsOp::new();
+        let (eq, errors) = equals(&mut op, Value::String("1".into()), Value::Vec3([1.0, 1.0, 1.0]));
+        assert!(!eq);
+        assert!(matches!(
+            errors.as_slice(),
+            [OperatorError::TypeMismatch {
+                expected: ValueType::String,
+                actual: ValueType::Vec3
+            }]
+        ));
+    }
+}
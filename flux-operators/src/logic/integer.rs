@@ -1,8 +1,15 @@
 //! Integer operators: IntAdd, IntMultiply, IntDivide, IntModulo, IntClamp, IntToFloat
+//!
+//! Arithmetic never panics. IntAdd, IntMultiply and IntDivide take a Mode
+//! input choosing what happens when a result leaves the `i32` range (see
+//! [`IntOverflow`]). Division or modulo by zero always outputs 0 and reports
+//! an error. `i32::MIN % -1` is 0 in every mode, since the remainder itself
+//! can't overflow.
 
 use std::any::Any;
 
 use flux_core::context::EvalContext;
+use flux_core::error::OperatorError;
 use flux_core::id::Id;
 use flux_core::operator::{InputResolver, Operator};
 use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
@@ -16,13 +23,117 @@ fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
     }
 }
 
+// ============================================================================
+// Overflow Handling
+// ============================================================================
+
+/// How integer operators handle results outside the `i32` range
+///
+/// Selected through an Int "Mode" input: 0 = Wrap, 1 = Saturate, 2 = Checked.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum IntOverflow {
+    /// Two's complement wrap-around (`i32::MAX + 1 == i32::MIN`)
+    #[default]
+    Wrap,
+    /// Clamp to `i32::MIN`/`i32::MAX`
+    Saturate,
+    /// Output 0 and report an error
+    Checked,
+}
+
+impl IntOverflow {
+    /// Mode for a Mode input value (unknown values wrap)
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => IntOverflow::Saturate,
+            2 => IntOverflow::Checked,
+            _ => IntOverflow::Wrap,
+        }
+    }
+
+    /// Mode input value for this mode
+    pub fn index(self) -> i32 {
+        self as i32
+    }
+
+    fn apply(
+        self,
+        op: &str,
+        checked: Option<i32>,
+        wrapping: i32,
+        saturating: i32,
+    ) -> Result<i32, OperatorError> {
+        match (self, checked) {
+            (_, Some(result)) => Ok(result),
+            (IntOverflow::Wrap, None) => Ok(wrapping),
+            (IntOverflow::Saturate, None) => Ok(saturating),
+            (IntOverflow::Checked, None) => Err(OperatorError::InvalidValue {
+                message: format!("integer overflow in {}", op),
+            }),
+        }
+    }
+}
+
+fn division_by_zero() -> OperatorError {
+    OperatorError::InvalidValue {
+        message: "integer division by zero".to_string(),
+    }
+}
+
+/// `a + b` under the given overflow mode
+pub fn int_add(a: i32, b: i32, mode: IntOverflow) -> Result<i32, OperatorError> {
+    mode.apply("addition", a.checked_add(b), a.wrapping_add(b), a.saturating_add(b))
+}
+
+/// `a * b` under the given overflow mode
+pub fn int_mul(a: i32, b: i32, mode: IntOverflow) -> Result<i32, OperatorError> {
+    mode.apply("multiplication", a.checked_mul(b), a.wrapping_mul(b), a.saturating_mul(b))
+}
+
+/// `a / b` (truncating) under the given overflow mode
+///
+/// Only `i32::MIN / -1` overflows. Division by zero is an error in every mode.
+pub fn int_div(a: i32, b: i32, mode: IntOverflow) -> Result<i32, OperatorError> {
+    if b == 0 {
+        return Err(division_by_zero());
+    }
+    mode.apply("division", a.checked_div(b), a.wrapping_div(b), a.saturating_div(b))
+}
+
+/// `a % b` (sign follows `a`); `i32::MIN % -1` is 0
+///
+/// Modulo by zero is an error.
+pub fn int_rem(a: i32, b: i32) -> Result<i32, OperatorError> {
+    if b == 0 {
+        return Err(division_by_zero());
+    }
+    Ok(a.wrapping_rem(b))
+}
+
+/// Unwrap an integer result, reporting errors and outputting 0 instead
+fn int_or_report(ctx: &EvalContext, result: Result<i32, OperatorError>) -> i32 {
+    result.unwrap_or_else(|error| {
+        ctx.report_error(error);
+        0
+    })
+}
+
+fn get_mode(input: &InputPort, get_input: InputResolver) -> IntOverflow {
+    IntOverflow::from_index(get_int(input, get_input))
+}
+
+fn mode_meta() -> PortMeta {
+    // 0=Wrap, 1=Saturate, 2=Checked
+    PortMeta::new("Mode").with_range(0.0, 2.0).with_step(1.0)
+}
+
 // ============================================================================
 // IntAdd Operator
 // ============================================================================
 
 pub struct IntAddOp {
     id: Id,
-    inputs: [InputPort; 2],
+    inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
 }
 
@@ -30,10 +141,21 @@ impl IntAddOp {
     pub fn new() -> Self {
         Self {
             id: Id::new(),
-            inputs: [InputPort::int("A", 0), InputPort::int("B", 0)],
+            inputs: [
+                InputPort::int("A", 0),
+                InputPort::int("B", 0),
+                InputPort::int("Mode", IntOverflow::Wrap.index()),
+            ],
             outputs: [OutputPort::int("Result")],
         }
     }
+
+    /// Create with the given overflow mode as the Mode default
+    pub fn with_mode(mode: IntOverflow) -> Self {
+        let mut op = Self::new();
+        op.inputs[2].default = flux_core::Value::Int(mode.index());
+        op
+    }
 }
 
 impl Default for IntAddOp {
@@ -52,10 +174,11 @@ impl Operator for IntAddOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let a = get_int(&self.inputs[0], get_input);
         let b = get_int(&self.inputs[1], get_input);
-        self.outputs[0].set_int(a.wrapping_add(b));
+        let mode = get_mode(&self.inputs[2], get_input);
+        self.outputs[0].set_int(int_or_report(ctx, int_add(a, b, mode)));
     }
 }
 
@@ -67,6 +190,7 @@ impl OperatorMeta for IntAddOp {
         match index {
             0 => Some(PortMeta::new("A")),
             1 => Some(PortMeta::new("B")),
+            2 => Some(mode_meta()),
             _ => None,
         }
     }
@@ -84,7 +208,7 @@ impl OperatorMeta for IntAddOp {
 
 pub struct IntMultiplyOp {
     id: Id,
-    inputs: [InputPort; 2],
+    inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
 }
 
@@ -92,10 +216,21 @@ impl IntMultiplyOp {
     pub fn new() -> Self {
         Self {
             id: Id::new(),
-            inputs: [InputPort::int("A", 0), InputPort::int("B", 1)],
+            inputs: [
+                InputPort::int("A", 0),
+                InputPort::int("B", 1),
+                InputPort::int("Mode", IntOverflow::Wrap.index()),
+            ],
             outputs: [OutputPort::int("Result")],
         }
     }
+
+    /// Create with the given overflow mode as the Mode default
+    pub fn with_mode(mode: IntOverflow) -> Self {
+        let mut op = Self::new();
+        op.inputs[2].default = flux_core::Value::Int(mode.index());
+        op
+    }
 }
 
 impl Default for IntMultiplyOp {
@@ -114,10 +249,11 @@ impl Operator for IntMultiplyOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let a = get_int(&self.inputs[0], get_input);
         let b = get_int(&self.inputs[1], get_input);
-        self.outputs[0].set_int(a.wrapping_mul(b));
+        let mode = get_mode(&self.inputs[2], get_input);
+        self.outputs[0].set_int(int_or_report(ctx, int_mul(a, b, mode)));
     }
 }
 
@@ -129,6 +265,7 @@ impl OperatorMeta for IntMultiplyOp {
         match index {
             0 => Some(PortMeta::new("A")),
             1 => Some(PortMeta::new("B")),
+            2 => Some(mode_meta()),
             _ => None,
         }
     }
@@ -146,7 +283,7 @@ impl OperatorMeta for IntMultiplyOp {
 
 pub struct IntDivideOp {
     id: Id,
-    inputs: [InputPort; 2],
+    inputs: [InputPort; 3],
     outputs: [OutputPort; 1],
 }
 
@@ -154,10 +291,21 @@ impl IntDivideOp {
     pub fn new() -> Self {
         Self {
             id: Id::new(),
-            inputs: [InputPort::int("A", 0), InputPort::int("B", 1)],
+            inputs: [
+                InputPort::int("A", 0),
+                InputPort::int("B", 1),
+                InputPort::int("Mode", IntOverflow::Wrap.index()),
+            ],
             outputs: [OutputPort::int("Result")],
         }
     }
+
+    /// Create with the given overflow mode as the Mode default
+    pub fn with_mode(mode: IntOverflow) -> Self {
+        let mut op = Self::new();
+        op.inputs[2].default = flux_core::Value::Int(mode.index());
+        op
+    }
 }
 
 impl Default for IntDivideOp {
@@ -176,12 +324,11 @@ impl Operator for IntDivideOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let a = get_int(&self.inputs[0], get_input);
         let b = get_int(&self.inputs[1], get_input);
-        // Division by zero returns 0
-        let result = if b == 0 { 0 } else { a / b };
-        self.outputs[0].set_int(result);
+        let mode = get_mode(&self.inputs[2], get_input);
+        self.outputs[0].set_int(int_or_report(ctx, int_div(a, b, mode)));
     }
 }
 
@@ -193,6 +340,7 @@ impl OperatorMeta for IntDivideOp {
         match index {
             0 => Some(PortMeta::new("A")),
             1 => Some(PortMeta::new("B")),
+            2 => Some(mode_meta()),
             _ => None,
         }
     }
@@ -240,11 +388,10 @@ impl Operator for IntModuloOp {
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
         let a = get_int(&self.inputs[0], get_input);
         let b = get_int(&self.inputs[1], get_input);
-        let result = if b == 0 { 0 } else { a % b };
-        self.outputs[0].set_int(result);
+        self.outputs[0].set_int(int_or_report(ctx, int_rem(a, b)));
     }
 }
 
@@ -311,7 +458,8 @@ impl Operator for IntClampOp {
         let value = get_int(&self.inputs[0], get_input);
         let min = get_int(&self.inputs[1], get_input);
         let max = get_int(&self.inputs[2], get_input);
-        self.outputs[0].set_int(value.clamp(min, max));
+        // Max wins when the bounds are inverted, rather than panicking
+        self.outputs[0].set_int(value.max(min).min(max));
     }
 }
 
@@ -401,62 +549,32 @@ impl OperatorMeta for IntToFloatOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntAdd",
-            category: "Logic",
-            description: "Integer addition",
-        },
+        RegistryEntry::new("IntAdd", "Logic", "Integer addition"),
         || capture_meta(IntAddOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntMultiply",
-            category: "Logic",
-            description: "Integer multiplication",
-        },
+        RegistryEntry::new("IntMultiply", "Logic", "Integer multiplication"),
         || capture_meta(IntMultiplyOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntDivide",
-            category: "Logic",
-            description: "Integer division",
-        },
+        RegistryEntry::new("IntDivide", "Logic", "Integer division"),
         || capture_meta(IntDivideOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntModulo",
-            category: "Logic",
-            description: "Integer modulo",
-        },
+        RegistryEntry::new("IntModulo", "Logic", "Integer modulo"),
         || capture_meta(IntModuloOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntClamp",
-            category: "Logic",
-            description: "Clamp integer to range",
-        },
+        RegistryEntry::new("IntClamp", "Logic", "Clamp integer to range"),
         || capture_meta(IntClampOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntToFloat",
-            category: "Logic",
-            description: "Convert integer to float",
-        },
+        RegistryEntry::new("IntToFloat", "Logic", "Convert integer to float"),
         || capture_meta(IntToFloatOp::new()),
     );
 }
@@ -508,6 +626,93 @@ mod tests {
         let ctx = EvalContext::new();
         op.compute(&ctx, &no_connections);
         assert_eq!(op.outputs[0].value.as_int(), Some(0));
+        assert_eq!(ctx.take_errors().len(), 1);
+    }
+
+    const MODES: [IntOverflow; 3] = [IntOverflow::Wrap, IntOverflow::Saturate, IntOverflow::Checked];
+
+    #[test]
+    fn test_overflow_modes() {
+        use IntOverflow::*;
+        const MIN: i32 = i32::MIN;
+        const MAX: i32 = i32::MAX;
+
+        assert_eq!(int_add(MAX, 1, Wrap).unwrap(), MIN);
+        assert_eq!(int_add(MAX, 1, Saturate).unwrap(), MAX);
+        assert!(int_add(MAX, 1, Checked).is_err());
+        assert_eq!(int_add(MIN, -1, Wrap).unwrap(), MAX);
+        assert_eq!(int_add(MIN, -1, Saturate).unwrap(), MIN);
+        assert!(int_add(MIN, -1, Checked).is_err());
+
+        assert_eq!(int_mul(MAX, 2, Wrap).unwrap(), -2);
+        assert_eq!(int_mul(MAX, 2, Saturate).unwrap(), MAX);
+        assert!(int_mul(MAX, 2, Checked).is_err());
+        assert_eq!(int_mul(MIN, -1, Wrap).unwrap(), MIN);
+        assert_eq!(int_mul(MIN, -1, Saturate).unwrap(), MAX);
+        assert!(int_mul(MIN, -1, Checked).is_err());
+
+        assert_eq!(int_div(MIN, -1, Wrap).unwrap(), MIN);
+        assert_eq!(int_div(MIN, -1, Saturate).unwrap(), MAX);
+        assert!(int_div(MIN, -1, Checked).is_err());
+
+        // In-range results are identical in every mode
+        for mode in MODES {
+            assert_eq!(int_add(MIN, MAX, mode).unwrap(), -1);
+            assert_eq!(int_mul(MIN, 1, mode).unwrap(), MIN);
+            assert_eq!(int_mul(MAX, -1, mode).unwrap(), -MAX);
+            assert_eq!(int_mul(MIN, 0, mode).unwrap(), 0);
+            assert_eq!(int_div(MAX, -1, mode).unwrap(), -MAX);
+            assert_eq!(int_div(-7, 2, mode).unwrap(), -3);
+            assert_eq!(int_div(0, MIN, mode).unwrap(), 0);
+            assert!(int_div(MIN, 0, mode).is_err());
+            assert!(int_div(0, 0, mode).is_err());
+        }
+
+        assert_eq!(int_rem(MIN, -1).unwrap(), 0);
+        assert_eq!(int_rem(MAX, -1).unwrap(), 0);
+        assert_eq!(int_rem(-7, 2).unwrap(), -1);
+        assert_eq!(int_rem(MIN, MAX).unwrap(), -1);
+        assert!(int_rem(MIN, 0).is_err());
+    }
+
+    #[test]
+    fn test_mode_input() {
+        for (index, mode) in MODES.iter().enumerate() {
+            assert_eq!(IntOverflow::from_index(index as i32), *mode);
+            assert_eq!(mode.index(), index as i32);
+        }
+        assert_eq!(IntOverflow::from_index(-1), IntOverflow::Wrap);
+
+        let mut op = IntAddOp::with_mode(IntOverflow::Saturate);
+        op.inputs[0].default = Value::Int(i32::MAX);
+        op.inputs[1].default = Value::Int(10);
+        let ctx = EvalContext::new();
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_int(), Some(i32::MAX));
+        assert!(ctx.take_errors().is_empty());
+
+        let mut op = IntDivideOp::with_mode(IntOverflow::Checked);
+        op.inputs[0].default = Value::Int(i32::MIN);
+        op.inputs[1].default = Value::Int(-1);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_int(), Some(0));
+        assert_eq!(ctx.take_errors().len(), 1);
+    }
+
+    #[test]
+    fn test_int_modulo_edge_cases() {
+        let mut op = IntModuloOp::new();
+        op.inputs[0].default = Value::Int(i32::MIN);
+        op.inputs[1].default = Value::Int(-1);
+        let ctx = EvalContext::new();
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_int(), Some(0));
+        assert!(ctx.take_errors().is_empty());
+
+        op.inputs[1].default = Value::Int(0);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_int(), Some(0));
+        assert_eq!(ctx.take_errors().len(), 1);
     }
 
     #[test]
@@ -529,6 +734,12 @@ mod tests {
         let ctx = EvalContext::new();
         op.compute(&ctx, &no_connections);
         assert_eq!(op.outputs[0].value.as_int(), Some(100));
+
+        // Inverted bounds don't panic
+        op.inputs[1].default = Value::Int(10);
+        op.inputs[2].default = Value::Int(-10);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_int(), Some(-10));
     }
 
     #[test]
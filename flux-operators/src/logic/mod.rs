@@ -1,17 +1,21 @@
-//! Logic and Integer operators (12 total)
+//! Logic and Integer operators (13 total)
 //!
 //! - Boolean (6): And, Or, Not, Xor, All, Any
+//! - Equality (1): Equals
 //! - Integer (6): IntAdd, IntMultiply, IntDivide, IntModulo, IntClamp, IntToFloat
 
 mod boolean;
+mod equality;
 mod integer;
 
 pub use boolean::*;
+pub use equality::*;
 pub use integer::*;
 
 use crate::registry::OperatorRegistry;
 
 pub fn register_all(registry: &OperatorRegistry) {
     boolean::register(registry);
+    equality::register(registry);
     integer::register(registry);
 }
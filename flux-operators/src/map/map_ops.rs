@@ -0,0 +1,480 @@
+//! Map operators: MakeMap, GetField, SetField, MapKeys
+//!
+//! Maps are string-keyed records (`Value::Map`) for passing structured data
+//! between nodes. Field paths are dotted (`"transform.position"`) and walk
+//! into nested maps. Maps never coerce to or from other types, so a map
+//! output only connects to map inputs.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::port::OutputTypeRule;
+use flux_core::value::ValueType;
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Value};
+use crate::names::intern;
+use crate::registry::{
+    capture_meta, OperatorRegistry, ParameterMeta, ParameterType, ParameterValue, RegistryEntry,
+};
+use flux_core::port::{InputPort, OutputPort};
+
+fn get_value(input: &InputPort, get_input: InputResolver) -> Value {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx),
+        None => input.default.clone(),
+    }
+}
+
+fn get_string(input: &InputPort, get_input: InputResolver) -> String {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx)
+            .as_string()
+            .unwrap_or_default()
+            .to_string(),
+        None => input.default.as_string().unwrap_or_default().to_string(),
+    }
+}
+
+fn get_map(input: &InputPort, get_input: InputResolver) -> Value {
+    match get_value(input, get_input) {
+        map @ Value::Map(_) => map,
+        _ => Value::Map(BTreeMap::new()),
+    }
+}
+
+/// Parse a `"name:Type, other"` field list. Fields without a (known) type
+/// are Float; repeated names keep their first occurrence.
+fn parse_fields(spec: &str) -> Vec<(String, ValueType)> {
+    let mut fields: Vec<(String, ValueType)> = Vec::new();
+    for field in spec.split(',') {
+        let (name, type_name) = field.split_once(':').unwrap_or((field, ""));
+        let name = name.trim();
+        if name.is_empty() || fields.iter().any(|(existing, _)| existing == name) {
+            continue;
+        }
+        let value_type = ValueType::from_name(type_name.trim()).unwrap_or(ValueType::Float);
+        fields.push((name.to_string(), value_type));
+    }
+    fields
+}
+
+// ============================================================================
+// MakeMap Operator
+// ============================================================================
+
+pub struct MakeMapOp {
+    id: Id,
+    /// One input per field, named after the field
+    inputs: Vec<InputPort>,
+    outputs: [OutputPort; 1],
+}
+
+impl MakeMapOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: Vec::new(),
+            outputs: [OutputPort::map("Map")],
+        }
+    }
+
+    /// Create with fields given as `"name:Type, other"` (untyped fields are Float)
+    pub fn with_fields(spec: &str) -> Self {
+        let mut op = Self::new();
+        op.inputs = parse_fields(spec)
+            .into_iter()
+            .map(|(name, value_type)| InputPort::new(intern(&name), value_type.default_value()))
+            .collect();
+        op
+    }
+
+    /// Field names, in input order
+    pub fn field_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.inputs.iter().map(|input| input.name)
+    }
+}
+
+impl Default for MakeMapOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for MakeMapOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "MakeMap" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let map = self
+            .inputs
+            .iter()
+            .map(|input| (input.name.to_string(), get_value(input, get_input)))
+            .collect();
+        self.outputs[0].set(Value::Map(map));
+    }
+}
+
+impl OperatorMeta for MakeMapOp {
+    fn category(&self) -> &'static str { "Map" }
+    fn category_color(&self) -> [f32; 4] { category_colors::MAP }
+    fn description(&self) -> &'static str { "Build a map from named field inputs" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        self.inputs.get(index).map(|input| PortMeta::new(input.name))
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Map").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// GetField Operator (Dynamic output type)
+// ============================================================================
+
+pub struct GetFieldOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 2],
+}
+
+impl GetFieldOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::map("Map"), InputPort::string("Field", "")],
+            // Value takes the type of the field found at the last evaluation
+            outputs: [
+                OutputPort::polymorphic("Value", OutputTypeRule::Dynamic),
+                OutputPort::bool("Found"),
+            ],
+        }
+    }
+
+    /// Create reading the given dotted field path
+    pub fn with_field(path: &str) -> Self {
+        let mut op = Self::new();
+        op.inputs[1].default = Value::String(path.to_string());
+        op
+    }
+}
+
+impl Default for GetFieldOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for GetFieldOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "GetField" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let map = get_map(&self.inputs[0], get_input);
+        let path = get_string(&self.inputs[1], get_input);
+
+        match map.get_field(&path) {
+            Some(value) => {
+                self.outputs[0].set_resolved_type(value.value_type());
+                self.outputs[0].set(value.clone());
+                self.outputs[1].set_bool(true);
+            }
+            None => {
+                // Keep the last resolved type so downstream connections stay valid
+                let default = self.outputs[0].value_type.default_value();
+                self.outputs[0].set(default);
+                self.outputs[1].set_bool(false);
+            }
+        }
+    }
+}
+
+impl OperatorMeta for GetFieldOp {
+    fn category(&self) -> &'static str { "Map" }
+    fn category_color(&self) -> [f32; 4] { category_colors::MAP }
+    fn description(&self) -> &'static str { "Read a field from a map by dotted path" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Map")),
+            1 => Some(PortMeta::new("Field")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Found").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// SetField Operator
+// ============================================================================
+
+pub struct SetFieldOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl SetFieldOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::map("Map"),
+                InputPort::string("Field", ""),
+                InputPort::any("Value", Value::Float(0.0)),
+            ],
+            outputs: [OutputPort::map("Map")],
+        }
+    }
+}
+
+impl Default for SetFieldOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for SetFieldOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "SetField" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let mut map = get_map(&self.inputs[0], get_input);
+        let path = get_string(&self.inputs[1], get_input);
+        let value = get_value(&self.inputs[2], get_input);
+
+        // An invalid path (e.g. through a non-map field) passes the map through
+        let mut updated = map.clone();
+        if updated.set_field(&path, value) {
+            map = updated;
+        }
+        self.outputs[0].set(map);
+    }
+}
+
+impl OperatorMeta for SetFieldOp {
+    fn category(&self) -> &'static str { "Map" }
+    fn category_color(&self) -> [f32; 4] { category_colors::MAP }
+    fn description(&self) -> &'static str { "Set a field in a map by dotted path" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Map")),
+            1 => Some(PortMeta::new("Field")),
+            2 => Some(PortMeta::new("Value")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Map").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// MapKeys Operator
+// ============================================================================
+
+pub struct MapKeysOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 1],
+}
+
+impl MapKeysOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::map("Map")],
+            outputs: [OutputPort::string_list("Keys")],
+        }
+    }
+}
+
+impl Default for MapKeysOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for MapKeysOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "MapKeys" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let map = get_map(&self.inputs[0], get_input);
+        let keys = map
+            .as_map()
+            .map(|m| m.keys().cloned().collect())
+            .unwrap_or_default();
+        self.outputs[0].set(Value::string_list(keys));
+    }
+}
+
+impl OperatorMeta for MapKeysOp {
+    fn category(&self) -> &'static str { "Map" }
+    fn category_color(&self) -> [f32; 4] { category_colors::MAP }
+    fn description(&self) -> &'static str { "List the keys of a map in sorted order" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Map")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Keys").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register_with_params(
+        RegistryEntry::new("MakeMap", "Map", "Build a map from named field inputs"),
+        || capture_meta(MakeMapOp::new()),
+        |params| capture_meta(MakeMapOp::with_fields(params.get_string("fields", ""))),
+        vec![ParameterMeta {
+            name: "fields",
+            param_type: ParameterType::String,
+            default: ParameterValue::String(String::new()),
+        }],
+    );
+
+    registry.register(
+        RegistryEntry::new("GetField", "Map", "Read a field from a map by dotted path"),
+        || capture_meta(GetFieldOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("SetField", "Map", "Set a field in a map by dotted path"),
+        || capture_meta(SetFieldOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("MapKeys", "Map", "List the keys of a map in sorted order"),
+        || capture_meta(MapKeysOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::OperatorParams;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    fn nested_map() -> Value {
+        let mut map = Value::Map(BTreeMap::new());
+        assert!(map.set_field("name", Value::String("cube".to_string())));
+        assert!(map.set_field("transform.position", Value::Vec3([1.0, 2.0, 3.0])));
+        assert!(map.set_field("transform.scale", Value::Float(2.0)));
+        map
+    }
+
+    #[test]
+    fn test_make_map_fields_from_params() {
+        let registry = OperatorRegistry::new();
+        register(&registry);
+        let params = OperatorParams::new()
+            .set("fields", ParameterValue::String("size, color:Color, size, label:String".into()));
+        let op = registry.create_with_params("MakeMap", &params).unwrap();
+        let names: Vec<_> = op.inputs().iter().map(|i| i.name).collect();
+        assert_eq!(names, vec!["size", "color", "label"]);
+        let types: Vec<_> = op.inputs().iter().map(|i| i.value_type).collect();
+        assert_eq!(types, vec![ValueType::Float, ValueType::Color, ValueType::String]);
+
+        let mut op = MakeMapOp::with_fields("a, b:Int");
+        op.inputs[0].default = Value::Float(1.5);
+        op.inputs[1].default = Value::Int(4);
+        op.compute(&EvalContext::new(), &no_connections);
+        let map = &op.outputs[0].value;
+        assert_eq!(map.get_field("a"), Some(&Value::Float(1.5)));
+        assert_eq!(map.get_field("b"), Some(&Value::Int(4)));
+    }
+
+    #[test]
+    fn test_get_field_resolves_output_type() {
+        let source = Id::new();
+        let mut op = GetFieldOp::with_field("transform.position");
+        op.inputs[0].connect(source, 0);
+        let ctx = EvalContext::new();
+        assert_eq!(op.outputs[0].value_type, ValueType::Float);
+
+        op.compute(&ctx, &|_, _| nested_map());
+        assert_eq!(op.outputs[0].value_type, ValueType::Vec3);
+        assert_eq!(op.outputs[0].value, Value::Vec3([1.0, 2.0, 3.0]));
+        assert_eq!(op.outputs[1].value, Value::Bool(true));
+
+        // A missing field keeps the resolved type and outputs its default
+        op.inputs[1].default = Value::String("transform.rotation".to_string());
+        op.compute(&ctx, &|_, _| nested_map());
+        assert_eq!(op.outputs[0].value_type, ValueType::Vec3);
+        assert_eq!(op.outputs[0].value, Value::Vec3([0.0; 3]));
+        assert_eq!(op.outputs[1].value, Value::Bool(false));
+    }
+
+    #[test]
+    fn test_set_field_and_keys() {
+        let source = Id::new();
+        let ctx = EvalContext::new();
+        let mut set = SetFieldOp::new();
+        set.inputs[0].connect(source, 0);
+        set.inputs[1].default = Value::String("transform.scale".to_string());
+        set.inputs[2].default = Value::Float(0.5);
+        set.compute(&ctx, &|_, _| nested_map());
+        assert_eq!(set.outputs[0].value.get_field("transform.scale"), Some(&Value::Float(0.5)));
+
+        // Paths through a non-map field leave the map unchanged
+        set.inputs[1].default = Value::String("name.first".to_string());
+        set.compute(&ctx, &|_, _| nested_map());
+        assert_eq!(set.outputs[0].value, nested_map());
+
+        let mut keys = MapKeysOp::new();
+        keys.inputs[0].connect(source, 0);
+        keys.compute(&ctx, &|_, _| nested_map());
+        assert_eq!(
+            keys.outputs[0].value,
+            Value::string_list(vec!["name".to_string(), "transform".to_string()])
+        );
+    }
+}
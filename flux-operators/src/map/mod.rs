@@ -0,0 +1,14 @@
+//! Map operators (4 total)
+//! - MakeMap (one input per field, fields set by the `fields` parameter)
+//! - GetField, SetField (dotted paths into nested maps)
+//! - MapKeys
+
+use crate::registry::OperatorRegistry;
+
+mod map_ops;
+
+pub use map_ops::*;
+
+pub fn register_all(registry: &OperatorRegistry) {
+    map_ops::register(registry);
+}
@@ -12,6 +12,9 @@
 //! let mul = MultiplyOp::new(); // Scalar * Vec3 = scaled Vec3
 //! ```
 
+// Factory types (`AddOp::new()` etc.) intentionally return the shared operator type.
+#![allow(clippy::new_ret_no_self)]
+
 use std::any::Any;
 
 use flux_core::context::EvalContext;
@@ -176,7 +179,7 @@ impl Operator for BinaryOp {
 }
 
 impl OperatorMeta for BinaryOp {
-    fn category(&self) -> &'static str { "Math" }
+    fn category(&self) -> &'static str { "Math/Arithmetic" }
     fn category_color(&self) -> [f32; 4] { category_colors::MATH }
     fn description(&self) -> &'static str { self.op.description() }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
@@ -192,6 +195,12 @@ impl OperatorMeta for BinaryOp {
             _ => None,
         }
     }
+    fn swappable_input_pairs(&self) -> &'static [(usize, usize)] {
+        match self.op {
+            BinaryArithOp::Add | BinaryArithOp::Mul => &[],
+            BinaryArithOp::Sub | BinaryArithOp::Div | BinaryArithOp::Mod => &[(0, 1)],
+        }
+    }
 }
 
 // ============================================================================
@@ -321,7 +330,7 @@ impl Operator for UnaryOp {
 }
 
 impl OperatorMeta for UnaryOp {
-    fn category(&self) -> &'static str { "Math" }
+    fn category(&self) -> &'static str { "Math/Arithmetic" }
     fn category_color(&self) -> [f32; 4] { category_colors::MATH }
     fn description(&self) -> &'static str { self.op.description() }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
@@ -397,7 +406,7 @@ impl Operator for PowOp {
 }
 
 impl OperatorMeta for PowOp {
-    fn category(&self) -> &'static str { "Math" }
+    fn category(&self) -> &'static str { "Math/Arithmetic" }
     fn category_color(&self) -> [f32; 4] { category_colors::MATH }
     fn description(&self) -> &'static str { "Raises base to exponent power" }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
@@ -469,7 +478,7 @@ impl Operator for LogOp {
 }
 
 impl OperatorMeta for LogOp {
-    fn category(&self) -> &'static str { "Math" }
+    fn category(&self) -> &'static str { "Math/Arithmetic" }
     fn category_color(&self) -> [f32; 4] { category_colors::MATH }
     fn description(&self) -> &'static str { "Logarithm of value with base" }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
@@ -575,63 +584,63 @@ pub type PolyPowOp = PowOp;
 pub fn register(registry: &OperatorRegistry) {
     // Binary operators
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Add", category: "Math", description: "Adds two values" },
+        RegistryEntry::new("Add", "Math/Arithmetic", "Adds two values"),
         || capture_meta(BinaryOp::add()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Subtract", category: "Math", description: "Subtracts B from A" },
+        RegistryEntry::new("Subtract", "Math/Arithmetic", "Subtracts B from A"),
         || capture_meta(BinaryOp::sub()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Multiply", category: "Math", description: "Multiplies two values" },
+        RegistryEntry::new("Multiply", "Math/Arithmetic", "Multiplies two values"),
         || capture_meta(BinaryOp::mul()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Divide", category: "Math", description: "Divides A by B" },
+        RegistryEntry::new("Divide", "Math/Arithmetic", "Divides A by B"),
         || capture_meta(BinaryOp::div()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Modulo", category: "Math", description: "A modulo B" },
+        RegistryEntry::new("Modulo", "Math/Arithmetic", "A modulo B"),
         || capture_meta(BinaryOp::modulo()),
     );
 
     // Unary operators
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Negate", category: "Math", description: "Negates the value" },
+        RegistryEntry::new("Negate", "Math/Arithmetic", "Negates the value"),
         || capture_meta(UnaryOp::negate()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Abs", category: "Math", description: "Absolute value" },
+        RegistryEntry::new("Abs", "Math/Arithmetic", "Absolute value"),
         || capture_meta(UnaryOp::abs()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Sqrt", category: "Math", description: "Square root" },
+        RegistryEntry::new("Sqrt", "Math/Arithmetic", "Square root"),
         || capture_meta(UnaryOp::sqrt()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Floor", category: "Math", description: "Floor (round down)" },
+        RegistryEntry::new("Floor", "Math/Arithmetic", "Floor (round down)"),
         || capture_meta(UnaryOp::floor()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Ceil", category: "Math", description: "Ceiling (round up)" },
+        RegistryEntry::new("Ceil", "Math/Arithmetic", "Ceiling (round up)"),
         || capture_meta(UnaryOp::ceil()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Round", category: "Math", description: "Round to nearest integer" },
+        RegistryEntry::new("Round", "Math/Arithmetic", "Round to nearest integer"),
         || capture_meta(UnaryOp::round()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Truncate", category: "Math", description: "Truncate toward zero" },
+        RegistryEntry::new("Truncate", "Math/Arithmetic", "Truncate toward zero"),
         || capture_meta(UnaryOp::trunc()),
     );
 
     // Pow and Log
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Pow", category: "Math", description: "Raises base to exponent power" },
+        RegistryEntry::new("Pow", "Math/Arithmetic", "Raises base to exponent power"),
         || capture_meta(PowOp::new()),
     );
     registry.register(
-        RegistryEntry { type_id: Id::new(), name: "Log", category: "Math", description: "Logarithm of value with base" },
+        RegistryEntry::new("Log", "Math/Arithmetic", "Logarithm of value with base"),
         || capture_meta(LogOp::new()),
     );
 }
@@ -89,7 +89,7 @@ impl Operator for MinOp {
 
 impl OperatorMeta for MinOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Arithmetic"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -178,7 +178,7 @@ impl Operator for MaxOp {
 
 impl OperatorMeta for MaxOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Arithmetic"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -271,7 +271,7 @@ impl Operator for ClampOp {
 
 impl OperatorMeta for ClampOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Arithmetic"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -356,7 +356,7 @@ impl Operator for SignOp {
 
 impl OperatorMeta for SignOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Arithmetic"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -445,7 +445,7 @@ impl Operator for StepOp {
 
 impl OperatorMeta for StepOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Arithmetic"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -474,52 +474,39 @@ impl OperatorMeta for StepOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Min",
-            category: "Math",
-            description: "Per-component minimum of two values",
-        },
+        RegistryEntry::new("Min", "Math/Arithmetic", "Per-component minimum of two values"),
         || capture_meta(MinOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Max",
-            category: "Math",
-            description: "Per-component maximum of two values",
-        },
+        RegistryEntry::new("Max", "Math/Arithmetic", "Per-component maximum of two values"),
         || capture_meta(MaxOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Clamp",
-            category: "Math",
-            description: "Clamps value to range [min, max] per-component",
-        },
+        RegistryEntry::new(
+            "Clamp",
+            "Math/Arithmetic",
+            "Clamps value to range [min, max] per-component",
+        ),
         || capture_meta(ClampOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Sign",
-            category: "Math",
-            description: "Returns -1, 0, or 1 per-component based on sign",
-        },
+        RegistryEntry::new(
+            "Sign",
+            "Math/Arithmetic",
+            "Returns -1, 0, or 1 per-component based on sign",
+        ),
         || capture_meta(SignOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Step",
-            category: "Math",
-            description: "Returns 0 if value < edge, else 1 (per-component)",
-        },
+        RegistryEntry::new(
+            "Step",
+            "Math/Arithmetic",
+            "Returns 0 if value < edge, else 1 (per-component)",
+        ),
         || capture_meta(StepOp::new()),
     );
 }
@@ -1,15 +1,18 @@
-//! Interpolation operators: Lerp, SmoothStep, Remap, InverseLerp, MapRange
+//! Interpolation operators: Lerp, SmoothStep, Remap, InverseLerp, MapRange,
+//! TransferFunction, TransferFunctionList, Crossfade, MorphList
 //!
 //! Lerp and SmoothStep are polymorphic and work with:
 //! Float, Int, Vec2, Vec3, Vec4, Color
 
 use std::any::Any;
+use std::array::from_fn;
 
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
-use flux_core::operator::{InputResolver, Operator};
-use flux_core::port::{InputPort, OutputPort};
+use flux_core::operator::{ActiveInput, InputResolver, InputValidationIssue, Operator, OperatorCost};
+use flux_core::port::{InputPort, OutputPort, TypeConstraint};
+use flux_core::value::{Color, ValueType};
 use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Value};
 
 // =============================================================================
@@ -98,7 +101,7 @@ impl Operator for LerpOp {
 
 impl OperatorMeta for LerpOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Interpolation"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -120,6 +123,9 @@ impl OperatorMeta for LerpOp {
             _ => None,
         }
     }
+    fn swappable_input_pairs(&self) -> &'static [(usize, usize)] {
+        &[(0, 1)]
+    }
 }
 
 // =============================================================================
@@ -190,13 +196,13 @@ impl Operator for SmoothStepOp {
 
 impl OperatorMeta for SmoothStepOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Interpolation"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
     }
     fn description(&self) -> &'static str {
-        "Hermite interpolation with smooth edges (per-component)"
+        "Hermite interpolation with smooth edges (per-component), always in 0..1"
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
@@ -208,7 +214,11 @@ impl OperatorMeta for SmoothStepOp {
     }
     fn output_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            0 => Some(
+                PortMeta::new("Result")
+                    .with_range(0.0, 1.0)
+                    .with_shape(PinShape::TriangleFilled),
+            ),
             _ => None,
         }
     }
@@ -218,9 +228,44 @@ impl OperatorMeta for SmoothStepOp {
 // Remap Operator (float-only)
 // =============================================================================
 
+/// Map `value` from `in_min..in_max` onto `out_min..out_max`
+///
+/// Shared by [`RemapOp`] and [`MapRangeOp`]. A collapsed input range maps
+/// everything to the midpoint of the output range. With `clamp` the result
+/// stays between the output bounds, whichever way round they are given.
+/// Finite inputs never give NaN.
+pub fn remap_range(
+    value: f32,
+    in_min: f32,
+    in_max: f32,
+    out_min: f32,
+    out_max: f32,
+    clamp: bool,
+) -> f32 {
+    // In f64 so differences of large values can't overflow
+    let (value, in_min, in_max) = (value as f64, in_min as f64, in_max as f64);
+    let (out_min, out_max) = (out_min as f64, out_max as f64);
+    let in_range = in_max - in_min;
+    let result = if in_range.abs() < f32::EPSILON as f64 {
+        (out_min + out_max) / 2.0
+    } else {
+        out_min + (value - in_min) / in_range * (out_max - out_min)
+    };
+    let result = if clamp {
+        result.clamp(out_min.min(out_max), out_min.max(out_max))
+    } else {
+        result
+    };
+    result as f32
+}
+
+/// Remaps a value between ranges
+///
+/// An empty input range gives the midpoint of the output range. "Clamp"
+/// keeps the result inside the output range, also when OutMin > OutMax.
 pub struct RemapOp {
     id: Id,
-    inputs: [InputPort; 5],
+    inputs: [InputPort; 6],
     outputs: [OutputPort; 1],
 }
 
@@ -234,6 +279,7 @@ impl RemapOp {
                 InputPort::float("InMax", 1.0),
                 InputPort::float("OutMin", 0.0),
                 InputPort::float("OutMax", 1.0),
+                InputPort::bool("Clamp", false),
             ],
             outputs: [OutputPort::float("Result")],
         }
@@ -278,28 +324,22 @@ impl Operator for RemapOp {
         let in_max = get_float(&self.inputs[2], get_input);
         let out_min = get_float(&self.inputs[3], get_input);
         let out_max = get_float(&self.inputs[4], get_input);
+        let clamp = get_bool(&self.inputs[5], get_input);
 
-        let in_range = in_max - in_min;
-        if in_range.abs() < f32::EPSILON {
-            self.outputs[0].set_float(out_min);
-            return;
-        }
-
-        let t = (value - in_min) / in_range;
-        let result = out_min + t * (out_max - out_min);
+        let result = remap_range(value, in_min, in_max, out_min, out_max, clamp);
         self.outputs[0].set_float(result);
     }
 }
 
 impl OperatorMeta for RemapOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Interpolation"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
     }
     fn description(&self) -> &'static str {
-        "Remaps value from one range to another"
+        "Remaps value from one range to another (midpoint for an empty input range), optionally clamped"
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
@@ -308,6 +348,7 @@ impl OperatorMeta for RemapOp {
             2 => Some(PortMeta::new("InMax")),
             3 => Some(PortMeta::new("OutMin")),
             4 => Some(PortMeta::new("OutMax")),
+            5 => Some(PortMeta::new("Clamp")),
             _ => None,
         }
     }
@@ -380,26 +421,26 @@ impl Operator for InverseLerpOp {
         let b = get_float(&self.inputs[1], get_input);
         let value = get_float(&self.inputs[2], get_input);
 
-        let range = b - a;
-        if range.abs() < f32::EPSILON {
-            self.outputs[0].set_float(0.0);
-            return;
-        }
-
-        let t = (value - a) / range;
-        self.outputs[0].set_float(t);
+        // In f64 so differences of large values can't overflow
+        let range = b as f64 - a as f64;
+        let t = if range.abs() < f32::EPSILON as f64 {
+            0.5
+        } else {
+            (value as f64 - a as f64) / range
+        };
+        self.outputs[0].set_float(t as f32);
     }
 }
 
 impl OperatorMeta for InverseLerpOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Interpolation"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
     }
     fn description(&self) -> &'static str {
-        "Gets T from lerp result"
+        "Gets T from lerp result (0.5 when A equals B)"
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
@@ -421,9 +462,13 @@ impl OperatorMeta for InverseLerpOp {
 // MapRange Operator (float-only)
 // =============================================================================
 
+/// Maps a value between ranges
+///
+/// Same mapping as [`RemapOp`]: an empty From range gives the midpoint of
+/// the To range, and "Clamp" keeps the result inside the To range.
 pub struct MapRangeOp {
     id: Id,
-    inputs: [InputPort; 5],
+    inputs: [InputPort; 6],
     outputs: [OutputPort; 1],
 }
 
@@ -437,6 +482,7 @@ impl MapRangeOp {
                 InputPort::float("FromMax", 1.0),
                 InputPort::float("ToMin", 0.0),
                 InputPort::float("ToMax", 1.0),
+                InputPort::bool("Clamp", false),
             ],
             outputs: [OutputPort::float("Result")],
         }
@@ -481,28 +527,48 @@ impl Operator for MapRangeOp {
         let from_max = get_float(&self.inputs[2], get_input);
         let to_min = get_float(&self.inputs[3], get_input);
         let to_max = get_float(&self.inputs[4], get_input);
+        let clamp = get_bool(&self.inputs[5], get_input);
 
-        let from_range = from_max - from_min;
-        if from_range.abs() < f32::EPSILON {
-            self.outputs[0].set_float(to_min);
-            return;
-        }
-
-        let t = (value - from_min) / from_range;
-        let result = to_min + t * (to_max - to_min);
+        let result = remap_range(value, from_min, from_max, to_min, to_max, clamp);
         self.outputs[0].set_float(result);
     }
+
+    fn validate_inputs(&self, proposed: &[Value]) -> Vec<InputValidationIssue> {
+        let literal = |index: usize| {
+            (!self.inputs[index].is_connected())
+                .then(|| proposed.get(index).and_then(Value::as_float))
+                .flatten()
+        };
+        let mut issues = Vec::new();
+        if let (Some(from_min), Some(from_max)) = (literal(1), literal(2)) {
+            if (from_max - from_min).abs() < f32::EPSILON {
+                issues.push(InputValidationIssue::warning(
+                    2,
+                    "FromMin and FromMax are equal; the result is always the middle of ToMin..ToMax",
+                ));
+            }
+        }
+        if let (Some(to_min), Some(to_max)) = (literal(3), literal(4)) {
+            if to_min == to_max {
+                issues.push(InputValidationIssue::info(
+                    4,
+                    "ToMin and ToMax are equal; the result is constant",
+                ));
+            }
+        }
+        issues
+    }
 }
 
 impl OperatorMeta for MapRangeOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Interpolation"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
     }
     fn description(&self) -> &'static str {
-        "Maps value from one range to another"
+        "Maps value from one range to another (midpoint for an empty From range), optionally clamped"
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
@@ -511,9 +577,825 @@ impl OperatorMeta for MapRangeOp {
             2 => Some(PortMeta::new("FromMax")),
             3 => Some(PortMeta::new("ToMin")),
             4 => Some(PortMeta::new("ToMax")),
+            5 => Some(PortMeta::new("Clamp")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// Transfer Functions
+// =============================================================================
+
+/// What a transfer function does with inputs outside its points
+///
+/// Selected through an Int "Mode" input: 0 = Clamp, 1 = Extrapolate, 2 = Wrap.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutOfRange {
+    /// Hold the first/last point's value
+    #[default]
+    Clamp,
+    /// Continue the slope at the first/last point
+    Extrapolate,
+    /// Repeat the curve over the span of its points
+    Wrap,
+}
+
+impl OutOfRange {
+    /// Mode for a Mode input value (unknown values clamp)
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => OutOfRange::Extrapolate,
+            2 => OutOfRange::Wrap,
+            _ => OutOfRange::Clamp,
+        }
+    }
+
+    /// Mode input value for this mode
+    pub fn index(self) -> i32 {
+        self as i32
+    }
+}
+
+/// Parse interleaved `(x, y)` pairs into points sorted by x
+///
+/// A trailing unpaired value and pairs containing NaN are ignored. When
+/// several points share an x, the last one in `pairs` wins.
+pub fn transfer_points(pairs: &[f32]) -> Vec<[f32; 2]> {
+    let mut points: Vec<[f32; 2]> = pairs
+        .chunks_exact(2)
+        .map(|pair| [pair[0], pair[1]])
+        .filter(|p| !p[0].is_nan() && !p[1].is_nan())
+        .collect();
+    // Stable, so equal x keep their input order and the last one survives
+    points.sort_by(|a, b| a[0].total_cmp(&b[0]));
+    let mut deduped: Vec<[f32; 2]> = Vec::with_capacity(points.len());
+    for point in points {
+        match deduped.last_mut() {
+            Some(last) if last[0] == point[0] => *last = point,
+            _ => deduped.push(point),
+        }
+    }
+    deduped
+}
+
+/// Index of the segment (`points[k]` to `points[k + 1]`) used for `x`
+///
+/// Inputs beyond either end use the first/last segment. Needs two points.
+fn segment(points: &[[f32; 2]], x: f32) -> usize {
+    points
+        .partition_point(|p| p[0] <= x)
+        .saturating_sub(1)
+        .min(points.len() - 2)
+}
+
+/// Piecewise-linear interpolation through sorted `points`
+///
+/// Inputs beyond the ends continue the first/last segment. With no points
+/// the input is returned unchanged; a single point is a constant. Finite
+/// inputs never give NaN.
+pub fn piecewise_linear(points: &[[f32; 2]], x: f32) -> f32 {
+    match points {
+        [] => x,
+        [only] => only[1],
+        _ => {
+            let k = segment(points, x);
+            // In f64 so differences of large values can't overflow
+            let [x0, y0] = points[k].map(f64::from);
+            let [x1, y1] = points[k + 1].map(f64::from);
+            (y0 + (x as f64 - x0) * (y1 - y0) / (x1 - x0)) as f32
+        }
+    }
+}
+
+/// Fritsch–Carlson tangents for a monotone cubic through sorted `points`
+///
+/// Tangents are limited so that every segment stays between its endpoint
+/// values: the curve is monotone wherever the points are, and never
+/// overshoots a local extremum.
+pub fn monotone_tangents(points: &[[f32; 2]]) -> Vec<f32> {
+    let n = points.len();
+    if n < 2 {
+        return vec![0.0; n];
+    }
+    let slopes: Vec<f32> = points
+        .windows(2)
+        .map(|w| (w[1][1] - w[0][1]) / (w[1][0] - w[0][0]))
+        .collect();
+
+    let mut tangents = vec![0.0; n];
+    tangents[0] = slopes[0];
+    tangents[n - 1] = slopes[n - 2];
+    for k in 1..n - 1 {
+        // Zero at local extrema so the curve flattens instead of overshooting
+        if slopes[k - 1] * slopes[k] > 0.0 {
+            tangents[k] = (slopes[k - 1] + slopes[k]) / 2.0;
+        }
+    }
+
+    for (k, &slope) in slopes.iter().enumerate() {
+        if slope == 0.0 {
+            tangents[k] = 0.0;
+            tangents[k + 1] = 0.0;
+            continue;
+        }
+        let (a, b) = (tangents[k] / slope, tangents[k + 1] / slope);
+        let s = a * a + b * b;
+        if s > 9.0 {
+            let tau = 3.0 / s.sqrt();
+            tangents[k] = tau * a * slope;
+            tangents[k + 1] = tau * b * slope;
+        }
+    }
+    tangents
+}
+
+/// Cubic Hermite interpolation through sorted `points` with `tangents`
+/// (from [`monotone_tangents`])
+///
+/// Inputs beyond the ends continue along the end tangent. With no points
+/// the input is returned unchanged; a single point is a constant.
+pub fn monotone_cubic(points: &[[f32; 2]], tangents: &[f32], x: f32) -> f32 {
+    match points {
+        [] => x,
+        [only] => only[1],
+        [first, ..] if x < first[0] => first[1] + (x - first[0]) * tangents[0],
+        [.., last] if x > last[0] => last[1] + (x - last[0]) * tangents[points.len() - 1],
+        _ => {
+            let k = segment(points, x);
+            let ([x0, y0], [x1, y1]) = (points[k], points[k + 1]);
+            let h = x1 - x0;
+            let t = (x - x0) / h;
+            let (t2, t3) = (t * t, t * t * t);
+            let h00 = 2.0 * t3 - 3.0 * t2 + 1.0;
+            let h10 = t3 - 2.0 * t2 + t;
+            let h01 = -2.0 * t3 + 3.0 * t2;
+            let h11 = t3 - t2;
+            h00 * y0 + h10 * h * tangents[k] + h01 * y1 + h11 * h * tangents[k + 1]
+        }
+    }
+}
+
+/// A response curve defined by control points
+///
+/// Shared by [`TransferFunctionOp`] and [`TransferFunctionListOp`]: built
+/// once per compute, then applied to each input value.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransferFunction {
+    points: Vec<[f32; 2]>,
+    /// Monotone cubic tangents, when smoothing
+    tangents: Option<Vec<f32>>,
+    mode: OutOfRange,
+}
+
+impl TransferFunction {
+    /// Build from interleaved `(x, y)` pairs (see [`transfer_points`]).
+    pub fn new(pairs: &[f32], mode: OutOfRange, smooth: bool) -> Self {
+        let points = transfer_points(pairs);
+        let tangents = smooth.then(|| monotone_tangents(&points));
+        Self {
+            points,
+            tangents,
+            mode,
+        }
+    }
+
+    /// Map one input value through the curve.
+    ///
+    /// Finite inputs never give NaN: where the smooth curve overflows, the
+    /// linear one is used instead.
+    pub fn apply(&self, x: f32) -> f32 {
+        let (Some(first), Some(last)) = (self.points.first(), self.points.last()) else {
+            return x;
+        };
+        let x = match self.mode {
+            OutOfRange::Clamp => x.clamp(first[0], last[0]),
+            OutOfRange::Extrapolate => x,
+            OutOfRange::Wrap => {
+                let (start, span) = (first[0] as f64, last[0] as f64 - first[0] as f64);
+                if span > 0.0 {
+                    (start + (x as f64 - start).rem_euclid(span)).clamp(start, last[0] as f64)
+                        as f32
+                } else {
+                    first[0]
+                }
+            }
+        };
+        match &self.tangents {
+            Some(tangents) => {
+                let y = monotone_cubic(&self.points, tangents, x);
+                if y.is_nan() {
+                    piecewise_linear(&self.points, x)
+                } else {
+                    y
+                }
+            }
+            None => piecewise_linear(&self.points, x),
+        }
+    }
+}
+
+fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
+    get_value(input, get_input).as_int().unwrap_or(0)
+}
+
+fn get_bool(input: &InputPort, get_input: InputResolver) -> bool {
+    get_value(input, get_input).as_bool().unwrap_or(false)
+}
+
+/// Build the transfer function from the Points, Mode and Smooth inputs
+fn get_transfer_function(inputs: &[InputPort], get_input: InputResolver) -> TransferFunction {
+    let points = get_value(&inputs[0], get_input);
+    let mode = OutOfRange::from_index(get_int(&inputs[1], get_input));
+    let smooth = get_bool(&inputs[2], get_input);
+    TransferFunction::new(points.as_float_list().unwrap_or(&[]), mode, smooth)
+}
+
+/// Points, Mode and Smooth inputs shared by the transfer function operators
+fn transfer_function_inputs() -> [InputPort; 3] {
+    [
+        // Identity over 0..1
+        InputPort::new("Points", Value::float_list(vec![0.0, 0.0, 1.0, 1.0])),
+        InputPort::int("Mode", OutOfRange::Clamp.index()),
+        InputPort::bool("Smooth", false),
+    ]
+}
+
+fn transfer_function_input_meta(index: usize) -> Option<PortMeta> {
+    match index {
+        0 => Some(PortMeta::new("Points")),
+        // 0=Clamp, 1=Extrapolate, 2=Wrap
+        1 => Some(PortMeta::new("Mode").with_range(0.0, 2.0).with_step(1.0)),
+        2 => Some(PortMeta::new("Smooth")),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// TransferFunction Operator (float-only)
+// =============================================================================
+
+/// Maps a value through a curve given by control points
+///
+/// "Points" holds interleaved `(x, y)` pairs. Between points the curve is
+/// piecewise-linear, or a monotone cubic (no overshoot) with "Smooth" on.
+/// "Mode" selects what happens outside the points (see [`OutOfRange`]).
+pub struct TransferFunctionOp {
+    id: Id,
+    inputs: [InputPort; 4],
+    outputs: [OutputPort; 1],
+}
+
+impl TransferFunctionOp {
+    pub fn new() -> Self {
+        let [points, mode, smooth] = transfer_function_inputs();
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::float("Input", 0.0), points, mode, smooth],
+            outputs: [OutputPort::float("Result")],
+        }
+    }
+}
+
+impl Default for TransferFunctionOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for TransferFunctionOp {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn id(&self) -> Id {
+        self.id
+    }
+    fn name(&self) -> &'static str {
+        "TransferFunction"
+    }
+    fn inputs(&self) -> &[InputPort] {
+        &self.inputs
+    }
+    fn inputs_mut(&mut self) -> &mut [InputPort] {
+        &mut self.inputs
+    }
+    fn outputs(&self) -> &[OutputPort] {
+        &self.outputs
+    }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] {
+        &mut self.outputs
+    }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let x = get_float(&self.inputs[0], get_input);
+        let function = get_transfer_function(&self.inputs[1..], get_input);
+        self.outputs[0].set_float(function.apply(x));
+    }
+}
+
+impl OperatorMeta for TransferFunctionOp {
+    fn category(&self) -> &'static str {
+        "Math/Interpolation"
+    }
+    fn category_color(&self) -> [f32; 4] {
+        category_colors::MATH
+    }
+    fn description(&self) -> &'static str {
+        "Maps value through a curve given by (x, y) points"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Input")),
+            _ => transfer_function_input_meta(index - 1),
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
             _ => None,
         }
     }
+}
+
+// =============================================================================
+// TransferFunctionList Operator (FloatList)
+// =============================================================================
+
+/// [`TransferFunctionOp`] applied to every element of a FloatList
+pub struct TransferFunctionListOp {
+    id: Id,
+    inputs: [InputPort; 4],
+    outputs: [OutputPort; 1],
+}
+
+impl TransferFunctionListOp {
+    pub fn new() -> Self {
+        let [points, mode, smooth] = transfer_function_inputs();
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::float_list("List"), points, mode, smooth],
+            outputs: [OutputPort::float_list("Result")],
+        }
+    }
+}
+
+impl Default for TransferFunctionListOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for TransferFunctionListOp {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn id(&self) -> Id {
+        self.id
+    }
+    fn name(&self) -> &'static str {
+        "TransferFunctionList"
+    }
+    fn inputs(&self) -> &[InputPort] {
+        &self.inputs
+    }
+    fn inputs_mut(&mut self) -> &mut [InputPort] {
+        &mut self.inputs
+    }
+    fn outputs(&self) -> &[OutputPort] {
+        &self.outputs
+    }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] {
+        &mut self.outputs
+    }
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Expensive
+    }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let mut result = match get_value(&self.inputs[0], get_input) {
+            value @ Value::FloatList(_) => value,
+            Value::Float(f) => Value::float_list(vec![f]),
+            _ => Value::float_list(Vec::new()),
+        };
+        let function = get_transfer_function(&self.inputs[1..], get_input);
+        if let Some(values) = result.as_float_list_mut() {
+            for v in values {
+                *v = function.apply(*v);
+            }
+        }
+        self.outputs[0].value = result;
+    }
+}
+
+impl OperatorMeta for TransferFunctionListOp {
+    fn category(&self) -> &'static str {
+        "Math/Interpolation"
+    }
+    fn category_color(&self) -> [f32; 4] {
+        category_colors::MATH
+    }
+    fn description(&self) -> &'static str {
+        "Maps every list value through a curve given by (x, y) points"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("List")),
+            _ => transfer_function_input_meta(index - 1),
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// Crossfades
+// =============================================================================
+
+/// How a crossfade weights its two inputs along Mix
+///
+/// Selected through an Int "Curve" input: 0 = Linear, 1 = EqualPower,
+/// 2 = Smooth.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CrossfadeCurve {
+    /// Gains `1 - mix` and `mix`; the sum of the gains stays 1
+    #[default]
+    Linear,
+    /// Gains `cos` and `sin` of `mix * π/2`; the sum of their squares stays
+    /// 1, so uncorrelated levels (audio) don't dip halfway
+    EqualPower,
+    /// Linear with `mix` eased by smoothstep, for gentle starts and ends
+    Smooth,
+}
+
+impl CrossfadeCurve {
+    /// Curve for a Curve input value (unknown values are linear)
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => CrossfadeCurve::EqualPower,
+            2 => CrossfadeCurve::Smooth,
+            _ => CrossfadeCurve::Linear,
+        }
+    }
+
+    /// Curve input value for this curve
+    pub fn index(self) -> i32 {
+        self as i32
+    }
+
+    /// Gains applied to A and B at `mix` (clamped to 0..1)
+    pub fn gains(self, mix: f32) -> (f32, f32) {
+        let mix = mix.clamp(0.0, 1.0);
+        match self {
+            CrossfadeCurve::Linear => (1.0 - mix, mix),
+            CrossfadeCurve::EqualPower => {
+                let angle = mix * std::f32::consts::FRAC_PI_2;
+                (angle.cos(), angle.sin())
+            }
+            CrossfadeCurve::Smooth => {
+                let eased = mix * mix * (3.0 - 2.0 * mix);
+                (1.0 - eased, eased)
+            }
+        }
+    }
+}
+
+/// `a * gain_a + b * gain_b`, per component
+///
+/// Differing types are converted to the wider one first, and Ints blend as
+/// Floats. Returns `None` for non-arithmetic values.
+pub fn weighted_sum(a: &Value, b: &Value, gain_a: f32, gain_b: f32) -> Option<Value> {
+    let mix = |x: f32, y: f32| x * gain_a + y * gain_b;
+    match (a, b) {
+        (Value::Float(x), Value::Float(y)) => Some(Value::Float(mix(*x, *y))),
+        (Value::Vec2(x), Value::Vec2(y)) => Some(Value::Vec2(from_fn(|i| mix(x[i], y[i])))),
+        (Value::Vec3(x), Value::Vec3(y)) => Some(Value::Vec3(from_fn(|i| mix(x[i], y[i])))),
+        (Value::Vec4(x), Value::Vec4(y)) => Some(Value::Vec4(from_fn(|i| mix(x[i], y[i])))),
+        (Value::Color(x), Value::Color(y)) => {
+            let (x, y) = (x.to_array(), y.to_array());
+            Some(Value::Color(Color::from_array(from_fn(|i| mix(x[i], y[i])))))
+        }
+        _ if a.is_arithmetic() && b.is_arithmetic() => {
+            let wider = if a.type_width() >= b.type_width() { a } else { b };
+            let target = match wider.value_type() {
+                ValueType::Int => ValueType::Float,
+                other => other,
+            };
+            let (a, b) = (a.coerce_to(target)?, b.coerce_to(target)?);
+            // Both are now the same non-Int type, matched above
+            (a.value_type() == target && b.value_type() == target)
+                .then(|| weighted_sum(&a, &b, gain_a, gain_b))
+                .flatten()
+        }
+        _ => None,
+    }
+}
+
+/// Which input a crossfade reads alone at the ends of its Mix range
+fn crossfade_endpoint(mix: f32) -> Option<usize> {
+    if mix <= 0.0 {
+        Some(0)
+    } else if mix >= 1.0 {
+        Some(1)
+    } else {
+        None
+    }
+}
+
+/// Active inputs of a crossfade with A, B and Mix at indices 0, 1 and 2:
+/// only the selected branch (and Mix) at the ends of the range
+fn crossfade_active_inputs(
+    resolve_cheap: &dyn Fn(usize) -> Option<Value>,
+) -> Option<Vec<ActiveInput>> {
+    let mix = resolve_cheap(2)?.as_float()?;
+    let branch = crossfade_endpoint(mix)?;
+    Some(vec![ActiveInput::Input(branch), ActiveInput::Input(2)])
+}
+
+fn crossfade_input_meta(index: usize) -> Option<PortMeta> {
+    match index {
+        0 => Some(PortMeta::new("A")),
+        1 => Some(PortMeta::new("B")),
+        2 => Some(PortMeta::new("Mix").with_range(0.0, 1.0)),
+        // 0=Linear, 1=EqualPower, 2=Smooth
+        3 => Some(PortMeta::new("Curve").with_range(0.0, 2.0).with_step(1.0)),
+        _ => None,
+    }
+}
+
+// =============================================================================
+// Crossfade Operator (polymorphic)
+// =============================================================================
+
+/// Blends A into B along Mix with a selectable curve
+///
+/// At Mix 0 (or below) the output is A unchanged and at 1 (or above) it is
+/// B, and only that branch is evaluated.
+pub struct CrossfadeOp {
+    id: Id,
+    inputs: Vec<InputPort>,
+    outputs: Vec<OutputPort>,
+}
+
+impl CrossfadeOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: vec![
+                InputPort::arithmetic("A", Value::Float(0.0)),
+                InputPort::arithmetic("B", Value::Float(1.0)),
+                InputPort::float("Mix", 0.0),
+                InputPort::int("Curve", CrossfadeCurve::Linear.index()),
+            ],
+            outputs: vec![OutputPort::wider_of_inputs("Result")],
+        }
+    }
+}
+
+impl Default for CrossfadeOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for CrossfadeOp {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn id(&self) -> Id {
+        self.id
+    }
+    fn name(&self) -> &'static str {
+        "Crossfade"
+    }
+    fn inputs(&self) -> &[InputPort] {
+        &self.inputs
+    }
+    fn inputs_mut(&mut self) -> &mut [InputPort] {
+        &mut self.inputs
+    }
+    fn outputs(&self) -> &[OutputPort] {
+        &self.outputs
+    }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] {
+        &mut self.outputs
+    }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let mix = get_float(&self.inputs[2], get_input);
+        let result = match crossfade_endpoint(mix) {
+            Some(branch) => get_value(&self.inputs[branch], get_input),
+            None => {
+                let curve = CrossfadeCurve::from_index(get_int(&self.inputs[3], get_input));
+                let (gain_a, gain_b) = curve.gains(mix);
+                let a = get_value(&self.inputs[0], get_input);
+                let b = get_value(&self.inputs[1], get_input);
+                weighted_sum(&a, &b, gain_a, gain_b).unwrap_or(Value::Float(0.0))
+            }
+        };
+        self.outputs[0].set(result);
+    }
+
+    fn active_inputs(
+        &self,
+        _ctx: &EvalContext,
+        resolve_cheap: &dyn Fn(usize) -> Option<Value>,
+    ) -> Option<Vec<ActiveInput>> {
+        crossfade_active_inputs(resolve_cheap)
+    }
+}
+
+impl OperatorMeta for CrossfadeOp {
+    fn category(&self) -> &'static str {
+        "Math/Interpolation"
+    }
+    fn category_color(&self) -> [f32; 4] {
+        category_colors::MATH
+    }
+    fn description(&self) -> &'static str {
+        "Crossfades from A to B with a linear, equal-power or smooth curve"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        crossfade_input_meta(index)
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Out").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+    fn swappable_input_pairs(&self) -> &'static [(usize, usize)] {
+        &[(0, 1)]
+    }
+}
+
+// =============================================================================
+// MorphList Operator (FloatList, Vec3List)
+// =============================================================================
+
+/// Crossfades two lists element-wise
+///
+/// Lists of different lengths are zipped to the longer one: where one list
+/// has run out, its element is taken to be the other list's, so the extra
+/// elements pass through unchanged. A FloatList morphing with a Vec3List is
+/// read as a Vec3List. At Mix 0 and 1 the output is A or B unchanged, and
+/// only that branch is evaluated.
+pub struct MorphListOp {
+    id: Id,
+    inputs: [InputPort; 4],
+    outputs: [OutputPort; 1],
+}
+
+impl MorphListOp {
+    pub fn new() -> Self {
+        let list = |name| {
+            InputPort::constrained(
+                name,
+                TypeConstraint::OneOf(vec![ValueType::FloatList, ValueType::Vec3List]),
+                Value::float_list(Vec::new()),
+            )
+        };
+        Self {
+            id: Id::new(),
+            inputs: [
+                list("A"),
+                list("B"),
+                InputPort::float("Mix", 0.0),
+                InputPort::int("Curve", CrossfadeCurve::Linear.index()),
+            ],
+            outputs: [OutputPort::float_list("Result")],
+        }
+    }
+}
+
+impl Default for MorphListOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Zip-longest element-wise blend of two lists of the same element type
+fn morph_elements<T: Copy>(a: &[T], b: &[T], blend: impl Fn(T, T) -> T) -> Vec<T> {
+    (0..a.len().max(b.len()))
+        .map(|i| match (a.get(i), b.get(i)) {
+            (Some(&x), Some(&y)) => blend(x, y),
+            (Some(&x), None) | (None, Some(&x)) => x,
+            (None, None) => unreachable!("index below the longer length"),
+        })
+        .collect()
+}
+
+/// Blend two FloatLists or Vec3Lists element-wise (see [`MorphListOp`])
+pub fn morph_lists(a: &Value, b: &Value, gain_a: f32, gain_b: f32) -> Option<Value> {
+    let mix = |x: f32, y: f32| x * gain_a + y * gain_b;
+    match (a, b) {
+        (Value::FloatList(x), Value::FloatList(y)) => {
+            Some(Value::float_list(morph_elements(x, y, mix)))
+        }
+        (Value::Vec3List(x), Value::Vec3List(y)) => Some(Value::vec3_list(morph_elements(
+            x,
+            y,
+            |x, y| from_fn(|i| mix(x[i], y[i])),
+        ))),
+        (Value::FloatList(_), Value::Vec3List(_)) => {
+            morph_lists(&a.coerce_to(ValueType::Vec3List)?, b, gain_a, gain_b)
+        }
+        (Value::Vec3List(_), Value::FloatList(_)) => {
+            morph_lists(a, &b.coerce_to(ValueType::Vec3List)?, gain_a, gain_b)
+        }
+        _ => None,
+    }
+}
+
+impl Operator for MorphListOp {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+    fn id(&self) -> Id {
+        self.id
+    }
+    fn name(&self) -> &'static str {
+        "MorphList"
+    }
+    fn inputs(&self) -> &[InputPort] {
+        &self.inputs
+    }
+    fn inputs_mut(&mut self) -> &mut [InputPort] {
+        &mut self.inputs
+    }
+    fn outputs(&self) -> &[OutputPort] {
+        &self.outputs
+    }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] {
+        &mut self.outputs
+    }
+    fn cost_hint(&self) -> OperatorCost {
+        OperatorCost::Moderate
+    }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let mix = get_float(&self.inputs[2], get_input);
+        let result = match crossfade_endpoint(mix) {
+            Some(branch) => get_value(&self.inputs[branch], get_input),
+            None => {
+                let curve = CrossfadeCurve::from_index(get_int(&self.inputs[3], get_input));
+                let (gain_a, gain_b) = curve.gains(mix);
+                let a = get_value(&self.inputs[0], get_input);
+                let b = get_value(&self.inputs[1], get_input);
+                morph_lists(&a, &b, gain_a, gain_b).unwrap_or(Value::float_list(Vec::new()))
+            }
+        };
+
+        // Follow the element type of the result
+        if self.outputs[0].value_type != result.value_type() {
+            self.outputs[0] = OutputPort::new("Result", result.value_type());
+        }
+        self.outputs[0].value = result;
+    }
+
+    fn active_inputs(
+        &self,
+        _ctx: &EvalContext,
+        resolve_cheap: &dyn Fn(usize) -> Option<Value>,
+    ) -> Option<Vec<ActiveInput>> {
+        crossfade_active_inputs(resolve_cheap)
+    }
+}
+
+impl OperatorMeta for MorphListOp {
+    fn category(&self) -> &'static str {
+        "Math/Interpolation"
+    }
+    fn category_color(&self) -> [f32; 4] {
+        category_colors::MATH
+    }
+    fn description(&self) -> &'static str {
+        "Crossfades two FloatLists or Vec3Lists element-wise"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        crossfade_input_meta(index)
+    }
     fn output_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
             0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
@@ -528,60 +1410,91 @@ impl OperatorMeta for MapRangeOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Lerp",
-            category: "Math",
-            description: "Linear interpolation between A and B (per-component)",
-        },
+        RegistryEntry::new(
+            "Lerp",
+            "Math/Interpolation",
+            "Linear interpolation between A and B (per-component)",
+        ),
         || capture_meta(LerpOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "SmoothStep",
-            category: "Math",
-            description: "Hermite interpolation with smooth edges (per-component)",
-        },
+        RegistryEntry::new(
+            "SmoothStep",
+            "Math/Interpolation",
+            "Hermite interpolation with smooth edges (per-component), always in 0..1",
+        ),
         || capture_meta(SmoothStepOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Remap",
-            category: "Math",
-            description: "Remaps value from one range to another",
-        },
+        RegistryEntry::new(
+            "Remap",
+            "Math/Interpolation",
+            "Remaps value from one range to another (midpoint for an empty input range), optionally clamped",
+        ),
         || capture_meta(RemapOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "InverseLerp",
-            category: "Math",
-            description: "Gets T from lerp result",
-        },
+        RegistryEntry::new(
+            "InverseLerp",
+            "Math/Interpolation",
+            "Gets T from lerp result (0.5 when A equals B)",
+        ),
         || capture_meta(InverseLerpOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "MapRange",
-            category: "Math",
-            description: "Maps value from one range to another",
-        },
+        RegistryEntry::new(
+            "MapRange",
+            "Math/Interpolation",
+            "Maps value from one range to another (midpoint for an empty From range), optionally clamped",
+        ),
         || capture_meta(MapRangeOp::new()),
     );
+
+    registry.register(
+        RegistryEntry::new(
+            "TransferFunction",
+            "Math/Interpolation",
+            "Maps value through a curve given by (x, y) points",
+        ),
+        || capture_meta(TransferFunctionOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "TransferFunctionList",
+            "Math/Interpolation",
+            "Maps every list value through a curve given by (x, y) points",
+        ),
+        || capture_meta(TransferFunctionListOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "Crossfade",
+            "Math/Interpolation",
+            "Crossfades from A to B with a linear, equal-power or smooth curve",
+        ),
+        || capture_meta(CrossfadeOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "MorphList",
+            "Math/Interpolation",
+            "Crossfades two FloatLists or Vec3Lists element-wise",
+        ),
+        || capture_meta(MorphListOp::new()),
+    );
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use flux_core::Color;
+    use flux_core::{Color, ValidationSeverity};
 
     fn no_connections(_: Id, _: usize) -> Value {
         Value::Float(0.0)
@@ -676,6 +1589,242 @@ mod tests {
         assert_eq!(op.outputs[0].value.as_float(), Some(150.0));
     }
 
+    #[test]
+    fn test_remap_collapsed_range_gives_midpoint() {
+        let ctx = EvalContext::new();
+        let mut remap = RemapOp::new();
+        let mut map_range = MapRangeOp::new();
+        for inputs in [&mut remap.inputs, &mut map_range.inputs] {
+            inputs[0].default = Value::Float(7.0);
+            inputs[1].default = Value::Float(3.0);
+            inputs[2].default = Value::Float(3.0);
+            inputs[3].default = Value::Float(10.0);
+            inputs[4].default = Value::Float(20.0);
+        }
+        remap.compute(&ctx, &no_connections);
+        map_range.compute(&ctx, &no_connections);
+        assert_eq!(remap.outputs[0].value.as_float(), Some(15.0));
+        assert_eq!(map_range.outputs[0].value.as_float(), Some(15.0));
+    }
+
+    #[test]
+    fn test_remap_clamp_with_inverted_output() {
+        let mut op = MapRangeOp::new();
+        op.inputs[3].default = Value::Float(1.0);
+        op.inputs[4].default = Value::Float(0.0);
+        let ctx = EvalContext::new();
+        let mut map = |value: f32, clamp: bool| {
+            op.inputs[0].default = Value::Float(value);
+            op.inputs[5].default = Value::Bool(clamp);
+            op.compute(&ctx, &no_connections);
+            op.outputs[0].value.as_float().unwrap()
+        };
+        assert_eq!(map(0.25, true), 0.75);
+        assert_eq!(map(-1.0, false), 2.0);
+        assert_eq!(map(-1.0, true), 1.0);
+        assert_eq!(map(2.0, true), 0.0);
+    }
+
+    #[test]
+    fn test_inverse_lerp_collapsed_range() {
+        let mut op = InverseLerpOp::new();
+        op.inputs[0].default = Value::Float(4.0);
+        op.inputs[1].default = Value::Float(4.0);
+        op.inputs[2].default = Value::Float(9.0);
+        let ctx = EvalContext::new();
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_float(), Some(0.5));
+    }
+
+    #[test]
+    fn test_smoothstep_collapsed_edges() {
+        let mut op = SmoothStepOp::new();
+        op.inputs[0].default = Value::Float(1.0);
+        op.inputs[1].default = Value::Float(1.0);
+        let ctx = EvalContext::new();
+        let mut step = |x: f32| {
+            op.inputs[2].default = Value::Float(x);
+            op.compute(&ctx, &no_connections);
+            op.outputs[0].value.as_float().unwrap()
+        };
+        assert_eq!((step(0.0), step(1.0), step(2.0)), (0.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn test_extreme_finite_inputs_never_nan() {
+        let extremes = [-f32::MAX, -1.0, 0.0, f32::MIN_POSITIVE, 1.0, f32::MAX];
+        for &a in &extremes {
+            for &b in &extremes {
+                for &x in &extremes {
+                    assert!(!remap_range(x, a, b, b, a, false).is_nan());
+                    assert!(!remap_range(x, a, a, a, b, true).is_nan());
+                    let points = transfer_points(&[a, b, b, x]);
+                    assert!(!piecewise_linear(&points, x).is_nan());
+                    for mode in [OutOfRange::Clamp, OutOfRange::Extrapolate, OutOfRange::Wrap] {
+                        let curve = TransferFunction::new(&[a, a, 0.0, b, x, x], mode, true);
+                        assert!(!curve.apply(b).is_nan(), "{a} {b} {x} {mode:?}");
+                    }
+                }
+            }
+        }
+
+        let mut op = InverseLerpOp::new();
+        op.inputs[0].default = Value::Float(-f32::MAX);
+        op.inputs[1].default = Value::Float(f32::MAX);
+        op.inputs[2].default = Value::Float(0.0);
+        op.compute(&EvalContext::new(), &no_connections);
+        assert_eq!(op.outputs[0].value.as_float(), Some(0.5));
+    }
+
+    fn crossfade(a: Value, b: Value, mix: f32, curve: CrossfadeCurve) -> Value {
+        let mut op = CrossfadeOp::new();
+        op.inputs[0].default = a;
+        op.inputs[1].default = b;
+        op.inputs[2].default = Value::Float(mix);
+        op.inputs[3].default = Value::Int(curve.index());
+        op.compute(&EvalContext::new(), &no_connections);
+        op.outputs[0].value.clone()
+    }
+
+    #[test]
+    fn test_crossfade_curves() {
+        let curves = [
+            CrossfadeCurve::Linear,
+            CrossfadeCurve::EqualPower,
+            CrossfadeCurve::Smooth,
+        ];
+        for curve in curves {
+            assert_eq!(CrossfadeCurve::from_index(curve.index()), curve);
+            for mix in [0.0, 0.25, 0.5, 0.75, 1.0] {
+                let (gain_a, gain_b) = curve.gains(mix);
+                let expected = 10.0 * gain_a + 20.0 * gain_b;
+                let result = crossfade(Value::Float(10.0), Value::Float(20.0), mix, curve);
+                let result = result.as_float().unwrap();
+                assert!((result - expected).abs() < 1e-5, "{:?} at {}", curve, mix);
+            }
+        }
+
+        assert_eq!(CrossfadeCurve::Linear.gains(0.25), (0.75, 0.25));
+        assert_eq!(CrossfadeCurve::Smooth.gains(0.5), (0.5, 0.5));
+        assert!((CrossfadeCurve::Smooth.gains(0.25).1 - 0.15625).abs() < 1e-6);
+        let (a, b) = CrossfadeCurve::EqualPower.gains(0.5);
+        assert!((a - b).abs() < 1e-6 && (a - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+        // Mix outside 0..1 is clamped
+        assert_eq!(CrossfadeCurve::Linear.gains(1.5), (0.0, 1.0));
+    }
+
+    #[test]
+    fn test_crossfade_equal_power_keeps_power() {
+        for i in 0..=20 {
+            let (a, b) = CrossfadeCurve::EqualPower.gains(i as f32 / 20.0);
+            assert!((a * a + b * b - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_crossfade_polymorphic() {
+        let result = crossfade(
+            Value::Vec3([0.0, 2.0, 4.0]),
+            Value::Vec3([4.0, 2.0, 0.0]),
+            0.25,
+            CrossfadeCurve::Linear,
+        );
+        assert_eq!(result, Value::Vec3([1.0, 2.0, 3.0]));
+
+        let result = crossfade(
+            Value::Color(Color::rgba(0.0, 0.0, 0.0, 1.0)),
+            Value::Color(Color::rgba(1.0, 0.5, 0.0, 0.0)),
+            0.5,
+            CrossfadeCurve::Linear,
+        );
+        assert_eq!(result, Value::Color(Color::rgba(0.5, 0.25, 0.0, 0.5)));
+
+        // A scalar blends into every component of a vector
+        let result = crossfade(
+            Value::Float(1.0),
+            Value::Vec2([3.0, 5.0]),
+            0.5,
+            CrossfadeCurve::Linear,
+        );
+        assert_eq!(result, Value::Vec2([2.0, 3.0]));
+
+        // The ends pass the selected input through
+        let b = Value::Vec4([1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(crossfade(Value::Float(0.0), b.clone(), 1.0, CrossfadeCurve::EqualPower), b);
+    }
+
+    #[test]
+    fn test_crossfade_active_inputs() {
+        let op = CrossfadeOp::new();
+        let ctx = EvalContext::new();
+        let with_mix = |mix: Option<f32>| {
+            move |index: usize| match index {
+                2 => mix.map(Value::Float),
+                _ => None,
+            }
+        };
+        let active = |mix| op.active_inputs(&ctx, &with_mix(mix));
+        assert_eq!(
+            active(Some(0.0)),
+            Some(vec![ActiveInput::Input(0), ActiveInput::Input(2)])
+        );
+        assert_eq!(
+            active(Some(1.0)),
+            Some(vec![ActiveInput::Input(1), ActiveInput::Input(2)])
+        );
+        assert_eq!(active(Some(0.5)), None);
+        // A Mix still being computed can't rule out either branch
+        assert_eq!(active(None), None);
+    }
+
+    #[test]
+    fn test_morph_list_mismatched_lengths() {
+        let mut op = MorphListOp::new();
+        op.inputs[0].default = Value::float_list(vec![0.0, 10.0]);
+        op.inputs[1].default = Value::float_list(vec![10.0, 20.0, 30.0, 40.0]);
+        op.inputs[2].default = Value::Float(0.5);
+        let ctx = EvalContext::new();
+        op.compute(&ctx, &no_connections);
+        // Elements past the end of A are B's
+        assert_eq!(
+            op.outputs[0].value,
+            Value::float_list(vec![5.0, 15.0, 30.0, 40.0])
+        );
+
+        op.inputs[0].default = Value::vec3_list(vec![[0.0, 0.0, 0.0], [1.0, 1.0, 1.0]]);
+        op.inputs[1].default = Value::vec3_list(vec![[2.0, 4.0, 6.0]]);
+        op.inputs[2].default = Value::Float(0.25);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value_type, ValueType::Vec3List);
+        assert_eq!(
+            op.outputs[0].value,
+            Value::vec3_list(vec![[0.5, 1.0, 1.5], [1.0, 1.0, 1.0]])
+        );
+
+        // At the ends the selected list passes through at its own length
+        op.inputs[2].default = Value::Float(0.0);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.list_len(), Some(2));
+    }
+
+    #[test]
+    fn test_map_range_validation() {
+        let op = MapRangeOp::new();
+        let proposed = |ranges: [f32; 4]| {
+            let mut values = vec![Value::Float(0.5)];
+            values.extend(ranges.map(Value::Float));
+            values
+        };
+        assert!(op.validate_inputs(&proposed([0.0, 1.0, 0.0, 1.0])).is_empty());
+
+        let issues = op.validate_inputs(&proposed([2.0, 2.0, 5.0, 5.0]));
+        let flagged: Vec<_> = issues.iter().map(|i| (i.input, i.severity)).collect();
+        assert_eq!(
+            flagged,
+            [(2, ValidationSeverity::Warning), (4, ValidationSeverity::Info)]
+        );
+    }
+
     // Vec3 tests (polymorphic)
     #[test]
     fn test_lerp_vec3() {
@@ -721,4 +1870,111 @@ mod tests {
             panic!("Expected Vec3");
         }
     }
+
+    // Transfer function kernels
+    #[test]
+    fn test_transfer_points_sorted_last_wins() {
+        let points = transfer_points(&[2.0, 4.0, 0.0, 0.0, 1.0, 1.0, 1.0, 3.0, 5.0]);
+        assert_eq!(points, vec![[0.0, 0.0], [1.0, 3.0], [2.0, 4.0]]);
+        assert!(transfer_points(&[f32::NAN, 1.0]).is_empty());
+    }
+
+    #[test]
+    fn test_piecewise_linear_known_values() {
+        let points = [[0.0, 0.0], [1.0, 2.0], [3.0, 0.0]];
+        assert_eq!(piecewise_linear(&points, 0.5), 1.0);
+        assert_eq!(piecewise_linear(&points, 1.0), 2.0);
+        assert_eq!(piecewise_linear(&points, 2.0), 1.0);
+        // End segments continue outside the points
+        assert_eq!(piecewise_linear(&points, -1.0), -2.0);
+        assert_eq!(piecewise_linear(&points, 4.0), -1.0);
+        assert_eq!(piecewise_linear(&[], 0.7), 0.7);
+        assert_eq!(piecewise_linear(&[[1.0, 5.0]], 0.7), 5.0);
+    }
+
+    #[test]
+    fn test_monotone_cubic_known_values() {
+        // Peak: the middle tangent is zero, the ends follow the secants
+        let peak = [[0.0, 0.0], [1.0, 1.0], [2.0, 0.0]];
+        let tangents = monotone_tangents(&peak);
+        assert_eq!(tangents, vec![1.0, 0.0, -1.0]);
+        assert!((monotone_cubic(&peak, &tangents, 0.5) - 0.625).abs() < 1e-6);
+        assert!((monotone_cubic(&peak, &tangents, 1.5) - 0.625).abs() < 1e-6);
+
+        // Collinear points reproduce the line
+        let line = [[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]];
+        let tangents = monotone_tangents(&line);
+        assert!((monotone_cubic(&line, &tangents, 1.5) - 1.5).abs() < 1e-6);
+
+        // A steep step gets its tangents limited (alpha^2 + beta^2 <= 9)
+        let step = [[0.0, 0.0], [1.0, 0.1], [2.0, 10.0], [3.0, 10.1]];
+        let tangents = monotone_tangents(&step);
+        for k in 0..3 {
+            let slope = (step[k + 1][1] - step[k][1]) / (step[k + 1][0] - step[k][0]);
+            let (a, b) = (tangents[k] / slope, tangents[k + 1] / slope);
+            assert!(a * a + b * b <= 9.0 + 1e-4);
+        }
+    }
+
+    fn transfer(points: &[f32], mode: OutOfRange, smooth: bool, x: f32) -> f32 {
+        let mut op = TransferFunctionOp::new();
+        op.inputs[0].default = Value::Float(x);
+        op.inputs[1].default = Value::float_list(points.to_vec());
+        op.inputs[2].default = Value::Int(mode.index());
+        op.inputs[3].default = Value::Bool(smooth);
+        op.compute(&EvalContext::new(), &no_connections);
+        op.outputs[0].value.as_float().unwrap()
+    }
+
+    #[test]
+    fn test_transfer_function_unsorted_points() {
+        // (1, 10), (0, 0), (2, 0) given out of order
+        let points = [1.0, 10.0, 0.0, 0.0, 2.0, 0.0];
+        assert_eq!(transfer(&points, OutOfRange::Clamp, false, 0.5), 5.0);
+        assert_eq!(transfer(&points, OutOfRange::Clamp, false, 1.5), 5.0);
+    }
+
+    #[test]
+    fn test_transfer_function_out_of_range_modes() {
+        let points = [0.0, 0.0, 1.0, 2.0];
+        assert_eq!(transfer(&points, OutOfRange::Clamp, false, 1.5), 2.0);
+        assert_eq!(transfer(&points, OutOfRange::Clamp, false, -1.0), 0.0);
+        assert_eq!(transfer(&points, OutOfRange::Extrapolate, false, 1.5), 3.0);
+        assert_eq!(
+            transfer(&points, OutOfRange::Extrapolate, false, -1.0),
+            -2.0
+        );
+        assert_eq!(transfer(&points, OutOfRange::Wrap, false, 1.25), 0.5);
+        assert_eq!(transfer(&points, OutOfRange::Wrap, false, -0.25), 1.5);
+
+        // Smooth extrapolation continues along the end tangent
+        assert_eq!(transfer(&points, OutOfRange::Extrapolate, true, 2.0), 4.0);
+    }
+
+    #[test]
+    fn test_transfer_function_smooth_is_monotone() {
+        // Plateau then a jump: a plain cubic spline would overshoot here
+        let points = [0.0, 0.0, 1.0, 0.0, 1.2, 1.0, 3.0, 1.0];
+        let mut previous = f32::NEG_INFINITY;
+        for i in 0..=300 {
+            let y = transfer(&points, OutOfRange::Clamp, true, i as f32 / 100.0);
+            assert!((0.0..=1.0).contains(&y), "overshoot: {}", y);
+            assert!(y >= previous, "not monotone at {}", i);
+            previous = y;
+        }
+        // Still passes through the points
+        assert_eq!(transfer(&points, OutOfRange::Clamp, true, 1.2), 1.0);
+    }
+
+    #[test]
+    fn test_transfer_function_list() {
+        let mut op = TransferFunctionListOp::new();
+        op.inputs[0].default = Value::float_list(vec![-1.0, 0.25, 0.5, 2.0]);
+        op.inputs[1].default = Value::float_list(vec![0.0, 0.0, 1.0, 2.0]);
+        op.compute(&EvalContext::new(), &no_connections);
+        assert_eq!(
+            op.outputs[0].value.as_float_list(),
+            Some(&[0.0, 0.5, 1.0, 2.0][..])
+        );
+    }
 }
@@ -3,9 +3,14 @@
 //! - Arithmetic (14): Add, Subtract, Multiply, Divide, Modulo, Pow, Sqrt, Log, Abs, Negate, Floor, Ceil, Round, Truncate
 //!   All arithmetic operators are polymorphic and work with Float, Int, Vec2, Vec3, Vec4, and Color.
 //! - Comparison (5): Min, Max, Clamp, Sign, Step - all polymorphic
-//! - Interpolation (5): Lerp, SmoothStep (polymorphic), Remap, InverseLerp, MapRange
+//! - Interpolation (9): Lerp, SmoothStep, Crossfade (polymorphic), Remap, InverseLerp,
+//!   MapRange, TransferFunction, TransferFunctionList, MorphList
 //! - Trigonometry (6): Sin, Cos (polymorphic), Tan, Atan2, DegreesToRadians, RadiansToDegrees
 //! - Random/Noise (4): Random, PerlinNoise, PerlinNoise3D, Hash
+//!
+//! Operators are registered in the sub-categories `Math/Arithmetic` (which
+//! also holds comparison), `Math/Interpolation`, `Math/Trig` and
+//! `Math/Random`.
 
 mod arithmetic;
 mod comparison;
@@ -4,7 +4,7 @@ use std::any::Any;
 
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
-use flux_core::operator::{InputResolver, Operator};
+use flux_core::operator::{InputResolver, Operator, OperatorCost};
 use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::port::{InputPort, OutputPort};
@@ -206,7 +206,7 @@ impl Operator for RandomOp {
 }
 
 impl OperatorMeta for RandomOp {
-    fn category(&self) -> &'static str { "Math" }
+    fn category(&self) -> &'static str { "Math/Random" }
     fn category_color(&self) -> [f32; 4] { category_colors::MATH }
     fn description(&self) -> &'static str { "Deterministic random value in range" }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
@@ -264,6 +264,7 @@ impl Operator for PerlinNoiseOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Expensive }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let x = get_float(&self.inputs[0], get_input);
@@ -276,7 +277,7 @@ impl Operator for PerlinNoiseOp {
 }
 
 impl OperatorMeta for PerlinNoiseOp {
-    fn category(&self) -> &'static str { "Math" }
+    fn category(&self) -> &'static str { "Math/Random" }
     fn category_color(&self) -> [f32; 4] { category_colors::MATH }
     fn description(&self) -> &'static str { "2D Perlin noise" }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
@@ -335,6 +336,7 @@ impl Operator for PerlinNoise3DOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn cost_hint(&self) -> OperatorCost { OperatorCost::Expensive }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let x = get_float(&self.inputs[0], get_input);
@@ -348,7 +350,7 @@ impl Operator for PerlinNoise3DOp {
 }
 
 impl OperatorMeta for PerlinNoise3DOp {
-    fn category(&self) -> &'static str { "Math" }
+    fn category(&self) -> &'static str { "Math/Random" }
     fn category_color(&self) -> [f32; 4] { category_colors::MATH }
     fn description(&self) -> &'static str { "3D Perlin noise" }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
@@ -420,7 +422,7 @@ impl Operator for HashOp {
 }
 
 impl OperatorMeta for HashOp {
-    fn category(&self) -> &'static str { "Math" }
+    fn category(&self) -> &'static str { "Math/Random" }
     fn category_color(&self) -> [f32; 4] { category_colors::MATH }
     fn description(&self) -> &'static str { "Deterministic hash of value" }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
@@ -444,42 +446,22 @@ impl OperatorMeta for HashOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Random",
-            category: "Math",
-            description: "Deterministic random value in range",
-        },
+        RegistryEntry::new("Random", "Math/Random", "Deterministic random value in range"),
         || capture_meta(RandomOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "PerlinNoise",
-            category: "Math",
-            description: "2D Perlin noise",
-        },
+        RegistryEntry::new("PerlinNoise", "Math/Random", "2D Perlin noise"),
         || capture_meta(PerlinNoiseOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "PerlinNoise3D",
-            category: "Math",
-            description: "3D Perlin noise",
-        },
+        RegistryEntry::new("PerlinNoise3D", "Math/Random", "3D Perlin noise"),
         || capture_meta(PerlinNoise3DOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Hash",
-            category: "Math",
-            description: "Deterministic hash of value",
-        },
+        RegistryEntry::new("Hash", "Math/Random", "Deterministic hash of value"),
         || capture_meta(HashOp::new()),
     );
 }
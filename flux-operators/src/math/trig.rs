@@ -4,13 +4,14 @@
 //! Float, Int, Vec2, Vec3, Vec4
 
 use std::any::Any;
+use std::f32::consts::PI;
 
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
 use flux_core::operator::{InputResolver, Operator};
 use flux_core::port::{InputPort, OutputPort};
-use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Value};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Unit, Value};
 
 // =============================================================================
 // Helper functions
@@ -91,7 +92,7 @@ impl Operator for SinOp {
 
 impl OperatorMeta for SinOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Trig"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -101,7 +102,7 @@ impl OperatorMeta for SinOp {
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Angle").with_unit("rad")),
+            0 => Some(PortMeta::new("Angle").with_typed_unit(Unit::Radians)),
             _ => None,
         }
     }
@@ -174,7 +175,7 @@ impl Operator for CosOp {
 
 impl OperatorMeta for CosOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Trig"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -184,7 +185,7 @@ impl OperatorMeta for CosOp {
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Angle").with_unit("rad")),
+            0 => Some(PortMeta::new("Angle").with_typed_unit(Unit::Radians)),
             _ => None,
         }
     }
@@ -256,7 +257,7 @@ impl Operator for TanOp {
 
 impl OperatorMeta for TanOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Trig"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -266,7 +267,7 @@ impl OperatorMeta for TanOp {
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Angle").with_unit("rad")),
+            0 => Some(PortMeta::new("Angle").with_typed_unit(Unit::Radians)),
             _ => None,
         }
     }
@@ -282,6 +283,10 @@ impl OperatorMeta for TanOp {
 // Atan2 Operator (float-only, inherently scalar)
 // =============================================================================
 
+/// Angle of the point (X, Y) in -π..π
+///
+/// The origin has no direction; it gives 0 whatever the signs of the zeros,
+/// where `f32::atan2` would give ±π for a negative-zero X.
 pub struct Atan2Op {
     id: Id,
     inputs: [InputPort; 2],
@@ -333,19 +338,20 @@ impl Operator for Atan2Op {
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let y = get_float(&self.inputs[0], get_input);
         let x = get_float(&self.inputs[1], get_input);
-        self.outputs[0].set_float(y.atan2(x));
+        let angle = if y == 0.0 && x == 0.0 { 0.0 } else { y.atan2(x) };
+        self.outputs[0].set_float(angle);
     }
 }
 
 impl OperatorMeta for Atan2Op {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Trig"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
     }
     fn description(&self) -> &'static str {
-        "Two-argument arctangent"
+        "Two-argument arctangent in -π..π (0 at the origin)"
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
@@ -359,7 +365,8 @@ impl OperatorMeta for Atan2Op {
             0 => Some(
                 PortMeta::new("Angle")
                     .with_shape(PinShape::TriangleFilled)
-                    .with_unit("rad"),
+                    .with_typed_unit(Unit::Radians)
+                    .with_range(-PI, PI),
             ),
             _ => None,
         }
@@ -426,7 +433,7 @@ impl Operator for DegreesToRadiansOp {
 
 impl OperatorMeta for DegreesToRadiansOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Trig"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -436,7 +443,7 @@ impl OperatorMeta for DegreesToRadiansOp {
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Degrees").with_unit("deg")),
+            0 => Some(PortMeta::new("Degrees").with_typed_unit(Unit::Degrees)),
             _ => None,
         }
     }
@@ -445,7 +452,7 @@ impl OperatorMeta for DegreesToRadiansOp {
             0 => Some(
                 PortMeta::new("Radians")
                     .with_shape(PinShape::TriangleFilled)
-                    .with_unit("rad"),
+                    .with_typed_unit(Unit::Radians),
             ),
             _ => None,
         }
@@ -512,7 +519,7 @@ impl Operator for RadiansToDegreesOp {
 
 impl OperatorMeta for RadiansToDegreesOp {
     fn category(&self) -> &'static str {
-        "Math"
+        "Math/Trig"
     }
     fn category_color(&self) -> [f32; 4] {
         category_colors::MATH
@@ -522,7 +529,7 @@ impl OperatorMeta for RadiansToDegreesOp {
     }
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Radians").with_unit("rad")),
+            0 => Some(PortMeta::new("Radians").with_typed_unit(Unit::Radians)),
             _ => None,
         }
     }
@@ -531,7 +538,7 @@ impl OperatorMeta for RadiansToDegreesOp {
             0 => Some(
                 PortMeta::new("Degrees")
                     .with_shape(PinShape::TriangleFilled)
-                    .with_unit("deg"),
+                    .with_typed_unit(Unit::Degrees),
             ),
             _ => None,
         }
@@ -544,62 +551,36 @@ impl OperatorMeta for RadiansToDegreesOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Sin",
-            category: "Math",
-            description: "Sine of angle (radians, per-component)",
-        },
+        RegistryEntry::new("Sin", "Math/Trig", "Sine of angle (radians, per-component)"),
         || capture_meta(SinOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Cos",
-            category: "Math",
-            description: "Cosine of angle (radians, per-component)",
-        },
+        RegistryEntry::new("Cos", "Math/Trig", "Cosine of angle (radians, per-component)"),
         || capture_meta(CosOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Tan",
-            category: "Math",
-            description: "Tangent of angle (radians)",
-        },
+        RegistryEntry::new("Tan", "Math/Trig", "Tangent of angle (radians)"),
         || capture_meta(TanOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Atan2",
-            category: "Math",
-            description: "Two-argument arctangent",
-        },
+        RegistryEntry::new(
+            "Atan2",
+            "Math/Trig",
+            "Two-argument arctangent in -π..π (0 at the origin)",
+        ),
         || capture_meta(Atan2Op::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "DegreesToRadians",
-            category: "Math",
-            description: "Converts degrees to radians",
-        },
+        RegistryEntry::new("DegreesToRadians", "Math/Trig", "Converts degrees to radians"),
         || capture_meta(DegreesToRadiansOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "RadiansToDegrees",
-            category: "Math",
-            description: "Converts radians to degrees",
-        },
+        RegistryEntry::new("RadiansToDegrees", "Math/Trig", "Converts radians to degrees"),
         || capture_meta(RadiansToDegreesOp::new()),
     );
 }
@@ -654,6 +635,24 @@ mod tests {
         assert!((result - PI / 4.0).abs() < 0.0001);
     }
 
+    #[test]
+    fn test_atan2_origin_is_zero() {
+        let mut op = Atan2Op::new();
+        let ctx = EvalContext::new();
+        for (y, x) in [(0.0, 0.0), (-0.0, -0.0), (0.0, -0.0), (-0.0, 0.0)] {
+            op.inputs[0].default = Value::Float(y);
+            op.inputs[1].default = Value::Float(x);
+            op.compute(&ctx, &no_connections);
+            assert_eq!(op.outputs[0].value.as_float(), Some(0.0));
+        }
+
+        // Everywhere else the full -π..π range is kept
+        op.inputs[0].default = Value::Float(0.0);
+        op.inputs[1].default = Value::Float(-1.0);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_float(), Some(PI));
+    }
+
     #[test]
     fn test_degrees_to_radians() {
         let mut op = DegreesToRadiansOp::new();
@@ -0,0 +1,23 @@
+//! Interned names for generated ports
+//!
+//! Port names are `&'static str`. Operators that derive ports from user data
+//! (template placeholders, map fields) leak each distinct name once, so
+//! regenerating ports doesn't grow memory.
+
+use std::collections::HashSet;
+use std::sync::{Mutex, OnceLock};
+
+/// Get a `'static` copy of `name`, leaking it the first time it is seen
+pub(crate) fn intern(name: &str) -> &'static str {
+    static NAMES: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+    let mut names = NAMES
+        .get_or_init(|| Mutex::new(HashSet::new()))
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some(&existing) = names.get(name) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(name.to_string().into_boxed_str());
+    names.insert(leaked);
+    leaked
+}
@@ -1,15 +1,36 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 
+use flux_core::category::Category;
 use flux_core::id::Id;
 use flux_core::operator::Operator;
 use flux_core::operator_meta::PortMeta;
+use serde::Serialize;
 
-/// Result of creating an operator: the operator and its input port metadata.
+/// Result of creating an operator: the operator and its captured metadata.
 ///
-/// The `PortMeta` must be captured at creation time because `OperatorMeta` is not
-/// object-safe and cannot be accessed through `Box<dyn Operator>`.
-pub type OperatorWithMeta = (Box<dyn Operator>, Vec<Option<PortMeta>>);
+/// The metadata must be captured at creation time because `OperatorMeta` cannot
+/// be accessed through `Box<dyn Operator>`.
+pub type OperatorWithMeta = (Box<dyn Operator>, CapturedMeta);
+
+/// `OperatorMeta` captured from an operator before boxing it
+#[derive(Debug, Clone, Default)]
+pub struct CapturedMeta {
+    /// Input port metadata by index
+    pub inputs: Vec<Option<PortMeta>>,
+    /// Output port metadata by index
+    pub outputs: Vec<Option<PortMeta>>,
+    /// `OperatorMeta` category, which theme category overrides match against
+    pub category: Option<Category>,
+    /// Category color, if the operator implements `OperatorMeta`
+    pub category_color: Option<[f32; 4]>,
+    /// Titlebar icon, if any
+    pub icon: Option<&'static str>,
+    /// Deprecation notice from `OperatorMeta`, if any
+    pub deprecation: Option<&'static str>,
+    /// Input pairs offered for swapping, from `OperatorMeta`
+    pub swappable_input_pairs: &'static [(usize, usize)],
+}
 
 /// Factory function type for creating operators with metadata capture.
 pub type MetaCapturingFactory = Arc<dyn Fn() -> OperatorWithMeta + Send + Sync>;
@@ -33,12 +54,36 @@ pub type ParameterizedFactory = Arc<dyn Fn(&OperatorParams) -> Box<dyn Operator>
 pub struct RegistryEntry {
     pub type_id: Id,
     pub name: &'static str,
+    /// Category path, sub-categories separated by `/` (e.g. "Math/Trig")
     pub category: &'static str,
     pub description: &'static str,
+    /// Hidden from listings and the catalog, but still created by name
+    pub deprecated: bool,
+    /// Operator to use instead of a deprecated one
+    pub replaced_by: Option<&'static str>,
+}
+
+impl RegistryEntry {
+    /// Entry for a current (not deprecated) operator with a fresh type ID
+    pub fn new(name: &'static str, category: &'static str, description: &'static str) -> Self {
+        Self {
+            type_id: Id::new(),
+            name,
+            category,
+            description,
+            deprecated: false,
+            replaced_by: None,
+        }
+    }
+
+    /// Structured form of `category`
+    pub fn category_path(&self) -> Category {
+        Category::parse(self.category)
+    }
 }
 
 /// Metadata about an operator parameter
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct ParameterMeta {
     /// Parameter name used in OperatorParams
     pub name: &'static str,
@@ -49,7 +94,7 @@ pub struct ParameterMeta {
 }
 
 /// Type of an operator parameter
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ParameterType {
     /// Float with optional min/max range
     Float { min: Option<f32>, max: Option<f32> },
@@ -59,15 +104,18 @@ pub enum ParameterType {
     Bool,
     /// Enum with named variants
     Enum { variants: Vec<&'static str> },
+    /// Free-form text
+    String,
 }
 
 /// Value for an operator parameter
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub enum ParameterValue {
     Float(f32),
     Int(i32),
     Bool(bool),
     Enum(&'static str),
+    String(String),
 }
 
 impl ParameterValue {
@@ -98,6 +146,13 @@ impl ParameterValue {
             _ => None,
         }
     }
+
+    pub fn as_string(&self) -> Option<&str> {
+        match self {
+            ParameterValue::String(v) => Some(v),
+            _ => None,
+        }
+    }
 }
 
 /// Parameters for creating an operator
@@ -154,6 +209,14 @@ impl OperatorParams {
             .and_then(|v| v.as_enum())
             .unwrap_or(default)
     }
+
+    /// Get a string parameter with fallback to default
+    pub fn get_string<'a>(&'a self, name: &str, default: &'a str) -> &'a str {
+        self.values
+            .get(name)
+            .and_then(|v| v.as_string())
+            .unwrap_or(default)
+    }
 }
 
 /// Extended metadata for operators with parameters
@@ -164,6 +227,38 @@ pub struct ExtendedEntry {
     pub parameters: Vec<ParameterMeta>,
 }
 
+/// A category and the operators in it, from [`OperatorRegistry::category_tree`]
+#[derive(Clone)]
+pub struct CategoryNode {
+    pub category: Category,
+    /// Operators directly in this category, sorted by name
+    pub operators: Vec<ExtendedEntry>,
+    /// Sub-categories, sorted
+    pub children: Vec<CategoryNode>,
+}
+
+impl CategoryNode {
+    fn new(category: Category) -> Self {
+        Self { category, operators: Vec::new(), children: Vec::new() }
+    }
+
+    /// Number of operators in this category and its sub-categories
+    pub fn operator_count(&self) -> usize {
+        self.operators.len() + self.children.iter().map(CategoryNode::operator_count).sum::<usize>()
+    }
+
+    /// The node for `category` if it is this one or below it
+    pub fn find(&self, category: &Category) -> Option<&CategoryNode> {
+        if self.category == *category {
+            return Some(self);
+        }
+        if !category.is_within(&self.category) {
+            return None;
+        }
+        self.children.iter().find_map(|child| child.find(category))
+    }
+}
+
 /// Registration entry for an operator
 struct Registration {
     entry: ExtendedEntry,
@@ -185,13 +280,13 @@ struct Registration {
 ///
 /// // Register a simple operator
 /// registry.register(
-///     RegistryEntry { type_id: Id::new(), name: "Add", category: "Math", description: "..." },
+///     RegistryEntry::new("Add", "Math", "..."),
 ///     || Box::new(AddOp::new()),
 /// );
 ///
 /// // Register an operator with parameters
 /// registry.register_with_params(
-///     RegistryEntry { type_id: Id::new(), name: "Compare", category: "Logic", description: "..." },
+///     RegistryEntry::new("Compare", "Logic", "..."),
 ///     || Box::new(CompareOp::new(CompareMode::Equal)),
 ///     |params| {
 ///         let mode = match params.get_enum("mode", "Equal") {
@@ -213,10 +308,10 @@ struct Registration {
 /// let compare = registry.create_with_params("Compare", OperatorParams::new()
 ///     .set("mode", ParameterValue::Enum("GreaterThan")));
 ///
-/// // List by category
-/// for (category, entries) in registry.by_category() {
-///     println!("{}:", category);
-///     for entry in entries {
+/// // List by category, sub-categories nested in their parents
+/// for node in registry.category_tree() {
+///     println!("{}: {} operators", node.category, node.operator_count());
+///     for entry in &node.operators {
 ///         println!("  - {}", entry.meta.name);
 ///     }
 /// }
@@ -226,6 +321,8 @@ pub struct OperatorRegistry {
     by_id: RwLock<HashMap<Id, Registration>>,
     /// Lookup by name for convenience
     by_name: RwLock<HashMap<&'static str, Id>>,
+    /// Old operator names -> the names they were renamed to
+    renamed: RwLock<HashMap<&'static str, &'static str>>,
 }
 
 /// Backward-compatible type alias
@@ -237,39 +334,37 @@ impl OperatorRegistry {
         Self {
             by_id: RwLock::new(HashMap::new()),
             by_name: RwLock::new(HashMap::new()),
+            renamed: RwLock::new(HashMap::new()),
         }
     }
 
     /// Register an operator type with the registry.
     ///
-    /// The factory function must return `(Box<dyn Operator>, Vec<Option<PortMeta>>)`
-    /// to capture input port metadata before boxing.
+    /// The factory function must return an [`OperatorWithMeta`]
+    /// to capture port metadata before boxing.
     ///
     /// Use the `capture!` helper macro or call `capture_meta()` to create the factory.
+    ///
+    /// Registering a name that is already registered replaces the previous
+    /// registration but keeps its type ID, so registering twice is harmless.
     pub fn register<F>(&self, meta: RegistryEntry, factory: F)
     where
         F: Fn() -> OperatorWithMeta + Send + Sync + 'static,
     {
-        let type_id = meta.type_id;
-        let name = meta.name;
-
-        let registration = Registration {
+        self.insert(Registration {
             entry: ExtendedEntry {
                 meta,
                 parameters: Vec::new(),
             },
             factory: Arc::new(factory),
             param_factory: None,
-        };
-
-        self.by_id.write().unwrap().insert(type_id, registration);
-        self.by_name.write().unwrap().insert(name, type_id);
+        });
     }
 
     /// Register an operator with parameter support.
     ///
-    /// Both factory functions must return `(Box<dyn Operator>, Vec<Option<PortMeta>>)`
-    /// to capture input port metadata before boxing.
+    /// Both factory functions must return an [`OperatorWithMeta`]
+    /// to capture port metadata before boxing.
     pub fn register_with_params<F, P>(
         &self,
         meta: RegistryEntry,
@@ -280,17 +375,61 @@ impl OperatorRegistry {
         F: Fn() -> OperatorWithMeta + Send + Sync + 'static,
         P: Fn(&OperatorParams) -> OperatorWithMeta + Send + Sync + 'static,
     {
-        let type_id = meta.type_id;
-        let name = meta.name;
-
-        let registration = Registration {
+        self.insert(Registration {
             entry: ExtendedEntry { meta, parameters },
             factory: Arc::new(factory),
             param_factory: Some(Arc::new(param_factory)),
-        };
+        });
+    }
+
+    /// Store a registration, replacing any previous one with the same name.
+    fn insert(&self, mut registration: Registration) {
+        let mut by_id = self.by_id.write().unwrap();
+        let mut by_name = self.by_name.write().unwrap();
+
+        let name = registration.entry.meta.name;
+        if let Some(&existing) = by_name.get(name) {
+            by_id.remove(&existing);
+            registration.entry.meta.type_id = existing;
+        }
+        let type_id = registration.entry.meta.type_id;
+        by_id.insert(type_id, registration);
+        by_name.insert(name, type_id);
+    }
+
+    /// Record that an operator was renamed
+    ///
+    /// `old_name` keeps creating the operator registered as `new_name`, so
+    /// saved files using it still load, but listings and the catalog only
+    /// show the new name. Renames may chain; the new name doesn't have to be
+    /// registered yet.
+    pub fn register_renamed(&self, old_name: &'static str, new_name: &'static str) {
+        self.renamed.write().unwrap().insert(old_name, new_name);
+    }
+
+    /// The current name of a renamed operator
+    ///
+    /// Returns `None` if `name` was never renamed, or is registered itself.
+    pub fn renamed_to(&self, name: &str) -> Option<&'static str> {
+        if self.by_name.read().unwrap().contains_key(name) {
+            return None;
+        }
+        let renamed = self.renamed.read().unwrap();
+        let mut current = *renamed.get(name)?;
+        // Follow chained renames, bounded in case of a cycle
+        for _ in 0..renamed.len() {
+            match renamed.get(current) {
+                Some(&next) => current = next,
+                None => break,
+            }
+        }
+        Some(current)
+    }
 
-        self.by_id.write().unwrap().insert(type_id, registration);
-        self.by_name.write().unwrap().insert(name, type_id);
+    /// Type ID for a registered name, following renames
+    fn lookup(&self, name: &str) -> Option<Id> {
+        let name = self.renamed_to(name).unwrap_or(name);
+        self.by_name.read().unwrap().get(name).copied()
     }
 
     /// Register an operator using a simpler interface.
@@ -301,14 +440,9 @@ impl OperatorRegistry {
     where
         F: Fn() -> Box<dyn Operator> + Send + Sync + 'static,
     {
-        let meta = RegistryEntry {
-            type_id: Id::new(),
-            name,
-            category: "Uncategorized",
-            description: "",
-        };
+        let meta = RegistryEntry::new(name, "Uncategorized", "");
         // Wrap in metadata-capturing factory with empty metadata
-        self.register(meta, move || (factory(), Vec::new()));
+        self.register(meta, move || (factory(), CapturedMeta::default()));
     }
 
     /// Create an operator instance by type ID with default parameters.
@@ -324,7 +458,7 @@ impl OperatorRegistry {
     /// Returns the operator without port metadata. For UI integration that needs
     /// port metadata (ranges, labels), use `create_with_meta_by_name()` instead.
     pub fn create_by_name(&self, name: &str) -> Option<Box<dyn Operator>> {
-        let type_id = self.by_name.read().unwrap().get(name).copied()?;
+        let type_id = self.lookup(name)?;
         self.create_by_id(type_id)
     }
 
@@ -343,7 +477,7 @@ impl OperatorRegistry {
     ///
     /// Returns `(operator, input_port_metadata)` for UI integration.
     pub fn create_with_meta_by_name(&self, name: &str) -> Option<OperatorWithMeta> {
-        let type_id = self.by_name.read().unwrap().get(name).copied()?;
+        let type_id = self.lookup(name)?;
         self.create_with_meta_by_id(type_id)
     }
 
@@ -369,7 +503,7 @@ impl OperatorRegistry {
         name: &str,
         params: &OperatorParams,
     ) -> Option<Box<dyn Operator>> {
-        let type_id = self.by_name.read().unwrap().get(name).copied()?;
+        let type_id = self.lookup(name)?;
         self.create_with_params_by_id(type_id, params)
     }
 
@@ -398,7 +532,7 @@ impl OperatorRegistry {
         name: &str,
         params: &OperatorParams,
     ) -> Option<OperatorWithMeta> {
-        let type_id = self.by_name.read().unwrap().get(name).copied()?;
+        let type_id = self.lookup(name)?;
         self.create_with_meta_and_params_by_id(type_id, params)
     }
 
@@ -422,49 +556,96 @@ impl OperatorRegistry {
 
     /// Get extended metadata by name
     pub fn get_extended_meta_by_name(&self, name: &str) -> Option<ExtendedEntry> {
-        let type_id = self.by_name.read().unwrap().get(name).copied()?;
+        let type_id = self.lookup(name)?;
         self.get_extended_meta(type_id)
     }
 
-    /// Get the type ID for an operator name
+    /// Get the type ID for an operator name, following renames
     pub fn get_type_id(&self, name: &str) -> Option<Id> {
-        self.by_name.read().unwrap().get(name).copied()
+        self.lookup(name)
     }
 
-    /// List all registered operator names
+    /// List all registered operator names, except deprecated ones
     pub fn list_names(&self) -> Vec<&'static str> {
-        self.by_name.read().unwrap().keys().copied().collect()
+        self.list_all().into_iter().map(|meta| meta.name).collect()
     }
 
-    /// List all registered operators with their metadata
+    /// List all registered operators with their metadata, except deprecated ones
     pub fn list_all(&self) -> Vec<RegistryEntry> {
+        self.list_all_extended()
+            .into_iter()
+            .map(|entry| entry.meta)
+            .collect()
+    }
+
+    /// List all registered operators with extended metadata, except deprecated ones
+    pub fn list_all_extended(&self) -> Vec<ExtendedEntry> {
         self.by_id
             .read()
             .unwrap()
             .values()
-            .map(|reg| reg.entry.meta.clone())
+            .filter(|reg| !reg.entry.meta.deprecated)
+            .map(|reg| reg.entry.clone())
             .collect()
     }
 
-    /// List all registered operators with extended metadata
-    pub fn list_all_extended(&self) -> Vec<ExtendedEntry> {
-        self.by_id
+    /// List every operator that can be created by name
+    ///
+    /// Includes deprecated operators, and an entry for each renamed name that
+    /// resolves: a copy of the current operator's entry under the old name,
+    /// marked deprecated and `replaced_by` the current name.
+    pub fn list_all_including_deprecated(&self) -> Vec<ExtendedEntry> {
+        let mut entries: Vec<ExtendedEntry> = self
+            .by_id
             .read()
             .unwrap()
             .values()
             .map(|reg| reg.entry.clone())
-            .collect()
+            .collect();
+
+        let old_names: Vec<&'static str> = self.renamed.read().unwrap().keys().copied().collect();
+        for old_name in old_names {
+            let Some(new_name) = self.renamed_to(old_name) else {
+                continue;
+            };
+            if let Some(mut entry) = self.get_extended_meta_by_name(new_name) {
+                entry.meta.name = old_name;
+                entry.meta.deprecated = true;
+                entry.meta.replaced_by = Some(new_name);
+                entries.push(entry);
+            }
+        }
+        entries
+    }
+
+    /// Find operators for a picker by name, category or description
+    ///
+    /// Matching is case-insensitive. Exact name matches come first, then name
+    /// prefixes, then other name matches, then category and description
+    /// matches; ties are sorted by name. Deprecated operators are left out.
+    pub fn search(&self, query: &str) -> Vec<ExtendedEntry> {
+        rank_matches(self.list_all_extended(), query)
+    }
+
+    /// Like [`search`](Self::search), but also matching deprecated operators
+    /// and renamed names
+    pub fn search_including_deprecated(&self, query: &str) -> Vec<ExtendedEntry> {
+        rank_matches(self.list_all_including_deprecated(), query)
     }
 
     /// Get operators grouped by category
     ///
-    /// Returns a HashMap where keys are category names and values are lists
-    /// of operators in that category.
-    pub fn by_category(&self) -> HashMap<&'static str, Vec<ExtendedEntry>> {
-        let mut result: HashMap<&'static str, Vec<ExtendedEntry>> = HashMap::new();
+    /// Returns a HashMap where keys are categories and values are lists of
+    /// the operators directly in that category; an operator in `Math/Trig`
+    /// is listed under `Math/Trig` only. Deprecated operators are left out.
+    pub fn by_category(&self) -> HashMap<Category, Vec<ExtendedEntry>> {
+        let mut result: HashMap<Category, Vec<ExtendedEntry>> = HashMap::new();
 
         for reg in self.by_id.read().unwrap().values() {
-            let category = reg.entry.meta.category;
+            if reg.entry.meta.deprecated {
+                continue;
+            }
+            let category = reg.entry.meta.category_path();
             result.entry(category).or_default().push(reg.entry.clone());
         }
 
@@ -476,14 +657,45 @@ impl OperatorRegistry {
         result
     }
 
-    /// Get all categories in alphabetical order
-    pub fn categories(&self) -> Vec<&'static str> {
-        let mut cats: Vec<&'static str> = self
+    /// Get operators grouped into a tree of categories
+    ///
+    /// Returns the top-level categories in order, each holding its
+    /// sub-categories. Categories without operators of their own appear when
+    /// a sub-category has some. Deprecated operators are left out.
+    pub fn category_tree(&self) -> Vec<CategoryNode> {
+        // Sorted, so every category comes right after its enclosing ones
+        let by_category: BTreeMap<Category, Vec<ExtendedEntry>> = self.by_category().into_iter().collect();
+        let mut roots: Vec<CategoryNode> = Vec::new();
+
+        for (category, operators) in by_category {
+            let mut path: Vec<Category> = category.ancestors().collect();
+            path.reverse();
+            let leaf = path.pop().expect("categories have at least one segment");
+
+            let mut nodes = &mut roots;
+            for enclosing in path {
+                if nodes.last().is_none_or(|node| node.category != enclosing) {
+                    nodes.push(CategoryNode::new(enclosing));
+                }
+                let node = nodes.last_mut().expect("just ensured a node");
+                nodes = &mut node.children;
+            }
+            nodes.push(CategoryNode { operators, ..CategoryNode::new(leaf) });
+        }
+
+        roots
+    }
+
+    /// Get all categories in order, including the enclosing categories of
+    /// sub-categories
+    pub fn categories(&self) -> Vec<Category> {
+        let mut cats: Vec<Category> = self
             .by_id
             .read()
             .unwrap()
             .values()
-            .map(|reg| reg.entry.meta.category)
+            .filter(|reg| !reg.entry.meta.deprecated)
+            .flat_map(|reg| reg.entry.meta.category_path().ancestors().collect::<Vec<_>>())
             .collect();
         cats.sort();
         cats.dedup();
@@ -501,16 +713,44 @@ impl OperatorRegistry {
     }
 }
 
+/// Entries matching `query`, best match first
+fn rank_matches(entries: Vec<ExtendedEntry>, query: &str) -> Vec<ExtendedEntry> {
+    let query = query.to_lowercase();
+    let rank = |entry: &ExtendedEntry| {
+        let name = entry.meta.name.to_lowercase();
+        if name == query {
+            Some(0)
+        } else if name.starts_with(&query) {
+            Some(1)
+        } else if name.contains(&query) {
+            Some(2)
+        } else if entry.meta.category_path().segments().any(|s| s.to_lowercase().contains(&query)) {
+            Some(3)
+        } else if entry.meta.description.to_lowercase().contains(&query) {
+            Some(4)
+        } else {
+            None
+        }
+    };
+
+    let mut ranked: Vec<(u8, ExtendedEntry)> = entries
+        .into_iter()
+        .filter_map(|entry| Some((rank(&entry)?, entry)))
+        .collect();
+    ranked.sort_by_key(|(rank, entry)| (*rank, entry.meta.name));
+    ranked.into_iter().map(|(_, entry)| entry).collect()
+}
+
 impl Default for OperatorRegistry {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// Captures `PortMeta` from an operator before boxing it.
+/// Captures `OperatorMeta` from an operator before boxing it.
 ///
-/// This helper function creates an operator and extracts its input port metadata
-/// while the concrete type is still available. This is necessary because `OperatorMeta`
+/// This helper function creates an operator and extracts its port metadata, category
+/// color and icon while the concrete type is still available. This is necessary because `OperatorMeta`
 /// cannot be accessed through a trait object (`Box<dyn Operator>`).
 ///
 /// # Example
@@ -528,26 +768,55 @@ pub fn capture_meta<T>(op: T) -> OperatorWithMeta
 where
     T: Operator + flux_core::OperatorMeta + 'static,
 {
-    let input_count = op.inputs().len();
-    let meta: Vec<Option<PortMeta>> = (0..input_count).map(|i| op.input_meta(i)).collect();
+    let meta = CapturedMeta {
+        inputs: (0..op.inputs().len()).map(|i| op.input_meta(i)).collect(),
+        outputs: (0..op.outputs().len()).map(|i| op.output_meta(i)).collect(),
+        category: Some(op.category_path()),
+        category_color: Some(op.category_color()),
+        icon: op.icon(),
+        deprecation: op.deprecation(),
+        swappable_input_pairs: op.swappable_input_pairs(),
+    };
     (Box::new(op), meta)
 }
 
 /// Boxes an operator without capturing `PortMeta`.
 ///
 /// Use this for operators that don't implement `OperatorMeta`. The returned
-/// metadata vector will contain `None` for all inputs.
+/// metadata contains `None` for all ports.
 ///
 /// Prefer `capture_meta` when the operator implements `OperatorMeta`.
 pub fn capture_meta_simple<T>(op: T) -> OperatorWithMeta
 where
     T: Operator + 'static,
 {
-    let input_count = op.inputs().len();
-    let meta: Vec<Option<PortMeta>> = vec![None; input_count];
+    let meta = CapturedMeta {
+        inputs: vec![None; op.inputs().len()],
+        outputs: vec![None; op.outputs().len()],
+        ..Default::default()
+    };
     (Box::new(op), meta)
 }
 
+/// Register operators derived with `#[operator(register)]`
+///
+/// Takes the registry and the generated `register_<snake_name>` functions:
+///
+/// ```ignore
+/// pub fn register(registry: &OperatorRegistry) {
+///     collect!(registry, register_and_op, register_or_op);
+/// }
+/// ```
+#[macro_export]
+macro_rules! collect_operators {
+    ($registry:expr $(, $register:path)* $(,)?) => {{
+        let registry: &$crate::registry::OperatorRegistry = $registry;
+        $( $register(registry); )*
+    }};
+}
+
+pub use crate::collect_operators as collect;
+
 /// Create a pre-populated registry with all built-in operators.
 ///
 /// This registers all operators with captured `PortMeta` so that UI code can
@@ -566,23 +835,13 @@ pub fn create_default_registry() -> OperatorRegistry {
 
     // Constant has a parameter (initial value)
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Constant",
-            category: "Sources",
-            description: "Outputs a constant float value",
-        },
+        RegistryEntry::new("Constant", "Sources", "Outputs a constant float value"),
         || capture_meta(ConstantOp::new(0.0)),
     );
 
     // SineWave is in builtin, not time/oscillators
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "SineWave",
-            category: "Oscillators",
-            description: "Generates a sine wave based on time",
-        },
+        RegistryEntry::new("SineWave", "Oscillators", "Generates a sine wave based on time"),
         || capture_meta(SineWaveOp::new()),
     );
 
@@ -590,12 +849,7 @@ pub fn create_default_registry() -> OperatorRegistry {
 
     // Compare has parameter-based construction (mode enum)
     registry.register_with_params(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Compare",
-            category: "Logic",
-            description: "Compares two values and outputs a boolean",
-        },
+        RegistryEntry::new("Compare", "Logic", "Compares two values and outputs a boolean"),
         || capture_meta(CompareOp::new(CompareMode::Equal)),
         |params| {
             let mode = match params.get_enum("mode", "Equal") {
@@ -626,12 +880,7 @@ pub fn create_default_registry() -> OperatorRegistry {
 
     // Scope is in builtin, not elsewhere
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Scope",
-            category: "Output",
-            description: "Visualizes signal values over time",
-        },
+        RegistryEntry::new("Scope", "Output", "Visualizes signal values over time"),
         || capture_meta(ScopeOp::new()),
     );
 
@@ -643,6 +892,21 @@ mod tests {
     use super::*;
 
     #[test]
+    fn test_register_all_is_idempotent() {
+        let registry = OperatorRegistry::new();
+        crate::register_all_operators(&registry);
+        let count = registry.len();
+        let names = registry.list_names();
+        let ids: Vec<_> = names.iter().map(|n| registry.get_type_id(n)).collect();
+
+        crate::register_all_operators(&registry);
+        assert_eq!(registry.len(), count);
+        let ids_again: Vec<_> = names.iter().map(|n| registry.get_type_id(n)).collect();
+        assert_eq!(ids, ids_again);
+    }
+
+    #[test]
+    #[cfg(feature = "math")]
     fn test_registry_create_by_name() {
         let registry = create_default_registry();
 
@@ -655,6 +919,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "math")]
     fn test_registry_list_names() {
         let registry = create_default_registry();
         let names = registry.list_names();
@@ -666,21 +931,75 @@ mod tests {
     }
 
     #[test]
+    #[cfg(all(feature = "math", feature = "logic", feature = "time"))]
     fn test_registry_by_category() {
         let registry = create_default_registry();
         let by_cat = registry.by_category();
+        let arithmetic = Category::MATH.child("Arithmetic");
 
-        // Check Math category
-        assert!(by_cat.contains_key("Math"));
-        let math_ops: Vec<_> = by_cat["Math"].iter().map(|e| e.meta.name).collect();
+        // Math operators are grouped by sub-category
+        assert!(!by_cat.contains_key(&Category::MATH));
+        let math_ops: Vec<_> = by_cat[&arithmetic].iter().map(|e| e.meta.name).collect();
         assert!(math_ops.contains(&"Add"));
         assert!(math_ops.contains(&"Multiply"));
 
-        // Check categories list
+        // Check categories list, enclosing categories included
         let cats = registry.categories();
-        assert!(cats.contains(&"Math"));
-        assert!(cats.contains(&"Logic"));
-        assert!(cats.contains(&"Time"));
+        assert!(cats.contains(&Category::MATH));
+        assert!(cats.contains(&arithmetic));
+        assert!(cats.contains(&Category::LOGIC));
+        assert!(cats.contains(&Category::TIME));
+    }
+
+    #[test]
+    #[cfg(all(feature = "math", feature = "logic"))]
+    fn test_registry_category_tree() {
+        let registry = create_default_registry();
+        let tree = registry.category_tree();
+        let roots: Vec<Category> = tree.iter().map(|node| node.category.clone()).collect();
+        assert!(roots.iter().all(Category::is_top_level));
+        assert!(roots.windows(2).all(|pair| pair[0] < pair[1]));
+
+        let math = tree.iter().find(|node| node.category == Category::MATH).unwrap();
+        assert!(math.operators.is_empty());
+        let subs: Vec<&str> = math.children.iter().map(|node| node.category.name()).collect();
+        assert_eq!(subs, ["Arithmetic", "Interpolation", "Random", "Trig"]);
+        let trig = math.find(&Category::parse("Math/Trig")).unwrap();
+        assert!(trig.operators.iter().any(|e| e.meta.name == "Sin"));
+        assert!(math.find(&Category::LOGIC).is_none());
+
+        // Every listed operator appears exactly once in the tree
+        let total: usize = tree.iter().map(CategoryNode::operator_count).sum();
+        assert_eq!(total, registry.list_all_extended().len());
+    }
+
+    #[test]
+    fn test_category_tree_nests_sub_categories() {
+        let registry = OperatorRegistry::new();
+        for (name, category) in [("Noise", "Math/Random/Noise"), ("Add", "Math"), ("Sin", "Math/Trig"), ("Get", "List")] {
+            registry.register(
+                RegistryEntry::new(name, category, ""),
+                || capture_meta(crate::builtin::ConstantOp::new(0.0)),
+            );
+        }
+
+        let tree = registry.category_tree();
+        assert_eq!(tree.len(), 2);
+        let (list, math) = (&tree[0], &tree[1]);
+        assert_eq!(list.category, "List");
+        assert_eq!(math.operators[0].meta.name, "Add");
+        assert_eq!(math.operator_count(), 3);
+
+        // Math/Random has no operators of its own but holds Math/Random/Noise
+        let random = &math.children[0];
+        assert_eq!(random.category, "Math/Random");
+        assert!(random.operators.is_empty());
+        assert_eq!(random.children[0].operators[0].meta.name, "Noise");
+        assert_eq!(math.children[1].category, "Math/Trig");
+
+        let names: Vec<String> = registry.categories().iter().map(Category::to_string).collect();
+        assert_eq!(names, ["List", "Math", "Math/Random", "Math/Random/Noise", "Math/Trig"]);
+        assert_eq!(registry.search("trig")[0].meta.name, "Sin");
     }
 
     #[test]
@@ -702,18 +1021,78 @@ mod tests {
         assert_eq!(meta.parameters[0].name, "mode");
     }
 
+    #[test]
+    #[cfg(feature = "list")]
+    fn test_renamed_operators_resolve_but_stay_hidden() {
+        let registry = create_default_registry();
+        let op = registry.create_by_name("ListMap").unwrap();
+        assert_eq!(op.name(), "ListScaleOffset");
+        assert_eq!(registry.renamed_to("ListMap"), Some("ListScaleOffset"));
+        assert_eq!(registry.renamed_to("ListScaleOffset"), None);
+        assert_eq!(registry.get_type_id("ListMap"), registry.get_type_id("ListScaleOffset"));
+        assert!(!registry.list_names().contains(&"ListMap"));
+
+        let names = |entries: Vec<ExtendedEntry>| -> Vec<&'static str> {
+            entries.iter().map(|e| e.meta.name).collect()
+        };
+        assert!(!names(registry.search("listmap")).contains(&"ListMap"));
+        assert_eq!(names(registry.search("ListScale"))[0], "ListScaleOffset");
+
+        let found = registry.search_including_deprecated("listmap");
+        let old = found.iter().find(|e| e.meta.name == "ListMap").unwrap();
+        assert!(old.meta.deprecated);
+        assert_eq!(old.meta.replaced_by, Some("ListScaleOffset"));
+    }
+
+    #[test]
+    fn test_deprecated_entries_are_hidden() {
+        let registry = OperatorRegistry::new();
+        registry.register(
+            RegistryEntry {
+                deprecated: true,
+                replaced_by: Some("Crossfade"),
+                ..RegistryEntry::new("Fade", "Math", "Old fade")
+            },
+            || capture_meta(crate::builtin::ConstantOp::new(1.0)),
+        );
+        registry.register_simple("Constant", || Box::new(crate::builtin::ConstantOp::new(0.0)));
+        registry.register_renamed("Const", "Constant");
+        registry.register_renamed("Konst", "Const");
+
+        assert!(registry.create_by_name("Fade").is_some());
+        assert_eq!(registry.list_names(), vec!["Constant"]);
+        assert_eq!(registry.categories(), vec![Category::UNCATEGORIZED]);
+        assert!(registry.search("fade").is_empty());
+        assert_eq!(registry.search_including_deprecated("fade").len(), 1);
+
+        // Chained renames resolve to the current name
+        assert_eq!(registry.renamed_to("Konst"), Some("Constant"));
+        assert_eq!(registry.create_by_name("Konst").unwrap().name(), "Constant");
+
+        assert_eq!(registry.catalog().operators.len(), 1);
+        let all = registry.catalog_including_deprecated();
+        assert_eq!(all.operators.len(), 4);
+        let fade = all.get("Fade").unwrap();
+        assert!(fade.deprecated);
+        assert_eq!(fade.replaced_by, Some("Crossfade"));
+        assert!(all.to_markdown().contains("*Deprecated: use Crossfade instead.*"));
+    }
+
     #[test]
     fn test_operator_params() {
         let params = OperatorParams::new()
             .set("float_val", ParameterValue::Float(1.5))
             .set("int_val", ParameterValue::Int(42))
             .set("bool_val", ParameterValue::Bool(true))
-            .set("enum_val", ParameterValue::Enum("Option1"));
+            .set("enum_val", ParameterValue::Enum("Option1"))
+            .set("string_val", ParameterValue::String("a,b".into()));
 
         assert_eq!(params.get_float("float_val", 0.0), 1.5);
         assert_eq!(params.get_float("missing", 0.0), 0.0);
         assert_eq!(params.get_int("int_val", 0), 42);
         assert!(params.get_bool("bool_val", false));
         assert_eq!(params.get_enum("enum_val", "Default"), "Option1");
+        assert_eq!(params.get_string("string_val", ""), "a,b");
+        assert_eq!(params.get_string("missing", "x"), "x");
     }
 }
@@ -0,0 +1,572 @@
+//! JSON operators: JsonParse, JsonQuery, JsonStringify
+//!
+//! Parsed documents are plain [`Value`]s so they flow through the map
+//! operators as well:
+//! - objects become `Value::Map`
+//! - numbers become Float (or Int for integral numbers in [`JsonNumbers::Int`] mode)
+//! - arrays of only numbers, strings or bools become FloatList/IntList,
+//!   StringList or BoolList; any other array becomes a Map keyed by index
+//!   (`"0"`, `"1"`, ...)
+//! - `null` becomes an empty Map
+//!
+//! Query paths mix dotted keys and indices: `"items[2].name"` and
+//! `"items.2.name"` are equivalent.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::port::OutputTypeRule;
+use flux_core::value::ValueType;
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Value};
+use serde_json::Value as Json;
+use crate::registry::{
+    capture_meta, OperatorRegistry, ParameterMeta, ParameterType, ParameterValue, RegistryEntry,
+};
+use flux_core::port::{InputPort, OutputPort};
+
+fn get_value(input: &InputPort, get_input: InputResolver) -> Value {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx),
+        None => input.default.clone(),
+    }
+}
+
+fn get_string(input: &InputPort, get_input: InputResolver) -> String {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx)
+            .as_string()
+            .unwrap_or_default()
+            .to_string(),
+        None => input.default.as_string().unwrap_or_default().to_string(),
+    }
+}
+
+fn get_bool(input: &InputPort, get_input: InputResolver) -> bool {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_bool().unwrap_or(false),
+        None => input.default.as_bool().unwrap_or(false),
+    }
+}
+
+/// How JSON numbers are converted
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonNumbers {
+    /// Every number becomes a Float
+    #[default]
+    Float,
+    /// Integral numbers that fit in an `i32` become Int
+    Int,
+}
+
+impl JsonNumbers {
+    fn int(self, number: &serde_json::Number) -> Option<i32> {
+        match self {
+            JsonNumbers::Float => None,
+            JsonNumbers::Int => number.as_i64().and_then(|n| i32::try_from(n).ok()),
+        }
+    }
+}
+
+/// Convert a JSON document to a [`Value`]
+pub fn json_to_value(json: &Json, numbers: JsonNumbers) -> Value {
+    match json {
+        Json::Null => Value::Map(BTreeMap::new()),
+        Json::Bool(b) => Value::Bool(*b),
+        Json::Number(n) => match numbers.int(n) {
+            Some(i) => Value::Int(i),
+            None => Value::Float(n.as_f64().unwrap_or(0.0) as f32),
+        },
+        Json::String(s) => Value::String(s.clone()),
+        Json::Array(items) => array_to_value(items, numbers),
+        Json::Object(fields) => Value::Map(
+            fields
+                .iter()
+                .map(|(key, field)| (key.clone(), json_to_value(field, numbers)))
+                .collect(),
+        ),
+    }
+}
+
+fn array_to_value(items: &[Json], numbers: JsonNumbers) -> Value {
+    let ints: Option<Vec<i32>> = items
+        .iter()
+        .map(|item| item.as_number().and_then(|n| numbers.int(n)))
+        .collect();
+    if let Some(ints) = ints.filter(|ints| !ints.is_empty()) {
+        return Value::int_list(ints);
+    }
+    let floats: Option<Vec<f32>> = items.iter().map(|item| item.as_f64().map(|f| f as f32)).collect();
+    if let Some(floats) = floats {
+        return Value::float_list(floats);
+    }
+    let strings: Option<Vec<String>> = items.iter().map(|item| item.as_str().map(String::from)).collect();
+    if let Some(strings) = strings {
+        return Value::string_list(strings);
+    }
+    let bools: Option<Vec<bool>> = items.iter().map(Json::as_bool).collect();
+    if let Some(bools) = bools {
+        return Value::bool_list(bools);
+    }
+    Value::Map(
+        items
+            .iter()
+            .enumerate()
+            .map(|(i, item)| (i.to_string(), json_to_value(item, numbers)))
+            .collect(),
+    )
+}
+
+fn float_to_json(f: f32) -> Json {
+    serde_json::Number::from_f64(f as f64).map_or(Json::Null, Json::Number)
+}
+
+fn floats_to_json(floats: &[f32]) -> Json {
+    Json::Array(floats.iter().copied().map(float_to_json).collect())
+}
+
+/// Convert a [`Value`] to JSON
+///
+/// Vectors and colors become number arrays. Non-finite floats and resource
+/// references (whose ids mean nothing outside the running host) become `null`.
+pub fn value_to_json(value: &Value) -> Json {
+    match value {
+        Value::Float(f) => float_to_json(*f),
+        Value::Int(i) => Json::from(*i),
+        Value::Bool(b) => Json::Bool(*b),
+        Value::Vec2(v) => floats_to_json(v),
+        Value::Vec3(v) => floats_to_json(v),
+        Value::Vec4(v) => floats_to_json(v),
+        Value::String(s) => Json::String(s.clone()),
+        Value::Color(c) => floats_to_json(&[c.r, c.g, c.b, c.a]),
+        Value::Gradient(g) => serde_json::to_value(g).unwrap_or(Json::Null),
+        Value::Matrix4(m) => Json::Array(m.0.iter().map(|row| floats_to_json(row)).collect()),
+        Value::FloatList(l) => floats_to_json(l),
+        Value::IntList(l) => Json::from(l.to_vec()),
+        Value::BoolList(l) => Json::from(l.to_vec()),
+        Value::Vec2List(l) => Json::Array(l.iter().map(|v| floats_to_json(v)).collect()),
+        Value::Vec3List(l) => Json::Array(l.iter().map(|v| floats_to_json(v)).collect()),
+        Value::Vec4List(l) => Json::Array(l.iter().map(|v| floats_to_json(v)).collect()),
+        Value::ColorList(l) => Json::Array(
+            l.iter()
+                .map(|c| floats_to_json(&[c.r, c.g, c.b, c.a]))
+                .collect(),
+        ),
+        Value::StringList(l) => Json::from(l.to_vec()),
+        Value::Map(m) => Json::Object(
+            m.iter()
+                .map(|(key, field)| (key.clone(), value_to_json(field)))
+                .collect(),
+        ),
+        Value::ResourceRef { .. } => Json::Null,
+    }
+}
+
+/// Element `index` of a list value
+fn list_element(list: &Value, index: usize) -> Option<Value> {
+    match list {
+        Value::FloatList(l) => l.get(index).copied().map(Value::Float),
+        Value::IntList(l) => l.get(index).copied().map(Value::Int),
+        Value::BoolList(l) => l.get(index).copied().map(Value::Bool),
+        Value::Vec2List(l) => l.get(index).copied().map(Value::Vec2),
+        Value::Vec3List(l) => l.get(index).copied().map(Value::Vec3),
+        Value::Vec4List(l) => l.get(index).copied().map(Value::Vec4),
+        Value::ColorList(l) => l.get(index).copied().map(Value::Color),
+        Value::StringList(l) => l.get(index).cloned().map(Value::String),
+        _ => None,
+    }
+}
+
+/// Look up `path` (e.g. `"items[2].name"`) in a parsed document
+pub fn query(value: &Value, path: &str) -> Option<Value> {
+    let path = path.replace('[', ".").replace(']', "");
+    let mut current = value.clone();
+    for segment in path.split('.').filter(|s| !s.is_empty()) {
+        current = match &current {
+            Value::Map(map) => map.get(segment)?.clone(),
+            list => list_element(list, segment.parse().ok()?)?,
+        };
+    }
+    Some(current)
+}
+
+// ============================================================================
+// JsonParse Operator
+// ============================================================================
+
+pub struct JsonParseOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 3],
+    numbers: JsonNumbers,
+    /// Text parsed at the last evaluation, to skip re-parsing unchanged input
+    parsed_text: Option<String>,
+}
+
+impl JsonParseOp {
+    pub fn new() -> Self {
+        Self::with_numbers(JsonNumbers::Float)
+    }
+
+    pub fn with_numbers(numbers: JsonNumbers) -> Self {
+        // Documents are usually objects, so start out as a Map output
+        let mut value = OutputPort::polymorphic("Value", OutputTypeRule::Dynamic);
+        value.set_resolved_type(ValueType::Map);
+        value.value = Value::Map(BTreeMap::new());
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::string("Json", "")],
+            outputs: [value, OutputPort::bool("Valid"), OutputPort::string("Error")],
+            numbers,
+            parsed_text: None,
+        }
+    }
+
+    pub fn numbers(&self) -> JsonNumbers {
+        self.numbers
+    }
+}
+
+impl Default for JsonParseOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for JsonParseOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "JsonParse" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let text = get_string(&self.inputs[0], get_input);
+        if self.parsed_text.as_deref() == Some(text.as_str()) {
+            return;
+        }
+
+        match serde_json::from_str::<Json>(&text) {
+            Ok(json) => {
+                let value = json_to_value(&json, self.numbers);
+                self.outputs[0].set_resolved_type(value.value_type());
+                self.outputs[0].set(value);
+                self.outputs[1].set_bool(true);
+                self.outputs[2].set(Value::String(String::new()));
+            }
+            Err(err) => {
+                // Keep the last resolved type so downstream connections stay valid
+                let default = self.outputs[0].value_type.default_value();
+                self.outputs[0].set(default);
+                self.outputs[1].set_bool(false);
+                self.outputs[2].set(Value::String(err.to_string()));
+            }
+        }
+        self.parsed_text = Some(text);
+    }
+}
+
+impl OperatorMeta for JsonParseOp {
+    fn category(&self) -> &'static str { "String" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STRING }
+    fn description(&self) -> &'static str { "Parse JSON text into maps, lists and values" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Json")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Valid").with_shape(PinShape::TriangleFilled)),
+            2 => Some(PortMeta::new("Error").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// JsonQuery Operator (Dynamic output type)
+// ============================================================================
+
+pub struct JsonQueryOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 2],
+}
+
+impl JsonQueryOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::map("Value"), InputPort::string("Path", "")],
+            // Value takes the type found at the last evaluation
+            outputs: [
+                OutputPort::polymorphic("Value", OutputTypeRule::Dynamic),
+                OutputPort::bool("Found"),
+            ],
+        }
+    }
+
+    /// Create reading the given path
+    pub fn with_path(path: &str) -> Self {
+        let mut op = Self::new();
+        op.inputs[1].default = Value::String(path.to_string());
+        op
+    }
+}
+
+impl Default for JsonQueryOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for JsonQueryOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "JsonQuery" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let document = get_value(&self.inputs[0], get_input);
+        let path = get_string(&self.inputs[1], get_input);
+
+        match query(&document, &path) {
+            Some(value) => {
+                self.outputs[0].set_resolved_type(value.value_type());
+                self.outputs[0].set(value);
+                self.outputs[1].set_bool(true);
+            }
+            None => {
+                let default = self.outputs[0].value_type.default_value();
+                self.outputs[0].set(default);
+                self.outputs[1].set_bool(false);
+            }
+        }
+    }
+}
+
+impl OperatorMeta for JsonQueryOp {
+    fn category(&self) -> &'static str { "String" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STRING }
+    fn description(&self) -> &'static str { "Read a value from a parsed document by path" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value")),
+            1 => Some(PortMeta::new("Path")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Found").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// JsonStringify Operator
+// ============================================================================
+
+pub struct JsonStringifyOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+}
+
+impl JsonStringifyOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::any("Value", Value::Map(BTreeMap::new())),
+                InputPort::bool("Pretty", false),
+            ],
+            outputs: [OutputPort::string("Json")],
+        }
+    }
+}
+
+impl Default for JsonStringifyOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for JsonStringifyOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "JsonStringify" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let json = value_to_json(&get_value(&self.inputs[0], get_input));
+        let text = if get_bool(&self.inputs[1], get_input) {
+            serde_json::to_string_pretty(&json)
+        } else {
+            serde_json::to_string(&json)
+        };
+        self.outputs[0].set_string(&text.unwrap_or_default());
+    }
+}
+
+impl OperatorMeta for JsonStringifyOp {
+    fn category(&self) -> &'static str { "String" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STRING }
+    fn description(&self) -> &'static str { "Convert a value to JSON text" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value")),
+            1 => Some(PortMeta::new("Pretty")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Json").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register_with_params(
+        RegistryEntry::new("JsonParse", "String", "Parse JSON text into maps, lists and values"),
+        || capture_meta(JsonParseOp::new()),
+        |params| {
+            let numbers = match params.get_enum("numbers", "Float") {
+                "Int" => JsonNumbers::Int,
+                _ => JsonNumbers::Float,
+            };
+            capture_meta(JsonParseOp::with_numbers(numbers))
+        },
+        vec![ParameterMeta {
+            name: "numbers",
+            param_type: ParameterType::Enum { variants: vec!["Float", "Int"] },
+            default: ParameterValue::Enum("Float"),
+        }],
+    );
+
+    registry.register(
+        RegistryEntry::new("JsonQuery", "String", "Read a value from a parsed document by path"),
+        || capture_meta(JsonQueryOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("JsonStringify", "String", "Convert a value to JSON text"),
+        || capture_meta(JsonStringifyOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORECAST: &str = r#"{
+        "location": { "city": "Utrecht", "coords": [52.09, 5.12] },
+        "updated": 1718000000,
+        "days": [
+            { "date": "2024-06-10", "temps": [14.5, 21.0], "rain": false },
+            { "date": "2024-06-11", "temps": [12, 17], "rain": true, "note": null }
+        ],
+        "alerts": [],
+        "tags": ["mild", "windy"]
+    }"#;
+
+    fn parse(numbers: JsonNumbers) -> Value {
+        let mut op = JsonParseOp::with_numbers(numbers);
+        op.inputs[0].default = Value::String(FORECAST.to_string());
+        op.compute(&EvalContext::new(), &|_, _| Value::Float(0.0));
+        assert_eq!(op.outputs[1].value, Value::Bool(true));
+        op.outputs[0].value.clone()
+    }
+
+    #[test]
+    fn test_parse_type_mapping() {
+        let doc = parse(JsonNumbers::Float);
+        assert_eq!(doc.value_type(), ValueType::Map);
+        assert_eq!(query(&doc, "location.city"), Some(Value::String("Utrecht".into())));
+        assert_eq!(query(&doc, "updated"), Some(Value::Float(1718000000.0)));
+        assert_eq!(query(&doc, "location.coords"), Some(Value::float_list(vec![52.09, 5.12])));
+        assert_eq!(
+            query(&doc, "tags"),
+            Some(Value::string_list(vec!["mild".into(), "windy".into()]))
+        );
+        // Arrays of objects become index-keyed maps; empty arrays are FloatLists
+        assert_eq!(query(&doc, "days").unwrap().value_type(), ValueType::Map);
+        assert_eq!(query(&doc, "alerts"), Some(Value::float_list(Vec::new())));
+        assert_eq!(query(&doc, "days[1].note"), Some(Value::Map(BTreeMap::new())));
+
+        // Int mode keeps integral numbers (and integral arrays) as Int
+        let doc = parse(JsonNumbers::Int);
+        assert_eq!(query(&doc, "days[1].temps"), Some(Value::int_list(vec![12, 17])));
+        assert_eq!(query(&doc, "days[0].temps"), Some(Value::float_list(vec![14.5, 21.0])));
+        assert_eq!(query(&doc, "updated"), Some(Value::Int(1718000000)));
+    }
+
+    #[test]
+    fn test_query_indexing_and_missing_paths() {
+        let mut op = JsonQueryOp::with_path("days[1].temps[0]");
+        op.inputs[0].default = parse(JsonNumbers::Float);
+        let ctx = EvalContext::new();
+
+        op.compute(&ctx, &|_, _| Value::Float(0.0));
+        assert_eq!(op.outputs[0].value, Value::Float(12.0));
+        assert_eq!(op.outputs[1].value, Value::Bool(true));
+
+        op.inputs[1].default = Value::String("days.0.rain".into());
+        op.compute(&ctx, &|_, _| Value::Float(0.0));
+        assert_eq!(op.outputs[0].value_type, ValueType::Bool);
+        assert_eq!(op.outputs[0].value, Value::Bool(false));
+
+        for missing in ["days[5].rain", "location.country", "tags[x]", "updated.value"] {
+            op.inputs[1].default = Value::String(missing.into());
+            op.compute(&ctx, &|_, _| Value::Float(0.0));
+            assert_eq!(op.outputs[1].value, Value::Bool(false), "{missing}");
+            assert_eq!(op.outputs[0].value_type, ValueType::Bool);
+        }
+    }
+
+    #[test]
+    fn test_malformed_input_reports_error() {
+        let mut op = JsonParseOp::new();
+        op.inputs[0].default = Value::String(r#"{"a": [1, 2"#.to_string());
+        op.compute(&EvalContext::new(), &|_, _| Value::Float(0.0));
+        assert_eq!(op.outputs[1].value, Value::Bool(false));
+        assert!(op.outputs[2].value.as_string().unwrap().contains("line 1"));
+        assert_eq!(op.outputs[0].value_type, ValueType::Map);
+    }
+
+    #[test]
+    fn test_stringify_round_trip() {
+        let doc = parse(JsonNumbers::Int);
+        let mut op = JsonStringifyOp::new();
+        op.inputs[0].default = query(&doc, "location").unwrap();
+        op.compute(&EvalContext::new(), &|_, _| Value::Float(0.0));
+        let text = op.outputs[0].value.as_string().unwrap().to_string();
+        let json: Json = serde_json::from_str(&text).unwrap();
+        assert_eq!(json["city"], "Utrecht");
+        assert_eq!(json_to_value(&json, JsonNumbers::Int), query(&doc, "location").unwrap());
+    }
+}
@@ -1,13 +1,21 @@
-//! String operators (8 total)
+//! String operators (13 total)
 //! - StringConcat, StringFormat, StringLength, SubString
-//! - StringSplit, FloatToString, IntToString, StringContains
+//! - StringSplit, FloatToString, IntToString, StringContains, ParseNumber
+//! - StringTemplate (named placeholders, one input per placeholder)
+//! - JsonParse, JsonQuery, JsonStringify
 
 use crate::registry::OperatorRegistry;
 
+mod json;
 mod string_ops;
+mod template;
 
+pub use json::*;
 pub use string_ops::*;
+pub use template::*;
 
 pub fn register_all(registry: &OperatorRegistry) {
     string_ops::register(registry);
+    json::register(registry);
+    template::register(registry);
 }
@@ -1,4 +1,5 @@
-//! String operators: Concat, Format, Length, SubString, Split, FloatToString, IntToString, Contains
+//! String operators: Concat, Format, Length, SubString, Split, FloatToString, IntToString, Contains,
+//! ParseNumber
 
 use std::any::Any;
 
@@ -8,6 +9,7 @@ use flux_core::operator::{InputResolver, Operator};
 use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::port::{InputPort, OutputPort};
+use flux_core::value::{Value, ValueType};
 
 fn get_string(input: &InputPort, get_input: InputResolver) -> String {
     match input.connection {
@@ -583,90 +585,152 @@ impl OperatorMeta for StringContainsOp {
     }
 }
 
+// ============================================================================
+// ParseNumber Operator
+// ============================================================================
+
+/// Parse text such as `"1,5"` or `"42 px"` into a float.
+///
+/// Unlike the automatic String -> Float conversion, which only accepts clean
+/// numbers, this can tolerate a decimal comma and trailing units. When the
+/// text still doesn't parse, `Value` is the `Default` input and `IsValid` is
+/// false.
+pub struct ParseNumberOp {
+    id: Id,
+    inputs: [InputPort; 4],
+    outputs: [OutputPort; 2],
+}
+
+impl ParseNumberOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::string("Text", ""),
+                InputPort::bool("DecimalComma", false),
+                InputPort::bool("StripUnits", false),
+                InputPort::float("Default", 0.0),
+            ],
+            outputs: [OutputPort::float("Value"), OutputPort::bool("IsValid")],
+        }
+    }
+}
+
+impl Default for ParseNumberOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Parse `text` as a float, optionally reading ',' as the decimal point and
+/// ignoring a trailing unit ("42 px", "50%").
+fn parse_number(text: &str, decimal_comma: bool, strip_units: bool) -> Option<f32> {
+    let mut text = text.trim().to_string();
+    if decimal_comma {
+        text = text.replace(',', ".");
+    }
+    if strip_units {
+        let end = text.trim_end_matches(|c: char| !c.is_ascii_digit() && c != '.');
+        text.truncate(end.len());
+    }
+    Value::String(text)
+        .coerce_to(ValueType::Float)
+        .and_then(|value| value.as_float())
+}
+
+impl Operator for ParseNumberOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ParseNumber" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let text = get_string(&self.inputs[0], get_input);
+        let decimal_comma = get_bool(&self.inputs[1], get_input);
+        let strip_units = get_bool(&self.inputs[2], get_input);
+
+        let parsed = parse_number(&text, decimal_comma, strip_units);
+        let value = parsed.unwrap_or_else(|| get_float(&self.inputs[3], get_input));
+        self.outputs[0].set_float(value);
+        self.outputs[1].set_bool(parsed.is_some());
+    }
+}
+
+impl OperatorMeta for ParseNumberOp {
+    fn category(&self) -> &'static str { "String" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STRING }
+    fn description(&self) -> &'static str { "Parse text as a number, with a fallback" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Text")),
+            1 => Some(PortMeta::new("DecimalComma")),
+            2 => Some(PortMeta::new("StripUnits")),
+            3 => Some(PortMeta::new("Default")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("IsValid").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
 // ============================================================================
 // Registration
 // ============================================================================
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "StringConcat",
-            category: "String",
-            description: "Concatenate two strings",
-        },
+        RegistryEntry::new("StringConcat", "String", "Concatenate two strings"),
         || capture_meta(StringConcatOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "StringFormat",
-            category: "String",
-            description: "Format string with value",
-        },
+        RegistryEntry::new("StringFormat", "String", "Format string with value"),
         || capture_meta(StringFormatOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "StringLength",
-            category: "String",
-            description: "Get string length",
-        },
+        RegistryEntry::new("StringLength", "String", "Get string length"),
         || capture_meta(StringLengthOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "SubString",
-            category: "String",
-            description: "Extract substring",
-        },
+        RegistryEntry::new("SubString", "String", "Extract substring"),
         || capture_meta(SubStringOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "StringSplit",
-            category: "String",
-            description: "Split string by delimiter",
-        },
+        RegistryEntry::new("StringSplit", "String", "Split string by delimiter"),
         || capture_meta(StringSplitOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "FloatToString",
-            category: "String",
-            description: "Convert float to string",
-        },
+        RegistryEntry::new("FloatToString", "String", "Convert float to string"),
         || capture_meta(FloatToStringOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IntToString",
-            category: "String",
-            description: "Convert integer to string",
-        },
+        RegistryEntry::new("IntToString", "String", "Convert integer to string"),
         || capture_meta(IntToStringOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "StringContains",
-            category: "String",
-            description: "Check if string contains substring",
-        },
+        RegistryEntry::new("StringContains", "String", "Check if string contains substring"),
         || capture_meta(StringContainsOp::new()),
     );
+
+    registry.register(
+        RegistryEntry::new("ParseNumber", "String", "Parse text as a number, with a fallback"),
+        || capture_meta(ParseNumberOp::new()),
+    );
 }
 
 #[cfg(test)]
@@ -674,7 +738,6 @@ mod tests {
     use std::f32::consts::PI;
 
     use super::*;
-    use flux_core::Value;
 
     fn no_connections(_: Id, _: usize) -> Value {
         Value::Float(0.0)
@@ -784,4 +847,30 @@ mod tests {
         op.compute(&ctx, &no_connections);
         assert_eq!(op.outputs[0].value.as_bool(), Some(false));
     }
+
+    #[test]
+    fn test_parse_number() {
+        let mut op = ParseNumberOp::new();
+        let ctx = EvalContext::new();
+        let mut parse = |text: &str, decimal_comma: bool, strip_units: bool| {
+            op.inputs[0].default = Value::String(text.to_string());
+            op.inputs[1].default = Value::Bool(decimal_comma);
+            op.inputs[2].default = Value::Bool(strip_units);
+            op.inputs[3].default = Value::Float(-1.0);
+            op.compute(&ctx, &no_connections);
+            (
+                op.outputs[0].value.as_float().unwrap(),
+                op.outputs[1].value.as_bool().unwrap(),
+            )
+        };
+
+        assert_eq!(parse("3.14", false, false), ("3.14".parse().unwrap(), true));
+        assert_eq!(parse(" 42 px", false, true), (42.0, true));
+        assert_eq!(parse("1,5", true, false), (1.5, true));
+
+        // Failures fall back to Default instead of 0
+        assert_eq!(parse("abc", true, true), (-1.0, false));
+        assert_eq!(parse(" 42 px", false, false), (-1.0, false));
+        assert_eq!(parse("1,5", false, false), (-1.0, false));
+    }
 }
@@ -0,0 +1,374 @@
+//! String template operator: StringTemplate
+//!
+//! Templates contain named placeholders such as `"FPS: {fps:.1} at {time:.2}s"`.
+//! Every unique placeholder name becomes an input port. Placeholders are
+//! Float inputs by default; an `:s` hint makes them String inputs.
+//!
+//! Format specs:
+//! - `.N` (or `.Nf`) - fixed precision for floats, up to `.65535`
+//! - `s` - string input
+//! - anything else - plain `Display` formatting
+//!
+//! `{{` and `}}` render literal braces. Braces that don't form a valid
+//! placeholder are kept as literal text.
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::value::ValueType;
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Value};
+use crate::names::intern;
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort, PortChange, PortRemap};
+
+fn get_string(input: &InputPort, get_input: InputResolver) -> String {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx)
+            .as_string()
+            .unwrap_or_default()
+            .to_string(),
+        None => input.default.as_string().unwrap_or_default().to_string(),
+    }
+}
+
+fn get_float(input: &InputPort, get_input: InputResolver) -> f32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float().unwrap_or(0.0),
+        None => input.default.as_float().unwrap_or(0.0),
+    }
+}
+
+/// A parsed piece of a template.
+#[derive(Clone, Debug, PartialEq)]
+enum Segment {
+    Literal(String),
+    Placeholder {
+        /// Index into the placeholder list (input index - 1)
+        slot: usize,
+        precision: Option<u16>,
+    },
+}
+
+/// A unique placeholder and the type of its input.
+#[derive(Clone, Debug, PartialEq)]
+struct Placeholder {
+    name: String,
+    value_type: ValueType,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+struct ParsedTemplate {
+    segments: Vec<Segment>,
+    placeholders: Vec<Placeholder>,
+}
+
+fn is_valid_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Parse a `.N` / `.Nf` precision spec.
+///
+/// Precisions that don't fit `format!`'s `u16` argument fall back to `Display`.
+fn parse_precision(spec: &str) -> Option<u16> {
+    let digits = spec.strip_prefix('.')?;
+    let digits = digits.strip_suffix('f').unwrap_or(digits);
+    digits.parse().ok()
+}
+
+fn parse_template(template: &str) -> ParsedTemplate {
+    let mut parsed = ParsedTemplate::default();
+    let mut literal = String::new();
+    let mut rest = template;
+
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with("{{") {
+            literal.push('{');
+            rest = &rest[2..];
+            continue;
+        }
+        if rest.starts_with("}}") {
+            literal.push('}');
+            rest = &rest[2..];
+            continue;
+        }
+        if c == '{' {
+            if let Some(end) = rest.find('}') {
+                let body = &rest[1..end];
+                let (name, spec) = body.split_once(':').unwrap_or((body, ""));
+                if is_valid_name(name) && !spec.contains('{') {
+                    let value_type = if spec == "s" {
+                        ValueType::String
+                    } else {
+                        ValueType::Float
+                    };
+                    let slot = match parsed.placeholders.iter().position(|p| p.name == name) {
+                        Some(slot) => slot,
+                        None => {
+                            parsed.placeholders.push(Placeholder {
+                                name: name.to_string(),
+                                value_type,
+                            });
+                            parsed.placeholders.len() - 1
+                        }
+                    };
+                    if !literal.is_empty() {
+                        parsed.segments.push(Segment::Literal(std::mem::take(&mut literal)));
+                    }
+                    parsed.segments.push(Segment::Placeholder {
+                        slot,
+                        precision: parse_precision(spec),
+                    });
+                    rest = &rest[end + 1..];
+                    continue;
+                }
+            }
+        }
+        literal.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+
+    if !literal.is_empty() {
+        parsed.segments.push(Segment::Literal(literal));
+    }
+    parsed
+}
+
+// ============================================================================
+// StringTemplate Operator
+// ============================================================================
+
+pub struct StringTemplateOp {
+    id: Id,
+    /// Template input followed by one input per placeholder
+    inputs: Vec<InputPort>,
+    outputs: [OutputPort; 1],
+    /// Template the placeholder ports were generated from
+    template: String,
+    parsed: ParsedTemplate,
+    /// Input layout change not yet taken by the graph
+    port_change: Option<PortChange>,
+}
+
+impl StringTemplateOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: vec![InputPort::string("Template", "")],
+            outputs: [OutputPort::string("Result")],
+            template: String::new(),
+            parsed: ParsedTemplate::default(),
+            port_change: None,
+        }
+    }
+
+    /// Create with a template, generating its placeholder ports.
+    pub fn with_template(template: &str) -> Self {
+        let mut op = Self::new();
+        op.set_template(template);
+        op.port_change = None;
+        op
+    }
+
+    pub fn template(&self) -> &str {
+        &self.template
+    }
+
+    /// Set the template default and regenerate placeholder ports.
+    pub fn set_template(&mut self, template: &str) {
+        self.inputs[0].default = Value::String(template.to_string());
+        self.update_ports(template);
+    }
+
+    /// Names of the placeholder inputs, in input order (starting at index 1).
+    pub fn placeholder_names(&self) -> impl Iterator<Item = &str> {
+        self.parsed.placeholders.iter().map(|p| p.name.as_str())
+    }
+
+    /// Re-parse the template if it changed, rebuilding placeholder ports.
+    ///
+    /// Ports whose placeholder still exists with the same type are kept,
+    /// along with their connections and defaults. The graph finds them again
+    /// by name; ports that were replaced get no name to match.
+    fn update_ports(&mut self, template: &str) {
+        if template == self.template {
+            return;
+        }
+        let parsed = parse_template(template);
+
+        // Names of the layout the graph last saw
+        let previous = match self.port_change.take() {
+            Some(PortChange { inputs: PortRemap::ByName(names), .. }) => names,
+            _ => self.inputs.iter().map(|p| p.name.to_string()).collect(),
+        };
+        let mut kept: Vec<&'static str> = vec![self.inputs[0].name];
+        let mut old_ports: Vec<InputPort> = self.inputs.drain(1..).collect();
+        for placeholder in &parsed.placeholders {
+            let existing = old_ports
+                .iter()
+                .position(|p| p.name == placeholder.name && p.value_type == placeholder.value_type);
+            let port = match existing {
+                Some(index) => {
+                    kept.push(old_ports[index].name);
+                    old_ports.swap_remove(index)
+                }
+                None => {
+                    let name = intern(&placeholder.name);
+                    match placeholder.value_type {
+                        ValueType::String => InputPort::string(name, ""),
+                        _ => InputPort::float(name, 0.0),
+                    }
+                }
+            };
+            self.inputs.push(port);
+        }
+
+        let previous = previous
+            .into_iter()
+            .map(|name| if kept.contains(&name.as_str()) { name } else { String::new() });
+        self.port_change = Some(PortChange::new().inputs_by_name(previous));
+        self.template = template.to_string();
+        self.parsed = parsed;
+    }
+}
+
+impl Default for StringTemplateOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for StringTemplateOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "StringTemplate" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn take_port_change(&mut self) -> Option<PortChange> { self.port_change.take() }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let template = get_string(&self.inputs[0], get_input);
+        self.update_ports(&template);
+
+        let mut result = String::new();
+        for segment in &self.parsed.segments {
+            match segment {
+                Segment::Literal(text) => result.push_str(text),
+                Segment::Placeholder { slot, precision } => {
+                    let input = &self.inputs[slot + 1];
+                    if input.value_type == ValueType::String {
+                        result.push_str(&get_string(input, get_input));
+                    } else {
+                        let value = get_float(input, get_input);
+                        match precision {
+                            Some(p) => result.push_str(&format!("{:.*}", usize::from(*p), value)),
+                            None => result.push_str(&value.to_string()),
+                        }
+                    }
+                }
+            }
+        }
+        self.outputs[0].set_string(&result);
+    }
+}
+
+impl OperatorMeta for StringTemplateOp {
+    fn category(&self) -> &'static str { "String" }
+    fn category_color(&self) -> [f32; 4] { category_colors::STRING }
+    fn description(&self) -> &'static str { "Fill named {placeholders} in a template" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Template")),
+            _ => self.inputs.get(index).map(|input| PortMeta::new(input.name)),
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new("StringTemplate", "String", "Fill named {placeholders} in a template"),
+        || capture_meta(StringTemplateOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    #[test]
+    fn test_precision_formatting() {
+        let mut op = StringTemplateOp::with_template("FPS: {fps:.1} at {time:.2}s ({fps})");
+        let ctx = EvalContext::new();
+        assert_eq!(op.placeholder_names().collect::<Vec<_>>(), vec!["fps", "time"]);
+
+        op.inputs[1].default = Value::Float(59.94);
+        op.inputs[2].default = Value::Float(1.5);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_string(), Some("FPS: 59.9 at 1.50s (59.94)"));
+    }
+
+    #[test]
+    fn test_string_placeholder_and_escapes() {
+        let mut op = StringTemplateOp::with_template("{{{name:s}}} = {x:.0f} {not valid} }");
+        let ctx = EvalContext::new();
+        assert_eq!(op.inputs[1].value_type, ValueType::String);
+        assert_eq!(op.inputs.len(), 3);
+
+        op.inputs[1].default = Value::String("width".to_string());
+        op.inputs[2].default = Value::Float(2.6);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_string(), Some("{width} = 3 {not valid} }"));
+    }
+
+    #[test]
+    fn test_out_of_range_precision_falls_back_to_display() {
+        let mut op = StringTemplateOp::with_template("{x:.99999} {x:.3}");
+        let ctx = EvalContext::new();
+
+        op.inputs[1].default = Value::Float(0.5);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_string(), Some("0.5 0.500"));
+    }
+
+    #[test]
+    fn test_template_edit_keeps_connections() {
+        let source = Id::new();
+        let mut op = StringTemplateOp::with_template("{a} {b}");
+        op.inputs[1].connect(source, 0);
+        op.inputs[2].default = Value::Float(7.0);
+
+        // Insert a new placeholder before the existing ones, drop `b`
+        op.set_template("{c:s} {a}");
+        assert_eq!(op.placeholder_names().collect::<Vec<_>>(), vec!["c", "a"]);
+        assert_eq!(op.inputs[1].name, "c");
+        assert!(op.inputs[1].connection.is_none());
+        assert_eq!(op.inputs[2].name, "a");
+        assert_eq!(op.inputs[2].connection, Some((source, 0)));
+
+        // Changing the template through the input regenerates ports at compute
+        let ctx = EvalContext::new();
+        op.inputs[0].default = Value::String("{a:.1}!".to_string());
+        op.compute(&ctx, &|_, _| Value::Float(0.26));
+        assert_eq!(op.inputs.len(), 2);
+        assert_eq!(op.inputs[1].connection, Some((source, 0)));
+        assert_eq!(op.outputs[0].value.as_string(), Some("0.3!"));
+    }
+}
@@ -0,0 +1,256 @@
+//! Tempo operators: BeatClock, Envelope
+//!
+//! Both are pure functions of their inputs: BeatClock converts a time in
+//! seconds to a beat position, so it follows whatever drives its Time input
+//! (usually the Time operator, or a transport's playhead through the context).
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Unit};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort};
+
+fn get_float(input: &InputPort, get_input: InputResolver) -> f32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float().unwrap_or(0.0),
+        None => input.default.as_float().unwrap_or(0.0),
+    }
+}
+
+// ============================================================================
+// BeatClock Operator
+// ============================================================================
+
+/// Beat position of a time at a fixed tempo
+///
+/// `Beat` counts beats since time 0, `Phase` is the position within the
+/// current beat (0 to 1) and `Count` is the whole number of beats. The beat
+/// is computed in f64 so `Phase` stays exact for long-running times.
+pub struct BeatClockOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 3],
+}
+
+impl BeatClockOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::float("Time", 0.0), InputPort::float("BPM", 120.0)],
+            outputs: [
+                OutputPort::float("Beat"),
+                OutputPort::float("Phase"),
+                OutputPort::int("Count"),
+            ],
+        }
+    }
+}
+
+impl Default for BeatClockOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for BeatClockOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "BeatClock" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let time = get_float(&self.inputs[0], get_input) as f64;
+        // A non-positive tempo stops the clock at beat 0
+        let bpm = get_float(&self.inputs[1], get_input).max(0.0) as f64;
+        let beat = time * bpm / 60.0;
+        let count = beat.floor();
+        self.outputs[0].set_float(beat as f32);
+        self.outputs[1].set_float((beat - count) as f32);
+        self.outputs[2].set_int(count as i32);
+    }
+}
+
+impl OperatorMeta for BeatClockOp {
+    fn category(&self) -> &'static str {
+        "Time"
+    }
+
+    fn category_color(&self) -> [f32; 4] {
+        category_colors::TIME
+    }
+
+    fn description(&self) -> &'static str {
+        "Beat position, phase within the beat and beat count of a time at a tempo"
+    }
+
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Time").with_shape(PinShape::CircleFilled).with_typed_unit(Unit::Seconds)),
+            1 => Some(PortMeta::new("BPM").with_shape(PinShape::CircleFilled).with_range(1.0, 300.0)),
+            _ => None,
+        }
+    }
+
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Beat").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Phase").with_shape(PinShape::TriangleFilled).with_range(0.0, 1.0)),
+            2 => Some(PortMeta::new("Count").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Envelope Operator
+// ============================================================================
+
+/// Attack/decay envelope over a phase
+///
+/// Rises linearly from 0 to 1 over `Attack`, falls back to 0 over `Decay`
+/// and stays at 0 for the rest of the cycle. Attack and decay are fractions
+/// of the phase range; feed it a BeatClock's `Phase` for one hit per beat.
+pub struct EnvelopeOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl EnvelopeOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float("Phase", 0.0),
+                InputPort::float("Attack", 0.1),
+                InputPort::float("Decay", 0.5),
+            ],
+            outputs: [OutputPort::float("Value")],
+        }
+    }
+}
+
+impl Default for EnvelopeOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for EnvelopeOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "Envelope" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let phase = get_float(&self.inputs[0], get_input).rem_euclid(1.0);
+        let attack = get_float(&self.inputs[1], get_input).max(0.0);
+        let decay = get_float(&self.inputs[2], get_input).max(0.0);
+
+        let value = if phase < attack {
+            phase / attack
+        } else if phase < attack + decay {
+            1.0 - (phase - attack) / decay
+        } else {
+            0.0
+        };
+        self.outputs[0].set_float(value);
+    }
+}
+
+impl OperatorMeta for EnvelopeOp {
+    fn category(&self) -> &'static str {
+        "Time"
+    }
+
+    fn category_color(&self) -> [f32; 4] {
+        category_colors::TIME
+    }
+
+    fn description(&self) -> &'static str {
+        "Attack/decay envelope over a 0-1 phase"
+    }
+
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Phase").with_shape(PinShape::CircleFilled).with_range(0.0, 1.0)),
+            1 => Some(PortMeta::new("Attack").with_shape(PinShape::CircleFilled).with_range(0.0, 1.0)),
+            2 => Some(PortMeta::new("Decay").with_shape(PinShape::CircleFilled).with_range(0.0, 1.0)),
+            _ => None,
+        }
+    }
+
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Value").with_shape(PinShape::TriangleFilled).with_range(0.0, 1.0)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new("BeatClock", "Time", "Beat position of a time at a tempo"),
+        || capture_meta(BeatClockOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("Envelope", "Time", "Attack/decay envelope over a phase"),
+        || capture_meta(EnvelopeOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::Value;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    #[test]
+    fn test_beat_clock() {
+        let mut op = BeatClockOp::new();
+        op.inputs[0].default = Value::Float(1.25);
+        op.compute(&EvalContext::new(), &no_connections);
+        // 120 BPM: two beats per second
+        assert_eq!(op.outputs[0].value.as_float(), Some(2.5));
+        assert_eq!(op.outputs[1].value.as_float(), Some(0.5));
+        assert_eq!(op.outputs[2].value.as_int(), Some(2));
+
+        op.inputs[1].default = Value::Float(0.0);
+        op.compute(&EvalContext::new(), &no_connections);
+        assert_eq!(op.outputs[0].value.as_float(), Some(0.0));
+    }
+
+    #[test]
+    fn test_envelope() {
+        let mut op = EnvelopeOp::new();
+        let mut at = |phase: f32| {
+            op.inputs[0].default = Value::Float(phase);
+            op.compute(&EvalContext::new(), &no_connections);
+            op.outputs[0].value.as_float().unwrap()
+        };
+        assert_eq!(at(0.0), 0.0);
+        assert!((at(0.05) - 0.5).abs() < 1e-6);
+        assert!((at(0.1) - 1.0).abs() < 1e-6);
+        assert!((at(0.35) - 0.5).abs() < 1e-6);
+        assert_eq!(at(0.8), 0.0);
+    }
+}
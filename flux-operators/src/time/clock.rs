@@ -5,7 +5,7 @@ use std::any::Any;
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
 use flux_core::operator::{InputResolver, Operator};
-use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Unit};
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::port::{InputPort, OutputPort};
 
@@ -13,16 +13,23 @@ use flux_core::port::{InputPort, OutputPort};
 // Time Operator
 // ============================================================================
 
+/// Global time, plus time wrapped to a period
+///
+/// `Time` loses precision as a show runs: f32 seconds are ~2ms apart after
+/// six hours. `Wrapped` is computed in f64 before converting, so it stays
+/// exact; drive phase-based animation from it instead.
 pub struct TimeOp {
     id: Id,
-    outputs: [OutputPort; 1],
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 2],
 }
 
 impl TimeOp {
     pub fn new() -> Self {
         Self {
             id: Id::new(),
-            outputs: [OutputPort::float("Time")],
+            inputs: [InputPort::float("Period", 60.0)],
+            outputs: [OutputPort::float("Time"), OutputPort::float("Wrapped")],
         }
     }
 }
@@ -38,13 +45,23 @@ impl Operator for TimeOp {
     fn as_any_mut(&mut self) -> &mut dyn Any { self }
     fn id(&self) -> Id { self.id }
     fn name(&self) -> &'static str { "Time" }
-    fn inputs(&self) -> &[InputPort] { &[] }
-    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut [] }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
 
-    fn compute(&mut self, ctx: &EvalContext, _get_input: InputResolver) {
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let period = match self.inputs[0].connection {
+            Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float(),
+            None => self.inputs[0].default.as_float(),
+        };
+        // A non-positive period disables wrapping
+        let wrapped = match period {
+            Some(period) if period > 0.0 => ctx.time.rem_euclid(period as f64),
+            _ => ctx.time,
+        };
         self.outputs[0].set_float(ctx.time as f32);
+        self.outputs[1].set_float(wrapped as f32);
     }
 
     fn is_time_varying(&self) -> bool {
@@ -62,12 +79,20 @@ impl OperatorMeta for TimeOp {
     }
 
     fn description(&self) -> &'static str {
-        "Current global time in seconds"
+        "Current global time in seconds, and time wrapped to a period for precise phases"
+    }
+
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Period").with_range(0.0, 3600.0).with_typed_unit(Unit::Seconds)),
+            _ => None,
+        }
     }
 
     fn output_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Time").with_shape(PinShape::TriangleFilled).with_unit("s")),
+            0 => Some(PortMeta::new("Time").with_shape(PinShape::TriangleFilled).with_typed_unit(Unit::Seconds)),
+            1 => Some(PortMeta::new("Wrapped").with_shape(PinShape::TriangleFilled).with_typed_unit(Unit::Seconds)),
             _ => None,
         }
     }
@@ -131,7 +156,7 @@ impl OperatorMeta for LocalTimeOp {
 
     fn output_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("LocalTime").with_shape(PinShape::TriangleFilled).with_unit("s")),
+            0 => Some(PortMeta::new("LocalTime").with_shape(PinShape::TriangleFilled).with_typed_unit(Unit::Seconds)),
             _ => None,
         }
     }
@@ -195,7 +220,7 @@ impl OperatorMeta for DeltaTimeOp {
 
     fn output_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("DT").with_shape(PinShape::TriangleFilled).with_unit("s")),
+            0 => Some(PortMeta::new("DT").with_shape(PinShape::TriangleFilled).with_typed_unit(Unit::Seconds)),
             _ => None,
         }
     }
@@ -271,42 +296,22 @@ impl OperatorMeta for FrameOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Time",
-            category: "Time",
-            description: "Current global time in seconds",
-        },
+        RegistryEntry::new("Time", "Time", "Current global time in seconds"),
         || capture_meta(TimeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "LocalTime",
-            category: "Time",
-            description: "Local time in current composition",
-        },
+        RegistryEntry::new("LocalTime", "Time", "Local time in current composition"),
         || capture_meta(LocalTimeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "DeltaTime",
-            category: "Time",
-            description: "Time since last frame",
-        },
+        RegistryEntry::new("DeltaTime", "Time", "Time since last frame"),
         || capture_meta(DeltaTimeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Frame",
-            category: "Time",
-            description: "Current frame number",
-        },
+        RegistryEntry::new("Frame", "Time", "Current frame number"),
         || capture_meta(FrameOp::new()),
     );
 }
@@ -327,6 +332,25 @@ mod tests {
         ctx.time = 5.5;
         op.compute(&ctx, &no_connections);
         assert_eq!(op.outputs[0].value.as_float(), Some(5.5));
+        assert_eq!(op.outputs[1].value.as_float(), Some(5.5));
+    }
+
+    #[test]
+    fn test_wrapped_time_keeps_precision() {
+        let mut op = TimeOp::new();
+        op.inputs[0].default = Value::Float(10.0);
+        let mut ctx = EvalContext::new();
+        ctx.time = 6.0 * 3600.0 + 1.0 / 60.0;
+        op.compute(&ctx, &no_connections);
+        let wrapped = op.outputs[1].value.as_float().unwrap();
+        assert!((wrapped - 1.0 / 60.0).abs() < 1e-6);
+        // f32 time is off by most of a millisecond here
+        let time = op.outputs[0].value.as_float().unwrap();
+        assert!((time as f64 - ctx.time).abs() > 1e-4);
+
+        op.inputs[0].default = Value::Float(0.0);
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[1].value.as_float(), Some(time));
     }
 
     #[test]
@@ -1,14 +1,17 @@
-//! Time and animation operators (10 total)
+//! Time and animation operators (12 total)
 
 use crate::registry::OperatorRegistry;
 
+mod beat;
 mod clock;
 mod oscillators;
 
+pub use beat::*;
 pub use clock::*;
 pub use oscillators::*;
 
 pub fn register_all(registry: &OperatorRegistry) {
     clock::register(registry);
+    beat::register(registry);
     oscillators::register(registry);
 }
@@ -1,12 +1,16 @@
 //! Oscillator operators: SawWave, TriangleWave, PulseWave, Accumulator, Spring
 //! Note: SineWave is in the legacy operator.rs
+//!
+//! The waves accumulate their phase in f64 (see [`PhaseAccumulator`]) so they
+//! stay smooth however long the clock has been running.
 
 use std::any::Any;
 
 use flux_core::context::EvalContext;
 use flux_core::id::Id;
 use flux_core::operator::{InputResolver, Operator};
-use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use flux_core::{category_colors, InputValidationIssue, OperatorMeta, PinShape, PortMeta, Unit, Value};
+use crate::builtin::{validate_frequency, PhaseAccumulator};
 use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
 use flux_core::port::{InputPort, OutputPort};
 
@@ -25,6 +29,7 @@ pub struct SawWaveOp {
     id: Id,
     inputs: [InputPort; 4],
     outputs: [OutputPort; 1],
+    phase: PhaseAccumulator,
 }
 
 impl SawWaveOp {
@@ -38,6 +43,7 @@ impl SawWaveOp {
                 InputPort::float("Offset", 0.0),
             ],
             outputs: [OutputPort::float("Value")],
+            phase: PhaseAccumulator::default(),
         }
     }
 }
@@ -64,9 +70,8 @@ impl Operator for SawWaveOp {
         let phase = get_float(&self.inputs[2], get_input);
         let offset = get_float(&self.inputs[3], get_input);
 
-        let t = ctx.time as f32;
         // Sawtooth: goes from -1 to 1 over one period
-        let cycle = (t * freq + phase).rem_euclid(1.0);
+        let cycle = (self.phase.advance(ctx.time, freq) + phase as f64).rem_euclid(1.0) as f32;
         let value = (cycle * 2.0 - 1.0) * amp + offset;
         self.outputs[0].set_float(value);
     }
@@ -74,6 +79,10 @@ impl Operator for SawWaveOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn validate_inputs(&self, proposed: &[Value]) -> Vec<InputValidationIssue> {
+        validate_frequency(&self.inputs, proposed)
+    }
 }
 
 impl OperatorMeta for SawWaveOp {
@@ -91,7 +100,7 @@ impl OperatorMeta for SawWaveOp {
 
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Frequency").with_shape(PinShape::CircleFilled).with_unit("Hz")),
+            0 => Some(PortMeta::new("Frequency").with_shape(PinShape::CircleFilled).with_typed_unit(Unit::Hertz)),
             1 => Some(PortMeta::new("Amplitude").with_shape(PinShape::CircleFilled).with_range(0.0,10.0)),
             2 => Some(PortMeta::new("Phase").with_shape(PinShape::CircleFilled)),
             3 => Some(PortMeta::new("Offset").with_shape(PinShape::CircleFilled)),
@@ -115,6 +124,7 @@ pub struct TriangleWaveOp {
     id: Id,
     inputs: [InputPort; 4],
     outputs: [OutputPort; 1],
+    phase: PhaseAccumulator,
 }
 
 impl TriangleWaveOp {
@@ -128,6 +138,7 @@ impl TriangleWaveOp {
                 InputPort::float("Offset", 0.0),
             ],
             outputs: [OutputPort::float("Value")],
+            phase: PhaseAccumulator::default(),
         }
     }
 }
@@ -154,8 +165,7 @@ impl Operator for TriangleWaveOp {
         let phase = get_float(&self.inputs[2], get_input);
         let offset = get_float(&self.inputs[3], get_input);
 
-        let t = ctx.time as f32;
-        let cycle = (t * freq + phase).rem_euclid(1.0);
+        let cycle = (self.phase.advance(ctx.time, freq) + phase as f64).rem_euclid(1.0) as f32;
         // Triangle: goes from -1 to 1 to -1 over one period
         let value = (1.0 - (cycle * 2.0 - 1.0).abs() * 2.0) * amp + offset;
         self.outputs[0].set_float(value);
@@ -164,6 +174,10 @@ impl Operator for TriangleWaveOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn validate_inputs(&self, proposed: &[Value]) -> Vec<InputValidationIssue> {
+        validate_frequency(&self.inputs, proposed)
+    }
 }
 
 impl OperatorMeta for TriangleWaveOp {
@@ -181,7 +195,7 @@ impl OperatorMeta for TriangleWaveOp {
 
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Frequency").with_shape(PinShape::CircleFilled).with_unit("Hz")),
+            0 => Some(PortMeta::new("Frequency").with_shape(PinShape::CircleFilled).with_typed_unit(Unit::Hertz)),
             1 => Some(PortMeta::new("Amplitude").with_shape(PinShape::CircleFilled)),
             2 => Some(PortMeta::new("Phase").with_shape(PinShape::CircleFilled)),
             3 => Some(PortMeta::new("Offset").with_shape(PinShape::CircleFilled)),
@@ -205,6 +219,7 @@ pub struct PulseWaveOp {
     id: Id,
     inputs: [InputPort; 4],
     outputs: [OutputPort; 1],
+    phase: PhaseAccumulator,
 }
 
 impl PulseWaveOp {
@@ -218,6 +233,7 @@ impl PulseWaveOp {
                 InputPort::float("Offset", 0.0),
             ],
             outputs: [OutputPort::float("Value")],
+            phase: PhaseAccumulator::default(),
         }
     }
 }
@@ -244,8 +260,7 @@ impl Operator for PulseWaveOp {
         let amp = get_float(&self.inputs[2], get_input);
         let offset = get_float(&self.inputs[3], get_input);
 
-        let t = ctx.time as f32;
-        let cycle = (t * freq).rem_euclid(1.0);
+        let cycle = self.phase.advance(ctx.time, freq) as f32;
         let value = if cycle < duty { amp } else { -amp };
         self.outputs[0].set_float(value + offset);
     }
@@ -253,6 +268,10 @@ impl Operator for PulseWaveOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn validate_inputs(&self, proposed: &[Value]) -> Vec<InputValidationIssue> {
+        validate_frequency(&self.inputs, proposed)
+    }
 }
 
 impl OperatorMeta for PulseWaveOp {
@@ -270,7 +289,7 @@ impl OperatorMeta for PulseWaveOp {
 
     fn input_meta(&self, index: usize) -> Option<PortMeta> {
         match index {
-            0 => Some(PortMeta::new("Frequency").with_shape(PinShape::CircleFilled).with_unit("Hz")),
+            0 => Some(PortMeta::new("Frequency").with_shape(PinShape::CircleFilled).with_typed_unit(Unit::Hertz)),
             1 => Some(PortMeta::new("Duty").with_shape(PinShape::CircleFilled)),
             2 => Some(PortMeta::new("Amplitude").with_shape(PinShape::CircleFilled)),
             3 => Some(PortMeta::new("Offset").with_shape(PinShape::CircleFilled)),
@@ -347,6 +366,16 @@ impl Operator for AccumulatorOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.accumulated = 0.0;
+        self.last_time = 0.0;
+        true
+    }
 }
 
 impl OperatorMeta for AccumulatorOp {
@@ -450,6 +479,17 @@ impl Operator for SpringOp {
     fn is_time_varying(&self) -> bool {
         true
     }
+
+    fn is_stateful(&self) -> bool {
+        true
+    }
+
+    fn reset_state(&mut self) -> bool {
+        self.current = 0.0;
+        self.velocity = 0.0;
+        self.last_time = 0.0;
+        true
+    }
 }
 
 impl OperatorMeta for SpringOp {
@@ -488,52 +528,27 @@ impl OperatorMeta for SpringOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "SawWave",
-            category: "Oscillators",
-            description: "Sawtooth wave oscillator",
-        },
+        RegistryEntry::new("SawWave", "Oscillators", "Sawtooth wave oscillator"),
         || capture_meta(SawWaveOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "TriangleWave",
-            category: "Oscillators",
-            description: "Triangle wave oscillator",
-        },
+        RegistryEntry::new("TriangleWave", "Oscillators", "Triangle wave oscillator"),
         || capture_meta(TriangleWaveOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "PulseWave",
-            category: "Oscillators",
-            description: "Pulse/square wave oscillator",
-        },
+        RegistryEntry::new("PulseWave", "Oscillators", "Pulse/square wave oscillator"),
         || capture_meta(PulseWaveOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Accumulator",
-            category: "Time",
-            description: "Accumulate value over time",
-        },
+        RegistryEntry::new("Accumulator", "Time", "Accumulate value over time"),
         || capture_meta(AccumulatorOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Spring",
-            category: "Time",
-            description: "Spring physics simulation",
-        },
+        RegistryEntry::new("Spring", "Time", "Spring physics simulation"),
         || capture_meta(SpringOp::new()),
     );
 }
@@ -593,6 +608,24 @@ mod tests {
         assert_eq!(v2, -1.0);
     }
 
+    #[test]
+    fn test_waves_keep_precision_after_six_hours() {
+        let mut op = TriangleWaveOp::new();
+        let mut ctx = EvalContext::new();
+        let start = 6.0 * 3600.0;
+        let values: Vec<f32> = (0..240)
+            .map(|i| {
+                ctx.time = start + i as f64 / 60.0;
+                op.compute(&ctx, &no_connections);
+                op.outputs[0].value.as_float().unwrap()
+            })
+            .collect();
+        // A 1Hz triangle moves 4/60 per frame, except around its turning points
+        let steps: Vec<f32> = values.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+        let straight = steps.iter().filter(|step| (**step - 4.0 / 60.0).abs() < 1e-4).count();
+        assert!(straight >= steps.len() - 8, "{} of {} steps are exact", straight, steps.len());
+    }
+
     #[test]
     fn test_spring_convergence() {
         let mut op = SpringOp::new();
@@ -377,6 +377,8 @@ impl Operator for TypeOfOp {
             Value::Vec4List(_) => "Vec4List",
             Value::ColorList(_) => "ColorList",
             Value::StringList(_) => "StringList",
+            Value::Map(_) => "Map",
+            Value::ResourceRef { .. } => "ResourceRef",
         };
         self.outputs[0].set_string(type_name);
     }
@@ -467,62 +469,32 @@ impl OperatorMeta for IsConnectedOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Print",
-            category: "Utility",
-            description: "Debug print value",
-        },
+        RegistryEntry::new("Print", "Utility", "Debug print value"),
         || capture_meta(PrintOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Passthrough",
-            category: "Utility",
-            description: "Pass value through",
-        },
+        RegistryEntry::new("Passthrough", "Utility", "Pass value through"),
         || capture_meta(PassthroughOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Comment",
-            category: "Utility",
-            description: "Add annotation comment",
-        },
+        RegistryEntry::new("Comment", "Utility", "Add annotation comment"),
         || capture_meta(CommentOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Bookmark",
-            category: "Utility",
-            description: "Named reference point",
-        },
+        RegistryEntry::new("Bookmark", "Utility", "Named reference point"),
         || capture_meta(BookmarkOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "TypeOf",
-            category: "Utility",
-            description: "Get value type name",
-        },
+        RegistryEntry::new("TypeOf", "Utility", "Get value type name"),
         || capture_meta(TypeOfOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "IsConnected",
-            category: "Utility",
-            description: "Check if input is connected",
-        },
+        RegistryEntry::new("IsConnected", "Utility", "Check if input is connected"),
         || capture_meta(IsConnectedOp::new()),
     );
 }
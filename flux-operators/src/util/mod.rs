@@ -1,13 +1,21 @@
-//! Utility operators (6 total)
+//! Utility operators (11 total)
 //! - Print, Passthrough, Comment
 //! - Bookmark, TypeOf, IsNull
+//! - ResourceByName, ResourceInfo
+//! - Resolution, NormalizeCoords, DenormalizeCoords
 
 use crate::registry::OperatorRegistry;
 
 mod debug;
+mod resolution;
+mod resource;
 
 pub use debug::*;
+pub use resolution::*;
+pub use resource::*;
 
 pub fn register_all(registry: &OperatorRegistry) {
     debug::register(registry);
+    resource::register(registry);
+    resolution::register(registry);
 }
@@ -0,0 +1,385 @@
+//! Resolution operators: Resolution, NormalizeCoords, DenormalizeCoords
+//!
+//! All three read [`EvalContext::resolution`] and declare
+//! [`ContextDependency::Resolution`], so the graph recomputes them when
+//! the resolution changes.
+
+use std::any::Any;
+
+use flux_core::context::{ContextDependency, EvalContext};
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort};
+
+const RESOLUTION: &[ContextDependency] = &[ContextDependency::Resolution];
+
+fn get_vec2(input: &InputPort, get_input: InputResolver) -> [f32; 2] {
+    match input.connection {
+        Some((node_id, output_idx)) => {
+            get_input(node_id, output_idx).as_vec2().unwrap_or([0.0, 0.0])
+        }
+        None => input.default.as_vec2().unwrap_or([0.0, 0.0]),
+    }
+}
+
+fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_int().unwrap_or(0),
+        None => input.default.as_int().unwrap_or(0),
+    }
+}
+
+// ============================================================================
+// Fit Mode
+// ============================================================================
+
+/// How normalized coordinates map onto a non-square resolution
+///
+/// Selected through an Int "Mode" input: 0 = Stretch, 1 = FitWidth,
+/// 2 = FitHeight.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum FitMode {
+    /// Both axes span -1..1, distorting non-square resolutions
+    #[default]
+    Stretch,
+    /// X spans -1..1; Y keeps square units and spans ±1/aspect
+    FitWidth,
+    /// Y spans -1..1; X keeps square units and spans ±aspect
+    FitHeight,
+}
+
+impl FitMode {
+    /// Mode for a Mode input value (unknown values stretch)
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => FitMode::FitWidth,
+            2 => FitMode::FitHeight,
+            _ => FitMode::Stretch,
+        }
+    }
+
+    /// Mode input value for this mode
+    pub fn index(self) -> i32 {
+        self as i32
+    }
+
+    /// Pixels per normalized unit along each axis
+    fn scale(self, (width, height): (u32, u32)) -> [f32; 2] {
+        let half = [width as f32 / 2.0, height as f32 / 2.0];
+        match self {
+            FitMode::Stretch => half,
+            FitMode::FitWidth => [half[0], half[0]],
+            FitMode::FitHeight => [half[1], half[1]],
+        }
+    }
+}
+
+/// Pixel coordinates (origin top-left, Y down) to normalized coordinates
+/// (origin at the center, Y up)
+///
+/// A zero-sized axis maps to 0.
+pub fn normalize_coords(pixel: [f32; 2], resolution: (u32, u32), mode: FitMode) -> [f32; 2] {
+    let [sx, sy] = mode.scale(resolution);
+    let center = [resolution.0 as f32 / 2.0, resolution.1 as f32 / 2.0];
+    let x = if sx > 0.0 { (pixel[0] - center[0]) / sx } else { 0.0 };
+    let y = if sy > 0.0 { (center[1] - pixel[1]) / sy } else { 0.0 };
+    [x, y]
+}
+
+/// Inverse of [`normalize_coords`]
+pub fn denormalize_coords(coords: [f32; 2], resolution: (u32, u32), mode: FitMode) -> [f32; 2] {
+    let [sx, sy] = mode.scale(resolution);
+    let center = [resolution.0 as f32 / 2.0, resolution.1 as f32 / 2.0];
+    [center[0] + coords[0] * sx, center[1] - coords[1] * sy]
+}
+
+fn mode_meta() -> PortMeta {
+    // 0=Stretch, 1=FitWidth, 2=FitHeight
+    PortMeta::new("Mode").with_range(0.0, 2.0).with_step(1.0)
+}
+
+// ============================================================================
+// Resolution Operator
+// ============================================================================
+
+pub struct ResolutionOp {
+    id: Id,
+    inputs: [InputPort; 0],
+    outputs: [OutputPort; 4],
+}
+
+impl ResolutionOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [],
+            outputs: [
+                OutputPort::float("Width"),
+                OutputPort::float("Height"),
+                OutputPort::float("Aspect"),
+                OutputPort::vec2("Resolution"),
+            ],
+        }
+    }
+}
+
+impl Default for ResolutionOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ResolutionOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "Resolution" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn context_dependencies(&self) -> &'static [ContextDependency] { RESOLUTION }
+
+    fn compute(&mut self, ctx: &EvalContext, _get_input: InputResolver) {
+        let (width, height) = (ctx.resolution.0 as f32, ctx.resolution.1 as f32);
+        self.outputs[0].set_float(width);
+        self.outputs[1].set_float(height);
+        self.outputs[2].set_float(ctx.aspect_ratio());
+        self.outputs[3].set_vec2([width, height]);
+    }
+}
+
+impl OperatorMeta for ResolutionOp {
+    fn category(&self) -> &'static str { "Util" }
+    fn category_color(&self) -> [f32; 4] { category_colors::UTIL }
+    fn description(&self) -> &'static str { "Current render resolution and aspect ratio" }
+    fn input_meta(&self, _index: usize) -> Option<PortMeta> {
+        None
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Width").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Height")),
+            2 => Some(PortMeta::new("Aspect")),
+            3 => Some(PortMeta::new("Resolution")),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// NormalizeCoords Operator
+// ============================================================================
+
+pub struct NormalizeCoordsOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+}
+
+impl NormalizeCoordsOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::vec2("Pixel", [0.0, 0.0]),
+                InputPort::int("Mode", FitMode::Stretch.index()),
+            ],
+            outputs: [OutputPort::vec2("Coords")],
+        }
+    }
+}
+
+impl Default for NormalizeCoordsOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for NormalizeCoordsOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "NormalizeCoords" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn context_dependencies(&self) -> &'static [ContextDependency] { RESOLUTION }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let pixel = get_vec2(&self.inputs[0], get_input);
+        let mode = FitMode::from_index(get_int(&self.inputs[1], get_input));
+        self.outputs[0].set_vec2(normalize_coords(pixel, ctx.resolution, mode));
+    }
+}
+
+impl OperatorMeta for NormalizeCoordsOp {
+    fn category(&self) -> &'static str { "Util" }
+    fn category_color(&self) -> [f32; 4] { category_colors::UTIL }
+    fn description(&self) -> &'static str { "Pixel coordinates to -1..1 coordinates" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Pixel")),
+            1 => Some(mode_meta()),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Coords").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// DenormalizeCoords Operator
+// ============================================================================
+
+pub struct DenormalizeCoordsOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+}
+
+impl DenormalizeCoordsOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::vec2("Coords", [0.0, 0.0]),
+                InputPort::int("Mode", FitMode::Stretch.index()),
+            ],
+            outputs: [OutputPort::vec2("Pixel")],
+        }
+    }
+}
+
+impl Default for DenormalizeCoordsOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for DenormalizeCoordsOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "DenormalizeCoords" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn context_dependencies(&self) -> &'static [ContextDependency] { RESOLUTION }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let coords = get_vec2(&self.inputs[0], get_input);
+        let mode = FitMode::from_index(get_int(&self.inputs[1], get_input));
+        self.outputs[0].set_vec2(denormalize_coords(coords, ctx.resolution, mode));
+    }
+}
+
+impl OperatorMeta for DenormalizeCoordsOp {
+    fn category(&self) -> &'static str { "Util" }
+    fn category_color(&self) -> [f32; 4] { category_colors::UTIL }
+    fn description(&self) -> &'static str { "-1..1 coordinates to pixel coordinates" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Coords")),
+            1 => Some(mode_meta()),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Pixel").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new("Resolution", "Utility", "Get render resolution"),
+        || capture_meta(ResolutionOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("NormalizeCoords", "Utility", "Pixel to normalized coordinates"),
+        || capture_meta(NormalizeCoordsOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("DenormalizeCoords", "Utility", "Normalized to pixel coordinates"),
+        || capture_meta(DenormalizeCoordsOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::Value;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    fn assert_vec2_eq(actual: [f32; 2], expected: [f32; 2]) {
+        assert!(
+            (actual[0] - expected[0]).abs() < 1e-5 && (actual[1] - expected[1]).abs() < 1e-5,
+            "{:?} != {:?}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn test_resolution() {
+        let mut op = ResolutionOp::new();
+        op.compute(&EvalContext::new().with_resolution(800, 400), &no_connections);
+        assert_eq!(op.outputs[0].value, Value::Float(800.0));
+        assert_eq!(op.outputs[1].value, Value::Float(400.0));
+        assert_eq!(op.outputs[2].value, Value::Float(2.0));
+        assert_eq!(op.outputs[3].value, Value::Vec2([800.0, 400.0]));
+    }
+
+    #[test]
+    fn test_normalize_modes() {
+        let resolution = (800, 400);
+        let corner = [800.0, 0.0];
+        assert_vec2_eq(normalize_coords(corner, resolution, FitMode::Stretch), [1.0, 1.0]);
+        assert_vec2_eq(normalize_coords(corner, resolution, FitMode::FitWidth), [1.0, 0.5]);
+        assert_vec2_eq(normalize_coords(corner, resolution, FitMode::FitHeight), [2.0, 1.0]);
+        assert_vec2_eq(normalize_coords([400.0, 200.0], resolution, FitMode::Stretch), [0.0, 0.0]);
+        assert_eq!(normalize_coords([5.0, 5.0], (0, 0), FitMode::Stretch), [0.0, 0.0]);
+
+        for mode in [FitMode::Stretch, FitMode::FitWidth, FitMode::FitHeight] {
+            let pixel = [123.0, 321.0];
+            let coords = normalize_coords(pixel, resolution, mode);
+            assert_vec2_eq(denormalize_coords(coords, resolution, mode), pixel);
+        }
+    }
+
+    #[test]
+    fn test_normalize_ops() {
+        let ctx = EvalContext::new().with_resolution(200, 100);
+        let mut normalize = NormalizeCoordsOp::new();
+        normalize.inputs[0].default = Value::Vec2([0.0, 100.0]);
+        normalize.inputs[1].default = Value::Int(FitMode::FitHeight.index());
+        normalize.compute(&ctx, &no_connections);
+        assert_eq!(normalize.outputs[0].value, Value::Vec2([-2.0, -1.0]));
+
+        let mut denormalize = DenormalizeCoordsOp::new();
+        denormalize.inputs[0].default = normalize.outputs[0].value.clone();
+        denormalize.inputs[1].default = Value::Int(FitMode::FitHeight.index());
+        denormalize.compute(&ctx, &no_connections);
+        assert_eq!(denormalize.outputs[0].value, Value::Vec2([0.0, 100.0]));
+    }
+}
@@ -0,0 +1,234 @@
+//! Resource operators: ResourceByName, ResourceInfo
+//!
+//! Both look resources up in the host's table on
+//! [`EvalContext::resources`](flux_core::EvalContext::resources).
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort};
+use flux_core::{Value, ValueType};
+
+fn get_value(input: &InputPort, get_input: InputResolver) -> Value {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx),
+        None => input.default.clone(),
+    }
+}
+
+// ============================================================================
+// ResourceByName Operator
+// ============================================================================
+
+/// Resolves a resource name to a reference
+///
+/// Outputs an unresolved reference and `Found = false` when no table is
+/// installed or the name isn't registered.
+pub struct ResourceByNameOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 2],
+}
+
+impl ResourceByNameOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::string("Name", "")],
+            outputs: [OutputPort::resource_ref("Resource"), OutputPort::bool("Found")],
+        }
+    }
+}
+
+impl Default for ResourceByNameOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ResourceByNameOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ResourceByName" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let name = get_value(&self.inputs[0], get_input);
+        let found = ctx.resources.as_ref().and_then(|table| {
+            let id = table.lookup(name.as_string()?)?;
+            Some(Value::ResourceRef { kind: table.get(id)?.kind, id })
+        });
+        self.outputs[1].set_bool(found.is_some());
+        self.outputs[0].set(found.unwrap_or_else(|| ValueType::ResourceRef.default_value()));
+    }
+}
+
+impl OperatorMeta for ResourceByNameOp {
+    fn category(&self) -> &'static str { "Util" }
+    fn category_color(&self) -> [f32; 4] { category_colors::UTIL }
+    fn description(&self) -> &'static str { "Look up a host resource by name" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Name")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Resource").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Found")),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// ResourceInfo Operator
+// ============================================================================
+
+/// Reports a reference's kind and whether it resolves
+pub struct ResourceInfoOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 2],
+}
+
+impl ResourceInfoOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::resource_ref("Resource")],
+            outputs: [OutputPort::string("Kind"), OutputPort::bool("Exists")],
+        }
+    }
+}
+
+impl Default for ResourceInfoOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for ResourceInfoOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "ResourceInfo" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let resource = get_value(&self.inputs[0], get_input);
+        let kind = resource.as_resource_ref().map(|(kind, _)| kind.to_string());
+        self.outputs[0].set_string(kind.as_deref().unwrap_or_default());
+        self.outputs[1].set_bool(ctx.resource(&resource).is_some());
+    }
+}
+
+impl OperatorMeta for ResourceInfoOp {
+    fn category(&self) -> &'static str { "Util" }
+    fn category_color(&self) -> [f32; 4] { category_colors::UTIL }
+    fn description(&self) -> &'static str { "Kind of a resource reference and whether it exists" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Resource")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Kind").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Exists")),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new("ResourceByName", "Utility", "Look up resource by name"),
+        || capture_meta(ResourceByNameOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new("ResourceInfo", "Utility", "Get resource kind and existence"),
+        || capture_meta(ResourceInfoOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use flux_core::resource::{ResourceKind, ResourceTable, NULL_RESOURCE_ID};
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    fn context() -> (EvalContext, u64) {
+        let mut table = ResourceTable::new();
+        let id = table.insert(ResourceKind::Mesh, "teapot", "mesh data");
+        let mut ctx = EvalContext::new();
+        ctx.resources = Some(Arc::new(table));
+        (ctx, id)
+    }
+
+    #[test]
+    fn test_resource_by_name() {
+        let (ctx, id) = context();
+        let mut op = ResourceByNameOp::new();
+
+        op.inputs[0].default = Value::String("teapot".to_string());
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value, Value::ResourceRef { kind: ResourceKind::Mesh, id });
+        assert_eq!(op.outputs[1].value.as_bool(), Some(true));
+
+        op.inputs[0].default = Value::String("missing".to_string());
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_resource_ref().map(|(_, id)| id), Some(NULL_RESOURCE_ID));
+        assert_eq!(op.outputs[1].value.as_bool(), Some(false));
+
+        // No table installed
+        op.inputs[0].default = Value::String("teapot".to_string());
+        op.compute(&EvalContext::new(), &no_connections);
+        assert_eq!(op.outputs[1].value.as_bool(), Some(false));
+    }
+
+    #[test]
+    fn test_resource_info() {
+        let (ctx, id) = context();
+        let mut op = ResourceInfoOp::new();
+
+        op.inputs[0].default = Value::ResourceRef { kind: ResourceKind::Mesh, id };
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_string(), Some("Mesh"));
+        assert_eq!(op.outputs[1].value.as_bool(), Some(true));
+
+        // Right id, wrong kind
+        op.inputs[0].default = Value::ResourceRef { kind: ResourceKind::Texture, id };
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_string(), Some("Texture"));
+        assert_eq!(op.outputs[1].value.as_bool(), Some(false));
+
+        op.inputs[0].default = Value::ResourceRef { kind: ResourceKind::Custom(7), id: id + 1 };
+        op.compute(&ctx, &no_connections);
+        assert_eq!(op.outputs[0].value.as_string(), Some("Custom(7)"));
+        assert_eq!(op.outputs[1].value.as_bool(), Some(false));
+    }
+}
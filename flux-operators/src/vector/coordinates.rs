@@ -0,0 +1,821 @@
+//! Coordinate system conversions
+//!
+//! Polar and spherical coordinates, and grid cells for instancing layouts.
+//! All angles are in degrees:
+//!
+//! - Polar angle: from +X, counterclockwise towards +Y, in -180..180
+//! - Azimuth: from +X, counterclockwise seen from above (+Y, right-handed),
+//!   so 90° points along -Z; in -180..180
+//! - Elevation: from the XZ plane, positive towards +Y, in -90..90
+//!
+//! Angles of the origin (and the azimuth at the poles) are undefined; they
+//! are reported as 0 so every output stays finite.
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta, Unit};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+use flux_core::port::{InputPort, OutputPort};
+
+fn get_float(input: &InputPort, get_input: InputResolver) -> f32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_float().unwrap_or(0.0),
+        None => input.default.as_float().unwrap_or(0.0),
+    }
+}
+
+fn get_int(input: &InputPort, get_input: InputResolver) -> i32 {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_int().unwrap_or(0),
+        None => input.default.as_int().unwrap_or(0),
+    }
+}
+
+fn get_bool(input: &InputPort, get_input: InputResolver) -> bool {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_bool().unwrap_or(false),
+        None => input.default.as_bool().unwrap_or(false),
+    }
+}
+
+fn get_vec2(input: &InputPort, get_input: InputResolver) -> [f32; 2] {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_vec2().unwrap_or([0.0, 0.0]),
+        None => input.default.as_vec2().unwrap_or([0.0, 0.0]),
+    }
+}
+
+fn get_vec3(input: &InputPort, get_input: InputResolver) -> [f32; 3] {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx).as_vec3().unwrap_or([0.0; 3]),
+        None => input.default.as_vec3().unwrap_or([0.0; 3]),
+    }
+}
+
+/// `atan2` in degrees, 0 where both arguments are zero
+fn angle_degrees(y: f32, x: f32) -> f32 {
+    if y == 0.0 && x == 0.0 {
+        0.0
+    } else {
+        y.atan2(x).to_degrees()
+    }
+}
+
+/// Radius and angle (degrees) of a 2D point
+pub fn cartesian_to_polar(v: [f32; 2]) -> (f32, f32) {
+    (v[0].hypot(v[1]), angle_degrees(v[1], v[0]))
+}
+
+/// Point at `radius` and `angle` (degrees); inverse of [`cartesian_to_polar`]
+pub fn polar_to_cartesian(radius: f32, angle: f32) -> [f32; 2] {
+    let (sin, cos) = angle.to_radians().sin_cos();
+    [radius * cos, radius * sin]
+}
+
+/// Radius, azimuth and elevation (degrees) of a 3D point
+pub fn cartesian_to_spherical(v: [f32; 3]) -> (f32, f32, f32) {
+    let [x, y, z] = v;
+    let horizontal = x.hypot(z);
+    (
+        horizontal.hypot(y),
+        angle_degrees(-z, x),
+        angle_degrees(y, horizontal),
+    )
+}
+
+/// Point at `radius`, `azimuth` and `elevation` (degrees); inverse of
+/// [`cartesian_to_spherical`]
+pub fn spherical_to_cartesian(radius: f32, azimuth: f32, elevation: f32) -> [f32; 3] {
+    let (sin_az, cos_az) = azimuth.to_radians().sin_cos();
+    let (sin_el, cos_el) = elevation.to_radians().sin_cos();
+    let horizontal = radius * cos_el;
+    [horizontal * cos_az, radius * sin_el, -horizontal * sin_az]
+}
+
+// ============================================================================
+// CartesianToPolar Operator
+// ============================================================================
+
+pub struct CartesianToPolarOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 2],
+}
+
+impl CartesianToPolarOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::vec2("Vector", [1.0, 0.0])],
+            outputs: [OutputPort::float("Radius"), OutputPort::float("Angle")],
+        }
+    }
+}
+
+impl Default for CartesianToPolarOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for CartesianToPolarOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "CartesianToPolar" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let (radius, angle) = cartesian_to_polar(get_vec2(&self.inputs[0], get_input));
+        self.outputs[0].set_float(radius);
+        self.outputs[1].set_float(angle);
+    }
+}
+
+impl OperatorMeta for CartesianToPolarOp {
+    fn category(&self) -> &'static str { "Vector" }
+    fn category_color(&self) -> [f32; 4] { category_colors::VECTORS }
+    fn description(&self) -> &'static str {
+        "Radius and angle of a Vec2 (degrees from +X towards +Y, 0 at the origin)"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Vector")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Radius").with_shape(PinShape::TriangleFilled)),
+            1 => Some(
+                PortMeta::new("Angle")
+                    .with_shape(PinShape::TriangleFilled)
+                    .with_typed_unit(Unit::Degrees)
+                    .with_range(-180.0, 180.0),
+            ),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// PolarToCartesian Operator
+// ============================================================================
+
+pub struct PolarToCartesianOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+}
+
+impl PolarToCartesianOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::float("Radius", 1.0), InputPort::float("Angle", 0.0)],
+            outputs: [OutputPort::vec2("Vector")],
+        }
+    }
+}
+
+impl Default for PolarToCartesianOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for PolarToCartesianOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "PolarToCartesian" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let radius = get_float(&self.inputs[0], get_input);
+        let angle = get_float(&self.inputs[1], get_input);
+        self.outputs[0].set_vec2(polar_to_cartesian(radius, angle));
+    }
+}
+
+impl OperatorMeta for PolarToCartesianOp {
+    fn category(&self) -> &'static str { "Vector" }
+    fn category_color(&self) -> [f32; 4] { category_colors::VECTORS }
+    fn description(&self) -> &'static str {
+        "Vec2 from radius and angle (degrees from +X towards +Y)"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Radius")),
+            1 => Some(
+                PortMeta::new("Angle")
+                    .with_typed_unit(Unit::Degrees)
+                    .with_range(-180.0, 180.0),
+            ),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Vector").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// CartesianToSpherical Operator
+// ============================================================================
+
+pub struct CartesianToSphericalOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 3],
+}
+
+impl CartesianToSphericalOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::vec3("Vector", [1.0, 0.0, 0.0])],
+            outputs: [
+                OutputPort::float("Radius"),
+                OutputPort::float("Azimuth"),
+                OutputPort::float("Elevation"),
+            ],
+        }
+    }
+}
+
+impl Default for CartesianToSphericalOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for CartesianToSphericalOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "CartesianToSpherical" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let v = get_vec3(&self.inputs[0], get_input);
+        let (radius, azimuth, elevation) = cartesian_to_spherical(v);
+        self.outputs[0].set_float(radius);
+        self.outputs[1].set_float(azimuth);
+        self.outputs[2].set_float(elevation);
+    }
+}
+
+impl OperatorMeta for CartesianToSphericalOp {
+    fn category(&self) -> &'static str { "Vector" }
+    fn category_color(&self) -> [f32; 4] { category_colors::VECTORS }
+    fn description(&self) -> &'static str {
+        "Radius, azimuth (degrees from +X, 90 = -Z) and elevation (degrees from the XZ plane) of a Vec3"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Vector")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Radius").with_shape(PinShape::TriangleFilled)),
+            1 => Some(
+                PortMeta::new("Azimuth")
+                    .with_shape(PinShape::TriangleFilled)
+                    .with_typed_unit(Unit::Degrees)
+                    .with_range(-180.0, 180.0),
+            ),
+            2 => Some(
+                PortMeta::new("Elevation")
+                    .with_shape(PinShape::TriangleFilled)
+                    .with_typed_unit(Unit::Degrees)
+                    .with_range(-90.0, 90.0),
+            ),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// SphericalToCartesian Operator
+// ============================================================================
+
+pub struct SphericalToCartesianOp {
+    id: Id,
+    inputs: [InputPort; 3],
+    outputs: [OutputPort; 1],
+}
+
+impl SphericalToCartesianOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::float("Radius", 1.0),
+                InputPort::float("Azimuth", 0.0),
+                InputPort::float("Elevation", 0.0),
+            ],
+            outputs: [OutputPort::vec3("Vector")],
+        }
+    }
+}
+
+impl Default for SphericalToCartesianOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for SphericalToCartesianOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "SphericalToCartesian" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let radius = get_float(&self.inputs[0], get_input);
+        let azimuth = get_float(&self.inputs[1], get_input);
+        let elevation = get_float(&self.inputs[2], get_input);
+        self.outputs[0].set_vec3(spherical_to_cartesian(radius, azimuth, elevation));
+    }
+}
+
+impl OperatorMeta for SphericalToCartesianOp {
+    fn category(&self) -> &'static str { "Vector" }
+    fn category_color(&self) -> [f32; 4] { category_colors::VECTORS }
+    fn description(&self) -> &'static str {
+        "Vec3 from radius, azimuth (degrees from +X, 90 = -Z) and elevation (degrees from the XZ plane)"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Radius")),
+            1 => Some(
+                PortMeta::new("Azimuth")
+                    .with_typed_unit(Unit::Degrees)
+                    .with_range(-180.0, 180.0),
+            ),
+            2 => Some(
+                PortMeta::new("Elevation")
+                    .with_typed_unit(Unit::Degrees)
+                    .with_range(-90.0, 90.0),
+            ),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Vector").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Grid cells
+// ============================================================================
+
+/// Order in which grid cells are numbered
+///
+/// Selected through an Int "Order" input: 0 = RowMajor, 1 = ColumnMajor.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GridOrder {
+    /// Along each row, then down to the next row
+    #[default]
+    RowMajor,
+    /// Down each column, then across to the next column
+    ColumnMajor,
+}
+
+impl GridOrder {
+    /// Order for an Order input value (unknown values are row-major)
+    pub fn from_index(index: i32) -> Self {
+        match index {
+            1 => GridOrder::ColumnMajor,
+            _ => GridOrder::RowMajor,
+        }
+    }
+
+    /// Order input value for this order
+    pub fn index(self) -> i32 {
+        self as i32
+    }
+}
+
+/// Column and row of cell `index` in a `columns` × `rows` grid
+///
+/// With `wrap` the index repeats every `columns * rows` cells; otherwise
+/// indices past the end add rows (row-major) or columns (column-major).
+/// Sizes below 1 count as 1.
+pub fn index_to_grid(index: i32, columns: i32, rows: i32, order: GridOrder, wrap: bool) -> [i32; 2] {
+    let (columns, rows) = (columns.max(1), rows.max(1));
+    let index = if wrap {
+        index.rem_euclid(columns.saturating_mul(rows))
+    } else {
+        index
+    };
+    match order {
+        GridOrder::RowMajor => [index.rem_euclid(columns), index.div_euclid(columns)],
+        GridOrder::ColumnMajor => [index.div_euclid(rows), index.rem_euclid(rows)],
+    }
+}
+
+/// Index of the cell at `column` and `row`; inverse of [`index_to_grid`]
+///
+/// With `wrap` the column and row are first wrapped into the grid.
+pub fn grid_to_index(
+    column: i32,
+    row: i32,
+    columns: i32,
+    rows: i32,
+    order: GridOrder,
+    wrap: bool,
+) -> i32 {
+    let (columns, rows) = (columns.max(1), rows.max(1));
+    let (column, row) = if wrap {
+        (column.rem_euclid(columns), row.rem_euclid(rows))
+    } else {
+        (column, row)
+    };
+    match order {
+        GridOrder::RowMajor => row.saturating_mul(columns).saturating_add(column),
+        GridOrder::ColumnMajor => column.saturating_mul(rows).saturating_add(row),
+    }
+}
+
+fn grid_size_input_meta(index: usize) -> Option<PortMeta> {
+    match index {
+        0 => Some(PortMeta::new("Columns").with_range(1.0, 64.0).with_step(1.0)),
+        1 => Some(PortMeta::new("Rows").with_range(1.0, 64.0).with_step(1.0)),
+        // 0=RowMajor, 1=ColumnMajor
+        2 => Some(PortMeta::new("Order").with_range(0.0, 1.0).with_step(1.0)),
+        3 => Some(PortMeta::new("Wrap")),
+        _ => None,
+    }
+}
+
+fn grid_size_inputs() -> [InputPort; 4] {
+    [
+        InputPort::int("Columns", 4),
+        InputPort::int("Rows", 4),
+        InputPort::int("Order", GridOrder::RowMajor.index()),
+        InputPort::bool("Wrap", false),
+    ]
+}
+
+// ============================================================================
+// IndexToGrid Operator
+// ============================================================================
+
+/// Column and row of an instance index in a grid layout
+///
+/// Rows are only needed for column-major order and for wrapping.
+pub struct IndexToGridOp {
+    id: Id,
+    inputs: [InputPort; 5],
+    outputs: [OutputPort; 2],
+}
+
+impl IndexToGridOp {
+    pub fn new() -> Self {
+        let [columns, rows, order, wrap] = grid_size_inputs();
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::int("Index", 0), columns, rows, order, wrap],
+            outputs: [OutputPort::int("Column"), OutputPort::int("Row")],
+        }
+    }
+}
+
+impl Default for IndexToGridOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for IndexToGridOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "IndexToGrid" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let index = get_int(&self.inputs[0], get_input);
+        let columns = get_int(&self.inputs[1], get_input);
+        let rows = get_int(&self.inputs[2], get_input);
+        let order = GridOrder::from_index(get_int(&self.inputs[3], get_input));
+        let wrap = get_bool(&self.inputs[4], get_input);
+        let [column, row] = index_to_grid(index, columns, rows, order, wrap);
+        self.outputs[0].set_int(column);
+        self.outputs[1].set_int(row);
+    }
+}
+
+impl OperatorMeta for IndexToGridOp {
+    fn category(&self) -> &'static str { "Vector" }
+    fn category_color(&self) -> [f32; 4] { category_colors::VECTORS }
+    fn description(&self) -> &'static str {
+        "Column and row of an index in a grid (row- or column-major, optionally wrapped)"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Index")),
+            _ => grid_size_input_meta(index - 1),
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Column").with_shape(PinShape::TriangleFilled)),
+            1 => Some(PortMeta::new("Row").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// GridToIndex Operator
+// ============================================================================
+
+/// Instance index of a grid cell; inverse of [`IndexToGridOp`]
+pub struct GridToIndexOp {
+    id: Id,
+    inputs: [InputPort; 6],
+    outputs: [OutputPort; 1],
+}
+
+impl GridToIndexOp {
+    pub fn new() -> Self {
+        let [columns, rows, order, wrap] = grid_size_inputs();
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::int("Column", 0),
+                InputPort::int("Row", 0),
+                columns,
+                rows,
+                order,
+                wrap,
+            ],
+            outputs: [OutputPort::int("Index")],
+        }
+    }
+}
+
+impl Default for GridToIndexOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for GridToIndexOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "GridToIndex" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        let column = get_int(&self.inputs[0], get_input);
+        let row = get_int(&self.inputs[1], get_input);
+        let columns = get_int(&self.inputs[2], get_input);
+        let rows = get_int(&self.inputs[3], get_input);
+        let order = GridOrder::from_index(get_int(&self.inputs[4], get_input));
+        let wrap = get_bool(&self.inputs[5], get_input);
+        self.outputs[0].set_int(grid_to_index(column, row, columns, rows, order, wrap));
+    }
+}
+
+impl OperatorMeta for GridToIndexOp {
+    fn category(&self) -> &'static str { "Vector" }
+    fn category_color(&self) -> [f32; 4] { category_colors::VECTORS }
+    fn description(&self) -> &'static str {
+        "Index of a grid cell (row- or column-major, optionally wrapped)"
+    }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Column")),
+            1 => Some(PortMeta::new("Row")),
+            _ => grid_size_input_meta(index - 2),
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Index").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new(
+            "CartesianToPolar",
+            "Vector",
+            "Radius and angle of a Vec2 (degrees from +X towards +Y, 0 at the origin)",
+        ),
+        || capture_meta(CartesianToPolarOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "PolarToCartesian",
+            "Vector",
+            "Vec2 from radius and angle (degrees from +X towards +Y)",
+        ),
+        || capture_meta(PolarToCartesianOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "CartesianToSpherical",
+            "Vector",
+            "Radius, azimuth (degrees from +X, 90 = -Z) and elevation (degrees from the XZ plane) of a Vec3",
+        ),
+        || capture_meta(CartesianToSphericalOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "SphericalToCartesian",
+            "Vector",
+            "Vec3 from radius, azimuth (degrees from +X, 90 = -Z) and elevation (degrees from the XZ plane)",
+        ),
+        || capture_meta(SphericalToCartesianOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "IndexToGrid",
+            "Vector",
+            "Column and row of an index in a grid (row- or column-major, optionally wrapped)",
+        ),
+        || capture_meta(IndexToGridOp::new()),
+    );
+
+    registry.register(
+        RegistryEntry::new(
+            "GridToIndex",
+            "Vector",
+            "Index of a grid cell (row- or column-major, optionally wrapped)",
+        ),
+        || capture_meta(GridToIndexOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::Value;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    /// Deterministic pseudo-random values in -range..range
+    fn random_values(count: usize, range: f32) -> Vec<f32> {
+        let mut state: u32 = 0x2545_f491;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 * 2.0 - 1.0) * range
+            })
+            .collect()
+    }
+
+    fn assert_close(a: &[f32], b: &[f32]) {
+        for (a, b) in a.iter().zip(b) {
+            assert!((a - b).abs() <= 1e-4 * a.abs().max(1.0), "{:?} != {:?}", a, b);
+        }
+    }
+
+    #[test]
+    fn test_polar_round_trip() {
+        let ctx = EvalContext::new();
+        let mut to_polar = CartesianToPolarOp::new();
+        let mut to_cartesian = PolarToCartesianOp::new();
+        for point in random_values(200, 100.0).chunks(2) {
+            to_polar.inputs[0].default = Value::Vec2([point[0], point[1]]);
+            to_polar.compute(&ctx, &no_connections);
+            to_cartesian.inputs[0].default = to_polar.outputs[0].value.clone();
+            to_cartesian.inputs[1].default = to_polar.outputs[1].value.clone();
+            to_cartesian.compute(&ctx, &no_connections);
+            assert_close(&to_cartesian.outputs[0].value.as_vec2().unwrap(), point);
+        }
+
+        // Angle conventions: +Y is 90°, the origin is 0°
+        assert_close(&polar_to_cartesian(2.0, 90.0), &[0.0, 2.0]);
+        assert_eq!(cartesian_to_polar([0.0, 3.0]), (3.0, 90.0));
+        assert_eq!(cartesian_to_polar([0.0, 0.0]), (0.0, 0.0));
+        assert_eq!(cartesian_to_polar([-0.0, -0.0]), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_spherical_round_trip() {
+        let ctx = EvalContext::new();
+        let mut to_spherical = CartesianToSphericalOp::new();
+        let mut to_cartesian = SphericalToCartesianOp::new();
+        for point in random_values(300, 100.0).chunks(3) {
+            to_spherical.inputs[0].default = Value::Vec3([point[0], point[1], point[2]]);
+            to_spherical.compute(&ctx, &no_connections);
+            for i in 0..3 {
+                to_cartesian.inputs[i].default = to_spherical.outputs[i].value.clone();
+            }
+            to_cartesian.compute(&ctx, &no_connections);
+            assert_close(&to_cartesian.outputs[0].value.as_vec3().unwrap(), point);
+        }
+
+        // Azimuth turns from +X towards -Z; elevation rises towards +Y
+        assert_eq!(cartesian_to_spherical([0.0, 0.0, -2.0]), (2.0, 90.0, 0.0));
+        assert_close(&spherical_to_cartesian(1.0, 90.0, 0.0), &[0.0, 0.0, -1.0]);
+        assert_close(&spherical_to_cartesian(1.0, 0.0, 90.0), &[0.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_spherical_poles_and_origin() {
+        assert_eq!(cartesian_to_spherical([0.0, 5.0, 0.0]), (5.0, 0.0, 90.0));
+        assert_eq!(cartesian_to_spherical([0.0, -5.0, -0.0]), (5.0, 0.0, -90.0));
+        assert_eq!(cartesian_to_spherical([0.0, 0.0, 0.0]), (0.0, 0.0, 0.0));
+        assert_eq!(cartesian_to_spherical([-0.0, -0.0, -0.0]), (0.0, 0.0, 0.0));
+        let huge = cartesian_to_spherical([f32::MAX, f32::MAX, 0.0]);
+        assert!(!huge.1.is_nan() && !huge.2.is_nan());
+        assert_eq!(spherical_to_cartesian(0.0, 123.0, 45.0), [0.0, 0.0, -0.0]);
+    }
+
+    #[test]
+    fn test_grid_mapping_exhaustive() {
+        let (columns, rows) = (3, 4);
+        for order in [GridOrder::RowMajor, GridOrder::ColumnMajor] {
+            let mut seen = Vec::new();
+            for index in 0..columns * rows {
+                let [column, row] = index_to_grid(index, columns, rows, order, false);
+                assert!((0..columns).contains(&column) && (0..rows).contains(&row));
+                assert_eq!(grid_to_index(column, row, columns, rows, order, false), index);
+                seen.push((column, row));
+            }
+            seen.sort();
+            seen.dedup();
+            assert_eq!(seen.len(), 12);
+        }
+        assert_eq!(index_to_grid(4, 3, 4, GridOrder::RowMajor, false), [1, 1]);
+        assert_eq!(index_to_grid(4, 3, 4, GridOrder::ColumnMajor, false), [1, 0]);
+    }
+
+    #[test]
+    fn test_grid_wrap_and_ops() {
+        assert_eq!(index_to_grid(13, 3, 4, GridOrder::RowMajor, true), [1, 0]);
+        assert_eq!(index_to_grid(13, 3, 4, GridOrder::RowMajor, false), [1, 4]);
+        assert_eq!(index_to_grid(-1, 3, 4, GridOrder::RowMajor, true), [2, 3]);
+        assert_eq!(grid_to_index(4, -1, 3, 4, GridOrder::RowMajor, true), 10);
+        assert_eq!(index_to_grid(5, 0, 0, GridOrder::RowMajor, false), [0, 5]);
+
+        let ctx = EvalContext::new();
+        let mut to_grid = IndexToGridOp::new();
+        to_grid.inputs[0].default = Value::Int(7);
+        to_grid.inputs[1].default = Value::Int(3);
+        to_grid.inputs[3].default = Value::Int(GridOrder::ColumnMajor.index());
+        to_grid.compute(&ctx, &no_connections);
+        assert_eq!(to_grid.outputs[0].value, Value::Int(1));
+        assert_eq!(to_grid.outputs[1].value, Value::Int(3));
+
+        let mut to_index = GridToIndexOp::new();
+        to_index.inputs[0].default = Value::Int(1);
+        to_index.inputs[1].default = Value::Int(3);
+        to_index.inputs[2].default = Value::Int(3);
+        to_index.inputs[4].default = Value::Int(GridOrder::ColumnMajor.index());
+        to_index.compute(&ctx, &no_connections);
+        assert_eq!(to_index.outputs[0].value, Value::Int(7));
+    }
+}
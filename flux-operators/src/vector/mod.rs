@@ -1,13 +1,19 @@
-//! Vector operators (15 total)
+//! Vector operators (24 total)
 //!
 //! - Vec2 (5): Vec2Compose, Vec2Decompose, Vec2Add, Vec2Scale, Vec2Length
-//! - Vec3 (7): Vec3Compose, Vec3Decompose, Vec3Add, Vec3Subtract, Vec3Scale, Vec3Normalize, Vec3Dot, Vec3Cross, Vec3Length, Vec3Distance
+//! - Vec3 (9): Vec3Decompose, Vec3Add, Vec3Subtract, Vec3Scale, Vec3Normalize, Vec3Dot, Vec3Cross, Vec3Length, Vec3Distance
 //! - Vec4 (3): Vec4Compose, Vec4Decompose, Vec3ToVec4
+//! - Swizzle (1): Swizzle (pattern-based component reordering)
+//! - Coordinates (6): CartesianToPolar, PolarToCartesian, CartesianToSpherical, SphericalToCartesian, IndexToGrid, GridToIndex
 
+mod coordinates;
+mod swizzle;
 mod vec2;
 mod vec3;
 mod vec4;
 
+pub use coordinates::*;
+pub use swizzle::*;
 pub use vec2::*;
 pub use vec3::*;
 pub use vec4::*;
@@ -18,4 +24,6 @@ pub fn register_all(registry: &OperatorRegistry) {
     vec2::register(registry);
     vec3::register(registry);
     vec4::register(registry);
+    swizzle::register(registry);
+    coordinates::register(registry);
 }
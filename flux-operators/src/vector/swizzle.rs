@@ -0,0 +1,271 @@
+//! Swizzle operator: reorder, repeat or drop vector components by pattern
+
+use std::any::Any;
+
+use flux_core::context::EvalContext;
+use flux_core::error::OperatorError;
+use flux_core::id::Id;
+use flux_core::operator::{InputResolver, Operator};
+use flux_core::port::{InputPort, OutputPort, OutputTypeRule, TypeConstraint};
+use flux_core::value::{Value, ValueType};
+use flux_core::{category_colors, OperatorMeta, PinShape, PortMeta};
+use crate::registry::{capture_meta, OperatorRegistry, RegistryEntry};
+
+/// Longest pattern (one character per output component)
+const MAX_PATTERN_LEN: usize = 4;
+
+fn get_value(input: &InputPort, get_input: InputResolver) -> Value {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx),
+        None => input.default.clone(),
+    }
+}
+
+fn get_string(input: &InputPort, get_input: InputResolver) -> String {
+    let value = get_value(input, get_input);
+    value.as_string().unwrap_or_default().to_string()
+}
+
+/// Parse a swizzle pattern into component indices.
+///
+/// Characters come from either `xyzw` or `rgba` (mapped onto the same
+/// indices); mixing the two sets, unknown characters, and empty patterns or
+/// patterns longer than 4 are rejected.
+pub fn parse_swizzle(pattern: &str) -> Result<Vec<usize>, String> {
+    const SETS: [&str; 2] = ["xyzw", "rgba"];
+
+    let len = pattern.chars().count();
+    if len == 0 || len > MAX_PATTERN_LEN {
+        return Err(format!(
+            "swizzle pattern '{}' must have 1 to {} components",
+            pattern, MAX_PATTERN_LEN
+        ));
+    }
+    let first = pattern.chars().next().unwrap_or_default();
+    let Some(set) = SETS.iter().find(|set| set.contains(first)) else {
+        return Err(format!("invalid swizzle component '{}' in '{}'", first, pattern));
+    };
+    pattern
+        .chars()
+        .map(|c| {
+            set.find(c)
+                .ok_or_else(|| format!("invalid swizzle component '{}' in '{}'", c, pattern))
+        })
+        .collect()
+}
+
+/// Components of a vector or color value, or `None` for other types.
+fn components(value: &Value) -> Option<Vec<f32>> {
+    match value {
+        Value::Vec2(v) => Some(v.to_vec()),
+        Value::Vec3(v) => Some(v.to_vec()),
+        Value::Vec4(v) => Some(v.to_vec()),
+        Value::Color(c) => Some(c.to_array().to_vec()),
+        _ => None,
+    }
+}
+
+/// Pick `indices` from `source`, using 0.0 for components it doesn't have.
+///
+/// Returns the swizzled value and whether any index was out of range.
+pub fn swizzle(source: &[f32], indices: &[usize]) -> (Value, bool) {
+    let picked: Vec<f32> = indices
+        .iter()
+        .map(|&i| source.get(i).copied().unwrap_or(0.0))
+        .collect();
+    let out_of_range = indices.iter().any(|&i| i >= source.len());
+    let value = match picked[..] {
+        [x] => Value::Float(x),
+        [x, y] => Value::Vec2([x, y]),
+        [x, y, z] => Value::Vec3([x, y, z]),
+        [x, y, z, w] => Value::Vec4([x, y, z, w]),
+        _ => Value::Float(0.0),
+    };
+    (value, out_of_range)
+}
+
+// ============================================================================
+// Swizzle Operator
+// ============================================================================
+
+/// Build a new vector from components of a Vec2/Vec3/Vec4/Color input.
+///
+/// The output type follows the pattern length: Float, Vec2, Vec3 or Vec4.
+/// An invalid pattern passes the input through unchanged, and components
+/// the input doesn't have (e.g. "z" on a Vec2) read as 0.0; both report an
+/// operator error.
+pub struct SwizzleOp {
+    id: Id,
+    inputs: [InputPort; 2],
+    outputs: [OutputPort; 1],
+}
+
+impl SwizzleOp {
+    pub fn new() -> Self {
+        let vector_types = vec![
+            ValueType::Vec2,
+            ValueType::Vec3,
+            ValueType::Vec4,
+            ValueType::Color,
+        ];
+        let mut output = OutputPort::polymorphic("Result", OutputTypeRule::Dynamic);
+        output.set_resolved_type(ValueType::Vec3);
+        output.set(Value::Vec3([0.0, 0.0, 0.0]));
+        Self {
+            id: Id::new(),
+            inputs: [
+                InputPort::constrained(
+                    "Vector",
+                    TypeConstraint::OneOf(vector_types),
+                    Value::Vec4([0.0, 0.0, 0.0, 1.0]),
+                ),
+                InputPort::string("Pattern", "xyz"),
+            ],
+            outputs: [output],
+        }
+    }
+
+    /// Create with the given pattern
+    pub fn with_pattern(pattern: &str) -> Self {
+        let mut op = Self::new();
+        op.inputs[1].default = Value::String(pattern.to_string());
+        op
+    }
+}
+
+impl Default for SwizzleOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for SwizzleOp {
+    fn as_any(&self) -> &dyn Any { self }
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+    fn id(&self) -> Id { self.id }
+    fn name(&self) -> &'static str { "Swizzle" }
+    fn inputs(&self) -> &[InputPort] { &self.inputs }
+    fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
+    fn outputs(&self) -> &[OutputPort] { &self.outputs }
+    fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let input = get_value(&self.inputs[0], get_input);
+        let pattern = get_string(&self.inputs[1], get_input);
+        let source = components(&input).unwrap_or_default();
+
+        let value = match parse_swizzle(&pattern) {
+            Ok(indices) => {
+                let (value, out_of_range) = swizzle(&source, &indices);
+                if out_of_range {
+                    ctx.report_error(OperatorError::InvalidValue {
+                        message: format!(
+                            "swizzle '{}' reads past the {} components of {:?}",
+                            pattern,
+                            source.len(),
+                            input.value_type()
+                        ),
+                    });
+                }
+                value
+            }
+            Err(message) => {
+                ctx.report_error(OperatorError::InvalidValue { message });
+                input
+            }
+        };
+
+        self.outputs[0].set_resolved_type(value.value_type());
+        self.outputs[0].set(value);
+    }
+}
+
+impl OperatorMeta for SwizzleOp {
+    fn category(&self) -> &'static str { "Vector" }
+    fn category_color(&self) -> [f32; 4] { category_colors::VECTORS }
+    fn description(&self) -> &'static str { "Reorder vector components by pattern (e.g. zyx, bgra)" }
+    fn input_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Vector")),
+            1 => Some(PortMeta::new("Pattern")),
+            _ => None,
+        }
+    }
+    fn output_meta(&self, index: usize) -> Option<PortMeta> {
+        match index {
+            0 => Some(PortMeta::new("Result").with_shape(PinShape::TriangleFilled)),
+            _ => None,
+        }
+    }
+}
+
+// ============================================================================
+// Registration
+// ============================================================================
+
+pub fn register(registry: &OperatorRegistry) {
+    registry.register(
+        RegistryEntry::new(
+            "Swizzle",
+            "Vector",
+            "Reorder vector components by pattern (e.g. zyx, bgra)",
+        ),
+        || capture_meta(SwizzleOp::new()),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_core::value::Color;
+
+    fn no_connections(_: Id, _: usize) -> Value {
+        Value::Float(0.0)
+    }
+
+    fn run(input: Value, pattern: &str) -> (Value, ValueType, usize) {
+        let mut op = SwizzleOp::with_pattern(pattern);
+        op.inputs[0].default = input;
+        let ctx = EvalContext::new();
+        op.compute(&ctx, &no_connections);
+        let output = &op.outputs[0];
+        (output.value.clone(), output.value_type, ctx.take_errors().len())
+    }
+
+    #[test]
+    fn test_swizzle_patterns() {
+        let vec2 = Value::Vec2([1.0, 2.0]);
+        let vec3 = Value::Vec3([1.0, 2.0, 3.0]);
+        let vec4 = Value::Vec4([1.0, 2.0, 3.0, 4.0]);
+        let color = Value::Color(Color::rgba(0.1, 0.2, 0.3, 0.4));
+
+        assert_eq!(run(vec2.clone(), "yx"), (Value::Vec2([2.0, 1.0]), ValueType::Vec2, 0));
+        assert_eq!(run(vec2, "xxyy"), (Value::Vec4([1.0, 1.0, 2.0, 2.0]), ValueType::Vec4, 0));
+        assert_eq!(run(vec3.clone(), "zyx"), (Value::Vec3([3.0, 2.0, 1.0]), ValueType::Vec3, 0));
+        assert_eq!(run(vec3, "y"), (Value::Float(2.0), ValueType::Float, 0));
+        assert_eq!(run(vec4.clone(), "wxy"), (Value::Vec3([4.0, 1.0, 2.0]), ValueType::Vec3, 0));
+        assert_eq!(run(vec4, "rgb"), (Value::Vec3([1.0, 2.0, 3.0]), ValueType::Vec3, 0));
+        assert_eq!(
+            run(color.clone(), "bgra"),
+            (Value::Vec4([0.3, 0.2, 0.1, 0.4]), ValueType::Vec4, 0)
+        );
+        assert_eq!(run(color, "xy"), (Value::Vec2([0.1, 0.2]), ValueType::Vec2, 0));
+    }
+
+    #[test]
+    fn test_swizzle_invalid_pattern_passes_through() {
+        let vec3 = Value::Vec3([1.0, 2.0, 3.0]);
+        for pattern in ["xg", "xyzwx", "", "xq"] {
+            assert_eq!(run(vec3.clone(), pattern), (vec3.clone(), ValueType::Vec3, 1), "{}", pattern);
+        }
+        assert!(parse_swizzle("rgba").is_ok());
+        assert!(parse_swizzle("ra").is_ok());
+    }
+
+    #[test]
+    fn test_swizzle_out_of_range_component() {
+        let vec2 = Value::Vec2([1.0, 2.0]);
+        assert_eq!(run(vec2.clone(), "z"), (Value::Float(0.0), ValueType::Float, 1));
+        assert_eq!(run(vec2, "xyzw"), (Value::Vec4([1.0, 2.0, 0.0, 0.0]), ValueType::Vec4, 1));
+    }
+}
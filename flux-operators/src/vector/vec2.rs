@@ -120,6 +120,7 @@ impl Operator for Vec2DecomposeOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn outputs_coupled(&self) -> bool { false }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let v = get_vec2(&self.inputs[0], get_input);
@@ -344,52 +345,27 @@ impl OperatorMeta for Vec2LengthOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec2Compose",
-            category: "Vector",
-            description: "Create Vec2 from X, Y components",
-        },
+        RegistryEntry::new("Vec2Compose", "Vector", "Create Vec2 from X, Y components"),
         || capture_meta(Vec2ComposeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec2Decompose",
-            category: "Vector",
-            description: "Split Vec2 into X, Y components",
-        },
+        RegistryEntry::new("Vec2Decompose", "Vector", "Split Vec2 into X, Y components"),
         || capture_meta(Vec2DecomposeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec2Add",
-            category: "Vector",
-            description: "Add two Vec2 vectors",
-        },
+        RegistryEntry::new("Vec2Add", "Vector", "Add two Vec2 vectors"),
         || capture_meta(Vec2AddOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec2Scale",
-            category: "Vector",
-            description: "Scale Vec2 by scalar",
-        },
+        RegistryEntry::new("Vec2Scale", "Vector", "Scale Vec2 by scalar"),
         || capture_meta(Vec2ScaleOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec2Length",
-            category: "Vector",
-            description: "Get length of Vec2",
-        },
+        RegistryEntry::new("Vec2Length", "Vector", "Get length of Vec2"),
         || capture_meta(Vec2LengthOp::new()),
     );
 }
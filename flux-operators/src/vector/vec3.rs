@@ -63,6 +63,7 @@ impl Operator for Vec3DecomposeOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn outputs_coupled(&self) -> bool { false }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let v = get_vec3(&self.inputs[0], get_input);
@@ -621,92 +622,47 @@ impl OperatorMeta for Vec3DistanceOp {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Decompose",
-            category: "Vector",
-            description: "Split Vec3 into X, Y, Z components",
-        },
+        RegistryEntry::new("Vec3Decompose", "Vector", "Split Vec3 into X, Y, Z components"),
         || capture_meta(Vec3DecomposeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Add",
-            category: "Vector",
-            description: "Add two Vec3 vectors",
-        },
+        RegistryEntry::new("Vec3Add", "Vector", "Add two Vec3 vectors"),
         || capture_meta(Vec3AddOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Subtract",
-            category: "Vector",
-            description: "Subtract Vec3 B from A",
-        },
+        RegistryEntry::new("Vec3Subtract", "Vector", "Subtract Vec3 B from A"),
         || capture_meta(Vec3SubtractOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Scale",
-            category: "Vector",
-            description: "Scale Vec3 by scalar",
-        },
+        RegistryEntry::new("Vec3Scale", "Vector", "Scale Vec3 by scalar"),
         || capture_meta(Vec3ScaleOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Normalize",
-            category: "Vector",
-            description: "Normalize Vec3 to unit length",
-        },
+        RegistryEntry::new("Vec3Normalize", "Vector", "Normalize Vec3 to unit length"),
         || capture_meta(Vec3NormalizeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Dot",
-            category: "Vector",
-            description: "Dot product of two Vec3",
-        },
+        RegistryEntry::new("Vec3Dot", "Vector", "Dot product of two Vec3"),
         || capture_meta(Vec3DotOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Cross",
-            category: "Vector",
-            description: "Cross product of two Vec3",
-        },
+        RegistryEntry::new("Vec3Cross", "Vector", "Cross product of two Vec3"),
         || capture_meta(Vec3CrossOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Length",
-            category: "Vector",
-            description: "Get length of Vec3",
-        },
+        RegistryEntry::new("Vec3Length", "Vector", "Get length of Vec3"),
         || capture_meta(Vec3LengthOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3Distance",
-            category: "Vector",
-            description: "Distance between two Vec3 points",
-        },
+        RegistryEntry::new("Vec3Distance", "Vector", "Distance between two Vec3 points"),
         || capture_meta(Vec3DistanceOp::new()),
     );
 }
@@ -141,6 +141,7 @@ impl Operator for Vec4DecomposeOp {
     fn inputs_mut(&mut self) -> &mut [InputPort] { &mut self.inputs }
     fn outputs(&self) -> &[OutputPort] { &self.outputs }
     fn outputs_mut(&mut self) -> &mut [OutputPort] { &mut self.outputs }
+    fn outputs_coupled(&self) -> bool { false }
 
     fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
         let v = get_vec4(&self.inputs[0], get_input);
@@ -243,32 +244,17 @@ impl OperatorMeta for Vec3ToVec4Op {
 
 pub fn register(registry: &OperatorRegistry) {
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec4Compose",
-            category: "Vector",
-            description: "Create Vec4 from X, Y, Z, W components",
-        },
+        RegistryEntry::new("Vec4Compose", "Vector", "Create Vec4 from X, Y, Z, W components"),
         || capture_meta(Vec4ComposeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec4Decompose",
-            category: "Vector",
-            description: "Split Vec4 into X, Y, Z, W components",
-        },
+        RegistryEntry::new("Vec4Decompose", "Vector", "Split Vec4 into X, Y, Z, W components"),
         || capture_meta(Vec4DecomposeOp::new()),
     );
 
     registry.register(
-        RegistryEntry {
-            type_id: Id::new(),
-            name: "Vec3ToVec4",
-            category: "Vector",
-            description: "Extend Vec3 to Vec4 with W component",
-        },
+        RegistryEntry::new("Vec3ToVec4", "Vector", "Extend Vec3 to Vec4 with W component"),
         || capture_meta(Vec3ToVec4Op::new()),
     );
 }
@@ -0,0 +1,68 @@
+//! Registry contents under the enabled category features
+//!
+//! Runs against whatever feature set the crate is built with. Run it with no
+//! features, each feature alone, a few combinations and all features, e.g.:
+//!
+//! ```text
+//! cargo test -p flux-operators
+//! cargo test -p flux-operators --no-default-features
+//! cargo test -p flux-operators --no-default-features --features math,flow
+//! cargo test -p flux-operators --no-default-features --features string,list,util
+//! ```
+
+use flux_operators::{create_default_registry, register_all_operators, OperatorRegistry};
+
+/// Categories registered by each feature
+const FEATURE_CATEGORIES: &[(bool, &[&str])] = &[
+    (cfg!(feature = "math"), &["Math", "Math/Arithmetic", "Math/Interpolation", "Math/Random", "Math/Trig"]),
+    (cfg!(feature = "logic"), &["Logic"]),
+    (cfg!(feature = "vector"), &["Vector"]),
+    (cfg!(feature = "color"), &["Color"]),
+    (cfg!(feature = "time"), &["Time", "Oscillators"]),
+    (cfg!(feature = "flow"), &["Flow"]),
+    (cfg!(feature = "string"), &["String"]),
+    (cfg!(feature = "list"), &["List"]),
+    (cfg!(feature = "map"), &["Map"]),
+    (cfg!(feature = "util"), &["Utility"]),
+];
+
+fn expected_categories(extra: &[&'static str]) -> Vec<&'static str> {
+    let mut categories: Vec<&'static str> = FEATURE_CATEGORIES
+        .iter()
+        .filter(|(enabled, _)| *enabled)
+        .flat_map(|(_, categories)| categories.iter().copied())
+        .chain(extra.iter().copied())
+        .collect();
+    categories.sort();
+    categories.dedup();
+    categories
+}
+
+#[test]
+fn register_all_contains_exactly_enabled_categories() {
+    let registry = OperatorRegistry::new();
+    register_all_operators(&registry);
+    assert_eq!(registry.categories(), expected_categories(&[]));
+}
+
+#[test]
+fn default_registry_adds_builtins() {
+    // Constant, SineWave, Compare and Scope are always available
+    let registry = create_default_registry();
+    assert_eq!(
+        registry.categories(),
+        expected_categories(&["Sources", "Oscillators", "Logic", "Output"])
+    );
+    for name in ["Constant", "SineWave", "Compare", "Scope"] {
+        assert!(registry.create_by_name(name).is_some(), "{name} missing");
+    }
+}
+
+#[test]
+#[cfg(all(feature = "math", feature = "flow", not(feature = "string")))]
+fn minimal_build_excludes_other_categories() {
+    let registry = create_default_registry();
+    assert!(registry.create_by_name("Add").is_some());
+    assert!(registry.create_by_name("StringTemplate").is_none());
+    assert!(registry.create_by_name("ListSum").is_none());
+}
@@ -0,0 +1,311 @@
+//! Graph assertions
+//!
+//! Functions that panic with a description of what went wrong, and macros
+//! wrapping them for the common call shapes:
+//!
+//! - [`assert_evaluates_to!`](crate::assert_evaluates_to) - an output has a
+//!   value, floats within a tolerance
+//! - [`assert_cache_hit!`](crate::assert_cache_hit) /
+//!   [`assert_cache_miss!`](crate::assert_cache_miss) - a node was served
+//!   from the cache, or computed, by an evaluation
+//! - [`assert_no_events_of_kind!`](crate::assert_no_events_of_kind) - no
+//!   pending graph event matches a pattern
+//!
+//! Cache assertions evaluate through [`Graph::trace_evaluate`], which runs
+//! the same code as [`Graph::evaluate`] and reports why each node was or
+//! wasn't computed.
+
+use flux_core::{EvalContext, Id, Value};
+use flux_graph::Graph;
+
+use crate::compare::first_difference;
+
+/// True if two values are equal, with floats compared within `epsilon`.
+///
+/// Applies to every float in the value: vector components, colors, list
+/// elements. Values of different types are never equal.
+pub fn values_approx_eq(expected: &Value, actual: &Value, epsilon: f32) -> bool {
+    value_difference(expected, actual, epsilon).is_none()
+}
+
+/// Where `actual` differs from `expected`, if it does.
+fn value_difference(expected: &Value, actual: &Value, epsilon: f32) -> Option<String> {
+    let expected = serde_json::to_value(expected).expect("values serialize");
+    let actual = serde_json::to_value(actual).expect("values serialize");
+    first_difference(&expected, &actual, epsilon as f64, "value")
+}
+
+/// Mutable access to the graph a macro was given, whether it is a `Graph`
+/// or a `&mut Graph` binding.
+#[doc(hidden)]
+pub trait AsGraphMut {
+    fn as_graph_mut(&mut self) -> &mut Graph;
+}
+
+impl AsGraphMut for Graph {
+    fn as_graph_mut(&mut self) -> &mut Graph {
+        self
+    }
+}
+
+/// Name of a node for assertion messages.
+fn describe(graph: &Graph, node: Id) -> String {
+    format!("{} ({})", graph.node_name(node).unwrap_or("?"), node)
+}
+
+/// Assert that evaluating `node`'s output gives `expected`, floats within
+/// `epsilon`.
+///
+/// # Panics
+///
+/// Panics if evaluation fails or the value differs.
+#[track_caller]
+pub fn assert_evaluates_to(
+    graph: &mut Graph,
+    node: Id,
+    output: usize,
+    ctx: &EvalContext,
+    expected: &Value,
+    epsilon: f32,
+) {
+    let actual = match graph.evaluate(node, output, ctx) {
+        Ok(value) => value,
+        Err(e) => panic!("evaluating {} output {} failed: {}", describe(graph, node), output, e),
+    };
+    if let Some(difference) = value_difference(expected, &actual, epsilon) {
+        panic!(
+            "{} output {} evaluated to {:?}, expected {:?} (within {})\n  {}",
+            describe(graph, node),
+            output,
+            actual,
+            expected,
+            epsilon,
+            difference
+        );
+    }
+}
+
+/// Evaluate `output_node`'s output and assert whether `node` was computed.
+#[track_caller]
+fn assert_computed(
+    graph: &mut Graph,
+    node: Id,
+    output_node: Id,
+    output: usize,
+    ctx: &EvalContext,
+    computed: bool,
+) {
+    let (result, trace) = graph.trace_evaluate(output_node, output, ctx);
+    if let Err(e) = result {
+        panic!("evaluating {} output {} failed: {}", describe(graph, output_node), output, e);
+    }
+    let Some(entry) = trace.node(node) else {
+        panic!(
+            "{} was not visited evaluating {} output {}",
+            describe(graph, node),
+            describe(graph, output_node),
+            output
+        );
+    };
+    if entry.computed != computed {
+        panic!(
+            "expected {} to be {}, but it was {} ({})",
+            describe(graph, node),
+            if computed { "computed" } else { "served from the cache" },
+            if entry.computed { "computed" } else { "served from the cache" },
+            entry.reason.label()
+        );
+    }
+}
+
+/// Assert that evaluating `output_node`'s output serves `node` from the
+/// cache.
+///
+/// # Panics
+///
+/// Panics if evaluation fails, `node` isn't visited, or it is computed.
+#[track_caller]
+pub fn assert_cache_hit(
+    graph: &mut Graph,
+    node: Id,
+    output_node: Id,
+    output: usize,
+    ctx: &EvalContext,
+) {
+    assert_computed(graph, node, output_node, output, ctx, false);
+}
+
+/// Assert that evaluating `output_node`'s output computes `node`.
+///
+/// # Panics
+///
+/// Panics if evaluation fails, `node` isn't visited, or it is served from
+/// the cache.
+#[track_caller]
+pub fn assert_cache_miss(
+    graph: &mut Graph,
+    node: Id,
+    output_node: Id,
+    output: usize,
+    ctx: &EvalContext,
+) {
+    assert_computed(graph, node, output_node, output, ctx, true);
+}
+
+/// `&mut Graph` from a `Graph` or `&mut Graph` binding, reborrowing
+/// through method-call syntax so neither needs to be declared `mut`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __graph_mut {
+    ($graph:expr) => {{
+        use $crate::assert::AsGraphMut as _;
+        $graph.as_graph_mut()
+    }};
+}
+
+/// Assert that evaluating an output gives a value.
+///
+/// `assert_evaluates_to!(graph, node, output, ctx, expected)` compares
+/// exactly; add an epsilon as a sixth argument to compare floats within it.
+///
+/// ```ignore
+/// assert_evaluates_to!(graph, add, 0, ctx, Value::Float(3.0), 1e-6);
+/// ```
+#[macro_export]
+macro_rules! assert_evaluates_to {
+    ($graph:expr, $node:expr, $output:expr, $ctx:expr, $expected:expr $(,)?) => {
+        $crate::assert_evaluates_to!($graph, $node, $output, $ctx, $expected, 0.0)
+    };
+    ($graph:expr, $node:expr, $output:expr, $ctx:expr, $expected:expr, $epsilon:expr $(,)?) => {
+        $crate::assert::assert_evaluates_to(
+            $crate::__graph_mut!($graph),
+            $node,
+            $output,
+            &$ctx,
+            &$expected,
+            $epsilon,
+        )
+    };
+}
+
+/// Assert that an evaluation serves a node from the cache.
+///
+/// `assert_cache_hit!(graph, node, ctx)` evaluates the node's first output;
+/// `assert_cache_hit!(graph, node, ctx, output_node, output)` evaluates
+/// another output and checks `node` on the way.
+#[macro_export]
+macro_rules! assert_cache_hit {
+    ($graph:expr, $node:expr, $ctx:expr $(,)?) => {
+        $crate::assert_cache_hit!($graph, $node, $ctx, $node, 0)
+    };
+    ($graph:expr, $node:expr, $ctx:expr, $output_node:expr, $output:expr $(,)?) => {
+        $crate::assert::assert_cache_hit($crate::__graph_mut!($graph), $node, $output_node, $output, &$ctx)
+    };
+}
+
+/// Assert that an evaluation computes a node. Takes the same arguments as
+/// [`assert_cache_hit!`](crate::assert_cache_hit).
+#[macro_export]
+macro_rules! assert_cache_miss {
+    ($graph:expr, $node:expr, $ctx:expr $(,)?) => {
+        $crate::assert_cache_miss!($graph, $node, $ctx, $node, 0)
+    };
+    ($graph:expr, $node:expr, $ctx:expr, $output_node:expr, $output:expr $(,)?) => {
+        $crate::assert::assert_cache_miss($crate::__graph_mut!($graph), $node, $output_node, $output, &$ctx)
+    };
+}
+
+/// Drain a graph's pending events and assert none matches a pattern.
+///
+/// Evaluates to the drained events, for further checks.
+///
+/// ```ignore
+/// assert_no_events_of_kind!(graph, GraphEvent::NodePanicked { .. });
+/// assert_no_events_of_kind!(graph, GraphEvent::NodeRemoved { id, .. } if *id == kept);
+/// ```
+#[macro_export]
+macro_rules! assert_no_events_of_kind {
+    ($graph:expr, $pattern:pat $(if $guard:expr)? $(,)?) => {{
+        let events: ::std::vec::Vec<_> = $graph.drain_events().collect();
+        if let Some(event) = events
+            .iter()
+            .find(|event| matches!(event, $pattern $(if $guard)?))
+        {
+            panic!(
+                "unexpected event matching `{}`: {:?}",
+                stringify!($pattern $(if $guard)?),
+                event
+            );
+        }
+        events
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ops::CountingOp;
+    use flux_graph::GraphEvent;
+
+    fn counting_graph() -> (Graph, Id) {
+        let mut graph = Graph::new();
+        let node = graph.add(CountingOp::new());
+        (graph, node)
+    }
+
+    #[test]
+    fn test_values_approx_eq() {
+        let a = Value::Vec3([1.0, 2.0, 3.0]);
+        assert!(values_approx_eq(&a, &Value::Vec3([1.0, 2.0005, 3.0]), 1e-3));
+        assert!(!values_approx_eq(&a, &Value::Vec3([1.0, 2.1, 3.0]), 1e-3));
+        assert!(!values_approx_eq(&Value::Float(1.0), &Value::Int(1), 1.0));
+    }
+
+    #[test]
+    fn test_assert_evaluates_to() {
+        let (mut graph, node) = counting_graph();
+        let ctx = EvalContext::new();
+        assert_evaluates_to!(graph, node, 0, ctx, Value::Float(1.0));
+        graph.set_input_default(node, 0, Value::Float(0.1 + 0.2));
+        assert_evaluates_to!(graph, node, 0, ctx, Value::Float(0.3), 1e-6);
+    }
+
+    #[test]
+    #[should_panic(expected = "evaluated to Float(1.0), expected Float(2.0)")]
+    fn test_assert_evaluates_to_reports_mismatch() {
+        let (mut graph, node) = counting_graph();
+        assert_evaluates_to!(graph, node, 0, EvalContext::new(), Value::Float(2.0));
+    }
+
+    #[test]
+    fn test_cache_assertions() {
+        let (mut graph, node) = counting_graph();
+        let ctx = EvalContext::new();
+        assert_cache_miss!(graph, node, ctx);
+        assert_cache_hit!(graph, node, ctx);
+        graph.set_input_default(node, 0, Value::Float(2.0));
+        assert_cache_miss!(graph, node, ctx);
+    }
+
+    #[test]
+    #[should_panic(expected = "to be served from the cache, but it was computed (never computed)")]
+    fn test_assert_cache_hit_reports_compute() {
+        let (mut graph, node) = counting_graph();
+        assert_cache_hit!(graph, node, EvalContext::new());
+    }
+
+    #[test]
+    fn test_assert_no_events_of_kind() {
+        let (mut graph, node) = counting_graph();
+        let events = assert_no_events_of_kind!(graph, GraphEvent::NodeRemoved { .. });
+        assert!(matches!(events[..], [GraphEvent::NodeAdded { id }] if id == node));
+        assert!(!graph.has_pending_events());
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected event matching `GraphEvent::NodeAdded { id } if *id == node`")]
+    fn test_assert_no_events_of_kind_reports_match() {
+        let (mut graph, node) = counting_graph();
+        assert_no_events_of_kind!(graph, GraphEvent::NodeAdded { id } if *id == node);
+    }
+}
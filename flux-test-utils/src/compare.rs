@@ -0,0 +1,77 @@
+//! Tolerant comparison of serialized values
+//!
+//! Values are compared through their JSON form, so every [`Value`] variant
+//! (vectors, colors, lists, matrices) gets the same float tolerance without
+//! a match over each one.
+//!
+//! [`Value`]: flux_core::Value
+
+use serde_json::Value as Json;
+
+/// Describe the first place `actual` differs from `expected`, or `None` if
+/// they match with numbers within `epsilon`.
+///
+/// `path` names the compared value in the description.
+pub(crate) fn first_difference(
+    expected: &Json,
+    actual: &Json,
+    epsilon: f64,
+    path: &str,
+) -> Option<String> {
+    match (expected, actual) {
+        (Json::Number(e), Json::Number(a)) => {
+            let (e, a) = (e.as_f64()?, a.as_f64()?);
+            ((e - a).abs() > epsilon).then(|| format!("{}: expected {}, got {}", path, e, a))
+        }
+        (Json::Array(e), Json::Array(a)) => {
+            if e.len() != a.len() {
+                return Some(format!(
+                    "{}: expected {} elements, got {}",
+                    path,
+                    e.len(),
+                    a.len()
+                ));
+            }
+            e.iter().zip(a).enumerate().find_map(|(i, (e, a))| {
+                first_difference(e, a, epsilon, &format!("{}[{}]", path, i))
+            })
+        }
+        (Json::Object(e), Json::Object(a)) => {
+            if let Some(key) = e.keys().find(|key| !a.contains_key(*key)) {
+                return Some(format!("{}: missing \"{}\"", path, key));
+            }
+            if let Some(key) = a.keys().find(|key| !e.contains_key(*key)) {
+                return Some(format!("{}: unexpected \"{}\"", path, key));
+            }
+            e.iter().find_map(|(key, e)| {
+                first_difference(e, &a[key], epsilon, &format!("{}.{}", path, key))
+            })
+        }
+        _ => (expected != actual).then(|| format!("{}: expected {}, got {}", path, expected, actual)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_first_difference() {
+        let expected = json!({ "out": [1.0, 2.0], "name": "a" });
+        let close = json!({ "out": [1.0005, 2.0], "name": "a" });
+        assert_eq!(first_difference(&expected, &close, 1e-3, "root"), None);
+
+        let far = json!({ "out": [1.0, 2.5], "name": "a" });
+        assert_eq!(
+            first_difference(&expected, &far, 1e-3, "root").unwrap(),
+            "root.out[1]: expected 2, got 2.5"
+        );
+        let short = json!({ "out": [1.0], "name": "a" });
+        assert!(first_difference(&expected, &short, 1e-3, "root")
+            .unwrap()
+            .contains("expected 2 elements, got 1"));
+        let renamed = json!({ "out": [1.0, 2.0], "name": "b" });
+        assert!(first_difference(&expected, &renamed, 1e-3, "root").is_some());
+    }
+}
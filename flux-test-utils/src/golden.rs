@@ -0,0 +1,170 @@
+//! Golden snapshots of output time series
+//!
+//! A [`GoldenRecorder`] collects named series of `(frame, value)` samples
+//! and compares them against a JSON snapshot committed next to the test,
+//! with floats compared within a tolerance. Set `FLUX_BLESS=1` to write the
+//! snapshot instead, after a deliberate change:
+//!
+//! ```text
+//! FLUX_BLESS=1 cargo test
+//! ```
+//!
+//! ```ignore
+//! let mut golden = GoldenRecorder::new();
+//! run_frames(&mut graph, 60, 60.0, |graph, frame, ctx| {
+//!     golden.record("out", frame, graph.evaluate(out, 0, ctx).unwrap());
+//! });
+//! golden.assert_matches("tests/golden/ramp.json", 1e-5);
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use flux_core::Value;
+use serde::{Deserialize, Serialize};
+
+use crate::compare::first_difference;
+use crate::ops::Recording;
+
+/// Environment variable that makes [`GoldenRecorder::assert_matches`] write
+/// snapshots instead of comparing them.
+pub const BLESS_ENV: &str = "FLUX_BLESS";
+
+/// One sample of a series.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GoldenSample {
+    pub frame: u64,
+    pub value: Value,
+}
+
+/// Named time series of values, saved and compared as JSON.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct GoldenRecorder {
+    /// Samples by series name, in recording order
+    series: BTreeMap<String, Vec<GoldenSample>>,
+}
+
+impl GoldenRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a sample to a series, creating the series if needed.
+    pub fn record(&mut self, series: &str, frame: u64, value: Value) {
+        self.series
+            .entry(series.to_string())
+            .or_default()
+            .push(GoldenSample { frame, value });
+    }
+
+    /// Append everything a [`RecordingSinkOp`](crate::RecordingSinkOp)
+    /// received to a series.
+    pub fn record_all(&mut self, series: &str, recording: &Recording) {
+        for (frame, value) in recording.entries() {
+            self.record(series, frame, value);
+        }
+    }
+
+    /// The samples of a series.
+    pub fn series(&self, name: &str) -> &[GoldenSample] {
+        self.series.get(name).map_or(&[], Vec::as_slice)
+    }
+
+    /// The snapshot as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("golden series serialize")
+    }
+
+    /// Compare against a JSON snapshot, floats within `epsilon`.
+    ///
+    /// # Errors
+    ///
+    /// Describes the first difference, or why the snapshot can't be read.
+    pub fn compare(&self, snapshot: &str, epsilon: f32) -> Result<(), String> {
+        let expected: serde_json::Value =
+            serde_json::from_str(snapshot).map_err(|e| format!("invalid snapshot: {}", e))?;
+        let actual = serde_json::to_value(self).expect("golden series serialize");
+        match first_difference(&expected, &actual, epsilon as f64, "snapshot") {
+            Some(difference) => Err(difference),
+            None => Ok(()),
+        }
+    }
+
+    /// Write the snapshot to `path`, creating parent directories.
+    pub fn write(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, self.to_json() + "\n")
+    }
+
+    /// Assert the series match the snapshot at `path`, floats within
+    /// `epsilon`; with [`BLESS_ENV`] set, write the snapshot instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshot is missing, unreadable or differs.
+    #[track_caller]
+    pub fn assert_matches(&self, path: impl AsRef<Path>, epsilon: f32) {
+        let path = path.as_ref();
+        if std::env::var_os(BLESS_ENV).is_some() {
+            self.write(path)
+                .unwrap_or_else(|e| panic!("cannot write {}: {}", path.display(), e));
+            return;
+        }
+        let snapshot = std::fs::read_to_string(path).unwrap_or_else(|e| {
+            panic!(
+                "cannot read golden snapshot {}: {} (run with {}=1 to create it)",
+                path.display(),
+                e,
+                BLESS_ENV
+            )
+        });
+        if let Err(difference) = self.compare(&snapshot, epsilon) {
+            panic!(
+                "{} differs from the recorded series: {} (run with {}=1 to update it)",
+                path.display(),
+                difference,
+                BLESS_ENV
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ramp(step: f32) -> GoldenRecorder {
+        let mut golden = GoldenRecorder::new();
+        for frame in 0..4 {
+            golden.record("ramp", frame, Value::Float(frame as f32 * step));
+        }
+        golden.record("label", 0, Value::String("a".into()));
+        golden
+    }
+
+    #[test]
+    fn test_compare_within_epsilon() {
+        let snapshot = ramp(0.5).to_json();
+        assert_eq!(ramp(0.5).compare(&snapshot, 0.0), Ok(()));
+        assert_eq!(ramp(0.5001).compare(&snapshot, 1e-3), Ok(()));
+
+        let difference = ramp(0.6).compare(&snapshot, 1e-3).unwrap_err();
+        assert!(difference.starts_with("snapshot.series.ramp[1].value.Float"), "{}", difference);
+    }
+
+    #[test]
+    fn test_write_and_assert_matches() {
+        let path = std::env::temp_dir()
+            .join(format!("flux-test-utils-{}", std::process::id()))
+            .join("ramp.json");
+        ramp(0.5).write(&path).unwrap();
+        ramp(0.5).assert_matches(&path, 0.0);
+        let loaded: GoldenRecorder =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(loaded.series("ramp").len(), 4);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
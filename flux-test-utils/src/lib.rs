@@ -0,0 +1,38 @@
+//! Flux Test Utils - Helpers for testing Flux graphs and operators
+//!
+//! For this workspace's tests and for operator packs built on Flux. Add it
+//! as a dev-dependency only; nothing in the core crates depends on it.
+//!
+//! # Modules
+//!
+//! - [`ops`] - Configurable fake operators: scripted source, recording sink,
+//!   counting, panicking and slow operators
+//! - [`assert`] - Value, cache and event assertions, with macros
+//! - [`harness`] - Stepping a graph through frames like a host loop
+//! - [`golden`] - JSON snapshots of output time series
+//!
+//! # Example
+//!
+//! ```ignore
+//! use flux_test_utils::{assert_cache_hit, assert_evaluates_to, run_frames, CountingOp};
+//!
+//! let mut graph = Graph::new();
+//! let node = graph.add(CountingOp::new());
+//! let ctx = EvalContext::new();
+//! assert_evaluates_to!(graph, node, 0, ctx, Value::Float(1.0));
+//! assert_cache_hit!(graph, node, ctx);
+//! ```
+
+mod compare;
+
+pub mod assert;
+pub mod golden;
+pub mod harness;
+pub mod ops;
+
+pub use assert::values_approx_eq;
+pub use golden::{GoldenRecorder, GoldenSample, BLESS_ENV};
+pub use harness::{run_frames, run_frames_from};
+pub use ops::{
+    ComputeCounter, CountingOp, PanickingOp, Recording, RecordingSinkOp, ScriptedSourceOp, SlowOp,
+};
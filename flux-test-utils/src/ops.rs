@@ -0,0 +1,477 @@
+//! Configurable fake operators
+//!
+//! Each fake covers one behavior tests keep needing. Counters and recordings
+//! are shared handles, so a test can read them after the operator has moved
+//! into a graph without downcasting:
+//!
+//! ```ignore
+//! let sink = RecordingSinkOp::float();
+//! let recording = sink.recording();
+//! let sink = graph.add(sink);
+//! graph.connect(source, 0, sink, 0)?;
+//! graph.evaluate(sink, 0, &ctx)?;
+//! assert_eq!(recording.values(), [Value::Float(1.0)]);
+//! ```
+
+use std::any::Any;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use flux_core::operator::InputResolver;
+use flux_core::{EvalContext, Id, InputPort, Operator, OutputPort, Value, ValueType};
+
+/// Value of an input: its connection's if connected, its default otherwise.
+fn input_value(input: &InputPort, get_input: InputResolver) -> Value {
+    match input.connection {
+        Some((node_id, output_idx)) => get_input(node_id, output_idx),
+        None => input.default.clone(),
+    }
+}
+
+/// Implements the accessor half of [`Operator`] for fakes with `id`,
+/// `inputs` and `outputs` fields.
+macro_rules! fake_operator_accessors {
+    ($name:literal) => {
+        fn as_any(&self) -> &dyn Any {
+            self
+        }
+        fn as_any_mut(&mut self) -> &mut dyn Any {
+            self
+        }
+        fn id(&self) -> Id {
+            self.id
+        }
+        fn name(&self) -> &'static str {
+            $name
+        }
+        fn inputs(&self) -> &[InputPort] {
+            &self.inputs
+        }
+        fn inputs_mut(&mut self) -> &mut [InputPort] {
+            &mut self.inputs
+        }
+        fn outputs(&self) -> &[OutputPort] {
+            &self.outputs
+        }
+        fn outputs_mut(&mut self) -> &mut [OutputPort] {
+            &mut self.outputs
+        }
+    };
+}
+
+// ============================================================================
+// Shared handles
+// ============================================================================
+
+/// Number of times an operator's compute ran, readable after the operator
+/// moved into a graph.
+#[derive(Clone, Debug, Default)]
+pub struct ComputeCounter(Arc<AtomicUsize>);
+
+impl ComputeCounter {
+    /// Computes so far.
+    pub fn get(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Start counting from zero again.
+    pub fn reset(&self) {
+        self.0.store(0, Ordering::SeqCst);
+    }
+
+    fn increment(&self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Values a [`RecordingSinkOp`] received, with the frame of each.
+#[derive(Clone, Debug, Default)]
+pub struct Recording(Arc<Mutex<Vec<(u64, Value)>>>);
+
+impl Recording {
+    /// Every `(frame, value)` received, oldest first.
+    pub fn entries(&self) -> Vec<(u64, Value)> {
+        self.lock().clone()
+    }
+
+    /// The received values, oldest first.
+    pub fn values(&self) -> Vec<Value> {
+        self.lock().iter().map(|(_, value)| value.clone()).collect()
+    }
+
+    /// The frames values were received on, oldest first.
+    pub fn frames(&self) -> Vec<u64> {
+        self.lock().iter().map(|(frame, _)| *frame).collect()
+    }
+
+    /// The most recent value, if any.
+    pub fn last(&self) -> Option<Value> {
+        self.lock().last().map(|(_, value)| value.clone())
+    }
+
+    /// Number of values received.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// True if nothing was received yet.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Forget everything received so far.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn push(&self, frame: u64, value: Value) {
+        self.lock().push((frame, value));
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(u64, Value)>> {
+        // A panicking test thread must not hide what was recorded
+        self.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+// ============================================================================
+// ScriptedSourceOp
+// ============================================================================
+
+/// Time-varying source that outputs a scripted value per frame.
+///
+/// Frame `n` of the context outputs `script[n]`; frames past the end of the
+/// script hold its last value.
+pub struct ScriptedSourceOp {
+    id: Id,
+    inputs: [InputPort; 0],
+    outputs: [OutputPort; 1],
+    script: Vec<Value>,
+    counter: ComputeCounter,
+}
+
+impl ScriptedSourceOp {
+    /// Source playing `script`, typed after its first value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `script` is empty.
+    pub fn new(script: impl IntoIterator<Item = Value>) -> Self {
+        let script: Vec<Value> = script.into_iter().collect();
+        let value_type = script
+            .first()
+            .expect("ScriptedSourceOp needs at least one value")
+            .value_type();
+        Self {
+            id: Id::new(),
+            inputs: [],
+            outputs: [OutputPort::new("Out", value_type)],
+            script,
+            counter: ComputeCounter::default(),
+        }
+    }
+
+    /// Float source playing `values`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    pub fn floats(values: impl IntoIterator<Item = f32>) -> Self {
+        Self::new(values.into_iter().map(Value::Float))
+    }
+
+    /// Handle counting this operator's computes.
+    pub fn counter(&self) -> ComputeCounter {
+        self.counter.clone()
+    }
+}
+
+impl Operator for ScriptedSourceOp {
+    fake_operator_accessors!("ScriptedSource");
+
+    fn compute(&mut self, ctx: &EvalContext, _get_input: InputResolver) {
+        self.counter.increment();
+        let index = (ctx.frame as usize).min(self.script.len() - 1);
+        self.outputs[0].set(self.script[index].clone());
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+}
+
+// ============================================================================
+// RecordingSinkOp
+// ============================================================================
+
+/// Sink that records every value it receives with the frame it arrived on.
+///
+/// Time-varying, so each evaluation records once. The value is passed
+/// through to the output, which is what evaluating the sink returns.
+pub struct RecordingSinkOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 1],
+    recording: Recording,
+}
+
+impl RecordingSinkOp {
+    /// Sink with an input of `value_type`.
+    pub fn new(value_type: ValueType) -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::new("In", value_type.default_value())],
+            outputs: [OutputPort::new("Out", value_type)],
+            recording: Recording::default(),
+        }
+    }
+
+    /// Sink with a Float input.
+    pub fn float() -> Self {
+        Self::new(ValueType::Float)
+    }
+
+    /// Handle to the values received.
+    pub fn recording(&self) -> Recording {
+        self.recording.clone()
+    }
+}
+
+impl Operator for RecordingSinkOp {
+    fake_operator_accessors!("RecordingSink");
+
+    fn compute(&mut self, ctx: &EvalContext, get_input: InputResolver) {
+        let value = input_value(&self.inputs[0], get_input);
+        self.recording.push(ctx.frame, value.clone());
+        self.outputs[0].set(value);
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+}
+
+// ============================================================================
+// CountingOp
+// ============================================================================
+
+/// Float pass-through that counts its computes.
+///
+/// Not time-varying, so the count shows how often the graph decided the node
+/// needed recomputing.
+pub struct CountingOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 1],
+    counter: ComputeCounter,
+}
+
+impl CountingOp {
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::float("In", 1.0)],
+            outputs: [OutputPort::float("Out")],
+            counter: ComputeCounter::default(),
+        }
+    }
+
+    /// Handle counting this operator's computes.
+    pub fn counter(&self) -> ComputeCounter {
+        self.counter.clone()
+    }
+}
+
+impl Default for CountingOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for CountingOp {
+    fake_operator_accessors!("Counting");
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        self.counter.increment();
+        let value = input_value(&self.inputs[0], get_input);
+        self.outputs[0].set(value);
+    }
+}
+
+// ============================================================================
+// PanickingOp
+// ============================================================================
+
+/// Time-varying Float source that panics when computed.
+///
+/// Outputs the frame number on frames it doesn't panic on. Pair with
+/// [`PanicPolicy::Catch`](flux_graph::PanicPolicy::Catch) to test
+/// quarantine, or `#[should_panic]` to test propagation.
+pub struct PanickingOp {
+    id: Id,
+    inputs: [InputPort; 0],
+    outputs: [OutputPort; 1],
+    panic_frame: Option<u64>,
+}
+
+impl PanickingOp {
+    /// Panics on every compute.
+    pub fn new() -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [],
+            outputs: [OutputPort::float("Frame")],
+            panic_frame: None,
+        }
+    }
+
+    /// Panics only when computed on `frame`.
+    pub fn on_frame(frame: u64) -> Self {
+        Self {
+            panic_frame: Some(frame),
+            ..Self::new()
+        }
+    }
+}
+
+impl Default for PanickingOp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Operator for PanickingOp {
+    fake_operator_accessors!("Panicking");
+
+    fn compute(&mut self, ctx: &EvalContext, _get_input: InputResolver) {
+        if self.panic_frame.is_none_or(|frame| frame == ctx.frame) {
+            panic!("PanickingOp panicked on frame {}", ctx.frame);
+        }
+        self.outputs[0].set(Value::Float(ctx.frame as f32));
+    }
+
+    fn is_time_varying(&self) -> bool {
+        true
+    }
+}
+
+// ============================================================================
+// SlowOp
+// ============================================================================
+
+/// Float pass-through that sleeps on every compute.
+///
+/// For budgeted evaluation and timing tests.
+pub struct SlowOp {
+    id: Id,
+    inputs: [InputPort; 1],
+    outputs: [OutputPort; 1],
+    delay: Duration,
+    time_varying: bool,
+    counter: ComputeCounter,
+}
+
+impl SlowOp {
+    /// Sleeps for `delay` per compute.
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            id: Id::new(),
+            inputs: [InputPort::float("In", 0.0)],
+            outputs: [OutputPort::float("Out")],
+            delay,
+            time_varying: false,
+            counter: ComputeCounter::default(),
+        }
+    }
+
+    /// Recompute on every evaluation.
+    pub fn time_varying(mut self) -> Self {
+        self.time_varying = true;
+        self
+    }
+
+    /// Handle counting this operator's computes.
+    pub fn counter(&self) -> ComputeCounter {
+        self.counter.clone()
+    }
+}
+
+impl Operator for SlowOp {
+    fake_operator_accessors!("Slow");
+
+    fn compute(&mut self, _ctx: &EvalContext, get_input: InputResolver) {
+        self.counter.increment();
+        std::thread::sleep(self.delay);
+        let value = input_value(&self.inputs[0], get_input);
+        self.outputs[0].set(value);
+    }
+
+    fn is_time_varying(&self) -> bool {
+        self.time_varying
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_graph::{Graph, PanicPolicy};
+
+    #[test]
+    fn test_scripted_source_records_into_sink() {
+        let mut graph = Graph::new();
+        let source = graph.add(ScriptedSourceOp::floats([1.0, 2.0, 3.0]));
+        let sink = RecordingSinkOp::float();
+        let recording = sink.recording();
+        let sink = graph.add(sink);
+        graph.connect(source, 0, sink, 0).unwrap();
+
+        let mut ctx = EvalContext::new();
+        for _ in 0..4 {
+            graph.evaluate(sink, 0, &ctx).unwrap();
+            ctx.advance(0.1);
+        }
+        // The last scripted value holds
+        let floats = [1.0, 2.0, 3.0, 3.0].map(Value::Float);
+        assert_eq!(recording.values(), floats);
+        assert_eq!(recording.frames(), [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_counting_op_counts_computes() {
+        let mut graph = Graph::new();
+        let op = CountingOp::new();
+        let counter = op.counter();
+        let op = graph.add(op);
+        let ctx = EvalContext::new();
+        graph.evaluate(op, 0, &ctx).unwrap();
+        graph.evaluate(op, 0, &ctx).unwrap();
+        assert_eq!(counter.get(), 1);
+
+        graph.set_input_default(op, 0, Value::Float(5.0));
+        assert_eq!(graph.evaluate(op, 0, &ctx).unwrap(), Value::Float(5.0));
+        assert_eq!(counter.get(), 2);
+    }
+
+    #[test]
+    fn test_panicking_op_on_frame() {
+        let mut graph = Graph::new();
+        graph.set_panic_policy(PanicPolicy::Catch);
+        let op = graph.add(PanickingOp::on_frame(1));
+        let mut ctx = EvalContext::new();
+        assert_eq!(graph.evaluate(op, 0, &ctx).unwrap(), Value::Float(0.0));
+        assert!(!graph.is_quarantined(op));
+        ctx.advance(0.1);
+        graph.evaluate(op, 0, &ctx).unwrap();
+        assert!(graph.is_quarantined(op));
+    }
+
+    #[test]
+    fn test_slow_op_sleeps() {
+        let mut op = SlowOp::new(Duration::from_millis(5));
+        let start = std::time::Instant::now();
+        op.compute(&EvalContext::new(), &|_, _| Value::Float(0.0));
+        assert!(start.elapsed() >= Duration::from_millis(5));
+        assert_eq!(op.counter().get(), 1);
+    }
+}
@@ -5,3 +5,10 @@
 //! - `flux-operators` - Operator implementations
 //! - `flux-graph` - Graph execution, symbols, serialization
 //! - `flux-macros` - Procedural macros
+//!
+//! Examples that need more than a single file keep their testable core here:
+//! - [`monitor`] - Headless core of the `flux_monitor` dashboard
+//! - [`tutorial_synth`] - The `tutorial_synth` walkthrough, verified by tests
+
+pub mod monitor;
+pub mod tutorial_synth;
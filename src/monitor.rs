@@ -0,0 +1,592 @@
+//! Headless core of the `flux_monitor` example
+//!
+//! A [`Monitor`] owns a graph, steps it at a fixed rate (one graph frame per
+//! step, see [`flux_graph::frame`]) and turns what the graph reports (events,
+//! watched outputs, stats, operator errors and, when profiling, per-node
+//! timings) into a [`Dashboard`] that the example prints to the terminal.
+//! Text commands edit the graph through the undo stack:
+//!
+//! ```text
+//! set <node> <input> <value>   set an input default (input by name or index)
+//! mute <node>                  mute or unmute every wire into a node
+//! profile                      toggle per-node timings
+//! undo / redo
+//! ```
+//!
+//! The graph has no node-level bypass, so `mute` (alias `bypass`) takes a
+//! node's upstream out of play by muting its incoming wires; the node then
+//! computes from its own defaults.
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::time::Duration;
+
+use flux_core::{Id, Value};
+use flux_graph::serialization::{resolve_port, SymbolFile};
+use flux_graph::{
+    Command, Connection, FrameStepper, Graph, GraphBuilder, GraphEvent, MacroCommand,
+    SetConnectionMutedCommand, SetInputDefaultCommand, UndoRedoStack, WatchId,
+};
+use flux_operators::OperatorRegistry;
+
+/// Events kept for the dashboard
+const MAX_EVENTS: usize = 8;
+
+/// A watched output and the label it is shown under
+struct WatchedOutput {
+    label: String,
+    watch: WatchId,
+    node: Id,
+    output: usize,
+}
+
+/// Steps a graph at a fixed rate and collects what it reports.
+pub struct Monitor {
+    graph: Graph,
+    stepper: FrameStepper,
+    undo: UndoRedoStack,
+    /// Node labels used by commands, in creation order
+    labels: Vec<(String, Id)>,
+    watched: Vec<WatchedOutput>,
+    events: VecDeque<String>,
+    profiling: bool,
+    /// Wall time per node in the last step (profiling only)
+    timings: HashMap<Id, Duration>,
+    /// Operator errors, evaluation errors and non-finite outputs of the last step
+    problems: Vec<String>,
+    /// Nodes computed in the last step
+    computed_nodes: usize,
+}
+
+/// Everything the monitor shows for one frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dashboard {
+    pub frame: u64,
+    pub time: f64,
+    pub node_count: usize,
+    pub connection_count: usize,
+    /// Nodes computed in the last step
+    pub computed_nodes: usize,
+    /// Most recent graph events, oldest first
+    pub events: Vec<String>,
+    /// `(label, value)` of every watched output
+    pub watches: Vec<(String, String)>,
+    /// `(node, time)` of every node that ran, slowest first; empty unless profiling
+    pub timings: Vec<(String, Duration)>,
+    /// Operator errors and non-finite outputs
+    pub problems: Vec<String>,
+}
+
+impl Monitor {
+    /// Monitor a small demo graph: an LFO scaled by a gain, divided by a
+    /// ratio, plus a color swizzle.
+    pub fn demo(registry: &OperatorRegistry, rate: f64) -> Self {
+        let b = GraphBuilder::new(registry);
+        let lfo = b.node_with("SineWave", |n| n.input("Frequency", 0.5));
+        let gain = b.node_with("Multiply", |n| n.input("B", 2.0));
+        let ratio = b.node_with("Divide", |n| n.input("B", 1.0));
+        let tint = b.node_with("Swizzle", |n| {
+            n.input("Vector", Value::Vec4([0.2, 0.4, 0.6, 1.0]))
+                .input("Pattern", "bgr")
+        });
+        b.wire(lfo.out("Value"), gain.input_ref("A"));
+        b.wire(gain.out("Result"), ratio.input_ref("A"));
+        let (graph, handles) = b.build().expect("demo graph is valid");
+
+        let labels = [
+            ("lfo", lfo),
+            ("gain", gain),
+            ("ratio", ratio),
+            ("tint", tint),
+        ]
+        .into_iter()
+        .map(|(label, node)| (label.to_string(), handles.id(node)))
+        .collect();
+        Self::new(graph, labels, rate)
+    }
+
+    /// Monitor the nodes of a saved symbol.
+    ///
+    /// Nodes are labelled by their display name, or else by operator name
+    /// with a numeric suffix on duplicates (`Add`, `Add2`, ...).
+    pub fn from_symbol(file: &SymbolFile, registry: &OperatorRegistry, rate: f64) -> Self {
+        let mut graph = Graph::new();
+        let result = file.symbol.instantiate(&mut graph, registry);
+
+        let mut labels: Vec<(String, Id)> = Vec::new();
+        for child in &file.symbol.children {
+            let Some(&id) = result.id_map.get(&child.id) else {
+                continue;
+            };
+            let name = graph.node_name(id).unwrap_or("Node");
+            let count = labels
+                .iter()
+                .filter(|(_, other)| graph.node_name(*other) == Some(name))
+                .count();
+            let label = match (&child.name, count) {
+                (Some(display), _) => display.clone(),
+                (None, 0) => name.to_string(),
+                (None, n) => format!("{}{}", name, n + 1),
+            };
+            labels.push((label, id));
+        }
+        Self::new(graph, labels, rate)
+    }
+
+    /// Monitor `graph`, watching every output of the labelled nodes that
+    /// nothing else consumes.
+    pub fn new(mut graph: Graph, labels: Vec<(String, Id)>, rate: f64) -> Self {
+        let mut watched = Vec::new();
+        for (label, id) in &labels {
+            if !graph.downstream_of(*id).is_empty() {
+                continue;
+            }
+            let Some(outputs) = graph.get(*id).map(|op| op.outputs().len()) else {
+                continue;
+            };
+            for output in 0..outputs {
+                let name = graph.get(*id).unwrap().outputs()[output].name;
+                if let Ok(watch) = graph.watch_output(*id, output) {
+                    watched.push(WatchedOutput {
+                        label: format!("{}.{}", label, name),
+                        watch,
+                        node: *id,
+                        output,
+                    });
+                }
+            }
+        }
+
+        let mut monitor = Self {
+            graph,
+            stepper: FrameStepper::new(rate),
+            undo: UndoRedoStack::new(),
+            labels,
+            watched,
+            events: VecDeque::new(),
+            profiling: false,
+            timings: HashMap::new(),
+            problems: Vec::new(),
+            computed_nodes: 0,
+        };
+        monitor.collect_events();
+        monitor
+    }
+
+    /// The monitored graph.
+    pub fn graph(&self) -> &Graph {
+        &self.graph
+    }
+
+    /// Turn per-node timings on or off.
+    pub fn set_profiling(&mut self, enabled: bool) {
+        self.profiling = enabled;
+        self.timings.clear();
+    }
+
+    /// Add `wall_dt` seconds of wall time and run the steps that are due.
+    ///
+    /// Returns the number of steps run.
+    pub fn update(&mut self, wall_dt: f64) -> u32 {
+        let steps = self.stepper.advance(wall_dt);
+        for _ in 0..steps {
+            self.step();
+        }
+        self.collect_events();
+        steps
+    }
+
+    /// Evaluate every watched output once, in one frame.
+    fn step(&mut self) {
+        let ctx = self.stepper.begin_step();
+        self.problems.clear();
+        self.timings.clear();
+        self.graph
+            .begin_frame(ctx)
+            .expect("every frame is ended before the next step");
+
+        for watched in &self.watched {
+            let result = if self.profiling {
+                let (result, trace) = self.graph.trace_evaluate(watched.node, watched.output, ctx);
+                for node in trace.nodes.iter().filter(|node| node.computed) {
+                    *self.timings.entry(node.node).or_default() += node.wall_time;
+                }
+                result
+            } else {
+                self.graph.evaluate(watched.node, watched.output, ctx)
+            };
+            match result {
+                Ok(value) if !is_finite(&value) => {
+                    self.problems
+                        .push(format!("{}: non-finite value {}", watched.label, value));
+                }
+                Ok(_) => {}
+                Err(e) => self.problems.push(format!("{}: {}", watched.label, e)),
+            }
+        }
+        self.computed_nodes = self.graph.end_frame().unwrap_or_default();
+
+        // Errors of nodes served from the cache still apply, so report the
+        // latest errors of every node rather than just this frame's
+        for (label, id) in &self.labels {
+            for error in self.graph.node_errors(*id) {
+                self.problems.push(format!("{}: {}", label, error));
+            }
+        }
+    }
+
+    /// Move pending graph events into the event log.
+    fn collect_events(&mut self) {
+        let events: Vec<GraphEvent> = self.graph.drain_events().collect();
+        for event in events {
+            // One per step; the dashboard shows the count instead
+            if matches!(event, GraphEvent::FrameCompleted { .. }) {
+                continue;
+            }
+            let line = self.describe_event(&event);
+            self.events.push_back(line);
+        }
+        while self.events.len() > MAX_EVENTS {
+            self.events.pop_front();
+        }
+    }
+
+    /// Build the dashboard for the current state.
+    pub fn dashboard(&self) -> Dashboard {
+        let stats = self.graph.stats();
+        let ctx = self.stepper.context();
+        let values = self.graph.watch_values();
+
+        let mut timings: Vec<(String, Duration)> = self
+            .timings
+            .iter()
+            .map(|(&id, &time)| (self.label(id), time))
+            .collect();
+        timings.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+
+        Dashboard {
+            frame: ctx.frame,
+            time: ctx.time,
+            node_count: stats.node_count,
+            connection_count: stats.connection_count,
+            computed_nodes: self.computed_nodes,
+            events: self.events.iter().cloned().collect(),
+            watches: self
+                .watched
+                .iter()
+                .map(|w| {
+                    let value = values
+                        .get(&w.watch)
+                        .map_or_else(|| "-".to_string(), Value::to_string);
+                    (w.label.clone(), value)
+                })
+                .collect(),
+            timings,
+            problems: self.problems.clone(),
+        }
+    }
+
+    /// Run a text command and describe what it did.
+    ///
+    /// # Errors
+    ///
+    /// A message for unknown commands, nodes or inputs and unparsable values.
+    pub fn execute(&mut self, line: &str) -> Result<String, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let message = match words[..] {
+            ["set", node, input, ref value @ ..] if !value.is_empty() => {
+                self.set_input(node, input, &value.join(" "))?
+            }
+            ["mute" | "bypass", node] => self.toggle_mute(node)?,
+            ["profile"] => {
+                self.set_profiling(!self.profiling);
+                format!("profiling {}", if self.profiling { "on" } else { "off" })
+            }
+            ["undo"] => match self.undo.undo(&mut self.graph) {
+                true => "undone".to_string(),
+                false => return Err("nothing to undo".to_string()),
+            },
+            ["redo"] => match self.undo.redo(&mut self.graph) {
+                true => "redone".to_string(),
+                false => return Err("nothing to redo".to_string()),
+            },
+            _ => {
+                return Err(
+                    "commands: set <node> <input> <value>, mute <node>, profile, undo, redo"
+                        .to_string(),
+                )
+            }
+        };
+        self.collect_events();
+        Ok(message)
+    }
+
+    fn set_input(&mut self, node: &str, input: &str, text: &str) -> Result<String, String> {
+        let id = self.resolve_node(node)?;
+        let op = self
+            .graph
+            .get(id)
+            .ok_or_else(|| format!("'{}' was removed", node))?;
+        let names: Vec<&str> = op.inputs().iter().map(|port| port.name).collect();
+        let index = resolve_port(&names, input.parse().ok(), Some(input))
+            .index()
+            .ok_or_else(|| {
+                format!(
+                    "{} has no input '{}' (inputs: {})",
+                    node,
+                    input,
+                    names.join(", ")
+                )
+            })?;
+
+        let port = &op.inputs()[index];
+        let value = Value::String(text.to_string())
+            .coerce_to(port.value_type)
+            .ok_or_else(|| format!("cannot read '{}' as {}", text, port.value_type))?;
+        let message = format!("{} = {}", self.port_label(id, index, true), value);
+        self.undo.execute(
+            &mut self.graph,
+            SetInputDefaultCommand::new(id, index, value),
+        );
+        Ok(message)
+    }
+
+    fn toggle_mute(&mut self, node: &str) -> Result<String, String> {
+        let id = self.resolve_node(node)?;
+        let incoming: Vec<Connection> = self
+            .graph
+            .connections()
+            .filter(|c| c.target_node == id)
+            .collect();
+        if incoming.is_empty() {
+            return Err(format!("{} has no incoming connections", node));
+        }
+
+        let mute = incoming.iter().any(|c| !c.muted);
+        let commands = incoming
+            .into_iter()
+            .map(|c| Box::new(SetConnectionMutedCommand::new(c, mute)) as Box<dyn Command>)
+            .collect();
+        let name = if mute { "Mute Inputs" } else { "Unmute Inputs" };
+        self.undo
+            .execute(&mut self.graph, MacroCommand::from_commands(name, commands));
+        Ok(format!(
+            "{} {}",
+            if mute { "muted" } else { "unmuted" },
+            node
+        ))
+    }
+
+    /// Find a node by label, or by operator name if exactly one node has it.
+    fn resolve_node(&self, name: &str) -> Result<Id, String> {
+        if let Some((_, id)) = self.labels.iter().find(|(label, _)| label == name) {
+            return Ok(*id);
+        }
+        let mut by_operator = self.labels.iter().filter(|(_, id)| {
+            self.graph
+                .node_name(*id)
+                .is_some_and(|op| op.eq_ignore_ascii_case(name))
+        });
+        match (by_operator.next(), by_operator.next()) {
+            (Some((_, id)), None) => Ok(*id),
+            (Some(_), Some(_)) => Err(format!("'{}' matches several nodes, use a label", name)),
+            _ => Err(format!("no node named '{}'", name)),
+        }
+    }
+
+    fn label(&self, id: Id) -> String {
+        self.labels
+            .iter()
+            .find(|(_, other)| *other == id)
+            .map(|(label, _)| label.clone())
+            .or_else(|| self.graph.node_name(id).map(str::to_string))
+            .unwrap_or_else(|| "?".to_string())
+    }
+
+    fn port_label(&self, id: Id, index: usize, input: bool) -> String {
+        let name = self.graph.get(id).and_then(|op| match input {
+            true => op.inputs().get(index).map(|p| p.name),
+            false => op.outputs().get(index).map(|p| p.name),
+        });
+        match name {
+            Some(name) => format!("{}.{}", self.label(id), name),
+            None => format!("{}[{}]", self.label(id), index),
+        }
+    }
+
+    fn describe_event(&self, event: &GraphEvent) -> String {
+        match event {
+            GraphEvent::NodeAdded { id } => format!("added {}", self.label(*id)),
+            GraphEvent::NodeRemoved { operator, .. } => format!("removed {}", operator),
+            GraphEvent::Connected {
+                source,
+                source_output,
+                target,
+                target_input,
+            } => format!(
+                "connected {} -> {}",
+                self.port_label(*source, *source_output, false),
+                self.port_label(*target, *target_input, true)
+            ),
+            GraphEvent::Disconnected {
+                source,
+                source_output,
+                target,
+                target_input,
+                ..
+            } => format!(
+                "disconnected {} -> {}",
+                self.port_label(*source, *source_output, false),
+                self.port_label(*target, *target_input, true)
+            ),
+            GraphEvent::ConnectionMuted { connection: c } => format!(
+                "{} {} -> {}",
+                if c.muted { "muted" } else { "unmuted" },
+                self.port_label(c.source_node, c.source_output, false),
+                self.port_label(c.target_node, c.target_input, true)
+            ),
+            GraphEvent::InputDefaultChanged {
+                node, input, value, ..
+            } => {
+                format!("{} = {}", self.port_label(*node, *input, true), value)
+            }
+            other => format!("{:?}", other),
+        }
+    }
+}
+
+/// False if a float or any vector component is NaN or infinite.
+fn is_finite(value: &Value) -> bool {
+    match value {
+        Value::Float(v) => v.is_finite(),
+        Value::Vec2(v) => v.iter().all(|c| c.is_finite()),
+        Value::Vec3(v) => v.iter().all(|c| c.is_finite()),
+        Value::Vec4(v) => v.iter().all(|c| c.is_finite()),
+        Value::FloatList(v) => v.iter().all(|c| c.is_finite()),
+        _ => true,
+    }
+}
+
+impl fmt::Display for Dashboard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "=== Flux Monitor === frame {}  t = {:.3}s",
+            self.frame, self.time
+        )?;
+        writeln!(
+            f,
+            "nodes: {}  connections: {}  computed: {}",
+            self.node_count, self.connection_count, self.computed_nodes
+        )?;
+
+        writeln!(f, "\n-- Watches --")?;
+        for (label, value) in &self.watches {
+            writeln!(f, "  {:<20} {}", label, value)?;
+        }
+
+        if !self.timings.is_empty() {
+            writeln!(f, "\n-- Timings --")?;
+            for (label, time) in &self.timings {
+                writeln!(f, "  {:<20} {:>8.1}us", label, time.as_secs_f64() * 1e6)?;
+            }
+        }
+
+        writeln!(f, "\n-- Problems --")?;
+        if self.problems.is_empty() {
+            writeln!(f, "  none")?;
+        }
+        for problem in &self.problems {
+            writeln!(f, "  {}", problem)?;
+        }
+
+        writeln!(f, "\n-- Events --")?;
+        for event in &self.events {
+            writeln!(f, "  {}", event)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flux_operators::create_default_registry;
+
+    const RATE: f64 = 60.0;
+
+    fn run(monitor: &mut Monitor, iterations: usize) {
+        for _ in 0..iterations {
+            monitor.update(1.0 / RATE);
+        }
+    }
+
+    #[test]
+    fn test_headless_iterations() {
+        let registry = create_default_registry();
+        let mut monitor = Monitor::demo(&registry, RATE);
+        monitor.set_profiling(true);
+        run(&mut monitor, 10);
+
+        let dashboard = monitor.dashboard();
+        assert_eq!(dashboard.frame, 10);
+        assert_eq!(dashboard.node_count, 4);
+        assert_eq!(dashboard.connection_count, 2);
+        // The static swizzle is served from the cache
+        assert_eq!(dashboard.computed_nodes, 3);
+        let labels: Vec<&str> = dashboard.watches.iter().map(|(l, _)| l.as_str()).collect();
+        assert_eq!(labels, ["ratio.Result", "tint.Result"]);
+        assert_eq!(
+            dashboard.watches[1].1,
+            Value::Vec3([0.6, 0.4, 0.2]).to_string()
+        );
+        assert!(dashboard.timings.iter().any(|(label, _)| label == "lfo"));
+        assert!(dashboard.problems.is_empty(), "{:?}", dashboard.problems);
+        assert!(dashboard
+            .events
+            .iter()
+            .any(|e| e == "connected gain.Result -> ratio.A"));
+        assert!(dashboard.to_string().contains("ratio.Result"));
+    }
+
+    #[test]
+    fn test_commands_edit_graph_and_report_problems() {
+        let registry = create_default_registry();
+        let mut monitor = Monitor::demo(&registry, RATE);
+
+        assert_eq!(monitor.execute("set ratio B 0").unwrap(), "ratio.B = 0");
+        assert_eq!(
+            monitor.execute("set Swizzle 1 xq").unwrap(),
+            r#"tint.Pattern = "xq""#
+        );
+        assert!(monitor.execute("set ratio Q 1").is_err());
+        assert!(monitor.execute("set lfo Frequency fast").is_err());
+        run(&mut monitor, 10);
+
+        let dashboard = monitor.dashboard();
+        assert!(dashboard.events.iter().any(|e| e == "ratio.B = 0"));
+        assert!(dashboard
+            .problems
+            .iter()
+            .any(|p| p.starts_with("ratio.Result: non-finite")));
+        assert!(dashboard.problems.iter().any(|p| p.starts_with("tint: ")));
+        assert!(dashboard.timings.is_empty());
+
+        let ratio = monitor.resolve_node("ratio").unwrap();
+        assert_eq!(monitor.execute("mute ratio").unwrap(), "muted ratio");
+        let into_ratio = |c: &Connection| c.target_node == ratio;
+        assert!(monitor
+            .graph()
+            .connections()
+            .filter(into_ratio)
+            .all(|c| c.muted));
+        assert_eq!(monitor.execute("bypass ratio").unwrap(), "unmuted ratio");
+        monitor.execute("undo").unwrap();
+        assert!(monitor
+            .dashboard()
+            .events
+            .last()
+            .unwrap()
+            .starts_with("muted"));
+    }
+}
@@ -0,0 +1,402 @@
+//! Core of the `tutorial_synth` example: a control-rate synth built end to end
+//!
+//! The tutorial builds a small synth entirely through public APIs:
+//!
+//! ```text
+//! Time -> BeatClock -> Envelope -> Multiply -> Add -> SineWave (carrier) -+
+//!                                   (FM depth)  (base)                     +-> Crossfade -> Out
+//!                                                      TriangleWave (pad) -+
+//! ```
+//!
+//! 1. [`TutorialSynth::build`] records the nodes by registry name with a
+//!    [`GraphBuilder`], makes two edits through the undo stack (and takes one
+//!    back), animates the crossfade with an [`Animator`] and sets up a
+//!    [`Transport`] whose tempo drives the BeatClock.
+//! 2. [`TutorialSynth::to_files`] saves the synth as a symbol (the nodes,
+//!    the animation and the named outputs) and a graph file referencing it
+//!    (the playback settings); [`TutorialSynth::from_files`] loads them back.
+//!    The saved form is committed under `examples/fixtures/`.
+//! 3. [`TutorialSynth::render`] plays [`FRAMES`] frames and [`verify`]
+//!    checks a handful of them against values worked out by hand.
+//!
+//! `tests/tutorial_synth.rs` runs the same steps, so the tutorial can't
+//! drift from the library.
+
+use std::fmt;
+
+use flux_core::id::OutputRef;
+use flux_core::{EvalContext, Id, IdGenerator, Value};
+use flux_graph::animation::{Animator, CurveBuilder, LoopMode};
+use flux_graph::graph::GraphError;
+use flux_graph::playback::{PlaybackSettings, Transport, TRANSPORT_NAMESPACE};
+use flux_graph::serialization::{GraphFile, OutputDef, PlaybackDef, SymbolDef, SymbolFile};
+use flux_graph::{BuildError, Graph, GraphBuilder, SetInputDefaultCommand, UndoRedoStack};
+use flux_operators::OperatorRegistry;
+
+/// Frames played by the verification pass
+pub const FRAMES: usize = 120;
+/// Frames per second
+pub const FRAME_RATE: f64 = 60.0;
+/// Tempo of the transport, and so of the BeatClock
+pub const TEMPO: f64 = 120.0;
+/// Carrier frequency in Hz while the envelope is closed
+pub const BASE_FREQUENCY: f32 = 3.0;
+/// Carrier frequency added in Hz at the envelope's peak
+pub const FM_DEPTH: f32 = 2.0;
+/// Envelope decay set through the undo stack, as a fraction of a beat
+pub const PLUCK_DECAY: f32 = 0.25;
+/// Seconds the crossfade takes to move from the carrier to the pad
+pub const FADE_SECONDS: f64 = 1.0;
+/// Length of the playback loop in seconds (two bars at [`TEMPO`])
+pub const LOOP_SECONDS: f64 = 4.0;
+/// Tolerance of the checks made by [`verify`]
+pub const EPSILON: f32 = 1e-3;
+
+/// The committed symbol file of the synth
+pub const FIXTURE_SYMBOL: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/examples/fixtures/tutorial_synth.rsym"
+);
+/// The committed graph file of the synth, referencing [`FIXTURE_SYMBOL`]
+pub const FIXTURE_GRAPH: &str = concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/examples/fixtures/tutorial_synth.rgraph"
+);
+
+/// Outputs of the synth read by [`TutorialSynth::render`]
+///
+/// They are saved as the symbol's outputs, so a loaded synth finds them by
+/// name.
+#[derive(Debug, Clone, Copy)]
+pub struct SynthPorts {
+    /// The crossfade between carrier and pad
+    pub out: OutputRef,
+    /// The envelope, 0 to 1 once per beat
+    pub envelope: OutputRef,
+    /// The carrier frequency in Hz
+    pub frequency: OutputRef,
+    /// The frequency-modulated sine
+    pub carrier: OutputRef,
+    /// The triangle pad
+    pub pad: OutputRef,
+}
+
+impl SynthPorts {
+    /// Names the outputs are saved under
+    const NAMES: [&'static str; 5] = ["Out", "Envelope", "Frequency", "Carrier", "Pad"];
+
+    fn refs(&self) -> [OutputRef; 5] {
+        [
+            self.out,
+            self.envelope,
+            self.frequency,
+            self.carrier,
+            self.pad,
+        ]
+    }
+}
+
+/// The values read in one frame of [`TutorialSynth::render`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FrameValues {
+    /// Transport playhead in seconds
+    pub time: f64,
+    pub out: f32,
+    pub envelope: f32,
+    pub frequency: f32,
+    pub carrier: f32,
+    pub pad: f32,
+}
+
+/// The synth graph with the animation and transport that play it
+pub struct TutorialSynth {
+    pub graph: Graph,
+    pub ports: SynthPorts,
+    pub animator: Animator,
+    pub transport: Transport,
+}
+
+impl TutorialSynth {
+    /// Build the synth from operator and port names.
+    ///
+    /// # Errors
+    ///
+    /// Returns the builder's error if the registry lacks an operator or port.
+    pub fn build(registry: &OperatorRegistry) -> Result<Self, BuildError> {
+        // Nodes by registry name, wired by port name. The BeatClock reads
+        // its tempo from the transport, falling back to TEMPO without one.
+        let b = GraphBuilder::new(registry);
+        let time = b.node("Time");
+        let bpm_var = format!("{}.bpm", TRANSPORT_NAMESPACE);
+        let clock = b.node_with("BeatClock", |n| {
+            n.bind("BPM", bpm_var.as_str(), TEMPO as f32)
+        });
+        let envelope = b.node_with("Envelope", |n| {
+            n.input("Attack", 0.1f32).input("Decay", 0.5f32)
+        });
+        let depth = b.node_with("Multiply", |n| n.input("B", FM_DEPTH));
+        let frequency = b.node_with("Add", |n| n.input("B", BASE_FREQUENCY));
+        let carrier = b.node("SineWave");
+        let pad = b.node_with("TriangleWave", |n| n.input("Frequency", 1.0f32));
+        let mix = b.node("Crossfade");
+        b.wire(time.out("Time"), clock.input_ref("Time"));
+        b.wire(clock.out("Phase"), envelope.input_ref("Phase"));
+        b.wire(envelope.out("Value"), depth.input_ref("A"));
+        b.wire(depth.out("Result"), frequency.input_ref("A"));
+        b.wire(frequency.out("Result"), carrier.input_ref("Frequency"));
+        b.wire(carrier.out("Value"), mix.input_ref("A"));
+        b.wire(pad.out("Value"), mix.input_ref("B"));
+        let (mut graph, handles) = b.build()?;
+
+        let ports = SynthPorts {
+            out: handles.output(&mix.out("Result"))?,
+            envelope: handles.output(&envelope.out("Value"))?,
+            frequency: handles.output(&frequency.out("Result"))?,
+            carrier: handles.output(&carrier.out("Value"))?,
+            pad: handles.output(&pad.out("Value"))?,
+        };
+
+        // Edits go through the undo stack: shorten the pluck, then try a
+        // faster pad and take it back
+        let decay = handles.input(&envelope.input_ref("Decay"))?;
+        let pad_frequency = handles.input(&pad.input_ref("Frequency"))?;
+        let mut undo = UndoRedoStack::new();
+        undo.execute(
+            &mut graph,
+            SetInputDefaultCommand::for_input(decay, Value::Float(PLUCK_DECAY)),
+        );
+        undo.execute(
+            &mut graph,
+            SetInputDefaultCommand::for_input(pad_frequency, Value::Float(4.0)),
+        );
+        undo.undo(&mut graph);
+
+        // Fade from the carrier to the pad, then hold on the pad
+        let mix_amount = handles.input(&mix.input_ref("Mix"))?;
+        let curve = CurveBuilder::named("Mix")
+            .keyframe(0.0, 0.0)
+            .keyframe(FADE_SECONDS, 1.0)
+            .build();
+        let mut animator = Animator::with_range(0.0, FADE_SECONDS);
+        animator.set_loop_mode(LoopMode::Hold);
+        animator.add_curve(curve, mix_amount.node.id(), mix_amount.index.get());
+
+        let mut settings = PlaybackSettings {
+            bpm: TEMPO,
+            ..PlaybackSettings::default()
+        };
+        settings.set_loop_range(0.0, LOOP_SECONDS);
+
+        Ok(Self {
+            graph,
+            ports,
+            animator,
+            transport: Transport::new(settings),
+        })
+    }
+
+    /// Build and save the synth with sequential IDs, so the files are the
+    /// same on every run (this is how the fixtures are made).
+    pub fn fixture_files(
+        registry: &OperatorRegistry,
+    ) -> Result<(SymbolFile, GraphFile), BuildError> {
+        Id::with_generator(IdGenerator::sequential(0), || {
+            Ok(Self::build(registry)?.to_files(registry))
+        })
+    }
+
+    /// Save the synth as a symbol file and a graph file referencing it.
+    pub fn to_files(&self, registry: &OperatorRegistry) -> (SymbolFile, GraphFile) {
+        let mut def = SymbolDef::from_graph("TutorialSynth", &self.graph, registry)
+            .with_description("Beat-clocked FM pluck crossfading into a triangle pad");
+        def.add_animations(&self.animator, &self.graph);
+        for (name, port) in SynthPorts::NAMES.into_iter().zip(self.ports.refs()) {
+            def.add_output(OutputDef::float(name).with_source(port.node.id(), port.index.get()));
+        }
+
+        let mut graph = GraphFile::new("Tutorial Synth", def.id);
+        graph.graph.playback = PlaybackDef::from_settings(self.transport.settings());
+        (SymbolFile::from_def(def), graph)
+    }
+
+    /// Load a synth saved by [`to_files`](Self::to_files).
+    ///
+    /// # Errors
+    ///
+    /// Describes the first problem: a graph file for another symbol, any
+    /// issue in the load report, or a missing named output.
+    pub fn from_files(
+        symbol: &SymbolFile,
+        graph_file: &GraphFile,
+        registry: &OperatorRegistry,
+    ) -> Result<Self, String> {
+        let def = &symbol.symbol;
+        if graph_file.graph.root_symbol != def.id {
+            return Err(format!(
+                "graph file is for symbol {}, not {}",
+                graph_file.graph.root_symbol, def.id
+            ));
+        }
+
+        let mut graph = Graph::new();
+        let result = def.instantiate(&mut graph, registry);
+        if !result.is_clean() {
+            return Err(result.report.to_string());
+        }
+
+        let port = |name: &str| {
+            def.outputs
+                .iter()
+                .find(|output| output.name == name)
+                .and_then(|output| output.source.as_ref())
+                .and_then(|source| {
+                    let node = result.id_map.get(&source.child_id)?;
+                    Some(OutputRef::new(*node, source.slot_index))
+                })
+                .ok_or_else(|| format!("symbol has no output '{}'", name))
+        };
+        let [out, envelope, frequency, carrier, pad] = SynthPorts::NAMES;
+        let ports = SynthPorts {
+            out: port(out)?,
+            envelope: port(envelope)?,
+            frequency: port(frequency)?,
+            carrier: port(carrier)?,
+            pad: port(pad)?,
+        };
+
+        Ok(Self {
+            graph,
+            ports,
+            animator: def.instance_animator(&result),
+            transport: Transport::new(graph_file.graph.playback.to_settings()),
+        })
+    }
+
+    /// Play `frames` frames at [`FRAME_RATE`] from the transport's playhead.
+    ///
+    /// Each frame publishes the transport into the context, applies the
+    /// animation at the playhead and reads every [`SynthPorts`] output in one
+    /// graph frame. The transport is paused afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first evaluation error.
+    pub fn render(&mut self, frames: usize) -> Result<Vec<FrameValues>, GraphError> {
+        let dt = 1.0 / FRAME_RATE;
+        let mut ctx = EvalContext::new();
+        ctx.delta_time = dt;
+        self.transport.play();
+
+        let mut values = Vec::with_capacity(frames);
+        for frame in 0..frames {
+            self.transport.apply_to_context(&mut ctx);
+            ctx.frame = frame as u64;
+            self.animator.set_time(self.transport.playhead());
+            for (target, value) in self.animator.sample_all() {
+                self.graph.set_input_default(
+                    target.node_id,
+                    target.input_index,
+                    Value::Float(value as f32),
+                );
+            }
+
+            self.graph.begin_frame(&ctx)?;
+            let read = self.read_frame(&ctx);
+            self.graph.end_frame()?;
+            values.push(read?);
+            self.transport.tick(dt);
+        }
+
+        self.transport.pause();
+        Ok(values)
+    }
+
+    fn read_frame(&mut self, ctx: &EvalContext) -> Result<FrameValues, GraphError> {
+        let mut read = |port| {
+            let value = self.graph.evaluate_ref(port, ctx)?;
+            Ok::<_, GraphError>(value.as_float().unwrap_or(f32::NAN))
+        };
+        Ok(FrameValues {
+            time: ctx.time,
+            out: read(self.ports.out)?,
+            envelope: read(self.ports.envelope)?,
+            frequency: read(self.ports.frequency)?,
+            carrier: read(self.ports.carrier)?,
+            pad: read(self.ports.pad)?,
+        })
+    }
+}
+
+/// One expected value of a rendered frame
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub frame: usize,
+    pub what: &'static str,
+    pub expected: f32,
+    pub actual: f32,
+}
+
+impl Check {
+    /// Whether the actual value is within [`EPSILON`] of the expected one
+    pub fn passed(&self) -> bool {
+        (self.actual - self.expected).abs() <= EPSILON
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frame {:>3}  {:<34} expected {:>8.4}  got {:>8.4}  {}",
+            self.frame,
+            self.what,
+            self.expected,
+            self.actual,
+            if self.passed() { "ok" } else { "FAIL" }
+        )
+    }
+}
+
+/// Check rendered frames against values worked out by hand
+///
+/// At 120 BPM a beat lasts half a second, so frame `n` is at beat phase
+/// `(n / 30) mod 1`. The envelope peaks at phase 0.1 and closes at
+/// 0.1 + [`PLUCK_DECAY`]; the pad is a 1 Hz triangle. `frames` must hold at
+/// least [`FRAMES`] frames starting at time 0.
+pub fn verify(frames: &[FrameValues]) -> Vec<Check> {
+    let check = |frame: usize, what, expected, actual| Check {
+        frame,
+        what,
+        expected,
+        actual,
+    };
+    let half_fade = frames[30];
+    vec![
+        // Everything starts at rest: sine phase 0, crossfade on the carrier
+        check(0, "out (sine at phase 0)", 0.0, frames[0].out),
+        // Beat phase 0.1: envelope peak, full modulation
+        check(3, "envelope (peak)", 1.0, frames[3].envelope),
+        check(
+            3,
+            "frequency (base + depth)",
+            BASE_FREQUENCY + FM_DEPTH,
+            frames[3].frequency,
+        ),
+        // Beat phase 0.2: 0.1 into the shortened decay
+        check(6, "envelope (decaying)", 0.6, frames[6].envelope),
+        // Beat phase 0.5: closed, so only the base frequency
+        check(15, "envelope (closed)", 0.0, frames[15].envelope),
+        check(15, "frequency (base)", BASE_FREQUENCY, frames[15].frequency),
+        // Halfway through the fade: an even mix of both branches
+        check(30, "pad (triangle peak)", 1.0, half_fade.pad),
+        check(
+            30,
+            "out (half carrier, half pad)",
+            0.5 * (half_fade.carrier + half_fade.pad),
+            half_fade.out,
+        ),
+        // After the fade only the pad plays: cycle 0.1 and 59/60
+        check(66, "out (pad rising)", -0.6, frames[66].out),
+        check(119, "out (pad at cycle end)", -14.0 / 15.0, frames[119].out),
+    ]
+}
@@ -0,0 +1,351 @@
+//! Random edit sequences checked against graph invariants
+//!
+//! Each run builds a graph from the default registry by applying random
+//! operations (add, connect, disconnect, remove, set default, undo, redo,
+//! evaluate) through an [`UndoRedoStack`], checking after every step that:
+//!
+//! - [`Graph::check_invariants`] reports nothing
+//! - a step that changed the saved form of the graph emitted events
+//! - undo and redo restore byte-identical saved states
+//! - evaluation never panics
+//!
+//! Runs are seeded, and ids come from a seeded [`IdGenerator`], so a failure
+//! reproduces exactly. The failure message carries the seed and the
+//! operations applied; rerun a single seed with
+//!
+//! ```text
+//! FLUX_FUZZ_SEED=<seed> cargo test --test graph_fuzz
+//! ```
+//!
+//! `FLUX_FUZZ_STEPS` overrides the number of operations per run.
+
+use std::panic::{self, AssertUnwindSafe};
+
+use flux_core::{Color, EvalContext, Id, IdGenerator, Value, ValueType};
+use flux_graph::commands::{
+    AddNodeCommand, ConnectCommand, DisconnectCommand, RemoveNodeCommand, SetInputDefaultCommand,
+};
+use flux_graph::serialization::{save_symbol_str, SymbolDef, SymbolFile};
+use flux_graph::{ConversionOp, Graph, UndoRedoStack, UnitConversionOp};
+use flux_operators::{create_default_registry, OperatorRegistry};
+
+const RUNS: u64 = 16;
+const STEPS: usize = 300;
+/// Adds become rarer past this many nodes
+const TARGET_NODES: usize = 24;
+
+/// SplitMix64; small, seedable and stable across platforms
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n` (`n` must be non-zero)
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    fn pick<T: Copy>(&mut self, items: &[T]) -> Option<T> {
+        (!items.is_empty()).then(|| items[self.below(items.len())])
+    }
+
+    /// Uniform in `-range..range`
+    fn float(&mut self, range: f32) -> f32 {
+        let unit = (self.next_u64() >> 40) as f32 / (1u64 << 24) as f32;
+        (unit * 2.0 - 1.0) * range
+    }
+
+    /// A random value of `value_type`, kept small so list-building
+    /// operators stay cheap
+    fn value(&mut self, value_type: ValueType) -> Value {
+        match value_type {
+            ValueType::Float => Value::Float(self.float(10.0)),
+            ValueType::Int => Value::Int(self.below(21) as i32 - 10),
+            ValueType::Bool => Value::Bool(self.chance(50)),
+            ValueType::Vec2 => Value::Vec2([self.float(10.0), self.float(10.0)]),
+            ValueType::Vec3 => Value::Vec3([self.float(10.0), self.float(10.0), self.float(10.0)]),
+            ValueType::Vec4 => Value::Vec4([0.0; 4].map(|_| self.float(10.0))),
+            ValueType::String => Value::String(format!("s{}", self.below(100))),
+            ValueType::Color => {
+                let [r, g, b, a] = [0.0; 4].map(|_| self.float(0.5) + 0.5);
+                Value::Color(Color::rgba(r, g, b, a))
+            }
+            other => other.default_value(),
+        }
+    }
+}
+
+struct Fuzzer {
+    seed: u64,
+    rng: Rng,
+    registry: OperatorRegistry,
+    operators: Vec<&'static str>,
+    graph: Graph,
+    history: UndoRedoStack,
+    /// Saved state before each undoable command, innermost last
+    undo_states: Vec<String>,
+    /// Saved state before each undone command was undone, innermost last
+    redo_states: Vec<String>,
+    frame: u64,
+    /// Operations applied so far, for the failure report
+    log: Vec<String>,
+}
+
+impl Fuzzer {
+    fn new(seed: u64) -> Self {
+        let registry = create_default_registry();
+        let mut operators = registry.list_names();
+        operators.sort_unstable();
+        let mut graph = Graph::new();
+        // Undoing a removal restores the node and its connections from the trash
+        graph.set_trash_capacity(usize::MAX);
+        Self {
+            seed,
+            rng: Rng(seed),
+            registry,
+            operators,
+            graph,
+            history: UndoRedoStack::new(),
+            undo_states: Vec::new(),
+            redo_states: Vec::new(),
+            frame: 0,
+            log: Vec::new(),
+        }
+    }
+
+    /// The graph's saved form, which ignores auto-inserted conversion nodes
+    fn saved_state(&self) -> String {
+        Id::with_generator(IdGenerator::sequential(0), || {
+            let def = SymbolDef::from_graph("Fuzz", &self.graph, &self.registry);
+            save_symbol_str(&SymbolFile::from_def(def)).expect("graph serializes")
+        })
+    }
+
+    /// Nodes a user could edit; auto-inserted conversions aren't saved, so
+    /// they're left to the graph
+    fn node_ids(&self) -> Vec<Id> {
+        let mut ids = self.graph.node_ids_in_insertion_order();
+        ids.retain(|&id| {
+            let any = self.graph.get(id).map(|op| op.as_any());
+            !any.is_some_and(|any| any.is::<ConversionOp>() || any.is::<UnitConversionOp>())
+        });
+        ids
+    }
+
+    fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+            let violations = self.graph.check_invariants();
+            assert!(violations.is_empty(), "invariants violated: {:#?}", violations);
+        }
+    }
+
+    fn step(&mut self) {
+        let before = self.saved_state();
+        let nodes = self.node_ids();
+        let add_weight = if nodes.len() < TARGET_NODES { 25 } else { 5 };
+        let roll = self.rng.below(100 + add_weight);
+        let changes_expected = match roll {
+            _ if roll < add_weight => self.add(),
+            _ if roll < add_weight + 30 => self.connect(&nodes),
+            _ if roll < add_weight + 40 => self.disconnect(&nodes),
+            _ if roll < add_weight + 48 => self.remove(&nodes),
+            _ if roll < add_weight + 63 => self.set_default(&nodes),
+            _ if roll < add_weight + 75 => return self.undo(before),
+            _ if roll < add_weight + 85 => return self.redo(before),
+            _ => return self.evaluate(&nodes),
+        };
+        if changes_expected.is_some() {
+            self.undo_states.push(before.clone());
+            self.redo_states.clear();
+        }
+        self.check_events(&before);
+    }
+
+    /// A mutation that changed the saved form must have emitted events
+    fn check_events(&mut self, before: &str) {
+        let changed = self.saved_state() != before;
+        let events = self.graph.drain_events().count();
+        assert!(!changed || events > 0, "graph changed without emitting events");
+    }
+
+    fn add(&mut self) -> Option<()> {
+        let name = self.rng.pick(&self.operators)?;
+        let operator = self.registry.create_by_name(name)?;
+        self.log.push(format!("add {} as {}", name, operator.id()));
+        self.history.execute(&mut self.graph, AddNodeCommand::from_boxed(operator));
+        Some(())
+    }
+
+    fn connect(&mut self, nodes: &[Id]) -> Option<()> {
+        // Retry a few times to find a type-compatible pair
+        for _ in 0..8 {
+            let (source, target) = (self.rng.pick(nodes)?, self.rng.pick(nodes)?);
+            let source_op = self.graph.get(source)?;
+            let target_op = self.graph.get(target)?;
+            if source_op.outputs().is_empty() || target_op.inputs().is_empty() {
+                continue;
+            }
+            let output = self.rng.below(source_op.outputs().len());
+            let input = self.rng.below(target_op.inputs().len());
+            let source_type = source_op.outputs()[output].value_type;
+            let target_type = target_op.inputs()[input].value_type;
+            if !source_type.can_coerce_to(target_type) {
+                continue;
+            }
+            self.log.push(format!("connect {}:{} -> {}:{}", source, output, target, input));
+            let command = ConnectCommand::new(source, output, target, input);
+            self.history.execute(&mut self.graph, command);
+            return Some(());
+        }
+        None
+    }
+
+    fn disconnect(&mut self, nodes: &[Id]) -> Option<()> {
+        let connected: Vec<(Id, usize)> = nodes
+            .iter()
+            .flat_map(|&id| {
+                let inputs = self.graph.get(id).map(|op| op.inputs()).unwrap_or_default();
+                inputs
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, input)| input.connection.is_some())
+                    .map(move |(index, _)| (id, index))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        let (target, input) = self.rng.pick(&connected)?;
+        self.log.push(format!("disconnect {}:{}", target, input));
+        self.history.execute(&mut self.graph, DisconnectCommand::new(target, input));
+        Some(())
+    }
+
+    fn remove(&mut self, nodes: &[Id]) -> Option<()> {
+        let id = self.rng.pick(nodes)?;
+        self.log.push(format!("remove {}", id));
+        self.history.execute(&mut self.graph, RemoveNodeCommand::new(id));
+        Some(())
+    }
+
+    fn set_default(&mut self, nodes: &[Id]) -> Option<()> {
+        let id = self.rng.pick(nodes)?;
+        let inputs = self.graph.get(id)?.inputs();
+        if inputs.is_empty() {
+            return None;
+        }
+        let index = self.rng.below(inputs.len());
+        let value = self.rng.value(inputs[index].value_type);
+        self.log.push(format!("set {}:{} = {:?}", id, index, value));
+        let command = SetInputDefaultCommand::new(id, index, value);
+        self.history.execute(&mut self.graph, command);
+        Some(())
+    }
+
+    fn undo(&mut self, before: String) {
+        if !self.history.undo(&mut self.graph) {
+            return;
+        }
+        self.log.push("undo".to_string());
+        let expected = self.undo_states.pop().expect("an undoable command was recorded");
+        self.redo_states.push(before.clone());
+        check_same(&expected, &self.saved_state(), "undo");
+        self.check_events(&before);
+    }
+
+    fn redo(&mut self, before: String) {
+        if !self.history.redo(&mut self.graph) {
+            return;
+        }
+        self.log.push("redo".to_string());
+        let expected = self.redo_states.pop().expect("an undone command was recorded");
+        self.undo_states.push(before.clone());
+        check_same(&expected, &self.saved_state(), "redo");
+        self.check_events(&before);
+    }
+
+    fn evaluate(&mut self, nodes: &[Id]) {
+        let Some(id) = self.rng.pick(nodes) else {
+            return;
+        };
+        let outputs = self.graph.get(id).map_or(0, |op| op.outputs().len());
+        if outputs == 0 {
+            return;
+        }
+        let output = self.rng.below(outputs);
+        let mut ctx = EvalContext::new();
+        self.frame += 1;
+        ctx.frame = self.frame;
+        ctx.time = f64::from(self.rng.float(100.0)).abs();
+        self.log.push(format!("evaluate {}:{} at {:.3}", id, output, ctx.time));
+        // Errors are fine; panics are not
+        let _ = self.graph.evaluate(id, output, &ctx);
+        self.graph.clear_events();
+    }
+}
+
+/// Panic unless `actual` matches `expected`, showing where they first differ
+fn check_same(expected: &str, actual: &str, step: &str) {
+    if expected == actual {
+        return;
+    }
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let first = expected.iter().zip(&actual).take_while(|(e, a)| e == a).count();
+    let start = first.saturating_sub(12);
+    let excerpt = |lines: &[&str]| lines[start..lines.len().min(first + 6)].join("\n");
+    panic!(
+        "{} did not restore the saved state; first difference at line {}\n\
+         expected:\n{}\nactual:\n{}",
+        step,
+        first + 1,
+        excerpt(&expected),
+        excerpt(&actual)
+    );
+}
+
+/// Run one seed, panicking with a reproducible report on failure
+fn fuzz(seed: u64, steps: usize) {
+    Id::with_generator(IdGenerator::seeded(seed), || {
+        let mut fuzzer = Fuzzer::new(seed);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| fuzzer.run(steps)));
+        if let Err(cause) = result {
+            let message = cause
+                .downcast_ref::<String>()
+                .map(String::as_str)
+                .or_else(|| cause.downcast_ref::<&str>().copied())
+                .unwrap_or("panic");
+            panic!(
+                "fuzz seed {} failed after {} operations: {}\n\
+                 rerun with FLUX_FUZZ_SEED={}\noperations:\n  {}",
+                fuzzer.seed,
+                fuzzer.log.len(),
+                message,
+                fuzzer.seed,
+                fuzzer.log.join("\n  ")
+            );
+        }
+    });
+}
+
+fn env_number(name: &str) -> Option<u64> {
+    std::env::var(name).ok()?.parse().ok()
+}
+
+#[test]
+fn random_edits_keep_graph_consistent() {
+    let steps = env_number("FLUX_FUZZ_STEPS").map_or(STEPS, |steps| steps as usize);
+    match env_number("FLUX_FUZZ_SEED") {
+        Some(seed) => fuzz(seed, steps),
+        None => (0..RUNS).for_each(|seed| fuzz(seed, steps)),
+    }
+}
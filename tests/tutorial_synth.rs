@@ -0,0 +1,87 @@
+//! The `tutorial_synth` example, run as a test
+//!
+//! Builds the synth, plays it, saves and reloads it and checks it against
+//! the committed fixture. If the synth changes on purpose, regenerate the
+//! fixture with
+//!
+//! ```text
+//! cargo run --example tutorial_synth -- --bless
+//! ```
+
+use flux_examples::tutorial_synth::{
+    verify, FrameValues, TutorialSynth, EPSILON, FIXTURE_GRAPH, FIXTURE_SYMBOL, FRAMES,
+};
+use flux_graph::playback::TransportEvent;
+use flux_graph::serialization::{
+    load_graph, load_graph_str, load_symbol, load_symbol_str, save_graph_str, save_symbol_str,
+};
+use flux_operators::create_default_registry;
+
+fn assert_verified(frames: &[FrameValues]) {
+    let failed: Vec<String> = verify(frames)
+        .iter()
+        .filter(|check| !check.passed())
+        .map(ToString::to_string)
+        .collect();
+    assert!(failed.is_empty(), "failed checks:\n{}", failed.join("\n"));
+}
+
+fn assert_same_frames(a: &[FrameValues], b: &[FrameValues]) {
+    assert_eq!(a.len(), b.len());
+    for (frame, (a, b)) in a.iter().zip(b).enumerate() {
+        assert!(
+            (a.out - b.out).abs() <= EPSILON,
+            "frame {}: {} vs {}",
+            frame,
+            a.out,
+            b.out
+        );
+    }
+}
+
+#[test]
+fn test_tutorial_synth_verifies() {
+    let registry = create_default_registry();
+    let mut synth = TutorialSynth::build(&registry).unwrap();
+    let frames = synth.render(FRAMES).unwrap();
+    assert_verified(&frames);
+
+    let events: Vec<_> = synth.transport.drain_events().collect();
+    assert_eq!(events, [TransportEvent::Started, TransportEvent::Paused]);
+    assert!((synth.transport.playhead() - FRAMES as f64 / 60.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_tutorial_synth_roundtrip() {
+    let registry = create_default_registry();
+    let mut synth = TutorialSynth::build(&registry).unwrap();
+    let (symbol, graph) = synth.to_files(&registry);
+    let frames = synth.render(FRAMES).unwrap();
+
+    let symbol = load_symbol_str(&save_symbol_str(&symbol).unwrap()).unwrap();
+    let graph = load_graph_str(&save_graph_str(&graph).unwrap()).unwrap();
+    let mut loaded = TutorialSynth::from_files(&symbol, &graph, &registry).unwrap();
+    assert_eq!(
+        loaded.transport.settings().bpm,
+        synth.transport.settings().bpm
+    );
+    assert_eq!(loaded.animator.binding_count(), 1);
+    assert_same_frames(&frames, &loaded.render(FRAMES).unwrap());
+}
+
+#[test]
+fn test_tutorial_synth_fixture_is_current() {
+    let registry = create_default_registry();
+    let (symbol, graph) = TutorialSynth::fixture_files(&registry).unwrap();
+    let committed = |path| std::fs::read_to_string(path).unwrap().replace("\r\n", "\n");
+    assert_eq!(save_symbol_str(&symbol).unwrap(), committed(FIXTURE_SYMBOL));
+    assert_eq!(save_graph_str(&graph).unwrap(), committed(FIXTURE_GRAPH));
+
+    let mut fixture = TutorialSynth::from_files(
+        &load_symbol(FIXTURE_SYMBOL).unwrap(),
+        &load_graph(FIXTURE_GRAPH).unwrap(),
+        &registry,
+    )
+    .unwrap();
+    assert_verified(&fixture.render(FRAMES).unwrap());
+}